@@ -21,6 +21,9 @@ pub(crate) enum Commands {
     /// Execute the main code generator
     Generate(GenerateCommandArgs),
 
+    /// Verify the generated code is up to date, without modifying it
+    Check(CheckCommandArgs),
+
     /// Create a new Flutter + Rust project
     Create(CreateCommandArgs),
 
@@ -30,6 +33,9 @@ pub(crate) enum Commands {
     /// Compile for the Web (WASM)
     BuildWeb(BuildWebCommandArgs),
 
+    /// Report API changes between two MIR dumps, classified as breaking or not for Dart callers
+    ApiDiff(ApiDiffCommandArgs),
+
     /// Generate internally used code
     #[clap(hide = true)]
     InternalGenerate(InternalGenerateCommandArgs),
@@ -45,6 +51,12 @@ pub(crate) struct GenerateCommandArgs {
     pub primary: GenerateCommandArgsPrimary,
 }
 
+#[derive(Debug, Args, Default, Eq, PartialEq)]
+pub(crate) struct CheckCommandArgs {
+    #[clap(flatten)]
+    pub primary: GenerateCommandArgsPrimary,
+}
+
 // Deliberately decoupled from `codegen::Config`,
 // because the command line arguments contains extra things like `--config-file`,
 // which is not a config to the real codegen.
@@ -165,6 +177,24 @@ pub(crate) struct GenerateCommandArgsPrimary {
     #[arg(long)]
     pub stop_on_error: bool,
 
+    /// Use the compact serialization codec (varint-based) for all functions
+    #[arg(long)]
+    pub compact_serialize: bool,
+
+    /// Disable the on-disk cache, which skips `cargo expand` and code generation when the inputs are unchanged
+    #[arg(long)]
+    pub no_cache: bool,
+
+    /// Read the Rust crate from the file system instead of using `cargo expand`, which is faster.
+    /// Falls back to `cargo expand` when items generated by macros are detected.
+    #[arg(long)]
+    pub no_cargo_expand: bool,
+
+    /// Features of the Rust crate that are enabled when evaluating `#[cfg(...)]` without `cargo expand`.
+    /// Defaults to the default features of the crate.
+    #[arg(long, num_args = 1..)]
+    pub rust_features: Option<Vec<String>>,
+
     /// A list of data to be dumped. If specified without a value, defaults to all.
     #[arg(long, value_enum, num_args = 0.., default_missing_values = ["config", "ir"])]
     pub dump: Option<Vec<ConfigDumpContent>>,
@@ -245,6 +275,19 @@ pub(crate) struct BuildWebCommandArgs {
     pub(crate) args: Vec<String>,
 }
 
+#[derive(Debug, Args)]
+pub(crate) struct ApiDiffCommandArgs {
+    /// MIR dump of the old version, e.g. `target/frb_dump/mir/2_filter_trait_impl_transformer.json` produced by `generate --dump mir`
+    pub(crate) old: PathBuf,
+
+    /// MIR dump of the new version
+    pub(crate) new: PathBuf,
+
+    /// Exit with a non-zero code if there are breaking changes
+    #[arg(long)]
+    pub(crate) fail_on_breaking: bool,
+}
+
 #[derive(Debug, Args)]
 pub(crate) struct InternalGenerateCommandArgs {}
 
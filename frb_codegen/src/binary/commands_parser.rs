@@ -54,10 +54,15 @@ fn compute_codegen_config_from_naive_command_args(args: GenerateCommandArgsPrima
         local: positive_bool_arg(args.local),
         default_external_library_loader_web_prefix: args.default_external_library_loader_web_prefix,
         dart_type_rename: None, // complex type, not supported on command line yet
+        thread_pools: None,     // complex type, not supported on command line yet
         enable_lifetime: positive_bool_arg(args.enable_lifetime),
         type_64bit_int: positive_bool_arg(args.type_64bit_int),
         default_dart_async: negative_bool_arg(args.no_default_dart_async),
         stop_on_error: positive_bool_arg(args.stop_on_error),
+        compact_serialize: positive_bool_arg(args.compact_serialize),
+        cache: negative_bool_arg(args.no_cache),
+        cargo_expand: negative_bool_arg(args.no_cargo_expand),
+        rust_features: args.rust_features,
         dump: args.dump,
         dump_all: positive_bool_arg(args.dump_all),
     }
@@ -0,0 +1,448 @@
+//! Compare two MIR dumps (`--dump mir`) and report the changes of the API seen by Dart
+
+use anyhow::Context;
+use itertools::Itertools;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::Path;
+
+/// Changes between two versions of the API
+#[derive(Debug, Default)]
+pub struct ApiDiffReport {
+    pub changes: Vec<ApiChange>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiChange {
+    /// Whether existing Dart callers may fail to compile or behave differently
+    pub breaking: bool,
+    pub description: String,
+}
+
+impl ApiDiffReport {
+    pub fn has_breaking(&self) -> bool {
+        self.changes.iter().any(|change| change.breaking)
+    }
+
+    fn add(&mut self, breaking: bool, description: String) {
+        self.changes.push(ApiChange {
+            breaking,
+            description,
+        });
+    }
+}
+
+impl fmt::Display for ApiDiffReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.changes.is_empty() {
+            return writeln!(f, "No API changes.");
+        }
+        for (breaking, title) in [(true, "Breaking changes"), (false, "Non-breaking changes")] {
+            let changes = (self.changes.iter())
+                .filter(|change| change.breaking == breaking)
+                .collect_vec();
+            if !changes.is_empty() {
+                writeln!(f, "{title}:")?;
+                for change in changes {
+                    writeln!(f, "  - {}", change.description)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Compare two MIR dump files, such as `target/frb_dump/mir/2_filter_trait_impl_transformer.json`
+pub fn api_diff(old_path: &Path, new_path: &Path) -> anyhow::Result<ApiDiffReport> {
+    let read = |path: &Path| -> anyhow::Result<Value> {
+        let text = std::fs::read_to_string(path)
+            .with_context(|| format!("Cannot read MIR dump {path:?}"))?;
+        serde_json::from_str(&text).with_context(|| format!("Cannot parse MIR dump {path:?}"))
+    };
+    Ok(compute_diff(&read(old_path)?, &read(new_path)?))
+}
+
+fn compute_diff(old: &Value, new: &Value) -> ApiDiffReport {
+    let mut report = ApiDiffReport::default();
+    diff_items(
+        &mut report,
+        "function",
+        &funcs_by_name(old),
+        &funcs_by_name(new),
+        diff_func,
+    );
+    diff_items(
+        &mut report,
+        "struct",
+        &pool_by_name(&old["struct_pool"]),
+        &pool_by_name(&new["struct_pool"]),
+        |report, name, old, new| diff_fields(report, &format!("struct `{name}`"), old, new),
+    );
+    diff_items(
+        &mut report,
+        "enum",
+        &pool_by_name(&old["enum_pool"]),
+        &pool_by_name(&new["enum_pool"]),
+        diff_enum,
+    );
+    report
+}
+
+fn funcs_by_name(pack: &Value) -> BTreeMap<String, &Value> {
+    (pack["funcs_all"].as_array().into_iter().flatten())
+        .map(|func| (str_of(&func["name"]), func))
+        .collect()
+}
+
+fn pool_by_name(pool: &Value) -> BTreeMap<String, &Value> {
+    (pool.as_object().into_iter().flatten())
+        .filter(|(_, item)| item["ignore"] != Value::Bool(true))
+        .map(|(name, item)| (name.clone(), item))
+        .collect()
+}
+
+fn diff_items(
+    report: &mut ApiDiffReport,
+    kind: &str,
+    old: &BTreeMap<String, &Value>,
+    new: &BTreeMap<String, &Value>,
+    diff_item: impl Fn(&mut ApiDiffReport, &str, &Value, &Value),
+) {
+    for (name, old_item) in old {
+        match new.get(name) {
+            Some(new_item) => diff_item(report, name, old_item, new_item),
+            None => report.add(true, format!("Removed {kind} `{name}`")),
+        }
+    }
+    for name in new.keys().filter(|name| !old.contains_key(*name)) {
+        report.add(false, format!("Added {kind} `{name}`"));
+    }
+}
+
+fn diff_func(report: &mut ApiDiffReport, name: &str, old: &Value, new: &Value) {
+    let subject = format!("function `{name}`");
+
+    for (key, what) in [
+        ("dart_name", "Dart name"),
+        ("mode", "sync/async mode"),
+        ("arg_mode", "argument mode"),
+        ("owner", "owner"),
+    ] {
+        if old[key] != new[key] {
+            report.add(
+                true,
+                format!(
+                    "Changed {what} of {subject} from {} to {}",
+                    brief(&old[key]),
+                    brief(&new[key])
+                ),
+            );
+        }
+    }
+
+    for (key, what) in [("normal", "return type"), ("error", "error type")] {
+        let (old_ty, new_ty) = (&old["output"][key], &new["output"][key]);
+        if type_name(old_ty) != type_name(new_ty) {
+            report.add(
+                true,
+                format!(
+                    "Changed {what} of {subject} from `{}` to `{}`",
+                    type_name(old_ty),
+                    type_name(new_ty)
+                ),
+            );
+        }
+    }
+
+    let inputs = |func: &Value| -> Vec<Value> {
+        (func["inputs"].as_array().into_iter().flatten())
+            .map(|input| input["inner"].clone())
+            .collect()
+    };
+    let positional = new["arg_mode"] == "Positional";
+    diff_field_list(
+        report,
+        &subject,
+        "argument",
+        &inputs(old),
+        &inputs(new),
+        positional,
+    );
+}
+
+fn diff_enum(report: &mut ApiDiffReport, name: &str, old: &Value, new: &Value) {
+    let subject = format!("enum `{name}`");
+
+    if old["mode"] != new["mode"] {
+        report.add(
+            true,
+            format!(
+                "Changed {subject} from {} to {} (plain Dart enum vs class hierarchy)",
+                brief(&old["mode"]),
+                brief(&new["mode"])
+            ),
+        );
+    }
+
+    let variants = |item: &Value| -> BTreeMap<String, Value> {
+        (item["variants"].as_array().into_iter().flatten())
+            .map(|variant| (str_of(&variant["name"]["rust_style"]), variant.clone()))
+            .collect()
+    };
+    let (old_variants, new_variants) = (variants(old), variants(new));
+
+    for (variant_name, old_variant) in &old_variants {
+        let Some(new_variant) = new_variants.get(variant_name) else {
+            report.add(
+                true,
+                format!("Removed variant `{variant_name}` of {subject}"),
+            );
+            continue;
+        };
+        let (old_kind, new_kind) = (
+            &old_variant["kind"]["Struct"],
+            &new_variant["kind"]["Struct"],
+        );
+        if old_kind.is_null() != new_kind.is_null() {
+            report.add(
+                true,
+                format!(
+                    "Changed variant `{variant_name}` of {subject} between unit and data-carrying"
+                ),
+            );
+        } else if !old_kind.is_null() {
+            diff_fields(
+                report,
+                &format!("variant `{variant_name}` of {subject}"),
+                old_kind,
+                new_kind,
+            );
+        }
+    }
+    for variant_name in new_variants
+        .keys()
+        .filter(|x| !old_variants.contains_key(*x))
+    {
+        // Dart 3 `switch` on enums and sealed classes must be exhaustive
+        report.add(
+            true,
+            format!("Added variant `{variant_name}` of {subject} (breaks exhaustive `switch`es)"),
+        );
+    }
+}
+
+fn diff_fields(report: &mut ApiDiffReport, subject: &str, old: &Value, new: &Value) {
+    let fields = |item: &Value| item["fields"].as_array().cloned().unwrap_or_default();
+    let positional = new["is_fields_named"] == Value::Bool(false);
+    if old["is_fields_named"] != new["is_fields_named"] {
+        report.add(
+            true,
+            format!("Changed {subject} between named and positional fields"),
+        );
+    }
+    diff_field_list(
+        report,
+        subject,
+        "field",
+        &fields(old),
+        &fields(new),
+        positional,
+    );
+}
+
+/// Compare fields of structs or arguments of functions, which share the same representation
+fn diff_field_list(
+    report: &mut ApiDiffReport,
+    subject: &str,
+    kind: &str,
+    old: &[Value],
+    new: &[Value],
+    positional: bool,
+) {
+    let field_name = |field: &Value| str_of(&field["name"]["rust_style"]);
+    let old_by_name: BTreeMap<_, _> = old.iter().map(|x| (field_name(x), x)).collect();
+    let new_by_name: BTreeMap<_, _> = new.iter().map(|x| (field_name(x), x)).collect();
+
+    for (name, old_field) in &old_by_name {
+        match new_by_name.get(name) {
+            Some(new_field) => {
+                let (old_ty, new_ty) = (type_name(&old_field["ty"]), type_name(&new_field["ty"]));
+                if old_ty != new_ty {
+                    report.add(
+                        true,
+                        format!("Changed type of {kind} `{name}` of {subject} from `{old_ty}` to `{new_ty}`"),
+                    );
+                }
+            }
+            None => report.add(true, format!("Removed {kind} `{name}` of {subject}")),
+        }
+    }
+
+    for (name, new_field) in &new_by_name {
+        if old_by_name.contains_key(name) {
+            continue;
+        }
+        // Positional parameters are always required in the generated Dart code
+        let optional = !positional
+            && (!new_field["default"].is_null() || new_field["ty"]["type"] == "Optional");
+        report.add(
+            !optional,
+            format!(
+                "Added {} {kind} `{name}` of {subject}",
+                if optional { "optional" } else { "required" }
+            ),
+        );
+    }
+
+    if positional {
+        let names = |fields: &[Value]| fields.iter().map(field_name).collect_vec();
+        let (old_names, new_names) = (names(old), names(new));
+        let common_old = (old_names.iter())
+            .filter(|x| new_names.contains(x))
+            .collect_vec();
+        let common_new = (new_names.iter())
+            .filter(|x| old_names.contains(x))
+            .collect_vec();
+        if common_old != common_new {
+            report.add(true, format!("Reordered positional {kind}s of {subject}"));
+        }
+    }
+}
+
+fn type_name(ty: &Value) -> String {
+    match ty {
+        Value::Null => "()".to_owned(),
+        _ => str_of(&ty["safe_ident"]),
+    }
+}
+
+fn brief(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Null => "none".to_owned(),
+        _ => value.to_string(),
+    }
+}
+
+fn str_of(value: &Value) -> String {
+    value.as_str().unwrap_or_default().to_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn field(name: &str, safe_ident: &str, ty: &str) -> Value {
+        json!({
+            "name": {"rust_style": name},
+            "ty": {"safe_ident": safe_ident, "type": ty},
+            "default": null,
+        })
+    }
+
+    fn func(name: &str, inputs: Vec<Value>) -> Value {
+        json!({
+            "name": name,
+            "dart_name": null,
+            "mode": "Normal",
+            "arg_mode": "Named",
+            "owner": "Function",
+            "inputs": inputs.into_iter().map(|x| json!({"inner": x})).collect_vec(),
+            "output": {"normal": {"safe_ident": "unit", "type": "Primitive"}, "error": null},
+        })
+    }
+
+    fn pack(funcs: Vec<Value>, structs: Value, enums: Value) -> Value {
+        json!({"funcs_all": funcs, "struct_pool": structs, "enum_pool": enums})
+    }
+
+    fn descriptions(report: &ApiDiffReport, breaking: bool) -> Vec<String> {
+        (report.changes.iter())
+            .filter(|x| x.breaking == breaking)
+            .map(|x| x.description.clone())
+            .collect()
+    }
+
+    #[test]
+    fn test_compute_diff_functions() {
+        let old = pack(
+            vec![
+                func("crate::api/f", vec![field("a", "i_32", "Primitive")]),
+                func("crate::api/g", vec![]),
+            ],
+            json!({}),
+            json!({}),
+        );
+        let new = pack(
+            vec![
+                func(
+                    "crate::api/f",
+                    vec![
+                        field("a", "String", "Delegate"),
+                        field("b", "opt_String", "Optional"),
+                    ],
+                ),
+                func("crate::api/h", vec![]),
+            ],
+            json!({}),
+            json!({}),
+        );
+
+        let report = compute_diff(&old, &new);
+        assert_eq!(
+            descriptions(&report, true),
+            vec![
+                "Changed type of argument `a` of function `crate::api/f` from `i_32` to `String`",
+                "Removed function `crate::api/g`",
+            ]
+        );
+        assert_eq!(
+            descriptions(&report, false),
+            vec![
+                "Added optional argument `b` of function `crate::api/f`",
+                "Added function `crate::api/h`",
+            ]
+        );
+        assert!(report.has_breaking());
+    }
+
+    #[test]
+    fn test_compute_diff_structs_and_enums() {
+        let old = pack(
+            vec![],
+            json!({"crate::api/S": {"is_fields_named": true, "fields": [field("a", "i_32", "Primitive")]}}),
+            json!({"crate::api/E": {"mode": "Simple", "variants": [{"name": {"rust_style": "A"}, "kind": "Value"}]}}),
+        );
+        let new = pack(
+            vec![],
+            json!({"crate::api/S": {"is_fields_named": true, "fields": [
+                field("a", "i_32", "Primitive"),
+                field("b", "i_32", "Primitive"),
+            ]}}),
+            json!({"crate::api/E": {"mode": "Simple", "variants": [
+                {"name": {"rust_style": "A"}, "kind": "Value"},
+                {"name": {"rust_style": "B"}, "kind": "Value"},
+            ]}}),
+        );
+
+        let report = compute_diff(&old, &new);
+        assert_eq!(
+            descriptions(&report, true),
+            vec![
+                "Added required field `b` of struct `crate::api/S`",
+                "Added variant `B` of enum `crate::api/E` (breaks exhaustive `switch`es)",
+            ]
+        );
+        assert!(descriptions(&report, false).is_empty());
+    }
+
+    #[test]
+    fn test_compute_diff_unchanged() {
+        let value = pack(vec![func("crate::api/f", vec![])], json!({}), json!({}));
+        let report = compute_diff(&value, &value);
+        assert!(report.changes.is_empty());
+        assert_eq!(report.to_string(), "No API changes.\n");
+    }
+}
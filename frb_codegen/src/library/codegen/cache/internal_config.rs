@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub(crate) struct CacheInternalConfig {
+    pub(crate) enabled: bool,
+    pub(crate) cache_directory: PathBuf,
+    /// Files that are outputs of the code generator, thus not considered as inputs
+    pub(crate) excluded_source_paths: Vec<PathBuf>,
+}
@@ -0,0 +1,66 @@
+use std::any::Any;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+type Value = Rc<dyn Any>;
+
+/// In-memory cache shared by the iterations of watch mode, for values that are too expensive
+/// to serialize to disk.
+///
+/// Entries that are not used during an iteration are dropped at the start of the next one,
+/// so that it does not grow without bound while the user keeps editing.
+#[derive(Default)]
+pub(crate) struct MemoryCache {
+    generations: RefCell<Generations>,
+}
+
+#[derive(Default)]
+struct Generations {
+    current: HashMap<String, Value>,
+    previous: HashMap<String, Value>,
+}
+
+impl MemoryCache {
+    pub(crate) fn start_iteration(&self) {
+        let mut generations = self.generations.borrow_mut();
+        generations.previous = std::mem::take(&mut generations.current);
+    }
+
+    pub(crate) fn get<T: 'static>(&self, key: &str) -> Option<Rc<T>> {
+        let mut generations = self.generations.borrow_mut();
+        let value = match generations.current.get(key) {
+            Some(value) => value.clone(),
+            None => {
+                let value = generations.previous.remove(key)?;
+                generations.current.insert(key.to_owned(), value.clone());
+                value
+            }
+        };
+        value.downcast().ok()
+    }
+
+    pub(crate) fn insert<T: 'static>(&self, key: String, value: Rc<T>) {
+        (self.generations.borrow_mut()).current.insert(key, value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_drop_entries_unused_for_an_iteration() {
+        let cache = MemoryCache::default();
+        cache.insert("a".to_owned(), Rc::new(1));
+        cache.insert("b".to_owned(), Rc::new(2));
+
+        cache.start_iteration();
+        assert_eq!(cache.get::<i32>("a").as_deref(), Some(&1));
+        assert_eq!(cache.get::<String>("a"), None);
+
+        cache.start_iteration();
+        assert_eq!(cache.get::<i32>("a").as_deref(), Some(&1));
+        assert_eq!(cache.get::<i32>("b"), None);
+    }
+}
@@ -0,0 +1,357 @@
+//! On-disk cache to skip unchanged work across runs of the code generator
+
+use crate::codegen::cache::internal_config::CacheInternalConfig;
+use crate::codegen::cache::memory::MemoryCache;
+use crate::library::commands::cargo_expand::list_module_files;
+use crate::library::commands::cargo_metadata::execute_cargo_metadata;
+use crate::utils::file_utils::create_dir_all_and_write;
+use itertools::Itertools;
+use log::{debug, warn};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use sha1::{Digest, Sha1};
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub(crate) mod internal_config;
+pub(crate) mod memory;
+pub(crate) mod outputs;
+
+#[derive(Clone, Copy)]
+pub(crate) struct Cache<'a> {
+    config: &'a CacheInternalConfig,
+    memory: Option<&'a MemoryCache>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Entry<T> {
+    key: String,
+    value: T,
+}
+
+impl<'a> Cache<'a> {
+    pub(crate) fn new(config: &'a CacheInternalConfig) -> Self {
+        Self {
+            config,
+            memory: None,
+        }
+    }
+
+    /// Also reuse the values that are only kept in memory, e.g. across the iterations of watch mode
+    pub(crate) fn with_memory(self, memory: &'a MemoryCache) -> Self {
+        Self {
+            memory: Some(memory),
+            ..self
+        }
+    }
+
+    /// Reuse the cached value if it was computed with the same key, otherwise compute and store it
+    pub(crate) fn get_or_compute<T: Serialize + DeserializeOwned>(
+        &self,
+        name: &str,
+        key: impl FnOnce() -> anyhow::Result<String>,
+        compute: impl FnOnce() -> anyhow::Result<T>,
+    ) -> anyhow::Result<T> {
+        if !self.config.enabled {
+            return compute();
+        }
+
+        let key = key()?;
+        if let Some(entry) = self.read::<Entry<T>>(name) {
+            if entry.key == key {
+                debug!("Cache hit name={name}");
+                return Ok(entry.value);
+            }
+        }
+
+        debug!("Cache miss name={name}");
+        let value = compute()?;
+        let entry = Entry { key, value };
+        self.write(name, &entry)?;
+        Ok(entry.value)
+    }
+
+    pub(crate) fn is_enabled(&self) -> bool {
+        self.config.enabled
+    }
+
+    pub(crate) fn memory(&self) -> Option<&'a MemoryCache> {
+        self.memory.filter(|_| self.config.enabled)
+    }
+
+    pub(crate) fn read<T: DeserializeOwned>(&self, name: &str) -> Option<T> {
+        if !self.config.enabled {
+            return None;
+        }
+        let text = fs::read_to_string(self.path(name)).ok()?;
+        match serde_json::from_str(&text) {
+            Ok(data) => Some(data),
+            // A corrupted or outdated cache is simply ignored and overwritten later
+            Err(e) => {
+                warn!("Ignore unreadable cache name={name} error={e:?}");
+                None
+            }
+        }
+    }
+
+    pub(crate) fn write<T: Serialize>(&self, name: &str, data: &T) -> anyhow::Result<()> {
+        if !self.config.enabled {
+            return Ok(());
+        }
+        create_dir_all_and_write(self.path(name), serde_json::to_string(data)?)
+    }
+
+    /// Hash of the Rust sources that `cargo expand` depends on, i.e. the files of the crate,
+    /// the sources of its path and workspace dependencies, and the lock file that pins the others.
+    pub(crate) fn compute_rust_sources_hash(
+        &self,
+        rust_crate_dir: &Path,
+    ) -> anyhow::Result<RustSourcesHash> {
+        let module_files = list_module_files(rust_crate_dir);
+        let module_file_paths: HashSet<_> = module_files.iter().map(|x| x.path.clone()).collect();
+
+        let mut paths = vec![];
+        collect_rust_source_paths(rust_crate_dir, &mut paths)?;
+        collect_local_dependency_paths(rust_crate_dir, &mut paths)?;
+        paths.retain(|path| !(self.is_excluded(path) || module_file_paths.contains(path)));
+        paths.extend(
+            (rust_crate_dir.ancestors())
+                .map(|dir| dir.join("Cargo.lock"))
+                .find(|path| path.exists()),
+        );
+
+        let parts = (paths.into_iter().sorted().dedup())
+            .map(|path| {
+                let content = fs::read(&path)?;
+                Ok([path.to_string_lossy().as_bytes().to_owned(), content])
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        let module_hashes = (module_files.into_iter())
+            .map(|file| {
+                let hash = if self.is_excluded(&file.path) {
+                    None
+                } else {
+                    let content = fs::read(&file.path)?;
+                    Some(compute_hash([
+                        file.path.to_string_lossy().as_bytes(),
+                        &content,
+                    ]))
+                };
+                let module_file_hash = ModuleFileHash {
+                    path: file.path,
+                    hash,
+                };
+                Ok((file.module_path, module_file_hash))
+            })
+            .collect::<anyhow::Result<_>>()?;
+
+        Ok(RustSourcesHash {
+            crate_hash: compute_hash(parts.iter().flatten()),
+            module_hashes,
+        })
+    }
+
+    fn is_excluded(&self, path: &Path) -> bool {
+        self.config.excluded_source_paths.iter().any(|x| x == path)
+    }
+
+    fn path(&self, name: &str) -> PathBuf {
+        self.config.cache_directory.join(format!("{name}.json"))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct RustSourcesHash {
+    /// Everything except for the module files
+    pub(crate) crate_hash: String,
+    /// Keyed by the module path, e.g. `api::simple`
+    pub(crate) module_hashes: BTreeMap<String, ModuleFileHash>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ModuleFileHash {
+    pub(crate) path: PathBuf,
+    /// `None` for the outputs of the code generator, whose changes are ignored
+    pub(crate) hash: Option<String>,
+}
+
+impl RustSourcesHash {
+    pub(crate) fn overall(&self) -> String {
+        compute_hash(
+            [self.crate_hash.as_str()].into_iter().chain(
+                (self.module_hashes.iter())
+                    .flat_map(|(module_path, x)| [module_path, x.hash.as_deref().unwrap_or("")]),
+            ),
+        )
+    }
+}
+
+pub(crate) fn compute_hash<T: AsRef<[u8]>>(parts: impl IntoIterator<Item = T>) -> String {
+    let mut hasher = Sha1::new();
+    for part in parts {
+        let part = part.as_ref();
+        // Length prefix, such that `["ab", "c"]` and `["a", "bc"]` differ
+        hasher.update((part.len() as u64).to_le_bytes());
+        hasher.update(part);
+    }
+    hex::encode(hasher.finalize())
+}
+
+/// Sources of the path and workspace dependencies, which are not pinned by the lock file,
+/// and the manifest of the workspace, which may e.g. specify dependencies for the crate
+fn collect_local_dependency_paths(
+    rust_crate_dir: &Path,
+    paths: &mut Vec<PathBuf>,
+) -> anyhow::Result<()> {
+    let metadata = execute_cargo_metadata(&rust_crate_dir.join("Cargo.toml"))?;
+    let root_id = metadata.root_package().map(|package| &package.id);
+    for package in &metadata.packages {
+        if package.source.is_none() && Some(&package.id) != root_id {
+            if let Some(dir) = package.manifest_path.parent() {
+                collect_rust_source_paths(dir.as_std_path(), paths)?;
+            }
+        }
+    }
+
+    let workspace_manifest_path = metadata.workspace_root.join("Cargo.toml");
+    if workspace_manifest_path.exists() {
+        paths.push(workspace_manifest_path.into_std_path_buf());
+    }
+    Ok(())
+}
+
+fn collect_rust_source_paths(dir: &Path, paths: &mut Vec<PathBuf>) -> anyhow::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+        if path.is_dir() {
+            if !(file_name.starts_with('.') || file_name == "target") {
+                collect_rust_source_paths(&path, paths)?;
+            }
+        } else if file_name.ends_with(".rs") || file_name.ends_with(".toml") {
+            paths.push(path);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{compute_hash, Cache};
+    use crate::codegen::cache::internal_config::CacheInternalConfig;
+    use itertools::Itertools;
+    use std::fs;
+
+    #[test]
+    fn test_compute_hash() {
+        assert_ne!(compute_hash(["ab", "c"]), compute_hash(["a", "bc"]));
+        assert_eq!(compute_hash(["a", "b"]), compute_hash(["a", "b"]));
+    }
+
+    #[test]
+    fn test_cache_get_or_compute() -> anyhow::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let config = CacheInternalConfig {
+            enabled: true,
+            cache_directory: dir.path().to_owned(),
+            excluded_source_paths: vec![],
+        };
+        let cache = Cache::new(&config);
+
+        let compute = |value: &str| {
+            let value = value.to_owned();
+            move || Ok(value)
+        };
+        let key = |key: &str| {
+            let key = key.to_owned();
+            move || Ok(key)
+        };
+        assert_eq!(cache.get_or_compute("x", key("k1"), compute("a"))?, "a");
+        assert_eq!(cache.get_or_compute("x", key("k1"), compute("b"))?, "a");
+        assert_eq!(cache.get_or_compute("x", key("k2"), compute("c"))?, "c");
+
+        let disabled_config = CacheInternalConfig {
+            enabled: false,
+            ..config.clone()
+        };
+        let disabled_cache = Cache::new(&disabled_config);
+        assert_eq!(
+            disabled_cache.get_or_compute("x", key("k2"), compute("d"))?,
+            "d"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_compute_rust_sources_hash() -> anyhow::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let write = |path: &str, content: &str| {
+            let path = dir.path().join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        };
+        let rust_crate_dir = dir.path().join("hello");
+        let config = CacheInternalConfig {
+            enabled: true,
+            cache_directory: rust_crate_dir.join("target/frb_cache"),
+            excluded_source_paths: vec![rust_crate_dir.join("src/frb_generated.rs")],
+        };
+        let cache = Cache::new(&config);
+
+        write(
+            "hello/Cargo.toml",
+            r#"
+[package]
+name = "hello"
+version = "0.1.0"
+
+[dependencies]
+world = { path = "../world" }
+"#,
+        );
+        write("hello/src/lib.rs", "mod api; mod frb_generated;");
+        write("hello/src/api.rs", "fn a() {}");
+        write("hello/src/frb_generated.rs", "fn b() {}");
+        write(
+            "world/Cargo.toml",
+            "[package]\nname = \"world\"\nversion = \"0.1.0\"",
+        );
+        write("world/src/lib.rs", "fn c() {}");
+        let hash_original = cache.compute_rust_sources_hash(&rust_crate_dir)?;
+        assert_eq!(
+            hash_original.module_hashes.keys().collect_vec(),
+            vec!["", "api", "frb_generated"],
+        );
+        assert_eq!(hash_original.module_hashes["frb_generated"].hash, None);
+
+        write("hello/target/ignored.rs", "fn d() {}");
+        write("hello/src/frb_generated.rs", "fn e() {}");
+        assert_eq!(
+            cache.compute_rust_sources_hash(&rust_crate_dir)?,
+            hash_original
+        );
+
+        write("hello/src/api.rs", "fn f() {}");
+        let hash_module_changed = cache.compute_rust_sources_hash(&rust_crate_dir)?;
+        assert_eq!(hash_module_changed.crate_hash, hash_original.crate_hash);
+        assert_ne!(
+            hash_module_changed.module_hashes["api"],
+            hash_original.module_hashes["api"]
+        );
+        assert_eq!(
+            hash_module_changed.module_hashes[""],
+            hash_original.module_hashes[""]
+        );
+        assert_ne!(hash_module_changed.overall(), hash_original.overall());
+
+        write("world/src/lib.rs", "fn g() {}");
+        let hash_dependency_changed = cache.compute_rust_sources_hash(&rust_crate_dir)?;
+        assert_ne!(
+            hash_dependency_changed.crate_hash,
+            hash_module_changed.crate_hash
+        );
+        Ok(())
+    }
+}
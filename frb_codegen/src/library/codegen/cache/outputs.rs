@@ -0,0 +1,124 @@
+use crate::codegen::cache::{compute_hash, Cache};
+use crate::codegen::generator::misc::path_texts::{PathText, PathTexts};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const NAME: &str = "outputs";
+
+/// The outputs of the last run, to know which files can be left untouched
+#[derive(Serialize, Deserialize, Default)]
+struct OutputsRecord {
+    key: String,
+    files: Vec<OutputFileRecord>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct OutputFileRecord {
+    path: PathBuf,
+    /// Hash of the text produced by the generator, before polishing
+    generated_hash: String,
+    /// Hash of the file on disk after polishing (formatting etc)
+    disk_hash: String,
+}
+
+impl OutputFileRecord {
+    fn is_disk_unchanged(&self) -> bool {
+        compute_file_hash(&self.path).as_deref() == Some(self.disk_hash.as_str())
+    }
+}
+
+/// Whether the last run used the same key, and its outputs are not modified afterwards
+pub(crate) fn is_up_to_date(cache: &Cache, key: &str) -> bool {
+    cache.read::<OutputsRecord>(NAME).is_some_and(|record| {
+        record.key == key && record.files.iter().all(|file| file.is_disk_unchanged())
+    })
+}
+
+/// Only keep the outputs that differ from what the last run wrote
+pub(crate) fn filter_changed(cache: &Cache, output_texts: &PathTexts) -> PathTexts {
+    let record = cache.read::<OutputsRecord>(NAME).unwrap_or_default();
+    PathTexts(
+        (output_texts.0.iter())
+            .filter(|item| {
+                let unchanged = record.files.iter().any(|file| {
+                    file.path == item.path
+                        && file.generated_hash == compute_generated_hash(item)
+                        && file.is_disk_unchanged()
+                });
+                !unchanged
+            })
+            .cloned()
+            .collect(),
+    )
+}
+
+/// Remember the outputs, which should be called after they are written and polished
+pub(crate) fn record(cache: &Cache, key: &str, output_texts: &PathTexts) -> anyhow::Result<()> {
+    let files = (output_texts.0.iter())
+        .map(|item| OutputFileRecord {
+            path: item.path.clone(),
+            generated_hash: compute_generated_hash(item),
+            disk_hash: compute_file_hash(&item.path).unwrap_or_default(),
+        })
+        .collect();
+    cache.write(
+        NAME,
+        &OutputsRecord {
+            key: key.to_owned(),
+            files,
+        },
+    )
+}
+
+fn compute_generated_hash(item: &PathText) -> String {
+    compute_hash([item.text.all_code()])
+}
+
+fn compute_file_hash(path: &Path) -> Option<String> {
+    fs::read(path).ok().map(|content| compute_hash([content]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{filter_changed, is_up_to_date, record};
+    use crate::codegen::cache::internal_config::CacheInternalConfig;
+    use crate::codegen::cache::Cache;
+    use crate::codegen::generator::misc::path_texts::{PathText, PathTexts};
+    use crate::utils::basic_code::general_code::GeneralCode;
+    use std::fs;
+
+    #[test]
+    fn test_outputs_record() -> anyhow::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let config = CacheInternalConfig {
+            enabled: true,
+            cache_directory: dir.path().join("cache"),
+            excluded_source_paths: vec![],
+        };
+        let cache = Cache::new(&config);
+
+        let path_texts = |text: &str| {
+            PathTexts(vec![PathText::new(
+                dir.path().join("a.rs"),
+                GeneralCode::new_rust(text.to_owned()),
+            )])
+        };
+
+        assert!(!is_up_to_date(&cache, "k"));
+        assert_eq!(filter_changed(&cache, &path_texts("x")).0.len(), 1);
+
+        path_texts("x").write_to_disk()?;
+        record(&cache, "k", &path_texts("x"))?;
+        assert!(is_up_to_date(&cache, "k"));
+        assert!(!is_up_to_date(&cache, "k2"));
+        assert_eq!(filter_changed(&cache, &path_texts("x")).0.len(), 0);
+        assert_eq!(filter_changed(&cache, &path_texts("y")).0.len(), 1);
+
+        // Modified by users
+        fs::write(dir.path().join("a.rs"), "z")?;
+        assert!(!is_up_to_date(&cache, "k"));
+        assert_eq!(filter_changed(&cache, &path_texts("x")).0.len(), 1);
+        Ok(())
+    }
+}
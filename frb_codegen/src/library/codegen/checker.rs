@@ -0,0 +1,126 @@
+use crate::codegen::generator::misc::path_texts::PathTexts;
+use crate::codegen::polisher::internal_config::PolisherInternalConfig;
+use crate::commands::format_rust::format_rust;
+use crate::library::commands::format_dart::format_dart;
+use crate::utils::file_utils::create_dir_all_and_write;
+use crate::utils::path_utils::path_to_string;
+use anyhow::bail;
+use itertools::Itertools;
+use pathdiff::diff_paths;
+use similar::TextDiff;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+/// Compare the generated code with the files on disk, printing the unified diff of mismatched ones.
+///
+/// The generated code is formatted in staging directories inside the Dart and Rust projects,
+/// so that the formatters pick up the same configurations as when writing the real files.
+pub(super) fn check(
+    config: &PolisherInternalConfig,
+    output_texts: &PathTexts,
+) -> anyhow::Result<()> {
+    let dart_staging = StagingDir::new(&config.dart_root, ".dart_tool")?;
+    let rust_staging = StagingDir::new(&config.rust_crate_dir, "target")?;
+
+    let mut staged = vec![];
+    for item in output_texts.0.iter() {
+        let staging = match extension(&item.path) {
+            "dart" => &dart_staging,
+            _ => &rust_staging,
+        };
+        let staged_path = staging.path_for(&item.path);
+        create_dir_all_and_write(&staged_path, item.text.all_code())?;
+        staged.push((item.path.clone(), staged_path));
+    }
+
+    let staged_paths_with_extension = |ext: &str| {
+        (staged.iter())
+            .filter(|(path, _)| extension(path) == ext)
+            .map(|(_, staged_path)| staged_path.clone())
+            .collect_vec()
+    };
+    format_dart(
+        &staged_paths_with_extension("dart"),
+        &config.dart_root,
+        config.dart_format_line_length,
+        &[],
+    )?;
+    format_rust(&staged_paths_with_extension("rs"), &config.rust_crate_dir)?;
+
+    let mut mismatched_paths = vec![];
+    for (path, staged_path) in staged.iter() {
+        let expect = fs::read_to_string(staged_path)?;
+        let actual = fs::read_to_string(path).unwrap_or_default();
+        if let Some(diff) = compute_diff(&path_to_string(path)?, &actual, &expect) {
+            print!("{diff}");
+            mismatched_paths.push(path);
+        }
+    }
+
+    if !mismatched_paths.is_empty() {
+        bail!(
+            "The generated code is not up to date (mismatched files: {}), please run `flutter_rust_bridge_codegen generate`",
+            mismatched_paths.iter().map(|p| p.display()).join(", ")
+        );
+    }
+    println!("The generated code is up to date.");
+    Ok(())
+}
+
+fn compute_diff(name: &str, actual: &str, expect: &str) -> Option<String> {
+    if actual == expect {
+        return None;
+    }
+    Some(
+        TextDiff::from_lines(actual, expect)
+            .unified_diff()
+            .header(&format!("a/{name}"), &format!("b/{name}"))
+            .to_string(),
+    )
+}
+
+fn extension(path: &Path) -> &str {
+    path.extension()
+        .and_then(|x| x.to_str())
+        .unwrap_or_default()
+}
+
+struct StagingDir {
+    project_root: PathBuf,
+    dir: tempfile::TempDir,
+}
+
+impl StagingDir {
+    fn new(project_root: &Path, parent_name: &str) -> anyhow::Result<Self> {
+        let parent = project_root.join(parent_name);
+        fs::create_dir_all(&parent)?;
+        Ok(Self {
+            project_root: project_root.to_owned(),
+            dir: tempfile::Builder::new()
+                .prefix("flutter_rust_bridge_check_")
+                .tempdir_in(parent)?,
+        })
+    }
+
+    /// Mirror the path relative to the project root, or only keep the file name for outside paths
+    fn path_for(&self, path: &Path) -> PathBuf {
+        let relative = diff_paths(path, &self.project_root)
+            .filter(|p| !p.components().any(|c| c == Component::ParentDir));
+        let relative = relative.unwrap_or_else(|| path.file_name().unwrap_or_default().into());
+        self.dir.path().join(relative)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::compute_diff;
+
+    #[test]
+    fn test_compute_diff() {
+        assert_eq!(compute_diff("a.rs", "x\ny\n", "x\ny\n"), None);
+        assert_eq!(
+            compute_diff("a.rs", "x\ny\n", "x\nz\n").unwrap(),
+            "--- a/a.rs\n+++ b/a.rs\n@@ -1,2 +1,2 @@\n x\n-y\n+z\n"
+        );
+    }
+}
@@ -32,10 +32,15 @@ pub struct Config {
     pub local: Option<bool>,
     pub default_external_library_loader_web_prefix: Option<String>,
     pub dart_type_rename: Option<HashMap<String, String>>,
+    pub thread_pools: Option<HashMap<String, usize>>,
     pub enable_lifetime: Option<bool>,
     pub type_64bit_int: Option<bool>,
     pub default_dart_async: Option<bool>,
     pub stop_on_error: Option<bool>,
+    pub compact_serialize: Option<bool>,
+    pub cache: Option<bool>,
+    pub cargo_expand: Option<bool>,
+    pub rust_features: Option<Vec<String>>,
     pub dump: Option<Vec<ConfigDumpContent>>,
     pub dump_all: Option<bool>,
 }
@@ -87,10 +92,15 @@ generate_merge!(
     local,
     default_external_library_loader_web_prefix,
     dart_type_rename,
+    thread_pools,
     enable_lifetime,
     type_64bit_int,
     default_dart_async,
     stop_on_error,
+    compact_serialize,
+    cache,
+    cargo_expand,
+    rust_features,
     dump,
     dump_all,
 );
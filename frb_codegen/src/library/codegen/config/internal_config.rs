@@ -1,3 +1,4 @@
+use crate::codegen::cache::internal_config::CacheInternalConfig;
 use crate::codegen::dumper::internal_config::DumperInternalConfig;
 use crate::codegen::generator::api_dart::internal_config::GeneratorApiDartInternalConfig;
 use crate::codegen::generator::wire::c::internal_config::GeneratorWireCInternalConfig;
@@ -17,6 +18,7 @@ pub(crate) struct InternalConfig {
     pub generator: GeneratorInternalConfig,
     pub polisher: PolisherInternalConfig,
     pub dumper: DumperInternalConfig,
+    pub cache: CacheInternalConfig,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
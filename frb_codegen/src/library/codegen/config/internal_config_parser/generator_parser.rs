@@ -13,13 +13,14 @@ use crate::codegen::generator::wire::rust::internal_config::GeneratorWireRustInt
 use crate::codegen::ir::mir::ty::rust_opaque::RustOpaqueCodecMode;
 use crate::codegen::Config;
 use crate::library::commands::cargo_metadata::execute_cargo_metadata;
+use crate::library::misc::consts::BUILTIN_EXECUTORS;
 use crate::utils::dart_repository::get_dart_package_name;
 use crate::utils::path_utils::path_to_string;
 use crate::utils::syn_utils::canonicalize_rust_type;
-use anyhow::Context;
+use anyhow::{bail, Context};
 use itertools::Itertools;
 use pathdiff::diff_paths;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::path::{Path, PathBuf};
 
 pub(super) struct Args<'a> {
@@ -95,6 +96,7 @@ pub(super) fn parse(args: Args) -> anyhow::Result<GeneratorInternalConfig> {
                 default_stream_sink_codec,
                 default_rust_opaque_codec,
                 rust_preamble: config.rust_preamble.clone().unwrap_or_default(),
+                thread_pools: compute_thread_pools(config)?,
             },
             c: GeneratorWireCInternalConfig {
                 enable: full_dep,
@@ -173,6 +175,14 @@ fn compute_default_external_library_relative_directory(
     Ok(path_to_string(&diff.join("target").join("release/"))?.replace('\\', "/"))
 }
 
+fn compute_thread_pools(config: &Config) -> anyhow::Result<BTreeMap<String, usize>> {
+    let thread_pools = config.thread_pools.clone().unwrap_or_default();
+    if let Some(name) = (thread_pools.keys()).find(|x| BUILTIN_EXECUTORS.contains(&x.as_str())) {
+        bail!("thread_pools cannot configure the built-in executor class \"{name}\"");
+    }
+    Ok(thread_pools.into_iter().collect())
+}
+
 fn compute_dart_type_rename(config: &Config) -> anyhow::Result<HashMap<String, String>> {
     fn convert_rust_type(raw: &str) -> anyhow::Result<Vec<String>> {
         Ok(vec![
@@ -1,3 +1,4 @@
+use crate::codegen::cache::internal_config::CacheInternalConfig;
 use crate::codegen::config::config::MetaConfig;
 use crate::codegen::config::internal_config::InternalConfig;
 use crate::codegen::config::internal_config_parser::rust_path_parser::RustInputInfo;
@@ -73,12 +74,18 @@ impl InternalConfig {
         let web_enabled = config.web.unwrap_or(true);
 
         let dump_directory = rust_crate_dir.join("target").join("frb_dump");
+        let cache = CacheInternalConfig {
+            enabled: config.cache.unwrap_or(true),
+            cache_directory: rust_crate_dir.join("target").join("frb_cache"),
+            excluded_source_paths: vec![rust_output_path.clone()],
+        };
 
         let full_dep = config.full_dep.unwrap_or(false);
         let default_stream_sink_codec = generate_default_stream_sink_codec(full_dep);
         let default_rust_opaque_codec = generate_default_rust_opaque_codec(full_dep);
         let enable_local_dependency = config.local.unwrap_or_default();
         let stop_on_error = config.stop_on_error.unwrap_or_default();
+        let compact_serialize = config.compact_serialize.unwrap_or_default();
 
         let controller = controller_parser::parse(meta_config, &rust_crate_dir, &rust_output_path)?;
 
@@ -108,10 +115,15 @@ impl InternalConfig {
                     rust_crate_dir: rust_crate_dir.clone(),
                     rust_input_namespace_pack: rust_input_namespace_pack.clone(),
                     third_party_crate_names,
+                    cargo_expand: config.cargo_expand.unwrap_or(true),
+                    rust_features: config.rust_features.clone(),
                 },
                 mir: ParserMirInternalConfig {
                     rust_input_namespace_pack: rust_input_namespace_pack.clone(),
-                    force_codec_mode_pack: compute_force_codec_mode_pack(full_dep),
+                    force_codec_mode_pack: compute_force_codec_mode_pack(
+                        full_dep,
+                        compact_serialize,
+                    ),
                     default_stream_sink_codec,
                     default_rust_opaque_codec,
                     stop_on_error,
@@ -137,6 +149,7 @@ impl InternalConfig {
                 dump_contents: parse_dump_contents(config),
                 dump_directory,
             },
+            cache,
         })
     }
 }
@@ -165,7 +178,17 @@ fn compute_dart_output_class_name_pack(config: &Config) -> DartOutputClassNamePa
     }
 }
 
-pub(crate) fn compute_force_codec_mode_pack(full_dep: bool) -> Option<CodecModePack> {
+pub(crate) fn compute_force_codec_mode_pack(
+    full_dep: bool,
+    compact_serialize: bool,
+) -> Option<CodecModePack> {
+    if compact_serialize {
+        return Some(CodecModePack {
+            dart2rust: CodecMode::Cse,
+            rust2dart: CodecMode::Cse,
+        });
+    }
+
     (!full_dep).then_some(CodecModePack {
         dart2rust: CodecMode::Pde,
         rust2dart: CodecMode::Pde,
@@ -39,6 +39,7 @@ pub(crate) fn generate(
 
 #[cfg(test)]
 mod tests {
+    use crate::codegen::cache::Cache;
     use crate::codegen::config::config::MetaConfig;
     use crate::codegen::config::internal_config::InternalConfig;
     use crate::codegen::dumper::Dumper;
@@ -86,6 +87,7 @@ mod tests {
         let mir_pack = crate::codegen::parser::parse(
             &internal_config.parser,
             &Dumper::new(&Default::default()),
+            &Cache::new(&Default::default()),
             &GeneratorProgressBarPack::new(),
         )?;
         let actual = generate(
@@ -26,6 +26,9 @@ impl<'a> ApiDartGeneratorClassTrait for EnumRefApiDartGenerator<'a> {
 
         match src.mode {
             MirEnumMode::Simple => self.generate_mode_simple(src, &body, header),
+            MirEnumMode::Complex if src.typed_exceptions => {
+                self.generate_mode_typed_exceptions(src, &body, header)
+            }
             MirEnumMode::Complex => self.generate_mode_complex(src, &body, header),
         }
     }
@@ -129,9 +129,7 @@ impl<'a> EnumRefApiDartGenerator<'a> {
     }
 
     fn generate_implements_exception(&self, variant: &MirEnumVariant) -> &str {
-        let has_backtrace = matches!(&variant.kind,
-            MirVariantKind::Struct(MirStruct {is_fields_named: true, fields, ..}) if fields.iter().any(|field| field.name.rust_style() == BACKTRACE_IDENT));
-        if self.mir.is_exception && has_backtrace {
+        if self.mir.is_exception && variant_has_backtrace(variant) {
             "@Implements<FrbBacktracedException>()"
         } else {
             ""
@@ -139,6 +137,11 @@ impl<'a> EnumRefApiDartGenerator<'a> {
     }
 }
 
+pub(crate) fn variant_has_backtrace(variant: &MirEnumVariant) -> bool {
+    matches!(&variant.kind,
+        MirVariantKind::Struct(MirStruct {is_fields_named: true, fields, ..}) if fields.iter().any(|field| field.name.rust_style() == BACKTRACE_IDENT))
+}
+
 fn optional_boundary_index(fields: &[MirField]) -> Option<usize> {
     fields
         .iter()
@@ -0,0 +1,112 @@
+use crate::codegen::generator::api_dart::spec_generator::class::field::generate_field_required_modifier;
+use crate::codegen::generator::api_dart::spec_generator::class::ty::enumeration_complex::variant_has_backtrace;
+use crate::codegen::generator::api_dart::spec_generator::class::ApiDartGeneratedClass;
+use crate::codegen::generator::api_dart::spec_generator::misc::generate_dart_comments;
+use crate::codegen::ir::mir::ty::enumeration::{MirEnum, MirEnumVariant};
+use crate::library::codegen::generator::api_dart::spec_generator::base::*;
+use crate::library::codegen::generator::api_dart::spec_generator::info::ApiDartGeneratorInfoTrait;
+use crate::utils::basic_code::dart_header_code::DartHeaderCode;
+use itertools::Itertools;
+
+/// The Dart field holding the `Display` representation of the Rust error
+pub(crate) const DISPLAY_MESSAGE_IDENT: &str = "displayMessage";
+
+impl<'a> EnumRefApiDartGenerator<'a> {
+    pub(crate) fn generate_mode_typed_exceptions(
+        &self,
+        src: &MirEnum,
+        extra_body: &str,
+        header: DartHeaderCode,
+    ) -> Option<ApiDartGeneratedClass> {
+        let name = &self.mir.ident.0.name;
+        let sealed = if self.context.config.dart3 {
+            "sealed"
+        } else {
+            "abstract"
+        };
+        let comments = generate_dart_comments(&src.comments);
+        let variants = (src.variants().iter())
+            .map(|variant| self.generate_typed_exception_variant(name, variant))
+            .join("\n");
+
+        Some(ApiDartGeneratedClass {
+            namespace: src.name.namespace.clone(),
+            class_name: name.clone(),
+            code: format!(
+                "{comments}{sealed} class {name} implements FrbException {{
+                    const {name}();
+
+                    /// The `Display` representation of the error in Rust
+                    String get {DISPLAY_MESSAGE_IDENT};
+
+                    {extra_body}
+                }}
+
+                {variants}",
+            ),
+            needs_freezed: false,
+            header,
+        })
+    }
+
+    fn generate_typed_exception_variant(
+        &self,
+        enum_name: &str,
+        variant: &MirEnumVariant,
+    ) -> String {
+        let class_name = variant.wrapper_name.rust_style();
+        let comments = generate_dart_comments(&variant.comments);
+        let implements_backtraced = if variant_has_backtrace(variant) {
+            "implements FrbBacktracedException"
+        } else {
+            ""
+        };
+
+        let fields = variant.kind.fields();
+        let is_fields_named = variant.kind.is_fields_named();
+
+        let field_declarations = (fields.iter())
+            .map(|field| {
+                format!(
+                    "{comments}final {ty} {name};\n",
+                    comments = generate_dart_comments(&field.comments),
+                    ty = ApiDartGenerator::new(field.ty.clone(), self.context).dart_api_type(),
+                    name = field.name.dart_style(),
+                )
+            })
+            .join("");
+
+        let field_params = (fields.iter())
+            .map(|field| {
+                if is_fields_named {
+                    format!(
+                        "{required}this.{name},",
+                        required = generate_field_required_modifier(field),
+                        name = field.name.dart_style(),
+                    )
+                } else {
+                    format!("this.{},", field.name.dart_style())
+                }
+            })
+            .join("");
+        let (named_params_prefix, positional_params) = if is_fields_named {
+            (field_params, "".to_owned())
+        } else {
+            ("".to_owned(), field_params)
+        };
+
+        format!(
+            "{comments}class {class_name} extends {enum_name} {implements_backtraced} {{
+                {field_declarations}
+
+                @override
+                final String {DISPLAY_MESSAGE_IDENT};
+
+                const {class_name}({positional_params}{{ {named_params_prefix} this.{DISPLAY_MESSAGE_IDENT} = '' }});
+
+                @override
+                String toString() => {DISPLAY_MESSAGE_IDENT}.isEmpty ? '{class_name}' : {DISPLAY_MESSAGE_IDENT};
+            }}"
+        )
+    }
+}
@@ -5,6 +5,7 @@ use enum_dispatch::enum_dispatch;
 pub(crate) mod delegate;
 pub(crate) mod enumeration;
 pub(crate) mod enumeration_complex;
+pub(crate) mod enumeration_exception;
 pub(crate) mod enumeration_simple;
 pub(crate) mod rust_opaque;
 pub(crate) mod structure;
@@ -1,7 +1,7 @@
 use crate::codegen::generator::api_dart::spec_generator::base::*;
 use crate::codegen::ir::mir::ty::delegate::{
-    MirTypeDelegate, MirTypeDelegateArray, MirTypeDelegateArrayMode, MirTypeDelegatePrimitiveEnum,
-    MirTypeDelegateTime,
+    MirTypeDelegate, MirTypeDelegateArray, MirTypeDelegateArrayMode, MirTypeDelegateBigPrimitive,
+    MirTypeDelegateJson, MirTypeDelegatePrimitiveEnum, MirTypeDelegateTime,
 };
 use crate::codegen::ir::mir::ty::general_list::MirTypeGeneralList;
 use crate::codegen::ir::mir::ty::primitive::MirTypePrimitive;
@@ -61,14 +61,30 @@ impl<'a> ApiDartGeneratorInfoTrait for DelegateApiDartGenerator<'a> {
             MirTypeDelegate::Time(mir) => match mir {
                 MirTypeDelegateTime::Local
                 | MirTypeDelegateTime::Utc
-                | MirTypeDelegateTime::Naive => "DateTime".to_string(),
-                MirTypeDelegateTime::Duration => "Duration".to_string(),
+                | MirTypeDelegateTime::Naive
+                | MirTypeDelegateTime::NaiveDate
+                | MirTypeDelegateTime::StdSystemTime
+                | MirTypeDelegateTime::TimeOffsetDateTime
+                | MirTypeDelegateTime::TimePrimitiveDateTime
+                | MirTypeDelegateTime::TimeDate => "DateTime".to_string(),
+                MirTypeDelegateTime::Duration
+                | MirTypeDelegateTime::NaiveTime
+                | MirTypeDelegateTime::StdDuration
+                | MirTypeDelegateTime::TimeDuration => "Duration".to_string(),
+                MirTypeDelegateTime::FixedOffset | MirTypeDelegateTime::Tz => {
+                    "DateTimeWithOffset".to_string()
+                }
             },
             // MirTypeDelegate::TimeList(
             //     MirTypeDelegateTime::Local | MirTypeDelegateTime::Utc | MirTypeDelegateTime::Naive,
             // ) => "List<DateTime>".to_string(),
             // MirTypeDelegate::TimeList(MirTypeDelegateTime::Duration) => "List<Duration>".to_string(),
             MirTypeDelegate::Uuid => "UuidValue".to_owned(),
+            MirTypeDelegate::Json(mir) => match mir {
+                MirTypeDelegateJson::Value => "Object?",
+                MirTypeDelegateJson::Map => "Map<String, dynamic>",
+            }
+            .to_owned(),
             // MirTypeDelegate::Uuids => "List<UuidValue>".to_owned(),
             MirTypeDelegate::Backtrace => "String".to_string(),
             MirTypeDelegate::AnyhowException => "AnyhowException".to_string(),
@@ -81,11 +97,18 @@ impl<'a> ApiDartGeneratorInfoTrait for DelegateApiDartGenerator<'a> {
                 "Set<{}>",
                 ApiDartGenerator::new(*mir.inner.clone(), self.context).dart_api_type(),
             ),
+            MirTypeDelegate::VecDeque(_) => {
+                ApiDartGenerator::new(self.mir.get_delegate(), self.context).dart_api_type()
+            }
             MirTypeDelegate::StreamSink(mir) => format!(
                 "RustStreamSink<{}>",
                 ApiDartGenerator::new(*mir.inner_ok.clone(), self.context).dart_api_type(),
             ),
-            MirTypeDelegate::BigPrimitive(_) => "BigInt".to_owned(),
+            MirTypeDelegate::DartStream(mir) => format!(
+                "Stream<{}>",
+                ApiDartGenerator::new(*mir.inner.clone(), self.context).dart_api_type(),
+            ),
+            MirTypeDelegate::BigPrimitive(mir) => mir.dart_api_type().to_owned(),
             MirTypeDelegate::CastedPrimitive(mir) => match mir.inner {
                 MirTypePrimitive::U64
                 | MirTypePrimitive::I64
@@ -117,6 +140,9 @@ impl<'a> ApiDartGeneratorInfoTrait for DelegateApiDartGenerator<'a> {
             MirTypeDelegate::Uuid /*| MirTypeDelegate::Uuids*/ => {
                 Some("import 'package:uuid/uuid.dart';".to_owned())
             }
+            MirTypeDelegate::BigPrimitive(MirTypeDelegateBigPrimitive::Decimal) => {
+                Some("import 'package:decimal/decimal.dart';".to_owned())
+            }
             _ => None,
         }
     }
@@ -163,8 +189,13 @@ impl<'a> ApiDartGeneratorInfoTrait for GeneralListApiDartGenerator<'a> {
 
 impl<'a> ApiDartGeneratorInfoTrait for OptionalApiDartGenerator<'a> {
     fn dart_api_type(&self) -> String {
-        let inner = ApiDartGenerator::new(self.mir.inner.clone(), self.context);
-        format!("{}?", inner.dart_api_type())
+        let inner = ApiDartGenerator::new(self.mir.inner.clone(), self.context).dart_api_type();
+        // e.g. `Object?` is already nullable
+        if inner.ends_with('?') {
+            inner
+        } else {
+            format!("{inner}?")
+        }
     }
 }
 
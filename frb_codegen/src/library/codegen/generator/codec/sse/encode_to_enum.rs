@@ -1,6 +1,10 @@
 use itertools::Itertools;
 
-pub(super) fn generate_encode_to_enum(enum_name: &str, variants: &[VariantInfo]) -> String {
+pub(super) fn generate_encode_to_enum(
+    enum_name: &str,
+    variants: &[VariantInfo],
+    fallback: Option<String>,
+) -> String {
     let variants = (variants.iter())
         .map(|variant| {
             format!(
@@ -15,11 +19,13 @@ pub(super) fn generate_encode_to_enum(enum_name: &str, variants: &[VariantInfo])
         })
         .join("");
 
+    let fallback = fallback.unwrap_or_else(|| "throw Exception('not reachable');".to_owned());
+
     format!(
         "
         (() {{
             {variants}
-            throw Exception('not reachable');
+            {fallback}
         }})()
         "
     )
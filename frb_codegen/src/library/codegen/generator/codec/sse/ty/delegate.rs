@@ -4,8 +4,8 @@ use crate::codegen::generator::codec::sse::encode_to_enum;
 use crate::codegen::generator::codec::sse::lang::*;
 use crate::codegen::generator::codec::sse::ty::*;
 use crate::codegen::ir::mir::ty::delegate::{
-    MirTypeDelegateDynTrait, MirTypeDelegatePrimitiveEnum, MirTypeDelegateProxyEnum,
-    MirTypeDelegateSet, MirTypeDelegateStreamSink, MirTypeDelegateTime,
+    MirTypeDelegateDynTrait, MirTypeDelegateJson, MirTypeDelegatePrimitiveEnum,
+    MirTypeDelegateProxyEnum, MirTypeDelegateSet, MirTypeDelegateStreamSink, MirTypeDelegateTime,
 };
 use crate::library::codegen::generator::api_dart::spec_generator::info::ApiDartGeneratorInfoTrait;
 use convert_case::{Case, Casing};
@@ -22,27 +22,55 @@ impl<'a> CodecSseTyTrait for DelegateCodecSseTy<'a> {
                 MirTypeDelegate::Backtrace | MirTypeDelegate::ProxyVariant(_) => {
                     return Some(format!("{};", lang.throw_unreachable("")));
                 }
-                MirTypeDelegate::AnyhowException => "self.message".to_owned(),
+                MirTypeDelegate::AnyhowException => {
+                    "[self.message, self.backtrace ?? '', self.typeName ?? '', ...self.causes]"
+                        .to_owned()
+                }
                 MirTypeDelegate::Map(_) => {
                     "self.entries.map((e) => (e.key, e.value)).toList()".to_owned()
                 }
                 MirTypeDelegate::Set(mir) => {
                     generate_set_to_list(mir, self.context.as_api_dart_context(), "self")
                 }
+                MirTypeDelegate::VecDeque(_) => "self".to_owned(),
                 MirTypeDelegate::Time(mir) => match mir {
                     MirTypeDelegateTime::Utc
                     | MirTypeDelegateTime::Local
-                    | MirTypeDelegateTime::Naive => {
+                    | MirTypeDelegateTime::Naive
+                    | MirTypeDelegateTime::StdSystemTime
+                    | MirTypeDelegateTime::TimeOffsetDateTime
+                    | MirTypeDelegateTime::TimePrimitiveDateTime => {
                         "PlatformInt64Util.from(self.microsecondsSinceEpoch)".to_owned()
                     }
-                    MirTypeDelegateTime::Duration => {
+                    MirTypeDelegateTime::NaiveDate | MirTypeDelegateTime::TimeDate => {
+                        "PlatformInt64Util.from(DateTime.utc(self.year, self.month, self.day).microsecondsSinceEpoch)".to_owned()
+                    }
+                    MirTypeDelegateTime::Duration
+                    | MirTypeDelegateTime::NaiveTime
+                    | MirTypeDelegateTime::TimeDuration => {
                         "PlatformInt64Util.from(self.inMicroseconds)".to_owned()
                     }
+                    MirTypeDelegateTime::StdDuration => {
+                        return Some(format!(
+                            "{}\n{}",
+                            dart_check_std_duration("self"),
+                            simple_delegate_encode(
+                                lang,
+                                &self.mir.get_delegate(),
+                                "PlatformInt64Util.from(self.inMicroseconds)"
+                            )
+                        ));
+                    }
+                    MirTypeDelegateTime::FixedOffset | MirTypeDelegateTime::Tz => {
+                        "self.toString()".to_owned()
+                    }
                 },
                 MirTypeDelegate::Uuid => "self.toBytes()".to_owned(),
+                MirTypeDelegate::Json(_) => "jsonEncode(self)".to_owned(),
                 MirTypeDelegate::StreamSink(mir) => {
                     generate_stream_sink_setup_and_serialize(mir, "self")
                 }
+                MirTypeDelegate::DartStream(_) => "encodeDartStream(self)".to_owned(),
                 MirTypeDelegate::BigPrimitive(_) => "self.toString()".to_owned(),
                 MirTypeDelegate::CastedPrimitive(mir) => {
                     let postfix = match mir.inner {
@@ -77,7 +105,7 @@ impl<'a> CodecSseTyTrait for DelegateCodecSseTy<'a> {
                     let variants = (src.variants.iter().enumerate())
                         .map(|(idx, variant)| {
                             (
-                                format!("{}::{}", src.name.rust_style(), variant.name),
+                                format!("{}::{}", src.rust_style(), variant.name),
                                 format!("{idx}"),
                             )
                         })
@@ -89,9 +117,13 @@ impl<'a> CodecSseTyTrait for DelegateCodecSseTy<'a> {
                     )
                 }
                 MirTypeDelegate::Backtrace => r#"format!("{:?}", self)"#.to_owned(),
-                MirTypeDelegate::AnyhowException => r#"format!("{:?}", self)"#.to_owned(),
+                MirTypeDelegate::AnyhowException => {
+                    "flutter_rust_bridge::for_generated::anyhow_error_encode(self)".to_owned()
+                }
                 MirTypeDelegate::Map(_) => "self.into_iter().collect()".to_owned(),
-                MirTypeDelegate::Set(_) => "self.into_iter().collect()".to_owned(),
+                MirTypeDelegate::Set(_) | MirTypeDelegate::VecDeque(_) => {
+                    "self.into_iter().collect()".to_owned()
+                }
                 MirTypeDelegate::Time(mir) => match mir {
                     MirTypeDelegateTime::Utc | MirTypeDelegateTime::Local => {
                         "self.timestamp_micros()".to_owned()
@@ -101,8 +133,47 @@ impl<'a> CodecSseTyTrait for DelegateCodecSseTy<'a> {
                         r#"self.num_microseconds().expect("cannot get microseconds from time")"#
                             .to_owned()
                     }
+                    MirTypeDelegateTime::StdDuration => {
+                        "flutter_rust_bridge::for_generated::std_duration_to_micros(self)"
+                            .to_owned()
+                    }
+                    MirTypeDelegateTime::NaiveDate => {
+                        r#"self.signed_duration_since(chrono::NaiveDate::from_ymd_opt(1970, 1, 1).unwrap()).num_microseconds().expect("cannot get microseconds from date")"#.to_owned()
+                    }
+                    MirTypeDelegateTime::NaiveTime => {
+                        r#"self.signed_duration_since(chrono::NaiveTime::from_hms_opt(0, 0, 0).unwrap()).num_microseconds().expect("cannot get microseconds from time")"#.to_owned()
+                    }
+                    MirTypeDelegateTime::FixedOffset => {
+                        "flutter_rust_bridge::for_generated::encode_date_time_with_offset(&self, None)"
+                            .to_owned()
+                    }
+                    MirTypeDelegateTime::Tz => {
+                        "flutter_rust_bridge::for_generated::encode_date_time_with_offset(&self, Some(self.timezone().name()))"
+                            .to_owned()
+                    }
+                    MirTypeDelegateTime::StdSystemTime => {
+                        "flutter_rust_bridge::for_generated::system_time_to_micros(self)".to_owned()
+                    }
+                    MirTypeDelegateTime::TimeOffsetDateTime => {
+                        "flutter_rust_bridge::for_generated::offset_date_time_to_micros(self)"
+                            .to_owned()
+                    }
+                    MirTypeDelegateTime::TimePrimitiveDateTime => {
+                        "flutter_rust_bridge::for_generated::primitive_date_time_to_micros(self)"
+                            .to_owned()
+                    }
+                    MirTypeDelegateTime::TimeDate => {
+                        "flutter_rust_bridge::for_generated::date_to_micros(self)".to_owned()
+                    }
+                    MirTypeDelegateTime::TimeDuration => {
+                        "flutter_rust_bridge::for_generated::time_duration_to_micros(self)"
+                            .to_owned()
+                    }
                 },
                 MirTypeDelegate::Uuid => "self.as_bytes().to_vec()".to_owned(),
+                MirTypeDelegate::Json(_) => {
+                    r#"serde_json::to_string(&self).expect("fail to encode json")"#.to_owned()
+                }
                 MirTypeDelegate::StreamSink(_) => return Some(lang.throw_unimplemented("")),
                 MirTypeDelegate::BigPrimitive(_) => "self.to_string()".to_owned(),
                 MirTypeDelegate::RustAutoOpaqueExplicit(_ir) => {
@@ -112,6 +183,7 @@ impl<'a> CodecSseTyTrait for DelegateCodecSseTy<'a> {
                 MirTypeDelegate::ProxyVariant(_)
                 | MirTypeDelegate::ProxyEnum(_)
                 | MirTypeDelegate::DynTrait(_)
+                | MirTypeDelegate::DartStream(_)
                 | MirTypeDelegate::CastedPrimitive(_)
                 | MirTypeDelegate::Lifetimeable(_) => return None,
                 MirTypeDelegate::CustomSerDes(mir) => {
@@ -128,63 +200,76 @@ impl<'a> CodecSseTyTrait for DelegateCodecSseTy<'a> {
 
     fn generate_decode(&self, lang: &Lang) -> Option<String> {
         let wrapper_expr = match lang {
-            Lang::DartLang(_) => {
-                match &self.mir {
-                    MirTypeDelegate::Array(_) => format!(
-                        "{}(inner)",
-                        ApiDartGenerator::new(self.mir.clone(), self.context.as_api_dart_context())
-                            .dart_api_type()
-                    ),
-                    MirTypeDelegate::String => "utf8.decoder.convert(inner)".to_owned(),
-                    MirTypeDelegate::Char => "inner".to_owned(),
-                    MirTypeDelegate::PrimitiveEnum(inner) => {
-                        format!(
-                            "{}.values[inner]",
-                            ApiDartGenerator::new(
-                                inner.mir.clone(),
-                                self.context.as_api_dart_context()
-                            )
-                            .dart_api_type()
+            Lang::DartLang(_) => match &self.mir {
+                MirTypeDelegate::Array(_) => format!(
+                    "{}(inner)",
+                    ApiDartGenerator::new(self.mir.clone(), self.context.as_api_dart_context())
+                        .dart_api_type()
+                ),
+                MirTypeDelegate::String => "utf8.decoder.convert(inner)".to_owned(),
+                MirTypeDelegate::Char => "inner".to_owned(),
+                MirTypeDelegate::PrimitiveEnum(inner) => {
+                    format!(
+                        "{}.values[inner]",
+                        ApiDartGenerator::new(
+                            inner.mir.clone(),
+                            self.context.as_api_dart_context()
                         )
-                    }
-                    MirTypeDelegate::Backtrace => "inner".to_owned(),
-                    MirTypeDelegate::AnyhowException => "AnyhowException(inner)".to_owned(),
-                    MirTypeDelegate::Map(_) => {
-                        "Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)))".to_owned()
-                    }
-                    MirTypeDelegate::Set(_) => "Set.from(inner)".to_owned(),
-                    MirTypeDelegate::Time(mir) => match mir {
-                        MirTypeDelegateTime::Utc
-                        | MirTypeDelegateTime::Local
-                        | MirTypeDelegateTime::Naive => {
-                            format!(
+                        .dart_api_type()
+                    )
+                }
+                MirTypeDelegate::Backtrace => "inner".to_owned(),
+                MirTypeDelegate::AnyhowException => generate_anyhow_exception_dart_decode("inner"),
+                MirTypeDelegate::Map(_) => {
+                    "Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)))".to_owned()
+                }
+                MirTypeDelegate::Set(_) => "Set.from(inner)".to_owned(),
+                MirTypeDelegate::VecDeque(_) => "inner".to_owned(),
+                MirTypeDelegate::Time(mir) => match mir {
+                    MirTypeDelegateTime::Utc
+                    | MirTypeDelegateTime::Local
+                    | MirTypeDelegateTime::Naive
+                    | MirTypeDelegateTime::NaiveDate
+                    | MirTypeDelegateTime::StdSystemTime
+                    | MirTypeDelegateTime::TimeOffsetDateTime
+                    | MirTypeDelegateTime::TimePrimitiveDateTime
+                    | MirTypeDelegateTime::TimeDate => {
+                        format!(
                             "DateTime.fromMicrosecondsSinceEpoch(inner.toInt(), isUtc: {is_utc})",
-                            is_utc =
-                                matches!(mir, MirTypeDelegateTime::Naive | MirTypeDelegateTime::Utc),
+                            is_utc = mir.is_utc(),
                         )
-                        }
-                        MirTypeDelegateTime::Duration => {
-                            "Duration(microseconds: inner.toInt())".to_owned()
-                        }
-                    },
-                    MirTypeDelegate::Uuid => "UuidValue.fromByteList(inner)".to_owned(),
-                    MirTypeDelegate::StreamSink(_)
-                    | MirTypeDelegate::ProxyVariant(_)
-                    | MirTypeDelegate::ProxyEnum(_) => {
-                        return Some(format!("{};", lang.throw_unreachable("")));
                     }
-                    MirTypeDelegate::BigPrimitive(_) => "BigInt.parse(inner)".to_owned(),
-                    MirTypeDelegate::CastedPrimitive(_) => "inner.toInt()".to_owned(),
-                    MirTypeDelegate::RustAutoOpaqueExplicit(_ir) => "inner".to_owned(),
-                    MirTypeDelegate::DynTrait(_) => {
-                        return Some(format!("{};", lang.throw_unimplemented("")))
+                    MirTypeDelegateTime::Duration
+                    | MirTypeDelegateTime::NaiveTime
+                    | MirTypeDelegateTime::StdDuration
+                    | MirTypeDelegateTime::TimeDuration => {
+                        "Duration(microseconds: inner.toInt())".to_owned()
                     }
-                    MirTypeDelegate::Lifetimeable(_) => "inner".to_owned(),
-                    MirTypeDelegate::CustomSerDes(mir) => {
-                        mir.info.rust2dart.dart_code.replace("{}", "inner")
+                    MirTypeDelegateTime::FixedOffset | MirTypeDelegateTime::Tz => {
+                        "DateTimeWithOffset.parse(inner)".to_owned()
                     }
+                },
+                MirTypeDelegate::Uuid => "UuidValue.fromByteList(inner)".to_owned(),
+                MirTypeDelegate::Json(mir) => dart_decode_json(mir, "inner"),
+                MirTypeDelegate::StreamSink(_)
+                | MirTypeDelegate::DartStream(_)
+                | MirTypeDelegate::ProxyVariant(_)
+                | MirTypeDelegate::ProxyEnum(_) => {
+                    return Some(format!("{};", lang.throw_unreachable("")));
                 }
-            }
+                MirTypeDelegate::BigPrimitive(mir) => {
+                    format!("{}.parse(inner)", mir.dart_api_type())
+                }
+                MirTypeDelegate::CastedPrimitive(_) => "inner.toInt()".to_owned(),
+                MirTypeDelegate::RustAutoOpaqueExplicit(_ir) => "inner".to_owned(),
+                MirTypeDelegate::DynTrait(_) => {
+                    return Some(format!("{};", lang.throw_unimplemented("")))
+                }
+                MirTypeDelegate::Lifetimeable(_) => "inner".to_owned(),
+                MirTypeDelegate::CustomSerDes(mir) => {
+                    mir.info.rust2dart.dart_code.replace("{}", "inner")
+                }
+            },
             Lang::RustLang(_) => match &self.mir {
                 MirTypeDelegate::Array(_) => {
                     "flutter_rust_bridge::for_generated::from_vec_to_array(inner)".to_owned()
@@ -198,10 +283,12 @@ impl<'a> CodecSseTyTrait for DelegateCodecSseTy<'a> {
                     return Some(format!("{};", lang.throw_unreachable("")));
                 }
                 MirTypeDelegate::AnyhowException => {
-                    r#"flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner)"#.to_owned()
+                    "flutter_rust_bridge::for_generated::anyhow_error_decode(inner)".to_owned()
                 }
                 MirTypeDelegate::Map(_) => "inner.into_iter().collect()".to_owned(),
-                MirTypeDelegate::Set(_) => "inner.into_iter().collect()".to_owned(),
+                MirTypeDelegate::Set(_) | MirTypeDelegate::VecDeque(_) => {
+                    "inner.into_iter().collect()".to_owned()
+                }
                 MirTypeDelegate::Time(mir) => {
                     let naive = "chrono::DateTime::from_timestamp_micros(inner).expect(\"invalid or out-of-range datetime\").naive_utc()";
                     let utc = format!("chrono::DateTime::<chrono::Utc>::from_naive_utc_and_offset({naive}, chrono::Utc)");
@@ -211,14 +298,46 @@ impl<'a> CodecSseTyTrait for DelegateCodecSseTy<'a> {
                         MirTypeDelegateTime::Local => {
                             format!("chrono::DateTime::<chrono::Local>::from({utc})")
                         }
+                        MirTypeDelegateTime::NaiveDate => format!("{naive}.date()"),
                         MirTypeDelegateTime::Duration => {
                             "chrono::Duration::microseconds(inner)".to_owned()
                         }
+                        MirTypeDelegateTime::NaiveTime => {
+                            "chrono::NaiveTime::from_hms_opt(0, 0, 0).unwrap() + chrono::Duration::microseconds(inner)".to_owned()
+                        }
+                        MirTypeDelegateTime::FixedOffset | MirTypeDelegateTime::Tz => {
+                            rust_decode_date_time_with_offset(mir, "&inner")
+                        }
+                        // Negative values are already rejected on the Dart side
+                        MirTypeDelegateTime::StdDuration => {
+                            "flutter_rust_bridge::for_generated::std_duration_from_micros(inner).unwrap()"
+                                .to_owned()
+                        }
+                        MirTypeDelegateTime::StdSystemTime => {
+                            "flutter_rust_bridge::for_generated::system_time_from_micros(inner)"
+                                .to_owned()
+                        }
+                        MirTypeDelegateTime::TimeOffsetDateTime => {
+                            "flutter_rust_bridge::for_generated::offset_date_time_from_micros(inner)"
+                                .to_owned()
+                        }
+                        MirTypeDelegateTime::TimePrimitiveDateTime => {
+                            "flutter_rust_bridge::for_generated::primitive_date_time_from_micros(inner)"
+                                .to_owned()
+                        }
+                        MirTypeDelegateTime::TimeDate => {
+                            "flutter_rust_bridge::for_generated::date_from_micros(inner)".to_owned()
+                        }
+                        MirTypeDelegateTime::TimeDuration => {
+                            "flutter_rust_bridge::for_generated::time_duration_from_micros(inner)"
+                                .to_owned()
+                        }
                     }
                 }
                 MirTypeDelegate::Uuid => {
                     r#"uuid::Uuid::from_slice(&inner).expect("fail to decode uuid")"#.to_owned()
                 }
+                MirTypeDelegate::Json(_) => rust_decode_json("&inner"),
                 MirTypeDelegate::StreamSink(_) => "StreamSink::deserialize(inner)".to_owned(),
                 MirTypeDelegate::BigPrimitive(_) => "inner.parse().unwrap()".to_owned(),
                 MirTypeDelegate::RustAutoOpaqueExplicit(_ir) => {
@@ -228,6 +347,7 @@ impl<'a> CodecSseTyTrait for DelegateCodecSseTy<'a> {
                 MirTypeDelegate::ProxyVariant(_)
                 | MirTypeDelegate::ProxyEnum(_)
                 | MirTypeDelegate::DynTrait(_)
+                | MirTypeDelegate::DartStream(_)
                 | MirTypeDelegate::CastedPrimitive(_)
                 | MirTypeDelegate::Lifetimeable(_) => return None,
                 MirTypeDelegate::CustomSerDes(mir) => {
@@ -268,7 +388,7 @@ pub(crate) fn rust_decode_primitive_enum(
 ) -> String {
     let enu = inner.mir.get(mir_pack);
     let variants = (enu.variants().iter().enumerate())
-        .map(|(idx, variant)| format!("{} => {}::{},", idx, enu.name.rust_style(), variant.name))
+        .map(|(idx, variant)| format!("{} => {}::{},", idx, enu.rust_style(), variant.name))
         .collect_vec()
         .join("\n");
 
@@ -281,6 +401,37 @@ pub(crate) fn rust_decode_primitive_enum(
     )
 }
 
+/// Shared with other codecs, since JSON is encoded as strings in all of them
+pub(crate) fn dart_decode_json(mir: &MirTypeDelegateJson, raw: &str) -> String {
+    match mir {
+        MirTypeDelegateJson::Value => format!("jsonDecode({raw})"),
+        MirTypeDelegateJson::Map => format!("jsonDecode({raw}) as Map<String, dynamic>"),
+    }
+}
+
+pub(crate) fn rust_decode_json(raw: &str) -> String {
+    format!(r#"serde_json::from_str({raw}).expect("fail to decode json")"#)
+}
+
+/// Shared with other codecs, since both offset types are encoded as strings
+pub(crate) fn rust_decode_date_time_with_offset(mir: &MirTypeDelegateTime, raw: &str) -> String {
+    match mir {
+        MirTypeDelegateTime::FixedOffset => {
+            format!("flutter_rust_bridge::for_generated::decode_date_time_with_offset({raw}).0")
+        }
+        MirTypeDelegateTime::Tz => format!(
+            "flutter_rust_bridge::for_generated::decode_date_time_with_time_zone::<chrono_tz::Tz>({raw})"
+        ),
+        _ => unreachable!(),
+    }
+}
+
+/// Shared with other codecs, since `std::time::Duration` cannot be negative,
+/// and it is better to throw on the Dart side than to panic when decoding on the Rust side
+pub(crate) fn dart_check_std_duration(raw: &str) -> String {
+    format!("if ({raw}.isNegative) throw ArgumentError.value({raw}, null, 'std::time::Duration cannot be negative');")
+}
+
 pub(crate) fn generate_unimplemented_in_sse_message(mir: &MirType) -> String {
     format!("The type {mir:?} is not yet supported in serialized mode, please use full_dep mode, and feel free to create an issue")
 }
@@ -337,7 +488,7 @@ fn generate_proxy_enum_dart_encode(
         })
         .collect_vec();
 
-    encode_to_enum::generate_encode_to_enum(&enum_name, &variants)
+    encode_to_enum::generate_encode_to_enum(&enum_name, &variants, None)
 }
 
 fn generate_dyn_trait_dart_encode(
@@ -354,5 +505,18 @@ fn generate_dyn_trait_dart_encode(
         })
         .collect_vec();
 
-    encode_to_enum::generate_encode_to_enum(&enum_name, &variants)
+    // Any other Dart object implementing the trait is wrapped, if the trait is `#[frb(dart_implementable)]`
+    let fallback = mir.data().dart_impl_variant.map(|index| {
+        format!(
+            "return {enum_name}.variant{index}({}.from(self));",
+            variants[index].ty_name
+        )
+    });
+
+    encode_to_enum::generate_encode_to_enum(&enum_name, &variants, fallback)
+}
+
+/// Shared with the DCO codec, since both receive the strings produced by `anyhow_error_encode`
+pub(crate) fn generate_anyhow_exception_dart_decode(inner: &str) -> String {
+    format!("AnyhowException({inner}[0], backtrace: {inner}[1].isEmpty ? null : {inner}[1], typeName: {inner}[2].isEmpty ? null : {inner}[2], causes: {inner}.sublist(3))")
 }
@@ -1,34 +1,46 @@
+use crate::codegen::generator::api_dart::spec_generator::class::ty::enumeration_exception::DISPLAY_MESSAGE_IDENT;
 use crate::codegen::generator::codec::sse::ty::structure::GeneralizedStructGenerator;
 use crate::codegen::generator::codec::sse::ty::*;
 use crate::codegen::generator::misc::struct_or_record::StructOrRecord;
 use crate::codegen::ir::mir::ty::enumeration::{MirEnum, MirEnumVariant, MirVariantKind};
 use crate::library::codegen::generator::codec::sse::lang::LangTrait;
-use crate::utils::namespace::NamespacedName;
 use itertools::Itertools;
 
 impl<'a> CodecSseTyTrait for EnumRefCodecSseTy<'a> {
     fn generate_encode(&self, lang: &Lang) -> Option<String> {
         let src = self.mir.get(self.context.mir_pack);
-        Some(generate_enum_encode_rust_general(
-            lang,
-            src,
-            "self",
-            |idx, variant| {
-                let fields = (variant.kind.fields().iter())
-                    .map(|field| {
-                        format!(
-                            "{};\n",
-                            lang.call_encode(&field.ty, &field.name.style(lang))
-                        )
-                    })
-                    .join("");
+        // The `Display` message is only needed when Rust errors are thrown as Dart exceptions
+        let encode_display_message = src.typed_exceptions && matches!(lang, Lang::RustLang(_));
 
+        let body = generate_enum_encode_rust_general(lang, src, "self", |idx, variant| {
+            let fields = (variant.kind.fields().iter())
+                .map(|field| {
+                    format!(
+                        "{};\n",
+                        lang.call_encode(&field.ty, &field.name.style(lang))
+                    )
+                })
+                .join("");
+            let display_message = if encode_display_message {
                 format!(
-                    "{}; {fields}",
-                    lang.call_encode(&TAG_TYPE, &format!("{idx}")),
+                    "{};",
+                    lang.call_encode(&DISPLAY_MESSAGE_TYPE, "display_message_")
                 )
-            },
-        ))
+            } else {
+                "".to_owned()
+            };
+
+            format!(
+                "{}; {fields} {display_message}",
+                lang.call_encode(&TAG_TYPE, &format!("{idx}")),
+            )
+        });
+
+        Some(if encode_display_message {
+            format!("let display_message_ = self.to_string();\n{body}")
+        } else {
+            body
+        })
     }
 
     fn generate_decode(&self, lang: &Lang) -> Option<String> {
@@ -41,7 +53,7 @@ impl<'a> CodecSseTyTrait for EnumRefCodecSseTy<'a> {
             .map(|(idx, variant)| {
                 (
                     format!("{idx}"),
-                    generate_decode_variant(variant, &src.name, lang, self.context),
+                    generate_decode_variant(variant, src, lang, self.context),
                 )
             })
             .collect_vec();
@@ -63,11 +75,15 @@ impl<'a> CodecSseTyTrait for EnumRefCodecSseTy<'a> {
 
 fn generate_decode_variant(
     variant: &MirEnumVariant,
-    enum_name: &NamespacedName,
+    src: &MirEnum,
     lang: &Lang,
     context: CodecSseTyContext,
 ) -> String {
-    let enum_name_str = enum_name.style(lang);
+    if src.typed_exceptions && matches!(lang, Lang::DartLang(_)) {
+        return generate_decode_variant_typed_exception(variant, lang);
+    }
+
+    let enum_name_str = src.style(lang);
     let enum_sep = enum_sep(lang);
     match &variant.kind {
         MirVariantKind::Value => {
@@ -91,13 +107,45 @@ fn generate_decode_variant(
     }
 }
 
+fn generate_decode_variant_typed_exception(variant: &MirEnumVariant, lang: &Lang) -> String {
+    let fields = variant.kind.fields();
+    let var_decl = lang.var_decl();
+
+    let decode_fields = (fields.iter())
+        .map(|field| {
+            format!(
+                "{var_decl} var_{} = {};\n",
+                field.name.dart_style(),
+                lang.call_decode(&field.ty)
+            )
+        })
+        .join("");
+    let args = (fields.iter())
+        .map(|field| {
+            let name = field.name.dart_style();
+            if variant.kind.is_fields_named() {
+                format!("{name}: var_{name}, ")
+            } else {
+                format!("var_{name}, ")
+            }
+        })
+        .join("");
+
+    format!(
+        "{decode_fields}{var_decl} var_{DISPLAY_MESSAGE_IDENT} = {decode_display_message};
+        return {class_name}({args}{DISPLAY_MESSAGE_IDENT}: var_{DISPLAY_MESSAGE_IDENT});",
+        decode_display_message = lang.call_decode(&DISPLAY_MESSAGE_TYPE),
+        class_name = variant.wrapper_name.rust_style(),
+    )
+}
+
 pub(crate) fn generate_enum_encode_rust_general(
     lang: &Lang,
     src: &MirEnum,
     self_ref: &str,
     generate_branch: impl Fn(usize, &MirEnumVariant) -> String,
 ) -> String {
-    let enum_name_str = src.name.style(lang);
+    let enum_name_str = src.style(lang);
     let enum_sep = enum_sep(lang);
     let variants = (src.variants().iter().enumerate())
         .map(|(idx, variant)| {
@@ -150,3 +198,5 @@ fn enum_sep(lang: &Lang) -> &'static str {
 }
 
 const TAG_TYPE: MirType = Primitive(MirTypePrimitive::I32);
+
+const DISPLAY_MESSAGE_TYPE: MirType = MirType::Delegate(MirTypeDelegate::String);
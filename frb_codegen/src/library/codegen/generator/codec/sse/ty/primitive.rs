@@ -18,6 +18,10 @@ impl<'a> CodecSseTyTrait for PrimitiveCodecSseTy<'a> {
                     "serializer.buffer.put{}(self{dart_cast});",
                     get_serializer_dart_postfix(&self.mir, false)
                 ),
+                Lang::RustLang(_) if is_integer(&self.mir) => format!(
+                    "serializer.write_{}(self{rust_cast});",
+                    get_serializer_rust_type(&self.mir),
+                ),
                 Lang::RustLang(_) => format!(
                     "serializer.cursor.write_{}{}(self{rust_cast}).unwrap();",
                     get_serializer_rust_type(&self.mir),
@@ -45,6 +49,10 @@ impl<'a> CodecSseTyTrait for PrimitiveCodecSseTy<'a> {
                     "return deserializer.buffer.get{}(){dart_cast};",
                     get_serializer_dart_postfix(&self.mir, false)
                 ),
+                Lang::RustLang(_) if is_integer(&self.mir) => format!(
+                    "deserializer.read_{}(){rust_cast}",
+                    get_serializer_rust_type(&self.mir),
+                ),
                 Lang::RustLang(_) => {
                     format!(
                         "deserializer.cursor.read_{}{}().unwrap(){rust_cast}",
@@ -107,3 +115,18 @@ fn maybe_endian(ty: &MirTypePrimitive) -> &'static str {
         _ => "::<NativeEndian>",
     }
 }
+
+// Multi-byte integers, which are varints in the compact format, thus handled by the (de)serializer
+fn is_integer(ty: &MirTypePrimitive) -> bool {
+    matches!(
+        ty,
+        MirTypePrimitive::U16
+            | MirTypePrimitive::I16
+            | MirTypePrimitive::U32
+            | MirTypePrimitive::I32
+            | MirTypePrimitive::U64
+            | MirTypePrimitive::I64
+            | MirTypePrimitive::Usize
+            | MirTypePrimitive::Isize
+    )
+}
@@ -18,11 +18,20 @@ impl<'a> CodecSseTyTrait for PrimitiveListCodecSseTy<'a> {
                     )
                 };
 
+                let put_list = if self.mir.primitive == MirTypePrimitive::U8 {
+                    // May pass the bytes in a standalone buffer to avoid copying
+                    format!("sseEncodeUint8List({type_converter}, serializer);")
+                } else {
+                    format!(
+                        "serializer.buffer.put{}List({type_converter});",
+                        get_serializer_dart_postfix(&self.mir.primitive, true)
+                    )
+                };
+
                 Some(format!(
                     "{};
-                    serializer.buffer.put{}List({type_converter});",
+                    {put_list}",
                     lang.call_encode(&LEN_TYPE, &format!("self.{}", list_len_method(lang))),
-                    get_serializer_dart_postfix(&self.mir.primitive, true)
                 ))
             }
             Lang::RustLang(_) => {
@@ -46,6 +55,15 @@ impl<'a> CodecSseTyTrait for PrimitiveListCodecSseTy<'a> {
                 lang.call_decode(&LEN_TYPE),
                 get_serializer_dart_postfix(&self.mir.primitive, true)
             )),
+            Lang::RustLang(_) if self.mir.primitive == MirTypePrimitive::U8 => {
+                self.mir.strict_dart_type.then(|| {
+                    format!(
+                        "{var_decl} len_ = {};
+                        return deserializer.decode_u8_list(len_);",
+                        lang.call_decode(&LEN_TYPE),
+                    )
+                })
+            }
             Lang::RustLang(_) => {
                 // TODO do not use naive loop
                 self.mir.strict_dart_type.then(|| {
@@ -6,6 +6,7 @@ use crate::codegen::generator::codec::sse::ty::rust_opaque::{
 };
 use crate::codegen::generator::codec::sse::ty::*;
 use crate::codegen::ir::mir::func::OwnershipMode;
+use crate::codegen::ir::mir::ty::rust_auto_opaque_implicit::MirRustAutoOpaqueLockStrategy;
 use convert_case::{Case, Casing};
 
 impl<'a> CodecSseTyTrait for RustAutoOpaqueImplicitCodecSseTy<'a> {
@@ -62,8 +63,19 @@ pub(crate) fn generate_encode_rust_auto_opaque(
     variable: &str,
 ) -> String {
     let arc = mir.inner.codec.arc_ty();
+    if mir.lock_strategy.is_default() {
+        return format!(
+            "flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, {arc}<_>>({variable})"
+        );
+    }
+    let lock_strategy = match mir.lock_strategy {
+        MirRustAutoOpaqueLockStrategy::RwLock => "RwLock",
+        MirRustAutoOpaqueLockStrategy::Mutex => "Mutex",
+        MirRustAutoOpaqueLockStrategy::ParkingLot => "ParkingLot",
+        MirRustAutoOpaqueLockStrategy::None => "None",
+    };
     format!(
-        "flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, {arc}<_>>({variable})"
+        "flutter_rust_bridge::for_generated::rust_auto_opaque_encode_with_lock_strategy::<_, {arc}<_>>({variable}, flutter_rust_bridge::for_generated::RustAutoOpaqueLockStrategy::{lock_strategy})"
     )
 }
 
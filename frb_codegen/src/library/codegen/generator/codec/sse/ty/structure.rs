@@ -77,7 +77,7 @@ impl<'a> GeneralizedStructGenerator<'a> {
 
         let ctor = match self.mode {
             Struct => lang.call_constructor(
-                &override_struct_name.unwrap_or_else(|| self.st.name.style(lang)),
+                &override_struct_name.unwrap_or_else(|| self.st.style(lang)),
                 dart_constructor_postfix(
                     &self.st.name.name,
                     &self.context.mir_pack.funcs_with_impl(),
@@ -11,6 +11,7 @@ pub(crate) enum CodecMode {
     Dco,
     Sse,
     Pde,
+    Cse,
 }
 
 impl CodecMode {
@@ -24,6 +25,14 @@ impl CodecMode {
     pub(crate) fn delegate_or_self(self) -> Self {
         self.delegate().unwrap_or(self)
     }
+
+    /// The codec whose generated encoders and decoders of types are used
+    pub(crate) fn serialization(self) -> Self {
+        match self {
+            CodecMode::Pde | CodecMode::Cse => CodecMode::Sse,
+            _ => self,
+        }
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Hash)]
@@ -51,6 +60,7 @@ macro_rules! codegen_codec_structs {
             Dco,
             Sse,
             Pde,
+            Cse,
         );
     );
     (@private $partial_name:ident ; $($name:ident),*,) => (
@@ -117,7 +127,8 @@ pub(crate) fn get_interest_types_for_codec(
         CodecMode::Dco => cache.distinct_types.clone(),
         // For simplicity, consider all types, since (1) PDE needs SSE (2) non-SSE DartFn still requires SSE
         CodecMode::Sse => cache.distinct_types.clone(),
-        CodecMode::Pde => vec![],
+        // Both use the SSE encoders and decoders
+        CodecMode::Pde | CodecMode::Cse => vec![],
     }
 }
 
@@ -6,6 +6,7 @@ pub(crate) fn generate_codec_comments(codec: CodecMode) -> String {
         CodecMode::Dco => "DartCObject based",
         CodecMode::Sse => "Serialization based",
         CodecMode::Pde => "Serialization + dispatch",
+        CodecMode::Cse => "Compact serialization + dispatch",
     };
     format!("// Codec={codec} ({brief_explanation}), see doc to use other codecs")
 }
@@ -33,6 +33,11 @@ pub fn is_js_value(ty: &MirType) -> bool {
     }
 }
 
+/// Whether the type is sent in the same way as a `String`, e.g. `i128` or `chrono::DateTime<FixedOffset>`
+pub(crate) fn is_delegate_of_string(ty: &MirType) -> bool {
+    matches!(ty, MirType::Delegate(inner) if matches!(inner.get_delegate(), MirType::Delegate(MirTypeDelegate::String)))
+}
+
 pub(crate) fn generate_code_header() -> String {
     format!(
         "// This file is automatically generated, so please do not edit it.
@@ -3,6 +3,7 @@ use crate::codegen::generator::codec::structs::get_interest_types_for_codec;
 use crate::codegen::generator::codec::structs::EncodeOrDecode;
 use crate::codegen::generator::codec::structs::{BaseCodecEntrypointTrait, CodecMode};
 use crate::codegen::generator::wire::dart::spec_generator::base::WireDartGeneratorContext;
+use crate::codegen::generator::wire::dart::spec_generator::codec::cse::entrypoint::CseWireDartCodecEntrypoint;
 use crate::codegen::generator::wire::dart::spec_generator::codec::cst::entrypoint::CstWireDartCodecEntrypoint;
 use crate::codegen::generator::wire::dart::spec_generator::codec::dco::entrypoint::DcoWireDartCodecEntrypoint;
 use crate::codegen::generator::wire::dart::spec_generator::codec::pde::entrypoint::PdeWireDartCodecEntrypoint;
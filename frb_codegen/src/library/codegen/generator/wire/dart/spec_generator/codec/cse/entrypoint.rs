@@ -0,0 +1,32 @@
+use crate::codegen::generator::codec::structs::{BaseCodecEntrypointTrait, EncodeOrDecode};
+use crate::codegen::generator::wire::dart::spec_generator::base::WireDartGeneratorContext;
+use crate::codegen::generator::wire::dart::spec_generator::codec::base::{
+    WireDartCodecEntrypointTrait, WireDartCodecOutputSpec,
+};
+use crate::codegen::generator::wire::dart::spec_generator::codec::pde::entrypoint::generate_dart2rust_inner_func_stmt_with_serializer;
+use crate::codegen::ir::mir::func::MirFunc;
+use crate::codegen::ir::mir::ty::MirType;
+
+pub(crate) struct CseWireDartCodecEntrypoint;
+
+impl BaseCodecEntrypointTrait<WireDartGeneratorContext<'_>, WireDartCodecOutputSpec>
+    for CseWireDartCodecEntrypoint
+{
+    fn generate(
+        &self,
+        _context: WireDartGeneratorContext,
+        _types: &[MirType],
+        _mode: EncodeOrDecode,
+    ) -> Option<WireDartCodecOutputSpec> {
+        None
+    }
+}
+
+impl WireDartCodecEntrypointTrait<'_> for CseWireDartCodecEntrypoint {
+    fn generate_dart2rust_inner_func_stmt(&self, func: &MirFunc, _wire_func_name: &str) -> String {
+        generate_dart2rust_inner_func_stmt_with_serializer(
+            func,
+            "SseSerializer(generalizedFrbRustBinding, compact: true)",
+        )
+    }
+}
@@ -1,6 +1,6 @@
 use crate::codegen::generator::acc::Acc;
 use crate::codegen::generator::codec::sse::ty::delegate::{
-    generate_set_to_list, generate_stream_sink_setup_and_serialize,
+    dart_check_std_duration, generate_set_to_list, generate_stream_sink_setup_and_serialize,
 };
 use crate::codegen::generator::misc::target::Target;
 use crate::codegen::generator::wire::dart::spec_generator::codec::cst::base::*;
@@ -82,18 +82,43 @@ impl<'a> WireDartCodecCstGeneratorEncoderTrait for DelegateWireDartCodecCstGener
             MirTypeDelegate::Time(mir) => match mir {
                 MirTypeDelegateTime::Utc
                 | MirTypeDelegateTime::Local
-                | MirTypeDelegateTime::Naive => Acc {
+                | MirTypeDelegateTime::Naive
+                | MirTypeDelegateTime::StdSystemTime
+                | MirTypeDelegateTime::TimeOffsetDateTime
+                | MirTypeDelegateTime::TimePrimitiveDateTime => Acc {
                     io: Some("return cst_encode_i_64(raw.microsecondsSinceEpoch);".into()),
                     web: Some(
                         "return cst_encode_i_64(BigInt.from(raw.millisecondsSinceEpoch));".into(),
                     ),
                     ..Default::default()
                 },
-                MirTypeDelegateTime::Duration => Acc {
+                MirTypeDelegateTime::NaiveDate | MirTypeDelegateTime::TimeDate => Acc {
+                    io: Some("return cst_encode_i_64(DateTime.utc(raw.year, raw.month, raw.day).microsecondsSinceEpoch);".into()),
+                    web: Some(
+                        "return cst_encode_i_64(BigInt.from(DateTime.utc(raw.year, raw.month, raw.day).millisecondsSinceEpoch));".into(),
+                    ),
+                    ..Default::default()
+                },
+                MirTypeDelegateTime::Duration
+                | MirTypeDelegateTime::NaiveTime
+                | MirTypeDelegateTime::TimeDuration => Acc {
                     io: Some("return cst_encode_i_64(raw.inMicroseconds);".into()),
                     web: Some("return cst_encode_i_64(BigInt.from(raw.inMilliseconds));".into()),
                     ..Default::default()
                 },
+                MirTypeDelegateTime::StdDuration => {
+                    let check = dart_check_std_duration("raw");
+                    Acc {
+                        io: Some(format!("{check}\nreturn cst_encode_i_64(raw.inMicroseconds);")),
+                        web: Some(format!(
+                            "{check}\nreturn cst_encode_i_64(BigInt.from(raw.inMilliseconds));"
+                        )),
+                        ..Default::default()
+                    }
+                }
+                MirTypeDelegateTime::FixedOffset | MirTypeDelegateTime::Tz => {
+                    Acc::distribute(Some("return cst_encode_String(raw.toString());".into()))
+                }
             },
             // MirTypeDelegate::TimeList(t) => Acc::distribute(Some(format!(
             //     "final ans = Int64List(raw.length);
@@ -105,6 +130,9 @@ impl<'a> WireDartCodecCstGeneratorEncoderTrait for DelegateWireDartCodecCstGener
                 "return cst_encode_{}(raw.toBytes());",
                 uint8list_safe_ident(true)
             ))),
+            MirTypeDelegate::Json(_) => {
+                Acc::distribute(Some("return cst_encode_String(jsonEncode(raw));".into()))
+            }
             // MirTypeDelegate::Uuids => Acc::distribute(Some(format!(
             //     "final builder = BytesBuilder();
             //     for (final element in raw) {{
@@ -127,11 +155,19 @@ impl<'a> WireDartCodecCstGeneratorEncoderTrait for DelegateWireDartCodecCstGener
                 self.mir.get_delegate().safe_ident(),
                 generate_set_to_list(mir, self.context.as_api_dart_context(), "raw"),
             ))),
+            MirTypeDelegate::VecDeque(_) => Acc::distribute(Some(format!(
+                "return cst_encode_{}(raw);",
+                self.mir.get_delegate().safe_ident(),
+            ))),
             MirTypeDelegate::StreamSink(mir) => Acc::distribute(Some(format!(
                 "return cst_encode_{}({});",
                 self.mir.get_delegate().safe_ident(),
                 generate_stream_sink_setup_and_serialize(mir, "raw")
             ))),
+            MirTypeDelegate::DartStream(_) => Acc::distribute(Some(format!(
+                "return cst_encode_{}(encodeDartStream(raw));",
+                self.mir.get_delegate().safe_ident(),
+            ))),
             MirTypeDelegate::BigPrimitive(_) => Acc::distribute(Some(
                 "return cst_encode_String(raw.toString());".to_string(),
             )),
@@ -1,4 +1,5 @@
 use crate::codegen::generator::acc::Acc;
+use crate::codegen::generator::misc::is_delegate_of_string;
 use crate::codegen::generator::misc::target::Target;
 use crate::codegen::generator::wire::dart::spec_generator::codec::cst::base::*;
 use crate::codegen::generator::wire::dart::spec_generator::codec::cst::encoder::ty::WireDartCodecCstGeneratorEncoderTrait;
@@ -33,6 +34,7 @@ impl<'a> WireDartCodecCstGeneratorEncoderTrait for GeneralListWireDartCodecCstGe
                             | MirType::Delegate(MirTypeDelegate::Time(_))
                             | MirType::Delegate(MirTypeDelegate::Uuid)
                     )
+                    || is_delegate_of_string(&self.mir.inner)
                 {
                     format!("ans.ref.ptr[i] = cst_encode_{inner}(raw[i]);")
                 } else {
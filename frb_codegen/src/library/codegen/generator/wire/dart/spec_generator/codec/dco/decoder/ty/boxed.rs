@@ -25,13 +25,13 @@ impl<'a> WireDartCodecDcoGeneratorDecoderTrait for BoxedWireDartCodecDcoGenerato
                 | MirTypePrimitive::U64
                 | MirTypePrimitive::Usize,
             )
-            | Delegate(MirTypeDelegate::Array(_) | MirTypeDelegate::PrimitiveEnum { .. }) => {
+            | Delegate(
+                MirTypeDelegate::Array(_)
+                | MirTypeDelegate::PrimitiveEnum { .. }
+                | MirTypeDelegate::Time(_),
+            ) => {
                 format!("return dco_decode_{}(raw);", self.mir.inner.safe_ident())
             }
-            // TODO merge with above
-            Delegate(MirTypeDelegate::Time(time)) => {
-                format!("return dco_decode_Chrono_{}(raw);", time)
-            }
             _ => gen_decode_simple_type_cast(self.mir.clone().into(), self.context),
         }
     }
@@ -1,3 +1,6 @@
+use crate::codegen::generator::codec::sse::ty::delegate::{
+    dart_decode_json, generate_anyhow_exception_dart_decode,
+};
 use crate::codegen::generator::wire::dart::spec_generator::codec::dco::base::*;
 use crate::codegen::generator::wire::dart::spec_generator::codec::dco::decoder::misc::gen_decode_simple_type_cast;
 use crate::codegen::generator::wire::dart::spec_generator::codec::dco::decoder::ty::WireDartCodecDcoGeneratorDecoderTrait;
@@ -53,12 +56,20 @@ impl<'a> WireDartCodecDcoGeneratorDecoderTrait for DelegateWireDartCodecDcoGener
                 ) // here `as int` is neccessary in strict dynamic mode
             }
             MirTypeDelegate::Time(mir) => {
-                if mir == &MirTypeDelegateTime::Duration {
+                if mir.is_encoded_as_string() {
+                    "return DateTimeWithOffset.parse(dco_decode_String(raw));".to_owned()
+                } else if matches!(
+                    mir,
+                    MirTypeDelegateTime::Duration
+                        | MirTypeDelegateTime::NaiveTime
+                        | MirTypeDelegateTime::StdDuration
+                        | MirTypeDelegateTime::TimeDuration
+                ) {
                     "return dcoDecodeDuration(dco_decode_i_64(raw).toInt());".to_owned()
                 } else {
                     format!(
                         "return dcoDecodeTimestamp(ts: dco_decode_i_64(raw).toInt(), isUtc: {is_utc});",
-                        is_utc = matches!(mir, MirTypeDelegateTime::Naive | MirTypeDelegateTime::Utc)
+                        is_utc = mir.is_utc()
                     )
                 }
             }
@@ -71,8 +82,15 @@ impl<'a> WireDartCodecDcoGeneratorDecoderTrait for DelegateWireDartCodecDcoGener
             MirTypeDelegate::Uuid => {
                 "return UuidValue.fromByteList(dco_decode_list_prim_u_8_strict(raw));".to_owned()
             }
+            MirTypeDelegate::Json(mir) => {
+                format!("return {};", dart_decode_json(mir, "dco_decode_String(raw)"))
+            }
             // MirTypeDelegate::Uuids => ...,
-            MirTypeDelegate::AnyhowException => "return AnyhowException(raw as String);".to_owned(),
+            MirTypeDelegate::AnyhowException => format!(
+                "final inner = (raw as List<dynamic>).cast<String>();
+                return {};",
+                generate_anyhow_exception_dart_decode("inner"),
+            ),
             MirTypeDelegate::Map(_) => format!(
                 "return Map.fromEntries(dco_decode_{}(raw).map((e) => MapEntry(e.$1, e.$2)));",
                 self.mir.get_delegate().safe_ident(),
@@ -81,9 +99,13 @@ impl<'a> WireDartCodecDcoGeneratorDecoderTrait for DelegateWireDartCodecDcoGener
                 "return Set.from(dco_decode_{}(raw));",
                 self.mir.get_delegate().safe_ident(),
             ),
-            MirTypeDelegate::StreamSink(_) | MirTypeDelegate::DynTrait(_) => "throw UnimplementedError();".to_owned(),
-            MirTypeDelegate::BigPrimitive(_) => {
-                "return BigInt.parse(raw);".to_owned()
+            MirTypeDelegate::VecDeque(_) => format!(
+                "return dco_decode_{}(raw);",
+                self.mir.get_delegate().safe_ident(),
+            ),
+            MirTypeDelegate::StreamSink(_) | MirTypeDelegate::DartStream(_) | MirTypeDelegate::DynTrait(_) => "throw UnimplementedError();".to_owned(),
+            MirTypeDelegate::BigPrimitive(mir) => {
+                format!("return {}.parse(raw);", mir.dart_api_type())
             }
             MirTypeDelegate::RustAutoOpaqueExplicit(mir) => format!(r"return dco_decode_{}(raw);", mir.inner.safe_ident()),
             MirTypeDelegate::ProxyVariant(_)
@@ -1,6 +1,7 @@
+use crate::codegen::generator::api_dart::spec_generator::class::ty::enumeration_exception::DISPLAY_MESSAGE_IDENT;
 use crate::codegen::generator::wire::dart::spec_generator::codec::dco::base::*;
 use crate::codegen::generator::wire::dart::spec_generator::codec::dco::decoder::ty::WireDartCodecDcoGeneratorDecoderTrait;
-use crate::codegen::ir::mir::ty::enumeration::{MirEnumMode, MirVariantKind};
+use crate::codegen::ir::mir::ty::enumeration::MirEnumMode;
 use crate::library::codegen::ir::mir::ty::MirTypeTrait;
 use itertools::Itertools;
 
@@ -14,24 +15,24 @@ impl<'a> WireDartCodecDcoGeneratorDecoderTrait for EnumRefWireDartCodecDcoGenera
             .iter()
             .enumerate()
             .map(|(idx, variant)| {
-                let args = match &variant.kind {
-                    MirVariantKind::Value => "".to_owned(),
-                    MirVariantKind::Struct(st) => st
-                        .fields
-                        .iter()
-                        .enumerate()
-                        .map(|(idx, field)| {
-                            let val =
-                                format!("dco_decode_{}(raw[{}]),", field.ty.safe_ident(), idx + 1);
-                            if st.is_fields_named {
-                                format!("{}: {}", field.name.dart_style(), val)
-                            } else {
-                                val
-                            }
-                        })
-                        .collect_vec()
-                        .join(""),
-                };
+                let fields = variant.kind.fields();
+                let mut args = (fields.iter().enumerate())
+                    .map(|(idx, field)| {
+                        let val =
+                            format!("dco_decode_{}(raw[{}]),", field.ty.safe_ident(), idx + 1);
+                        if variant.kind.is_fields_named() {
+                            format!("{}: {}", field.name.dart_style(), val)
+                        } else {
+                            val
+                        }
+                    })
+                    .join("");
+                if enu.typed_exceptions {
+                    args += &format!(
+                        "{DISPLAY_MESSAGE_IDENT}: dco_decode_String(raw[{}]),",
+                        fields.len() + 1
+                    );
+                }
                 format!("case {}: return {}({});", idx, variant.wrapper_name, args)
             })
             .collect_vec();
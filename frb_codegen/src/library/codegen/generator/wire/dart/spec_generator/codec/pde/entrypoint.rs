@@ -25,18 +25,28 @@ impl BaseCodecEntrypointTrait<WireDartGeneratorContext<'_>, WireDartCodecOutputS
 
 impl WireDartCodecEntrypointTrait<'_> for PdeWireDartCodecEntrypoint {
     fn generate_dart2rust_inner_func_stmt(&self, func: &MirFunc, _wire_func_name: &str) -> String {
-        let serialize_inputs = generate_serialize_inputs(func);
-        let (maybe_port, maybe_return, maybe_bang) = if has_port_argument(func.mode) {
-            (", port: port_", "", "")
-        } else {
-            ("", "return ", "!")
-        };
-        let func_id = func.id.unwrap();
-        format!(
+        generate_dart2rust_inner_func_stmt_with_serializer(
+            func,
+            "SseSerializer(generalizedFrbRustBinding)",
+        )
+    }
+}
+
+pub(crate) fn generate_dart2rust_inner_func_stmt_with_serializer(
+    func: &MirFunc,
+    serializer: &str,
+) -> String {
+    let serialize_inputs = generate_serialize_inputs(func);
+    let (maybe_port, maybe_return, maybe_bang) = if has_port_argument(func.mode) {
+        (", port: port_", "", "")
+    } else {
+        ("", "return ", "!")
+    };
+    let func_id = func.id.unwrap();
+    format!(
             "
-            final serializer = SseSerializer(generalizedFrbRustBinding);{serialize_inputs}
+            final serializer = {serializer};{serialize_inputs}
             {maybe_return}pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: {func_id}{maybe_port}){maybe_bang};
             "
         )
-    }
 }
@@ -69,9 +69,13 @@ pub(crate) fn generate_api_impl_normal_function(
             MirFuncMode::Sync => call_handler.clone(),
         };
 
+        let return_stream_args = (func.stream_capacity)
+            .map(|capacity| format!("capacity: {capacity}, binding: generalizedFrbRustBinding"))
+            .unwrap_or_default();
+
         format!(
             "
-            final {return_stream_name} = {return_stream_type}();
+            final {return_stream_name} = {return_stream_type}({return_stream_args});
             {wrapped_call_handler};
             return {return_stream_name}.stream;
             ",
@@ -156,7 +160,7 @@ fn generate_arg_values(func: &MirFunc) -> String {
 fn generate_rust2dart_codec_object(func: &MirFunc) -> String {
     let codec_mode = func.codec_mode_pack.rust2dart;
     let codec_name_pascal = codec_mode.delegate_or_self().to_string();
-    let codec_name_snake = codec_name_pascal.to_case(Case::Snake);
+    let codec_name_snake = (codec_mode.serialization().to_string()).to_case(Case::Snake);
 
     let parse_success_data = format!(
         "{codec_name_snake}_decode_{}",
@@ -1,6 +1,7 @@
 use crate::codegen::generator::codec::structs::CodecMode;
 use crate::codegen::ir::mir::ty::rust_opaque::RustOpaqueCodecMode;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -13,4 +14,6 @@ pub(crate) struct GeneratorWireRustInternalConfig {
     pub default_stream_sink_codec: CodecMode,
     pub default_rust_opaque_codec: RustOpaqueCodecMode,
     pub rust_preamble: String,
+    /// The number of threads of the thread pool for each executor class
+    pub thread_pools: BTreeMap<String, usize>,
 }
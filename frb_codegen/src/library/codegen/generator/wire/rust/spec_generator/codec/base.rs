@@ -3,6 +3,7 @@ use crate::codegen::generator::codec::structs::get_interest_types_for_codec;
 use crate::codegen::generator::codec::structs::EncodeOrDecode;
 use crate::codegen::generator::codec::structs::{BaseCodecEntrypointTrait, CodecMode};
 use crate::codegen::generator::wire::rust::spec_generator::base::WireRustGeneratorContext;
+use crate::codegen::generator::wire::rust::spec_generator::codec::cse::entrypoint::CseWireRustCodecEntrypoint;
 use crate::codegen::generator::wire::rust::spec_generator::codec::cst::entrypoint::CstWireRustCodecEntrypoint;
 use crate::codegen::generator::wire::rust::spec_generator::codec::dco::entrypoint::DcoWireRustCodecEntrypoint;
 use crate::codegen::generator::wire::rust::spec_generator::codec::pde::entrypoint::PdeWireRustCodecEntrypoint;
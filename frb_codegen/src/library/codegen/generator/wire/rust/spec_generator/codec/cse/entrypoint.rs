@@ -0,0 +1,46 @@
+use crate::codegen::generator::acc::Acc;
+use crate::codegen::generator::codec::structs::{BaseCodecEntrypointTrait, EncodeOrDecode};
+use crate::codegen::generator::wire::rust::spec_generator::base::WireRustGeneratorContext;
+use crate::codegen::generator::wire::rust::spec_generator::codec::base::{
+    WireRustCodecEntrypointTrait, WireRustCodecOutputSpec,
+};
+use crate::codegen::generator::wire::rust::spec_generator::codec::sse::entrypoint::{
+    generate_func_call_decode_with_deserializer, SseWireRustCodecEntrypoint,
+};
+use crate::codegen::generator::wire::rust::spec_generator::extern_func::ExternFuncParam;
+use crate::codegen::ir::mir::func::MirFunc;
+use crate::codegen::ir::mir::ty::MirType;
+
+pub(crate) struct CseWireRustCodecEntrypoint;
+
+impl BaseCodecEntrypointTrait<WireRustGeneratorContext<'_>, WireRustCodecOutputSpec>
+    for CseWireRustCodecEntrypoint
+{
+    fn generate(
+        &self,
+        _context: WireRustGeneratorContext,
+        _types: &[MirType],
+        _mode: EncodeOrDecode,
+    ) -> Option<WireRustCodecOutputSpec> {
+        // The SSE encoders and decoders are reused, and the functions are dispatched as in PDE
+        None
+    }
+}
+
+impl WireRustCodecEntrypointTrait<'_> for CseWireRustCodecEntrypoint {
+    fn generate_func_params(
+        &self,
+        func: &MirFunc,
+        context: WireRustGeneratorContext,
+    ) -> Acc<Vec<ExternFuncParam>> {
+        SseWireRustCodecEntrypoint.generate_func_params(func, context)
+    }
+
+    fn generate_func_call_decode(
+        &self,
+        func: &MirFunc,
+        context: WireRustGeneratorContext,
+    ) -> String {
+        generate_func_call_decode_with_deserializer(func, context, "new_compact")
+    }
+}
@@ -1,5 +1,7 @@
 use crate::codegen::generator::acc::Acc;
-use crate::codegen::generator::codec::sse::ty::delegate::rust_decode_primitive_enum;
+use crate::codegen::generator::codec::sse::ty::delegate::{
+    rust_decode_date_time_with_offset, rust_decode_json, rust_decode_primitive_enum,
+};
 use crate::codegen::generator::misc::is_js_value;
 use crate::codegen::generator::misc::target::{Target, TargetOrCommon};
 use crate::codegen::generator::wire::rust::spec_generator::codec::cst::base::*;
@@ -7,7 +9,7 @@ use crate::codegen::generator::wire::rust::spec_generator::codec::cst::decoder::
 use crate::codegen::generator::wire::rust::spec_generator::output_code::WireRustOutputCode;
 use crate::codegen::ir::mir::ty::delegate::{
     MirTypeDelegate, MirTypeDelegateArray, MirTypeDelegateMap, MirTypeDelegatePrimitiveEnum,
-    MirTypeDelegateSet, MirTypeDelegateTime,
+    MirTypeDelegateSet, MirTypeDelegateTime, MirTypeDelegateVecDeque,
 };
 use crate::library::codegen::ir::mir::ty::MirTypeTrait;
 
@@ -38,6 +40,35 @@ impl<'a> WireRustCodecCstGeneratorDecoderTrait for DelegateWireRustCodecCstGener
             // MirTypeDelegate::StringList => general_list_impl_decode_body(),
             MirTypeDelegate::PrimitiveEnum (inner) => rust_decode_primitive_enum(inner, self.context.mir_pack, "self").into(),
             MirTypeDelegate::Time(mir) => {
+                if mir.is_encoded_as_string() {
+                    return Acc::distribute(Some(rust_decode_date_time_with_offset(
+                        mir,
+                        "&CstDecode::<String>::cst_decode(self)",
+                    )));
+                }
+                if mir == &MirTypeDelegateTime::StdDuration {
+                    // Negative values are already rejected on the Dart side
+                    return Acc {
+                        common: Some("flutter_rust_bridge::for_generated::decode_std_duration(self).unwrap()".into()),
+                        ..Default::default()
+                    };
+                }
+                let codegen_std = match mir {
+                    MirTypeDelegateTime::StdSystemTime => Some("decode_system_time"),
+                    MirTypeDelegateTime::TimeOffsetDateTime => Some("decode_offset_date_time"),
+                    MirTypeDelegateTime::TimePrimitiveDateTime => {
+                        Some("decode_primitive_date_time")
+                    }
+                    MirTypeDelegateTime::TimeDate => Some("decode_date"),
+                    MirTypeDelegateTime::TimeDuration => Some("decode_time_duration"),
+                    _ => None,
+                };
+                if let Some(func) = codegen_std {
+                    return Acc {
+                        common: Some(format!("flutter_rust_bridge::for_generated::{func}(self)")),
+                        ..Default::default()
+                    };
+                }
                 if mir == &MirTypeDelegateTime::Duration {
                     return Acc {
                         io: Some("chrono::Duration::microseconds(self)".into()),
@@ -50,12 +81,24 @@ impl<'a> WireRustCodecCstGeneratorDecoderTrait for DelegateWireRustCodecCstGener
                     "chrono::DateTime::from_timestamp(s, ns).expect(\"invalid or out-of-range datetime\").naive_utc()";
                 let codegen_utc = format!("chrono::DateTime::<chrono::Utc>::from_naive_utc_and_offset({codegen_naive}, chrono::Utc)");
                 let codegen_local = format!("chrono::DateTime::<chrono::Local>::from({codegen_utc})");
+                let codegen_naive_date = format!("{codegen_naive}.date()");
+                let codegen_naive_time = "chrono::NaiveTime::from_num_seconds_from_midnight_opt(u32::try_from(s).expect(\"invalid or out-of-range time\"), ns).expect(\"invalid or out-of-range time\")";
                 let codegen_conversion = match mir {
                     MirTypeDelegateTime::Naive => codegen_naive,
                     MirTypeDelegateTime::Utc => codegen_utc.as_str(),
                     MirTypeDelegateTime::Local => codegen_local.as_str(),
+                    MirTypeDelegateTime::NaiveDate => codegen_naive_date.as_str(),
+                    MirTypeDelegateTime::NaiveTime => codegen_naive_time,
                     // frb-coverage:ignore-start
-                    MirTypeDelegateTime::Duration => unreachable!(),
+                    MirTypeDelegateTime::Duration
+                    | MirTypeDelegateTime::FixedOffset
+                    | MirTypeDelegateTime::Tz
+                    | MirTypeDelegateTime::StdDuration
+                    | MirTypeDelegateTime::StdSystemTime
+                    | MirTypeDelegateTime::TimeOffsetDateTime
+                    | MirTypeDelegateTime::TimePrimitiveDateTime
+                    | MirTypeDelegateTime::TimeDate
+                    | MirTypeDelegateTime::TimeDuration => unreachable!(),
                     // frb-coverage:ignore-end
                 };
                 Acc {
@@ -75,6 +118,7 @@ impl<'a> WireRustCodecCstGeneratorDecoderTrait for DelegateWireRustCodecCstGener
                     "let single: Vec<u8> = self.cst_decode(); flutter_rust_bridge::for_generated::decode_uuid(single)".into(),
                 ),
             ),
+            MirTypeDelegate::Json(_) => Acc::distribute(Some(rust_decode_json("&CstDecode::<String>::cst_decode(self)"))),
             // MirTypeDelegate::Uuids => Acc::distribute(
             //     Some(
             //         "let multiple: Vec<u8> = self.cst_decode(); flutter_rust_bridge::for_generated::decode_uuids(multiple)".into(),
@@ -89,6 +133,7 @@ impl<'a> WireRustCodecCstGeneratorDecoderTrait for DelegateWireRustCodecCstGener
             },
             MirTypeDelegate::Map(mir) => self.generate_skip_web_if_jsvalue(generate_decode_map(mir)),
             MirTypeDelegate::Set(mir) => self.generate_skip_web_if_jsvalue(generate_decode_set(mir)),
+            MirTypeDelegate::VecDeque(mir) => self.generate_skip_web_if_jsvalue(generate_decode_vec_deque(mir)),
             MirTypeDelegate::StreamSink(_) => Acc {
                 web: Some("StreamSink::deserialize(self)".into()),
                 io: Some("let raw: String = self.cst_decode(); StreamSink::deserialize(raw)".into()),
@@ -110,6 +155,7 @@ impl<'a> WireRustCodecCstGeneratorDecoderTrait for DelegateWireRustCodecCstGener
                 Acc::distribute(Some(r#"unimplemented!("Not implemented in this codec, please use the other one")"#.to_string())),
             MirTypeDelegate::CastedPrimitive(_)
             | MirTypeDelegate::CustomSerDes(_)
+            | MirTypeDelegate::DartStream(_)
             | MirTypeDelegate::Lifetimeable(_) => Acc::distribute(None),
             // frb-coverage:ignore-end
         }
@@ -133,10 +179,14 @@ impl<'a> WireRustCodecCstGeneratorDecoderTrait for DelegateWireRustCodecCstGener
             // }
             MirTypeDelegate::Time(mir) => match mir {
                 MirTypeDelegateTime::Duration => "chrono::Duration::milliseconds(CstDecode::<i64>::cst_decode(self))".into(),
+                MirTypeDelegateTime::FixedOffset | MirTypeDelegateTime::Tz => {
+                    rust_decode_date_time_with_offset(mir, "&CstDecode::<String>::cst_decode(self)").into()
+                }
                 _ => "CstDecode::<i64>::cst_decode(self).cst_decode()".into(),
             },
             // MirTypeDelegate::TimeList(_) =>
             //     "self.unchecked_into::<flutter_rust_bridge::for_generated::js_sys::BigInt64Array>().to_vec().into_iter().map(CstDecode::cst_decode).collect()".into(),
+            MirTypeDelegate::Json(_) => rust_decode_json("&CstDecode::<String>::cst_decode(self)").into(),
             MirTypeDelegate::Uuid /*| MirTypeDelegate::Uuids*/ => {
                 "self.unchecked_into::<flutter_rust_bridge::for_generated::js_sys::Uint8Array>().to_vec().into_boxed_slice().cst_decode()"
                     .into()
@@ -146,6 +196,7 @@ impl<'a> WireRustCodecCstGeneratorDecoderTrait for DelegateWireRustCodecCstGener
                 .into(),
             MirTypeDelegate::Map(mir) => generate_decode_map(mir).into(),
             MirTypeDelegate::Set(mir) => generate_decode_set(mir).into(),
+            MirTypeDelegate::VecDeque(mir) => generate_decode_vec_deque(mir).into(),
             MirTypeDelegate::StreamSink(_) => "StreamSink::deserialize(self.as_string().expect(\"should be a string\"))".into(),
             MirTypeDelegate::BigPrimitive(_) => "CstDecode::<String>::cst_decode(self).parse().unwrap()".into(),
             MirTypeDelegate::RustAutoOpaqueExplicit(_) =>
@@ -156,6 +207,7 @@ impl<'a> WireRustCodecCstGeneratorDecoderTrait for DelegateWireRustCodecCstGener
                 r#"unimplemented!("Not implemented in this codec, please use the other one")"#.into(),
             MirTypeDelegate::CastedPrimitive(_)
             | MirTypeDelegate::CustomSerDes(_)
+            | MirTypeDelegate::DartStream(_)
             | MirTypeDelegate::Lifetimeable(_) => return None,
             // frb-coverage:ignore-end
         })
@@ -165,6 +217,16 @@ impl<'a> WireRustCodecCstGeneratorDecoderTrait for DelegateWireRustCodecCstGener
         Default::default()
     }
 
+    fn generate_wire_func_param_api_type(&self) -> Option<String> {
+        match &self.mir {
+            MirTypeDelegate::DartStream(_) => {
+                WireRustCodecCstGenerator::new(self.mir.get_delegate(), self.context)
+                    .generate_wire_func_param_api_type()
+            }
+            _ => None,
+        }
+    }
+
     // the function signature is not covered while the whole body is covered - looks like a bug in coverage tool
     // frb-coverage:ignore-start
     fn rust_wire_type(&self, target: Target) -> String {
@@ -218,3 +280,10 @@ fn generate_decode_set(mir: &MirTypeDelegateSet) -> String {
         mir.inner.rust_api_type()
     )
 }
+
+fn generate_decode_vec_deque(mir: &MirTypeDelegateVecDeque) -> String {
+    format!(
+        "let vec: Vec<{}> = self.cst_decode(); vec.into_iter().collect()",
+        mir.inner.rust_api_type()
+    )
+}
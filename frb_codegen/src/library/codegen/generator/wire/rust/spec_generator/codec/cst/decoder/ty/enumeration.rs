@@ -157,7 +157,7 @@ fn generate_impl_cst_decode_body_variant(
 ) -> String {
     match &variant.kind {
         MirVariantKind::Value => {
-            format!("{} => {}::{},", idx, enu.name.rust_style(), variant.name)
+            format!("{} => {}::{},", idx, enu.rust_style(), variant.name)
         }
         MirVariantKind::Struct(st) => {
             let fields = st
@@ -181,7 +181,7 @@ fn generate_impl_cst_decode_body_variant(
                 .join(",");
 
             let (left, right) = st.brackets_pair();
-            let enum_name = &enu.name.rust_style();
+            let enum_name = &enu.rust_style();
             let variant_name = &variant.name;
 
             if target == TargetOrCommon::Web {
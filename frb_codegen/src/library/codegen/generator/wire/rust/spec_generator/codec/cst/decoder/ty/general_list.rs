@@ -1,4 +1,5 @@
 use crate::codegen::generator::acc::Acc;
+use crate::codegen::generator::misc::is_delegate_of_string;
 use crate::codegen::generator::misc::target::Target;
 use crate::codegen::generator::wire::rust::spec_generator::codec::cst::base::*;
 use crate::codegen::generator::wire::rust::spec_generator::codec::cst::decoder::misc::{
@@ -66,7 +67,8 @@ fn general_list_maybe_extra_pointer_indirection(mir: &MirTypeGeneralList) -> &'s
             | Delegate(MirTypeDelegate::StreamSink(_))
             | Delegate(MirTypeDelegate::Uuid)
             | MirType::PrimitiveList(_)
-    ) {
+    ) || is_delegate_of_string(&mir.inner)
+    {
         "*mut "
     } else {
         ""
@@ -17,8 +17,10 @@ impl<'a> WireRustCodecDcoGeneratorEncoderTrait for DelegateWireRustCodecDcoGener
         if let MirTypeDelegate::PrimitiveEnum(MirTypeDelegatePrimitiveEnum { mir, .. }) = &self.mir
         {
             let src = mir.get(self.context.mir_pack);
-            let (name, self_path) =
-                parse_wrapper_name_into_dart_name_and_self_path(&src.name, &src.wrapper_name);
+            let (name, self_path) = parse_wrapper_name_into_dart_name_and_self_path(
+                &src.rust_style(),
+                &src.wrapper_name,
+            );
 
             let self_ref = generate_enum_access_object_core(mir, "self".to_owned(), self.context);
             let variants = src
@@ -40,7 +42,7 @@ impl<'a> WireRustCodecDcoGeneratorEncoderTrait for DelegateWireRustCodecDcoGener
 
             return Some(
                 generate_impl_into_dart(&name, &body)
-                    + &generate_impl_into_into_dart(&src.name.rust_style(), &src.wrapper_name),
+                    + &generate_impl_into_into_dart(&src.rust_style(), &src.wrapper_name),
             );
         }
         None
@@ -7,14 +7,13 @@ use crate::codegen::generator::wire::rust::spec_generator::codec::dco::encoder::
 };
 use crate::codegen::generator::wire::rust::spec_generator::codec::dco::encoder::ty::WireRustCodecDcoGeneratorEncoderTrait;
 use crate::codegen::ir::mir::ty::enumeration::MirTypeEnumRef;
-use crate::utils::namespace::NamespacedName;
 use itertools::Itertools;
 
 impl<'a> WireRustCodecDcoGeneratorEncoderTrait for EnumRefWireRustCodecDcoGenerator<'a> {
     fn generate_impl_into_dart(&self) -> Option<String> {
         let src = self.mir.get(self.context.mir_pack);
         let (name, _self_path) =
-            parse_wrapper_name_into_dart_name_and_self_path(&src.name, &src.wrapper_name);
+            parse_wrapper_name_into_dart_name_and_self_path(&src.rust_style(), &src.wrapper_name);
         let self_ref = generate_enum_access_object_core(&self.mir, "self".to_owned(), self.context);
 
         let body = generate_enum_encode_rust_general(
@@ -23,18 +22,26 @@ impl<'a> WireRustCodecDcoGeneratorEncoderTrait for EnumRefWireRustCodecDcoGenera
             &self_ref,
             |idx, variant| {
                 let tag = format!("{idx}.into_dart()");
+                let display_message =
+                    (src.typed_exceptions).then(|| "display_message_.into_dart()".to_owned());
                 let fields = (Some(tag).into_iter())
                     .chain(variant.kind.fields().iter().map(|field| {
                         format!("{}.into_into_dart().into_dart()", field.name.rust_style())
                     }))
+                    .chain(display_message)
                     .join(",\n");
                 format!("[{fields}].into_dart()")
             },
         );
+        let body = if src.typed_exceptions {
+            format!("let display_message_ = {self_ref}.to_string();\n{body}")
+        } else {
+            body
+        };
 
         Some(
             generate_impl_into_dart(&name, &body)
-                + &generate_impl_into_into_dart(&src.name.rust_style(), &src.wrapper_name),
+                + &generate_impl_into_into_dart(&src.rust_style(), &src.wrapper_name),
         )
     }
 }
@@ -52,11 +59,11 @@ pub(super) fn generate_enum_access_object_core(
 }
 
 pub(super) fn parse_wrapper_name_into_dart_name_and_self_path(
-    name: &NamespacedName,
+    rust_style: &str,
     wrapper_name: &Option<String>,
 ) -> (String, String) {
     match &wrapper_name {
-        Some(wrapper) => (wrapper.clone(), name.rust_style()),
-        None => (name.rust_style(), "Self".into()),
+        Some(wrapper) => (wrapper.clone(), rust_style.to_owned()),
+        None => (rust_style.to_owned(), "Self".into()),
     }
 }
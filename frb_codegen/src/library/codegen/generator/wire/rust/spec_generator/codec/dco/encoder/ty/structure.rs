@@ -31,7 +31,7 @@ impl<'a> WireRustCodecDcoGeneratorEncoderTrait for StructRefWireRustCodecDcoGene
             .join(",\n");
 
         let (name, _) =
-            parse_wrapper_name_into_dart_name_and_self_path(&src.name, &src.wrapper_name);
+            parse_wrapper_name_into_dart_name_and_self_path(&src.rust_style(), &src.wrapper_name);
 
         let body = if src.is_empty() {
             "Vec::<u8>::new().into_dart()".to_string()
@@ -45,7 +45,7 @@ impl<'a> WireRustCodecDcoGeneratorEncoderTrait for StructRefWireRustCodecDcoGene
 
         Some(
             generate_impl_into_dart(&name, &body)
-                + &generate_impl_into_into_dart(&src.name.rust_style(), &src.wrapper_name),
+                + &generate_impl_into_into_dart(&src.rust_style(), &src.wrapper_name),
         )
     }
 }
@@ -1,4 +1,5 @@
 pub(crate) mod base;
+pub(crate) mod cse;
 pub(crate) mod cst;
 pub(crate) mod dco;
 pub(crate) mod pde;
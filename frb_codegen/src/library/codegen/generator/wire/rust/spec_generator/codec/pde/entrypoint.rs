@@ -44,7 +44,9 @@ fn generate_ffi_dispatcher(funcs: &[MirFunc]) -> WireRustCodecOutputSpec {
             (
                 mode,
                 (funcs.iter())
-                    .filter(|f| f.codec_mode_pack.dart2rust == CodecMode::Pde)
+                    .filter(|f| {
+                        matches!(f.codec_mode_pack.dart2rust, CodecMode::Pde | CodecMode::Cse)
+                    })
                     .filter(|f| FfiDispatcherMode::from(&f.mode) == mode)
                     .map(|f| {
                         let maybe_port = if has_port_argument(f.mode) {
@@ -58,30 +58,37 @@ impl WireRustCodecEntrypointTrait<'_> for SseWireRustCodecEntrypoint {
         func: &MirFunc,
         context: WireRustGeneratorContext,
     ) -> String {
-        let primary = (func.inputs.iter())
-            .map(|field| {
-                let gen = WireRustGenerator::new(field.inner.ty.clone(), context);
+        generate_func_call_decode_with_deserializer(func, context, "new")
+    }
+}
 
-                let name = field.inner.name.rust_style();
-                let effective_rust_api_type = (gen.generate_wire_func_call_decode_type())
-                    .unwrap_or_else(|| field.inner.ty.rust_api_type());
+pub(crate) fn generate_func_call_decode_with_deserializer(
+    func: &MirFunc,
+    context: WireRustGeneratorContext,
+    deserializer_constructor: &str,
+) -> String {
+    let primary = (func.inputs.iter())
+        .map(|field| {
+            let gen = WireRustGenerator::new(field.inner.ty.clone(), context);
 
-                let mut expr =
-                    format!("<{effective_rust_api_type}>::sse_decode(&mut deserializer)");
-                if let Some(wrapper) = gen.generate_wire_func_call_decode_wrapper() {
-                    expr = format!("{wrapper}({expr})");
-                }
+            let name = field.inner.name.rust_style();
+            let effective_rust_api_type = (gen.generate_wire_func_call_decode_type())
+                .unwrap_or_else(|| field.inner.ty.rust_api_type());
 
-                format!("let api_{name} = {expr};")
-            })
-            .join("\n");
-        format!(
-            "
-            let message = unsafe {{ flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) }};
-            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            {primary}deserializer.end();"
-        )
-    }
+            let mut expr = format!("<{effective_rust_api_type}>::sse_decode(&mut deserializer)");
+            if let Some(wrapper) = gen.generate_wire_func_call_decode_wrapper() {
+                expr = format!("{wrapper}({expr})");
+            }
+
+            format!("let api_{name} = {expr};")
+        })
+        .join("\n");
+    format!(
+        "
+        let message = unsafe {{ flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) }};
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::{deserializer_constructor}(message);
+        {primary}deserializer.end();"
+    )
 }
 
 pub(crate) fn create_maybe_port_param(
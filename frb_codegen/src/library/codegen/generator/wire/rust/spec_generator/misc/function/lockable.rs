@@ -71,13 +71,23 @@ fn generate_decode_statement(
     ownership_mode: OwnershipMode,
 ) -> String {
     let mode = ownership_mode.to_string().to_case(Case::Snake);
-    format!(
-        "api_{name}_guard = Some(api_{name}{maybe_illegal_static_ref}.lockable_decode_{syncness}_{mode}(){maybe_await})",
-        name = get_variable_name(field),
-        syncness = if func.rust_async { "async" } else { "sync" },
-        maybe_await = if func.rust_async { ".await" } else { "" },
-        maybe_illegal_static_ref = if field.needs_extend_lifetime { "_illegal_static_ref" } else { "" },
-    )
+    let name = get_variable_name(field);
+    let maybe_illegal_static_ref = if field.needs_extend_lifetime {
+        "_illegal_static_ref"
+    } else {
+        ""
+    };
+    // The function and argument names are only used in the errors of e.g. deadlocks
+    let func_name = &func.name.name;
+    if func.rust_async {
+        format!(
+            "api_{name}_guard = Some(flutter_rust_bridge::for_generated::lockable_with_site_async(\"{func_name}\", \"{name}\", api_{name}{maybe_illegal_static_ref}.lockable_decode_async_{mode}()).await)",
+        )
+    } else {
+        format!(
+            "api_{name}_guard = Some(flutter_rust_bridge::for_generated::lockable_with_site(\"{func_name}\", \"{name}\", || api_{name}{maybe_illegal_static_ref}.lockable_decode_sync_{mode}()))",
+        )
+    }
 }
 
 fn get_variable_name(field: &MirFuncInput) -> String {
@@ -103,8 +113,8 @@ fn compute_interest_field_ownership_mode(ty: &MirType) -> Option<OwnershipMode>
         MirType::Delegate(MirTypeDelegate::ProxyEnum(ty)) => {
             compute_interest_field_ownership_mode(&ty.original)
         }
-        // temporarily only support Ref
-        MirType::Delegate(MirTypeDelegate::DynTrait(_)) => Some(OwnershipMode::Ref),
+        // temporarily only support Ref, while `Box<dyn Trait>` is decoded as owned
+        MirType::Delegate(MirTypeDelegate::DynTrait(ty)) if !ty.boxed => Some(OwnershipMode::Ref),
         MirType::Delegate(MirTypeDelegate::Lifetimeable(mir)) => {
             Some(if mir.api_type.ownership_mode == OwnershipMode::RefMut {
                 OwnershipMode::RefMut
@@ -12,7 +12,10 @@ use crate::codegen::generator::wire::rust::spec_generator::extern_func::{
 };
 use crate::codegen::generator::wire::rust::spec_generator::output_code::WireRustOutputCode;
 use crate::codegen::ir::mir::func::{MirFunc, MirFuncMode, MirFuncOwnerInfo};
+use crate::codegen::ir::mir::ty::delegate::MirTypeDelegate;
+use crate::codegen::ir::mir::ty::enumeration::MirTypeEnumRef;
 use crate::codegen::ir::mir::ty::primitive::MirTypePrimitive;
+use crate::codegen::ir::mir::ty::structure::MirTypeStructRef;
 use crate::codegen::ir::mir::ty::MirType;
 use crate::library::codegen::ir::mir::ty::MirTypeTrait;
 use crate::misc::consts::HANDLER_NAME;
@@ -92,7 +95,7 @@ fn generate_inner_func_args(func: &MirFunc) -> Vec<String> {
 
 fn generate_wrap_info_obj(func: &MirFunc) -> String {
     format!(
-        "flutter_rust_bridge::for_generated::TaskInfo{{ debug_name: \"{name}\", port: {port}, mode: flutter_rust_bridge::for_generated::FfiCallMode::{mode} }}",
+        "flutter_rust_bridge::for_generated::TaskInfo{{ debug_name: \"{name}\", port: {port}, mode: flutter_rust_bridge::for_generated::FfiCallMode::{mode}, executor: {executor} }}",
         name = func.name.name,
         port = if has_port_argument(func.mode) {
             "Some(port_)"
@@ -100,6 +103,10 @@ fn generate_wrap_info_obj(func: &MirFunc) -> String {
             "None"
         },
         mode = ffi_call_mode(func.mode),
+        executor = match &func.executor {
+            Some(executor) => format!("Some({executor:?})"),
+            None => "None".to_owned(),
+        },
     )
 }
 
@@ -111,18 +118,37 @@ fn generate_code_call_inner_func_result(func: &MirFunc, inner_func_args: Vec<Str
     let mut ans = (func.rust_call_code.clone()).unwrap_or_else(|| {
         match &func.owner {
             MirFuncOwnerInfo::Function => {
-                format!("{}({})", func.name.rust_style(), inner_func_args.join(", "))
+                let name = match &func.instantiation {
+                    Some(instantiation) => instantiation.rust_style(&func.name.namespace),
+                    None => func.name.rust_style(),
+                };
+                format!("{name}({})", inner_func_args.join(", "))
             }
             MirFuncOwnerInfo::Method(method) => {
-                let owner_ty_name = method.owner_ty_name().unwrap().rust_style();
-                // For simplicity, remove all generics currently
-                lazy_static! {
-                    static ref REGEX: Regex = Regex::new(r#"<(.+)>"#).unwrap();
-                }
-                let stripped_name = REGEX.replace_all(&owner_ty_name, "").to_string();
+                let stripped_name = match &method.owner_ty {
+                    MirType::StructRef(MirTypeStructRef {
+                        instantiation: Some(_),
+                        ..
+                    })
+                    | MirType::EnumRef(MirTypeEnumRef {
+                        instantiation: Some(_),
+                        ..
+                    }) => method.owner_ty.rust_api_type(),
+                    _ => {
+                        let owner_ty_name = method.owner_ty_name().unwrap().rust_style();
+                        // For simplicity, remove all generics currently
+                        lazy_static! {
+                            static ref REGEX: Regex = Regex::new(r#"<(.+)>"#).unwrap();
+                        }
+                        REGEX.replace_all(&owner_ty_name, "").to_string()
+                    }
+                };
+                let turbofish = (func.instantiation.as_ref())
+                    .map(|instantiation| instantiation.turbofish())
+                    .unwrap_or_default();
 
                 format!(
-                    r"{stripped_name}::{}({})",
+                    r"{stripped_name}::{}{turbofish}({})",
                     method.actual_method_name,
                     inner_func_args.join(", ")
                 )
@@ -183,7 +209,7 @@ fn generate_return_type(func: &MirFunc) -> Option<String> {
     match func.mode {
         MirFuncMode::Sync => Some(format!(
             "flutter_rust_bridge::for_generated::WireSyncRust2Dart{}",
-            func.codec_mode_pack.rust2dart.delegate_or_self(),
+            func.codec_mode_pack.rust2dart.serialization(),
         )),
         MirFuncMode::Normal => None,
     }
@@ -209,15 +235,32 @@ fn generate_code_closure(
         "{code_inner_decode} {code_call_inner_func_result} {code_postprocess_inner_output} {code_aop_after} Ok(output_ok)"
     );
 
-    let err_type = (func.output.error.as_ref())
-        .map(|e| e.rust_api_type())
-        .unwrap_or("()".to_owned());
+    let err_type = match &func.output.error {
+        // The `IntoDart` of `anyhow::Error` only keeps the `Debug` representation
+        Some(MirType::Delegate(MirTypeDelegate::AnyhowException))
+            if codec_mode == CodecMode::Dco =>
+        {
+            "flutter_rust_bridge::for_generated::DcoAnyhowError".to_owned()
+        }
+        Some(error) => error.rust_api_type(),
+        None => "()".to_owned(),
+    };
+
+    // Let the error listener see the `Debug` representation of the error
+    let (record_error_start, record_error_end) = if func.output.error.is_some() {
+        (
+            format!("flutter_rust_bridge::frb_record_error_debug!({err_type}, "),
+            ")",
+        )
+    } else {
+        ("".to_owned(), "")
+    };
 
     let transform_result_func = format!(
         "transform_result_{codec}::<{generic_prefix}, {err_type}>",
         generic_prefix = match codec_mode {
             CodecMode::Dco => "_, _",
-            CodecMode::Sse => "_",
+            CodecMode::Sse | CodecMode::Cse => "_",
             _ => unreachable!(),
         }
     );
@@ -226,9 +269,9 @@ fn generate_code_closure(
         MirFuncMode::Sync => {
             format!(
                 "{code_decode}
-                {transform_result_func}((move || {{
+                {transform_result_func}({record_error_start}(move || {{
                     {code_inner}
-                }})())"
+                }})(){record_error_end})"
             )
         }
         MirFuncMode::Normal => {
@@ -236,9 +279,9 @@ fn generate_code_closure(
             let maybe_await = if func.rust_async { ".await" } else { "" };
             format!(
                 "{code_decode} move |context| {maybe_async_move} {{
-                    {transform_result_func}((move || {maybe_async_move} {{
+                    {transform_result_func}({record_error_start}(move || {maybe_async_move} {{
                         {code_inner}
-                    }})(){maybe_await})
+                    }})(){maybe_await}{record_error_end})
                 }}"
             )
         }
@@ -14,11 +14,12 @@ use crate::codegen::ir::mir::ty::rust_opaque::RustOpaqueCodecMode;
 use crate::codegen::ir::mir::ty::MirType;
 use crate::if_then_some;
 use crate::library::codegen::generator::wire::rust::spec_generator::misc::ty::WireRustGeneratorMiscTrait;
+use crate::library::misc::consts::BUILTIN_EXECUTORS;
 use crate::utils::namespace::Namespace;
 use itertools::Itertools;
 use serde::Serialize;
 use sha1::{Digest, Sha1};
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 
 pub(crate) mod function;
 pub(crate) mod ty;
@@ -47,7 +48,11 @@ pub(crate) fn generate(
         code_header: Acc::new(|_| vec![(generate_code_header() + "\n\n").into()]),
         file_attributes: Acc::new_common(vec![FILE_ATTRIBUTES.to_string().into()]),
         imports: generate_imports(&cache.distinct_types, context),
-        executor: Acc::new_common(vec![generate_handler(context.mir_pack).into()]),
+        executor: Acc::new_common(vec![generate_handler(
+            context.mir_pack,
+            &context.config.thread_pools,
+        )
+        .into()]),
         boilerplate: generate_boilerplate(
             context.config.default_stream_sink_codec,
             context.config.default_rust_opaque_codec,
@@ -247,11 +252,33 @@ fn generate_boilerplate(
 //     }
 // }
 
-fn generate_handler(mir_pack: &MirPack) -> String {
+fn generate_handler(mir_pack: &MirPack, thread_pools_config: &BTreeMap<String, usize>) -> String {
     if let Some(existing_handler) = &mir_pack.existing_handler {
-        format!("pub use {};", existing_handler.rust_style())
-    } else {
+        return format!("pub use {};", existing_handler.rust_style());
+    }
+
+    // A thread pool for each executor class, including the ones used without being configured
+    let thread_pools = (mir_pack.funcs_with_impl().iter())
+        .filter_map(|func| func.executor.clone())
+        .filter(|name| !BUILTIN_EXECUTORS.contains(&name.as_str()))
+        .chain(thread_pools_config.keys().cloned())
+        .sorted()
+        .dedup()
+        .map(|name| {
+            let num_threads = match thread_pools_config.get(&name) {
+                Some(num_threads) => format!("Some({num_threads})"),
+                None => "None".to_owned(),
+            };
+            format!("({name:?}, {num_threads})")
+        })
+        .join(", ");
+
+    if thread_pools.is_empty() {
         r#"flutter_rust_bridge::frb_generated_default_handler!();"#.to_owned()
+    } else {
+        format!(
+            "flutter_rust_bridge::frb_generated_default_handler!(thread_pools: [{thread_pools}]);"
+        )
     }
 }
 
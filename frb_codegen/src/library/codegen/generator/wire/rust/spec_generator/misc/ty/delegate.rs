@@ -1,5 +1,7 @@
+use crate::codegen::generator::acc::Acc;
 use crate::codegen::generator::wire::rust::spec_generator::base::*;
 use crate::codegen::generator::wire::rust::spec_generator::misc::ty::WireRustGeneratorMiscTrait;
+use crate::codegen::generator::wire::rust::spec_generator::output_code::WireRustOutputCode;
 use crate::codegen::ir::mir::ty::delegate::MirTypeDelegate;
 use crate::library::codegen::ir::mir::ty::MirTypeTrait;
 use crate::utils::namespace::Namespace;
@@ -30,11 +32,43 @@ impl<'a> WireRustGeneratorMiscTrait for DelegateWireRustGenerator<'a> {
         }
     }
 
+    fn generate_related_funcs(&self) -> Acc<WireRustOutputCode> {
+        if let MirTypeDelegate::DartStream(mir) = &self.mir {
+            Acc::new_common(
+                format!(
+                    "fn decode_{safe_ident}(
+                        dart_opaque: flutter_rust_bridge::DartOpaque,
+                    ) -> {rust_api_type} {{
+                        flutter_rust_bridge::DartStream::new(decode_{pull_safe_ident}(dart_opaque))
+                    }}",
+                    safe_ident = self.mir.safe_ident(),
+                    rust_api_type = self.mir.rust_api_type(),
+                    pull_safe_ident = mir.pull.safe_ident(),
+                )
+                .into(),
+            )
+        } else {
+            Default::default()
+        }
+    }
+
+    fn generate_wire_func_call_decode_wrapper(&self) -> Option<String> {
+        match &self.mir {
+            MirTypeDelegate::DartStream(_) => Some(format!("decode_{}", self.mir.safe_ident())),
+            // The implementor enum is converted into the boxed trait object
+            MirTypeDelegate::DynTrait(mir) if mir.boxed => {
+                Some(format!("<{}>::from", self.mir.rust_api_type()))
+            }
+            _ => None,
+        }
+    }
+
     fn generate_wire_func_call_decode_type(&self) -> Option<String> {
         match &self.mir {
             MirTypeDelegate::ProxyEnum(mir) => Some(mir.get_delegate().rust_api_type()),
             MirTypeDelegate::DynTrait(mir) => Some(mir.get_delegate().rust_api_type()),
             MirTypeDelegate::Lifetimeable(mir) => Some(mir.delegate.inner.rust_api_type()),
+            MirTypeDelegate::DartStream(mir) => Some(mir.pull.get_delegate().rust_api_type()),
             _ => None,
         }
     }
@@ -19,7 +19,7 @@ impl<'a> WireRustGeneratorMiscTrait for EnumRefWireRustGenerator<'a> {
             .iter()
             .map(|variant| match &variant.kind {
                 MirVariantKind::Value => {
-                    format!("{}::{} => {{}}", src.name.rust_style(), &variant.name)
+                    format!("{}::{} => {{}}", src.rust_style(), &variant.name)
                 }
                 MirVariantKind::Struct(s) => {
                     let pattern = s
@@ -30,14 +30,14 @@ impl<'a> WireRustGeneratorMiscTrait for EnumRefWireRustGenerator<'a> {
                     let pattern = if s.is_fields_named {
                         format!(
                             "{}::{} {{ {} }}",
-                            src.name.rust_style(),
+                            src.rust_style(),
                             variant.name,
                             pattern.join(",")
                         )
                     } else {
                         format!(
                             "{}::{}({})",
-                            src.name.rust_style(),
+                            src.rust_style(),
                             &variant.name,
                             pattern.join(",")
                         )
@@ -62,7 +62,7 @@ impl<'a> WireRustGeneratorMiscTrait for EnumRefWireRustGenerator<'a> {
 
         Some(format!(
             "match None::<{}>.unwrap() {{ {} }}",
-            src.name.rust_style(),
+            src.rust_style(),
             branches.join(","),
         ))
     }
@@ -40,7 +40,7 @@ impl<'a> WireRustGeneratorMiscTrait for StructRefWireRustGenerator<'a> {
 
         Some(format!(
             "{{ let {var} = None::<{src_name}>.unwrap(); {checks} }} ",
-            src_name = src.name.rust_style(),
+            src_name = src.rust_style(),
         ))
     }
 }
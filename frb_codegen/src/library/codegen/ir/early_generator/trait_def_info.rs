@@ -6,4 +6,5 @@ pub(crate) struct IrEarlyGeneratorTraitDefInfo {
     pub trait_def_name: NamespacedName,
     pub delegate_namespace: Namespace,
     pub variants: Vec<MirTypeDelegateDynTraitVariant>,
+    pub dart_impl_variant: Option<usize>,
 }
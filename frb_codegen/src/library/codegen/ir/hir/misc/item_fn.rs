@@ -1,4 +1,5 @@
-use proc_macro2::Span;
+use proc_macro2::{Span, TokenStream};
+use quote::ToTokens;
 use syn::spanned::Spanned;
 use syn::{Attribute, ImplItemFn, ItemFn, Signature, TraitItemFn, Visibility};
 
@@ -19,6 +20,14 @@ impl GeneralizedItemFn {
         }
     }
 
+    pub(crate) fn sig_mut(&mut self) -> &mut Signature {
+        match self {
+            Self::ItemFn(inner) => &mut inner.sig,
+            Self::ImplItemFn(inner) => &mut inner.sig,
+            Self::TraitItemFn(inner) => &mut inner.sig,
+        }
+    }
+
     pub(crate) fn name(&self) -> String {
         self.sig().ident.to_string()
     }
@@ -57,3 +66,13 @@ impl GeneralizedItemFn {
         }
     }
 }
+
+impl ToTokens for GeneralizedItemFn {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Self::ItemFn(inner) => inner.to_tokens(tokens),
+            Self::ImplItemFn(inner) => inner.to_tokens(tokens),
+            Self::TraitItemFn(inner) => inner.to_tokens(tokens),
+        }
+    }
+}
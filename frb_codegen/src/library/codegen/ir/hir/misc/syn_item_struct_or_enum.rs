@@ -1,3 +1,4 @@
+use syn::visit_mut::VisitMut;
 use syn::*;
 
 pub(crate) trait SynItemStructOrEnum: Clone {
@@ -6,10 +7,14 @@ pub(crate) trait SynItemStructOrEnum: Clone {
     fn attrs_mut(&mut self) -> &mut Vec<Attribute>;
 
     fn generics(&self) -> &Generics;
+
+    fn generics_mut(&mut self) -> &mut Generics;
+
+    fn visit_mut(&mut self, visitor: &mut impl VisitMut);
 }
 
 macro_rules! impl_trait {
-    ($name:ident) => {
+    ($name:ident, $visit:ident) => {
         impl SynItemStructOrEnum for $name {
             fn attrs(&self) -> &[syn::Attribute] {
                 &self.attrs
@@ -22,9 +27,17 @@ macro_rules! impl_trait {
             fn generics(&self) -> &syn::Generics {
                 &self.generics
             }
+
+            fn generics_mut(&mut self) -> &mut syn::Generics {
+                &mut self.generics
+            }
+
+            fn visit_mut(&mut self, visitor: &mut impl VisitMut) {
+                visitor.$visit(self);
+            }
         }
     };
 }
 
-impl_trait!(ItemStruct);
-impl_trait!(ItemEnum);
+impl_trait!(ItemStruct, visit_item_struct_mut);
+impl_trait!(ItemEnum, visit_item_enum_mut);
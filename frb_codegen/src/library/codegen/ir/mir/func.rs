@@ -1,6 +1,7 @@
 use crate::codegen::generator::codec::structs::CodecModePack;
 use crate::codegen::ir::mir::comment::MirComment;
 use crate::codegen::ir::mir::field::MirField;
+use crate::codegen::ir::mir::instantiation::MirInstantiation;
 use crate::codegen::ir::mir::ty::delegate::{
     MirTypeDelegate, MirTypeDelegatePrimitiveEnum, MirTypeDelegateProxyVariant,
 };
@@ -22,6 +23,8 @@ pub struct MirFunc {
     pub owner: MirFuncOwnerInfo,
     pub mode: MirFuncMode,
     pub stream_dart_await: bool,
+    pub stream_capacity: Option<usize>,
+    pub executor: Option<String>,
     pub rust_async: bool,
     pub initializer: bool,
     pub arg_mode: MirFuncArgMode,
@@ -31,6 +34,8 @@ pub struct MirFunc {
     pub rust_call_code: Option<String>,
     pub rust_aop_after: Option<String>,
     pub impl_mode: MirFuncImplMode,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instantiation: Option<MirInstantiation>,
     // Currently, we use serde only for tests. Since lineno can be unstable, we skip this field for comparison
     #[serde(skip_serializing)]
     pub src_lineno_pseudo: usize,
@@ -0,0 +1,33 @@
+use crate::utils::namespace::{Namespace, NamespacedName};
+
+crate::mir! {
+/// A concrete instantiation of a generic item, e.g. `Page<User>` of `struct Page<T>`.
+pub struct MirInstantiation {
+    /// The name of the generic item, e.g. `Page`
+    pub name: String,
+    /// The (Rust style) concrete types of the type parameters, e.g. `crate::api::User`
+    pub args: Vec<String>,
+}
+}
+
+impl MirInstantiation {
+    /// Use the turbofish syntax, since it is valid in both type and expression positions.
+    pub fn turbofish(&self) -> String {
+        format!("::<{}>", self.args.join(", "))
+    }
+
+    pub fn rust_style(&self, namespace: &Namespace) -> String {
+        format!("{namespace}::{}{}", self.name, self.turbofish())
+    }
+}
+
+/// The Rust path of an item, which is the instantiated generic item if `instantiation` exists.
+pub(crate) fn compute_rust_style(
+    name: &NamespacedName,
+    instantiation: &Option<MirInstantiation>,
+) -> String {
+    match instantiation {
+        Some(instantiation) => instantiation.rust_style(&name.namespace),
+        None => name.rust_style(),
+    }
+}
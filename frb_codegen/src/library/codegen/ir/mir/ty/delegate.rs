@@ -1,5 +1,6 @@
 use crate::codegen::generator::codec::structs::CodecMode;
 use crate::codegen::ir::mir::custom_ser_des::MirCustomSerDes;
+use crate::codegen::ir::mir::ty::dart_fn::MirTypeDartFn;
 use crate::codegen::ir::mir::ty::enumeration::{MirEnumIdent, MirTypeEnumRef};
 use crate::codegen::ir::mir::ty::general_list::{mir_list, MirTypeGeneralList};
 use crate::codegen::ir::mir::ty::primitive::MirTypePrimitive;
@@ -25,11 +26,14 @@ pub enum MirTypeDelegate {
     // TimeList(MirTypeDelegateTime),// TODO avoid this special case?
     Uuid,
     // Uuids,// TODO avoid this special case?
+    Json(MirTypeDelegateJson),
     Backtrace,
     AnyhowException,
     Map(MirTypeDelegateMap),
     Set(MirTypeDelegateSet),
+    VecDeque(MirTypeDelegateVecDeque),
     StreamSink(MirTypeDelegateStreamSink),
+    DartStream(MirTypeDelegateDartStream),
     BigPrimitive(MirTypeDelegateBigPrimitive),
     CastedPrimitive(MirTypeDelegateCastedPrimitive),
     RustAutoOpaqueExplicit(MirTypeDelegateRustAutoOpaqueExplicit),
@@ -63,15 +67,53 @@ pub enum MirTypeDelegateTime {
     Utc,
     Naive,
     Duration,
+    NaiveDate,
+    NaiveTime,
+    /// `chrono::DateTime<chrono::FixedOffset>`
+    FixedOffset,
+    /// `chrono::DateTime<chrono_tz::Tz>`
+    Tz,
+    /// `std::time::Duration`
+    StdDuration,
+    /// `std::time::SystemTime`
+    StdSystemTime,
+    /// `time::OffsetDateTime`
+    TimeOffsetDateTime,
+    /// `time::PrimitiveDateTime`
+    TimePrimitiveDateTime,
+    /// `time::Date`
+    TimeDate,
+    /// `time::Duration`
+    TimeDuration,
 }
 
 pub struct MirTypeDelegateMap {
+    pub kind: MirTypeDelegateMapKind,
     pub key: Box<MirType>,
     pub value: Box<MirType>,
     pub element_delegate: MirTypeRecord,
 }
 
+#[derive(Copy)]
+pub enum MirTypeDelegateMapKind {
+    HashMap,
+    /// Ordered by key, and the ordering is kept on the Dart side
+    BTreeMap,
+}
+
 pub struct MirTypeDelegateSet {
+    pub kind: MirTypeDelegateSetKind,
+    pub inner: Box<MirType>,
+}
+
+#[derive(Copy)]
+pub enum MirTypeDelegateSetKind {
+    HashSet,
+    /// Ordered, and the ordering is kept on the Dart side
+    BTreeSet,
+}
+
+pub struct MirTypeDelegateVecDeque {
     pub inner: Box<MirType>,
 }
 
@@ -81,10 +123,28 @@ pub struct MirTypeDelegateStreamSink {
     pub codec: CodecMode,
 }
 
+pub struct MirTypeDelegateDartStream {
+    pub inner: Box<MirType>,
+    /// The Dart function pulling the next item, i.e. `Fn(bool) -> DartFnFuture<Result<Option<T>>>`
+    pub pull: MirTypeDartFn,
+}
+
+#[derive(Copy, strum_macros::Display)]
+pub enum MirTypeDelegateJson {
+    /// `serde_json::Value`
+    Value,
+    /// `serde_json::Map<String, serde_json::Value>`
+    Map,
+}
+
 #[derive(Copy, strum_macros::Display)]
 pub enum MirTypeDelegateBigPrimitive {
     I128,
     U128,
+    /// `num_bigint::BigInt`
+    BigInt,
+    /// `rust_decimal::Decimal`
+    Decimal,
 }
 
 pub struct MirTypeDelegateCastedPrimitive {
@@ -110,6 +170,8 @@ pub struct MirTypeDelegateProxyEnum {
 
 pub struct MirTypeDelegateDynTrait {
     pub trait_def_name: NamespacedName,
+    /// `Box<dyn Trait>` (owned) instead of `dyn Trait` (borrowed)
+    pub boxed: bool,
     // `None` if and only if dummy mode
     pub data: Option<MirTypeDelegateDynTraitData>,
 }
@@ -122,6 +184,8 @@ pub struct MirTypeDelegateLifetimeable {
 pub struct MirTypeDelegateDynTraitData {
     pub delegate_namespace: Namespace,
     pub variants: Vec<MirTypeDelegateDynTraitVariant>,
+    /// Index of the variant wrapping Dart implementations, if the trait is `#[frb(dart_implementable)]`
+    pub dart_impl_variant: Option<usize>,
 }
 
 pub struct MirTypeDelegateDynTraitVariant {
@@ -162,19 +226,43 @@ impl MirTypeTrait for MirTypeDelegate {
             //     "ZeroCopyBuffer_".to_owned() + &self.get_delegate().safe_ident()
             // }
             MirTypeDelegate::PrimitiveEnum(mir) => mir.mir.safe_ident(),
-            MirTypeDelegate::Time(mir) => format!("Chrono_{}", mir),
+            MirTypeDelegate::Time(mir) => match mir {
+                MirTypeDelegateTime::StdDuration
+                | MirTypeDelegateTime::StdSystemTime
+                | MirTypeDelegateTime::TimeOffsetDateTime
+                | MirTypeDelegateTime::TimePrimitiveDateTime
+                | MirTypeDelegateTime::TimeDate
+                | MirTypeDelegateTime::TimeDuration => mir.to_string(),
+                _ => format!("Chrono_{}", mir),
+            },
             // MirTypeDelegate::TimeList(mir) => format!("Chrono_{}List", mir),
             MirTypeDelegate::Uuid => "Uuid".to_owned(),
+            MirTypeDelegate::Json(mir) => format!("Json{mir}"),
             // MirTypeDelegate::Uuids => "Uuids".to_owned(),
             MirTypeDelegate::Backtrace => "Backtrace".to_owned(),
             MirTypeDelegate::AnyhowException => "AnyhowException".to_owned(),
-            MirTypeDelegate::Map(mir) => {
-                format!("Map_{}_{}", mir.key.safe_ident(), mir.value.safe_ident())
-            }
-            MirTypeDelegate::Set(mir) => format!("Set_{}", mir.inner.safe_ident()),
+            MirTypeDelegate::Map(mir) => format!(
+                "{}_{}_{}",
+                match mir.kind {
+                    MirTypeDelegateMapKind::HashMap => "Map",
+                    MirTypeDelegateMapKind::BTreeMap => "BTreeMap",
+                },
+                mir.key.safe_ident(),
+                mir.value.safe_ident()
+            ),
+            MirTypeDelegate::Set(mir) => format!(
+                "{}_{}",
+                match mir.kind {
+                    MirTypeDelegateSetKind::HashSet => "Set",
+                    MirTypeDelegateSetKind::BTreeSet => "BTreeSet",
+                },
+                mir.inner.safe_ident()
+            ),
+            MirTypeDelegate::VecDeque(mir) => format!("VecDeque_{}", mir.inner.safe_ident()),
             MirTypeDelegate::StreamSink(mir) => {
                 format!("StreamSink_{}_{}", mir.inner_ok.safe_ident(), mir.codec)
             }
+            MirTypeDelegate::DartStream(mir) => format!("DartStream_{}", mir.inner.safe_ident()),
             MirTypeDelegate::BigPrimitive(mir) => mir.to_string(),
             MirTypeDelegate::CastedPrimitive(mir) => {
                 format!("CastedPrimitive_{}", mir.inner.safe_ident())
@@ -224,6 +312,16 @@ impl MirTypeTrait for MirTypeDelegate {
                 MirTypeDelegateTime::Local => "chrono::DateTime::<chrono::Local>",
                 MirTypeDelegateTime::Utc => "chrono::DateTime::<chrono::Utc>",
                 MirTypeDelegateTime::Duration => "chrono::Duration",
+                MirTypeDelegateTime::NaiveDate => "chrono::NaiveDate",
+                MirTypeDelegateTime::NaiveTime => "chrono::NaiveTime",
+                MirTypeDelegateTime::FixedOffset => "chrono::DateTime::<chrono::FixedOffset>",
+                MirTypeDelegateTime::Tz => "chrono::DateTime::<chrono_tz::Tz>",
+                MirTypeDelegateTime::StdDuration => "std::time::Duration",
+                MirTypeDelegateTime::StdSystemTime => "std::time::SystemTime",
+                MirTypeDelegateTime::TimeOffsetDateTime => "time::OffsetDateTime",
+                MirTypeDelegateTime::TimePrimitiveDateTime => "time::PrimitiveDateTime",
+                MirTypeDelegateTime::TimeDate => "time::Date",
+                MirTypeDelegateTime::TimeDuration => "time::Duration",
             }
             .to_owned(),
             // MirTypeDelegate::TimeList(mir) => match mir {
@@ -234,18 +332,35 @@ impl MirTypeTrait for MirTypeDelegate {
             // }
             // .to_owned(),
             MirTypeDelegate::Uuid => "uuid::Uuid".to_owned(),
+            MirTypeDelegate::Json(mir) => match mir {
+                MirTypeDelegateJson::Value => "serde_json::Value",
+                MirTypeDelegateJson::Map => "serde_json::Map<String, serde_json::Value>",
+            }
+            .to_owned(),
             // MirTypeDelegate::Uuids => "Vec<uuid::Uuid>".to_owned(),
             MirTypeDelegate::Backtrace => "backtrace::Backtrace".to_owned(),
             MirTypeDelegate::AnyhowException => {
                 "flutter_rust_bridge::for_generated::anyhow::Error".to_owned()
             }
             MirTypeDelegate::Map(mir) => format!(
-                "std::collections::HashMap<{}, {}>",
+                "std::collections::{}<{}, {}>",
+                match mir.kind {
+                    MirTypeDelegateMapKind::HashMap => "HashMap",
+                    MirTypeDelegateMapKind::BTreeMap => "BTreeMap",
+                },
                 mir.key.rust_api_type(),
                 mir.value.rust_api_type()
             ),
-            MirTypeDelegate::Set(mir) => {
-                format!("std::collections::HashSet<{}>", mir.inner.rust_api_type())
+            MirTypeDelegate::Set(mir) => format!(
+                "std::collections::{}<{}>",
+                match mir.kind {
+                    MirTypeDelegateSetKind::HashSet => "HashSet",
+                    MirTypeDelegateSetKind::BTreeSet => "BTreeSet",
+                },
+                mir.inner.rust_api_type()
+            ),
+            MirTypeDelegate::VecDeque(mir) => {
+                format!("std::collections::VecDeque<{}>", mir.inner.rust_api_type())
             }
             MirTypeDelegate::StreamSink(mir) => {
                 format!(
@@ -254,9 +369,17 @@ impl MirTypeTrait for MirTypeDelegate {
                     codec = mir.codec,
                 )
             }
+            MirTypeDelegate::DartStream(mir) => {
+                format!(
+                    "flutter_rust_bridge::DartStream<{}>",
+                    mir.inner.rust_api_type()
+                )
+            }
             MirTypeDelegate::BigPrimitive(mir) => match mir {
                 MirTypeDelegateBigPrimitive::I128 => "i128".to_owned(),
                 MirTypeDelegateBigPrimitive::U128 => "u128".to_owned(),
+                MirTypeDelegateBigPrimitive::BigInt => "num_bigint::BigInt".to_owned(),
+                MirTypeDelegateBigPrimitive::Decimal => "rust_decimal::Decimal".to_owned(),
             },
             MirTypeDelegate::CastedPrimitive(mir) => mir.inner.rust_api_type(),
             MirTypeDelegate::RustAutoOpaqueExplicit(mir) => {
@@ -266,7 +389,14 @@ impl MirTypeTrait for MirTypeDelegate {
                     mir.raw.string.with_static_lifetime()
                 )
             }
-            MirTypeDelegate::DynTrait(mir) => format!("dyn {}", mir.trait_def_name.name),
+            MirTypeDelegate::DynTrait(mir) => {
+                let dyn_trait = format!("dyn {}", mir.trait_def_name.name);
+                if mir.boxed {
+                    format!("Box<{dyn_trait}>")
+                } else {
+                    dyn_trait
+                }
+            }
             MirTypeDelegate::ProxyVariant(mir) => mir.inner.rust_api_type(),
             MirTypeDelegate::ProxyEnum(mir) => mir.original.rust_api_type(),
             MirTypeDelegate::Lifetimeable(mir) => mir.api_type.rust_api_type(),
@@ -277,7 +407,9 @@ impl MirTypeTrait for MirTypeDelegate {
     fn as_primitive(&self) -> Option<&MirTypePrimitive> {
         match self {
             MirTypeDelegate::PrimitiveEnum(MirTypeDelegatePrimitiveEnum { repr, .. }) => Some(repr),
-            MirTypeDelegate::Time(_) => Some(&MirTypePrimitive::I64),
+            MirTypeDelegate::Time(mir) if !mir.is_encoded_as_string() => {
+                Some(&MirTypePrimitive::I64)
+            }
             _ => None,
         }
     }
@@ -321,7 +453,13 @@ impl MirTypeDelegate {
             // }
             // MirTypeDelegate::StringList => MirType::Delegate(MirTypeDelegate::String),
             MirTypeDelegate::PrimitiveEnum(inner) => MirType::Primitive(inner.repr.clone()),
-            MirTypeDelegate::Time(_) => MirType::Primitive(MirTypePrimitive::I64),
+            MirTypeDelegate::Time(mir) => {
+                if mir.is_encoded_as_string() {
+                    MirType::Delegate(MirTypeDelegate::String)
+                } else {
+                    MirType::Primitive(MirTypePrimitive::I64)
+                }
+            }
             // MirTypeDelegate::TimeList(_) => MirType::PrimitiveList(MirTypePrimitiveList {
             //     primitive: MirTypePrimitive::I64,
             // }),
@@ -333,13 +471,19 @@ impl MirTypeDelegate {
             //     primitive: MirTypePrimitive::U8,
             // }),
             MirTypeDelegate::Backtrace => MirType::Delegate(MirTypeDelegate::String),
-            MirTypeDelegate::AnyhowException => MirType::Delegate(MirTypeDelegate::String),
+            MirTypeDelegate::AnyhowException => {
+                mir_list(MirType::Delegate(MirTypeDelegate::String), true)
+            }
             MirTypeDelegate::Map(mir) => {
                 mir_list(MirType::Record(mir.element_delegate.clone()), true)
             }
             MirTypeDelegate::Set(mir) => mir_list(*mir.inner.to_owned(), true),
+            MirTypeDelegate::VecDeque(mir) => mir_list(*mir.inner.to_owned(), true),
             MirTypeDelegate::StreamSink(_) => MirType::Delegate(MirTypeDelegate::String),
-            MirTypeDelegate::BigPrimitive(_) => MirType::Delegate(MirTypeDelegate::String),
+            MirTypeDelegate::DartStream(mir) => MirType::DartFn(mir.pull.clone()),
+            MirTypeDelegate::BigPrimitive(_) | MirTypeDelegate::Json(_) => {
+                MirType::Delegate(MirTypeDelegate::String)
+            }
             MirTypeDelegate::CastedPrimitive(mir) => MirType::Primitive(mir.inner.clone()),
             MirTypeDelegate::RustAutoOpaqueExplicit(mir) => MirType::RustOpaque(mir.inner.clone()),
             MirTypeDelegate::DynTrait(mir) => mir.get_delegate(),
@@ -353,6 +497,40 @@ impl MirTypeDelegate {
     }
 }
 
+impl MirTypeDelegateBigPrimitive {
+    pub(crate) fn dart_api_type(&self) -> &'static str {
+        match self {
+            MirTypeDelegateBigPrimitive::Decimal => "Decimal",
+            _ => "BigInt",
+        }
+    }
+}
+
+impl MirTypeDelegateTime {
+    /// Whether the Dart `DateTime` is in UTC (otherwise local time)
+    pub(crate) fn is_utc(&self) -> bool {
+        matches!(
+            self,
+            MirTypeDelegateTime::Naive
+                | MirTypeDelegateTime::Utc
+                | MirTypeDelegateTime::NaiveDate
+                | MirTypeDelegateTime::StdSystemTime
+                | MirTypeDelegateTime::TimeOffsetDateTime
+                | MirTypeDelegateTime::TimePrimitiveDateTime
+                | MirTypeDelegateTime::TimeDate
+        )
+    }
+
+    /// Types with offsets are sent as RFC 3339 strings to keep the offset,
+    /// while the others are sent as `i64` timestamps or durations
+    pub(crate) fn is_encoded_as_string(&self) -> bool {
+        matches!(
+            self,
+            MirTypeDelegateTime::FixedOffset | MirTypeDelegateTime::Tz
+        )
+    }
+}
+
 impl MirTypeDelegateArray {
     pub fn get_delegate(&self) -> MirType {
         match &self.mode {
@@ -398,6 +576,7 @@ impl MirTypeDelegateProxyEnum {
                 self.delegate_enum_name(),
             )),
             is_exception: false,
+            instantiation: None,
         })
     }
 
@@ -419,6 +598,7 @@ impl MirTypeDelegateDynTrait {
                     self.delegate_enum_name(),
                 )),
                 is_exception: false,
+                instantiation: None,
             })
         } else {
             MirType::Primitive(MirTypePrimitive::Unit)
@@ -430,7 +610,8 @@ impl MirTypeDelegateDynTrait {
     }
 
     pub(crate) fn safe_ident(&self) -> String {
-        format!("DynTrait_{}", self.trait_def_name.name)
+        let maybe_box = if self.boxed { "box_" } else { "" };
+        format!("{maybe_box}DynTrait_{}", self.trait_def_name.name)
     }
 
     pub(crate) fn data(&self) -> &MirTypeDelegateDynTraitData {
@@ -1,8 +1,11 @@
 // Name "enumeration" not "enum", since the latter is a keyword
 
+use crate::codegen::generator::codec::sse::lang::Lang;
 use crate::codegen::ir::mir::comment::MirComment;
 use crate::codegen::ir::mir::field::MirField;
 use crate::codegen::ir::mir::ident::MirIdent;
+use crate::codegen::ir::mir::instantiation::{compute_rust_style, MirInstantiation};
+use crate::codegen::ir::mir::ty::delegate::MirTypeDelegate;
 use crate::codegen::ir::mir::ty::structure::MirStruct;
 use crate::codegen::ir::mir::ty::{MirContext, MirType, MirTypeTrait};
 use crate::utils::namespace::{Namespace, NamespacedName};
@@ -12,6 +15,8 @@ crate::mir! {
 pub struct MirTypeEnumRef {
     pub ident: MirEnumIdent,
     pub is_exception: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instantiation: Option<MirInstantiation>,
 }
 
 pub struct MirEnumIdent(pub NamespacedName);
@@ -19,9 +24,13 @@ pub struct MirEnumIdent(pub NamespacedName);
 pub struct MirEnum {
     pub name: NamespacedName,
     pub wrapper_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instantiation: Option<MirInstantiation>,
     pub comments: Vec<MirComment>,
     pub variants: Vec<MirEnumVariant>,
     pub mode: MirEnumMode,
+    /// Generate a sealed Dart exception class hierarchy, one subclass per variant
+    pub typed_exceptions: bool,
     pub ignore: bool,
 }
 
@@ -66,6 +75,10 @@ impl MirTypeTrait for MirTypeEnumRef {
                     .for_each(|field| field.ty.visit_types(f, mir_context));
             }
         }
+        if enu.typed_exceptions {
+            // The `Display` message of the error
+            MirType::Delegate(MirTypeDelegate::String).visit_types(f, mir_context);
+        }
     }
 
     fn safe_ident(&self) -> String {
@@ -73,7 +86,7 @@ impl MirTypeTrait for MirTypeEnumRef {
     }
 
     fn rust_api_type(&self) -> String {
-        self.ident.0.rust_style()
+        compute_rust_style(&self.ident.0, &self.instantiation)
     }
 
     fn self_namespace(&self) -> Option<Namespace> {
@@ -86,6 +99,17 @@ impl MirTypeTrait for MirTypeEnumRef {
 }
 
 impl MirEnum {
+    pub fn rust_style(&self) -> String {
+        compute_rust_style(&self.name, &self.instantiation)
+    }
+
+    pub fn style(&self, lang: &Lang) -> String {
+        match lang {
+            Lang::DartLang(_) => self.name.name.clone(),
+            Lang::RustLang(_) => self.rust_style(),
+        }
+    }
+
     pub fn variants(&self) -> &[MirEnumVariant] {
         &self.variants
     }
@@ -98,6 +122,16 @@ impl MirVariantKind {
             MirVariantKind::Struct(st) => st.fields.clone(),
         }
     }
+
+    pub(crate) fn is_fields_named(&self) -> bool {
+        matches!(
+            self,
+            MirVariantKind::Struct(MirStruct {
+                is_fields_named: true,
+                ..
+            })
+        )
+    }
 }
 
 impl From<NamespacedName> for MirEnumIdent {
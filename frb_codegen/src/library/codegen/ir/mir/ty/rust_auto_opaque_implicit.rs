@@ -13,6 +13,8 @@ pub struct MirTypeRustAutoOpaqueImplicit {
     pub raw: MirRustAutoOpaqueRaw,
     pub reason: Option<MirTypeRustAutoOpaqueImplicitReason>,
     pub ignore: bool,
+    #[serde(skip_serializing_if = "MirRustAutoOpaqueLockStrategy::is_default")]
+    pub lock_strategy: MirRustAutoOpaqueLockStrategy,
 }
 
 /// Original type without any transformation
@@ -25,6 +27,16 @@ pub struct MirRustAutoOpaqueRaw {
 pub enum MirTypeRustAutoOpaqueImplicitReason {
     StructOrEnumRequireOpaque,
 }
+
+/// Configured via `#[frb(lock = ..)]`, see `RustAutoOpaqueLockStrategy` in `flutter_rust_bridge`
+#[derive(Copy, Default)]
+pub enum MirRustAutoOpaqueLockStrategy {
+    #[default]
+    RwLock,
+    Mutex,
+    ParkingLot,
+    None,
+}
 }
 
 impl MirTypeTrait for MirTypeRustAutoOpaqueImplicit {
@@ -70,3 +82,14 @@ impl MirTypeRustAutoOpaqueImplicit {
         self.inner.sanitized_type()
     }
 }
+
+impl MirRustAutoOpaqueLockStrategy {
+    pub(crate) fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Whether the object can only be borrowed immutably, thus no `&mut self` APIs.
+    pub(crate) fn forbid_borrow_mut(&self) -> bool {
+        *self == Self::None
+    }
+}
@@ -1,8 +1,10 @@
 // Name "structure" not "struct", since the latter is a keyword
 
+use crate::codegen::generator::codec::sse::lang::Lang;
 use crate::codegen::ir::mir::annotation::MirDartAnnotation;
 use crate::codegen::ir::mir::comment::MirComment;
 use crate::codegen::ir::mir::field::MirField;
+use crate::codegen::ir::mir::instantiation::{compute_rust_style, MirInstantiation};
 use crate::codegen::ir::mir::ty::{MirContext, MirType, MirTypeTrait};
 use crate::utils::namespace::{Namespace, NamespacedName};
 use convert_case::{Case, Casing};
@@ -11,6 +13,8 @@ crate::mir! {
 pub struct MirTypeStructRef {
     pub ident: MirStructIdent,
     pub is_exception: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instantiation: Option<MirInstantiation>,
 }
 
 pub struct MirStructIdent(pub NamespacedName);
@@ -18,6 +22,8 @@ pub struct MirStructIdent(pub NamespacedName);
 pub struct MirStruct {
     pub name: NamespacedName,
     pub wrapper_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instantiation: Option<MirInstantiation>,
     pub fields: Vec<MirField>,
     pub is_fields_named: bool,
     pub dart_metadata: Vec<MirDartAnnotation>,
@@ -54,7 +60,7 @@ impl MirTypeTrait for MirTypeStructRef {
     }
 
     fn rust_api_type(&self) -> String {
-        self.ident.0.rust_style()
+        compute_rust_style(&self.ident.0, &self.instantiation)
     }
 
     fn self_namespace(&self) -> Option<Namespace> {
@@ -67,6 +73,17 @@ impl MirTypeTrait for MirTypeStructRef {
 }
 
 impl MirStruct {
+    pub fn rust_style(&self) -> String {
+        compute_rust_style(&self.name, &self.instantiation)
+    }
+
+    pub fn style(&self, lang: &Lang) -> String {
+        match lang {
+            Lang::DartLang(_) => self.name.name.clone(),
+            Lang::RustLang(_) => self.rust_style(),
+        }
+    }
+
     pub fn using_freezed(&self) -> bool {
         self.dart_metadata.iter().any(|it| it.content == "freezed")
     }
@@ -53,6 +53,7 @@ impl IrSkipReason {
     }
 }
 
+#[derive(Clone)]
 pub(crate) enum IrValueOrSkip<T, S> {
     Value(T),
     Skip(S),
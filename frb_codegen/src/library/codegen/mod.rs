@@ -1,5 +1,7 @@
 //! Code generator for `flutter_rust_bridge`
 
+pub(crate) mod cache;
+mod checker;
 pub(crate) mod config;
 mod controller;
 pub(crate) mod dumper;
@@ -10,9 +12,12 @@ pub(crate) mod parser;
 mod polisher;
 mod preparer;
 
+use crate::codegen::cache::memory::MemoryCache;
+use crate::codegen::cache::{compute_hash, Cache};
 use crate::codegen::config::internal_config::InternalConfig;
 use crate::codegen::dumper::internal_config::ConfigDumpContent::Config as ContentConfig;
 use crate::codegen::dumper::Dumper;
+use crate::codegen::generator::GeneratorOutput;
 use crate::codegen::misc::GeneratorProgressBarPack;
 pub use config::config::{Config, MetaConfig};
 pub use dumper::internal_config::ConfigDumpContent;
@@ -30,16 +35,96 @@ pub fn generate(config: Config, meta_config: MetaConfig) -> anyhow::Result<()> {
         .with_content(ContentConfig)
         .dump("config.json", &config)?;
 
+    // Only watch mode runs more than once in the same process, thus can benefit from it
+    let memory_cache = (internal_config.controller.watch).then(MemoryCache::default);
+
     controller::run(&internal_config.controller, &|| {
-        generate_once(&internal_config, &dumper)
+        generate_once(&internal_config, &dumper, memory_cache.as_ref())
     })?;
 
     Ok(())
 }
 
-fn generate_once(internal_config: &InternalConfig, dumper: &Dumper) -> anyhow::Result<()> {
+/// Verify the generated code on disk is up to date, without modifying it
+pub fn check(config: Config) -> anyhow::Result<()> {
+    debug!("config={config:?}");
+
+    let internal_config = InternalConfig::parse(&config, &MetaConfig { watch: false })?;
+    debug!("internal_config={internal_config:?}");
+
+    let dumper = Dumper::new(&internal_config.dumper);
+    let cache = Cache::new(&internal_config.cache);
+    let progress_bar_pack = GeneratorProgressBarPack::new();
+
+    let generator_output =
+        parse_and_generate(&internal_config, &dumper, &cache, &progress_bar_pack)?;
+    checker::check(&internal_config.polisher, &generator_output.output_texts)
+}
+
+fn generate_once(
+    internal_config: &InternalConfig,
+    dumper: &Dumper,
+    memory_cache: Option<&MemoryCache>,
+) -> anyhow::Result<()> {
     let progress_bar_pack = GeneratorProgressBarPack::new();
+    let cache = Cache::new(&internal_config.cache);
+    let cache = memory_cache.map_or(cache, |memory_cache| cache.with_memory(memory_cache));
+
+    let cache_key = compute_cache_key(internal_config, &cache)?;
+    // When dumping is requested, the full pipeline should run to produce the dumps
+    if internal_config.dumper.dump_contents.is_empty()
+        && cache::outputs::is_up_to_date(&cache, &cache_key)
+    {
+        println!("Done! (Inputs are unchanged, use `--no-cache` to force regenerating)");
+        return Ok(());
+    }
+
+    if let Some(memory_cache) = cache.memory() {
+        memory_cache.start_iteration();
+    }
+    let generator_output = parse_and_generate(internal_config, dumper, &cache, &progress_bar_pack)?;
+
+    let changed_output_texts =
+        cache::outputs::filter_changed(&cache, &generator_output.output_texts);
+    changed_output_texts.write_to_disk()?;
+
+    let pb = progress_bar_pack.polish.start();
+    polisher::polish(
+        &internal_config.polisher,
+        generator_output.dart_needs_freezed,
+        &changed_output_texts.paths(),
+        &progress_bar_pack,
+    )?;
+    drop(pb);
+
+    cache::outputs::record(&cache, &cache_key, &generator_output.output_texts)?;
+
+    println!("Done!");
+
+    Ok(())
+}
+
+fn compute_cache_key(internal_config: &InternalConfig, cache: &Cache) -> anyhow::Result<String> {
+    if !internal_config.cache.enabled {
+        return Ok(String::new());
+    }
+    Ok(compute_hash([
+        env!("CARGO_PKG_VERSION").to_owned(),
+        serde_json::to_string(&internal_config.parser)?,
+        serde_json::to_string(&internal_config.generator)?,
+        serde_json::to_string(&internal_config.polisher)?,
+        cache
+            .compute_rust_sources_hash(&internal_config.parser.hir.rust_crate_dir)?
+            .overall(),
+    ]))
+}
 
+fn parse_and_generate(
+    internal_config: &InternalConfig,
+    dumper: &Dumper,
+    cache: &Cache,
+    progress_bar_pack: &GeneratorProgressBarPack,
+) -> anyhow::Result<GeneratorOutput> {
     dumper
         .with_content(ContentConfig)
         .dump("internal_config.json", &internal_config)?;
@@ -47,7 +132,7 @@ fn generate_once(internal_config: &InternalConfig, dumper: &Dumper) -> anyhow::R
     preparer::prepare(&internal_config.preparer)?;
 
     let pb = progress_bar_pack.parse.start();
-    let mir_pack = parser::parse(&internal_config.parser, dumper, &progress_bar_pack)?;
+    let mir_pack = parser::parse(&internal_config.parser, dumper, cache, progress_bar_pack)?;
     drop(pb);
 
     let pb = progress_bar_pack.generate.start();
@@ -55,22 +140,9 @@ fn generate_once(internal_config: &InternalConfig, dumper: &Dumper) -> anyhow::R
         &mir_pack,
         &internal_config.generator,
         dumper,
-        &progress_bar_pack,
+        progress_bar_pack,
     )?;
     drop(pb);
 
-    generator_output.output_texts.write_to_disk()?;
-
-    let pb = progress_bar_pack.polish.start();
-    polisher::polish(
-        &internal_config.polisher,
-        generator_output.dart_needs_freezed,
-        &generator_output.output_texts.paths(),
-        &progress_bar_pack,
-    )?;
-    drop(pb);
-
-    println!("Done!");
-
-    Ok(())
+    Ok(generator_output)
 }
@@ -1,9 +1,11 @@
 mod proxy_enum;
 mod sorter;
+pub(crate) mod trait_dart_impl;
 pub(crate) mod trait_impl_enum;
 pub(crate) mod ui_related;
 pub(crate) mod utils;
 
+use crate::codegen::cache::Cache;
 use crate::codegen::dumper::Dumper;
 use crate::codegen::ir::early_generator::pack::IrEarlyGeneratorPack;
 use crate::codegen::ir::hir::flat::pack::HirFlatPack;
@@ -14,18 +16,24 @@ pub(crate) fn execute(
     hir_flat_pack: HirFlatPack,
     config_mir: &ParserMirInternalConfig,
     dumper: &Dumper,
+    cache: &Cache,
 ) -> anyhow::Result<IrEarlyGeneratorPack> {
     let mut pack = IrEarlyGeneratorPack {
         hir_flat_pack,
         ..Default::default()
     };
 
+    // Before the tentative MIR, since the generated Dart implementations are implementors of the traits
+    trait_dart_impl::generate(&mut pack, config_mir)?;
+    dumper.dump("0_trait_dart_impl.json", &pack)?;
+
     let dumper_tentative_mir = dumper.with_add_name_prefix("1_tentative_mir/");
     let tentative_mir_pack = mir::parse(
         config_mir,
         &pack,
         &dumper_tentative_mir,
         mir::ParseMode::Early,
+        cache,
     )?;
 
     trait_impl_enum::generate(&mut pack, &tentative_mir_pack, config_mir)?;
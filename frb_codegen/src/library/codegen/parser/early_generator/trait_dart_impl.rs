@@ -0,0 +1,252 @@
+use crate::codegen::ir::early_generator::pack::IrEarlyGeneratorPack;
+use crate::codegen::ir::hir::flat::function::{HirFlatFunction, HirFlatFunctionOwner};
+use crate::codegen::ir::hir::flat::traits::HirFlatTrait;
+use crate::codegen::ir::hir::misc::item_fn::GeneralizedItemFn;
+use crate::codegen::ir::mir::ident::MirIdent;
+use crate::codegen::parser::hir::flat::extra_code_injector::{
+    inject_extra_codes, InjectExtraCodeBlock,
+};
+use crate::codegen::parser::mir::internal_config::ParserMirInternalConfig;
+use crate::codegen::parser::mir::parser::attribute::FrbAttributes;
+use crate::codegen::parser::mir::parser::ty::dart_fn::extract_dart_fn_future_inner;
+use crate::if_then_some;
+use crate::utils::syn_utils::ty_to_string;
+use anyhow::{bail, ensure};
+use convert_case::{Case, Casing};
+use itertools::Itertools;
+use syn::{FnArg, Pat, ReturnType};
+
+pub(crate) fn generate(
+    pack: &mut IrEarlyGeneratorPack,
+    config_mir: &ParserMirInternalConfig,
+) -> anyhow::Result<()> {
+    let output_namespace = &(config_mir.rust_input_namespace_pack).rust_output_path_namespace;
+
+    let mut extra_codes = vec![];
+    for hir_trait in pack
+        .hir_flat_pack
+        .traits
+        .iter()
+        .sorted_by_key(|x| x.name.clone())
+    {
+        if FrbAttributes::parse(&hir_trait.attrs)?.dart_implementable() {
+            let methods = parse_methods(hir_trait, &pack.hir_flat_pack.functions)?;
+            // Methods with default implementations are not overridden by Dart
+            let methods = (methods.into_iter())
+                .filter(|method| !method.has_default)
+                .collect_vec();
+            extra_codes.push(InjectExtraCodeBlock {
+                code: generate_dart_impl(hir_trait, &methods),
+                should_parse: true,
+            });
+        }
+    }
+
+    inject_extra_codes(&mut pack.hir_flat_pack, output_namespace, &extra_codes)
+}
+
+pub(crate) fn compute_struct_name(trait_def_name: &str) -> String {
+    format!("{trait_def_name}DartImpl")
+}
+
+pub(crate) struct MethodInfo {
+    pub name: String,
+    pub dart_name: String,
+    pub receiver: String,
+    pub receiver_mut: bool,
+    pub args: Vec<(String, String)>,
+    pub output: String,
+    pub is_async: bool,
+    pub has_default: bool,
+    pub positional: bool,
+}
+
+pub(crate) fn parse_methods(
+    hir_trait: &HirFlatTrait,
+    functions: &[HirFlatFunction],
+) -> anyhow::Result<Vec<MethodInfo>> {
+    (functions.iter())
+        .filter(|f| {
+            matches!(&f.owner, HirFlatFunctionOwner::TraitDef { trait_def_name } if trait_def_name == &hir_trait.name)
+        })
+        .map(|f| parse_method(hir_trait, f))
+        .collect()
+}
+
+// the function signature is not covered while the whole body is covered - looks like a bug in coverage tool
+// frb-coverage:ignore-start
+fn parse_method(hir_trait: &HirFlatTrait, func: &HirFlatFunction) -> anyhow::Result<MethodInfo> {
+    // frb-coverage:ignore-end
+    let sig = func.item_fn.sig();
+    let name = sig.ident.to_string();
+    let debug_name = format!("{}::{name}", hir_trait.name.name);
+
+    let Some(receiver) = sig.receiver().filter(|x| x.reference.is_some()) else {
+        bail!("Method `{debug_name}` of a `#[frb(dart_implementable)]` trait should take `&self` or `&mut self`")
+    };
+    ensure!(
+        sig.generics.params.is_empty(),
+        "Method `{debug_name}` of a `#[frb(dart_implementable)]` trait should not be generic"
+    );
+
+    let args = (sig.inputs.iter())
+        .filter_map(|x| if_then_some!(let FnArg::Typed(pat_type) = x, pat_type))
+        .map(|pat_type| match &*pat_type.pat {
+            Pat::Ident(pat_ident) => Ok((pat_ident.ident.to_string(), ty_to_string(&pat_type.ty))),
+            // This will stop the whole generator and tell the users, so we do not care about testing it
+            // frb-coverage:ignore-start
+            _ => bail!("Arguments of `{debug_name}` should be simple identifiers"),
+            // frb-coverage:ignore-end
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    let (output, is_async) = match &sig.output {
+        ReturnType::Default => ("()".to_owned(), false),
+        ReturnType::Type(_, ty) => match extract_dart_fn_future_inner(ty) {
+            Some(inner) => (ty_to_string(inner), true),
+            None => (ty_to_string(ty), false),
+        },
+    };
+
+    let attributes = FrbAttributes::parse(func.item_fn.attrs())?;
+    let dart_name = match attributes.name() {
+        Some(dart_name) => dart_name.to_case(Case::Camel),
+        None => MirIdent::new(name.clone(), None).dart_style(),
+    };
+
+    Ok(MethodInfo {
+        name,
+        dart_name,
+        receiver: ty_to_string(receiver),
+        receiver_mut: receiver.mutability.is_some(),
+        args,
+        output,
+        is_async,
+        has_default: matches!(&func.item_fn, GeneralizedItemFn::TraitItemFn(inner) if inner.default.is_some()),
+        positional: attributes.positional(),
+    })
+}
+
+fn generate_dart_impl(hir_trait: &HirFlatTrait, methods: &[MethodInfo]) -> String {
+    let trait_def_name = &hir_trait.name.name;
+    let struct_name = compute_struct_name(trait_def_name);
+
+    let dart_fn_type = |method: &MethodInfo| {
+        format!(
+            "Fn({}) -> flutter_rust_bridge::DartFnFuture<{}> + Send + Sync",
+            method.args.iter().map(|(_, ty)| ty).join(", "),
+            method.output,
+        )
+    };
+
+    let fields = (methods.iter())
+        .map(|method| format!("{}: Box<dyn {}>,\n", method.name, dart_fn_type(method)))
+        .join("");
+
+    let constructor_params = (methods.iter())
+        .map(|method| {
+            format!(
+                "{}: impl {} + 'static,\n",
+                method.name,
+                dart_fn_type(method)
+            )
+        })
+        .join("");
+    let constructor_fields = (methods.iter())
+        .map(|method| format!("{name}: Box::new({name}),\n", name = method.name))
+        .join("");
+
+    let trait_methods = (methods.iter())
+        .map(|method| {
+            let params = (method.args.iter())
+                .map(|(name, ty)| format!(", {name}: {ty}"))
+                .join("");
+            let call = format!(
+                "(self.{})({})",
+                method.name,
+                method.args.iter().map(|(name, _)| name).join(", ")
+            );
+            let (output, body) = if method.is_async {
+                (
+                    format!("flutter_rust_bridge::DartFnFuture<{}>", method.output),
+                    call,
+                )
+            } else {
+                (
+                    method.output.clone(),
+                    format!("flutter_rust_bridge::for_generated::dart_fn_block_on({call})"),
+                )
+            };
+            format!(
+                "fn {name}({receiver}{params}) -> {output} {{
+                    {body}
+                }}
+                ",
+                name = method.name,
+                receiver = method.receiver,
+            )
+        })
+        .join("\n");
+
+    let dart_code = generate_dart_factory(trait_def_name, &struct_name, methods);
+
+    format!(
+        r###"
+#[flutter_rust_bridge::frb(opaque)]
+#[flutter_rust_bridge::frb(dart_code = r#"
+{dart_code}
+"#)]
+pub struct {struct_name} {{
+    {fields}
+}}
+
+impl {struct_name} {{
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn new(
+        {constructor_params}
+    ) -> Self {{
+        Self {{
+            {constructor_fields}
+        }}
+    }}
+}}
+
+impl {trait_def_name} for {struct_name} {{
+    {trait_methods}
+}}
+        "###
+    )
+}
+
+fn generate_dart_factory(
+    trait_def_name: &str,
+    struct_name: &str,
+    methods: &[MethodInfo],
+) -> String {
+    let fields = (methods.iter())
+        .map(|method| {
+            let params = (method.args.iter())
+                .map(|(name, _)| MirIdent::new(name.clone(), None).dart_style())
+                .collect_vec();
+            let args = if method.positional {
+                params.iter().join(", ")
+            } else {
+                params.iter().map(|x| format!("{x}: {x}")).join(", ")
+            };
+            format!(
+                "{field}: ({params}) => implementation.{method_name}({args}),\n",
+                field = MirIdent::new(method.name.clone(), None).dart_style(),
+                params = params.join(", "),
+                method_name = method.dart_name,
+            )
+        })
+        .join("");
+
+    format!(
+        "
+    factory {struct_name}.from({trait_def_name} implementation) => {struct_name}(
+        {fields}
+    );
+"
+    )
+}
@@ -1,15 +1,18 @@
 use crate::codegen::ir::early_generator::pack::IrEarlyGeneratorPack;
 use crate::codegen::ir::early_generator::trait_def_info::IrEarlyGeneratorTraitDefInfo;
+use crate::codegen::ir::hir::flat::function::HirFlatFunction;
 use crate::codegen::ir::hir::flat::traits::HirFlatTrait;
 use crate::codegen::ir::mir::pack::MirPack;
 use crate::codegen::ir::mir::trait_impl::MirTraitImpl;
 use crate::codegen::ir::mir::ty::delegate::{MirTypeDelegate, MirTypeDelegateDynTraitVariant};
 use crate::codegen::ir::mir::ty::MirType;
+use crate::codegen::parser::early_generator::trait_dart_impl;
 use crate::codegen::parser::early_generator::utils::lockable;
 use crate::codegen::parser::hir::flat::extra_code_injector::{
     inject_extra_codes, InjectExtraCodeBlock,
 };
 use crate::codegen::parser::mir::internal_config::ParserMirInternalConfig;
+use crate::codegen::parser::mir::parser::attribute::FrbAttributes;
 use crate::if_then_some;
 use crate::library::codegen::ir::mir::ty::MirTypeTrait;
 use crate::utils::namespace::Namespace;
@@ -25,15 +28,28 @@ pub(crate) fn generate(
 
     let dyn_trait_types = (distinct_types.iter())
         .filter_map(|ty| if_then_some!(let MirType::Delegate(MirTypeDelegate::DynTrait(inner)) = ty, inner.clone()));
-    let interest_trait_names = dyn_trait_types
+    let mut interest_trait_names = dyn_trait_types
         .map(|ty| ty.trait_def_name.clone())
-        .unique()
         .collect_vec();
+    // Also generate for traits explicitly asking for it, even if not used as `dyn Trait`
+    for hir_trait in &pack.hir_flat_pack.traits {
+        if FrbAttributes::parse(&hir_trait.attrs)?.generate_implementor_enum() {
+            interest_trait_names.push(hir_trait.name.clone());
+        }
+    }
+    let interest_trait_names = interest_trait_names.into_iter().unique().collect_vec();
 
     let generated_items = (pack.hir_flat_pack.traits.iter())
         .filter(|x| interest_trait_names.contains(&x.name))
         .sorted_by_key(|x| x.name.clone())
-        .map(|x| generate_trait_impl_enum(x, &tentative_mir_pack.trait_impls, output_namespace))
+        .map(|x| {
+            generate_trait_impl_enum(
+                x,
+                &pack.hir_flat_pack.functions,
+                &tentative_mir_pack.trait_impls,
+                output_namespace,
+            )
+        })
         .collect::<anyhow::Result<Vec<_>>>()?;
 
     let extra_codes = (generated_items.iter())
@@ -50,6 +66,7 @@ pub(crate) fn generate(
 
 fn generate_trait_impl_enum(
     hir_trait: &HirFlatTrait,
+    functions: &[HirFlatFunction],
     all_trait_impls: &[MirTraitImpl],
     output_namespace: &Namespace,
 ) -> anyhow::Result<(Vec<InjectExtraCodeBlock>, IrEarlyGeneratorTraitDefInfo)> {
@@ -72,20 +89,143 @@ fn generate_trait_impl_enum(
         })
         .collect_vec();
 
-    let extra_codes = lockable::generate(
+    let mut extra_codes = lockable::generate(
         &enum_name,
         &format!("dyn {trait_def_name}"),
         true,
         &variants,
     )?;
 
+    // Only traits implementable in Dart can be passed as `Box<dyn Trait>`
+    if FrbAttributes::parse(&hir_trait.attrs)?.dart_implementable() {
+        let methods = trait_dart_impl::parse_methods(hir_trait, functions)?;
+        extra_codes.push(InjectExtraCodeBlock {
+            code: format!(
+                "{}\n{}",
+                generate_forward_impl(&enum_name, trait_def_name, &methods, &variants),
+                generate_into_box_dyn(&enum_name, trait_def_name, &variants),
+            ),
+            should_parse: false,
+        });
+    }
+
+    let dart_impl_name = trait_dart_impl::compute_struct_name(trait_def_name);
+    let dart_impl_variant = (variants.iter()).position(|x| x.ty_name == dart_impl_name);
+
     let info = IrEarlyGeneratorTraitDefInfo {
         trait_def_name: trait_def_namespaced_name.clone(),
         delegate_namespace: output_namespace.clone(),
         variants: (interest_trait_impls.into_iter())
             .map(|ty| MirTypeDelegateDynTraitVariant { ty })
             .collect_vec(),
+        dart_impl_variant,
     };
 
     Ok((extra_codes, info))
 }
+
+// Used when the object is still referenced elsewhere (e.g. by Dart), thus cannot be moved out,
+// so every call borrows it instead, in the same way as the arguments of normal functions
+fn generate_forward_impl(
+    enum_name: &str,
+    trait_def_name: &str,
+    methods: &[trait_dart_impl::MethodInfo],
+    variants: &[lockable::VariantInfo],
+) -> String {
+    let trait_methods = (methods.iter())
+        .map(|method| {
+            let params = (method.args.iter())
+                .map(|(name, ty)| format!(", {name}: {ty}"))
+                .join("");
+            let args = (method.args.iter())
+                .map(|(name, _)| format!(", {name}"))
+                .join("");
+            let (guard, ref_mut, decode_suffix) = if method.receiver_mut {
+                ("mut guard", "&mut ", "_mut")
+            } else {
+                ("guard", "&", "")
+            };
+            if method.is_async {
+                // Borrow asynchronously, thus waiting for the lock does not block the executor
+                format!(
+                    "fn {name}({receiver}{params}) -> flutter_rust_bridge::DartFnFuture<{output}> {{
+                        let object = self.clone();
+                        Box::pin(async move {{
+                            let {guard} = object.lockable_decode_async_ref{decode_suffix}().await;
+                            {trait_def_name}::{name}({ref_mut}*guard{args}).await
+                        }})
+                    }}
+                    ",
+                    name = method.name,
+                    receiver = method.receiver,
+                    output = method.output,
+                )
+            } else {
+                format!(
+                    "fn {name}({receiver}{params}) -> {output} {{
+                        {trait_def_name}::{name}({ref_mut}*self.lockable_decode_sync_ref{decode_suffix}(){args})
+                    }}
+                    ",
+                    name = method.name,
+                    receiver = method.receiver,
+                    output = method.output,
+                )
+            }
+        })
+        .join("\n");
+
+    let clone_arms = (variants.iter())
+        .map(|variant| {
+            format!(
+                "Self::{name}(inner) => Self::{name}(inner.clone()),\n",
+                name = variant.enum_variant_name
+            )
+        })
+        .join("");
+
+    format!(
+        "
+        impl {trait_def_name} for {enum_name} {{
+            {trait_methods}
+        }}
+
+        impl Clone for {enum_name} {{
+            fn clone(&self) -> Self {{
+                match self {{
+                    {clone_arms}
+                }}
+            }}
+        }}
+        "
+    )
+}
+
+fn generate_into_box_dyn(
+    enum_name: &str,
+    trait_def_name: &str,
+    variants: &[lockable::VariantInfo],
+) -> String {
+    let arms = (variants.iter())
+        .map(|variant| {
+            format!(
+                "{enum_name}::{name}(inner) => match flutter_rust_bridge::for_generated::rust_auto_opaque_try_into_inner(inner) {{
+                    Ok(inner) => Box::new(inner),
+                    Err(inner) => Box::new({enum_name}::{name}(inner)),
+                }},\n",
+                name = variant.enum_variant_name
+            )
+        })
+        .join("");
+
+    format!(
+        "
+        impl From<{enum_name}> for Box<dyn {trait_def_name}> {{
+            fn from(value: {enum_name}) -> Self {{
+                match value {{
+                    {arms}
+                }}
+            }}
+        }}
+        "
+    )
+}
@@ -100,6 +100,9 @@ fn generate_code_lockable_impl(
     let lockable_order_body = generate_match_raw(variants, |_variant| {
         "flutter_rust_bridge::for_generated::rust_auto_opaque_lockable_order(inner)".to_string()
     });
+    let lockable_exclusive_body = generate_match_raw(variants, |_variant| {
+        "flutter_rust_bridge::for_generated::rust_auto_opaque_lockable_exclusive(inner)".to_string()
+    });
 
     format!(
         "
@@ -129,6 +132,10 @@ fn generate_code_lockable_impl(
                 {lockable_order_body}
             }}
 
+            fn lockable_exclusive(&self) -> bool {{
+                {lockable_exclusive_body}
+            }}
+
             fn lockable_decode_sync_ref(&self) -> Self::RwLockReadGuard<'_> {{
                 self.blocking_read()
             }}
@@ -177,7 +184,7 @@ fn generate_code_read_write_guard(
     let enum_name = format!("{enum_name}RwLock{rw_pascal}Guard");
     let enum_def = generate_enum_raw(variants, &format!("{enum_name}<'a>"), |variant| {
         format!(
-            "flutter_rust_bridge::for_generated::rust_async::RwLock{rw_pascal}Guard<'a, {}>",
+            "flutter_rust_bridge::for_generated::RustAutoOpaque{rw_pascal}Guard<'a, {}>",
             variant.ty_name
         )
     });
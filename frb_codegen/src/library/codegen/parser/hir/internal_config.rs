@@ -8,4 +8,6 @@ pub(crate) struct ParserHirInternalConfig {
     pub rust_input_namespace_pack: RustInputNamespacePack,
     pub rust_crate_dir: PathBuf,
     pub third_party_crate_names: Vec<CrateName>,
+    pub cargo_expand: bool,
+    pub rust_features: Option<Vec<String>>,
 }
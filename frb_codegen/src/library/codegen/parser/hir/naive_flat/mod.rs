@@ -20,5 +20,8 @@ pub(crate) fn parse(
     let pack = transformer::filter_transformer::transform(pack, config)?;
     dumper.dump("3_filter_transformer.json", &pack)?;
 
+    let pack = transformer::resolve_import_transformer::transform(pack)?;
+    dumper.dump("4_resolve_import_transformer.json", &pack)?;
+
     Ok(pack)
 }
@@ -1,2 +1,3 @@
 pub(crate) mod filter_transformer;
 pub(crate) mod move_third_party_override_transformer;
+pub(crate) mod resolve_import_transformer;
@@ -0,0 +1,142 @@
+use crate::codegen::ir::hir::naive_flat::pack::HirNaiveFlatPack;
+use crate::utils::namespace::Namespace;
+use std::collections::HashMap;
+use syn::visit_mut::VisitMut;
+use syn::UseTree;
+
+/// Types that are recognized by their full path, since e.g. `Duration` can be
+/// `std::time::Duration`, `chrono::Duration` or `time::Duration`.
+const PATH_DEPENDENT_TYPE_NAMES: [&str; 2] = ["Duration", "Date"];
+
+/// Replaces e.g. `Duration` with `std::time::Duration` when the module has `use std::time::Duration;`,
+/// so that the type can be recognized later.
+pub(crate) fn transform(mut pack: HirNaiveFlatPack) -> anyhow::Result<HirNaiveFlatPack> {
+    let mut imports_of_namespace: HashMap<Namespace, HashMap<String, syn::Path>> = HashMap::new();
+    for item in pack.items.iter() {
+        if let syn::Item::Use(item_use) = &item.item {
+            let imports = (imports_of_namespace.entry(item.meta.namespace.clone())).or_default();
+            collect_imports(&item_use.tree, &mut vec![], imports);
+        }
+    }
+
+    for item in pack.items.iter_mut() {
+        if let Some(imports) = imports_of_namespace.get(&item.meta.namespace) {
+            Visitor { imports }.visit_item_mut(&mut item.item);
+        }
+    }
+
+    Ok(pack)
+}
+
+fn collect_imports(
+    tree: &UseTree,
+    prefix: &mut Vec<syn::Ident>,
+    imports: &mut HashMap<String, syn::Path>,
+) {
+    match tree {
+        UseTree::Path(inner) => {
+            prefix.push(inner.ident.clone());
+            collect_imports(&inner.tree, prefix, imports);
+            prefix.pop();
+        }
+        UseTree::Name(inner) => add_import(prefix, &inner.ident, &inner.ident, imports),
+        UseTree::Rename(inner) => add_import(prefix, &inner.ident, &inner.rename, imports),
+        UseTree::Group(inner) => {
+            for tree in inner.items.iter() {
+                collect_imports(tree, prefix, imports);
+            }
+        }
+        // The names are unknown without looking into the other crates
+        UseTree::Glob(_) => {}
+    }
+}
+
+fn add_import(
+    prefix: &[syn::Ident],
+    ident: &syn::Ident,
+    local_name: &syn::Ident,
+    imports: &mut HashMap<String, syn::Path>,
+) {
+    // Only the types from other crates are recognized by their path
+    let is_from_other_crate = (prefix.first())
+        .is_some_and(|first| !["crate", "self", "super"].contains(&&*first.to_string()));
+    if is_from_other_crate && PATH_DEPENDENT_TYPE_NAMES.contains(&&*ident.to_string()) {
+        let segments = prefix.iter().chain([ident]);
+        imports.insert(local_name.to_string(), syn::parse_quote!(#(#segments)::*));
+    }
+}
+
+struct Visitor<'a> {
+    imports: &'a HashMap<String, syn::Path>,
+}
+
+impl VisitMut for Visitor<'_> {
+    fn visit_type_path_mut(&mut self, node: &mut syn::TypePath) {
+        if node.qself.is_none()
+            && node.path.leading_colon.is_none()
+            && node.path.segments.len() == 1
+        {
+            let segment = &node.path.segments[0];
+            if let Some(path) = self.imports.get(&segment.ident.to_string()) {
+                let mut path = path.clone();
+                path.segments.last_mut().unwrap().arguments = segment.arguments.clone();
+                node.path = path;
+            }
+        }
+        syn::visit_mut::visit_type_path_mut(self, node);
+    }
+
+    // Function bodies are not used
+    fn visit_block_mut(&mut self, _node: &mut syn::Block) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codegen::ir::hir::naive_flat::item::{HirNaiveFlatItem, HirNaiveFlatItemMeta};
+    use itertools::Itertools;
+    use quote::ToTokens;
+
+    #[test]
+    fn test_transform() -> anyhow::Result<()> {
+        let items = [
+            ("crate::a", "use std::time::Duration;"),
+            ("crate::a", "use time::{Date, Duration as TimeDuration};"),
+            ("crate::a", "use crate::b::Date as OtherDate;"),
+            (
+                "crate::a",
+                "pub fn f(a: Duration, b: Vec<TimeDuration>, c: Date, d: OtherDate) {}",
+            ),
+            (
+                "crate::a",
+                "pub struct S { a: Option<Duration>, b: chrono::Duration }",
+            ),
+            ("crate::b", "pub fn g(a: Duration) {}"),
+        ];
+        let pack = HirNaiveFlatPack {
+            items: (items.into_iter())
+                .map(|(namespace, code)| HirNaiveFlatItem {
+                    meta: HirNaiveFlatItemMeta {
+                        namespace: Namespace::new_raw(namespace.to_owned()),
+                        sources: vec![],
+                        is_module_public: true,
+                    },
+                    item: syn::parse_str(code).unwrap(),
+                })
+                .collect_vec(),
+        };
+
+        let actual = (transform(pack)?.items.iter())
+            .map(|item| item.item.to_token_stream().to_string())
+            .collect_vec();
+        assert_eq!(
+            actual[3..],
+            [
+                "pub fn f (a : std :: time :: Duration , b : Vec < time :: Duration > , c : time :: Date , d : OtherDate) { }",
+                "pub struct S { a : Option < std :: time :: Duration > , b : chrono :: Duration }",
+                "pub fn g (a : Duration) { }",
+            ]
+        );
+        Ok(())
+    }
+}
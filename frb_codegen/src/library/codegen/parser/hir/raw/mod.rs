@@ -1,14 +1,16 @@
+use crate::codegen::cache::Cache;
 use crate::codegen::dumper::Dumper;
 use crate::codegen::ir::hir::raw::crates::HirRawCrate;
 use crate::codegen::ir::hir::raw::pack::HirRawPack;
 use crate::codegen::parser::hir::internal_config::ParserHirInternalConfig;
-use crate::library::commands::cargo_expand::run_cargo_expand;
+use crate::library::commands::cargo_expand::{read_module_tree, run_cargo_expand};
 use crate::utils::crate_name::CrateName;
 use itertools::concat;
 
 pub(crate) fn parse(
     config: &ParserHirInternalConfig,
     dumper: &Dumper,
+    cache: &Cache,
 ) -> anyhow::Result<HirRawPack> {
     let crates = concat([
         vec![CrateName::self_crate()],
@@ -18,11 +20,7 @@ pub(crate) fn parse(
     .map(|crate_name| {
         Ok(HirRawCrate {
             name: crate_name.to_owned(),
-            syn_file: run_cargo_expand(
-                &config.rust_crate_dir,
-                (!crate_name.is_self_crate()).then_some(crate_name),
-                dumper,
-            )?,
+            syn_file: read_crate_source(config, crate_name, dumper, cache)?,
         })
     })
     .collect::<anyhow::Result<Vec<_>>>()?
@@ -30,3 +28,28 @@ pub(crate) fn parse(
     .collect();
     Ok(HirRawPack { crates })
 }
+
+fn read_crate_source(
+    config: &ParserHirInternalConfig,
+    crate_name: &CrateName,
+    dumper: &Dumper,
+    cache: &Cache,
+) -> anyhow::Result<syn::File> {
+    // Third party crates are not inside the current crate, thus always expanded
+    if !config.cargo_expand && crate_name.is_self_crate() {
+        if let Some(file) = read_module_tree(
+            &config.rust_crate_dir,
+            config.rust_features.as_deref(),
+            dumper,
+        )? {
+            return Ok(file);
+        }
+    }
+
+    run_cargo_expand(
+        &config.rust_crate_dir,
+        (!crate_name.is_self_crate()).then_some(crate_name),
+        dumper,
+        cache,
+    )
+}
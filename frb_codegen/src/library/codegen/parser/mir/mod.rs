@@ -1,3 +1,4 @@
+use crate::codegen::cache::Cache;
 use crate::codegen::dumper::Dumper;
 use crate::codegen::ir::early_generator::pack::IrEarlyGeneratorPack;
 use crate::codegen::ir::mir::pack::MirPack;
@@ -13,8 +14,9 @@ pub(crate) fn parse(
     ir_pack: &IrEarlyGeneratorPack,
     dumper: &Dumper,
     parse_mode: ParseMode,
+    cache: &Cache,
 ) -> anyhow::Result<MirPack> {
-    let pack = parser::parse(config, ir_pack, parse_mode)?;
+    let pack = parser::parse(config, ir_pack, parse_mode, cache)?;
     dumper.dump("1_parse_pack.json", &pack)?;
 
     let pack = transformer::filter_trait_impl_transformer::transform(pack)?;
@@ -3,6 +3,7 @@ use crate::codegen::ir::mir::annotation::MirDartAnnotation;
 use crate::codegen::ir::mir::default::MirDefaultValue;
 use crate::codegen::ir::mir::func::MirFuncAccessorMode;
 use crate::codegen::ir::mir::import::MirDartImport;
+use crate::codegen::ir::mir::ty::rust_auto_opaque_implicit::MirRustAutoOpaqueLockStrategy;
 use crate::codegen::ir::mir::ty::rust_opaque::RustOpaqueCodecMode;
 use crate::if_then_some;
 use anyhow::Context;
@@ -80,6 +81,37 @@ impl FrbAttributes {
         self.any_eq(&FrbAttribute::StreamDartAwait)
     }
 
+    pub(crate) fn stream_capacity(&self) -> Option<usize> {
+        (self.0.iter())
+            .filter_map(
+                |item| if_then_some!(let FrbAttribute::StreamCapacity(inner) = item, inner.0),
+            )
+            .next()
+    }
+
+    pub(crate) fn executor(&self) -> Option<String> {
+        (self.0.iter())
+            .filter_map(
+                |item| if_then_some!(let FrbAttribute::Executor(inner) = item, inner.0.clone()),
+            )
+            .next()
+    }
+
+    pub(crate) fn lock(&self) -> Option<MirRustAutoOpaqueLockStrategy> {
+        (self.0.iter())
+            .filter_map(|item| if_then_some!(let FrbAttribute::Lock(inner) = item, inner.0))
+            .next()
+    }
+
+    pub(crate) fn instantiate(&self) -> Vec<Type> {
+        (self.0.iter())
+            .filter_map(
+                |item| if_then_some!(let FrbAttribute::Instantiate(inner) = item, inner.0.clone()),
+            )
+            .flatten()
+            .collect()
+    }
+
     pub(crate) fn accessor(&self) -> Option<MirFuncAccessorMode> {
         if self.any_eq(&FrbAttribute::Getter) {
             Some(MirFuncAccessorMode::Getter)
@@ -135,9 +167,9 @@ impl FrbAttributes {
         self.any_eq(&FrbAttribute::Type64bitInt)
     }
 
-    // pub(crate) fn generate_implementor_enum(&self) -> bool {
-    //     self.any_eq(&FrbAttribute::GenerateImplEnum)
-    // }
+    pub(crate) fn generate_implementor_enum(&self) -> bool {
+        self.any_eq(&FrbAttribute::GenerateImplEnum)
+    }
 
     pub(crate) fn rust_opaque_codec(&self) -> Option<RustOpaqueCodecMode> {
         if self.any_eq(&FrbAttribute::RustOpaqueCodecMoi) {
@@ -148,7 +180,12 @@ impl FrbAttributes {
     }
 
     pub(crate) fn codec_mode_pack(&self) -> Option<CodecModePack> {
-        if self.any_eq(&FrbAttribute::Serialize) {
+        if self.any_eq(&FrbAttribute::CompactSerialize) {
+            Some(CodecModePack {
+                dart2rust: CodecMode::Cse,
+                rust2dart: CodecMode::Cse,
+            })
+        } else if self.any_eq(&FrbAttribute::Serialize) {
             Some(CodecModePack {
                 dart2rust: CodecMode::Sse,
                 rust2dart: CodecMode::Sse,
@@ -219,6 +256,14 @@ impl FrbAttributes {
             .next()
     }
 
+    pub(crate) fn dart_implementable(&self) -> bool {
+        self.any_eq(&FrbAttribute::DartImplementable)
+    }
+
+    pub(crate) fn typed_exceptions(&self) -> bool {
+        self.any_eq(&FrbAttribute::TypedExceptions)
+    }
+
     pub(crate) fn ui_state(&self) -> bool {
         self.any_eq(&FrbAttribute::UiState)
     }
@@ -266,9 +311,13 @@ mod frb_keyword {
     syn::custom_keyword!(sync);
     syn::custom_keyword!(dart_async);
     syn::custom_keyword!(stream_dart_await);
+    syn::custom_keyword!(stream_capacity);
+    syn::custom_keyword!(executor);
     syn::custom_keyword!(getter);
     syn::custom_keyword!(setter);
     syn::custom_keyword!(init);
+    syn::custom_keyword!(instantiate);
+    syn::custom_keyword!(lock);
     syn::custom_keyword!(ignore);
     syn::custom_keyword!(opaque);
     syn::custom_keyword!(non_opaque);
@@ -282,14 +331,17 @@ mod frb_keyword {
     syn::custom_keyword!(rust_opaque_codec_moi);
     syn::custom_keyword!(serialize);
     syn::custom_keyword!(semi_serialize);
+    syn::custom_keyword!(compact_serialize);
     syn::custom_keyword!(dart_metadata);
     syn::custom_keyword!(import);
     syn::custom_keyword!(default);
     syn::custom_keyword!(dart_code);
+    syn::custom_keyword!(dart_implementable);
     syn::custom_keyword!(name);
     syn::custom_keyword!(rust2dart);
     syn::custom_keyword!(dart2rust);
     syn::custom_keyword!(dart_type);
+    syn::custom_keyword!(typed_exceptions);
     syn::custom_keyword!(ui_state);
     syn::custom_keyword!(ui_mutation);
 }
@@ -311,13 +363,19 @@ impl Parse for FrbAttributesInner {
 // Alphabetical order
 #[derive(Eq, PartialEq, Debug, Clone)]
 enum FrbAttribute {
+    CompactSerialize,
     Dart2Rust(FrbAttributeSerDes),
     DartCode(FrbAttributeDartCode),
+    DartImplementable,
     Default(FrbAttributeDefaultValue),
+    Executor(FrbAttributeExecutor),
     External,
+    GenerateImplEnum,
     Getter,
     Ignore,
     Init,
+    Instantiate(FrbAttributeInstantiate),
+    Lock(FrbAttributeLock),
     Mirror(FrbAttributeMirror),
     Name(FrbAttributeName),
     NonEq,
@@ -330,10 +388,12 @@ enum FrbAttribute {
     Rust2Dart(FrbAttributeSerDes),
     Setter,
     Serialize,
+    StreamCapacity(FrbAttributeStreamCapacity),
     StreamDartAwait,
     Sync,
     DartAsync,
     Type64bitInt,
+    TypedExceptions,
 
     // === Mainly undocumented since may subject to change ===
 
@@ -379,14 +439,14 @@ impl Parse for FrbAttribute {
             .or_else(|| {
                 parse_keyword::<type_64bit_int, _>(input, &lookahead, type_64bit_int, Type64bitInt)
             })
-            // .or_else(|| {
-            //     parse_keyword::<generate_implementor_enum, _>(
-            //         input,
-            //         &lookahead,
-            //         generate_implementor_enum,
-            //         GenerateImplEnum,
-            //     )
-            // })
+            .or_else(|| {
+                parse_keyword::<generate_implementor_enum, _>(
+                    input,
+                    &lookahead,
+                    generate_implementor_enum,
+                    GenerateImplEnum,
+                )
+            })
             .or_else(|| {
                 parse_keyword::<rust_opaque_codec_moi, _>(
                     input,
@@ -399,6 +459,30 @@ impl Parse for FrbAttribute {
             .or_else(|| {
                 parse_keyword::<semi_serialize, _>(input, &lookahead, semi_serialize, SemiSerialize)
             })
+            .or_else(|| {
+                parse_keyword::<compact_serialize, _>(
+                    input,
+                    &lookahead,
+                    compact_serialize,
+                    CompactSerialize,
+                )
+            })
+            .or_else(|| {
+                parse_keyword::<dart_implementable, _>(
+                    input,
+                    &lookahead,
+                    dart_implementable,
+                    DartImplementable,
+                )
+            })
+            .or_else(|| {
+                parse_keyword::<typed_exceptions, _>(
+                    input,
+                    &lookahead,
+                    typed_exceptions,
+                    TypedExceptions,
+                )
+            })
             .or_else(|| parse_keyword::<ui_state, _>(input, &lookahead, ui_state, UiState))
             .or_else(|| {
                 parse_keyword::<ui_mutation, _>(input, &lookahead, ui_mutation, UiMutation)
@@ -424,6 +508,22 @@ impl Parse for FrbAttribute {
             input.parse::<name>()?;
             input.parse::<Token![=]>()?;
             input.parse().map(Name)?
+        } else if lookahead.peek(executor) {
+            input.parse::<executor>()?;
+            input.parse::<Token![=]>()?;
+            input.parse().map(Executor)?
+        } else if lookahead.peek(instantiate) {
+            input.parse::<instantiate>()?;
+            input.parse::<Token![=]>()?;
+            input.parse().map(Instantiate)?
+        } else if lookahead.peek(lock) {
+            input.parse::<lock>()?;
+            input.parse::<Token![=]>()?;
+            input.parse().map(Lock)?
+        } else if lookahead.peek(stream_capacity) {
+            input.parse::<stream_capacity>()?;
+            input.parse::<Token![=]>()?;
+            input.parse().map(StreamCapacity)?
         } else if lookahead.peek(frb_keyword::dart2rust) {
             input.parse::<frb_keyword::dart2rust>()?;
             input.parse().map(Dart2Rust)?
@@ -660,6 +760,68 @@ impl Parse for FrbAttributeName {
     }
 }
 
+#[derive(Clone, Serialize, Eq, PartialEq, Debug)]
+struct FrbAttributeExecutor(String);
+
+impl Parse for FrbAttributeExecutor {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let lit = input.parse::<syn::LitStr>()?;
+        if lit.value().is_empty() {
+            return Err(Error::new(lit.span(), "executor should not be empty"));
+        }
+        Ok(Self(lit.value()))
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+struct FrbAttributeLock(MirRustAutoOpaqueLockStrategy);
+
+impl Parse for FrbAttributeLock {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let lit = input.parse::<syn::LitStr>()?;
+        Ok(Self(match lit.value().as_str() {
+            "rwlock" => MirRustAutoOpaqueLockStrategy::RwLock,
+            "mutex" => MirRustAutoOpaqueLockStrategy::Mutex,
+            "parking_lot" => MirRustAutoOpaqueLockStrategy::ParkingLot,
+            "none" => MirRustAutoOpaqueLockStrategy::None,
+            _ => {
+                return Err(Error::new(
+                    lit.span(),
+                    r#"lock should be one of "rwlock", "mutex", "parking_lot" and "none""#,
+                ))
+            }
+        }))
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+struct FrbAttributeInstantiate(Vec<Type>);
+
+impl Parse for FrbAttributeInstantiate {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let lit = input.parse::<syn::LitStr>()?;
+        let types = lit.parse_with(Punctuated::<Type, Token![,]>::parse_terminated)?;
+        if types.is_empty() {
+            return Err(Error::new(lit.span(), "instantiate should not be empty"));
+        }
+        Ok(Self(types.into_iter().collect()))
+    }
+}
+
+#[derive(Clone, Serialize, Eq, PartialEq, Debug)]
+struct FrbAttributeStreamCapacity(usize);
+
+impl Parse for FrbAttributeStreamCapacity {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let lit = input.parse::<syn::LitInt>()?;
+        let value = lit.base10_parse::<usize>()?;
+        if value == 0 {
+            return Err(Error::new(lit.span(), "stream_capacity should be positive"));
+        }
+        Ok(Self(value))
+    }
+}
+
 #[derive(Clone, Serialize, Eq, PartialEq, Debug)]
 pub(crate) struct FrbAttributeSerDes {
     pub dart_type: String,
@@ -691,9 +853,11 @@ impl Parse for FrbAttributeSerDes {
 #[cfg(test)]
 mod tests {
     use crate::codegen::ir::mir::default::MirDefaultValue;
+    use crate::codegen::ir::mir::ty::rust_auto_opaque_implicit::MirRustAutoOpaqueLockStrategy;
     use crate::codegen::parser::mir::parser::attribute::{
-        FrbAttribute, FrbAttributeDartCode, FrbAttributeDefaultValue, FrbAttributeMirror,
-        FrbAttributeName, FrbAttributeSerDes, FrbAttributes, NamedOption,
+        FrbAttribute, FrbAttributeDartCode, FrbAttributeDefaultValue, FrbAttributeExecutor,
+        FrbAttributeLock, FrbAttributeMirror, FrbAttributeName, FrbAttributeSerDes,
+        FrbAttributeStreamCapacity, FrbAttributes, NamedOption,
     };
     use crate::if_then_some;
     use quote::quote;
@@ -849,10 +1013,15 @@ mod tests {
         simple_keyword_tester("type_64bit_int", FrbAttribute::Type64bitInt);
     }
 
-    // #[test]
-    // fn test_generate_implementor_enum() {
-    //     simple_keyword_tester("generate_implementor_enum", FrbAttribute::GenerateImplEnum);
-    // }
+    #[test]
+    fn test_generate_implementor_enum() {
+        simple_keyword_tester("generate_implementor_enum", FrbAttribute::GenerateImplEnum);
+    }
+
+    #[test]
+    fn test_compact_serialize() {
+        simple_keyword_tester("compact_serialize", FrbAttribute::CompactSerialize);
+    }
 
     #[test]
     fn test_rust_opaque_codec_moi() {
@@ -883,6 +1052,67 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_executor() -> anyhow::Result<()> {
+        let parsed = parse(r###"#[frb(executor = "io")]"###)?;
+        assert_eq!(
+            parsed,
+            FrbAttributes(vec![FrbAttribute::Executor(FrbAttributeExecutor(
+                "io".to_owned()
+            ))])
+        );
+        assert_eq!(parsed.executor(), Some("io".to_owned()));
+        assert!(parse(r###"#[frb(executor = "")]"###).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_instantiate() -> anyhow::Result<()> {
+        let parsed =
+            parse(r###"#[frb(instantiate = "Page<User>, Page<HashMap<String, i32>>")]"###)?;
+        assert_eq!(
+            (parsed.instantiate().iter())
+                .map(|ty| quote!(#ty).to_string())
+                .collect::<Vec<_>>(),
+            vec!["Page < User >", "Page < HashMap < String , i32 > >"]
+        );
+        assert!(parse(r###"#[frb(instantiate = "")]"###).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_lock() -> anyhow::Result<()> {
+        let parsed = parse(r###"#[frb(opaque, lock = "mutex")]"###)?;
+        assert_eq!(
+            parsed,
+            FrbAttributes(vec![
+                FrbAttribute::Opaque,
+                FrbAttribute::Lock(FrbAttributeLock(MirRustAutoOpaqueLockStrategy::Mutex))
+            ])
+        );
+        assert_eq!(parsed.lock(), Some(MirRustAutoOpaqueLockStrategy::Mutex));
+        assert_eq!(
+            parse(r###"#[frb(lock = "none")]"###)?.lock(),
+            Some(MirRustAutoOpaqueLockStrategy::None)
+        );
+        assert!(parse(r###"#[frb(lock = "spin")]"###).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_stream_capacity() -> anyhow::Result<()> {
+        let parsed = parse(r###"#[frb(stream_capacity = 16)]"###)?;
+        assert_eq!(
+            parsed,
+            FrbAttributes(vec![FrbAttribute::StreamCapacity(
+                FrbAttributeStreamCapacity(16)
+            )])
+        );
+        assert_eq!(parsed.stream_capacity(), Some(16));
+        assert!(parse(r###"#[frb(stream_capacity = 0)]"###).is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_rust2dart() -> anyhow::Result<()> {
         let parsed =
@@ -930,6 +1160,16 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_dart_implementable() {
+        simple_keyword_tester("dart_implementable", FrbAttribute::DartImplementable);
+    }
+
+    #[test]
+    fn test_typed_exceptions() {
+        simple_keyword_tester("typed_exceptions", FrbAttribute::TypedExceptions);
+    }
+
     #[test]
     fn test_ui_state() {
         simple_keyword_tester("ui_state", FrbAttribute::UiState);
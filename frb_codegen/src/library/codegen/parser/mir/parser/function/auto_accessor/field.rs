@@ -87,6 +87,8 @@ pub(super) fn parse_auto_accessor_of_field(
         owner: MirFuncOwnerInfo::Method(owner),
         mode: MirFuncMode::Sync,
         stream_dart_await: false,
+        stream_capacity: None,
+        executor: None,
         rust_async: false,
         initializer: false,
         arg_mode: MirFuncArgMode::Named,
@@ -100,6 +102,7 @@ pub(super) fn parse_auto_accessor_of_field(
         rust_aop_after: (ty_struct.ui_state && accessor_mode == MirFuncAccessorMode::Setter)
             .then(|| UI_MUTATION_FUNCTION_RUST_AOP_AFTER.to_owned()),
         impl_mode: MirFuncImplMode::Normal,
+        instantiation: None,
         src_lineno_pseudo: compute_src_lineno_pseudo(struct_name, field),
     };
 
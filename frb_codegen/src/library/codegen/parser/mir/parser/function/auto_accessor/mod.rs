@@ -81,9 +81,15 @@ fn parse_auto_accessors_of_struct(
             // We do not care about parsing errors here (e.g. some type that we do not support)
             Err(_) => return Ok(vec![]),
         };
-    if !matches!(ty_direct_parse, MirType::RustAutoOpaqueImplicit(_)) {
+    let MirType::RustAutoOpaqueImplicit(ty_direct_parse_inner) = &ty_direct_parse else {
         return Ok(vec![]);
-    }
+    };
+    // Setters need `&mut self`, which is not allowed without a lock
+    let accessor_modes = if ty_direct_parse_inner.lock_strategy.forbid_borrow_mut() {
+        vec![MirFuncAccessorMode::Getter]
+    } else {
+        vec![MirFuncAccessorMode::Getter, MirFuncAccessorMode::Setter]
+    };
     if ty_direct_parse.should_ignore(type_parser) {
         return Ok(vec![]);
     }
@@ -106,8 +112,7 @@ fn parse_auto_accessors_of_struct(
     (ty_struct.fields.iter())
         .filter(|field| field.is_rust_public.unwrap() && !is_ty_opaque_reference_type(&field.ty))
         .flat_map(|field| {
-            [MirFuncAccessorMode::Getter, MirFuncAccessorMode::Setter]
-                .into_iter()
+            (accessor_modes.iter().copied())
                 .map(|accessor_mode| {
                     parse_auto_accessor_of_field(
                         config,
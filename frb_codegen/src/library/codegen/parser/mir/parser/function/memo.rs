@@ -0,0 +1,124 @@
+use crate::codegen::cache::memory::MemoryCache;
+use crate::codegen::cache::{compute_hash, Cache};
+use crate::codegen::ir::early_generator::pack::IrEarlyGeneratorPack;
+use crate::codegen::ir::hir::flat::function::HirFlatFunction;
+use crate::codegen::ir::hir::flat::pack::HirFlatPack;
+use crate::codegen::ir::misc::skip::MirFuncOrSkip;
+use crate::codegen::parser::mir::internal_config::ParserMirInternalConfig;
+use crate::codegen::parser::mir::parser::ty::state::TypeParserState;
+use crate::codegen::parser::mir::parser::ty::TypeParser;
+use crate::codegen::parser::mir::ParseMode;
+use itertools::Itertools;
+use log::debug;
+use quote::ToTokens;
+use std::rc::Rc;
+
+/// Reuses the parsed functions of the namespaces that are unchanged since the last iteration
+/// of watch mode.
+///
+/// Parsing a function may also parse the types it uses, thus the changes to the [`TypeParser`]
+/// are stored together with the functions, and are replayed when the functions are reused.
+/// Since they in turn depend on what was parsed before, each key covers all the namespaces
+/// before it, so a change in one namespace makes the later ones parsed again.
+pub(crate) struct FunctionsMemo<'a> {
+    memory: Option<&'a MemoryCache>,
+    state_key: String,
+}
+
+struct Entry {
+    items: Vec<MirFuncOrSkip>,
+    type_parser_delta: TypeParserState,
+    type_parser_delta_hash: String,
+}
+
+impl<'a> FunctionsMemo<'a> {
+    pub(crate) fn new(
+        cache: &Cache<'a>,
+        config: &ParserMirInternalConfig,
+        ir_pack: &IrEarlyGeneratorPack,
+        type_parser: &TypeParser,
+        parse_mode: ParseMode,
+    ) -> anyhow::Result<Self> {
+        let Some(memory) = cache.memory() else {
+            return Ok(Self {
+                memory: None,
+                state_key: String::new(),
+            });
+        };
+
+        let state_key = compute_hash([
+            serde_json::to_string(config)?,
+            format!("{parse_mode:?}"),
+            serde_json::to_string(&HirFlatPack {
+                functions: vec![],
+                ..ir_pack.hir_flat_pack.clone()
+            })?,
+            serde_json::to_string(&ir_pack.proxied_types)?,
+            serde_json::to_string(&ir_pack.trait_def_infos)?,
+            // Skipped when serializing, but the types are parsed from them
+            (ir_pack.hir_flat_pack.structs.iter())
+                .map(|x| x.src.to_token_stream().to_string())
+                .join("\n"),
+            (ir_pack.hir_flat_pack.enums.iter())
+                .map(|x| x.src.to_token_stream().to_string())
+                .join("\n"),
+            format!("{:?}", type_parser.custom_ser_des_infos),
+            type_parser.state().compute_hash(),
+        ]);
+
+        Ok(Self {
+            memory: Some(memory),
+            state_key,
+        })
+    }
+
+    pub(crate) fn get_or_parse<'b>(
+        &mut self,
+        src_fns: &[HirFlatFunction],
+        type_parser: &mut TypeParser<'b>,
+        parse: impl FnOnce(&mut TypeParser<'b>) -> anyhow::Result<Vec<MirFuncOrSkip>>,
+    ) -> anyhow::Result<Vec<MirFuncOrSkip>> {
+        let Some(memory) = self.memory else {
+            return parse(type_parser);
+        };
+
+        let key = compute_key(&self.state_key, src_fns)?;
+        let entry = if let Some(entry) = memory.get::<Entry>(&key) {
+            debug!("Memory cache hit functions={}", src_fns.len());
+            type_parser.apply_state_delta(&entry.type_parser_delta);
+            entry
+        } else {
+            let before = type_parser.state();
+            let items = parse(type_parser)?;
+            let type_parser_delta = type_parser.state_delta(&before);
+            let entry = Rc::new(Entry {
+                items,
+                type_parser_delta_hash: type_parser_delta.compute_hash(),
+                type_parser_delta,
+            });
+            memory.insert(key, entry.clone());
+            entry
+        };
+
+        self.state_key = compute_hash([&self.state_key, &entry.type_parser_delta_hash]);
+        Ok(entry.items.clone())
+    }
+}
+
+fn compute_key(state_key: &str, src_fns: &[HirFlatFunction]) -> anyhow::Result<String> {
+    let parts = (src_fns.iter())
+        .map(|f| {
+            Ok([
+                serde_json::to_string(f)?,
+                f.item_fn.to_token_stream().to_string(),
+                // The line numbers are used to sort the functions
+                f.item_fn.span().start().line.to_string(),
+            ])
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    Ok(compute_hash(
+        [state_key.to_owned()]
+            .into_iter()
+            .chain(parts.into_iter().flatten()),
+    ))
+}
@@ -3,12 +3,14 @@ use crate::codegen::ir::hir::flat::struct_or_enum::HirFlatStruct;
 use crate::codegen::ir::mir::func::MirFunc;
 use crate::codegen::ir::misc::skip::{IrSkip, IrValueOrSkip};
 use crate::codegen::parser::mir::internal_config::ParserMirInternalConfig;
+use crate::codegen::parser::mir::parser::function::memo::FunctionsMemo;
 use crate::codegen::parser::mir::parser::ty::TypeParser;
 use crate::codegen::parser::mir::ParseMode;
 use itertools::{concat, Itertools};
 use std::collections::HashMap;
 
 pub(crate) mod auto_accessor;
+pub(crate) mod memo;
 pub(crate) mod real;
 pub(crate) mod ui_related;
 
@@ -18,9 +20,10 @@ pub(crate) fn parse(
     type_parser: &mut TypeParser,
     src_structs: &HashMap<String, &HirFlatStruct>,
     parse_mode: ParseMode,
+    memo: &mut FunctionsMemo,
 ) -> anyhow::Result<(Vec<MirFunc>, Vec<IrSkip>)> {
     let items = concat([
-        real::parse(src_fns, type_parser, config, parse_mode)?,
+        real::parse(src_fns, type_parser, config, parse_mode, memo)?,
         auto_accessor::parse(config, src_structs, type_parser, parse_mode)?,
     ]);
     let (funcs, skips) = IrValueOrSkip::split(items);
@@ -5,6 +5,7 @@ use crate::codegen::ir::mir::func::{
     MirFunc, MirFuncArgMode, MirFuncImplMode, MirFuncImplModeDartOnly, MirFuncInput, MirFuncMode,
     MirFuncOutput, MirFuncOwnerInfo, MirFuncOwnerInfoMethod,
 };
+use crate::codegen::ir::mir::instantiation::MirInstantiation;
 use crate::codegen::ir::mir::ty::delegate::MirTypeDelegate;
 use crate::codegen::ir::mir::ty::primitive::MirTypePrimitive;
 use crate::codegen::ir::mir::ty::rust_auto_opaque_implicit::MirTypeRustAutoOpaqueImplicitReason;
@@ -17,19 +18,28 @@ use crate::codegen::ir::misc::skip::IrSkipReason::{
 use crate::codegen::ir::misc::skip::{IrSkip, IrSkipReason, IrValueOrSkip, MirFuncOrSkip};
 use crate::codegen::parser::mir::internal_config::ParserMirInternalConfig;
 use crate::codegen::parser::mir::parser::attribute::FrbAttributes;
+use crate::codegen::parser::mir::parser::function::memo::FunctionsMemo;
 use crate::codegen::parser::mir::parser::function::real::lifetime::parse_function_lifetime;
+use crate::codegen::parser::mir::parser::function::real::output::is_dart_fn_future_output;
 use crate::codegen::parser::mir::parser::function::ui_related::UI_MUTATION_FUNCTION_RUST_AOP_AFTER;
 use crate::codegen::parser::mir::parser::ty::concrete::ERROR_MESSAGE_FORBID_TYPE_SELF;
-use crate::codegen::parser::mir::parser::ty::generics::should_ignore_because_generics;
+use crate::codegen::parser::mir::parser::ty::generics::{
+    compute_instantiation_name, instantiate_generics, parse_type_params,
+    should_ignore_because_generics, split_instantiation,
+};
 use crate::codegen::parser::mir::parser::ty::misc::parse_comments;
+use crate::codegen::parser::mir::parser::ty::trait_def::is_trait_dart_implementable;
 use crate::codegen::parser::mir::parser::ty::{TypeParser, TypeParserParsingContext};
 use crate::codegen::parser::mir::ParseMode;
 use crate::library::codegen::ir::mir::ty::MirTypeTrait;
 use crate::utils::namespace::{Namespace, NamespacedName};
 use anyhow::{bail, Context};
-use itertools::concat;
+use convert_case::Case;
+use itertools::{concat, Itertools};
 use log::{debug, warn};
 use std::fmt::Debug;
+use syn::visit_mut::VisitMut;
+use syn::Type;
 use IrSkipReason::IgnoreBecauseFunctionNotPub;
 use MirType::Primitive;
 
@@ -44,12 +54,33 @@ pub(crate) fn parse(
     type_parser: &mut TypeParser,
     config: &ParserMirInternalConfig,
     parse_mode: ParseMode,
+    memo: &mut FunctionsMemo,
+) -> anyhow::Result<Vec<MirFuncOrSkip>> {
+    let mut ans = vec![];
+    for (_, namespace_fns) in &(src_fns.iter()).group_by(|f| &f.namespace) {
+        let namespace_fns = namespace_fns.cloned().collect_vec();
+        ans.extend(
+            memo.get_or_parse(&namespace_fns, type_parser, |type_parser| {
+                parse_namespace(&namespace_fns, type_parser, config, parse_mode)
+            })?,
+        );
+    }
+    Ok(ans)
+}
+
+fn parse_namespace(
+    src_fns: &[HirFlatFunction],
+    type_parser: &mut TypeParser,
+    config: &ParserMirInternalConfig,
+    parse_mode: ParseMode,
 ) -> anyhow::Result<Vec<MirFuncOrSkip>> {
     let mut function_parser = FunctionParser::new(type_parser);
     (src_fns.iter())
-        .map(|f| {
+        .flat_map(instantiate_function)
+        .map(|(f, instantiate_args)| {
             function_parser.parse_function(
-                f,
+                &f,
+                instantiate_args.as_deref(),
                 &config.force_codec_mode_pack,
                 config
                     .rust_input_namespace_pack
@@ -67,6 +98,36 @@ pub(crate) fn parse(
         .collect()
 }
 
+/// Expands a generic function into the instantiations declared via `#[frb(instantiate = ..)]`,
+/// with the type parameters in the signature replaced by the type arguments.
+fn instantiate_function(func: &HirFlatFunction) -> Vec<(HirFlatFunction, Option<Vec<Type>>)> {
+    let name = func.item_fn.name();
+    let type_params = parse_type_params(&func.item_fn.sig().generics);
+    let declared = (FrbAttributes::parse(func.item_fn.attrs()))
+        .map(|attributes| attributes.instantiate())
+        .unwrap_or_default();
+    let candidates = (declared.iter())
+        .filter_map(split_instantiation)
+        .filter(|(declared_name, args)| *declared_name == name && args.len() == type_params.len())
+        .collect_vec();
+    if type_params.is_empty() || candidates.is_empty() {
+        return vec![(func.clone(), None)];
+    }
+
+    (candidates.into_iter())
+        .map(|(_, args)| {
+            let mut ans = func.clone();
+            let sig = ans.item_fn.sig_mut();
+            let mut generics = sig.generics.clone();
+            instantiate_generics(&mut generics, &args, |substitutor| {
+                substitutor.visit_signature_mut(sig)
+            });
+            sig.generics = generics;
+            (ans, Some(args))
+        })
+        .collect_vec()
+}
+
 pub(crate) struct FunctionParser<'a, 'b> {
     type_parser: &'a mut TypeParser<'b>,
 }
@@ -80,6 +141,7 @@ impl<'a, 'b> FunctionParser<'a, 'b> {
     pub(crate) fn parse_function(
         &mut self,
         func: &HirFlatFunction,
+        instantiate_args: Option<&[Type]>,
         force_codec_mode_pack: &Option<CodecModePack>,
         rust_output_path_namespace: Namespace,
         default_stream_sink_codec: CodecMode,
@@ -92,6 +154,7 @@ impl<'a, 'b> FunctionParser<'a, 'b> {
     ) -> anyhow::Result<MirFuncOrSkip> {
         match self.parse_function_inner(
             func,
+            instantiate_args,
             force_codec_mode_pack,
             rust_output_path_namespace,
             default_stream_sink_codec,
@@ -127,6 +190,7 @@ impl<'a, 'b> FunctionParser<'a, 'b> {
     fn parse_function_inner(
         &mut self,
         func: &HirFlatFunction,
+        instantiate_args: Option<&[Type]>,
         force_codec_mode_pack: &Option<CodecModePack>,
         rust_output_path_namespace: Namespace,
         default_stream_sink_codec: CodecMode,
@@ -171,7 +235,7 @@ impl<'a, 'b> FunctionParser<'a, 'b> {
             };
 
         let is_owner_trait_def = matches!(func.owner, HirFlatFunctionOwner::TraitDef { .. });
-        let owner = match self.parse_owner(
+        let mut owner = match self.parse_owner(
             func,
             &create_context(None, false),
             dart_name.clone(),
@@ -181,7 +245,21 @@ impl<'a, 'b> FunctionParser<'a, 'b> {
             IrValueOrSkip::Skip(reason) => return Ok(create_output_skip(func, reason)),
         };
 
+        let instantiation = match instantiate_args {
+            Some(args) => Some(self.parse_instantiation(
+                func,
+                args,
+                &mut owner,
+                &create_context(None, false),
+            )?),
+            None => None,
+        };
+
         let func_name = parse_name(&func.item_fn.name(), &owner);
+        let func_name = match &instantiation {
+            Some((mir_args, _)) => compute_instantiation_name(&func_name, mir_args, Case::Snake),
+            None => func_name,
+        };
 
         if attributes.ignore() {
             return Ok(create_output_skip(func, IgnoreBecauseExplicitAttribute));
@@ -215,8 +293,11 @@ impl<'a, 'b> FunctionParser<'a, 'b> {
             };
             info = info.merge(arg_info)?;
         }
+        let dart_fn_future_output = is_owner_trait_dart_implementable(func, self.type_parser)?
+            && is_dart_fn_future_output(func.item_fn.sig());
         info = info.merge(self.parse_fn_output(
             func.item_fn.sig(),
+            dart_fn_future_output,
             &owner,
             &context_output,
             &attributes,
@@ -250,7 +331,9 @@ impl<'a, 'b> FunctionParser<'a, 'b> {
             owner,
             mode,
             stream_dart_await,
-            rust_async: func.item_fn.sig().asyncness.is_some(),
+            stream_capacity: attributes.stream_capacity(),
+            executor: attributes.executor(),
+            rust_async: func.item_fn.sig().asyncness.is_some() || dart_fn_future_output,
             initializer: attributes.init(),
             accessor,
             arg_mode: if attributes.positional() {
@@ -264,11 +347,45 @@ impl<'a, 'b> FunctionParser<'a, 'b> {
             rust_aop_after: (attributes.ui_mutation())
                 .then(|| UI_MUTATION_FUNCTION_RUST_AOP_AFTER.to_owned()),
             impl_mode,
+            instantiation: instantiation.map(|(_, instantiation)| instantiation),
             src_lineno_pseudo: src_lineno,
         }))
     }
 }
 
+impl<'a, 'b> FunctionParser<'a, 'b> {
+    /// Parses the type arguments of one instantiation of a generic function, e.g. `get_page<User>`.
+    /// For methods, the instantiation name (e.g. `get_page_user`) becomes the Dart method name,
+    /// since it is the only thing to distinguish them.
+    fn parse_instantiation(
+        &mut self,
+        func: &HirFlatFunction,
+        args: &[Type],
+        owner: &mut MirFuncOwnerInfo,
+        context: &TypeParserParsingContext,
+    ) -> anyhow::Result<(Vec<MirType>, MirInstantiation)> {
+        let mir_args = (args.iter())
+            .map(|arg| self.type_parser.parse_type(arg, context))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        let name = func.item_fn.name();
+
+        if let MirFuncOwnerInfo::Method(method) = owner {
+            let dart_name = (method.actual_method_dart_name.clone()).unwrap_or(name.clone());
+            method.actual_method_dart_name = Some(compute_instantiation_name(
+                &dart_name,
+                &mir_args,
+                Case::Snake,
+            ));
+        }
+
+        let instantiation = MirInstantiation {
+            name,
+            args: mir_args.iter().map(|arg| arg.rust_api_type()).collect(),
+        };
+        Ok((mir_args, instantiation))
+    }
+}
+
 fn compute_dart_async(
     func: &HirFlatFunction,
     attributes: &FrbAttributes,
@@ -279,6 +396,21 @@ fn compute_dart_async(
         .unwrap_or(func.is_async() || default_dart_async)
 }
 
+fn is_owner_trait_dart_implementable(
+    func: &HirFlatFunction,
+    type_parser: &TypeParser,
+) -> anyhow::Result<bool> {
+    let trait_def_name = match &func.owner {
+        HirFlatFunctionOwner::TraitDef { trait_def_name } => &trait_def_name.name,
+        HirFlatFunctionOwner::StructOrEnum {
+            trait_def_name: Some(trait_def_name),
+            ..
+        } => trait_def_name,
+        _ => return Ok(false),
+    };
+    is_trait_dart_implementable(trait_def_name, type_parser)
+}
+
 fn should_forbid_type_self_for_inputs(owner: &MirFuncOwnerInfo) -> bool {
     if let MirFuncOwnerInfo::Method(method) = owner {
         if matches!(method.owner_ty, MirType::TraitDef(_)) {
@@ -362,6 +494,13 @@ pub(crate) fn compute_codec_mode_pack(
     };
     let attr_ans = attributes.codec_mode_pack();
 
+    // The compact codec is dispatched in the same way as PDE, thus also works when full_dep=false
+    if let (Some(force_ans), Some(attr_ans)) = (force_ans, &attr_ans) {
+        if force_ans.dart2rust == CodecMode::Pde && attr_ans.dart2rust == CodecMode::Cse {
+            return attr_ans.to_owned();
+        }
+    }
+
     if force_ans.is_some() && attr_ans.is_some() {
         warn!("Ignore attributes setting codec mode (e.g. when full_dep=false)");
     }
@@ -5,6 +5,7 @@ use crate::codegen::ir::mir::ty::rust_auto_opaque_implicit::MirTypeRustAutoOpaqu
 use crate::codegen::ir::mir::ty::MirType;
 use crate::codegen::parser::mir::parser::attribute::FrbAttributes;
 use crate::codegen::parser::mir::parser::function::real::{FunctionParser, FunctionPartialInfo};
+use crate::codegen::parser::mir::parser::ty::dart_fn::extract_dart_fn_future_inner;
 use crate::codegen::parser::mir::parser::ty::result::parse_type_maybe_result;
 use crate::codegen::parser::mir::parser::ty::TypeParserParsingContext;
 use crate::codegen::parser::mir::ParseMode;
@@ -15,13 +16,23 @@ impl<'a, 'b> FunctionParser<'a, 'b> {
     pub(super) fn parse_fn_output(
         &mut self,
         sig: &Signature,
+        dart_fn_future_output: bool,
         owner: &MirFuncOwnerInfo,
         context: &TypeParserParsingContext,
         attributes: &FrbAttributes,
     ) -> anyhow::Result<FunctionPartialInfo> {
         Ok(match &sig.output {
             ReturnType::Type(_, ty) => remove_reference_type(
-                remove_primitive_unit(self.parse_fn_output_type(ty, owner, context, attributes)?),
+                remove_primitive_unit(
+                    self.parse_fn_output_type(
+                        (extract_dart_fn_future_inner(ty))
+                            .filter(|_| dart_fn_future_output)
+                            .unwrap_or(ty),
+                        owner,
+                        context,
+                        attributes,
+                    )?,
+                ),
                 context.parse_mode,
                 &sig.ident.to_string(),
             ),
@@ -48,6 +59,12 @@ impl<'a, 'b> FunctionParser<'a, 'b> {
     }
 }
 
+/// A method of a `#[frb(dart_implementable)]` trait returning `DartFnFuture<T>` is regarded as
+/// an async function returning `T`, since object-safe traits cannot have `async fn`.
+pub(super) fn is_dart_fn_future_output(sig: &Signature) -> bool {
+    matches!(&sig.output, ReturnType::Type(_, ty) if extract_dart_fn_future_inner(ty).is_some())
+}
+
 // Convert primitive Unit type -> None
 fn remove_primitive_unit(info: FunctionPartialInfo) -> FunctionPartialInfo {
     if info.ok_output == Some(MirType::Primitive(MirTypePrimitive::Unit)) {
@@ -7,6 +7,7 @@ pub(crate) mod misc;
 pub(crate) mod trait_impl;
 pub(crate) mod ty;
 
+use crate::codegen::cache::Cache;
 use crate::codegen::ir::early_generator::pack::IrEarlyGeneratorPack;
 use crate::codegen::ir::hir::flat::struct_or_enum::{HirFlatEnum, HirFlatStruct};
 use crate::codegen::ir::mir::pack::MirPack;
@@ -14,6 +15,7 @@ use crate::codegen::ir::misc::skip::{IrSkip, IrSkipReason};
 use crate::codegen::parser::mir::internal_config::{
     ParserMirInternalConfig, RustInputNamespacePack,
 };
+use crate::codegen::parser::mir::parser::function::memo::FunctionsMemo;
 use crate::codegen::parser::mir::parser::ty::TypeParser;
 use crate::codegen::parser::mir::sanity_checker::opaque_inside_translatable_checker::check_opaque_inside_translatable;
 use crate::codegen::parser::mir::sanity_checker::unused_checker::get_unused_types;
@@ -25,6 +27,7 @@ pub(crate) fn parse(
     config: &ParserMirInternalConfig,
     ir_pack: &IrEarlyGeneratorPack,
     parse_mode: ParseMode,
+    cache: &Cache,
 ) -> anyhow::Result<MirPack> {
     let hir_flat = &ir_pack.hir_flat_pack;
     let structs_map = hir_flat.structs_map();
@@ -65,12 +68,14 @@ pub(crate) fn parse(
         .custom_ser_des_infos
         .extend(custom_ser_des_infos);
 
+    let mut functions_memo = FunctionsMemo::new(cache, config, ir_pack, &type_parser, parse_mode)?;
     let (funcs_all, funcs_skip) = function::parse(
         config,
         &hir_flat.functions,
         &mut type_parser,
         &structs_map,
         parse_mode,
+        &mut functions_memo,
     )?;
 
     let (struct_pool, enum_pool, dart_code_of_type) = type_parser.consume();
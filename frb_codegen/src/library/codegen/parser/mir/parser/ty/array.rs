@@ -3,6 +3,7 @@ use crate::codegen::ir::mir::ty::delegate::{
 };
 use crate::codegen::ir::mir::ty::MirType;
 use crate::codegen::ir::mir::ty::MirType::{Delegate, Primitive};
+use crate::codegen::parser::mir::parser::ty::state::ParserInfoDelta;
 use crate::codegen::parser::mir::parser::ty::TypeParserWithContext;
 use crate::utils::namespace::Namespace;
 use anyhow::bail;
@@ -47,3 +48,20 @@ impl<'a, 'b, 'c> TypeParserWithContext<'a, 'b, 'c> {
 pub(super) struct ArrayParserInfo {
     namespace_of_parsed_types: HashMap<(MirTypeDelegateArrayMode, usize), Namespace>,
 }
+
+impl ParserInfoDelta for ArrayParserInfo {
+    fn delta(&self, before: &Self) -> Self {
+        Self {
+            namespace_of_parsed_types: (self.namespace_of_parsed_types)
+                .delta(&before.namespace_of_parsed_types),
+        }
+    }
+
+    fn apply(&mut self, delta: &Self) {
+        (self.namespace_of_parsed_types).apply(&delta.namespace_of_parsed_types);
+    }
+
+    fn debug_entries(&self, ans: &mut Vec<String>) {
+        self.namespace_of_parsed_types.debug_entries(ans);
+    }
+}
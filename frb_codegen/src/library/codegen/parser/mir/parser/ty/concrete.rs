@@ -1,22 +1,28 @@
 use crate::codegen::generator::codec::structs::CodecMode;
 use crate::codegen::ir::mir::func::MirFuncOwnerInfo;
 use crate::codegen::ir::mir::ty::boxed::MirTypeBoxed;
+use crate::codegen::ir::mir::ty::dart_fn::{MirDartFnOutput, MirTypeDartFn};
 use crate::codegen::ir::mir::ty::dart_opaque::MirTypeDartOpaque;
 use crate::codegen::ir::mir::ty::delegate::{
-    MirTypeDelegate, MirTypeDelegateMap, MirTypeDelegateSet, MirTypeDelegateStreamSink,
-    MirTypeDelegateTime,
+    MirTypeDelegate, MirTypeDelegateBigPrimitive, MirTypeDelegateDartStream,
+    MirTypeDelegateDynTrait, MirTypeDelegateJson, MirTypeDelegateMap, MirTypeDelegateMapKind,
+    MirTypeDelegateSet, MirTypeDelegateSetKind, MirTypeDelegateStreamSink, MirTypeDelegateTime,
+    MirTypeDelegateVecDeque,
 };
 use crate::codegen::ir::mir::ty::dynamic::MirTypeDynamic;
 use crate::codegen::ir::mir::ty::general_list::mir_list;
+use crate::codegen::ir::mir::ty::primitive::MirTypePrimitive;
 use crate::codegen::ir::mir::ty::MirType;
 use crate::codegen::ir::mir::ty::MirType::{Boxed, DartOpaque, Delegate, Dynamic};
 use crate::codegen::parser::mir::parser::ty::path_data::extract_path_data;
+use crate::codegen::parser::mir::parser::ty::trait_def::is_trait_dart_implementable;
 use crate::codegen::parser::mir::parser::ty::unencodable::{splay_segments, SplayedSegment};
 use crate::codegen::parser::mir::parser::ty::TypeParserWithContext;
 use crate::if_then_some;
-use anyhow::{bail, Context};
+use anyhow::{bail, ensure, Context};
 use itertools::Itertools;
-use syn::{parse_str, Type};
+use quote::ToTokens;
+use syn::{parse_quote, parse_str, Type};
 
 impl<'a, 'b, 'c> TypeParserWithContext<'a, 'b, 'c> {
     pub(crate) fn parse_type_path_data_concrete(
@@ -35,9 +41,26 @@ impl<'a, 'b, 'c> TypeParserWithContext<'a, 'b, 'c> {
 
             ("Duration", []) if check_prefix("chrono") => Delegate(MirTypeDelegate::Time(MirTypeDelegateTime::Duration)),
             ("NaiveDateTime", []) if check_prefix("chrono") => Delegate(MirTypeDelegate::Time(MirTypeDelegateTime::Naive)),
+            ("NaiveDate", []) if check_prefix("chrono") => Delegate(MirTypeDelegate::Time(MirTypeDelegateTime::NaiveDate)),
+            ("NaiveTime", []) if check_prefix("chrono") => Delegate(MirTypeDelegate::Time(MirTypeDelegateTime::NaiveTime)),
             ("DateTime", args) if check_prefix("chrono") => self.parse_datetime(args)?,
 
+            // A bare `Duration` is treated as `chrono::Duration` above, unless `std::time::Duration` is imported by `use`
+            ("Duration", []) if ["std::time", "core::time"].contains(&non_last_segments.as_str()) => Delegate(MirTypeDelegate::Time(MirTypeDelegateTime::StdDuration)),
+            ("SystemTime", []) if check_prefix("std::time") => Delegate(MirTypeDelegate::Time(MirTypeDelegateTime::StdSystemTime)),
+
+            ("OffsetDateTime", []) if check_prefix("time") => Delegate(MirTypeDelegate::Time(MirTypeDelegateTime::TimeOffsetDateTime)),
+            ("PrimitiveDateTime", []) if check_prefix("time") => Delegate(MirTypeDelegate::Time(MirTypeDelegateTime::TimePrimitiveDateTime)),
+            // Names that are too common to be recognized without the full path
+            ("Date", []) if non_last_segments == "time" => Delegate(MirTypeDelegate::Time(MirTypeDelegateTime::TimeDate)),
+            ("Duration", []) if non_last_segments == "time" => Delegate(MirTypeDelegate::Time(MirTypeDelegateTime::TimeDuration)),
+
             ("Uuid", []) if check_prefix("uuid") => Delegate(MirTypeDelegate::Uuid),
+            // `serde_json::Map` can only be `Map<String, Value>`, thus the arguments are not checked
+            ("Value", []) if non_last_segments == "serde_json" => Delegate(MirTypeDelegate::Json(MirTypeDelegateJson::Value)),
+            ("Map", _) if non_last_segments == "serde_json" => Delegate(MirTypeDelegate::Json(MirTypeDelegateJson::Map)),
+            ("BigInt", []) if check_prefix("num_bigint") => Delegate(MirTypeDelegate::BigPrimitive(MirTypeDelegateBigPrimitive::BigInt)),
+            ("Decimal", []) if check_prefix("rust_decimal") => Delegate(MirTypeDelegate::BigPrimitive(MirTypeDelegateBigPrimitive::Decimal)),
             ("String", []) | ("str", []) => Delegate(MirTypeDelegate::String),
             ("char", []) => Delegate(MirTypeDelegate::Char),
             ("Backtrace", []) => Delegate(MirTypeDelegate::Backtrace),
@@ -62,6 +85,14 @@ impl<'a, 'b, 'c> TypeParserWithContext<'a, 'b, 'c> {
                         &ty_raw,
                         |raw| format!("Box<{raw}>"),
                     )?,
+                    MirType::Delegate(MirTypeDelegate::DynTrait(ty))
+                        if is_trait_dart_implementable(&ty.trait_def_name.name, self.inner)? =>
+                    {
+                        Delegate(MirTypeDelegate::DynTrait(MirTypeDelegateDynTrait {
+                            boxed: true,
+                            ..ty
+                        }))
+                    }
                     _ => Boxed(MirTypeBoxed {
                         exist_in_real_api: true,
                         inner: Box::new(inner),
@@ -71,16 +102,17 @@ impl<'a, 'b, 'c> TypeParserWithContext<'a, 'b, 'c> {
 
             ("Vec", [element]) => mir_list(self.parse_type(element)?, true),
 
-            ("HashMap", [key, value]) => {
-                let key  = self.parse_type(key)?;
-                let value  = self.parse_type(value)?;
-                Delegate(MirTypeDelegate::Map(MirTypeDelegateMap {
-                    key: Box::new(key.clone()),
-                    value: Box::new(value.clone()),
-                    element_delegate: self.create_mir_record(vec![key, value]),
-                }))
-            },
+            ("HashMap", [key, value]) => self.parse_map(MirTypeDelegateMapKind::HashMap, key, value)?,
+            ("BTreeMap", [key, value]) => self.parse_map(MirTypeDelegateMapKind::BTreeMap, key, value)?,
             ("HashSet", [inner]) => Delegate(MirTypeDelegate::Set(MirTypeDelegateSet {
+                kind: MirTypeDelegateSetKind::HashSet,
+                inner: Box::new(self.parse_type(inner)?),
+            })),
+            ("BTreeSet", [inner]) => Delegate(MirTypeDelegate::Set(MirTypeDelegateSet {
+                kind: MirTypeDelegateSetKind::BTreeSet,
+                inner: Box::new(self.parse_type(inner)?),
+            })),
+            ("VecDeque", [inner]) => Delegate(MirTypeDelegate::VecDeque(MirTypeDelegateVecDeque {
                 inner: Box::new(self.parse_type(inner)?),
             })),
 
@@ -95,6 +127,8 @@ impl<'a, 'b, 'c> TypeParserWithContext<'a, 'b, 'c> {
                 codec: parse_stream_sink_codec(codec)?,
             })),
 
+            ("DartStream", [inner]) => self.parse_dart_stream(inner)?,
+
             _ => return Ok(None),
         }))
     }
@@ -111,6 +145,51 @@ impl<'a, 'b, 'c> TypeParserWithContext<'a, 'b, 'c> {
         self.parse_type(&parse_str::<Type>(&enum_or_struct_name)?)
     }
 
+    fn parse_map(
+        &mut self,
+        kind: MirTypeDelegateMapKind,
+        key: &Type,
+        value: &Type,
+    ) -> anyhow::Result<MirType> {
+        let key = self.parse_type(key)?;
+        let value = self.parse_type(value)?;
+        Ok(Delegate(MirTypeDelegate::Map(MirTypeDelegateMap {
+            kind,
+            key: Box::new(key.clone()),
+            value: Box::new(value.clone()),
+            element_delegate: self.create_mir_record(vec![key, value]),
+        })))
+    }
+
+    fn parse_dart_stream(&mut self, inner: &Type) -> anyhow::Result<MirType> {
+        // This will stop the whole generator and tell the users, so we do not care about testing it
+        // frb-coverage:ignore-start
+        ensure!(
+            !matches!(self.parse_type(inner)?, MirType::Optional(_)),
+            "`DartStream<Option<T>>` is not supported, since `None` is used to mark the end of the stream. {}",
+            inner.to_token_stream()
+        );
+        // frb-coverage:ignore-end
+
+        // Each item is pulled by calling the Dart side with a `cancel` flag,
+        // and `None` means the stream is done
+        let pull = MirTypeDartFn {
+            inputs: vec![MirType::Primitive(MirTypePrimitive::Bool)],
+            output: Box::new(MirDartFnOutput {
+                normal: self.parse_type(&parse_quote!(Option<#inner>))?,
+                error: MirType::Delegate(MirTypeDelegate::AnyhowException),
+                api_fallible: true,
+            }),
+        };
+
+        Ok(Delegate(MirTypeDelegate::DartStream(
+            MirTypeDelegateDartStream {
+                inner: Box::new(self.parse_type(inner)?),
+                pull,
+            },
+        )))
+    }
+
     // the function signature is not covered while the whole body is covered - looks like a bug in coverage tool
     // frb-coverage:ignore-start
     fn parse_datetime(&mut self, args: &[Type]) -> anyhow::Result<MirType> {
@@ -120,6 +199,10 @@ impl<'a, 'b, 'c> TypeParserWithContext<'a, 'b, 'c> {
             return Ok(match splay_segments(&inner.raw.segments).last().unwrap() {
                 ("Utc", []) => Delegate(MirTypeDelegate::Time(MirTypeDelegateTime::Utc)),
                 ("Local", []) => Delegate(MirTypeDelegate::Time(MirTypeDelegateTime::Local)),
+                ("FixedOffset", []) => {
+                    Delegate(MirTypeDelegate::Time(MirTypeDelegateTime::FixedOffset))
+                }
+                ("Tz", []) => Delegate(MirTypeDelegate::Time(MirTypeDelegateTime::Tz)),
                 // This will stop the whole generator and tell the users, so we do not care about testing it
                 // frb-coverage:ignore-start
                 _ => bail!("Invalid DateTime generic: {args:?}"),
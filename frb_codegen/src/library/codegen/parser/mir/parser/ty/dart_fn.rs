@@ -65,34 +65,37 @@ impl<'a, 'b, 'c> TypeParserWithContext<'a, 'b, 'c> {
     fn parse_dart_fn_output(&mut self, return_type: &ReturnType) -> anyhow::Result<ResultTypeInfo> {
         // frb-coverage:ignore-end
         if let ReturnType::Type(_, ret_ty) = return_type {
-            if let Type::Path(TypePath { ref path, .. }) = **ret_ty {
-                if let Some(PathSegment {
-                    ident,
-                    arguments:
-                        PathArguments::AngleBracketed(AngleBracketedGenericArguments { args, .. }),
-                }) = path.segments.first()
-                {
-                    if &ident.to_string() == "DartFnFuture" {
-                        if let GenericArgument::Type(inner_ty) = (args.iter())
-                            .find(|arg| matches!(arg, GenericArgument::Type(_)))
-                            .unwrap()
-                        {
-                            let mir = self.parse_type(inner_ty)?;
-                            return parse_type_maybe_result(&mir, self.inner, self.context);
-
-                            // This will stop the whole generator and tell the users, so we do not care about testing it
-                            // frb-coverage:ignore-start
-                        }
-                    }
-                }
+            if let Some(inner_ty) = extract_dart_fn_future_inner(ret_ty) {
+                let mir = self.parse_type(inner_ty)?;
+                return parse_type_maybe_result(&mir, self.inner, self.context);
             }
         }
 
+        // This will stop the whole generator and tell the users, so we do not care about testing it
+        // frb-coverage:ignore-start
         bail!("DartFn does not support return types except `DartFnFuture<T>` yet")
         // frb-coverage:ignore-end
     }
 }
 
+/// Extract `T` from `DartFnFuture<T>` (possibly written as `flutter_rust_bridge::DartFnFuture<T>`)
+pub(crate) fn extract_dart_fn_future_inner(ty: &Type) -> Option<&Type> {
+    if let Type::Path(TypePath { path, .. }) = ty {
+        if let Some(PathSegment {
+            ident,
+            arguments: PathArguments::AngleBracketed(AngleBracketedGenericArguments { args, .. }),
+        }) = path.segments.last()
+        {
+            if ident == "DartFnFuture" {
+                return (args.iter())
+                    .filter_map(|arg| if_then_some!(let GenericArgument::Type(ty) = arg, ty))
+                    .next();
+            }
+        }
+    }
+    None
+}
+
 const FALLBACK_ERROR_TYPE: MirType = MirType::Delegate(MirTypeDelegate::AnyhowException);
 
 // // Use this unit "test" to see how a type will be parsed into a tree
@@ -1,10 +1,15 @@
 use crate::codegen::ir::hir::flat::struct_or_enum::HirFlatStructOrEnum;
 use crate::codegen::ir::hir::misc::syn_item_struct_or_enum::SynItemStructOrEnum;
 use crate::codegen::ir::hir::misc::visibility::HirVisibility;
+use crate::codegen::ir::mir::instantiation::{compute_rust_style, MirInstantiation};
 use crate::codegen::ir::mir::ty::rust_auto_opaque_implicit::MirTypeRustAutoOpaqueImplicitReason;
 use crate::codegen::ir::mir::ty::MirType;
 use crate::codegen::parser::mir::parser::attribute::FrbAttributes;
-use crate::codegen::parser::mir::parser::ty::generics::should_ignore_because_generics;
+use crate::codegen::parser::mir::parser::ty::generics::{
+    compute_instantiation_name, instantiate_generics, parse_type_params,
+    should_ignore_because_generics, split_instantiation,
+};
+use crate::codegen::parser::mir::parser::ty::state::ParserInfoDelta;
 use crate::codegen::parser::mir::parser::ty::unencodable::SplayedSegment;
 use crate::codegen::parser::mir::parser::ty::TypeParserParsingContext;
 use crate::library::codegen::ir::mir::ty::MirTypeTrait;
@@ -12,7 +17,9 @@ use crate::utils::basic_code::general_code::GeneralDartCode;
 use crate::utils::basic_code::parser::parse_dart_code;
 use crate::utils::crate_name::CrateName;
 use crate::utils::namespace::{Namespace, NamespacedName};
-use log::debug;
+use convert_case::Case;
+use itertools::Itertools;
+use log::{debug, warn};
 use std::collections::{HashMap, HashSet};
 use std::fmt::Debug;
 use std::hash::Hash;
@@ -39,16 +46,22 @@ where
         last_segment: &SplayedSegment,
         override_opaque: Option<bool>,
     ) -> anyhow::Result<Option<(MirType, FrbAttributes)>> {
-        let (name, _) = last_segment;
+        let (name, args) = last_segment;
         // let name = external_impl::parse_name_or_original(name)?;
 
         if let Some(src_object) = self.src_objects().get(*name) {
             let src_object = (*src_object).clone();
 
             let namespace = self.parse_namespace(name).unwrap();
-            let namespaced_name = NamespacedName::new(namespace, name.to_string());
 
             let attrs = FrbAttributes::parse(src_object.src.attrs())?;
+            let (src_object, instantiation) =
+                match self.parse_instantiation(&src_object, &attrs, args)? {
+                    Some((src_object, instantiation)) => (src_object, Some(instantiation)),
+                    None => (src_object, None),
+                };
+            let namespaced_name = NamespacedName::new(namespace, src_object.name.name.clone());
+
             let attrs_opaque = override_opaque.or(attrs.opaque());
             if attrs_opaque == Some(true) {
                 debug!("Treat {name} as opaque since attribute says so");
@@ -65,9 +78,15 @@ where
                     &namespaced_name.namespace,
                     &src_object.name.name,
                     src_object.mirror,
+                    &instantiation,
                 );
                 let parsed_object = self
-                    .parse_inner_impl(&src_object, name.clone(), wrapper_name)
+                    .parse_inner_impl(
+                        &src_object,
+                        name.clone(),
+                        wrapper_name,
+                        instantiation.clone(),
+                    )
                     .map_err(|e| {
                         // Because this will cause the object not inserted into object_pool, thus may be confusing in later stages
                         log::info!(
@@ -89,12 +108,71 @@ where
                 )));
             }
 
-            return Ok(Some((self.construct_output(ident)?, attrs)));
+            return Ok(Some((self.construct_output(ident, instantiation)?, attrs)));
         }
 
         Ok(None)
     }
 
+    /// Monomorphize the generic struct or enum if the type arguments are declared via `#[frb(instantiate = ..)]`.
+    /// The output object is renamed (e.g. `PageUser` for `Page<User>`), and has no type parameters.
+    fn parse_instantiation(
+        &mut self,
+        src_object: &HirFlatStructOrEnum<Item>,
+        attrs: &FrbAttributes,
+        args: &[Type],
+    ) -> anyhow::Result<Option<(HirFlatStructOrEnum<Item>, MirInstantiation)>> {
+        let name = &src_object.name.name;
+        let type_params = parse_type_params(src_object.src.generics());
+        if type_params.is_empty() || type_params.len() != args.len() {
+            return Ok(None);
+        }
+
+        let candidates = (attrs.instantiate().iter())
+            .filter_map(split_instantiation)
+            .filter(|(declared_name, declared_args)| {
+                declared_name == name && declared_args.len() == args.len()
+            })
+            .collect_vec();
+        if candidates.is_empty() {
+            return Ok(None);
+        }
+
+        let mir_args = self.parse_type_args(args)?;
+        let rust_args = mir_args.iter().map(|arg| arg.rust_api_type()).collect_vec();
+
+        let mut declared = false;
+        for (_, declared_args) in candidates {
+            let declared_rust_args = (self.parse_type_args(&declared_args)?.iter())
+                .map(|arg| arg.rust_api_type())
+                .collect_vec();
+            declared |= declared_rust_args == rust_args;
+        }
+        if !declared {
+            warn!(
+                "Generic type `{name}` is used with type arguments {rust_args:?}, \
+                which is not declared via `#[frb(instantiate = ..)]`, thus it will be ignored"
+            );
+            return Ok(None);
+        }
+
+        let mut ans = src_object.clone();
+        let mut generics = ans.src.generics().clone();
+        instantiate_generics(&mut generics, args, |substitutor| {
+            ans.src.visit_mut(substitutor)
+        });
+        *ans.src.generics_mut() = generics;
+        ans.name.name = compute_instantiation_name(name, &mir_args, Case::Pascal);
+
+        Ok(Some((
+            ans,
+            MirInstantiation {
+                name: name.to_owned(),
+                args: rust_args,
+            },
+        )))
+    }
+
     fn parse_namespace(&mut self, name: &str) -> Option<Namespace> {
         self.src_objects()
             .get(name)
@@ -148,9 +226,16 @@ where
         src_object: &HirFlatStructOrEnum<Item>,
         name: NamespacedName,
         wrapper_name: Option<String>,
+        instantiation: Option<MirInstantiation>,
     ) -> anyhow::Result<Obj>;
 
-    fn construct_output(&self, ident: Id) -> anyhow::Result<MirType>;
+    fn construct_output(
+        &self,
+        ident: Id,
+        instantiation: Option<MirInstantiation>,
+    ) -> anyhow::Result<MirType>;
+
+    fn parse_type_args(&mut self, args: &[Type]) -> anyhow::Result<Vec<MirType>>;
 
     fn src_objects(&self) -> &HashMap<String, &HirFlatStructOrEnum<Item>>;
 
@@ -186,14 +271,40 @@ impl<Id, Obj> EnumOrStructParserInfo<Id, Obj> {
     }
 }
 
+impl<Id: Clone + Eq + Hash + Debug, Obj: Clone + PartialEq + Debug> ParserInfoDelta
+    for EnumOrStructParserInfo<Id, Obj>
+{
+    fn delta(&self, before: &Self) -> Self {
+        Self {
+            parsing_or_parsed_objects: (self.parsing_or_parsed_objects)
+                .delta(&before.parsing_or_parsed_objects),
+            object_pool: self.object_pool.delta(&before.object_pool),
+        }
+    }
+
+    fn apply(&mut self, delta: &Self) {
+        (self.parsing_or_parsed_objects).apply(&delta.parsing_or_parsed_objects);
+        self.object_pool.apply(&delta.object_pool);
+    }
+
+    fn debug_entries(&self, ans: &mut Vec<String>) {
+        self.parsing_or_parsed_objects.debug_entries(ans);
+        self.object_pool.debug_entries(ans);
+    }
+}
+
 fn compute_name_and_wrapper_name(
     namespace: &Namespace,
     name: &str,
     mirror: bool,
+    instantiation: &Option<MirInstantiation>,
 ) -> (NamespacedName, Option<String>) {
     let namespaced_name = NamespacedName::new(namespace.clone(), name.to_owned());
     let wrapper_name = if mirror {
-        Some(format!("FrbWrapper<{}>", namespaced_name.rust_style()))
+        Some(format!(
+            "FrbWrapper<{}>",
+            compute_rust_style(&namespaced_name, instantiation)
+        ))
     } else {
         None
     };
@@ -1,6 +1,7 @@
 use crate::codegen::ir::hir::flat::struct_or_enum::HirFlatEnum;
 use crate::codegen::ir::mir::field::{MirField, MirFieldSettings};
 use crate::codegen::ir::mir::ident::MirIdent;
+use crate::codegen::ir::mir::instantiation::MirInstantiation;
 use crate::codegen::ir::mir::ty::boxed::MirTypeBoxed;
 use crate::codegen::ir::mir::ty::delegate::{MirTypeDelegate, MirTypeDelegatePrimitiveEnum};
 use crate::codegen::ir::mir::ty::enumeration::{
@@ -43,8 +44,11 @@ impl<'a, 'b, 'c> TypeParserWithContext<'a, 'b, 'c> {
         src_enum: &HirFlatEnum,
         name: NamespacedName,
         wrapper_name: Option<String>,
+        instantiation: Option<MirInstantiation>,
     ) -> anyhow::Result<MirEnum> {
         let comments = parse_comments(&src_enum.src.attrs);
+        let attributes = FrbAttributes::parse(&src_enum.src.attrs)?;
+        let typed_exceptions = attributes.typed_exceptions();
         let raw_variants = src_enum
             .src
             .variants
@@ -52,7 +56,7 @@ impl<'a, 'b, 'c> TypeParserWithContext<'a, 'b, 'c> {
             .map(|variant| self.parse_variant(src_enum, variant))
             .collect::<anyhow::Result<Vec<_>>>()?;
 
-        let mode = compute_enum_mode(&raw_variants);
+        let mode = compute_enum_mode(&raw_variants, typed_exceptions);
         let variants = maybe_field_wrap_box(raw_variants, mode);
         let ignore = parse_struct_or_enum_should_ignore(
             src_enum,
@@ -63,9 +67,11 @@ impl<'a, 'b, 'c> TypeParserWithContext<'a, 'b, 'c> {
         Ok(MirEnum {
             name,
             wrapper_name,
+            instantiation,
             comments,
             variants,
             mode,
+            typed_exceptions,
             ignore,
         })
     }
@@ -109,6 +115,7 @@ impl<'a, 'b, 'c> TypeParserWithContext<'a, 'b, 'c> {
         Ok(MirVariantKind::Struct(MirStruct {
             name: compute_enum_variant_kind_struct_name(&src_enum.name, variant_name),
             wrapper_name: None,
+            instantiation: None,
             is_fields_named: field_ident.is_some(),
             dart_metadata: attributes.dart_metadata(),
             ignore: attributes.ignore(),
@@ -165,14 +172,21 @@ impl EnumOrStructParser<MirEnumIdent, MirEnum, ItemEnum>
         src_object: &HirFlatEnum,
         name: NamespacedName,
         wrapper_name: Option<String>,
+        instantiation: Option<MirInstantiation>,
     ) -> anyhow::Result<MirEnum> {
-        self.0.parse_enum(src_object, name, wrapper_name)
+        self.0
+            .parse_enum(src_object, name, wrapper_name, instantiation)
     }
 
-    fn construct_output(&self, ident: MirEnumIdent) -> anyhow::Result<MirType> {
+    fn construct_output(
+        &self,
+        ident: MirEnumIdent,
+        instantiation: Option<MirInstantiation>,
+    ) -> anyhow::Result<MirType> {
         let enum_ref = MirTypeEnumRef {
             ident: ident.clone(),
             is_exception: false,
+            instantiation,
         };
         let enu = self.0.inner.enum_parser_info.object_pool.get(&ident);
 
@@ -191,6 +205,10 @@ impl EnumOrStructParser<MirEnumIdent, MirEnum, ItemEnum>
         )
     }
 
+    fn parse_type_args(&mut self, args: &[Type]) -> anyhow::Result<Vec<MirType>> {
+        (args.iter()).map(|arg| self.0.parse_type(arg)).collect()
+    }
+
     fn src_objects(&self) -> &HashMap<String, &HirFlatEnum> {
         &self.0.inner.src_enums
     }
@@ -252,10 +270,12 @@ fn mir_type_wrap_box(ty: &mut MirType) {
     }
 }
 
-fn compute_enum_mode(variants: &[MirEnumVariant]) -> MirEnumMode {
-    if variants
-        .iter()
-        .any(|variant| !matches!(variant.kind, MirVariantKind::Value))
+fn compute_enum_mode(variants: &[MirEnumVariant], typed_exceptions: bool) -> MirEnumMode {
+    // Exceptions are classes in Dart, thus a Dart `enum` cannot be used even if all variants are values
+    if typed_exceptions
+        || variants
+            .iter()
+            .any(|variant| !matches!(variant.kind, MirVariantKind::Value))
     {
         MirEnumMode::Complex
     } else {
@@ -1,5 +1,10 @@
+use crate::codegen::ir::mir::ty::{MirType, MirTypeTrait};
 use crate::if_then_some;
+use convert_case::{Case, Casing};
 use itertools::Itertools;
+use std::collections::HashMap;
+use syn::visit_mut::VisitMut;
+use syn::{GenericArgument, GenericParam, Ident, PathArguments, Type, TypePath};
 
 pub(crate) fn parse_generics_info(generics: &syn::Generics) -> GenericsInfo {
     if generics.params.is_empty() {
@@ -35,3 +40,108 @@ pub(crate) fn should_ignore_because_generics(
         GenericsInfo::Unsupported => true,
     }
 }
+
+pub(crate) fn parse_type_params(generics: &syn::Generics) -> Vec<Ident> {
+    (generics.params.iter())
+        .filter_map(
+            |param| if_then_some!(let GenericParam::Type(inner) = param, inner.ident.clone()),
+        )
+        .collect_vec()
+}
+
+/// Splits a declared instantiation (e.g. `Page<User>`) into the name and the type arguments.
+pub(crate) fn split_instantiation(ty: &Type) -> Option<(String, Vec<Type>)> {
+    let Type::Path(TypePath { qself: None, path }) = ty else {
+        return None;
+    };
+    let segment = path.segments.last()?;
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    let args = (args.args.iter())
+        .filter_map(|arg| if_then_some!(let GenericArgument::Type(inner) = arg, inner.clone()))
+        .collect_vec();
+    Some((segment.ident.to_string(), args))
+}
+
+/// The name of an instantiation, e.g. `PageUser` for `Page<User>`, or `get_page_user` for `get_page<User>`
+pub(crate) fn compute_instantiation_name(name: &str, args: &[MirType], case: Case) -> String {
+    let sep = if case == Case::Snake { "_" } else { "" };
+    let args = (args.iter()).map(|arg| arg.safe_ident().to_case(case));
+    [name.to_owned()].into_iter().chain(args).join(sep)
+}
+
+/// Replaces the type parameters with concrete types, and removes them from the generics.
+pub(crate) fn instantiate_generics(
+    generics: &mut syn::Generics,
+    type_args: &[Type],
+    visit: impl FnOnce(&mut TypeParamSubstitutor),
+) {
+    let type_params = parse_type_params(generics);
+    assert_eq!(type_params.len(), type_args.len());
+
+    let mut substitutor = TypeParamSubstitutor(
+        (type_params.iter().map(ToString::to_string))
+            .zip(type_args.iter().cloned())
+            .collect(),
+    );
+    visit(&mut substitutor);
+
+    generics.params = (generics.params.iter())
+        .filter(|param| !matches!(param, GenericParam::Type(_)))
+        .cloned()
+        .collect();
+    generics.where_clause = None;
+}
+
+pub(crate) struct TypeParamSubstitutor(HashMap<String, Type>);
+
+impl VisitMut for TypeParamSubstitutor {
+    fn visit_type_mut(&mut self, ty: &mut Type) {
+        if let Type::Path(TypePath { qself: None, path }) = ty {
+            if let Some(arg) = (path.get_ident()).and_then(|ident| self.0.get(&ident.to_string())) {
+                *ty = arg.clone();
+                return;
+            }
+        }
+        syn::visit_mut::visit_type_mut(self, ty);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quote::ToTokens;
+    use syn::ItemStruct;
+
+    #[test]
+    fn test_instantiate_generics() {
+        let mut item: ItemStruct = syn::parse_str(
+            "pub struct Page<'a, T: Clone> where T: Send { items: Vec<T>, first: &'a T, total: i32 }",
+        )
+        .unwrap();
+        let mut generics = item.generics.clone();
+        instantiate_generics(&mut generics, &[syn::parse_str("User").unwrap()], |s| {
+            s.visit_fields_mut(&mut item.fields)
+        });
+        item.generics = generics;
+        assert_eq!(
+            item.to_token_stream().to_string(),
+            "pub struct Page < 'a > { items : Vec < User > , first : & 'a User , total : i32 }"
+        );
+    }
+
+    #[test]
+    fn test_split_instantiation() {
+        let (name, args) =
+            split_instantiation(&syn::parse_str("Page<User, i32>").unwrap()).unwrap();
+        assert_eq!(name, "Page");
+        assert_eq!(
+            args.iter()
+                .map(|x| x.to_token_stream().to_string())
+                .collect_vec(),
+            vec!["User", "i32"]
+        );
+        assert!(split_instantiation(&syn::parse_str("Page").unwrap()).is_none());
+    }
+}
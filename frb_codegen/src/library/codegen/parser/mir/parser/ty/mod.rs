@@ -28,7 +28,7 @@ use syn::Type;
 pub(crate) mod array;
 pub(crate) mod concrete;
 pub(crate) mod custom_ser_des;
-mod dart_fn;
+pub(crate) mod dart_fn;
 mod enum_or_struct;
 pub(crate) mod enumeration;
 pub(crate) mod external_impl;
@@ -45,6 +45,7 @@ pub(crate) mod rust_auto_opaque_explicit;
 pub(crate) mod rust_auto_opaque_implicit;
 mod rust_opaque;
 pub(crate) mod slice;
+pub(crate) mod state;
 pub(crate) mod structure;
 pub(crate) mod trait_def;
 pub(crate) mod trait_object;
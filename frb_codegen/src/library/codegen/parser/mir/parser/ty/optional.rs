@@ -42,7 +42,7 @@ impl<'a, 'b, 'c> TypeParserWithContext<'a, 'b, 'c> {
                     | Delegate(MirTypeDelegate::PrimitiveEnum(..)) => {
                         MirTypeOptional::new_with_boxed_wrapper(inner.clone())
                     }
-                    Delegate(MirTypeDelegate::Time(..)) => {
+                    Delegate(MirTypeDelegate::Time(time)) if !time.is_encoded_as_string() => {
                         MirTypeOptional::new_with_boxed_wrapper(inner.clone())
                     }
                     PrimitiveList(_) | GeneralList(_) | Boxed(_) | Dynamic(_) | Delegate(_) => {
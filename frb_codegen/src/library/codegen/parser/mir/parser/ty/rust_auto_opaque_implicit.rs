@@ -1,19 +1,22 @@
+use crate::codegen::ir::hir::misc::syn_item_struct_or_enum::SynItemStructOrEnum;
 use crate::codegen::ir::mir::func::OwnershipMode;
 use crate::codegen::ir::mir::llfetime_aware_type::MirLifetimeAwareType;
 use crate::codegen::ir::mir::ty::rust_auto_opaque_implicit::{
-    MirRustAutoOpaqueRaw, MirTypeRustAutoOpaqueImplicit, MirTypeRustAutoOpaqueImplicitReason,
+    MirRustAutoOpaqueLockStrategy, MirRustAutoOpaqueRaw, MirTypeRustAutoOpaqueImplicit,
+    MirTypeRustAutoOpaqueImplicitReason,
 };
 use crate::codegen::ir::mir::ty::rust_opaque::{
     MirRustOpaqueInner, MirTypeRustOpaque, RustOpaqueCodecMode,
 };
 use crate::codegen::ir::mir::ty::{MirType, MirTypeTrait};
+use crate::codegen::parser::mir::parser::attribute::FrbAttributes;
 use crate::codegen::parser::mir::parser::ty::path_data::extract_path_data;
 use crate::codegen::parser::mir::parser::ty::rust_opaque::{
     GeneralizedRustOpaqueParserInfo, RustOpaqueParserTypeInfo,
 };
 use crate::codegen::parser::mir::parser::ty::TypeParserWithContext;
 use crate::utils::namespace::Namespace;
-use anyhow::Result;
+use anyhow::{ensure, Result};
 use lazy_static::lazy_static;
 use quote::ToTokens;
 use regex::Regex;
@@ -28,6 +31,13 @@ impl<'a, 'b, 'c> TypeParserWithContext<'a, 'b, 'c> {
         override_ignore: Option<bool>,
     ) -> Result<MirType> {
         let (inner, ownership_mode) = split_ownership_from_ty(ty);
+        let lock_strategy = self.parse_rust_auto_opaque_lock_strategy(&inner)?;
+        ensure!(
+            !(ownership_mode == OwnershipMode::RefMut && lock_strategy.forbid_borrow_mut()),
+            "Cannot borrow `{}` mutably, since it uses `#[frb(lock = \"none\")]`",
+            inner.to_token_stream(),
+        );
+
         let (ans_raw, ans_inner) =
             self.parse_type_rust_auto_opaque_common(inner, namespace.clone(), None, None)?;
         let ans = MirTypeRustAutoOpaqueImplicit {
@@ -36,6 +46,7 @@ impl<'a, 'b, 'c> TypeParserWithContext<'a, 'b, 'c> {
             inner: ans_inner,
             ignore: override_ignore.unwrap_or(false),
             reason,
+            lock_strategy,
         };
         self.parse_maybe_lifetimeable(ans, namespace)
     }
@@ -52,6 +63,29 @@ impl<'a, 'b, 'c> TypeParserWithContext<'a, 'b, 'c> {
         parse_type_rust_auto_opaque_common_raw(inner, info.namespace, info.codec, dart_api_type)
     }
 
+    /// The lock strategy is specified by `#[frb(lock = ..)]` on the struct or enum.
+    fn parse_rust_auto_opaque_lock_strategy(
+        &self,
+        inner: &Type,
+    ) -> Result<MirRustAutoOpaqueLockStrategy> {
+        let Type::Path(path) = inner else {
+            return Ok(Default::default());
+        };
+        let Some(last_segment) = path.path.segments.last() else {
+            return Ok(Default::default());
+        };
+        let name = last_segment.ident.to_string();
+
+        let attrs = if let Some(src) = self.inner.src_structs.get(&name) {
+            src.src.attrs()
+        } else if let Some(src) = self.inner.src_enums.get(&name) {
+            src.src.attrs()
+        } else {
+            return Ok(Default::default());
+        };
+        Ok(FrbAttributes::parse(attrs)?.lock().unwrap_or_default())
+    }
+
     fn get_or_insert_rust_auto_opaque_info(
         &mut self,
         inner: &str,
@@ -4,6 +4,7 @@ use crate::codegen::ir::mir::ty::rust_opaque::{
 };
 use crate::codegen::ir::mir::ty::MirType;
 use crate::codegen::ir::mir::ty::MirType::RustOpaque;
+use crate::codegen::parser::mir::parser::ty::state::ParserInfoDelta;
 use crate::codegen::parser::mir::parser::ty::unencodable::SplayedSegment;
 use crate::codegen::parser::mir::parser::ty::TypeParserWithContext;
 use crate::utils::namespace::Namespace;
@@ -57,7 +58,7 @@ impl<'a, 'b, 'c> TypeParserWithContext<'a, 'b, 'c> {
 
 pub(super) type RustOpaqueParserInfo = GeneralizedRustOpaqueParserInfo;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub(super) struct RustOpaqueParserTypeInfo {
     pub namespace: Namespace,
     pub codec: RustOpaqueCodecMode,
@@ -81,3 +82,17 @@ impl GeneralizedRustOpaqueParserInfo {
         (self.0.entry(type_safe_ident).or_insert(insert_value)).clone()
     }
 }
+
+impl ParserInfoDelta for GeneralizedRustOpaqueParserInfo {
+    fn delta(&self, before: &Self) -> Self {
+        Self(self.0.delta(&before.0))
+    }
+
+    fn apply(&mut self, delta: &Self) {
+        self.0.apply(&delta.0);
+    }
+
+    fn debug_entries(&self, ans: &mut Vec<String>) {
+        self.0.debug_entries(ans);
+    }
+}
@@ -0,0 +1,126 @@
+use crate::codegen::cache::compute_hash;
+use crate::codegen::ir::mir::ty::enumeration::{MirEnum, MirEnumIdent};
+use crate::codegen::ir::mir::ty::structure::{MirStruct, MirStructIdent};
+use crate::codegen::parser::mir::parser::ty::array::ArrayParserInfo;
+use crate::codegen::parser::mir::parser::ty::enum_or_struct::EnumOrStructParserInfo;
+use crate::codegen::parser::mir::parser::ty::rust_auto_opaque_implicit::RustAutoOpaqueParserInfo;
+use crate::codegen::parser::mir::parser::ty::rust_opaque::RustOpaqueParserInfo;
+use crate::codegen::parser::mir::parser::ty::TypeParser;
+use crate::utils::basic_code::general_code::GeneralDartCode;
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet};
+use std::fmt::Debug;
+use std::hash::Hash;
+
+/// The part of [`TypeParser`] that is filled while parsing.
+///
+/// Entries are only added (or overwritten) but never removed, thus the changes made by parsing
+/// something can be represented by a state with only the new entries, and be applied later.
+#[derive(Clone, Debug)]
+pub(crate) struct TypeParserState {
+    dart_code_of_type: HashMap<String, GeneralDartCode>,
+    struct_parser_info: EnumOrStructParserInfo<MirStructIdent, MirStruct>,
+    enum_parser_info: EnumOrStructParserInfo<MirEnumIdent, MirEnum>,
+    rust_opaque_parser_info: RustOpaqueParserInfo,
+    rust_auto_opaque_parser_info: RustAutoOpaqueParserInfo,
+    array_parser_info: ArrayParserInfo,
+    has_logged_lifetimeable: bool,
+}
+
+impl TypeParserState {
+    /// Hash that does not depend on the iteration order of the maps
+    pub(crate) fn compute_hash(&self) -> String {
+        let mut entries = vec![format!("{}", self.has_logged_lifetimeable)];
+        self.dart_code_of_type.debug_entries(&mut entries);
+        self.struct_parser_info.debug_entries(&mut entries);
+        self.enum_parser_info.debug_entries(&mut entries);
+        self.rust_opaque_parser_info.debug_entries(&mut entries);
+        self.rust_auto_opaque_parser_info
+            .debug_entries(&mut entries);
+        self.array_parser_info.debug_entries(&mut entries);
+        compute_hash(entries.into_iter().sorted())
+    }
+}
+
+impl TypeParser<'_> {
+    pub(crate) fn state(&self) -> TypeParserState {
+        TypeParserState {
+            dart_code_of_type: self.dart_code_of_type.clone(),
+            struct_parser_info: self.struct_parser_info.clone(),
+            enum_parser_info: self.enum_parser_info.clone(),
+            rust_opaque_parser_info: self.rust_opaque_parser_info.clone(),
+            rust_auto_opaque_parser_info: self.rust_auto_opaque_parser_info.clone(),
+            array_parser_info: self.array_parser_info.clone(),
+            has_logged_lifetimeable: self.has_logged_lifetimeable,
+        }
+    }
+
+    /// The changes since the `before` state
+    pub(crate) fn state_delta(&self, before: &TypeParserState) -> TypeParserState {
+        TypeParserState {
+            dart_code_of_type: self.dart_code_of_type.delta(&before.dart_code_of_type),
+            struct_parser_info: self.struct_parser_info.delta(&before.struct_parser_info),
+            enum_parser_info: self.enum_parser_info.delta(&before.enum_parser_info),
+            rust_opaque_parser_info: (self.rust_opaque_parser_info)
+                .delta(&before.rust_opaque_parser_info),
+            rust_auto_opaque_parser_info: (self.rust_auto_opaque_parser_info)
+                .delta(&before.rust_auto_opaque_parser_info),
+            array_parser_info: self.array_parser_info.delta(&before.array_parser_info),
+            has_logged_lifetimeable: self.has_logged_lifetimeable
+                && !before.has_logged_lifetimeable,
+        }
+    }
+
+    pub(crate) fn apply_state_delta(&mut self, delta: &TypeParserState) {
+        (self.dart_code_of_type).apply(&delta.dart_code_of_type);
+        (self.struct_parser_info).apply(&delta.struct_parser_info);
+        (self.enum_parser_info).apply(&delta.enum_parser_info);
+        (self.rust_opaque_parser_info).apply(&delta.rust_opaque_parser_info);
+        (self.rust_auto_opaque_parser_info).apply(&delta.rust_auto_opaque_parser_info);
+        (self.array_parser_info).apply(&delta.array_parser_info);
+        self.has_logged_lifetimeable |= delta.has_logged_lifetimeable;
+    }
+}
+
+pub(super) trait ParserInfoDelta {
+    fn delta(&self, before: &Self) -> Self;
+
+    fn apply(&mut self, delta: &Self);
+
+    fn debug_entries(&self, ans: &mut Vec<String>);
+}
+
+impl<K: Clone + Eq + Hash + Debug, V: Clone + PartialEq + Debug> ParserInfoDelta for HashMap<K, V> {
+    fn delta(&self, before: &Self) -> Self {
+        (self.iter())
+            .filter(|(key, value)| before.get(key) != Some(value))
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect()
+    }
+
+    fn apply(&mut self, delta: &Self) {
+        self.extend(
+            delta
+                .iter()
+                .map(|(key, value)| (key.clone(), value.clone())),
+        );
+    }
+
+    fn debug_entries(&self, ans: &mut Vec<String>) {
+        ans.extend((self.iter()).map(|(key, value)| format!("{key:?}={value:?}")));
+    }
+}
+
+impl<K: Clone + Eq + Hash + Debug> ParserInfoDelta for HashSet<K> {
+    fn delta(&self, before: &Self) -> Self {
+        self.difference(before).cloned().collect()
+    }
+
+    fn apply(&mut self, delta: &Self) {
+        self.extend(delta.iter().cloned());
+    }
+
+    fn debug_entries(&self, ans: &mut Vec<String>) {
+        ans.extend(self.iter().map(|key| format!("{key:?}")));
+    }
+}
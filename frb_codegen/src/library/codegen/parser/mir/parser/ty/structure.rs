@@ -1,6 +1,7 @@
 use crate::codegen::ir::hir::flat::struct_or_enum::HirFlatStruct;
 use crate::codegen::ir::mir::field::{MirField, MirFieldSettings};
 use crate::codegen::ir::mir::ident::MirIdent;
+use crate::codegen::ir::mir::instantiation::MirInstantiation;
 use crate::codegen::ir::mir::ty::rust_auto_opaque_implicit::MirTypeRustAutoOpaqueImplicitReason;
 use crate::codegen::ir::mir::ty::structure::{MirStruct, MirStructIdent, MirTypeStructRef};
 use crate::codegen::ir::mir::ty::MirType;
@@ -38,6 +39,7 @@ impl<'a, 'b, 'c> TypeParserWithContext<'a, 'b, 'c> {
         src_struct: &HirFlatStruct,
         name: NamespacedName,
         wrapper_name: Option<String>,
+        instantiation: Option<MirInstantiation>,
     ) -> anyhow::Result<MirStruct> {
         let (is_fields_named, struct_fields) = match &src_struct.src.fields {
             Fields::Named(FieldsNamed { named, .. }) => (true, named),
@@ -68,6 +70,7 @@ impl<'a, 'b, 'c> TypeParserWithContext<'a, 'b, 'c> {
         Ok(MirStruct {
             name,
             wrapper_name,
+            instantiation,
             fields,
             is_fields_named,
             dart_metadata,
@@ -115,17 +118,28 @@ impl EnumOrStructParser<MirStructIdent, MirStruct, ItemStruct>
         src_object: &HirFlatStruct,
         name: NamespacedName,
         wrapper_name: Option<String>,
+        instantiation: Option<MirInstantiation>,
     ) -> anyhow::Result<MirStruct> {
-        self.0.parse_struct(src_object, name, wrapper_name)
+        self.0
+            .parse_struct(src_object, name, wrapper_name, instantiation)
     }
 
-    fn construct_output(&self, ident: MirStructIdent) -> anyhow::Result<MirType> {
+    fn construct_output(
+        &self,
+        ident: MirStructIdent,
+        instantiation: Option<MirInstantiation>,
+    ) -> anyhow::Result<MirType> {
         Ok(StructRef(MirTypeStructRef {
             ident,
             is_exception: false,
+            instantiation,
         }))
     }
 
+    fn parse_type_args(&mut self, args: &[Type]) -> anyhow::Result<Vec<MirType>> {
+        (args.iter()).map(|arg| self.0.parse_type(arg)).collect()
+    }
+
     fn src_objects(&self) -> &HashMap<String, &HirFlatStruct> {
         &self.0.inner.src_structs
     }
@@ -1,5 +1,6 @@
 use crate::codegen::ir::mir::ty::trait_def::MirTypeTraitDef;
 use crate::codegen::ir::mir::ty::MirType;
+use crate::codegen::parser::mir::parser::attribute::FrbAttributes;
 use crate::codegen::parser::mir::parser::ty::unencodable::SplayedSegment;
 use crate::codegen::parser::mir::parser::ty::{TypeParser, TypeParserWithContext};
 
@@ -17,3 +18,13 @@ pub(crate) fn parse_type_trait(name: &str, type_parser: &TypeParser) -> Option<M
         name: trait_info.name.clone(),
     })
 }
+
+pub(crate) fn is_trait_dart_implementable(
+    name: &str,
+    type_parser: &TypeParser,
+) -> anyhow::Result<bool> {
+    Ok(match type_parser.src_traits.get(name) {
+        Some(trait_info) => FrbAttributes::parse(&trait_info.attrs)?.dart_implementable(),
+        None => false,
+    })
+}
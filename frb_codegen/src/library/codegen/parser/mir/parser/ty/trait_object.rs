@@ -49,6 +49,7 @@ impl<'a, 'b, 'c> TypeParserWithContext<'a, 'b, 'c> {
                         Some(MirTypeDelegateDynTraitData {
                             delegate_namespace: trait_def_info.delegate_namespace.clone(),
                             variants: trait_def_info.variants.clone(),
+                            dart_impl_variant: trait_def_info.dart_impl_variant,
                         })
                     }
                 };
@@ -56,6 +57,7 @@ impl<'a, 'b, 'c> TypeParserWithContext<'a, 'b, 'c> {
                 return Ok(Some(MirType::Delegate(MirTypeDelegate::DynTrait(
                     MirTypeDelegateDynTrait {
                         trait_def_name: trait_ty.name.clone(),
+                        boxed: false,
                         data,
                     },
                 ))));
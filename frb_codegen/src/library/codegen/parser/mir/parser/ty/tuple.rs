@@ -38,6 +38,7 @@ impl<'a, 'b, 'c> TypeParserWithContext<'a, 'b, 'c> {
             MirStruct {
                 name: NamespacedName::new(namespace.clone(), safe_ident.clone()),
                 wrapper_name: None,
+                instantiation: None,
                 is_fields_named: true,
                 dart_metadata: vec![],
                 ignore: false,
@@ -68,6 +69,7 @@ impl<'a, 'b, 'c> TypeParserWithContext<'a, 'b, 'c> {
                 // empty: false,
                 ident: MirStructIdent(NamespacedName::new(namespace, safe_ident)),
                 is_exception: false,
+                instantiation: None,
             },
             values: values.into_boxed_slice(),
         }
@@ -1,3 +1,4 @@
+use crate::codegen::cache::Cache;
 use crate::codegen::dumper::Dumper;
 use crate::codegen::ir::hir::flat::pack::HirFlatPack;
 use crate::codegen::ir::mir::pack::MirPack;
@@ -15,14 +16,16 @@ pub(crate) mod mir;
 pub(crate) fn parse(
     config: &ParserInternalConfig,
     dumper: &Dumper,
+    cache: &Cache,
     progress_bar_pack: &GeneratorProgressBarPack,
 ) -> anyhow::Result<MirPack> {
-    parse_inner(config, dumper, progress_bar_pack, |_| Ok(()))
+    parse_inner(config, dumper, cache, progress_bar_pack, |_| Ok(()))
 }
 
 fn parse_inner(
     config: &ParserInternalConfig,
     dumper: &Dumper,
+    cache: &Cache,
     progress_bar_pack: &GeneratorProgressBarPack,
     on_hir_flat: impl FnOnce(&HirFlatPack) -> anyhow::Result<()>,
 ) -> anyhow::Result<MirPack> {
@@ -34,7 +37,7 @@ fn parse_inner(
     let dumper_mir = dumper.with_content(Mir);
 
     let pb = progress_bar_pack.parse_hir_raw.start();
-    let hir_raw = hir::raw::parse(&config.hir, dumper)?;
+    let hir_raw = hir::raw::parse(&config.hir, dumper, cache)?;
     drop(pb);
 
     let pb = progress_bar_pack.parse_hir_primary.start();
@@ -43,7 +46,7 @@ fn parse_inner(
     let hir_flat = hir::flat::parse(&config.hir, hir_naive_flat, &dumper_hir_flat)?;
     on_hir_flat(&hir_flat)?;
     let ir_early_generator =
-        early_generator::execute(hir_flat, &config.mir, &dumper_early_generator)?;
+        early_generator::execute(hir_flat, &config.mir, &dumper_early_generator, cache)?;
     drop(pb);
 
     let pb = progress_bar_pack.parse_mir.start();
@@ -52,6 +55,7 @@ fn parse_inner(
         &ir_early_generator,
         &dumper_mir,
         mir::ParseMode::Normal,
+        cache,
     )?;
     drop(pb);
 
@@ -60,6 +64,9 @@ fn parse_inner(
 
 #[cfg(test)]
 mod tests {
+    use crate::codegen::cache::internal_config::CacheInternalConfig;
+    use crate::codegen::cache::memory::MemoryCache;
+    use crate::codegen::cache::Cache;
     use crate::codegen::config::internal_config_parser::compute_force_codec_mode_pack;
     use crate::codegen::dumper::Dumper;
     use crate::codegen::generator::codec::structs::CodecMode;
@@ -113,6 +120,23 @@ mod tests {
         body("library/codegen/parser/mod/use_type_in_another_file", None)
     }
 
+    #[test]
+    #[serial]
+    fn test_use_type_in_another_file_without_cargo_expand() -> anyhow::Result<()> {
+        let fixture_name = "library/codegen/parser/mod/use_type_in_another_file";
+        let (actual_ir, rust_crate_dir) = execute_parse_with_options(
+            fixture_name,
+            None,
+            false,
+            &Cache::new(&Default::default()),
+        )?;
+        json_golden_test(
+            &serde_json::to_value(actual_ir)?,
+            &rust_crate_dir.join("expect_mir.json"),
+            &[],
+        )
+    }
+
     #[test]
     #[serial]
     fn test_qualified_names() -> anyhow::Result<()> {
@@ -131,12 +155,104 @@ mod tests {
         body("library/codegen/parser/mod/generics", None)
     }
 
+    #[test]
+    #[serial]
+    fn test_instantiate() -> anyhow::Result<()> {
+        body("library/codegen/parser/mod/instantiate", None)
+    }
+
     #[test]
     #[serial]
     fn test_unused_struct_enum() -> anyhow::Result<()> {
         body("library/codegen/parser/mod/unused_struct_enum", None)
     }
 
+    #[test]
+    #[serial]
+    fn test_dart_implementable() -> anyhow::Result<()> {
+        body("library/codegen/parser/mod/dart_implementable", None)
+    }
+
+    #[test]
+    #[serial]
+    fn test_typed_exceptions() -> anyhow::Result<()> {
+        body("library/codegen/parser/mod/typed_exceptions", None)
+    }
+
+    #[test]
+    #[serial]
+    fn test_std_time() -> anyhow::Result<()> {
+        body("library/codegen/parser/mod/std_time", None)
+    }
+
+    #[test]
+    #[serial]
+    fn test_chrono_extra() -> anyhow::Result<()> {
+        body("library/codegen/parser/mod/chrono_extra", None)
+    }
+
+    #[test]
+    #[serial]
+    fn test_time_crate() -> anyhow::Result<()> {
+        body("library/codegen/parser/mod/time_crate", None)
+    }
+
+    #[test]
+    #[serial]
+    fn test_big_number() -> anyhow::Result<()> {
+        body("library/codegen/parser/mod/big_number", None)
+    }
+
+    #[test]
+    #[serial]
+    fn test_serde_json_value() -> anyhow::Result<()> {
+        body("library/codegen/parser/mod/serde_json_value", None)
+    }
+
+    #[test]
+    #[serial]
+    fn test_memory_cache() -> anyhow::Result<()> {
+        let fixture_name = "library/codegen/parser/mod/multi_input_file";
+        let rust_input_namespace_pack = || {
+            Some(Box::new(|_rust_crate_dir: &Path| RustInputNamespacePack {
+                rust_input_namespace_prefixes: vec![
+                    Namespace::new_self_crate("api_one".to_owned()),
+                    Namespace::new_self_crate("api_two".to_owned()),
+                ],
+                rust_output_path_namespace: Namespace::new_self_crate("frb_generated".to_owned()),
+            }) as Box<_>)
+        };
+        let (expect_ir, _) = execute_parse_with_options(
+            fixture_name,
+            rust_input_namespace_pack(),
+            false,
+            &Cache::new(&Default::default()),
+        )?;
+
+        let dir = tempfile::tempdir()?;
+        let cache_config = CacheInternalConfig {
+            enabled: true,
+            cache_directory: dir.path().to_owned(),
+            excluded_source_paths: vec![],
+        };
+        let memory_cache = MemoryCache::default();
+        let cache = Cache::new(&cache_config).with_memory(&memory_cache);
+        for _ in 0..2 {
+            memory_cache.start_iteration();
+            let (actual_ir, _) = execute_parse_with_options(
+                fixture_name,
+                rust_input_namespace_pack(),
+                false,
+                &cache,
+            )?;
+            assert_eq!(
+                serde_json::to_value(&actual_ir)?,
+                serde_json::to_value(&expect_ir)?
+            );
+        }
+        Ok(())
+    }
+
     #[allow(clippy::type_complexity)]
     fn body(
         fixture_name: &str,
@@ -156,6 +272,21 @@ mod tests {
     fn execute_parse(
         fixture_name: &str,
         rust_input_namespace_pack: Option<Box<dyn Fn(&Path) -> RustInputNamespacePack>>,
+    ) -> anyhow::Result<(MirPack, PathBuf)> {
+        execute_parse_with_options(
+            fixture_name,
+            rust_input_namespace_pack,
+            true,
+            &Cache::new(&Default::default()),
+        )
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn execute_parse_with_options(
+        fixture_name: &str,
+        rust_input_namespace_pack: Option<Box<dyn Fn(&Path) -> RustInputNamespacePack>>,
+        cargo_expand: bool,
+        cache: &Cache,
     ) -> anyhow::Result<(MirPack, PathBuf)> {
         configure_opinionated_test_logging();
         let test_fixture_dir = get_test_fixture_dir(fixture_name);
@@ -174,10 +305,12 @@ mod tests {
                 rust_input_namespace_pack: rust_input_namespace_pack.clone(),
                 rust_crate_dir: rust_crate_dir.clone(),
                 third_party_crate_names: vec![],
+                cargo_expand,
+                rust_features: None,
             },
             mir: ParserMirInternalConfig {
                 rust_input_namespace_pack: rust_input_namespace_pack.clone(),
-                force_codec_mode_pack: compute_force_codec_mode_pack(true),
+                force_codec_mode_pack: compute_force_codec_mode_pack(true, false),
                 default_stream_sink_codec: CodecMode::Dco,
                 default_rust_opaque_codec: RustOpaqueCodecMode::Nom,
                 stop_on_error: true,
@@ -190,6 +323,7 @@ mod tests {
         let pack = parse_inner(
             &config,
             &Dumper::new(&Default::default()),
+            cache,
             &GeneratorProgressBarPack::new(),
             |hir_flat| {
                 json_golden_test(
@@ -0,0 +1,397 @@
+//! Split the output of `cargo expand` by module files, such that the unchanged modules are reused,
+//! and only the changed modules are expanded again.
+
+use crate::codegen::cache::RustSourcesHash;
+use crate::library::commands::cargo_expand::module_tree::{
+    is_frb_attribute, is_frb_expanded_attribute, join_module_path,
+};
+use log::debug;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::ops::Range;
+use syn::visit_mut::VisitMut;
+use syn::{Attribute, Expr, ExprLit, Item, Lit, Meta};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(super) struct ExpandedCrate {
+    /// Key of everything except for the module files
+    pub(super) crate_key: String,
+    /// Keyed by the module path, e.g. `api::simple`
+    pub(super) modules: BTreeMap<String, ExpandedModule>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(super) struct ExpandedModule {
+    /// Hash of the module file when the code is expanded
+    hash: Option<String>,
+    /// Expanded code of the module, where the content of each child module file is left out
+    code: String,
+}
+
+impl ExpandedCrate {
+    pub(super) fn split(
+        code: &str,
+        crate_key: String,
+        sources_hash: &RustSourcesHash,
+    ) -> anyhow::Result<Self> {
+        let file = syn::parse_file(code)?;
+        let module_hashes = &sources_hash.module_hashes;
+
+        let mut modules = BTreeMap::new();
+        split_module(
+            code,
+            &file.items,
+            "",
+            0..code.len(),
+            &|module_path| module_hashes.contains_key(module_path),
+            &mut |module_path, code| {
+                let module = ExpandedModule {
+                    hash: module_hashes.get(module_path).and_then(|x| x.hash.clone()),
+                    code,
+                };
+                modules.insert(module_path.to_owned(), module);
+            },
+        );
+        Ok(Self { crate_key, modules })
+    }
+
+    /// Reuse the unchanged modules and read the changed ones from the file system,
+    /// or return `None` when the crate needs to be expanded as a whole.
+    pub(super) fn reassemble(&mut self, sources_hash: &RustSourcesHash) -> Option<String> {
+        let mut get_code = |module_path: &str| -> Option<String> {
+            let module_hash = sources_hash.module_hashes.get(module_path)?;
+            if let Some(module) = self.modules.get(module_path) {
+                if module.hash == module_hash.hash {
+                    return Some(module.code.clone());
+                }
+            }
+
+            // The root module of `cargo expand` contains extra items such as the prelude
+            if module_path.is_empty() {
+                return None;
+            }
+            let code = fs::read_to_string(&module_hash.path).ok()?;
+            if !is_unchanged_by_expansion(syn::parse_file(&code).ok()?) {
+                debug!("Module {module_path} needs to be expanded by cargo expand");
+                return None;
+            }
+            debug!("Module {module_path} is changed, and is read from the file system");
+            let module = ExpandedModule {
+                hash: module_hash.hash.clone(),
+                code: code.clone(),
+            };
+            self.modules.insert(module_path.to_owned(), module);
+            Some(code)
+        };
+
+        assemble_module("", &mut get_code, &|module_path| {
+            sources_hash.module_hashes.contains_key(module_path)
+        })
+    }
+}
+
+fn split_module(
+    code: &str,
+    items: &[Item],
+    module_path: &str,
+    range: Range<usize>,
+    is_module_file: &impl Fn(&str) -> bool,
+    on_module: &mut impl FnMut(&str, String),
+) {
+    let mut children = vec![];
+    collect_expanded_child_modules(items, module_path, is_module_file, &mut children);
+
+    let mut module_code = String::new();
+    let mut pos = range.start;
+    for (_, _, child_range) in &children {
+        module_code += &code[pos..child_range.start];
+        pos = child_range.end;
+    }
+    module_code += &code[pos..range.end];
+    on_module(module_path, module_code);
+
+    for (child_module_path, child_items, child_range) in children {
+        split_module(
+            code,
+            child_items,
+            &child_module_path,
+            child_range,
+            is_module_file,
+            on_module,
+        );
+    }
+}
+
+/// The child module files, and the range of their content inside the braces
+fn collect_expanded_child_modules<'a>(
+    items: &'a [Item],
+    module_path: &str,
+    is_module_file: &impl Fn(&str) -> bool,
+    ans: &mut Vec<(String, &'a [Item], Range<usize>)>,
+) {
+    for item in items {
+        if let Item::Mod(item_mod) = item {
+            if let Some((brace, child_items)) = &item_mod.content {
+                let child_module_path = join_module_path(module_path, &item_mod.ident.to_string());
+                if is_module_file(&child_module_path) {
+                    let range = brace_inner_range(brace);
+                    ans.push((child_module_path, child_items, range));
+                } else {
+                    collect_expanded_child_modules(
+                        child_items,
+                        &child_module_path,
+                        is_module_file,
+                        ans,
+                    );
+                }
+            }
+        }
+    }
+}
+
+fn brace_inner_range(brace: &syn::token::Brace) -> Range<usize> {
+    brace.span.open().byte_range().end..brace.span.close().byte_range().start
+}
+
+fn assemble_module(
+    module_path: &str,
+    get_code: &mut impl FnMut(&str) -> Option<String>,
+    is_module_file: &impl Fn(&str) -> bool,
+) -> Option<String> {
+    let code = get_code(module_path)?;
+    let file = syn::parse_file(&code).ok()?;
+
+    let mut placeholders = vec![];
+    collect_placeholders(&file.items, module_path, is_module_file, &mut placeholders)?;
+
+    let mut ans = String::new();
+    let mut pos = 0;
+    for (child_module_path, range, needs_braces) in placeholders {
+        let child_code = assemble_module(&child_module_path, get_code, is_module_file)?;
+        ans += &code[pos..range.start];
+        if needs_braces {
+            ans += &format!(" {{{child_code}}}");
+        } else {
+            ans += &child_code;
+        }
+        pos = range.end;
+    }
+    ans += &code[pos..];
+    Some(ans)
+}
+
+/// Where the child module files should be put, which is either the inside of the braces
+/// (in expanded code) or the semicolon of `mod something;` (in the code read from file system)
+fn collect_placeholders(
+    items: &[Item],
+    module_path: &str,
+    is_module_file: &impl Fn(&str) -> bool,
+    ans: &mut Vec<(String, Range<usize>, bool)>,
+) -> Option<()> {
+    for item in items {
+        if let Item::Mod(item_mod) = item {
+            let child_module_path = join_module_path(module_path, &item_mod.ident.to_string());
+            match (&item_mod.content, &item_mod.semi) {
+                (Some((brace, child_items)), _) => {
+                    if is_module_file(&child_module_path) {
+                        ans.push((child_module_path, brace_inner_range(brace), false));
+                    } else {
+                        collect_placeholders(child_items, &child_module_path, is_module_file, ans)?;
+                    }
+                }
+                (None, Some(semi)) => {
+                    // Let `cargo expand` report the missing file
+                    if !is_module_file(&child_module_path) {
+                        return None;
+                    }
+                    ans.push((child_module_path, semi.span.byte_range(), true));
+                }
+                (None, None) => {}
+            }
+        }
+    }
+    Some(())
+}
+
+/// Whether `cargo expand` would output the same items (except for the child modules),
+/// i.e. there are no macros or attributes that it would expand or remove.
+/// Function bodies are ignored, since they do not affect the generated code.
+fn is_unchanged_by_expansion(mut file: syn::File) -> bool {
+    #[derive(Default)]
+    struct Visitor {
+        found: bool,
+    }
+
+    impl VisitMut for Visitor {
+        fn visit_attribute_mut(&mut self, attr: &mut Attribute) {
+            self.found |= !is_inert_attribute(attr);
+        }
+
+        fn visit_macro_mut(&mut self, _: &mut syn::Macro) {
+            self.found = true;
+        }
+
+        fn visit_block_mut(&mut self, _: &mut syn::Block) {}
+    }
+
+    let mut visitor = Visitor::default();
+    visitor.visit_file_mut(&mut file);
+    !visitor.found
+}
+
+fn is_inert_attribute(attr: &Attribute) -> bool {
+    const INERT_ATTRIBUTES: [&str; 7] = [
+        "allow",
+        "warn",
+        "deny",
+        "expect",
+        "must_use",
+        "inline",
+        "deprecated",
+    ];
+    let path = attr.path();
+    if path.is_ident("doc") {
+        matches!(
+            &attr.meta,
+            Meta::NameValue(meta) if matches!(&meta.value, Expr::Lit(ExprLit { lit: Lit::Str(_), .. }))
+        )
+    } else if is_frb_attribute(attr) {
+        !is_frb_expanded_attribute(attr)
+    } else {
+        INERT_ATTRIBUTES.iter().any(|name| path.is_ident(name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ExpandedCrate;
+    use crate::codegen::cache::{ModuleFileHash, RustSourcesHash};
+    use std::fs;
+    use std::path::Path;
+
+    fn create_sources_hash(dir: &Path, modules: &[(&str, &str, &str)]) -> RustSourcesHash {
+        RustSourcesHash {
+            crate_hash: "crate".to_owned(),
+            module_hashes: (modules.iter())
+                .map(|(module_path, path, hash)| {
+                    let module_hash = ModuleFileHash {
+                        path: dir.join(path),
+                        hash: Some(hash.to_string()),
+                    };
+                    (module_path.to_string(), module_hash)
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_split_and_reassemble() -> anyhow::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let expanded = "use std::prelude::rust_2021::*;
+pub mod api {
+    pub mod simple {
+        #[derive(Clone)]
+        pub struct A {}
+    }
+    pub mod inline {
+        pub mod nested {
+            pub fn f() {}
+        }
+    }
+    pub fn g() {}
+}
+mod frb_generated {}
+";
+        let modules = [
+            ("", "src/lib.rs", "h0"),
+            ("api", "src/api/mod.rs", "h1"),
+            ("api::simple", "src/api/simple.rs", "h2"),
+            ("api::inline::nested", "src/api/inline/nested.rs", "h3"),
+            ("frb_generated", "src/frb_generated.rs", "h4"),
+        ];
+        let sources_hash = create_sources_hash(dir.path(), &modules);
+
+        let mut cached = ExpandedCrate::split(expanded, "key".to_owned(), &sources_hash)?;
+        assert_eq!(cached.modules.len(), 5);
+        assert!(!cached.modules["api"].code.contains("pub fn f"));
+        assert_eq!(cached.reassemble(&sources_hash).unwrap(), expanded);
+
+        // Changed module without macros
+        fs::create_dir_all(dir.path().join("src/api/inline"))?;
+        fs::write(
+            dir.path().join("src/api/inline/nested.rs"),
+            "/// Hello\n#[frb(sync)]\npub fn f2() { println!(\"body\"); }",
+        )?;
+        let nested_changed = ("api::inline::nested", "src/api/inline/nested.rs", "h3b");
+        let sources_hash = create_sources_hash(
+            dir.path(),
+            &[
+                modules[0],
+                modules[1],
+                modules[2],
+                nested_changed,
+                modules[4],
+            ],
+        );
+        let actual = cached.reassemble(&sources_hash).unwrap();
+        assert!(actual.contains("pub fn f2") && !actual.contains("pub fn f()"));
+        assert!(actual.contains("pub struct A"));
+        syn::parse_file(&actual)?;
+
+        // Changed module with a new child module file
+        fs::create_dir_all(dir.path().join("src/api/simple"))?;
+        fs::write(
+            dir.path().join("src/api/simple.rs"),
+            "mod child; pub struct B {}",
+        )?;
+        fs::write(
+            dir.path().join("src/api/simple/child.rs"),
+            "pub struct C {}",
+        )?;
+        let sources_hash = create_sources_hash(
+            dir.path(),
+            &[
+                modules[0],
+                modules[1],
+                ("api::simple", "src/api/simple.rs", "h2b"),
+                ("api::simple::child", "src/api/simple/child.rs", "h5"),
+                nested_changed,
+                modules[4],
+            ],
+        );
+        let actual = cached.reassemble(&sources_hash).unwrap();
+        assert!(actual.contains("mod child {pub struct C {}}"), "{actual}");
+        assert!(actual.contains("pub struct B"));
+
+        // Changed modules needing expansion
+        fs::write(
+            dir.path().join("src/api/simple.rs"),
+            "#[derive(Clone)] pub struct B {}",
+        )?;
+        let sources_hash = create_sources_hash(
+            dir.path(),
+            &[
+                modules[0],
+                modules[1],
+                ("api::simple", "src/api/simple.rs", "h2c"),
+                nested_changed,
+                modules[4],
+            ],
+        );
+        assert!(cached.reassemble(&sources_hash).is_none());
+
+        let sources_hash = create_sources_hash(
+            dir.path(),
+            &[
+                ("", "src/lib.rs", "h0b"),
+                modules[1],
+                modules[2],
+                modules[3],
+                modules[4],
+            ],
+        );
+        assert!(cached.reassemble(&sources_hash).is_none());
+        Ok(())
+    }
+}
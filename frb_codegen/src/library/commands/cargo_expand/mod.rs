@@ -1,26 +1,49 @@
+mod incremental;
+mod module_tree;
 mod pseudo;
 mod real;
 
+use crate::codegen::cache::Cache;
 use crate::codegen::dumper::Dumper;
+use crate::codegen::ConfigDumpContent;
 use crate::utils::crate_name::CrateName;
 use crate::utils::path_utils::{normalize_windows_unc_path, path_to_string};
 use anyhow::Result;
 use log::debug;
+use quote::ToTokens;
 use std::env;
 use std::path::Path;
 
+pub(crate) use module_tree::list_module_files;
+
 pub(crate) fn run_cargo_expand(
     rust_crate_dir: &Path,
     interest_crate_name: Option<&CrateName>,
     dumper: &Dumper,
+    cache: &Cache,
 ) -> Result<syn::File> {
     if can_execute_real(rust_crate_dir)? {
-        real::run(rust_crate_dir, interest_crate_name, dumper)
+        real::run(rust_crate_dir, interest_crate_name, dumper, cache)
     } else {
         pseudo::run(rust_crate_dir, interest_crate_name)
     }
 }
 
+/// Read the crate from the file system without `cargo expand`,
+/// or return `None` if macros are used such that `cargo expand` is still needed.
+pub(crate) fn read_module_tree(
+    rust_crate_dir: &Path,
+    features: Option<&[String]>,
+    dumper: &Dumper,
+) -> Result<Option<syn::File>> {
+    let file = module_tree::run(rust_crate_dir, features)?;
+    if let Some(file) = &file {
+        (dumper.with_content(ConfigDumpContent::Source))
+            .dump_str("module_tree.rs", &file.to_token_stream().to_string())?;
+    }
+    Ok(file)
+}
+
 fn can_execute_real(rust_crate_dir: &Path) -> anyhow::Result<bool> {
     let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap_or_default();
     debug!("run_cargo_expand manifest_dir={manifest_dir} rust_crate_dir={rust_crate_dir:?}");
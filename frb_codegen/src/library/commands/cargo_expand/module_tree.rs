@@ -0,0 +1,707 @@
+//! Read the module tree of a crate directly from the file system,
+//! as a cheaper alternative to `cargo expand` when the code does not rely on macros.
+
+use anyhow::Context;
+use itertools::Itertools;
+use log::{debug, info};
+use quote::ToTokens;
+use std::collections::HashSet;
+use std::env::consts;
+use std::fs;
+use std::path::{Path, PathBuf};
+use syn::parse::ParseStream;
+use syn::punctuated::Punctuated;
+use syn::{Attribute, Expr, ExprLit, Fields, ImplItem, Item, Lit, Meta, Token, TraitItem};
+
+/// Returns `None` when items generated by macros are detected,
+/// since then only `cargo expand` can provide the full code.
+pub(super) fn run(
+    rust_crate_dir: &Path,
+    features: Option<&[String]>,
+) -> anyhow::Result<Option<syn::File>> {
+    let cfg = CfgEvaluator {
+        features: compute_enabled_features(rust_crate_dir, features)?,
+    };
+    debug!("module_tree::run features={:?}", cfg.features);
+
+    let file = parse_file(&cfg, &rust_crate_dir.join("src/lib.rs"), true)?;
+
+    if let Some(name) = find_macro_generated_item(&file.items) {
+        info!("Fallback to cargo expand, since items may be generated by the macro `{name}`");
+        return Ok(None);
+    }
+    Ok(Some(file))
+}
+
+/// A module whose content lives in its own file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ModuleFile {
+    /// E.g. `api::simple`, or empty for the crate root
+    pub(crate) module_path: String,
+    pub(crate) path: PathBuf,
+}
+
+/// All module files reachable from `src/lib.rs`, regardless of `#[cfg]`
+pub(crate) fn list_module_files(rust_crate_dir: &Path) -> Vec<ModuleFile> {
+    let mut ans = vec![];
+    collect_module_files(&rust_crate_dir.join("src/lib.rs"), true, "", &mut ans);
+    ans
+}
+
+fn collect_module_files(
+    path: &Path,
+    is_mod_rs: bool,
+    module_path: &str,
+    ans: &mut Vec<ModuleFile>,
+) {
+    ans.push(ModuleFile {
+        module_path: module_path.to_owned(),
+        path: path.to_owned(),
+    });
+    // Unreadable files are still listed, such that their changes are noticed
+    if let Some(file) = (fs::read_to_string(path).ok()).and_then(|code| syn::parse_file(&code).ok())
+    {
+        let dirs = ModuleDirs::of_file(path, is_mod_rs);
+        collect_module_files_in_items(&file.items, &dirs, module_path, ans);
+    }
+}
+
+fn collect_module_files_in_items(
+    items: &[Item],
+    dirs: &ModuleDirs,
+    module_path: &str,
+    ans: &mut Vec<ModuleFile>,
+) {
+    for item in items {
+        if let Item::Mod(item_mod) = item {
+            let child_module_path = join_module_path(module_path, &item_mod.ident.to_string());
+            match &item_mod.content {
+                Some((_, items)) => {
+                    let inner_dirs = dirs.of_inline_mod(item_mod);
+                    collect_module_files_in_items(items, &inner_dirs, &child_module_path, ans);
+                }
+                None => {
+                    if let Some((path, is_mod_rs)) = dirs.locate_mod_file(item_mod) {
+                        collect_module_files(&path, is_mod_rs, &child_module_path, ans);
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub(crate) fn join_module_path(parent: &str, name: &str) -> String {
+    if parent.is_empty() {
+        name.to_owned()
+    } else {
+        format!("{parent}::{name}")
+    }
+}
+
+/// Where `mod` declarations inside a (file or inline) module are looked up
+struct ModuleDirs {
+    /// Base of the `#[path]` attributes
+    path_attr_base: PathBuf,
+    /// Base of the `mod something;` declarations without `#[path]`
+    children: PathBuf,
+}
+
+impl ModuleDirs {
+    fn of_file(path: &Path, is_mod_rs: bool) -> Self {
+        let parent = path.parent().unwrap().to_owned();
+        Self {
+            children: if is_mod_rs {
+                parent.clone()
+            } else {
+                parent.join(path.file_stem().unwrap())
+            },
+            path_attr_base: parent,
+        }
+    }
+
+    fn of_inline_mod(&self, item_mod: &syn::ItemMod) -> Self {
+        let dir = (self.children)
+            .join(parse_path_attr(&item_mod.attrs).unwrap_or_else(|| item_mod.ident.to_string()));
+        Self {
+            path_attr_base: dir.clone(),
+            children: dir,
+        }
+    }
+
+    /// The file of a `mod something;` declaration, and whether it is a `mod.rs`-like file
+    fn locate_mod_file(&self, item_mod: &syn::ItemMod) -> Option<(PathBuf, bool)> {
+        if let Some(path_attr) = parse_path_attr(&item_mod.attrs) {
+            return Some((self.path_attr_base.join(path_attr), true));
+        }
+
+        let mod_name = item_mod.ident.to_string();
+        let candidates = [
+            (self.children.join(format!("{mod_name}.rs")), false),
+            (self.children.join(&mod_name).join("mod.rs"), true),
+        ];
+        candidates.into_iter().find(|(path, _)| path.exists())
+    }
+}
+
+fn parse_file(cfg: &CfgEvaluator, path: &Path, is_mod_rs: bool) -> anyhow::Result<syn::File> {
+    let code =
+        fs::read_to_string(path).with_context(|| format!("Could not read module file {path:?}"))?;
+    let mut file =
+        syn::parse_file(&code).with_context(|| format!("Could not parse module file {path:?}"))?;
+
+    resolve_items(cfg, &mut file.items, &ModuleDirs::of_file(path, is_mod_rs))?;
+    Ok(file)
+}
+
+fn resolve_items(
+    cfg: &CfgEvaluator,
+    items: &mut Vec<Item>,
+    dirs: &ModuleDirs,
+) -> anyhow::Result<()> {
+    items.retain_mut(|item| item_attrs_mut(item).map_or(true, |attrs| cfg.process(attrs)));
+
+    for item in items.iter_mut() {
+        match item {
+            Item::Mod(item_mod) => resolve_mod(cfg, item_mod, dirs)?,
+            Item::Impl(item_impl) => (item_impl.items).retain_mut(|item| {
+                impl_item_attrs_mut(item).map_or(true, |attrs| cfg.process(attrs))
+            }),
+            Item::Trait(item_trait) => (item_trait.items).retain_mut(|item| {
+                trait_item_attrs_mut(item).map_or(true, |attrs| cfg.process(attrs))
+            }),
+            Item::Struct(item_struct) => retain_fields(cfg, &mut item_struct.fields),
+            Item::Enum(item_enum) => {
+                item_enum.variants = (std::mem::take(&mut item_enum.variants).into_iter())
+                    .filter_map(|mut variant| cfg.process(&mut variant.attrs).then_some(variant))
+                    .collect();
+                for variant in item_enum.variants.iter_mut() {
+                    retain_fields(cfg, &mut variant.fields);
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+fn resolve_mod(
+    cfg: &CfgEvaluator,
+    item_mod: &mut syn::ItemMod,
+    dirs: &ModuleDirs,
+) -> anyhow::Result<()> {
+    let inner_dirs = dirs.of_inline_mod(item_mod);
+    if let Some((_, items)) = &mut item_mod.content {
+        return resolve_items(cfg, items, &inner_dirs);
+    }
+
+    let Some((mod_path, is_mod_rs)) = dirs.locate_mod_file(item_mod) else {
+        debug!(
+            "Skip parsing {} since do not know its corresponding file path",
+            item_mod.ident
+        );
+        return Ok(());
+    };
+
+    let mod_file = parse_file(cfg, &mod_path, is_mod_rs)?;
+    item_mod.semi = None;
+    item_mod.content = Some((syn::token::Brace::default(), mod_file.items));
+    Ok(())
+}
+
+fn retain_fields(cfg: &CfgEvaluator, fields: &mut Fields) {
+    match fields {
+        Fields::Named(fields) => {
+            fields.named = (std::mem::take(&mut fields.named).into_iter())
+                .filter_map(|mut field| cfg.process(&mut field.attrs).then_some(field))
+                .collect();
+        }
+        Fields::Unnamed(fields) => {
+            fields.unnamed = (std::mem::take(&mut fields.unnamed).into_iter())
+                .filter_map(|mut field| cfg.process(&mut field.attrs).then_some(field))
+                .collect();
+        }
+        Fields::Unit => {}
+    }
+}
+
+fn parse_path_attr(attrs: &[Attribute]) -> Option<String> {
+    attrs.iter().find_map(|attr| match &attr.meta {
+        Meta::NameValue(meta) if meta.path.is_ident("path") => parse_str_lit(&meta.value),
+        _ => None,
+    })
+}
+
+fn parse_str_lit(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(lit), ..
+        }) => Some(lit.value()),
+        _ => None,
+    }
+}
+
+/// Find macro invocations that may generate items, which are invisible without expansion.
+/// The boilerplate macros of the generated code are ignored, since that code is filtered out later.
+/// Attribute macros (other than the built-in attributes) may change the item arbitrarily,
+/// e.g. `#[frb(ui_state)]` adds fields and methods to the struct.
+fn find_macro_generated_item(items: &[Item]) -> Option<String> {
+    fn is_ignored(mac: &syn::Macro) -> bool {
+        let name = mac.path.segments.last().unwrap().ident.to_string();
+        name == "macro_rules" || name.starts_with("frb_generated_")
+    }
+
+    fn macro_name(mac: &syn::Macro) -> String {
+        path_to_string(&mac.path)
+    }
+
+    items.iter().find_map(|item| {
+        if let Some(name) = find_attribute_macro(item_attrs(item)) {
+            return Some(name);
+        }
+        match item {
+            Item::Macro(item_macro) if !is_ignored(&item_macro.mac) => {
+                Some(macro_name(&item_macro.mac))
+            }
+            Item::Mod(item_mod) => {
+                (item_mod.content.as_ref()).and_then(|(_, items)| find_macro_generated_item(items))
+            }
+            Item::Impl(item_impl) => item_impl.items.iter().find_map(|item| match item {
+                ImplItem::Macro(item_macro) if !is_ignored(&item_macro.mac) => {
+                    Some(macro_name(&item_macro.mac))
+                }
+                _ => find_attribute_macro(impl_item_attrs(item)),
+            }),
+            Item::Trait(item_trait) => item_trait.items.iter().find_map(|item| match item {
+                TraitItem::Macro(item_macro) if !is_ignored(&item_macro.mac) => {
+                    Some(macro_name(&item_macro.mac))
+                }
+                _ => find_attribute_macro(trait_item_attrs(item)),
+            }),
+            _ => None,
+        }
+    })
+}
+
+/// Attributes after a `#[derive]` are regarded as its helper attributes (e.g. `#[serde(..)]`),
+/// since attribute macros are not expected there.
+fn find_attribute_macro(attrs: &[Attribute]) -> Option<String> {
+    let mut after_derive = false;
+    for attr in attrs {
+        if is_frb_expanded_attribute(attr) {
+            return Some(attr.meta.to_token_stream().to_string());
+        }
+        after_derive |= attr.path().is_ident("derive");
+        if !(after_derive || is_builtin_attribute(attr)) {
+            return Some(path_to_string(attr.path()));
+        }
+    }
+    None
+}
+
+/// Built-in attributes that `cargo expand` keeps as is (`cfg_attr` is already expanded),
+/// and `#[frb(..)]` that is understood by the parser directly
+fn is_builtin_attribute(attr: &Attribute) -> bool {
+    const BUILTIN_ATTRIBUTES: [&str; 24] = [
+        "allow",
+        "automatically_derived",
+        "cfg",
+        "cold",
+        "deny",
+        "deprecated",
+        "derive",
+        "doc",
+        "expect",
+        "export_name",
+        "forbid",
+        "inline",
+        "link",
+        "link_name",
+        "link_section",
+        "macro_export",
+        "macro_use",
+        "must_use",
+        "no_mangle",
+        "non_exhaustive",
+        "path",
+        "repr",
+        "track_caller",
+        "warn",
+    ];
+    const TOOL_NAMESPACES: [&str; 4] = ["clippy", "diagnostic", "rustdoc", "rustfmt"];
+
+    if is_frb_attribute(attr) {
+        return true;
+    }
+    let path = attr.path();
+    match (path.segments.first(), path.segments.len()) {
+        (Some(segment), 1) => BUILTIN_ATTRIBUTES.iter().any(|name| segment.ident == name),
+        (Some(segment), _) => TOOL_NAMESPACES.iter().any(|name| segment.ident == name),
+        (None, _) => false,
+    }
+}
+
+/// The `#[frb(..)]` that changes the item instead of only guiding the parser, see `frb_macros`
+pub(super) fn is_frb_expanded_attribute(attr: &Attribute) -> bool {
+    const EXPANDED_FRB_ATTRIBUTES: [&str; 2] = ["external", "ui_state"];
+
+    is_frb_attribute(attr)
+        && (attr.meta.require_list().ok())
+            .is_some_and(|list| EXPANDED_FRB_ATTRIBUTES.contains(&&*list.tokens.to_string()))
+}
+
+/// Either `#[frb(..)]` or `#[flutter_rust_bridge::frb(..)]`
+pub(super) fn is_frb_attribute(attr: &Attribute) -> bool {
+    (attr.path().segments.last()).is_some_and(|segment| segment.ident == "frb")
+}
+
+fn path_to_string(path: &syn::Path) -> String {
+    (path.segments.iter())
+        .map(|segment| segment.ident.to_string())
+        .join("::")
+}
+
+/// Evaluate `#[cfg(...)]` in the same way as `cargo expand` does on the host machine
+struct CfgEvaluator {
+    features: HashSet<String>,
+}
+
+impl CfgEvaluator {
+    /// Expand the `#[cfg_attr(...)]`, and return whether the `#[cfg(...)]` are satisfied
+    fn process(&self, attrs: &mut Vec<Attribute>) -> bool {
+        *attrs = (std::mem::take(attrs).into_iter())
+            .flat_map(|attr| self.expand_cfg_attr(attr))
+            .collect();
+        self.is_enabled(attrs)
+    }
+
+    fn expand_cfg_attr(&self, attr: Attribute) -> Vec<Attribute> {
+        if !attr.path().is_ident("cfg_attr") {
+            return vec![attr];
+        }
+        let parsed = attr.parse_args_with(|input: ParseStream| {
+            let predicate: Meta = input.parse()?;
+            input.parse::<Token![,]>()?;
+            let metas = Punctuated::<Meta, Token![,]>::parse_terminated(input)?;
+            Ok((predicate, metas))
+        });
+        match parsed {
+            Ok((predicate, metas)) if self.evaluate(&predicate) => (metas.into_iter())
+                .flat_map(|meta| {
+                    self.expand_cfg_attr(Attribute {
+                        meta,
+                        ..attr.clone()
+                    })
+                })
+                .collect(),
+            Ok(_) => vec![],
+            // Keep it when unsure, thus `cargo expand` will be used since it is not a built-in attribute
+            Err(_) => vec![attr],
+        }
+    }
+
+    fn is_enabled(&self, attrs: &[Attribute]) -> bool {
+        (attrs.iter())
+            .filter(|attr| attr.path().is_ident("cfg"))
+            .all(|attr| match attr.parse_args::<Meta>() {
+                Ok(meta) => self.evaluate(&meta),
+                // Keep the item when unsure, and let the later stages decide
+                Err(_) => true,
+            })
+    }
+
+    fn evaluate(&self, meta: &Meta) -> bool {
+        match meta {
+            Meta::Path(path) => path
+                .get_ident()
+                .is_some_and(|ident| self.evaluate_name(&ident.to_string())),
+            Meta::NameValue(meta) => match (meta.path.get_ident(), parse_str_lit(&meta.value)) {
+                (Some(ident), Some(value)) => self.evaluate_name_value(&ident.to_string(), &value),
+                _ => false,
+            },
+            Meta::List(meta) => {
+                let Ok(nested) =
+                    meta.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+                else {
+                    return false;
+                };
+                let Some(ident) = meta.path.get_ident() else {
+                    return false;
+                };
+                match ident.to_string().as_str() {
+                    "all" => nested.iter().all(|x| self.evaluate(x)),
+                    "any" => nested.iter().any(|x| self.evaluate(x)),
+                    "not" => nested.len() == 1 && !self.evaluate(&nested[0]),
+                    _ => false,
+                }
+            }
+        }
+    }
+
+    fn evaluate_name(&self, name: &str) -> bool {
+        match name {
+            "frb_expand" | "debug_assertions" => true,
+            "unix" | "windows" => consts::FAMILY == name,
+            _ => false,
+        }
+    }
+
+    fn evaluate_name_value(&self, name: &str, value: &str) -> bool {
+        match name {
+            "feature" => self.features.contains(value),
+            "target_os" => consts::OS == value,
+            "target_family" => consts::FAMILY == value,
+            "target_arch" => consts::ARCH == value,
+            "target_pointer_width" => usize::BITS.to_string() == value,
+            "target_endian" => {
+                (if cfg!(target_endian = "little") {
+                    "little"
+                } else {
+                    "big"
+                }) == value
+            }
+            _ => false,
+        }
+    }
+}
+
+/// The requested features (or the `default` feature if not specified),
+/// together with the features they transitively enable
+fn compute_enabled_features(
+    rust_crate_dir: &Path,
+    features: Option<&[String]>,
+) -> anyhow::Result<HashSet<String>> {
+    let manifest = cargo_toml::Manifest::from_path(rust_crate_dir.join("Cargo.toml"))?;
+
+    let mut pending = match features {
+        Some(features) => features.to_vec(),
+        None => vec!["default".to_owned()],
+    };
+    let mut ans = HashSet::new();
+    while let Some(feature) = pending.pop() {
+        if ans.insert(feature.clone()) {
+            pending.extend(
+                (manifest.features.get(&feature).into_iter().flatten())
+                    // Skip dependencies and features of dependencies, e.g. `dep:a` and `a/b`
+                    .filter(|x| !x.contains(':') && !x.contains('/'))
+                    .cloned(),
+            );
+        }
+    }
+    Ok(ans)
+}
+
+fn item_attrs(item: &Item) -> &[Attribute] {
+    match item {
+        Item::Const(x) => &x.attrs,
+        Item::Enum(x) => &x.attrs,
+        Item::ExternCrate(x) => &x.attrs,
+        Item::Fn(x) => &x.attrs,
+        Item::ForeignMod(x) => &x.attrs,
+        Item::Impl(x) => &x.attrs,
+        Item::Macro(x) => &x.attrs,
+        Item::Mod(x) => &x.attrs,
+        Item::Static(x) => &x.attrs,
+        Item::Struct(x) => &x.attrs,
+        Item::Trait(x) => &x.attrs,
+        Item::TraitAlias(x) => &x.attrs,
+        Item::Type(x) => &x.attrs,
+        Item::Union(x) => &x.attrs,
+        Item::Use(x) => &x.attrs,
+        _ => &[],
+    }
+}
+
+fn item_attrs_mut(item: &mut Item) -> Option<&mut Vec<Attribute>> {
+    Some(match item {
+        Item::Const(x) => &mut x.attrs,
+        Item::Enum(x) => &mut x.attrs,
+        Item::ExternCrate(x) => &mut x.attrs,
+        Item::Fn(x) => &mut x.attrs,
+        Item::ForeignMod(x) => &mut x.attrs,
+        Item::Impl(x) => &mut x.attrs,
+        Item::Macro(x) => &mut x.attrs,
+        Item::Mod(x) => &mut x.attrs,
+        Item::Static(x) => &mut x.attrs,
+        Item::Struct(x) => &mut x.attrs,
+        Item::Trait(x) => &mut x.attrs,
+        Item::TraitAlias(x) => &mut x.attrs,
+        Item::Type(x) => &mut x.attrs,
+        Item::Union(x) => &mut x.attrs,
+        Item::Use(x) => &mut x.attrs,
+        _ => return None,
+    })
+}
+
+fn impl_item_attrs(item: &ImplItem) -> &[Attribute] {
+    match item {
+        ImplItem::Const(x) => &x.attrs,
+        ImplItem::Fn(x) => &x.attrs,
+        ImplItem::Type(x) => &x.attrs,
+        ImplItem::Macro(x) => &x.attrs,
+        _ => &[],
+    }
+}
+
+fn impl_item_attrs_mut(item: &mut ImplItem) -> Option<&mut Vec<Attribute>> {
+    Some(match item {
+        ImplItem::Const(x) => &mut x.attrs,
+        ImplItem::Fn(x) => &mut x.attrs,
+        ImplItem::Type(x) => &mut x.attrs,
+        ImplItem::Macro(x) => &mut x.attrs,
+        _ => return None,
+    })
+}
+
+fn trait_item_attrs(item: &TraitItem) -> &[Attribute] {
+    match item {
+        TraitItem::Const(x) => &x.attrs,
+        TraitItem::Fn(x) => &x.attrs,
+        TraitItem::Type(x) => &x.attrs,
+        TraitItem::Macro(x) => &x.attrs,
+        _ => &[],
+    }
+}
+
+fn trait_item_attrs_mut(item: &mut TraitItem) -> Option<&mut Vec<Attribute>> {
+    Some(match item {
+        TraitItem::Const(x) => &mut x.attrs,
+        TraitItem::Fn(x) => &mut x.attrs,
+        TraitItem::Type(x) => &mut x.attrs,
+        TraitItem::Macro(x) => &mut x.attrs,
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::run;
+    use quote::ToTokens;
+    use std::fs;
+
+    #[test]
+    fn test_run() -> anyhow::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let write = |path: &str, content: &str| {
+            let path = dir.path().join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        };
+        write(
+            "Cargo.toml",
+            r#"
+[package]
+name = "hello"
+version = "0.1.0"
+
+[features]
+default = ["a"]
+a = ["b", "dep:serde"]
+b = []
+c = []
+"#,
+        );
+        write(
+            "src/lib.rs",
+            r#"
+mod api;
+mod frb_generated;
+#[path = "elsewhere/renamed.rs"]
+mod custom_path;
+#[cfg(feature = "c")]
+mod missing;
+mod inline {
+    mod nested;
+}
+"#,
+        );
+        write(
+            "src/api/mod.rs",
+            r#"
+mod child;
+#[cfg(all(feature = "b", not(feature = "c")))]
+pub fn enabled() {}
+#[cfg(any(feature = "c", test))]
+pub fn disabled() {}
+pub struct S {
+    pub x: i32,
+    #[cfg(feature = "c")]
+    pub y: i32,
+}
+#[cfg_attr(feature = "b", frb(sync))]
+#[cfg_attr(feature = "c", frb(ignore))]
+pub fn with_cfg_attr() {}
+#[cfg_attr(feature = "c", cfg(feature = "unknown"))]
+pub fn kept_by_cfg_attr() {}
+#[cfg_attr(feature = "b", cfg(feature = "c"))]
+pub fn removed_by_cfg_attr() {}
+#[derive(Clone)]
+#[serde(rename = "U")]
+#[flutter_rust_bridge::frb(opaque)]
+pub struct T {}
+"#,
+        );
+        write("src/api/child.rs", "mod grandchild; pub fn child() {}");
+        write("src/api/child/grandchild.rs", "pub fn grandchild() {}");
+        write("src/elsewhere/renamed.rs", "pub fn custom_path() {}");
+        write("src/inline/nested.rs", "pub fn nested() {}");
+        write(
+            "src/frb_generated.rs",
+            "flutter_rust_bridge::frb_generated_boilerplate!();",
+        );
+
+        let actual = run(dir.path(), None)?
+            .unwrap()
+            .to_token_stream()
+            .to_string();
+        for expect in [
+            "fn enabled",
+            "fn child",
+            "fn grandchild",
+            "fn custom_path",
+            "fn nested",
+            "pub x : i32",
+            "# [frb (sync)] pub fn with_cfg_attr",
+            "fn kept_by_cfg_attr",
+            "pub struct T",
+        ] {
+            assert!(actual.contains(expect), "{expect} not in {actual}");
+        }
+        for unexpected in [
+            "fn disabled",
+            "mod missing",
+            "pub y : i32",
+            "frb (ignore)",
+            "fn removed_by_cfg_attr",
+        ] {
+            assert!(!actual.contains(unexpected), "{unexpected} in {actual}");
+        }
+
+        let actual = (run(dir.path(), Some(&["c".to_owned()]))?.unwrap())
+            .to_token_stream()
+            .to_string();
+        assert!(actual.contains("fn disabled") && actual.contains("pub y : i32"));
+        assert!(!actual.contains("fn enabled"));
+
+        for (code, expect_expanded) in [
+            ("my_macro!(); pub fn custom_path() {}", true),
+            ("#[tokio::main] pub fn custom_path() {}", true),
+            ("#[frb(external)] impl A {}", true),
+            ("#[frb(ui_state)] pub struct A {}", true),
+            ("pub struct A {} impl A { #[my_attr] pub fn f() {} }", true),
+            (
+                "#[cfg_attr(feature = \"b\", my_attr)] pub fn custom_path() {}",
+                true,
+            ),
+            (
+                "#[cfg_attr(feature = \"c\", my_attr)] pub fn custom_path() {}",
+                false,
+            ),
+            ("#[rustfmt::skip] #[inline] pub fn custom_path() {}", false),
+        ] {
+            write("src/elsewhere/renamed.rs", code);
+            let actual = run(dir.path(), None)?;
+            assert_eq!(actual.is_none(), expect_expanded, "{code}");
+        }
+        Ok(())
+    }
+}
@@ -1,6 +1,8 @@
+use crate::codegen::cache::{compute_hash, Cache};
 use crate::codegen::dumper::Dumper;
 use crate::codegen::ConfigDumpContent;
 use crate::command_args;
+use crate::library::commands::cargo_expand::incremental::ExpandedCrate;
 use crate::library::commands::command_runner::execute_command;
 use crate::utils::crate_name::CrateName;
 use anyhow::{bail, Context, Result};
@@ -16,12 +18,65 @@ pub(super) fn run(
     rust_crate_dir: &Path,
     interest_crate_name: Option<&CrateName>,
     dumper: &Dumper,
+    cache: &Cache,
 ) -> Result<syn::File> {
-    let text = run_with_frb_aware(rust_crate_dir, interest_crate_name)?;
+    let text = if cache.is_enabled() {
+        run_with_cache(rust_crate_dir, interest_crate_name, cache)?
+    } else {
+        run_with_frb_aware(rust_crate_dir, interest_crate_name)?
+    };
     (dumper.with_content(ConfigDumpContent::Source)).dump_str("cargo_expand.rs", &text)?;
     Ok(syn::parse_file(&text)?)
 }
 
+fn run_with_cache(
+    rust_crate_dir: &Path,
+    interest_crate_name: Option<&CrateName>,
+    cache: &Cache,
+) -> Result<String> {
+    let crate_name = interest_crate_name.map_or("", |x| x.raw());
+    let name = format!("cargo_expand_{crate_name}");
+    let sources_hash = cache.compute_rust_sources_hash(rust_crate_dir)?;
+
+    // The module files are only known for the current crate
+    if interest_crate_name.is_some() {
+        return cache.get_or_compute(
+            &name,
+            || {
+                Ok(compute_hash([
+                    env!("CARGO_PKG_VERSION"),
+                    crate_name,
+                    &env::var("RUSTFLAGS").unwrap_or_default(),
+                    &sources_hash.overall(),
+                ]))
+            },
+            || run_with_frb_aware(rust_crate_dir, interest_crate_name),
+        );
+    }
+
+    let crate_key = compute_hash([
+        env!("CARGO_PKG_VERSION"),
+        &env::var("RUSTFLAGS").unwrap_or_default(),
+        &sources_hash.crate_hash,
+    ]);
+    if let Some(mut expanded_crate) = (cache.read::<ExpandedCrate>(&name))
+        .filter(|expanded_crate| expanded_crate.crate_key == crate_key)
+    {
+        if let Some(text) = expanded_crate.reassemble(&sources_hash) {
+            debug!("Reuse the cached cargo expand output of unchanged modules");
+            cache.write(&name, &expanded_crate)?;
+            return Ok(text);
+        }
+    }
+
+    let text = run_with_frb_aware(rust_crate_dir, interest_crate_name)?;
+    cache.write(
+        &name,
+        &ExpandedCrate::split(&text, crate_key, &sources_hash)?,
+    )?;
+    Ok(text)
+}
+
 fn run_with_frb_aware(
     rust_crate_dir: &Path,
     interest_crate_name: Option<&CrateName>,
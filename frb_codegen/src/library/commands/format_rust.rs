@@ -5,6 +5,10 @@ use log::debug;
 use std::path::{Path, PathBuf};
 
 pub fn format_rust(paths: &[PathBuf], base_path: &Path) -> anyhow::Result<()> {
+    if paths.is_empty() {
+        return Ok(());
+    }
+
     let paths = prepare_paths(paths, base_path, &[])?;
     debug!("execute format_rust paths={paths:?}");
 
@@ -1 +1,4 @@
 pub(crate) const HANDLER_NAME: &str = "FLUTTER_RUST_BRIDGE_HANDLER";
+
+/// The executor classes which are handled by the executor itself, thus need no thread pool.
+pub(crate) const BUILTIN_EXECUTORS: [&str; 2] = ["dedicated_thread", "priority"];
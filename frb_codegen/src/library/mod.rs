@@ -1,5 +1,6 @@
 //! Code used in `lib.rs`
 
+pub mod api_diff;
 pub mod build_web;
 pub mod codegen;
 pub(crate) mod commands;
@@ -3,7 +3,7 @@ use itertools::Itertools;
 use serde::Serialize;
 use std::ops::AddAssign;
 
-#[derive(Default, Clone, Debug, Serialize)]
+#[derive(Default, Clone, Debug, PartialEq, Serialize)]
 pub(crate) struct DartHeaderCode {
     pub file_top: String,
     pub import: String,
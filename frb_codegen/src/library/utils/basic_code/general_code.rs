@@ -10,7 +10,7 @@ pub(crate) enum GeneralCode {
     C(GeneralCCode),
 }
 
-#[derive(Default, Clone, Debug, Serialize)]
+#[derive(Default, Clone, Debug, PartialEq, Serialize)]
 pub(crate) struct GeneralDartCode {
     pub header: DartHeaderCode,
     pub body: String,
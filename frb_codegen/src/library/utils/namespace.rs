@@ -1,4 +1,3 @@
-use crate::codegen::generator::codec::sse::lang::Lang;
 use crate::utils::crate_name::CrateName;
 use crate::utils::rust_project_utils::compute_mod_from_rust_crate_path;
 use itertools::Itertools;
@@ -142,13 +141,6 @@ impl NamespacedName {
     pub fn rust_style(&self) -> String {
         format!("{}::{}", self.namespace, self.name)
     }
-
-    pub fn style(&self, lang: &Lang) -> String {
-        match lang {
-            Lang::DartLang(_) => self.name.clone(),
-            Lang::RustLang(_) => self.rust_style(),
-        }
-    }
 }
 
 impl Serialize for NamespacedName {
@@ -7,6 +7,7 @@ mod binary;
 
 use crate::binary::commands::{Cli, Commands, CreateOrIntegrateCommandCommonArgs};
 use crate::binary::commands_parser::{compute_codegen_config, compute_codegen_meta_config};
+use anyhow::bail;
 use clap::Parser;
 use lib_flutter_rust_bridge_codegen::integration::{CreateConfig, IntegrateConfig};
 use lib_flutter_rust_bridge_codegen::utils::logs::configure_opinionated_logging;
@@ -28,6 +29,7 @@ fn main_given_cli(cli: Cli) -> anyhow::Result<()> {
             let config = compute_codegen_config(args.primary)?;
             codegen::generate(config, meta_config)?
         }
+        Commands::Check(args) => codegen::check(compute_codegen_config(args.primary)?)?,
         Commands::Create(args) => integration::create(CreateConfig {
             name: args.name,
             org: args.org,
@@ -46,6 +48,13 @@ fn main_given_cli(cli: Cli) -> anyhow::Result<()> {
         Commands::BuildWeb(args) => {
             build_web::build(args.dart_root, args.dart_coverage, args.args)?
         }
+        Commands::ApiDiff(args) => {
+            let report = api_diff::api_diff(&args.old, &args.new)?;
+            print!("{report}");
+            if args.fail_on_breaking && report.has_breaking() {
+                bail!("Found breaking API changes");
+            }
+        }
         Commands::InternalGenerate(_args) => internal::generate()?,
     }
     Ok(())
@@ -0,0 +1,11 @@
+use num_bigint::BigInt;
+
+pub struct Invoice {
+    pub amount: rust_decimal::Decimal,
+    pub discount: Option<rust_decimal::Decimal>,
+    pub serial: BigInt,
+}
+
+pub fn total(invoices: Vec<Invoice>) -> rust_decimal::Decimal {
+    todo!()
+}
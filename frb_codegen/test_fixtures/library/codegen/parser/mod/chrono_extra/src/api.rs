@@ -0,0 +1,10 @@
+pub struct Meeting {
+    pub day: chrono::NaiveDate,
+    pub starts: chrono::NaiveTime,
+    pub at: chrono::DateTime<chrono::FixedOffset>,
+    pub zoned: Option<chrono::DateTime<chrono_tz::Tz>>,
+}
+
+pub fn reschedule(meeting: Meeting, day: Option<chrono::NaiveDate>) -> Vec<chrono::DateTime<chrono::FixedOffset>> {
+    todo!()
+}
@@ -0,0 +1,10 @@
+use flutter_rust_bridge::{frb, DartFnFuture};
+
+#[frb(dart_implementable)]
+pub trait Logger {
+    fn log(&self, message: String) -> DartFnFuture<()>;
+
+    fn level(&self) -> i32;
+}
+
+pub fn set_logger(logger: Box<dyn Logger>) {}
@@ -0,0 +1,28 @@
+pub struct User {
+    pub name: String,
+}
+
+#[frb(instantiate = "Page<User>, Page<i32>")]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub total: i32,
+}
+
+pub fn func_page_user(arg: Page<User>) {}
+
+pub fn func_page_i32(arg: Page<i32>) {}
+
+pub fn func_page_bool(arg: Page<bool>) {}
+
+#[frb(instantiate = "MyEither<User, i32>")]
+pub enum MyEither<L, R> {
+    Left(L),
+    Right(R),
+}
+
+pub fn func_either(arg: MyEither<User, i32>) {}
+
+#[frb(instantiate = "make_page<User>, make_page<i32>")]
+pub fn make_page<T>(total: i32) -> Page<T> {
+    todo!()
+}
@@ -0,0 +1,9 @@
+pub struct Document {
+    pub body: serde_json::Value,
+    pub meta: serde_json::Map<String, serde_json::Value>,
+    pub extra: Option<serde_json::Value>,
+}
+
+pub fn patch(document: Document, patches: Vec<serde_json::Value>) -> serde_json::Value {
+    todo!()
+}
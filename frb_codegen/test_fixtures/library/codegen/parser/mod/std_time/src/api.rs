@@ -0,0 +1,14 @@
+use std::time::Duration;
+
+pub struct Event {
+    pub at: std::time::SystemTime,
+    pub took: Option<std::time::Duration>,
+}
+
+pub fn schedule(delay: std::time::Duration, events: Vec<Event>) -> Vec<std::time::SystemTime> {
+    todo!()
+}
+
+pub fn wait(delay: Duration) -> Option<Duration> {
+    todo!()
+}
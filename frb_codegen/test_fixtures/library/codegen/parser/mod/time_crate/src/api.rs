@@ -0,0 +1,10 @@
+pub struct Booking {
+    pub created: time::OffsetDateTime,
+    pub starts: time::PrimitiveDateTime,
+    pub day: Option<time::Date>,
+    pub length: time::Duration,
+}
+
+pub fn extend(booking: Booking, by: Vec<time::Duration>) -> OffsetDateTime {
+    todo!()
+}
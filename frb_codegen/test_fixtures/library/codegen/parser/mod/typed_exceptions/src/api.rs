@@ -0,0 +1,22 @@
+use flutter_rust_bridge::frb;
+
+#[frb(typed_exceptions)]
+pub enum MyError {
+    NotFound { path: String },
+    Io(String),
+    Unknown,
+}
+
+impl std::fmt::Display for MyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MyError::NotFound { path } => write!(f, "not found: {path}"),
+            MyError::Io(message) => write!(f, "io: {message}"),
+            MyError::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+pub fn f() -> Result<(), MyError> {
+    Ok(())
+}
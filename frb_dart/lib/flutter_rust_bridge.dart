@@ -11,5 +11,7 @@ export 'src/generalized_typed_data/generalized_typed_data.dart'
     show Int64List, Uint64List;
 export 'src/loader/loader.dart' show loadExternalLibrary;
 export 'src/main_components/handler.dart' show BaseHandler;
+export 'src/misc/date_time_with_offset.dart' show DateTimeWithOffset;
+export 'src/misc/rust_cancel_token.dart' show RustCancelToken;
 export 'src/task.dart' show NormalTask, SyncTask;
 export 'src/stream/stream_sink.dart' show RustStreamSink;
@@ -10,6 +10,7 @@ export 'dart:typed_data' hide Int64List, Uint64List;
 export 'package:meta/meta.dart' show internal, protected, sealed;
 
 export 'flutter_rust_bridge.dart';
+export 'src/codec/cse.dart';
 export 'src/codec/cst.dart';
 export 'src/codec/dco.dart';
 export 'src/codec/pde.dart';
@@ -27,5 +28,6 @@ export 'src/misc/box.dart';
 export 'src/misc/rust_opaque.dart';
 export 'src/misc/simple_disposable.dart';
 export 'src/rust_arc/_common.dart';
+export 'src/stream/dart_stream.dart';
 export 'src/stream/stream_sink.dart';
 export 'src/task.dart';
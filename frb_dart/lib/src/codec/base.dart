@@ -1,3 +1,4 @@
+import 'package:flutter_rust_bridge/src/exceptions.dart';
 import 'package:flutter_rust_bridge/src/generalized_frb_rust_binding/generalized_frb_rust_binding.dart';
 import 'package:meta/meta.dart';
 
@@ -29,11 +30,21 @@ abstract class SimpleDecoder<S, E extends Object> {
         return decodeSuccess();
 
       case _Rust2DartAction.error:
-        throw decodeError();
+        final error = decodeError();
+        final rustBacktrace = error is AnyhowException ? error.backtrace : null;
+        if (rustBacktrace != null) {
+          // Put the Rust frames on top of the Dart ones, as if the Rust code were called directly
+          Error.throwWithStackTrace(error,
+              StackTrace.fromString('$rustBacktrace\n${StackTrace.current}'));
+        }
+        throw error;
 
       case _Rust2DartAction.panic:
         throw decodePanic();
 
+      case _Rust2DartAction.lockTimeout:
+        throw decodeLockTimeout();
+
       case _Rust2DartAction.closeStream:
         throw CloseStreamException();
 
@@ -55,6 +66,10 @@ abstract class SimpleDecoder<S, E extends Object> {
   /// {@macro flutter_rust_bridge.internal}
   @protected
   Object decodePanic();
+
+  /// {@macro flutter_rust_bridge.internal}
+  @protected
+  Object decodeLockTimeout();
 }
 
 /// NOTE: Please keep in sync with the Rust side
@@ -64,4 +79,5 @@ class _Rust2DartAction {
   static const error = 1;
   static const closeStream = 2;
   static const panic = 3;
+  static const lockTimeout = 4;
 }
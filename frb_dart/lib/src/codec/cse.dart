@@ -0,0 +1,18 @@
+import 'package:flutter_rust_bridge/src/codec/sse.dart';
+
+/// Codec that does a compact serialization.
+///
+/// It shares the generated decoders with [SseCodec], but integers wider than
+/// one byte (including lengths) are LEB128 varints instead of fixed-width bytes.
+///
+/// {@macro flutter_rust_bridge.only_for_generated_code}
+class CseCodec<S, E extends Object> extends SseCodec<S, E> {
+  /// {@macro flutter_rust_bridge.only_for_generated_code}
+  const CseCodec({
+    required super.decodeSuccessData,
+    required super.decodeErrorData,
+  });
+
+  @override
+  bool get compact => true;
+}
@@ -64,4 +64,10 @@ class _DcoSimpleDecoder<S, E extends Object> extends SimpleDecoder<S, E> {
     assert(rawList.length == 2);
     return dcoDecodePanicError(rawList[1]);
   }
+
+  @override
+  Object decodeLockTimeout() {
+    assert(rawList.length == 2);
+    return dcoDecodeLockTimeoutError(rawList[1]);
+  }
 }
@@ -36,8 +36,12 @@ class SseCodec<S, E extends Object>
   S decodeWireSyncType(WireSyncRust2DartSse raw) =>
       _decode(wireSyncRust2DartSseAsUint8ListView(raw));
 
+  /// Whether the data is in the compact format, see `CseCodec`.
+  bool get compact => false;
+
   S _decode(Uint8List bytes) {
-    final deserializer = SseDeserializer(bytes.buffer.asByteData());
+    final deserializer =
+        SseDeserializer(bytes.buffer.asByteData(), compact: compact);
     final action = deserializer.buffer.getUint8();
     final ans = _SseSimpleDecoder(this, deserializer).decode(action);
     assert(!deserializer.buffer.hasRemaining);
@@ -72,6 +76,9 @@ class _SseSimpleDecoder<S, E extends Object> extends SimpleDecoder<S, E> {
 
   @override
   Object decodePanic() => sseDecodePanicError(deserializer);
+
+  @override
+  Object decodeLockTimeout() => sseDecodeLockTimeoutError(deserializer);
 }
 
 /// {@macro flutter_rust_bridge.only_for_generated_code}
@@ -80,11 +87,30 @@ class SseSerializer {
   final WriteBuffer buffer;
 
   /// {@macro flutter_rust_bridge.only_for_generated_code}
-  SseSerializer(GeneralizedFrbRustBinding binding)
-      : buffer = WriteBuffer(binding: binding);
+  final GeneralizedFrbRustBinding binding;
+
+  static final _standaloneBufferFinalizer =
+      Finalizer<void Function()>((free) => free());
 
   /// {@macro flutter_rust_bridge.only_for_generated_code}
-  WriteBufferRaw intoRaw() => buffer.intoRaw();
+  SseSerializer(this.binding, {bool compact = false})
+      : buffer = WriteBuffer(binding: binding, compact: compact);
+
+  /// {@macro flutter_rust_bridge.only_for_generated_code}
+  ///
+  /// Registers a buffer outside of [buffer] which the serialized data points to.
+  /// Its ownership is handed to Rust together with [buffer] in [intoRaw].
+  /// If that never happens, e.g. when encoding a later argument throws,
+  /// [free] is called once this serializer is garbage collected.
+  /// Thus [free] must not reference this serializer.
+  void addStandaloneBuffer(void Function() free) =>
+      _standaloneBufferFinalizer.attach(this, free, detach: this);
+
+  /// {@macro flutter_rust_bridge.only_for_generated_code}
+  WriteBufferRaw intoRaw() {
+    _standaloneBufferFinalizer.detach(this);
+    return buffer.intoRaw();
+  }
 }
 
 /// {@macro flutter_rust_bridge.only_for_generated_code}
@@ -93,7 +119,8 @@ class SseDeserializer {
   final ReadBuffer buffer;
 
   /// {@macro flutter_rust_bridge.only_for_generated_code}
-  SseDeserializer(ByteData data) : buffer = ReadBuffer(data);
+  SseDeserializer(ByteData data, {bool compact = false})
+      : buffer = ReadBuffer(data, compact: compact);
 }
 
 S _decodeObjectOfOtherType<S>(dynamic raw) {
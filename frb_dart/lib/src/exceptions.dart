@@ -13,18 +13,62 @@ class PanicException implements FrbException {
   String toString() => 'PanicException($message)';
 }
 
+/// Borrowing a `RustAutoOpaque` object took longer than the lock timeout,
+/// which is usually caused by a deadlock.
+///
+/// The Rust side panics in this case, thus it is also a [PanicException].
+/// See `set_rust_auto_opaque_lock_timeout` on the Rust side.
+class RustAutoOpaqueLockTimeoutException extends PanicException {
+  /// The Rust function whose argument was being borrowed, if known
+  final String? functionName;
+
+  /// The argument being borrowed, if known
+  final String? argumentName;
+
+  /// The lock timeout that was exceeded
+  final Duration timeout;
+
+  /// Borrowing a `RustAutoOpaque` object took longer than the lock timeout
+  RustAutoOpaqueLockTimeoutException(super.message,
+      {this.functionName, this.argumentName, required this.timeout});
+
+  @override
+  String toString() => 'RustAutoOpaqueLockTimeoutException($message)';
+}
+
 /// The rust code returns `anyhow::Error`
 class AnyhowException implements FrbException {
   /// The error message
   final String message;
 
+  /// The messages of the chain of causes, from the outermost context to the root cause.
+  final List<String> causes;
+
+  /// The backtrace captured by `anyhow`, if any.
+  ///
+  /// Only available when the Rust side enables the `backtrace` feature.
+  final String? backtrace;
+
+  /// The name of a well-known Rust type that the error can be downcast to, such as `std::io::error::Error`.
+  final String? typeName;
+
   /// The rust code returns `anyhow::Error`
-  AnyhowException(this.message);
+  AnyhowException(this.message,
+      {this.causes = const [], this.backtrace, this.typeName});
 
   @override
   String toString() => 'AnyhowException($message)';
 }
 
+/// The Rust function call is cancelled via a `RustCancelToken`
+class RustCancelledException implements FrbException {
+  /// Constructs an exception
+  const RustCancelledException();
+
+  @override
+  String toString() => 'RustCancelledException';
+}
+
 /// Interface indicating exceptions that have backtrace (stack trace)
 abstract class FrbBacktracedException extends FrbException {
   /// The backtrace (stack trace) of the exception
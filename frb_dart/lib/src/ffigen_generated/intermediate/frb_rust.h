@@ -52,6 +52,10 @@ uint8_t *rust_vec_u8_resize(uint8_t *ptr, int32_t old_len, int32_t new_len);
 
 void rust_vec_u8_free(uint8_t *ptr, int32_t len);
 
+void frb_cancel_task(MessagePort port);
+
+void frb_stream_sink_ack(MessagePort port, int32_t count);
+
 /**
  * # Safety
  *
@@ -397,6 +397,36 @@ class MultiPackageCBinding {
   late final _rust_vec_u8_free = _rust_vec_u8_freePtr
       .asFunction<void Function(ffi.Pointer<ffi.Uint8>, int)>();
 
+  void frb_cancel_task(
+    int port,
+  ) {
+    return _frb_cancel_task(
+      port,
+    );
+  }
+
+  late final _frb_cancel_taskPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(MessagePort)>>(
+          'frb_cancel_task');
+  late final _frb_cancel_task =
+      _frb_cancel_taskPtr.asFunction<void Function(int)>();
+
+  void frb_stream_sink_ack(
+    int port,
+    int count,
+  ) {
+    return _frb_stream_sink_ack(
+      port,
+      count,
+    );
+  }
+
+  late final _frb_stream_sink_ackPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(MessagePort, ffi.Int32)>>(
+          'frb_stream_sink_ack');
+  late final _frb_stream_sink_ack =
+      _frb_stream_sink_ackPtr.asFunction<void Function(int, int)>();
+
   /// # Safety
   ///
   /// This function should never be called manually.
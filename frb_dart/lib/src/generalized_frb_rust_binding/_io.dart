@@ -93,6 +93,14 @@ class GeneralizedFrbRustBinding {
   void freeWireSyncRust2DartSse(WireSyncRust2DartSse val) =>
       _binding.free_wire_sync_rust2dart_sse(val);
 
+  /// {@macro flutter_rust_bridge.only_for_generated_code}
+  void cancelTask({required NativePortType port}) =>
+      _binding.frb_cancel_task(port);
+
+  /// {@macro flutter_rust_bridge.only_for_generated_code}
+  void streamSinkAck({required NativePortType port, required int count}) =>
+      _binding.frb_stream_sink_ack(port, count);
+
   /// {@macro flutter_rust_bridge.only_for_generated_code}
   ffi.Pointer<ffi.Uint8> rustVecU8New(int len) => _binding.rust_vec_u8_new(len);
 
@@ -68,6 +68,16 @@ class GeneralizedFrbRustBinding {
 
   /// {@macro flutter_rust_bridge.only_for_generated_code}
   void freeWireSyncRust2DartSse(WireSyncRust2DartSse raw) {}
+
+  /// {@macro flutter_rust_bridge.only_for_generated_code}
+  ///
+  /// Cancellation does not reach Rust on web yet, so nothing to do.
+  void cancelTask({required NativePortType port}) {}
+
+  /// {@macro flutter_rust_bridge.only_for_generated_code}
+  ///
+  /// Bounded stream sinks behave as unbounded ones on web, so nothing to do.
+  void streamSinkAck({required NativePortType port, required int count}) {}
 }
 
 /// {@macro flutter_rust_bridge.only_for_generated_code}
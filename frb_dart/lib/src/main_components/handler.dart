@@ -4,17 +4,30 @@ import 'package:flutter_rust_bridge/src/dart_opaque/dart_opaque.dart';
 import 'package:flutter_rust_bridge/src/exceptions.dart';
 import 'package:flutter_rust_bridge/src/generalized_frb_rust_binding/generalized_frb_rust_binding.dart';
 import 'package:flutter_rust_bridge/src/generalized_isolate/generalized_isolate.dart';
+import 'package:flutter_rust_bridge/src/misc/rust_cancel_token.dart';
 import 'package:flutter_rust_bridge/src/task.dart';
 import 'package:flutter_rust_bridge/src/utils/single_complete_port.dart';
 
 /// Generically handles a Dart-Rust call.
 class BaseHandler {
   /// Execute a normal ffi call. Usually called by generated code instead of manually called.
+  ///
+  /// If called inside [RustCancelToken.run], the call is cancelled together with the token.
   Future<S> executeNormal<S, E extends Object>(NormalTask<S, E> task) {
+    final cancelToken = RustCancelToken.current;
+    if (cancelToken != null && cancelToken.isCancelled) {
+      return Future.error(const RustCancelledException());
+    }
+
     final completer = Completer<dynamic>();
     final SendPort sendPort = singleCompletePort(completer);
     task.callFfi(sendPort.nativePort);
-    return completer.future.then(task.codec.decodeObject);
+    final future = completer.future.then(task.codec.decodeObject);
+
+    if (cancelToken == null) return future;
+    return cancelToken.guard(future,
+        onCancel: () => task.apiImpl.generalizedFrbRustBinding
+            .cancelTask(port: sendPort.nativePort));
   }
 
   /// Similar to [executeNormal], except that this will return synchronously
@@ -10,6 +10,16 @@ import 'package:flutter_rust_bridge/src/exceptions.dart';
 PanicException dcoDecodePanicError(dynamic raw) =>
     PanicException(raw as String);
 
+/// {@macro flutter_rust_bridge.only_for_generated_code}
+RustAutoOpaqueLockTimeoutException dcoDecodeLockTimeoutError(dynamic raw) {
+  final [message, functionName, argumentName, timeoutMicros] =
+      raw as List<dynamic>;
+  return RustAutoOpaqueLockTimeoutException(message as String,
+      functionName: functionName as String?,
+      argumentName: argumentName as String?,
+      timeout: Duration(microseconds: timeoutMicros as int));
+}
+
 /// {@macro flutter_rust_bridge.only_for_generated_code}
 DateTime dcoDecodeTimestamp({required int ts, required bool isUtc}) {
   if (kIsWeb) {
@@ -27,9 +37,26 @@ Duration dcoDecodeDuration(int ts) {
 }
 
 /// {@macro flutter_rust_bridge.only_for_generated_code}
-PanicException sseDecodePanicError(SseDeserializer deserializer) {
+PanicException sseDecodePanicError(SseDeserializer deserializer) =>
+    PanicException(_sseDecodeString(deserializer));
+
+/// {@macro flutter_rust_bridge.only_for_generated_code}
+RustAutoOpaqueLockTimeoutException sseDecodeLockTimeoutError(
+    SseDeserializer deserializer) {
+  final message = _sseDecodeString(deserializer);
+  final hasSite = deserializer.buffer.getUint8() != 0;
+  final functionName = hasSite ? _sseDecodeString(deserializer) : null;
+  final argumentName = hasSite ? _sseDecodeString(deserializer) : null;
+  final timeoutMicros = deserializer.buffer.getInt64();
+  return RustAutoOpaqueLockTimeoutException(message,
+      functionName: functionName,
+      argumentName: argumentName,
+      timeout: Duration(microseconds: timeoutMicros));
+}
+
+String _sseDecodeString(SseDeserializer deserializer) {
   // NOTE copied from auto-generated SSE deserialization code
   final len = deserializer.buffer.getInt32();
   final inner = deserializer.buffer.getUint8List(len);
-  return PanicException(utf8.decoder.convert(inner));
+  return utf8.decoder.convert(inner);
 }
@@ -1,6 +1,8 @@
 import 'dart:ffi';
 
+import 'package:flutter_rust_bridge/src/codec/sse.dart';
 import 'package:flutter_rust_bridge/src/dart_c_object_into_dart/_io.dart';
+import 'package:flutter_rust_bridge/src/generalized_uint8list/rust_vec_u8.dart';
 import 'package:flutter_rust_bridge/src/platform_types/_io.dart';
 import 'package:flutter_rust_bridge/src/generalized_typed_data/_io.dart';
 import 'dart:typed_data' as $data;
@@ -28,3 +30,27 @@ int sseEncodeCastedPrimitiveI64(int raw) => raw;
 
 /// {@macro flutter_rust_bridge.only_for_generated_code}
 BigInt sseEncodeCastedPrimitiveU64(int raw) => BigInt.from(raw);
+
+/// Lists at least this large are passed in standalone buffers.
+/// For smaller ones, the overhead of allocation is larger than the copying.
+const _kSseStandaloneUint8ListMinLength = 64 * 1024;
+
+/// {@macro flutter_rust_bridge.only_for_generated_code}
+void sseEncodeUint8List($data.Uint8List raw, SseSerializer serializer) {
+  if (raw.length < _kSseStandaloneUint8ListMinLength) {
+    serializer.buffer.putUint8(0);
+    serializer.buffer.putUint8List(raw);
+    return;
+  }
+
+  // Copy once into a Rust `Vec<u8>`, which is then taken by Rust without copying.
+  // Until `intoRaw`, the serializer owns it and frees it if the call never happens.
+  final vec = RustVecU8(raw.length, serializer.binding)
+    ..setRange(0, raw.length, raw);
+  final vecRaw = vec.intoRaw();
+  final binding = serializer.binding;
+  serializer.addStandaloneBuffer(
+      () => binding.rustVecU8Free(vecRaw.ptr, vecRaw.length));
+  serializer.buffer.putUint8(1);
+  serializer.buffer.putBigUint64(BigInt.from(vecRaw.ptr.address));
+}
@@ -1,3 +1,6 @@
+import 'dart:typed_data' as $data;
+
+import 'package:flutter_rust_bridge/src/codec/sse.dart';
 import 'package:flutter_rust_bridge/src/generalized_typed_data/_web.dart';
 import 'package:flutter_rust_bridge/src/platform_types/_web.dart';
 import 'package:flutter_rust_bridge/src/platform_utils/_web.dart';
@@ -28,3 +31,10 @@ BigInt sseEncodeCastedPrimitiveI64(int raw) => BigInt.from(raw);
 
 /// {@macro flutter_rust_bridge.only_for_generated_code}
 BigInt sseEncodeCastedPrimitiveU64(int raw) => BigInt.from(raw);
+
+/// {@macro flutter_rust_bridge.only_for_generated_code}
+void sseEncodeUint8List($data.Uint8List raw, SseSerializer serializer) {
+  // Standalone buffers are not supported, since Rust cannot access JavaScript memory
+  serializer.buffer.putUint8(0);
+  serializer.buffer.putUint8List(raw);
+}
@@ -0,0 +1,86 @@
+import 'package:meta/meta.dart';
+
+/// A point in time together with the UTC offset (and optionally the time zone name)
+/// it was observed in, such as Rust's `chrono::DateTime<FixedOffset>`.
+///
+/// Dart's [DateTime] can only be UTC or local time, so the offset is kept separately.
+@immutable
+class DateTimeWithOffset {
+  static final _pattern = RegExp(
+    r'^(.+?)(Z|[+-]\d{2}:\d{2}(?::\d{2})?)(?:\[([^\]]+)\])?$',
+    caseSensitive: false,
+  );
+
+  /// The point in time, always in UTC
+  final DateTime utc;
+
+  /// The offset from UTC, e.g. `Duration(hours: 8)` for `+08:00`
+  final Duration offset;
+
+  /// The IANA time zone name, e.g. `Asia/Shanghai`, if known
+  final String? timeZone;
+
+  /// Creates an instance from the given point in time, which is converted to UTC.
+  DateTimeWithOffset(DateTime dateTime, this.offset, {this.timeZone})
+      : utc = dateTime.toUtc();
+
+  /// The local date and time at [offset].
+  ///
+  /// The returned value has `isUtc == true`, but its fields are the local ones.
+  DateTime get wallClock => utc.add(offset);
+
+  /// Parses an RFC 3339 string, optionally followed by an RFC 9557 time zone suffix,
+  /// e.g. `2024-01-02T03:04:05.000006+08:00[Asia/Shanghai]`.
+  static DateTimeWithOffset parse(String raw) {
+    final match = _pattern.firstMatch(raw);
+    if (match == null) {
+      throw FormatException('Invalid date time with offset', raw);
+    }
+    final offset = _parseOffset(match.group(2)!);
+    final wallClock = DateTime.parse('${match.group(1)!}Z');
+    return DateTimeWithOffset(wallClock.subtract(offset), offset,
+        timeZone: match.group(3));
+  }
+
+  static Duration _parseOffset(String raw) {
+    if (raw.toUpperCase() == 'Z') return Duration.zero;
+    final parts = raw.substring(1).split(':').map(int.parse).toList();
+    final offset = Duration(
+      hours: parts[0],
+      minutes: parts[1],
+      seconds: parts.length > 2 ? parts[2] : 0,
+    );
+    return raw.startsWith('-') ? -offset : offset;
+  }
+
+  @override
+  String toString() {
+    final w = wallClock;
+    final date = '${_pad(w.year, 4)}-${_pad(w.month, 2)}-${_pad(w.day, 2)}';
+    final fraction = _pad(w.millisecond * 1000 + w.microsecond, 6);
+    final time =
+        '${_pad(w.hour, 2)}:${_pad(w.minute, 2)}:${_pad(w.second, 2)}.$fraction';
+    final suffix = timeZone == null ? '' : '[$timeZone]';
+    return '${date}T$time${_formatOffset(offset)}$suffix';
+  }
+
+  static String _formatOffset(Duration offset) {
+    final sign = offset.isNegative ? '-' : '+';
+    final seconds = offset.inSeconds.abs();
+    final ans = '$sign${_pad(seconds ~/ 3600, 2)}:${_pad(seconds ~/ 60 % 60, 2)}';
+    return seconds % 60 == 0 ? ans : '$ans:${_pad(seconds % 60, 2)}';
+  }
+
+  static String _pad(int value, int width) =>
+      value.toString().padLeft(width, '0');
+
+  @override
+  bool operator ==(Object other) =>
+      other is DateTimeWithOffset &&
+      utc == other.utc &&
+      offset == other.offset &&
+      timeZone == other.timeZone;
+
+  @override
+  int get hashCode => Object.hash(utc, offset, timeZone);
+}
@@ -0,0 +1,76 @@
+import 'dart:async';
+
+import 'package:async/async.dart';
+import 'package:flutter_rust_bridge/src/exceptions.dart';
+import 'package:meta/meta.dart';
+
+/// Cancels the in-flight Rust function calls that are started inside [run].
+///
+/// Once cancelled, the Dart futures of those calls complete with [RustCancelledException].
+/// On the Rust side, `async` functions are dropped at their next `.await` point,
+/// while other functions can check `CancellationToken::is_cancelled` cooperatively.
+/// Synchronous (`#[frb(sync)]`) calls are not affected.
+class RustCancelToken {
+  static const _zoneKey = #flutterRustBridgeCancelToken;
+
+  final _listeners = <void Function()>{};
+  var _isCancelled = false;
+
+  /// Whether [cancel] has been called
+  bool get isCancelled => _isCancelled;
+
+  /// Runs [body], and associates Rust calls made inside it
+  /// (including the ones after `await`) with this token.
+  R run<R>(R Function() body) =>
+      runZoned(body, zoneValues: {_zoneKey: this});
+
+  /// Cancels the associated Rust calls that are not finished yet,
+  /// and makes future calls inside [run] fail immediately.
+  void cancel() {
+    if (_isCancelled) return;
+    _isCancelled = true;
+    final listeners = _listeners.toList();
+    _listeners.clear();
+    for (final listener in listeners) {
+      listener();
+    }
+  }
+
+  /// Runs [body] as a [CancelableOperation],
+  /// such that cancelling the operation cancels the Rust calls inside it.
+  static CancelableOperation<T> cancelable<T>(Future<T> Function() body) {
+    final token = RustCancelToken();
+    return CancelableOperation.fromFuture(token.run(body),
+        onCancel: token.cancel);
+  }
+
+  /// {@macro flutter_rust_bridge.internal}
+  @internal
+  static RustCancelToken? get current =>
+      Zone.current[_zoneKey] as RustCancelToken?;
+
+  /// {@macro flutter_rust_bridge.internal}
+  @internal
+  Future<T> guard<T>(Future<T> future,
+      {required void Function() onCancel}) {
+    final completer = Completer<T>();
+
+    void listener() {
+      onCancel();
+      if (!completer.isCompleted) {
+        completer.completeError(const RustCancelledException());
+      }
+    }
+
+    _listeners.add(listener);
+    future.then((value) {
+      _listeners.remove(listener);
+      if (!completer.isCompleted) completer.complete(value);
+    }, onError: (Object error, StackTrace stackTrace) {
+      _listeners.remove(listener);
+      if (!completer.isCompleted) completer.completeError(error, stackTrace);
+    });
+
+    return completer.future;
+  }
+}
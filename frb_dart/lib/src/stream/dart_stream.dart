@@ -0,0 +1,27 @@
+import 'dart:async';
+
+import 'package:async/async.dart';
+
+/// {@macro flutter_rust_bridge.only_for_generated_code}
+///
+/// Converts a Dart [Stream] into a function that the Rust `DartStream<T>` pulls items from.
+/// Each call returns the next item, or `null` when the stream is done,
+/// and an error event of the stream is thrown so that Rust receives it as an `Err` item.
+/// The stream is paused while Rust is not asking for items,
+/// and it is cancelled when Rust calls it with `cancel` being true.
+Future<T?> Function(bool cancel) encodeDartStream<T>(Stream<T> stream) {
+  final queue = StreamQueue(stream);
+  var cancelled = false;
+
+  return (cancel) async {
+    if (cancelled) return null;
+    if (cancel) {
+      cancelled = true;
+      await queue.cancel(immediate: true);
+      return null;
+    }
+
+    if (!await queue.hasNext) return null;
+    return await queue.next;
+  };
+}
@@ -2,17 +2,30 @@ import 'dart:async';
 
 import 'package:async/async.dart';
 import 'package:flutter_rust_bridge/src/codec/base.dart';
+import 'package:flutter_rust_bridge/src/generalized_frb_rust_binding/generalized_frb_rust_binding.dart';
 import 'package:flutter_rust_bridge/src/generalized_isolate/generalized_isolate.dart';
 import 'package:flutter_rust_bridge/src/utils/port_generator.dart';
 
 /// The Rust `StreamSink<T>` on the Dart side.
 class RustStreamSink<T> {
+  final int? _capacity;
+  final GeneralizedFrbRustBinding? _binding;
   _State<T>? _state;
 
+  /// {@macro flutter_rust_bridge.only_for_generated_code}
+  ///
+  /// When [capacity] is given, Rust may only send that many items
+  /// before they are consumed, and each consumed item is acknowledged
+  /// via [binding].
+  RustStreamSink({int? capacity, GeneralizedFrbRustBinding? binding})
+      : _capacity = capacity,
+        _binding = binding;
+
   /// {@macro flutter_rust_bridge.only_for_generated_code}
   String setupAndSerialize({required BaseCodec<T, dynamic, dynamic> codec}) {
-    _state ??= _setup(codec);
-    return serializeNativePort(_state!.receivePort.sendPort.nativePort);
+    _state ??= _setup(codec, _capacity != null ? _binding : null);
+    final port = serializeNativePort(_state!.receivePort.sendPort.nativePort);
+    return _capacity == null ? port : '$port,$_capacity';
   }
 
   /// The Dart stream for the Rust sink
@@ -26,7 +39,8 @@ class _State<T> {
   const _State(this.receivePort, this.stream);
 }
 
-_State<T> _setup<T>(BaseCodec<T, dynamic, dynamic> codec) {
+_State<T> _setup<T>(
+    BaseCodec<T, dynamic, dynamic> codec, GeneralizedFrbRustBinding? binding) {
   final portName = ExecuteStreamPortGenerator.create('RustStreamSink');
   final receivePort = broadcastPort(portName);
 
@@ -37,6 +51,11 @@ _State<T> _setup<T>(BaseCodec<T, dynamic, dynamic> codec) {
           yield codec.decodeObject(raw);
         } on CloseStreamException {
           break;
+        } catch (e, s) {
+          // An error event (e.g. `add_error` in Rust) ends the stream as usual,
+          // except for bounded streams, where it is acknowledged like other items
+          if (binding == null) rethrow;
+          yield* Stream.error(e, s);
         }
       }
     } finally {
@@ -44,7 +63,53 @@ _State<T> _setup<T>(BaseCodec<T, dynamic, dynamic> codec) {
     }
   }();
 
-  final stream = rawStream.listenAndBuffer();
+  var stream = rawStream.listenAndBuffer();
+  if (binding != null) {
+    final port = receivePort.sendPort.nativePort;
+    stream = stream.transform(_acknowledge(
+        (count) => binding.streamSinkAck(port: port, count: count)));
+  }
 
   return _State(receivePort, stream);
 }
+
+/// Tells Rust each time an item (or error) is consumed,
+/// and sends a negative count when Dart stops listening.
+///
+/// Error events are forwarded without ending the stream, like other items.
+StreamTransformer<T, T> _acknowledge<T>(void Function(int count) ack) =>
+    StreamTransformer.fromBind((stream) {
+      late StreamSubscription<T> subscription;
+      var stopped = false;
+      void stop() {
+        if (stopped) return;
+        stopped = true;
+        ack(-1);
+      }
+
+      final controller = StreamController<T>(sync: true);
+      controller
+        ..onListen = () {
+          subscription = stream.listen(
+            (item) {
+              controller.add(item);
+              ack(1);
+            },
+            onError: (Object e, StackTrace s) {
+              controller.addError(e, s);
+              ack(1);
+            },
+            onDone: () {
+              stop();
+              controller.close();
+            },
+          );
+        }
+        ..onPause = (() => subscription.pause())
+        ..onResume = (() => subscription.resume())
+        ..onCancel = () {
+          stop();
+          return subscription.cancel();
+        };
+      return controller.stream;
+    });
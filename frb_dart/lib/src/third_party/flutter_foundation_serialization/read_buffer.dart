@@ -8,13 +8,18 @@ import 'package:flutter_rust_bridge/src/generalized_typed_data/generalized_typed
 /// Read-only buffer for reading sequentially from a [ByteData] instance.
 ///
 /// The byte order used is [Endian.host] throughout.
+///
+/// If [compact] is true, the data is in the compact format of `WriteBuffer`.
 class ReadBuffer {
   /// Creates a [ReadBuffer] for reading from the specified [data].
-  ReadBuffer(this.data);
+  ReadBuffer(this.data, {this.compact = false});
 
   /// The underlying data being read.
   final ByteData data;
 
+  /// Whether the data is in the compact format.
+  final bool compact;
+
   /// The position to read next.
   int _position = 0;
 
@@ -28,6 +33,7 @@ class ReadBuffer {
 
   /// Reads a Uint16 from the buffer.
   int getUint16({Endian? endian}) {
+    if (compact) return _getVarUint();
     final int value = data.getUint16(_position, endian ?? Endian.host);
     _position += 2;
     return value;
@@ -35,6 +41,7 @@ class ReadBuffer {
 
   /// Reads a Uint32 from the buffer.
   int getUint32({Endian? endian}) {
+    if (compact) return _getVarUint();
     final int value = data.getUint32(_position, endian ?? Endian.host);
     _position += 4;
     return value;
@@ -45,6 +52,7 @@ class ReadBuffer {
 
   /// Reads a Uint64 from the buffer.
   BigInt getBigUint64({Endian? endian}) {
+    if (compact) return _getVarBigUint();
     final value = byteDataGetUint64(data, _position, endian ?? Endian.host);
     _position += 8;
     return value;
@@ -58,6 +66,7 @@ class ReadBuffer {
 
   /// Reads an Int16 from the buffer.
   int getInt16({Endian? endian}) {
+    if (compact) return _unzigzag(_getVarUint());
     final int value = data.getInt16(_position, endian ?? Endian.host);
     _position += 2;
     return value;
@@ -65,6 +74,7 @@ class ReadBuffer {
 
   /// Reads an Int32 from the buffer.
   int getInt32({Endian? endian}) {
+    if (compact) return _unzigzag(_getVarUint());
     final int value = data.getInt32(_position, endian ?? Endian.host);
     _position += 4;
     return value;
@@ -75,6 +85,10 @@ class ReadBuffer {
 
   /// Reads an Int64 from the buffer.
   BigInt getBigInt64({Endian? endian}) {
+    if (compact) {
+      final value = _getVarBigUint();
+      return value.isEven ? value >> 1 : -((value + BigInt.one) >> 1);
+    }
     final value = byteDataGetInt64(data, _position, endian ?? Endian.host);
     _position += 8;
     return value;
@@ -109,11 +123,25 @@ class ReadBuffer {
 
   /// Reads the given number of Uint16s from the buffer.
   Uint16List getUint16List(int length) {
+    if (compact) {
+      final ans = Uint16List(length);
+      for (var i = 0; i < length; ++i) {
+        ans[i] = getUint16();
+      }
+      return ans;
+    }
     return getUint8List(length * 2).buffer.asUint16List();
   }
 
   /// Reads the given number of Uint32s from the buffer.
   Uint32List getUint32List(int length) {
+    if (compact) {
+      final ans = Uint32List(length);
+      for (var i = 0; i < length; ++i) {
+        ans[i] = getUint32();
+      }
+      return ans;
+    }
     return getUint8List(length * 4).buffer.asUint32List();
   }
 
@@ -133,11 +161,25 @@ class ReadBuffer {
 
   /// Reads the given number of Int16s from the buffer.
   Int16List getInt16List(int length) {
+    if (compact) {
+      final ans = Int16List(length);
+      for (var i = 0; i < length; ++i) {
+        ans[i] = getInt16();
+      }
+      return ans;
+    }
     return getUint8List(length * 2).buffer.asInt16List();
   }
 
   /// Reads the given number of Int32s from the buffer.
   Int32List getInt32List(int length) {
+    if (compact) {
+      final ans = Int32List(length);
+      for (var i = 0; i < length; ++i) {
+        ans[i] = getInt32();
+      }
+      return ans;
+    }
     return getUint8List(length * 4).buffer.asInt32List();
   }
 
@@ -160,6 +202,33 @@ class ReadBuffer {
     return getUint8List(length * 8).buffer.asFloat64List();
   }
 
+  // NOTE ADD for the compact format
+  // Use arithmetic instead of bitwise operations, since the latter is 32-bit on the web
+  int _getVarUint() {
+    var ans = 0;
+    var multiplier = 1;
+    while (true) {
+      final byte = getUint8();
+      ans += byte % 0x80 * multiplier;
+      if (byte < 0x80) return ans;
+      multiplier *= 0x80;
+    }
+  }
+
+  BigInt _getVarBigUint() {
+    var ans = BigInt.zero;
+    var shift = 0;
+    while (true) {
+      final byte = getUint8();
+      ans |= BigInt.from(byte & 0x7f) << shift;
+      if (byte < 0x80) return ans;
+      shift += 7;
+    }
+  }
+
+  static int _unzigzag(int value) =>
+      value.isEven ? value ~/ 2 : -((value + 1) ~/ 2);
+
 // NOTE MODIFIED try remove this to simplify rust side
 // void _alignTo(int alignment) {
 //   final int mod = _position % alignment;
@@ -10,27 +10,38 @@ import 'package:flutter_rust_bridge/src/generalized_uint8list/generalized_uint8l
 
 // NOTE MAIN MODIFICATION:
 // * Uint8List -> BaseGeneralizedUint8List
+// * Add the compact format
 /// Write-only buffer for incrementally building a [ByteData] instance.
 ///
 /// A WriteBuffer instance can be used only once. Attempts to reuse will result
 /// in [StateError]s being thrown.
 ///
 /// The byte order used is [Endian.host] throughout.
+///
+/// If [compact] is true, integers wider than one byte are written as LEB128
+/// varints (zigzag-encoded if signed), and the lists of them are packed
+/// varints as well.
 class WriteBuffer {
   /// Creates an interface for incrementally building a [ByteData] instance.
   /// [startCapacity] determines the start size of the [WriteBuffer] in bytes.
   /// The closer that value is to the real size used, the better the
   /// performance.
   factory WriteBuffer(
-      {int startCapacity = 8, required GeneralizedFrbRustBinding binding}) {
+      {int startCapacity = 8,
+      required GeneralizedFrbRustBinding binding,
+      bool compact = false}) {
     assert(startCapacity > 0);
     final ByteData eightBytes = ByteData(8);
     final Uint8List eightBytesAsList = eightBytes.buffer.asUint8List();
     return WriteBuffer._(PlatformGeneralizedUint8List(startCapacity, binding),
-        eightBytes, eightBytesAsList);
+        eightBytes, eightBytesAsList, compact);
   }
 
-  WriteBuffer._(this._buffer, this._eightBytes, this._eightBytesAsList);
+  WriteBuffer._(
+      this._buffer, this._eightBytes, this._eightBytesAsList, this.compact);
+
+  /// Whether to use the compact format.
+  final bool compact;
 
   final BaseGeneralizedUint8List _buffer;
   int _currentSize = 0;
@@ -84,6 +95,7 @@ class WriteBuffer {
   /// Write a Uint16 into the buffer.
   void putUint16(int value, {Endian? endian}) {
     assert(!_isDone);
+    if (compact) return _putVarUint(value);
     _eightBytes.setUint16(0, value, endian ?? Endian.host);
     _addAll(_eightBytesAsList, 0, 2);
   }
@@ -91,6 +103,7 @@ class WriteBuffer {
   /// Write a Uint32 into the buffer.
   void putUint32(int value, {Endian? endian}) {
     assert(!_isDone);
+    if (compact) return _putVarUint(value);
     _eightBytes.setUint32(0, value, endian ?? Endian.host);
     _addAll(_eightBytesAsList, 0, 4);
   }
@@ -103,6 +116,7 @@ class WriteBuffer {
   /// Write a Uint64 into the buffer.
   void putBigUint64(BigInt value, {Endian? endian}) {
     assert(!_isDone);
+    if (compact) return _putVarBigUint(value);
     byteDataSetUint64(_eightBytes, 0, value, endian ?? Endian.host);
     _addAll(_eightBytesAsList, 0, 8);
   }
@@ -117,6 +131,7 @@ class WriteBuffer {
   /// Write an Int16 into the buffer.
   void putInt16(int value, {Endian? endian}) {
     assert(!_isDone);
+    if (compact) return _putVarUint(_zigzag(value));
     _eightBytes.setInt16(0, value, endian ?? Endian.host);
     _addAll(_eightBytesAsList, 0, 2);
   }
@@ -124,6 +139,7 @@ class WriteBuffer {
   /// Write an Int32 into the buffer.
   void putInt32(int value, {Endian? endian}) {
     assert(!_isDone);
+    if (compact) return _putVarUint(_zigzag(value));
     _eightBytes.setInt32(0, value, endian ?? Endian.host);
     _addAll(_eightBytesAsList, 0, 4);
   }
@@ -136,6 +152,11 @@ class WriteBuffer {
   /// Write an Int64 into the buffer.
   void putBigInt64(BigInt value, {Endian? endian}) {
     assert(!_isDone);
+    if (compact) {
+      return _putVarBigUint(value.isNegative
+          ? ((-value) << 1) - BigInt.one
+          : value << 1);
+    }
     byteDataSetInt64(_eightBytes, 0, value, endian ?? Endian.host);
     _addAll(_eightBytesAsList, 0, 8);
   }
@@ -166,12 +187,24 @@ class WriteBuffer {
   /// Write all the values from an [Uint16List] into the buffer.
   void putUint16List(Uint16List list) {
     assert(!_isDone);
+    if (compact) {
+      for (final value in list) {
+        putUint16(value);
+      }
+      return;
+    }
     _append(list.buffer.asUint8List(list.offsetInBytes, 2 * list.length));
   }
 
   /// Write all the values from an [Uint32List] into the buffer.
   void putUint32List(Uint32List list) {
     assert(!_isDone);
+    if (compact) {
+      for (final value in list) {
+        putUint32(value);
+      }
+      return;
+    }
     _append(list.buffer.asUint8List(list.offsetInBytes, 4 * list.length));
   }
 
@@ -192,12 +225,24 @@ class WriteBuffer {
   /// Write all the values from an [Int16List] into the buffer.
   void putInt16List(Int16List list) {
     assert(!_isDone);
+    if (compact) {
+      for (final value in list) {
+        putInt16(value);
+      }
+      return;
+    }
     _append(list.buffer.asUint8List(list.offsetInBytes, 2 * list.length));
   }
 
   /// Write all the values from an [Int32List] into the buffer.
   void putInt32List(Int32List list) {
     assert(!_isDone);
+    if (compact) {
+      for (final value in list) {
+        putInt32(value);
+      }
+      return;
+    }
     // _alignTo(4);
     _append(list.buffer.asUint8List(list.offsetInBytes, 4 * list.length));
   }
@@ -225,6 +270,30 @@ class WriteBuffer {
     _append(list.buffer.asUint8List(list.offsetInBytes, 8 * list.length));
   }
 
+  // NOTE ADD for the compact format
+  // Use arithmetic instead of bitwise operations, since the latter is 32-bit on the web
+  void _putVarUint(int value) {
+    assert(value >= 0);
+    while (value >= 0x80) {
+      _add(value % 0x80 + 0x80);
+      value ~/= 0x80;
+    }
+    _add(value);
+  }
+
+  void _putVarBigUint(BigInt value) {
+    while (value >= _kBigInt0x80) {
+      _add((value & _kBigInt0x7f).toInt() | 0x80);
+      value >>= 7;
+    }
+    _add(value.toInt());
+  }
+
+  static int _zigzag(int value) => value >= 0 ? value * 2 : -value * 2 - 1;
+
+  static final _kBigInt0x7f = BigInt.from(0x7f);
+  static final _kBigInt0x80 = BigInt.from(0x80);
+
   // NOTE MODIFIED try remove this to simplify rust side
   // void _alignTo(int alignment) {
   //   assert(!_isDone);
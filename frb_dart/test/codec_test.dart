@@ -0,0 +1,56 @@
+@TestOn('vm')
+import 'dart:convert';
+import 'dart:typed_data';
+
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_common.dart';
+import 'package:test/test.dart';
+
+void main() {
+  const message = 'Timed out after 50ms when borrowing argument `a` '
+      'of function `f`, maybe because of a deadlock';
+
+  Matcher isLockTimeout({String? functionName, String? argumentName}) =>
+      isA<RustAutoOpaqueLockTimeoutException>()
+          .having((e) => e.message, 'message', message)
+          .having((e) => e.functionName, 'functionName', functionName)
+          .having((e) => e.argumentName, 'argumentName', argumentName)
+          .having((e) => e.timeout, 'timeout',
+              const Duration(milliseconds: 50));
+
+  test('DcoCodec decodes lock timeout', () {
+    const codec =
+        DcoCodec(decodeSuccessData: _unreachable, decodeErrorData: null);
+    expect(
+        () => codec.decodeObject([
+              4,
+              [message, 'f', 'a', 50000]
+            ]),
+        throwsA(isLockTimeout(functionName: 'f', argumentName: 'a')));
+  });
+
+  test('SseCodec decodes lock timeout', () {
+    const codec =
+        SseCodec(decodeSuccessData: _unreachable, decodeErrorData: null);
+    final builder = BytesBuilder();
+    void addString(String value) {
+      final bytes = utf8.encode(value);
+      builder.add((ByteData(4)..setInt32(0, bytes.length, Endian.host))
+          .buffer
+          .asUint8List());
+      builder.add(bytes);
+    }
+
+    builder.addByte(4);
+    addString(message);
+    builder.addByte(1);
+    addString('f');
+    addString('a');
+    builder.add(
+        (ByteData(8)..setInt64(0, 50000, Endian.host)).buffer.asUint8List());
+
+    expect(() => codec.decodeObject(builder.takeBytes()),
+        throwsA(isLockTimeout(functionName: 'f', argumentName: 'a')));
+  });
+}
+
+Never _unreachable(dynamic _) => throw UnimplementedError();
@@ -0,0 +1,40 @@
+import 'package:flutter_rust_bridge/flutter_rust_bridge.dart';
+import 'package:test/test.dart';
+
+void main() {
+  test('parse and format with offset', () {
+    const raw = '2024-01-02T03:04:05.000006+08:00';
+    final value = DateTimeWithOffset.parse(raw);
+    expect(value.utc, DateTime.utc(2024, 1, 1, 19, 4, 5, 0, 6));
+    expect(value.offset, const Duration(hours: 8));
+    expect(value.timeZone, null);
+    expect(value.wallClock.hour, 3);
+    expect(value.toString(), raw);
+  });
+
+  test('parse and format with negative offset and time zone', () {
+    final value =
+        DateTimeWithOffset.parse('2024-07-01T12:00:00.000000-04:00[America/New_York]');
+    expect(value.utc, DateTime.utc(2024, 7, 1, 16));
+    expect(value.offset, const Duration(hours: -4));
+    expect(value.timeZone, 'America/New_York');
+    expect(value.toString(), '2024-07-01T12:00:00.000000-04:00[America/New_York]');
+  });
+
+  test('parse Z', () {
+    final value = DateTimeWithOffset.parse('2024-01-02T03:04:05Z');
+    expect(value.offset, Duration.zero);
+    expect(value.toString(), '2024-01-02T03:04:05.000000+00:00');
+  });
+
+  test('equality', () {
+    expect(
+      DateTimeWithOffset.parse('2024-01-02T03:04:05+08:00'),
+      DateTimeWithOffset(DateTime.utc(2024, 1, 1, 19, 4, 5), const Duration(hours: 8)),
+    );
+  });
+
+  test('invalid', () {
+    expect(() => DateTimeWithOffset.parse('2024-01-02'), throwsFormatException);
+  });
+}
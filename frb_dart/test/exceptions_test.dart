@@ -6,6 +6,15 @@ void main() {
     expect(PanicException('hello').toString(), contains('PanicException'));
   });
 
+  test('RustAutoOpaqueLockTimeoutException', () {
+    final e = RustAutoOpaqueLockTimeoutException('Timed out',
+        functionName: 'f',
+        argumentName: 'a',
+        timeout: const Duration(milliseconds: 50));
+    expect(e, isA<PanicException>());
+    expect(e.toString(), 'RustAutoOpaqueLockTimeoutException(Timed out)');
+  });
+
   test('AnyhowException', () {
     expect(AnyhowException('hello').toString(), contains('AnyhowException'));
   });
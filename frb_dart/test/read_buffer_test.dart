@@ -0,0 +1,47 @@
+import 'dart:typed_data';
+
+import 'package:flutter_rust_bridge/src/third_party/flutter_foundation_serialization/read_buffer.dart';
+import 'package:test/test.dart';
+
+void main() {
+  ReadBuffer createCompact(List<int> bytes) =>
+      ReadBuffer(Uint8List.fromList(bytes).buffer.asByteData(), compact: true);
+
+  test('compact unsigned', () {
+    final buffer = createCompact([0, 127, 0x80, 1, 0xff, 0xff, 3]);
+    expect(buffer.getUint32(), 0);
+    expect(buffer.getUint32(), 127);
+    expect(buffer.getUint16(), 128);
+    expect(buffer.getUint16(), 65535);
+    expect(buffer.hasRemaining, false);
+  });
+
+  test('compact signed', () {
+    final buffer = createCompact([0, 1, 2, 127, 0x80, 1]);
+    expect(buffer.getInt32(), 0);
+    expect(buffer.getInt32(), -1);
+    expect(buffer.getInt32(), 1);
+    expect(buffer.getInt16(), -64);
+    expect(buffer.getInt16(), 64);
+    expect(buffer.hasRemaining, false);
+  });
+
+  test('compact 64bit', () {
+    final buffer = createCompact([
+      ...List.filled(9, 0xff),
+      1,
+      ...List.filled(9, 0xff),
+      1,
+    ]);
+    expect(buffer.getBigUint64(), BigInt.parse('18446744073709551615'));
+    expect(buffer.getBigInt64(), BigInt.parse('-9223372036854775808'));
+    expect(buffer.hasRemaining, false);
+  });
+
+  test('compact list', () {
+    final buffer = createCompact([1, 3, 0x80, 1]);
+    expect(buffer.getInt32List(2), [-1, -2]);
+    expect(buffer.getUint16List(1), [128]);
+    expect(buffer.hasRemaining, false);
+  });
+}
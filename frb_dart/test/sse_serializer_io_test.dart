@@ -0,0 +1,63 @@
+@TestOn('vm')
+import 'dart:ffi';
+import 'dart:typed_data';
+
+import 'package:flutter_rust_bridge/src/codec/sse.dart';
+import 'package:flutter_rust_bridge/src/generalized_frb_rust_binding/generalized_frb_rust_binding.dart';
+import 'package:flutter_rust_bridge/src/manual_impl/manual_impl.dart';
+import 'package:mocktail/mocktail.dart';
+import 'package:test/test.dart';
+
+class _MockGeneralizedFrbRustBinding extends Mock
+    implements GeneralizedFrbRustBinding {}
+
+final _libc = DynamicLibrary.process();
+final _malloc = _libc.lookupFunction<Pointer<Uint8> Function(IntPtr),
+    Pointer<Uint8> Function(int)>('malloc');
+final _realloc = _libc.lookupFunction<
+    Pointer<Uint8> Function(Pointer<Uint8>, IntPtr),
+    Pointer<Uint8> Function(Pointer<Uint8>, int)>('realloc');
+final _free = _libc.lookupFunction<Void Function(Pointer<Uint8>),
+    void Function(Pointer<Uint8>)>('free');
+
+void main() {
+  late _MockGeneralizedFrbRustBinding binding;
+
+  setUpAll(() => registerFallbackValue(Pointer<Uint8>.fromAddress(0)));
+
+  setUp(() {
+    binding = _MockGeneralizedFrbRustBinding();
+    when(() => binding.rustVecU8New(any()))
+        .thenAnswer((i) => _malloc(i.positionalArguments[0] as int));
+    when(() => binding.rustVecU8Resize(any(), any(), any())).thenAnswer((i) =>
+        _realloc(i.positionalArguments[0] as Pointer<Uint8>,
+            i.positionalArguments[2] as int));
+  });
+
+  test('sseEncodeUint8List puts small lists inline', () {
+    final serializer = SseSerializer(binding);
+    sseEncodeUint8List(Uint8List.fromList([1, 2, 3]), serializer);
+    final raw = serializer.intoRaw();
+
+    expect(raw.ptr.asTypedList(raw.dataLen), [0, 1, 2, 3]);
+    verify(() => binding.rustVecU8New(any())).called(1);
+    _free(raw.ptr);
+  });
+
+  test('sseEncodeUint8List hands standalone buffers to Rust in intoRaw', () {
+    const length = 100000;
+    final serializer = SseSerializer(binding);
+    sseEncodeUint8List(Uint8List(length)..fillRange(0, length, 42), serializer);
+    final raw = serializer.intoRaw();
+
+    final data = raw.ptr.asTypedList(raw.dataLen);
+    expect(data[0], 1);
+    final standalone = Pointer<Uint8>.fromAddress(
+        ByteData.sublistView(data).getUint64(1, Endian.host));
+    expect(standalone.asTypedList(length), everyElement(42));
+    // From now on, Rust owns the standalone buffer and frees it after decoding
+    verifyNever(() => binding.rustVecU8Free(any(), any()));
+    _free(standalone);
+    _free(raw.ptr);
+  });
+}
@@ -56,6 +56,7 @@ fn wire__crate__api__minimal__init_app_impl(
             debug_name: "init_app",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -90,6 +91,7 @@ fn wire__crate__api__minimal__minimal_adder_impl(
             debug_name: "minimal_adder",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -120,7 +122,7 @@ fn wire__crate__api__minimal__minimal_adder_impl(
 impl SseDecode for i32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+        deserializer.read_i32()
     }
 }
 
@@ -168,7 +170,7 @@ fn pde_ffi_dispatcher_sync_impl(
 impl SseEncode for i32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+        serializer.write_i32(self);
     }
 }
 
@@ -56,6 +56,7 @@ fn wire__crate__api__simple__make_data_race_impl(
             debug_name: "make_data_race",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -90,6 +91,7 @@ fn wire__crate__api__simple__make_heap_use_after_free_impl(
             debug_name: "make_heap_use_after_free",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -124,6 +126,7 @@ fn wire__crate__api__simple__make_memory_leak_impl(
             debug_name: "make_memory_leak",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -158,6 +161,7 @@ fn wire__crate__api__simple__make_stack_buffer_overflow_impl(
             debug_name: "make_stack_buffer_overflow",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -192,6 +196,7 @@ fn wire__crate__api__simple__make_use_of_uninitialized_value_impl(
             debug_name: "make_use_of_uninitialized_value",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -226,7 +231,7 @@ impl SseDecode for () {
 impl SseDecode for i32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+        deserializer.read_i32()
     }
 }
 
@@ -292,7 +297,7 @@ impl SseEncode for () {
 impl SseEncode for i32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+        serializer.write_i32(self);
     }
 }
 
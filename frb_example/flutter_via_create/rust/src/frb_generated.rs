@@ -55,6 +55,7 @@ fn wire__crate__api__simple__greet_impl(
             debug_name: "greet",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -86,6 +87,7 @@ fn wire__crate__api__simple__init_app_impl(
             debug_name: "init_app",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -124,11 +126,7 @@ impl SseDecode for Vec<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
-        }
-        return ans_;
+        return deserializer.decode_u8_list(len_);
     }
 }
 
@@ -147,7 +145,7 @@ impl SseDecode for () {
 impl SseDecode for i32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+        deserializer.read_i32()
     }
 }
 
@@ -219,7 +217,7 @@ impl SseEncode for () {
 impl SseEncode for i32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+        serializer.write_i32(self);
     }
 }
 
@@ -56,6 +56,7 @@ fn wire__crate__api__mandelbrot__draw_mandelbrot_impl(
             debug_name: "draw_mandelbrot",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -74,17 +75,20 @@ fn wire__crate__api__mandelbrot__draw_mandelbrot_impl(
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || async move {
-                        let output_ok = crate::api::mandelbrot::draw_mandelbrot(
-                            api_image_size,
-                            api_zoom_point,
-                            api_scale,
-                            api_num_threads,
-                        )
-                        .await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
+                    flutter_rust_bridge::frb_record_error_debug!(
+                        flutter_rust_bridge::for_generated::anyhow::Error,
+                        (move || async move {
+                            let output_ok = crate::api::mandelbrot::draw_mandelbrot(
+                                api_image_size,
+                                api_zoom_point,
+                                api_scale,
+                                api_num_threads,
+                            )
+                            .await?;
+                            Ok(output_ok)
+                        })()
+                        .await
+                    ),
                 )
             }
         },
@@ -96,8 +100,8 @@ fn wire__crate__api__mandelbrot__draw_mandelbrot_impl(
 impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <String>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
+        let mut inner = <Vec<String>>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::anyhow_error_decode(inner);
     }
 }
 
@@ -119,22 +123,30 @@ impl SseDecode for f64 {
 impl SseDecode for i32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+        deserializer.read_i32()
     }
 }
 
-impl SseDecode for Vec<u8> {
+impl SseDecode for Vec<String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = vec![];
         for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
+            ans_.push(<String>::sse_decode(deserializer));
         }
         return ans_;
     }
 }
 
+impl SseDecode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        return deserializer.decode_u8_list(len_);
+    }
+}
+
 impl SseDecode for crate::api::mandelbrot::Point {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -238,7 +250,10 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::mandelbrot::Size>
 impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(format!("{:?}", self), serializer);
+        <Vec<String>>::sse_encode(
+            flutter_rust_bridge::for_generated::anyhow_error_encode(self),
+            serializer,
+        );
     }
 }
 
@@ -259,7 +274,17 @@ impl SseEncode for f64 {
 impl SseEncode for i32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+        serializer.write_i32(self);
+    }
+}
+
+impl SseEncode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <String>::sse_encode(item, serializer);
+        }
     }
 }
 
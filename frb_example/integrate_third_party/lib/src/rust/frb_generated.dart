@@ -18441,7 +18441,11 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   @protected
   AnyhowException dco_decode_AnyhowException(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AnyhowException(raw as String);
+    final inner = (raw as List<dynamic>).cast<String>();
+    return AnyhowException(inner[0],
+        backtrace: inner[1].isEmpty ? null : inner[1],
+        typeName: inner[2].isEmpty ? null : inner[2],
+        causes: inner.sublist(3));
   }
 
   @protected
@@ -21130,6 +21134,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         .toList();
   }
 
+  @protected
+  List<String> dco_decode_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  }
+
   @protected
   List<AudioParamDescriptor> dco_decode_list_audio_param_descriptor(
       dynamic raw) {
@@ -21355,8 +21365,11 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   @protected
   AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_String(deserializer);
-    return AnyhowException(inner);
+    var inner = sse_decode_list_String(deserializer);
+    return AnyhowException(inner[0],
+        backtrace: inner[1].isEmpty ? null : inner[1],
+        typeName: inner[2].isEmpty ? null : inner[2],
+        causes: inner.sublist(3));
   }
 
   @protected
@@ -23956,6 +23969,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <String>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_String(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<AudioParamDescriptor> sse_decode_list_audio_param_descriptor(
       SseDeserializer deserializer) {
@@ -24225,7 +24250,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   void sse_encode_AnyhowException(
       AnyhowException self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.message, serializer);
+    sse_encode_list_String([
+      self.message,
+      self.backtrace ?? '',
+      self.typeName ?? '',
+      ...self.causes
+    ], serializer);
   }
 
   @protected
@@ -27070,6 +27100,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_String(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_audio_param_descriptor(
       List<AudioParamDescriptor> self, SseSerializer serializer) {
@@ -27129,7 +27168,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       Uint8List self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
+    sseEncodeUint8List(self, serializer);
   }
 
   @protected
@@ -101,6 +101,7 @@ fn wire__crate__api__media_element__MyMediaElement_current_time_impl(
             debug_name: "MyMediaElement_current_time",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -127,7 +128,14 @@ fn wire__crate__api__media_element__MyMediaElement_current_time_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "MyMediaElement_current_time",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -152,6 +160,7 @@ fn wire__crate__api__media_element__MyMediaElement_loop__impl(
             debug_name: "MyMediaElement_loop_",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -178,7 +187,14 @@ fn wire__crate__api__media_element__MyMediaElement_loop__impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "MyMediaElement_loop_",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -202,6 +218,7 @@ fn wire__crate__api__media_element__MyMediaElement_new_impl(
             debug_name: "MyMediaElement_new",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -216,10 +233,13 @@ fn wire__crate__api__media_element__MyMediaElement_new_impl(
             let api_file = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                (move || {
-                    let output_ok = crate::api::media_element::MyMediaElement::new(api_file)?;
-                    Ok(output_ok)
-                })(),
+                flutter_rust_bridge::frb_record_error_debug!(
+                    flutter_rust_bridge::for_generated::anyhow::Error,
+                    (move || {
+                        let output_ok = crate::api::media_element::MyMediaElement::new(api_file)?;
+                        Ok(output_ok)
+                    })()
+                ),
             )
         },
     )
@@ -235,6 +255,7 @@ fn wire__crate__api__media_element__MyMediaElement_pause_impl(
             debug_name: "MyMediaElement_pause",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -261,7 +282,14 @@ fn wire__crate__api__media_element__MyMediaElement_pause_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "MyMediaElement_pause",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -286,6 +314,7 @@ fn wire__crate__api__media_element__MyMediaElement_paused_impl(
             debug_name: "MyMediaElement_paused",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -312,7 +341,14 @@ fn wire__crate__api__media_element__MyMediaElement_paused_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "MyMediaElement_paused",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -337,6 +373,7 @@ fn wire__crate__api__media_element__MyMediaElement_play_impl(
             debug_name: "MyMediaElement_play",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -363,7 +400,14 @@ fn wire__crate__api__media_element__MyMediaElement_play_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "MyMediaElement_play",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -388,6 +432,7 @@ fn wire__crate__api__media_element__MyMediaElement_playback_rate_impl(
             debug_name: "MyMediaElement_playback_rate",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -414,7 +459,14 @@ fn wire__crate__api__media_element__MyMediaElement_playback_rate_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "MyMediaElement_playback_rate",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -439,6 +491,7 @@ fn wire__crate__api__media_element__MyMediaElement_set_current_time_impl(
             debug_name: "MyMediaElement_set_current_time",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -466,7 +519,14 @@ fn wire__crate__api__media_element__MyMediaElement_set_current_time_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "MyMediaElement_set_current_time",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -494,6 +554,7 @@ fn wire__crate__api__media_element__MyMediaElement_set_loop_impl(
             debug_name: "MyMediaElement_set_loop",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -521,7 +582,14 @@ fn wire__crate__api__media_element__MyMediaElement_set_loop_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "MyMediaElement_set_loop",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -549,6 +617,7 @@ fn wire__crate__api__media_element__MyMediaElement_set_playback_rate_impl(
             debug_name: "MyMediaElement_set_playback_rate",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -576,7 +645,14 @@ fn wire__crate__api__media_element__MyMediaElement_set_playback_rate_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "MyMediaElement_set_playback_rate",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -604,6 +680,7 @@ fn wire__crate__api__simple__f_impl(
             debug_name: "f",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -639,6 +716,7 @@ fn wire__crate__api__simple__init_app_impl(
             debug_name: "init_app",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -673,6 +751,7 @@ fn wire__web_audio_api__AudioBuffer_duration_impl(
             debug_name: "AudioBuffer_duration",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -699,7 +778,14 @@ fn wire__web_audio_api__AudioBuffer_duration_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioBuffer_duration",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -724,6 +810,7 @@ fn wire__web_audio_api__AudioBuffer_from_impl(
             debug_name: "AudioBuffer_from",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -761,6 +848,7 @@ fn wire__web_audio_api__AudioBuffer_get_channel_data_impl(
             debug_name: "AudioBuffer_get_channel_data",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -788,7 +876,14 @@ fn wire__web_audio_api__AudioBuffer_get_channel_data_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioBuffer_get_channel_data",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -816,6 +911,7 @@ fn wire__web_audio_api__AudioBuffer_get_channel_data_mut_impl(
             debug_name: "AudioBuffer_get_channel_data_mut",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -843,7 +939,14 @@ fn wire__web_audio_api__AudioBuffer_get_channel_data_mut_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioBuffer_get_channel_data_mut",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref_mut(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -871,6 +974,7 @@ fn wire__web_audio_api__AudioBuffer_length_impl(
             debug_name: "AudioBuffer_length",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -897,7 +1001,14 @@ fn wire__web_audio_api__AudioBuffer_length_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioBuffer_length",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -921,6 +1032,7 @@ fn wire__web_audio_api__AudioBuffer_new_impl(
             debug_name: "AudioBuffer_new",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -955,6 +1067,7 @@ fn wire__web_audio_api__AudioBuffer_number_of_channels_impl(
             debug_name: "AudioBuffer_number_of_channels",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -981,7 +1094,14 @@ fn wire__web_audio_api__AudioBuffer_number_of_channels_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioBuffer_number_of_channels",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -1006,6 +1126,7 @@ fn wire__web_audio_api__AudioBuffer_sample_rate_impl(
             debug_name: "AudioBuffer_sample_rate",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -1032,7 +1153,14 @@ fn wire__web_audio_api__AudioBuffer_sample_rate_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioBuffer_sample_rate",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -1052,16 +1180,16 @@ fn wire__web_audio_api__AudioParam_automation_rate_impl(
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "AudioParam_automation_rate", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
-            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
-            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "AudioParam_automation_rate", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal, executor: None }, move || { 
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
                     transform_result_sse::<_, ()>((move ||  {
                         let mut api_that_guard = None;
 let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
         for i in decode_indices_ {
             match i {
-                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                0 => api_that_guard = Some(flutter_rust_bridge::for_generated::lockable_with_site("AudioParam_automation_rate", "that", || api_that.lockable_decode_sync_ref())),
                 _ => unreachable!(),
             }
         }
@@ -1076,17 +1204,17 @@ fn wire__web_audio_api__AudioParam_cancel_and_hold_at_time_impl(
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "AudioParam_cancel_and_hold_at_time", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
-            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
-            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum>::sse_decode(&mut deserializer);
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "AudioParam_cancel_and_hold_at_time", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal, executor: None }, move || { 
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum>::sse_decode(&mut deserializer);
 let api_cancel_time = <f64>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
                     transform_result_sse::<_, ()>((move ||  {
                         let mut api_that_guard = None;
 let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
         for i in decode_indices_ {
             match i {
-                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                0 => api_that_guard = Some(flutter_rust_bridge::for_generated::lockable_with_site("AudioParam_cancel_and_hold_at_time", "that", || api_that.lockable_decode_sync_ref())),
                 _ => unreachable!(),
             }
         }
@@ -1101,17 +1229,17 @@ fn wire__web_audio_api__AudioParam_cancel_scheduled_values_impl(
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "AudioParam_cancel_scheduled_values", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
-            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
-            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum>::sse_decode(&mut deserializer);
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "AudioParam_cancel_scheduled_values", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal, executor: None }, move || { 
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum>::sse_decode(&mut deserializer);
 let api_cancel_time = <f64>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
                     transform_result_sse::<_, ()>((move ||  {
                         let mut api_that_guard = None;
 let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
         for i in decode_indices_ {
             match i {
-                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                0 => api_that_guard = Some(flutter_rust_bridge::for_generated::lockable_with_site("AudioParam_cancel_scheduled_values", "that", || api_that.lockable_decode_sync_ref())),
                 _ => unreachable!(),
             }
         }
@@ -1126,16 +1254,16 @@ fn wire__web_audio_api__AudioParam_channel_config_impl(
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "AudioParam_channel_config", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
-            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
-            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "AudioParam_channel_config", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal, executor: None }, move || { 
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
                     transform_result_sse::<_, ()>((move ||  {
                         let mut api_that_guard = None;
 let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
         for i in decode_indices_ {
             match i {
-                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                0 => api_that_guard = Some(flutter_rust_bridge::for_generated::lockable_with_site("AudioParam_channel_config", "that", || api_that.lockable_decode_sync_ref())),
                 _ => unreachable!(),
             }
         }
@@ -1150,16 +1278,16 @@ fn wire__web_audio_api__AudioParam_channel_count_impl(
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "AudioParam_channel_count", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
-            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
-            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "AudioParam_channel_count", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal, executor: None }, move || { 
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
                     transform_result_sse::<_, ()>((move ||  {
                         let mut api_that_guard = None;
 let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
         for i in decode_indices_ {
             match i {
-                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                0 => api_that_guard = Some(flutter_rust_bridge::for_generated::lockable_with_site("AudioParam_channel_count", "that", || api_that.lockable_decode_sync_ref())),
                 _ => unreachable!(),
             }
         }
@@ -1174,16 +1302,16 @@ fn wire__web_audio_api__AudioParam_channel_count_mode_impl(
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "AudioParam_channel_count_mode", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
-            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
-            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "AudioParam_channel_count_mode", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal, executor: None }, move || { 
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
                     transform_result_sse::<_, ()>((move ||  {
                         let mut api_that_guard = None;
 let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
         for i in decode_indices_ {
             match i {
-                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                0 => api_that_guard = Some(flutter_rust_bridge::for_generated::lockable_with_site("AudioParam_channel_count_mode", "that", || api_that.lockable_decode_sync_ref())),
                 _ => unreachable!(),
             }
         }
@@ -1198,16 +1326,16 @@ fn wire__web_audio_api__AudioParam_channel_interpretation_impl(
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "AudioParam_channel_interpretation", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
-            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
-            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "AudioParam_channel_interpretation", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal, executor: None }, move || { 
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
                     transform_result_sse::<_, ()>((move ||  {
                         let mut api_that_guard = None;
 let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
         for i in decode_indices_ {
             match i {
-                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                0 => api_that_guard = Some(flutter_rust_bridge::for_generated::lockable_with_site("AudioParam_channel_interpretation", "that", || api_that.lockable_decode_sync_ref())),
                 _ => unreachable!(),
             }
         }
@@ -1222,16 +1350,16 @@ fn wire__web_audio_api__AudioParam_clear_onprocessorerror_impl(
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "AudioParam_clear_onprocessorerror", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
-            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
-            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "AudioParam_clear_onprocessorerror", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal, executor: None }, move || { 
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
                     transform_result_sse::<_, ()>((move ||  {
                         let mut api_that_guard = None;
 let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
         for i in decode_indices_ {
             match i {
-                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                0 => api_that_guard = Some(flutter_rust_bridge::for_generated::lockable_with_site("AudioParam_clear_onprocessorerror", "that", || api_that.lockable_decode_sync_ref())),
                 _ => unreachable!(),
             }
         }
@@ -1246,16 +1374,16 @@ fn wire__web_audio_api__AudioParam_default_value_impl(
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "AudioParam_default_value", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
-            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
-            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "AudioParam_default_value", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal, executor: None }, move || { 
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
                     transform_result_sse::<_, ()>((move ||  {
                         let mut api_that_guard = None;
 let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
         for i in decode_indices_ {
             match i {
-                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                0 => api_that_guard = Some(flutter_rust_bridge::for_generated::lockable_with_site("AudioParam_default_value", "that", || api_that.lockable_decode_sync_ref())),
                 _ => unreachable!(),
             }
         }
@@ -1270,16 +1398,16 @@ fn wire__web_audio_api__AudioParam_disconnect_impl(
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "AudioParam_disconnect", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
-            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
-            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "AudioParam_disconnect", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal, executor: None }, move || { 
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
                     transform_result_sse::<_, ()>((move ||  {
                         let mut api_that_guard = None;
 let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
         for i in decode_indices_ {
             match i {
-                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                0 => api_that_guard = Some(flutter_rust_bridge::for_generated::lockable_with_site("AudioParam_disconnect", "that", || api_that.lockable_decode_sync_ref())),
                 _ => unreachable!(),
             }
         }
@@ -1294,17 +1422,17 @@ fn wire__web_audio_api__AudioParam_disconnect_output_impl(
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "AudioParam_disconnect_output", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
-            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
-            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum>::sse_decode(&mut deserializer);
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "AudioParam_disconnect_output", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal, executor: None }, move || { 
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum>::sse_decode(&mut deserializer);
 let api_output = <usize>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
                     transform_result_sse::<_, ()>((move ||  {
                         let mut api_that_guard = None;
 let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
         for i in decode_indices_ {
             match i {
-                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                0 => api_that_guard = Some(flutter_rust_bridge::for_generated::lockable_with_site("AudioParam_disconnect_output", "that", || api_that.lockable_decode_sync_ref())),
                 _ => unreachable!(),
             }
         }
@@ -1319,10 +1447,10 @@ fn wire__web_audio_api__AudioParam_exponential_ramp_to_value_at_time_impl(
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "AudioParam_exponential_ramp_to_value_at_time", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
-            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
-            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum>::sse_decode(&mut deserializer);
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "AudioParam_exponential_ramp_to_value_at_time", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal, executor: None }, move || { 
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum>::sse_decode(&mut deserializer);
 let api_value = <f32>::sse_decode(&mut deserializer);
 let api_end_time = <f64>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
                     transform_result_sse::<_, ()>((move ||  {
@@ -1330,7 +1458,7 @@ let api_end_time = <f64>::sse_decode(&mut deserializer);deserializer.end(); move
 let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
         for i in decode_indices_ {
             match i {
-                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                0 => api_that_guard = Some(flutter_rust_bridge::for_generated::lockable_with_site("AudioParam_exponential_ramp_to_value_at_time", "that", || api_that.lockable_decode_sync_ref())),
                 _ => unreachable!(),
             }
         }
@@ -1345,10 +1473,10 @@ fn wire__web_audio_api__AudioParam_frb_override_connect_impl(
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "AudioParam_frb_override_connect", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
-            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
-            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum>::sse_decode(&mut deserializer);
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "AudioParam_frb_override_connect", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal, executor: None }, move || { 
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum>::sse_decode(&mut deserializer);
 let api_dest = <crate::frb_generated::AudioNodeImplementor>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
                     transform_result_sse::<_, ()>((move ||  {
                         let mut api_that_guard = None;
@@ -1356,8 +1484,8 @@ let mut api_dest_guard = None;
 let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false), flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_dest, 1, false)]);
         for i in decode_indices_ {
             match i {
-                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-1 => api_dest_guard = Some(api_dest.lockable_decode_sync_ref()),
+                0 => api_that_guard = Some(flutter_rust_bridge::for_generated::lockable_with_site("AudioParam_frb_override_connect", "that", || api_that.lockable_decode_sync_ref())),
+1 => api_dest_guard = Some(flutter_rust_bridge::for_generated::lockable_with_site("AudioParam_frb_override_connect", "dest", || api_dest.lockable_decode_sync_ref())),
                 _ => unreachable!(),
             }
         }
@@ -1373,10 +1501,10 @@ fn wire__web_audio_api__AudioParam_linear_ramp_to_value_at_time_impl(
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "AudioParam_linear_ramp_to_value_at_time", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
-            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
-            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum>::sse_decode(&mut deserializer);
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "AudioParam_linear_ramp_to_value_at_time", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal, executor: None }, move || { 
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum>::sse_decode(&mut deserializer);
 let api_value = <f32>::sse_decode(&mut deserializer);
 let api_end_time = <f64>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
                     transform_result_sse::<_, ()>((move ||  {
@@ -1384,7 +1512,7 @@ let api_end_time = <f64>::sse_decode(&mut deserializer);deserializer.end(); move
 let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
         for i in decode_indices_ {
             match i {
-                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                0 => api_that_guard = Some(flutter_rust_bridge::for_generated::lockable_with_site("AudioParam_linear_ramp_to_value_at_time", "that", || api_that.lockable_decode_sync_ref())),
                 _ => unreachable!(),
             }
         }
@@ -1399,16 +1527,16 @@ fn wire__web_audio_api__AudioParam_max_value_impl(
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "AudioParam_max_value", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
-            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
-            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "AudioParam_max_value", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal, executor: None }, move || { 
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
                     transform_result_sse::<_, ()>((move ||  {
                         let mut api_that_guard = None;
 let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
         for i in decode_indices_ {
             match i {
-                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                0 => api_that_guard = Some(flutter_rust_bridge::for_generated::lockable_with_site("AudioParam_max_value", "that", || api_that.lockable_decode_sync_ref())),
                 _ => unreachable!(),
             }
         }
@@ -1423,16 +1551,16 @@ fn wire__web_audio_api__AudioParam_min_value_impl(
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "AudioParam_min_value", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
-            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
-            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "AudioParam_min_value", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal, executor: None }, move || { 
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
                     transform_result_sse::<_, ()>((move ||  {
                         let mut api_that_guard = None;
 let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
         for i in decode_indices_ {
             match i {
-                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                0 => api_that_guard = Some(flutter_rust_bridge::for_generated::lockable_with_site("AudioParam_min_value", "that", || api_that.lockable_decode_sync_ref())),
                 _ => unreachable!(),
             }
         }
@@ -1447,16 +1575,16 @@ fn wire__web_audio_api__AudioParam_number_of_inputs_impl(
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "AudioParam_number_of_inputs", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
-            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
-            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "AudioParam_number_of_inputs", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal, executor: None }, move || { 
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
                     transform_result_sse::<_, ()>((move ||  {
                         let mut api_that_guard = None;
 let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
         for i in decode_indices_ {
             match i {
-                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                0 => api_that_guard = Some(flutter_rust_bridge::for_generated::lockable_with_site("AudioParam_number_of_inputs", "that", || api_that.lockable_decode_sync_ref())),
                 _ => unreachable!(),
             }
         }
@@ -1471,16 +1599,16 @@ fn wire__web_audio_api__AudioParam_number_of_outputs_impl(
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "AudioParam_number_of_outputs", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
-            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
-            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "AudioParam_number_of_outputs", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal, executor: None }, move || { 
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
                     transform_result_sse::<_, ()>((move ||  {
                         let mut api_that_guard = None;
 let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
         for i in decode_indices_ {
             match i {
-                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                0 => api_that_guard = Some(flutter_rust_bridge::for_generated::lockable_with_site("AudioParam_number_of_outputs", "that", || api_that.lockable_decode_sync_ref())),
                 _ => unreachable!(),
             }
         }
@@ -1495,16 +1623,16 @@ fn wire__web_audio_api__AudioParam_registration_impl(
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "AudioParam_registration", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
-            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
-            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "AudioParam_registration", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal, executor: None }, move || { 
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
                     transform_result_sse::<_, ()>((move ||  {
                         let mut api_that_guard = None;
 let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
         for i in decode_indices_ {
             match i {
-                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                0 => api_that_guard = Some(flutter_rust_bridge::for_generated::lockable_with_site("AudioParam_registration", "that", || api_that.lockable_decode_sync_ref())),
                 _ => unreachable!(),
             }
         }
@@ -1519,17 +1647,17 @@ fn wire__web_audio_api__AudioParam_set_automation_rate_impl(
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "AudioParam_set_automation_rate", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
-            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
-            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum>::sse_decode(&mut deserializer);
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "AudioParam_set_automation_rate", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal, executor: None }, move || { 
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum>::sse_decode(&mut deserializer);
 let api_value = <web_audio_api::AutomationRate>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
                     transform_result_sse::<_, ()>((move ||  {
                         let mut api_that_guard = None;
 let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
         for i in decode_indices_ {
             match i {
-                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                0 => api_that_guard = Some(flutter_rust_bridge::for_generated::lockable_with_site("AudioParam_set_automation_rate", "that", || api_that.lockable_decode_sync_ref())),
                 _ => unreachable!(),
             }
         }
@@ -1544,17 +1672,17 @@ fn wire__web_audio_api__AudioParam_set_on_processor_error_impl(
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "AudioParam_set_on_processor_error", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
-            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
-            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum>::sse_decode(&mut deserializer);
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "AudioParam_set_on_processor_error", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal, executor: None }, move || { 
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum>::sse_decode(&mut deserializer);
 let api_callback = decode_DartFn_Inputs_String_Output_unit_AnyhowException(<flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer));deserializer.end(); move |context|  {
                     transform_result_sse::<_, ()>((move ||  {
                         let mut api_that_guard = None;
 let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
         for i in decode_indices_ {
             match i {
-                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                0 => api_that_guard = Some(flutter_rust_bridge::for_generated::lockable_with_site("AudioParam_set_on_processor_error", "that", || api_that.lockable_decode_sync_ref())),
                 _ => unreachable!(),
             }
         }
@@ -1569,10 +1697,10 @@ fn wire__web_audio_api__AudioParam_set_target_at_time_impl(
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "AudioParam_set_target_at_time", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
-            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
-            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum>::sse_decode(&mut deserializer);
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "AudioParam_set_target_at_time", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal, executor: None }, move || { 
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum>::sse_decode(&mut deserializer);
 let api_value = <f32>::sse_decode(&mut deserializer);
 let api_start_time = <f64>::sse_decode(&mut deserializer);
 let api_time_constant = <f64>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
@@ -1581,7 +1709,7 @@ let api_time_constant = <f64>::sse_decode(&mut deserializer);deserializer.end();
 let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
         for i in decode_indices_ {
             match i {
-                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                0 => api_that_guard = Some(flutter_rust_bridge::for_generated::lockable_with_site("AudioParam_set_target_at_time", "that", || api_that.lockable_decode_sync_ref())),
                 _ => unreachable!(),
             }
         }
@@ -1595,17 +1723,17 @@ fn wire__web_audio_api__AudioParam_set_value_impl(
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "AudioParam_set_value", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
-            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
-            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum>::sse_decode(&mut deserializer);
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "AudioParam_set_value", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync, executor: None }, move || { 
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum>::sse_decode(&mut deserializer);
 let api_value = <f32>::sse_decode(&mut deserializer);deserializer.end();
                 transform_result_sse::<_, ()>((move || {
                     let mut api_that_guard = None;
 let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
         for i in decode_indices_ {
             match i {
-                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                0 => api_that_guard = Some(flutter_rust_bridge::for_generated::lockable_with_site("AudioParam_set_value", "that", || api_that.lockable_decode_sync_ref())),
                 _ => unreachable!(),
             }
         }
@@ -1619,10 +1747,10 @@ fn wire__web_audio_api__AudioParam_set_value_at_time_impl(
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "AudioParam_set_value_at_time", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
-            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
-            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum>::sse_decode(&mut deserializer);
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "AudioParam_set_value_at_time", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal, executor: None }, move || { 
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum>::sse_decode(&mut deserializer);
 let api_value = <f32>::sse_decode(&mut deserializer);
 let api_start_time = <f64>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
                     transform_result_sse::<_, ()>((move ||  {
@@ -1630,7 +1758,7 @@ let api_start_time = <f64>::sse_decode(&mut deserializer);deserializer.end(); mo
 let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
         for i in decode_indices_ {
             match i {
-                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                0 => api_that_guard = Some(flutter_rust_bridge::for_generated::lockable_with_site("AudioParam_set_value_at_time", "that", || api_that.lockable_decode_sync_ref())),
                 _ => unreachable!(),
             }
         }
@@ -1645,10 +1773,10 @@ fn wire__web_audio_api__AudioParam_set_value_curve_at_time_impl(
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "AudioParam_set_value_curve_at_time", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
-            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
-            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum>::sse_decode(&mut deserializer);
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "AudioParam_set_value_curve_at_time", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal, executor: None }, move || { 
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum>::sse_decode(&mut deserializer);
 let api_values = <Vec<f32>>::sse_decode(&mut deserializer);
 let api_start_time = <f64>::sse_decode(&mut deserializer);
 let api_duration = <f64>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
@@ -1657,7 +1785,7 @@ let api_duration = <f64>::sse_decode(&mut deserializer);deserializer.end(); move
 let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
         for i in decode_indices_ {
             match i {
-                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                0 => api_that_guard = Some(flutter_rust_bridge::for_generated::lockable_with_site("AudioParam_set_value_curve_at_time", "that", || api_that.lockable_decode_sync_ref())),
                 _ => unreachable!(),
             }
         }
@@ -1671,16 +1799,16 @@ fn wire__web_audio_api__AudioParam_value_impl(
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "AudioParam_value", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
-            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
-            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum>::sse_decode(&mut deserializer);deserializer.end();
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "AudioParam_value", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync, executor: None }, move || { 
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum>::sse_decode(&mut deserializer);deserializer.end();
                 transform_result_sse::<_, ()>((move || {
                     let mut api_that_guard = None;
 let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
         for i in decode_indices_ {
             match i {
-                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                0 => api_that_guard = Some(flutter_rust_bridge::for_generated::lockable_with_site("AudioParam_value", "that", || api_that.lockable_decode_sync_ref())),
                 _ => unreachable!(),
             }
         }
@@ -1698,6 +1826,7 @@ fn wire__web_audio_api__AudioProcessingEvent_auto_accessor_get_input_buffer_impl
             debug_name: "AudioProcessingEvent_auto_accessor_get_input_buffer",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -1723,7 +1852,14 @@ fn wire__web_audio_api__AudioProcessingEvent_auto_accessor_get_input_buffer_impl
                     ]);
                 for i in decode_indices_ {
                     match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        0 => {
+                            api_that_guard =
+                                Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                    "AudioProcessingEvent_auto_accessor_get_input_buffer",
+                                    "that",
+                                    || api_that.lockable_decode_sync_ref(),
+                                ))
+                        }
                         _ => unreachable!(),
                     }
                 }
@@ -1744,6 +1880,7 @@ fn wire__web_audio_api__AudioProcessingEvent_auto_accessor_get_output_buffer_imp
             debug_name: "AudioProcessingEvent_auto_accessor_get_output_buffer",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -1769,7 +1906,14 @@ fn wire__web_audio_api__AudioProcessingEvent_auto_accessor_get_output_buffer_imp
                     ]);
                 for i in decode_indices_ {
                     match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        0 => {
+                            api_that_guard =
+                                Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                    "AudioProcessingEvent_auto_accessor_get_output_buffer",
+                                    "that",
+                                    || api_that.lockable_decode_sync_ref(),
+                                ))
+                        }
                         _ => unreachable!(),
                     }
                 }
@@ -1790,6 +1934,7 @@ fn wire__web_audio_api__AudioProcessingEvent_auto_accessor_get_playback_time_imp
             debug_name: "AudioProcessingEvent_auto_accessor_get_playback_time",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -1815,7 +1960,14 @@ fn wire__web_audio_api__AudioProcessingEvent_auto_accessor_get_playback_time_imp
                     ]);
                 for i in decode_indices_ {
                     match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        0 => {
+                            api_that_guard =
+                                Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                    "AudioProcessingEvent_auto_accessor_get_playback_time",
+                                    "that",
+                                    || api_that.lockable_decode_sync_ref(),
+                                ))
+                        }
                         _ => unreachable!(),
                     }
                 }
@@ -1836,6 +1988,7 @@ fn wire__web_audio_api__AudioProcessingEvent_auto_accessor_set_input_buffer_impl
             debug_name: "AudioProcessingEvent_auto_accessor_set_input_buffer",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -1862,7 +2015,14 @@ fn wire__web_audio_api__AudioProcessingEvent_auto_accessor_set_input_buffer_impl
                     ]);
                 for i in decode_indices_ {
                     match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                        0 => {
+                            api_that_guard =
+                                Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                    "AudioProcessingEvent_auto_accessor_set_input_buffer",
+                                    "that",
+                                    || api_that.lockable_decode_sync_ref_mut(),
+                                ))
+                        }
                         _ => unreachable!(),
                     }
                 }
@@ -1887,6 +2047,7 @@ fn wire__web_audio_api__AudioProcessingEvent_auto_accessor_set_output_buffer_imp
             debug_name: "AudioProcessingEvent_auto_accessor_set_output_buffer",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -1913,7 +2074,14 @@ fn wire__web_audio_api__AudioProcessingEvent_auto_accessor_set_output_buffer_imp
                     ]);
                 for i in decode_indices_ {
                     match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                        0 => {
+                            api_that_guard =
+                                Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                    "AudioProcessingEvent_auto_accessor_set_output_buffer",
+                                    "that",
+                                    || api_that.lockable_decode_sync_ref_mut(),
+                                ))
+                        }
                         _ => unreachable!(),
                     }
                 }
@@ -1938,6 +2106,7 @@ fn wire__web_audio_api__AudioProcessingEvent_auto_accessor_set_playback_time_imp
             debug_name: "AudioProcessingEvent_auto_accessor_set_playback_time",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -1964,7 +2133,14 @@ fn wire__web_audio_api__AudioProcessingEvent_auto_accessor_set_playback_time_imp
                     ]);
                 for i in decode_indices_ {
                     match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                        0 => {
+                            api_that_guard =
+                                Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                    "AudioProcessingEvent_auto_accessor_set_playback_time",
+                                    "that",
+                                    || api_that.lockable_decode_sync_ref_mut(),
+                                ))
+                        }
                         _ => unreachable!(),
                     }
                 }
@@ -1989,6 +2165,7 @@ fn wire__web_audio_api__AudioRenderCapacityEvent_auto_accessor_get_average_load_
             debug_name: "AudioRenderCapacityEvent_auto_accessor_get_average_load",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -2014,7 +2191,14 @@ fn wire__web_audio_api__AudioRenderCapacityEvent_auto_accessor_get_average_load_
                     ]);
                 for i in decode_indices_ {
                     match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        0 => {
+                            api_that_guard =
+                                Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                    "AudioRenderCapacityEvent_auto_accessor_get_average_load",
+                                    "that",
+                                    || api_that.lockable_decode_sync_ref(),
+                                ))
+                        }
                         _ => unreachable!(),
                     }
                 }
@@ -2035,6 +2219,7 @@ fn wire__web_audio_api__AudioRenderCapacityEvent_auto_accessor_get_event_impl(
             debug_name: "AudioRenderCapacityEvent_auto_accessor_get_event",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -2060,7 +2245,14 @@ fn wire__web_audio_api__AudioRenderCapacityEvent_auto_accessor_get_event_impl(
                     ]);
                 for i in decode_indices_ {
                     match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        0 => {
+                            api_that_guard =
+                                Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                    "AudioRenderCapacityEvent_auto_accessor_get_event",
+                                    "that",
+                                    || api_that.lockable_decode_sync_ref(),
+                                ))
+                        }
                         _ => unreachable!(),
                     }
                 }
@@ -2081,6 +2273,7 @@ fn wire__web_audio_api__AudioRenderCapacityEvent_auto_accessor_get_peak_load_imp
             debug_name: "AudioRenderCapacityEvent_auto_accessor_get_peak_load",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -2106,7 +2299,14 @@ fn wire__web_audio_api__AudioRenderCapacityEvent_auto_accessor_get_peak_load_imp
                     ]);
                 for i in decode_indices_ {
                     match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        0 => {
+                            api_that_guard =
+                                Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                    "AudioRenderCapacityEvent_auto_accessor_get_peak_load",
+                                    "that",
+                                    || api_that.lockable_decode_sync_ref(),
+                                ))
+                        }
                         _ => unreachable!(),
                     }
                 }
@@ -2127,6 +2327,7 @@ fn wire__web_audio_api__AudioRenderCapacityEvent_auto_accessor_get_timestamp_imp
             debug_name: "AudioRenderCapacityEvent_auto_accessor_get_timestamp",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -2152,7 +2353,14 @@ fn wire__web_audio_api__AudioRenderCapacityEvent_auto_accessor_get_timestamp_imp
                     ]);
                 for i in decode_indices_ {
                     match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        0 => {
+                            api_that_guard =
+                                Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                    "AudioRenderCapacityEvent_auto_accessor_get_timestamp",
+                                    "that",
+                                    || api_that.lockable_decode_sync_ref(),
+                                ))
+                        }
                         _ => unreachable!(),
                     }
                 }
@@ -2173,6 +2381,7 @@ fn wire__web_audio_api__AudioRenderCapacityEvent_auto_accessor_get_underrun_rati
             debug_name: "AudioRenderCapacityEvent_auto_accessor_get_underrun_ratio",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -2198,7 +2407,14 @@ fn wire__web_audio_api__AudioRenderCapacityEvent_auto_accessor_get_underrun_rati
                     ]);
                 for i in decode_indices_ {
                     match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        0 => {
+                            api_that_guard =
+                                Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                    "AudioRenderCapacityEvent_auto_accessor_get_underrun_ratio",
+                                    "that",
+                                    || api_that.lockable_decode_sync_ref(),
+                                ))
+                        }
                         _ => unreachable!(),
                     }
                 }
@@ -2219,6 +2435,7 @@ fn wire__web_audio_api__AudioRenderCapacityEvent_auto_accessor_set_average_load_
             debug_name: "AudioRenderCapacityEvent_auto_accessor_set_average_load",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -2245,7 +2462,14 @@ fn wire__web_audio_api__AudioRenderCapacityEvent_auto_accessor_set_average_load_
                     ]);
                 for i in decode_indices_ {
                     match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                        0 => {
+                            api_that_guard =
+                                Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                    "AudioRenderCapacityEvent_auto_accessor_set_average_load",
+                                    "that",
+                                    || api_that.lockable_decode_sync_ref_mut(),
+                                ))
+                        }
                         _ => unreachable!(),
                     }
                 }
@@ -2270,6 +2494,7 @@ fn wire__web_audio_api__AudioRenderCapacityEvent_auto_accessor_set_event_impl(
             debug_name: "AudioRenderCapacityEvent_auto_accessor_set_event",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -2296,7 +2521,14 @@ fn wire__web_audio_api__AudioRenderCapacityEvent_auto_accessor_set_event_impl(
                     ]);
                 for i in decode_indices_ {
                     match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                        0 => {
+                            api_that_guard =
+                                Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                    "AudioRenderCapacityEvent_auto_accessor_set_event",
+                                    "that",
+                                    || api_that.lockable_decode_sync_ref_mut(),
+                                ))
+                        }
                         _ => unreachable!(),
                     }
                 }
@@ -2321,6 +2553,7 @@ fn wire__web_audio_api__AudioRenderCapacityEvent_auto_accessor_set_peak_load_imp
             debug_name: "AudioRenderCapacityEvent_auto_accessor_set_peak_load",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -2347,7 +2580,14 @@ fn wire__web_audio_api__AudioRenderCapacityEvent_auto_accessor_set_peak_load_imp
                     ]);
                 for i in decode_indices_ {
                     match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                        0 => {
+                            api_that_guard =
+                                Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                    "AudioRenderCapacityEvent_auto_accessor_set_peak_load",
+                                    "that",
+                                    || api_that.lockable_decode_sync_ref_mut(),
+                                ))
+                        }
                         _ => unreachable!(),
                     }
                 }
@@ -2372,6 +2612,7 @@ fn wire__web_audio_api__AudioRenderCapacityEvent_auto_accessor_set_timestamp_imp
             debug_name: "AudioRenderCapacityEvent_auto_accessor_set_timestamp",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -2398,7 +2639,14 @@ fn wire__web_audio_api__AudioRenderCapacityEvent_auto_accessor_set_timestamp_imp
                     ]);
                 for i in decode_indices_ {
                     match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                        0 => {
+                            api_that_guard =
+                                Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                    "AudioRenderCapacityEvent_auto_accessor_set_timestamp",
+                                    "that",
+                                    || api_that.lockable_decode_sync_ref_mut(),
+                                ))
+                        }
                         _ => unreachable!(),
                     }
                 }
@@ -2423,6 +2671,7 @@ fn wire__web_audio_api__AudioRenderCapacityEvent_auto_accessor_set_underrun_rati
             debug_name: "AudioRenderCapacityEvent_auto_accessor_set_underrun_ratio",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -2449,7 +2698,14 @@ fn wire__web_audio_api__AudioRenderCapacityEvent_auto_accessor_set_underrun_rati
                     ]);
                 for i in decode_indices_ {
                     match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                        0 => {
+                            api_that_guard =
+                                Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                    "AudioRenderCapacityEvent_auto_accessor_set_underrun_ratio",
+                                    "that",
+                                    || api_that.lockable_decode_sync_ref_mut(),
+                                ))
+                        }
                         _ => unreachable!(),
                     }
                 }
@@ -2475,6 +2731,7 @@ fn wire__web_audio_api__AudioRenderCapacity_clear_onupdate_impl(
             debug_name: "AudioRenderCapacity_clear_onupdate",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -2501,7 +2758,14 @@ fn wire__web_audio_api__AudioRenderCapacity_clear_onupdate_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioRenderCapacity_clear_onupdate",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -2526,6 +2790,7 @@ fn wire__web_audio_api__AudioRenderCapacity_start_impl(
             debug_name: "AudioRenderCapacity_start",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -2554,7 +2819,14 @@ fn wire__web_audio_api__AudioRenderCapacity_start_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioRenderCapacity_start",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -2579,6 +2851,7 @@ fn wire__web_audio_api__AudioRenderCapacity_stop_impl(
             debug_name: "AudioRenderCapacity_stop",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -2605,7 +2878,14 @@ fn wire__web_audio_api__AudioRenderCapacity_stop_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioRenderCapacity_stop",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -2629,6 +2909,7 @@ fn wire__web_audio_api__Event_type__impl(
             debug_name: "Event_type_",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -2654,7 +2935,14 @@ fn wire__web_audio_api__Event_type__impl(
                     ]);
                 for i in decode_indices_ {
                     match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        0 => {
+                            api_that_guard =
+                                Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                    "Event_type_",
+                                    "that",
+                                    || api_that.lockable_decode_sync_ref(),
+                                ))
+                        }
                         _ => unreachable!(),
                     }
                 }
@@ -2675,6 +2963,7 @@ fn wire__web_audio_api__OfflineAudioCompletionEvent_auto_accessor_get_event_impl
             debug_name: "OfflineAudioCompletionEvent_auto_accessor_get_event",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -2702,7 +2991,14 @@ fn wire__web_audio_api__OfflineAudioCompletionEvent_auto_accessor_get_event_impl
                     ]);
                 for i in decode_indices_ {
                     match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        0 => {
+                            api_that_guard =
+                                Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                    "OfflineAudioCompletionEvent_auto_accessor_get_event",
+                                    "that",
+                                    || api_that.lockable_decode_sync_ref(),
+                                ))
+                        }
                         _ => unreachable!(),
                     }
                 }
@@ -2723,6 +3019,7 @@ fn wire__web_audio_api__OfflineAudioCompletionEvent_auto_accessor_get_rendered_b
             debug_name: "OfflineAudioCompletionEvent_auto_accessor_get_rendered_buffer",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -2750,7 +3047,14 @@ fn wire__web_audio_api__OfflineAudioCompletionEvent_auto_accessor_get_rendered_b
                     ]);
                 for i in decode_indices_ {
                     match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        0 => {
+                            api_that_guard =
+                                Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                    "OfflineAudioCompletionEvent_auto_accessor_get_rendered_buffer",
+                                    "that",
+                                    || api_that.lockable_decode_sync_ref(),
+                                ))
+                        }
                         _ => unreachable!(),
                     }
                 }
@@ -2771,6 +3075,7 @@ fn wire__web_audio_api__OfflineAudioCompletionEvent_auto_accessor_set_event_impl
             debug_name: "OfflineAudioCompletionEvent_auto_accessor_set_event",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -2799,7 +3104,14 @@ fn wire__web_audio_api__OfflineAudioCompletionEvent_auto_accessor_set_event_impl
                     ]);
                 for i in decode_indices_ {
                     match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                        0 => {
+                            api_that_guard =
+                                Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                    "OfflineAudioCompletionEvent_auto_accessor_set_event",
+                                    "that",
+                                    || api_that.lockable_decode_sync_ref_mut(),
+                                ))
+                        }
                         _ => unreachable!(),
                     }
                 }
@@ -2824,6 +3136,7 @@ fn wire__web_audio_api__OfflineAudioCompletionEvent_auto_accessor_set_rendered_b
             debug_name: "OfflineAudioCompletionEvent_auto_accessor_set_rendered_buffer",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -2852,7 +3165,14 @@ fn wire__web_audio_api__OfflineAudioCompletionEvent_auto_accessor_set_rendered_b
                     ]);
                 for i in decode_indices_ {
                     match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                        0 => {
+                            api_that_guard =
+                                Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                    "OfflineAudioCompletionEvent_auto_accessor_set_rendered_buffer",
+                                    "that",
+                                    || api_that.lockable_decode_sync_ref_mut(),
+                                ))
+                        }
                         _ => unreachable!(),
                     }
                 }
@@ -2878,6 +3198,7 @@ fn wire__web_audio_api__PeriodicWave_default_impl(
             debug_name: "PeriodicWave_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -2910,6 +3231,7 @@ fn wire__web_audio_api__audio_render_capacity_options_default_impl(
             debug_name: "audio_render_capacity_options_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -2943,6 +3265,7 @@ fn wire__web_audio_api__periodic_wave_options_default_impl(
             debug_name: "periodic_wave_options_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -2976,6 +3299,7 @@ fn wire__web_audio_api__context__AudioContext_base_latency_impl(
             debug_name: "AudioContext_base_latency",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -3002,7 +3326,14 @@ fn wire__web_audio_api__context__AudioContext_base_latency_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioContext_base_latency",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -3027,6 +3358,7 @@ fn wire__web_audio_api__context__AudioContext_clear_onsinkchange_impl(
             debug_name: "AudioContext_clear_onsinkchange",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -3053,7 +3385,14 @@ fn wire__web_audio_api__context__AudioContext_clear_onsinkchange_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioContext_clear_onsinkchange",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -3078,6 +3417,7 @@ fn wire__web_audio_api__context__AudioContext_clear_onstatechange_impl(
             debug_name: "AudioContext_clear_onstatechange",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -3104,7 +3444,14 @@ fn wire__web_audio_api__context__AudioContext_clear_onstatechange_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioContext_clear_onstatechange",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -3129,6 +3476,7 @@ fn wire__web_audio_api__context__AudioContext_close_impl(
             debug_name: "AudioContext_close",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -3156,10 +3504,14 @@ fn wire__web_audio_api__context__AudioContext_close_impl(
                             );
                         for i in decode_indices_ {
                             match i {
-                                0 => {
-                                    api_that_guard =
-                                        Some(api_that.lockable_decode_async_ref().await)
-                                }
+                                0 => api_that_guard = Some(
+                                    flutter_rust_bridge::for_generated::lockable_with_site_async(
+                                        "AudioContext_close",
+                                        "that",
+                                        api_that.lockable_decode_async_ref(),
+                                    )
+                                    .await,
+                                ),
                                 _ => unreachable!(),
                             }
                         }
@@ -3186,6 +3538,7 @@ fn wire__web_audio_api__context__AudioContext_close_sync_impl(
             debug_name: "AudioContext_close_sync",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -3212,7 +3565,14 @@ fn wire__web_audio_api__context__AudioContext_close_sync_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioContext_close_sync",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -3237,6 +3597,7 @@ fn wire__web_audio_api__context__AudioContext_create_analyser_impl(
             debug_name: "AudioContext_create_analyser",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -3263,7 +3624,14 @@ fn wire__web_audio_api__context__AudioContext_create_analyser_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioContext_create_analyser",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -3288,6 +3656,7 @@ fn wire__web_audio_api__context__AudioContext_create_audio_param_impl(
             debug_name: "AudioContext_create_audio_param",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -3322,8 +3691,22 @@ fn wire__web_audio_api__context__AudioContext_create_audio_param_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                            1 => api_dest_guard = Some(api_dest.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioContext_create_audio_param",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
+                            1 => {
+                                api_dest_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioContext_create_audio_param",
+                                        "dest",
+                                        || api_dest.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -3353,6 +3736,7 @@ fn wire__web_audio_api__context__AudioContext_create_biquad_filter_impl(
             debug_name: "AudioContext_create_biquad_filter",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -3379,7 +3763,14 @@ fn wire__web_audio_api__context__AudioContext_create_biquad_filter_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioContext_create_biquad_filter",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -3406,6 +3797,7 @@ fn wire__web_audio_api__context__AudioContext_create_buffer_impl(
             debug_name: "AudioContext_create_buffer",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -3435,7 +3827,14 @@ fn wire__web_audio_api__context__AudioContext_create_buffer_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioContext_create_buffer",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -3464,6 +3863,7 @@ fn wire__web_audio_api__context__AudioContext_create_buffer_source_impl(
             debug_name: "AudioContext_create_buffer_source",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -3490,7 +3890,14 @@ fn wire__web_audio_api__context__AudioContext_create_buffer_source_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioContext_create_buffer_source",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -3517,6 +3924,7 @@ fn wire__web_audio_api__context__AudioContext_create_channel_merger_impl(
             debug_name: "AudioContext_create_channel_merger",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -3544,7 +3952,14 @@ fn wire__web_audio_api__context__AudioContext_create_channel_merger_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioContext_create_channel_merger",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -3572,6 +3987,7 @@ fn wire__web_audio_api__context__AudioContext_create_channel_splitter_impl(
             debug_name: "AudioContext_create_channel_splitter",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -3599,7 +4015,14 @@ fn wire__web_audio_api__context__AudioContext_create_channel_splitter_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioContext_create_channel_splitter",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -3627,6 +4050,7 @@ fn wire__web_audio_api__context__AudioContext_create_constant_source_impl(
             debug_name: "AudioContext_create_constant_source",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -3653,7 +4077,14 @@ fn wire__web_audio_api__context__AudioContext_create_constant_source_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioContext_create_constant_source",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -3680,6 +4111,7 @@ fn wire__web_audio_api__context__AudioContext_create_convolver_impl(
             debug_name: "AudioContext_create_convolver",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -3706,7 +4138,14 @@ fn wire__web_audio_api__context__AudioContext_create_convolver_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioContext_create_convolver",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -3731,6 +4170,7 @@ fn wire__web_audio_api__context__AudioContext_create_delay_impl(
             debug_name: "AudioContext_create_delay",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -3758,7 +4198,14 @@ fn wire__web_audio_api__context__AudioContext_create_delay_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioContext_create_delay",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -3785,6 +4232,7 @@ fn wire__web_audio_api__context__AudioContext_create_dynamics_compressor_impl(
             debug_name: "AudioContext_create_dynamics_compressor",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -3811,7 +4259,14 @@ fn wire__web_audio_api__context__AudioContext_create_dynamics_compressor_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioContext_create_dynamics_compressor",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -3838,6 +4293,7 @@ fn wire__web_audio_api__context__AudioContext_create_gain_impl(
             debug_name: "AudioContext_create_gain",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -3864,7 +4320,14 @@ fn wire__web_audio_api__context__AudioContext_create_gain_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioContext_create_gain",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -3889,6 +4352,7 @@ fn wire__web_audio_api__context__AudioContext_create_iir_filter_impl(
             debug_name: "AudioContext_create_iir_filter",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -3917,7 +4381,14 @@ fn wire__web_audio_api__context__AudioContext_create_iir_filter_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioContext_create_iir_filter",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -3946,6 +4417,7 @@ fn wire__web_audio_api__context__AudioContext_create_media_stream_destination_im
             debug_name: "AudioContext_create_media_stream_destination",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -3972,7 +4444,14 @@ fn wire__web_audio_api__context__AudioContext_create_media_stream_destination_im
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioContext_create_media_stream_destination",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -3994,10 +4473,10 @@ fn wire__web_audio_api__context__AudioContext_create_media_stream_source_impl(
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "AudioContext_create_media_stream_source", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
-            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
-            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioContext>>>::sse_decode(&mut deserializer);
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "AudioContext_create_media_stream_source", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal, executor: None }, move || { 
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioContext>>>::sse_decode(&mut deserializer);
 let api_media = <crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaStreamProxyEnum>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
                     transform_result_sse::<_, ()>((move ||  {
                         let mut api_that_guard = None;
@@ -4005,8 +4484,8 @@ let mut api_media_guard = None;
 let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false), flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_media, 1, false)]);
         for i in decode_indices_ {
             match i {
-                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-1 => api_media_guard = Some(api_media.lockable_decode_sync_ref()),
+                0 => api_that_guard = Some(flutter_rust_bridge::for_generated::lockable_with_site("AudioContext_create_media_stream_source", "that", || api_that.lockable_decode_sync_ref())),
+1 => api_media_guard = Some(flutter_rust_bridge::for_generated::lockable_with_site("AudioContext_create_media_stream_source", "media", || api_media.lockable_decode_sync_ref())),
                 _ => unreachable!(),
             }
         }
@@ -4027,6 +4506,7 @@ fn wire__web_audio_api__context__AudioContext_create_media_stream_track_source_i
             debug_name: "AudioContext_create_media_stream_track_source",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -4060,8 +4540,22 @@ fn wire__web_audio_api__context__AudioContext_create_media_stream_track_source_i
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                            1 => api_media_guard = Some(api_media.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioContext_create_media_stream_track_source",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
+                            1 => {
+                                api_media_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioContext_create_media_stream_track_source",
+                                        "media",
+                                        || api_media.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -4090,6 +4584,7 @@ fn wire__web_audio_api__context__AudioContext_create_oscillator_impl(
             debug_name: "AudioContext_create_oscillator",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -4116,7 +4611,14 @@ fn wire__web_audio_api__context__AudioContext_create_oscillator_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioContext_create_oscillator",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -4141,6 +4643,7 @@ fn wire__web_audio_api__context__AudioContext_create_panner_impl(
             debug_name: "AudioContext_create_panner",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -4167,7 +4670,14 @@ fn wire__web_audio_api__context__AudioContext_create_panner_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioContext_create_panner",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -4192,6 +4702,7 @@ fn wire__web_audio_api__context__AudioContext_create_periodic_wave_impl(
             debug_name: "AudioContext_create_periodic_wave",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -4219,7 +4730,14 @@ fn wire__web_audio_api__context__AudioContext_create_periodic_wave_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioContext_create_periodic_wave",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -4247,6 +4765,7 @@ fn wire__web_audio_api__context__AudioContext_create_script_processor_impl(
             debug_name: "AudioContext_create_script_processor",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -4276,7 +4795,14 @@ fn wire__web_audio_api__context__AudioContext_create_script_processor_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioContext_create_script_processor",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -4306,6 +4832,7 @@ fn wire__web_audio_api__context__AudioContext_create_stereo_panner_impl(
             debug_name: "AudioContext_create_stereo_panner",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -4332,7 +4859,14 @@ fn wire__web_audio_api__context__AudioContext_create_stereo_panner_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioContext_create_stereo_panner",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -4359,6 +4893,7 @@ fn wire__web_audio_api__context__AudioContext_create_wave_shaper_impl(
             debug_name: "AudioContext_create_wave_shaper",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -4385,7 +4920,14 @@ fn wire__web_audio_api__context__AudioContext_create_wave_shaper_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioContext_create_wave_shaper",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -4410,6 +4952,7 @@ fn wire__web_audio_api__context__AudioContext_current_time_impl(
             debug_name: "AudioContext_current_time",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -4436,7 +4979,14 @@ fn wire__web_audio_api__context__AudioContext_current_time_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioContext_current_time",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -4461,6 +5011,7 @@ fn wire__web_audio_api__context__AudioContext_default_impl(
             debug_name: "AudioContext_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -4494,6 +5045,7 @@ fn wire__web_audio_api__context__AudioContext_destination_impl(
             debug_name: "AudioContext_destination",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -4520,7 +5072,14 @@ fn wire__web_audio_api__context__AudioContext_destination_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioContext_destination",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -4540,10 +5099,10 @@ fn wire__web_audio_api__context__AudioContext_frb_override_create_media_element_
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "AudioContext_frb_override_create_media_element_source", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
-            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
-            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioContext>>>::sse_decode(&mut deserializer);
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "AudioContext_frb_override_create_media_element_source", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal, executor: None }, move || { 
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioContext>>>::sse_decode(&mut deserializer);
 let api_media_element = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MyMediaElement>>>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
                     transform_result_sse::<_, ()>((move ||  {
                         let mut api_that_guard = None;
@@ -4551,8 +5110,8 @@ let mut api_media_element_guard = None;
 let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false), flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_media_element, 1, true)]);
         for i in decode_indices_ {
             match i {
-                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-1 => api_media_element_guard = Some(api_media_element.lockable_decode_sync_ref_mut()),
+                0 => api_that_guard = Some(flutter_rust_bridge::for_generated::lockable_with_site("AudioContext_frb_override_create_media_element_source", "that", || api_that.lockable_decode_sync_ref())),
+1 => api_media_element_guard = Some(flutter_rust_bridge::for_generated::lockable_with_site("AudioContext_frb_override_create_media_element_source", "media_element", || api_media_element.lockable_decode_sync_ref_mut())),
                 _ => unreachable!(),
             }
         }
@@ -4568,23 +5127,23 @@ fn wire__web_audio_api__context__AudioContext_frb_override_decode_audio_data_syn
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "AudioContext_frb_override_decode_audio_data_sync", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
-            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
-            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioContext>>>::sse_decode(&mut deserializer);
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "AudioContext_frb_override_decode_audio_data_sync", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal, executor: None }, move || { 
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioContext>>>::sse_decode(&mut deserializer);
 let api_input_path = <String>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
-                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move ||  {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(flutter_rust_bridge::frb_record_error_debug!(flutter_rust_bridge::for_generated::anyhow::Error, (move ||  {
                         let mut api_that_guard = None;
 let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
         for i in decode_indices_ {
             match i {
-                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                0 => api_that_guard = Some(flutter_rust_bridge::for_generated::lockable_with_site("AudioContext_frb_override_decode_audio_data_sync", "that", || api_that.lockable_decode_sync_ref())),
                 _ => unreachable!(),
             }
         }
         let api_that_guard = api_that_guard.unwrap();
  let output_ok = web_audio_api::context::AudioContext::frb_override_decode_audio_data_sync(&*api_that_guard, api_input_path)?;   Ok(output_ok)
-                    })())
+                    })()))
                 } })
 }
 fn wire__web_audio_api__context__AudioContext_listener_impl(
@@ -4598,6 +5157,7 @@ fn wire__web_audio_api__context__AudioContext_listener_impl(
             debug_name: "AudioContext_listener",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -4624,7 +5184,14 @@ fn wire__web_audio_api__context__AudioContext_listener_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioContext_listener",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -4648,6 +5215,7 @@ fn wire__web_audio_api__context__AudioContext_new_impl(
             debug_name: "AudioContext_new",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -4681,6 +5249,7 @@ fn wire__web_audio_api__context__AudioContext_output_latency_impl(
             debug_name: "AudioContext_output_latency",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -4707,7 +5276,14 @@ fn wire__web_audio_api__context__AudioContext_output_latency_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioContext_output_latency",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -4732,6 +5308,7 @@ fn wire__web_audio_api__context__AudioContext_render_capacity_impl(
             debug_name: "AudioContext_render_capacity",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -4758,7 +5335,14 @@ fn wire__web_audio_api__context__AudioContext_render_capacity_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioContext_render_capacity",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -4783,6 +5367,7 @@ fn wire__web_audio_api__context__AudioContext_resume_sync_impl(
             debug_name: "AudioContext_resume_sync",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -4809,7 +5394,14 @@ fn wire__web_audio_api__context__AudioContext_resume_sync_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioContext_resume_sync",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -4834,6 +5426,7 @@ fn wire__web_audio_api__context__AudioContext_sample_rate_impl(
             debug_name: "AudioContext_sample_rate",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -4860,7 +5453,14 @@ fn wire__web_audio_api__context__AudioContext_sample_rate_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioContext_sample_rate",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -4880,17 +5480,17 @@ fn wire__web_audio_api__context__AudioContext_set_on_state_change_impl(
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "AudioContext_set_on_state_change", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
-            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
-            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioContext>>>::sse_decode(&mut deserializer);
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "AudioContext_set_on_state_change", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal, executor: None }, move || { 
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioContext>>>::sse_decode(&mut deserializer);
 let api_callback = decode_DartFn_Inputs_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEvent_Output_unit_AnyhowException(<flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer));deserializer.end(); move |context|  {
                     transform_result_sse::<_, ()>((move ||  {
                         let mut api_that_guard = None;
 let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
         for i in decode_indices_ {
             match i {
-                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                0 => api_that_guard = Some(flutter_rust_bridge::for_generated::lockable_with_site("AudioContext_set_on_state_change", "that", || api_that.lockable_decode_sync_ref())),
                 _ => unreachable!(),
             }
         }
@@ -4910,6 +5510,7 @@ fn wire__web_audio_api__context__AudioContext_set_sink_id_impl(
             debug_name: "AudioContext_set_sink_id",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -4928,27 +5529,40 @@ fn wire__web_audio_api__context__AudioContext_set_sink_id_impl(
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let mut api_that_guard = None;
-                        let decode_indices_ =
-                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
-                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                                    &api_that, 0, false,
-                                )],
-                            );
-                        for i in decode_indices_ {
-                            match i {
-                                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                                _ => unreachable!(),
+                    flutter_rust_bridge::frb_record_error_debug!(
+                        flutter_rust_bridge::for_generated::anyhow::Error,
+                        (move || {
+                            let mut api_that_guard = None;
+                            let decode_indices_ =
+                                flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                    vec![
+                                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                            &api_that, 0, false,
+                                        ),
+                                    ],
+                                );
+                            for i in decode_indices_ {
+                                match i {
+                                    0 => {
+                                        api_that_guard = Some(
+                                            flutter_rust_bridge::for_generated::lockable_with_site(
+                                                "AudioContext_set_sink_id",
+                                                "that",
+                                                || api_that.lockable_decode_sync_ref(),
+                                            ),
+                                        )
+                                    }
+                                    _ => unreachable!(),
+                                }
                             }
-                        }
-                        let api_that_guard = api_that_guard.unwrap();
-                        let output_ok = web_audio_api::context::AudioContext::set_sink_id(
-                            &*api_that_guard,
-                            api_sink_id,
-                        )?;
-                        Ok(output_ok)
-                    })(),
+                            let api_that_guard = api_that_guard.unwrap();
+                            let output_ok = web_audio_api::context::AudioContext::set_sink_id(
+                                &*api_that_guard,
+                                api_sink_id,
+                            )?;
+                            Ok(output_ok)
+                        })()
+                    ),
                 )
             }
         },
@@ -4965,6 +5579,7 @@ fn wire__web_audio_api__context__AudioContext_sink_id_impl(
             debug_name: "AudioContext_sink_id",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -4991,7 +5606,14 @@ fn wire__web_audio_api__context__AudioContext_sink_id_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioContext_sink_id",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -5016,6 +5638,7 @@ fn wire__web_audio_api__context__AudioContext_state_impl(
             debug_name: "AudioContext_state",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -5042,7 +5665,14 @@ fn wire__web_audio_api__context__AudioContext_state_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioContext_state",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -5067,6 +5697,7 @@ fn wire__web_audio_api__context__AudioContext_suspend_impl(
             debug_name: "AudioContext_suspend",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -5094,10 +5725,14 @@ fn wire__web_audio_api__context__AudioContext_suspend_impl(
                             );
                         for i in decode_indices_ {
                             match i {
-                                0 => {
-                                    api_that_guard =
-                                        Some(api_that.lockable_decode_async_ref().await)
-                                }
+                                0 => api_that_guard = Some(
+                                    flutter_rust_bridge::for_generated::lockable_with_site_async(
+                                        "AudioContext_suspend",
+                                        "that",
+                                        api_that.lockable_decode_async_ref(),
+                                    )
+                                    .await,
+                                ),
                                 _ => unreachable!(),
                             }
                         }
@@ -5124,6 +5759,7 @@ fn wire__web_audio_api__context__AudioContext_suspend_sync_impl(
             debug_name: "AudioContext_suspend_sync",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -5150,7 +5786,14 @@ fn wire__web_audio_api__context__AudioContext_suspend_sync_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioContext_suspend_sync",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -5175,6 +5818,7 @@ fn wire__web_audio_api__context__ConcreteBaseAudioContext_clear_onstatechange_im
             debug_name: "ConcreteBaseAudioContext_clear_onstatechange",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -5201,7 +5845,14 @@ fn wire__web_audio_api__context__ConcreteBaseAudioContext_clear_onstatechange_im
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ConcreteBaseAudioContext_clear_onstatechange",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -5228,6 +5879,7 @@ fn wire__web_audio_api__context__ConcreteBaseAudioContext_create_analyser_impl(
             debug_name: "ConcreteBaseAudioContext_create_analyser",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -5254,7 +5906,14 @@ fn wire__web_audio_api__context__ConcreteBaseAudioContext_create_analyser_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ConcreteBaseAudioContext_create_analyser",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -5281,6 +5940,7 @@ fn wire__web_audio_api__context__ConcreteBaseAudioContext_create_audio_param_imp
             debug_name: "ConcreteBaseAudioContext_create_audio_param",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -5315,8 +5975,22 @@ fn wire__web_audio_api__context__ConcreteBaseAudioContext_create_audio_param_imp
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                            1 => api_dest_guard = Some(api_dest.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ConcreteBaseAudioContext_create_audio_param",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
+                            1 => {
+                                api_dest_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ConcreteBaseAudioContext_create_audio_param",
+                                        "dest",
+                                        || api_dest.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -5346,6 +6020,7 @@ fn wire__web_audio_api__context__ConcreteBaseAudioContext_create_biquad_filter_i
             debug_name: "ConcreteBaseAudioContext_create_biquad_filter",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -5372,7 +6047,14 @@ fn wire__web_audio_api__context__ConcreteBaseAudioContext_create_biquad_filter_i
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ConcreteBaseAudioContext_create_biquad_filter",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -5399,6 +6081,7 @@ fn wire__web_audio_api__context__ConcreteBaseAudioContext_create_buffer_impl(
             debug_name: "ConcreteBaseAudioContext_create_buffer",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -5428,7 +6111,14 @@ fn wire__web_audio_api__context__ConcreteBaseAudioContext_create_buffer_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ConcreteBaseAudioContext_create_buffer",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -5458,6 +6148,7 @@ fn wire__web_audio_api__context__ConcreteBaseAudioContext_create_buffer_source_i
             debug_name: "ConcreteBaseAudioContext_create_buffer_source",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -5484,7 +6175,14 @@ fn wire__web_audio_api__context__ConcreteBaseAudioContext_create_buffer_source_i
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ConcreteBaseAudioContext_create_buffer_source",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -5511,6 +6209,7 @@ fn wire__web_audio_api__context__ConcreteBaseAudioContext_create_channel_merger_
             debug_name: "ConcreteBaseAudioContext_create_channel_merger",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -5538,7 +6237,14 @@ fn wire__web_audio_api__context__ConcreteBaseAudioContext_create_channel_merger_
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ConcreteBaseAudioContext_create_channel_merger",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -5566,6 +6272,7 @@ fn wire__web_audio_api__context__ConcreteBaseAudioContext_create_channel_splitte
             debug_name: "ConcreteBaseAudioContext_create_channel_splitter",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -5593,7 +6300,14 @@ fn wire__web_audio_api__context__ConcreteBaseAudioContext_create_channel_splitte
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ConcreteBaseAudioContext_create_channel_splitter",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -5621,6 +6335,7 @@ fn wire__web_audio_api__context__ConcreteBaseAudioContext_create_constant_source
             debug_name: "ConcreteBaseAudioContext_create_constant_source",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -5647,7 +6362,14 @@ fn wire__web_audio_api__context__ConcreteBaseAudioContext_create_constant_source
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ConcreteBaseAudioContext_create_constant_source",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -5674,6 +6396,7 @@ fn wire__web_audio_api__context__ConcreteBaseAudioContext_create_convolver_impl(
             debug_name: "ConcreteBaseAudioContext_create_convolver",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -5700,7 +6423,14 @@ fn wire__web_audio_api__context__ConcreteBaseAudioContext_create_convolver_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ConcreteBaseAudioContext_create_convolver",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -5727,6 +6457,7 @@ fn wire__web_audio_api__context__ConcreteBaseAudioContext_create_delay_impl(
             debug_name: "ConcreteBaseAudioContext_create_delay",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -5754,7 +6485,14 @@ fn wire__web_audio_api__context__ConcreteBaseAudioContext_create_delay_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ConcreteBaseAudioContext_create_delay",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -5777,16 +6515,16 @@ fn wire__web_audio_api__context__ConcreteBaseAudioContext_create_dynamics_compre
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "ConcreteBaseAudioContext_create_dynamics_compressor", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
-            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
-            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ConcreteBaseAudioContext>>>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "ConcreteBaseAudioContext_create_dynamics_compressor", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal, executor: None }, move || { 
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ConcreteBaseAudioContext>>>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
                     transform_result_sse::<_, ()>((move ||  {
                         let mut api_that_guard = None;
 let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
         for i in decode_indices_ {
             match i {
-                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                0 => api_that_guard = Some(flutter_rust_bridge::for_generated::lockable_with_site("ConcreteBaseAudioContext_create_dynamics_compressor", "that", || api_that.lockable_decode_sync_ref())),
                 _ => unreachable!(),
             }
         }
@@ -5806,6 +6544,7 @@ fn wire__web_audio_api__context__ConcreteBaseAudioContext_create_gain_impl(
             debug_name: "ConcreteBaseAudioContext_create_gain",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -5832,7 +6571,14 @@ fn wire__web_audio_api__context__ConcreteBaseAudioContext_create_gain_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ConcreteBaseAudioContext_create_gain",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -5859,6 +6605,7 @@ fn wire__web_audio_api__context__ConcreteBaseAudioContext_create_iir_filter_impl
             debug_name: "ConcreteBaseAudioContext_create_iir_filter",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -5887,7 +6634,14 @@ fn wire__web_audio_api__context__ConcreteBaseAudioContext_create_iir_filter_impl
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ConcreteBaseAudioContext_create_iir_filter",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -5916,6 +6670,7 @@ fn wire__web_audio_api__context__ConcreteBaseAudioContext_create_oscillator_impl
             debug_name: "ConcreteBaseAudioContext_create_oscillator",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -5942,7 +6697,14 @@ fn wire__web_audio_api__context__ConcreteBaseAudioContext_create_oscillator_impl
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ConcreteBaseAudioContext_create_oscillator",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -5969,6 +6731,7 @@ fn wire__web_audio_api__context__ConcreteBaseAudioContext_create_panner_impl(
             debug_name: "ConcreteBaseAudioContext_create_panner",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -5995,7 +6758,14 @@ fn wire__web_audio_api__context__ConcreteBaseAudioContext_create_panner_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ConcreteBaseAudioContext_create_panner",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -6022,6 +6792,7 @@ fn wire__web_audio_api__context__ConcreteBaseAudioContext_create_periodic_wave_i
             debug_name: "ConcreteBaseAudioContext_create_periodic_wave",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -6049,7 +6820,14 @@ fn wire__web_audio_api__context__ConcreteBaseAudioContext_create_periodic_wave_i
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ConcreteBaseAudioContext_create_periodic_wave",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -6077,6 +6855,7 @@ fn wire__web_audio_api__context__ConcreteBaseAudioContext_create_script_processo
             debug_name: "ConcreteBaseAudioContext_create_script_processor",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -6106,7 +6885,14 @@ fn wire__web_audio_api__context__ConcreteBaseAudioContext_create_script_processo
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ConcreteBaseAudioContext_create_script_processor",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -6136,6 +6922,7 @@ fn wire__web_audio_api__context__ConcreteBaseAudioContext_create_stereo_panner_i
             debug_name: "ConcreteBaseAudioContext_create_stereo_panner",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -6162,7 +6949,14 @@ fn wire__web_audio_api__context__ConcreteBaseAudioContext_create_stereo_panner_i
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ConcreteBaseAudioContext_create_stereo_panner",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -6189,6 +6983,7 @@ fn wire__web_audio_api__context__ConcreteBaseAudioContext_create_wave_shaper_imp
             debug_name: "ConcreteBaseAudioContext_create_wave_shaper",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -6215,7 +7010,14 @@ fn wire__web_audio_api__context__ConcreteBaseAudioContext_create_wave_shaper_imp
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ConcreteBaseAudioContext_create_wave_shaper",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -6242,6 +7044,7 @@ fn wire__web_audio_api__context__ConcreteBaseAudioContext_current_time_impl(
             debug_name: "ConcreteBaseAudioContext_current_time",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -6268,7 +7071,14 @@ fn wire__web_audio_api__context__ConcreteBaseAudioContext_current_time_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ConcreteBaseAudioContext_current_time",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -6295,6 +7105,7 @@ fn wire__web_audio_api__context__ConcreteBaseAudioContext_destination_impl(
             debug_name: "ConcreteBaseAudioContext_destination",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -6321,7 +7132,14 @@ fn wire__web_audio_api__context__ConcreteBaseAudioContext_destination_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ConcreteBaseAudioContext_destination",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -6348,6 +7166,7 @@ fn wire__web_audio_api__context__ConcreteBaseAudioContext_listener_impl(
             debug_name: "ConcreteBaseAudioContext_listener",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -6374,7 +7193,14 @@ fn wire__web_audio_api__context__ConcreteBaseAudioContext_listener_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ConcreteBaseAudioContext_listener",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -6401,6 +7227,7 @@ fn wire__web_audio_api__context__ConcreteBaseAudioContext_mark_cycle_breaker_imp
             debug_name: "ConcreteBaseAudioContext_mark_cycle_breaker",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -6434,8 +7261,22 @@ fn wire__web_audio_api__context__ConcreteBaseAudioContext_mark_cycle_breaker_imp
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                            1 => api_reg_guard = Some(api_reg.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ConcreteBaseAudioContext_mark_cycle_breaker",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
+                            1 => {
+                                api_reg_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ConcreteBaseAudioContext_mark_cycle_breaker",
+                                        "reg",
+                                        || api_reg.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -6464,6 +7305,7 @@ fn wire__web_audio_api__context__ConcreteBaseAudioContext_sample_rate_impl(
             debug_name: "ConcreteBaseAudioContext_sample_rate",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -6490,7 +7332,14 @@ fn wire__web_audio_api__context__ConcreteBaseAudioContext_sample_rate_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ConcreteBaseAudioContext_sample_rate",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -6517,6 +7366,7 @@ fn wire__web_audio_api__context__ConcreteBaseAudioContext_state_impl(
             debug_name: "ConcreteBaseAudioContext_state",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -6543,7 +7393,14 @@ fn wire__web_audio_api__context__ConcreteBaseAudioContext_state_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ConcreteBaseAudioContext_state",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -6568,6 +7425,7 @@ fn wire__web_audio_api__context__OfflineAudioContext_clear_oncomplete_impl(
             debug_name: "OfflineAudioContext_clear_oncomplete",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -6594,7 +7452,14 @@ fn wire__web_audio_api__context__OfflineAudioContext_clear_oncomplete_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "OfflineAudioContext_clear_oncomplete",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -6621,6 +7486,7 @@ fn wire__web_audio_api__context__OfflineAudioContext_clear_onstatechange_impl(
             debug_name: "OfflineAudioContext_clear_onstatechange",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -6647,7 +7513,14 @@ fn wire__web_audio_api__context__OfflineAudioContext_clear_onstatechange_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "OfflineAudioContext_clear_onstatechange",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -6674,6 +7547,7 @@ fn wire__web_audio_api__context__OfflineAudioContext_create_analyser_impl(
             debug_name: "OfflineAudioContext_create_analyser",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -6700,7 +7574,14 @@ fn wire__web_audio_api__context__OfflineAudioContext_create_analyser_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "OfflineAudioContext_create_analyser",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -6727,6 +7608,7 @@ fn wire__web_audio_api__context__OfflineAudioContext_create_audio_param_impl(
             debug_name: "OfflineAudioContext_create_audio_param",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -6761,8 +7643,22 @@ fn wire__web_audio_api__context__OfflineAudioContext_create_audio_param_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                            1 => api_dest_guard = Some(api_dest.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "OfflineAudioContext_create_audio_param",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
+                            1 => {
+                                api_dest_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "OfflineAudioContext_create_audio_param",
+                                        "dest",
+                                        || api_dest.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -6792,6 +7688,7 @@ fn wire__web_audio_api__context__OfflineAudioContext_create_biquad_filter_impl(
             debug_name: "OfflineAudioContext_create_biquad_filter",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -6818,7 +7715,14 @@ fn wire__web_audio_api__context__OfflineAudioContext_create_biquad_filter_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "OfflineAudioContext_create_biquad_filter",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -6845,6 +7749,7 @@ fn wire__web_audio_api__context__OfflineAudioContext_create_buffer_impl(
             debug_name: "OfflineAudioContext_create_buffer",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -6874,7 +7779,14 @@ fn wire__web_audio_api__context__OfflineAudioContext_create_buffer_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "OfflineAudioContext_create_buffer",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -6904,6 +7816,7 @@ fn wire__web_audio_api__context__OfflineAudioContext_create_buffer_source_impl(
             debug_name: "OfflineAudioContext_create_buffer_source",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -6930,7 +7843,14 @@ fn wire__web_audio_api__context__OfflineAudioContext_create_buffer_source_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "OfflineAudioContext_create_buffer_source",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -6957,6 +7877,7 @@ fn wire__web_audio_api__context__OfflineAudioContext_create_channel_merger_impl(
             debug_name: "OfflineAudioContext_create_channel_merger",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -6984,7 +7905,14 @@ fn wire__web_audio_api__context__OfflineAudioContext_create_channel_merger_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "OfflineAudioContext_create_channel_merger",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -7012,6 +7940,7 @@ fn wire__web_audio_api__context__OfflineAudioContext_create_channel_splitter_imp
             debug_name: "OfflineAudioContext_create_channel_splitter",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -7039,7 +7968,14 @@ fn wire__web_audio_api__context__OfflineAudioContext_create_channel_splitter_imp
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "OfflineAudioContext_create_channel_splitter",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -7067,6 +8003,7 @@ fn wire__web_audio_api__context__OfflineAudioContext_create_constant_source_impl
             debug_name: "OfflineAudioContext_create_constant_source",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -7093,7 +8030,14 @@ fn wire__web_audio_api__context__OfflineAudioContext_create_constant_source_impl
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "OfflineAudioContext_create_constant_source",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -7120,6 +8064,7 @@ fn wire__web_audio_api__context__OfflineAudioContext_create_convolver_impl(
             debug_name: "OfflineAudioContext_create_convolver",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -7146,7 +8091,14 @@ fn wire__web_audio_api__context__OfflineAudioContext_create_convolver_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "OfflineAudioContext_create_convolver",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -7173,6 +8125,7 @@ fn wire__web_audio_api__context__OfflineAudioContext_create_delay_impl(
             debug_name: "OfflineAudioContext_create_delay",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -7200,7 +8153,14 @@ fn wire__web_audio_api__context__OfflineAudioContext_create_delay_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "OfflineAudioContext_create_delay",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -7228,6 +8188,7 @@ fn wire__web_audio_api__context__OfflineAudioContext_create_dynamics_compressor_
             debug_name: "OfflineAudioContext_create_dynamics_compressor",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -7254,7 +8215,14 @@ fn wire__web_audio_api__context__OfflineAudioContext_create_dynamics_compressor_
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "OfflineAudioContext_create_dynamics_compressor",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -7281,6 +8249,7 @@ fn wire__web_audio_api__context__OfflineAudioContext_create_gain_impl(
             debug_name: "OfflineAudioContext_create_gain",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -7307,7 +8276,14 @@ fn wire__web_audio_api__context__OfflineAudioContext_create_gain_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "OfflineAudioContext_create_gain",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -7332,6 +8308,7 @@ fn wire__web_audio_api__context__OfflineAudioContext_create_iir_filter_impl(
             debug_name: "OfflineAudioContext_create_iir_filter",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -7360,7 +8337,14 @@ fn wire__web_audio_api__context__OfflineAudioContext_create_iir_filter_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "OfflineAudioContext_create_iir_filter",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -7389,6 +8373,7 @@ fn wire__web_audio_api__context__OfflineAudioContext_create_oscillator_impl(
             debug_name: "OfflineAudioContext_create_oscillator",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -7415,7 +8400,14 @@ fn wire__web_audio_api__context__OfflineAudioContext_create_oscillator_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "OfflineAudioContext_create_oscillator",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -7442,6 +8434,7 @@ fn wire__web_audio_api__context__OfflineAudioContext_create_panner_impl(
             debug_name: "OfflineAudioContext_create_panner",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -7468,7 +8461,14 @@ fn wire__web_audio_api__context__OfflineAudioContext_create_panner_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "OfflineAudioContext_create_panner",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -7495,6 +8495,7 @@ fn wire__web_audio_api__context__OfflineAudioContext_create_periodic_wave_impl(
             debug_name: "OfflineAudioContext_create_periodic_wave",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -7522,7 +8523,14 @@ fn wire__web_audio_api__context__OfflineAudioContext_create_periodic_wave_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "OfflineAudioContext_create_periodic_wave",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -7550,6 +8558,7 @@ fn wire__web_audio_api__context__OfflineAudioContext_create_script_processor_imp
             debug_name: "OfflineAudioContext_create_script_processor",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -7579,7 +8588,14 @@ fn wire__web_audio_api__context__OfflineAudioContext_create_script_processor_imp
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "OfflineAudioContext_create_script_processor",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -7609,6 +8625,7 @@ fn wire__web_audio_api__context__OfflineAudioContext_create_stereo_panner_impl(
             debug_name: "OfflineAudioContext_create_stereo_panner",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -7635,7 +8652,14 @@ fn wire__web_audio_api__context__OfflineAudioContext_create_stereo_panner_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "OfflineAudioContext_create_stereo_panner",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -7662,6 +8686,7 @@ fn wire__web_audio_api__context__OfflineAudioContext_create_wave_shaper_impl(
             debug_name: "OfflineAudioContext_create_wave_shaper",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -7688,7 +8713,14 @@ fn wire__web_audio_api__context__OfflineAudioContext_create_wave_shaper_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "OfflineAudioContext_create_wave_shaper",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -7715,6 +8747,7 @@ fn wire__web_audio_api__context__OfflineAudioContext_current_time_impl(
             debug_name: "OfflineAudioContext_current_time",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -7741,7 +8774,14 @@ fn wire__web_audio_api__context__OfflineAudioContext_current_time_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "OfflineAudioContext_current_time",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -7766,6 +8806,7 @@ fn wire__web_audio_api__context__OfflineAudioContext_destination_impl(
             debug_name: "OfflineAudioContext_destination",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -7792,7 +8833,14 @@ fn wire__web_audio_api__context__OfflineAudioContext_destination_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "OfflineAudioContext_destination",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -7817,6 +8865,7 @@ fn wire__web_audio_api__context__OfflineAudioContext_length_impl(
             debug_name: "OfflineAudioContext_length",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -7843,7 +8892,14 @@ fn wire__web_audio_api__context__OfflineAudioContext_length_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "OfflineAudioContext_length",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -7868,6 +8924,7 @@ fn wire__web_audio_api__context__OfflineAudioContext_listener_impl(
             debug_name: "OfflineAudioContext_listener",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -7894,7 +8951,14 @@ fn wire__web_audio_api__context__OfflineAudioContext_listener_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "OfflineAudioContext_listener",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -7919,6 +8983,7 @@ fn wire__web_audio_api__context__OfflineAudioContext_new_impl(
             debug_name: "OfflineAudioContext_new",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -7959,6 +9024,7 @@ fn wire__web_audio_api__context__OfflineAudioContext_resume_impl(
             debug_name: "OfflineAudioContext_resume",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -7986,10 +9052,14 @@ fn wire__web_audio_api__context__OfflineAudioContext_resume_impl(
                             );
                         for i in decode_indices_ {
                             match i {
-                                0 => {
-                                    api_that_guard =
-                                        Some(api_that.lockable_decode_async_ref().await)
-                                }
+                                0 => api_that_guard = Some(
+                                    flutter_rust_bridge::for_generated::lockable_with_site_async(
+                                        "OfflineAudioContext_resume",
+                                        "that",
+                                        api_that.lockable_decode_async_ref(),
+                                    )
+                                    .await,
+                                ),
                                 _ => unreachable!(),
                             }
                         }
@@ -8017,6 +9087,7 @@ fn wire__web_audio_api__context__OfflineAudioContext_sample_rate_impl(
             debug_name: "OfflineAudioContext_sample_rate",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -8043,7 +9114,14 @@ fn wire__web_audio_api__context__OfflineAudioContext_sample_rate_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "OfflineAudioContext_sample_rate",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -8063,17 +9141,17 @@ fn wire__web_audio_api__context__OfflineAudioContext_set_on_complete_impl(
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "OfflineAudioContext_set_on_complete", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
-            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
-            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<OfflineAudioContext>>>::sse_decode(&mut deserializer);
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "OfflineAudioContext_set_on_complete", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal, executor: None }, move || { 
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<OfflineAudioContext>>>::sse_decode(&mut deserializer);
 let api_callback = decode_DartFn_Inputs_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOfflineAudioCompletionEvent_Output_unit_AnyhowException(<flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer));deserializer.end(); move |context|  {
                     transform_result_sse::<_, ()>((move ||  {
                         let mut api_that_guard = None;
 let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
         for i in decode_indices_ {
             match i {
-                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                0 => api_that_guard = Some(flutter_rust_bridge::for_generated::lockable_with_site("OfflineAudioContext_set_on_complete", "that", || api_that.lockable_decode_sync_ref())),
                 _ => unreachable!(),
             }
         }
@@ -8093,6 +9171,7 @@ fn wire__web_audio_api__context__OfflineAudioContext_start_rendering_impl(
             debug_name: "OfflineAudioContext_start_rendering",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -8120,10 +9199,14 @@ fn wire__web_audio_api__context__OfflineAudioContext_start_rendering_impl(
                             );
                         for i in decode_indices_ {
                             match i {
-                                0 => {
-                                    api_that_guard =
-                                        Some(api_that.lockable_decode_async_ref().await)
-                                }
+                                0 => api_that_guard = Some(
+                                    flutter_rust_bridge::for_generated::lockable_with_site_async(
+                                        "OfflineAudioContext_start_rendering",
+                                        "that",
+                                        api_that.lockable_decode_async_ref(),
+                                    )
+                                    .await,
+                                ),
                                 _ => unreachable!(),
                             }
                         }
@@ -8153,6 +9236,7 @@ fn wire__web_audio_api__context__OfflineAudioContext_start_rendering_sync_impl(
             debug_name: "OfflineAudioContext_start_rendering_sync",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -8179,7 +9263,14 @@ fn wire__web_audio_api__context__OfflineAudioContext_start_rendering_sync_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "OfflineAudioContext_start_rendering_sync",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref_mut(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -8206,6 +9297,7 @@ fn wire__web_audio_api__context__OfflineAudioContext_state_impl(
             debug_name: "OfflineAudioContext_state",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -8232,7 +9324,14 @@ fn wire__web_audio_api__context__OfflineAudioContext_state_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "OfflineAudioContext_state",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -8257,6 +9356,7 @@ fn wire__web_audio_api__context__OfflineAudioContext_suspend_impl(
             debug_name: "OfflineAudioContext_suspend",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -8285,10 +9385,14 @@ fn wire__web_audio_api__context__OfflineAudioContext_suspend_impl(
                             );
                         for i in decode_indices_ {
                             match i {
-                                0 => {
-                                    api_that_guard =
-                                        Some(api_that.lockable_decode_async_ref().await)
-                                }
+                                0 => api_that_guard = Some(
+                                    flutter_rust_bridge::for_generated::lockable_with_site_async(
+                                        "OfflineAudioContext_suspend",
+                                        "that",
+                                        api_that.lockable_decode_async_ref(),
+                                    )
+                                    .await,
+                                ),
                                 _ => unreachable!(),
                             }
                         }
@@ -8319,6 +9423,7 @@ fn wire__web_audio_api__context__audio_context_latency_category_default_impl(
             debug_name: "audio_context_latency_category_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -8353,6 +9458,7 @@ fn wire__web_audio_api__context__audio_context_options_default_impl(
             debug_name: "audio_context_options_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -8387,6 +9493,7 @@ fn wire__web_audio_api__media_devices__get_user_media_sync_impl(
             debug_name: "get_user_media_sync",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -8421,6 +9528,7 @@ fn wire__web_audio_api__media_recorder__BlobEvent_auto_accessor_get_blob_impl(
             debug_name: "BlobEvent_auto_accessor_get_blob",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -8446,7 +9554,14 @@ fn wire__web_audio_api__media_recorder__BlobEvent_auto_accessor_get_blob_impl(
                     ]);
                 for i in decode_indices_ {
                     match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        0 => {
+                            api_that_guard =
+                                Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                    "BlobEvent_auto_accessor_get_blob",
+                                    "that",
+                                    || api_that.lockable_decode_sync_ref(),
+                                ))
+                        }
                         _ => unreachable!(),
                     }
                 }
@@ -8467,6 +9582,7 @@ fn wire__web_audio_api__media_recorder__BlobEvent_auto_accessor_get_event_impl(
             debug_name: "BlobEvent_auto_accessor_get_event",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -8492,7 +9608,14 @@ fn wire__web_audio_api__media_recorder__BlobEvent_auto_accessor_get_event_impl(
                     ]);
                 for i in decode_indices_ {
                     match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        0 => {
+                            api_that_guard =
+                                Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                    "BlobEvent_auto_accessor_get_event",
+                                    "that",
+                                    || api_that.lockable_decode_sync_ref(),
+                                ))
+                        }
                         _ => unreachable!(),
                     }
                 }
@@ -8513,6 +9636,7 @@ fn wire__web_audio_api__media_recorder__BlobEvent_auto_accessor_get_timecode_imp
             debug_name: "BlobEvent_auto_accessor_get_timecode",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -8538,7 +9662,14 @@ fn wire__web_audio_api__media_recorder__BlobEvent_auto_accessor_get_timecode_imp
                     ]);
                 for i in decode_indices_ {
                     match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        0 => {
+                            api_that_guard =
+                                Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                    "BlobEvent_auto_accessor_get_timecode",
+                                    "that",
+                                    || api_that.lockable_decode_sync_ref(),
+                                ))
+                        }
                         _ => unreachable!(),
                     }
                 }
@@ -8559,6 +9690,7 @@ fn wire__web_audio_api__media_recorder__BlobEvent_auto_accessor_set_blob_impl(
             debug_name: "BlobEvent_auto_accessor_set_blob",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -8585,7 +9717,14 @@ fn wire__web_audio_api__media_recorder__BlobEvent_auto_accessor_set_blob_impl(
                     ]);
                 for i in decode_indices_ {
                     match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                        0 => {
+                            api_that_guard =
+                                Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                    "BlobEvent_auto_accessor_set_blob",
+                                    "that",
+                                    || api_that.lockable_decode_sync_ref_mut(),
+                                ))
+                        }
                         _ => unreachable!(),
                     }
                 }
@@ -8610,6 +9749,7 @@ fn wire__web_audio_api__media_recorder__BlobEvent_auto_accessor_set_event_impl(
             debug_name: "BlobEvent_auto_accessor_set_event",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -8636,7 +9776,14 @@ fn wire__web_audio_api__media_recorder__BlobEvent_auto_accessor_set_event_impl(
                     ]);
                 for i in decode_indices_ {
                     match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                        0 => {
+                            api_that_guard =
+                                Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                    "BlobEvent_auto_accessor_set_event",
+                                    "that",
+                                    || api_that.lockable_decode_sync_ref_mut(),
+                                ))
+                        }
                         _ => unreachable!(),
                     }
                 }
@@ -8661,6 +9808,7 @@ fn wire__web_audio_api__media_recorder__BlobEvent_auto_accessor_set_timecode_imp
             debug_name: "BlobEvent_auto_accessor_set_timecode",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -8687,7 +9835,14 @@ fn wire__web_audio_api__media_recorder__BlobEvent_auto_accessor_set_timecode_imp
                     ]);
                 for i in decode_indices_ {
                     match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                        0 => {
+                            api_that_guard =
+                                Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                    "BlobEvent_auto_accessor_set_timecode",
+                                    "that",
+                                    || api_that.lockable_decode_sync_ref_mut(),
+                                ))
+                        }
                         _ => unreachable!(),
                     }
                 }
@@ -8713,6 +9868,7 @@ fn wire__web_audio_api__media_recorder__MediaRecorder_clear_ondataavailable_impl
             debug_name: "MediaRecorder_clear_ondataavailable",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -8739,7 +9895,14 @@ fn wire__web_audio_api__media_recorder__MediaRecorder_clear_ondataavailable_impl
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "MediaRecorder_clear_ondataavailable",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -8766,6 +9929,7 @@ fn wire__web_audio_api__media_recorder__MediaRecorder_clear_onerror_impl(
             debug_name: "MediaRecorder_clear_onerror",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -8792,7 +9956,14 @@ fn wire__web_audio_api__media_recorder__MediaRecorder_clear_onerror_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "MediaRecorder_clear_onerror",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -8819,6 +9990,7 @@ fn wire__web_audio_api__media_recorder__MediaRecorder_clear_onstop_impl(
             debug_name: "MediaRecorder_clear_onstop",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -8845,7 +10017,14 @@ fn wire__web_audio_api__media_recorder__MediaRecorder_clear_onstop_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "MediaRecorder_clear_onstop",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -8867,16 +10046,16 @@ fn wire__web_audio_api__media_recorder__MediaRecorder_new_impl(
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "MediaRecorder_new", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
-            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
-            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_stream = <crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaStreamProxyEnum>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "MediaRecorder_new", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal, executor: None }, move || { 
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_stream = <crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaStreamProxyEnum>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
                     transform_result_sse::<_, ()>((move ||  {
                         let mut api_stream_guard = None;
 let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_stream, 0, false)]);
         for i in decode_indices_ {
             match i {
-                0 => api_stream_guard = Some(api_stream.lockable_decode_sync_ref()),
+                0 => api_stream_guard = Some(flutter_rust_bridge::for_generated::lockable_with_site("MediaRecorder_new", "stream", || api_stream.lockable_decode_sync_ref())),
                 _ => unreachable!(),
             }
         }
@@ -8896,6 +10075,7 @@ fn wire__web_audio_api__media_recorder__MediaRecorder_start_impl(
             debug_name: "MediaRecorder_start",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -8922,7 +10102,14 @@ fn wire__web_audio_api__media_recorder__MediaRecorder_start_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "MediaRecorder_start",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -8947,6 +10134,7 @@ fn wire__web_audio_api__media_recorder__MediaRecorder_stop_impl(
             debug_name: "MediaRecorder_stop",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -8973,7 +10161,14 @@ fn wire__web_audio_api__media_recorder__MediaRecorder_stop_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "MediaRecorder_stop",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -8998,6 +10193,7 @@ fn wire__web_audio_api__media_streams__MediaStreamTrack_close_impl(
             debug_name: "MediaStreamTrack_close",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -9024,7 +10220,14 @@ fn wire__web_audio_api__media_streams__MediaStreamTrack_close_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "MediaStreamTrack_close",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -9049,6 +10252,7 @@ fn wire__web_audio_api__media_streams__MediaStreamTrack_ready_state_impl(
             debug_name: "MediaStreamTrack_ready_state",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -9075,7 +10279,14 @@ fn wire__web_audio_api__media_streams__MediaStreamTrack_ready_state_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "MediaStreamTrack_ready_state",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -9097,16 +10308,16 @@ fn wire__web_audio_api__media_streams__MediaStream_frb_override_get_tracks_impl(
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "MediaStream_frb_override_get_tracks", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
-            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
-            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaStreamProxyEnum>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "MediaStream_frb_override_get_tracks", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal, executor: None }, move || { 
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaStreamProxyEnum>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
                     transform_result_sse::<_, ()>((move ||  {
                         let mut api_that_guard = None;
 let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
         for i in decode_indices_ {
             match i {
-                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                0 => api_that_guard = Some(flutter_rust_bridge::for_generated::lockable_with_site("MediaStream_frb_override_get_tracks", "that", || api_that.lockable_decode_sync_ref())),
                 _ => unreachable!(),
             }
         }
@@ -9126,6 +10337,7 @@ fn wire__web_audio_api__media_streams__MediaStream_from_tracks_impl(
             debug_name: "MediaStream_from_tracks",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -9161,6 +10373,7 @@ fn wire__web_audio_api__node__AnalyserNode_channel_config_impl(
             debug_name: "AnalyserNode_channel_config",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -9187,7 +10400,14 @@ fn wire__web_audio_api__node__AnalyserNode_channel_config_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AnalyserNode_channel_config",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -9212,6 +10432,7 @@ fn wire__web_audio_api__node__AnalyserNode_channel_count_impl(
             debug_name: "AnalyserNode_channel_count",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -9238,7 +10459,14 @@ fn wire__web_audio_api__node__AnalyserNode_channel_count_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AnalyserNode_channel_count",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -9263,6 +10491,7 @@ fn wire__web_audio_api__node__AnalyserNode_channel_count_mode_impl(
             debug_name: "AnalyserNode_channel_count_mode",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -9289,7 +10518,14 @@ fn wire__web_audio_api__node__AnalyserNode_channel_count_mode_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AnalyserNode_channel_count_mode",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -9314,6 +10550,7 @@ fn wire__web_audio_api__node__AnalyserNode_channel_interpretation_impl(
             debug_name: "AnalyserNode_channel_interpretation",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -9340,7 +10577,14 @@ fn wire__web_audio_api__node__AnalyserNode_channel_interpretation_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AnalyserNode_channel_interpretation",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -9365,6 +10609,7 @@ fn wire__web_audio_api__node__AnalyserNode_clear_onprocessorerror_impl(
             debug_name: "AnalyserNode_clear_onprocessorerror",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -9391,7 +10636,14 @@ fn wire__web_audio_api__node__AnalyserNode_clear_onprocessorerror_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AnalyserNode_clear_onprocessorerror",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -9416,6 +10668,7 @@ fn wire__web_audio_api__node__AnalyserNode_disconnect_impl(
             debug_name: "AnalyserNode_disconnect",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -9442,7 +10695,14 @@ fn wire__web_audio_api__node__AnalyserNode_disconnect_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AnalyserNode_disconnect",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -9467,6 +10727,7 @@ fn wire__web_audio_api__node__AnalyserNode_disconnect_output_impl(
             debug_name: "AnalyserNode_disconnect_output",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -9494,7 +10755,14 @@ fn wire__web_audio_api__node__AnalyserNode_disconnect_output_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AnalyserNode_disconnect_output",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -9522,6 +10790,7 @@ fn wire__web_audio_api__node__AnalyserNode_fft_size_impl(
             debug_name: "AnalyserNode_fft_size",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -9548,7 +10817,14 @@ fn wire__web_audio_api__node__AnalyserNode_fft_size_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AnalyserNode_fft_size",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -9573,6 +10849,7 @@ fn wire__web_audio_api__node__AnalyserNode_frb_override_connect_impl(
             debug_name: "AnalyserNode_frb_override_connect",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -9605,8 +10882,22 @@ fn wire__web_audio_api__node__AnalyserNode_frb_override_connect_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                            1 => api_dest_guard = Some(api_dest.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AnalyserNode_frb_override_connect",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
+                            1 => {
+                                api_dest_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AnalyserNode_frb_override_connect",
+                                        "dest",
+                                        || api_dest.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -9635,6 +10926,7 @@ fn wire__web_audio_api__node__AnalyserNode_frb_override_get_byte_time_domain_dat
             debug_name: "AnalyserNode_frb_override_get_byte_time_domain_data",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -9662,7 +10954,14 @@ fn wire__web_audio_api__node__AnalyserNode_frb_override_get_byte_time_domain_dat
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AnalyserNode_frb_override_get_byte_time_domain_data",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref_mut(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -9690,6 +10989,7 @@ fn wire__web_audio_api__node__AnalyserNode_frb_override_get_float_time_domain_da
             debug_name: "AnalyserNode_frb_override_get_float_time_domain_data",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -9717,7 +11017,14 @@ fn wire__web_audio_api__node__AnalyserNode_frb_override_get_float_time_domain_da
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AnalyserNode_frb_override_get_float_time_domain_data",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref_mut(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -9745,6 +11052,7 @@ fn wire__web_audio_api__node__AnalyserNode_frequency_bin_count_impl(
             debug_name: "AnalyserNode_frequency_bin_count",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -9771,7 +11079,14 @@ fn wire__web_audio_api__node__AnalyserNode_frequency_bin_count_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AnalyserNode_frequency_bin_count",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -9796,6 +11111,7 @@ fn wire__web_audio_api__node__AnalyserNode_max_decibels_impl(
             debug_name: "AnalyserNode_max_decibels",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -9822,7 +11138,14 @@ fn wire__web_audio_api__node__AnalyserNode_max_decibels_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AnalyserNode_max_decibels",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -9847,6 +11170,7 @@ fn wire__web_audio_api__node__AnalyserNode_min_decibels_impl(
             debug_name: "AnalyserNode_min_decibels",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -9873,7 +11197,14 @@ fn wire__web_audio_api__node__AnalyserNode_min_decibels_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AnalyserNode_min_decibels",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -9898,6 +11229,7 @@ fn wire__web_audio_api__node__AnalyserNode_number_of_inputs_impl(
             debug_name: "AnalyserNode_number_of_inputs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -9924,7 +11256,14 @@ fn wire__web_audio_api__node__AnalyserNode_number_of_inputs_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AnalyserNode_number_of_inputs",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -9949,6 +11288,7 @@ fn wire__web_audio_api__node__AnalyserNode_number_of_outputs_impl(
             debug_name: "AnalyserNode_number_of_outputs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -9975,7 +11315,14 @@ fn wire__web_audio_api__node__AnalyserNode_number_of_outputs_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AnalyserNode_number_of_outputs",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -10000,6 +11347,7 @@ fn wire__web_audio_api__node__AnalyserNode_registration_impl(
             debug_name: "AnalyserNode_registration",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -10026,7 +11374,14 @@ fn wire__web_audio_api__node__AnalyserNode_registration_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AnalyserNode_registration",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -10051,6 +11406,7 @@ fn wire__web_audio_api__node__AnalyserNode_set_fft_size_impl(
             debug_name: "AnalyserNode_set_fft_size",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -10078,7 +11434,14 @@ fn wire__web_audio_api__node__AnalyserNode_set_fft_size_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AnalyserNode_set_fft_size",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref_mut(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -10106,6 +11469,7 @@ fn wire__web_audio_api__node__AnalyserNode_set_max_decibels_impl(
             debug_name: "AnalyserNode_set_max_decibels",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -10133,7 +11497,14 @@ fn wire__web_audio_api__node__AnalyserNode_set_max_decibels_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AnalyserNode_set_max_decibels",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref_mut(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -10161,6 +11532,7 @@ fn wire__web_audio_api__node__AnalyserNode_set_min_decibels_impl(
             debug_name: "AnalyserNode_set_min_decibels",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -10188,7 +11560,14 @@ fn wire__web_audio_api__node__AnalyserNode_set_min_decibels_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AnalyserNode_set_min_decibels",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref_mut(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -10216,6 +11595,7 @@ fn wire__web_audio_api__node__AnalyserNode_set_on_processor_error_impl(
             debug_name: "AnalyserNode_set_on_processor_error",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -10245,7 +11625,14 @@ fn wire__web_audio_api__node__AnalyserNode_set_on_processor_error_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AnalyserNode_set_on_processor_error",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -10273,6 +11660,7 @@ fn wire__web_audio_api__node__AnalyserNode_set_smoothing_time_constant_impl(
             debug_name: "AnalyserNode_set_smoothing_time_constant",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -10300,7 +11688,14 @@ fn wire__web_audio_api__node__AnalyserNode_set_smoothing_time_constant_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AnalyserNode_set_smoothing_time_constant",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref_mut(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -10328,6 +11723,7 @@ fn wire__web_audio_api__node__AnalyserNode_smoothing_time_constant_impl(
             debug_name: "AnalyserNode_smoothing_time_constant",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -10354,7 +11750,14 @@ fn wire__web_audio_api__node__AnalyserNode_smoothing_time_constant_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AnalyserNode_smoothing_time_constant",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -10381,6 +11784,7 @@ fn wire__web_audio_api__node__AudioBufferSourceNode_channel_config_impl(
             debug_name: "AudioBufferSourceNode_channel_config",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -10407,7 +11811,14 @@ fn wire__web_audio_api__node__AudioBufferSourceNode_channel_config_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioBufferSourceNode_channel_config",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -10434,6 +11845,7 @@ fn wire__web_audio_api__node__AudioBufferSourceNode_channel_count_impl(
             debug_name: "AudioBufferSourceNode_channel_count",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -10460,7 +11872,14 @@ fn wire__web_audio_api__node__AudioBufferSourceNode_channel_count_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioBufferSourceNode_channel_count",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -10485,6 +11904,7 @@ fn wire__web_audio_api__node__AudioBufferSourceNode_channel_count_mode_impl(
             debug_name: "AudioBufferSourceNode_channel_count_mode",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -10511,7 +11931,14 @@ fn wire__web_audio_api__node__AudioBufferSourceNode_channel_count_mode_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioBufferSourceNode_channel_count_mode",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -10538,6 +11965,7 @@ fn wire__web_audio_api__node__AudioBufferSourceNode_channel_interpretation_impl(
             debug_name: "AudioBufferSourceNode_channel_interpretation",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -10564,7 +11992,14 @@ fn wire__web_audio_api__node__AudioBufferSourceNode_channel_interpretation_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioBufferSourceNode_channel_interpretation",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -10591,6 +12026,7 @@ fn wire__web_audio_api__node__AudioBufferSourceNode_clear_onended_impl(
             debug_name: "AudioBufferSourceNode_clear_onended",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -10617,7 +12053,14 @@ fn wire__web_audio_api__node__AudioBufferSourceNode_clear_onended_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioBufferSourceNode_clear_onended",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -10642,6 +12085,7 @@ fn wire__web_audio_api__node__AudioBufferSourceNode_clear_onprocessorerror_impl(
             debug_name: "AudioBufferSourceNode_clear_onprocessorerror",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -10668,7 +12112,14 @@ fn wire__web_audio_api__node__AudioBufferSourceNode_clear_onprocessorerror_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioBufferSourceNode_clear_onprocessorerror",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -10695,6 +12146,7 @@ fn wire__web_audio_api__node__AudioBufferSourceNode_disconnect_impl(
             debug_name: "AudioBufferSourceNode_disconnect",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -10721,7 +12173,14 @@ fn wire__web_audio_api__node__AudioBufferSourceNode_disconnect_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioBufferSourceNode_disconnect",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -10746,6 +12205,7 @@ fn wire__web_audio_api__node__AudioBufferSourceNode_disconnect_output_impl(
             debug_name: "AudioBufferSourceNode_disconnect_output",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -10773,7 +12233,14 @@ fn wire__web_audio_api__node__AudioBufferSourceNode_disconnect_output_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioBufferSourceNode_disconnect_output",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -10801,6 +12268,7 @@ fn wire__web_audio_api__node__AudioBufferSourceNode_frb_override_connect_impl(
             debug_name: "AudioBufferSourceNode_frb_override_connect",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -10833,8 +12301,22 @@ fn wire__web_audio_api__node__AudioBufferSourceNode_frb_override_connect_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                            1 => api_dest_guard = Some(api_dest.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioBufferSourceNode_frb_override_connect",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
+                            1 => {
+                                api_dest_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioBufferSourceNode_frb_override_connect",
+                                        "dest",
+                                        || api_dest.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -10863,6 +12345,7 @@ fn wire__web_audio_api__node__AudioBufferSourceNode_frb_override_set_buffer_impl
             debug_name: "AudioBufferSourceNode_frb_override_set_buffer",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -10898,10 +12381,21 @@ fn wire__web_audio_api__node__AudioBufferSourceNode_frb_override_set_buffer_impl
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioBufferSourceNode_frb_override_set_buffer",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref_mut(),
+                                    ))
+                            }
                             1 => {
                                 api_audio_buffer_guard =
-                                    Some(api_audio_buffer.lockable_decode_sync_ref())
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioBufferSourceNode_frb_override_set_buffer",
+                                        "audio_buffer",
+                                        || api_audio_buffer.lockable_decode_sync_ref(),
+                                    ))
                             }
                             _ => unreachable!(),
                         }
@@ -10931,6 +12425,7 @@ fn wire__web_audio_api__node__AudioBufferSourceNode_loop__impl(
             debug_name: "AudioBufferSourceNode_loop_",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -10957,7 +12452,14 @@ fn wire__web_audio_api__node__AudioBufferSourceNode_loop__impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioBufferSourceNode_loop_",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -10982,6 +12484,7 @@ fn wire__web_audio_api__node__AudioBufferSourceNode_loop_end_impl(
             debug_name: "AudioBufferSourceNode_loop_end",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -11008,7 +12511,14 @@ fn wire__web_audio_api__node__AudioBufferSourceNode_loop_end_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioBufferSourceNode_loop_end",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -11033,6 +12543,7 @@ fn wire__web_audio_api__node__AudioBufferSourceNode_loop_start_impl(
             debug_name: "AudioBufferSourceNode_loop_start",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -11059,7 +12570,14 @@ fn wire__web_audio_api__node__AudioBufferSourceNode_loop_start_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioBufferSourceNode_loop_start",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -11084,6 +12602,7 @@ fn wire__web_audio_api__node__AudioBufferSourceNode_number_of_inputs_impl(
             debug_name: "AudioBufferSourceNode_number_of_inputs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -11110,7 +12629,14 @@ fn wire__web_audio_api__node__AudioBufferSourceNode_number_of_inputs_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioBufferSourceNode_number_of_inputs",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -11137,6 +12663,7 @@ fn wire__web_audio_api__node__AudioBufferSourceNode_number_of_outputs_impl(
             debug_name: "AudioBufferSourceNode_number_of_outputs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -11163,7 +12690,14 @@ fn wire__web_audio_api__node__AudioBufferSourceNode_number_of_outputs_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioBufferSourceNode_number_of_outputs",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -11190,6 +12724,7 @@ fn wire__web_audio_api__node__AudioBufferSourceNode_position_impl(
             debug_name: "AudioBufferSourceNode_position",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -11216,7 +12751,14 @@ fn wire__web_audio_api__node__AudioBufferSourceNode_position_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioBufferSourceNode_position",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -11241,6 +12783,7 @@ fn wire__web_audio_api__node__AudioBufferSourceNode_registration_impl(
             debug_name: "AudioBufferSourceNode_registration",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -11267,7 +12810,14 @@ fn wire__web_audio_api__node__AudioBufferSourceNode_registration_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioBufferSourceNode_registration",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -11292,6 +12842,7 @@ fn wire__web_audio_api__node__AudioBufferSourceNode_set_loop_impl(
             debug_name: "AudioBufferSourceNode_set_loop",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -11319,7 +12870,14 @@ fn wire__web_audio_api__node__AudioBufferSourceNode_set_loop_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioBufferSourceNode_set_loop",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref_mut(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -11347,6 +12905,7 @@ fn wire__web_audio_api__node__AudioBufferSourceNode_set_loop_end_impl(
             debug_name: "AudioBufferSourceNode_set_loop_end",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -11374,7 +12933,14 @@ fn wire__web_audio_api__node__AudioBufferSourceNode_set_loop_end_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioBufferSourceNode_set_loop_end",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref_mut(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -11402,6 +12968,7 @@ fn wire__web_audio_api__node__AudioBufferSourceNode_set_loop_start_impl(
             debug_name: "AudioBufferSourceNode_set_loop_start",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -11429,7 +12996,14 @@ fn wire__web_audio_api__node__AudioBufferSourceNode_set_loop_start_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioBufferSourceNode_set_loop_start",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref_mut(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -11452,17 +13026,17 @@ fn wire__web_audio_api__node__AudioBufferSourceNode_set_on_ended_impl(
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "AudioBufferSourceNode_set_on_ended", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
-            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
-            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioBufferSourceNode>>>::sse_decode(&mut deserializer);
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "AudioBufferSourceNode_set_on_ended", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal, executor: None }, move || { 
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioBufferSourceNode>>>::sse_decode(&mut deserializer);
 let api_callback = decode_DartFn_Inputs_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEvent_Output_unit_AnyhowException(<flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer));deserializer.end(); move |context|  {
                     transform_result_sse::<_, ()>((move ||  {
                         let mut api_that_guard = None;
 let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
         for i in decode_indices_ {
             match i {
-                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                0 => api_that_guard = Some(flutter_rust_bridge::for_generated::lockable_with_site("AudioBufferSourceNode_set_on_ended", "that", || api_that.lockable_decode_sync_ref())),
                 _ => unreachable!(),
             }
         }
@@ -11482,6 +13056,7 @@ fn wire__web_audio_api__node__AudioBufferSourceNode_set_on_processor_error_impl(
             debug_name: "AudioBufferSourceNode_set_on_processor_error",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -11511,7 +13086,14 @@ fn wire__web_audio_api__node__AudioBufferSourceNode_set_on_processor_error_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioBufferSourceNode_set_on_processor_error",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -11539,6 +13121,7 @@ fn wire__web_audio_api__node__AudioBufferSourceNode_start_impl(
             debug_name: "AudioBufferSourceNode_start",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -11565,7 +13148,14 @@ fn wire__web_audio_api__node__AudioBufferSourceNode_start_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioBufferSourceNode_start",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref_mut(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -11590,6 +13180,7 @@ fn wire__web_audio_api__node__AudioBufferSourceNode_start_at_impl(
             debug_name: "AudioBufferSourceNode_start_at",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -11617,7 +13208,14 @@ fn wire__web_audio_api__node__AudioBufferSourceNode_start_at_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioBufferSourceNode_start_at",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref_mut(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -11645,6 +13243,7 @@ fn wire__web_audio_api__node__AudioBufferSourceNode_start_at_with_offset_impl(
             debug_name: "AudioBufferSourceNode_start_at_with_offset",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -11673,7 +13272,14 @@ fn wire__web_audio_api__node__AudioBufferSourceNode_start_at_with_offset_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioBufferSourceNode_start_at_with_offset",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref_mut(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -11697,10 +13303,10 @@ fn wire__web_audio_api__node__AudioBufferSourceNode_start_at_with_offset_and_dur
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "AudioBufferSourceNode_start_at_with_offset_and_duration", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
-            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
-            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioBufferSourceNode>>>::sse_decode(&mut deserializer);
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "AudioBufferSourceNode_start_at_with_offset_and_duration", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal, executor: None }, move || { 
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioBufferSourceNode>>>::sse_decode(&mut deserializer);
 let api_start = <f64>::sse_decode(&mut deserializer);
 let api_offset = <f64>::sse_decode(&mut deserializer);
 let api_duration = <f64>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
@@ -11709,7 +13315,7 @@ let api_duration = <f64>::sse_decode(&mut deserializer);deserializer.end(); move
 let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, true)]);
         for i in decode_indices_ {
             match i {
-                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                0 => api_that_guard = Some(flutter_rust_bridge::for_generated::lockable_with_site("AudioBufferSourceNode_start_at_with_offset_and_duration", "that", || api_that.lockable_decode_sync_ref_mut())),
                 _ => unreachable!(),
             }
         }
@@ -11729,6 +13335,7 @@ fn wire__web_audio_api__node__AudioBufferSourceNode_stop_impl(
             debug_name: "AudioBufferSourceNode_stop",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -11755,7 +13362,14 @@ fn wire__web_audio_api__node__AudioBufferSourceNode_stop_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioBufferSourceNode_stop",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref_mut(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -11780,6 +13394,7 @@ fn wire__web_audio_api__node__AudioBufferSourceNode_stop_at_impl(
             debug_name: "AudioBufferSourceNode_stop_at",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -11807,7 +13422,14 @@ fn wire__web_audio_api__node__AudioBufferSourceNode_stop_at_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioBufferSourceNode_stop_at",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref_mut(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -11835,6 +13457,7 @@ fn wire__web_audio_api__node__AudioDestinationNode_channel_config_impl(
             debug_name: "AudioDestinationNode_channel_config",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -11861,7 +13484,14 @@ fn wire__web_audio_api__node__AudioDestinationNode_channel_config_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioDestinationNode_channel_config",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -11886,6 +13516,7 @@ fn wire__web_audio_api__node__AudioDestinationNode_channel_count_impl(
             debug_name: "AudioDestinationNode_channel_count",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -11912,7 +13543,14 @@ fn wire__web_audio_api__node__AudioDestinationNode_channel_count_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioDestinationNode_channel_count",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -11937,6 +13575,7 @@ fn wire__web_audio_api__node__AudioDestinationNode_channel_count_mode_impl(
             debug_name: "AudioDestinationNode_channel_count_mode",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -11963,7 +13602,14 @@ fn wire__web_audio_api__node__AudioDestinationNode_channel_count_mode_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioDestinationNode_channel_count_mode",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -11990,6 +13636,7 @@ fn wire__web_audio_api__node__AudioDestinationNode_channel_interpretation_impl(
             debug_name: "AudioDestinationNode_channel_interpretation",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -12016,7 +13663,14 @@ fn wire__web_audio_api__node__AudioDestinationNode_channel_interpretation_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioDestinationNode_channel_interpretation",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -12043,6 +13697,7 @@ fn wire__web_audio_api__node__AudioDestinationNode_clear_onprocessorerror_impl(
             debug_name: "AudioDestinationNode_clear_onprocessorerror",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -12069,7 +13724,14 @@ fn wire__web_audio_api__node__AudioDestinationNode_clear_onprocessorerror_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioDestinationNode_clear_onprocessorerror",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -12096,6 +13758,7 @@ fn wire__web_audio_api__node__AudioDestinationNode_disconnect_impl(
             debug_name: "AudioDestinationNode_disconnect",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -12122,7 +13785,14 @@ fn wire__web_audio_api__node__AudioDestinationNode_disconnect_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioDestinationNode_disconnect",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -12147,6 +13817,7 @@ fn wire__web_audio_api__node__AudioDestinationNode_disconnect_output_impl(
             debug_name: "AudioDestinationNode_disconnect_output",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -12174,7 +13845,14 @@ fn wire__web_audio_api__node__AudioDestinationNode_disconnect_output_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioDestinationNode_disconnect_output",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -12202,6 +13880,7 @@ fn wire__web_audio_api__node__AudioDestinationNode_frb_override_connect_impl(
             debug_name: "AudioDestinationNode_frb_override_connect",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -12234,8 +13913,22 @@ fn wire__web_audio_api__node__AudioDestinationNode_frb_override_connect_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                            1 => api_dest_guard = Some(api_dest.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioDestinationNode_frb_override_connect",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
+                            1 => {
+                                api_dest_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioDestinationNode_frb_override_connect",
+                                        "dest",
+                                        || api_dest.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -12264,6 +13957,7 @@ fn wire__web_audio_api__node__AudioDestinationNode_max_channel_count_impl(
             debug_name: "AudioDestinationNode_max_channel_count",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -12290,7 +13984,14 @@ fn wire__web_audio_api__node__AudioDestinationNode_max_channel_count_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioDestinationNode_max_channel_count",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -12317,6 +14018,7 @@ fn wire__web_audio_api__node__AudioDestinationNode_number_of_inputs_impl(
             debug_name: "AudioDestinationNode_number_of_inputs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -12343,7 +14045,14 @@ fn wire__web_audio_api__node__AudioDestinationNode_number_of_inputs_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioDestinationNode_number_of_inputs",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -12370,6 +14079,7 @@ fn wire__web_audio_api__node__AudioDestinationNode_number_of_outputs_impl(
             debug_name: "AudioDestinationNode_number_of_outputs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -12396,7 +14106,14 @@ fn wire__web_audio_api__node__AudioDestinationNode_number_of_outputs_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioDestinationNode_number_of_outputs",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -12423,6 +14140,7 @@ fn wire__web_audio_api__node__AudioDestinationNode_registration_impl(
             debug_name: "AudioDestinationNode_registration",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -12449,7 +14167,14 @@ fn wire__web_audio_api__node__AudioDestinationNode_registration_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioDestinationNode_registration",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -12474,6 +14199,7 @@ fn wire__web_audio_api__node__AudioDestinationNode_set_on_processor_error_impl(
             debug_name: "AudioDestinationNode_set_on_processor_error",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -12503,7 +14229,14 @@ fn wire__web_audio_api__node__AudioDestinationNode_set_on_processor_error_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioDestinationNode_set_on_processor_error",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -12531,6 +14264,7 @@ fn wire__web_audio_api__node__BiquadFilterNode_channel_config_impl(
             debug_name: "BiquadFilterNode_channel_config",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -12557,7 +14291,14 @@ fn wire__web_audio_api__node__BiquadFilterNode_channel_config_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "BiquadFilterNode_channel_config",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -12582,6 +14323,7 @@ fn wire__web_audio_api__node__BiquadFilterNode_channel_count_impl(
             debug_name: "BiquadFilterNode_channel_count",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -12608,7 +14350,14 @@ fn wire__web_audio_api__node__BiquadFilterNode_channel_count_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "BiquadFilterNode_channel_count",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -12633,6 +14382,7 @@ fn wire__web_audio_api__node__BiquadFilterNode_channel_count_mode_impl(
             debug_name: "BiquadFilterNode_channel_count_mode",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -12659,7 +14409,14 @@ fn wire__web_audio_api__node__BiquadFilterNode_channel_count_mode_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "BiquadFilterNode_channel_count_mode",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -12684,6 +14441,7 @@ fn wire__web_audio_api__node__BiquadFilterNode_channel_interpretation_impl(
             debug_name: "BiquadFilterNode_channel_interpretation",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -12710,7 +14468,14 @@ fn wire__web_audio_api__node__BiquadFilterNode_channel_interpretation_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "BiquadFilterNode_channel_interpretation",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -12737,6 +14502,7 @@ fn wire__web_audio_api__node__BiquadFilterNode_clear_onprocessorerror_impl(
             debug_name: "BiquadFilterNode_clear_onprocessorerror",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -12763,7 +14529,14 @@ fn wire__web_audio_api__node__BiquadFilterNode_clear_onprocessorerror_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "BiquadFilterNode_clear_onprocessorerror",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -12790,6 +14563,7 @@ fn wire__web_audio_api__node__BiquadFilterNode_disconnect_impl(
             debug_name: "BiquadFilterNode_disconnect",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -12816,7 +14590,14 @@ fn wire__web_audio_api__node__BiquadFilterNode_disconnect_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "BiquadFilterNode_disconnect",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -12841,6 +14622,7 @@ fn wire__web_audio_api__node__BiquadFilterNode_disconnect_output_impl(
             debug_name: "BiquadFilterNode_disconnect_output",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -12868,7 +14650,14 @@ fn wire__web_audio_api__node__BiquadFilterNode_disconnect_output_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "BiquadFilterNode_disconnect_output",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -12896,6 +14685,7 @@ fn wire__web_audio_api__node__BiquadFilterNode_frb_override_connect_impl(
             debug_name: "BiquadFilterNode_frb_override_connect",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -12928,8 +14718,22 @@ fn wire__web_audio_api__node__BiquadFilterNode_frb_override_connect_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                            1 => api_dest_guard = Some(api_dest.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "BiquadFilterNode_frb_override_connect",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
+                            1 => {
+                                api_dest_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "BiquadFilterNode_frb_override_connect",
+                                        "dest",
+                                        || api_dest.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -12958,6 +14762,7 @@ fn wire__web_audio_api__node__BiquadFilterNode_number_of_inputs_impl(
             debug_name: "BiquadFilterNode_number_of_inputs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -12984,7 +14789,14 @@ fn wire__web_audio_api__node__BiquadFilterNode_number_of_inputs_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "BiquadFilterNode_number_of_inputs",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -13009,6 +14821,7 @@ fn wire__web_audio_api__node__BiquadFilterNode_number_of_outputs_impl(
             debug_name: "BiquadFilterNode_number_of_outputs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -13035,7 +14848,14 @@ fn wire__web_audio_api__node__BiquadFilterNode_number_of_outputs_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "BiquadFilterNode_number_of_outputs",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -13060,6 +14880,7 @@ fn wire__web_audio_api__node__BiquadFilterNode_registration_impl(
             debug_name: "BiquadFilterNode_registration",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -13086,7 +14907,14 @@ fn wire__web_audio_api__node__BiquadFilterNode_registration_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "BiquadFilterNode_registration",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -13111,6 +14939,7 @@ fn wire__web_audio_api__node__BiquadFilterNode_set_on_processor_error_impl(
             debug_name: "BiquadFilterNode_set_on_processor_error",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -13140,7 +14969,14 @@ fn wire__web_audio_api__node__BiquadFilterNode_set_on_processor_error_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "BiquadFilterNode_set_on_processor_error",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -13168,6 +15004,7 @@ fn wire__web_audio_api__node__BiquadFilterNode_set_type_impl(
             debug_name: "BiquadFilterNode_set_type",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -13195,7 +15032,14 @@ fn wire__web_audio_api__node__BiquadFilterNode_set_type_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "BiquadFilterNode_set_type",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref_mut(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -13223,6 +15067,7 @@ fn wire__web_audio_api__node__BiquadFilterNode_type__impl(
             debug_name: "BiquadFilterNode_type_",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -13249,7 +15094,14 @@ fn wire__web_audio_api__node__BiquadFilterNode_type__impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "BiquadFilterNode_type_",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -13274,6 +15126,7 @@ fn wire__web_audio_api__node__ChannelConfig_default_impl(
             debug_name: "ChannelConfig_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -13307,6 +15160,7 @@ fn wire__web_audio_api__node__ChannelMergerNode_channel_config_impl(
             debug_name: "ChannelMergerNode_channel_config",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -13333,7 +15187,14 @@ fn wire__web_audio_api__node__ChannelMergerNode_channel_config_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ChannelMergerNode_channel_config",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -13358,6 +15219,7 @@ fn wire__web_audio_api__node__ChannelMergerNode_channel_count_impl(
             debug_name: "ChannelMergerNode_channel_count",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -13384,7 +15246,14 @@ fn wire__web_audio_api__node__ChannelMergerNode_channel_count_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ChannelMergerNode_channel_count",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -13409,6 +15278,7 @@ fn wire__web_audio_api__node__ChannelMergerNode_channel_count_mode_impl(
             debug_name: "ChannelMergerNode_channel_count_mode",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -13435,7 +15305,14 @@ fn wire__web_audio_api__node__ChannelMergerNode_channel_count_mode_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ChannelMergerNode_channel_count_mode",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -13462,6 +15339,7 @@ fn wire__web_audio_api__node__ChannelMergerNode_channel_interpretation_impl(
             debug_name: "ChannelMergerNode_channel_interpretation",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -13488,7 +15366,14 @@ fn wire__web_audio_api__node__ChannelMergerNode_channel_interpretation_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ChannelMergerNode_channel_interpretation",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -13515,6 +15400,7 @@ fn wire__web_audio_api__node__ChannelMergerNode_clear_onprocessorerror_impl(
             debug_name: "ChannelMergerNode_clear_onprocessorerror",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -13541,7 +15427,14 @@ fn wire__web_audio_api__node__ChannelMergerNode_clear_onprocessorerror_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ChannelMergerNode_clear_onprocessorerror",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -13568,6 +15461,7 @@ fn wire__web_audio_api__node__ChannelMergerNode_disconnect_impl(
             debug_name: "ChannelMergerNode_disconnect",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -13594,7 +15488,14 @@ fn wire__web_audio_api__node__ChannelMergerNode_disconnect_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ChannelMergerNode_disconnect",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -13619,6 +15520,7 @@ fn wire__web_audio_api__node__ChannelMergerNode_disconnect_output_impl(
             debug_name: "ChannelMergerNode_disconnect_output",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -13646,7 +15548,14 @@ fn wire__web_audio_api__node__ChannelMergerNode_disconnect_output_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ChannelMergerNode_disconnect_output",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -13674,6 +15583,7 @@ fn wire__web_audio_api__node__ChannelMergerNode_frb_override_connect_impl(
             debug_name: "ChannelMergerNode_frb_override_connect",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -13706,8 +15616,22 @@ fn wire__web_audio_api__node__ChannelMergerNode_frb_override_connect_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                            1 => api_dest_guard = Some(api_dest.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ChannelMergerNode_frb_override_connect",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
+                            1 => {
+                                api_dest_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ChannelMergerNode_frb_override_connect",
+                                        "dest",
+                                        || api_dest.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -13736,6 +15660,7 @@ fn wire__web_audio_api__node__ChannelMergerNode_number_of_inputs_impl(
             debug_name: "ChannelMergerNode_number_of_inputs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -13762,7 +15687,14 @@ fn wire__web_audio_api__node__ChannelMergerNode_number_of_inputs_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ChannelMergerNode_number_of_inputs",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -13787,6 +15719,7 @@ fn wire__web_audio_api__node__ChannelMergerNode_number_of_outputs_impl(
             debug_name: "ChannelMergerNode_number_of_outputs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -13813,7 +15746,14 @@ fn wire__web_audio_api__node__ChannelMergerNode_number_of_outputs_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ChannelMergerNode_number_of_outputs",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -13838,6 +15778,7 @@ fn wire__web_audio_api__node__ChannelMergerNode_registration_impl(
             debug_name: "ChannelMergerNode_registration",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -13864,7 +15805,14 @@ fn wire__web_audio_api__node__ChannelMergerNode_registration_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ChannelMergerNode_registration",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -13889,6 +15837,7 @@ fn wire__web_audio_api__node__ChannelMergerNode_set_on_processor_error_impl(
             debug_name: "ChannelMergerNode_set_on_processor_error",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -13918,7 +15867,14 @@ fn wire__web_audio_api__node__ChannelMergerNode_set_on_processor_error_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ChannelMergerNode_set_on_processor_error",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -13946,6 +15902,7 @@ fn wire__web_audio_api__node__ChannelSplitterNode_channel_config_impl(
             debug_name: "ChannelSplitterNode_channel_config",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -13972,7 +15929,14 @@ fn wire__web_audio_api__node__ChannelSplitterNode_channel_config_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ChannelSplitterNode_channel_config",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -13997,6 +15961,7 @@ fn wire__web_audio_api__node__ChannelSplitterNode_channel_count_impl(
             debug_name: "ChannelSplitterNode_channel_count",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -14023,7 +15988,14 @@ fn wire__web_audio_api__node__ChannelSplitterNode_channel_count_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ChannelSplitterNode_channel_count",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -14048,6 +16020,7 @@ fn wire__web_audio_api__node__ChannelSplitterNode_channel_count_mode_impl(
             debug_name: "ChannelSplitterNode_channel_count_mode",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -14074,7 +16047,14 @@ fn wire__web_audio_api__node__ChannelSplitterNode_channel_count_mode_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ChannelSplitterNode_channel_count_mode",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -14101,6 +16081,7 @@ fn wire__web_audio_api__node__ChannelSplitterNode_channel_interpretation_impl(
             debug_name: "ChannelSplitterNode_channel_interpretation",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -14127,7 +16108,14 @@ fn wire__web_audio_api__node__ChannelSplitterNode_channel_interpretation_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ChannelSplitterNode_channel_interpretation",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -14154,6 +16142,7 @@ fn wire__web_audio_api__node__ChannelSplitterNode_clear_onprocessorerror_impl(
             debug_name: "ChannelSplitterNode_clear_onprocessorerror",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -14180,7 +16169,14 @@ fn wire__web_audio_api__node__ChannelSplitterNode_clear_onprocessorerror_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ChannelSplitterNode_clear_onprocessorerror",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -14207,6 +16203,7 @@ fn wire__web_audio_api__node__ChannelSplitterNode_disconnect_impl(
             debug_name: "ChannelSplitterNode_disconnect",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -14233,7 +16230,14 @@ fn wire__web_audio_api__node__ChannelSplitterNode_disconnect_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ChannelSplitterNode_disconnect",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -14258,6 +16262,7 @@ fn wire__web_audio_api__node__ChannelSplitterNode_disconnect_output_impl(
             debug_name: "ChannelSplitterNode_disconnect_output",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -14285,7 +16290,14 @@ fn wire__web_audio_api__node__ChannelSplitterNode_disconnect_output_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ChannelSplitterNode_disconnect_output",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -14313,6 +16325,7 @@ fn wire__web_audio_api__node__ChannelSplitterNode_frb_override_connect_impl(
             debug_name: "ChannelSplitterNode_frb_override_connect",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -14345,8 +16358,22 @@ fn wire__web_audio_api__node__ChannelSplitterNode_frb_override_connect_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                            1 => api_dest_guard = Some(api_dest.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ChannelSplitterNode_frb_override_connect",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
+                            1 => {
+                                api_dest_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ChannelSplitterNode_frb_override_connect",
+                                        "dest",
+                                        || api_dest.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -14375,6 +16402,7 @@ fn wire__web_audio_api__node__ChannelSplitterNode_number_of_inputs_impl(
             debug_name: "ChannelSplitterNode_number_of_inputs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -14401,7 +16429,14 @@ fn wire__web_audio_api__node__ChannelSplitterNode_number_of_inputs_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ChannelSplitterNode_number_of_inputs",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -14428,6 +16463,7 @@ fn wire__web_audio_api__node__ChannelSplitterNode_number_of_outputs_impl(
             debug_name: "ChannelSplitterNode_number_of_outputs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -14454,7 +16490,14 @@ fn wire__web_audio_api__node__ChannelSplitterNode_number_of_outputs_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ChannelSplitterNode_number_of_outputs",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -14481,6 +16524,7 @@ fn wire__web_audio_api__node__ChannelSplitterNode_registration_impl(
             debug_name: "ChannelSplitterNode_registration",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -14507,7 +16551,14 @@ fn wire__web_audio_api__node__ChannelSplitterNode_registration_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ChannelSplitterNode_registration",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -14532,6 +16583,7 @@ fn wire__web_audio_api__node__ChannelSplitterNode_set_on_processor_error_impl(
             debug_name: "ChannelSplitterNode_set_on_processor_error",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -14561,7 +16613,14 @@ fn wire__web_audio_api__node__ChannelSplitterNode_set_on_processor_error_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ChannelSplitterNode_set_on_processor_error",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -14589,6 +16648,7 @@ fn wire__web_audio_api__node__ConstantSourceNode_channel_config_impl(
             debug_name: "ConstantSourceNode_channel_config",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -14615,7 +16675,14 @@ fn wire__web_audio_api__node__ConstantSourceNode_channel_config_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ConstantSourceNode_channel_config",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -14640,6 +16707,7 @@ fn wire__web_audio_api__node__ConstantSourceNode_channel_count_impl(
             debug_name: "ConstantSourceNode_channel_count",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -14666,7 +16734,14 @@ fn wire__web_audio_api__node__ConstantSourceNode_channel_count_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ConstantSourceNode_channel_count",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -14691,6 +16766,7 @@ fn wire__web_audio_api__node__ConstantSourceNode_channel_count_mode_impl(
             debug_name: "ConstantSourceNode_channel_count_mode",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -14717,7 +16793,14 @@ fn wire__web_audio_api__node__ConstantSourceNode_channel_count_mode_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ConstantSourceNode_channel_count_mode",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -14744,6 +16827,7 @@ fn wire__web_audio_api__node__ConstantSourceNode_channel_interpretation_impl(
             debug_name: "ConstantSourceNode_channel_interpretation",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -14770,7 +16854,14 @@ fn wire__web_audio_api__node__ConstantSourceNode_channel_interpretation_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ConstantSourceNode_channel_interpretation",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -14797,6 +16888,7 @@ fn wire__web_audio_api__node__ConstantSourceNode_clear_onended_impl(
             debug_name: "ConstantSourceNode_clear_onended",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -14823,7 +16915,14 @@ fn wire__web_audio_api__node__ConstantSourceNode_clear_onended_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ConstantSourceNode_clear_onended",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -14848,6 +16947,7 @@ fn wire__web_audio_api__node__ConstantSourceNode_clear_onprocessorerror_impl(
             debug_name: "ConstantSourceNode_clear_onprocessorerror",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -14874,7 +16974,14 @@ fn wire__web_audio_api__node__ConstantSourceNode_clear_onprocessorerror_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ConstantSourceNode_clear_onprocessorerror",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -14901,6 +17008,7 @@ fn wire__web_audio_api__node__ConstantSourceNode_disconnect_impl(
             debug_name: "ConstantSourceNode_disconnect",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -14927,7 +17035,14 @@ fn wire__web_audio_api__node__ConstantSourceNode_disconnect_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ConstantSourceNode_disconnect",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -14952,6 +17067,7 @@ fn wire__web_audio_api__node__ConstantSourceNode_disconnect_output_impl(
             debug_name: "ConstantSourceNode_disconnect_output",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -14979,7 +17095,14 @@ fn wire__web_audio_api__node__ConstantSourceNode_disconnect_output_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ConstantSourceNode_disconnect_output",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -15007,6 +17130,7 @@ fn wire__web_audio_api__node__ConstantSourceNode_frb_override_connect_impl(
             debug_name: "ConstantSourceNode_frb_override_connect",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -15039,8 +17163,22 @@ fn wire__web_audio_api__node__ConstantSourceNode_frb_override_connect_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                            1 => api_dest_guard = Some(api_dest.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ConstantSourceNode_frb_override_connect",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
+                            1 => {
+                                api_dest_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ConstantSourceNode_frb_override_connect",
+                                        "dest",
+                                        || api_dest.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -15069,6 +17207,7 @@ fn wire__web_audio_api__node__ConstantSourceNode_number_of_inputs_impl(
             debug_name: "ConstantSourceNode_number_of_inputs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -15095,7 +17234,14 @@ fn wire__web_audio_api__node__ConstantSourceNode_number_of_inputs_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ConstantSourceNode_number_of_inputs",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -15120,6 +17266,7 @@ fn wire__web_audio_api__node__ConstantSourceNode_number_of_outputs_impl(
             debug_name: "ConstantSourceNode_number_of_outputs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -15146,7 +17293,14 @@ fn wire__web_audio_api__node__ConstantSourceNode_number_of_outputs_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ConstantSourceNode_number_of_outputs",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -15173,6 +17327,7 @@ fn wire__web_audio_api__node__ConstantSourceNode_registration_impl(
             debug_name: "ConstantSourceNode_registration",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -15199,7 +17354,14 @@ fn wire__web_audio_api__node__ConstantSourceNode_registration_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ConstantSourceNode_registration",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -15219,17 +17381,17 @@ fn wire__web_audio_api__node__ConstantSourceNode_set_on_ended_impl(
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "ConstantSourceNode_set_on_ended", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
-            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
-            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ConstantSourceNode>>>::sse_decode(&mut deserializer);
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "ConstantSourceNode_set_on_ended", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal, executor: None }, move || { 
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ConstantSourceNode>>>::sse_decode(&mut deserializer);
 let api_callback = decode_DartFn_Inputs_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEvent_Output_unit_AnyhowException(<flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer));deserializer.end(); move |context|  {
                     transform_result_sse::<_, ()>((move ||  {
                         let mut api_that_guard = None;
 let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
         for i in decode_indices_ {
             match i {
-                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                0 => api_that_guard = Some(flutter_rust_bridge::for_generated::lockable_with_site("ConstantSourceNode_set_on_ended", "that", || api_that.lockable_decode_sync_ref())),
                 _ => unreachable!(),
             }
         }
@@ -15249,6 +17411,7 @@ fn wire__web_audio_api__node__ConstantSourceNode_set_on_processor_error_impl(
             debug_name: "ConstantSourceNode_set_on_processor_error",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -15278,7 +17441,14 @@ fn wire__web_audio_api__node__ConstantSourceNode_set_on_processor_error_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ConstantSourceNode_set_on_processor_error",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -15306,6 +17476,7 @@ fn wire__web_audio_api__node__ConstantSourceNode_start_impl(
             debug_name: "ConstantSourceNode_start",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -15332,7 +17503,14 @@ fn wire__web_audio_api__node__ConstantSourceNode_start_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ConstantSourceNode_start",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref_mut(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -15357,6 +17535,7 @@ fn wire__web_audio_api__node__ConstantSourceNode_start_at_impl(
             debug_name: "ConstantSourceNode_start_at",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -15384,7 +17563,14 @@ fn wire__web_audio_api__node__ConstantSourceNode_start_at_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ConstantSourceNode_start_at",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref_mut(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -15412,6 +17598,7 @@ fn wire__web_audio_api__node__ConstantSourceNode_stop_impl(
             debug_name: "ConstantSourceNode_stop",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -15438,7 +17625,14 @@ fn wire__web_audio_api__node__ConstantSourceNode_stop_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ConstantSourceNode_stop",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref_mut(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -15463,6 +17657,7 @@ fn wire__web_audio_api__node__ConstantSourceNode_stop_at_impl(
             debug_name: "ConstantSourceNode_stop_at",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -15490,7 +17685,14 @@ fn wire__web_audio_api__node__ConstantSourceNode_stop_at_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ConstantSourceNode_stop_at",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref_mut(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -15518,6 +17720,7 @@ fn wire__web_audio_api__node__ConvolverNode_channel_config_impl(
             debug_name: "ConvolverNode_channel_config",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -15544,7 +17747,14 @@ fn wire__web_audio_api__node__ConvolverNode_channel_config_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ConvolverNode_channel_config",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -15569,6 +17779,7 @@ fn wire__web_audio_api__node__ConvolverNode_channel_count_impl(
             debug_name: "ConvolverNode_channel_count",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -15595,7 +17806,14 @@ fn wire__web_audio_api__node__ConvolverNode_channel_count_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ConvolverNode_channel_count",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -15620,6 +17838,7 @@ fn wire__web_audio_api__node__ConvolverNode_channel_count_mode_impl(
             debug_name: "ConvolverNode_channel_count_mode",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -15646,7 +17865,14 @@ fn wire__web_audio_api__node__ConvolverNode_channel_count_mode_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ConvolverNode_channel_count_mode",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -15671,6 +17897,7 @@ fn wire__web_audio_api__node__ConvolverNode_channel_interpretation_impl(
             debug_name: "ConvolverNode_channel_interpretation",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -15697,7 +17924,14 @@ fn wire__web_audio_api__node__ConvolverNode_channel_interpretation_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ConvolverNode_channel_interpretation",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -15724,6 +17958,7 @@ fn wire__web_audio_api__node__ConvolverNode_clear_onprocessorerror_impl(
             debug_name: "ConvolverNode_clear_onprocessorerror",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -15750,7 +17985,14 @@ fn wire__web_audio_api__node__ConvolverNode_clear_onprocessorerror_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ConvolverNode_clear_onprocessorerror",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -15777,6 +18019,7 @@ fn wire__web_audio_api__node__ConvolverNode_disconnect_impl(
             debug_name: "ConvolverNode_disconnect",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -15803,7 +18046,14 @@ fn wire__web_audio_api__node__ConvolverNode_disconnect_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ConvolverNode_disconnect",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -15828,6 +18078,7 @@ fn wire__web_audio_api__node__ConvolverNode_disconnect_output_impl(
             debug_name: "ConvolverNode_disconnect_output",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -15855,7 +18106,14 @@ fn wire__web_audio_api__node__ConvolverNode_disconnect_output_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ConvolverNode_disconnect_output",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -15883,6 +18141,7 @@ fn wire__web_audio_api__node__ConvolverNode_frb_override_connect_impl(
             debug_name: "ConvolverNode_frb_override_connect",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -15915,8 +18174,22 @@ fn wire__web_audio_api__node__ConvolverNode_frb_override_connect_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                            1 => api_dest_guard = Some(api_dest.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ConvolverNode_frb_override_connect",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
+                            1 => {
+                                api_dest_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ConvolverNode_frb_override_connect",
+                                        "dest",
+                                        || api_dest.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -15945,6 +18218,7 @@ fn wire__web_audio_api__node__ConvolverNode_normalize_impl(
             debug_name: "ConvolverNode_normalize",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -15971,7 +18245,14 @@ fn wire__web_audio_api__node__ConvolverNode_normalize_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ConvolverNode_normalize",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -15996,6 +18277,7 @@ fn wire__web_audio_api__node__ConvolverNode_number_of_inputs_impl(
             debug_name: "ConvolverNode_number_of_inputs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -16022,7 +18304,14 @@ fn wire__web_audio_api__node__ConvolverNode_number_of_inputs_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ConvolverNode_number_of_inputs",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -16047,6 +18336,7 @@ fn wire__web_audio_api__node__ConvolverNode_number_of_outputs_impl(
             debug_name: "ConvolverNode_number_of_outputs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -16073,7 +18363,14 @@ fn wire__web_audio_api__node__ConvolverNode_number_of_outputs_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ConvolverNode_number_of_outputs",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -16098,6 +18395,7 @@ fn wire__web_audio_api__node__ConvolverNode_registration_impl(
             debug_name: "ConvolverNode_registration",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -16124,7 +18422,14 @@ fn wire__web_audio_api__node__ConvolverNode_registration_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ConvolverNode_registration",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -16149,6 +18454,7 @@ fn wire__web_audio_api__node__ConvolverNode_set_buffer_impl(
             debug_name: "ConvolverNode_set_buffer",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -16176,7 +18482,14 @@ fn wire__web_audio_api__node__ConvolverNode_set_buffer_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ConvolverNode_set_buffer",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref_mut(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -16204,6 +18517,7 @@ fn wire__web_audio_api__node__ConvolverNode_set_normalize_impl(
             debug_name: "ConvolverNode_set_normalize",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -16231,7 +18545,14 @@ fn wire__web_audio_api__node__ConvolverNode_set_normalize_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ConvolverNode_set_normalize",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref_mut(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -16259,6 +18580,7 @@ fn wire__web_audio_api__node__ConvolverNode_set_on_processor_error_impl(
             debug_name: "ConvolverNode_set_on_processor_error",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -16288,7 +18610,14 @@ fn wire__web_audio_api__node__ConvolverNode_set_on_processor_error_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ConvolverNode_set_on_processor_error",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -16316,6 +18645,7 @@ fn wire__web_audio_api__node__DelayNode_channel_config_impl(
             debug_name: "DelayNode_channel_config",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -16342,7 +18672,14 @@ fn wire__web_audio_api__node__DelayNode_channel_config_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "DelayNode_channel_config",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -16367,6 +18704,7 @@ fn wire__web_audio_api__node__DelayNode_channel_count_impl(
             debug_name: "DelayNode_channel_count",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -16393,7 +18731,14 @@ fn wire__web_audio_api__node__DelayNode_channel_count_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "DelayNode_channel_count",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -16418,6 +18763,7 @@ fn wire__web_audio_api__node__DelayNode_channel_count_mode_impl(
             debug_name: "DelayNode_channel_count_mode",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -16444,7 +18790,14 @@ fn wire__web_audio_api__node__DelayNode_channel_count_mode_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "DelayNode_channel_count_mode",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -16469,6 +18822,7 @@ fn wire__web_audio_api__node__DelayNode_channel_interpretation_impl(
             debug_name: "DelayNode_channel_interpretation",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -16495,7 +18849,14 @@ fn wire__web_audio_api__node__DelayNode_channel_interpretation_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "DelayNode_channel_interpretation",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -16520,6 +18881,7 @@ fn wire__web_audio_api__node__DelayNode_clear_onprocessorerror_impl(
             debug_name: "DelayNode_clear_onprocessorerror",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -16546,7 +18908,14 @@ fn wire__web_audio_api__node__DelayNode_clear_onprocessorerror_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "DelayNode_clear_onprocessorerror",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -16571,6 +18940,7 @@ fn wire__web_audio_api__node__DelayNode_disconnect_impl(
             debug_name: "DelayNode_disconnect",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -16597,7 +18967,14 @@ fn wire__web_audio_api__node__DelayNode_disconnect_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "DelayNode_disconnect",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -16622,6 +18999,7 @@ fn wire__web_audio_api__node__DelayNode_disconnect_output_impl(
             debug_name: "DelayNode_disconnect_output",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -16649,7 +19027,14 @@ fn wire__web_audio_api__node__DelayNode_disconnect_output_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "DelayNode_disconnect_output",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -16677,6 +19062,7 @@ fn wire__web_audio_api__node__DelayNode_frb_override_connect_impl(
             debug_name: "DelayNode_frb_override_connect",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -16709,8 +19095,22 @@ fn wire__web_audio_api__node__DelayNode_frb_override_connect_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                            1 => api_dest_guard = Some(api_dest.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "DelayNode_frb_override_connect",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
+                            1 => {
+                                api_dest_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "DelayNode_frb_override_connect",
+                                        "dest",
+                                        || api_dest.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -16739,6 +19139,7 @@ fn wire__web_audio_api__node__DelayNode_number_of_inputs_impl(
             debug_name: "DelayNode_number_of_inputs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -16765,7 +19166,14 @@ fn wire__web_audio_api__node__DelayNode_number_of_inputs_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "DelayNode_number_of_inputs",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -16790,6 +19198,7 @@ fn wire__web_audio_api__node__DelayNode_number_of_outputs_impl(
             debug_name: "DelayNode_number_of_outputs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -16816,7 +19225,14 @@ fn wire__web_audio_api__node__DelayNode_number_of_outputs_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "DelayNode_number_of_outputs",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -16841,6 +19257,7 @@ fn wire__web_audio_api__node__DelayNode_registration_impl(
             debug_name: "DelayNode_registration",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -16867,7 +19284,14 @@ fn wire__web_audio_api__node__DelayNode_registration_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "DelayNode_registration",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -16892,6 +19316,7 @@ fn wire__web_audio_api__node__DelayNode_set_on_processor_error_impl(
             debug_name: "DelayNode_set_on_processor_error",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -16921,7 +19346,14 @@ fn wire__web_audio_api__node__DelayNode_set_on_processor_error_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "DelayNode_set_on_processor_error",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -16949,6 +19381,7 @@ fn wire__web_audio_api__node__DynamicsCompressorNode_channel_config_impl(
             debug_name: "DynamicsCompressorNode_channel_config",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -16975,7 +19408,14 @@ fn wire__web_audio_api__node__DynamicsCompressorNode_channel_config_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "DynamicsCompressorNode_channel_config",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -17002,6 +19442,7 @@ fn wire__web_audio_api__node__DynamicsCompressorNode_channel_count_impl(
             debug_name: "DynamicsCompressorNode_channel_count",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -17028,7 +19469,14 @@ fn wire__web_audio_api__node__DynamicsCompressorNode_channel_count_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "DynamicsCompressorNode_channel_count",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -17055,6 +19503,7 @@ fn wire__web_audio_api__node__DynamicsCompressorNode_channel_count_mode_impl(
             debug_name: "DynamicsCompressorNode_channel_count_mode",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -17081,7 +19530,14 @@ fn wire__web_audio_api__node__DynamicsCompressorNode_channel_count_mode_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "DynamicsCompressorNode_channel_count_mode",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -17108,6 +19564,7 @@ fn wire__web_audio_api__node__DynamicsCompressorNode_channel_interpretation_impl
             debug_name: "DynamicsCompressorNode_channel_interpretation",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -17134,7 +19591,14 @@ fn wire__web_audio_api__node__DynamicsCompressorNode_channel_interpretation_impl
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "DynamicsCompressorNode_channel_interpretation",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -17161,6 +19625,7 @@ fn wire__web_audio_api__node__DynamicsCompressorNode_clear_onprocessorerror_impl
             debug_name: "DynamicsCompressorNode_clear_onprocessorerror",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -17187,7 +19652,14 @@ fn wire__web_audio_api__node__DynamicsCompressorNode_clear_onprocessorerror_impl
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "DynamicsCompressorNode_clear_onprocessorerror",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -17214,6 +19686,7 @@ fn wire__web_audio_api__node__DynamicsCompressorNode_disconnect_impl(
             debug_name: "DynamicsCompressorNode_disconnect",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -17240,7 +19713,14 @@ fn wire__web_audio_api__node__DynamicsCompressorNode_disconnect_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "DynamicsCompressorNode_disconnect",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -17265,6 +19745,7 @@ fn wire__web_audio_api__node__DynamicsCompressorNode_disconnect_output_impl(
             debug_name: "DynamicsCompressorNode_disconnect_output",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -17292,7 +19773,14 @@ fn wire__web_audio_api__node__DynamicsCompressorNode_disconnect_output_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "DynamicsCompressorNode_disconnect_output",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -17320,6 +19808,7 @@ fn wire__web_audio_api__node__DynamicsCompressorNode_frb_override_connect_impl(
             debug_name: "DynamicsCompressorNode_frb_override_connect",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -17352,8 +19841,22 @@ fn wire__web_audio_api__node__DynamicsCompressorNode_frb_override_connect_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                            1 => api_dest_guard = Some(api_dest.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "DynamicsCompressorNode_frb_override_connect",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
+                            1 => {
+                                api_dest_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "DynamicsCompressorNode_frb_override_connect",
+                                        "dest",
+                                        || api_dest.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -17382,6 +19885,7 @@ fn wire__web_audio_api__node__DynamicsCompressorNode_number_of_inputs_impl(
             debug_name: "DynamicsCompressorNode_number_of_inputs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -17408,7 +19912,14 @@ fn wire__web_audio_api__node__DynamicsCompressorNode_number_of_inputs_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "DynamicsCompressorNode_number_of_inputs",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -17435,6 +19946,7 @@ fn wire__web_audio_api__node__DynamicsCompressorNode_number_of_outputs_impl(
             debug_name: "DynamicsCompressorNode_number_of_outputs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -17461,7 +19973,14 @@ fn wire__web_audio_api__node__DynamicsCompressorNode_number_of_outputs_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "DynamicsCompressorNode_number_of_outputs",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -17488,6 +20007,7 @@ fn wire__web_audio_api__node__DynamicsCompressorNode_reduction_impl(
             debug_name: "DynamicsCompressorNode_reduction",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -17514,7 +20034,14 @@ fn wire__web_audio_api__node__DynamicsCompressorNode_reduction_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "DynamicsCompressorNode_reduction",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -17539,6 +20066,7 @@ fn wire__web_audio_api__node__DynamicsCompressorNode_registration_impl(
             debug_name: "DynamicsCompressorNode_registration",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -17565,7 +20093,14 @@ fn wire__web_audio_api__node__DynamicsCompressorNode_registration_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "DynamicsCompressorNode_registration",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -17590,6 +20125,7 @@ fn wire__web_audio_api__node__DynamicsCompressorNode_set_on_processor_error_impl
             debug_name: "DynamicsCompressorNode_set_on_processor_error",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -17619,7 +20155,14 @@ fn wire__web_audio_api__node__DynamicsCompressorNode_set_on_processor_error_impl
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "DynamicsCompressorNode_set_on_processor_error",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -17647,6 +20190,7 @@ fn wire__web_audio_api__node__GainNode_channel_config_impl(
             debug_name: "GainNode_channel_config",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -17673,7 +20217,14 @@ fn wire__web_audio_api__node__GainNode_channel_config_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "GainNode_channel_config",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -17698,6 +20249,7 @@ fn wire__web_audio_api__node__GainNode_channel_count_impl(
             debug_name: "GainNode_channel_count",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -17724,7 +20276,14 @@ fn wire__web_audio_api__node__GainNode_channel_count_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "GainNode_channel_count",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -17749,6 +20308,7 @@ fn wire__web_audio_api__node__GainNode_channel_count_mode_impl(
             debug_name: "GainNode_channel_count_mode",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -17775,7 +20335,14 @@ fn wire__web_audio_api__node__GainNode_channel_count_mode_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "GainNode_channel_count_mode",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -17800,6 +20367,7 @@ fn wire__web_audio_api__node__GainNode_channel_interpretation_impl(
             debug_name: "GainNode_channel_interpretation",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -17826,7 +20394,14 @@ fn wire__web_audio_api__node__GainNode_channel_interpretation_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "GainNode_channel_interpretation",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -17851,6 +20426,7 @@ fn wire__web_audio_api__node__GainNode_clear_onprocessorerror_impl(
             debug_name: "GainNode_clear_onprocessorerror",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -17877,7 +20453,14 @@ fn wire__web_audio_api__node__GainNode_clear_onprocessorerror_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "GainNode_clear_onprocessorerror",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -17902,6 +20485,7 @@ fn wire__web_audio_api__node__GainNode_disconnect_impl(
             debug_name: "GainNode_disconnect",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -17928,7 +20512,14 @@ fn wire__web_audio_api__node__GainNode_disconnect_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "GainNode_disconnect",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -17953,6 +20544,7 @@ fn wire__web_audio_api__node__GainNode_disconnect_output_impl(
             debug_name: "GainNode_disconnect_output",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -17980,7 +20572,14 @@ fn wire__web_audio_api__node__GainNode_disconnect_output_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "GainNode_disconnect_output",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -18008,6 +20607,7 @@ fn wire__web_audio_api__node__GainNode_frb_override_connect_impl(
             debug_name: "GainNode_frb_override_connect",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -18040,8 +20640,22 @@ fn wire__web_audio_api__node__GainNode_frb_override_connect_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                            1 => api_dest_guard = Some(api_dest.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "GainNode_frb_override_connect",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
+                            1 => {
+                                api_dest_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "GainNode_frb_override_connect",
+                                        "dest",
+                                        || api_dest.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -18070,6 +20684,7 @@ fn wire__web_audio_api__node__GainNode_number_of_inputs_impl(
             debug_name: "GainNode_number_of_inputs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -18096,7 +20711,14 @@ fn wire__web_audio_api__node__GainNode_number_of_inputs_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "GainNode_number_of_inputs",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -18121,6 +20743,7 @@ fn wire__web_audio_api__node__GainNode_number_of_outputs_impl(
             debug_name: "GainNode_number_of_outputs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -18147,7 +20770,14 @@ fn wire__web_audio_api__node__GainNode_number_of_outputs_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "GainNode_number_of_outputs",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -18172,6 +20802,7 @@ fn wire__web_audio_api__node__GainNode_registration_impl(
             debug_name: "GainNode_registration",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -18198,7 +20829,14 @@ fn wire__web_audio_api__node__GainNode_registration_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "GainNode_registration",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -18223,6 +20861,7 @@ fn wire__web_audio_api__node__GainNode_set_on_processor_error_impl(
             debug_name: "GainNode_set_on_processor_error",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -18252,7 +20891,14 @@ fn wire__web_audio_api__node__GainNode_set_on_processor_error_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "GainNode_set_on_processor_error",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -18280,6 +20926,7 @@ fn wire__web_audio_api__node__IirFilterNode_channel_config_impl(
             debug_name: "IirFilterNode_channel_config",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -18306,7 +20953,14 @@ fn wire__web_audio_api__node__IirFilterNode_channel_config_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "IirFilterNode_channel_config",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -18331,6 +20985,7 @@ fn wire__web_audio_api__node__IirFilterNode_channel_count_impl(
             debug_name: "IirFilterNode_channel_count",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -18357,7 +21012,14 @@ fn wire__web_audio_api__node__IirFilterNode_channel_count_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "IirFilterNode_channel_count",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -18382,6 +21044,7 @@ fn wire__web_audio_api__node__IirFilterNode_channel_count_mode_impl(
             debug_name: "IirFilterNode_channel_count_mode",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -18408,7 +21071,14 @@ fn wire__web_audio_api__node__IirFilterNode_channel_count_mode_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "IirFilterNode_channel_count_mode",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -18433,6 +21103,7 @@ fn wire__web_audio_api__node__IirFilterNode_channel_interpretation_impl(
             debug_name: "IirFilterNode_channel_interpretation",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -18459,7 +21130,14 @@ fn wire__web_audio_api__node__IirFilterNode_channel_interpretation_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "IirFilterNode_channel_interpretation",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -18486,6 +21164,7 @@ fn wire__web_audio_api__node__IirFilterNode_clear_onprocessorerror_impl(
             debug_name: "IirFilterNode_clear_onprocessorerror",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -18512,7 +21191,14 @@ fn wire__web_audio_api__node__IirFilterNode_clear_onprocessorerror_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "IirFilterNode_clear_onprocessorerror",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -18539,6 +21225,7 @@ fn wire__web_audio_api__node__IirFilterNode_disconnect_impl(
             debug_name: "IirFilterNode_disconnect",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -18565,7 +21252,14 @@ fn wire__web_audio_api__node__IirFilterNode_disconnect_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "IirFilterNode_disconnect",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -18590,6 +21284,7 @@ fn wire__web_audio_api__node__IirFilterNode_disconnect_output_impl(
             debug_name: "IirFilterNode_disconnect_output",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -18617,7 +21312,14 @@ fn wire__web_audio_api__node__IirFilterNode_disconnect_output_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "IirFilterNode_disconnect_output",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -18645,6 +21347,7 @@ fn wire__web_audio_api__node__IirFilterNode_frb_override_connect_impl(
             debug_name: "IirFilterNode_frb_override_connect",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -18677,8 +21380,22 @@ fn wire__web_audio_api__node__IirFilterNode_frb_override_connect_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                            1 => api_dest_guard = Some(api_dest.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "IirFilterNode_frb_override_connect",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
+                            1 => {
+                                api_dest_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "IirFilterNode_frb_override_connect",
+                                        "dest",
+                                        || api_dest.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -18707,6 +21424,7 @@ fn wire__web_audio_api__node__IirFilterNode_number_of_inputs_impl(
             debug_name: "IirFilterNode_number_of_inputs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -18733,7 +21451,14 @@ fn wire__web_audio_api__node__IirFilterNode_number_of_inputs_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "IirFilterNode_number_of_inputs",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -18758,6 +21483,7 @@ fn wire__web_audio_api__node__IirFilterNode_number_of_outputs_impl(
             debug_name: "IirFilterNode_number_of_outputs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -18784,7 +21510,14 @@ fn wire__web_audio_api__node__IirFilterNode_number_of_outputs_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "IirFilterNode_number_of_outputs",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -18809,6 +21542,7 @@ fn wire__web_audio_api__node__IirFilterNode_registration_impl(
             debug_name: "IirFilterNode_registration",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -18835,7 +21569,14 @@ fn wire__web_audio_api__node__IirFilterNode_registration_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "IirFilterNode_registration",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -18860,6 +21601,7 @@ fn wire__web_audio_api__node__IirFilterNode_set_on_processor_error_impl(
             debug_name: "IirFilterNode_set_on_processor_error",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -18889,7 +21631,14 @@ fn wire__web_audio_api__node__IirFilterNode_set_on_processor_error_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "IirFilterNode_set_on_processor_error",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -18917,6 +21666,7 @@ fn wire__web_audio_api__node__MediaElementAudioSourceNode_channel_config_impl(
             debug_name: "MediaElementAudioSourceNode_channel_config",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -18945,7 +21695,14 @@ fn wire__web_audio_api__node__MediaElementAudioSourceNode_channel_config_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "MediaElementAudioSourceNode_channel_config",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -18972,6 +21729,7 @@ fn wire__web_audio_api__node__MediaElementAudioSourceNode_channel_count_impl(
             debug_name: "MediaElementAudioSourceNode_channel_count",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -19000,7 +21758,14 @@ fn wire__web_audio_api__node__MediaElementAudioSourceNode_channel_count_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "MediaElementAudioSourceNode_channel_count",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -19027,6 +21792,7 @@ fn wire__web_audio_api__node__MediaElementAudioSourceNode_channel_count_mode_imp
             debug_name: "MediaElementAudioSourceNode_channel_count_mode",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -19055,7 +21821,14 @@ fn wire__web_audio_api__node__MediaElementAudioSourceNode_channel_count_mode_imp
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "MediaElementAudioSourceNode_channel_count_mode",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -19082,6 +21855,7 @@ fn wire__web_audio_api__node__MediaElementAudioSourceNode_channel_interpretation
             debug_name: "MediaElementAudioSourceNode_channel_interpretation",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -19110,7 +21884,14 @@ fn wire__web_audio_api__node__MediaElementAudioSourceNode_channel_interpretation
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "MediaElementAudioSourceNode_channel_interpretation",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -19137,6 +21918,7 @@ fn wire__web_audio_api__node__MediaElementAudioSourceNode_clear_onprocessorerror
             debug_name: "MediaElementAudioSourceNode_clear_onprocessorerror",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -19165,7 +21947,14 @@ fn wire__web_audio_api__node__MediaElementAudioSourceNode_clear_onprocessorerror
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "MediaElementAudioSourceNode_clear_onprocessorerror",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -19192,6 +21981,7 @@ fn wire__web_audio_api__node__MediaElementAudioSourceNode_disconnect_impl(
             debug_name: "MediaElementAudioSourceNode_disconnect",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -19220,7 +22010,14 @@ fn wire__web_audio_api__node__MediaElementAudioSourceNode_disconnect_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "MediaElementAudioSourceNode_disconnect",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -19247,6 +22044,7 @@ fn wire__web_audio_api__node__MediaElementAudioSourceNode_disconnect_output_impl
             debug_name: "MediaElementAudioSourceNode_disconnect_output",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -19276,7 +22074,14 @@ fn wire__web_audio_api__node__MediaElementAudioSourceNode_disconnect_output_impl
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "MediaElementAudioSourceNode_disconnect_output",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -19304,6 +22109,7 @@ fn wire__web_audio_api__node__MediaElementAudioSourceNode_frb_override_connect_i
             debug_name: "MediaElementAudioSourceNode_frb_override_connect",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -19338,8 +22144,22 @@ fn wire__web_audio_api__node__MediaElementAudioSourceNode_frb_override_connect_i
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                            1 => api_dest_guard = Some(api_dest.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "MediaElementAudioSourceNode_frb_override_connect",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
+                            1 => {
+                                api_dest_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "MediaElementAudioSourceNode_frb_override_connect",
+                                        "dest",
+                                        || api_dest.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -19368,6 +22188,7 @@ fn wire__web_audio_api__node__MediaElementAudioSourceNode_number_of_inputs_impl(
             debug_name: "MediaElementAudioSourceNode_number_of_inputs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -19396,7 +22217,14 @@ fn wire__web_audio_api__node__MediaElementAudioSourceNode_number_of_inputs_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "MediaElementAudioSourceNode_number_of_inputs",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -19423,6 +22251,7 @@ fn wire__web_audio_api__node__MediaElementAudioSourceNode_number_of_outputs_impl
             debug_name: "MediaElementAudioSourceNode_number_of_outputs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -19451,7 +22280,14 @@ fn wire__web_audio_api__node__MediaElementAudioSourceNode_number_of_outputs_impl
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "MediaElementAudioSourceNode_number_of_outputs",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -19478,6 +22314,7 @@ fn wire__web_audio_api__node__MediaElementAudioSourceNode_registration_impl(
             debug_name: "MediaElementAudioSourceNode_registration",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -19506,7 +22343,14 @@ fn wire__web_audio_api__node__MediaElementAudioSourceNode_registration_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "MediaElementAudioSourceNode_registration",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -19533,6 +22377,7 @@ fn wire__web_audio_api__node__MediaElementAudioSourceNode_set_on_processor_error
             debug_name: "MediaElementAudioSourceNode_set_on_processor_error",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -19564,7 +22409,14 @@ fn wire__web_audio_api__node__MediaElementAudioSourceNode_set_on_processor_error
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "MediaElementAudioSourceNode_set_on_processor_error",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -19592,6 +22444,7 @@ fn wire__web_audio_api__node__MediaStreamAudioDestinationNode_channel_config_imp
             debug_name: "MediaStreamAudioDestinationNode_channel_config",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -19620,7 +22473,14 @@ fn wire__web_audio_api__node__MediaStreamAudioDestinationNode_channel_config_imp
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "MediaStreamAudioDestinationNode_channel_config",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -19647,6 +22507,7 @@ fn wire__web_audio_api__node__MediaStreamAudioDestinationNode_channel_count_impl
             debug_name: "MediaStreamAudioDestinationNode_channel_count",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -19675,7 +22536,14 @@ fn wire__web_audio_api__node__MediaStreamAudioDestinationNode_channel_count_impl
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "MediaStreamAudioDestinationNode_channel_count",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -19702,6 +22570,7 @@ fn wire__web_audio_api__node__MediaStreamAudioDestinationNode_channel_count_mode
             debug_name: "MediaStreamAudioDestinationNode_channel_count_mode",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -19730,7 +22599,14 @@ fn wire__web_audio_api__node__MediaStreamAudioDestinationNode_channel_count_mode
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "MediaStreamAudioDestinationNode_channel_count_mode",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -19752,16 +22628,16 @@ fn wire__web_audio_api__node__MediaStreamAudioDestinationNode_channel_interpreta
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "MediaStreamAudioDestinationNode_channel_interpretation", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
-            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
-            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MediaStreamAudioDestinationNode>>>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "MediaStreamAudioDestinationNode_channel_interpretation", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal, executor: None }, move || { 
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MediaStreamAudioDestinationNode>>>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
                     transform_result_sse::<_, ()>((move ||  {
                         let mut api_that_guard = None;
 let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
         for i in decode_indices_ {
             match i {
-                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                0 => api_that_guard = Some(flutter_rust_bridge::for_generated::lockable_with_site("MediaStreamAudioDestinationNode_channel_interpretation", "that", || api_that.lockable_decode_sync_ref())),
                 _ => unreachable!(),
             }
         }
@@ -19776,16 +22652,16 @@ fn wire__web_audio_api__node__MediaStreamAudioDestinationNode_clear_onprocessore
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "MediaStreamAudioDestinationNode_clear_onprocessorerror", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
-            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
-            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MediaStreamAudioDestinationNode>>>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "MediaStreamAudioDestinationNode_clear_onprocessorerror", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal, executor: None }, move || { 
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MediaStreamAudioDestinationNode>>>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
                     transform_result_sse::<_, ()>((move ||  {
                         let mut api_that_guard = None;
 let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
         for i in decode_indices_ {
             match i {
-                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                0 => api_that_guard = Some(flutter_rust_bridge::for_generated::lockable_with_site("MediaStreamAudioDestinationNode_clear_onprocessorerror", "that", || api_that.lockable_decode_sync_ref())),
                 _ => unreachable!(),
             }
         }
@@ -19805,6 +22681,7 @@ fn wire__web_audio_api__node__MediaStreamAudioDestinationNode_disconnect_impl(
             debug_name: "MediaStreamAudioDestinationNode_disconnect",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -19833,7 +22710,14 @@ fn wire__web_audio_api__node__MediaStreamAudioDestinationNode_disconnect_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "MediaStreamAudioDestinationNode_disconnect",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -19860,6 +22744,7 @@ fn wire__web_audio_api__node__MediaStreamAudioDestinationNode_disconnect_output_
             debug_name: "MediaStreamAudioDestinationNode_disconnect_output",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -19889,7 +22774,14 @@ fn wire__web_audio_api__node__MediaStreamAudioDestinationNode_disconnect_output_
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "MediaStreamAudioDestinationNode_disconnect_output",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -19917,6 +22809,7 @@ fn wire__web_audio_api__node__MediaStreamAudioDestinationNode_frb_override_conne
             debug_name: "MediaStreamAudioDestinationNode_frb_override_connect",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -19951,8 +22844,22 @@ fn wire__web_audio_api__node__MediaStreamAudioDestinationNode_frb_override_conne
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                            1 => api_dest_guard = Some(api_dest.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "MediaStreamAudioDestinationNode_frb_override_connect",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
+                            1 => {
+                                api_dest_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "MediaStreamAudioDestinationNode_frb_override_connect",
+                                        "dest",
+                                        || api_dest.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -19981,6 +22888,7 @@ fn wire__web_audio_api__node__MediaStreamAudioDestinationNode_number_of_inputs_i
             debug_name: "MediaStreamAudioDestinationNode_number_of_inputs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -20009,7 +22917,14 @@ fn wire__web_audio_api__node__MediaStreamAudioDestinationNode_number_of_inputs_i
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "MediaStreamAudioDestinationNode_number_of_inputs",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -20036,6 +22951,7 @@ fn wire__web_audio_api__node__MediaStreamAudioDestinationNode_number_of_outputs_
             debug_name: "MediaStreamAudioDestinationNode_number_of_outputs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -20064,7 +22980,14 @@ fn wire__web_audio_api__node__MediaStreamAudioDestinationNode_number_of_outputs_
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "MediaStreamAudioDestinationNode_number_of_outputs",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -20091,6 +23014,7 @@ fn wire__web_audio_api__node__MediaStreamAudioDestinationNode_registration_impl(
             debug_name: "MediaStreamAudioDestinationNode_registration",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -20119,7 +23043,14 @@ fn wire__web_audio_api__node__MediaStreamAudioDestinationNode_registration_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "MediaStreamAudioDestinationNode_registration",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -20141,17 +23072,17 @@ fn wire__web_audio_api__node__MediaStreamAudioDestinationNode_set_on_processor_e
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "MediaStreamAudioDestinationNode_set_on_processor_error", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
-            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
-            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MediaStreamAudioDestinationNode>>>::sse_decode(&mut deserializer);
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "MediaStreamAudioDestinationNode_set_on_processor_error", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal, executor: None }, move || { 
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MediaStreamAudioDestinationNode>>>::sse_decode(&mut deserializer);
 let api_callback = decode_DartFn_Inputs_String_Output_unit_AnyhowException(<flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer));deserializer.end(); move |context|  {
                     transform_result_sse::<_, ()>((move ||  {
                         let mut api_that_guard = None;
 let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
         for i in decode_indices_ {
             match i {
-                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                0 => api_that_guard = Some(flutter_rust_bridge::for_generated::lockable_with_site("MediaStreamAudioDestinationNode_set_on_processor_error", "that", || api_that.lockable_decode_sync_ref())),
                 _ => unreachable!(),
             }
         }
@@ -20171,6 +23102,7 @@ fn wire__web_audio_api__node__MediaStreamAudioSourceNode_channel_config_impl(
             debug_name: "MediaStreamAudioSourceNode_channel_config",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -20197,7 +23129,14 @@ fn wire__web_audio_api__node__MediaStreamAudioSourceNode_channel_config_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "MediaStreamAudioSourceNode_channel_config",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -20224,6 +23163,7 @@ fn wire__web_audio_api__node__MediaStreamAudioSourceNode_channel_count_impl(
             debug_name: "MediaStreamAudioSourceNode_channel_count",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -20250,7 +23190,14 @@ fn wire__web_audio_api__node__MediaStreamAudioSourceNode_channel_count_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "MediaStreamAudioSourceNode_channel_count",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -20277,6 +23224,7 @@ fn wire__web_audio_api__node__MediaStreamAudioSourceNode_channel_count_mode_impl
             debug_name: "MediaStreamAudioSourceNode_channel_count_mode",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -20303,7 +23251,14 @@ fn wire__web_audio_api__node__MediaStreamAudioSourceNode_channel_count_mode_impl
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "MediaStreamAudioSourceNode_channel_count_mode",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -20330,6 +23285,7 @@ fn wire__web_audio_api__node__MediaStreamAudioSourceNode_channel_interpretation_
             debug_name: "MediaStreamAudioSourceNode_channel_interpretation",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -20356,7 +23312,14 @@ fn wire__web_audio_api__node__MediaStreamAudioSourceNode_channel_interpretation_
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "MediaStreamAudioSourceNode_channel_interpretation",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -20383,6 +23346,7 @@ fn wire__web_audio_api__node__MediaStreamAudioSourceNode_clear_onprocessorerror_
             debug_name: "MediaStreamAudioSourceNode_clear_onprocessorerror",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -20409,7 +23373,14 @@ fn wire__web_audio_api__node__MediaStreamAudioSourceNode_clear_onprocessorerror_
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "MediaStreamAudioSourceNode_clear_onprocessorerror",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -20436,6 +23407,7 @@ fn wire__web_audio_api__node__MediaStreamAudioSourceNode_disconnect_impl(
             debug_name: "MediaStreamAudioSourceNode_disconnect",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -20462,7 +23434,14 @@ fn wire__web_audio_api__node__MediaStreamAudioSourceNode_disconnect_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "MediaStreamAudioSourceNode_disconnect",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -20489,6 +23468,7 @@ fn wire__web_audio_api__node__MediaStreamAudioSourceNode_disconnect_output_impl(
             debug_name: "MediaStreamAudioSourceNode_disconnect_output",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -20516,7 +23496,14 @@ fn wire__web_audio_api__node__MediaStreamAudioSourceNode_disconnect_output_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "MediaStreamAudioSourceNode_disconnect_output",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -20544,6 +23531,7 @@ fn wire__web_audio_api__node__MediaStreamAudioSourceNode_frb_override_connect_im
             debug_name: "MediaStreamAudioSourceNode_frb_override_connect",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -20576,8 +23564,22 @@ fn wire__web_audio_api__node__MediaStreamAudioSourceNode_frb_override_connect_im
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                            1 => api_dest_guard = Some(api_dest.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "MediaStreamAudioSourceNode_frb_override_connect",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
+                            1 => {
+                                api_dest_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "MediaStreamAudioSourceNode_frb_override_connect",
+                                        "dest",
+                                        || api_dest.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -20606,6 +23608,7 @@ fn wire__web_audio_api__node__MediaStreamAudioSourceNode_number_of_inputs_impl(
             debug_name: "MediaStreamAudioSourceNode_number_of_inputs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -20632,7 +23635,14 @@ fn wire__web_audio_api__node__MediaStreamAudioSourceNode_number_of_inputs_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "MediaStreamAudioSourceNode_number_of_inputs",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -20659,6 +23669,7 @@ fn wire__web_audio_api__node__MediaStreamAudioSourceNode_number_of_outputs_impl(
             debug_name: "MediaStreamAudioSourceNode_number_of_outputs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -20685,7 +23696,14 @@ fn wire__web_audio_api__node__MediaStreamAudioSourceNode_number_of_outputs_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "MediaStreamAudioSourceNode_number_of_outputs",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -20712,6 +23730,7 @@ fn wire__web_audio_api__node__MediaStreamAudioSourceNode_registration_impl(
             debug_name: "MediaStreamAudioSourceNode_registration",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -20738,7 +23757,14 @@ fn wire__web_audio_api__node__MediaStreamAudioSourceNode_registration_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "MediaStreamAudioSourceNode_registration",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -20765,6 +23791,7 @@ fn wire__web_audio_api__node__MediaStreamAudioSourceNode_set_on_processor_error_
             debug_name: "MediaStreamAudioSourceNode_set_on_processor_error",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -20794,7 +23821,14 @@ fn wire__web_audio_api__node__MediaStreamAudioSourceNode_set_on_processor_error_
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "MediaStreamAudioSourceNode_set_on_processor_error",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -20822,6 +23856,7 @@ fn wire__web_audio_api__node__MediaStreamTrackAudioSourceNode_channel_config_imp
             debug_name: "MediaStreamTrackAudioSourceNode_channel_config",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -20850,7 +23885,14 @@ fn wire__web_audio_api__node__MediaStreamTrackAudioSourceNode_channel_config_imp
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "MediaStreamTrackAudioSourceNode_channel_config",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -20877,6 +23919,7 @@ fn wire__web_audio_api__node__MediaStreamTrackAudioSourceNode_channel_count_impl
             debug_name: "MediaStreamTrackAudioSourceNode_channel_count",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -20905,7 +23948,14 @@ fn wire__web_audio_api__node__MediaStreamTrackAudioSourceNode_channel_count_impl
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "MediaStreamTrackAudioSourceNode_channel_count",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -20932,6 +23982,7 @@ fn wire__web_audio_api__node__MediaStreamTrackAudioSourceNode_channel_count_mode
             debug_name: "MediaStreamTrackAudioSourceNode_channel_count_mode",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -20960,7 +24011,14 @@ fn wire__web_audio_api__node__MediaStreamTrackAudioSourceNode_channel_count_mode
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "MediaStreamTrackAudioSourceNode_channel_count_mode",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -20982,16 +24040,16 @@ fn wire__web_audio_api__node__MediaStreamTrackAudioSourceNode_channel_interpreta
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "MediaStreamTrackAudioSourceNode_channel_interpretation", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
-            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
-            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MediaStreamTrackAudioSourceNode>>>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "MediaStreamTrackAudioSourceNode_channel_interpretation", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal, executor: None }, move || { 
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MediaStreamTrackAudioSourceNode>>>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
                     transform_result_sse::<_, ()>((move ||  {
                         let mut api_that_guard = None;
 let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
         for i in decode_indices_ {
             match i {
-                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                0 => api_that_guard = Some(flutter_rust_bridge::for_generated::lockable_with_site("MediaStreamTrackAudioSourceNode_channel_interpretation", "that", || api_that.lockable_decode_sync_ref())),
                 _ => unreachable!(),
             }
         }
@@ -21006,16 +24064,16 @@ fn wire__web_audio_api__node__MediaStreamTrackAudioSourceNode_clear_onprocessore
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "MediaStreamTrackAudioSourceNode_clear_onprocessorerror", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
-            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
-            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MediaStreamTrackAudioSourceNode>>>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "MediaStreamTrackAudioSourceNode_clear_onprocessorerror", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal, executor: None }, move || { 
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MediaStreamTrackAudioSourceNode>>>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
                     transform_result_sse::<_, ()>((move ||  {
                         let mut api_that_guard = None;
 let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
         for i in decode_indices_ {
             match i {
-                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                0 => api_that_guard = Some(flutter_rust_bridge::for_generated::lockable_with_site("MediaStreamTrackAudioSourceNode_clear_onprocessorerror", "that", || api_that.lockable_decode_sync_ref())),
                 _ => unreachable!(),
             }
         }
@@ -21035,6 +24093,7 @@ fn wire__web_audio_api__node__MediaStreamTrackAudioSourceNode_disconnect_impl(
             debug_name: "MediaStreamTrackAudioSourceNode_disconnect",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -21063,7 +24122,14 @@ fn wire__web_audio_api__node__MediaStreamTrackAudioSourceNode_disconnect_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "MediaStreamTrackAudioSourceNode_disconnect",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -21090,6 +24156,7 @@ fn wire__web_audio_api__node__MediaStreamTrackAudioSourceNode_disconnect_output_
             debug_name: "MediaStreamTrackAudioSourceNode_disconnect_output",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -21119,7 +24186,14 @@ fn wire__web_audio_api__node__MediaStreamTrackAudioSourceNode_disconnect_output_
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "MediaStreamTrackAudioSourceNode_disconnect_output",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -21147,6 +24221,7 @@ fn wire__web_audio_api__node__MediaStreamTrackAudioSourceNode_frb_override_conne
             debug_name: "MediaStreamTrackAudioSourceNode_frb_override_connect",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -21181,8 +24256,22 @@ fn wire__web_audio_api__node__MediaStreamTrackAudioSourceNode_frb_override_conne
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                            1 => api_dest_guard = Some(api_dest.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "MediaStreamTrackAudioSourceNode_frb_override_connect",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
+                            1 => {
+                                api_dest_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "MediaStreamTrackAudioSourceNode_frb_override_connect",
+                                        "dest",
+                                        || api_dest.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -21211,6 +24300,7 @@ fn wire__web_audio_api__node__MediaStreamTrackAudioSourceNode_number_of_inputs_i
             debug_name: "MediaStreamTrackAudioSourceNode_number_of_inputs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -21239,7 +24329,14 @@ fn wire__web_audio_api__node__MediaStreamTrackAudioSourceNode_number_of_inputs_i
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "MediaStreamTrackAudioSourceNode_number_of_inputs",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -21266,6 +24363,7 @@ fn wire__web_audio_api__node__MediaStreamTrackAudioSourceNode_number_of_outputs_
             debug_name: "MediaStreamTrackAudioSourceNode_number_of_outputs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -21294,7 +24392,14 @@ fn wire__web_audio_api__node__MediaStreamTrackAudioSourceNode_number_of_outputs_
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "MediaStreamTrackAudioSourceNode_number_of_outputs",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -21321,6 +24426,7 @@ fn wire__web_audio_api__node__MediaStreamTrackAudioSourceNode_registration_impl(
             debug_name: "MediaStreamTrackAudioSourceNode_registration",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -21349,7 +24455,14 @@ fn wire__web_audio_api__node__MediaStreamTrackAudioSourceNode_registration_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "MediaStreamTrackAudioSourceNode_registration",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -21371,17 +24484,17 @@ fn wire__web_audio_api__node__MediaStreamTrackAudioSourceNode_set_on_processor_e
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "MediaStreamTrackAudioSourceNode_set_on_processor_error", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
-            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
-            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MediaStreamTrackAudioSourceNode>>>::sse_decode(&mut deserializer);
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "MediaStreamTrackAudioSourceNode_set_on_processor_error", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal, executor: None }, move || { 
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MediaStreamTrackAudioSourceNode>>>::sse_decode(&mut deserializer);
 let api_callback = decode_DartFn_Inputs_String_Output_unit_AnyhowException(<flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer));deserializer.end(); move |context|  {
                     transform_result_sse::<_, ()>((move ||  {
                         let mut api_that_guard = None;
 let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
         for i in decode_indices_ {
             match i {
-                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                0 => api_that_guard = Some(flutter_rust_bridge::for_generated::lockable_with_site("MediaStreamTrackAudioSourceNode_set_on_processor_error", "that", || api_that.lockable_decode_sync_ref())),
                 _ => unreachable!(),
             }
         }
@@ -21401,6 +24514,7 @@ fn wire__web_audio_api__node__OscillatorNode_channel_config_impl(
             debug_name: "OscillatorNode_channel_config",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -21427,7 +24541,14 @@ fn wire__web_audio_api__node__OscillatorNode_channel_config_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "OscillatorNode_channel_config",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -21452,6 +24573,7 @@ fn wire__web_audio_api__node__OscillatorNode_channel_count_impl(
             debug_name: "OscillatorNode_channel_count",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -21478,7 +24600,14 @@ fn wire__web_audio_api__node__OscillatorNode_channel_count_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "OscillatorNode_channel_count",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -21503,6 +24632,7 @@ fn wire__web_audio_api__node__OscillatorNode_channel_count_mode_impl(
             debug_name: "OscillatorNode_channel_count_mode",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -21529,7 +24659,14 @@ fn wire__web_audio_api__node__OscillatorNode_channel_count_mode_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "OscillatorNode_channel_count_mode",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -21554,6 +24691,7 @@ fn wire__web_audio_api__node__OscillatorNode_channel_interpretation_impl(
             debug_name: "OscillatorNode_channel_interpretation",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -21580,7 +24718,14 @@ fn wire__web_audio_api__node__OscillatorNode_channel_interpretation_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "OscillatorNode_channel_interpretation",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -21607,6 +24752,7 @@ fn wire__web_audio_api__node__OscillatorNode_clear_onended_impl(
             debug_name: "OscillatorNode_clear_onended",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -21633,7 +24779,14 @@ fn wire__web_audio_api__node__OscillatorNode_clear_onended_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "OscillatorNode_clear_onended",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -21658,6 +24811,7 @@ fn wire__web_audio_api__node__OscillatorNode_clear_onprocessorerror_impl(
             debug_name: "OscillatorNode_clear_onprocessorerror",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -21684,7 +24838,14 @@ fn wire__web_audio_api__node__OscillatorNode_clear_onprocessorerror_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "OscillatorNode_clear_onprocessorerror",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -21711,6 +24872,7 @@ fn wire__web_audio_api__node__OscillatorNode_disconnect_impl(
             debug_name: "OscillatorNode_disconnect",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -21737,7 +24899,14 @@ fn wire__web_audio_api__node__OscillatorNode_disconnect_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "OscillatorNode_disconnect",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -21762,6 +24931,7 @@ fn wire__web_audio_api__node__OscillatorNode_disconnect_output_impl(
             debug_name: "OscillatorNode_disconnect_output",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -21789,7 +24959,14 @@ fn wire__web_audio_api__node__OscillatorNode_disconnect_output_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "OscillatorNode_disconnect_output",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -21817,6 +24994,7 @@ fn wire__web_audio_api__node__OscillatorNode_frb_override_connect_impl(
             debug_name: "OscillatorNode_frb_override_connect",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -21849,8 +25027,22 @@ fn wire__web_audio_api__node__OscillatorNode_frb_override_connect_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                            1 => api_dest_guard = Some(api_dest.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "OscillatorNode_frb_override_connect",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
+                            1 => {
+                                api_dest_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "OscillatorNode_frb_override_connect",
+                                        "dest",
+                                        || api_dest.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -21879,6 +25071,7 @@ fn wire__web_audio_api__node__OscillatorNode_number_of_inputs_impl(
             debug_name: "OscillatorNode_number_of_inputs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -21905,7 +25098,14 @@ fn wire__web_audio_api__node__OscillatorNode_number_of_inputs_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "OscillatorNode_number_of_inputs",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -21930,6 +25130,7 @@ fn wire__web_audio_api__node__OscillatorNode_number_of_outputs_impl(
             debug_name: "OscillatorNode_number_of_outputs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -21956,7 +25157,14 @@ fn wire__web_audio_api__node__OscillatorNode_number_of_outputs_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "OscillatorNode_number_of_outputs",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -21981,6 +25189,7 @@ fn wire__web_audio_api__node__OscillatorNode_registration_impl(
             debug_name: "OscillatorNode_registration",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -22007,7 +25216,14 @@ fn wire__web_audio_api__node__OscillatorNode_registration_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "OscillatorNode_registration",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -22027,17 +25243,17 @@ fn wire__web_audio_api__node__OscillatorNode_set_on_ended_impl(
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "OscillatorNode_set_on_ended", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
-            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
-            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<OscillatorNode>>>::sse_decode(&mut deserializer);
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "OscillatorNode_set_on_ended", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal, executor: None }, move || { 
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<OscillatorNode>>>::sse_decode(&mut deserializer);
 let api_callback = decode_DartFn_Inputs_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEvent_Output_unit_AnyhowException(<flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer));deserializer.end(); move |context|  {
                     transform_result_sse::<_, ()>((move ||  {
                         let mut api_that_guard = None;
 let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
         for i in decode_indices_ {
             match i {
-                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                0 => api_that_guard = Some(flutter_rust_bridge::for_generated::lockable_with_site("OscillatorNode_set_on_ended", "that", || api_that.lockable_decode_sync_ref())),
                 _ => unreachable!(),
             }
         }
@@ -22057,6 +25273,7 @@ fn wire__web_audio_api__node__OscillatorNode_set_on_processor_error_impl(
             debug_name: "OscillatorNode_set_on_processor_error",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -22086,7 +25303,14 @@ fn wire__web_audio_api__node__OscillatorNode_set_on_processor_error_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "OscillatorNode_set_on_processor_error",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -22114,6 +25338,7 @@ fn wire__web_audio_api__node__OscillatorNode_set_periodic_wave_impl(
             debug_name: "OscillatorNode_set_periodic_wave",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -22141,7 +25366,14 @@ fn wire__web_audio_api__node__OscillatorNode_set_periodic_wave_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "OscillatorNode_set_periodic_wave",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref_mut(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -22169,6 +25401,7 @@ fn wire__web_audio_api__node__OscillatorNode_set_type_impl(
             debug_name: "OscillatorNode_set_type",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -22196,7 +25429,14 @@ fn wire__web_audio_api__node__OscillatorNode_set_type_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "OscillatorNode_set_type",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref_mut(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -22224,6 +25464,7 @@ fn wire__web_audio_api__node__OscillatorNode_start_impl(
             debug_name: "OscillatorNode_start",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -22250,7 +25491,14 @@ fn wire__web_audio_api__node__OscillatorNode_start_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "OscillatorNode_start",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref_mut(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -22275,6 +25523,7 @@ fn wire__web_audio_api__node__OscillatorNode_start_at_impl(
             debug_name: "OscillatorNode_start_at",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -22302,7 +25551,14 @@ fn wire__web_audio_api__node__OscillatorNode_start_at_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "OscillatorNode_start_at",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref_mut(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -22330,6 +25586,7 @@ fn wire__web_audio_api__node__OscillatorNode_stop_impl(
             debug_name: "OscillatorNode_stop",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -22356,7 +25613,14 @@ fn wire__web_audio_api__node__OscillatorNode_stop_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "OscillatorNode_stop",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref_mut(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -22381,6 +25645,7 @@ fn wire__web_audio_api__node__OscillatorNode_stop_at_impl(
             debug_name: "OscillatorNode_stop_at",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -22408,7 +25673,14 @@ fn wire__web_audio_api__node__OscillatorNode_stop_at_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "OscillatorNode_stop_at",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref_mut(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -22436,6 +25708,7 @@ fn wire__web_audio_api__node__OscillatorNode_type__impl(
             debug_name: "OscillatorNode_type_",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -22462,7 +25735,14 @@ fn wire__web_audio_api__node__OscillatorNode_type__impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "OscillatorNode_type_",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -22487,6 +25767,7 @@ fn wire__web_audio_api__node__PannerNode_channel_config_impl(
             debug_name: "PannerNode_channel_config",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -22513,7 +25794,14 @@ fn wire__web_audio_api__node__PannerNode_channel_config_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "PannerNode_channel_config",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -22538,6 +25826,7 @@ fn wire__web_audio_api__node__PannerNode_channel_count_impl(
             debug_name: "PannerNode_channel_count",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -22564,7 +25853,14 @@ fn wire__web_audio_api__node__PannerNode_channel_count_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "PannerNode_channel_count",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -22589,6 +25885,7 @@ fn wire__web_audio_api__node__PannerNode_channel_count_mode_impl(
             debug_name: "PannerNode_channel_count_mode",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -22615,7 +25912,14 @@ fn wire__web_audio_api__node__PannerNode_channel_count_mode_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "PannerNode_channel_count_mode",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -22640,6 +25944,7 @@ fn wire__web_audio_api__node__PannerNode_channel_interpretation_impl(
             debug_name: "PannerNode_channel_interpretation",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -22666,7 +25971,14 @@ fn wire__web_audio_api__node__PannerNode_channel_interpretation_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "PannerNode_channel_interpretation",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -22691,6 +26003,7 @@ fn wire__web_audio_api__node__PannerNode_clear_onprocessorerror_impl(
             debug_name: "PannerNode_clear_onprocessorerror",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -22717,7 +26030,14 @@ fn wire__web_audio_api__node__PannerNode_clear_onprocessorerror_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "PannerNode_clear_onprocessorerror",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -22742,6 +26062,7 @@ fn wire__web_audio_api__node__PannerNode_cone_inner_angle_impl(
             debug_name: "PannerNode_cone_inner_angle",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -22768,7 +26089,14 @@ fn wire__web_audio_api__node__PannerNode_cone_inner_angle_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "PannerNode_cone_inner_angle",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -22793,6 +26121,7 @@ fn wire__web_audio_api__node__PannerNode_cone_outer_angle_impl(
             debug_name: "PannerNode_cone_outer_angle",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -22819,7 +26148,14 @@ fn wire__web_audio_api__node__PannerNode_cone_outer_angle_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "PannerNode_cone_outer_angle",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -22844,6 +26180,7 @@ fn wire__web_audio_api__node__PannerNode_cone_outer_gain_impl(
             debug_name: "PannerNode_cone_outer_gain",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -22870,7 +26207,14 @@ fn wire__web_audio_api__node__PannerNode_cone_outer_gain_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "PannerNode_cone_outer_gain",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -22895,6 +26239,7 @@ fn wire__web_audio_api__node__PannerNode_disconnect_impl(
             debug_name: "PannerNode_disconnect",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -22921,7 +26266,14 @@ fn wire__web_audio_api__node__PannerNode_disconnect_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "PannerNode_disconnect",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -22946,6 +26298,7 @@ fn wire__web_audio_api__node__PannerNode_disconnect_output_impl(
             debug_name: "PannerNode_disconnect_output",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -22973,7 +26326,14 @@ fn wire__web_audio_api__node__PannerNode_disconnect_output_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "PannerNode_disconnect_output",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -23001,6 +26361,7 @@ fn wire__web_audio_api__node__PannerNode_distance_model_impl(
             debug_name: "PannerNode_distance_model",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -23027,7 +26388,14 @@ fn wire__web_audio_api__node__PannerNode_distance_model_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "PannerNode_distance_model",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -23052,6 +26420,7 @@ fn wire__web_audio_api__node__PannerNode_frb_override_connect_impl(
             debug_name: "PannerNode_frb_override_connect",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -23084,8 +26453,22 @@ fn wire__web_audio_api__node__PannerNode_frb_override_connect_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                            1 => api_dest_guard = Some(api_dest.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "PannerNode_frb_override_connect",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
+                            1 => {
+                                api_dest_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "PannerNode_frb_override_connect",
+                                        "dest",
+                                        || api_dest.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -23114,6 +26497,7 @@ fn wire__web_audio_api__node__PannerNode_max_distance_impl(
             debug_name: "PannerNode_max_distance",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -23140,7 +26524,14 @@ fn wire__web_audio_api__node__PannerNode_max_distance_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "PannerNode_max_distance",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -23165,6 +26556,7 @@ fn wire__web_audio_api__node__PannerNode_number_of_inputs_impl(
             debug_name: "PannerNode_number_of_inputs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -23191,7 +26583,14 @@ fn wire__web_audio_api__node__PannerNode_number_of_inputs_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "PannerNode_number_of_inputs",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -23216,6 +26615,7 @@ fn wire__web_audio_api__node__PannerNode_number_of_outputs_impl(
             debug_name: "PannerNode_number_of_outputs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -23242,7 +26642,14 @@ fn wire__web_audio_api__node__PannerNode_number_of_outputs_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "PannerNode_number_of_outputs",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -23267,6 +26674,7 @@ fn wire__web_audio_api__node__PannerNode_panning_model_impl(
             debug_name: "PannerNode_panning_model",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -23293,7 +26701,14 @@ fn wire__web_audio_api__node__PannerNode_panning_model_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "PannerNode_panning_model",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -23318,6 +26733,7 @@ fn wire__web_audio_api__node__PannerNode_ref_distance_impl(
             debug_name: "PannerNode_ref_distance",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -23344,7 +26760,14 @@ fn wire__web_audio_api__node__PannerNode_ref_distance_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "PannerNode_ref_distance",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -23369,6 +26792,7 @@ fn wire__web_audio_api__node__PannerNode_registration_impl(
             debug_name: "PannerNode_registration",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -23395,7 +26819,14 @@ fn wire__web_audio_api__node__PannerNode_registration_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "PannerNode_registration",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -23420,6 +26851,7 @@ fn wire__web_audio_api__node__PannerNode_rolloff_factor_impl(
             debug_name: "PannerNode_rolloff_factor",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -23446,7 +26878,14 @@ fn wire__web_audio_api__node__PannerNode_rolloff_factor_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "PannerNode_rolloff_factor",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -23471,6 +26910,7 @@ fn wire__web_audio_api__node__PannerNode_set_cone_inner_angle_impl(
             debug_name: "PannerNode_set_cone_inner_angle",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -23498,7 +26938,14 @@ fn wire__web_audio_api__node__PannerNode_set_cone_inner_angle_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "PannerNode_set_cone_inner_angle",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref_mut(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -23526,6 +26973,7 @@ fn wire__web_audio_api__node__PannerNode_set_cone_outer_angle_impl(
             debug_name: "PannerNode_set_cone_outer_angle",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -23553,7 +27001,14 @@ fn wire__web_audio_api__node__PannerNode_set_cone_outer_angle_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "PannerNode_set_cone_outer_angle",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref_mut(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -23581,6 +27036,7 @@ fn wire__web_audio_api__node__PannerNode_set_cone_outer_gain_impl(
             debug_name: "PannerNode_set_cone_outer_gain",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -23608,7 +27064,14 @@ fn wire__web_audio_api__node__PannerNode_set_cone_outer_gain_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "PannerNode_set_cone_outer_gain",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref_mut(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -23636,6 +27099,7 @@ fn wire__web_audio_api__node__PannerNode_set_distance_model_impl(
             debug_name: "PannerNode_set_distance_model",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -23663,7 +27127,14 @@ fn wire__web_audio_api__node__PannerNode_set_distance_model_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "PannerNode_set_distance_model",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref_mut(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -23691,6 +27162,7 @@ fn wire__web_audio_api__node__PannerNode_set_max_distance_impl(
             debug_name: "PannerNode_set_max_distance",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -23718,7 +27190,14 @@ fn wire__web_audio_api__node__PannerNode_set_max_distance_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "PannerNode_set_max_distance",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref_mut(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -23746,6 +27225,7 @@ fn wire__web_audio_api__node__PannerNode_set_on_processor_error_impl(
             debug_name: "PannerNode_set_on_processor_error",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -23775,7 +27255,14 @@ fn wire__web_audio_api__node__PannerNode_set_on_processor_error_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "PannerNode_set_on_processor_error",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -23803,6 +27290,7 @@ fn wire__web_audio_api__node__PannerNode_set_orientation_impl(
             debug_name: "PannerNode_set_orientation",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -23832,7 +27320,14 @@ fn wire__web_audio_api__node__PannerNode_set_orientation_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "PannerNode_set_orientation",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -23862,6 +27357,7 @@ fn wire__web_audio_api__node__PannerNode_set_panning_model_impl(
             debug_name: "PannerNode_set_panning_model",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -23889,7 +27385,14 @@ fn wire__web_audio_api__node__PannerNode_set_panning_model_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "PannerNode_set_panning_model",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref_mut(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -23917,6 +27420,7 @@ fn wire__web_audio_api__node__PannerNode_set_position_impl(
             debug_name: "PannerNode_set_position",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -23946,7 +27450,14 @@ fn wire__web_audio_api__node__PannerNode_set_position_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "PannerNode_set_position",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -23976,6 +27487,7 @@ fn wire__web_audio_api__node__PannerNode_set_ref_distance_impl(
             debug_name: "PannerNode_set_ref_distance",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -24003,7 +27515,14 @@ fn wire__web_audio_api__node__PannerNode_set_ref_distance_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "PannerNode_set_ref_distance",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref_mut(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -24031,6 +27550,7 @@ fn wire__web_audio_api__node__PannerNode_set_rolloff_factor_impl(
             debug_name: "PannerNode_set_rolloff_factor",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -24058,7 +27578,14 @@ fn wire__web_audio_api__node__PannerNode_set_rolloff_factor_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "PannerNode_set_rolloff_factor",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref_mut(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -24086,6 +27613,7 @@ fn wire__web_audio_api__node__ScriptProcessorNode_buffer_size_impl(
             debug_name: "ScriptProcessorNode_buffer_size",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -24112,7 +27640,14 @@ fn wire__web_audio_api__node__ScriptProcessorNode_buffer_size_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ScriptProcessorNode_buffer_size",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -24137,6 +27672,7 @@ fn wire__web_audio_api__node__ScriptProcessorNode_channel_config_impl(
             debug_name: "ScriptProcessorNode_channel_config",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -24163,7 +27699,14 @@ fn wire__web_audio_api__node__ScriptProcessorNode_channel_config_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ScriptProcessorNode_channel_config",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -24188,6 +27731,7 @@ fn wire__web_audio_api__node__ScriptProcessorNode_channel_count_impl(
             debug_name: "ScriptProcessorNode_channel_count",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -24214,7 +27758,14 @@ fn wire__web_audio_api__node__ScriptProcessorNode_channel_count_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ScriptProcessorNode_channel_count",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -24239,6 +27790,7 @@ fn wire__web_audio_api__node__ScriptProcessorNode_channel_count_mode_impl(
             debug_name: "ScriptProcessorNode_channel_count_mode",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -24265,7 +27817,14 @@ fn wire__web_audio_api__node__ScriptProcessorNode_channel_count_mode_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ScriptProcessorNode_channel_count_mode",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -24292,6 +27851,7 @@ fn wire__web_audio_api__node__ScriptProcessorNode_channel_interpretation_impl(
             debug_name: "ScriptProcessorNode_channel_interpretation",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -24318,7 +27878,14 @@ fn wire__web_audio_api__node__ScriptProcessorNode_channel_interpretation_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ScriptProcessorNode_channel_interpretation",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -24345,6 +27912,7 @@ fn wire__web_audio_api__node__ScriptProcessorNode_clear_onaudioprocess_impl(
             debug_name: "ScriptProcessorNode_clear_onaudioprocess",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -24371,7 +27939,14 @@ fn wire__web_audio_api__node__ScriptProcessorNode_clear_onaudioprocess_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ScriptProcessorNode_clear_onaudioprocess",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -24398,6 +27973,7 @@ fn wire__web_audio_api__node__ScriptProcessorNode_clear_onprocessorerror_impl(
             debug_name: "ScriptProcessorNode_clear_onprocessorerror",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -24424,7 +28000,14 @@ fn wire__web_audio_api__node__ScriptProcessorNode_clear_onprocessorerror_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ScriptProcessorNode_clear_onprocessorerror",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -24451,6 +28034,7 @@ fn wire__web_audio_api__node__ScriptProcessorNode_disconnect_impl(
             debug_name: "ScriptProcessorNode_disconnect",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -24477,7 +28061,14 @@ fn wire__web_audio_api__node__ScriptProcessorNode_disconnect_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ScriptProcessorNode_disconnect",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -24502,6 +28093,7 @@ fn wire__web_audio_api__node__ScriptProcessorNode_disconnect_output_impl(
             debug_name: "ScriptProcessorNode_disconnect_output",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -24529,7 +28121,14 @@ fn wire__web_audio_api__node__ScriptProcessorNode_disconnect_output_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ScriptProcessorNode_disconnect_output",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -24557,6 +28156,7 @@ fn wire__web_audio_api__node__ScriptProcessorNode_frb_override_connect_impl(
             debug_name: "ScriptProcessorNode_frb_override_connect",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -24589,8 +28189,22 @@ fn wire__web_audio_api__node__ScriptProcessorNode_frb_override_connect_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                            1 => api_dest_guard = Some(api_dest.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ScriptProcessorNode_frb_override_connect",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
+                            1 => {
+                                api_dest_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ScriptProcessorNode_frb_override_connect",
+                                        "dest",
+                                        || api_dest.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -24614,17 +28228,17 @@ fn wire__web_audio_api__node__ScriptProcessorNode_frb_override_set_onaudioproces
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "ScriptProcessorNode_frb_override_set_onaudioprocess", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
-            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
-            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ScriptProcessorNode>>>::sse_decode(&mut deserializer);
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "ScriptProcessorNode_frb_override_set_onaudioprocess", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal, executor: None }, move || { 
+        let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let api_that = <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ScriptProcessorNode>>>::sse_decode(&mut deserializer);
 let api_callback = decode_DartFn_Inputs_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioProcessingEvent_Output_unit_AnyhowException(<flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer));deserializer.end(); move |context|  {
                     transform_result_sse::<_, ()>((move ||  {
                         let mut api_that_guard = None;
 let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(&api_that, 0, false)]);
         for i in decode_indices_ {
             match i {
-                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                0 => api_that_guard = Some(flutter_rust_bridge::for_generated::lockable_with_site("ScriptProcessorNode_frb_override_set_onaudioprocess", "that", || api_that.lockable_decode_sync_ref())),
                 _ => unreachable!(),
             }
         }
@@ -24644,6 +28258,7 @@ fn wire__web_audio_api__node__ScriptProcessorNode_number_of_inputs_impl(
             debug_name: "ScriptProcessorNode_number_of_inputs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -24670,7 +28285,14 @@ fn wire__web_audio_api__node__ScriptProcessorNode_number_of_inputs_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ScriptProcessorNode_number_of_inputs",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -24697,6 +28319,7 @@ fn wire__web_audio_api__node__ScriptProcessorNode_number_of_outputs_impl(
             debug_name: "ScriptProcessorNode_number_of_outputs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -24723,7 +28346,14 @@ fn wire__web_audio_api__node__ScriptProcessorNode_number_of_outputs_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ScriptProcessorNode_number_of_outputs",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -24750,6 +28380,7 @@ fn wire__web_audio_api__node__ScriptProcessorNode_registration_impl(
             debug_name: "ScriptProcessorNode_registration",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -24776,7 +28407,14 @@ fn wire__web_audio_api__node__ScriptProcessorNode_registration_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ScriptProcessorNode_registration",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -24801,6 +28439,7 @@ fn wire__web_audio_api__node__ScriptProcessorNode_set_on_processor_error_impl(
             debug_name: "ScriptProcessorNode_set_on_processor_error",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -24830,7 +28469,14 @@ fn wire__web_audio_api__node__ScriptProcessorNode_set_on_processor_error_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "ScriptProcessorNode_set_on_processor_error",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -24858,6 +28504,7 @@ fn wire__web_audio_api__node__StereoPannerNode_channel_config_impl(
             debug_name: "StereoPannerNode_channel_config",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -24884,7 +28531,14 @@ fn wire__web_audio_api__node__StereoPannerNode_channel_config_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "StereoPannerNode_channel_config",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -24909,6 +28563,7 @@ fn wire__web_audio_api__node__StereoPannerNode_channel_count_impl(
             debug_name: "StereoPannerNode_channel_count",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -24935,7 +28590,14 @@ fn wire__web_audio_api__node__StereoPannerNode_channel_count_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "StereoPannerNode_channel_count",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -24960,6 +28622,7 @@ fn wire__web_audio_api__node__StereoPannerNode_channel_count_mode_impl(
             debug_name: "StereoPannerNode_channel_count_mode",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -24986,7 +28649,14 @@ fn wire__web_audio_api__node__StereoPannerNode_channel_count_mode_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "StereoPannerNode_channel_count_mode",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -25011,6 +28681,7 @@ fn wire__web_audio_api__node__StereoPannerNode_channel_interpretation_impl(
             debug_name: "StereoPannerNode_channel_interpretation",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -25037,7 +28708,14 @@ fn wire__web_audio_api__node__StereoPannerNode_channel_interpretation_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "StereoPannerNode_channel_interpretation",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -25064,6 +28742,7 @@ fn wire__web_audio_api__node__StereoPannerNode_clear_onprocessorerror_impl(
             debug_name: "StereoPannerNode_clear_onprocessorerror",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -25090,7 +28769,14 @@ fn wire__web_audio_api__node__StereoPannerNode_clear_onprocessorerror_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "StereoPannerNode_clear_onprocessorerror",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -25117,6 +28803,7 @@ fn wire__web_audio_api__node__StereoPannerNode_disconnect_impl(
             debug_name: "StereoPannerNode_disconnect",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -25143,7 +28830,14 @@ fn wire__web_audio_api__node__StereoPannerNode_disconnect_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "StereoPannerNode_disconnect",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -25168,6 +28862,7 @@ fn wire__web_audio_api__node__StereoPannerNode_disconnect_output_impl(
             debug_name: "StereoPannerNode_disconnect_output",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -25195,7 +28890,14 @@ fn wire__web_audio_api__node__StereoPannerNode_disconnect_output_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "StereoPannerNode_disconnect_output",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -25223,6 +28925,7 @@ fn wire__web_audio_api__node__StereoPannerNode_frb_override_connect_impl(
             debug_name: "StereoPannerNode_frb_override_connect",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -25255,8 +28958,22 @@ fn wire__web_audio_api__node__StereoPannerNode_frb_override_connect_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                            1 => api_dest_guard = Some(api_dest.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "StereoPannerNode_frb_override_connect",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
+                            1 => {
+                                api_dest_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "StereoPannerNode_frb_override_connect",
+                                        "dest",
+                                        || api_dest.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -25285,6 +29002,7 @@ fn wire__web_audio_api__node__StereoPannerNode_number_of_inputs_impl(
             debug_name: "StereoPannerNode_number_of_inputs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -25311,7 +29029,14 @@ fn wire__web_audio_api__node__StereoPannerNode_number_of_inputs_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "StereoPannerNode_number_of_inputs",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -25336,6 +29061,7 @@ fn wire__web_audio_api__node__StereoPannerNode_number_of_outputs_impl(
             debug_name: "StereoPannerNode_number_of_outputs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -25362,7 +29088,14 @@ fn wire__web_audio_api__node__StereoPannerNode_number_of_outputs_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "StereoPannerNode_number_of_outputs",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -25387,6 +29120,7 @@ fn wire__web_audio_api__node__StereoPannerNode_registration_impl(
             debug_name: "StereoPannerNode_registration",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -25413,7 +29147,14 @@ fn wire__web_audio_api__node__StereoPannerNode_registration_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "StereoPannerNode_registration",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -25438,6 +29179,7 @@ fn wire__web_audio_api__node__StereoPannerNode_set_on_processor_error_impl(
             debug_name: "StereoPannerNode_set_on_processor_error",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -25467,7 +29209,14 @@ fn wire__web_audio_api__node__StereoPannerNode_set_on_processor_error_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "StereoPannerNode_set_on_processor_error",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -25495,6 +29244,7 @@ fn wire__web_audio_api__node__WaveShaperNode_channel_config_impl(
             debug_name: "WaveShaperNode_channel_config",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -25521,7 +29271,14 @@ fn wire__web_audio_api__node__WaveShaperNode_channel_config_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "WaveShaperNode_channel_config",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -25546,6 +29303,7 @@ fn wire__web_audio_api__node__WaveShaperNode_channel_count_impl(
             debug_name: "WaveShaperNode_channel_count",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -25572,7 +29330,14 @@ fn wire__web_audio_api__node__WaveShaperNode_channel_count_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "WaveShaperNode_channel_count",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -25597,6 +29362,7 @@ fn wire__web_audio_api__node__WaveShaperNode_channel_count_mode_impl(
             debug_name: "WaveShaperNode_channel_count_mode",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -25623,7 +29389,14 @@ fn wire__web_audio_api__node__WaveShaperNode_channel_count_mode_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "WaveShaperNode_channel_count_mode",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -25648,6 +29421,7 @@ fn wire__web_audio_api__node__WaveShaperNode_channel_interpretation_impl(
             debug_name: "WaveShaperNode_channel_interpretation",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -25674,7 +29448,14 @@ fn wire__web_audio_api__node__WaveShaperNode_channel_interpretation_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "WaveShaperNode_channel_interpretation",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -25701,6 +29482,7 @@ fn wire__web_audio_api__node__WaveShaperNode_clear_onprocessorerror_impl(
             debug_name: "WaveShaperNode_clear_onprocessorerror",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -25727,7 +29509,14 @@ fn wire__web_audio_api__node__WaveShaperNode_clear_onprocessorerror_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "WaveShaperNode_clear_onprocessorerror",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -25754,6 +29543,7 @@ fn wire__web_audio_api__node__WaveShaperNode_disconnect_impl(
             debug_name: "WaveShaperNode_disconnect",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -25780,7 +29570,14 @@ fn wire__web_audio_api__node__WaveShaperNode_disconnect_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "WaveShaperNode_disconnect",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -25805,6 +29602,7 @@ fn wire__web_audio_api__node__WaveShaperNode_disconnect_output_impl(
             debug_name: "WaveShaperNode_disconnect_output",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -25832,7 +29630,14 @@ fn wire__web_audio_api__node__WaveShaperNode_disconnect_output_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "WaveShaperNode_disconnect_output",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -25860,6 +29665,7 @@ fn wire__web_audio_api__node__WaveShaperNode_frb_override_connect_impl(
             debug_name: "WaveShaperNode_frb_override_connect",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -25892,8 +29698,22 @@ fn wire__web_audio_api__node__WaveShaperNode_frb_override_connect_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                            1 => api_dest_guard = Some(api_dest.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "WaveShaperNode_frb_override_connect",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
+                            1 => {
+                                api_dest_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "WaveShaperNode_frb_override_connect",
+                                        "dest",
+                                        || api_dest.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -25922,6 +29742,7 @@ fn wire__web_audio_api__node__WaveShaperNode_frb_override_curve_impl(
             debug_name: "WaveShaperNode_frb_override_curve",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -25948,7 +29769,14 @@ fn wire__web_audio_api__node__WaveShaperNode_frb_override_curve_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "WaveShaperNode_frb_override_curve",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -25973,6 +29801,7 @@ fn wire__web_audio_api__node__WaveShaperNode_number_of_inputs_impl(
             debug_name: "WaveShaperNode_number_of_inputs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -25999,7 +29828,14 @@ fn wire__web_audio_api__node__WaveShaperNode_number_of_inputs_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "WaveShaperNode_number_of_inputs",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -26024,6 +29860,7 @@ fn wire__web_audio_api__node__WaveShaperNode_number_of_outputs_impl(
             debug_name: "WaveShaperNode_number_of_outputs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -26050,7 +29887,14 @@ fn wire__web_audio_api__node__WaveShaperNode_number_of_outputs_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "WaveShaperNode_number_of_outputs",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -26075,6 +29919,7 @@ fn wire__web_audio_api__node__WaveShaperNode_oversample_impl(
             debug_name: "WaveShaperNode_oversample",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -26101,7 +29946,14 @@ fn wire__web_audio_api__node__WaveShaperNode_oversample_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "WaveShaperNode_oversample",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -26126,6 +29978,7 @@ fn wire__web_audio_api__node__WaveShaperNode_registration_impl(
             debug_name: "WaveShaperNode_registration",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -26152,7 +30005,14 @@ fn wire__web_audio_api__node__WaveShaperNode_registration_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "WaveShaperNode_registration",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -26177,6 +30037,7 @@ fn wire__web_audio_api__node__WaveShaperNode_set_curve_impl(
             debug_name: "WaveShaperNode_set_curve",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -26204,7 +30065,14 @@ fn wire__web_audio_api__node__WaveShaperNode_set_curve_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "WaveShaperNode_set_curve",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref_mut(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -26232,6 +30100,7 @@ fn wire__web_audio_api__node__WaveShaperNode_set_on_processor_error_impl(
             debug_name: "WaveShaperNode_set_on_processor_error",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -26261,7 +30130,14 @@ fn wire__web_audio_api__node__WaveShaperNode_set_on_processor_error_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "WaveShaperNode_set_on_processor_error",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -26289,6 +30165,7 @@ fn wire__web_audio_api__node__WaveShaperNode_set_oversample_impl(
             debug_name: "WaveShaperNode_set_oversample",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -26317,7 +30194,14 @@ fn wire__web_audio_api__node__WaveShaperNode_set_oversample_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "WaveShaperNode_set_oversample",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref_mut(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -26345,6 +30229,7 @@ fn wire__web_audio_api__node__analyser_options_default_impl(
             debug_name: "analyser_options_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -26378,6 +30263,7 @@ fn wire__web_audio_api__node__audio_buffer_source_options_default_impl(
             debug_name: "audio_buffer_source_options_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -26412,6 +30298,7 @@ fn wire__web_audio_api__node__audio_node_options_default_impl(
             debug_name: "audio_node_options_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -26445,6 +30332,7 @@ fn wire__web_audio_api__node__biquad_filter_options_default_impl(
             debug_name: "biquad_filter_options_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -26478,6 +30366,7 @@ fn wire__web_audio_api__node__biquad_filter_type_default_impl(
             debug_name: "biquad_filter_type_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -26511,6 +30400,7 @@ fn wire__web_audio_api__node__channel_merger_options_default_impl(
             debug_name: "channel_merger_options_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -26544,6 +30434,7 @@ fn wire__web_audio_api__node__channel_splitter_options_default_impl(
             debug_name: "channel_splitter_options_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -26578,6 +30469,7 @@ fn wire__web_audio_api__node__constant_source_options_default_impl(
             debug_name: "constant_source_options_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -26611,6 +30503,7 @@ fn wire__web_audio_api__node__convolver_options_default_impl(
             debug_name: "convolver_options_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -26644,6 +30537,7 @@ fn wire__web_audio_api__node__delay_options_default_impl(
             debug_name: "delay_options_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -26677,6 +30571,7 @@ fn wire__web_audio_api__node__distance_model_type_default_impl(
             debug_name: "distance_model_type_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -26710,6 +30605,7 @@ fn wire__web_audio_api__node__dynamics_compressor_options_default_impl(
             debug_name: "dynamics_compressor_options_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -26744,6 +30640,7 @@ fn wire__web_audio_api__node__gain_options_default_impl(
             debug_name: "gain_options_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -26777,6 +30674,7 @@ fn wire__web_audio_api__node__oscillator_options_default_impl(
             debug_name: "oscillator_options_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -26810,6 +30708,7 @@ fn wire__web_audio_api__node__oscillator_type_default_impl(
             debug_name: "oscillator_type_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -26843,6 +30742,7 @@ fn wire__web_audio_api__node__over_sample_type_default_impl(
             debug_name: "over_sample_type_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -26876,6 +30776,7 @@ fn wire__web_audio_api__node__panner_options_default_impl(
             debug_name: "panner_options_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -26909,6 +30810,7 @@ fn wire__web_audio_api__node__panning_model_type_default_impl(
             debug_name: "panning_model_type_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -26942,6 +30844,7 @@ fn wire__web_audio_api__node__stereo_panner_options_default_impl(
             debug_name: "stereo_panner_options_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -26975,6 +30878,7 @@ fn wire__web_audio_api__node__wave_shaper_options_default_impl(
             debug_name: "wave_shaper_options_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -27008,6 +30912,7 @@ fn wire__web_audio_api__worklet__AudioWorkletNode_channel_config_impl(
             debug_name: "AudioWorkletNode_channel_config",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -27034,7 +30939,14 @@ fn wire__web_audio_api__worklet__AudioWorkletNode_channel_config_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioWorkletNode_channel_config",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -27059,6 +30971,7 @@ fn wire__web_audio_api__worklet__AudioWorkletNode_channel_count_impl(
             debug_name: "AudioWorkletNode_channel_count",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -27085,7 +30998,14 @@ fn wire__web_audio_api__worklet__AudioWorkletNode_channel_count_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioWorkletNode_channel_count",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -27110,6 +31030,7 @@ fn wire__web_audio_api__worklet__AudioWorkletNode_channel_count_mode_impl(
             debug_name: "AudioWorkletNode_channel_count_mode",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -27136,7 +31057,14 @@ fn wire__web_audio_api__worklet__AudioWorkletNode_channel_count_mode_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioWorkletNode_channel_count_mode",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -27163,6 +31091,7 @@ fn wire__web_audio_api__worklet__AudioWorkletNode_channel_interpretation_impl(
             debug_name: "AudioWorkletNode_channel_interpretation",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -27189,7 +31118,14 @@ fn wire__web_audio_api__worklet__AudioWorkletNode_channel_interpretation_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioWorkletNode_channel_interpretation",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -27216,6 +31152,7 @@ fn wire__web_audio_api__worklet__AudioWorkletNode_clear_onprocessorerror_impl(
             debug_name: "AudioWorkletNode_clear_onprocessorerror",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -27242,7 +31179,14 @@ fn wire__web_audio_api__worklet__AudioWorkletNode_clear_onprocessorerror_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioWorkletNode_clear_onprocessorerror",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -27269,6 +31213,7 @@ fn wire__web_audio_api__worklet__AudioWorkletNode_disconnect_impl(
             debug_name: "AudioWorkletNode_disconnect",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -27295,7 +31240,14 @@ fn wire__web_audio_api__worklet__AudioWorkletNode_disconnect_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioWorkletNode_disconnect",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -27320,6 +31272,7 @@ fn wire__web_audio_api__worklet__AudioWorkletNode_disconnect_output_impl(
             debug_name: "AudioWorkletNode_disconnect_output",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -27347,7 +31300,14 @@ fn wire__web_audio_api__worklet__AudioWorkletNode_disconnect_output_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioWorkletNode_disconnect_output",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -27375,6 +31335,7 @@ fn wire__web_audio_api__worklet__AudioWorkletNode_number_of_inputs_impl(
             debug_name: "AudioWorkletNode_number_of_inputs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -27401,7 +31362,14 @@ fn wire__web_audio_api__worklet__AudioWorkletNode_number_of_inputs_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioWorkletNode_number_of_inputs",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -27428,6 +31396,7 @@ fn wire__web_audio_api__worklet__AudioWorkletNode_number_of_outputs_impl(
             debug_name: "AudioWorkletNode_number_of_outputs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -27454,7 +31423,14 @@ fn wire__web_audio_api__worklet__AudioWorkletNode_number_of_outputs_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioWorkletNode_number_of_outputs",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -27481,6 +31457,7 @@ fn wire__web_audio_api__worklet__AudioWorkletNode_parameters_impl(
             debug_name: "AudioWorkletNode_parameters",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -27507,7 +31484,14 @@ fn wire__web_audio_api__worklet__AudioWorkletNode_parameters_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioWorkletNode_parameters",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -27532,6 +31516,7 @@ fn wire__web_audio_api__worklet__AudioWorkletNode_registration_impl(
             debug_name: "AudioWorkletNode_registration",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -27558,7 +31543,14 @@ fn wire__web_audio_api__worklet__AudioWorkletNode_registration_impl(
                         ]);
                     for i in decode_indices_ {
                         match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            0 => {
+                                api_that_guard =
+                                    Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                        "AudioWorkletNode_registration",
+                                        "that",
+                                        || api_that.lockable_decode_sync_ref(),
+                                    ))
+                            }
                             _ => unreachable!(),
                         }
                     }
@@ -28403,6 +32395,80 @@ impl Lockable for AudioNodeImplementor {
         }
     }
 
+    fn lockable_exclusive(&self) -> bool {
+        match self {
+            Self::Variant0(inner) => {
+                flutter_rust_bridge::for_generated::rust_auto_opaque_lockable_exclusive(inner)
+            }
+            Self::Variant1(inner) => {
+                flutter_rust_bridge::for_generated::rust_auto_opaque_lockable_exclusive(inner)
+            }
+            Self::Variant2(inner) => {
+                flutter_rust_bridge::for_generated::rust_auto_opaque_lockable_exclusive(inner)
+            }
+            Self::Variant3(inner) => {
+                flutter_rust_bridge::for_generated::rust_auto_opaque_lockable_exclusive(inner)
+            }
+            Self::Variant4(inner) => {
+                flutter_rust_bridge::for_generated::rust_auto_opaque_lockable_exclusive(inner)
+            }
+            Self::Variant5(inner) => {
+                flutter_rust_bridge::for_generated::rust_auto_opaque_lockable_exclusive(inner)
+            }
+            Self::Variant6(inner) => {
+                flutter_rust_bridge::for_generated::rust_auto_opaque_lockable_exclusive(inner)
+            }
+            Self::Variant7(inner) => {
+                flutter_rust_bridge::for_generated::rust_auto_opaque_lockable_exclusive(inner)
+            }
+            Self::Variant8(inner) => {
+                flutter_rust_bridge::for_generated::rust_auto_opaque_lockable_exclusive(inner)
+            }
+            Self::Variant9(inner) => {
+                flutter_rust_bridge::for_generated::rust_auto_opaque_lockable_exclusive(inner)
+            }
+            Self::Variant10(inner) => {
+                flutter_rust_bridge::for_generated::rust_auto_opaque_lockable_exclusive(inner)
+            }
+            Self::Variant11(inner) => {
+                flutter_rust_bridge::for_generated::rust_auto_opaque_lockable_exclusive(inner)
+            }
+            Self::Variant12(inner) => {
+                flutter_rust_bridge::for_generated::rust_auto_opaque_lockable_exclusive(inner)
+            }
+            Self::Variant13(inner) => {
+                flutter_rust_bridge::for_generated::rust_auto_opaque_lockable_exclusive(inner)
+            }
+            Self::Variant14(inner) => {
+                flutter_rust_bridge::for_generated::rust_auto_opaque_lockable_exclusive(inner)
+            }
+            Self::Variant15(inner) => {
+                flutter_rust_bridge::for_generated::rust_auto_opaque_lockable_exclusive(inner)
+            }
+            Self::Variant16(inner) => {
+                flutter_rust_bridge::for_generated::rust_auto_opaque_lockable_exclusive(inner)
+            }
+            Self::Variant17(inner) => {
+                flutter_rust_bridge::for_generated::rust_auto_opaque_lockable_exclusive(inner)
+            }
+            Self::Variant18(inner) => {
+                flutter_rust_bridge::for_generated::rust_auto_opaque_lockable_exclusive(inner)
+            }
+            Self::Variant19(inner) => {
+                flutter_rust_bridge::for_generated::rust_auto_opaque_lockable_exclusive(inner)
+            }
+            Self::Variant20(inner) => {
+                flutter_rust_bridge::for_generated::rust_auto_opaque_lockable_exclusive(inner)
+            }
+            Self::Variant21(inner) => {
+                flutter_rust_bridge::for_generated::rust_auto_opaque_lockable_exclusive(inner)
+            }
+            Self::Variant22(inner) => {
+                flutter_rust_bridge::for_generated::rust_auto_opaque_lockable_exclusive(inner)
+            }
+        }
+    }
+
     fn lockable_decode_sync_ref(&self) -> Self::RwLockReadGuard<'_> {
         self.blocking_read()
     }
@@ -28431,65 +32497,50 @@ impl Lockable for AudioNodeImplementor {
 }
 
 pub enum AudioNodeImplementorRwLockReadGuard<'a> {
-    Variant0(flutter_rust_bridge::for_generated::rust_async::RwLockReadGuard<'a, AnalyserNode>),
+    Variant0(flutter_rust_bridge::for_generated::RustAutoOpaqueReadGuard<'a, AnalyserNode>),
     Variant1(
-        flutter_rust_bridge::for_generated::rust_async::RwLockReadGuard<'a, AudioBufferSourceNode>,
-    ),
-    Variant2(
-        flutter_rust_bridge::for_generated::rust_async::RwLockReadGuard<'a, AudioDestinationNode>,
+        flutter_rust_bridge::for_generated::RustAutoOpaqueReadGuard<'a, AudioBufferSourceNode>,
     ),
-    Variant3(flutter_rust_bridge::for_generated::rust_async::RwLockReadGuard<'a, AudioParam>),
-    Variant4(flutter_rust_bridge::for_generated::rust_async::RwLockReadGuard<'a, AudioWorkletNode>),
-    Variant5(flutter_rust_bridge::for_generated::rust_async::RwLockReadGuard<'a, BiquadFilterNode>),
-    Variant6(
-        flutter_rust_bridge::for_generated::rust_async::RwLockReadGuard<'a, ChannelMergerNode>,
-    ),
-    Variant7(
-        flutter_rust_bridge::for_generated::rust_async::RwLockReadGuard<'a, ChannelSplitterNode>,
-    ),
-    Variant8(
-        flutter_rust_bridge::for_generated::rust_async::RwLockReadGuard<'a, ConstantSourceNode>,
-    ),
-    Variant9(flutter_rust_bridge::for_generated::rust_async::RwLockReadGuard<'a, ConvolverNode>),
-    Variant10(flutter_rust_bridge::for_generated::rust_async::RwLockReadGuard<'a, DelayNode>),
+    Variant2(flutter_rust_bridge::for_generated::RustAutoOpaqueReadGuard<'a, AudioDestinationNode>),
+    Variant3(flutter_rust_bridge::for_generated::RustAutoOpaqueReadGuard<'a, AudioParam>),
+    Variant4(flutter_rust_bridge::for_generated::RustAutoOpaqueReadGuard<'a, AudioWorkletNode>),
+    Variant5(flutter_rust_bridge::for_generated::RustAutoOpaqueReadGuard<'a, BiquadFilterNode>),
+    Variant6(flutter_rust_bridge::for_generated::RustAutoOpaqueReadGuard<'a, ChannelMergerNode>),
+    Variant7(flutter_rust_bridge::for_generated::RustAutoOpaqueReadGuard<'a, ChannelSplitterNode>),
+    Variant8(flutter_rust_bridge::for_generated::RustAutoOpaqueReadGuard<'a, ConstantSourceNode>),
+    Variant9(flutter_rust_bridge::for_generated::RustAutoOpaqueReadGuard<'a, ConvolverNode>),
+    Variant10(flutter_rust_bridge::for_generated::RustAutoOpaqueReadGuard<'a, DelayNode>),
     Variant11(
-        flutter_rust_bridge::for_generated::rust_async::RwLockReadGuard<'a, DynamicsCompressorNode>,
+        flutter_rust_bridge::for_generated::RustAutoOpaqueReadGuard<'a, DynamicsCompressorNode>,
     ),
-    Variant12(flutter_rust_bridge::for_generated::rust_async::RwLockReadGuard<'a, GainNode>),
-    Variant13(flutter_rust_bridge::for_generated::rust_async::RwLockReadGuard<'a, IIRFilterNode>),
+    Variant12(flutter_rust_bridge::for_generated::RustAutoOpaqueReadGuard<'a, GainNode>),
+    Variant13(flutter_rust_bridge::for_generated::RustAutoOpaqueReadGuard<'a, IIRFilterNode>),
     Variant14(
-        flutter_rust_bridge::for_generated::rust_async::RwLockReadGuard<
+        flutter_rust_bridge::for_generated::RustAutoOpaqueReadGuard<
             'a,
             MediaElementAudioSourceNode,
         >,
     ),
     Variant15(
-        flutter_rust_bridge::for_generated::rust_async::RwLockReadGuard<
+        flutter_rust_bridge::for_generated::RustAutoOpaqueReadGuard<
             'a,
             MediaStreamAudioDestinationNode,
         >,
     ),
     Variant16(
-        flutter_rust_bridge::for_generated::rust_async::RwLockReadGuard<
-            'a,
-            MediaStreamAudioSourceNode,
-        >,
+        flutter_rust_bridge::for_generated::RustAutoOpaqueReadGuard<'a, MediaStreamAudioSourceNode>,
     ),
     Variant17(
-        flutter_rust_bridge::for_generated::rust_async::RwLockReadGuard<
+        flutter_rust_bridge::for_generated::RustAutoOpaqueReadGuard<
             'a,
             MediaStreamTrackAudioSourceNode,
         >,
     ),
-    Variant18(flutter_rust_bridge::for_generated::rust_async::RwLockReadGuard<'a, OscillatorNode>),
-    Variant19(flutter_rust_bridge::for_generated::rust_async::RwLockReadGuard<'a, PannerNode>),
-    Variant20(
-        flutter_rust_bridge::for_generated::rust_async::RwLockReadGuard<'a, ScriptProcessorNode>,
-    ),
-    Variant21(
-        flutter_rust_bridge::for_generated::rust_async::RwLockReadGuard<'a, StereoPannerNode>,
-    ),
-    Variant22(flutter_rust_bridge::for_generated::rust_async::RwLockReadGuard<'a, WaveShaperNode>),
+    Variant18(flutter_rust_bridge::for_generated::RustAutoOpaqueReadGuard<'a, OscillatorNode>),
+    Variant19(flutter_rust_bridge::for_generated::RustAutoOpaqueReadGuard<'a, PannerNode>),
+    Variant20(flutter_rust_bridge::for_generated::RustAutoOpaqueReadGuard<'a, ScriptProcessorNode>),
+    Variant21(flutter_rust_bridge::for_generated::RustAutoOpaqueReadGuard<'a, StereoPannerNode>),
+    Variant22(flutter_rust_bridge::for_generated::RustAutoOpaqueReadGuard<'a, WaveShaperNode>),
 }
 
 impl std::ops::Deref for AudioNodeImplementorRwLockReadGuard<'_> {
@@ -28525,72 +32576,57 @@ impl std::ops::Deref for AudioNodeImplementorRwLockReadGuard<'_> {
 }
 
 pub enum AudioNodeImplementorRwLockWriteGuard<'a> {
-    Variant0(flutter_rust_bridge::for_generated::rust_async::RwLockWriteGuard<'a, AnalyserNode>),
+    Variant0(flutter_rust_bridge::for_generated::RustAutoOpaqueWriteGuard<'a, AnalyserNode>),
     Variant1(
-        flutter_rust_bridge::for_generated::rust_async::RwLockWriteGuard<'a, AudioBufferSourceNode>,
+        flutter_rust_bridge::for_generated::RustAutoOpaqueWriteGuard<'a, AudioBufferSourceNode>,
     ),
     Variant2(
-        flutter_rust_bridge::for_generated::rust_async::RwLockWriteGuard<'a, AudioDestinationNode>,
-    ),
-    Variant3(flutter_rust_bridge::for_generated::rust_async::RwLockWriteGuard<'a, AudioParam>),
-    Variant4(
-        flutter_rust_bridge::for_generated::rust_async::RwLockWriteGuard<'a, AudioWorkletNode>,
-    ),
-    Variant5(
-        flutter_rust_bridge::for_generated::rust_async::RwLockWriteGuard<'a, BiquadFilterNode>,
-    ),
-    Variant6(
-        flutter_rust_bridge::for_generated::rust_async::RwLockWriteGuard<'a, ChannelMergerNode>,
+        flutter_rust_bridge::for_generated::RustAutoOpaqueWriteGuard<'a, AudioDestinationNode>,
     ),
-    Variant7(
-        flutter_rust_bridge::for_generated::rust_async::RwLockWriteGuard<'a, ChannelSplitterNode>,
-    ),
-    Variant8(
-        flutter_rust_bridge::for_generated::rust_async::RwLockWriteGuard<'a, ConstantSourceNode>,
-    ),
-    Variant9(flutter_rust_bridge::for_generated::rust_async::RwLockWriteGuard<'a, ConvolverNode>),
-    Variant10(flutter_rust_bridge::for_generated::rust_async::RwLockWriteGuard<'a, DelayNode>),
+    Variant3(flutter_rust_bridge::for_generated::RustAutoOpaqueWriteGuard<'a, AudioParam>),
+    Variant4(flutter_rust_bridge::for_generated::RustAutoOpaqueWriteGuard<'a, AudioWorkletNode>),
+    Variant5(flutter_rust_bridge::for_generated::RustAutoOpaqueWriteGuard<'a, BiquadFilterNode>),
+    Variant6(flutter_rust_bridge::for_generated::RustAutoOpaqueWriteGuard<'a, ChannelMergerNode>),
+    Variant7(flutter_rust_bridge::for_generated::RustAutoOpaqueWriteGuard<'a, ChannelSplitterNode>),
+    Variant8(flutter_rust_bridge::for_generated::RustAutoOpaqueWriteGuard<'a, ConstantSourceNode>),
+    Variant9(flutter_rust_bridge::for_generated::RustAutoOpaqueWriteGuard<'a, ConvolverNode>),
+    Variant10(flutter_rust_bridge::for_generated::RustAutoOpaqueWriteGuard<'a, DelayNode>),
     Variant11(
-        flutter_rust_bridge::for_generated::rust_async::RwLockWriteGuard<
-            'a,
-            DynamicsCompressorNode,
-        >,
+        flutter_rust_bridge::for_generated::RustAutoOpaqueWriteGuard<'a, DynamicsCompressorNode>,
     ),
-    Variant12(flutter_rust_bridge::for_generated::rust_async::RwLockWriteGuard<'a, GainNode>),
-    Variant13(flutter_rust_bridge::for_generated::rust_async::RwLockWriteGuard<'a, IIRFilterNode>),
+    Variant12(flutter_rust_bridge::for_generated::RustAutoOpaqueWriteGuard<'a, GainNode>),
+    Variant13(flutter_rust_bridge::for_generated::RustAutoOpaqueWriteGuard<'a, IIRFilterNode>),
     Variant14(
-        flutter_rust_bridge::for_generated::rust_async::RwLockWriteGuard<
+        flutter_rust_bridge::for_generated::RustAutoOpaqueWriteGuard<
             'a,
             MediaElementAudioSourceNode,
         >,
     ),
     Variant15(
-        flutter_rust_bridge::for_generated::rust_async::RwLockWriteGuard<
+        flutter_rust_bridge::for_generated::RustAutoOpaqueWriteGuard<
             'a,
             MediaStreamAudioDestinationNode,
         >,
     ),
     Variant16(
-        flutter_rust_bridge::for_generated::rust_async::RwLockWriteGuard<
+        flutter_rust_bridge::for_generated::RustAutoOpaqueWriteGuard<
             'a,
             MediaStreamAudioSourceNode,
         >,
     ),
     Variant17(
-        flutter_rust_bridge::for_generated::rust_async::RwLockWriteGuard<
+        flutter_rust_bridge::for_generated::RustAutoOpaqueWriteGuard<
             'a,
             MediaStreamTrackAudioSourceNode,
         >,
     ),
-    Variant18(flutter_rust_bridge::for_generated::rust_async::RwLockWriteGuard<'a, OscillatorNode>),
-    Variant19(flutter_rust_bridge::for_generated::rust_async::RwLockWriteGuard<'a, PannerNode>),
+    Variant18(flutter_rust_bridge::for_generated::RustAutoOpaqueWriteGuard<'a, OscillatorNode>),
+    Variant19(flutter_rust_bridge::for_generated::RustAutoOpaqueWriteGuard<'a, PannerNode>),
     Variant20(
-        flutter_rust_bridge::for_generated::rust_async::RwLockWriteGuard<'a, ScriptProcessorNode>,
+        flutter_rust_bridge::for_generated::RustAutoOpaqueWriteGuard<'a, ScriptProcessorNode>,
     ),
-    Variant21(
-        flutter_rust_bridge::for_generated::rust_async::RwLockWriteGuard<'a, StereoPannerNode>,
-    ),
-    Variant22(flutter_rust_bridge::for_generated::rust_async::RwLockWriteGuard<'a, WaveShaperNode>),
+    Variant21(flutter_rust_bridge::for_generated::RustAutoOpaqueWriteGuard<'a, StereoPannerNode>),
+    Variant22(flutter_rust_bridge::for_generated::RustAutoOpaqueWriteGuard<'a, WaveShaperNode>),
 }
 
 impl std::ops::Deref for AudioNodeImplementorRwLockWriteGuard<'_> {
@@ -28889,6 +32925,107 @@ impl Lockable
         }
     }
 
+    fn lockable_exclusive(&self) -> bool {
+        match self {
+            Self::Variant0(inner) => {
+                flutter_rust_bridge::for_generated::rust_auto_opaque_lockable_exclusive(inner)
+            }
+            Self::Variant1(inner) => {
+                flutter_rust_bridge::for_generated::rust_auto_opaque_lockable_exclusive(inner)
+            }
+            Self::Variant2(inner) => {
+                flutter_rust_bridge::for_generated::rust_auto_opaque_lockable_exclusive(inner)
+            }
+            Self::Variant3(inner) => {
+                flutter_rust_bridge::for_generated::rust_auto_opaque_lockable_exclusive(inner)
+            }
+            Self::Variant4(inner) => {
+                flutter_rust_bridge::for_generated::rust_auto_opaque_lockable_exclusive(inner)
+            }
+            Self::Variant5(inner) => {
+                flutter_rust_bridge::for_generated::rust_auto_opaque_lockable_exclusive(inner)
+            }
+            Self::Variant6(inner) => {
+                flutter_rust_bridge::for_generated::rust_auto_opaque_lockable_exclusive(inner)
+            }
+            Self::Variant7(inner) => {
+                flutter_rust_bridge::for_generated::rust_auto_opaque_lockable_exclusive(inner)
+            }
+            Self::Variant8(inner) => {
+                flutter_rust_bridge::for_generated::rust_auto_opaque_lockable_exclusive(inner)
+            }
+            Self::Variant9(inner) => {
+                flutter_rust_bridge::for_generated::rust_auto_opaque_lockable_exclusive(inner)
+            }
+            Self::Variant10(inner) => {
+                flutter_rust_bridge::for_generated::rust_auto_opaque_lockable_exclusive(inner)
+            }
+            Self::Variant11(inner) => {
+                flutter_rust_bridge::for_generated::rust_auto_opaque_lockable_exclusive(inner)
+            }
+            Self::Variant12(inner) => {
+                flutter_rust_bridge::for_generated::rust_auto_opaque_lockable_exclusive(inner)
+            }
+            Self::Variant13(inner) => {
+                flutter_rust_bridge::for_generated::rust_auto_opaque_lockable_exclusive(inner)
+            }
+            Self::Variant14(inner) => {
+                flutter_rust_bridge::for_generated::rust_auto_opaque_lockable_exclusive(inner)
+            }
+            Self::Variant15(inner) => {
+                flutter_rust_bridge::for_generated::rust_auto_opaque_lockable_exclusive(inner)
+            }
+            Self::Variant16(inner) => {
+                flutter_rust_bridge::for_generated::rust_auto_opaque_lockable_exclusive(inner)
+            }
+            Self::Variant17(inner) => {
+                flutter_rust_bridge::for_generated::rust_auto_opaque_lockable_exclusive(inner)
+            }
+            Self::Variant18(inner) => {
+                flutter_rust_bridge::for_generated::rust_auto_opaque_lockable_exclusive(inner)
+            }
+            Self::Variant19(inner) => {
+                flutter_rust_bridge::for_generated::rust_auto_opaque_lockable_exclusive(inner)
+            }
+            Self::Variant20(inner) => {
+                flutter_rust_bridge::for_generated::rust_auto_opaque_lockable_exclusive(inner)
+            }
+            Self::Variant21(inner) => {
+                flutter_rust_bridge::for_generated::rust_auto_opaque_lockable_exclusive(inner)
+            }
+            Self::Variant22(inner) => {
+                flutter_rust_bridge::for_generated::rust_auto_opaque_lockable_exclusive(inner)
+            }
+            Self::Variant23(inner) => {
+                flutter_rust_bridge::for_generated::rust_auto_opaque_lockable_exclusive(inner)
+            }
+            Self::Variant24(inner) => {
+                flutter_rust_bridge::for_generated::rust_auto_opaque_lockable_exclusive(inner)
+            }
+            Self::Variant25(inner) => {
+                flutter_rust_bridge::for_generated::rust_auto_opaque_lockable_exclusive(inner)
+            }
+            Self::Variant26(inner) => {
+                flutter_rust_bridge::for_generated::rust_auto_opaque_lockable_exclusive(inner)
+            }
+            Self::Variant27(inner) => {
+                flutter_rust_bridge::for_generated::rust_auto_opaque_lockable_exclusive(inner)
+            }
+            Self::Variant28(inner) => {
+                flutter_rust_bridge::for_generated::rust_auto_opaque_lockable_exclusive(inner)
+            }
+            Self::Variant29(inner) => {
+                flutter_rust_bridge::for_generated::rust_auto_opaque_lockable_exclusive(inner)
+            }
+            Self::Variant30(inner) => {
+                flutter_rust_bridge::for_generated::rust_auto_opaque_lockable_exclusive(inner)
+            }
+            Self::Variant31(inner) => {
+                flutter_rust_bridge::for_generated::rust_auto_opaque_lockable_exclusive(inner)
+            }
+        }
+    }
+
     fn lockable_decode_sync_ref(&self) -> Self::RwLockReadGuard<'_> {
         self.blocking_read()
     }
@@ -28920,63 +33057,51 @@ pub enum Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInner
     'a,
 > {
     Variant0(
-        flutter_rust_bridge::for_generated::rust_async::RwLockReadGuard<'a, AudioBufferSourceNode>,
+        flutter_rust_bridge::for_generated::RustAutoOpaqueReadGuard<'a, AudioBufferSourceNode>,
     ),
     Variant1(
-        flutter_rust_bridge::for_generated::rust_async::RwLockReadGuard<'a, AudioBufferSourceNode>,
-    ),
-    Variant2(flutter_rust_bridge::for_generated::rust_async::RwLockReadGuard<'a, AudioListener>),
-    Variant3(flutter_rust_bridge::for_generated::rust_async::RwLockReadGuard<'a, AudioListener>),
-    Variant4(flutter_rust_bridge::for_generated::rust_async::RwLockReadGuard<'a, AudioListener>),
-    Variant5(flutter_rust_bridge::for_generated::rust_async::RwLockReadGuard<'a, AudioListener>),
-    Variant6(flutter_rust_bridge::for_generated::rust_async::RwLockReadGuard<'a, AudioListener>),
-    Variant7(flutter_rust_bridge::for_generated::rust_async::RwLockReadGuard<'a, AudioListener>),
-    Variant8(flutter_rust_bridge::for_generated::rust_async::RwLockReadGuard<'a, AudioListener>),
-    Variant9(flutter_rust_bridge::for_generated::rust_async::RwLockReadGuard<'a, AudioListener>),
-    Variant10(flutter_rust_bridge::for_generated::rust_async::RwLockReadGuard<'a, AudioListener>),
-    Variant11(
-        flutter_rust_bridge::for_generated::rust_async::RwLockReadGuard<'a, BiquadFilterNode>,
-    ),
-    Variant12(
-        flutter_rust_bridge::for_generated::rust_async::RwLockReadGuard<'a, BiquadFilterNode>,
-    ),
-    Variant13(
-        flutter_rust_bridge::for_generated::rust_async::RwLockReadGuard<'a, BiquadFilterNode>,
-    ),
-    Variant14(
-        flutter_rust_bridge::for_generated::rust_async::RwLockReadGuard<'a, BiquadFilterNode>,
-    ),
-    Variant15(
-        flutter_rust_bridge::for_generated::rust_async::RwLockReadGuard<'a, ConstantSourceNode>,
+        flutter_rust_bridge::for_generated::RustAutoOpaqueReadGuard<'a, AudioBufferSourceNode>,
     ),
-    Variant16(flutter_rust_bridge::for_generated::rust_async::RwLockReadGuard<'a, DelayNode>),
+    Variant2(flutter_rust_bridge::for_generated::RustAutoOpaqueReadGuard<'a, AudioListener>),
+    Variant3(flutter_rust_bridge::for_generated::RustAutoOpaqueReadGuard<'a, AudioListener>),
+    Variant4(flutter_rust_bridge::for_generated::RustAutoOpaqueReadGuard<'a, AudioListener>),
+    Variant5(flutter_rust_bridge::for_generated::RustAutoOpaqueReadGuard<'a, AudioListener>),
+    Variant6(flutter_rust_bridge::for_generated::RustAutoOpaqueReadGuard<'a, AudioListener>),
+    Variant7(flutter_rust_bridge::for_generated::RustAutoOpaqueReadGuard<'a, AudioListener>),
+    Variant8(flutter_rust_bridge::for_generated::RustAutoOpaqueReadGuard<'a, AudioListener>),
+    Variant9(flutter_rust_bridge::for_generated::RustAutoOpaqueReadGuard<'a, AudioListener>),
+    Variant10(flutter_rust_bridge::for_generated::RustAutoOpaqueReadGuard<'a, AudioListener>),
+    Variant11(flutter_rust_bridge::for_generated::RustAutoOpaqueReadGuard<'a, BiquadFilterNode>),
+    Variant12(flutter_rust_bridge::for_generated::RustAutoOpaqueReadGuard<'a, BiquadFilterNode>),
+    Variant13(flutter_rust_bridge::for_generated::RustAutoOpaqueReadGuard<'a, BiquadFilterNode>),
+    Variant14(flutter_rust_bridge::for_generated::RustAutoOpaqueReadGuard<'a, BiquadFilterNode>),
+    Variant15(flutter_rust_bridge::for_generated::RustAutoOpaqueReadGuard<'a, ConstantSourceNode>),
+    Variant16(flutter_rust_bridge::for_generated::RustAutoOpaqueReadGuard<'a, DelayNode>),
     Variant17(
-        flutter_rust_bridge::for_generated::rust_async::RwLockReadGuard<'a, DynamicsCompressorNode>,
+        flutter_rust_bridge::for_generated::RustAutoOpaqueReadGuard<'a, DynamicsCompressorNode>,
     ),
     Variant18(
-        flutter_rust_bridge::for_generated::rust_async::RwLockReadGuard<'a, DynamicsCompressorNode>,
+        flutter_rust_bridge::for_generated::RustAutoOpaqueReadGuard<'a, DynamicsCompressorNode>,
     ),
     Variant19(
-        flutter_rust_bridge::for_generated::rust_async::RwLockReadGuard<'a, DynamicsCompressorNode>,
+        flutter_rust_bridge::for_generated::RustAutoOpaqueReadGuard<'a, DynamicsCompressorNode>,
     ),
     Variant20(
-        flutter_rust_bridge::for_generated::rust_async::RwLockReadGuard<'a, DynamicsCompressorNode>,
+        flutter_rust_bridge::for_generated::RustAutoOpaqueReadGuard<'a, DynamicsCompressorNode>,
     ),
     Variant21(
-        flutter_rust_bridge::for_generated::rust_async::RwLockReadGuard<'a, DynamicsCompressorNode>,
-    ),
-    Variant22(flutter_rust_bridge::for_generated::rust_async::RwLockReadGuard<'a, GainNode>),
-    Variant23(flutter_rust_bridge::for_generated::rust_async::RwLockReadGuard<'a, OscillatorNode>),
-    Variant24(flutter_rust_bridge::for_generated::rust_async::RwLockReadGuard<'a, OscillatorNode>),
-    Variant25(flutter_rust_bridge::for_generated::rust_async::RwLockReadGuard<'a, PannerNode>),
-    Variant26(flutter_rust_bridge::for_generated::rust_async::RwLockReadGuard<'a, PannerNode>),
-    Variant27(flutter_rust_bridge::for_generated::rust_async::RwLockReadGuard<'a, PannerNode>),
-    Variant28(flutter_rust_bridge::for_generated::rust_async::RwLockReadGuard<'a, PannerNode>),
-    Variant29(flutter_rust_bridge::for_generated::rust_async::RwLockReadGuard<'a, PannerNode>),
-    Variant30(flutter_rust_bridge::for_generated::rust_async::RwLockReadGuard<'a, PannerNode>),
-    Variant31(
-        flutter_rust_bridge::for_generated::rust_async::RwLockReadGuard<'a, StereoPannerNode>,
+        flutter_rust_bridge::for_generated::RustAutoOpaqueReadGuard<'a, DynamicsCompressorNode>,
     ),
+    Variant22(flutter_rust_bridge::for_generated::RustAutoOpaqueReadGuard<'a, GainNode>),
+    Variant23(flutter_rust_bridge::for_generated::RustAutoOpaqueReadGuard<'a, OscillatorNode>),
+    Variant24(flutter_rust_bridge::for_generated::RustAutoOpaqueReadGuard<'a, OscillatorNode>),
+    Variant25(flutter_rust_bridge::for_generated::RustAutoOpaqueReadGuard<'a, PannerNode>),
+    Variant26(flutter_rust_bridge::for_generated::RustAutoOpaqueReadGuard<'a, PannerNode>),
+    Variant27(flutter_rust_bridge::for_generated::RustAutoOpaqueReadGuard<'a, PannerNode>),
+    Variant28(flutter_rust_bridge::for_generated::RustAutoOpaqueReadGuard<'a, PannerNode>),
+    Variant29(flutter_rust_bridge::for_generated::RustAutoOpaqueReadGuard<'a, PannerNode>),
+    Variant30(flutter_rust_bridge::for_generated::RustAutoOpaqueReadGuard<'a, PannerNode>),
+    Variant31(flutter_rust_bridge::for_generated::RustAutoOpaqueReadGuard<'a, StereoPannerNode>),
 }
 
 impl std::ops::Deref for Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnumRwLockReadGuard<'_> {
@@ -29025,78 +33150,51 @@ pub enum Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInner
     'a,
 > {
     Variant0(
-        flutter_rust_bridge::for_generated::rust_async::RwLockWriteGuard<'a, AudioBufferSourceNode>,
+        flutter_rust_bridge::for_generated::RustAutoOpaqueWriteGuard<'a, AudioBufferSourceNode>,
     ),
     Variant1(
-        flutter_rust_bridge::for_generated::rust_async::RwLockWriteGuard<'a, AudioBufferSourceNode>,
-    ),
-    Variant2(flutter_rust_bridge::for_generated::rust_async::RwLockWriteGuard<'a, AudioListener>),
-    Variant3(flutter_rust_bridge::for_generated::rust_async::RwLockWriteGuard<'a, AudioListener>),
-    Variant4(flutter_rust_bridge::for_generated::rust_async::RwLockWriteGuard<'a, AudioListener>),
-    Variant5(flutter_rust_bridge::for_generated::rust_async::RwLockWriteGuard<'a, AudioListener>),
-    Variant6(flutter_rust_bridge::for_generated::rust_async::RwLockWriteGuard<'a, AudioListener>),
-    Variant7(flutter_rust_bridge::for_generated::rust_async::RwLockWriteGuard<'a, AudioListener>),
-    Variant8(flutter_rust_bridge::for_generated::rust_async::RwLockWriteGuard<'a, AudioListener>),
-    Variant9(flutter_rust_bridge::for_generated::rust_async::RwLockWriteGuard<'a, AudioListener>),
-    Variant10(flutter_rust_bridge::for_generated::rust_async::RwLockWriteGuard<'a, AudioListener>),
-    Variant11(
-        flutter_rust_bridge::for_generated::rust_async::RwLockWriteGuard<'a, BiquadFilterNode>,
-    ),
-    Variant12(
-        flutter_rust_bridge::for_generated::rust_async::RwLockWriteGuard<'a, BiquadFilterNode>,
+        flutter_rust_bridge::for_generated::RustAutoOpaqueWriteGuard<'a, AudioBufferSourceNode>,
     ),
-    Variant13(
-        flutter_rust_bridge::for_generated::rust_async::RwLockWriteGuard<'a, BiquadFilterNode>,
-    ),
-    Variant14(
-        flutter_rust_bridge::for_generated::rust_async::RwLockWriteGuard<'a, BiquadFilterNode>,
-    ),
-    Variant15(
-        flutter_rust_bridge::for_generated::rust_async::RwLockWriteGuard<'a, ConstantSourceNode>,
-    ),
-    Variant16(flutter_rust_bridge::for_generated::rust_async::RwLockWriteGuard<'a, DelayNode>),
+    Variant2(flutter_rust_bridge::for_generated::RustAutoOpaqueWriteGuard<'a, AudioListener>),
+    Variant3(flutter_rust_bridge::for_generated::RustAutoOpaqueWriteGuard<'a, AudioListener>),
+    Variant4(flutter_rust_bridge::for_generated::RustAutoOpaqueWriteGuard<'a, AudioListener>),
+    Variant5(flutter_rust_bridge::for_generated::RustAutoOpaqueWriteGuard<'a, AudioListener>),
+    Variant6(flutter_rust_bridge::for_generated::RustAutoOpaqueWriteGuard<'a, AudioListener>),
+    Variant7(flutter_rust_bridge::for_generated::RustAutoOpaqueWriteGuard<'a, AudioListener>),
+    Variant8(flutter_rust_bridge::for_generated::RustAutoOpaqueWriteGuard<'a, AudioListener>),
+    Variant9(flutter_rust_bridge::for_generated::RustAutoOpaqueWriteGuard<'a, AudioListener>),
+    Variant10(flutter_rust_bridge::for_generated::RustAutoOpaqueWriteGuard<'a, AudioListener>),
+    Variant11(flutter_rust_bridge::for_generated::RustAutoOpaqueWriteGuard<'a, BiquadFilterNode>),
+    Variant12(flutter_rust_bridge::for_generated::RustAutoOpaqueWriteGuard<'a, BiquadFilterNode>),
+    Variant13(flutter_rust_bridge::for_generated::RustAutoOpaqueWriteGuard<'a, BiquadFilterNode>),
+    Variant14(flutter_rust_bridge::for_generated::RustAutoOpaqueWriteGuard<'a, BiquadFilterNode>),
+    Variant15(flutter_rust_bridge::for_generated::RustAutoOpaqueWriteGuard<'a, ConstantSourceNode>),
+    Variant16(flutter_rust_bridge::for_generated::RustAutoOpaqueWriteGuard<'a, DelayNode>),
     Variant17(
-        flutter_rust_bridge::for_generated::rust_async::RwLockWriteGuard<
-            'a,
-            DynamicsCompressorNode,
-        >,
+        flutter_rust_bridge::for_generated::RustAutoOpaqueWriteGuard<'a, DynamicsCompressorNode>,
     ),
     Variant18(
-        flutter_rust_bridge::for_generated::rust_async::RwLockWriteGuard<
-            'a,
-            DynamicsCompressorNode,
-        >,
+        flutter_rust_bridge::for_generated::RustAutoOpaqueWriteGuard<'a, DynamicsCompressorNode>,
     ),
     Variant19(
-        flutter_rust_bridge::for_generated::rust_async::RwLockWriteGuard<
-            'a,
-            DynamicsCompressorNode,
-        >,
+        flutter_rust_bridge::for_generated::RustAutoOpaqueWriteGuard<'a, DynamicsCompressorNode>,
     ),
     Variant20(
-        flutter_rust_bridge::for_generated::rust_async::RwLockWriteGuard<
-            'a,
-            DynamicsCompressorNode,
-        >,
+        flutter_rust_bridge::for_generated::RustAutoOpaqueWriteGuard<'a, DynamicsCompressorNode>,
     ),
     Variant21(
-        flutter_rust_bridge::for_generated::rust_async::RwLockWriteGuard<
-            'a,
-            DynamicsCompressorNode,
-        >,
-    ),
-    Variant22(flutter_rust_bridge::for_generated::rust_async::RwLockWriteGuard<'a, GainNode>),
-    Variant23(flutter_rust_bridge::for_generated::rust_async::RwLockWriteGuard<'a, OscillatorNode>),
-    Variant24(flutter_rust_bridge::for_generated::rust_async::RwLockWriteGuard<'a, OscillatorNode>),
-    Variant25(flutter_rust_bridge::for_generated::rust_async::RwLockWriteGuard<'a, PannerNode>),
-    Variant26(flutter_rust_bridge::for_generated::rust_async::RwLockWriteGuard<'a, PannerNode>),
-    Variant27(flutter_rust_bridge::for_generated::rust_async::RwLockWriteGuard<'a, PannerNode>),
-    Variant28(flutter_rust_bridge::for_generated::rust_async::RwLockWriteGuard<'a, PannerNode>),
-    Variant29(flutter_rust_bridge::for_generated::rust_async::RwLockWriteGuard<'a, PannerNode>),
-    Variant30(flutter_rust_bridge::for_generated::rust_async::RwLockWriteGuard<'a, PannerNode>),
-    Variant31(
-        flutter_rust_bridge::for_generated::rust_async::RwLockWriteGuard<'a, StereoPannerNode>,
+        flutter_rust_bridge::for_generated::RustAutoOpaqueWriteGuard<'a, DynamicsCompressorNode>,
     ),
+    Variant22(flutter_rust_bridge::for_generated::RustAutoOpaqueWriteGuard<'a, GainNode>),
+    Variant23(flutter_rust_bridge::for_generated::RustAutoOpaqueWriteGuard<'a, OscillatorNode>),
+    Variant24(flutter_rust_bridge::for_generated::RustAutoOpaqueWriteGuard<'a, OscillatorNode>),
+    Variant25(flutter_rust_bridge::for_generated::RustAutoOpaqueWriteGuard<'a, PannerNode>),
+    Variant26(flutter_rust_bridge::for_generated::RustAutoOpaqueWriteGuard<'a, PannerNode>),
+    Variant27(flutter_rust_bridge::for_generated::RustAutoOpaqueWriteGuard<'a, PannerNode>),
+    Variant28(flutter_rust_bridge::for_generated::RustAutoOpaqueWriteGuard<'a, PannerNode>),
+    Variant29(flutter_rust_bridge::for_generated::RustAutoOpaqueWriteGuard<'a, PannerNode>),
+    Variant30(flutter_rust_bridge::for_generated::RustAutoOpaqueWriteGuard<'a, PannerNode>),
+    Variant31(flutter_rust_bridge::for_generated::RustAutoOpaqueWriteGuard<'a, StereoPannerNode>),
 }
 
 impl std::ops::Deref for Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnumRwLockWriteGuard<'_> {
@@ -29195,6 +33293,14 @@ impl Lockable
         }
     }
 
+    fn lockable_exclusive(&self) -> bool {
+        match self {
+            Self::Variant0(inner) => {
+                flutter_rust_bridge::for_generated::rust_auto_opaque_lockable_exclusive(inner)
+            }
+        }
+    }
+
     fn lockable_decode_sync_ref(&self) -> Self::RwLockReadGuard<'_> {
         self.blocking_read()
     }
@@ -29226,7 +33332,7 @@ pub enum Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInner
     'a,
 > {
     Variant0(
-        flutter_rust_bridge::for_generated::rust_async::RwLockReadGuard<
+        flutter_rust_bridge::for_generated::RustAutoOpaqueReadGuard<
             'a,
             MediaStreamAudioDestinationNode,
         >,
@@ -29248,7 +33354,7 @@ pub enum Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInner
     'a,
 > {
     Variant0(
-        flutter_rust_bridge::for_generated::rust_async::RwLockWriteGuard<
+        flutter_rust_bridge::for_generated::RustAutoOpaqueWriteGuard<
             'a,
             MediaStreamAudioDestinationNode,
         >,
@@ -29277,8 +33383,8 @@ impl std::ops::DerefMut for Auto_Ref_RustOpaque_flutter_rust_bridgefor_generated
 impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <String>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
+        let mut inner = <Vec<String>>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::anyhow_error_decode(inner);
     }
 }
 
@@ -31010,7 +35116,7 @@ impl SseDecode for web_audio_api::node::GainOptions {
 impl SseDecode for i32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+        deserializer.read_i32()
     }
 }
 
@@ -31026,6 +35132,18 @@ impl SseDecode for Vec<MediaStreamTrack> {
     }
 }
 
+impl SseDecode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<String>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
 impl SseDecode for Vec<web_audio_api::AudioParamDescriptor> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -31080,11 +35198,7 @@ impl SseDecode for Vec<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
-        }
-        return ans_;
+        return deserializer.decode_u8_list(len_);
     }
 }
 
@@ -31295,7 +35409,7 @@ impl SseDecode for () {
 impl SseDecode for usize {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u64::<NativeEndian>().unwrap() as _
+        deserializer.read_u64() as _
     }
 }
 
@@ -36156,7 +40270,10 @@ impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<web_audio_api::node::WaveShape
 impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(format!("{:?}", self), serializer);
+        <Vec<String>>::sse_encode(
+            flutter_rust_bridge::for_generated::anyhow_error_encode(self),
+            serializer,
+        );
     }
 }
 
@@ -37497,76 +41614,76 @@ impl SseEncode for web_audio_api::AudioRenderCapacityOptions {
 impl SseEncode for crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum {
                     // Codec=Sse (Serialization based), see doc to use other codecs
                     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {match self {crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum::Variant0(field0) => { <i32>::sse_encode(0, serializer); <RustAutoOpaqueMoi<AudioBufferSourceNode>>::sse_encode(field0, serializer);
- }
+  }
 crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum::Variant1(field0) => { <i32>::sse_encode(1, serializer); <RustAutoOpaqueMoi<AudioBufferSourceNode>>::sse_encode(field0, serializer);
- }
+  }
 crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum::Variant2(field0) => { <i32>::sse_encode(2, serializer); <RustAutoOpaqueMoi<AudioListener>>::sse_encode(field0, serializer);
- }
+  }
 crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum::Variant3(field0) => { <i32>::sse_encode(3, serializer); <RustAutoOpaqueMoi<AudioListener>>::sse_encode(field0, serializer);
- }
+  }
 crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum::Variant4(field0) => { <i32>::sse_encode(4, serializer); <RustAutoOpaqueMoi<AudioListener>>::sse_encode(field0, serializer);
- }
+  }
 crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum::Variant5(field0) => { <i32>::sse_encode(5, serializer); <RustAutoOpaqueMoi<AudioListener>>::sse_encode(field0, serializer);
- }
+  }
 crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum::Variant6(field0) => { <i32>::sse_encode(6, serializer); <RustAutoOpaqueMoi<AudioListener>>::sse_encode(field0, serializer);
- }
+  }
 crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum::Variant7(field0) => { <i32>::sse_encode(7, serializer); <RustAutoOpaqueMoi<AudioListener>>::sse_encode(field0, serializer);
- }
+  }
 crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum::Variant8(field0) => { <i32>::sse_encode(8, serializer); <RustAutoOpaqueMoi<AudioListener>>::sse_encode(field0, serializer);
- }
+  }
 crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum::Variant9(field0) => { <i32>::sse_encode(9, serializer); <RustAutoOpaqueMoi<AudioListener>>::sse_encode(field0, serializer);
- }
+  }
 crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum::Variant10(field0) => { <i32>::sse_encode(10, serializer); <RustAutoOpaqueMoi<AudioListener>>::sse_encode(field0, serializer);
- }
+  }
 crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum::Variant11(field0) => { <i32>::sse_encode(11, serializer); <RustAutoOpaqueMoi<BiquadFilterNode>>::sse_encode(field0, serializer);
- }
+  }
 crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum::Variant12(field0) => { <i32>::sse_encode(12, serializer); <RustAutoOpaqueMoi<BiquadFilterNode>>::sse_encode(field0, serializer);
- }
+  }
 crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum::Variant13(field0) => { <i32>::sse_encode(13, serializer); <RustAutoOpaqueMoi<BiquadFilterNode>>::sse_encode(field0, serializer);
- }
+  }
 crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum::Variant14(field0) => { <i32>::sse_encode(14, serializer); <RustAutoOpaqueMoi<BiquadFilterNode>>::sse_encode(field0, serializer);
- }
+  }
 crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum::Variant15(field0) => { <i32>::sse_encode(15, serializer); <RustAutoOpaqueMoi<ConstantSourceNode>>::sse_encode(field0, serializer);
- }
+  }
 crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum::Variant16(field0) => { <i32>::sse_encode(16, serializer); <RustAutoOpaqueMoi<DelayNode>>::sse_encode(field0, serializer);
- }
+  }
 crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum::Variant17(field0) => { <i32>::sse_encode(17, serializer); <RustAutoOpaqueMoi<DynamicsCompressorNode>>::sse_encode(field0, serializer);
- }
+  }
 crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum::Variant18(field0) => { <i32>::sse_encode(18, serializer); <RustAutoOpaqueMoi<DynamicsCompressorNode>>::sse_encode(field0, serializer);
- }
+  }
 crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum::Variant19(field0) => { <i32>::sse_encode(19, serializer); <RustAutoOpaqueMoi<DynamicsCompressorNode>>::sse_encode(field0, serializer);
- }
+  }
 crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum::Variant20(field0) => { <i32>::sse_encode(20, serializer); <RustAutoOpaqueMoi<DynamicsCompressorNode>>::sse_encode(field0, serializer);
- }
+  }
 crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum::Variant21(field0) => { <i32>::sse_encode(21, serializer); <RustAutoOpaqueMoi<DynamicsCompressorNode>>::sse_encode(field0, serializer);
- }
+  }
 crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum::Variant22(field0) => { <i32>::sse_encode(22, serializer); <RustAutoOpaqueMoi<GainNode>>::sse_encode(field0, serializer);
- }
+  }
 crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum::Variant23(field0) => { <i32>::sse_encode(23, serializer); <RustAutoOpaqueMoi<OscillatorNode>>::sse_encode(field0, serializer);
- }
+  }
 crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum::Variant24(field0) => { <i32>::sse_encode(24, serializer); <RustAutoOpaqueMoi<OscillatorNode>>::sse_encode(field0, serializer);
- }
+  }
 crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum::Variant25(field0) => { <i32>::sse_encode(25, serializer); <RustAutoOpaqueMoi<PannerNode>>::sse_encode(field0, serializer);
- }
+  }
 crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum::Variant26(field0) => { <i32>::sse_encode(26, serializer); <RustAutoOpaqueMoi<PannerNode>>::sse_encode(field0, serializer);
- }
+  }
 crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum::Variant27(field0) => { <i32>::sse_encode(27, serializer); <RustAutoOpaqueMoi<PannerNode>>::sse_encode(field0, serializer);
- }
+  }
 crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum::Variant28(field0) => { <i32>::sse_encode(28, serializer); <RustAutoOpaqueMoi<PannerNode>>::sse_encode(field0, serializer);
- }
+  }
 crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum::Variant29(field0) => { <i32>::sse_encode(29, serializer); <RustAutoOpaqueMoi<PannerNode>>::sse_encode(field0, serializer);
- }
+  }
 crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum::Variant30(field0) => { <i32>::sse_encode(30, serializer); <RustAutoOpaqueMoi<PannerNode>>::sse_encode(field0, serializer);
- }
+  }
 crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioParamProxyEnum::Variant31(field0) => { <i32>::sse_encode(31, serializer); <RustAutoOpaqueMoi<StereoPannerNode>>::sse_encode(field0, serializer);
- }
+  }
  _ => { unimplemented!(""); }}}
                 }
 
 impl SseEncode for crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaStreamProxyEnum {
                     // Codec=Sse (Serialization based), see doc to use other codecs
                     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {match self {crate::frb_generated::Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaStreamProxyEnum::Variant0(field0) => { <i32>::sse_encode(0, serializer); <RustAutoOpaqueMoi<MediaStreamAudioDestinationNode>>::sse_encode(field0, serializer);
- }
+  }
  _ => { unimplemented!(""); }}}
                 }
 
@@ -37755,7 +41872,7 @@ impl SseEncode for web_audio_api::node::GainOptions {
 impl SseEncode for i32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+        serializer.write_i32(self);
     }
 }
 
@@ -37769,6 +41886,16 @@ impl SseEncode for Vec<MediaStreamTrack> {
     }
 }
 
+impl SseEncode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <String>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<web_audio_api::AudioParamDescriptor> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -37999,10 +42126,7 @@ impl SseEncode for () {
 impl SseEncode for usize {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer
-            .cursor
-            .write_u64::<NativeEndian>(self as _)
-            .unwrap();
+        serializer.write_u64(self as _);
     }
 }
 
@@ -9,8 +9,11 @@ import 'dart:typed_data';
 
 import 'package:benchmark_harness/benchmark_harness.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart'
+    show SseDeserializer;
 import 'package:frb_example_pure_dart/src/rust/api/benchmark_misc.dart';
 import 'package:frb_example_pure_dart/src/rust/api/pseudo_manual/benchmark_api.dart';
+import 'package:frb_example_pure_dart/src/rust/api/pseudo_manual/benchmark_api_twin_sse.dart';
 import 'package:frb_example_pure_dart/src/rust/api/pseudo_manual/benchmark_api_twin_sync.dart';
 import 'package:frb_example_pure_dart/src/rust/api/pseudo_manual/benchmark_api_twin_sync_sse.dart';
 import 'package:frb_example_pure_dart/src/rust/frb_generated.dart';
@@ -44,6 +47,14 @@ List<MaybeAsyncBenchmarkBase> createBenchmarks(
     Bytes_Frb_Input_Sync_Benchmark(len: 0, emitter: emitter),
     Bytes_Frb_Input_Sync_Benchmark(len: 10000, emitter: emitter),
     Bytes_Frb_Input_Sync_Benchmark(len: 1000000, emitter: emitter),
+    Bytes_FrbSse_Input_Async_Benchmark(len: 0, emitter: emitter),
+    Bytes_FrbSse_Input_Async_Benchmark(len: 10000, emitter: emitter),
+    Bytes_FrbSse_Input_Async_Benchmark(len: 1000000, emitter: emitter),
+    Bytes_FrbSse_Input_Async_Benchmark(len: 50000000, emitter: emitter),
+    Bytes_FrbSse_Input_Sync_Benchmark(len: 0, emitter: emitter),
+    Bytes_FrbSse_Input_Sync_Benchmark(len: 10000, emitter: emitter),
+    Bytes_FrbSse_Input_Sync_Benchmark(len: 1000000, emitter: emitter),
+    Bytes_FrbSse_Input_Sync_Benchmark(len: 50000000, emitter: emitter),
     Bytes_Raw_Input_Sync_Benchmark(len: 0, emitter: emitter),
     Bytes_Raw_Input_Sync_Benchmark(len: 10000, emitter: emitter),
     Bytes_Raw_Input_Sync_Benchmark(len: 1000000, emitter: emitter),
@@ -104,6 +115,12 @@ List<MaybeAsyncBenchmarkBase> createBenchmarks(
     Blob_Json_Output_Sync_Benchmark(len: 0, emitter: emitter),
     Blob_Json_Output_Sync_Benchmark(len: 10000, emitter: emitter),
     Blob_Json_Output_Sync_Benchmark(len: 1000000, emitter: emitter),
+    SmallInts_FrbSse_Output_Sync_Benchmark(len: 10, emitter: emitter),
+    SmallInts_FrbSse_Output_Sync_Benchmark(len: 1000, emitter: emitter),
+    SmallInts_FrbSse_Output_Sync_Benchmark(len: 100000, emitter: emitter),
+    SmallInts_FrbCse_Output_Sync_Benchmark(len: 10, emitter: emitter),
+    SmallInts_FrbCse_Output_Sync_Benchmark(len: 1000, emitter: emitter),
+    SmallInts_FrbCse_Output_Sync_Benchmark(len: 100000, emitter: emitter),
   ];
 }
 
@@ -312,6 +329,48 @@ class Bytes_Frb_Input_Sync_Benchmark extends EnhancedBenchmarkBase {
   }
 }
 
+class Bytes_FrbSse_Input_Async_Benchmark extends EnhancedAsyncBenchmarkBase {
+  late final Uint8List setupData;
+  final int len;
+
+  Bytes_FrbSse_Input_Async_Benchmark({
+    required this.len,
+    super.emitter,
+  }) : super(
+            '{"area":"PureDart","task":"Bytes","approach":"FrbSse","direction":"Input","asynchronous":true,"arg":"$len","platform":"$currentPlatformName"}');
+
+  @override
+  Future<void> setup() async {
+    setupData = Uint8List(len);
+  }
+
+  @override
+  Future<void> run() async {
+    await benchmarkInputBytesTwinSse(bytes: setupData);
+  }
+}
+
+class Bytes_FrbSse_Input_Sync_Benchmark extends EnhancedBenchmarkBase {
+  late final Uint8List setupData;
+  final int len;
+
+  Bytes_FrbSse_Input_Sync_Benchmark({
+    required this.len,
+    super.emitter,
+  }) : super(
+            '{"area":"PureDart","task":"Bytes","approach":"FrbSse","direction":"Input","asynchronous":false,"arg":"$len","platform":"$currentPlatformName"}');
+
+  @override
+  void setup() {
+    setupData = Uint8List(len);
+  }
+
+  @override
+  void run() {
+    benchmarkInputBytesTwinSyncSse(bytes: setupData);
+  }
+}
+
 class Bytes_Raw_Input_Sync_Benchmark extends EnhancedBenchmarkBase {
   late final Uint8List setupData;
   final int len;
@@ -809,3 +868,76 @@ class Blob_Json_Output_Sync_Benchmark extends EnhancedBenchmarkBase {
     dummyValue ^= json.hashCode;
   }
 }
+
+class SmallInts_FrbSse_Output_Sync_Benchmark extends EnhancedBenchmarkBase {
+  late final ByteData setupData;
+  final int len;
+
+  SmallInts_FrbSse_Output_Sync_Benchmark({
+    required this.len,
+    super.emitter,
+  }) : super(
+            '{"area":"PureDart","task":"SmallInts","approach":"FrbSse","direction":"Output","asynchronous":false,"arg":"$len","platform":"$currentPlatformName"}');
+
+  @override
+  void setup() {
+    setupData = _encode(len);
+  }
+
+  @override
+  void run() {
+    final deserializer = SseDeserializer(setupData, compact: false);
+    final ans =
+        deserializer.buffer.getInt32List(deserializer.buffer.getInt32());
+    dummyValue ^= ans.length;
+  }
+
+  static ByteData _encode(int len) {
+    final data = ByteData(4 + 4 * len);
+    data.setInt32(0, len, Endian.host);
+    for (var i = 0; i < len; ++i) {
+      data.setInt32(4 + 4 * i, i % 50, Endian.host);
+    }
+    return data;
+  }
+}
+
+class SmallInts_FrbCse_Output_Sync_Benchmark extends EnhancedBenchmarkBase {
+  late final ByteData setupData;
+  final int len;
+
+  SmallInts_FrbCse_Output_Sync_Benchmark({
+    required this.len,
+    super.emitter,
+  }) : super(
+            '{"area":"PureDart","task":"SmallInts","approach":"FrbCse","direction":"Output","asynchronous":false,"arg":"$len","platform":"$currentPlatformName"}');
+
+  @override
+  void setup() {
+    setupData = _encode(len);
+  }
+
+  @override
+  void run() {
+    final deserializer = SseDeserializer(setupData, compact: true);
+    final ans =
+        deserializer.buffer.getInt32List(deserializer.buffer.getInt32());
+    dummyValue ^= ans.length;
+  }
+
+  // Each value is a zigzag-encoded single-byte varint
+  static ByteData _encode(int len) {
+    final bytes = <int>[];
+    for (var rest = len * 2;; rest ~/= 0x80) {
+      if (rest < 0x80) {
+        bytes.add(rest);
+        break;
+      }
+      bytes.add(rest % 0x80 + 0x80);
+    }
+    for (var i = 0; i < len; ++i) {
+      bytes.add((i % 50) * 2);
+    }
+    return Uint8List.fromList(bytes).buffer.asByteData();
+  }
+}
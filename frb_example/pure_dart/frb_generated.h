@@ -64,6 +64,27 @@ typedef struct wire_cst_user_id_twin_normal {
   uint32_t value;
 } wire_cst_user_id_twin_normal;
 
+typedef struct wire_cst_list_BigInt {
+  struct wire_cst_list_prim_u_8_strict **ptr;
+  int32_t len;
+} wire_cst_list_BigInt;
+
+typedef struct wire_cst_big_numbers_twin_normal {
+  struct wire_cst_list_prim_u_8_strict *amount;
+  struct wire_cst_list_prim_u_8_strict *discount;
+  struct wire_cst_list_prim_u_8_strict *serial;
+} wire_cst_big_numbers_twin_normal;
+
+typedef struct wire_cst_list_Decimal {
+  struct wire_cst_list_prim_u_8_strict **ptr;
+  int32_t len;
+} wire_cst_list_Decimal;
+
+typedef struct wire_cst_list_Chrono_FixedOffset {
+  struct wire_cst_list_prim_u_8_strict **ptr;
+  int32_t len;
+} wire_cst_list_Chrono_FixedOffset;
+
 typedef struct wire_cst_list_Chrono_Duration {
   int64_t *ptr;
   int32_t len;
@@ -81,6 +102,18 @@ typedef struct wire_cst_feature_chrono_twin_normal {
   int64_t naive;
 } wire_cst_feature_chrono_twin_normal;
 
+typedef struct wire_cst_list_Chrono_NaiveDate {
+  int64_t *ptr;
+  int32_t len;
+} wire_cst_list_Chrono_NaiveDate;
+
+typedef struct wire_cst_schedule_twin_normal {
+  int64_t day;
+  int64_t starts;
+  int64_t *ends;
+  struct wire_cst_list_prim_u_8_strict *at;
+} wire_cst_schedule_twin_normal;
+
 typedef struct wire_cst_struct_with_comments_twin_normal {
   int32_t field_with_comments;
 } wire_cst_struct_with_comments_twin_normal;
@@ -330,6 +363,17 @@ typedef struct wire_cst_macro_struct {
   int32_t data;
 } wire_cst_macro_struct;
 
+typedef struct wire_cst_json_document_twin_normal {
+  struct wire_cst_list_prim_u_8_strict *body;
+  struct wire_cst_list_prim_u_8_strict *meta;
+  struct wire_cst_list_prim_u_8_strict *extra;
+} wire_cst_json_document_twin_normal;
+
+typedef struct wire_cst_list_JsonValue {
+  struct wire_cst_list_prim_u_8_strict **ptr;
+  int32_t len;
+} wire_cst_list_JsonValue;
+
 typedef struct wire_cst_record_i_32_i_32 {
   int32_t field0;
   int32_t field1;
@@ -340,6 +384,26 @@ typedef struct wire_cst_list_record_i_32_i_32 {
   int32_t len;
 } wire_cst_list_record_i_32_i_32;
 
+typedef struct wire_cst_my_size {
+  int32_t width;
+  int32_t height;
+} wire_cst_my_size;
+
+typedef struct wire_cst_record_string_my_size {
+  struct wire_cst_list_prim_u_8_strict *field0;
+  struct wire_cst_my_size field1;
+} wire_cst_record_string_my_size;
+
+typedef struct wire_cst_list_record_string_my_size {
+  struct wire_cst_record_string_my_size *ptr;
+  int32_t len;
+} wire_cst_list_record_string_my_size;
+
+typedef struct wire_cst_list_String {
+  struct wire_cst_list_prim_u_8_strict **ptr;
+  int32_t len;
+} wire_cst_list_String;
+
 typedef struct wire_cst_record_string_list_prim_u_8_strict {
   struct wire_cst_list_prim_u_8_strict *field0;
   struct wire_cst_list_prim_u_8_strict *field1;
@@ -380,26 +444,6 @@ typedef struct wire_cst_list_record_string_string {
   int32_t len;
 } wire_cst_list_record_string_string;
 
-typedef struct wire_cst_my_size {
-  int32_t width;
-  int32_t height;
-} wire_cst_my_size;
-
-typedef struct wire_cst_record_string_my_size {
-  struct wire_cst_list_prim_u_8_strict *field0;
-  struct wire_cst_my_size field1;
-} wire_cst_record_string_my_size;
-
-typedef struct wire_cst_list_record_string_my_size {
-  struct wire_cst_record_string_my_size *ptr;
-  int32_t len;
-} wire_cst_list_record_string_my_size;
-
-typedef struct wire_cst_list_String {
-  struct wire_cst_list_prim_u_8_strict **ptr;
-  int32_t len;
-} wire_cst_list_String;
-
 typedef struct wire_cst_concatenate_with_twin_normal {
   struct wire_cst_list_prim_u_8_strict *a;
 } wire_cst_concatenate_with_twin_normal;
@@ -1149,6 +1193,18 @@ typedef struct wire_cst_benchmark_blob_twin_sync {
   struct wire_cst_list_prim_u_8_strict *third;
 } wire_cst_benchmark_blob_twin_sync;
 
+typedef struct wire_cst_big_numbers_twin_rust_async {
+  struct wire_cst_list_prim_u_8_strict *amount;
+  struct wire_cst_list_prim_u_8_strict *discount;
+  struct wire_cst_list_prim_u_8_strict *serial;
+} wire_cst_big_numbers_twin_rust_async;
+
+typedef struct wire_cst_big_numbers_twin_sync {
+  struct wire_cst_list_prim_u_8_strict *amount;
+  struct wire_cst_list_prim_u_8_strict *discount;
+  struct wire_cst_list_prim_u_8_strict *serial;
+} wire_cst_big_numbers_twin_sync;
+
 typedef struct wire_cst_feature_chrono_twin_rust_async {
   int64_t utc;
   int64_t local;
@@ -1156,6 +1212,13 @@ typedef struct wire_cst_feature_chrono_twin_rust_async {
   int64_t naive;
 } wire_cst_feature_chrono_twin_rust_async;
 
+typedef struct wire_cst_schedule_twin_rust_async {
+  int64_t day;
+  int64_t starts;
+  int64_t *ends;
+  struct wire_cst_list_prim_u_8_strict *at;
+} wire_cst_schedule_twin_rust_async;
+
 typedef struct wire_cst_feature_chrono_twin_sync {
   int64_t utc;
   int64_t local;
@@ -1163,6 +1226,13 @@ typedef struct wire_cst_feature_chrono_twin_sync {
   int64_t naive;
 } wire_cst_feature_chrono_twin_sync;
 
+typedef struct wire_cst_schedule_twin_sync {
+  int64_t day;
+  int64_t starts;
+  int64_t *ends;
+  struct wire_cst_list_prim_u_8_strict *at;
+} wire_cst_schedule_twin_sync;
+
 typedef struct wire_cst_struct_with_comments_twin_rust_async {
   int32_t field_with_comments;
 } wire_cst_struct_with_comments_twin_rust_async;
@@ -1598,6 +1668,18 @@ typedef struct wire_cst_some_struct_twin_sync {
   uint32_t value;
 } wire_cst_some_struct_twin_sync;
 
+typedef struct wire_cst_json_document_twin_rust_async {
+  struct wire_cst_list_prim_u_8_strict *body;
+  struct wire_cst_list_prim_u_8_strict *meta;
+  struct wire_cst_list_prim_u_8_strict *extra;
+} wire_cst_json_document_twin_rust_async;
+
+typedef struct wire_cst_json_document_twin_sync {
+  struct wire_cst_list_prim_u_8_strict *body;
+  struct wire_cst_list_prim_u_8_strict *meta;
+  struct wire_cst_list_prim_u_8_strict *extra;
+} wire_cst_json_document_twin_sync;
+
 typedef struct wire_cst_record_string_kitchen_sink_twin_rust_async {
   struct wire_cst_list_prim_u_8_strict *field0;
   struct wire_cst_kitchen_sink_twin_rust_async field1;
@@ -2337,6 +2419,28 @@ typedef struct wire_cst_opaque_nested_twin_sync_moi {
   uintptr_t second;
 } wire_cst_opaque_nested_twin_sync_moi;
 
+typedef struct wire_cst_list_StdDuration {
+  int64_t *ptr;
+  int32_t len;
+} wire_cst_list_StdDuration;
+
+typedef struct wire_cst_list_StdSystemTime {
+  int64_t *ptr;
+  int32_t len;
+} wire_cst_list_StdSystemTime;
+
+typedef struct wire_cst_std_time_twin_rust_async {
+  int64_t duration;
+  int64_t time;
+  int64_t *optional_duration;
+} wire_cst_std_time_twin_rust_async;
+
+typedef struct wire_cst_std_time_twin_sync {
+  int64_t duration;
+  int64_t time;
+  int64_t *optional_duration;
+} wire_cst_std_time_twin_sync;
+
 typedef struct wire_cst_my_struct_containing_stream_sink_twin_rust_async {
   int32_t a;
   struct wire_cst_list_prim_u_8_strict *b;
@@ -2409,6 +2513,25 @@ typedef struct wire_cst_tuple_struct_with_two_field_twin_sync {
   int32_t field1;
 } wire_cst_tuple_struct_with_two_field_twin_sync;
 
+typedef struct wire_cst_list_TimeDuration {
+  int64_t *ptr;
+  int32_t len;
+} wire_cst_list_TimeDuration;
+
+typedef struct wire_cst_time_types_twin_rust_async {
+  int64_t offset;
+  int64_t *primitive;
+  int64_t date;
+  int64_t duration;
+} wire_cst_time_types_twin_rust_async;
+
+typedef struct wire_cst_time_types_twin_sync {
+  int64_t offset;
+  int64_t *primitive;
+  int64_t date;
+  int64_t duration;
+} wire_cst_time_types_twin_sync;
+
 typedef struct wire_cst_record_string_i_32 {
   struct wire_cst_list_prim_u_8_strict *field0;
   int32_t field1;
@@ -2509,6 +2632,12 @@ typedef struct wire_cst_opaque_nested_twin_normal {
   uintptr_t second;
 } wire_cst_opaque_nested_twin_normal;
 
+typedef struct wire_cst_std_time_twin_normal {
+  int64_t duration;
+  int64_t time;
+  int64_t *optional_duration;
+} wire_cst_std_time_twin_normal;
+
 typedef struct wire_cst_my_struct_containing_stream_sink_twin_normal {
   int32_t a;
   struct wire_cst_list_prim_u_8_strict *b;
@@ -2545,6 +2674,13 @@ typedef struct wire_cst_tuple_struct_with_two_field_twin_normal {
   int32_t field1;
 } wire_cst_tuple_struct_with_two_field_twin_normal;
 
+typedef struct wire_cst_time_types_twin_normal {
+  int64_t offset;
+  int64_t *primitive;
+  int64_t date;
+  int64_t duration;
+} wire_cst_time_types_twin_normal;
+
 typedef struct wire_cst_feature_uuid_twin_normal {
   struct wire_cst_list_prim_u_8_strict *one;
 } wire_cst_feature_uuid_twin_normal;
@@ -2621,6 +2757,24 @@ typedef struct wire_cst_custom_nested_error_2_twin_sync {
   union CustomNestedError2TwinSyncKind kind;
 } wire_cst_custom_nested_error_2_twin_sync;
 
+typedef struct wire_cst_DartImplementableTraitTwinNormalImplementor_Variant0 {
+  uintptr_t field0;
+} wire_cst_DartImplementableTraitTwinNormalImplementor_Variant0;
+
+typedef struct wire_cst_DartImplementableTraitTwinNormalImplementor_Variant1 {
+  uintptr_t field0;
+} wire_cst_DartImplementableTraitTwinNormalImplementor_Variant1;
+
+typedef union DartImplementableTraitTwinNormalImplementorKind {
+  struct wire_cst_DartImplementableTraitTwinNormalImplementor_Variant0 Variant0;
+  struct wire_cst_DartImplementableTraitTwinNormalImplementor_Variant1 Variant1;
+} DartImplementableTraitTwinNormalImplementorKind;
+
+typedef struct wire_cst_dart_implementable_trait_twin_normal_implementor {
+  int32_t tag;
+  union DartImplementableTraitTwinNormalImplementorKind kind;
+} wire_cst_dart_implementable_trait_twin_normal_implementor;
+
 typedef struct wire_cst_list_element_twin_normal {
   struct wire_cst_element_twin_normal *ptr;
   int32_t len;
@@ -3382,6 +3536,60 @@ typedef struct wire_cst_test_model_twin_sync {
   struct wire_cst_my_struct alias_struct;
 } wire_cst_test_model_twin_sync;
 
+typedef struct wire_cst_TypedExceptionErrorTwinNormal_NotFound {
+  struct wire_cst_list_prim_u_8_strict *path;
+} wire_cst_TypedExceptionErrorTwinNormal_NotFound;
+
+typedef struct wire_cst_TypedExceptionErrorTwinNormal_Io {
+  struct wire_cst_list_prim_u_8_strict *field0;
+} wire_cst_TypedExceptionErrorTwinNormal_Io;
+
+typedef union TypedExceptionErrorTwinNormalKind {
+  struct wire_cst_TypedExceptionErrorTwinNormal_NotFound NotFound;
+  struct wire_cst_TypedExceptionErrorTwinNormal_Io Io;
+} TypedExceptionErrorTwinNormalKind;
+
+typedef struct wire_cst_typed_exception_error_twin_normal {
+  int32_t tag;
+  union TypedExceptionErrorTwinNormalKind kind;
+} wire_cst_typed_exception_error_twin_normal;
+
+typedef struct wire_cst_TypedExceptionErrorTwinRustAsync_NotFound {
+  struct wire_cst_list_prim_u_8_strict *path;
+} wire_cst_TypedExceptionErrorTwinRustAsync_NotFound;
+
+typedef struct wire_cst_TypedExceptionErrorTwinRustAsync_Io {
+  struct wire_cst_list_prim_u_8_strict *field0;
+} wire_cst_TypedExceptionErrorTwinRustAsync_Io;
+
+typedef union TypedExceptionErrorTwinRustAsyncKind {
+  struct wire_cst_TypedExceptionErrorTwinRustAsync_NotFound NotFound;
+  struct wire_cst_TypedExceptionErrorTwinRustAsync_Io Io;
+} TypedExceptionErrorTwinRustAsyncKind;
+
+typedef struct wire_cst_typed_exception_error_twin_rust_async {
+  int32_t tag;
+  union TypedExceptionErrorTwinRustAsyncKind kind;
+} wire_cst_typed_exception_error_twin_rust_async;
+
+typedef struct wire_cst_TypedExceptionErrorTwinSync_NotFound {
+  struct wire_cst_list_prim_u_8_strict *path;
+} wire_cst_TypedExceptionErrorTwinSync_NotFound;
+
+typedef struct wire_cst_TypedExceptionErrorTwinSync_Io {
+  struct wire_cst_list_prim_u_8_strict *field0;
+} wire_cst_TypedExceptionErrorTwinSync_Io;
+
+typedef union TypedExceptionErrorTwinSyncKind {
+  struct wire_cst_TypedExceptionErrorTwinSync_NotFound NotFound;
+  struct wire_cst_TypedExceptionErrorTwinSync_Io Io;
+} TypedExceptionErrorTwinSyncKind;
+
+typedef struct wire_cst_typed_exception_error_twin_sync {
+  int32_t tag;
+  union TypedExceptionErrorTwinSyncKind kind;
+} wire_cst_typed_exception_error_twin_sync;
+
 typedef struct wire_cst_vec_of_primitive_pack_twin_normal {
   struct wire_cst_list_prim_i_8_strict *int8list;
   struct wire_cst_list_prim_u_8_strict *uint8list;
@@ -3465,12 +3673,16 @@ void frbgen_frb_example_pure_dart_wire__crate__api__array__use_boxed_blob_twin_n
 void frbgen_frb_example_pure_dart_wire__crate__api__array__use_msgid_twin_normal(int64_t port_,
                                                                                  struct wire_cst_message_id_twin_normal *id);
 
+void frbgen_frb_example_pure_dart_wire__crate__api__async_misc__func_async_never_complete_twin_normal(int64_t port_);
+
 void frbgen_frb_example_pure_dart_wire__crate__api__async_misc__func_async_simple_add_twin_normal(int64_t port_,
                                                                                                   int32_t a,
                                                                                                   int32_t b);
 
 void frbgen_frb_example_pure_dart_wire__crate__api__async_misc__func_async_void_twin_normal(int64_t port_);
 
+void frbgen_frb_example_pure_dart_wire__crate__api__async_misc__func_async_wait_for_cancellation_twin_normal(int64_t port_);
+
 void frbgen_frb_example_pure_dart_wire__crate__api__async_spawn__simple_use_async_spawn(int64_t port_,
                                                                                         struct wire_cst_list_prim_u_8_strict *arg);
 
@@ -3489,6 +3701,35 @@ void frbgen_frb_example_pure_dart_wire__crate__api__attribute__next_user_id_twin
 
 WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__benchmark_misc__benchmark_void_semi_serialize(void);
 
+void frbgen_frb_example_pure_dart_wire__crate__api__big_number__add_decimals_twin_normal(int64_t port_,
+                                                                                         struct wire_cst_list_prim_u_8_strict *a,
+                                                                                         struct wire_cst_list_prim_u_8_strict *b);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__big_number__big_int_twin_normal(int64_t port_,
+                                                                                    struct wire_cst_list_prim_u_8_strict *i);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__big_number__big_ints_twin_normal(int64_t port_,
+                                                                                     struct wire_cst_list_BigInt *ints);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__big_number__big_numbers_twin_normal(int64_t port_,
+                                                                                        struct wire_cst_big_numbers_twin_normal *value);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__big_number__decimal_twin_normal(int64_t port_,
+                                                                                    struct wire_cst_list_prim_u_8_strict *d);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__big_number__decimals_twin_normal(int64_t port_,
+                                                                                     struct wire_cst_list_Decimal *decimals);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__big_number__multiply_big_ints_twin_normal(int64_t port_,
+                                                                                              struct wire_cst_list_prim_u_8_strict *a,
+                                                                                              struct wire_cst_list_prim_u_8_strict *b);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__big_number__optional_big_int_twin_normal(int64_t port_,
+                                                                                             struct wire_cst_list_prim_u_8_strict *i);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__big_number__optional_decimal_twin_normal(int64_t port_,
+                                                                                             struct wire_cst_list_prim_u_8_strict *d);
+
 void frbgen_frb_example_pure_dart_wire__crate__api__casted_primitive__casted_primitive_i64_twin_normal(int64_t port_,
                                                                                                        uint8_t *ptr_,
                                                                                                        int32_t rust_vec_len_,
@@ -3519,6 +3760,12 @@ void frbgen_frb_example_pure_dart_wire__crate__api__casted_primitive__function_f
                                                                                                                             int32_t rust_vec_len_,
                                                                                                                             int32_t data_len_);
 
+void frbgen_frb_example_pure_dart_wire__crate__api__chrono_type__datetime_fixed_offset_twin_normal(int64_t port_,
+                                                                                                   struct wire_cst_list_prim_u_8_strict *d);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__chrono_type__datetime_fixed_offsets_twin_normal(int64_t port_,
+                                                                                                    struct wire_cst_list_Chrono_FixedOffset *dates);
+
 void frbgen_frb_example_pure_dart_wire__crate__api__chrono_type__datetime_local_twin_normal(int64_t port_,
                                                                                             int64_t d);
 
@@ -3539,16 +3786,57 @@ void frbgen_frb_example_pure_dart_wire__crate__api__chrono_type__handle_timestam
 void frbgen_frb_example_pure_dart_wire__crate__api__chrono_type__how_long_does_it_take_twin_normal(int64_t port_,
                                                                                                    struct wire_cst_feature_chrono_twin_normal *mine);
 
+void frbgen_frb_example_pure_dart_wire__crate__api__chrono_type__naive_date_twin_normal(int64_t port_,
+                                                                                        int64_t d);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__chrono_type__naive_dates_twin_normal(int64_t port_,
+                                                                                         struct wire_cst_list_Chrono_NaiveDate *dates);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__chrono_type__naive_time_twin_normal(int64_t port_,
+                                                                                        int64_t d);
+
 void frbgen_frb_example_pure_dart_wire__crate__api__chrono_type__naivedatetime_twin_normal(int64_t port_,
                                                                                            int64_t d);
 
+void frbgen_frb_example_pure_dart_wire__crate__api__chrono_type__optional_datetime_fixed_offset_twin_normal(int64_t port_,
+                                                                                                            struct wire_cst_list_prim_u_8_strict *d);
+
 void frbgen_frb_example_pure_dart_wire__crate__api__chrono_type__optional_empty_datetime_utc_twin_normal(int64_t port_,
                                                                                                          int64_t *d);
 
+void frbgen_frb_example_pure_dart_wire__crate__api__chrono_type__schedule_twin_normal(int64_t port_,
+                                                                                      struct wire_cst_schedule_twin_normal *schedule);
+
 void frbgen_frb_example_pure_dart_wire__crate__api__chrono_type__test_chrono_twin_normal(int64_t port_);
 
 void frbgen_frb_example_pure_dart_wire__crate__api__chrono_type__test_precise_chrono_twin_normal(int64_t port_);
 
+void frbgen_frb_example_pure_dart_wire__crate__api__chrono_tz_type__datetime_tz_name_twin_normal(int64_t port_,
+                                                                                                 struct wire_cst_list_prim_u_8_strict *d);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__chrono_tz_type__datetime_tz_to_fixed_offset_twin_normal(int64_t port_,
+                                                                                                            struct wire_cst_list_prim_u_8_strict *d);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__chrono_tz_type__datetime_tz_twin_normal(int64_t port_,
+                                                                                            uint8_t *ptr_,
+                                                                                            int32_t rust_vec_len_,
+                                                                                            int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__chrono_tz_type__datetime_tzs_twin_normal(int64_t port_,
+                                                                                             uint8_t *ptr_,
+                                                                                             int32_t rust_vec_len_,
+                                                                                             int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__chrono_tz_type__datetime_with_time_zone_twin_normal(int64_t port_,
+                                                                                                        uint8_t *ptr_,
+                                                                                                        int32_t rust_vec_len_,
+                                                                                                        int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__chrono_tz_type__optional_datetime_tz_twin_normal(int64_t port_,
+                                                                                                     uint8_t *ptr_,
+                                                                                                     int32_t rust_vec_len_,
+                                                                                                     int32_t data_len_);
+
 void frbgen_frb_example_pure_dart_wire__crate__api__comment__function_with_comments_slash_star_star_twin_normal(int64_t port_);
 
 void frbgen_frb_example_pure_dart_wire__crate__api__comment__function_with_comments_triple_slash_multi_line_twin_normal(int64_t port_);
@@ -3633,6 +3921,13 @@ void frbgen_frb_example_pure_dart_wire__crate__api__dart_fn__rust_call_dart_with
 void frbgen_frb_example_pure_dart_wire__crate__api__dart_fn__rust_call_dart_with_dart_opaque_result_twin_normal(int64_t port_,
                                                                                                                 const void *callback);
 
+void frbgen_frb_example_pure_dart_wire__crate__api__dart_fn__rust_consume_dart_stream_sum_twin_normal(int64_t port_,
+                                                                                                      const void *stream);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__dart_fn__rust_consume_dart_stream_take_twin_normal(int64_t port_,
+                                                                                                       const void *stream,
+                                                                                                       uintptr_t count);
+
 void frbgen_frb_example_pure_dart_wire__crate__api__dart_opaque__async_accept_dart_opaque_twin_normal(int64_t port_,
                                                                                                       const void *opaque);
 
@@ -3737,6 +4032,35 @@ void frbgen_frb_example_pure_dart_wire__crate__api__dyn_trait__StructTwoWithTrai
                                                                                                                             int32_t rust_vec_len_,
                                                                                                                             int32_t data_len_);
 
+void frbgen_frb_example_pure_dart_wire__crate__api__dyn_trait__StructWithDartImplementableTraitTwinNormal_add_twin_normal(int64_t port_,
+                                                                                                                          uint8_t *ptr_,
+                                                                                                                          int32_t rust_vec_len_,
+                                                                                                                          int32_t data_len_);
+
+WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__dyn_trait__StructWithDartImplementableTraitTwinNormal_auto_accessor_get_base(uintptr_t that);
+
+WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__dyn_trait__StructWithDartImplementableTraitTwinNormal_auto_accessor_set_base(uintptr_t that,
+                                                                                                                                                 int32_t base);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__dyn_trait__StructWithDartImplementableTraitTwinNormal_describe_twin_normal(int64_t port_,
+                                                                                                                               uint8_t *ptr_,
+                                                                                                                               int32_t rust_vec_len_,
+                                                                                                                               int32_t data_len_);
+
+WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__dyn_trait__StructWithDartImplementableTraitTwinNormal_new(uint8_t *ptr_,
+                                                                                                                              int32_t rust_vec_len_,
+                                                                                                                              int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__dyn_trait__func_arg_box_dyn_dart_implementable_async_twin_normal(int64_t port_,
+                                                                                                                     uint8_t *ptr_,
+                                                                                                                     int32_t rust_vec_len_,
+                                                                                                                     int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__dyn_trait__func_arg_box_dyn_dart_implementable_sync_twin_normal(int64_t port_,
+                                                                                                                    uint8_t *ptr_,
+                                                                                                                    int32_t rust_vec_len_,
+                                                                                                                    int32_t data_len_);
+
 void frbgen_frb_example_pure_dart_wire__crate__api__dyn_trait__func_arg_dyn_trait_twin_normal(int64_t port_,
                                                                                               uint8_t *ptr_,
                                                                                               int32_t rust_vec_len_,
@@ -3854,8 +4178,15 @@ void frbgen_frb_example_pure_dart_wire__crate__api__exception__some_struct_twin_
 void frbgen_frb_example_pure_dart_wire__crate__api__exception__stream_sink_throw_anyhow_twin_normal(int64_t port_,
                                                                                                     struct wire_cst_list_prim_u_8_strict *_sink);
 
+void frbgen_frb_example_pure_dart_wire__crate__api__exception__throw_anyhow_io_error_twin_normal(int64_t port_);
+
 void frbgen_frb_example_pure_dart_wire__crate__api__exception__throw_anyhow_twin_normal(int64_t port_);
 
+void frbgen_frb_example_pure_dart_wire__crate__api__exception__throw_anyhow_with_context_twin_normal(int64_t port_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__exception__typed_exception_return_error_twin_normal(int64_t port_,
+                                                                                                        int32_t variant);
+
 WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__external_impl__SimpleOpaqueExternalStructWithMethod_new(struct wire_cst_list_prim_u_8_strict *a);
 
 void frbgen_frb_example_pure_dart_wire__crate__api__external_impl__SimpleOpaqueExternalStructWithMethod_simple_external_method(int64_t port_,
@@ -3912,6 +4243,31 @@ void frbgen_frb_example_pure_dart_wire__crate__api__inside_macro__another_macro_
 void frbgen_frb_example_pure_dart_wire__crate__api__inside_macro__func_macro_struct_twin_normal(int64_t port_,
                                                                                                 struct wire_cst_macro_struct *arg);
 
+void frbgen_frb_example_pure_dart_wire__crate__api__json_type__create_json_map_twin_normal(int64_t port_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__json_type__create_json_value_twin_normal(int64_t port_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__json_type__json_document_twin_normal(int64_t port_,
+                                                                                         struct wire_cst_json_document_twin_normal *document);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__json_type__json_map_keys_twin_normal(int64_t port_,
+                                                                                         struct wire_cst_list_prim_u_8_strict *map);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__json_type__json_map_twin_normal(int64_t port_,
+                                                                                    struct wire_cst_list_prim_u_8_strict *map);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__json_type__json_value_to_string_twin_normal(int64_t port_,
+                                                                                                struct wire_cst_list_prim_u_8_strict *value);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__json_type__json_value_twin_normal(int64_t port_,
+                                                                                      struct wire_cst_list_prim_u_8_strict *value);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__json_type__json_values_twin_normal(int64_t port_,
+                                                                                       struct wire_cst_list_JsonValue *values);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__json_type__optional_json_value_twin_normal(int64_t port_,
+                                                                                               struct wire_cst_list_prim_u_8_strict *value);
+
 void frbgen_frb_example_pure_dart_wire__crate__api__lifetimeable__Lifetimeable_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLtNestedTypeWithLifetimeTwinNormalstatic_greet_borrow_mut_self_twin_normal(int64_t port_,
                                                                                                                                                                                                                                    uint8_t *ptr_,
                                                                                                                                                                                                                                    int32_t rust_vec_len_,
@@ -3992,6 +4348,18 @@ void frbgen_frb_example_pure_dart_wire__crate__api__lifetimeable__lt_compute_wit
                                                                                                                 int32_t rust_vec_len_,
                                                                                                                 int32_t data_len_);
 
+void frbgen_frb_example_pure_dart_wire__crate__api__map_and_set__func_btree_map_i32_i32_twin_normal(int64_t port_,
+                                                                                                    struct wire_cst_list_record_i_32_i_32 *arg);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__map_and_set__func_btree_map_string_struct_twin_normal(int64_t port_,
+                                                                                                          struct wire_cst_list_record_string_my_size *arg);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__map_and_set__func_btree_set_i32_twin_normal(int64_t port_,
+                                                                                                struct wire_cst_list_prim_i_32_strict *arg);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__map_and_set__func_btree_set_string_twin_normal(int64_t port_,
+                                                                                                   struct wire_cst_list_String *arg);
+
 void frbgen_frb_example_pure_dart_wire__crate__api__map_and_set__func_hash_map_i32_i32_twin_normal(int64_t port_,
                                                                                                    struct wire_cst_list_record_i_32_i_32 *arg);
 
@@ -4016,6 +4384,12 @@ void frbgen_frb_example_pure_dart_wire__crate__api__map_and_set__func_hash_set_i
 void frbgen_frb_example_pure_dart_wire__crate__api__map_and_set__func_hash_set_string_twin_normal(int64_t port_,
                                                                                                   struct wire_cst_list_String *arg);
 
+void frbgen_frb_example_pure_dart_wire__crate__api__map_and_set__func_vec_deque_i32_twin_normal(int64_t port_,
+                                                                                                struct wire_cst_list_prim_i_32_strict *arg);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__map_and_set__func_vec_deque_string_twin_normal(int64_t port_,
+                                                                                                   struct wire_cst_list_String *arg);
+
 void frbgen_frb_example_pure_dart_wire__crate__api__method__StaticGetterOnlyTwinNormal_static_getter_twin_normal(int64_t port_);
 
 void frbgen_frb_example_pure_dart_wire__crate__api__method__concatenate_with_twin_normal_concatenate_static_twin_normal(int64_t port_,
@@ -4598,6 +4972,11 @@ WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manua
                                                                                                                                 int32_t rust_vec_len_,
                                                                                                                                 int32_t data_len_);
 
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__async_misc_twin_sse__func_async_never_complete_twin_sse(int64_t port_,
+                                                                                                                           uint8_t *ptr_,
+                                                                                                                           int32_t rust_vec_len_,
+                                                                                                                           int32_t data_len_);
+
 void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__async_misc_twin_sse__func_async_simple_add_twin_sse(int64_t port_,
                                                                                                                        uint8_t *ptr_,
                                                                                                                        int32_t rust_vec_len_,
@@ -4608,6 +4987,11 @@ void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__async_misc_tw
                                                                                                                  int32_t rust_vec_len_,
                                                                                                                  int32_t data_len_);
 
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__async_misc_twin_sse__func_async_wait_for_cancellation_twin_sse(int64_t port_,
+                                                                                                                                  uint8_t *ptr_,
+                                                                                                                                  int32_t rust_vec_len_,
+                                                                                                                                  int32_t data_len_);
+
 void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__attribute_twin_rust_async__handle_customized_struct_twin_rust_async(int64_t port_,
                                                                                                                                        struct wire_cst_customized_twin_rust_async *val);
 
@@ -6682,76 +7066,307 @@ WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manua
                                                                                                                                              int32_t rust_vec_len_,
                                                                                                                                              int32_t data_len_);
 
-void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__datetime_local_twin_rust_async(int64_t port_,
-                                                                                                                               int64_t d);
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_rust_async__add_decimals_twin_rust_async(int64_t port_,
+                                                                                                                            struct wire_cst_list_prim_u_8_strict *a,
+                                                                                                                            struct wire_cst_list_prim_u_8_strict *b);
 
-void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__datetime_utc_twin_rust_async(int64_t port_,
-                                                                                                                             int64_t d);
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_rust_async__big_int_twin_rust_async(int64_t port_,
+                                                                                                                       struct wire_cst_list_prim_u_8_strict *i);
 
-void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__duration_twin_rust_async(int64_t port_,
-                                                                                                                         int64_t d);
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_rust_async__big_ints_twin_rust_async(int64_t port_,
+                                                                                                                        struct wire_cst_list_BigInt *ints);
 
-void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__handle_durations_twin_rust_async(int64_t port_,
-                                                                                                                                 struct wire_cst_list_Chrono_Duration *durations,
-                                                                                                                                 int64_t since);
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_rust_async__big_numbers_twin_rust_async(int64_t port_,
+                                                                                                                           struct wire_cst_big_numbers_twin_rust_async *value);
 
-void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__handle_timestamps_twin_rust_async(int64_t port_,
-                                                                                                                                  struct wire_cst_list_Chrono_Naive *timestamps,
-                                                                                                                                  int64_t epoch);
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_rust_async__decimal_twin_rust_async(int64_t port_,
+                                                                                                                       struct wire_cst_list_prim_u_8_strict *d);
 
-void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__how_long_does_it_take_twin_rust_async(int64_t port_,
-                                                                                                                                      struct wire_cst_feature_chrono_twin_rust_async *mine);
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_rust_async__decimals_twin_rust_async(int64_t port_,
+                                                                                                                        struct wire_cst_list_Decimal *decimals);
 
-void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__naivedatetime_twin_rust_async(int64_t port_,
-                                                                                                                              int64_t d);
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_rust_async__multiply_big_ints_twin_rust_async(int64_t port_,
+                                                                                                                                 struct wire_cst_list_prim_u_8_strict *a,
+                                                                                                                                 struct wire_cst_list_prim_u_8_strict *b);
 
-void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__optional_empty_datetime_utc_twin_rust_async(int64_t port_,
-                                                                                                                                            int64_t *d);
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_rust_async__optional_big_int_twin_rust_async(int64_t port_,
+                                                                                                                                struct wire_cst_list_prim_u_8_strict *i);
 
-void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__test_chrono_twin_rust_async(int64_t port_);
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_rust_async__optional_decimal_twin_rust_async(int64_t port_,
+                                                                                                                                struct wire_cst_list_prim_u_8_strict *d);
 
-void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__test_precise_chrono_twin_rust_async(int64_t port_);
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_rust_async_sse__add_decimals_twin_rust_async_sse(int64_t port_,
+                                                                                                                                    uint8_t *ptr_,
+                                                                                                                                    int32_t rust_vec_len_,
+                                                                                                                                    int32_t data_len_);
 
-void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__datetime_local_twin_rust_async_sse(int64_t port_,
-                                                                                                                                       uint8_t *ptr_,
-                                                                                                                                       int32_t rust_vec_len_,
-                                                                                                                                       int32_t data_len_);
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_rust_async_sse__big_int_twin_rust_async_sse(int64_t port_,
+                                                                                                                               uint8_t *ptr_,
+                                                                                                                               int32_t rust_vec_len_,
+                                                                                                                               int32_t data_len_);
 
-void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__datetime_utc_twin_rust_async_sse(int64_t port_,
-                                                                                                                                     uint8_t *ptr_,
-                                                                                                                                     int32_t rust_vec_len_,
-                                                                                                                                     int32_t data_len_);
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_rust_async_sse__big_ints_twin_rust_async_sse(int64_t port_,
+                                                                                                                                uint8_t *ptr_,
+                                                                                                                                int32_t rust_vec_len_,
+                                                                                                                                int32_t data_len_);
 
-void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__duration_twin_rust_async_sse(int64_t port_,
-                                                                                                                                 uint8_t *ptr_,
-                                                                                                                                 int32_t rust_vec_len_,
-                                                                                                                                 int32_t data_len_);
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_rust_async_sse__big_numbers_twin_rust_async_sse(int64_t port_,
+                                                                                                                                   uint8_t *ptr_,
+                                                                                                                                   int32_t rust_vec_len_,
+                                                                                                                                   int32_t data_len_);
 
-void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__handle_durations_twin_rust_async_sse(int64_t port_,
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_rust_async_sse__decimal_twin_rust_async_sse(int64_t port_,
+                                                                                                                               uint8_t *ptr_,
+                                                                                                                               int32_t rust_vec_len_,
+                                                                                                                               int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_rust_async_sse__decimals_twin_rust_async_sse(int64_t port_,
+                                                                                                                                uint8_t *ptr_,
+                                                                                                                                int32_t rust_vec_len_,
+                                                                                                                                int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_rust_async_sse__multiply_big_ints_twin_rust_async_sse(int64_t port_,
                                                                                                                                          uint8_t *ptr_,
                                                                                                                                          int32_t rust_vec_len_,
                                                                                                                                          int32_t data_len_);
 
-void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__handle_timestamps_twin_rust_async_sse(int64_t port_,
-                                                                                                                                          uint8_t *ptr_,
-                                                                                                                                          int32_t rust_vec_len_,
-                                                                                                                                          int32_t data_len_);
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_rust_async_sse__optional_big_int_twin_rust_async_sse(int64_t port_,
+                                                                                                                                        uint8_t *ptr_,
+                                                                                                                                        int32_t rust_vec_len_,
+                                                                                                                                        int32_t data_len_);
 
-void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__how_long_does_it_take_twin_rust_async_sse(int64_t port_,
-                                                                                                                                              uint8_t *ptr_,
-                                                                                                                                              int32_t rust_vec_len_,
-                                                                                                                                              int32_t data_len_);
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_rust_async_sse__optional_decimal_twin_rust_async_sse(int64_t port_,
+                                                                                                                                        uint8_t *ptr_,
+                                                                                                                                        int32_t rust_vec_len_,
+                                                                                                                                        int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_sse__add_decimals_twin_sse(int64_t port_,
+                                                                                                              uint8_t *ptr_,
+                                                                                                              int32_t rust_vec_len_,
+                                                                                                              int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_sse__big_int_twin_sse(int64_t port_,
+                                                                                                         uint8_t *ptr_,
+                                                                                                         int32_t rust_vec_len_,
+                                                                                                         int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_sse__big_ints_twin_sse(int64_t port_,
+                                                                                                          uint8_t *ptr_,
+                                                                                                          int32_t rust_vec_len_,
+                                                                                                          int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_sse__big_numbers_twin_sse(int64_t port_,
+                                                                                                             uint8_t *ptr_,
+                                                                                                             int32_t rust_vec_len_,
+                                                                                                             int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_sse__decimal_twin_sse(int64_t port_,
+                                                                                                         uint8_t *ptr_,
+                                                                                                         int32_t rust_vec_len_,
+                                                                                                         int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_sse__decimals_twin_sse(int64_t port_,
+                                                                                                          uint8_t *ptr_,
+                                                                                                          int32_t rust_vec_len_,
+                                                                                                          int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_sse__multiply_big_ints_twin_sse(int64_t port_,
+                                                                                                                   uint8_t *ptr_,
+                                                                                                                   int32_t rust_vec_len_,
+                                                                                                                   int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_sse__optional_big_int_twin_sse(int64_t port_,
+                                                                                                                  uint8_t *ptr_,
+                                                                                                                  int32_t rust_vec_len_,
+                                                                                                                  int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_sse__optional_decimal_twin_sse(int64_t port_,
+                                                                                                                  uint8_t *ptr_,
+                                                                                                                  int32_t rust_vec_len_,
+                                                                                                                  int32_t data_len_);
+
+WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_sync__add_decimals_twin_sync(struct wire_cst_list_prim_u_8_strict *a,
+                                                                                                                                struct wire_cst_list_prim_u_8_strict *b);
+
+WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_sync__big_int_twin_sync(struct wire_cst_list_prim_u_8_strict *i);
+
+WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_sync__big_ints_twin_sync(struct wire_cst_list_BigInt *ints);
+
+WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_sync__big_numbers_twin_sync(struct wire_cst_big_numbers_twin_sync *value);
+
+WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_sync__decimal_twin_sync(struct wire_cst_list_prim_u_8_strict *d);
+
+WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_sync__decimals_twin_sync(struct wire_cst_list_Decimal *decimals);
+
+WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_sync__multiply_big_ints_twin_sync(struct wire_cst_list_prim_u_8_strict *a,
+                                                                                                                                     struct wire_cst_list_prim_u_8_strict *b);
+
+WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_sync__optional_big_int_twin_sync(struct wire_cst_list_prim_u_8_strict *i);
+
+WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_sync__optional_decimal_twin_sync(struct wire_cst_list_prim_u_8_strict *d);
+
+WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_sync_sse__add_decimals_twin_sync_sse(uint8_t *ptr_,
+                                                                                                                                        int32_t rust_vec_len_,
+                                                                                                                                        int32_t data_len_);
+
+WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_sync_sse__big_int_twin_sync_sse(uint8_t *ptr_,
+                                                                                                                                   int32_t rust_vec_len_,
+                                                                                                                                   int32_t data_len_);
+
+WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_sync_sse__big_ints_twin_sync_sse(uint8_t *ptr_,
+                                                                                                                                    int32_t rust_vec_len_,
+                                                                                                                                    int32_t data_len_);
+
+WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_sync_sse__big_numbers_twin_sync_sse(uint8_t *ptr_,
+                                                                                                                                       int32_t rust_vec_len_,
+                                                                                                                                       int32_t data_len_);
+
+WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_sync_sse__decimal_twin_sync_sse(uint8_t *ptr_,
+                                                                                                                                   int32_t rust_vec_len_,
+                                                                                                                                   int32_t data_len_);
+
+WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_sync_sse__decimals_twin_sync_sse(uint8_t *ptr_,
+                                                                                                                                    int32_t rust_vec_len_,
+                                                                                                                                    int32_t data_len_);
+
+WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_sync_sse__multiply_big_ints_twin_sync_sse(uint8_t *ptr_,
+                                                                                                                                             int32_t rust_vec_len_,
+                                                                                                                                             int32_t data_len_);
+
+WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_sync_sse__optional_big_int_twin_sync_sse(uint8_t *ptr_,
+                                                                                                                                            int32_t rust_vec_len_,
+                                                                                                                                            int32_t data_len_);
+
+WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_sync_sse__optional_decimal_twin_sync_sse(uint8_t *ptr_,
+                                                                                                                                            int32_t rust_vec_len_,
+                                                                                                                                            int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__datetime_fixed_offset_twin_rust_async(int64_t port_,
+                                                                                                                                      struct wire_cst_list_prim_u_8_strict *d);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__datetime_fixed_offsets_twin_rust_async(int64_t port_,
+                                                                                                                                       struct wire_cst_list_Chrono_FixedOffset *dates);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__datetime_local_twin_rust_async(int64_t port_,
+                                                                                                                               int64_t d);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__datetime_utc_twin_rust_async(int64_t port_,
+                                                                                                                             int64_t d);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__duration_twin_rust_async(int64_t port_,
+                                                                                                                         int64_t d);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__handle_durations_twin_rust_async(int64_t port_,
+                                                                                                                                 struct wire_cst_list_Chrono_Duration *durations,
+                                                                                                                                 int64_t since);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__handle_timestamps_twin_rust_async(int64_t port_,
+                                                                                                                                  struct wire_cst_list_Chrono_Naive *timestamps,
+                                                                                                                                  int64_t epoch);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__how_long_does_it_take_twin_rust_async(int64_t port_,
+                                                                                                                                      struct wire_cst_feature_chrono_twin_rust_async *mine);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__naive_date_twin_rust_async(int64_t port_,
+                                                                                                                           int64_t d);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__naive_dates_twin_rust_async(int64_t port_,
+                                                                                                                            struct wire_cst_list_Chrono_NaiveDate *dates);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__naive_time_twin_rust_async(int64_t port_,
+                                                                                                                           int64_t d);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__naivedatetime_twin_rust_async(int64_t port_,
+                                                                                                                              int64_t d);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__optional_datetime_fixed_offset_twin_rust_async(int64_t port_,
+                                                                                                                                               struct wire_cst_list_prim_u_8_strict *d);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__optional_empty_datetime_utc_twin_rust_async(int64_t port_,
+                                                                                                                                            int64_t *d);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__schedule_twin_rust_async(int64_t port_,
+                                                                                                                         struct wire_cst_schedule_twin_rust_async *schedule);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__test_chrono_twin_rust_async(int64_t port_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__test_precise_chrono_twin_rust_async(int64_t port_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__datetime_fixed_offset_twin_rust_async_sse(int64_t port_,
+                                                                                                                                              uint8_t *ptr_,
+                                                                                                                                              int32_t rust_vec_len_,
+                                                                                                                                              int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__datetime_fixed_offsets_twin_rust_async_sse(int64_t port_,
+                                                                                                                                               uint8_t *ptr_,
+                                                                                                                                               int32_t rust_vec_len_,
+                                                                                                                                               int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__datetime_local_twin_rust_async_sse(int64_t port_,
+                                                                                                                                       uint8_t *ptr_,
+                                                                                                                                       int32_t rust_vec_len_,
+                                                                                                                                       int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__datetime_utc_twin_rust_async_sse(int64_t port_,
+                                                                                                                                     uint8_t *ptr_,
+                                                                                                                                     int32_t rust_vec_len_,
+                                                                                                                                     int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__duration_twin_rust_async_sse(int64_t port_,
+                                                                                                                                 uint8_t *ptr_,
+                                                                                                                                 int32_t rust_vec_len_,
+                                                                                                                                 int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__handle_durations_twin_rust_async_sse(int64_t port_,
+                                                                                                                                         uint8_t *ptr_,
+                                                                                                                                         int32_t rust_vec_len_,
+                                                                                                                                         int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__handle_timestamps_twin_rust_async_sse(int64_t port_,
+                                                                                                                                          uint8_t *ptr_,
+                                                                                                                                          int32_t rust_vec_len_,
+                                                                                                                                          int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__how_long_does_it_take_twin_rust_async_sse(int64_t port_,
+                                                                                                                                              uint8_t *ptr_,
+                                                                                                                                              int32_t rust_vec_len_,
+                                                                                                                                              int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__naive_date_twin_rust_async_sse(int64_t port_,
+                                                                                                                                   uint8_t *ptr_,
+                                                                                                                                   int32_t rust_vec_len_,
+                                                                                                                                   int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__naive_dates_twin_rust_async_sse(int64_t port_,
+                                                                                                                                    uint8_t *ptr_,
+                                                                                                                                    int32_t rust_vec_len_,
+                                                                                                                                    int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__naive_time_twin_rust_async_sse(int64_t port_,
+                                                                                                                                   uint8_t *ptr_,
+                                                                                                                                   int32_t rust_vec_len_,
+                                                                                                                                   int32_t data_len_);
 
 void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__naivedatetime_twin_rust_async_sse(int64_t port_,
                                                                                                                                       uint8_t *ptr_,
                                                                                                                                       int32_t rust_vec_len_,
                                                                                                                                       int32_t data_len_);
 
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__optional_datetime_fixed_offset_twin_rust_async_sse(int64_t port_,
+                                                                                                                                                       uint8_t *ptr_,
+                                                                                                                                                       int32_t rust_vec_len_,
+                                                                                                                                                       int32_t data_len_);
+
 void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__optional_empty_datetime_utc_twin_rust_async_sse(int64_t port_,
                                                                                                                                                     uint8_t *ptr_,
                                                                                                                                                     int32_t rust_vec_len_,
                                                                                                                                                     int32_t data_len_);
 
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__schedule_twin_rust_async_sse(int64_t port_,
+                                                                                                                                 uint8_t *ptr_,
+                                                                                                                                 int32_t rust_vec_len_,
+                                                                                                                                 int32_t data_len_);
+
 void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__test_chrono_twin_rust_async_sse(int64_t port_,
                                                                                                                                     uint8_t *ptr_,
                                                                                                                                     int32_t rust_vec_len_,
@@ -6762,6 +7377,16 @@ void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_t
                                                                                                                                             int32_t rust_vec_len_,
                                                                                                                                             int32_t data_len_);
 
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sse__datetime_fixed_offset_twin_sse(int64_t port_,
+                                                                                                                        uint8_t *ptr_,
+                                                                                                                        int32_t rust_vec_len_,
+                                                                                                                        int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sse__datetime_fixed_offsets_twin_sse(int64_t port_,
+                                                                                                                         uint8_t *ptr_,
+                                                                                                                         int32_t rust_vec_len_,
+                                                                                                                         int32_t data_len_);
+
 void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sse__datetime_local_twin_sse(int64_t port_,
                                                                                                                  uint8_t *ptr_,
                                                                                                                  int32_t rust_vec_len_,
@@ -6792,16 +7417,41 @@ void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_t
                                                                                                                         int32_t rust_vec_len_,
                                                                                                                         int32_t data_len_);
 
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sse__naive_date_twin_sse(int64_t port_,
+                                                                                                             uint8_t *ptr_,
+                                                                                                             int32_t rust_vec_len_,
+                                                                                                             int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sse__naive_dates_twin_sse(int64_t port_,
+                                                                                                              uint8_t *ptr_,
+                                                                                                              int32_t rust_vec_len_,
+                                                                                                              int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sse__naive_time_twin_sse(int64_t port_,
+                                                                                                             uint8_t *ptr_,
+                                                                                                             int32_t rust_vec_len_,
+                                                                                                             int32_t data_len_);
+
 void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sse__naivedatetime_twin_sse(int64_t port_,
                                                                                                                 uint8_t *ptr_,
                                                                                                                 int32_t rust_vec_len_,
                                                                                                                 int32_t data_len_);
 
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sse__optional_datetime_fixed_offset_twin_sse(int64_t port_,
+                                                                                                                                 uint8_t *ptr_,
+                                                                                                                                 int32_t rust_vec_len_,
+                                                                                                                                 int32_t data_len_);
+
 void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sse__optional_empty_datetime_utc_twin_sse(int64_t port_,
                                                                                                                               uint8_t *ptr_,
                                                                                                                               int32_t rust_vec_len_,
                                                                                                                               int32_t data_len_);
 
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sse__schedule_twin_sse(int64_t port_,
+                                                                                                           uint8_t *ptr_,
+                                                                                                           int32_t rust_vec_len_,
+                                                                                                           int32_t data_len_);
+
 void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sse__test_chrono_twin_sse(int64_t port_,
                                                                                                               uint8_t *ptr_,
                                                                                                               int32_t rust_vec_len_,
@@ -6812,6 +7462,10 @@ void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_t
                                                                                                                       int32_t rust_vec_len_,
                                                                                                                       int32_t data_len_);
 
+WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sync__datetime_fixed_offset_twin_sync(struct wire_cst_list_prim_u_8_strict *d);
+
+WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sync__datetime_fixed_offsets_twin_sync(struct wire_cst_list_Chrono_FixedOffset *dates);
+
 WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sync__datetime_local_twin_sync(int64_t d);
 
 WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sync__datetime_utc_twin_sync(int64_t d);
@@ -6826,14 +7480,32 @@ WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manua
 
 WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sync__how_long_does_it_take_twin_sync(struct wire_cst_feature_chrono_twin_sync *mine);
 
+WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sync__naive_date_twin_sync(int64_t d);
+
+WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sync__naive_dates_twin_sync(struct wire_cst_list_Chrono_NaiveDate *dates);
+
+WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sync__naive_time_twin_sync(int64_t d);
+
 WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sync__naivedatetime_twin_sync(int64_t d);
 
+WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sync__optional_datetime_fixed_offset_twin_sync(struct wire_cst_list_prim_u_8_strict *d);
+
 WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sync__optional_empty_datetime_utc_twin_sync(int64_t *d);
 
+WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sync__schedule_twin_sync(struct wire_cst_schedule_twin_sync *schedule);
+
 WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sync__test_chrono_twin_sync(void);
 
 WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sync__test_precise_chrono_twin_sync(void);
 
+WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sync_sse__datetime_fixed_offset_twin_sync_sse(uint8_t *ptr_,
+                                                                                                                                                  int32_t rust_vec_len_,
+                                                                                                                                                  int32_t data_len_);
+
+WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sync_sse__datetime_fixed_offsets_twin_sync_sse(uint8_t *ptr_,
+                                                                                                                                                   int32_t rust_vec_len_,
+                                                                                                                                                   int32_t data_len_);
+
 WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sync_sse__datetime_local_twin_sync_sse(uint8_t *ptr_,
                                                                                                                                            int32_t rust_vec_len_,
                                                                                                                                            int32_t data_len_);
@@ -6858,14 +7530,34 @@ WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manua
                                                                                                                                                   int32_t rust_vec_len_,
                                                                                                                                                   int32_t data_len_);
 
+WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sync_sse__naive_date_twin_sync_sse(uint8_t *ptr_,
+                                                                                                                                       int32_t rust_vec_len_,
+                                                                                                                                       int32_t data_len_);
+
+WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sync_sse__naive_dates_twin_sync_sse(uint8_t *ptr_,
+                                                                                                                                        int32_t rust_vec_len_,
+                                                                                                                                        int32_t data_len_);
+
+WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sync_sse__naive_time_twin_sync_sse(uint8_t *ptr_,
+                                                                                                                                       int32_t rust_vec_len_,
+                                                                                                                                       int32_t data_len_);
+
 WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sync_sse__naivedatetime_twin_sync_sse(uint8_t *ptr_,
                                                                                                                                           int32_t rust_vec_len_,
                                                                                                                                           int32_t data_len_);
 
+WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sync_sse__optional_datetime_fixed_offset_twin_sync_sse(uint8_t *ptr_,
+                                                                                                                                                           int32_t rust_vec_len_,
+                                                                                                                                                           int32_t data_len_);
+
 WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sync_sse__optional_empty_datetime_utc_twin_sync_sse(uint8_t *ptr_,
                                                                                                                                                         int32_t rust_vec_len_,
                                                                                                                                                         int32_t data_len_);
 
+WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sync_sse__schedule_twin_sync_sse(uint8_t *ptr_,
+                                                                                                                                     int32_t rust_vec_len_,
+                                                                                                                                     int32_t data_len_);
+
 WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sync_sse__test_chrono_twin_sync_sse(uint8_t *ptr_,
                                                                                                                                         int32_t rust_vec_len_,
                                                                                                                                         int32_t data_len_);
@@ -6999,6 +7691,13 @@ void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__dart_fn_twin_
 void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__dart_fn_twin_rust_async__rust_call_dart_with_dart_opaque_result_twin_rust_async(int64_t port_,
                                                                                                                                                    const void *callback);
 
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__dart_fn_twin_rust_async__rust_consume_dart_stream_sum_twin_rust_async(int64_t port_,
+                                                                                                                                         const void *stream);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__dart_fn_twin_rust_async__rust_consume_dart_stream_take_twin_rust_async(int64_t port_,
+                                                                                                                                          const void *stream,
+                                                                                                                                          uintptr_t count);
+
 void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__dart_fn_twin_rust_async_sse__rust_call_dart_loopback_twin_rust_async_sse(int64_t port_,
                                                                                                                                             uint8_t *ptr_,
                                                                                                                                             int32_t rust_vec_len_,
@@ -7044,6 +7743,16 @@ void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__dart_fn_twin_
                                                                                                                                                            int32_t rust_vec_len_,
                                                                                                                                                            int32_t data_len_);
 
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__dart_fn_twin_rust_async_sse__rust_consume_dart_stream_sum_twin_rust_async_sse(int64_t port_,
+                                                                                                                                                 uint8_t *ptr_,
+                                                                                                                                                 int32_t rust_vec_len_,
+                                                                                                                                                 int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__dart_fn_twin_rust_async_sse__rust_consume_dart_stream_take_twin_rust_async_sse(int64_t port_,
+                                                                                                                                                  uint8_t *ptr_,
+                                                                                                                                                  int32_t rust_vec_len_,
+                                                                                                                                                  int32_t data_len_);
+
 void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__dart_fn_twin_sse__rust_call_dart_loopback_twin_sse(int64_t port_,
                                                                                                                       uint8_t *ptr_,
                                                                                                                       int32_t rust_vec_len_,
@@ -7089,6 +7798,16 @@ void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__dart_fn_twin_
                                                                                                                                      int32_t rust_vec_len_,
                                                                                                                                      int32_t data_len_);
 
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__dart_fn_twin_sse__rust_consume_dart_stream_sum_twin_sse(int64_t port_,
+                                                                                                                           uint8_t *ptr_,
+                                                                                                                           int32_t rust_vec_len_,
+                                                                                                                           int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__dart_fn_twin_sse__rust_consume_dart_stream_take_twin_sse(int64_t port_,
+                                                                                                                            uint8_t *ptr_,
+                                                                                                                            int32_t rust_vec_len_,
+                                                                                                                            int32_t data_len_);
+
 WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__dart_opaque_sync_twin_sse__sync_accept_dart_opaque_twin_sse(uint8_t *ptr_,
                                                                                                                                                int32_t rust_vec_len_,
                                                                                                                                                int32_t data_len_);
@@ -7802,8 +8521,15 @@ void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twi
 void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_rust_async__stream_sink_throw_anyhow_twin_rust_async(int64_t port_,
                                                                                                                                        struct wire_cst_list_prim_u_8_strict *_sink);
 
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_rust_async__throw_anyhow_io_error_twin_rust_async(int64_t port_);
+
 void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_rust_async__throw_anyhow_twin_rust_async(int64_t port_);
 
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_rust_async__throw_anyhow_with_context_twin_rust_async(int64_t port_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_rust_async__typed_exception_return_error_twin_rust_async(int64_t port_,
+                                                                                                                                           int32_t variant);
+
 void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_rust_async_sse__custom_enum_error_panic_twin_rust_async_sse(int64_t port_,
                                                                                                                                               uint8_t *ptr_,
                                                                                                                                               int32_t rust_vec_len_,
@@ -7944,11 +8670,26 @@ void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twi
                                                                                                                                                int32_t rust_vec_len_,
                                                                                                                                                int32_t data_len_);
 
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_rust_async_sse__throw_anyhow_io_error_twin_rust_async_sse(int64_t port_,
+                                                                                                                                            uint8_t *ptr_,
+                                                                                                                                            int32_t rust_vec_len_,
+                                                                                                                                            int32_t data_len_);
+
 void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_rust_async_sse__throw_anyhow_twin_rust_async_sse(int64_t port_,
                                                                                                                                    uint8_t *ptr_,
                                                                                                                                    int32_t rust_vec_len_,
                                                                                                                                    int32_t data_len_);
 
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_rust_async_sse__throw_anyhow_with_context_twin_rust_async_sse(int64_t port_,
+                                                                                                                                                uint8_t *ptr_,
+                                                                                                                                                int32_t rust_vec_len_,
+                                                                                                                                                int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_rust_async_sse__typed_exception_return_error_twin_rust_async_sse(int64_t port_,
+                                                                                                                                                   uint8_t *ptr_,
+                                                                                                                                                   int32_t rust_vec_len_,
+                                                                                                                                                   int32_t data_len_);
+
 void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_sse__custom_enum_error_panic_twin_sse(int64_t port_,
                                                                                                                         uint8_t *ptr_,
                                                                                                                         int32_t rust_vec_len_,
@@ -8089,11 +8830,26 @@ void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twi
                                                                                                                          int32_t rust_vec_len_,
                                                                                                                          int32_t data_len_);
 
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_sse__throw_anyhow_io_error_twin_sse(int64_t port_,
+                                                                                                                      uint8_t *ptr_,
+                                                                                                                      int32_t rust_vec_len_,
+                                                                                                                      int32_t data_len_);
+
 void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_sse__throw_anyhow_twin_sse(int64_t port_,
                                                                                                              uint8_t *ptr_,
                                                                                                              int32_t rust_vec_len_,
                                                                                                              int32_t data_len_);
 
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_sse__throw_anyhow_with_context_twin_sse(int64_t port_,
+                                                                                                                          uint8_t *ptr_,
+                                                                                                                          int32_t rust_vec_len_,
+                                                                                                                          int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_sse__typed_exception_return_error_twin_sse(int64_t port_,
+                                                                                                                             uint8_t *ptr_,
+                                                                                                                             int32_t rust_vec_len_,
+                                                                                                                             int32_t data_len_);
+
 WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_sync__custom_enum_error_panic_twin_sync(void);
 
 WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_sync__custom_enum_error_return_error_twin_sync(void);
@@ -8150,8 +8906,14 @@ WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manua
 
 WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_sync__stream_sink_throw_anyhow_twin_sync(struct wire_cst_list_prim_u_8_strict *_sink);
 
+WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_sync__throw_anyhow_io_error_twin_sync(void);
+
 WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_sync__throw_anyhow_twin_sync(void);
 
+WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_sync__throw_anyhow_with_context_twin_sync(void);
+
+WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_sync__typed_exception_return_error_twin_sync(int32_t variant);
+
 WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_sync_sse__custom_enum_error_panic_twin_sync_sse(uint8_t *ptr_,
                                                                                                                                                   int32_t rust_vec_len_,
                                                                                                                                                   int32_t data_len_);
@@ -8264,10 +9026,22 @@ WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manua
                                                                                                                                                    int32_t rust_vec_len_,
                                                                                                                                                    int32_t data_len_);
 
+WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_sync_sse__throw_anyhow_io_error_twin_sync_sse(uint8_t *ptr_,
+                                                                                                                                                int32_t rust_vec_len_,
+                                                                                                                                                int32_t data_len_);
+
 WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_sync_sse__throw_anyhow_twin_sync_sse(uint8_t *ptr_,
                                                                                                                                        int32_t rust_vec_len_,
                                                                                                                                        int32_t data_len_);
 
+WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_sync_sse__throw_anyhow_with_context_twin_sync_sse(uint8_t *ptr_,
+                                                                                                                                                    int32_t rust_vec_len_,
+                                                                                                                                                    int32_t data_len_);
+
+WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_sync_sse__typed_exception_return_error_twin_sync_sse(uint8_t *ptr_,
+                                                                                                                                                       int32_t rust_vec_len_,
+                                                                                                                                                       int32_t data_len_);
+
 void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__external_type_in_crate_twin_rust_async__call_new_module_system_twin_rust_async(int64_t port_);
 
 void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__external_type_in_crate_twin_rust_async__call_old_module_system_twin_rust_async(int64_t port_);
@@ -8412,34 +9186,203 @@ void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__impl_trait_tw
                                                                                                                                                                uintptr_t that,
                                                                                                                                                                uintptr_t another);
 
-void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__impl_trait_twin_sync_sse__MyImplTraitWithSelfTwinSyncSse_method_with_good_self_twin_sync_sse(int64_t port_,
-                                                                                                                                                                uintptr_t that);
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__impl_trait_twin_sync_sse__MyImplTraitWithSelfTwinSyncSse_method_with_good_self_twin_sync_sse(int64_t port_,
+                                                                                                                                                                uintptr_t that);
+
+WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__impl_trait_twin_sync_sse__StructOneWithTraitTwinSyncSse_auto_accessor_get_one(uintptr_t that);
+
+WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__impl_trait_twin_sync_sse__StructOneWithTraitTwinSyncSse_auto_accessor_set_one(uintptr_t that,
+                                                                                                                                                                 int32_t one);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__impl_trait_twin_sync_sse__StructOneWithTraitTwinSyncSse_simple_trait_fn_receiver_borrow_twin_sync_sse(int64_t port_,
+                                                                                                                                                                         uintptr_t that);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__impl_trait_twin_sync_sse__StructOneWithTraitTwinSyncSse_simple_trait_fn_twin_sync_sse(int64_t port_,
+                                                                                                                                                         int32_t value);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__impl_trait_twin_sync_sse__StructOneWithTraitTwinSyncSse_simple_trait_fn_with_default_impl_twin_sync_sse(int64_t port_);
+
+WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__impl_trait_twin_sync_sse__StructTwoWithTraitTwinSyncSse_auto_accessor_get_two(uintptr_t that);
+
+WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__impl_trait_twin_sync_sse__StructTwoWithTraitTwinSyncSse_auto_accessor_set_two(uintptr_t that,
+                                                                                                                                                                 int32_t two);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__impl_trait_twin_sync_sse__StructTwoWithTraitTwinSyncSse_simple_trait_fn_receiver_borrow_twin_sync_sse(int64_t port_,
+                                                                                                                                                                         uintptr_t that);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__impl_trait_twin_sync_sse__StructTwoWithTraitTwinSyncSse_simple_trait_fn_twin_sync_sse(int64_t port_,
+                                                                                                                                                         int32_t value);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__impl_trait_twin_sync_sse__StructTwoWithTraitTwinSyncSse_simple_trait_fn_with_default_impl_twin_sync_sse(int64_t port_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_rust_async__create_json_map_twin_rust_async(int64_t port_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_rust_async__create_json_value_twin_rust_async(int64_t port_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_rust_async__json_document_twin_rust_async(int64_t port_,
+                                                                                                                            struct wire_cst_json_document_twin_rust_async *document);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_rust_async__json_map_keys_twin_rust_async(int64_t port_,
+                                                                                                                            struct wire_cst_list_prim_u_8_strict *map);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_rust_async__json_map_twin_rust_async(int64_t port_,
+                                                                                                                       struct wire_cst_list_prim_u_8_strict *map);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_rust_async__json_value_to_string_twin_rust_async(int64_t port_,
+                                                                                                                                   struct wire_cst_list_prim_u_8_strict *value);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_rust_async__json_value_twin_rust_async(int64_t port_,
+                                                                                                                         struct wire_cst_list_prim_u_8_strict *value);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_rust_async__json_values_twin_rust_async(int64_t port_,
+                                                                                                                          struct wire_cst_list_JsonValue *values);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_rust_async__optional_json_value_twin_rust_async(int64_t port_,
+                                                                                                                                  struct wire_cst_list_prim_u_8_strict *value);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_rust_async_sse__create_json_map_twin_rust_async_sse(int64_t port_,
+                                                                                                                                      uint8_t *ptr_,
+                                                                                                                                      int32_t rust_vec_len_,
+                                                                                                                                      int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_rust_async_sse__create_json_value_twin_rust_async_sse(int64_t port_,
+                                                                                                                                        uint8_t *ptr_,
+                                                                                                                                        int32_t rust_vec_len_,
+                                                                                                                                        int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_rust_async_sse__json_document_twin_rust_async_sse(int64_t port_,
+                                                                                                                                    uint8_t *ptr_,
+                                                                                                                                    int32_t rust_vec_len_,
+                                                                                                                                    int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_rust_async_sse__json_map_keys_twin_rust_async_sse(int64_t port_,
+                                                                                                                                    uint8_t *ptr_,
+                                                                                                                                    int32_t rust_vec_len_,
+                                                                                                                                    int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_rust_async_sse__json_map_twin_rust_async_sse(int64_t port_,
+                                                                                                                               uint8_t *ptr_,
+                                                                                                                               int32_t rust_vec_len_,
+                                                                                                                               int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_rust_async_sse__json_value_to_string_twin_rust_async_sse(int64_t port_,
+                                                                                                                                           uint8_t *ptr_,
+                                                                                                                                           int32_t rust_vec_len_,
+                                                                                                                                           int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_rust_async_sse__json_value_twin_rust_async_sse(int64_t port_,
+                                                                                                                                 uint8_t *ptr_,
+                                                                                                                                 int32_t rust_vec_len_,
+                                                                                                                                 int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_rust_async_sse__json_values_twin_rust_async_sse(int64_t port_,
+                                                                                                                                  uint8_t *ptr_,
+                                                                                                                                  int32_t rust_vec_len_,
+                                                                                                                                  int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_rust_async_sse__optional_json_value_twin_rust_async_sse(int64_t port_,
+                                                                                                                                          uint8_t *ptr_,
+                                                                                                                                          int32_t rust_vec_len_,
+                                                                                                                                          int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_sse__create_json_map_twin_sse(int64_t port_,
+                                                                                                                uint8_t *ptr_,
+                                                                                                                int32_t rust_vec_len_,
+                                                                                                                int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_sse__create_json_value_twin_sse(int64_t port_,
+                                                                                                                  uint8_t *ptr_,
+                                                                                                                  int32_t rust_vec_len_,
+                                                                                                                  int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_sse__json_document_twin_sse(int64_t port_,
+                                                                                                              uint8_t *ptr_,
+                                                                                                              int32_t rust_vec_len_,
+                                                                                                              int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_sse__json_map_keys_twin_sse(int64_t port_,
+                                                                                                              uint8_t *ptr_,
+                                                                                                              int32_t rust_vec_len_,
+                                                                                                              int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_sse__json_map_twin_sse(int64_t port_,
+                                                                                                         uint8_t *ptr_,
+                                                                                                         int32_t rust_vec_len_,
+                                                                                                         int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_sse__json_value_to_string_twin_sse(int64_t port_,
+                                                                                                                     uint8_t *ptr_,
+                                                                                                                     int32_t rust_vec_len_,
+                                                                                                                     int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_sse__json_value_twin_sse(int64_t port_,
+                                                                                                           uint8_t *ptr_,
+                                                                                                           int32_t rust_vec_len_,
+                                                                                                           int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_sse__json_values_twin_sse(int64_t port_,
+                                                                                                            uint8_t *ptr_,
+                                                                                                            int32_t rust_vec_len_,
+                                                                                                            int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_sse__optional_json_value_twin_sse(int64_t port_,
+                                                                                                                    uint8_t *ptr_,
+                                                                                                                    int32_t rust_vec_len_,
+                                                                                                                    int32_t data_len_);
+
+WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_sync__create_json_map_twin_sync(void);
+
+WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_sync__create_json_value_twin_sync(void);
+
+WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_sync__json_document_twin_sync(struct wire_cst_json_document_twin_sync *document);
+
+WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_sync__json_map_keys_twin_sync(struct wire_cst_list_prim_u_8_strict *map);
+
+WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_sync__json_map_twin_sync(struct wire_cst_list_prim_u_8_strict *map);
+
+WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_sync__json_value_to_string_twin_sync(struct wire_cst_list_prim_u_8_strict *value);
+
+WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_sync__json_value_twin_sync(struct wire_cst_list_prim_u_8_strict *value);
 
-WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__impl_trait_twin_sync_sse__StructOneWithTraitTwinSyncSse_auto_accessor_get_one(uintptr_t that);
+WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_sync__json_values_twin_sync(struct wire_cst_list_JsonValue *values);
 
-WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__impl_trait_twin_sync_sse__StructOneWithTraitTwinSyncSse_auto_accessor_set_one(uintptr_t that,
-                                                                                                                                                                 int32_t one);
+WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_sync__optional_json_value_twin_sync(struct wire_cst_list_prim_u_8_strict *value);
 
-void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__impl_trait_twin_sync_sse__StructOneWithTraitTwinSyncSse_simple_trait_fn_receiver_borrow_twin_sync_sse(int64_t port_,
-                                                                                                                                                                         uintptr_t that);
+WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_sync_sse__create_json_map_twin_sync_sse(uint8_t *ptr_,
+                                                                                                                                          int32_t rust_vec_len_,
+                                                                                                                                          int32_t data_len_);
 
-void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__impl_trait_twin_sync_sse__StructOneWithTraitTwinSyncSse_simple_trait_fn_twin_sync_sse(int64_t port_,
-                                                                                                                                                         int32_t value);
+WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_sync_sse__create_json_value_twin_sync_sse(uint8_t *ptr_,
+                                                                                                                                            int32_t rust_vec_len_,
+                                                                                                                                            int32_t data_len_);
 
-void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__impl_trait_twin_sync_sse__StructOneWithTraitTwinSyncSse_simple_trait_fn_with_default_impl_twin_sync_sse(int64_t port_);
+WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_sync_sse__json_document_twin_sync_sse(uint8_t *ptr_,
+                                                                                                                                        int32_t rust_vec_len_,
+                                                                                                                                        int32_t data_len_);
 
-WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__impl_trait_twin_sync_sse__StructTwoWithTraitTwinSyncSse_auto_accessor_get_two(uintptr_t that);
+WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_sync_sse__json_map_keys_twin_sync_sse(uint8_t *ptr_,
+                                                                                                                                        int32_t rust_vec_len_,
+                                                                                                                                        int32_t data_len_);
 
-WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__impl_trait_twin_sync_sse__StructTwoWithTraitTwinSyncSse_auto_accessor_set_two(uintptr_t that,
-                                                                                                                                                                 int32_t two);
+WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_sync_sse__json_map_twin_sync_sse(uint8_t *ptr_,
+                                                                                                                                   int32_t rust_vec_len_,
+                                                                                                                                   int32_t data_len_);
 
-void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__impl_trait_twin_sync_sse__StructTwoWithTraitTwinSyncSse_simple_trait_fn_receiver_borrow_twin_sync_sse(int64_t port_,
-                                                                                                                                                                         uintptr_t that);
+WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_sync_sse__json_value_to_string_twin_sync_sse(uint8_t *ptr_,
+                                                                                                                                               int32_t rust_vec_len_,
+                                                                                                                                               int32_t data_len_);
 
-void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__impl_trait_twin_sync_sse__StructTwoWithTraitTwinSyncSse_simple_trait_fn_twin_sync_sse(int64_t port_,
-                                                                                                                                                         int32_t value);
+WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_sync_sse__json_value_twin_sync_sse(uint8_t *ptr_,
+                                                                                                                                     int32_t rust_vec_len_,
+                                                                                                                                     int32_t data_len_);
 
-void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__impl_trait_twin_sync_sse__StructTwoWithTraitTwinSyncSse_simple_trait_fn_with_default_impl_twin_sync_sse(int64_t port_);
+WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_sync_sse__json_values_twin_sync_sse(uint8_t *ptr_,
+                                                                                                                                      int32_t rust_vec_len_,
+                                                                                                                                      int32_t data_len_);
+
+WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_sync_sse__optional_json_value_twin_sync_sse(uint8_t *ptr_,
+                                                                                                                                              int32_t rust_vec_len_,
+                                                                                                                                              int32_t data_len_);
 
 WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__lifetimeable_twin_sync__Lifetimeable_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLtNestedTypeWithLifetimeTwinSyncstatic_greet_borrow_mut_self_twin_sync(uint8_t *ptr_,
                                                                                                                                                                                                                                                                         int32_t rust_vec_len_,
@@ -8511,6 +9454,18 @@ void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__lifetimeable_
                                                                                                                                        int32_t rust_vec_len_,
                                                                                                                                        int32_t data_len_);
 
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_rust_async__func_btree_map_i32_i32_twin_rust_async(int64_t port_,
+                                                                                                                                       struct wire_cst_list_record_i_32_i_32 *arg);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_rust_async__func_btree_map_string_struct_twin_rust_async(int64_t port_,
+                                                                                                                                             struct wire_cst_list_record_string_my_size *arg);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_rust_async__func_btree_set_i32_twin_rust_async(int64_t port_,
+                                                                                                                                   struct wire_cst_list_prim_i_32_strict *arg);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_rust_async__func_btree_set_string_twin_rust_async(int64_t port_,
+                                                                                                                                      struct wire_cst_list_String *arg);
+
 void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_rust_async__func_hash_map_i32_i32_twin_rust_async(int64_t port_,
                                                                                                                                       struct wire_cst_list_record_i_32_i_32 *arg);
 
@@ -8535,6 +9490,32 @@ void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_t
 void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_rust_async__func_hash_set_string_twin_rust_async(int64_t port_,
                                                                                                                                      struct wire_cst_list_String *arg);
 
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_rust_async__func_vec_deque_i32_twin_rust_async(int64_t port_,
+                                                                                                                                   struct wire_cst_list_prim_i_32_strict *arg);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_rust_async__func_vec_deque_string_twin_rust_async(int64_t port_,
+                                                                                                                                      struct wire_cst_list_String *arg);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_rust_async_sse__func_btree_map_i32_i32_twin_rust_async_sse(int64_t port_,
+                                                                                                                                               uint8_t *ptr_,
+                                                                                                                                               int32_t rust_vec_len_,
+                                                                                                                                               int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_rust_async_sse__func_btree_map_string_struct_twin_rust_async_sse(int64_t port_,
+                                                                                                                                                     uint8_t *ptr_,
+                                                                                                                                                     int32_t rust_vec_len_,
+                                                                                                                                                     int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_rust_async_sse__func_btree_set_i32_twin_rust_async_sse(int64_t port_,
+                                                                                                                                           uint8_t *ptr_,
+                                                                                                                                           int32_t rust_vec_len_,
+                                                                                                                                           int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_rust_async_sse__func_btree_set_string_twin_rust_async_sse(int64_t port_,
+                                                                                                                                              uint8_t *ptr_,
+                                                                                                                                              int32_t rust_vec_len_,
+                                                                                                                                              int32_t data_len_);
+
 void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_rust_async_sse__func_hash_map_i32_i32_twin_rust_async_sse(int64_t port_,
                                                                                                                                               uint8_t *ptr_,
                                                                                                                                               int32_t rust_vec_len_,
@@ -8575,6 +9556,36 @@ void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_t
                                                                                                                                              int32_t rust_vec_len_,
                                                                                                                                              int32_t data_len_);
 
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_rust_async_sse__func_vec_deque_i32_twin_rust_async_sse(int64_t port_,
+                                                                                                                                           uint8_t *ptr_,
+                                                                                                                                           int32_t rust_vec_len_,
+                                                                                                                                           int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_rust_async_sse__func_vec_deque_string_twin_rust_async_sse(int64_t port_,
+                                                                                                                                              uint8_t *ptr_,
+                                                                                                                                              int32_t rust_vec_len_,
+                                                                                                                                              int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_sse__func_btree_map_i32_i32_twin_sse(int64_t port_,
+                                                                                                                         uint8_t *ptr_,
+                                                                                                                         int32_t rust_vec_len_,
+                                                                                                                         int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_sse__func_btree_map_string_struct_twin_sse(int64_t port_,
+                                                                                                                               uint8_t *ptr_,
+                                                                                                                               int32_t rust_vec_len_,
+                                                                                                                               int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_sse__func_btree_set_i32_twin_sse(int64_t port_,
+                                                                                                                     uint8_t *ptr_,
+                                                                                                                     int32_t rust_vec_len_,
+                                                                                                                     int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_sse__func_btree_set_string_twin_sse(int64_t port_,
+                                                                                                                        uint8_t *ptr_,
+                                                                                                                        int32_t rust_vec_len_,
+                                                                                                                        int32_t data_len_);
+
 void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_sse__func_hash_map_i32_i32_twin_sse(int64_t port_,
                                                                                                                         uint8_t *ptr_,
                                                                                                                         int32_t rust_vec_len_,
@@ -8615,6 +9626,24 @@ void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_t
                                                                                                                        int32_t rust_vec_len_,
                                                                                                                        int32_t data_len_);
 
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_sse__func_vec_deque_i32_twin_sse(int64_t port_,
+                                                                                                                     uint8_t *ptr_,
+                                                                                                                     int32_t rust_vec_len_,
+                                                                                                                     int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_sse__func_vec_deque_string_twin_sse(int64_t port_,
+                                                                                                                        uint8_t *ptr_,
+                                                                                                                        int32_t rust_vec_len_,
+                                                                                                                        int32_t data_len_);
+
+WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_sync__func_btree_map_i32_i32_twin_sync(struct wire_cst_list_record_i_32_i_32 *arg);
+
+WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_sync__func_btree_map_string_struct_twin_sync(struct wire_cst_list_record_string_my_size *arg);
+
+WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_sync__func_btree_set_i32_twin_sync(struct wire_cst_list_prim_i_32_strict *arg);
+
+WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_sync__func_btree_set_string_twin_sync(struct wire_cst_list_String *arg);
+
 WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_sync__func_hash_map_i32_i32_twin_sync(struct wire_cst_list_record_i_32_i_32 *arg);
 
 WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_sync__func_hash_map_string_bytes_twin_sync(struct wire_cst_list_record_string_list_prim_u_8_strict *arg);
@@ -8631,6 +9660,26 @@ WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manua
 
 WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_sync__func_hash_set_string_twin_sync(struct wire_cst_list_String *arg);
 
+WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_sync__func_vec_deque_i32_twin_sync(struct wire_cst_list_prim_i_32_strict *arg);
+
+WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_sync__func_vec_deque_string_twin_sync(struct wire_cst_list_String *arg);
+
+WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_sync_sse__func_btree_map_i32_i32_twin_sync_sse(uint8_t *ptr_,
+                                                                                                                                                   int32_t rust_vec_len_,
+                                                                                                                                                   int32_t data_len_);
+
+WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_sync_sse__func_btree_map_string_struct_twin_sync_sse(uint8_t *ptr_,
+                                                                                                                                                         int32_t rust_vec_len_,
+                                                                                                                                                         int32_t data_len_);
+
+WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_sync_sse__func_btree_set_i32_twin_sync_sse(uint8_t *ptr_,
+                                                                                                                                               int32_t rust_vec_len_,
+                                                                                                                                               int32_t data_len_);
+
+WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_sync_sse__func_btree_set_string_twin_sync_sse(uint8_t *ptr_,
+                                                                                                                                                  int32_t rust_vec_len_,
+                                                                                                                                                  int32_t data_len_);
+
 WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_sync_sse__func_hash_map_i32_i32_twin_sync_sse(uint8_t *ptr_,
                                                                                                                                                   int32_t rust_vec_len_,
                                                                                                                                                   int32_t data_len_);
@@ -8663,6 +9712,14 @@ WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manua
                                                                                                                                                  int32_t rust_vec_len_,
                                                                                                                                                  int32_t data_len_);
 
+WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_sync_sse__func_vec_deque_i32_twin_sync_sse(uint8_t *ptr_,
+                                                                                                                                               int32_t rust_vec_len_,
+                                                                                                                                               int32_t data_len_);
+
+WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_sync_sse__func_vec_deque_string_twin_sync_sse(uint8_t *ptr_,
+                                                                                                                                                  int32_t rust_vec_len_,
+                                                                                                                                                  int32_t data_len_);
+
 void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__method_twin_rust_async__StaticGetterOnlyTwinRustAsync_static_getter_twin_rust_async(int64_t port_);
 
 void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__method_twin_rust_async__concatenate_with_twin_rust_async_concatenate_static_twin_rust_async(int64_t port_,
@@ -12694,87 +13751,235 @@ WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manua
                                                                                                                                                              int32_t rust_vec_len_,
                                                                                                                                                              int32_t data_len_);
 
-WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__rust_opaque_twin_sync_sse_moi__create_nested_opaque_twin_sync_sse_moi(uint8_t *ptr_,
-                                                                                                                                                         int32_t rust_vec_len_,
-                                                                                                                                                         int32_t data_len_);
+WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__rust_opaque_twin_sync_sse_moi__create_nested_opaque_twin_sync_sse_moi(uint8_t *ptr_,
+                                                                                                                                                         int32_t rust_vec_len_,
+                                                                                                                                                         int32_t data_len_);
+
+WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__rust_opaque_twin_sync_sse_moi__create_opaque_twin_sync_sse_moi(uint8_t *ptr_,
+                                                                                                                                                  int32_t rust_vec_len_,
+                                                                                                                                                  int32_t data_len_);
+
+WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__rust_opaque_twin_sync_sse_moi__create_option_opaque_twin_sync_sse_moi(uint8_t *ptr_,
+                                                                                                                                                         int32_t rust_vec_len_,
+                                                                                                                                                         int32_t data_len_);
+
+WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__rust_opaque_twin_sync_sse_moi__frb_generator_test_twin_sync_sse_moi(uint8_t *ptr_,
+                                                                                                                                                       int32_t rust_vec_len_,
+                                                                                                                                                       int32_t data_len_);
+
+WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__rust_opaque_twin_sync_sse_moi__opaque_array_run_twin_sync_sse_moi(uint8_t *ptr_,
+                                                                                                                                                     int32_t rust_vec_len_,
+                                                                                                                                                     int32_t data_len_);
+
+WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__rust_opaque_twin_sync_sse_moi__opaque_array_twin_sync_sse_moi(uint8_t *ptr_,
+                                                                                                                                                 int32_t rust_vec_len_,
+                                                                                                                                                 int32_t data_len_);
+
+WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__rust_opaque_twin_sync_sse_moi__opaque_vec_run_twin_sync_sse_moi(uint8_t *ptr_,
+                                                                                                                                                   int32_t rust_vec_len_,
+                                                                                                                                                   int32_t data_len_);
+
+WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__rust_opaque_twin_sync_sse_moi__opaque_vec_twin_sync_sse_moi(uint8_t *ptr_,
+                                                                                                                                               int32_t rust_vec_len_,
+                                                                                                                                               int32_t data_len_);
+
+WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__rust_opaque_twin_sync_sse_moi__run_enum_opaque_twin_sync_sse_moi(uint8_t *ptr_,
+                                                                                                                                                    int32_t rust_vec_len_,
+                                                                                                                                                    int32_t data_len_);
+
+WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__rust_opaque_twin_sync_sse_moi__run_nested_opaque_twin_sync_sse_moi(uint8_t *ptr_,
+                                                                                                                                                      int32_t rust_vec_len_,
+                                                                                                                                                      int32_t data_len_);
+
+WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__rust_opaque_twin_sync_sse_moi__run_non_clone_twin_sync_sse_moi(uint8_t *ptr_,
+                                                                                                                                                  int32_t rust_vec_len_,
+                                                                                                                                                  int32_t data_len_);
+
+WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__rust_opaque_twin_sync_sse_moi__run_opaque_twin_sync_sse_moi(uint8_t *ptr_,
+                                                                                                                                               int32_t rust_vec_len_,
+                                                                                                                                               int32_t data_len_);
+
+WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__rust_opaque_twin_sync_sse_moi__run_opaque_with_delay_twin_sync_sse_moi(uint8_t *ptr_,
+                                                                                                                                                          int32_t rust_vec_len_,
+                                                                                                                                                          int32_t data_len_);
+
+WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__rust_opaque_twin_sync_sse_moi__unwrap_rust_opaque_twin_sync_sse_moi(uint8_t *ptr_,
+                                                                                                                                                       int32_t rust_vec_len_,
+                                                                                                                                                       int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__simple_twin_rust_async__simple_adder_twin_rust_async(int64_t port_,
+                                                                                                                        int32_t a,
+                                                                                                                        int32_t b);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__simple_twin_rust_async_sse__simple_adder_twin_rust_async_sse(int64_t port_,
+                                                                                                                                uint8_t *ptr_,
+                                                                                                                                int32_t rust_vec_len_,
+                                                                                                                                int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__simple_twin_sse__simple_adder_twin_sse(int64_t port_,
+                                                                                                          uint8_t *ptr_,
+                                                                                                          int32_t rust_vec_len_,
+                                                                                                          int32_t data_len_);
+
+WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__simple_twin_sync__simple_adder_twin_sync(int32_t a,
+                                                                                                                            int32_t b);
+
+WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__simple_twin_sync_sse__simple_adder_twin_sync_sse(uint8_t *ptr_,
+                                                                                                                                    int32_t rust_vec_len_,
+                                                                                                                                    int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_rust_async__std_duration_twin_rust_async(int64_t port_,
+                                                                                                                          int64_t d);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_rust_async__std_durations_twin_rust_async(int64_t port_,
+                                                                                                                           struct wire_cst_list_StdDuration *durations);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_rust_async__std_optional_duration_twin_rust_async(int64_t port_,
+                                                                                                                                   int64_t *d);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_rust_async__std_optional_system_time_twin_rust_async(int64_t port_,
+                                                                                                                                      int64_t *t);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_rust_async__std_system_time_twin_rust_async(int64_t port_,
+                                                                                                                             int64_t t);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_rust_async__std_system_times_twin_rust_async(int64_t port_,
+                                                                                                                              struct wire_cst_list_StdSystemTime *times);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_rust_async__std_time_struct_twin_rust_async(int64_t port_,
+                                                                                                                             struct wire_cst_std_time_twin_rust_async *value);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_rust_async_sse__std_duration_twin_rust_async_sse(int64_t port_,
+                                                                                                                                  uint8_t *ptr_,
+                                                                                                                                  int32_t rust_vec_len_,
+                                                                                                                                  int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_rust_async_sse__std_durations_twin_rust_async_sse(int64_t port_,
+                                                                                                                                   uint8_t *ptr_,
+                                                                                                                                   int32_t rust_vec_len_,
+                                                                                                                                   int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_rust_async_sse__std_optional_duration_twin_rust_async_sse(int64_t port_,
+                                                                                                                                           uint8_t *ptr_,
+                                                                                                                                           int32_t rust_vec_len_,
+                                                                                                                                           int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_rust_async_sse__std_optional_system_time_twin_rust_async_sse(int64_t port_,
+                                                                                                                                              uint8_t *ptr_,
+                                                                                                                                              int32_t rust_vec_len_,
+                                                                                                                                              int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_rust_async_sse__std_system_time_twin_rust_async_sse(int64_t port_,
+                                                                                                                                     uint8_t *ptr_,
+                                                                                                                                     int32_t rust_vec_len_,
+                                                                                                                                     int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_rust_async_sse__std_system_times_twin_rust_async_sse(int64_t port_,
+                                                                                                                                      uint8_t *ptr_,
+                                                                                                                                      int32_t rust_vec_len_,
+                                                                                                                                      int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_rust_async_sse__std_time_struct_twin_rust_async_sse(int64_t port_,
+                                                                                                                                     uint8_t *ptr_,
+                                                                                                                                     int32_t rust_vec_len_,
+                                                                                                                                     int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_sse__std_duration_twin_sse(int64_t port_,
+                                                                                                            uint8_t *ptr_,
+                                                                                                            int32_t rust_vec_len_,
+                                                                                                            int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_sse__std_durations_twin_sse(int64_t port_,
+                                                                                                             uint8_t *ptr_,
+                                                                                                             int32_t rust_vec_len_,
+                                                                                                             int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_sse__std_optional_duration_twin_sse(int64_t port_,
+                                                                                                                     uint8_t *ptr_,
+                                                                                                                     int32_t rust_vec_len_,
+                                                                                                                     int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_sse__std_optional_system_time_twin_sse(int64_t port_,
+                                                                                                                        uint8_t *ptr_,
+                                                                                                                        int32_t rust_vec_len_,
+                                                                                                                        int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_sse__std_system_time_twin_sse(int64_t port_,
+                                                                                                               uint8_t *ptr_,
+                                                                                                               int32_t rust_vec_len_,
+                                                                                                               int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_sse__std_system_times_twin_sse(int64_t port_,
+                                                                                                                uint8_t *ptr_,
+                                                                                                                int32_t rust_vec_len_,
+                                                                                                                int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_sse__std_time_struct_twin_sse(int64_t port_,
+                                                                                                               uint8_t *ptr_,
+                                                                                                               int32_t rust_vec_len_,
+                                                                                                               int32_t data_len_);
+
+WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_sync__std_duration_twin_sync(int64_t d);
+
+WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_sync__std_durations_twin_sync(struct wire_cst_list_StdDuration *durations);
 
-WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__rust_opaque_twin_sync_sse_moi__create_opaque_twin_sync_sse_moi(uint8_t *ptr_,
-                                                                                                                                                  int32_t rust_vec_len_,
-                                                                                                                                                  int32_t data_len_);
+WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_sync__std_optional_duration_twin_sync(int64_t *d);
 
-WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__rust_opaque_twin_sync_sse_moi__create_option_opaque_twin_sync_sse_moi(uint8_t *ptr_,
-                                                                                                                                                         int32_t rust_vec_len_,
-                                                                                                                                                         int32_t data_len_);
+WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_sync__std_optional_system_time_twin_sync(int64_t *t);
 
-WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__rust_opaque_twin_sync_sse_moi__frb_generator_test_twin_sync_sse_moi(uint8_t *ptr_,
-                                                                                                                                                       int32_t rust_vec_len_,
-                                                                                                                                                       int32_t data_len_);
+WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_sync__std_system_time_twin_sync(int64_t t);
 
-WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__rust_opaque_twin_sync_sse_moi__opaque_array_run_twin_sync_sse_moi(uint8_t *ptr_,
-                                                                                                                                                     int32_t rust_vec_len_,
-                                                                                                                                                     int32_t data_len_);
+WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_sync__std_system_times_twin_sync(struct wire_cst_list_StdSystemTime *times);
 
-WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__rust_opaque_twin_sync_sse_moi__opaque_array_twin_sync_sse_moi(uint8_t *ptr_,
-                                                                                                                                                 int32_t rust_vec_len_,
-                                                                                                                                                 int32_t data_len_);
+WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_sync__std_time_struct_twin_sync(struct wire_cst_std_time_twin_sync *value);
 
-WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__rust_opaque_twin_sync_sse_moi__opaque_vec_run_twin_sync_sse_moi(uint8_t *ptr_,
-                                                                                                                                                   int32_t rust_vec_len_,
-                                                                                                                                                   int32_t data_len_);
+WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_sync_sse__std_duration_twin_sync_sse(uint8_t *ptr_,
+                                                                                                                                      int32_t rust_vec_len_,
+                                                                                                                                      int32_t data_len_);
 
-WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__rust_opaque_twin_sync_sse_moi__opaque_vec_twin_sync_sse_moi(uint8_t *ptr_,
+WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_sync_sse__std_durations_twin_sync_sse(uint8_t *ptr_,
+                                                                                                                                       int32_t rust_vec_len_,
+                                                                                                                                       int32_t data_len_);
+
+WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_sync_sse__std_optional_duration_twin_sync_sse(uint8_t *ptr_,
                                                                                                                                                int32_t rust_vec_len_,
                                                                                                                                                int32_t data_len_);
 
-WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__rust_opaque_twin_sync_sse_moi__run_enum_opaque_twin_sync_sse_moi(uint8_t *ptr_,
-                                                                                                                                                    int32_t rust_vec_len_,
-                                                                                                                                                    int32_t data_len_);
-
-WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__rust_opaque_twin_sync_sse_moi__run_nested_opaque_twin_sync_sse_moi(uint8_t *ptr_,
-                                                                                                                                                      int32_t rust_vec_len_,
-                                                                                                                                                      int32_t data_len_);
-
-WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__rust_opaque_twin_sync_sse_moi__run_non_clone_twin_sync_sse_moi(uint8_t *ptr_,
+WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_sync_sse__std_optional_system_time_twin_sync_sse(uint8_t *ptr_,
                                                                                                                                                   int32_t rust_vec_len_,
                                                                                                                                                   int32_t data_len_);
 
-WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__rust_opaque_twin_sync_sse_moi__run_opaque_twin_sync_sse_moi(uint8_t *ptr_,
-                                                                                                                                               int32_t rust_vec_len_,
-                                                                                                                                               int32_t data_len_);
+WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_sync_sse__std_system_time_twin_sync_sse(uint8_t *ptr_,
+                                                                                                                                         int32_t rust_vec_len_,
+                                                                                                                                         int32_t data_len_);
 
-WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__rust_opaque_twin_sync_sse_moi__run_opaque_with_delay_twin_sync_sse_moi(uint8_t *ptr_,
-                                                                                                                                                          int32_t rust_vec_len_,
-                                                                                                                                                          int32_t data_len_);
+WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_sync_sse__std_system_times_twin_sync_sse(uint8_t *ptr_,
+                                                                                                                                          int32_t rust_vec_len_,
+                                                                                                                                          int32_t data_len_);
 
-WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__rust_opaque_twin_sync_sse_moi__unwrap_rust_opaque_twin_sync_sse_moi(uint8_t *ptr_,
-                                                                                                                                                       int32_t rust_vec_len_,
-                                                                                                                                                       int32_t data_len_);
+WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_sync_sse__std_time_struct_twin_sync_sse(uint8_t *ptr_,
+                                                                                                                                         int32_t rust_vec_len_,
+                                                                                                                                         int32_t data_len_);
 
-void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__simple_twin_rust_async__simple_adder_twin_rust_async(int64_t port_,
-                                                                                                                        int32_t a,
-                                                                                                                        int32_t b);
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__stream_misc_twin_sse__func_stream_realistic_twin_sse(int64_t port_,
+                                                                                                                        uint8_t *ptr_,
+                                                                                                                        int32_t rust_vec_len_,
+                                                                                                                        int32_t data_len_);
 
-void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__simple_twin_rust_async_sse__simple_adder_twin_rust_async_sse(int64_t port_,
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__stream_misc_twin_sse__stream_sink_bounded_add_async_twin_sse(int64_t port_,
                                                                                                                                 uint8_t *ptr_,
                                                                                                                                 int32_t rust_vec_len_,
                                                                                                                                 int32_t data_len_);
 
-void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__simple_twin_sse__simple_adder_twin_sse(int64_t port_,
-                                                                                                          uint8_t *ptr_,
-                                                                                                          int32_t rust_vec_len_,
-                                                                                                          int32_t data_len_);
-
-WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__simple_twin_sync__simple_adder_twin_sync(int32_t a,
-                                                                                                                            int32_t b);
-
-WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__simple_twin_sync_sse__simple_adder_twin_sync_sse(uint8_t *ptr_,
-                                                                                                                                    int32_t rust_vec_len_,
-                                                                                                                                    int32_t data_len_);
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__stream_misc_twin_sse__stream_sink_bounded_add_with_error_twin_sse(int64_t port_,
+                                                                                                                                     uint8_t *ptr_,
+                                                                                                                                     int32_t rust_vec_len_,
+                                                                                                                                     int32_t data_len_);
 
-void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__stream_misc_twin_sse__func_stream_realistic_twin_sse(int64_t port_,
-                                                                                                                        uint8_t *ptr_,
-                                                                                                                        int32_t rust_vec_len_,
-                                                                                                                        int32_t data_len_);
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__stream_misc_twin_sse__stream_sink_bounded_keep_latest_twin_sse(int64_t port_,
+                                                                                                                                  uint8_t *ptr_,
+                                                                                                                                  int32_t rust_vec_len_,
+                                                                                                                                  int32_t data_len_);
 
 void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__stream_misc_twin_sse__stream_sink_dart_async_twin_sse(int64_t port_,
                                                                                                                          uint8_t *ptr_,
@@ -13065,6 +14270,177 @@ WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manua
                                                                                                                                                            int32_t rust_vec_len_,
                                                                                                                                                            int32_t data_len_);
 
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_rust_async__date_twin_rust_async(int64_t port_,
+                                                                                                                   int64_t d);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_rust_async__negate_time_duration_twin_rust_async(int64_t port_,
+                                                                                                                                   int64_t d);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_rust_async__offset_date_time_twin_rust_async(int64_t port_,
+                                                                                                                               int64_t d);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_rust_async__optional_offset_date_time_twin_rust_async(int64_t port_,
+                                                                                                                                        int64_t *d);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_rust_async__optional_time_duration_twin_rust_async(int64_t port_,
+                                                                                                                                     int64_t *d);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_rust_async__primitive_date_time_twin_rust_async(int64_t port_,
+                                                                                                                                  int64_t d);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_rust_async__time_duration_twin_rust_async(int64_t port_,
+                                                                                                                            int64_t d);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_rust_async__time_durations_twin_rust_async(int64_t port_,
+                                                                                                                             struct wire_cst_list_TimeDuration *durations);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_rust_async__time_types_twin_rust_async(int64_t port_,
+                                                                                                                         struct wire_cst_time_types_twin_rust_async *value);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_rust_async_sse__date_twin_rust_async_sse(int64_t port_,
+                                                                                                                           uint8_t *ptr_,
+                                                                                                                           int32_t rust_vec_len_,
+                                                                                                                           int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_rust_async_sse__negate_time_duration_twin_rust_async_sse(int64_t port_,
+                                                                                                                                           uint8_t *ptr_,
+                                                                                                                                           int32_t rust_vec_len_,
+                                                                                                                                           int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_rust_async_sse__offset_date_time_twin_rust_async_sse(int64_t port_,
+                                                                                                                                       uint8_t *ptr_,
+                                                                                                                                       int32_t rust_vec_len_,
+                                                                                                                                       int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_rust_async_sse__optional_offset_date_time_twin_rust_async_sse(int64_t port_,
+                                                                                                                                                uint8_t *ptr_,
+                                                                                                                                                int32_t rust_vec_len_,
+                                                                                                                                                int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_rust_async_sse__optional_time_duration_twin_rust_async_sse(int64_t port_,
+                                                                                                                                             uint8_t *ptr_,
+                                                                                                                                             int32_t rust_vec_len_,
+                                                                                                                                             int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_rust_async_sse__primitive_date_time_twin_rust_async_sse(int64_t port_,
+                                                                                                                                          uint8_t *ptr_,
+                                                                                                                                          int32_t rust_vec_len_,
+                                                                                                                                          int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_rust_async_sse__time_duration_twin_rust_async_sse(int64_t port_,
+                                                                                                                                    uint8_t *ptr_,
+                                                                                                                                    int32_t rust_vec_len_,
+                                                                                                                                    int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_rust_async_sse__time_durations_twin_rust_async_sse(int64_t port_,
+                                                                                                                                     uint8_t *ptr_,
+                                                                                                                                     int32_t rust_vec_len_,
+                                                                                                                                     int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_rust_async_sse__time_types_twin_rust_async_sse(int64_t port_,
+                                                                                                                                 uint8_t *ptr_,
+                                                                                                                                 int32_t rust_vec_len_,
+                                                                                                                                 int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_sse__date_twin_sse(int64_t port_,
+                                                                                                     uint8_t *ptr_,
+                                                                                                     int32_t rust_vec_len_,
+                                                                                                     int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_sse__negate_time_duration_twin_sse(int64_t port_,
+                                                                                                                     uint8_t *ptr_,
+                                                                                                                     int32_t rust_vec_len_,
+                                                                                                                     int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_sse__offset_date_time_twin_sse(int64_t port_,
+                                                                                                                 uint8_t *ptr_,
+                                                                                                                 int32_t rust_vec_len_,
+                                                                                                                 int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_sse__optional_offset_date_time_twin_sse(int64_t port_,
+                                                                                                                          uint8_t *ptr_,
+                                                                                                                          int32_t rust_vec_len_,
+                                                                                                                          int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_sse__optional_time_duration_twin_sse(int64_t port_,
+                                                                                                                       uint8_t *ptr_,
+                                                                                                                       int32_t rust_vec_len_,
+                                                                                                                       int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_sse__primitive_date_time_twin_sse(int64_t port_,
+                                                                                                                    uint8_t *ptr_,
+                                                                                                                    int32_t rust_vec_len_,
+                                                                                                                    int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_sse__time_duration_twin_sse(int64_t port_,
+                                                                                                              uint8_t *ptr_,
+                                                                                                              int32_t rust_vec_len_,
+                                                                                                              int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_sse__time_durations_twin_sse(int64_t port_,
+                                                                                                               uint8_t *ptr_,
+                                                                                                               int32_t rust_vec_len_,
+                                                                                                               int32_t data_len_);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_sse__time_types_twin_sse(int64_t port_,
+                                                                                                           uint8_t *ptr_,
+                                                                                                           int32_t rust_vec_len_,
+                                                                                                           int32_t data_len_);
+
+WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_sync__date_twin_sync(int64_t d);
+
+WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_sync__negate_time_duration_twin_sync(int64_t d);
+
+WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_sync__offset_date_time_twin_sync(int64_t d);
+
+WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_sync__optional_offset_date_time_twin_sync(int64_t *d);
+
+WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_sync__optional_time_duration_twin_sync(int64_t *d);
+
+WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_sync__primitive_date_time_twin_sync(int64_t d);
+
+WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_sync__time_duration_twin_sync(int64_t d);
+
+WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_sync__time_durations_twin_sync(struct wire_cst_list_TimeDuration *durations);
+
+WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_sync__time_types_twin_sync(struct wire_cst_time_types_twin_sync *value);
+
+WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_sync_sse__date_twin_sync_sse(uint8_t *ptr_,
+                                                                                                                               int32_t rust_vec_len_,
+                                                                                                                               int32_t data_len_);
+
+WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_sync_sse__negate_time_duration_twin_sync_sse(uint8_t *ptr_,
+                                                                                                                                               int32_t rust_vec_len_,
+                                                                                                                                               int32_t data_len_);
+
+WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_sync_sse__offset_date_time_twin_sync_sse(uint8_t *ptr_,
+                                                                                                                                           int32_t rust_vec_len_,
+                                                                                                                                           int32_t data_len_);
+
+WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_sync_sse__optional_offset_date_time_twin_sync_sse(uint8_t *ptr_,
+                                                                                                                                                    int32_t rust_vec_len_,
+                                                                                                                                                    int32_t data_len_);
+
+WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_sync_sse__optional_time_duration_twin_sync_sse(uint8_t *ptr_,
+                                                                                                                                                 int32_t rust_vec_len_,
+                                                                                                                                                 int32_t data_len_);
+
+WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_sync_sse__primitive_date_time_twin_sync_sse(uint8_t *ptr_,
+                                                                                                                                              int32_t rust_vec_len_,
+                                                                                                                                              int32_t data_len_);
+
+WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_sync_sse__time_duration_twin_sync_sse(uint8_t *ptr_,
+                                                                                                                                        int32_t rust_vec_len_,
+                                                                                                                                        int32_t data_len_);
+
+WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_sync_sse__time_durations_twin_sync_sse(uint8_t *ptr_,
+                                                                                                                                         int32_t rust_vec_len_,
+                                                                                                                                         int32_t data_len_);
+
+WireSyncRust2DartSse frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_sync_sse__time_types_twin_sync_sse(uint8_t *ptr_,
+                                                                                                                                     int32_t rust_vec_len_,
+                                                                                                                                     int32_t data_len_);
+
 void frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__tuple_twin_rust_async__test_tuple_2_twin_rust_async(int64_t port_,
                                                                                                                        struct wire_cst_list_record_string_i_32 *value);
 
@@ -13404,6 +14780,27 @@ void frbgen_frb_example_pure_dart_wire__crate__api__simple__simple_adder_twin_no
                                                                                      int32_t a,
                                                                                      int32_t b);
 
+void frbgen_frb_example_pure_dart_wire__crate__api__std_time__std_duration_twin_normal(int64_t port_,
+                                                                                       int64_t d);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__std_time__std_durations_twin_normal(int64_t port_,
+                                                                                        struct wire_cst_list_StdDuration *durations);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__std_time__std_optional_duration_twin_normal(int64_t port_,
+                                                                                                int64_t *d);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__std_time__std_optional_system_time_twin_normal(int64_t port_,
+                                                                                                   int64_t *t);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__std_time__std_system_time_twin_normal(int64_t port_,
+                                                                                          int64_t t);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__std_time__std_system_times_twin_normal(int64_t port_,
+                                                                                           struct wire_cst_list_StdSystemTime *times);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__std_time__std_time_struct_twin_normal(int64_t port_,
+                                                                                          struct wire_cst_std_time_twin_normal *value);
+
 void frbgen_frb_example_pure_dart_wire__crate__api__stream__func_stream_add_value_and_error_twin_normal(int64_t port_,
                                                                                                         struct wire_cst_list_prim_u_8_strict *sink);
 
@@ -13449,6 +14846,15 @@ void frbgen_frb_example_pure_dart_wire__crate__api__stream_misc__func_stream_rea
                                                                                                    struct wire_cst_list_prim_u_8_strict *sink,
                                                                                                    struct wire_cst_list_prim_u_8_strict *arg);
 
+void frbgen_frb_example_pure_dart_wire__crate__api__stream_misc__stream_sink_bounded_add_async_twin_normal(int64_t port_,
+                                                                                                           struct wire_cst_list_prim_u_8_strict *sink);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__stream_misc__stream_sink_bounded_add_with_error_twin_normal(int64_t port_,
+                                                                                                                struct wire_cst_list_prim_u_8_strict *sink);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__stream_misc__stream_sink_bounded_keep_latest_twin_normal(int64_t port_,
+                                                                                                             struct wire_cst_list_prim_u_8_strict *sink);
+
 void frbgen_frb_example_pure_dart_wire__crate__api__stream_misc__stream_sink_dart_async_twin_normal(int64_t port_,
                                                                                                     struct wire_cst_list_prim_u_8_strict *sink);
 
@@ -13473,6 +14879,33 @@ void frbgen_frb_example_pure_dart_wire__crate__api__structure__func_tuple_struct
 void frbgen_frb_example_pure_dart_wire__crate__api__structure__func_tuple_struct_with_two_field_twin_normal(int64_t port_,
                                                                                                             struct wire_cst_tuple_struct_with_two_field_twin_normal *arg);
 
+void frbgen_frb_example_pure_dart_wire__crate__api__time_type__date_twin_normal(int64_t port_,
+                                                                                int64_t d);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__time_type__negate_time_duration_twin_normal(int64_t port_,
+                                                                                                int64_t d);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__time_type__offset_date_time_twin_normal(int64_t port_,
+                                                                                            int64_t d);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__time_type__optional_offset_date_time_twin_normal(int64_t port_,
+                                                                                                     int64_t *d);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__time_type__optional_time_duration_twin_normal(int64_t port_,
+                                                                                                  int64_t *d);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__time_type__primitive_date_time_twin_normal(int64_t port_,
+                                                                                               int64_t d);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__time_type__time_duration_twin_normal(int64_t port_,
+                                                                                         int64_t d);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__time_type__time_durations_twin_normal(int64_t port_,
+                                                                                          struct wire_cst_list_TimeDuration *durations);
+
+void frbgen_frb_example_pure_dart_wire__crate__api__time_type__time_types_twin_normal(int64_t port_,
+                                                                                      struct wire_cst_time_types_twin_normal *value);
+
 void frbgen_frb_example_pure_dart_wire__crate__api__tuple__test_tuple_2_twin_normal(int64_t port_,
                                                                                     struct wire_cst_list_record_string_i_32 *value);
 
@@ -13497,6 +14930,18 @@ void frbgen_frb_example_pure_dart_wire__crate__api__uuid_type__handle_uuid_twin_
 void frbgen_frb_example_pure_dart_wire__crate__api__uuid_type__handle_uuids_twin_normal(int64_t port_,
                                                                                         struct wire_cst_list_Uuid *ids);
 
+void frbgen_frb_example_pure_dart_wire__crate__frb_generated__DartImplementableTraitTwinNormalDartImpl_add_twin_normal(int64_t port_,
+                                                                                                                       uintptr_t that,
+                                                                                                                       int32_t a,
+                                                                                                                       int32_t b);
+
+void frbgen_frb_example_pure_dart_wire__crate__frb_generated__DartImplementableTraitTwinNormalDartImpl_describe_twin_normal(int64_t port_,
+                                                                                                                            uintptr_t that,
+                                                                                                                            int32_t value);
+
+WireSyncRust2DartDco frbgen_frb_example_pure_dart_wire__crate__frb_generated__DartImplementableTraitTwinNormalDartImpl_new(const void *add_twin_normal,
+                                                                                                                           const void *describe_twin_normal);
+
 void frbgen_frb_example_pure_dart_rust_arc_increment_strong_count_RustOpaque_BoxdynDartDebugTwinMoi(const void *ptr);
 
 void frbgen_frb_example_pure_dart_rust_arc_decrement_strong_count_RustOpaque_BoxdynDartDebugTwinMoi(const void *ptr);
@@ -13881,6 +15326,10 @@ void frbgen_frb_example_pure_dart_rust_arc_increment_strong_count_RustOpaque_flu
 
 void frbgen_frb_example_pure_dart_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConstructorOpaqueSyncStructTwinNormal(const void *ptr);
 
+void frbgen_frb_example_pure_dart_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl(const void *ptr);
+
+void frbgen_frb_example_pure_dart_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl(const void *ptr);
+
 void frbgen_frb_example_pure_dart_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeliberateFailSanityCheckTwinNormal(const void *ptr);
 
 void frbgen_frb_example_pure_dart_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeliberateFailSanityCheckTwinNormal(const void *ptr);
@@ -14309,6 +15758,10 @@ void frbgen_frb_example_pure_dart_rust_arc_increment_strong_count_RustOpaque_flu
 
 void frbgen_frb_example_pure_dart_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitTwinSyncSse(const void *ptr);
 
+void frbgen_frb_example_pure_dart_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(const void *ptr);
+
+void frbgen_frb_example_pure_dart_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(const void *ptr);
+
 void frbgen_frb_example_pure_dart_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinMoi(const void *ptr);
 
 void frbgen_frb_example_pure_dart_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinMoi(const void *ptr);
@@ -14391,6 +15844,8 @@ int64_t *frbgen_frb_example_pure_dart_cst_new_box_autoadd_Chrono_Duration(int64_
 
 int64_t *frbgen_frb_example_pure_dart_cst_new_box_autoadd_Chrono_Naive(int64_t value);
 
+int64_t *frbgen_frb_example_pure_dart_cst_new_box_autoadd_Chrono_NaiveTime(int64_t value);
+
 int64_t *frbgen_frb_example_pure_dart_cst_new_box_autoadd_Chrono_Utc(int64_t value);
 
 const void **frbgen_frb_example_pure_dart_cst_new_box_autoadd_DartOpaque(const void *value);
@@ -14411,6 +15866,16 @@ uintptr_t *frbgen_frb_example_pure_dart_cst_new_box_autoadd_RustOpaque_HideDataT
 
 uintptr_t *frbgen_frb_example_pure_dart_cst_new_box_autoadd_RustOpaque_HideDataTwinSyncMoi(uintptr_t value);
 
+int64_t *frbgen_frb_example_pure_dart_cst_new_box_autoadd_StdDuration(int64_t value);
+
+int64_t *frbgen_frb_example_pure_dart_cst_new_box_autoadd_StdSystemTime(int64_t value);
+
+int64_t *frbgen_frb_example_pure_dart_cst_new_box_autoadd_TimeDuration(int64_t value);
+
+int64_t *frbgen_frb_example_pure_dart_cst_new_box_autoadd_TimeOffsetDateTime(int64_t value);
+
+int64_t *frbgen_frb_example_pure_dart_cst_new_box_autoadd_TimePrimitiveDateTime(int64_t value);
+
 struct wire_cst_a_twin_normal *frbgen_frb_example_pure_dart_cst_new_box_autoadd_a_twin_normal(void);
 
 struct wire_cst_a_twin_rust_async *frbgen_frb_example_pure_dart_cst_new_box_autoadd_a_twin_rust_async(void);
@@ -14473,6 +15938,12 @@ struct wire_cst_benchmark_blob_twin_rust_async *frbgen_frb_example_pure_dart_cst
 
 struct wire_cst_benchmark_blob_twin_sync *frbgen_frb_example_pure_dart_cst_new_box_autoadd_benchmark_blob_twin_sync(void);
 
+struct wire_cst_big_numbers_twin_normal *frbgen_frb_example_pure_dart_cst_new_box_autoadd_big_numbers_twin_normal(void);
+
+struct wire_cst_big_numbers_twin_rust_async *frbgen_frb_example_pure_dart_cst_new_box_autoadd_big_numbers_twin_rust_async(void);
+
+struct wire_cst_big_numbers_twin_sync *frbgen_frb_example_pure_dart_cst_new_box_autoadd_big_numbers_twin_sync(void);
+
 bool *frbgen_frb_example_pure_dart_cst_new_box_autoadd_bool(bool value);
 
 struct wire_cst_c_twin_normal *frbgen_frb_example_pure_dart_cst_new_box_autoadd_c_twin_normal(void);
@@ -14523,6 +15994,8 @@ struct wire_cst_customized_twin_rust_async *frbgen_frb_example_pure_dart_cst_new
 
 struct wire_cst_customized_twin_sync *frbgen_frb_example_pure_dart_cst_new_box_autoadd_customized_twin_sync(void);
 
+struct wire_cst_dart_implementable_trait_twin_normal_implementor *frbgen_frb_example_pure_dart_cst_new_box_autoadd_dart_implementable_trait_twin_normal_implementor(void);
+
 struct wire_cst_dart_opaque_nested_twin_normal *frbgen_frb_example_pure_dart_cst_new_box_autoadd_dart_opaque_nested_twin_normal(void);
 
 struct wire_cst_dart_opaque_nested_twin_rust_async *frbgen_frb_example_pure_dart_cst_new_box_autoadd_dart_opaque_nested_twin_rust_async(void);
@@ -14633,6 +16106,12 @@ intptr_t *frbgen_frb_example_pure_dart_cst_new_box_autoadd_isize(intptr_t value)
 
 struct wire_cst_item_container_solution_two_twin_normal *frbgen_frb_example_pure_dart_cst_new_box_autoadd_item_container_solution_two_twin_normal(void);
 
+struct wire_cst_json_document_twin_normal *frbgen_frb_example_pure_dart_cst_new_box_autoadd_json_document_twin_normal(void);
+
+struct wire_cst_json_document_twin_rust_async *frbgen_frb_example_pure_dart_cst_new_box_autoadd_json_document_twin_rust_async(void);
+
+struct wire_cst_json_document_twin_sync *frbgen_frb_example_pure_dart_cst_new_box_autoadd_json_document_twin_sync(void);
+
 struct wire_cst_kitchen_sink_twin_normal *frbgen_frb_example_pure_dart_cst_new_box_autoadd_kitchen_sink_twin_normal(void);
 
 struct wire_cst_kitchen_sink_twin_rust_async *frbgen_frb_example_pure_dart_cst_new_box_autoadd_kitchen_sink_twin_rust_async(void);
@@ -14721,6 +16200,12 @@ struct wire_cst_raw_string_mirrored *frbgen_frb_example_pure_dart_cst_new_box_au
 
 struct wire_cst_record_string_i_32 *frbgen_frb_example_pure_dart_cst_new_box_autoadd_record_string_i_32(void);
 
+struct wire_cst_schedule_twin_normal *frbgen_frb_example_pure_dart_cst_new_box_autoadd_schedule_twin_normal(void);
+
+struct wire_cst_schedule_twin_rust_async *frbgen_frb_example_pure_dart_cst_new_box_autoadd_schedule_twin_rust_async(void);
+
+struct wire_cst_schedule_twin_sync *frbgen_frb_example_pure_dart_cst_new_box_autoadd_schedule_twin_sync(void);
+
 struct wire_cst_sequences *frbgen_frb_example_pure_dart_cst_new_box_autoadd_sequences(void);
 
 struct wire_cst_simple_enum_twin_normal *frbgen_frb_example_pure_dart_cst_new_box_autoadd_simple_enum_twin_normal(void);
@@ -14751,6 +16236,12 @@ struct wire_cst_some_struct_twin_rust_async *frbgen_frb_example_pure_dart_cst_ne
 
 struct wire_cst_some_struct_twin_sync *frbgen_frb_example_pure_dart_cst_new_box_autoadd_some_struct_twin_sync(void);
 
+struct wire_cst_std_time_twin_normal *frbgen_frb_example_pure_dart_cst_new_box_autoadd_std_time_twin_normal(void);
+
+struct wire_cst_std_time_twin_rust_async *frbgen_frb_example_pure_dart_cst_new_box_autoadd_std_time_twin_rust_async(void);
+
+struct wire_cst_std_time_twin_sync *frbgen_frb_example_pure_dart_cst_new_box_autoadd_std_time_twin_sync(void);
+
 struct wire_cst_struct_in_lower_level *frbgen_frb_example_pure_dart_cst_new_box_autoadd_struct_in_lower_level(void);
 
 struct wire_cst_struct_with_comments_twin_normal *frbgen_frb_example_pure_dart_cst_new_box_autoadd_struct_with_comments_twin_normal(void);
@@ -14835,6 +16326,12 @@ struct wire_cst_test_id_twin_rust_async *frbgen_frb_example_pure_dart_cst_new_bo
 
 struct wire_cst_test_id_twin_sync *frbgen_frb_example_pure_dart_cst_new_box_autoadd_test_id_twin_sync(void);
 
+struct wire_cst_time_types_twin_normal *frbgen_frb_example_pure_dart_cst_new_box_autoadd_time_types_twin_normal(void);
+
+struct wire_cst_time_types_twin_rust_async *frbgen_frb_example_pure_dart_cst_new_box_autoadd_time_types_twin_rust_async(void);
+
+struct wire_cst_time_types_twin_sync *frbgen_frb_example_pure_dart_cst_new_box_autoadd_time_types_twin_sync(void);
+
 struct wire_cst_translatable_struct_with_dart_code_twin_normal *frbgen_frb_example_pure_dart_cst_new_box_autoadd_translatable_struct_with_dart_code_twin_normal(void);
 
 struct wire_cst_tuple_struct_with_one_field_twin_normal *frbgen_frb_example_pure_dart_cst_new_box_autoadd_tuple_struct_with_one_field_twin_normal(void);
@@ -14949,14 +16446,24 @@ struct wire_cst_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustA
 
 struct wire_cst_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpaqueItemTwinNormal *frbgen_frb_example_pure_dart_cst_new_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpaqueItemTwinNormal(int32_t len);
 
+struct wire_cst_list_BigInt *frbgen_frb_example_pure_dart_cst_new_list_BigInt(int32_t len);
+
 struct wire_cst_list_Chrono_Duration *frbgen_frb_example_pure_dart_cst_new_list_Chrono_Duration(int32_t len);
 
+struct wire_cst_list_Chrono_FixedOffset *frbgen_frb_example_pure_dart_cst_new_list_Chrono_FixedOffset(int32_t len);
+
 struct wire_cst_list_Chrono_Local *frbgen_frb_example_pure_dart_cst_new_list_Chrono_Local(int32_t len);
 
 struct wire_cst_list_Chrono_Naive *frbgen_frb_example_pure_dart_cst_new_list_Chrono_Naive(int32_t len);
 
+struct wire_cst_list_Chrono_NaiveDate *frbgen_frb_example_pure_dart_cst_new_list_Chrono_NaiveDate(int32_t len);
+
 struct wire_cst_list_DartOpaque *frbgen_frb_example_pure_dart_cst_new_list_DartOpaque(int32_t len);
 
+struct wire_cst_list_Decimal *frbgen_frb_example_pure_dart_cst_new_list_Decimal(int32_t len);
+
+struct wire_cst_list_JsonValue *frbgen_frb_example_pure_dart_cst_new_list_JsonValue(int32_t len);
+
 struct wire_cst_list_RustOpaque_HideDataTwinMoi *frbgen_frb_example_pure_dart_cst_new_list_RustOpaque_HideDataTwinMoi(int32_t len);
 
 struct wire_cst_list_RustOpaque_HideDataTwinNormal *frbgen_frb_example_pure_dart_cst_new_list_RustOpaque_HideDataTwinNormal(int32_t len);
@@ -14969,10 +16476,16 @@ struct wire_cst_list_RustOpaque_HideDataTwinSync *frbgen_frb_example_pure_dart_c
 
 struct wire_cst_list_RustOpaque_HideDataTwinSyncMoi *frbgen_frb_example_pure_dart_cst_new_list_RustOpaque_HideDataTwinSyncMoi(int32_t len);
 
+struct wire_cst_list_StdDuration *frbgen_frb_example_pure_dart_cst_new_list_StdDuration(int32_t len);
+
+struct wire_cst_list_StdSystemTime *frbgen_frb_example_pure_dart_cst_new_list_StdSystemTime(int32_t len);
+
 struct wire_cst_list_StreamSink_i_32_Dco *frbgen_frb_example_pure_dart_cst_new_list_StreamSink_i_32_Dco(int32_t len);
 
 struct wire_cst_list_String *frbgen_frb_example_pure_dart_cst_new_list_String(int32_t len);
 
+struct wire_cst_list_TimeDuration *frbgen_frb_example_pure_dart_cst_new_list_TimeDuration(int32_t len);
+
 struct wire_cst_list_Uuid *frbgen_frb_example_pure_dart_cst_new_list_Uuid(int32_t len);
 
 struct wire_cst_list_application_env_var *frbgen_frb_example_pure_dart_cst_new_list_application_env_var(int32_t len);
@@ -15225,6 +16738,7 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSyncMoi);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_Chrono_Duration);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_Chrono_Naive);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_Chrono_NaiveTime);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_Chrono_Utc);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_DartOpaque);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_RustOpaque_HideDataAnotherTwinMoi);
@@ -15235,6 +16749,11 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_RustOpaque_HideDataTwinRustAsyncMoi);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_RustOpaque_HideDataTwinSync);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_RustOpaque_HideDataTwinSyncMoi);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_StdDuration);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_StdSystemTime);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_TimeDuration);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_TimeOffsetDateTime);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_TimePrimitiveDateTime);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_a_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_a_twin_rust_async);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_a_twin_sync);
@@ -15266,6 +16785,9 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_benchmark_blob_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_benchmark_blob_twin_rust_async);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_benchmark_blob_twin_sync);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_big_numbers_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_big_numbers_twin_rust_async);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_big_numbers_twin_sync);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_bool);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_c_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_c_twin_rust_async);
@@ -15291,6 +16813,7 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_customized_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_customized_twin_rust_async);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_customized_twin_sync);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_dart_implementable_trait_twin_normal_implementor);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_dart_opaque_nested_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_dart_opaque_nested_twin_rust_async);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_dart_opaque_nested_twin_sync);
@@ -15346,6 +16869,9 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_i_8);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_isize);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_item_container_solution_two_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_json_document_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_json_document_twin_rust_async);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_json_document_twin_sync);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_kitchen_sink_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_kitchen_sink_twin_rust_async);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_kitchen_sink_twin_sync);
@@ -15390,6 +16916,9 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_opt_vecs_twin_sync);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_raw_string_mirrored);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_record_string_i_32);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_schedule_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_schedule_twin_rust_async);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_schedule_twin_sync);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_sequences);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_simple_enum_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_simple_enum_twin_rust_async);
@@ -15405,6 +16934,9 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_some_struct_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_some_struct_twin_rust_async);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_some_struct_twin_sync);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_std_time_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_std_time_twin_rust_async);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_std_time_twin_sync);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_struct_in_lower_level);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_struct_with_comments_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_struct_with_comments_twin_rust_async);
@@ -15447,6 +16979,9 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_test_id_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_test_id_twin_rust_async);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_test_id_twin_sync);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_time_types_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_time_types_twin_rust_async);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_time_types_twin_sync);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_translatable_struct_with_dart_code_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_tuple_struct_with_one_field_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_box_autoadd_tuple_struct_with_one_field_twin_rust_async);
@@ -15504,18 +17039,26 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSync);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSyncMoi);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpaqueItemTwinNormal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_list_BigInt);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_list_Chrono_Duration);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_list_Chrono_FixedOffset);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_list_Chrono_Local);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_list_Chrono_Naive);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_list_Chrono_NaiveDate);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_list_DartOpaque);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_list_Decimal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_list_JsonValue);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_list_RustOpaque_HideDataTwinMoi);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_list_RustOpaque_HideDataTwinNormal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_list_RustOpaque_HideDataTwinRustAsync);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_list_RustOpaque_HideDataTwinRustAsyncMoi);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_list_RustOpaque_HideDataTwinSync);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_list_RustOpaque_HideDataTwinSyncMoi);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_list_StdDuration);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_list_StdSystemTime);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_list_StreamSink_i_32_Dco);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_list_String);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_list_TimeDuration);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_list_Uuid);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_list_application_env_var);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_cst_new_list_application_mode);
@@ -15733,6 +17276,7 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynMyTraitTwinSyncSseSendSync);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConstructorOpaqueStructTwinNormal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConstructorOpaqueSyncStructTwinNormal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeliberateFailSanityCheckTwinNormal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDroppableTwinNormal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDroppableTwinRustAsync);
@@ -15840,6 +17384,7 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitTwinSse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitTwinSync);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitTwinSyncSse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinMoi);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinNormal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinRustAsync);
@@ -15952,6 +17497,7 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynMyTraitTwinSyncSseSendSync);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConstructorOpaqueStructTwinNormal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConstructorOpaqueSyncStructTwinNormal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeliberateFailSanityCheckTwinNormal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDroppableTwinNormal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDroppableTwinRustAsync);
@@ -16059,6 +17605,7 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitTwinSse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitTwinSync);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitTwinSyncSse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinMoi);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinNormal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinRustAsync);
@@ -16086,30 +17633,54 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__array__return_boxed_raw_feed_id_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__array__use_boxed_blob_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__array__use_msgid_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__async_misc__func_async_never_complete_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__async_misc__func_async_simple_add_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__async_misc__func_async_void_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__async_misc__func_async_wait_for_cancellation_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__async_spawn__simple_use_async_spawn);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__async_spawn__simple_use_async_spawn_blocking);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__async_spawn__simple_use_async_spawn_local);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__attribute__handle_customized_struct_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__attribute__next_user_id_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__benchmark_misc__benchmark_void_semi_serialize);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__big_number__add_decimals_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__big_number__big_int_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__big_number__big_ints_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__big_number__big_numbers_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__big_number__decimal_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__big_number__decimals_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__big_number__multiply_big_ints_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__big_number__optional_big_int_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__big_number__optional_decimal_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__casted_primitive__casted_primitive_i64_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__casted_primitive__casted_primitive_isize_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__casted_primitive__casted_primitive_multi_arg_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__casted_primitive__casted_primitive_u64_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__casted_primitive__casted_primitive_usize_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__casted_primitive__function_for_struct_with_casted_primitive_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__chrono_type__datetime_fixed_offset_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__chrono_type__datetime_fixed_offsets_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__chrono_type__datetime_local_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__chrono_type__datetime_utc_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__chrono_type__duration_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__chrono_type__handle_durations_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__chrono_type__handle_timestamps_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__chrono_type__how_long_does_it_take_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__chrono_type__naive_date_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__chrono_type__naive_dates_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__chrono_type__naive_time_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__chrono_type__naivedatetime_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__chrono_type__optional_datetime_fixed_offset_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__chrono_type__optional_empty_datetime_utc_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__chrono_type__schedule_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__chrono_type__test_chrono_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__chrono_type__test_precise_chrono_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__chrono_tz_type__datetime_tz_name_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__chrono_tz_type__datetime_tz_to_fixed_offset_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__chrono_tz_type__datetime_tz_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__chrono_tz_type__datetime_tzs_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__chrono_tz_type__datetime_with_time_zone_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__chrono_tz_type__optional_datetime_tz_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__comment__function_with_comments_slash_star_star_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__comment__function_with_comments_triple_slash_multi_line_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__comment__function_with_comments_triple_slash_single_line_twin_normal);
@@ -16142,6 +17713,8 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__dart_fn__rust_call_dart_two_args_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__dart_fn__rust_call_dart_with_dart_opaque_arg_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__dart_fn__rust_call_dart_with_dart_opaque_result_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__dart_fn__rust_consume_dart_stream_sum_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__dart_fn__rust_consume_dart_stream_take_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__dart_opaque__async_accept_dart_opaque_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__dart_opaque__clone_dart_opaque_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__dart_opaque__create_enum_dart_opaque_twin_normal);
@@ -16176,6 +17749,13 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__dyn_trait__StructTwoWithTraitForDynTwinNormal_auto_accessor_set_two);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__dyn_trait__StructTwoWithTraitForDynTwinNormal_create_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__dyn_trait__StructTwoWithTraitForDynTwinNormal_simple_method_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__dyn_trait__StructWithDartImplementableTraitTwinNormal_add_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__dyn_trait__StructWithDartImplementableTraitTwinNormal_auto_accessor_get_base);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__dyn_trait__StructWithDartImplementableTraitTwinNormal_auto_accessor_set_base);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__dyn_trait__StructWithDartImplementableTraitTwinNormal_describe_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__dyn_trait__StructWithDartImplementableTraitTwinNormal_new);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__dyn_trait__func_arg_box_dyn_dart_implementable_async_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__dyn_trait__func_arg_box_dyn_dart_implementable_sync_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__dyn_trait__func_arg_dyn_trait_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__enumeration__func_enum_simple_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__enumeration__func_enum_with_discriminant_twin_normal);
@@ -16220,7 +17800,10 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__exception__some_struct_twin_normal_static_return_err_custom_error_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__exception__some_struct_twin_normal_static_return_ok_custom_error_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__exception__stream_sink_throw_anyhow_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__exception__throw_anyhow_io_error_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__exception__throw_anyhow_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__exception__throw_anyhow_with_context_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__exception__typed_exception_return_error_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__external_impl__SimpleOpaqueExternalStructWithMethod_new);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__external_impl__SimpleOpaqueExternalStructWithMethod_simple_external_method);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__external_impl__simple_translatable_external_struct_with_method_simple_external_method);
@@ -16243,6 +17826,15 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__impl_trait__StructTwoWithTraitTwinNormal_simple_trait_fn_with_default_impl_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__inside_macro__another_macro_struct_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__inside_macro__func_macro_struct_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__json_type__create_json_map_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__json_type__create_json_value_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__json_type__json_document_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__json_type__json_map_keys_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__json_type__json_map_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__json_type__json_value_to_string_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__json_type__json_value_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__json_type__json_values_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__json_type__optional_json_value_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__lifetimeable__Lifetimeable_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLtNestedTypeWithLifetimeTwinNormalstatic_greet_borrow_mut_self_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__lifetimeable__Lifetimeable_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLtNestedTypeWithLifetimeTwinNormalstatic_greet_borrow_self_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__lifetimeable__Lifetimeable_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLtTypeWithLifetimeTwinNormalstatic_compute_arg_generic_lifetime_twin_normal);
@@ -16259,6 +17851,10 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__lifetimeable__LtSubStructTwinNormal_greet_borrow_mut_self_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__lifetimeable__LtSubStructTwinNormal_greet_borrow_self_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__lifetimeable__lt_compute_with_lifetime_function_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__map_and_set__func_btree_map_i32_i32_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__map_and_set__func_btree_map_string_struct_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__map_and_set__func_btree_set_i32_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__map_and_set__func_btree_set_string_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__map_and_set__func_hash_map_i32_i32_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__map_and_set__func_hash_map_string_bytes_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__map_and_set__func_hash_map_string_complex_enum_twin_normal);
@@ -16267,6 +17863,8 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__map_and_set__func_hash_map_string_struct_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__map_and_set__func_hash_set_i32_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__map_and_set__func_hash_set_string_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__map_and_set__func_vec_deque_i32_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__map_and_set__func_vec_deque_string_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__method__StaticGetterOnlyTwinNormal_static_getter_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__method__concatenate_with_twin_normal_concatenate_static_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__method__concatenate_with_twin_normal_concatenate_twin_normal);
@@ -16446,8 +18044,10 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__array_twin_sync_sse__return_boxed_raw_feed_id_twin_sync_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__array_twin_sync_sse__use_boxed_blob_twin_sync_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__array_twin_sync_sse__use_msgid_twin_sync_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__async_misc_twin_sse__func_async_never_complete_twin_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__async_misc_twin_sse__func_async_simple_add_twin_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__async_misc_twin_sse__func_async_void_twin_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__async_misc_twin_sse__func_async_wait_for_cancellation_twin_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__attribute_twin_rust_async__handle_customized_struct_twin_rust_async);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__attribute_twin_rust_async__next_user_id_twin_rust_async);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__attribute_twin_rust_async_sse__handle_customized_struct_twin_rust_async_sse);
@@ -17004,54 +18604,134 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__benchmark_api_twin_sync_sse__benchmark_input_bytes_twin_sync_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__benchmark_api_twin_sync_sse__benchmark_output_bytes_twin_sync_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__benchmark_api_twin_sync_sse__benchmark_void_twin_sync_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_rust_async__add_decimals_twin_rust_async);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_rust_async__big_int_twin_rust_async);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_rust_async__big_ints_twin_rust_async);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_rust_async__big_numbers_twin_rust_async);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_rust_async__decimal_twin_rust_async);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_rust_async__decimals_twin_rust_async);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_rust_async__multiply_big_ints_twin_rust_async);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_rust_async__optional_big_int_twin_rust_async);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_rust_async__optional_decimal_twin_rust_async);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_rust_async_sse__add_decimals_twin_rust_async_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_rust_async_sse__big_int_twin_rust_async_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_rust_async_sse__big_ints_twin_rust_async_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_rust_async_sse__big_numbers_twin_rust_async_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_rust_async_sse__decimal_twin_rust_async_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_rust_async_sse__decimals_twin_rust_async_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_rust_async_sse__multiply_big_ints_twin_rust_async_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_rust_async_sse__optional_big_int_twin_rust_async_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_rust_async_sse__optional_decimal_twin_rust_async_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_sse__add_decimals_twin_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_sse__big_int_twin_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_sse__big_ints_twin_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_sse__big_numbers_twin_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_sse__decimal_twin_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_sse__decimals_twin_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_sse__multiply_big_ints_twin_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_sse__optional_big_int_twin_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_sse__optional_decimal_twin_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_sync__add_decimals_twin_sync);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_sync__big_int_twin_sync);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_sync__big_ints_twin_sync);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_sync__big_numbers_twin_sync);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_sync__decimal_twin_sync);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_sync__decimals_twin_sync);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_sync__multiply_big_ints_twin_sync);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_sync__optional_big_int_twin_sync);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_sync__optional_decimal_twin_sync);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_sync_sse__add_decimals_twin_sync_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_sync_sse__big_int_twin_sync_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_sync_sse__big_ints_twin_sync_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_sync_sse__big_numbers_twin_sync_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_sync_sse__decimal_twin_sync_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_sync_sse__decimals_twin_sync_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_sync_sse__multiply_big_ints_twin_sync_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_sync_sse__optional_big_int_twin_sync_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__big_number_twin_sync_sse__optional_decimal_twin_sync_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__datetime_fixed_offset_twin_rust_async);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__datetime_fixed_offsets_twin_rust_async);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__datetime_local_twin_rust_async);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__datetime_utc_twin_rust_async);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__duration_twin_rust_async);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__handle_durations_twin_rust_async);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__handle_timestamps_twin_rust_async);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__how_long_does_it_take_twin_rust_async);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__naive_date_twin_rust_async);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__naive_dates_twin_rust_async);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__naive_time_twin_rust_async);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__naivedatetime_twin_rust_async);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__optional_datetime_fixed_offset_twin_rust_async);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__optional_empty_datetime_utc_twin_rust_async);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__schedule_twin_rust_async);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__test_chrono_twin_rust_async);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__test_precise_chrono_twin_rust_async);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__datetime_fixed_offset_twin_rust_async_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__datetime_fixed_offsets_twin_rust_async_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__datetime_local_twin_rust_async_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__datetime_utc_twin_rust_async_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__duration_twin_rust_async_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__handle_durations_twin_rust_async_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__handle_timestamps_twin_rust_async_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__how_long_does_it_take_twin_rust_async_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__naive_date_twin_rust_async_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__naive_dates_twin_rust_async_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__naive_time_twin_rust_async_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__naivedatetime_twin_rust_async_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__optional_datetime_fixed_offset_twin_rust_async_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__optional_empty_datetime_utc_twin_rust_async_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__schedule_twin_rust_async_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__test_chrono_twin_rust_async_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__test_precise_chrono_twin_rust_async_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sse__datetime_fixed_offset_twin_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sse__datetime_fixed_offsets_twin_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sse__datetime_local_twin_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sse__datetime_utc_twin_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sse__duration_twin_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sse__handle_durations_twin_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sse__handle_timestamps_twin_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sse__how_long_does_it_take_twin_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sse__naive_date_twin_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sse__naive_dates_twin_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sse__naive_time_twin_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sse__naivedatetime_twin_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sse__optional_datetime_fixed_offset_twin_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sse__optional_empty_datetime_utc_twin_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sse__schedule_twin_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sse__test_chrono_twin_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sse__test_precise_chrono_twin_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sync__datetime_fixed_offset_twin_sync);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sync__datetime_fixed_offsets_twin_sync);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sync__datetime_local_twin_sync);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sync__datetime_utc_twin_sync);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sync__duration_twin_sync);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sync__handle_durations_twin_sync);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sync__handle_timestamps_twin_sync);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sync__how_long_does_it_take_twin_sync);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sync__naive_date_twin_sync);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sync__naive_dates_twin_sync);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sync__naive_time_twin_sync);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sync__naivedatetime_twin_sync);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sync__optional_datetime_fixed_offset_twin_sync);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sync__optional_empty_datetime_utc_twin_sync);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sync__schedule_twin_sync);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sync__test_chrono_twin_sync);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sync__test_precise_chrono_twin_sync);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sync_sse__datetime_fixed_offset_twin_sync_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sync_sse__datetime_fixed_offsets_twin_sync_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sync_sse__datetime_local_twin_sync_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sync_sse__datetime_utc_twin_sync_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sync_sse__duration_twin_sync_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sync_sse__handle_durations_twin_sync_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sync_sse__handle_timestamps_twin_sync_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sync_sse__how_long_does_it_take_twin_sync_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sync_sse__naive_date_twin_sync_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sync_sse__naive_dates_twin_sync_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sync_sse__naive_time_twin_sync_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sync_sse__naivedatetime_twin_sync_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sync_sse__optional_datetime_fixed_offset_twin_sync_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sync_sse__optional_empty_datetime_utc_twin_sync_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sync_sse__schedule_twin_sync_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sync_sse__test_chrono_twin_sync_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__chrono_type_twin_sync_sse__test_precise_chrono_twin_sync_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__comment_twin_rust_async__function_with_comments_slash_star_star_twin_rust_async);
@@ -17090,6 +18770,8 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__dart_fn_twin_rust_async__rust_call_dart_two_args_twin_rust_async);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__dart_fn_twin_rust_async__rust_call_dart_with_dart_opaque_arg_twin_rust_async);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__dart_fn_twin_rust_async__rust_call_dart_with_dart_opaque_result_twin_rust_async);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__dart_fn_twin_rust_async__rust_consume_dart_stream_sum_twin_rust_async);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__dart_fn_twin_rust_async__rust_consume_dart_stream_take_twin_rust_async);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__dart_fn_twin_rust_async_sse__rust_call_dart_loopback_twin_rust_async_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__dart_fn_twin_rust_async_sse__rust_call_dart_multi_times_twin_rust_async_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__dart_fn_twin_rust_async_sse__rust_call_dart_one_arg_twin_rust_async_sse);
@@ -17099,6 +18781,8 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__dart_fn_twin_rust_async_sse__rust_call_dart_two_args_twin_rust_async_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__dart_fn_twin_rust_async_sse__rust_call_dart_with_dart_opaque_arg_twin_rust_async_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__dart_fn_twin_rust_async_sse__rust_call_dart_with_dart_opaque_result_twin_rust_async_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__dart_fn_twin_rust_async_sse__rust_consume_dart_stream_sum_twin_rust_async_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__dart_fn_twin_rust_async_sse__rust_consume_dart_stream_take_twin_rust_async_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__dart_fn_twin_sse__rust_call_dart_loopback_twin_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__dart_fn_twin_sse__rust_call_dart_multi_times_twin_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__dart_fn_twin_sse__rust_call_dart_one_arg_twin_sse);
@@ -17108,6 +18792,8 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__dart_fn_twin_sse__rust_call_dart_two_args_twin_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__dart_fn_twin_sse__rust_call_dart_with_dart_opaque_arg_twin_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__dart_fn_twin_sse__rust_call_dart_with_dart_opaque_result_twin_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__dart_fn_twin_sse__rust_consume_dart_stream_sum_twin_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__dart_fn_twin_sse__rust_consume_dart_stream_take_twin_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__dart_opaque_sync_twin_sse__sync_accept_dart_opaque_twin_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__dart_opaque_sync_twin_sse__sync_loopback_twin_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__dart_opaque_sync_twin_sse__sync_option_dart_opaque_twin_sse);
@@ -17303,7 +18989,10 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_rust_async__some_struct_twin_rust_async_static_return_err_custom_error_twin_rust_async);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_rust_async__some_struct_twin_rust_async_static_return_ok_custom_error_twin_rust_async);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_rust_async__stream_sink_throw_anyhow_twin_rust_async);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_rust_async__throw_anyhow_io_error_twin_rust_async);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_rust_async__throw_anyhow_twin_rust_async);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_rust_async__throw_anyhow_with_context_twin_rust_async);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_rust_async__typed_exception_return_error_twin_rust_async);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_rust_async_sse__custom_enum_error_panic_twin_rust_async_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_rust_async_sse__custom_enum_error_return_error_twin_rust_async_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_rust_async_sse__custom_enum_error_return_ok_twin_rust_async_sse);
@@ -17332,7 +19021,10 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_rust_async_sse__some_struct_twin_rust_async_sse_static_return_err_custom_error_twin_rust_async_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_rust_async_sse__some_struct_twin_rust_async_sse_static_return_ok_custom_error_twin_rust_async_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_rust_async_sse__stream_sink_throw_anyhow_twin_rust_async_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_rust_async_sse__throw_anyhow_io_error_twin_rust_async_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_rust_async_sse__throw_anyhow_twin_rust_async_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_rust_async_sse__throw_anyhow_with_context_twin_rust_async_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_rust_async_sse__typed_exception_return_error_twin_rust_async_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_sse__custom_enum_error_panic_twin_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_sse__custom_enum_error_return_error_twin_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_sse__custom_enum_error_return_ok_twin_sse);
@@ -17361,7 +19053,10 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_sse__some_struct_twin_sse_static_return_err_custom_error_twin_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_sse__some_struct_twin_sse_static_return_ok_custom_error_twin_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_sse__stream_sink_throw_anyhow_twin_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_sse__throw_anyhow_io_error_twin_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_sse__throw_anyhow_twin_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_sse__throw_anyhow_with_context_twin_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_sse__typed_exception_return_error_twin_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_sync__custom_enum_error_panic_twin_sync);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_sync__custom_enum_error_return_error_twin_sync);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_sync__custom_enum_error_return_ok_twin_sync);
@@ -17390,7 +19085,10 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_sync__some_struct_twin_sync_static_return_err_custom_error_twin_sync);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_sync__some_struct_twin_sync_static_return_ok_custom_error_twin_sync);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_sync__stream_sink_throw_anyhow_twin_sync);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_sync__throw_anyhow_io_error_twin_sync);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_sync__throw_anyhow_twin_sync);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_sync__throw_anyhow_with_context_twin_sync);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_sync__typed_exception_return_error_twin_sync);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_sync_sse__custom_enum_error_panic_twin_sync_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_sync_sse__custom_enum_error_return_error_twin_sync_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_sync_sse__custom_enum_error_return_ok_twin_sync_sse);
@@ -17419,7 +19117,10 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_sync_sse__some_struct_twin_sync_sse_static_return_err_custom_error_twin_sync_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_sync_sse__some_struct_twin_sync_sse_static_return_ok_custom_error_twin_sync_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_sync_sse__stream_sink_throw_anyhow_twin_sync_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_sync_sse__throw_anyhow_io_error_twin_sync_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_sync_sse__throw_anyhow_twin_sync_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_sync_sse__throw_anyhow_with_context_twin_sync_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__exception_twin_sync_sse__typed_exception_return_error_twin_sync_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__external_type_in_crate_twin_rust_async__call_new_module_system_twin_rust_async);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__external_type_in_crate_twin_rust_async__call_old_module_system_twin_rust_async);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__external_type_in_crate_twin_rust_async__use_imported_enum_twin_rust_async);
@@ -17476,6 +19177,51 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__impl_trait_twin_sync_sse__StructTwoWithTraitTwinSyncSse_simple_trait_fn_receiver_borrow_twin_sync_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__impl_trait_twin_sync_sse__StructTwoWithTraitTwinSyncSse_simple_trait_fn_twin_sync_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__impl_trait_twin_sync_sse__StructTwoWithTraitTwinSyncSse_simple_trait_fn_with_default_impl_twin_sync_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_rust_async__create_json_map_twin_rust_async);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_rust_async__create_json_value_twin_rust_async);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_rust_async__json_document_twin_rust_async);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_rust_async__json_map_keys_twin_rust_async);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_rust_async__json_map_twin_rust_async);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_rust_async__json_value_to_string_twin_rust_async);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_rust_async__json_value_twin_rust_async);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_rust_async__json_values_twin_rust_async);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_rust_async__optional_json_value_twin_rust_async);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_rust_async_sse__create_json_map_twin_rust_async_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_rust_async_sse__create_json_value_twin_rust_async_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_rust_async_sse__json_document_twin_rust_async_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_rust_async_sse__json_map_keys_twin_rust_async_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_rust_async_sse__json_map_twin_rust_async_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_rust_async_sse__json_value_to_string_twin_rust_async_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_rust_async_sse__json_value_twin_rust_async_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_rust_async_sse__json_values_twin_rust_async_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_rust_async_sse__optional_json_value_twin_rust_async_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_sse__create_json_map_twin_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_sse__create_json_value_twin_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_sse__json_document_twin_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_sse__json_map_keys_twin_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_sse__json_map_twin_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_sse__json_value_to_string_twin_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_sse__json_value_twin_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_sse__json_values_twin_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_sse__optional_json_value_twin_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_sync__create_json_map_twin_sync);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_sync__create_json_value_twin_sync);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_sync__json_document_twin_sync);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_sync__json_map_keys_twin_sync);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_sync__json_map_twin_sync);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_sync__json_value_to_string_twin_sync);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_sync__json_value_twin_sync);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_sync__json_values_twin_sync);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_sync__optional_json_value_twin_sync);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_sync_sse__create_json_map_twin_sync_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_sync_sse__create_json_value_twin_sync_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_sync_sse__json_document_twin_sync_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_sync_sse__json_map_keys_twin_sync_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_sync_sse__json_map_twin_sync_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_sync_sse__json_value_to_string_twin_sync_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_sync_sse__json_value_twin_sync_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_sync_sse__json_values_twin_sync_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__json_type_twin_sync_sse__optional_json_value_twin_sync_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__lifetimeable_twin_sync__Lifetimeable_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLtNestedTypeWithLifetimeTwinSyncstatic_greet_borrow_mut_self_twin_sync);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__lifetimeable_twin_sync__Lifetimeable_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLtNestedTypeWithLifetimeTwinSyncstatic_greet_borrow_self_twin_sync);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__lifetimeable_twin_sync__Lifetimeable_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLtTypeWithLifetimeTwinSyncstatic_compute_arg_generic_lifetime_twin_sync);
@@ -17492,6 +19238,10 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__lifetimeable_twin_sync__LtSubStructTwinSync_greet_borrow_mut_self_twin_sync);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__lifetimeable_twin_sync__LtSubStructTwinSync_greet_borrow_self_twin_sync);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__lifetimeable_twin_sync__lt_compute_with_lifetime_function_twin_sync);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_rust_async__func_btree_map_i32_i32_twin_rust_async);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_rust_async__func_btree_map_string_struct_twin_rust_async);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_rust_async__func_btree_set_i32_twin_rust_async);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_rust_async__func_btree_set_string_twin_rust_async);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_rust_async__func_hash_map_i32_i32_twin_rust_async);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_rust_async__func_hash_map_string_bytes_twin_rust_async);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_rust_async__func_hash_map_string_complex_enum_twin_rust_async);
@@ -17500,6 +19250,12 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_rust_async__func_hash_map_string_struct_twin_rust_async);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_rust_async__func_hash_set_i32_twin_rust_async);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_rust_async__func_hash_set_string_twin_rust_async);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_rust_async__func_vec_deque_i32_twin_rust_async);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_rust_async__func_vec_deque_string_twin_rust_async);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_rust_async_sse__func_btree_map_i32_i32_twin_rust_async_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_rust_async_sse__func_btree_map_string_struct_twin_rust_async_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_rust_async_sse__func_btree_set_i32_twin_rust_async_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_rust_async_sse__func_btree_set_string_twin_rust_async_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_rust_async_sse__func_hash_map_i32_i32_twin_rust_async_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_rust_async_sse__func_hash_map_string_bytes_twin_rust_async_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_rust_async_sse__func_hash_map_string_complex_enum_twin_rust_async_sse);
@@ -17508,6 +19264,12 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_rust_async_sse__func_hash_map_string_struct_twin_rust_async_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_rust_async_sse__func_hash_set_i32_twin_rust_async_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_rust_async_sse__func_hash_set_string_twin_rust_async_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_rust_async_sse__func_vec_deque_i32_twin_rust_async_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_rust_async_sse__func_vec_deque_string_twin_rust_async_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_sse__func_btree_map_i32_i32_twin_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_sse__func_btree_map_string_struct_twin_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_sse__func_btree_set_i32_twin_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_sse__func_btree_set_string_twin_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_sse__func_hash_map_i32_i32_twin_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_sse__func_hash_map_string_bytes_twin_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_sse__func_hash_map_string_complex_enum_twin_sse);
@@ -17516,6 +19278,12 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_sse__func_hash_map_string_struct_twin_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_sse__func_hash_set_i32_twin_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_sse__func_hash_set_string_twin_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_sse__func_vec_deque_i32_twin_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_sse__func_vec_deque_string_twin_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_sync__func_btree_map_i32_i32_twin_sync);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_sync__func_btree_map_string_struct_twin_sync);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_sync__func_btree_set_i32_twin_sync);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_sync__func_btree_set_string_twin_sync);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_sync__func_hash_map_i32_i32_twin_sync);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_sync__func_hash_map_string_bytes_twin_sync);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_sync__func_hash_map_string_complex_enum_twin_sync);
@@ -17524,6 +19292,12 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_sync__func_hash_map_string_struct_twin_sync);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_sync__func_hash_set_i32_twin_sync);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_sync__func_hash_set_string_twin_sync);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_sync__func_vec_deque_i32_twin_sync);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_sync__func_vec_deque_string_twin_sync);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_sync_sse__func_btree_map_i32_i32_twin_sync_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_sync_sse__func_btree_map_string_struct_twin_sync_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_sync_sse__func_btree_set_i32_twin_sync_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_sync_sse__func_btree_set_string_twin_sync_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_sync_sse__func_hash_map_i32_i32_twin_sync_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_sync_sse__func_hash_map_string_bytes_twin_sync_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_sync_sse__func_hash_map_string_complex_enum_twin_sync_sse);
@@ -17532,6 +19306,8 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_sync_sse__func_hash_map_string_struct_twin_sync_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_sync_sse__func_hash_set_i32_twin_sync_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_sync_sse__func_hash_set_string_twin_sync_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_sync_sse__func_vec_deque_i32_twin_sync_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__map_and_set_twin_sync_sse__func_vec_deque_string_twin_sync_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__method_twin_rust_async__StaticGetterOnlyTwinRustAsync_static_getter_twin_rust_async);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__method_twin_rust_async__concatenate_with_twin_rust_async_concatenate_static_twin_rust_async);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__method_twin_rust_async__concatenate_with_twin_rust_async_concatenate_twin_rust_async);
@@ -18624,7 +20400,45 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__simple_twin_sse__simple_adder_twin_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__simple_twin_sync__simple_adder_twin_sync);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__simple_twin_sync_sse__simple_adder_twin_sync_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_rust_async__std_duration_twin_rust_async);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_rust_async__std_durations_twin_rust_async);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_rust_async__std_optional_duration_twin_rust_async);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_rust_async__std_optional_system_time_twin_rust_async);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_rust_async__std_system_time_twin_rust_async);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_rust_async__std_system_times_twin_rust_async);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_rust_async__std_time_struct_twin_rust_async);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_rust_async_sse__std_duration_twin_rust_async_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_rust_async_sse__std_durations_twin_rust_async_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_rust_async_sse__std_optional_duration_twin_rust_async_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_rust_async_sse__std_optional_system_time_twin_rust_async_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_rust_async_sse__std_system_time_twin_rust_async_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_rust_async_sse__std_system_times_twin_rust_async_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_rust_async_sse__std_time_struct_twin_rust_async_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_sse__std_duration_twin_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_sse__std_durations_twin_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_sse__std_optional_duration_twin_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_sse__std_optional_system_time_twin_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_sse__std_system_time_twin_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_sse__std_system_times_twin_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_sse__std_time_struct_twin_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_sync__std_duration_twin_sync);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_sync__std_durations_twin_sync);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_sync__std_optional_duration_twin_sync);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_sync__std_optional_system_time_twin_sync);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_sync__std_system_time_twin_sync);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_sync__std_system_times_twin_sync);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_sync__std_time_struct_twin_sync);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_sync_sse__std_duration_twin_sync_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_sync_sse__std_durations_twin_sync_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_sync_sse__std_optional_duration_twin_sync_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_sync_sse__std_optional_system_time_twin_sync_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_sync_sse__std_system_time_twin_sync_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_sync_sse__std_system_times_twin_sync_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__std_time_twin_sync_sse__std_time_struct_twin_sync_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__stream_misc_twin_sse__func_stream_realistic_twin_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__stream_misc_twin_sse__stream_sink_bounded_add_async_twin_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__stream_misc_twin_sse__stream_sink_bounded_add_with_error_twin_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__stream_misc_twin_sse__stream_sink_bounded_keep_latest_twin_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__stream_misc_twin_sse__stream_sink_dart_async_twin_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__stream_twin_rust_async__func_stream_add_value_and_error_twin_rust_async);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__stream_twin_rust_async__func_stream_return_error_twin_rust_async);
@@ -18694,6 +20508,51 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__structure_twin_sync_sse__func_struct_with_zero_field_twin_sync_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__structure_twin_sync_sse__func_tuple_struct_with_one_field_twin_sync_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__structure_twin_sync_sse__func_tuple_struct_with_two_field_twin_sync_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_rust_async__date_twin_rust_async);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_rust_async__negate_time_duration_twin_rust_async);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_rust_async__offset_date_time_twin_rust_async);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_rust_async__optional_offset_date_time_twin_rust_async);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_rust_async__optional_time_duration_twin_rust_async);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_rust_async__primitive_date_time_twin_rust_async);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_rust_async__time_duration_twin_rust_async);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_rust_async__time_durations_twin_rust_async);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_rust_async__time_types_twin_rust_async);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_rust_async_sse__date_twin_rust_async_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_rust_async_sse__negate_time_duration_twin_rust_async_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_rust_async_sse__offset_date_time_twin_rust_async_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_rust_async_sse__optional_offset_date_time_twin_rust_async_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_rust_async_sse__optional_time_duration_twin_rust_async_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_rust_async_sse__primitive_date_time_twin_rust_async_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_rust_async_sse__time_duration_twin_rust_async_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_rust_async_sse__time_durations_twin_rust_async_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_rust_async_sse__time_types_twin_rust_async_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_sse__date_twin_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_sse__negate_time_duration_twin_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_sse__offset_date_time_twin_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_sse__optional_offset_date_time_twin_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_sse__optional_time_duration_twin_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_sse__primitive_date_time_twin_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_sse__time_duration_twin_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_sse__time_durations_twin_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_sse__time_types_twin_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_sync__date_twin_sync);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_sync__negate_time_duration_twin_sync);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_sync__offset_date_time_twin_sync);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_sync__optional_offset_date_time_twin_sync);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_sync__optional_time_duration_twin_sync);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_sync__primitive_date_time_twin_sync);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_sync__time_duration_twin_sync);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_sync__time_durations_twin_sync);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_sync__time_types_twin_sync);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_sync_sse__date_twin_sync_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_sync_sse__negate_time_duration_twin_sync_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_sync_sse__offset_date_time_twin_sync_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_sync_sse__optional_offset_date_time_twin_sync_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_sync_sse__optional_time_duration_twin_sync_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_sync_sse__primitive_date_time_twin_sync_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_sync_sse__time_duration_twin_sync_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_sync_sse__time_durations_twin_sync_sse);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__time_type_twin_sync_sse__time_types_twin_sync_sse);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__tuple_twin_rust_async__test_tuple_2_twin_rust_async);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__tuple_twin_rust_async__test_tuple_twin_rust_async);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__pseudo_manual__tuple_twin_rust_async_sse__test_tuple_2_twin_rust_async_sse);
@@ -18801,6 +20660,13 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__rust_opaque_sync__sync_create_opaque_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__rust_opaque_sync__sync_option_rust_opaque_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__simple__simple_adder_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__std_time__std_duration_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__std_time__std_durations_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__std_time__std_optional_duration_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__std_time__std_optional_system_time_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__std_time__std_system_time_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__std_time__std_system_times_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__std_time__std_time_struct_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__stream__func_stream_add_value_and_error_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__stream__func_stream_return_error_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__stream__func_stream_return_panic_twin_normal);
@@ -18813,6 +20679,9 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__stream__stream_sink_inside_struct_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__stream__stream_sink_inside_vec_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__stream_misc__func_stream_realistic_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__stream_misc__stream_sink_bounded_add_async_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__stream_misc__stream_sink_bounded_add_with_error_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__stream_misc__stream_sink_bounded_keep_latest_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__stream_misc__stream_sink_dart_async_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__structure__func_for_struct_with_dart_keyword_field_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__structure__func_for_struct_with_field_rename_twin_normal);
@@ -18821,6 +20690,15 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__structure__func_struct_with_zero_field_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__structure__func_tuple_struct_with_one_field_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__structure__func_tuple_struct_with_two_field_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__time_type__date_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__time_type__negate_time_duration_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__time_type__offset_date_time_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__time_type__optional_offset_date_time_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__time_type__optional_time_duration_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__time_type__primitive_date_time_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__time_type__time_duration_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__time_type__time_durations_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__time_type__time_types_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__tuple__test_tuple_2_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__tuple__test_tuple_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__type_alias__handle_type_alias_id_twin_normal);
@@ -18829,6 +20707,9 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__uuid_type__handle_nested_uuids_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__uuid_type__handle_uuid_twin_normal);
     dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__api__uuid_type__handle_uuids_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__frb_generated__DartImplementableTraitTwinNormalDartImpl_add_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__frb_generated__DartImplementableTraitTwinNormalDartImpl_describe_twin_normal);
+    dummy_var ^= ((int64_t) (void*) frbgen_frb_example_pure_dart_wire__crate__frb_generated__DartImplementableTraitTwinNormalDartImpl_new);
     dummy_var ^= ((int64_t) (void*) store_dart_post_cobject);
     return dummy_var;
 }
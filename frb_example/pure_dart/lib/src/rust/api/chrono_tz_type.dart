@@ -0,0 +1,34 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.3.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+Future<DateTimeWithOffset> datetimeTzTwinNormal(
+        {required DateTimeWithOffset d}) =>
+    RustLib.instance.api.crateApiChronoTzTypeDatetimeTzTwinNormal(d: d);
+
+Future<DateTimeWithOffset?> optionalDatetimeTzTwinNormal(
+        {DateTimeWithOffset? d}) =>
+    RustLib.instance.api
+        .crateApiChronoTzTypeOptionalDatetimeTzTwinNormal(d: d);
+
+Future<List<DateTimeWithOffset>> datetimeTzsTwinNormal(
+        {required List<DateTimeWithOffset> dates}) =>
+    RustLib.instance.api
+        .crateApiChronoTzTypeDatetimeTzsTwinNormal(dates: dates);
+
+Future<DateTimeWithOffset> datetimeWithTimeZoneTwinNormal(
+        {required DateTimeWithOffset d, required String timeZone}) =>
+    RustLib.instance.api.crateApiChronoTzTypeDatetimeWithTimeZoneTwinNormal(
+        d: d, timeZone: timeZone);
+
+Future<String> datetimeTzNameTwinNormal({required DateTimeWithOffset d}) =>
+    RustLib.instance.api.crateApiChronoTzTypeDatetimeTzNameTwinNormal(d: d);
+
+Future<DateTimeWithOffset> datetimeTzToFixedOffsetTwinNormal(
+        {required DateTimeWithOffset d}) =>
+    RustLib.instance.api
+        .crateApiChronoTzTypeDatetimeTzToFixedOffsetTwinNormal(d: d);
@@ -10,6 +10,20 @@ Future<int> funcArgDynTraitTwinNormal(
         {required SimpleTraitForDynTwinNormal arg}) =>
     RustLib.instance.api.crateApiDynTraitFuncArgDynTraitTwinNormal(arg: arg);
 
+Future<int> funcArgBoxDynDartImplementableSyncTwinNormal(
+        {required DartImplementableTraitTwinNormal arg,
+        required int a,
+        required int b}) =>
+    RustLib.instance.api
+        .crateApiDynTraitFuncArgBoxDynDartImplementableSyncTwinNormal(
+            arg: arg, a: a, b: b);
+
+Future<String> funcArgBoxDynDartImplementableAsyncTwinNormal(
+        {required DartImplementableTraitTwinNormal arg, required int value}) =>
+    RustLib.instance.api
+        .crateApiDynTraitFuncArgBoxDynDartImplementableAsyncTwinNormal(
+            arg: arg, value: value);
+
 // Rust type: RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<StructOneWithTraitForDynTwinNormal>>
 abstract class StructOneWithTraitForDynTwinNormal
     implements RustOpaqueInterface, SimpleTraitForDynTwinNormal {
@@ -42,6 +56,29 @@ abstract class StructTwoWithTraitForDynTwinNormal
   Future<int> simpleMethodTwinNormal();
 }
 
+// Rust type: RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<StructWithDartImplementableTraitTwinNormal>>
+abstract class StructWithDartImplementableTraitTwinNormal
+    implements RustOpaqueInterface, DartImplementableTraitTwinNormal {
+  Future<int> addTwinNormal({required int a, required int b});
+
+  int get base;
+
+  set base(int base);
+
+  Future<String> describeTwinNormal({required int value});
+
+  factory StructWithDartImplementableTraitTwinNormal({required int base}) =>
+      RustLib.instance.api
+          .crateApiDynTraitStructWithDartImplementableTraitTwinNormalNew(
+              base: base);
+}
+
+abstract class DartImplementableTraitTwinNormal {
+  Future<int> addTwinNormal({required int a, required int b});
+
+  Future<String> describeTwinNormal({required int value});
+}
+
 abstract class SimpleTraitForDynTwinNormal {
   Future<int> simpleMethodTwinNormal();
 }
@@ -8,6 +8,7 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
 part 'exception.freezed.dart';
 
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `fmt`
 Future<int> funcReturnErrorTwinNormal() =>
     RustLib.instance.api.crateApiExceptionFuncReturnErrorTwinNormal();
 
@@ -72,6 +73,16 @@ Future<void> panicWithCustomResultTwinNormal() =>
 Future<Stream<String>> streamSinkThrowAnyhowTwinNormal() =>
     RustLib.instance.api.crateApiExceptionStreamSinkThrowAnyhowTwinNormal();
 
+Future<int> typedExceptionReturnErrorTwinNormal({required int variant}) =>
+    RustLib.instance.api
+        .crateApiExceptionTypedExceptionReturnErrorTwinNormal(variant: variant);
+
+Future<void> throwAnyhowWithContextTwinNormal() => RustLib.instance.api
+    .crateApiExceptionThrowAnyhowWithContextTwinNormal();
+
+Future<void> throwAnyhowIoErrorTwinNormal() => RustLib.instance.api
+    .crateApiExceptionThrowAnyhowIoErrorTwinNormal();
+
 @freezed
 sealed class CustomEnumErrorTwinNormal
     with _$CustomEnumErrorTwinNormal
@@ -277,3 +288,54 @@ class SomeStructTwinNormal {
           runtimeType == other.runtimeType &&
           value == other.value;
 }
+
+sealed class TypedExceptionErrorTwinNormal implements FrbException {
+  const TypedExceptionErrorTwinNormal();
+
+  /// The `Display` representation of the error in Rust
+  String get displayMessage;
+}
+
+class TypedExceptionErrorTwinNormal_NotFound
+    extends TypedExceptionErrorTwinNormal {
+  final String path;
+
+  @override
+  final String displayMessage;
+
+  const TypedExceptionErrorTwinNormal_NotFound(
+      {required this.path, this.displayMessage = ''});
+
+  @override
+  String toString() => displayMessage.isEmpty
+      ? 'TypedExceptionErrorTwinNormal_NotFound'
+      : displayMessage;
+}
+
+class TypedExceptionErrorTwinNormal_Io extends TypedExceptionErrorTwinNormal {
+  final String field0;
+
+  @override
+  final String displayMessage;
+
+  const TypedExceptionErrorTwinNormal_Io(this.field0,
+      {this.displayMessage = ''});
+
+  @override
+  String toString() => displayMessage.isEmpty
+      ? 'TypedExceptionErrorTwinNormal_Io'
+      : displayMessage;
+}
+
+class TypedExceptionErrorTwinNormal_Unknown
+    extends TypedExceptionErrorTwinNormal {
+  @override
+  final String displayMessage;
+
+  const TypedExceptionErrorTwinNormal_Unknown({this.displayMessage = ''});
+
+  @override
+  String toString() => displayMessage.isEmpty
+      ? 'TypedExceptionErrorTwinNormal_Unknown'
+      : displayMessage;
+}
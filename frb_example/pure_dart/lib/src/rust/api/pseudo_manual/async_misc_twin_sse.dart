@@ -13,3 +13,9 @@ Future<int> funcAsyncSimpleAddTwinSse({required int a, required int b}) =>
     RustLib.instance.api
         .crateApiPseudoManualAsyncMiscTwinSseFuncAsyncSimpleAddTwinSse(
             a: a, b: b);
+
+Future<void> funcAsyncNeverCompleteTwinSse() => RustLib.instance.api
+    .crateApiPseudoManualAsyncMiscTwinSseFuncAsyncNeverCompleteTwinSse();
+
+Future<void> funcAsyncWaitForCancellationTwinSse() => RustLib.instance.api
+    .crateApiPseudoManualAsyncMiscTwinSseFuncAsyncWaitForCancellationTwinSse();
@@ -0,0 +1,78 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.3.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import 'package:decimal/decimal.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+Future<Decimal> decimalTwinRustAsync({required Decimal d}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualBigNumberTwinRustAsyncDecimalTwinRustAsync(d: d);
+
+Future<Decimal> addDecimalsTwinRustAsync(
+        {required Decimal a, required Decimal b}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualBigNumberTwinRustAsyncAddDecimalsTwinRustAsync(
+            a: a, b: b);
+
+Future<Decimal?> optionalDecimalTwinRustAsync({Decimal? d}) => RustLib
+    .instance.api
+    .crateApiPseudoManualBigNumberTwinRustAsyncOptionalDecimalTwinRustAsync(
+        d: d);
+
+Future<List<Decimal>> decimalsTwinRustAsync(
+        {required List<Decimal> decimals}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualBigNumberTwinRustAsyncDecimalsTwinRustAsync(
+            decimals: decimals);
+
+Future<BigInt> bigIntTwinRustAsync({required BigInt i}) => RustLib.instance.api
+    .crateApiPseudoManualBigNumberTwinRustAsyncBigIntTwinRustAsync(i: i);
+
+Future<BigInt> multiplyBigIntsTwinRustAsync(
+        {required BigInt a, required BigInt b}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualBigNumberTwinRustAsyncMultiplyBigIntsTwinRustAsync(
+            a: a, b: b);
+
+Future<BigInt?> optionalBigIntTwinRustAsync({BigInt? i}) => RustLib
+    .instance.api
+    .crateApiPseudoManualBigNumberTwinRustAsyncOptionalBigIntTwinRustAsync(
+        i: i);
+
+Future<List<BigInt>> bigIntsTwinRustAsync({required List<BigInt> ints}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualBigNumberTwinRustAsyncBigIntsTwinRustAsync(
+            ints: ints);
+
+Future<BigNumbersTwinRustAsync> bigNumbersTwinRustAsync(
+        {required BigNumbersTwinRustAsync value}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualBigNumberTwinRustAsyncBigNumbersTwinRustAsync(
+            value: value);
+
+class BigNumbersTwinRustAsync {
+  final Decimal amount;
+  final Decimal? discount;
+  final BigInt serial;
+
+  const BigNumbersTwinRustAsync({
+    required this.amount,
+    this.discount,
+    required this.serial,
+  });
+
+  @override
+  int get hashCode => amount.hashCode ^ discount.hashCode ^ serial.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BigNumbersTwinRustAsync &&
+          runtimeType == other.runtimeType &&
+          amount == other.amount &&
+          discount == other.discount &&
+          serial == other.serial;
+}
@@ -0,0 +1,79 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.3.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import 'package:decimal/decimal.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+Future<Decimal> decimalTwinRustAsyncSse({required Decimal d}) => RustLib
+    .instance.api
+    .crateApiPseudoManualBigNumberTwinRustAsyncSseDecimalTwinRustAsyncSse(d: d);
+
+Future<Decimal> addDecimalsTwinRustAsyncSse(
+        {required Decimal a, required Decimal b}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualBigNumberTwinRustAsyncSseAddDecimalsTwinRustAsyncSse(
+            a: a, b: b);
+
+Future<Decimal?> optionalDecimalTwinRustAsyncSse({Decimal? d}) => RustLib
+    .instance.api
+    .crateApiPseudoManualBigNumberTwinRustAsyncSseOptionalDecimalTwinRustAsyncSse(
+        d: d);
+
+Future<List<Decimal>> decimalsTwinRustAsyncSse(
+        {required List<Decimal> decimals}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualBigNumberTwinRustAsyncSseDecimalsTwinRustAsyncSse(
+            decimals: decimals);
+
+Future<BigInt> bigIntTwinRustAsyncSse({required BigInt i}) => RustLib
+    .instance.api
+    .crateApiPseudoManualBigNumberTwinRustAsyncSseBigIntTwinRustAsyncSse(i: i);
+
+Future<BigInt> multiplyBigIntsTwinRustAsyncSse(
+        {required BigInt a, required BigInt b}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualBigNumberTwinRustAsyncSseMultiplyBigIntsTwinRustAsyncSse(
+            a: a, b: b);
+
+Future<BigInt?> optionalBigIntTwinRustAsyncSse({BigInt? i}) => RustLib
+    .instance.api
+    .crateApiPseudoManualBigNumberTwinRustAsyncSseOptionalBigIntTwinRustAsyncSse(
+        i: i);
+
+Future<List<BigInt>> bigIntsTwinRustAsyncSse({required List<BigInt> ints}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualBigNumberTwinRustAsyncSseBigIntsTwinRustAsyncSse(
+            ints: ints);
+
+Future<BigNumbersTwinRustAsyncSse> bigNumbersTwinRustAsyncSse(
+        {required BigNumbersTwinRustAsyncSse value}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualBigNumberTwinRustAsyncSseBigNumbersTwinRustAsyncSse(
+            value: value);
+
+class BigNumbersTwinRustAsyncSse {
+  final Decimal amount;
+  final Decimal? discount;
+  final BigInt serial;
+
+  const BigNumbersTwinRustAsyncSse({
+    required this.amount,
+    this.discount,
+    required this.serial,
+  });
+
+  @override
+  int get hashCode => amount.hashCode ^ discount.hashCode ^ serial.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BigNumbersTwinRustAsyncSse &&
+          runtimeType == other.runtimeType &&
+          amount == other.amount &&
+          discount == other.discount &&
+          serial == other.serial;
+}
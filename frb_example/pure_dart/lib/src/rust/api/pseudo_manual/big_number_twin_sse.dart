@@ -0,0 +1,68 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.3.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import 'package:decimal/decimal.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+Future<Decimal> decimalTwinSse({required Decimal d}) => RustLib.instance.api
+    .crateApiPseudoManualBigNumberTwinSseDecimalTwinSse(d: d);
+
+Future<Decimal> addDecimalsTwinSse({required Decimal a, required Decimal b}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualBigNumberTwinSseAddDecimalsTwinSse(a: a, b: b);
+
+Future<Decimal?> optionalDecimalTwinSse({Decimal? d}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualBigNumberTwinSseOptionalDecimalTwinSse(d: d);
+
+Future<List<Decimal>> decimalsTwinSse({required List<Decimal> decimals}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualBigNumberTwinSseDecimalsTwinSse(
+            decimals: decimals);
+
+Future<BigInt> bigIntTwinSse({required BigInt i}) => RustLib.instance.api
+    .crateApiPseudoManualBigNumberTwinSseBigIntTwinSse(i: i);
+
+Future<BigInt> multiplyBigIntsTwinSse({required BigInt a, required BigInt b}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualBigNumberTwinSseMultiplyBigIntsTwinSse(a: a, b: b);
+
+Future<BigInt?> optionalBigIntTwinSse({BigInt? i}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualBigNumberTwinSseOptionalBigIntTwinSse(i: i);
+
+Future<List<BigInt>> bigIntsTwinSse({required List<BigInt> ints}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualBigNumberTwinSseBigIntsTwinSse(ints: ints);
+
+Future<BigNumbersTwinSse> bigNumbersTwinSse(
+        {required BigNumbersTwinSse value}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualBigNumberTwinSseBigNumbersTwinSse(value: value);
+
+class BigNumbersTwinSse {
+  final Decimal amount;
+  final Decimal? discount;
+  final BigInt serial;
+
+  const BigNumbersTwinSse({
+    required this.amount,
+    this.discount,
+    required this.serial,
+  });
+
+  @override
+  int get hashCode => amount.hashCode ^ discount.hashCode ^ serial.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BigNumbersTwinSse &&
+          runtimeType == other.runtimeType &&
+          amount == other.amount &&
+          discount == other.discount &&
+          serial == other.serial;
+}
@@ -0,0 +1,69 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.3.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import 'package:decimal/decimal.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+Decimal decimalTwinSyncSse({required Decimal d}) => RustLib.instance.api
+    .crateApiPseudoManualBigNumberTwinSyncSseDecimalTwinSyncSse(d: d);
+
+Decimal addDecimalsTwinSyncSse({required Decimal a, required Decimal b}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualBigNumberTwinSyncSseAddDecimalsTwinSyncSse(
+            a: a, b: b);
+
+Decimal? optionalDecimalTwinSyncSse({Decimal? d}) => RustLib.instance.api
+    .crateApiPseudoManualBigNumberTwinSyncSseOptionalDecimalTwinSyncSse(d: d);
+
+List<Decimal> decimalsTwinSyncSse({required List<Decimal> decimals}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualBigNumberTwinSyncSseDecimalsTwinSyncSse(
+            decimals: decimals);
+
+BigInt bigIntTwinSyncSse({required BigInt i}) => RustLib.instance.api
+    .crateApiPseudoManualBigNumberTwinSyncSseBigIntTwinSyncSse(i: i);
+
+BigInt multiplyBigIntsTwinSyncSse({required BigInt a, required BigInt b}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualBigNumberTwinSyncSseMultiplyBigIntsTwinSyncSse(
+            a: a, b: b);
+
+BigInt? optionalBigIntTwinSyncSse({BigInt? i}) => RustLib.instance.api
+    .crateApiPseudoManualBigNumberTwinSyncSseOptionalBigIntTwinSyncSse(i: i);
+
+List<BigInt> bigIntsTwinSyncSse({required List<BigInt> ints}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualBigNumberTwinSyncSseBigIntsTwinSyncSse(ints: ints);
+
+BigNumbersTwinSyncSse bigNumbersTwinSyncSse(
+        {required BigNumbersTwinSyncSse value}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualBigNumberTwinSyncSseBigNumbersTwinSyncSse(
+            value: value);
+
+class BigNumbersTwinSyncSse {
+  final Decimal amount;
+  final Decimal? discount;
+  final BigInt serial;
+
+  const BigNumbersTwinSyncSse({
+    required this.amount,
+    this.discount,
+    required this.serial,
+  });
+
+  @override
+  int get hashCode => amount.hashCode ^ discount.hashCode ^ serial.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BigNumbersTwinSyncSse &&
+          runtimeType == other.runtimeType &&
+          amount == other.amount &&
+          discount == other.discount &&
+          serial == other.serial;
+}
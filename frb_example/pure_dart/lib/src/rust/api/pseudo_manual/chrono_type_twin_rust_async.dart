@@ -57,6 +57,44 @@ Future<Duration> howLongDoesItTakeTwinRustAsync(
         .crateApiPseudoManualChronoTypeTwinRustAsyncHowLongDoesItTakeTwinRustAsync(
             mine: mine);
 
+Future<DateTime> naiveDateTwinRustAsync({required DateTime d}) => RustLib
+    .instance.api
+    .crateApiPseudoManualChronoTypeTwinRustAsyncNaiveDateTwinRustAsync(d: d);
+
+Future<Duration> naiveTimeTwinRustAsync({required Duration d}) => RustLib
+    .instance.api
+    .crateApiPseudoManualChronoTypeTwinRustAsyncNaiveTimeTwinRustAsync(d: d);
+
+Future<DateTimeWithOffset> datetimeFixedOffsetTwinRustAsync(
+        {required DateTimeWithOffset d}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualChronoTypeTwinRustAsyncDatetimeFixedOffsetTwinRustAsync(
+            d: d);
+
+Future<DateTimeWithOffset?> optionalDatetimeFixedOffsetTwinRustAsync(
+        {DateTimeWithOffset? d}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualChronoTypeTwinRustAsyncOptionalDatetimeFixedOffsetTwinRustAsync(
+            d: d);
+
+Future<List<DateTime>> naiveDatesTwinRustAsync(
+        {required List<DateTime> dates}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualChronoTypeTwinRustAsyncNaiveDatesTwinRustAsync(
+            dates: dates);
+
+Future<List<DateTimeWithOffset>> datetimeFixedOffsetsTwinRustAsync(
+        {required List<DateTimeWithOffset> dates}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualChronoTypeTwinRustAsyncDatetimeFixedOffsetsTwinRustAsync(
+            dates: dates);
+
+Future<ScheduleTwinRustAsync> scheduleTwinRustAsync(
+        {required ScheduleTwinRustAsync schedule}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualChronoTypeTwinRustAsyncScheduleTwinRustAsync(
+            schedule: schedule);
+
 class FeatureChronoTwinRustAsync {
   final DateTime utc;
   final DateTime local;
@@ -85,6 +123,34 @@ class FeatureChronoTwinRustAsync {
           naive == other.naive;
 }
 
+class ScheduleTwinRustAsync {
+  final DateTime day;
+  final Duration starts;
+  final Duration? ends;
+  final DateTimeWithOffset at;
+
+  const ScheduleTwinRustAsync({
+    required this.day,
+    required this.starts,
+    this.ends,
+    required this.at,
+  });
+
+  @override
+  int get hashCode =>
+      day.hashCode ^ starts.hashCode ^ ends.hashCode ^ at.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ScheduleTwinRustAsync &&
+          runtimeType == other.runtimeType &&
+          day == other.day &&
+          starts == other.starts &&
+          ends == other.ends &&
+          at == other.at;
+}
+
 class TestChronoTwinRustAsync {
   final DateTime? dt;
   final DateTime? dt2;
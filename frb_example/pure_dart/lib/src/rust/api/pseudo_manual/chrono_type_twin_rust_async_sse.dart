@@ -59,6 +59,46 @@ Future<Duration> howLongDoesItTakeTwinRustAsyncSse(
         .crateApiPseudoManualChronoTypeTwinRustAsyncSseHowLongDoesItTakeTwinRustAsyncSse(
             mine: mine);
 
+Future<DateTime> naiveDateTwinRustAsyncSse({required DateTime d}) => RustLib
+    .instance.api
+    .crateApiPseudoManualChronoTypeTwinRustAsyncSseNaiveDateTwinRustAsyncSse(
+        d: d);
+
+Future<Duration> naiveTimeTwinRustAsyncSse({required Duration d}) => RustLib
+    .instance.api
+    .crateApiPseudoManualChronoTypeTwinRustAsyncSseNaiveTimeTwinRustAsyncSse(
+        d: d);
+
+Future<DateTimeWithOffset> datetimeFixedOffsetTwinRustAsyncSse(
+        {required DateTimeWithOffset d}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualChronoTypeTwinRustAsyncSseDatetimeFixedOffsetTwinRustAsyncSse(
+            d: d);
+
+Future<DateTimeWithOffset?> optionalDatetimeFixedOffsetTwinRustAsyncSse(
+        {DateTimeWithOffset? d}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualChronoTypeTwinRustAsyncSseOptionalDatetimeFixedOffsetTwinRustAsyncSse(
+            d: d);
+
+Future<List<DateTime>> naiveDatesTwinRustAsyncSse(
+        {required List<DateTime> dates}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualChronoTypeTwinRustAsyncSseNaiveDatesTwinRustAsyncSse(
+            dates: dates);
+
+Future<List<DateTimeWithOffset>> datetimeFixedOffsetsTwinRustAsyncSse(
+        {required List<DateTimeWithOffset> dates}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualChronoTypeTwinRustAsyncSseDatetimeFixedOffsetsTwinRustAsyncSse(
+            dates: dates);
+
+Future<ScheduleTwinRustAsyncSse> scheduleTwinRustAsyncSse(
+        {required ScheduleTwinRustAsyncSse schedule}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualChronoTypeTwinRustAsyncSseScheduleTwinRustAsyncSse(
+            schedule: schedule);
+
 class FeatureChronoTwinRustAsyncSse {
   final DateTime utc;
   final DateTime local;
@@ -87,6 +127,34 @@ class FeatureChronoTwinRustAsyncSse {
           naive == other.naive;
 }
 
+class ScheduleTwinRustAsyncSse {
+  final DateTime day;
+  final Duration starts;
+  final Duration? ends;
+  final DateTimeWithOffset at;
+
+  const ScheduleTwinRustAsyncSse({
+    required this.day,
+    required this.starts,
+    this.ends,
+    required this.at,
+  });
+
+  @override
+  int get hashCode =>
+      day.hashCode ^ starts.hashCode ^ ends.hashCode ^ at.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ScheduleTwinRustAsyncSse &&
+          runtimeType == other.runtimeType &&
+          day == other.day &&
+          starts == other.starts &&
+          ends == other.ends &&
+          at == other.at;
+}
+
 class TestChronoTwinRustAsyncSse {
   final DateTime? dt;
   final DateTime? dt2;
@@ -51,6 +51,37 @@ Future<Duration> howLongDoesItTakeTwinSse(
         .crateApiPseudoManualChronoTypeTwinSseHowLongDoesItTakeTwinSse(
             mine: mine);
 
+Future<DateTime> naiveDateTwinSse({required DateTime d}) => RustLib.instance.api
+    .crateApiPseudoManualChronoTypeTwinSseNaiveDateTwinSse(d: d);
+
+Future<Duration> naiveTimeTwinSse({required Duration d}) => RustLib.instance.api
+    .crateApiPseudoManualChronoTypeTwinSseNaiveTimeTwinSse(d: d);
+
+Future<DateTimeWithOffset> datetimeFixedOffsetTwinSse(
+        {required DateTimeWithOffset d}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualChronoTypeTwinSseDatetimeFixedOffsetTwinSse(d: d);
+
+Future<DateTimeWithOffset?> optionalDatetimeFixedOffsetTwinSse(
+        {DateTimeWithOffset? d}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualChronoTypeTwinSseOptionalDatetimeFixedOffsetTwinSse(
+            d: d);
+
+Future<List<DateTime>> naiveDatesTwinSse({required List<DateTime> dates}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualChronoTypeTwinSseNaiveDatesTwinSse(dates: dates);
+
+Future<List<DateTimeWithOffset>> datetimeFixedOffsetsTwinSse(
+        {required List<DateTimeWithOffset> dates}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualChronoTypeTwinSseDatetimeFixedOffsetsTwinSse(
+            dates: dates);
+
+Future<ScheduleTwinSse> scheduleTwinSse({required ScheduleTwinSse schedule}) =>
+    RustLib.instance.api.crateApiPseudoManualChronoTypeTwinSseScheduleTwinSse(
+        schedule: schedule);
+
 class FeatureChronoTwinSse {
   final DateTime utc;
   final DateTime local;
@@ -79,6 +110,34 @@ class FeatureChronoTwinSse {
           naive == other.naive;
 }
 
+class ScheduleTwinSse {
+  final DateTime day;
+  final Duration starts;
+  final Duration? ends;
+  final DateTimeWithOffset at;
+
+  const ScheduleTwinSse({
+    required this.day,
+    required this.starts,
+    this.ends,
+    required this.at,
+  });
+
+  @override
+  int get hashCode =>
+      day.hashCode ^ starts.hashCode ^ ends.hashCode ^ at.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ScheduleTwinSse &&
+          runtimeType == other.runtimeType &&
+          day == other.day &&
+          starts == other.starts &&
+          ends == other.ends &&
+          at == other.at;
+}
+
 class TestChronoTwinSse {
   final DateTime? dt;
   final DateTime? dt2;
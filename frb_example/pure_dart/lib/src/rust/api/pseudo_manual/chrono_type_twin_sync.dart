@@ -48,6 +48,39 @@ Duration howLongDoesItTakeTwinSync({required FeatureChronoTwinSync mine}) =>
         .crateApiPseudoManualChronoTypeTwinSyncHowLongDoesItTakeTwinSync(
             mine: mine);
 
+DateTime naiveDateTwinSync({required DateTime d}) => RustLib.instance.api
+    .crateApiPseudoManualChronoTypeTwinSyncNaiveDateTwinSync(d: d);
+
+Duration naiveTimeTwinSync({required Duration d}) => RustLib.instance.api
+    .crateApiPseudoManualChronoTypeTwinSyncNaiveTimeTwinSync(d: d);
+
+DateTimeWithOffset datetimeFixedOffsetTwinSync(
+        {required DateTimeWithOffset d}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualChronoTypeTwinSyncDatetimeFixedOffsetTwinSync(
+            d: d);
+
+DateTimeWithOffset? optionalDatetimeFixedOffsetTwinSync(
+        {DateTimeWithOffset? d}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualChronoTypeTwinSyncOptionalDatetimeFixedOffsetTwinSync(
+            d: d);
+
+List<DateTime> naiveDatesTwinSync({required List<DateTime> dates}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualChronoTypeTwinSyncNaiveDatesTwinSync(dates: dates);
+
+List<DateTimeWithOffset> datetimeFixedOffsetsTwinSync(
+        {required List<DateTimeWithOffset> dates}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualChronoTypeTwinSyncDatetimeFixedOffsetsTwinSync(
+            dates: dates);
+
+ScheduleTwinSync scheduleTwinSync({required ScheduleTwinSync schedule}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualChronoTypeTwinSyncScheduleTwinSync(
+            schedule: schedule);
+
 class FeatureChronoTwinSync {
   final DateTime utc;
   final DateTime local;
@@ -76,6 +109,34 @@ class FeatureChronoTwinSync {
           naive == other.naive;
 }
 
+class ScheduleTwinSync {
+  final DateTime day;
+  final Duration starts;
+  final Duration? ends;
+  final DateTimeWithOffset at;
+
+  const ScheduleTwinSync({
+    required this.day,
+    required this.starts,
+    this.ends,
+    required this.at,
+  });
+
+  @override
+  int get hashCode =>
+      day.hashCode ^ starts.hashCode ^ ends.hashCode ^ at.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ScheduleTwinSync &&
+          runtimeType == other.runtimeType &&
+          day == other.day &&
+          starts == other.starts &&
+          ends == other.ends &&
+          at == other.at;
+}
+
 class TestChronoTwinSync {
   final DateTime? dt;
   final DateTime? dt2;
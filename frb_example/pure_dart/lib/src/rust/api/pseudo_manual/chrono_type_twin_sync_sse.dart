@@ -49,6 +49,41 @@ Duration howLongDoesItTakeTwinSyncSse(
         .crateApiPseudoManualChronoTypeTwinSyncSseHowLongDoesItTakeTwinSyncSse(
             mine: mine);
 
+DateTime naiveDateTwinSyncSse({required DateTime d}) => RustLib.instance.api
+    .crateApiPseudoManualChronoTypeTwinSyncSseNaiveDateTwinSyncSse(d: d);
+
+Duration naiveTimeTwinSyncSse({required Duration d}) => RustLib.instance.api
+    .crateApiPseudoManualChronoTypeTwinSyncSseNaiveTimeTwinSyncSse(d: d);
+
+DateTimeWithOffset datetimeFixedOffsetTwinSyncSse(
+        {required DateTimeWithOffset d}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualChronoTypeTwinSyncSseDatetimeFixedOffsetTwinSyncSse(
+            d: d);
+
+DateTimeWithOffset? optionalDatetimeFixedOffsetTwinSyncSse(
+        {DateTimeWithOffset? d}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualChronoTypeTwinSyncSseOptionalDatetimeFixedOffsetTwinSyncSse(
+            d: d);
+
+List<DateTime> naiveDatesTwinSyncSse({required List<DateTime> dates}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualChronoTypeTwinSyncSseNaiveDatesTwinSyncSse(
+            dates: dates);
+
+List<DateTimeWithOffset> datetimeFixedOffsetsTwinSyncSse(
+        {required List<DateTimeWithOffset> dates}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualChronoTypeTwinSyncSseDatetimeFixedOffsetsTwinSyncSse(
+            dates: dates);
+
+ScheduleTwinSyncSse scheduleTwinSyncSse(
+        {required ScheduleTwinSyncSse schedule}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualChronoTypeTwinSyncSseScheduleTwinSyncSse(
+            schedule: schedule);
+
 class FeatureChronoTwinSyncSse {
   final DateTime utc;
   final DateTime local;
@@ -77,6 +112,34 @@ class FeatureChronoTwinSyncSse {
           naive == other.naive;
 }
 
+class ScheduleTwinSyncSse {
+  final DateTime day;
+  final Duration starts;
+  final Duration? ends;
+  final DateTimeWithOffset at;
+
+  const ScheduleTwinSyncSse({
+    required this.day,
+    required this.starts,
+    this.ends,
+    required this.at,
+  });
+
+  @override
+  int get hashCode =>
+      day.hashCode ^ starts.hashCode ^ ends.hashCode ^ at.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ScheduleTwinSyncSse &&
+          runtimeType == other.runtimeType &&
+          day == other.day &&
+          starts == other.starts &&
+          ends == other.ends &&
+          at == other.at;
+}
+
 class TestChronoTwinSyncSse {
   final DateTime? dt;
   final DateTime? dt2;
@@ -66,6 +66,18 @@ Future<void> rustCallDartReturnResultTwinRustAsyncSse(
         .crateApiPseudoManualDartFnTwinRustAsyncSseRustCallDartReturnResultTwinRustAsyncSse(
             callback: callback, expectOutput: expectOutput);
 
+Future<int> rustConsumeDartStreamSumTwinRustAsyncSse(
+        {required Stream<int> stream}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualDartFnTwinRustAsyncSseRustConsumeDartStreamSumTwinRustAsyncSse(
+            stream: stream);
+
+Future<List<String>> rustConsumeDartStreamTakeTwinRustAsyncSse(
+        {required Stream<String> stream, required BigInt count}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualDartFnTwinRustAsyncSseRustConsumeDartStreamTakeTwinRustAsyncSse(
+            stream: stream, count: count);
+
 class DemoStructForRustCallDartTwinRustAsyncSse {
   final String name;
 
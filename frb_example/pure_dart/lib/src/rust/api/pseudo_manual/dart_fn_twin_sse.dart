@@ -66,6 +66,17 @@ Future<void> rustCallDartReturnResultTwinSse(
         .crateApiPseudoManualDartFnTwinSseRustCallDartReturnResultTwinSse(
             callback: callback, expectOutput: expectOutput);
 
+Future<int> rustConsumeDartStreamSumTwinSse({required Stream<int> stream}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualDartFnTwinSseRustConsumeDartStreamSumTwinSse(
+            stream: stream);
+
+Future<List<String>> rustConsumeDartStreamTakeTwinSse(
+        {required Stream<String> stream, required BigInt count}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualDartFnTwinSseRustConsumeDartStreamTakeTwinSse(
+            stream: stream, count: count);
+
 class DemoStructForRustCallDartTwinSse {
   final String name;
 
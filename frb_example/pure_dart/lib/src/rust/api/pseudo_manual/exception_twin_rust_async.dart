@@ -8,6 +8,7 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
 part 'exception_twin_rust_async.freezed.dart';
 
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `fmt`
 Future<int> funcReturnErrorTwinRustAsync() => RustLib.instance.api
     .crateApiPseudoManualExceptionTwinRustAsyncFuncReturnErrorTwinRustAsync();
 
@@ -77,6 +78,17 @@ Future<Stream<String>> streamSinkThrowAnyhowTwinRustAsync() => RustLib
     .instance.api
     .crateApiPseudoManualExceptionTwinRustAsyncStreamSinkThrowAnyhowTwinRustAsync();
 
+Future<int> typedExceptionReturnErrorTwinRustAsync({required int variant}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualExceptionTwinRustAsyncTypedExceptionReturnErrorTwinRustAsync(
+            variant: variant);
+
+Future<void> throwAnyhowWithContextTwinRustAsync() => RustLib.instance.api
+    .crateApiPseudoManualExceptionTwinRustAsyncThrowAnyhowWithContextTwinRustAsync();
+
+Future<void> throwAnyhowIoErrorTwinRustAsync() => RustLib.instance.api
+    .crateApiPseudoManualExceptionTwinRustAsyncThrowAnyhowIoErrorTwinRustAsync();
+
 @freezed
 sealed class CustomEnumErrorTwinRustAsync
     with _$CustomEnumErrorTwinRustAsync
@@ -289,3 +301,55 @@ class SomeStructTwinRustAsync {
           runtimeType == other.runtimeType &&
           value == other.value;
 }
+
+sealed class TypedExceptionErrorTwinRustAsync implements FrbException {
+  const TypedExceptionErrorTwinRustAsync();
+
+  /// The `Display` representation of the error in Rust
+  String get displayMessage;
+}
+
+class TypedExceptionErrorTwinRustAsync_NotFound
+    extends TypedExceptionErrorTwinRustAsync {
+  final String path;
+
+  @override
+  final String displayMessage;
+
+  const TypedExceptionErrorTwinRustAsync_NotFound(
+      {required this.path, this.displayMessage = ''});
+
+  @override
+  String toString() => displayMessage.isEmpty
+      ? 'TypedExceptionErrorTwinRustAsync_NotFound'
+      : displayMessage;
+}
+
+class TypedExceptionErrorTwinRustAsync_Io
+    extends TypedExceptionErrorTwinRustAsync {
+  final String field0;
+
+  @override
+  final String displayMessage;
+
+  const TypedExceptionErrorTwinRustAsync_Io(this.field0,
+      {this.displayMessage = ''});
+
+  @override
+  String toString() => displayMessage.isEmpty
+      ? 'TypedExceptionErrorTwinRustAsync_Io'
+      : displayMessage;
+}
+
+class TypedExceptionErrorTwinRustAsync_Unknown
+    extends TypedExceptionErrorTwinRustAsync {
+  @override
+  final String displayMessage;
+
+  const TypedExceptionErrorTwinRustAsync_Unknown({this.displayMessage = ''});
+
+  @override
+  String toString() => displayMessage.isEmpty
+      ? 'TypedExceptionErrorTwinRustAsync_Unknown'
+      : displayMessage;
+}
@@ -8,6 +8,7 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
 part 'exception_twin_rust_async_sse.freezed.dart';
 
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `fmt`
 Future<int> funcReturnErrorTwinRustAsyncSse() => RustLib.instance.api
     .crateApiPseudoManualExceptionTwinRustAsyncSseFuncReturnErrorTwinRustAsyncSse();
 
@@ -77,6 +78,17 @@ Future<Stream<String>> streamSinkThrowAnyhowTwinRustAsyncSse() => RustLib
     .instance.api
     .crateApiPseudoManualExceptionTwinRustAsyncSseStreamSinkThrowAnyhowTwinRustAsyncSse();
 
+Future<int> typedExceptionReturnErrorTwinRustAsyncSse({required int variant}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualExceptionTwinRustAsyncSseTypedExceptionReturnErrorTwinRustAsyncSse(
+            variant: variant);
+
+Future<void> throwAnyhowWithContextTwinRustAsyncSse() => RustLib.instance.api
+    .crateApiPseudoManualExceptionTwinRustAsyncSseThrowAnyhowWithContextTwinRustAsyncSse();
+
+Future<void> throwAnyhowIoErrorTwinRustAsyncSse() => RustLib.instance.api
+    .crateApiPseudoManualExceptionTwinRustAsyncSseThrowAnyhowIoErrorTwinRustAsyncSse();
+
 @freezed
 sealed class CustomEnumErrorTwinRustAsyncSse
     with _$CustomEnumErrorTwinRustAsyncSse
@@ -289,3 +301,55 @@ class SomeStructTwinRustAsyncSse {
           runtimeType == other.runtimeType &&
           value == other.value;
 }
+
+sealed class TypedExceptionErrorTwinRustAsyncSse implements FrbException {
+  const TypedExceptionErrorTwinRustAsyncSse();
+
+  /// The `Display` representation of the error in Rust
+  String get displayMessage;
+}
+
+class TypedExceptionErrorTwinRustAsyncSse_NotFound
+    extends TypedExceptionErrorTwinRustAsyncSse {
+  final String path;
+
+  @override
+  final String displayMessage;
+
+  const TypedExceptionErrorTwinRustAsyncSse_NotFound(
+      {required this.path, this.displayMessage = ''});
+
+  @override
+  String toString() => displayMessage.isEmpty
+      ? 'TypedExceptionErrorTwinRustAsyncSse_NotFound'
+      : displayMessage;
+}
+
+class TypedExceptionErrorTwinRustAsyncSse_Io
+    extends TypedExceptionErrorTwinRustAsyncSse {
+  final String field0;
+
+  @override
+  final String displayMessage;
+
+  const TypedExceptionErrorTwinRustAsyncSse_Io(this.field0,
+      {this.displayMessage = ''});
+
+  @override
+  String toString() => displayMessage.isEmpty
+      ? 'TypedExceptionErrorTwinRustAsyncSse_Io'
+      : displayMessage;
+}
+
+class TypedExceptionErrorTwinRustAsyncSse_Unknown
+    extends TypedExceptionErrorTwinRustAsyncSse {
+  @override
+  final String displayMessage;
+
+  const TypedExceptionErrorTwinRustAsyncSse_Unknown({this.displayMessage = ''});
+
+  @override
+  String toString() => displayMessage.isEmpty
+      ? 'TypedExceptionErrorTwinRustAsyncSse_Unknown'
+      : displayMessage;
+}
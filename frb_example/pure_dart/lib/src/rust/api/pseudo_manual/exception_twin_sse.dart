@@ -8,6 +8,7 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
 part 'exception_twin_sse.freezed.dart';
 
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `fmt`
 Future<int> funcReturnErrorTwinSse() => RustLib.instance.api
     .crateApiPseudoManualExceptionTwinSseFuncReturnErrorTwinSse();
 
@@ -75,6 +76,17 @@ Future<void> panicWithCustomResultTwinSse() => RustLib.instance.api
 Future<Stream<String>> streamSinkThrowAnyhowTwinSse() => RustLib.instance.api
     .crateApiPseudoManualExceptionTwinSseStreamSinkThrowAnyhowTwinSse();
 
+Future<int> typedExceptionReturnErrorTwinSse({required int variant}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualExceptionTwinSseTypedExceptionReturnErrorTwinSse(
+            variant: variant);
+
+Future<void> throwAnyhowWithContextTwinSse() => RustLib.instance.api
+    .crateApiPseudoManualExceptionTwinSseThrowAnyhowWithContextTwinSse();
+
+Future<void> throwAnyhowIoErrorTwinSse() => RustLib.instance.api
+    .crateApiPseudoManualExceptionTwinSseThrowAnyhowIoErrorTwinSse();
+
 @freezed
 sealed class CustomEnumErrorTwinSse
     with _$CustomEnumErrorTwinSse
@@ -277,3 +289,50 @@ class SomeStructTwinSse {
           runtimeType == other.runtimeType &&
           value == other.value;
 }
+
+sealed class TypedExceptionErrorTwinSse implements FrbException {
+  const TypedExceptionErrorTwinSse();
+
+  /// The `Display` representation of the error in Rust
+  String get displayMessage;
+}
+
+class TypedExceptionErrorTwinSse_NotFound extends TypedExceptionErrorTwinSse {
+  final String path;
+
+  @override
+  final String displayMessage;
+
+  const TypedExceptionErrorTwinSse_NotFound(
+      {required this.path, this.displayMessage = ''});
+
+  @override
+  String toString() => displayMessage.isEmpty
+      ? 'TypedExceptionErrorTwinSse_NotFound'
+      : displayMessage;
+}
+
+class TypedExceptionErrorTwinSse_Io extends TypedExceptionErrorTwinSse {
+  final String field0;
+
+  @override
+  final String displayMessage;
+
+  const TypedExceptionErrorTwinSse_Io(this.field0, {this.displayMessage = ''});
+
+  @override
+  String toString() =>
+      displayMessage.isEmpty ? 'TypedExceptionErrorTwinSse_Io' : displayMessage;
+}
+
+class TypedExceptionErrorTwinSse_Unknown extends TypedExceptionErrorTwinSse {
+  @override
+  final String displayMessage;
+
+  const TypedExceptionErrorTwinSse_Unknown({this.displayMessage = ''});
+
+  @override
+  String toString() => displayMessage.isEmpty
+      ? 'TypedExceptionErrorTwinSse_Unknown'
+      : displayMessage;
+}
@@ -8,6 +8,7 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
 part 'exception_twin_sync.freezed.dart';
 
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `fmt`
 int funcReturnErrorTwinSync() => RustLib.instance.api
     .crateApiPseudoManualExceptionTwinSyncFuncReturnErrorTwinSync();
 
@@ -73,6 +74,17 @@ void panicWithCustomResultTwinSync() => RustLib.instance.api
 Stream<String> streamSinkThrowAnyhowTwinSync() => RustLib.instance.api
     .crateApiPseudoManualExceptionTwinSyncStreamSinkThrowAnyhowTwinSync();
 
+int typedExceptionReturnErrorTwinSync({required int variant}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualExceptionTwinSyncTypedExceptionReturnErrorTwinSync(
+            variant: variant);
+
+void throwAnyhowWithContextTwinSync() => RustLib.instance.api
+    .crateApiPseudoManualExceptionTwinSyncThrowAnyhowWithContextTwinSync();
+
+void throwAnyhowIoErrorTwinSync() => RustLib.instance.api
+    .crateApiPseudoManualExceptionTwinSyncThrowAnyhowIoErrorTwinSync();
+
 @freezed
 sealed class CustomEnumErrorTwinSync
     with _$CustomEnumErrorTwinSync
@@ -274,3 +286,51 @@ class SomeStructTwinSync {
           runtimeType == other.runtimeType &&
           value == other.value;
 }
+
+sealed class TypedExceptionErrorTwinSync implements FrbException {
+  const TypedExceptionErrorTwinSync();
+
+  /// The `Display` representation of the error in Rust
+  String get displayMessage;
+}
+
+class TypedExceptionErrorTwinSync_NotFound extends TypedExceptionErrorTwinSync {
+  final String path;
+
+  @override
+  final String displayMessage;
+
+  const TypedExceptionErrorTwinSync_NotFound(
+      {required this.path, this.displayMessage = ''});
+
+  @override
+  String toString() => displayMessage.isEmpty
+      ? 'TypedExceptionErrorTwinSync_NotFound'
+      : displayMessage;
+}
+
+class TypedExceptionErrorTwinSync_Io extends TypedExceptionErrorTwinSync {
+  final String field0;
+
+  @override
+  final String displayMessage;
+
+  const TypedExceptionErrorTwinSync_Io(this.field0, {this.displayMessage = ''});
+
+  @override
+  String toString() => displayMessage.isEmpty
+      ? 'TypedExceptionErrorTwinSync_Io'
+      : displayMessage;
+}
+
+class TypedExceptionErrorTwinSync_Unknown extends TypedExceptionErrorTwinSync {
+  @override
+  final String displayMessage;
+
+  const TypedExceptionErrorTwinSync_Unknown({this.displayMessage = ''});
+
+  @override
+  String toString() => displayMessage.isEmpty
+      ? 'TypedExceptionErrorTwinSync_Unknown'
+      : displayMessage;
+}
@@ -8,6 +8,7 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
 part 'exception_twin_sync_sse.freezed.dart';
 
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `fmt`
 int funcReturnErrorTwinSyncSse() => RustLib.instance.api
     .crateApiPseudoManualExceptionTwinSyncSseFuncReturnErrorTwinSyncSse();
 
@@ -75,6 +76,17 @@ void panicWithCustomResultTwinSyncSse() => RustLib.instance.api
 Stream<String> streamSinkThrowAnyhowTwinSyncSse() => RustLib.instance.api
     .crateApiPseudoManualExceptionTwinSyncSseStreamSinkThrowAnyhowTwinSyncSse();
 
+int typedExceptionReturnErrorTwinSyncSse({required int variant}) => RustLib
+    .instance.api
+    .crateApiPseudoManualExceptionTwinSyncSseTypedExceptionReturnErrorTwinSyncSse(
+        variant: variant);
+
+void throwAnyhowWithContextTwinSyncSse() => RustLib.instance.api
+    .crateApiPseudoManualExceptionTwinSyncSseThrowAnyhowWithContextTwinSyncSse();
+
+void throwAnyhowIoErrorTwinSyncSse() => RustLib.instance.api
+    .crateApiPseudoManualExceptionTwinSyncSseThrowAnyhowIoErrorTwinSyncSse();
+
 @freezed
 sealed class CustomEnumErrorTwinSyncSse
     with _$CustomEnumErrorTwinSyncSse
@@ -277,3 +289,54 @@ class SomeStructTwinSyncSse {
           runtimeType == other.runtimeType &&
           value == other.value;
 }
+
+sealed class TypedExceptionErrorTwinSyncSse implements FrbException {
+  const TypedExceptionErrorTwinSyncSse();
+
+  /// The `Display` representation of the error in Rust
+  String get displayMessage;
+}
+
+class TypedExceptionErrorTwinSyncSse_NotFound
+    extends TypedExceptionErrorTwinSyncSse {
+  final String path;
+
+  @override
+  final String displayMessage;
+
+  const TypedExceptionErrorTwinSyncSse_NotFound(
+      {required this.path, this.displayMessage = ''});
+
+  @override
+  String toString() => displayMessage.isEmpty
+      ? 'TypedExceptionErrorTwinSyncSse_NotFound'
+      : displayMessage;
+}
+
+class TypedExceptionErrorTwinSyncSse_Io extends TypedExceptionErrorTwinSyncSse {
+  final String field0;
+
+  @override
+  final String displayMessage;
+
+  const TypedExceptionErrorTwinSyncSse_Io(this.field0,
+      {this.displayMessage = ''});
+
+  @override
+  String toString() => displayMessage.isEmpty
+      ? 'TypedExceptionErrorTwinSyncSse_Io'
+      : displayMessage;
+}
+
+class TypedExceptionErrorTwinSyncSse_Unknown
+    extends TypedExceptionErrorTwinSyncSse {
+  @override
+  final String displayMessage;
+
+  const TypedExceptionErrorTwinSyncSse_Unknown({this.displayMessage = ''});
+
+  @override
+  String toString() => displayMessage.isEmpty
+      ? 'TypedExceptionErrorTwinSyncSse_Unknown'
+      : displayMessage;
+}
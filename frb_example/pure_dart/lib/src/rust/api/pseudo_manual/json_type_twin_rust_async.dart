@@ -0,0 +1,77 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.3.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+Future<Object?> jsonValueTwinRustAsync({required Object? value}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualJsonTypeTwinRustAsyncJsonValueTwinRustAsync(
+            value: value);
+
+Future<Object?> optionalJsonValueTwinRustAsync({Object? value}) => RustLib
+    .instance.api
+    .crateApiPseudoManualJsonTypeTwinRustAsyncOptionalJsonValueTwinRustAsync(
+        value: value);
+
+Future<List<Object?>> jsonValuesTwinRustAsync(
+        {required List<Object?> values}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualJsonTypeTwinRustAsyncJsonValuesTwinRustAsync(
+            values: values);
+
+Future<Map<String, dynamic>> jsonMapTwinRustAsync(
+        {required Map<String, dynamic> map}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualJsonTypeTwinRustAsyncJsonMapTwinRustAsync(
+            map: map);
+
+Future<String> jsonValueToStringTwinRustAsync({required Object? value}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualJsonTypeTwinRustAsyncJsonValueToStringTwinRustAsync(
+            value: value);
+
+Future<List<String>> jsonMapKeysTwinRustAsync(
+        {required Map<String, dynamic> map}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualJsonTypeTwinRustAsyncJsonMapKeysTwinRustAsync(
+            map: map);
+
+Future<Object?> createJsonValueTwinRustAsync() => RustLib.instance.api
+    .crateApiPseudoManualJsonTypeTwinRustAsyncCreateJsonValueTwinRustAsync();
+
+Future<Map<String, dynamic>> createJsonMapTwinRustAsync() =>
+    RustLib.instance.api
+        .crateApiPseudoManualJsonTypeTwinRustAsyncCreateJsonMapTwinRustAsync();
+
+Future<JsonDocumentTwinRustAsync> jsonDocumentTwinRustAsync(
+        {required JsonDocumentTwinRustAsync document}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualJsonTypeTwinRustAsyncJsonDocumentTwinRustAsync(
+            document: document);
+
+class JsonDocumentTwinRustAsync {
+  final Object? body;
+  final Map<String, dynamic> meta;
+  final Object? extra;
+
+  const JsonDocumentTwinRustAsync({
+    required this.body,
+    required this.meta,
+    this.extra,
+  });
+
+  @override
+  int get hashCode => body.hashCode ^ meta.hashCode ^ extra.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is JsonDocumentTwinRustAsync &&
+          runtimeType == other.runtimeType &&
+          body == other.body &&
+          meta == other.meta &&
+          extra == other.extra;
+}
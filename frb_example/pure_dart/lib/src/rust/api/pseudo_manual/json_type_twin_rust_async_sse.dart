@@ -0,0 +1,77 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.3.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+Future<Object?> jsonValueTwinRustAsyncSse({required Object? value}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualJsonTypeTwinRustAsyncSseJsonValueTwinRustAsyncSse(
+            value: value);
+
+Future<Object?> optionalJsonValueTwinRustAsyncSse({Object? value}) => RustLib
+    .instance.api
+    .crateApiPseudoManualJsonTypeTwinRustAsyncSseOptionalJsonValueTwinRustAsyncSse(
+        value: value);
+
+Future<List<Object?>> jsonValuesTwinRustAsyncSse(
+        {required List<Object?> values}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualJsonTypeTwinRustAsyncSseJsonValuesTwinRustAsyncSse(
+            values: values);
+
+Future<Map<String, dynamic>> jsonMapTwinRustAsyncSse(
+        {required Map<String, dynamic> map}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualJsonTypeTwinRustAsyncSseJsonMapTwinRustAsyncSse(
+            map: map);
+
+Future<String> jsonValueToStringTwinRustAsyncSse({required Object? value}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualJsonTypeTwinRustAsyncSseJsonValueToStringTwinRustAsyncSse(
+            value: value);
+
+Future<List<String>> jsonMapKeysTwinRustAsyncSse(
+        {required Map<String, dynamic> map}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualJsonTypeTwinRustAsyncSseJsonMapKeysTwinRustAsyncSse(
+            map: map);
+
+Future<Object?> createJsonValueTwinRustAsyncSse() => RustLib.instance.api
+    .crateApiPseudoManualJsonTypeTwinRustAsyncSseCreateJsonValueTwinRustAsyncSse();
+
+Future<Map<String, dynamic>> createJsonMapTwinRustAsyncSse() => RustLib
+    .instance.api
+    .crateApiPseudoManualJsonTypeTwinRustAsyncSseCreateJsonMapTwinRustAsyncSse();
+
+Future<JsonDocumentTwinRustAsyncSse> jsonDocumentTwinRustAsyncSse(
+        {required JsonDocumentTwinRustAsyncSse document}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualJsonTypeTwinRustAsyncSseJsonDocumentTwinRustAsyncSse(
+            document: document);
+
+class JsonDocumentTwinRustAsyncSse {
+  final Object? body;
+  final Map<String, dynamic> meta;
+  final Object? extra;
+
+  const JsonDocumentTwinRustAsyncSse({
+    required this.body,
+    required this.meta,
+    this.extra,
+  });
+
+  @override
+  int get hashCode => body.hashCode ^ meta.hashCode ^ extra.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is JsonDocumentTwinRustAsyncSse &&
+          runtimeType == other.runtimeType &&
+          body == other.body &&
+          meta == other.meta &&
+          extra == other.extra;
+}
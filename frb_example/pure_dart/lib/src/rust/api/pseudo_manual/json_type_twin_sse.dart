@@ -0,0 +1,67 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.3.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+Future<Object?> jsonValueTwinSse({required Object? value}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualJsonTypeTwinSseJsonValueTwinSse(value: value);
+
+Future<Object?> optionalJsonValueTwinSse({Object? value}) => RustLib
+    .instance.api
+    .crateApiPseudoManualJsonTypeTwinSseOptionalJsonValueTwinSse(value: value);
+
+Future<List<Object?>> jsonValuesTwinSse({required List<Object?> values}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualJsonTypeTwinSseJsonValuesTwinSse(values: values);
+
+Future<Map<String, dynamic>> jsonMapTwinSse(
+        {required Map<String, dynamic> map}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualJsonTypeTwinSseJsonMapTwinSse(map: map);
+
+Future<String> jsonValueToStringTwinSse({required Object? value}) => RustLib
+    .instance.api
+    .crateApiPseudoManualJsonTypeTwinSseJsonValueToStringTwinSse(value: value);
+
+Future<List<String>> jsonMapKeysTwinSse({required Map<String, dynamic> map}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualJsonTypeTwinSseJsonMapKeysTwinSse(map: map);
+
+Future<Object?> createJsonValueTwinSse() => RustLib.instance.api
+    .crateApiPseudoManualJsonTypeTwinSseCreateJsonValueTwinSse();
+
+Future<Map<String, dynamic>> createJsonMapTwinSse() => RustLib.instance.api
+    .crateApiPseudoManualJsonTypeTwinSseCreateJsonMapTwinSse();
+
+Future<JsonDocumentTwinSse> jsonDocumentTwinSse(
+        {required JsonDocumentTwinSse document}) =>
+    RustLib.instance.api.crateApiPseudoManualJsonTypeTwinSseJsonDocumentTwinSse(
+        document: document);
+
+class JsonDocumentTwinSse {
+  final Object? body;
+  final Map<String, dynamic> meta;
+  final Object? extra;
+
+  const JsonDocumentTwinSse({
+    required this.body,
+    required this.meta,
+    this.extra,
+  });
+
+  @override
+  int get hashCode => body.hashCode ^ meta.hashCode ^ extra.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is JsonDocumentTwinSse &&
+          runtimeType == other.runtimeType &&
+          body == other.body &&
+          meta == other.meta &&
+          extra == other.extra;
+}
@@ -0,0 +1,69 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.3.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+Object? jsonValueTwinSyncSse({required Object? value}) => RustLib.instance.api
+    .crateApiPseudoManualJsonTypeTwinSyncSseJsonValueTwinSyncSse(value: value);
+
+Object? optionalJsonValueTwinSyncSse({Object? value}) => RustLib.instance.api
+    .crateApiPseudoManualJsonTypeTwinSyncSseOptionalJsonValueTwinSyncSse(
+        value: value);
+
+List<Object?> jsonValuesTwinSyncSse({required List<Object?> values}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualJsonTypeTwinSyncSseJsonValuesTwinSyncSse(
+            values: values);
+
+Map<String, dynamic> jsonMapTwinSyncSse({required Map<String, dynamic> map}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualJsonTypeTwinSyncSseJsonMapTwinSyncSse(map: map);
+
+String jsonValueToStringTwinSyncSse({required Object? value}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualJsonTypeTwinSyncSseJsonValueToStringTwinSyncSse(
+            value: value);
+
+List<String> jsonMapKeysTwinSyncSse({required Map<String, dynamic> map}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualJsonTypeTwinSyncSseJsonMapKeysTwinSyncSse(
+            map: map);
+
+Object? createJsonValueTwinSyncSse() => RustLib.instance.api
+    .crateApiPseudoManualJsonTypeTwinSyncSseCreateJsonValueTwinSyncSse();
+
+Map<String, dynamic> createJsonMapTwinSyncSse() => RustLib.instance.api
+    .crateApiPseudoManualJsonTypeTwinSyncSseCreateJsonMapTwinSyncSse();
+
+JsonDocumentTwinSyncSse jsonDocumentTwinSyncSse(
+        {required JsonDocumentTwinSyncSse document}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualJsonTypeTwinSyncSseJsonDocumentTwinSyncSse(
+            document: document);
+
+class JsonDocumentTwinSyncSse {
+  final Object? body;
+  final Map<String, dynamic> meta;
+  final Object? extra;
+
+  const JsonDocumentTwinSyncSse({
+    required this.body,
+    required this.meta,
+    this.extra,
+  });
+
+  @override
+  int get hashCode => body.hashCode ^ meta.hashCode ^ extra.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is JsonDocumentTwinSyncSse &&
+          runtimeType == other.runtimeType &&
+          body == other.body &&
+          meta == other.meta &&
+          extra == other.extra;
+}
@@ -57,3 +57,37 @@ Future<Map<String, KitchenSinkTwinRustAsync>>
         RustLib.instance.api
             .crateApiPseudoManualMapAndSetTwinRustAsyncFuncHashMapStringComplexEnumTwinRustAsync(
                 arg: arg);
+
+Future<Map<int, int>> funcBtreeMapI32I32TwinRustAsync(
+        {required Map<int, int> arg}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualMapAndSetTwinRustAsyncFuncBtreeMapI32I32TwinRustAsync(
+            arg: arg);
+
+Future<Set<int>> funcBtreeSetI32TwinRustAsync({required Set<int> arg}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualMapAndSetTwinRustAsyncFuncBtreeSetI32TwinRustAsync(
+            arg: arg);
+
+Future<Map<String, MySize>> funcBtreeMapStringStructTwinRustAsync(
+        {required Map<String, MySize> arg}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualMapAndSetTwinRustAsyncFuncBtreeMapStringStructTwinRustAsync(
+            arg: arg);
+
+Future<Set<String>> funcBtreeSetStringTwinRustAsync(
+        {required Set<String> arg}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualMapAndSetTwinRustAsyncFuncBtreeSetStringTwinRustAsync(
+            arg: arg);
+
+Future<Int32List> funcVecDequeI32TwinRustAsync({required Int32List arg}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualMapAndSetTwinRustAsyncFuncVecDequeI32TwinRustAsync(
+            arg: arg);
+
+Future<List<String>> funcVecDequeStringTwinRustAsync(
+        {required List<String> arg}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualMapAndSetTwinRustAsyncFuncVecDequeStringTwinRustAsync(
+            arg: arg);
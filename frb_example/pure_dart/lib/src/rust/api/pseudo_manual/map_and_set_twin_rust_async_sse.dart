@@ -59,3 +59,39 @@ Future<Map<String, KitchenSinkTwinRustAsyncSse>>
         RustLib.instance.api
             .crateApiPseudoManualMapAndSetTwinRustAsyncSseFuncHashMapStringComplexEnumTwinRustAsyncSse(
                 arg: arg);
+
+Future<Map<int, int>> funcBtreeMapI32I32TwinRustAsyncSse(
+        {required Map<int, int> arg}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualMapAndSetTwinRustAsyncSseFuncBtreeMapI32I32TwinRustAsyncSse(
+            arg: arg);
+
+Future<
+    Set<
+        int>> funcBtreeSetI32TwinRustAsyncSse({required Set<int> arg}) => RustLib
+    .instance.api
+    .crateApiPseudoManualMapAndSetTwinRustAsyncSseFuncBtreeSetI32TwinRustAsyncSse(
+        arg: arg);
+
+Future<Map<String, MySize>> funcBtreeMapStringStructTwinRustAsyncSse(
+        {required Map<String, MySize> arg}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualMapAndSetTwinRustAsyncSseFuncBtreeMapStringStructTwinRustAsyncSse(
+            arg: arg);
+
+Future<Set<String>> funcBtreeSetStringTwinRustAsyncSse(
+        {required Set<String> arg}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualMapAndSetTwinRustAsyncSseFuncBtreeSetStringTwinRustAsyncSse(
+            arg: arg);
+
+Future<Int32List> funcVecDequeI32TwinRustAsyncSse({required Int32List arg}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualMapAndSetTwinRustAsyncSseFuncVecDequeI32TwinRustAsyncSse(
+            arg: arg);
+
+Future<List<String>> funcVecDequeStringTwinRustAsyncSse(
+        {required List<String> arg}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualMapAndSetTwinRustAsyncSseFuncVecDequeStringTwinRustAsyncSse(
+            arg: arg);
@@ -50,3 +50,33 @@ Future<Map<String, KitchenSinkTwinSse>> funcHashMapStringComplexEnumTwinSse(
     RustLib.instance.api
         .crateApiPseudoManualMapAndSetTwinSseFuncHashMapStringComplexEnumTwinSse(
             arg: arg);
+
+Future<Map<int, int>> funcBtreeMapI32I32TwinSse(
+        {required Map<int, int> arg}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualMapAndSetTwinSseFuncBtreeMapI32I32TwinSse(
+            arg: arg);
+
+Future<Set<int>> funcBtreeSetI32TwinSse({required Set<int> arg}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualMapAndSetTwinSseFuncBtreeSetI32TwinSse(arg: arg);
+
+Future<Map<String, MySize>> funcBtreeMapStringStructTwinSse(
+        {required Map<String, MySize> arg}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualMapAndSetTwinSseFuncBtreeMapStringStructTwinSse(
+            arg: arg);
+
+Future<Set<String>> funcBtreeSetStringTwinSse({required Set<String> arg}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualMapAndSetTwinSseFuncBtreeSetStringTwinSse(
+            arg: arg);
+
+Future<Int32List> funcVecDequeI32TwinSse({required Int32List arg}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualMapAndSetTwinSseFuncVecDequeI32TwinSse(arg: arg);
+
+Future<List<String>> funcVecDequeStringTwinSse({required List<String> arg}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualMapAndSetTwinSseFuncVecDequeStringTwinSse(
+            arg: arg);
@@ -53,3 +53,34 @@ Map<String, KitchenSinkTwinSyncSse> funcHashMapStringComplexEnumTwinSyncSse(
     RustLib.instance.api
         .crateApiPseudoManualMapAndSetTwinSyncSseFuncHashMapStringComplexEnumTwinSyncSse(
             arg: arg);
+
+Map<int, int> funcBtreeMapI32I32TwinSyncSse({required Map<int, int> arg}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualMapAndSetTwinSyncSseFuncBtreeMapI32I32TwinSyncSse(
+            arg: arg);
+
+Set<int> funcBtreeSetI32TwinSyncSse({required Set<int> arg}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualMapAndSetTwinSyncSseFuncBtreeSetI32TwinSyncSse(
+            arg: arg);
+
+Map<String, MySize> funcBtreeMapStringStructTwinSyncSse(
+        {required Map<String, MySize> arg}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualMapAndSetTwinSyncSseFuncBtreeMapStringStructTwinSyncSse(
+            arg: arg);
+
+Set<String> funcBtreeSetStringTwinSyncSse({required Set<String> arg}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualMapAndSetTwinSyncSseFuncBtreeSetStringTwinSyncSse(
+            arg: arg);
+
+Int32List funcVecDequeI32TwinSyncSse({required Int32List arg}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualMapAndSetTwinSyncSseFuncVecDequeI32TwinSyncSse(
+            arg: arg);
+
+List<String> funcVecDequeStringTwinSyncSse({required List<String> arg}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualMapAndSetTwinSyncSseFuncVecDequeStringTwinSyncSse(
+            arg: arg);
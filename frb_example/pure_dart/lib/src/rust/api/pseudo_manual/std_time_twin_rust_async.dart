@@ -0,0 +1,70 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.3.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+Future<Duration> stdDurationTwinRustAsync({required Duration d}) => RustLib
+    .instance.api
+    .crateApiPseudoManualStdTimeTwinRustAsyncStdDurationTwinRustAsync(d: d);
+
+Future<DateTime> stdSystemTimeTwinRustAsync({required DateTime t}) => RustLib
+    .instance.api
+    .crateApiPseudoManualStdTimeTwinRustAsyncStdSystemTimeTwinRustAsync(t: t);
+
+Future<List<Duration>> stdDurationsTwinRustAsync(
+        {required List<Duration> durations}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualStdTimeTwinRustAsyncStdDurationsTwinRustAsync(
+            durations: durations);
+
+Future<List<DateTime>> stdSystemTimesTwinRustAsync(
+        {required List<DateTime> times}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualStdTimeTwinRustAsyncStdSystemTimesTwinRustAsync(
+            times: times);
+
+Future<Duration?> stdOptionalDurationTwinRustAsync({Duration? d}) => RustLib
+    .instance.api
+    .crateApiPseudoManualStdTimeTwinRustAsyncStdOptionalDurationTwinRustAsync(
+        d: d);
+
+Future<DateTime?> stdOptionalSystemTimeTwinRustAsync({DateTime? t}) => RustLib
+    .instance.api
+    .crateApiPseudoManualStdTimeTwinRustAsyncStdOptionalSystemTimeTwinRustAsync(
+        t: t);
+
+Future<StdTimeTwinRustAsync> stdTimeStructTwinRustAsync(
+        {required StdTimeTwinRustAsync value}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualStdTimeTwinRustAsyncStdTimeStructTwinRustAsync(
+            value: value);
+
+class StdTimeTwinRustAsync {
+  final Duration duration;
+  final DateTime time;
+  final Duration? optionalDuration;
+
+  const StdTimeTwinRustAsync({
+    required this.duration,
+    required this.time,
+    this.optionalDuration,
+  });
+
+  @override
+  int get hashCode =>
+      duration.hashCode ^ time.hashCode ^ optionalDuration.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is StdTimeTwinRustAsync &&
+          runtimeType == other.runtimeType &&
+          duration == other.duration &&
+          time == other.time &&
+          optionalDuration == other.optionalDuration;
+}
@@ -0,0 +1,72 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.3.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+Future<Duration> stdDurationTwinRustAsyncSse({required Duration d}) => RustLib
+    .instance.api
+    .crateApiPseudoManualStdTimeTwinRustAsyncSseStdDurationTwinRustAsyncSse(
+        d: d);
+
+Future<DateTime> stdSystemTimeTwinRustAsyncSse({required DateTime t}) => RustLib
+    .instance.api
+    .crateApiPseudoManualStdTimeTwinRustAsyncSseStdSystemTimeTwinRustAsyncSse(
+        t: t);
+
+Future<List<Duration>> stdDurationsTwinRustAsyncSse(
+        {required List<Duration> durations}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualStdTimeTwinRustAsyncSseStdDurationsTwinRustAsyncSse(
+            durations: durations);
+
+Future<List<DateTime>> stdSystemTimesTwinRustAsyncSse(
+        {required List<DateTime> times}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualStdTimeTwinRustAsyncSseStdSystemTimesTwinRustAsyncSse(
+            times: times);
+
+Future<Duration?> stdOptionalDurationTwinRustAsyncSse({Duration? d}) => RustLib
+    .instance.api
+    .crateApiPseudoManualStdTimeTwinRustAsyncSseStdOptionalDurationTwinRustAsyncSse(
+        d: d);
+
+Future<DateTime?> stdOptionalSystemTimeTwinRustAsyncSse({DateTime? t}) => RustLib
+    .instance.api
+    .crateApiPseudoManualStdTimeTwinRustAsyncSseStdOptionalSystemTimeTwinRustAsyncSse(
+        t: t);
+
+Future<StdTimeTwinRustAsyncSse> stdTimeStructTwinRustAsyncSse(
+        {required StdTimeTwinRustAsyncSse value}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualStdTimeTwinRustAsyncSseStdTimeStructTwinRustAsyncSse(
+            value: value);
+
+class StdTimeTwinRustAsyncSse {
+  final Duration duration;
+  final DateTime time;
+  final Duration? optionalDuration;
+
+  const StdTimeTwinRustAsyncSse({
+    required this.duration,
+    required this.time,
+    this.optionalDuration,
+  });
+
+  @override
+  int get hashCode =>
+      duration.hashCode ^ time.hashCode ^ optionalDuration.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is StdTimeTwinRustAsyncSse &&
+          runtimeType == other.runtimeType &&
+          duration == other.duration &&
+          time == other.time &&
+          optionalDuration == other.optionalDuration;
+}
@@ -0,0 +1,64 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.3.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+Future<Duration> stdDurationTwinSse({required Duration d}) => RustLib
+    .instance.api
+    .crateApiPseudoManualStdTimeTwinSseStdDurationTwinSse(d: d);
+
+Future<DateTime> stdSystemTimeTwinSse({required DateTime t}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualStdTimeTwinSseStdSystemTimeTwinSse(t: t);
+
+Future<List<Duration>> stdDurationsTwinSse(
+        {required List<Duration> durations}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualStdTimeTwinSseStdDurationsTwinSse(
+            durations: durations);
+
+Future<List<DateTime>> stdSystemTimesTwinSse({required List<DateTime> times}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualStdTimeTwinSseStdSystemTimesTwinSse(times: times);
+
+Future<Duration?> stdOptionalDurationTwinSse({Duration? d}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualStdTimeTwinSseStdOptionalDurationTwinSse(d: d);
+
+Future<DateTime?> stdOptionalSystemTimeTwinSse({DateTime? t}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualStdTimeTwinSseStdOptionalSystemTimeTwinSse(t: t);
+
+Future<StdTimeTwinSse> stdTimeStructTwinSse({required StdTimeTwinSse value}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualStdTimeTwinSseStdTimeStructTwinSse(value: value);
+
+class StdTimeTwinSse {
+  final Duration duration;
+  final DateTime time;
+  final Duration? optionalDuration;
+
+  const StdTimeTwinSse({
+    required this.duration,
+    required this.time,
+    this.optionalDuration,
+  });
+
+  @override
+  int get hashCode =>
+      duration.hashCode ^ time.hashCode ^ optionalDuration.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is StdTimeTwinSse &&
+          runtimeType == other.runtimeType &&
+          duration == other.duration &&
+          time == other.time &&
+          optionalDuration == other.optionalDuration;
+}
@@ -0,0 +1,60 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.3.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+Duration stdDurationTwinSync({required Duration d}) => RustLib.instance.api
+    .crateApiPseudoManualStdTimeTwinSyncStdDurationTwinSync(d: d);
+
+DateTime stdSystemTimeTwinSync({required DateTime t}) => RustLib.instance.api
+    .crateApiPseudoManualStdTimeTwinSyncStdSystemTimeTwinSync(t: t);
+
+List<Duration> stdDurationsTwinSync({required List<Duration> durations}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualStdTimeTwinSyncStdDurationsTwinSync(
+            durations: durations);
+
+List<DateTime> stdSystemTimesTwinSync({required List<DateTime> times}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualStdTimeTwinSyncStdSystemTimesTwinSync(
+            times: times);
+
+Duration? stdOptionalDurationTwinSync({Duration? d}) => RustLib.instance.api
+    .crateApiPseudoManualStdTimeTwinSyncStdOptionalDurationTwinSync(d: d);
+
+DateTime? stdOptionalSystemTimeTwinSync({DateTime? t}) => RustLib.instance.api
+    .crateApiPseudoManualStdTimeTwinSyncStdOptionalSystemTimeTwinSync(t: t);
+
+StdTimeTwinSync stdTimeStructTwinSync({required StdTimeTwinSync value}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualStdTimeTwinSyncStdTimeStructTwinSync(value: value);
+
+class StdTimeTwinSync {
+  final Duration duration;
+  final DateTime time;
+  final Duration? optionalDuration;
+
+  const StdTimeTwinSync({
+    required this.duration,
+    required this.time,
+    this.optionalDuration,
+  });
+
+  @override
+  int get hashCode =>
+      duration.hashCode ^ time.hashCode ^ optionalDuration.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is StdTimeTwinSync &&
+          runtimeType == other.runtimeType &&
+          duration == other.duration &&
+          time == other.time &&
+          optionalDuration == other.optionalDuration;
+}
@@ -0,0 +1,64 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.3.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+Duration stdDurationTwinSyncSse({required Duration d}) => RustLib.instance.api
+    .crateApiPseudoManualStdTimeTwinSyncSseStdDurationTwinSyncSse(d: d);
+
+DateTime stdSystemTimeTwinSyncSse({required DateTime t}) => RustLib.instance.api
+    .crateApiPseudoManualStdTimeTwinSyncSseStdSystemTimeTwinSyncSse(t: t);
+
+List<Duration> stdDurationsTwinSyncSse({required List<Duration> durations}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualStdTimeTwinSyncSseStdDurationsTwinSyncSse(
+            durations: durations);
+
+List<DateTime> stdSystemTimesTwinSyncSse({required List<DateTime> times}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualStdTimeTwinSyncSseStdSystemTimesTwinSyncSse(
+            times: times);
+
+Duration? stdOptionalDurationTwinSyncSse({Duration? d}) => RustLib.instance.api
+    .crateApiPseudoManualStdTimeTwinSyncSseStdOptionalDurationTwinSyncSse(d: d);
+
+DateTime? stdOptionalSystemTimeTwinSyncSse({DateTime? t}) => RustLib
+    .instance.api
+    .crateApiPseudoManualStdTimeTwinSyncSseStdOptionalSystemTimeTwinSyncSse(
+        t: t);
+
+StdTimeTwinSyncSse stdTimeStructTwinSyncSse(
+        {required StdTimeTwinSyncSse value}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualStdTimeTwinSyncSseStdTimeStructTwinSyncSse(
+            value: value);
+
+class StdTimeTwinSyncSse {
+  final Duration duration;
+  final DateTime time;
+  final Duration? optionalDuration;
+
+  const StdTimeTwinSyncSse({
+    required this.duration,
+    required this.time,
+    this.optionalDuration,
+  });
+
+  @override
+  int get hashCode =>
+      duration.hashCode ^ time.hashCode ^ optionalDuration.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is StdTimeTwinSyncSse &&
+          runtimeType == other.runtimeType &&
+          duration == other.duration &&
+          time == other.time &&
+          optionalDuration == other.optionalDuration;
+}
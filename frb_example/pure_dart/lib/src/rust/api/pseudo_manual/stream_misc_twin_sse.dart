@@ -12,3 +12,12 @@ Stream<String> funcStreamRealisticTwinSse({required String arg}) => RustLib
 
 Future<Stream<int>> streamSinkDartAsyncTwinSse() => RustLib.instance.api
     .crateApiPseudoManualStreamMiscTwinSseStreamSinkDartAsyncTwinSse();
+
+Stream<int> streamSinkBoundedAddAsyncTwinSse() => RustLib.instance.api
+    .crateApiPseudoManualStreamMiscTwinSseStreamSinkBoundedAddAsyncTwinSse();
+
+Stream<int> streamSinkBoundedKeepLatestTwinSse() => RustLib.instance.api
+    .crateApiPseudoManualStreamMiscTwinSseStreamSinkBoundedKeepLatestTwinSse();
+
+Stream<int> streamSinkBoundedAddWithErrorTwinSse() => RustLib.instance.api
+    .crateApiPseudoManualStreamMiscTwinSseStreamSinkBoundedAddWithErrorTwinSse();
@@ -0,0 +1,81 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.3.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+Future<DateTime> offsetDateTimeTwinRustAsyncSse({required DateTime d}) => RustLib
+    .instance.api
+    .crateApiPseudoManualTimeTypeTwinRustAsyncSseOffsetDateTimeTwinRustAsyncSse(
+        d: d);
+
+Future<DateTime> primitiveDateTimeTwinRustAsyncSse({required DateTime d}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualTimeTypeTwinRustAsyncSsePrimitiveDateTimeTwinRustAsyncSse(
+            d: d);
+
+Future<DateTime> dateTwinRustAsyncSse({required DateTime d}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualTimeTypeTwinRustAsyncSseDateTwinRustAsyncSse(d: d);
+
+Future<Duration> timeDurationTwinRustAsyncSse({required Duration d}) => RustLib
+    .instance.api
+    .crateApiPseudoManualTimeTypeTwinRustAsyncSseTimeDurationTwinRustAsyncSse(
+        d: d);
+
+Future<Duration> negateTimeDurationTwinRustAsyncSse({required Duration d}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualTimeTypeTwinRustAsyncSseNegateTimeDurationTwinRustAsyncSse(
+            d: d);
+
+Future<List<Duration>> timeDurationsTwinRustAsyncSse(
+        {required List<Duration> durations}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualTimeTypeTwinRustAsyncSseTimeDurationsTwinRustAsyncSse(
+            durations: durations);
+
+Future<Duration?> optionalTimeDurationTwinRustAsyncSse({Duration? d}) => RustLib
+    .instance.api
+    .crateApiPseudoManualTimeTypeTwinRustAsyncSseOptionalTimeDurationTwinRustAsyncSse(
+        d: d);
+
+Future<DateTime?> optionalOffsetDateTimeTwinRustAsyncSse({DateTime? d}) => RustLib
+    .instance.api
+    .crateApiPseudoManualTimeTypeTwinRustAsyncSseOptionalOffsetDateTimeTwinRustAsyncSse(
+        d: d);
+
+Future<TimeTypesTwinRustAsyncSse> timeTypesTwinRustAsyncSse(
+        {required TimeTypesTwinRustAsyncSse value}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualTimeTypeTwinRustAsyncSseTimeTypesTwinRustAsyncSse(
+            value: value);
+
+class TimeTypesTwinRustAsyncSse {
+  final DateTime offset;
+  final DateTime? primitive;
+  final DateTime date;
+  final Duration duration;
+
+  const TimeTypesTwinRustAsyncSse({
+    required this.offset,
+    this.primitive,
+    required this.date,
+    required this.duration,
+  });
+
+  @override
+  int get hashCode =>
+      offset.hashCode ^ primitive.hashCode ^ date.hashCode ^ duration.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TimeTypesTwinRustAsyncSse &&
+          runtimeType == other.runtimeType &&
+          offset == other.offset &&
+          primitive == other.primitive &&
+          date == other.date &&
+          duration == other.duration;
+}
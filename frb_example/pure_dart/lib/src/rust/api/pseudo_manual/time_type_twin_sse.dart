@@ -0,0 +1,72 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.3.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+Future<DateTime> offsetDateTimeTwinSse({required DateTime d}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualTimeTypeTwinSseOffsetDateTimeTwinSse(d: d);
+
+Future<DateTime> primitiveDateTimeTwinSse({required DateTime d}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualTimeTypeTwinSsePrimitiveDateTimeTwinSse(d: d);
+
+Future<DateTime> dateTwinSse({required DateTime d}) =>
+    RustLib.instance.api.crateApiPseudoManualTimeTypeTwinSseDateTwinSse(d: d);
+
+Future<Duration> timeDurationTwinSse({required Duration d}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualTimeTypeTwinSseTimeDurationTwinSse(d: d);
+
+Future<Duration> negateTimeDurationTwinSse({required Duration d}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualTimeTypeTwinSseNegateTimeDurationTwinSse(d: d);
+
+Future<List<Duration>> timeDurationsTwinSse(
+        {required List<Duration> durations}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualTimeTypeTwinSseTimeDurationsTwinSse(
+            durations: durations);
+
+Future<Duration?> optionalTimeDurationTwinSse({Duration? d}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualTimeTypeTwinSseOptionalTimeDurationTwinSse(d: d);
+
+Future<DateTime?> optionalOffsetDateTimeTwinSse({DateTime? d}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualTimeTypeTwinSseOptionalOffsetDateTimeTwinSse(d: d);
+
+Future<TimeTypesTwinSse> timeTypesTwinSse({required TimeTypesTwinSse value}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualTimeTypeTwinSseTimeTypesTwinSse(value: value);
+
+class TimeTypesTwinSse {
+  final DateTime offset;
+  final DateTime? primitive;
+  final DateTime date;
+  final Duration duration;
+
+  const TimeTypesTwinSse({
+    required this.offset,
+    this.primitive,
+    required this.date,
+    required this.duration,
+  });
+
+  @override
+  int get hashCode =>
+      offset.hashCode ^ primitive.hashCode ^ date.hashCode ^ duration.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TimeTypesTwinSse &&
+          runtimeType == other.runtimeType &&
+          offset == other.offset &&
+          primitive == other.primitive &&
+          date == other.date &&
+          duration == other.duration;
+}
@@ -0,0 +1,67 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.3.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+DateTime offsetDateTimeTwinSync({required DateTime d}) => RustLib.instance.api
+    .crateApiPseudoManualTimeTypeTwinSyncOffsetDateTimeTwinSync(d: d);
+
+DateTime primitiveDateTimeTwinSync({required DateTime d}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualTimeTypeTwinSyncPrimitiveDateTimeTwinSync(d: d);
+
+DateTime dateTwinSync({required DateTime d}) => RustLib.instance.api
+    .crateApiPseudoManualTimeTypeTwinSyncDateTwinSync(d: d);
+
+Duration timeDurationTwinSync({required Duration d}) => RustLib.instance.api
+    .crateApiPseudoManualTimeTypeTwinSyncTimeDurationTwinSync(d: d);
+
+Duration negateTimeDurationTwinSync({required Duration d}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualTimeTypeTwinSyncNegateTimeDurationTwinSync(d: d);
+
+List<Duration> timeDurationsTwinSync({required List<Duration> durations}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualTimeTypeTwinSyncTimeDurationsTwinSync(
+            durations: durations);
+
+Duration? optionalTimeDurationTwinSync({Duration? d}) => RustLib.instance.api
+    .crateApiPseudoManualTimeTypeTwinSyncOptionalTimeDurationTwinSync(d: d);
+
+DateTime? optionalOffsetDateTimeTwinSync({DateTime? d}) => RustLib.instance.api
+    .crateApiPseudoManualTimeTypeTwinSyncOptionalOffsetDateTimeTwinSync(d: d);
+
+TimeTypesTwinSync timeTypesTwinSync({required TimeTypesTwinSync value}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualTimeTypeTwinSyncTimeTypesTwinSync(value: value);
+
+class TimeTypesTwinSync {
+  final DateTime offset;
+  final DateTime? primitive;
+  final DateTime date;
+  final Duration duration;
+
+  const TimeTypesTwinSync({
+    required this.offset,
+    this.primitive,
+    required this.date,
+    required this.duration,
+  });
+
+  @override
+  int get hashCode =>
+      offset.hashCode ^ primitive.hashCode ^ date.hashCode ^ duration.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TimeTypesTwinSync &&
+          runtimeType == other.runtimeType &&
+          offset == other.offset &&
+          primitive == other.primitive &&
+          date == other.date &&
+          duration == other.duration;
+}
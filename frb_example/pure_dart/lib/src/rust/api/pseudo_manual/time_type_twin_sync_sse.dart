@@ -0,0 +1,73 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.3.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+DateTime offsetDateTimeTwinSyncSse({required DateTime d}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualTimeTypeTwinSyncSseOffsetDateTimeTwinSyncSse(d: d);
+
+DateTime primitiveDateTimeTwinSyncSse({required DateTime d}) => RustLib
+    .instance.api
+    .crateApiPseudoManualTimeTypeTwinSyncSsePrimitiveDateTimeTwinSyncSse(d: d);
+
+DateTime dateTwinSyncSse({required DateTime d}) => RustLib.instance.api
+    .crateApiPseudoManualTimeTypeTwinSyncSseDateTwinSyncSse(d: d);
+
+Duration timeDurationTwinSyncSse({required Duration d}) => RustLib.instance.api
+    .crateApiPseudoManualTimeTypeTwinSyncSseTimeDurationTwinSyncSse(d: d);
+
+Duration negateTimeDurationTwinSyncSse({required Duration d}) => RustLib
+    .instance.api
+    .crateApiPseudoManualTimeTypeTwinSyncSseNegateTimeDurationTwinSyncSse(d: d);
+
+List<Duration> timeDurationsTwinSyncSse({required List<Duration> durations}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualTimeTypeTwinSyncSseTimeDurationsTwinSyncSse(
+            durations: durations);
+
+Duration? optionalTimeDurationTwinSyncSse({Duration? d}) => RustLib.instance.api
+    .crateApiPseudoManualTimeTypeTwinSyncSseOptionalTimeDurationTwinSyncSse(
+        d: d);
+
+DateTime? optionalOffsetDateTimeTwinSyncSse({DateTime? d}) => RustLib
+    .instance.api
+    .crateApiPseudoManualTimeTypeTwinSyncSseOptionalOffsetDateTimeTwinSyncSse(
+        d: d);
+
+TimeTypesTwinSyncSse timeTypesTwinSyncSse(
+        {required TimeTypesTwinSyncSse value}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualTimeTypeTwinSyncSseTimeTypesTwinSyncSse(
+            value: value);
+
+class TimeTypesTwinSyncSse {
+  final DateTime offset;
+  final DateTime? primitive;
+  final DateTime date;
+  final Duration duration;
+
+  const TimeTypesTwinSyncSse({
+    required this.offset,
+    this.primitive,
+    required this.date,
+    required this.duration,
+  });
+
+  @override
+  int get hashCode =>
+      offset.hashCode ^ primitive.hashCode ^ date.hashCode ^ duration.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TimeTypesTwinSyncSse &&
+          runtimeType == other.runtimeType &&
+          offset == other.offset &&
+          primitive == other.primitive &&
+          date == other.date &&
+          duration == other.duration;
+}
@@ -0,0 +1,62 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.3.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+Future<Duration> stdDurationTwinNormal({required Duration d}) =>
+    RustLib.instance.api.crateApiStdTimeStdDurationTwinNormal(d: d);
+
+Future<DateTime> stdSystemTimeTwinNormal({required DateTime t}) =>
+    RustLib.instance.api.crateApiStdTimeStdSystemTimeTwinNormal(t: t);
+
+Future<List<Duration>> stdDurationsTwinNormal(
+        {required List<Duration> durations}) =>
+    RustLib.instance.api
+        .crateApiStdTimeStdDurationsTwinNormal(durations: durations);
+
+Future<List<DateTime>> stdSystemTimesTwinNormal(
+        {required List<DateTime> times}) =>
+    RustLib.instance.api
+        .crateApiStdTimeStdSystemTimesTwinNormal(times: times);
+
+Future<Duration?> stdOptionalDurationTwinNormal({Duration? d}) =>
+    RustLib.instance.api
+        .crateApiStdTimeStdOptionalDurationTwinNormal(d: d);
+
+Future<DateTime?> stdOptionalSystemTimeTwinNormal({DateTime? t}) =>
+    RustLib.instance.api
+        .crateApiStdTimeStdOptionalSystemTimeTwinNormal(t: t);
+
+Future<StdTimeTwinNormal> stdTimeStructTwinNormal(
+        {required StdTimeTwinNormal value}) =>
+    RustLib.instance.api.crateApiStdTimeStdTimeStructTwinNormal(value: value);
+
+class StdTimeTwinNormal {
+  final Duration duration;
+  final DateTime time;
+  final Duration? optionalDuration;
+
+  const StdTimeTwinNormal({
+    required this.duration,
+    required this.time,
+    this.optionalDuration,
+  });
+
+  @override
+  int get hashCode =>
+      duration.hashCode ^ time.hashCode ^ optionalDuration.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is StdTimeTwinNormal &&
+          runtimeType == other.runtimeType &&
+          duration == other.duration &&
+          time == other.time &&
+          optionalDuration == other.optionalDuration;
+}
@@ -13,8 +13,10 @@ import 'api/async_misc.dart';
 import 'api/async_spawn.dart';
 import 'api/attribute.dart';
 import 'api/benchmark_misc.dart';
+import 'api/big_number.dart';
 import 'api/casted_primitive.dart';
 import 'api/chrono_type.dart';
+import 'api/chrono_tz_type.dart';
 import 'api/comment.dart';
 import 'api/constructor.dart';
 import 'api/custom_ser_des.dart';
@@ -34,6 +36,7 @@ import 'api/external_impl.dart';
 import 'api/external_type_in_crate.dart';
 import 'api/impl_trait.dart';
 import 'api/inside_macro.dart';
+import 'api/json_type.dart';
 import 'api/lifetimeable.dart';
 import 'api/map_and_set.dart';
 import 'api/method.dart';
@@ -90,6 +93,11 @@ import 'api/pseudo_manual/benchmark_api_twin_rust_async_sse.dart';
 import 'api/pseudo_manual/benchmark_api_twin_sse.dart';
 import 'api/pseudo_manual/benchmark_api_twin_sync.dart';
 import 'api/pseudo_manual/benchmark_api_twin_sync_sse.dart';
+import 'api/pseudo_manual/big_number_twin_rust_async.dart';
+import 'api/pseudo_manual/big_number_twin_rust_async_sse.dart';
+import 'api/pseudo_manual/big_number_twin_sse.dart';
+import 'api/pseudo_manual/big_number_twin_sync.dart';
+import 'api/pseudo_manual/big_number_twin_sync_sse.dart';
 import 'api/pseudo_manual/chrono_type_twin_rust_async.dart';
 import 'api/pseudo_manual/chrono_type_twin_rust_async_sse.dart';
 import 'api/pseudo_manual/chrono_type_twin_sse.dart';
@@ -137,6 +145,11 @@ import 'api/pseudo_manual/external_type_in_crate_twin_sync_sse.dart';
 import 'api/pseudo_manual/impl_trait_twin_sse.dart';
 import 'api/pseudo_manual/impl_trait_twin_sync.dart';
 import 'api/pseudo_manual/impl_trait_twin_sync_sse.dart';
+import 'api/pseudo_manual/json_type_twin_rust_async.dart';
+import 'api/pseudo_manual/json_type_twin_rust_async_sse.dart';
+import 'api/pseudo_manual/json_type_twin_sse.dart';
+import 'api/pseudo_manual/json_type_twin_sync.dart';
+import 'api/pseudo_manual/json_type_twin_sync_sse.dart';
 import 'api/pseudo_manual/lifetimeable_twin_sync.dart';
 import 'api/pseudo_manual/map_and_set_twin_rust_async.dart';
 import 'api/pseudo_manual/map_and_set_twin_rust_async_sse.dart';
@@ -228,6 +241,11 @@ import 'api/pseudo_manual/simple_twin_rust_async_sse.dart';
 import 'api/pseudo_manual/simple_twin_sse.dart';
 import 'api/pseudo_manual/simple_twin_sync.dart';
 import 'api/pseudo_manual/simple_twin_sync_sse.dart';
+import 'api/pseudo_manual/std_time_twin_rust_async.dart';
+import 'api/pseudo_manual/std_time_twin_rust_async_sse.dart';
+import 'api/pseudo_manual/std_time_twin_sse.dart';
+import 'api/pseudo_manual/std_time_twin_sync.dart';
+import 'api/pseudo_manual/std_time_twin_sync_sse.dart';
 import 'api/pseudo_manual/stream_misc_twin_sse.dart';
 import 'api/pseudo_manual/stream_twin_rust_async.dart';
 import 'api/pseudo_manual/stream_twin_rust_async_sse.dart';
@@ -237,6 +255,11 @@ import 'api/pseudo_manual/structure_twin_rust_async_sse.dart';
 import 'api/pseudo_manual/structure_twin_sse.dart';
 import 'api/pseudo_manual/structure_twin_sync.dart';
 import 'api/pseudo_manual/structure_twin_sync_sse.dart';
+import 'api/pseudo_manual/time_type_twin_rust_async.dart';
+import 'api/pseudo_manual/time_type_twin_rust_async_sse.dart';
+import 'api/pseudo_manual/time_type_twin_sse.dart';
+import 'api/pseudo_manual/time_type_twin_sync.dart';
+import 'api/pseudo_manual/time_type_twin_sync_sse.dart';
 import 'api/pseudo_manual/tuple_twin_rust_async.dart';
 import 'api/pseudo_manual/tuple_twin_rust_async_sse.dart';
 import 'api/pseudo_manual/tuple_twin_sse.dart';
@@ -257,9 +280,11 @@ import 'api/rust_auto_opaque.dart';
 import 'api/rust_opaque.dart';
 import 'api/rust_opaque_sync.dart';
 import 'api/simple.dart';
+import 'api/std_time.dart';
 import 'api/stream.dart';
 import 'api/stream_misc.dart';
 import 'api/structure.dart';
+import 'api/time_type.dart';
 import 'api/tuple.dart';
 import 'api/type_alias.dart';
 import 'api/uuid_type.dart';
@@ -272,6 +297,7 @@ import 'deliberate_name_conflict.dart';
 import 'frb_generated.dart';
 import 'frb_generated.io.dart'
     if (dart.library.js_interop) 'frb_generated.web.dart';
+import 'package:decimal/decimal.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
 import 'package:meta/meta.dart' as meta;
@@ -337,7 +363,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.3.0';
 
   @override
-  int get rustContentHash => 1810234069;
+  int get rustContentHash => 570656134;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -380,11 +406,15 @@ abstract class RustLibApi extends BaseApi {
   Future<U8Array32> crateApiArrayUseMsgidTwinNormal(
       {required MessageIdTwinNormal id});
 
+  Future<void> crateApiAsyncMiscFuncAsyncNeverCompleteTwinNormal();
+
   Future<int> crateApiAsyncMiscFuncAsyncSimpleAddTwinNormal(
       {required int a, required int b});
 
   Future<void> crateApiAsyncMiscFuncAsyncVoidTwinNormal();
 
+  Future<void> crateApiAsyncMiscFuncAsyncWaitForCancellationTwinNormal();
+
   Future<String> crateApiAsyncSpawnSimpleUseAsyncSpawn({required String arg});
 
   Future<String> crateApiAsyncSpawnSimpleUseAsyncSpawnBlocking(
@@ -401,6 +431,29 @@ abstract class RustLibApi extends BaseApi {
 
   void crateApiBenchmarkMiscBenchmarkVoidSemiSerialize();
 
+  Future<Decimal> crateApiBigNumberAddDecimalsTwinNormal(
+      {required Decimal a, required Decimal b});
+
+  Future<BigInt> crateApiBigNumberBigIntTwinNormal({required BigInt i});
+
+  Future<List<BigInt>> crateApiBigNumberBigIntsTwinNormal(
+      {required List<BigInt> ints});
+
+  Future<BigNumbersTwinNormal> crateApiBigNumberBigNumbersTwinNormal(
+      {required BigNumbersTwinNormal value});
+
+  Future<Decimal> crateApiBigNumberDecimalTwinNormal({required Decimal d});
+
+  Future<List<Decimal>> crateApiBigNumberDecimalsTwinNormal(
+      {required List<Decimal> decimals});
+
+  Future<BigInt> crateApiBigNumberMultiplyBigIntsTwinNormal(
+      {required BigInt a, required BigInt b});
+
+  Future<BigInt?> crateApiBigNumberOptionalBigIntTwinNormal({BigInt? i});
+
+  Future<Decimal?> crateApiBigNumberOptionalDecimalTwinNormal({Decimal? d});
+
   Future<int> crateApiCastedPrimitiveCastedPrimitiveI64TwinNormal(
       {required int arg});
 
@@ -420,6 +473,13 @@ abstract class RustLibApi extends BaseApi {
       crateApiCastedPrimitiveFunctionForStructWithCastedPrimitiveTwinNormal(
           {required StructWithCastedPrimitiveTwinNormal arg});
 
+  Future<DateTimeWithOffset> crateApiChronoTypeDatetimeFixedOffsetTwinNormal(
+      {required DateTimeWithOffset d});
+
+  Future<List<DateTimeWithOffset>>
+      crateApiChronoTypeDatetimeFixedOffsetsTwinNormal(
+          {required List<DateTimeWithOffset> dates});
+
   Future<DateTime> crateApiChronoTypeDatetimeLocalTwinNormal(
       {required DateTime d});
 
@@ -437,16 +497,49 @@ abstract class RustLibApi extends BaseApi {
   Future<Duration> crateApiChronoTypeHowLongDoesItTakeTwinNormal(
       {required FeatureChronoTwinNormal mine});
 
+  Future<DateTime> crateApiChronoTypeNaiveDateTwinNormal({required DateTime d});
+
+  Future<List<DateTime>> crateApiChronoTypeNaiveDatesTwinNormal(
+      {required List<DateTime> dates});
+
+  Future<Duration> crateApiChronoTypeNaiveTimeTwinNormal({required Duration d});
+
   Future<DateTime> crateApiChronoTypeNaivedatetimeTwinNormal(
       {required DateTime d});
 
+  Future<DateTimeWithOffset?>
+      crateApiChronoTypeOptionalDatetimeFixedOffsetTwinNormal(
+          {DateTimeWithOffset? d});
+
   Future<DateTime?> crateApiChronoTypeOptionalEmptyDatetimeUtcTwinNormal(
       {DateTime? d});
 
+  Future<ScheduleTwinNormal> crateApiChronoTypeScheduleTwinNormal(
+      {required ScheduleTwinNormal schedule});
+
   Future<TestChronoTwinNormal> crateApiChronoTypeTestChronoTwinNormal();
 
   Future<TestChronoTwinNormal> crateApiChronoTypeTestPreciseChronoTwinNormal();
 
+  Future<String> crateApiChronoTzTypeDatetimeTzNameTwinNormal(
+      {required DateTimeWithOffset d});
+
+  Future<DateTimeWithOffset>
+      crateApiChronoTzTypeDatetimeTzToFixedOffsetTwinNormal(
+          {required DateTimeWithOffset d});
+
+  Future<DateTimeWithOffset> crateApiChronoTzTypeDatetimeTzTwinNormal(
+      {required DateTimeWithOffset d});
+
+  Future<List<DateTimeWithOffset>> crateApiChronoTzTypeDatetimeTzsTwinNormal(
+      {required List<DateTimeWithOffset> dates});
+
+  Future<DateTimeWithOffset> crateApiChronoTzTypeDatetimeWithTimeZoneTwinNormal(
+      {required DateTimeWithOffset d, required String timeZone});
+
+  Future<DateTimeWithOffset?> crateApiChronoTzTypeOptionalDatetimeTzTwinNormal(
+      {DateTimeWithOffset? d});
+
   Future<void> crateApiCommentFunctionWithCommentsSlashStarStarTwinNormal();
 
   Future<void>
@@ -549,6 +642,12 @@ abstract class RustLibApi extends BaseApi {
   Future<Object> crateApiDartFnRustCallDartWithDartOpaqueResultTwinNormal(
       {required FutureOr<Object> Function() callback});
 
+  Future<int> crateApiDartFnRustConsumeDartStreamSumTwinNormal(
+      {required Stream<int> stream});
+
+  Future<List<String>> crateApiDartFnRustConsumeDartStreamTakeTwinNormal(
+      {required Stream<String> stream, required BigInt count});
+
   Future<String> crateApiDartOpaqueAsyncAcceptDartOpaqueTwinNormal(
       {required Object opaque});
 
@@ -653,6 +752,37 @@ abstract class RustLibApi extends BaseApi {
       crateApiDynTraitStructTwoWithTraitForDynTwinNormalSimpleMethodTwinNormal(
           {required StructTwoWithTraitForDynTwinNormal that});
 
+  Future<int>
+      crateApiDynTraitStructWithDartImplementableTraitTwinNormalAddTwinNormal(
+          {required StructWithDartImplementableTraitTwinNormal that,
+          required int a,
+          required int b});
+
+  int crateApiDynTraitStructWithDartImplementableTraitTwinNormalAutoAccessorGetBase(
+      {required StructWithDartImplementableTraitTwinNormal that});
+
+  void
+      crateApiDynTraitStructWithDartImplementableTraitTwinNormalAutoAccessorSetBase(
+          {required StructWithDartImplementableTraitTwinNormal that,
+          required int base});
+
+  Future<String>
+      crateApiDynTraitStructWithDartImplementableTraitTwinNormalDescribeTwinNormal(
+          {required StructWithDartImplementableTraitTwinNormal that,
+          required int value});
+
+  StructWithDartImplementableTraitTwinNormal
+      crateApiDynTraitStructWithDartImplementableTraitTwinNormalNew(
+          {required int base});
+
+  Future<String> crateApiDynTraitFuncArgBoxDynDartImplementableAsyncTwinNormal(
+      {required DartImplementableTraitTwinNormal arg, required int value});
+
+  Future<int> crateApiDynTraitFuncArgBoxDynDartImplementableSyncTwinNormal(
+      {required DartImplementableTraitTwinNormal arg,
+      required int a,
+      required int b});
+
   Future<int> crateApiDynTraitFuncArgDynTraitTwinNormal(
       {required SimpleTraitForDynTwinNormal arg});
 
@@ -779,8 +909,15 @@ abstract class RustLibApi extends BaseApi {
 
   Future<Stream<String>> crateApiExceptionStreamSinkThrowAnyhowTwinNormal();
 
+  Future<void> crateApiExceptionThrowAnyhowIoErrorTwinNormal();
+
   Future<void> crateApiExceptionThrowAnyhowTwinNormal();
 
+  Future<void> crateApiExceptionThrowAnyhowWithContextTwinNormal();
+
+  Future<int> crateApiExceptionTypedExceptionReturnErrorTwinNormal(
+      {required int variant});
+
   SimpleOpaqueExternalStructWithMethod
       crateApiExternalImplSimpleOpaqueExternalStructWithMethodNew(
           {required String a});
@@ -854,6 +991,29 @@ abstract class RustLibApi extends BaseApi {
   Future<MacroStruct> crateApiInsideMacroFuncMacroStructTwinNormal(
       {required MacroStruct arg});
 
+  Future<Map<String, dynamic>> crateApiJsonTypeCreateJsonMapTwinNormal();
+
+  Future<Object?> crateApiJsonTypeCreateJsonValueTwinNormal();
+
+  Future<JsonDocumentTwinNormal> crateApiJsonTypeJsonDocumentTwinNormal(
+      {required JsonDocumentTwinNormal document});
+
+  Future<List<String>> crateApiJsonTypeJsonMapKeysTwinNormal(
+      {required Map<String, dynamic> map});
+
+  Future<Map<String, dynamic>> crateApiJsonTypeJsonMapTwinNormal(
+      {required Map<String, dynamic> map});
+
+  Future<String> crateApiJsonTypeJsonValueToStringTwinNormal(
+      {required Object? value});
+
+  Future<Object?> crateApiJsonTypeJsonValueTwinNormal({required Object? value});
+
+  Future<List<Object?>> crateApiJsonTypeJsonValuesTwinNormal(
+      {required List<Object?> values});
+
+  Future<Object?> crateApiJsonTypeOptionalJsonValueTwinNormal({Object? value});
+
   Future<String>
       crateApiLifetimeableLifetimeableAutoOwnedRustOpaqueFlutterRustBridgeforGeneratedRustAutoOpaqueInnerLtNestedTypeWithLifetimeTwinNormalstaticGreetBorrowMutSelfTwinNormal(
           {required LtNestedTypeWithLifetimeTwinNormal that});
@@ -923,6 +1083,19 @@ abstract class RustLibApi extends BaseApi {
       crateApiLifetimeableLtComputeWithLifetimeFunctionTwinNormal(
           {required LtOwnedStructTwinNormal arg});
 
+  Future<Map<int, int>> crateApiMapAndSetFuncBtreeMapI32I32TwinNormal(
+      {required Map<int, int> arg});
+
+  Future<Map<String, MySize>>
+      crateApiMapAndSetFuncBtreeMapStringStructTwinNormal(
+          {required Map<String, MySize> arg});
+
+  Future<Set<int>> crateApiMapAndSetFuncBtreeSetI32TwinNormal(
+      {required Set<int> arg});
+
+  Future<Set<String>> crateApiMapAndSetFuncBtreeSetStringTwinNormal(
+      {required Set<String> arg});
+
   Future<Map<int, int>> crateApiMapAndSetFuncHashMapI32I32TwinNormal(
       {required Map<int, int> arg});
 
@@ -952,6 +1125,12 @@ abstract class RustLibApi extends BaseApi {
   Future<Set<String>> crateApiMapAndSetFuncHashSetStringTwinNormal(
       {required Set<String> arg});
 
+  Future<Int32List> crateApiMapAndSetFuncVecDequeI32TwinNormal(
+      {required Int32List arg});
+
+  Future<List<String>> crateApiMapAndSetFuncVecDequeStringTwinNormal(
+      {required List<String> arg});
+
   Future<int> crateApiMethodStaticGetterOnlyTwinNormalStaticGetterTwinNormal();
 
   Future<String>
@@ -1529,11 +1708,17 @@ abstract class RustLibApi extends BaseApi {
   U8Array32 crateApiPseudoManualArrayTwinSyncSseUseMsgidTwinSyncSse(
       {required MessageIdTwinSyncSse id});
 
+  Future<void>
+      crateApiPseudoManualAsyncMiscTwinSseFuncAsyncNeverCompleteTwinSse();
+
   Future<int> crateApiPseudoManualAsyncMiscTwinSseFuncAsyncSimpleAddTwinSse(
       {required int a, required int b});
 
   Future<void> crateApiPseudoManualAsyncMiscTwinSseFuncAsyncVoidTwinSse();
 
+  Future<void>
+      crateApiPseudoManualAsyncMiscTwinSseFuncAsyncWaitForCancellationTwinSse();
+
   Future<void>
       crateApiPseudoManualAttributeTwinRustAsyncHandleCustomizedStructTwinRustAsync(
           {required CustomizedTwinRustAsync val});
@@ -3671,6 +3856,168 @@ abstract class RustLibApi extends BaseApi {
 
   void crateApiPseudoManualBenchmarkApiTwinSyncSseBenchmarkVoidTwinSyncSse();
 
+  Future<Decimal>
+      crateApiPseudoManualBigNumberTwinRustAsyncAddDecimalsTwinRustAsync(
+          {required Decimal a, required Decimal b});
+
+  Future<BigInt> crateApiPseudoManualBigNumberTwinRustAsyncBigIntTwinRustAsync(
+      {required BigInt i});
+
+  Future<List<BigInt>>
+      crateApiPseudoManualBigNumberTwinRustAsyncBigIntsTwinRustAsync(
+          {required List<BigInt> ints});
+
+  Future<BigNumbersTwinRustAsync>
+      crateApiPseudoManualBigNumberTwinRustAsyncBigNumbersTwinRustAsync(
+          {required BigNumbersTwinRustAsync value});
+
+  Future<Decimal>
+      crateApiPseudoManualBigNumberTwinRustAsyncDecimalTwinRustAsync(
+          {required Decimal d});
+
+  Future<List<Decimal>>
+      crateApiPseudoManualBigNumberTwinRustAsyncDecimalsTwinRustAsync(
+          {required List<Decimal> decimals});
+
+  Future<BigInt>
+      crateApiPseudoManualBigNumberTwinRustAsyncMultiplyBigIntsTwinRustAsync(
+          {required BigInt a, required BigInt b});
+
+  Future<BigInt?>
+      crateApiPseudoManualBigNumberTwinRustAsyncOptionalBigIntTwinRustAsync(
+          {BigInt? i});
+
+  Future<Decimal?>
+      crateApiPseudoManualBigNumberTwinRustAsyncOptionalDecimalTwinRustAsync(
+          {Decimal? d});
+
+  Future<Decimal>
+      crateApiPseudoManualBigNumberTwinRustAsyncSseAddDecimalsTwinRustAsyncSse(
+          {required Decimal a, required Decimal b});
+
+  Future<BigInt>
+      crateApiPseudoManualBigNumberTwinRustAsyncSseBigIntTwinRustAsyncSse(
+          {required BigInt i});
+
+  Future<List<BigInt>>
+      crateApiPseudoManualBigNumberTwinRustAsyncSseBigIntsTwinRustAsyncSse(
+          {required List<BigInt> ints});
+
+  Future<BigNumbersTwinRustAsyncSse>
+      crateApiPseudoManualBigNumberTwinRustAsyncSseBigNumbersTwinRustAsyncSse(
+          {required BigNumbersTwinRustAsyncSse value});
+
+  Future<Decimal>
+      crateApiPseudoManualBigNumberTwinRustAsyncSseDecimalTwinRustAsyncSse(
+          {required Decimal d});
+
+  Future<List<Decimal>>
+      crateApiPseudoManualBigNumberTwinRustAsyncSseDecimalsTwinRustAsyncSse(
+          {required List<Decimal> decimals});
+
+  Future<BigInt>
+      crateApiPseudoManualBigNumberTwinRustAsyncSseMultiplyBigIntsTwinRustAsyncSse(
+          {required BigInt a, required BigInt b});
+
+  Future<BigInt?>
+      crateApiPseudoManualBigNumberTwinRustAsyncSseOptionalBigIntTwinRustAsyncSse(
+          {BigInt? i});
+
+  Future<Decimal?>
+      crateApiPseudoManualBigNumberTwinRustAsyncSseOptionalDecimalTwinRustAsyncSse(
+          {Decimal? d});
+
+  Future<Decimal> crateApiPseudoManualBigNumberTwinSseAddDecimalsTwinSse(
+      {required Decimal a, required Decimal b});
+
+  Future<BigInt> crateApiPseudoManualBigNumberTwinSseBigIntTwinSse(
+      {required BigInt i});
+
+  Future<List<BigInt>> crateApiPseudoManualBigNumberTwinSseBigIntsTwinSse(
+      {required List<BigInt> ints});
+
+  Future<BigNumbersTwinSse>
+      crateApiPseudoManualBigNumberTwinSseBigNumbersTwinSse(
+          {required BigNumbersTwinSse value});
+
+  Future<Decimal> crateApiPseudoManualBigNumberTwinSseDecimalTwinSse(
+      {required Decimal d});
+
+  Future<List<Decimal>> crateApiPseudoManualBigNumberTwinSseDecimalsTwinSse(
+      {required List<Decimal> decimals});
+
+  Future<BigInt> crateApiPseudoManualBigNumberTwinSseMultiplyBigIntsTwinSse(
+      {required BigInt a, required BigInt b});
+
+  Future<BigInt?> crateApiPseudoManualBigNumberTwinSseOptionalBigIntTwinSse(
+      {BigInt? i});
+
+  Future<Decimal?> crateApiPseudoManualBigNumberTwinSseOptionalDecimalTwinSse(
+      {Decimal? d});
+
+  Decimal crateApiPseudoManualBigNumberTwinSyncAddDecimalsTwinSync(
+      {required Decimal a, required Decimal b});
+
+  BigInt crateApiPseudoManualBigNumberTwinSyncBigIntTwinSync(
+      {required BigInt i});
+
+  List<BigInt> crateApiPseudoManualBigNumberTwinSyncBigIntsTwinSync(
+      {required List<BigInt> ints});
+
+  BigNumbersTwinSync crateApiPseudoManualBigNumberTwinSyncBigNumbersTwinSync(
+      {required BigNumbersTwinSync value});
+
+  Decimal crateApiPseudoManualBigNumberTwinSyncDecimalTwinSync(
+      {required Decimal d});
+
+  List<Decimal> crateApiPseudoManualBigNumberTwinSyncDecimalsTwinSync(
+      {required List<Decimal> decimals});
+
+  BigInt crateApiPseudoManualBigNumberTwinSyncMultiplyBigIntsTwinSync(
+      {required BigInt a, required BigInt b});
+
+  BigInt? crateApiPseudoManualBigNumberTwinSyncOptionalBigIntTwinSync(
+      {BigInt? i});
+
+  Decimal? crateApiPseudoManualBigNumberTwinSyncOptionalDecimalTwinSync(
+      {Decimal? d});
+
+  Decimal crateApiPseudoManualBigNumberTwinSyncSseAddDecimalsTwinSyncSse(
+      {required Decimal a, required Decimal b});
+
+  BigInt crateApiPseudoManualBigNumberTwinSyncSseBigIntTwinSyncSse(
+      {required BigInt i});
+
+  List<BigInt> crateApiPseudoManualBigNumberTwinSyncSseBigIntsTwinSyncSse(
+      {required List<BigInt> ints});
+
+  BigNumbersTwinSyncSse
+      crateApiPseudoManualBigNumberTwinSyncSseBigNumbersTwinSyncSse(
+          {required BigNumbersTwinSyncSse value});
+
+  Decimal crateApiPseudoManualBigNumberTwinSyncSseDecimalTwinSyncSse(
+      {required Decimal d});
+
+  List<Decimal> crateApiPseudoManualBigNumberTwinSyncSseDecimalsTwinSyncSse(
+      {required List<Decimal> decimals});
+
+  BigInt crateApiPseudoManualBigNumberTwinSyncSseMultiplyBigIntsTwinSyncSse(
+      {required BigInt a, required BigInt b});
+
+  BigInt? crateApiPseudoManualBigNumberTwinSyncSseOptionalBigIntTwinSyncSse(
+      {BigInt? i});
+
+  Decimal? crateApiPseudoManualBigNumberTwinSyncSseOptionalDecimalTwinSyncSse(
+      {Decimal? d});
+
+  Future<DateTimeWithOffset>
+      crateApiPseudoManualChronoTypeTwinRustAsyncDatetimeFixedOffsetTwinRustAsync(
+          {required DateTimeWithOffset d});
+
+  Future<List<DateTimeWithOffset>>
+      crateApiPseudoManualChronoTypeTwinRustAsyncDatetimeFixedOffsetsTwinRustAsync(
+          {required List<DateTimeWithOffset> dates});
+
   Future<DateTime>
       crateApiPseudoManualChronoTypeTwinRustAsyncDatetimeLocalTwinRustAsync(
           {required DateTime d});
@@ -3695,20 +4042,48 @@ abstract class RustLibApi extends BaseApi {
       crateApiPseudoManualChronoTypeTwinRustAsyncHowLongDoesItTakeTwinRustAsync(
           {required FeatureChronoTwinRustAsync mine});
 
+  Future<DateTime>
+      crateApiPseudoManualChronoTypeTwinRustAsyncNaiveDateTwinRustAsync(
+          {required DateTime d});
+
+  Future<List<DateTime>>
+      crateApiPseudoManualChronoTypeTwinRustAsyncNaiveDatesTwinRustAsync(
+          {required List<DateTime> dates});
+
+  Future<Duration>
+      crateApiPseudoManualChronoTypeTwinRustAsyncNaiveTimeTwinRustAsync(
+          {required Duration d});
+
   Future<DateTime>
       crateApiPseudoManualChronoTypeTwinRustAsyncNaivedatetimeTwinRustAsync(
           {required DateTime d});
 
+  Future<DateTimeWithOffset?>
+      crateApiPseudoManualChronoTypeTwinRustAsyncOptionalDatetimeFixedOffsetTwinRustAsync(
+          {DateTimeWithOffset? d});
+
   Future<DateTime?>
       crateApiPseudoManualChronoTypeTwinRustAsyncOptionalEmptyDatetimeUtcTwinRustAsync(
           {DateTime? d});
 
+  Future<ScheduleTwinRustAsync>
+      crateApiPseudoManualChronoTypeTwinRustAsyncScheduleTwinRustAsync(
+          {required ScheduleTwinRustAsync schedule});
+
   Future<TestChronoTwinRustAsync>
       crateApiPseudoManualChronoTypeTwinRustAsyncTestChronoTwinRustAsync();
 
   Future<TestChronoTwinRustAsync>
       crateApiPseudoManualChronoTypeTwinRustAsyncTestPreciseChronoTwinRustAsync();
 
+  Future<DateTimeWithOffset>
+      crateApiPseudoManualChronoTypeTwinRustAsyncSseDatetimeFixedOffsetTwinRustAsyncSse(
+          {required DateTimeWithOffset d});
+
+  Future<List<DateTimeWithOffset>>
+      crateApiPseudoManualChronoTypeTwinRustAsyncSseDatetimeFixedOffsetsTwinRustAsyncSse(
+          {required List<DateTimeWithOffset> dates});
+
   Future<DateTime>
       crateApiPseudoManualChronoTypeTwinRustAsyncSseDatetimeLocalTwinRustAsyncSse(
           {required DateTime d});
@@ -3733,20 +4108,48 @@ abstract class RustLibApi extends BaseApi {
       crateApiPseudoManualChronoTypeTwinRustAsyncSseHowLongDoesItTakeTwinRustAsyncSse(
           {required FeatureChronoTwinRustAsyncSse mine});
 
+  Future<DateTime>
+      crateApiPseudoManualChronoTypeTwinRustAsyncSseNaiveDateTwinRustAsyncSse(
+          {required DateTime d});
+
+  Future<List<DateTime>>
+      crateApiPseudoManualChronoTypeTwinRustAsyncSseNaiveDatesTwinRustAsyncSse(
+          {required List<DateTime> dates});
+
+  Future<Duration>
+      crateApiPseudoManualChronoTypeTwinRustAsyncSseNaiveTimeTwinRustAsyncSse(
+          {required Duration d});
+
   Future<DateTime>
       crateApiPseudoManualChronoTypeTwinRustAsyncSseNaivedatetimeTwinRustAsyncSse(
           {required DateTime d});
 
+  Future<DateTimeWithOffset?>
+      crateApiPseudoManualChronoTypeTwinRustAsyncSseOptionalDatetimeFixedOffsetTwinRustAsyncSse(
+          {DateTimeWithOffset? d});
+
   Future<DateTime?>
       crateApiPseudoManualChronoTypeTwinRustAsyncSseOptionalEmptyDatetimeUtcTwinRustAsyncSse(
           {DateTime? d});
 
+  Future<ScheduleTwinRustAsyncSse>
+      crateApiPseudoManualChronoTypeTwinRustAsyncSseScheduleTwinRustAsyncSse(
+          {required ScheduleTwinRustAsyncSse schedule});
+
   Future<TestChronoTwinRustAsyncSse>
       crateApiPseudoManualChronoTypeTwinRustAsyncSseTestChronoTwinRustAsyncSse();
 
   Future<TestChronoTwinRustAsyncSse>
       crateApiPseudoManualChronoTypeTwinRustAsyncSseTestPreciseChronoTwinRustAsyncSse();
 
+  Future<DateTimeWithOffset>
+      crateApiPseudoManualChronoTypeTwinSseDatetimeFixedOffsetTwinSse(
+          {required DateTimeWithOffset d});
+
+  Future<List<DateTimeWithOffset>>
+      crateApiPseudoManualChronoTypeTwinSseDatetimeFixedOffsetsTwinSse(
+          {required List<DateTimeWithOffset> dates});
+
   Future<DateTime> crateApiPseudoManualChronoTypeTwinSseDatetimeLocalTwinSse(
       {required DateTime d});
 
@@ -3768,19 +4171,43 @@ abstract class RustLibApi extends BaseApi {
       crateApiPseudoManualChronoTypeTwinSseHowLongDoesItTakeTwinSse(
           {required FeatureChronoTwinSse mine});
 
+  Future<DateTime> crateApiPseudoManualChronoTypeTwinSseNaiveDateTwinSse(
+      {required DateTime d});
+
+  Future<List<DateTime>> crateApiPseudoManualChronoTypeTwinSseNaiveDatesTwinSse(
+      {required List<DateTime> dates});
+
+  Future<Duration> crateApiPseudoManualChronoTypeTwinSseNaiveTimeTwinSse(
+      {required Duration d});
+
   Future<DateTime> crateApiPseudoManualChronoTypeTwinSseNaivedatetimeTwinSse(
       {required DateTime d});
 
+  Future<DateTimeWithOffset?>
+      crateApiPseudoManualChronoTypeTwinSseOptionalDatetimeFixedOffsetTwinSse(
+          {DateTimeWithOffset? d});
+
   Future<DateTime?>
       crateApiPseudoManualChronoTypeTwinSseOptionalEmptyDatetimeUtcTwinSse(
           {DateTime? d});
 
+  Future<ScheduleTwinSse> crateApiPseudoManualChronoTypeTwinSseScheduleTwinSse(
+      {required ScheduleTwinSse schedule});
+
   Future<TestChronoTwinSse>
       crateApiPseudoManualChronoTypeTwinSseTestChronoTwinSse();
 
   Future<TestChronoTwinSse>
       crateApiPseudoManualChronoTypeTwinSseTestPreciseChronoTwinSse();
 
+  DateTimeWithOffset
+      crateApiPseudoManualChronoTypeTwinSyncDatetimeFixedOffsetTwinSync(
+          {required DateTimeWithOffset d});
+
+  List<DateTimeWithOffset>
+      crateApiPseudoManualChronoTypeTwinSyncDatetimeFixedOffsetsTwinSync(
+          {required List<DateTimeWithOffset> dates});
+
   DateTime crateApiPseudoManualChronoTypeTwinSyncDatetimeLocalTwinSync(
       {required DateTime d});
 
@@ -3799,18 +4226,42 @@ abstract class RustLibApi extends BaseApi {
   Duration crateApiPseudoManualChronoTypeTwinSyncHowLongDoesItTakeTwinSync(
       {required FeatureChronoTwinSync mine});
 
+  DateTime crateApiPseudoManualChronoTypeTwinSyncNaiveDateTwinSync(
+      {required DateTime d});
+
+  List<DateTime> crateApiPseudoManualChronoTypeTwinSyncNaiveDatesTwinSync(
+      {required List<DateTime> dates});
+
+  Duration crateApiPseudoManualChronoTypeTwinSyncNaiveTimeTwinSync(
+      {required Duration d});
+
   DateTime crateApiPseudoManualChronoTypeTwinSyncNaivedatetimeTwinSync(
       {required DateTime d});
 
+  DateTimeWithOffset?
+      crateApiPseudoManualChronoTypeTwinSyncOptionalDatetimeFixedOffsetTwinSync(
+          {DateTimeWithOffset? d});
+
   DateTime?
       crateApiPseudoManualChronoTypeTwinSyncOptionalEmptyDatetimeUtcTwinSync(
           {DateTime? d});
 
+  ScheduleTwinSync crateApiPseudoManualChronoTypeTwinSyncScheduleTwinSync(
+      {required ScheduleTwinSync schedule});
+
   TestChronoTwinSync crateApiPseudoManualChronoTypeTwinSyncTestChronoTwinSync();
 
   TestChronoTwinSync
       crateApiPseudoManualChronoTypeTwinSyncTestPreciseChronoTwinSync();
 
+  DateTimeWithOffset
+      crateApiPseudoManualChronoTypeTwinSyncSseDatetimeFixedOffsetTwinSyncSse(
+          {required DateTimeWithOffset d});
+
+  List<DateTimeWithOffset>
+      crateApiPseudoManualChronoTypeTwinSyncSseDatetimeFixedOffsetsTwinSyncSse(
+          {required List<DateTimeWithOffset> dates});
+
   DateTime crateApiPseudoManualChronoTypeTwinSyncSseDatetimeLocalTwinSyncSse(
       {required DateTime d});
 
@@ -3832,13 +4283,30 @@ abstract class RustLibApi extends BaseApi {
       crateApiPseudoManualChronoTypeTwinSyncSseHowLongDoesItTakeTwinSyncSse(
           {required FeatureChronoTwinSyncSse mine});
 
+  DateTime crateApiPseudoManualChronoTypeTwinSyncSseNaiveDateTwinSyncSse(
+      {required DateTime d});
+
+  List<DateTime> crateApiPseudoManualChronoTypeTwinSyncSseNaiveDatesTwinSyncSse(
+      {required List<DateTime> dates});
+
+  Duration crateApiPseudoManualChronoTypeTwinSyncSseNaiveTimeTwinSyncSse(
+      {required Duration d});
+
   DateTime crateApiPseudoManualChronoTypeTwinSyncSseNaivedatetimeTwinSyncSse(
       {required DateTime d});
 
+  DateTimeWithOffset?
+      crateApiPseudoManualChronoTypeTwinSyncSseOptionalDatetimeFixedOffsetTwinSyncSse(
+          {DateTimeWithOffset? d});
+
   DateTime?
       crateApiPseudoManualChronoTypeTwinSyncSseOptionalEmptyDatetimeUtcTwinSyncSse(
           {DateTime? d});
 
+  ScheduleTwinSyncSse
+      crateApiPseudoManualChronoTypeTwinSyncSseScheduleTwinSyncSse(
+          {required ScheduleTwinSyncSse schedule});
+
   TestChronoTwinSyncSse
       crateApiPseudoManualChronoTypeTwinSyncSseTestChronoTwinSyncSse();
 
@@ -3972,6 +4440,14 @@ abstract class RustLibApi extends BaseApi {
       crateApiPseudoManualDartFnTwinRustAsyncRustCallDartWithDartOpaqueResultTwinRustAsync(
           {required FutureOr<Object> Function() callback});
 
+  Future<int>
+      crateApiPseudoManualDartFnTwinRustAsyncRustConsumeDartStreamSumTwinRustAsync(
+          {required Stream<int> stream});
+
+  Future<List<String>>
+      crateApiPseudoManualDartFnTwinRustAsyncRustConsumeDartStreamTakeTwinRustAsync(
+          {required Stream<String> stream, required BigInt count});
+
   Future<void>
       crateApiPseudoManualDartFnTwinRustAsyncSseRustCallDartLoopbackTwinRustAsyncSse(
           {required FutureOr<DemoStructForRustCallDartTwinRustAsyncSse>
@@ -4014,6 +4490,14 @@ abstract class RustLibApi extends BaseApi {
       crateApiPseudoManualDartFnTwinRustAsyncSseRustCallDartWithDartOpaqueResultTwinRustAsyncSse(
           {required FutureOr<Object> Function() callback});
 
+  Future<int>
+      crateApiPseudoManualDartFnTwinRustAsyncSseRustConsumeDartStreamSumTwinRustAsyncSse(
+          {required Stream<int> stream});
+
+  Future<List<String>>
+      crateApiPseudoManualDartFnTwinRustAsyncSseRustConsumeDartStreamTakeTwinRustAsyncSse(
+          {required Stream<String> stream, required BigInt count});
+
   Future<void> crateApiPseudoManualDartFnTwinSseRustCallDartLoopbackTwinSse(
       {required FutureOr<DemoStructForRustCallDartTwinSse> Function(
               DemoStructForRustCallDartTwinSse)
@@ -4049,6 +4533,13 @@ abstract class RustLibApi extends BaseApi {
       crateApiPseudoManualDartFnTwinSseRustCallDartWithDartOpaqueResultTwinSse(
           {required FutureOr<Object> Function() callback});
 
+  Future<int> crateApiPseudoManualDartFnTwinSseRustConsumeDartStreamSumTwinSse(
+      {required Stream<int> stream});
+
+  Future<List<String>>
+      crateApiPseudoManualDartFnTwinSseRustConsumeDartStreamTakeTwinSse(
+          {required Stream<String> stream, required BigInt count});
+
   String crateApiPseudoManualDartOpaqueSyncTwinSseSyncAcceptDartOpaqueTwinSse(
       {required Object opaque});
 
@@ -4749,9 +5240,19 @@ abstract class RustLibApi extends BaseApi {
   Future<Stream<String>>
       crateApiPseudoManualExceptionTwinRustAsyncStreamSinkThrowAnyhowTwinRustAsync();
 
+  Future<void>
+      crateApiPseudoManualExceptionTwinRustAsyncThrowAnyhowIoErrorTwinRustAsync();
+
   Future<void>
       crateApiPseudoManualExceptionTwinRustAsyncThrowAnyhowTwinRustAsync();
 
+  Future<void>
+      crateApiPseudoManualExceptionTwinRustAsyncThrowAnyhowWithContextTwinRustAsync();
+
+  Future<int>
+      crateApiPseudoManualExceptionTwinRustAsyncTypedExceptionReturnErrorTwinRustAsync(
+          {required int variant});
+
   Future<void>
       crateApiPseudoManualExceptionTwinRustAsyncSseCustomEnumErrorPanicTwinRustAsyncSse();
 
@@ -4846,9 +5347,19 @@ abstract class RustLibApi extends BaseApi {
   Future<Stream<String>>
       crateApiPseudoManualExceptionTwinRustAsyncSseStreamSinkThrowAnyhowTwinRustAsyncSse();
 
+  Future<void>
+      crateApiPseudoManualExceptionTwinRustAsyncSseThrowAnyhowIoErrorTwinRustAsyncSse();
+
   Future<void>
       crateApiPseudoManualExceptionTwinRustAsyncSseThrowAnyhowTwinRustAsyncSse();
 
+  Future<void>
+      crateApiPseudoManualExceptionTwinRustAsyncSseThrowAnyhowWithContextTwinRustAsyncSse();
+
+  Future<int>
+      crateApiPseudoManualExceptionTwinRustAsyncSseTypedExceptionReturnErrorTwinRustAsyncSse(
+          {required int variant});
+
   Future<void>
       crateApiPseudoManualExceptionTwinSseCustomEnumErrorPanicTwinSse();
 
@@ -4938,8 +5449,17 @@ abstract class RustLibApi extends BaseApi {
   Future<Stream<String>>
       crateApiPseudoManualExceptionTwinSseStreamSinkThrowAnyhowTwinSse();
 
+  Future<void> crateApiPseudoManualExceptionTwinSseThrowAnyhowIoErrorTwinSse();
+
   Future<void> crateApiPseudoManualExceptionTwinSseThrowAnyhowTwinSse();
 
+  Future<void>
+      crateApiPseudoManualExceptionTwinSseThrowAnyhowWithContextTwinSse();
+
+  Future<int>
+      crateApiPseudoManualExceptionTwinSseTypedExceptionReturnErrorTwinSse(
+          {required int variant});
+
   void crateApiPseudoManualExceptionTwinSyncCustomEnumErrorPanicTwinSync();
 
   int crateApiPseudoManualExceptionTwinSyncCustomEnumErrorReturnErrorTwinSync();
@@ -5014,8 +5534,15 @@ abstract class RustLibApi extends BaseApi {
   Stream<String>
       crateApiPseudoManualExceptionTwinSyncStreamSinkThrowAnyhowTwinSync();
 
+  void crateApiPseudoManualExceptionTwinSyncThrowAnyhowIoErrorTwinSync();
+
   void crateApiPseudoManualExceptionTwinSyncThrowAnyhowTwinSync();
 
+  void crateApiPseudoManualExceptionTwinSyncThrowAnyhowWithContextTwinSync();
+
+  int crateApiPseudoManualExceptionTwinSyncTypedExceptionReturnErrorTwinSync(
+      {required int variant});
+
   void
       crateApiPseudoManualExceptionTwinSyncSseCustomEnumErrorPanicTwinSyncSse();
 
@@ -5095,8 +5622,16 @@ abstract class RustLibApi extends BaseApi {
   Stream<String>
       crateApiPseudoManualExceptionTwinSyncSseStreamSinkThrowAnyhowTwinSyncSse();
 
+  void crateApiPseudoManualExceptionTwinSyncSseThrowAnyhowIoErrorTwinSyncSse();
+
   void crateApiPseudoManualExceptionTwinSyncSseThrowAnyhowTwinSyncSse();
 
+  void
+      crateApiPseudoManualExceptionTwinSyncSseThrowAnyhowWithContextTwinSyncSse();
+
+  int crateApiPseudoManualExceptionTwinSyncSseTypedExceptionReturnErrorTwinSyncSse(
+      {required int variant});
+
   Future<NewSimpleStruct>
       crateApiPseudoManualExternalTypeInCrateTwinRustAsyncCallNewModuleSystemTwinRustAsync();
 
@@ -5300,6 +5835,158 @@ abstract class RustLibApi extends BaseApi {
   Future<int>
       crateApiPseudoManualImplTraitTwinSyncSseStructTwoWithTraitTwinSyncSseSimpleTraitFnWithDefaultImplTwinSyncSse();
 
+  Future<Map<String, dynamic>>
+      crateApiPseudoManualJsonTypeTwinRustAsyncCreateJsonMapTwinRustAsync();
+
+  Future<Object?>
+      crateApiPseudoManualJsonTypeTwinRustAsyncCreateJsonValueTwinRustAsync();
+
+  Future<JsonDocumentTwinRustAsync>
+      crateApiPseudoManualJsonTypeTwinRustAsyncJsonDocumentTwinRustAsync(
+          {required JsonDocumentTwinRustAsync document});
+
+  Future<List<String>>
+      crateApiPseudoManualJsonTypeTwinRustAsyncJsonMapKeysTwinRustAsync(
+          {required Map<String, dynamic> map});
+
+  Future<Map<String, dynamic>>
+      crateApiPseudoManualJsonTypeTwinRustAsyncJsonMapTwinRustAsync(
+          {required Map<String, dynamic> map});
+
+  Future<String>
+      crateApiPseudoManualJsonTypeTwinRustAsyncJsonValueToStringTwinRustAsync(
+          {required Object? value});
+
+  Future<Object?>
+      crateApiPseudoManualJsonTypeTwinRustAsyncJsonValueTwinRustAsync(
+          {required Object? value});
+
+  Future<List<Object?>>
+      crateApiPseudoManualJsonTypeTwinRustAsyncJsonValuesTwinRustAsync(
+          {required List<Object?> values});
+
+  Future<Object?>
+      crateApiPseudoManualJsonTypeTwinRustAsyncOptionalJsonValueTwinRustAsync(
+          {Object? value});
+
+  Future<Map<String, dynamic>>
+      crateApiPseudoManualJsonTypeTwinRustAsyncSseCreateJsonMapTwinRustAsyncSse();
+
+  Future<Object?>
+      crateApiPseudoManualJsonTypeTwinRustAsyncSseCreateJsonValueTwinRustAsyncSse();
+
+  Future<JsonDocumentTwinRustAsyncSse>
+      crateApiPseudoManualJsonTypeTwinRustAsyncSseJsonDocumentTwinRustAsyncSse(
+          {required JsonDocumentTwinRustAsyncSse document});
+
+  Future<List<String>>
+      crateApiPseudoManualJsonTypeTwinRustAsyncSseJsonMapKeysTwinRustAsyncSse(
+          {required Map<String, dynamic> map});
+
+  Future<Map<String, dynamic>>
+      crateApiPseudoManualJsonTypeTwinRustAsyncSseJsonMapTwinRustAsyncSse(
+          {required Map<String, dynamic> map});
+
+  Future<String>
+      crateApiPseudoManualJsonTypeTwinRustAsyncSseJsonValueToStringTwinRustAsyncSse(
+          {required Object? value});
+
+  Future<Object?>
+      crateApiPseudoManualJsonTypeTwinRustAsyncSseJsonValueTwinRustAsyncSse(
+          {required Object? value});
+
+  Future<List<Object?>>
+      crateApiPseudoManualJsonTypeTwinRustAsyncSseJsonValuesTwinRustAsyncSse(
+          {required List<Object?> values});
+
+  Future<Object?>
+      crateApiPseudoManualJsonTypeTwinRustAsyncSseOptionalJsonValueTwinRustAsyncSse(
+          {Object? value});
+
+  Future<Map<String, dynamic>>
+      crateApiPseudoManualJsonTypeTwinSseCreateJsonMapTwinSse();
+
+  Future<Object?> crateApiPseudoManualJsonTypeTwinSseCreateJsonValueTwinSse();
+
+  Future<JsonDocumentTwinSse>
+      crateApiPseudoManualJsonTypeTwinSseJsonDocumentTwinSse(
+          {required JsonDocumentTwinSse document});
+
+  Future<List<String>> crateApiPseudoManualJsonTypeTwinSseJsonMapKeysTwinSse(
+      {required Map<String, dynamic> map});
+
+  Future<Map<String, dynamic>>
+      crateApiPseudoManualJsonTypeTwinSseJsonMapTwinSse(
+          {required Map<String, dynamic> map});
+
+  Future<String> crateApiPseudoManualJsonTypeTwinSseJsonValueToStringTwinSse(
+      {required Object? value});
+
+  Future<Object?> crateApiPseudoManualJsonTypeTwinSseJsonValueTwinSse(
+      {required Object? value});
+
+  Future<List<Object?>> crateApiPseudoManualJsonTypeTwinSseJsonValuesTwinSse(
+      {required List<Object?> values});
+
+  Future<Object?> crateApiPseudoManualJsonTypeTwinSseOptionalJsonValueTwinSse(
+      {Object? value});
+
+  Map<String, dynamic>
+      crateApiPseudoManualJsonTypeTwinSyncCreateJsonMapTwinSync();
+
+  Object? crateApiPseudoManualJsonTypeTwinSyncCreateJsonValueTwinSync();
+
+  JsonDocumentTwinSync crateApiPseudoManualJsonTypeTwinSyncJsonDocumentTwinSync(
+      {required JsonDocumentTwinSync document});
+
+  List<String> crateApiPseudoManualJsonTypeTwinSyncJsonMapKeysTwinSync(
+      {required Map<String, dynamic> map});
+
+  Map<String, dynamic> crateApiPseudoManualJsonTypeTwinSyncJsonMapTwinSync(
+      {required Map<String, dynamic> map});
+
+  String crateApiPseudoManualJsonTypeTwinSyncJsonValueToStringTwinSync(
+      {required Object? value});
+
+  Object? crateApiPseudoManualJsonTypeTwinSyncJsonValueTwinSync(
+      {required Object? value});
+
+  List<Object?> crateApiPseudoManualJsonTypeTwinSyncJsonValuesTwinSync(
+      {required List<Object?> values});
+
+  Object?
+      crateApiPseudoManualJsonTypeTwinSyncOptionalJsonValueTwinSync(
+          {Object? value});
+
+  Map<String, dynamic>
+      crateApiPseudoManualJsonTypeTwinSyncSseCreateJsonMapTwinSyncSse();
+
+  Object? crateApiPseudoManualJsonTypeTwinSyncSseCreateJsonValueTwinSyncSse();
+
+  JsonDocumentTwinSyncSse
+      crateApiPseudoManualJsonTypeTwinSyncSseJsonDocumentTwinSyncSse(
+          {required JsonDocumentTwinSyncSse document});
+
+  List<String> crateApiPseudoManualJsonTypeTwinSyncSseJsonMapKeysTwinSyncSse(
+      {required Map<String, dynamic> map});
+
+  Map<String, dynamic>
+      crateApiPseudoManualJsonTypeTwinSyncSseJsonMapTwinSyncSse(
+          {required Map<String, dynamic> map});
+
+  String crateApiPseudoManualJsonTypeTwinSyncSseJsonValueToStringTwinSyncSse(
+      {required Object? value});
+
+  Object? crateApiPseudoManualJsonTypeTwinSyncSseJsonValueTwinSyncSse(
+      {required Object? value});
+
+  List<Object?> crateApiPseudoManualJsonTypeTwinSyncSseJsonValuesTwinSyncSse(
+      {required List<Object?> values});
+
+  Object?
+      crateApiPseudoManualJsonTypeTwinSyncSseOptionalJsonValueTwinSyncSse(
+          {Object? value});
+
   String
       crateApiPseudoManualLifetimeableTwinSyncLifetimeableAutoOwnedRustOpaqueFlutterRustBridgeforGeneratedRustAutoOpaqueInnerLtNestedTypeWithLifetimeTwinSyncstaticGreetBorrowMutSelfTwinSync(
           {required LtNestedTypeWithLifetimeTwinSync that});
@@ -5369,6 +6056,22 @@ abstract class RustLibApi extends BaseApi {
       crateApiPseudoManualLifetimeableTwinSyncLtComputeWithLifetimeFunctionTwinSync(
           {required LtOwnedStructTwinSync arg});
 
+  Future<Map<int, int>>
+      crateApiPseudoManualMapAndSetTwinRustAsyncFuncBtreeMapI32I32TwinRustAsync(
+          {required Map<int, int> arg});
+
+  Future<Map<String, MySize>>
+      crateApiPseudoManualMapAndSetTwinRustAsyncFuncBtreeMapStringStructTwinRustAsync(
+          {required Map<String, MySize> arg});
+
+  Future<Set<int>>
+      crateApiPseudoManualMapAndSetTwinRustAsyncFuncBtreeSetI32TwinRustAsync(
+          {required Set<int> arg});
+
+  Future<Set<String>>
+      crateApiPseudoManualMapAndSetTwinRustAsyncFuncBtreeSetStringTwinRustAsync(
+          {required Set<String> arg});
+
   Future<Map<int, int>>
       crateApiPseudoManualMapAndSetTwinRustAsyncFuncHashMapI32I32TwinRustAsync(
           {required Map<int, int> arg});
@@ -5401,6 +6104,30 @@ abstract class RustLibApi extends BaseApi {
       crateApiPseudoManualMapAndSetTwinRustAsyncFuncHashSetStringTwinRustAsync(
           {required Set<String> arg});
 
+  Future<Int32List>
+      crateApiPseudoManualMapAndSetTwinRustAsyncFuncVecDequeI32TwinRustAsync(
+          {required Int32List arg});
+
+  Future<List<String>>
+      crateApiPseudoManualMapAndSetTwinRustAsyncFuncVecDequeStringTwinRustAsync(
+          {required List<String> arg});
+
+  Future<Map<int, int>>
+      crateApiPseudoManualMapAndSetTwinRustAsyncSseFuncBtreeMapI32I32TwinRustAsyncSse(
+          {required Map<int, int> arg});
+
+  Future<Map<String, MySize>>
+      crateApiPseudoManualMapAndSetTwinRustAsyncSseFuncBtreeMapStringStructTwinRustAsyncSse(
+          {required Map<String, MySize> arg});
+
+  Future<Set<int>>
+      crateApiPseudoManualMapAndSetTwinRustAsyncSseFuncBtreeSetI32TwinRustAsyncSse(
+          {required Set<int> arg});
+
+  Future<Set<String>>
+      crateApiPseudoManualMapAndSetTwinRustAsyncSseFuncBtreeSetStringTwinRustAsyncSse(
+          {required Set<String> arg});
+
   Future<Map<int, int>>
       crateApiPseudoManualMapAndSetTwinRustAsyncSseFuncHashMapI32I32TwinRustAsyncSse(
           {required Map<int, int> arg});
@@ -5433,6 +6160,29 @@ abstract class RustLibApi extends BaseApi {
       crateApiPseudoManualMapAndSetTwinRustAsyncSseFuncHashSetStringTwinRustAsyncSse(
           {required Set<String> arg});
 
+  Future<Int32List>
+      crateApiPseudoManualMapAndSetTwinRustAsyncSseFuncVecDequeI32TwinRustAsyncSse(
+          {required Int32List arg});
+
+  Future<List<String>>
+      crateApiPseudoManualMapAndSetTwinRustAsyncSseFuncVecDequeStringTwinRustAsyncSse(
+          {required List<String> arg});
+
+  Future<Map<int, int>>
+      crateApiPseudoManualMapAndSetTwinSseFuncBtreeMapI32I32TwinSse(
+          {required Map<int, int> arg});
+
+  Future<Map<String, MySize>>
+      crateApiPseudoManualMapAndSetTwinSseFuncBtreeMapStringStructTwinSse(
+          {required Map<String, MySize> arg});
+
+  Future<Set<int>> crateApiPseudoManualMapAndSetTwinSseFuncBtreeSetI32TwinSse(
+      {required Set<int> arg});
+
+  Future<Set<String>>
+      crateApiPseudoManualMapAndSetTwinSseFuncBtreeSetStringTwinSse(
+          {required Set<String> arg});
+
   Future<Map<int, int>>
       crateApiPseudoManualMapAndSetTwinSseFuncHashMapI32I32TwinSse(
           {required Map<int, int> arg});
@@ -5464,6 +6214,26 @@ abstract class RustLibApi extends BaseApi {
       crateApiPseudoManualMapAndSetTwinSseFuncHashSetStringTwinSse(
           {required Set<String> arg});
 
+  Future<Int32List> crateApiPseudoManualMapAndSetTwinSseFuncVecDequeI32TwinSse(
+      {required Int32List arg});
+
+  Future<List<String>>
+      crateApiPseudoManualMapAndSetTwinSseFuncVecDequeStringTwinSse(
+          {required List<String> arg});
+
+  Map<int, int> crateApiPseudoManualMapAndSetTwinSyncFuncBtreeMapI32I32TwinSync(
+      {required Map<int, int> arg});
+
+  Map<String, MySize>
+      crateApiPseudoManualMapAndSetTwinSyncFuncBtreeMapStringStructTwinSync(
+          {required Map<String, MySize> arg});
+
+  Set<int> crateApiPseudoManualMapAndSetTwinSyncFuncBtreeSetI32TwinSync(
+      {required Set<int> arg});
+
+  Set<String> crateApiPseudoManualMapAndSetTwinSyncFuncBtreeSetStringTwinSync(
+      {required Set<String> arg});
+
   Map<int, int> crateApiPseudoManualMapAndSetTwinSyncFuncHashMapI32I32TwinSync(
       {required Map<int, int> arg});
 
@@ -5493,6 +6263,27 @@ abstract class RustLibApi extends BaseApi {
   Set<String> crateApiPseudoManualMapAndSetTwinSyncFuncHashSetStringTwinSync(
       {required Set<String> arg});
 
+  Int32List crateApiPseudoManualMapAndSetTwinSyncFuncVecDequeI32TwinSync(
+      {required Int32List arg});
+
+  List<String> crateApiPseudoManualMapAndSetTwinSyncFuncVecDequeStringTwinSync(
+      {required List<String> arg});
+
+  Map<int, int>
+      crateApiPseudoManualMapAndSetTwinSyncSseFuncBtreeMapI32I32TwinSyncSse(
+          {required Map<int, int> arg});
+
+  Map<String, MySize>
+      crateApiPseudoManualMapAndSetTwinSyncSseFuncBtreeMapStringStructTwinSyncSse(
+          {required Map<String, MySize> arg});
+
+  Set<int> crateApiPseudoManualMapAndSetTwinSyncSseFuncBtreeSetI32TwinSyncSse(
+      {required Set<int> arg});
+
+  Set<String>
+      crateApiPseudoManualMapAndSetTwinSyncSseFuncBtreeSetStringTwinSyncSse(
+          {required Set<String> arg});
+
   Map<int, int>
       crateApiPseudoManualMapAndSetTwinSyncSseFuncHashMapI32I32TwinSyncSse(
           {required Map<int, int> arg});
@@ -5524,6 +6315,13 @@ abstract class RustLibApi extends BaseApi {
       crateApiPseudoManualMapAndSetTwinSyncSseFuncHashSetStringTwinSyncSse(
           {required Set<String> arg});
 
+  Int32List crateApiPseudoManualMapAndSetTwinSyncSseFuncVecDequeI32TwinSyncSse(
+      {required Int32List arg});
+
+  List<String>
+      crateApiPseudoManualMapAndSetTwinSyncSseFuncVecDequeStringTwinSyncSse(
+          {required List<String> arg});
+
   Future<int>
       crateApiPseudoManualMethodTwinRustAsyncStaticGetterOnlyTwinRustAsyncStaticGetterTwinRustAsync();
 
@@ -9531,10 +10329,145 @@ abstract class RustLibApi extends BaseApi {
   int crateApiPseudoManualSimpleTwinSyncSseSimpleAdderTwinSyncSse(
       {required int a, required int b});
 
+  Future<Duration>
+      crateApiPseudoManualStdTimeTwinRustAsyncStdDurationTwinRustAsync(
+          {required Duration d});
+
+  Future<List<Duration>>
+      crateApiPseudoManualStdTimeTwinRustAsyncStdDurationsTwinRustAsync(
+          {required List<Duration> durations});
+
+  Future<Duration?>
+      crateApiPseudoManualStdTimeTwinRustAsyncStdOptionalDurationTwinRustAsync(
+          {Duration? d});
+
+  Future<DateTime?>
+      crateApiPseudoManualStdTimeTwinRustAsyncStdOptionalSystemTimeTwinRustAsync(
+          {DateTime? t});
+
+  Future<DateTime>
+      crateApiPseudoManualStdTimeTwinRustAsyncStdSystemTimeTwinRustAsync(
+          {required DateTime t});
+
+  Future<List<DateTime>>
+      crateApiPseudoManualStdTimeTwinRustAsyncStdSystemTimesTwinRustAsync(
+          {required List<DateTime> times});
+
+  Future<StdTimeTwinRustAsync>
+      crateApiPseudoManualStdTimeTwinRustAsyncStdTimeStructTwinRustAsync(
+          {required StdTimeTwinRustAsync value});
+
+  Future<Duration>
+      crateApiPseudoManualStdTimeTwinRustAsyncSseStdDurationTwinRustAsyncSse(
+          {required Duration d});
+
+  Future<List<Duration>>
+      crateApiPseudoManualStdTimeTwinRustAsyncSseStdDurationsTwinRustAsyncSse(
+          {required List<Duration> durations});
+
+  Future<Duration?>
+      crateApiPseudoManualStdTimeTwinRustAsyncSseStdOptionalDurationTwinRustAsyncSse(
+          {Duration? d});
+
+  Future<DateTime?>
+      crateApiPseudoManualStdTimeTwinRustAsyncSseStdOptionalSystemTimeTwinRustAsyncSse(
+          {DateTime? t});
+
+  Future<DateTime>
+      crateApiPseudoManualStdTimeTwinRustAsyncSseStdSystemTimeTwinRustAsyncSse(
+          {required DateTime t});
+
+  Future<List<DateTime>>
+      crateApiPseudoManualStdTimeTwinRustAsyncSseStdSystemTimesTwinRustAsyncSse(
+          {required List<DateTime> times});
+
+  Future<StdTimeTwinRustAsyncSse>
+      crateApiPseudoManualStdTimeTwinRustAsyncSseStdTimeStructTwinRustAsyncSse(
+          {required StdTimeTwinRustAsyncSse value});
+
+  Future<Duration> crateApiPseudoManualStdTimeTwinSseStdDurationTwinSse(
+      {required Duration d});
+
+  Future<List<Duration>> crateApiPseudoManualStdTimeTwinSseStdDurationsTwinSse(
+      {required List<Duration> durations});
+
+  Future<Duration?>
+      crateApiPseudoManualStdTimeTwinSseStdOptionalDurationTwinSse(
+          {Duration? d});
+
+  Future<DateTime?>
+      crateApiPseudoManualStdTimeTwinSseStdOptionalSystemTimeTwinSse(
+          {DateTime? t});
+
+  Future<DateTime> crateApiPseudoManualStdTimeTwinSseStdSystemTimeTwinSse(
+      {required DateTime t});
+
+  Future<List<DateTime>>
+      crateApiPseudoManualStdTimeTwinSseStdSystemTimesTwinSse(
+          {required List<DateTime> times});
+
+  Future<StdTimeTwinSse> crateApiPseudoManualStdTimeTwinSseStdTimeStructTwinSse(
+      {required StdTimeTwinSse value});
+
+  Duration crateApiPseudoManualStdTimeTwinSyncStdDurationTwinSync(
+      {required Duration d});
+
+  List<Duration> crateApiPseudoManualStdTimeTwinSyncStdDurationsTwinSync(
+      {required List<Duration> durations});
+
+  Duration? crateApiPseudoManualStdTimeTwinSyncStdOptionalDurationTwinSync(
+      {Duration? d});
+
+  DateTime? crateApiPseudoManualStdTimeTwinSyncStdOptionalSystemTimeTwinSync(
+      {DateTime? t});
+
+  DateTime crateApiPseudoManualStdTimeTwinSyncStdSystemTimeTwinSync(
+      {required DateTime t});
+
+  List<DateTime> crateApiPseudoManualStdTimeTwinSyncStdSystemTimesTwinSync(
+      {required List<DateTime> times});
+
+  StdTimeTwinSync crateApiPseudoManualStdTimeTwinSyncStdTimeStructTwinSync(
+      {required StdTimeTwinSync value});
+
+  Duration crateApiPseudoManualStdTimeTwinSyncSseStdDurationTwinSyncSse(
+      {required Duration d});
+
+  List<Duration> crateApiPseudoManualStdTimeTwinSyncSseStdDurationsTwinSyncSse(
+      {required List<Duration> durations});
+
+  Duration?
+      crateApiPseudoManualStdTimeTwinSyncSseStdOptionalDurationTwinSyncSse(
+          {Duration? d});
+
+  DateTime?
+      crateApiPseudoManualStdTimeTwinSyncSseStdOptionalSystemTimeTwinSyncSse(
+          {DateTime? t});
+
+  DateTime crateApiPseudoManualStdTimeTwinSyncSseStdSystemTimeTwinSyncSse(
+      {required DateTime t});
+
+  List<DateTime>
+      crateApiPseudoManualStdTimeTwinSyncSseStdSystemTimesTwinSyncSse(
+          {required List<DateTime> times});
+
+  StdTimeTwinSyncSse
+      crateApiPseudoManualStdTimeTwinSyncSseStdTimeStructTwinSyncSse(
+          {required StdTimeTwinSyncSse value});
+
   Stream<String>
       crateApiPseudoManualStreamMiscTwinSseFuncStreamRealisticTwinSse(
           {required String arg});
 
+  Stream<int>
+      crateApiPseudoManualStreamMiscTwinSseStreamSinkBoundedAddAsyncTwinSse();
+
+  Stream<int>
+      crateApiPseudoManualStreamMiscTwinSseStreamSinkBoundedAddWithErrorTwinSse();
+
+  Stream<int>
+      crateApiPseudoManualStreamMiscTwinSseStreamSinkBoundedKeepLatestTwinSse();
+
   Future<Stream<int>>
       crateApiPseudoManualStreamMiscTwinSseStreamSinkDartAsyncTwinSse();
 
@@ -9792,6 +10725,165 @@ abstract class RustLibApi extends BaseApi {
       crateApiPseudoManualStructureTwinSyncSseFuncTupleStructWithTwoFieldTwinSyncSse(
           {required TupleStructWithTwoFieldTwinSyncSse arg});
 
+  Future<DateTime> crateApiPseudoManualTimeTypeTwinRustAsyncDateTwinRustAsync(
+      {required DateTime d});
+
+  Future<Duration>
+      crateApiPseudoManualTimeTypeTwinRustAsyncNegateTimeDurationTwinRustAsync(
+          {required Duration d});
+
+  Future<DateTime>
+      crateApiPseudoManualTimeTypeTwinRustAsyncOffsetDateTimeTwinRustAsync(
+          {required DateTime d});
+
+  Future<DateTime?>
+      crateApiPseudoManualTimeTypeTwinRustAsyncOptionalOffsetDateTimeTwinRustAsync(
+          {DateTime? d});
+
+  Future<Duration?>
+      crateApiPseudoManualTimeTypeTwinRustAsyncOptionalTimeDurationTwinRustAsync(
+          {Duration? d});
+
+  Future<DateTime>
+      crateApiPseudoManualTimeTypeTwinRustAsyncPrimitiveDateTimeTwinRustAsync(
+          {required DateTime d});
+
+  Future<Duration>
+      crateApiPseudoManualTimeTypeTwinRustAsyncTimeDurationTwinRustAsync(
+          {required Duration d});
+
+  Future<List<Duration>>
+      crateApiPseudoManualTimeTypeTwinRustAsyncTimeDurationsTwinRustAsync(
+          {required List<Duration> durations});
+
+  Future<TimeTypesTwinRustAsync>
+      crateApiPseudoManualTimeTypeTwinRustAsyncTimeTypesTwinRustAsync(
+          {required TimeTypesTwinRustAsync value});
+
+  Future<DateTime>
+      crateApiPseudoManualTimeTypeTwinRustAsyncSseDateTwinRustAsyncSse(
+          {required DateTime d});
+
+  Future<Duration>
+      crateApiPseudoManualTimeTypeTwinRustAsyncSseNegateTimeDurationTwinRustAsyncSse(
+          {required Duration d});
+
+  Future<DateTime>
+      crateApiPseudoManualTimeTypeTwinRustAsyncSseOffsetDateTimeTwinRustAsyncSse(
+          {required DateTime d});
+
+  Future<DateTime?>
+      crateApiPseudoManualTimeTypeTwinRustAsyncSseOptionalOffsetDateTimeTwinRustAsyncSse(
+          {DateTime? d});
+
+  Future<Duration?>
+      crateApiPseudoManualTimeTypeTwinRustAsyncSseOptionalTimeDurationTwinRustAsyncSse(
+          {Duration? d});
+
+  Future<DateTime>
+      crateApiPseudoManualTimeTypeTwinRustAsyncSsePrimitiveDateTimeTwinRustAsyncSse(
+          {required DateTime d});
+
+  Future<Duration>
+      crateApiPseudoManualTimeTypeTwinRustAsyncSseTimeDurationTwinRustAsyncSse(
+          {required Duration d});
+
+  Future<List<Duration>>
+      crateApiPseudoManualTimeTypeTwinRustAsyncSseTimeDurationsTwinRustAsyncSse(
+          {required List<Duration> durations});
+
+  Future<TimeTypesTwinRustAsyncSse>
+      crateApiPseudoManualTimeTypeTwinRustAsyncSseTimeTypesTwinRustAsyncSse(
+          {required TimeTypesTwinRustAsyncSse value});
+
+  Future<DateTime> crateApiPseudoManualTimeTypeTwinSseDateTwinSse(
+      {required DateTime d});
+
+  Future<Duration> crateApiPseudoManualTimeTypeTwinSseNegateTimeDurationTwinSse(
+      {required Duration d});
+
+  Future<DateTime> crateApiPseudoManualTimeTypeTwinSseOffsetDateTimeTwinSse(
+      {required DateTime d});
+
+  Future<DateTime?>
+      crateApiPseudoManualTimeTypeTwinSseOptionalOffsetDateTimeTwinSse(
+          {DateTime? d});
+
+  Future<Duration?>
+      crateApiPseudoManualTimeTypeTwinSseOptionalTimeDurationTwinSse(
+          {Duration? d});
+
+  Future<DateTime> crateApiPseudoManualTimeTypeTwinSsePrimitiveDateTimeTwinSse(
+      {required DateTime d});
+
+  Future<Duration> crateApiPseudoManualTimeTypeTwinSseTimeDurationTwinSse(
+      {required Duration d});
+
+  Future<List<Duration>>
+      crateApiPseudoManualTimeTypeTwinSseTimeDurationsTwinSse(
+          {required List<Duration> durations});
+
+  Future<TimeTypesTwinSse> crateApiPseudoManualTimeTypeTwinSseTimeTypesTwinSse(
+      {required TimeTypesTwinSse value});
+
+  DateTime crateApiPseudoManualTimeTypeTwinSyncDateTwinSync(
+      {required DateTime d});
+
+  Duration crateApiPseudoManualTimeTypeTwinSyncNegateTimeDurationTwinSync(
+      {required Duration d});
+
+  DateTime crateApiPseudoManualTimeTypeTwinSyncOffsetDateTimeTwinSync(
+      {required DateTime d});
+
+  DateTime? crateApiPseudoManualTimeTypeTwinSyncOptionalOffsetDateTimeTwinSync(
+      {DateTime? d});
+
+  Duration? crateApiPseudoManualTimeTypeTwinSyncOptionalTimeDurationTwinSync(
+      {Duration? d});
+
+  DateTime crateApiPseudoManualTimeTypeTwinSyncPrimitiveDateTimeTwinSync(
+      {required DateTime d});
+
+  Duration crateApiPseudoManualTimeTypeTwinSyncTimeDurationTwinSync(
+      {required Duration d});
+
+  List<Duration> crateApiPseudoManualTimeTypeTwinSyncTimeDurationsTwinSync(
+      {required List<Duration> durations});
+
+  TimeTypesTwinSync crateApiPseudoManualTimeTypeTwinSyncTimeTypesTwinSync(
+      {required TimeTypesTwinSync value});
+
+  DateTime crateApiPseudoManualTimeTypeTwinSyncSseDateTwinSyncSse(
+      {required DateTime d});
+
+  Duration crateApiPseudoManualTimeTypeTwinSyncSseNegateTimeDurationTwinSyncSse(
+      {required Duration d});
+
+  DateTime crateApiPseudoManualTimeTypeTwinSyncSseOffsetDateTimeTwinSyncSse(
+      {required DateTime d});
+
+  DateTime?
+      crateApiPseudoManualTimeTypeTwinSyncSseOptionalOffsetDateTimeTwinSyncSse(
+          {DateTime? d});
+
+  Duration?
+      crateApiPseudoManualTimeTypeTwinSyncSseOptionalTimeDurationTwinSyncSse(
+          {Duration? d});
+
+  DateTime crateApiPseudoManualTimeTypeTwinSyncSsePrimitiveDateTimeTwinSyncSse(
+      {required DateTime d});
+
+  Duration crateApiPseudoManualTimeTypeTwinSyncSseTimeDurationTwinSyncSse(
+      {required Duration d});
+
+  List<Duration>
+      crateApiPseudoManualTimeTypeTwinSyncSseTimeDurationsTwinSyncSse(
+          {required List<Duration> durations});
+
+  TimeTypesTwinSyncSse
+      crateApiPseudoManualTimeTypeTwinSyncSseTimeTypesTwinSyncSse(
+          {required TimeTypesTwinSyncSse value});
+
   Future<void> crateApiPseudoManualTupleTwinRustAsyncTestTuple2TwinRustAsync(
       {required List<(String, int)> value});
 
@@ -10154,6 +11246,25 @@ abstract class RustLibApi extends BaseApi {
   Future<int> crateApiSimpleSimpleAdderTwinNormal(
       {required int a, required int b});
 
+  Future<Duration> crateApiStdTimeStdDurationTwinNormal({required Duration d});
+
+  Future<List<Duration>> crateApiStdTimeStdDurationsTwinNormal(
+      {required List<Duration> durations});
+
+  Future<Duration?> crateApiStdTimeStdOptionalDurationTwinNormal({Duration? d});
+
+  Future<DateTime?> crateApiStdTimeStdOptionalSystemTimeTwinNormal(
+      {DateTime? t});
+
+  Future<DateTime> crateApiStdTimeStdSystemTimeTwinNormal(
+      {required DateTime t});
+
+  Future<List<DateTime>> crateApiStdTimeStdSystemTimesTwinNormal(
+      {required List<DateTime> times});
+
+  Future<StdTimeTwinNormal> crateApiStdTimeStdTimeStructTwinNormal(
+      {required StdTimeTwinNormal value});
+
   Stream<int> crateApiStreamFuncStreamAddValueAndErrorTwinNormal();
 
   Future<Stream<String>> crateApiStreamFuncStreamReturnErrorTwinNormal();
@@ -10186,6 +11297,12 @@ abstract class RustLibApi extends BaseApi {
   Stream<String> crateApiStreamMiscFuncStreamRealisticTwinNormal(
       {required String arg});
 
+  Stream<int> crateApiStreamMiscStreamSinkBoundedAddAsyncTwinNormal();
+
+  Stream<int> crateApiStreamMiscStreamSinkBoundedAddWithErrorTwinNormal();
+
+  Stream<int> crateApiStreamMiscStreamSinkBoundedKeepLatestTwinNormal();
+
   Future<Stream<int>> crateApiStreamMiscStreamSinkDartAsyncTwinNormal();
 
   Future<StructWithDartKeywordFieldTwinNormal>
@@ -10216,6 +11333,32 @@ abstract class RustLibApi extends BaseApi {
       crateApiStructureFuncTupleStructWithTwoFieldTwinNormal(
           {required TupleStructWithTwoFieldTwinNormal arg});
 
+  Future<DateTime> crateApiTimeTypeDateTwinNormal({required DateTime d});
+
+  Future<Duration> crateApiTimeTypeNegateTimeDurationTwinNormal(
+      {required Duration d});
+
+  Future<DateTime> crateApiTimeTypeOffsetDateTimeTwinNormal(
+      {required DateTime d});
+
+  Future<DateTime?> crateApiTimeTypeOptionalOffsetDateTimeTwinNormal(
+      {DateTime? d});
+
+  Future<Duration?> crateApiTimeTypeOptionalTimeDurationTwinNormal(
+      {Duration? d});
+
+  Future<DateTime> crateApiTimeTypePrimitiveDateTimeTwinNormal(
+      {required DateTime d});
+
+  Future<Duration> crateApiTimeTypeTimeDurationTwinNormal(
+      {required Duration d});
+
+  Future<List<Duration>> crateApiTimeTypeTimeDurationsTwinNormal(
+      {required List<Duration> durations});
+
+  Future<TimeTypesTwinNormal> crateApiTimeTypeTimeTypesTwinNormal(
+      {required TimeTypesTwinNormal value});
+
   Future<void> crateApiTupleTestTuple2TwinNormal(
       {required List<(String, int)> value});
 
@@ -10240,6 +11383,22 @@ abstract class RustLibApi extends BaseApi {
   Future<List<UuidValue>> crateApiUuidTypeHandleUuidsTwinNormal(
       {required List<UuidValue> ids});
 
+  Future<int>
+      crateFrbGeneratedDartImplementableTraitTwinNormalDartImplAddTwinNormal(
+          {required DartImplementableTraitTwinNormalDartImpl that,
+          required int a,
+          required int b});
+
+  Future<String>
+      crateFrbGeneratedDartImplementableTraitTwinNormalDartImplDescribeTwinNormal(
+          {required DartImplementableTraitTwinNormalDartImpl that,
+          required int value});
+
+  DartImplementableTraitTwinNormalDartImpl
+      crateFrbGeneratedDartImplementableTraitTwinNormalDartImplNew(
+          {required FutureOr<int> Function(int, int) addTwinNormal,
+          required FutureOr<String> Function(int) describeTwinNormal});
+
   RustArcIncrementStrongCountFnType
       get rust_arc_increment_strong_count_BoxDartDebugTwinMoi;
 
@@ -11104,6 +12263,15 @@ abstract class RustLibApi extends BaseApi {
   CrossPlatformFinalizerArg
       get rust_arc_decrement_strong_count_ConstructorOpaqueSyncStructTwinNormalPtr;
 
+  RustArcIncrementStrongCountFnType
+      get rust_arc_increment_strong_count_DartImplementableTraitTwinNormalDartImpl;
+
+  RustArcDecrementStrongCountFnType
+      get rust_arc_decrement_strong_count_DartImplementableTraitTwinNormalDartImpl;
+
+  CrossPlatformFinalizerArg
+      get rust_arc_decrement_strong_count_DartImplementableTraitTwinNormalDartImplPtr;
+
   RustArcIncrementStrongCountFnType
       get rust_arc_increment_strong_count_DeliberateFailSanityCheckTwinNormal;
 
@@ -12066,6 +13234,15 @@ abstract class RustLibApi extends BaseApi {
   CrossPlatformFinalizerArg
       get rust_arc_decrement_strong_count_StructTwoWithTraitTwinSyncSsePtr;
 
+  RustArcIncrementStrongCountFnType
+      get rust_arc_increment_strong_count_StructWithDartImplementableTraitTwinNormal;
+
+  RustArcDecrementStrongCountFnType
+      get rust_arc_decrement_strong_count_StructWithDartImplementableTraitTwinNormal;
+
+  CrossPlatformFinalizerArg
+      get rust_arc_decrement_strong_count_StructWithDartImplementableTraitTwinNormalPtr;
+
   RustArcIncrementStrongCountFnType
       get rust_arc_increment_strong_count_StructWithGoodAndOpaqueFieldWithoutOptionTwinMoi;
 
@@ -12502,6 +13679,31 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["id"],
       );
 
+  @override
+  Future<void> crateApiAsyncMiscFuncAsyncNeverCompleteTwinNormal() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        return wire
+            .wire__crate__api__async_misc__func_async_never_complete_twin_normal(
+                port_);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiAsyncMiscFuncAsyncNeverCompleteTwinNormalConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiAsyncMiscFuncAsyncNeverCompleteTwinNormalConstMeta =>
+          const TaskConstMeta(
+            debugName: "func_async_never_complete_twin_normal",
+            argNames: [],
+          );
+
   @override
   Future<int> crateApiAsyncMiscFuncAsyncSimpleAddTwinNormal(
       {required int a, required int b}) {
@@ -12552,6 +13754,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: [],
       );
 
+  @override
+  Future<void> crateApiAsyncMiscFuncAsyncWaitForCancellationTwinNormal() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        return wire
+            .wire__crate__api__async_misc__func_async_wait_for_cancellation_twin_normal(
+                port_);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiAsyncMiscFuncAsyncWaitForCancellationTwinNormalConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiAsyncMiscFuncAsyncWaitForCancellationTwinNormalConstMeta =>
+          const TaskConstMeta(
+            debugName: "func_async_wait_for_cancellation_twin_normal",
+            argNames: [],
+          );
+
   @override
   Future<String> crateApiAsyncSpawnSimpleUseAsyncSpawn({required String arg}) {
     return handler.executeNormal(NormalTask(
@@ -12705,6 +13933,231 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: [],
       );
 
+  @override
+  Future<Decimal> crateApiBigNumberAddDecimalsTwinNormal(
+      {required Decimal a, required Decimal b}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_Decimal(a);
+        var arg1 = cst_encode_Decimal(b);
+        return wire.wire__crate__api__big_number__add_decimals_twin_normal(
+            port_, arg0, arg1);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_Decimal,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiBigNumberAddDecimalsTwinNormalConstMeta,
+      argValues: [a, b],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiBigNumberAddDecimalsTwinNormalConstMeta =>
+      const TaskConstMeta(
+        debugName: "add_decimals_twin_normal",
+        argNames: ["a", "b"],
+      );
+
+  @override
+  Future<BigInt> crateApiBigNumberBigIntTwinNormal({required BigInt i}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_BigInt(i);
+        return wire.wire__crate__api__big_number__big_int_twin_normal(
+            port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_BigInt,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiBigNumberBigIntTwinNormalConstMeta,
+      argValues: [i],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiBigNumberBigIntTwinNormalConstMeta =>
+      const TaskConstMeta(
+        debugName: "big_int_twin_normal",
+        argNames: ["i"],
+      );
+
+  @override
+  Future<List<BigInt>> crateApiBigNumberBigIntsTwinNormal(
+      {required List<BigInt> ints}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_list_BigInt(ints);
+        return wire.wire__crate__api__big_number__big_ints_twin_normal(
+            port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_list_BigInt,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiBigNumberBigIntsTwinNormalConstMeta,
+      argValues: [ints],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiBigNumberBigIntsTwinNormalConstMeta =>
+      const TaskConstMeta(
+        debugName: "big_ints_twin_normal",
+        argNames: ["ints"],
+      );
+
+  @override
+  Future<BigNumbersTwinNormal> crateApiBigNumberBigNumbersTwinNormal(
+      {required BigNumbersTwinNormal value}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_box_autoadd_big_numbers_twin_normal(value);
+        return wire.wire__crate__api__big_number__big_numbers_twin_normal(
+            port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_big_numbers_twin_normal,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiBigNumberBigNumbersTwinNormalConstMeta,
+      argValues: [value],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiBigNumberBigNumbersTwinNormalConstMeta =>
+      const TaskConstMeta(
+        debugName: "big_numbers_twin_normal",
+        argNames: ["value"],
+      );
+
+  @override
+  Future<Decimal> crateApiBigNumberDecimalTwinNormal({required Decimal d}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_Decimal(d);
+        return wire.wire__crate__api__big_number__decimal_twin_normal(
+            port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_Decimal,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiBigNumberDecimalTwinNormalConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiBigNumberDecimalTwinNormalConstMeta =>
+      const TaskConstMeta(
+        debugName: "decimal_twin_normal",
+        argNames: ["d"],
+      );
+
+  @override
+  Future<List<Decimal>> crateApiBigNumberDecimalsTwinNormal(
+      {required List<Decimal> decimals}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_list_Decimal(decimals);
+        return wire.wire__crate__api__big_number__decimals_twin_normal(
+            port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_list_Decimal,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiBigNumberDecimalsTwinNormalConstMeta,
+      argValues: [decimals],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiBigNumberDecimalsTwinNormalConstMeta =>
+      const TaskConstMeta(
+        debugName: "decimals_twin_normal",
+        argNames: ["decimals"],
+      );
+
+  @override
+  Future<BigInt> crateApiBigNumberMultiplyBigIntsTwinNormal(
+      {required BigInt a, required BigInt b}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_BigInt(a);
+        var arg1 = cst_encode_BigInt(b);
+        return wire.wire__crate__api__big_number__multiply_big_ints_twin_normal(
+            port_, arg0, arg1);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_BigInt,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiBigNumberMultiplyBigIntsTwinNormalConstMeta,
+      argValues: [a, b],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiBigNumberMultiplyBigIntsTwinNormalConstMeta =>
+      const TaskConstMeta(
+        debugName: "multiply_big_ints_twin_normal",
+        argNames: ["a", "b"],
+      );
+
+  @override
+  Future<BigInt?> crateApiBigNumberOptionalBigIntTwinNormal({BigInt? i}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_opt_BigInt(i);
+        return wire
+            .wire__crate__api__big_number__optional_big_int_twin_normal(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_opt_BigInt,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiBigNumberOptionalBigIntTwinNormalConstMeta,
+      argValues: [i],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiBigNumberOptionalBigIntTwinNormalConstMeta =>
+      const TaskConstMeta(
+        debugName: "optional_big_int_twin_normal",
+        argNames: ["i"],
+      );
+
+  @override
+  Future<Decimal?> crateApiBigNumberOptionalDecimalTwinNormal({Decimal? d}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_opt_Decimal(d);
+        return wire
+            .wire__crate__api__big_number__optional_decimal_twin_normal(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_opt_Decimal,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiBigNumberOptionalDecimalTwinNormalConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiBigNumberOptionalDecimalTwinNormalConstMeta =>
+      const TaskConstMeta(
+        debugName: "optional_decimal_twin_normal",
+        argNames: ["d"],
+      );
+
   @override
   Future<int> crateApiCastedPrimitiveCastedPrimitiveI64TwinNormal(
       {required int arg}) {
@@ -12888,6 +14341,60 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             argNames: ["arg"],
           );
 
+  @override
+  Future<DateTimeWithOffset> crateApiChronoTypeDatetimeFixedOffsetTwinNormal(
+      {required DateTimeWithOffset d}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_Chrono_FixedOffset(d);
+        return wire
+            .wire__crate__api__chrono_type__datetime_fixed_offset_twin_normal(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_Chrono_FixedOffset,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiChronoTypeDatetimeFixedOffsetTwinNormalConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiChronoTypeDatetimeFixedOffsetTwinNormalConstMeta =>
+      const TaskConstMeta(
+        debugName: "datetime_fixed_offset_twin_normal",
+        argNames: ["d"],
+      );
+
+  @override
+  Future<List<DateTimeWithOffset>>
+      crateApiChronoTypeDatetimeFixedOffsetsTwinNormal(
+          {required List<DateTimeWithOffset> dates}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_list_Chrono_FixedOffset(dates);
+        return wire
+            .wire__crate__api__chrono_type__datetime_fixed_offsets_twin_normal(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_list_Chrono_FixedOffset,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiChronoTypeDatetimeFixedOffsetsTwinNormalConstMeta,
+      argValues: [dates],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiChronoTypeDatetimeFixedOffsetsTwinNormalConstMeta =>
+          const TaskConstMeta(
+            debugName: "datetime_fixed_offsets_twin_normal",
+            argNames: ["dates"],
+          );
+
   @override
   Future<DateTime> crateApiChronoTypeDatetimeLocalTwinNormal(
       {required DateTime d}) {
@@ -13041,6 +14548,81 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["mine"],
       );
 
+  @override
+  Future<DateTime> crateApiChronoTypeNaiveDateTwinNormal(
+      {required DateTime d}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_Chrono_NaiveDate(d);
+        return wire.wire__crate__api__chrono_type__naive_date_twin_normal(
+            port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_Chrono_NaiveDate,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiChronoTypeNaiveDateTwinNormalConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiChronoTypeNaiveDateTwinNormalConstMeta =>
+      const TaskConstMeta(
+        debugName: "naive_date_twin_normal",
+        argNames: ["d"],
+      );
+
+  @override
+  Future<List<DateTime>> crateApiChronoTypeNaiveDatesTwinNormal(
+      {required List<DateTime> dates}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_list_Chrono_NaiveDate(dates);
+        return wire.wire__crate__api__chrono_type__naive_dates_twin_normal(
+            port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_list_Chrono_NaiveDate,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiChronoTypeNaiveDatesTwinNormalConstMeta,
+      argValues: [dates],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiChronoTypeNaiveDatesTwinNormalConstMeta =>
+      const TaskConstMeta(
+        debugName: "naive_dates_twin_normal",
+        argNames: ["dates"],
+      );
+
+  @override
+  Future<Duration> crateApiChronoTypeNaiveTimeTwinNormal(
+      {required Duration d}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_Chrono_NaiveTime(d);
+        return wire.wire__crate__api__chrono_type__naive_time_twin_normal(
+            port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_Chrono_NaiveTime,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiChronoTypeNaiveTimeTwinNormalConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiChronoTypeNaiveTimeTwinNormalConstMeta =>
+      const TaskConstMeta(
+        debugName: "naive_time_twin_normal",
+        argNames: ["d"],
+      );
+
   @override
   Future<DateTime> crateApiChronoTypeNaivedatetimeTwinNormal(
       {required DateTime d}) {
@@ -13066,6 +14648,35 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["d"],
       );
 
+  @override
+  Future<DateTimeWithOffset?>
+      crateApiChronoTypeOptionalDatetimeFixedOffsetTwinNormal(
+          {DateTimeWithOffset? d}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_opt_Chrono_FixedOffset(d);
+        return wire
+            .wire__crate__api__chrono_type__optional_datetime_fixed_offset_twin_normal(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_opt_Chrono_FixedOffset,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiChronoTypeOptionalDatetimeFixedOffsetTwinNormalConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiChronoTypeOptionalDatetimeFixedOffsetTwinNormalConstMeta =>
+          const TaskConstMeta(
+            debugName: "optional_datetime_fixed_offset_twin_normal",
+            argNames: ["d"],
+          );
+
   @override
   Future<DateTime?> crateApiChronoTypeOptionalEmptyDatetimeUtcTwinNormal(
       {DateTime? d}) {
@@ -13093,6 +14704,31 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             argNames: ["d"],
           );
 
+  @override
+  Future<ScheduleTwinNormal> crateApiChronoTypeScheduleTwinNormal(
+      {required ScheduleTwinNormal schedule}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_box_autoadd_schedule_twin_normal(schedule);
+        return wire.wire__crate__api__chrono_type__schedule_twin_normal(
+            port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_schedule_twin_normal,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiChronoTypeScheduleTwinNormalConstMeta,
+      argValues: [schedule],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiChronoTypeScheduleTwinNormalConstMeta =>
+      const TaskConstMeta(
+        debugName: "schedule_twin_normal",
+        argNames: ["schedule"],
+      );
+
   @override
   Future<TestChronoTwinNormal> crateApiChronoTypeTestChronoTwinNormal() {
     return handler.executeNormal(NormalTask(
@@ -13140,6 +14776,178 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: [],
       );
 
+  @override
+  Future<String> crateApiChronoTzTypeDatetimeTzNameTwinNormal(
+      {required DateTimeWithOffset d}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_Chrono_Tz(d);
+        return wire
+            .wire__crate__api__chrono_tz_type__datetime_tz_name_twin_normal(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_String,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiChronoTzTypeDatetimeTzNameTwinNormalConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiChronoTzTypeDatetimeTzNameTwinNormalConstMeta =>
+      const TaskConstMeta(
+        debugName: "datetime_tz_name_twin_normal",
+        argNames: ["d"],
+      );
+
+  @override
+  Future<DateTimeWithOffset>
+      crateApiChronoTzTypeDatetimeTzToFixedOffsetTwinNormal(
+          {required DateTimeWithOffset d}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_Chrono_Tz(d);
+        return wire
+            .wire__crate__api__chrono_tz_type__datetime_tz_to_fixed_offset_twin_normal(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_Chrono_FixedOffset,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiChronoTzTypeDatetimeTzToFixedOffsetTwinNormalConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiChronoTzTypeDatetimeTzToFixedOffsetTwinNormalConstMeta =>
+          const TaskConstMeta(
+            debugName: "datetime_tz_to_fixed_offset_twin_normal",
+            argNames: ["d"],
+          );
+
+  @override
+  Future<DateTimeWithOffset> crateApiChronoTzTypeDatetimeTzTwinNormal(
+      {required DateTimeWithOffset d}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Chrono_Tz(d, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire.wire__crate__api__chrono_tz_type__datetime_tz_twin_normal(
+            port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_Chrono_Tz,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiChronoTzTypeDatetimeTzTwinNormalConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiChronoTzTypeDatetimeTzTwinNormalConstMeta =>
+      const TaskConstMeta(
+        debugName: "datetime_tz_twin_normal",
+        argNames: ["d"],
+      );
+
+  @override
+  Future<List<DateTimeWithOffset>> crateApiChronoTzTypeDatetimeTzsTwinNormal(
+      {required List<DateTimeWithOffset> dates}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_list_Chrono_Tz(dates, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__chrono_tz_type__datetime_tzs_twin_normal(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_Chrono_Tz,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiChronoTzTypeDatetimeTzsTwinNormalConstMeta,
+      argValues: [dates],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiChronoTzTypeDatetimeTzsTwinNormalConstMeta =>
+      const TaskConstMeta(
+        debugName: "datetime_tzs_twin_normal",
+        argNames: ["dates"],
+      );
+
+  @override
+  Future<DateTimeWithOffset> crateApiChronoTzTypeDatetimeWithTimeZoneTwinNormal(
+      {required DateTimeWithOffset d, required String timeZone}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Chrono_FixedOffset(d, serializer);
+        sse_encode_String(timeZone, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__chrono_tz_type__datetime_with_time_zone_twin_normal(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_Chrono_Tz,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiChronoTzTypeDatetimeWithTimeZoneTwinNormalConstMeta,
+      argValues: [d, timeZone],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiChronoTzTypeDatetimeWithTimeZoneTwinNormalConstMeta =>
+          const TaskConstMeta(
+            debugName: "datetime_with_time_zone_twin_normal",
+            argNames: ["d", "timeZone"],
+          );
+
+  @override
+  Future<DateTimeWithOffset?>
+      crateApiChronoTzTypeOptionalDatetimeTzTwinNormal(
+          {DateTimeWithOffset? d}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_opt_Chrono_Tz(d, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__chrono_tz_type__optional_datetime_tz_twin_normal(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_Chrono_Tz,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiChronoTzTypeOptionalDatetimeTzTwinNormalConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiChronoTzTypeOptionalDatetimeTzTwinNormalConstMeta =>
+          const TaskConstMeta(
+            debugName: "optional_datetime_tz_twin_normal",
+            argNames: ["d"],
+          );
+
   @override
   Future<void> crateApiCommentFunctionWithCommentsSlashStarStarTwinNormal() {
     return handler.executeNormal(NormalTask(
@@ -14049,6 +15857,61 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             argNames: ["callback"],
           );
 
+  @override
+  Future<int> crateApiDartFnRustConsumeDartStreamSumTwinNormal(
+      {required Stream<int> stream}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_DartStream_i_32(stream);
+        return wire
+            .wire__crate__api__dart_fn__rust_consume_dart_stream_sum_twin_normal(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_i_32,
+        decodeErrorData: dco_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiDartFnRustConsumeDartStreamSumTwinNormalConstMeta,
+      argValues: [stream],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiDartFnRustConsumeDartStreamSumTwinNormalConstMeta =>
+          const TaskConstMeta(
+            debugName: "rust_consume_dart_stream_sum_twin_normal",
+            argNames: ["stream"],
+          );
+
+  @override
+  Future<List<String>> crateApiDartFnRustConsumeDartStreamTakeTwinNormal(
+      {required Stream<String> stream, required BigInt count}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_DartStream_String(stream);
+        var arg1 = cst_encode_usize(count);
+        return wire
+            .wire__crate__api__dart_fn__rust_consume_dart_stream_take_twin_normal(
+                port_, arg0, arg1);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_list_String,
+        decodeErrorData: dco_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiDartFnRustConsumeDartStreamTakeTwinNormalConstMeta,
+      argValues: [stream, count],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiDartFnRustConsumeDartStreamTakeTwinNormalConstMeta =>
+          const TaskConstMeta(
+            debugName: "rust_consume_dart_stream_take_twin_normal",
+            argNames: ["stream", "count"],
+          );
+
   @override
   Future<String> crateApiDartOpaqueAsyncAcceptDartOpaqueTwinNormal(
       {required Object opaque}) {
@@ -15002,6 +16865,243 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             argNames: ["that"],
           );
 
+  @override
+  Future<int>
+      crateApiDynTraitStructWithDartImplementableTraitTwinNormalAddTwinNormal(
+          {required StructWithDartImplementableTraitTwinNormal that,
+          required int a,
+          required int b}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+            that, serializer);
+        sse_encode_i_32(a, serializer);
+        sse_encode_i_32(b, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__dyn_trait__StructWithDartImplementableTraitTwinNormal_add_twin_normal(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_i_32,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiDynTraitStructWithDartImplementableTraitTwinNormalAddTwinNormalConstMeta,
+      argValues: [that, a, b],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiDynTraitStructWithDartImplementableTraitTwinNormalAddTwinNormalConstMeta =>
+          const TaskConstMeta(
+            debugName:
+                "StructWithDartImplementableTraitTwinNormal_add_twin_normal",
+            argNames: ["that", "a", "b"],
+          );
+
+  @override
+  int crateApiDynTraitStructWithDartImplementableTraitTwinNormalAutoAccessorGetBase(
+      {required StructWithDartImplementableTraitTwinNormal that}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        var arg0 =
+            cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+                that);
+        return wire
+            .wire__crate__api__dyn_trait__StructWithDartImplementableTraitTwinNormal_auto_accessor_get_base(
+                arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_i_32,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiDynTraitStructWithDartImplementableTraitTwinNormalAutoAccessorGetBaseConstMeta,
+      argValues: [that],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiDynTraitStructWithDartImplementableTraitTwinNormalAutoAccessorGetBaseConstMeta =>
+          const TaskConstMeta(
+            debugName:
+                "StructWithDartImplementableTraitTwinNormal_auto_accessor_get_base",
+            argNames: ["that"],
+          );
+
+  @override
+  void
+      crateApiDynTraitStructWithDartImplementableTraitTwinNormalAutoAccessorSetBase(
+          {required StructWithDartImplementableTraitTwinNormal that,
+          required int base}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        var arg0 =
+            cst_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+                that);
+        var arg1 = cst_encode_i_32(base);
+        return wire
+            .wire__crate__api__dyn_trait__StructWithDartImplementableTraitTwinNormal_auto_accessor_set_base(
+                arg0, arg1);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiDynTraitStructWithDartImplementableTraitTwinNormalAutoAccessorSetBaseConstMeta,
+      argValues: [that, base],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiDynTraitStructWithDartImplementableTraitTwinNormalAutoAccessorSetBaseConstMeta =>
+          const TaskConstMeta(
+            debugName:
+                "StructWithDartImplementableTraitTwinNormal_auto_accessor_set_base",
+            argNames: ["that", "base"],
+          );
+
+  @override
+  Future<String>
+      crateApiDynTraitStructWithDartImplementableTraitTwinNormalDescribeTwinNormal(
+          {required StructWithDartImplementableTraitTwinNormal that,
+          required int value}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+            that, serializer);
+        sse_encode_i_32(value, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__dyn_trait__StructWithDartImplementableTraitTwinNormal_describe_twin_normal(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiDynTraitStructWithDartImplementableTraitTwinNormalDescribeTwinNormalConstMeta,
+      argValues: [that, value],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiDynTraitStructWithDartImplementableTraitTwinNormalDescribeTwinNormalConstMeta =>
+          const TaskConstMeta(
+            debugName:
+                "StructWithDartImplementableTraitTwinNormal_describe_twin_normal",
+            argNames: ["that", "value"],
+          );
+
+  @override
+  StructWithDartImplementableTraitTwinNormal
+      crateApiDynTraitStructWithDartImplementableTraitTwinNormalNew(
+          {required int base}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_i_32(base, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__dyn_trait__StructWithDartImplementableTraitTwinNormal_new(
+                raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData:
+            sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiDynTraitStructWithDartImplementableTraitTwinNormalNewConstMeta,
+      argValues: [base],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiDynTraitStructWithDartImplementableTraitTwinNormalNewConstMeta =>
+          const TaskConstMeta(
+            debugName: "StructWithDartImplementableTraitTwinNormal_new",
+            argNames: ["base"],
+          );
+
+  @override
+  Future<String>
+      crateApiDynTraitFuncArgBoxDynDartImplementableAsyncTwinNormal(
+          {required DartImplementableTraitTwinNormal arg, required int value}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_DynTrait_DartImplementableTraitTwinNormal(
+            arg, serializer);
+        sse_encode_i_32(value, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__dyn_trait__func_arg_box_dyn_dart_implementable_async_twin_normal(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiDynTraitFuncArgBoxDynDartImplementableAsyncTwinNormalConstMeta,
+      argValues: [arg, value],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiDynTraitFuncArgBoxDynDartImplementableAsyncTwinNormalConstMeta =>
+          const TaskConstMeta(
+            debugName: "func_arg_box_dyn_dart_implementable_async_twin_normal",
+            argNames: ["arg", "value"],
+          );
+
+  @override
+  Future<int> crateApiDynTraitFuncArgBoxDynDartImplementableSyncTwinNormal(
+      {required DartImplementableTraitTwinNormal arg,
+      required int a,
+      required int b}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_DynTrait_DartImplementableTraitTwinNormal(
+            arg, serializer);
+        sse_encode_i_32(a, serializer);
+        sse_encode_i_32(b, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__dyn_trait__func_arg_box_dyn_dart_implementable_sync_twin_normal(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_i_32,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiDynTraitFuncArgBoxDynDartImplementableSyncTwinNormalConstMeta,
+      argValues: [arg, a, b],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiDynTraitFuncArgBoxDynDartImplementableSyncTwinNormalConstMeta =>
+          const TaskConstMeta(
+            debugName: "func_arg_box_dyn_dart_implementable_sync_twin_normal",
+            argNames: ["arg", "a", "b"],
+          );
+
   @override
   Future<int> crateApiDynTraitFuncArgDynTraitTwinNormal(
       {required SimpleTraitForDynTwinNormal arg}) {
@@ -16179,6 +18279,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             argNames: ["sink"],
           );
 
+  @override
+  Future<void> crateApiExceptionThrowAnyhowIoErrorTwinNormal() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        return wire
+            .wire__crate__api__exception__throw_anyhow_io_error_twin_normal(
+                port_);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_unit,
+        decodeErrorData: dco_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiExceptionThrowAnyhowIoErrorTwinNormalConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiExceptionThrowAnyhowIoErrorTwinNormalConstMeta =>
+      const TaskConstMeta(
+        debugName: "throw_anyhow_io_error_twin_normal",
+        argNames: [],
+      );
+
   @override
   Future<void> crateApiExceptionThrowAnyhowTwinNormal() {
     return handler.executeNormal(NormalTask(
@@ -16202,6 +18326,58 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: [],
       );
 
+  @override
+  Future<void> crateApiExceptionThrowAnyhowWithContextTwinNormal() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        return wire
+            .wire__crate__api__exception__throw_anyhow_with_context_twin_normal(
+                port_);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_unit,
+        decodeErrorData: dco_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiExceptionThrowAnyhowWithContextTwinNormalConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiExceptionThrowAnyhowWithContextTwinNormalConstMeta =>
+          const TaskConstMeta(
+            debugName: "throw_anyhow_with_context_twin_normal",
+            argNames: [],
+          );
+
+  @override
+  Future<int> crateApiExceptionTypedExceptionReturnErrorTwinNormal(
+      {required int variant}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_i_32(variant);
+        return wire
+            .wire__crate__api__exception__typed_exception_return_error_twin_normal(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_i_32,
+        decodeErrorData: dco_decode_typed_exception_error_twin_normal,
+      ),
+      constMeta: kCrateApiExceptionTypedExceptionReturnErrorTwinNormalConstMeta,
+      argValues: [variant],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiExceptionTypedExceptionReturnErrorTwinNormalConstMeta =>
+          const TaskConstMeta(
+            debugName: "typed_exception_return_error_twin_normal",
+            argNames: ["variant"],
+          );
+
   @override
   SimpleOpaqueExternalStructWithMethod
       crateApiExternalImplSimpleOpaqueExternalStructWithMethodNew(
@@ -16829,6 +19005,229 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["arg"],
       );
 
+  @override
+  Future<Map<String, dynamic>> crateApiJsonTypeCreateJsonMapTwinNormal() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        return wire
+            .wire__crate__api__json_type__create_json_map_twin_normal(port_);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_JsonMap,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiJsonTypeCreateJsonMapTwinNormalConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiJsonTypeCreateJsonMapTwinNormalConstMeta =>
+      const TaskConstMeta(
+        debugName: "create_json_map_twin_normal",
+        argNames: [],
+      );
+
+  @override
+  Future<Object?> crateApiJsonTypeCreateJsonValueTwinNormal() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        return wire
+            .wire__crate__api__json_type__create_json_value_twin_normal(port_);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_JsonValue,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiJsonTypeCreateJsonValueTwinNormalConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiJsonTypeCreateJsonValueTwinNormalConstMeta =>
+      const TaskConstMeta(
+        debugName: "create_json_value_twin_normal",
+        argNames: [],
+      );
+
+  @override
+  Future<JsonDocumentTwinNormal> crateApiJsonTypeJsonDocumentTwinNormal(
+      {required JsonDocumentTwinNormal document}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_box_autoadd_json_document_twin_normal(document);
+        return wire.wire__crate__api__json_type__json_document_twin_normal(
+            port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_json_document_twin_normal,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiJsonTypeJsonDocumentTwinNormalConstMeta,
+      argValues: [document],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiJsonTypeJsonDocumentTwinNormalConstMeta =>
+      const TaskConstMeta(
+        debugName: "json_document_twin_normal",
+        argNames: ["document"],
+      );
+
+  @override
+  Future<List<String>> crateApiJsonTypeJsonMapKeysTwinNormal(
+      {required Map<String, dynamic> map}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_JsonMap(map);
+        return wire.wire__crate__api__json_type__json_map_keys_twin_normal(
+            port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_list_String,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiJsonTypeJsonMapKeysTwinNormalConstMeta,
+      argValues: [map],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiJsonTypeJsonMapKeysTwinNormalConstMeta =>
+      const TaskConstMeta(
+        debugName: "json_map_keys_twin_normal",
+        argNames: ["map"],
+      );
+
+  @override
+  Future<Map<String, dynamic>> crateApiJsonTypeJsonMapTwinNormal(
+      {required Map<String, dynamic> map}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_JsonMap(map);
+        return wire.wire__crate__api__json_type__json_map_twin_normal(
+            port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_JsonMap,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiJsonTypeJsonMapTwinNormalConstMeta,
+      argValues: [map],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiJsonTypeJsonMapTwinNormalConstMeta =>
+      const TaskConstMeta(
+        debugName: "json_map_twin_normal",
+        argNames: ["map"],
+      );
+
+  @override
+  Future<String> crateApiJsonTypeJsonValueToStringTwinNormal(
+      {required Object? value}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_JsonValue(value);
+        return wire
+            .wire__crate__api__json_type__json_value_to_string_twin_normal(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_String,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiJsonTypeJsonValueToStringTwinNormalConstMeta,
+      argValues: [value],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiJsonTypeJsonValueToStringTwinNormalConstMeta =>
+      const TaskConstMeta(
+        debugName: "json_value_to_string_twin_normal",
+        argNames: ["value"],
+      );
+
+  @override
+  Future<Object?> crateApiJsonTypeJsonValueTwinNormal(
+      {required Object? value}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_JsonValue(value);
+        return wire.wire__crate__api__json_type__json_value_twin_normal(
+            port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_JsonValue,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiJsonTypeJsonValueTwinNormalConstMeta,
+      argValues: [value],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiJsonTypeJsonValueTwinNormalConstMeta =>
+      const TaskConstMeta(
+        debugName: "json_value_twin_normal",
+        argNames: ["value"],
+      );
+
+  @override
+  Future<List<Object?>> crateApiJsonTypeJsonValuesTwinNormal(
+      {required List<Object?> values}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_list_JsonValue(values);
+        return wire.wire__crate__api__json_type__json_values_twin_normal(
+            port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_list_JsonValue,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiJsonTypeJsonValuesTwinNormalConstMeta,
+      argValues: [values],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiJsonTypeJsonValuesTwinNormalConstMeta =>
+      const TaskConstMeta(
+        debugName: "json_values_twin_normal",
+        argNames: ["values"],
+      );
+
+  @override
+  Future<Object?> crateApiJsonTypeOptionalJsonValueTwinNormal({Object? value}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_opt_JsonValue(value);
+        return wire
+            .wire__crate__api__json_type__optional_json_value_twin_normal(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_opt_JsonValue,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiJsonTypeOptionalJsonValueTwinNormalConstMeta,
+      argValues: [value],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiJsonTypeOptionalJsonValueTwinNormalConstMeta =>
+      const TaskConstMeta(
+        debugName: "optional_json_value_twin_normal",
+        argNames: ["value"],
+      );
+
   @override
   Future<String>
       crateApiLifetimeableLifetimeableAutoOwnedRustOpaqueFlutterRustBridgeforGeneratedRustAutoOpaqueInnerLtNestedTypeWithLifetimeTwinNormalstaticGreetBorrowMutSelfTwinNormal(
@@ -17376,6 +19775,112 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             argNames: ["arg"],
           );
 
+  @override
+  Future<Map<int, int>> crateApiMapAndSetFuncBtreeMapI32I32TwinNormal(
+      {required Map<int, int> arg}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_BTreeMap_i_32_i_32(arg);
+        return wire
+            .wire__crate__api__map_and_set__func_btree_map_i32_i32_twin_normal(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_BTreeMap_i_32_i_32,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiMapAndSetFuncBtreeMapI32I32TwinNormalConstMeta,
+      argValues: [arg],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMapAndSetFuncBtreeMapI32I32TwinNormalConstMeta =>
+      const TaskConstMeta(
+        debugName: "func_btree_map_i32_i32_twin_normal",
+        argNames: ["arg"],
+      );
+
+  @override
+  Future<Map<String, MySize>>
+      crateApiMapAndSetFuncBtreeMapStringStructTwinNormal(
+          {required Map<String, MySize> arg}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_BTreeMap_String_my_size(arg);
+        return wire
+            .wire__crate__api__map_and_set__func_btree_map_string_struct_twin_normal(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_BTreeMap_String_my_size,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiMapAndSetFuncBtreeMapStringStructTwinNormalConstMeta,
+      argValues: [arg],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiMapAndSetFuncBtreeMapStringStructTwinNormalConstMeta =>
+          const TaskConstMeta(
+            debugName: "func_btree_map_string_struct_twin_normal",
+            argNames: ["arg"],
+          );
+
+  @override
+  Future<Set<int>> crateApiMapAndSetFuncBtreeSetI32TwinNormal(
+      {required Set<int> arg}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_BTreeSet_i_32(arg);
+        return wire
+            .wire__crate__api__map_and_set__func_btree_set_i32_twin_normal(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_BTreeSet_i_32,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiMapAndSetFuncBtreeSetI32TwinNormalConstMeta,
+      argValues: [arg],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMapAndSetFuncBtreeSetI32TwinNormalConstMeta =>
+      const TaskConstMeta(
+        debugName: "func_btree_set_i32_twin_normal",
+        argNames: ["arg"],
+      );
+
+  @override
+  Future<Set<String>> crateApiMapAndSetFuncBtreeSetStringTwinNormal(
+      {required Set<String> arg}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_BTreeSet_String(arg);
+        return wire
+            .wire__crate__api__map_and_set__func_btree_set_string_twin_normal(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_BTreeSet_String,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiMapAndSetFuncBtreeSetStringTwinNormalConstMeta,
+      argValues: [arg],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMapAndSetFuncBtreeSetStringTwinNormalConstMeta =>
+      const TaskConstMeta(
+        debugName: "func_btree_set_string_twin_normal",
+        argNames: ["arg"],
+      );
+
   @override
   Future<Map<int, int>> crateApiMapAndSetFuncHashMapI32I32TwinNormal(
       {required Map<int, int> arg}) {
@@ -17596,6 +20101,58 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["arg"],
       );
 
+  @override
+  Future<Int32List> crateApiMapAndSetFuncVecDequeI32TwinNormal(
+      {required Int32List arg}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_VecDeque_i_32(arg);
+        return wire
+            .wire__crate__api__map_and_set__func_vec_deque_i32_twin_normal(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_VecDeque_i_32,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiMapAndSetFuncVecDequeI32TwinNormalConstMeta,
+      argValues: [arg],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMapAndSetFuncVecDequeI32TwinNormalConstMeta =>
+      const TaskConstMeta(
+        debugName: "func_vec_deque_i32_twin_normal",
+        argNames: ["arg"],
+      );
+
+  @override
+  Future<List<String>> crateApiMapAndSetFuncVecDequeStringTwinNormal(
+      {required List<String> arg}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_VecDeque_String(arg);
+        return wire
+            .wire__crate__api__map_and_set__func_vec_deque_string_twin_normal(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_VecDeque_String,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiMapAndSetFuncVecDequeStringTwinNormalConstMeta,
+      argValues: [arg],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMapAndSetFuncVecDequeStringTwinNormalConstMeta =>
+      const TaskConstMeta(
+        debugName: "func_vec_deque_string_twin_normal",
+        argNames: ["arg"],
+      );
+
   @override
   Future<int> crateApiMethodStaticGetterOnlyTwinNormalStaticGetterTwinNormal() {
     return handler.executeNormal(NormalTask(
@@ -22711,6 +25268,35 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             argNames: ["id"],
           );
 
+  @override
+  Future<void>
+      crateApiPseudoManualAsyncMiscTwinSseFuncAsyncNeverCompleteTwinSse() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__async_misc_twin_sse__func_async_never_complete_twin_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualAsyncMiscTwinSseFuncAsyncNeverCompleteTwinSseConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualAsyncMiscTwinSseFuncAsyncNeverCompleteTwinSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "func_async_never_complete_twin_sse",
+            argNames: [],
+          );
+
   @override
   Future<int> crateApiPseudoManualAsyncMiscTwinSseFuncAsyncSimpleAddTwinSse(
       {required int a, required int b}) {
@@ -22770,6 +25356,35 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             argNames: [],
           );
 
+  @override
+  Future<void>
+      crateApiPseudoManualAsyncMiscTwinSseFuncAsyncWaitForCancellationTwinSse() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__async_misc_twin_sse__func_async_wait_for_cancellation_twin_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualAsyncMiscTwinSseFuncAsyncWaitForCancellationTwinSseConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualAsyncMiscTwinSseFuncAsyncWaitForCancellationTwinSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "func_async_wait_for_cancellation_twin_sse",
+            argNames: [],
+          );
+
   @override
   Future<void>
       crateApiPseudoManualAttributeTwinRustAsyncHandleCustomizedStructTwinRustAsync(
@@ -39547,6 +42162,1408 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             argNames: [],
           );
 
+  @override
+  Future<Decimal>
+      crateApiPseudoManualBigNumberTwinRustAsyncAddDecimalsTwinRustAsync(
+          {required Decimal a, required Decimal b}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_Decimal(a);
+        var arg1 = cst_encode_Decimal(b);
+        return wire
+            .wire__crate__api__pseudo_manual__big_number_twin_rust_async__add_decimals_twin_rust_async(
+                port_, arg0, arg1);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_Decimal,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualBigNumberTwinRustAsyncAddDecimalsTwinRustAsyncConstMeta,
+      argValues: [a, b],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualBigNumberTwinRustAsyncAddDecimalsTwinRustAsyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "add_decimals_twin_rust_async",
+            argNames: ["a", "b"],
+          );
+
+  @override
+  Future<BigInt> crateApiPseudoManualBigNumberTwinRustAsyncBigIntTwinRustAsync(
+      {required BigInt i}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_BigInt(i);
+        return wire
+            .wire__crate__api__pseudo_manual__big_number_twin_rust_async__big_int_twin_rust_async(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_BigInt,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualBigNumberTwinRustAsyncBigIntTwinRustAsyncConstMeta,
+      argValues: [i],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualBigNumberTwinRustAsyncBigIntTwinRustAsyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "big_int_twin_rust_async",
+            argNames: ["i"],
+          );
+
+  @override
+  Future<List<BigInt>>
+      crateApiPseudoManualBigNumberTwinRustAsyncBigIntsTwinRustAsync(
+          {required List<BigInt> ints}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_list_BigInt(ints);
+        return wire
+            .wire__crate__api__pseudo_manual__big_number_twin_rust_async__big_ints_twin_rust_async(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_list_BigInt,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualBigNumberTwinRustAsyncBigIntsTwinRustAsyncConstMeta,
+      argValues: [ints],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualBigNumberTwinRustAsyncBigIntsTwinRustAsyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "big_ints_twin_rust_async",
+            argNames: ["ints"],
+          );
+
+  @override
+  Future<BigNumbersTwinRustAsync>
+      crateApiPseudoManualBigNumberTwinRustAsyncBigNumbersTwinRustAsync(
+          {required BigNumbersTwinRustAsync value}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_box_autoadd_big_numbers_twin_rust_async(value);
+        return wire
+            .wire__crate__api__pseudo_manual__big_number_twin_rust_async__big_numbers_twin_rust_async(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_big_numbers_twin_rust_async,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualBigNumberTwinRustAsyncBigNumbersTwinRustAsyncConstMeta,
+      argValues: [value],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualBigNumberTwinRustAsyncBigNumbersTwinRustAsyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "big_numbers_twin_rust_async",
+            argNames: ["value"],
+          );
+
+  @override
+  Future<Decimal>
+      crateApiPseudoManualBigNumberTwinRustAsyncDecimalTwinRustAsync(
+          {required Decimal d}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_Decimal(d);
+        return wire
+            .wire__crate__api__pseudo_manual__big_number_twin_rust_async__decimal_twin_rust_async(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_Decimal,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualBigNumberTwinRustAsyncDecimalTwinRustAsyncConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualBigNumberTwinRustAsyncDecimalTwinRustAsyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "decimal_twin_rust_async",
+            argNames: ["d"],
+          );
+
+  @override
+  Future<List<Decimal>>
+      crateApiPseudoManualBigNumberTwinRustAsyncDecimalsTwinRustAsync(
+          {required List<Decimal> decimals}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_list_Decimal(decimals);
+        return wire
+            .wire__crate__api__pseudo_manual__big_number_twin_rust_async__decimals_twin_rust_async(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_list_Decimal,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualBigNumberTwinRustAsyncDecimalsTwinRustAsyncConstMeta,
+      argValues: [decimals],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualBigNumberTwinRustAsyncDecimalsTwinRustAsyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "decimals_twin_rust_async",
+            argNames: ["decimals"],
+          );
+
+  @override
+  Future<BigInt>
+      crateApiPseudoManualBigNumberTwinRustAsyncMultiplyBigIntsTwinRustAsync(
+          {required BigInt a, required BigInt b}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_BigInt(a);
+        var arg1 = cst_encode_BigInt(b);
+        return wire
+            .wire__crate__api__pseudo_manual__big_number_twin_rust_async__multiply_big_ints_twin_rust_async(
+                port_, arg0, arg1);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_BigInt,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualBigNumberTwinRustAsyncMultiplyBigIntsTwinRustAsyncConstMeta,
+      argValues: [a, b],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualBigNumberTwinRustAsyncMultiplyBigIntsTwinRustAsyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "multiply_big_ints_twin_rust_async",
+            argNames: ["a", "b"],
+          );
+
+  @override
+  Future<BigInt?>
+      crateApiPseudoManualBigNumberTwinRustAsyncOptionalBigIntTwinRustAsync(
+          {BigInt? i}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_opt_BigInt(i);
+        return wire
+            .wire__crate__api__pseudo_manual__big_number_twin_rust_async__optional_big_int_twin_rust_async(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_opt_BigInt,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualBigNumberTwinRustAsyncOptionalBigIntTwinRustAsyncConstMeta,
+      argValues: [i],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualBigNumberTwinRustAsyncOptionalBigIntTwinRustAsyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "optional_big_int_twin_rust_async",
+            argNames: ["i"],
+          );
+
+  @override
+  Future<Decimal?>
+      crateApiPseudoManualBigNumberTwinRustAsyncOptionalDecimalTwinRustAsync(
+          {Decimal? d}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_opt_Decimal(d);
+        return wire
+            .wire__crate__api__pseudo_manual__big_number_twin_rust_async__optional_decimal_twin_rust_async(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_opt_Decimal,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualBigNumberTwinRustAsyncOptionalDecimalTwinRustAsyncConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualBigNumberTwinRustAsyncOptionalDecimalTwinRustAsyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "optional_decimal_twin_rust_async",
+            argNames: ["d"],
+          );
+
+  @override
+  Future<Decimal>
+      crateApiPseudoManualBigNumberTwinRustAsyncSseAddDecimalsTwinRustAsyncSse(
+          {required Decimal a, required Decimal b}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Decimal(a, serializer);
+        sse_encode_Decimal(b, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__big_number_twin_rust_async_sse__add_decimals_twin_rust_async_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_Decimal,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualBigNumberTwinRustAsyncSseAddDecimalsTwinRustAsyncSseConstMeta,
+      argValues: [a, b],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualBigNumberTwinRustAsyncSseAddDecimalsTwinRustAsyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "add_decimals_twin_rust_async_sse",
+            argNames: ["a", "b"],
+          );
+
+  @override
+  Future<BigInt>
+      crateApiPseudoManualBigNumberTwinRustAsyncSseBigIntTwinRustAsyncSse(
+          {required BigInt i}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_BigInt(i, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__big_number_twin_rust_async_sse__big_int_twin_rust_async_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_BigInt,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualBigNumberTwinRustAsyncSseBigIntTwinRustAsyncSseConstMeta,
+      argValues: [i],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualBigNumberTwinRustAsyncSseBigIntTwinRustAsyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "big_int_twin_rust_async_sse",
+            argNames: ["i"],
+          );
+
+  @override
+  Future<List<BigInt>>
+      crateApiPseudoManualBigNumberTwinRustAsyncSseBigIntsTwinRustAsyncSse(
+          {required List<BigInt> ints}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_list_BigInt(ints, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__big_number_twin_rust_async_sse__big_ints_twin_rust_async_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_BigInt,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualBigNumberTwinRustAsyncSseBigIntsTwinRustAsyncSseConstMeta,
+      argValues: [ints],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualBigNumberTwinRustAsyncSseBigIntsTwinRustAsyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "big_ints_twin_rust_async_sse",
+            argNames: ["ints"],
+          );
+
+  @override
+  Future<BigNumbersTwinRustAsyncSse>
+      crateApiPseudoManualBigNumberTwinRustAsyncSseBigNumbersTwinRustAsyncSse(
+          {required BigNumbersTwinRustAsyncSse value}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_big_numbers_twin_rust_async_sse(
+            value, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__big_number_twin_rust_async_sse__big_numbers_twin_rust_async_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_big_numbers_twin_rust_async_sse,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualBigNumberTwinRustAsyncSseBigNumbersTwinRustAsyncSseConstMeta,
+      argValues: [value],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualBigNumberTwinRustAsyncSseBigNumbersTwinRustAsyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "big_numbers_twin_rust_async_sse",
+            argNames: ["value"],
+          );
+
+  @override
+  Future<Decimal>
+      crateApiPseudoManualBigNumberTwinRustAsyncSseDecimalTwinRustAsyncSse(
+          {required Decimal d}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Decimal(d, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__big_number_twin_rust_async_sse__decimal_twin_rust_async_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_Decimal,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualBigNumberTwinRustAsyncSseDecimalTwinRustAsyncSseConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualBigNumberTwinRustAsyncSseDecimalTwinRustAsyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "decimal_twin_rust_async_sse",
+            argNames: ["d"],
+          );
+
+  @override
+  Future<List<Decimal>>
+      crateApiPseudoManualBigNumberTwinRustAsyncSseDecimalsTwinRustAsyncSse(
+          {required List<Decimal> decimals}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_list_Decimal(decimals, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__big_number_twin_rust_async_sse__decimals_twin_rust_async_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_Decimal,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualBigNumberTwinRustAsyncSseDecimalsTwinRustAsyncSseConstMeta,
+      argValues: [decimals],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualBigNumberTwinRustAsyncSseDecimalsTwinRustAsyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "decimals_twin_rust_async_sse",
+            argNames: ["decimals"],
+          );
+
+  @override
+  Future<BigInt>
+      crateApiPseudoManualBigNumberTwinRustAsyncSseMultiplyBigIntsTwinRustAsyncSse(
+          {required BigInt a, required BigInt b}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_BigInt(a, serializer);
+        sse_encode_BigInt(b, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__big_number_twin_rust_async_sse__multiply_big_ints_twin_rust_async_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_BigInt,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualBigNumberTwinRustAsyncSseMultiplyBigIntsTwinRustAsyncSseConstMeta,
+      argValues: [a, b],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualBigNumberTwinRustAsyncSseMultiplyBigIntsTwinRustAsyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "multiply_big_ints_twin_rust_async_sse",
+            argNames: ["a", "b"],
+          );
+
+  @override
+  Future<BigInt?>
+      crateApiPseudoManualBigNumberTwinRustAsyncSseOptionalBigIntTwinRustAsyncSse(
+          {BigInt? i}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_opt_BigInt(i, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__big_number_twin_rust_async_sse__optional_big_int_twin_rust_async_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_BigInt,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualBigNumberTwinRustAsyncSseOptionalBigIntTwinRustAsyncSseConstMeta,
+      argValues: [i],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualBigNumberTwinRustAsyncSseOptionalBigIntTwinRustAsyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "optional_big_int_twin_rust_async_sse",
+            argNames: ["i"],
+          );
+
+  @override
+  Future<Decimal?>
+      crateApiPseudoManualBigNumberTwinRustAsyncSseOptionalDecimalTwinRustAsyncSse(
+          {Decimal? d}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_opt_Decimal(d, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__big_number_twin_rust_async_sse__optional_decimal_twin_rust_async_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_Decimal,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualBigNumberTwinRustAsyncSseOptionalDecimalTwinRustAsyncSseConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualBigNumberTwinRustAsyncSseOptionalDecimalTwinRustAsyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "optional_decimal_twin_rust_async_sse",
+            argNames: ["d"],
+          );
+
+  @override
+  Future<Decimal> crateApiPseudoManualBigNumberTwinSseAddDecimalsTwinSse(
+      {required Decimal a, required Decimal b}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Decimal(a, serializer);
+        sse_encode_Decimal(b, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__big_number_twin_sse__add_decimals_twin_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_Decimal,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualBigNumberTwinSseAddDecimalsTwinSseConstMeta,
+      argValues: [a, b],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualBigNumberTwinSseAddDecimalsTwinSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "add_decimals_twin_sse",
+            argNames: ["a", "b"],
+          );
+
+  @override
+  Future<BigInt> crateApiPseudoManualBigNumberTwinSseBigIntTwinSse(
+      {required BigInt i}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_BigInt(i, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__big_number_twin_sse__big_int_twin_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_BigInt,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiPseudoManualBigNumberTwinSseBigIntTwinSseConstMeta,
+      argValues: [i],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualBigNumberTwinSseBigIntTwinSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "big_int_twin_sse",
+            argNames: ["i"],
+          );
+
+  @override
+  Future<List<BigInt>> crateApiPseudoManualBigNumberTwinSseBigIntsTwinSse(
+      {required List<BigInt> ints}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_list_BigInt(ints, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__big_number_twin_sse__big_ints_twin_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_BigInt,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualBigNumberTwinSseBigIntsTwinSseConstMeta,
+      argValues: [ints],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualBigNumberTwinSseBigIntsTwinSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "big_ints_twin_sse",
+            argNames: ["ints"],
+          );
+
+  @override
+  Future<BigNumbersTwinSse>
+      crateApiPseudoManualBigNumberTwinSseBigNumbersTwinSse(
+          {required BigNumbersTwinSse value}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_big_numbers_twin_sse(value, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__big_number_twin_sse__big_numbers_twin_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_big_numbers_twin_sse,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualBigNumberTwinSseBigNumbersTwinSseConstMeta,
+      argValues: [value],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualBigNumberTwinSseBigNumbersTwinSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "big_numbers_twin_sse",
+            argNames: ["value"],
+          );
+
+  @override
+  Future<Decimal> crateApiPseudoManualBigNumberTwinSseDecimalTwinSse(
+      {required Decimal d}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Decimal(d, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__big_number_twin_sse__decimal_twin_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_Decimal,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiPseudoManualBigNumberTwinSseDecimalTwinSseConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualBigNumberTwinSseDecimalTwinSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "decimal_twin_sse",
+            argNames: ["d"],
+          );
+
+  @override
+  Future<List<Decimal>> crateApiPseudoManualBigNumberTwinSseDecimalsTwinSse(
+      {required List<Decimal> decimals}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_list_Decimal(decimals, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__big_number_twin_sse__decimals_twin_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_Decimal,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualBigNumberTwinSseDecimalsTwinSseConstMeta,
+      argValues: [decimals],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualBigNumberTwinSseDecimalsTwinSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "decimals_twin_sse",
+            argNames: ["decimals"],
+          );
+
+  @override
+  Future<BigInt> crateApiPseudoManualBigNumberTwinSseMultiplyBigIntsTwinSse(
+      {required BigInt a, required BigInt b}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_BigInt(a, serializer);
+        sse_encode_BigInt(b, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__big_number_twin_sse__multiply_big_ints_twin_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_BigInt,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualBigNumberTwinSseMultiplyBigIntsTwinSseConstMeta,
+      argValues: [a, b],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualBigNumberTwinSseMultiplyBigIntsTwinSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "multiply_big_ints_twin_sse",
+            argNames: ["a", "b"],
+          );
+
+  @override
+  Future<BigInt?>
+      crateApiPseudoManualBigNumberTwinSseOptionalBigIntTwinSse(
+          {BigInt? i}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_opt_BigInt(i, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__big_number_twin_sse__optional_big_int_twin_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_BigInt,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualBigNumberTwinSseOptionalBigIntTwinSseConstMeta,
+      argValues: [i],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualBigNumberTwinSseOptionalBigIntTwinSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "optional_big_int_twin_sse",
+            argNames: ["i"],
+          );
+
+  @override
+  Future<Decimal?>
+      crateApiPseudoManualBigNumberTwinSseOptionalDecimalTwinSse(
+          {Decimal? d}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_opt_Decimal(d, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__big_number_twin_sse__optional_decimal_twin_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_Decimal,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualBigNumberTwinSseOptionalDecimalTwinSseConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualBigNumberTwinSseOptionalDecimalTwinSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "optional_decimal_twin_sse",
+            argNames: ["d"],
+          );
+
+  @override
+  Decimal crateApiPseudoManualBigNumberTwinSyncAddDecimalsTwinSync(
+      {required Decimal a, required Decimal b}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        var arg0 = cst_encode_Decimal(a);
+        var arg1 = cst_encode_Decimal(b);
+        return wire
+            .wire__crate__api__pseudo_manual__big_number_twin_sync__add_decimals_twin_sync(
+                arg0, arg1);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_Decimal,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualBigNumberTwinSyncAddDecimalsTwinSyncConstMeta,
+      argValues: [a, b],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualBigNumberTwinSyncAddDecimalsTwinSyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "add_decimals_twin_sync",
+            argNames: ["a", "b"],
+          );
+
+  @override
+  BigInt crateApiPseudoManualBigNumberTwinSyncBigIntTwinSync(
+      {required BigInt i}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        var arg0 = cst_encode_BigInt(i);
+        return wire
+            .wire__crate__api__pseudo_manual__big_number_twin_sync__big_int_twin_sync(
+                arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_BigInt,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiPseudoManualBigNumberTwinSyncBigIntTwinSyncConstMeta,
+      argValues: [i],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualBigNumberTwinSyncBigIntTwinSyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "big_int_twin_sync",
+            argNames: ["i"],
+          );
+
+  @override
+  List<BigInt> crateApiPseudoManualBigNumberTwinSyncBigIntsTwinSync(
+      {required List<BigInt> ints}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        var arg0 = cst_encode_list_BigInt(ints);
+        return wire
+            .wire__crate__api__pseudo_manual__big_number_twin_sync__big_ints_twin_sync(
+                arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_list_BigInt,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualBigNumberTwinSyncBigIntsTwinSyncConstMeta,
+      argValues: [ints],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualBigNumberTwinSyncBigIntsTwinSyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "big_ints_twin_sync",
+            argNames: ["ints"],
+          );
+
+  @override
+  BigNumbersTwinSync crateApiPseudoManualBigNumberTwinSyncBigNumbersTwinSync(
+      {required BigNumbersTwinSync value}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        var arg0 = cst_encode_box_autoadd_big_numbers_twin_sync(value);
+        return wire
+            .wire__crate__api__pseudo_manual__big_number_twin_sync__big_numbers_twin_sync(
+                arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_big_numbers_twin_sync,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualBigNumberTwinSyncBigNumbersTwinSyncConstMeta,
+      argValues: [value],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualBigNumberTwinSyncBigNumbersTwinSyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "big_numbers_twin_sync",
+            argNames: ["value"],
+          );
+
+  @override
+  Decimal crateApiPseudoManualBigNumberTwinSyncDecimalTwinSync(
+      {required Decimal d}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        var arg0 = cst_encode_Decimal(d);
+        return wire
+            .wire__crate__api__pseudo_manual__big_number_twin_sync__decimal_twin_sync(
+                arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_Decimal,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiPseudoManualBigNumberTwinSyncDecimalTwinSyncConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualBigNumberTwinSyncDecimalTwinSyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "decimal_twin_sync",
+            argNames: ["d"],
+          );
+
+  @override
+  List<Decimal> crateApiPseudoManualBigNumberTwinSyncDecimalsTwinSync(
+      {required List<Decimal> decimals}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        var arg0 = cst_encode_list_Decimal(decimals);
+        return wire
+            .wire__crate__api__pseudo_manual__big_number_twin_sync__decimals_twin_sync(
+                arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_list_Decimal,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualBigNumberTwinSyncDecimalsTwinSyncConstMeta,
+      argValues: [decimals],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualBigNumberTwinSyncDecimalsTwinSyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "decimals_twin_sync",
+            argNames: ["decimals"],
+          );
+
+  @override
+  BigInt crateApiPseudoManualBigNumberTwinSyncMultiplyBigIntsTwinSync(
+      {required BigInt a, required BigInt b}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        var arg0 = cst_encode_BigInt(a);
+        var arg1 = cst_encode_BigInt(b);
+        return wire
+            .wire__crate__api__pseudo_manual__big_number_twin_sync__multiply_big_ints_twin_sync(
+                arg0, arg1);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_BigInt,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualBigNumberTwinSyncMultiplyBigIntsTwinSyncConstMeta,
+      argValues: [a, b],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualBigNumberTwinSyncMultiplyBigIntsTwinSyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "multiply_big_ints_twin_sync",
+            argNames: ["a", "b"],
+          );
+
+  @override
+  BigInt?
+      crateApiPseudoManualBigNumberTwinSyncOptionalBigIntTwinSync(
+          {BigInt? i}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        var arg0 = cst_encode_opt_BigInt(i);
+        return wire
+            .wire__crate__api__pseudo_manual__big_number_twin_sync__optional_big_int_twin_sync(
+                arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_opt_BigInt,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualBigNumberTwinSyncOptionalBigIntTwinSyncConstMeta,
+      argValues: [i],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualBigNumberTwinSyncOptionalBigIntTwinSyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "optional_big_int_twin_sync",
+            argNames: ["i"],
+          );
+
+  @override
+  Decimal?
+      crateApiPseudoManualBigNumberTwinSyncOptionalDecimalTwinSync(
+          {Decimal? d}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        var arg0 = cst_encode_opt_Decimal(d);
+        return wire
+            .wire__crate__api__pseudo_manual__big_number_twin_sync__optional_decimal_twin_sync(
+                arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_opt_Decimal,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualBigNumberTwinSyncOptionalDecimalTwinSyncConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualBigNumberTwinSyncOptionalDecimalTwinSyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "optional_decimal_twin_sync",
+            argNames: ["d"],
+          );
+
+  @override
+  Decimal crateApiPseudoManualBigNumberTwinSyncSseAddDecimalsTwinSyncSse(
+      {required Decimal a, required Decimal b}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Decimal(a, serializer);
+        sse_encode_Decimal(b, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__big_number_twin_sync_sse__add_decimals_twin_sync_sse(
+                raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_Decimal,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualBigNumberTwinSyncSseAddDecimalsTwinSyncSseConstMeta,
+      argValues: [a, b],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualBigNumberTwinSyncSseAddDecimalsTwinSyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "add_decimals_twin_sync_sse",
+            argNames: ["a", "b"],
+          );
+
+  @override
+  BigInt crateApiPseudoManualBigNumberTwinSyncSseBigIntTwinSyncSse(
+      {required BigInt i}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_BigInt(i, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__big_number_twin_sync_sse__big_int_twin_sync_sse(
+                raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_BigInt,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualBigNumberTwinSyncSseBigIntTwinSyncSseConstMeta,
+      argValues: [i],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualBigNumberTwinSyncSseBigIntTwinSyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "big_int_twin_sync_sse",
+            argNames: ["i"],
+          );
+
+  @override
+  List<BigInt> crateApiPseudoManualBigNumberTwinSyncSseBigIntsTwinSyncSse(
+      {required List<BigInt> ints}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_list_BigInt(ints, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__big_number_twin_sync_sse__big_ints_twin_sync_sse(
+                raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_BigInt,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualBigNumberTwinSyncSseBigIntsTwinSyncSseConstMeta,
+      argValues: [ints],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualBigNumberTwinSyncSseBigIntsTwinSyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "big_ints_twin_sync_sse",
+            argNames: ["ints"],
+          );
+
+  @override
+  BigNumbersTwinSyncSse
+      crateApiPseudoManualBigNumberTwinSyncSseBigNumbersTwinSyncSse(
+          {required BigNumbersTwinSyncSse value}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_big_numbers_twin_sync_sse(value, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__big_number_twin_sync_sse__big_numbers_twin_sync_sse(
+                raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_big_numbers_twin_sync_sse,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualBigNumberTwinSyncSseBigNumbersTwinSyncSseConstMeta,
+      argValues: [value],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualBigNumberTwinSyncSseBigNumbersTwinSyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "big_numbers_twin_sync_sse",
+            argNames: ["value"],
+          );
+
+  @override
+  Decimal crateApiPseudoManualBigNumberTwinSyncSseDecimalTwinSyncSse(
+      {required Decimal d}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Decimal(d, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__big_number_twin_sync_sse__decimal_twin_sync_sse(
+                raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_Decimal,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualBigNumberTwinSyncSseDecimalTwinSyncSseConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualBigNumberTwinSyncSseDecimalTwinSyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "decimal_twin_sync_sse",
+            argNames: ["d"],
+          );
+
+  @override
+  List<Decimal> crateApiPseudoManualBigNumberTwinSyncSseDecimalsTwinSyncSse(
+      {required List<Decimal> decimals}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_list_Decimal(decimals, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__big_number_twin_sync_sse__decimals_twin_sync_sse(
+                raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_Decimal,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualBigNumberTwinSyncSseDecimalsTwinSyncSseConstMeta,
+      argValues: [decimals],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualBigNumberTwinSyncSseDecimalsTwinSyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "decimals_twin_sync_sse",
+            argNames: ["decimals"],
+          );
+
+  @override
+  BigInt crateApiPseudoManualBigNumberTwinSyncSseMultiplyBigIntsTwinSyncSse(
+      {required BigInt a, required BigInt b}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_BigInt(a, serializer);
+        sse_encode_BigInt(b, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__big_number_twin_sync_sse__multiply_big_ints_twin_sync_sse(
+                raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_BigInt,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualBigNumberTwinSyncSseMultiplyBigIntsTwinSyncSseConstMeta,
+      argValues: [a, b],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualBigNumberTwinSyncSseMultiplyBigIntsTwinSyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "multiply_big_ints_twin_sync_sse",
+            argNames: ["a", "b"],
+          );
+
+  @override
+  BigInt? crateApiPseudoManualBigNumberTwinSyncSseOptionalBigIntTwinSyncSse(
+      {BigInt? i}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_opt_BigInt(i, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__big_number_twin_sync_sse__optional_big_int_twin_sync_sse(
+                raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_BigInt,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualBigNumberTwinSyncSseOptionalBigIntTwinSyncSseConstMeta,
+      argValues: [i],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualBigNumberTwinSyncSseOptionalBigIntTwinSyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "optional_big_int_twin_sync_sse",
+            argNames: ["i"],
+          );
+
+  @override
+  Decimal? crateApiPseudoManualBigNumberTwinSyncSseOptionalDecimalTwinSyncSse(
+      {Decimal? d}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_opt_Decimal(d, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__big_number_twin_sync_sse__optional_decimal_twin_sync_sse(
+                raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_Decimal,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualBigNumberTwinSyncSseOptionalDecimalTwinSyncSseConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualBigNumberTwinSyncSseOptionalDecimalTwinSyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "optional_decimal_twin_sync_sse",
+            argNames: ["d"],
+          );
+
+  @override
+  Future<DateTimeWithOffset>
+      crateApiPseudoManualChronoTypeTwinRustAsyncDatetimeFixedOffsetTwinRustAsync(
+          {required DateTimeWithOffset d}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_Chrono_FixedOffset(d);
+        return wire
+            .wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__datetime_fixed_offset_twin_rust_async(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_Chrono_FixedOffset,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualChronoTypeTwinRustAsyncDatetimeFixedOffsetTwinRustAsyncConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualChronoTypeTwinRustAsyncDatetimeFixedOffsetTwinRustAsyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "datetime_fixed_offset_twin_rust_async",
+            argNames: ["d"],
+          );
+
+  @override
+  Future<List<DateTimeWithOffset>>
+      crateApiPseudoManualChronoTypeTwinRustAsyncDatetimeFixedOffsetsTwinRustAsync(
+          {required List<DateTimeWithOffset> dates}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_list_Chrono_FixedOffset(dates);
+        return wire
+            .wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__datetime_fixed_offsets_twin_rust_async(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_list_Chrono_FixedOffset,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualChronoTypeTwinRustAsyncDatetimeFixedOffsetsTwinRustAsyncConstMeta,
+      argValues: [dates],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualChronoTypeTwinRustAsyncDatetimeFixedOffsetsTwinRustAsyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "datetime_fixed_offsets_twin_rust_async",
+            argNames: ["dates"],
+          );
+
   @override
   Future<DateTime>
       crateApiPseudoManualChronoTypeTwinRustAsyncDatetimeLocalTwinRustAsync(
@@ -39723,6 +43740,93 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             argNames: ["mine"],
           );
 
+  @override
+  Future<DateTime>
+      crateApiPseudoManualChronoTypeTwinRustAsyncNaiveDateTwinRustAsync(
+          {required DateTime d}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_Chrono_NaiveDate(d);
+        return wire
+            .wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__naive_date_twin_rust_async(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_Chrono_NaiveDate,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualChronoTypeTwinRustAsyncNaiveDateTwinRustAsyncConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualChronoTypeTwinRustAsyncNaiveDateTwinRustAsyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "naive_date_twin_rust_async",
+            argNames: ["d"],
+          );
+
+  @override
+  Future<List<DateTime>>
+      crateApiPseudoManualChronoTypeTwinRustAsyncNaiveDatesTwinRustAsync(
+          {required List<DateTime> dates}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_list_Chrono_NaiveDate(dates);
+        return wire
+            .wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__naive_dates_twin_rust_async(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_list_Chrono_NaiveDate,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualChronoTypeTwinRustAsyncNaiveDatesTwinRustAsyncConstMeta,
+      argValues: [dates],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualChronoTypeTwinRustAsyncNaiveDatesTwinRustAsyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "naive_dates_twin_rust_async",
+            argNames: ["dates"],
+          );
+
+  @override
+  Future<Duration>
+      crateApiPseudoManualChronoTypeTwinRustAsyncNaiveTimeTwinRustAsync(
+          {required Duration d}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_Chrono_NaiveTime(d);
+        return wire
+            .wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__naive_time_twin_rust_async(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_Chrono_NaiveTime,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualChronoTypeTwinRustAsyncNaiveTimeTwinRustAsyncConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualChronoTypeTwinRustAsyncNaiveTimeTwinRustAsyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "naive_time_twin_rust_async",
+            argNames: ["d"],
+          );
+
   @override
   Future<DateTime>
       crateApiPseudoManualChronoTypeTwinRustAsyncNaivedatetimeTwinRustAsync(
@@ -39752,6 +43856,35 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             argNames: ["d"],
           );
 
+  @override
+  Future<DateTimeWithOffset?>
+      crateApiPseudoManualChronoTypeTwinRustAsyncOptionalDatetimeFixedOffsetTwinRustAsync(
+          {DateTimeWithOffset? d}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_opt_Chrono_FixedOffset(d);
+        return wire
+            .wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__optional_datetime_fixed_offset_twin_rust_async(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_opt_Chrono_FixedOffset,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualChronoTypeTwinRustAsyncOptionalDatetimeFixedOffsetTwinRustAsyncConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualChronoTypeTwinRustAsyncOptionalDatetimeFixedOffsetTwinRustAsyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "optional_datetime_fixed_offset_twin_rust_async",
+            argNames: ["d"],
+          );
+
   @override
   Future<DateTime?>
       crateApiPseudoManualChronoTypeTwinRustAsyncOptionalEmptyDatetimeUtcTwinRustAsync(
@@ -39781,6 +43914,35 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             argNames: ["d"],
           );
 
+  @override
+  Future<ScheduleTwinRustAsync>
+      crateApiPseudoManualChronoTypeTwinRustAsyncScheduleTwinRustAsync(
+          {required ScheduleTwinRustAsync schedule}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_box_autoadd_schedule_twin_rust_async(schedule);
+        return wire
+            .wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__schedule_twin_rust_async(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_schedule_twin_rust_async,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualChronoTypeTwinRustAsyncScheduleTwinRustAsyncConstMeta,
+      argValues: [schedule],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualChronoTypeTwinRustAsyncScheduleTwinRustAsyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "schedule_twin_rust_async",
+            argNames: ["schedule"],
+          );
+
   @override
   Future<TestChronoTwinRustAsync>
       crateApiPseudoManualChronoTypeTwinRustAsyncTestChronoTwinRustAsync() {
@@ -39835,6 +43997,68 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             argNames: [],
           );
 
+  @override
+  Future<DateTimeWithOffset>
+      crateApiPseudoManualChronoTypeTwinRustAsyncSseDatetimeFixedOffsetTwinRustAsyncSse(
+          {required DateTimeWithOffset d}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Chrono_FixedOffset(d, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__datetime_fixed_offset_twin_rust_async_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_Chrono_FixedOffset,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualChronoTypeTwinRustAsyncSseDatetimeFixedOffsetTwinRustAsyncSseConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualChronoTypeTwinRustAsyncSseDatetimeFixedOffsetTwinRustAsyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "datetime_fixed_offset_twin_rust_async_sse",
+            argNames: ["d"],
+          );
+
+  @override
+  Future<List<DateTimeWithOffset>>
+      crateApiPseudoManualChronoTypeTwinRustAsyncSseDatetimeFixedOffsetsTwinRustAsyncSse(
+          {required List<DateTimeWithOffset> dates}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_list_Chrono_FixedOffset(dates, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__datetime_fixed_offsets_twin_rust_async_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_Chrono_FixedOffset,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualChronoTypeTwinRustAsyncSseDatetimeFixedOffsetsTwinRustAsyncSseConstMeta,
+      argValues: [dates],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualChronoTypeTwinRustAsyncSseDatetimeFixedOffsetsTwinRustAsyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "datetime_fixed_offsets_twin_rust_async_sse",
+            argNames: ["dates"],
+          );
+
   @override
   Future<DateTime>
       crateApiPseudoManualChronoTypeTwinRustAsyncSseDatetimeLocalTwinRustAsyncSse(
@@ -40024,6 +44248,99 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             argNames: ["mine"],
           );
 
+  @override
+  Future<DateTime>
+      crateApiPseudoManualChronoTypeTwinRustAsyncSseNaiveDateTwinRustAsyncSse(
+          {required DateTime d}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Chrono_NaiveDate(d, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__naive_date_twin_rust_async_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_Chrono_NaiveDate,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualChronoTypeTwinRustAsyncSseNaiveDateTwinRustAsyncSseConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualChronoTypeTwinRustAsyncSseNaiveDateTwinRustAsyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "naive_date_twin_rust_async_sse",
+            argNames: ["d"],
+          );
+
+  @override
+  Future<List<DateTime>>
+      crateApiPseudoManualChronoTypeTwinRustAsyncSseNaiveDatesTwinRustAsyncSse(
+          {required List<DateTime> dates}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_list_Chrono_NaiveDate(dates, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__naive_dates_twin_rust_async_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_Chrono_NaiveDate,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualChronoTypeTwinRustAsyncSseNaiveDatesTwinRustAsyncSseConstMeta,
+      argValues: [dates],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualChronoTypeTwinRustAsyncSseNaiveDatesTwinRustAsyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "naive_dates_twin_rust_async_sse",
+            argNames: ["dates"],
+          );
+
+  @override
+  Future<Duration>
+      crateApiPseudoManualChronoTypeTwinRustAsyncSseNaiveTimeTwinRustAsyncSse(
+          {required Duration d}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Chrono_NaiveTime(d, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__naive_time_twin_rust_async_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_Chrono_NaiveTime,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualChronoTypeTwinRustAsyncSseNaiveTimeTwinRustAsyncSseConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualChronoTypeTwinRustAsyncSseNaiveTimeTwinRustAsyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "naive_time_twin_rust_async_sse",
+            argNames: ["d"],
+          );
+
   @override
   Future<DateTime>
       crateApiPseudoManualChronoTypeTwinRustAsyncSseNaivedatetimeTwinRustAsyncSse(
@@ -40055,6 +44372,37 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             argNames: ["d"],
           );
 
+  @override
+  Future<DateTimeWithOffset?>
+      crateApiPseudoManualChronoTypeTwinRustAsyncSseOptionalDatetimeFixedOffsetTwinRustAsyncSse(
+          {DateTimeWithOffset? d}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_opt_Chrono_FixedOffset(d, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__optional_datetime_fixed_offset_twin_rust_async_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_Chrono_FixedOffset,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualChronoTypeTwinRustAsyncSseOptionalDatetimeFixedOffsetTwinRustAsyncSseConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualChronoTypeTwinRustAsyncSseOptionalDatetimeFixedOffsetTwinRustAsyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "optional_datetime_fixed_offset_twin_rust_async_sse",
+            argNames: ["d"],
+          );
+
   @override
   Future<DateTime?>
       crateApiPseudoManualChronoTypeTwinRustAsyncSseOptionalEmptyDatetimeUtcTwinRustAsyncSse(
@@ -40086,6 +44434,38 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             argNames: ["d"],
           );
 
+  @override
+  Future<ScheduleTwinRustAsyncSse>
+      crateApiPseudoManualChronoTypeTwinRustAsyncSseScheduleTwinRustAsyncSse(
+          {required ScheduleTwinRustAsyncSse schedule}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_schedule_twin_rust_async_sse(
+            schedule, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__schedule_twin_rust_async_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_schedule_twin_rust_async_sse,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualChronoTypeTwinRustAsyncSseScheduleTwinRustAsyncSseConstMeta,
+      argValues: [schedule],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualChronoTypeTwinRustAsyncSseScheduleTwinRustAsyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "schedule_twin_rust_async_sse",
+            argNames: ["schedule"],
+          );
+
   @override
   Future<TestChronoTwinRustAsyncSse>
       crateApiPseudoManualChronoTypeTwinRustAsyncSseTestChronoTwinRustAsyncSse() {
@@ -40144,6 +44524,68 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             argNames: [],
           );
 
+  @override
+  Future<DateTimeWithOffset>
+      crateApiPseudoManualChronoTypeTwinSseDatetimeFixedOffsetTwinSse(
+          {required DateTimeWithOffset d}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Chrono_FixedOffset(d, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__chrono_type_twin_sse__datetime_fixed_offset_twin_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_Chrono_FixedOffset,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualChronoTypeTwinSseDatetimeFixedOffsetTwinSseConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualChronoTypeTwinSseDatetimeFixedOffsetTwinSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "datetime_fixed_offset_twin_sse",
+            argNames: ["d"],
+          );
+
+  @override
+  Future<List<DateTimeWithOffset>>
+      crateApiPseudoManualChronoTypeTwinSseDatetimeFixedOffsetsTwinSse(
+          {required List<DateTimeWithOffset> dates}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_list_Chrono_FixedOffset(dates, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__chrono_type_twin_sse__datetime_fixed_offsets_twin_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_Chrono_FixedOffset,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualChronoTypeTwinSseDatetimeFixedOffsetsTwinSseConstMeta,
+      argValues: [dates],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualChronoTypeTwinSseDatetimeFixedOffsetsTwinSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "datetime_fixed_offsets_twin_sse",
+            argNames: ["dates"],
+          );
+
   @override
   Future<DateTime> crateApiPseudoManualChronoTypeTwinSseDatetimeLocalTwinSse(
       {required DateTime d}) {
@@ -40328,6 +44770,96 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             argNames: ["mine"],
           );
 
+  @override
+  Future<DateTime> crateApiPseudoManualChronoTypeTwinSseNaiveDateTwinSse(
+      {required DateTime d}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Chrono_NaiveDate(d, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__chrono_type_twin_sse__naive_date_twin_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_Chrono_NaiveDate,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualChronoTypeTwinSseNaiveDateTwinSseConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualChronoTypeTwinSseNaiveDateTwinSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "naive_date_twin_sse",
+            argNames: ["d"],
+          );
+
+  @override
+  Future<List<DateTime>> crateApiPseudoManualChronoTypeTwinSseNaiveDatesTwinSse(
+      {required List<DateTime> dates}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_list_Chrono_NaiveDate(dates, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__chrono_type_twin_sse__naive_dates_twin_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_Chrono_NaiveDate,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualChronoTypeTwinSseNaiveDatesTwinSseConstMeta,
+      argValues: [dates],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualChronoTypeTwinSseNaiveDatesTwinSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "naive_dates_twin_sse",
+            argNames: ["dates"],
+          );
+
+  @override
+  Future<Duration> crateApiPseudoManualChronoTypeTwinSseNaiveTimeTwinSse(
+      {required Duration d}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Chrono_NaiveTime(d, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__chrono_type_twin_sse__naive_time_twin_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_Chrono_NaiveTime,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualChronoTypeTwinSseNaiveTimeTwinSseConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualChronoTypeTwinSseNaiveTimeTwinSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "naive_time_twin_sse",
+            argNames: ["d"],
+          );
+
   @override
   Future<DateTime> crateApiPseudoManualChronoTypeTwinSseNaivedatetimeTwinSse(
       {required DateTime d}) {
@@ -40358,6 +44890,37 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             argNames: ["d"],
           );
 
+  @override
+  Future<DateTimeWithOffset?>
+      crateApiPseudoManualChronoTypeTwinSseOptionalDatetimeFixedOffsetTwinSse(
+          {DateTimeWithOffset? d}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_opt_Chrono_FixedOffset(d, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__chrono_type_twin_sse__optional_datetime_fixed_offset_twin_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_Chrono_FixedOffset,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualChronoTypeTwinSseOptionalDatetimeFixedOffsetTwinSseConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualChronoTypeTwinSseOptionalDatetimeFixedOffsetTwinSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "optional_datetime_fixed_offset_twin_sse",
+            argNames: ["d"],
+          );
+
   @override
   Future<DateTime?>
       crateApiPseudoManualChronoTypeTwinSseOptionalEmptyDatetimeUtcTwinSse(
@@ -40389,6 +44952,35 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             argNames: ["d"],
           );
 
+  @override
+  Future<ScheduleTwinSse> crateApiPseudoManualChronoTypeTwinSseScheduleTwinSse(
+      {required ScheduleTwinSse schedule}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_schedule_twin_sse(schedule, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__chrono_type_twin_sse__schedule_twin_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_schedule_twin_sse,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiPseudoManualChronoTypeTwinSseScheduleTwinSseConstMeta,
+      argValues: [schedule],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualChronoTypeTwinSseScheduleTwinSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "schedule_twin_sse",
+            argNames: ["schedule"],
+          );
+
   @override
   Future<TestChronoTwinSse>
       crateApiPseudoManualChronoTypeTwinSseTestChronoTwinSse() {
@@ -40447,6 +45039,64 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             argNames: [],
           );
 
+  @override
+  DateTimeWithOffset
+      crateApiPseudoManualChronoTypeTwinSyncDatetimeFixedOffsetTwinSync(
+          {required DateTimeWithOffset d}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        var arg0 = cst_encode_Chrono_FixedOffset(d);
+        return wire
+            .wire__crate__api__pseudo_manual__chrono_type_twin_sync__datetime_fixed_offset_twin_sync(
+                arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_Chrono_FixedOffset,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualChronoTypeTwinSyncDatetimeFixedOffsetTwinSyncConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualChronoTypeTwinSyncDatetimeFixedOffsetTwinSyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "datetime_fixed_offset_twin_sync",
+            argNames: ["d"],
+          );
+
+  @override
+  List<DateTimeWithOffset>
+      crateApiPseudoManualChronoTypeTwinSyncDatetimeFixedOffsetsTwinSync(
+          {required List<DateTimeWithOffset> dates}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        var arg0 = cst_encode_list_Chrono_FixedOffset(dates);
+        return wire
+            .wire__crate__api__pseudo_manual__chrono_type_twin_sync__datetime_fixed_offsets_twin_sync(
+                arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_list_Chrono_FixedOffset,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualChronoTypeTwinSyncDatetimeFixedOffsetsTwinSyncConstMeta,
+      argValues: [dates],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualChronoTypeTwinSyncDatetimeFixedOffsetsTwinSyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "datetime_fixed_offsets_twin_sync",
+            argNames: ["dates"],
+          );
+
   @override
   DateTime crateApiPseudoManualChronoTypeTwinSyncDatetimeLocalTwinSync(
       {required DateTime d}) {
@@ -40617,6 +45267,90 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             argNames: ["mine"],
           );
 
+  @override
+  DateTime crateApiPseudoManualChronoTypeTwinSyncNaiveDateTwinSync(
+      {required DateTime d}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        var arg0 = cst_encode_Chrono_NaiveDate(d);
+        return wire
+            .wire__crate__api__pseudo_manual__chrono_type_twin_sync__naive_date_twin_sync(
+                arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_Chrono_NaiveDate,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualChronoTypeTwinSyncNaiveDateTwinSyncConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualChronoTypeTwinSyncNaiveDateTwinSyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "naive_date_twin_sync",
+            argNames: ["d"],
+          );
+
+  @override
+  List<DateTime> crateApiPseudoManualChronoTypeTwinSyncNaiveDatesTwinSync(
+      {required List<DateTime> dates}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        var arg0 = cst_encode_list_Chrono_NaiveDate(dates);
+        return wire
+            .wire__crate__api__pseudo_manual__chrono_type_twin_sync__naive_dates_twin_sync(
+                arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_list_Chrono_NaiveDate,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualChronoTypeTwinSyncNaiveDatesTwinSyncConstMeta,
+      argValues: [dates],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualChronoTypeTwinSyncNaiveDatesTwinSyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "naive_dates_twin_sync",
+            argNames: ["dates"],
+          );
+
+  @override
+  Duration crateApiPseudoManualChronoTypeTwinSyncNaiveTimeTwinSync(
+      {required Duration d}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        var arg0 = cst_encode_Chrono_NaiveTime(d);
+        return wire
+            .wire__crate__api__pseudo_manual__chrono_type_twin_sync__naive_time_twin_sync(
+                arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_Chrono_NaiveTime,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualChronoTypeTwinSyncNaiveTimeTwinSyncConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualChronoTypeTwinSyncNaiveTimeTwinSyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "naive_time_twin_sync",
+            argNames: ["d"],
+          );
+
   @override
   DateTime crateApiPseudoManualChronoTypeTwinSyncNaivedatetimeTwinSync(
       {required DateTime d}) {
@@ -40645,6 +45379,35 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             argNames: ["d"],
           );
 
+  @override
+  DateTimeWithOffset?
+      crateApiPseudoManualChronoTypeTwinSyncOptionalDatetimeFixedOffsetTwinSync(
+          {DateTimeWithOffset? d}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        var arg0 = cst_encode_opt_Chrono_FixedOffset(d);
+        return wire
+            .wire__crate__api__pseudo_manual__chrono_type_twin_sync__optional_datetime_fixed_offset_twin_sync(
+                arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_opt_Chrono_FixedOffset,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualChronoTypeTwinSyncOptionalDatetimeFixedOffsetTwinSyncConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualChronoTypeTwinSyncOptionalDatetimeFixedOffsetTwinSyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "optional_datetime_fixed_offset_twin_sync",
+            argNames: ["d"],
+          );
+
   @override
   DateTime?
       crateApiPseudoManualChronoTypeTwinSyncOptionalEmptyDatetimeUtcTwinSync(
@@ -40674,6 +45437,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             argNames: ["d"],
           );
 
+  @override
+  ScheduleTwinSync crateApiPseudoManualChronoTypeTwinSyncScheduleTwinSync(
+      {required ScheduleTwinSync schedule}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        var arg0 = cst_encode_box_autoadd_schedule_twin_sync(schedule);
+        return wire
+            .wire__crate__api__pseudo_manual__chrono_type_twin_sync__schedule_twin_sync(
+                arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_schedule_twin_sync,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualChronoTypeTwinSyncScheduleTwinSyncConstMeta,
+      argValues: [schedule],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualChronoTypeTwinSyncScheduleTwinSyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "schedule_twin_sync",
+            argNames: ["schedule"],
+          );
+
   @override
   TestChronoTwinSync
       crateApiPseudoManualChronoTypeTwinSyncTestChronoTwinSync() {
@@ -40726,6 +45517,68 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             argNames: [],
           );
 
+  @override
+  DateTimeWithOffset
+      crateApiPseudoManualChronoTypeTwinSyncSseDatetimeFixedOffsetTwinSyncSse(
+          {required DateTimeWithOffset d}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Chrono_FixedOffset(d, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__chrono_type_twin_sync_sse__datetime_fixed_offset_twin_sync_sse(
+                raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_Chrono_FixedOffset,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualChronoTypeTwinSyncSseDatetimeFixedOffsetTwinSyncSseConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualChronoTypeTwinSyncSseDatetimeFixedOffsetTwinSyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "datetime_fixed_offset_twin_sync_sse",
+            argNames: ["d"],
+          );
+
+  @override
+  List<DateTimeWithOffset>
+      crateApiPseudoManualChronoTypeTwinSyncSseDatetimeFixedOffsetsTwinSyncSse(
+          {required List<DateTimeWithOffset> dates}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_list_Chrono_FixedOffset(dates, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__chrono_type_twin_sync_sse__datetime_fixed_offsets_twin_sync_sse(
+                raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_Chrono_FixedOffset,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualChronoTypeTwinSyncSseDatetimeFixedOffsetsTwinSyncSseConstMeta,
+      argValues: [dates],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualChronoTypeTwinSyncSseDatetimeFixedOffsetsTwinSyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "datetime_fixed_offsets_twin_sync_sse",
+            argNames: ["dates"],
+          );
+
   @override
   DateTime crateApiPseudoManualChronoTypeTwinSyncSseDatetimeLocalTwinSyncSse(
       {required DateTime d}) {
@@ -40911,6 +45764,96 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             argNames: ["mine"],
           );
 
+  @override
+  DateTime crateApiPseudoManualChronoTypeTwinSyncSseNaiveDateTwinSyncSse(
+      {required DateTime d}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Chrono_NaiveDate(d, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__chrono_type_twin_sync_sse__naive_date_twin_sync_sse(
+                raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_Chrono_NaiveDate,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualChronoTypeTwinSyncSseNaiveDateTwinSyncSseConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualChronoTypeTwinSyncSseNaiveDateTwinSyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "naive_date_twin_sync_sse",
+            argNames: ["d"],
+          );
+
+  @override
+  List<DateTime> crateApiPseudoManualChronoTypeTwinSyncSseNaiveDatesTwinSyncSse(
+      {required List<DateTime> dates}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_list_Chrono_NaiveDate(dates, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__chrono_type_twin_sync_sse__naive_dates_twin_sync_sse(
+                raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_Chrono_NaiveDate,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualChronoTypeTwinSyncSseNaiveDatesTwinSyncSseConstMeta,
+      argValues: [dates],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualChronoTypeTwinSyncSseNaiveDatesTwinSyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "naive_dates_twin_sync_sse",
+            argNames: ["dates"],
+          );
+
+  @override
+  Duration crateApiPseudoManualChronoTypeTwinSyncSseNaiveTimeTwinSyncSse(
+      {required Duration d}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Chrono_NaiveTime(d, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__chrono_type_twin_sync_sse__naive_time_twin_sync_sse(
+                raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_Chrono_NaiveTime,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualChronoTypeTwinSyncSseNaiveTimeTwinSyncSseConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualChronoTypeTwinSyncSseNaiveTimeTwinSyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "naive_time_twin_sync_sse",
+            argNames: ["d"],
+          );
+
   @override
   DateTime crateApiPseudoManualChronoTypeTwinSyncSseNaivedatetimeTwinSyncSse(
       {required DateTime d}) {
@@ -40941,6 +45884,37 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             argNames: ["d"],
           );
 
+  @override
+  DateTimeWithOffset?
+      crateApiPseudoManualChronoTypeTwinSyncSseOptionalDatetimeFixedOffsetTwinSyncSse(
+          {DateTimeWithOffset? d}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_opt_Chrono_FixedOffset(d, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__chrono_type_twin_sync_sse__optional_datetime_fixed_offset_twin_sync_sse(
+                raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_Chrono_FixedOffset,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualChronoTypeTwinSyncSseOptionalDatetimeFixedOffsetTwinSyncSseConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualChronoTypeTwinSyncSseOptionalDatetimeFixedOffsetTwinSyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "optional_datetime_fixed_offset_twin_sync_sse",
+            argNames: ["d"],
+          );
+
   @override
   DateTime?
       crateApiPseudoManualChronoTypeTwinSyncSseOptionalEmptyDatetimeUtcTwinSyncSse(
@@ -40972,6 +45946,37 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             argNames: ["d"],
           );
 
+  @override
+  ScheduleTwinSyncSse
+      crateApiPseudoManualChronoTypeTwinSyncSseScheduleTwinSyncSse(
+          {required ScheduleTwinSyncSse schedule}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_schedule_twin_sync_sse(schedule, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__chrono_type_twin_sync_sse__schedule_twin_sync_sse(
+                raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_schedule_twin_sync_sse,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualChronoTypeTwinSyncSseScheduleTwinSyncSseConstMeta,
+      argValues: [schedule],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualChronoTypeTwinSyncSseScheduleTwinSyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "schedule_twin_sync_sse",
+            argNames: ["schedule"],
+          );
+
   @override
   TestChronoTwinSyncSse
       crateApiPseudoManualChronoTypeTwinSyncSseTestChronoTwinSyncSse() {
@@ -42098,6 +47103,65 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             argNames: ["callback"],
           );
 
+  @override
+  Future<int>
+      crateApiPseudoManualDartFnTwinRustAsyncRustConsumeDartStreamSumTwinRustAsync(
+          {required Stream<int> stream}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_DartStream_i_32(stream);
+        return wire
+            .wire__crate__api__pseudo_manual__dart_fn_twin_rust_async__rust_consume_dart_stream_sum_twin_rust_async(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_i_32,
+        decodeErrorData: dco_decode_AnyhowException,
+      ),
+      constMeta:
+          kCrateApiPseudoManualDartFnTwinRustAsyncRustConsumeDartStreamSumTwinRustAsyncConstMeta,
+      argValues: [stream],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualDartFnTwinRustAsyncRustConsumeDartStreamSumTwinRustAsyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "rust_consume_dart_stream_sum_twin_rust_async",
+            argNames: ["stream"],
+          );
+
+  @override
+  Future<List<String>>
+      crateApiPseudoManualDartFnTwinRustAsyncRustConsumeDartStreamTakeTwinRustAsync(
+          {required Stream<String> stream, required BigInt count}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_DartStream_String(stream);
+        var arg1 = cst_encode_usize(count);
+        return wire
+            .wire__crate__api__pseudo_manual__dart_fn_twin_rust_async__rust_consume_dart_stream_take_twin_rust_async(
+                port_, arg0, arg1);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_list_String,
+        decodeErrorData: dco_decode_AnyhowException,
+      ),
+      constMeta:
+          kCrateApiPseudoManualDartFnTwinRustAsyncRustConsumeDartStreamTakeTwinRustAsyncConstMeta,
+      argValues: [stream, count],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualDartFnTwinRustAsyncRustConsumeDartStreamTakeTwinRustAsyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "rust_consume_dart_stream_take_twin_rust_async",
+            argNames: ["stream", "count"],
+          );
+
   @override
   Future<void>
       crateApiPseudoManualDartFnTwinRustAsyncSseRustCallDartLoopbackTwinRustAsyncSse(
@@ -42398,6 +47462,69 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             argNames: ["callback"],
           );
 
+  @override
+  Future<int>
+      crateApiPseudoManualDartFnTwinRustAsyncSseRustConsumeDartStreamSumTwinRustAsyncSse(
+          {required Stream<int> stream}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_DartStream_i_32(stream, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__dart_fn_twin_rust_async_sse__rust_consume_dart_stream_sum_twin_rust_async_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_i_32,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta:
+          kCrateApiPseudoManualDartFnTwinRustAsyncSseRustConsumeDartStreamSumTwinRustAsyncSseConstMeta,
+      argValues: [stream],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualDartFnTwinRustAsyncSseRustConsumeDartStreamSumTwinRustAsyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "rust_consume_dart_stream_sum_twin_rust_async_sse",
+            argNames: ["stream"],
+          );
+
+  @override
+  Future<List<String>>
+      crateApiPseudoManualDartFnTwinRustAsyncSseRustConsumeDartStreamTakeTwinRustAsyncSse(
+          {required Stream<String> stream, required BigInt count}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_DartStream_String(stream, serializer);
+        sse_encode_usize(count, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__dart_fn_twin_rust_async_sse__rust_consume_dart_stream_take_twin_rust_async_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_String,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta:
+          kCrateApiPseudoManualDartFnTwinRustAsyncSseRustConsumeDartStreamTakeTwinRustAsyncSseConstMeta,
+      argValues: [stream, count],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualDartFnTwinRustAsyncSseRustConsumeDartStreamTakeTwinRustAsyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "rust_consume_dart_stream_take_twin_rust_async_sse",
+            argNames: ["stream", "count"],
+          );
+
   @override
   Future<void> crateApiPseudoManualDartFnTwinSseRustCallDartLoopbackTwinSse(
       {required FutureOr<DemoStructForRustCallDartTwinSse> Function(
@@ -42688,6 +47815,68 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             argNames: ["callback"],
           );
 
+  @override
+  Future<int> crateApiPseudoManualDartFnTwinSseRustConsumeDartStreamSumTwinSse(
+      {required Stream<int> stream}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_DartStream_i_32(stream, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__dart_fn_twin_sse__rust_consume_dart_stream_sum_twin_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_i_32,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta:
+          kCrateApiPseudoManualDartFnTwinSseRustConsumeDartStreamSumTwinSseConstMeta,
+      argValues: [stream],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualDartFnTwinSseRustConsumeDartStreamSumTwinSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "rust_consume_dart_stream_sum_twin_sse",
+            argNames: ["stream"],
+          );
+
+  @override
+  Future<List<String>>
+      crateApiPseudoManualDartFnTwinSseRustConsumeDartStreamTakeTwinSse(
+          {required Stream<String> stream, required BigInt count}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_DartStream_String(stream, serializer);
+        sse_encode_usize(count, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__dart_fn_twin_sse__rust_consume_dart_stream_take_twin_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_String,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta:
+          kCrateApiPseudoManualDartFnTwinSseRustConsumeDartStreamTakeTwinSseConstMeta,
+      argValues: [stream, count],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualDartFnTwinSseRustConsumeDartStreamTakeTwinSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "rust_consume_dart_stream_take_twin_sse",
+            argNames: ["stream", "count"],
+          );
+
   @override
   String crateApiPseudoManualDartOpaqueSyncTwinSseSyncAcceptDartOpaqueTwinSse(
       {required Object opaque}) {
@@ -48526,6 +53715,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             argNames: ["sink"],
           );
 
+  @override
+  Future<void>
+      crateApiPseudoManualExceptionTwinRustAsyncThrowAnyhowIoErrorTwinRustAsync() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        return wire
+            .wire__crate__api__pseudo_manual__exception_twin_rust_async__throw_anyhow_io_error_twin_rust_async(
+                port_);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_unit,
+        decodeErrorData: dco_decode_AnyhowException,
+      ),
+      constMeta:
+          kCrateApiPseudoManualExceptionTwinRustAsyncThrowAnyhowIoErrorTwinRustAsyncConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualExceptionTwinRustAsyncThrowAnyhowIoErrorTwinRustAsyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "throw_anyhow_io_error_twin_rust_async",
+            argNames: [],
+          );
+
   @override
   Future<void>
       crateApiPseudoManualExceptionTwinRustAsyncThrowAnyhowTwinRustAsync() {
@@ -48553,6 +53769,62 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             argNames: [],
           );
 
+  @override
+  Future<void>
+      crateApiPseudoManualExceptionTwinRustAsyncThrowAnyhowWithContextTwinRustAsync() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        return wire
+            .wire__crate__api__pseudo_manual__exception_twin_rust_async__throw_anyhow_with_context_twin_rust_async(
+                port_);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_unit,
+        decodeErrorData: dco_decode_AnyhowException,
+      ),
+      constMeta:
+          kCrateApiPseudoManualExceptionTwinRustAsyncThrowAnyhowWithContextTwinRustAsyncConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualExceptionTwinRustAsyncThrowAnyhowWithContextTwinRustAsyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "throw_anyhow_with_context_twin_rust_async",
+            argNames: [],
+          );
+
+  @override
+  Future<int>
+      crateApiPseudoManualExceptionTwinRustAsyncTypedExceptionReturnErrorTwinRustAsync(
+          {required int variant}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_i_32(variant);
+        return wire
+            .wire__crate__api__pseudo_manual__exception_twin_rust_async__typed_exception_return_error_twin_rust_async(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_i_32,
+        decodeErrorData: dco_decode_typed_exception_error_twin_rust_async,
+      ),
+      constMeta:
+          kCrateApiPseudoManualExceptionTwinRustAsyncTypedExceptionReturnErrorTwinRustAsyncConstMeta,
+      argValues: [variant],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualExceptionTwinRustAsyncTypedExceptionReturnErrorTwinRustAsyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "typed_exception_return_error_twin_rust_async",
+            argNames: ["variant"],
+          );
+
   @override
   Future<void>
       crateApiPseudoManualExceptionTwinRustAsyncSseCustomEnumErrorPanicTwinRustAsyncSse() {
@@ -49412,6 +54684,35 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             argNames: ["sink"],
           );
 
+  @override
+  Future<void>
+      crateApiPseudoManualExceptionTwinRustAsyncSseThrowAnyhowIoErrorTwinRustAsyncSse() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__exception_twin_rust_async_sse__throw_anyhow_io_error_twin_rust_async_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta:
+          kCrateApiPseudoManualExceptionTwinRustAsyncSseThrowAnyhowIoErrorTwinRustAsyncSseConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualExceptionTwinRustAsyncSseThrowAnyhowIoErrorTwinRustAsyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "throw_anyhow_io_error_twin_rust_async_sse",
+            argNames: [],
+          );
+
   @override
   Future<void>
       crateApiPseudoManualExceptionTwinRustAsyncSseThrowAnyhowTwinRustAsyncSse() {
@@ -49441,6 +54742,66 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             argNames: [],
           );
 
+  @override
+  Future<void>
+      crateApiPseudoManualExceptionTwinRustAsyncSseThrowAnyhowWithContextTwinRustAsyncSse() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__exception_twin_rust_async_sse__throw_anyhow_with_context_twin_rust_async_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta:
+          kCrateApiPseudoManualExceptionTwinRustAsyncSseThrowAnyhowWithContextTwinRustAsyncSseConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualExceptionTwinRustAsyncSseThrowAnyhowWithContextTwinRustAsyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "throw_anyhow_with_context_twin_rust_async_sse",
+            argNames: [],
+          );
+
+  @override
+  Future<int>
+      crateApiPseudoManualExceptionTwinRustAsyncSseTypedExceptionReturnErrorTwinRustAsyncSse(
+          {required int variant}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_i_32(variant, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__exception_twin_rust_async_sse__typed_exception_return_error_twin_rust_async_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_i_32,
+        decodeErrorData: sse_decode_typed_exception_error_twin_rust_async_sse,
+      ),
+      constMeta:
+          kCrateApiPseudoManualExceptionTwinRustAsyncSseTypedExceptionReturnErrorTwinRustAsyncSseConstMeta,
+      argValues: [variant],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualExceptionTwinRustAsyncSseTypedExceptionReturnErrorTwinRustAsyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "typed_exception_return_error_twin_rust_async_sse",
+            argNames: ["variant"],
+          );
+
   @override
   Future<void>
       crateApiPseudoManualExceptionTwinSseCustomEnumErrorPanicTwinSse() {
@@ -50282,6 +55643,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             argNames: ["sink"],
           );
 
+  @override
+  Future<void> crateApiPseudoManualExceptionTwinSseThrowAnyhowIoErrorTwinSse() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__exception_twin_sse__throw_anyhow_io_error_twin_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta:
+          kCrateApiPseudoManualExceptionTwinSseThrowAnyhowIoErrorTwinSseConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualExceptionTwinSseThrowAnyhowIoErrorTwinSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "throw_anyhow_io_error_twin_sse",
+            argNames: [],
+          );
+
   @override
   Future<void> crateApiPseudoManualExceptionTwinSseThrowAnyhowTwinSse() {
     return handler.executeNormal(NormalTask(
@@ -50310,6 +55699,66 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             argNames: [],
           );
 
+  @override
+  Future<void>
+      crateApiPseudoManualExceptionTwinSseThrowAnyhowWithContextTwinSse() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__exception_twin_sse__throw_anyhow_with_context_twin_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta:
+          kCrateApiPseudoManualExceptionTwinSseThrowAnyhowWithContextTwinSseConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualExceptionTwinSseThrowAnyhowWithContextTwinSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "throw_anyhow_with_context_twin_sse",
+            argNames: [],
+          );
+
+  @override
+  Future<int>
+      crateApiPseudoManualExceptionTwinSseTypedExceptionReturnErrorTwinSse(
+          {required int variant}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_i_32(variant, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__exception_twin_sse__typed_exception_return_error_twin_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_i_32,
+        decodeErrorData: sse_decode_typed_exception_error_twin_sse,
+      ),
+      constMeta:
+          kCrateApiPseudoManualExceptionTwinSseTypedExceptionReturnErrorTwinSseConstMeta,
+      argValues: [variant],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualExceptionTwinSseTypedExceptionReturnErrorTwinSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "typed_exception_return_error_twin_sse",
+            argNames: ["variant"],
+          );
+
   @override
   void crateApiPseudoManualExceptionTwinSyncCustomEnumErrorPanicTwinSync() {
     return handler.executeSync(SyncTask(
@@ -51061,6 +56510,31 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             argNames: ["sink"],
           );
 
+  @override
+  void crateApiPseudoManualExceptionTwinSyncThrowAnyhowIoErrorTwinSync() {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        return wire
+            .wire__crate__api__pseudo_manual__exception_twin_sync__throw_anyhow_io_error_twin_sync();
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_unit,
+        decodeErrorData: dco_decode_AnyhowException,
+      ),
+      constMeta:
+          kCrateApiPseudoManualExceptionTwinSyncThrowAnyhowIoErrorTwinSyncConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualExceptionTwinSyncThrowAnyhowIoErrorTwinSyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "throw_anyhow_io_error_twin_sync",
+            argNames: [],
+          );
+
   @override
   void crateApiPseudoManualExceptionTwinSyncThrowAnyhowTwinSync() {
     return handler.executeSync(SyncTask(
@@ -51086,6 +56560,59 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             argNames: [],
           );
 
+  @override
+  void crateApiPseudoManualExceptionTwinSyncThrowAnyhowWithContextTwinSync() {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        return wire
+            .wire__crate__api__pseudo_manual__exception_twin_sync__throw_anyhow_with_context_twin_sync();
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_unit,
+        decodeErrorData: dco_decode_AnyhowException,
+      ),
+      constMeta:
+          kCrateApiPseudoManualExceptionTwinSyncThrowAnyhowWithContextTwinSyncConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualExceptionTwinSyncThrowAnyhowWithContextTwinSyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "throw_anyhow_with_context_twin_sync",
+            argNames: [],
+          );
+
+  @override
+  int crateApiPseudoManualExceptionTwinSyncTypedExceptionReturnErrorTwinSync(
+      {required int variant}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        var arg0 = cst_encode_i_32(variant);
+        return wire
+            .wire__crate__api__pseudo_manual__exception_twin_sync__typed_exception_return_error_twin_sync(
+                arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_i_32,
+        decodeErrorData: dco_decode_typed_exception_error_twin_sync,
+      ),
+      constMeta:
+          kCrateApiPseudoManualExceptionTwinSyncTypedExceptionReturnErrorTwinSyncConstMeta,
+      argValues: [variant],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualExceptionTwinSyncTypedExceptionReturnErrorTwinSyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "typed_exception_return_error_twin_sync",
+            argNames: ["variant"],
+          );
+
   @override
   void
       crateApiPseudoManualExceptionTwinSyncSseCustomEnumErrorPanicTwinSyncSse() {
@@ -51916,6 +57443,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             argNames: ["sink"],
           );
 
+  @override
+  void crateApiPseudoManualExceptionTwinSyncSseThrowAnyhowIoErrorTwinSyncSse() {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__exception_twin_sync_sse__throw_anyhow_io_error_twin_sync_sse(
+                raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta:
+          kCrateApiPseudoManualExceptionTwinSyncSseThrowAnyhowIoErrorTwinSyncSseConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualExceptionTwinSyncSseThrowAnyhowIoErrorTwinSyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "throw_anyhow_io_error_twin_sync_sse",
+            argNames: [],
+          );
+
   @override
   void crateApiPseudoManualExceptionTwinSyncSseThrowAnyhowTwinSyncSse() {
     return handler.executeSync(SyncTask(
@@ -51944,6 +57499,65 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             argNames: [],
           );
 
+  @override
+  void
+      crateApiPseudoManualExceptionTwinSyncSseThrowAnyhowWithContextTwinSyncSse() {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__exception_twin_sync_sse__throw_anyhow_with_context_twin_sync_sse(
+                raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta:
+          kCrateApiPseudoManualExceptionTwinSyncSseThrowAnyhowWithContextTwinSyncSseConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualExceptionTwinSyncSseThrowAnyhowWithContextTwinSyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "throw_anyhow_with_context_twin_sync_sse",
+            argNames: [],
+          );
+
+  @override
+  int crateApiPseudoManualExceptionTwinSyncSseTypedExceptionReturnErrorTwinSyncSse(
+      {required int variant}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_i_32(variant, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__exception_twin_sync_sse__typed_exception_return_error_twin_sync_sse(
+                raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_i_32,
+        decodeErrorData: sse_decode_typed_exception_error_twin_sync_sse,
+      ),
+      constMeta:
+          kCrateApiPseudoManualExceptionTwinSyncSseTypedExceptionReturnErrorTwinSyncSseConstMeta,
+      argValues: [variant],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualExceptionTwinSyncSseTypedExceptionReturnErrorTwinSyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "typed_exception_return_error_twin_sync_sse",
+            argNames: ["variant"],
+          );
+
   @override
   Future<NewSimpleStruct>
       crateApiPseudoManualExternalTypeInCrateTwinRustAsyncCallNewModuleSystemTwinRustAsync() {
@@ -53645,6 +59259,1328 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             argNames: [],
           );
 
+  @override
+  Future<Map<String, dynamic>>
+      crateApiPseudoManualJsonTypeTwinRustAsyncCreateJsonMapTwinRustAsync() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        return wire
+            .wire__crate__api__pseudo_manual__json_type_twin_rust_async__create_json_map_twin_rust_async(
+                port_);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_JsonMap,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualJsonTypeTwinRustAsyncCreateJsonMapTwinRustAsyncConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualJsonTypeTwinRustAsyncCreateJsonMapTwinRustAsyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "create_json_map_twin_rust_async",
+            argNames: [],
+          );
+
+  @override
+  Future<Object?>
+      crateApiPseudoManualJsonTypeTwinRustAsyncCreateJsonValueTwinRustAsync() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        return wire
+            .wire__crate__api__pseudo_manual__json_type_twin_rust_async__create_json_value_twin_rust_async(
+                port_);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_JsonValue,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualJsonTypeTwinRustAsyncCreateJsonValueTwinRustAsyncConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualJsonTypeTwinRustAsyncCreateJsonValueTwinRustAsyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "create_json_value_twin_rust_async",
+            argNames: [],
+          );
+
+  @override
+  Future<JsonDocumentTwinRustAsync>
+      crateApiPseudoManualJsonTypeTwinRustAsyncJsonDocumentTwinRustAsync(
+          {required JsonDocumentTwinRustAsync document}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 =
+            cst_encode_box_autoadd_json_document_twin_rust_async(document);
+        return wire
+            .wire__crate__api__pseudo_manual__json_type_twin_rust_async__json_document_twin_rust_async(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_json_document_twin_rust_async,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualJsonTypeTwinRustAsyncJsonDocumentTwinRustAsyncConstMeta,
+      argValues: [document],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualJsonTypeTwinRustAsyncJsonDocumentTwinRustAsyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "json_document_twin_rust_async",
+            argNames: ["document"],
+          );
+
+  @override
+  Future<List<String>>
+      crateApiPseudoManualJsonTypeTwinRustAsyncJsonMapKeysTwinRustAsync(
+          {required Map<String, dynamic> map}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_JsonMap(map);
+        return wire
+            .wire__crate__api__pseudo_manual__json_type_twin_rust_async__json_map_keys_twin_rust_async(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_list_String,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualJsonTypeTwinRustAsyncJsonMapKeysTwinRustAsyncConstMeta,
+      argValues: [map],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualJsonTypeTwinRustAsyncJsonMapKeysTwinRustAsyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "json_map_keys_twin_rust_async",
+            argNames: ["map"],
+          );
+
+  @override
+  Future<Map<String, dynamic>>
+      crateApiPseudoManualJsonTypeTwinRustAsyncJsonMapTwinRustAsync(
+          {required Map<String, dynamic> map}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_JsonMap(map);
+        return wire
+            .wire__crate__api__pseudo_manual__json_type_twin_rust_async__json_map_twin_rust_async(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_JsonMap,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualJsonTypeTwinRustAsyncJsonMapTwinRustAsyncConstMeta,
+      argValues: [map],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualJsonTypeTwinRustAsyncJsonMapTwinRustAsyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "json_map_twin_rust_async",
+            argNames: ["map"],
+          );
+
+  @override
+  Future<String>
+      crateApiPseudoManualJsonTypeTwinRustAsyncJsonValueToStringTwinRustAsync(
+          {required Object? value}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_JsonValue(value);
+        return wire
+            .wire__crate__api__pseudo_manual__json_type_twin_rust_async__json_value_to_string_twin_rust_async(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_String,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualJsonTypeTwinRustAsyncJsonValueToStringTwinRustAsyncConstMeta,
+      argValues: [value],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualJsonTypeTwinRustAsyncJsonValueToStringTwinRustAsyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "json_value_to_string_twin_rust_async",
+            argNames: ["value"],
+          );
+
+  @override
+  Future<Object?>
+      crateApiPseudoManualJsonTypeTwinRustAsyncJsonValueTwinRustAsync(
+          {required Object? value}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_JsonValue(value);
+        return wire
+            .wire__crate__api__pseudo_manual__json_type_twin_rust_async__json_value_twin_rust_async(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_JsonValue,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualJsonTypeTwinRustAsyncJsonValueTwinRustAsyncConstMeta,
+      argValues: [value],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualJsonTypeTwinRustAsyncJsonValueTwinRustAsyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "json_value_twin_rust_async",
+            argNames: ["value"],
+          );
+
+  @override
+  Future<List<Object?>>
+      crateApiPseudoManualJsonTypeTwinRustAsyncJsonValuesTwinRustAsync(
+          {required List<Object?> values}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_list_JsonValue(values);
+        return wire
+            .wire__crate__api__pseudo_manual__json_type_twin_rust_async__json_values_twin_rust_async(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_list_JsonValue,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualJsonTypeTwinRustAsyncJsonValuesTwinRustAsyncConstMeta,
+      argValues: [values],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualJsonTypeTwinRustAsyncJsonValuesTwinRustAsyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "json_values_twin_rust_async",
+            argNames: ["values"],
+          );
+
+  @override
+  Future<Object?>
+      crateApiPseudoManualJsonTypeTwinRustAsyncOptionalJsonValueTwinRustAsync(
+          {Object? value}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_opt_JsonValue(value);
+        return wire
+            .wire__crate__api__pseudo_manual__json_type_twin_rust_async__optional_json_value_twin_rust_async(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_opt_JsonValue,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualJsonTypeTwinRustAsyncOptionalJsonValueTwinRustAsyncConstMeta,
+      argValues: [value],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualJsonTypeTwinRustAsyncOptionalJsonValueTwinRustAsyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "optional_json_value_twin_rust_async",
+            argNames: ["value"],
+          );
+
+  @override
+  Future<Map<String, dynamic>>
+      crateApiPseudoManualJsonTypeTwinRustAsyncSseCreateJsonMapTwinRustAsyncSse() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__json_type_twin_rust_async_sse__create_json_map_twin_rust_async_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_JsonMap,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualJsonTypeTwinRustAsyncSseCreateJsonMapTwinRustAsyncSseConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualJsonTypeTwinRustAsyncSseCreateJsonMapTwinRustAsyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "create_json_map_twin_rust_async_sse",
+            argNames: [],
+          );
+
+  @override
+  Future<Object?>
+      crateApiPseudoManualJsonTypeTwinRustAsyncSseCreateJsonValueTwinRustAsyncSse() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__json_type_twin_rust_async_sse__create_json_value_twin_rust_async_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_JsonValue,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualJsonTypeTwinRustAsyncSseCreateJsonValueTwinRustAsyncSseConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualJsonTypeTwinRustAsyncSseCreateJsonValueTwinRustAsyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "create_json_value_twin_rust_async_sse",
+            argNames: [],
+          );
+
+  @override
+  Future<JsonDocumentTwinRustAsyncSse>
+      crateApiPseudoManualJsonTypeTwinRustAsyncSseJsonDocumentTwinRustAsyncSse(
+          {required JsonDocumentTwinRustAsyncSse document}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_json_document_twin_rust_async_sse(
+            document, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__json_type_twin_rust_async_sse__json_document_twin_rust_async_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_json_document_twin_rust_async_sse,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualJsonTypeTwinRustAsyncSseJsonDocumentTwinRustAsyncSseConstMeta,
+      argValues: [document],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualJsonTypeTwinRustAsyncSseJsonDocumentTwinRustAsyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "json_document_twin_rust_async_sse",
+            argNames: ["document"],
+          );
+
+  @override
+  Future<List<String>>
+      crateApiPseudoManualJsonTypeTwinRustAsyncSseJsonMapKeysTwinRustAsyncSse(
+          {required Map<String, dynamic> map}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_JsonMap(map, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__json_type_twin_rust_async_sse__json_map_keys_twin_rust_async_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_String,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualJsonTypeTwinRustAsyncSseJsonMapKeysTwinRustAsyncSseConstMeta,
+      argValues: [map],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualJsonTypeTwinRustAsyncSseJsonMapKeysTwinRustAsyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "json_map_keys_twin_rust_async_sse",
+            argNames: ["map"],
+          );
+
+  @override
+  Future<Map<String, dynamic>>
+      crateApiPseudoManualJsonTypeTwinRustAsyncSseJsonMapTwinRustAsyncSse(
+          {required Map<String, dynamic> map}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_JsonMap(map, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__json_type_twin_rust_async_sse__json_map_twin_rust_async_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_JsonMap,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualJsonTypeTwinRustAsyncSseJsonMapTwinRustAsyncSseConstMeta,
+      argValues: [map],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualJsonTypeTwinRustAsyncSseJsonMapTwinRustAsyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "json_map_twin_rust_async_sse",
+            argNames: ["map"],
+          );
+
+  @override
+  Future<String>
+      crateApiPseudoManualJsonTypeTwinRustAsyncSseJsonValueToStringTwinRustAsyncSse(
+          {required Object? value}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_JsonValue(value, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__json_type_twin_rust_async_sse__json_value_to_string_twin_rust_async_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualJsonTypeTwinRustAsyncSseJsonValueToStringTwinRustAsyncSseConstMeta,
+      argValues: [value],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualJsonTypeTwinRustAsyncSseJsonValueToStringTwinRustAsyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "json_value_to_string_twin_rust_async_sse",
+            argNames: ["value"],
+          );
+
+  @override
+  Future<Object?>
+      crateApiPseudoManualJsonTypeTwinRustAsyncSseJsonValueTwinRustAsyncSse(
+          {required Object? value}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_JsonValue(value, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__json_type_twin_rust_async_sse__json_value_twin_rust_async_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_JsonValue,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualJsonTypeTwinRustAsyncSseJsonValueTwinRustAsyncSseConstMeta,
+      argValues: [value],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualJsonTypeTwinRustAsyncSseJsonValueTwinRustAsyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "json_value_twin_rust_async_sse",
+            argNames: ["value"],
+          );
+
+  @override
+  Future<List<Object?>>
+      crateApiPseudoManualJsonTypeTwinRustAsyncSseJsonValuesTwinRustAsyncSse(
+          {required List<Object?> values}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_list_JsonValue(values, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__json_type_twin_rust_async_sse__json_values_twin_rust_async_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_JsonValue,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualJsonTypeTwinRustAsyncSseJsonValuesTwinRustAsyncSseConstMeta,
+      argValues: [values],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualJsonTypeTwinRustAsyncSseJsonValuesTwinRustAsyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "json_values_twin_rust_async_sse",
+            argNames: ["values"],
+          );
+
+  @override
+  Future<Object?>
+      crateApiPseudoManualJsonTypeTwinRustAsyncSseOptionalJsonValueTwinRustAsyncSse(
+          {Object? value}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_opt_JsonValue(value, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__json_type_twin_rust_async_sse__optional_json_value_twin_rust_async_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_JsonValue,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualJsonTypeTwinRustAsyncSseOptionalJsonValueTwinRustAsyncSseConstMeta,
+      argValues: [value],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualJsonTypeTwinRustAsyncSseOptionalJsonValueTwinRustAsyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "optional_json_value_twin_rust_async_sse",
+            argNames: ["value"],
+          );
+
+  @override
+  Future<Map<String, dynamic>>
+      crateApiPseudoManualJsonTypeTwinSseCreateJsonMapTwinSse() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__json_type_twin_sse__create_json_map_twin_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_JsonMap,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualJsonTypeTwinSseCreateJsonMapTwinSseConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualJsonTypeTwinSseCreateJsonMapTwinSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "create_json_map_twin_sse",
+            argNames: [],
+          );
+
+  @override
+  Future<Object?>
+      crateApiPseudoManualJsonTypeTwinSseCreateJsonValueTwinSse() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__json_type_twin_sse__create_json_value_twin_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_JsonValue,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualJsonTypeTwinSseCreateJsonValueTwinSseConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualJsonTypeTwinSseCreateJsonValueTwinSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "create_json_value_twin_sse",
+            argNames: [],
+          );
+
+  @override
+  Future<JsonDocumentTwinSse>
+      crateApiPseudoManualJsonTypeTwinSseJsonDocumentTwinSse(
+          {required JsonDocumentTwinSse document}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_json_document_twin_sse(document, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__json_type_twin_sse__json_document_twin_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_json_document_twin_sse,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualJsonTypeTwinSseJsonDocumentTwinSseConstMeta,
+      argValues: [document],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualJsonTypeTwinSseJsonDocumentTwinSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "json_document_twin_sse",
+            argNames: ["document"],
+          );
+
+  @override
+  Future<List<String>> crateApiPseudoManualJsonTypeTwinSseJsonMapKeysTwinSse(
+      {required Map<String, dynamic> map}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_JsonMap(map, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__json_type_twin_sse__json_map_keys_twin_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_String,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualJsonTypeTwinSseJsonMapKeysTwinSseConstMeta,
+      argValues: [map],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualJsonTypeTwinSseJsonMapKeysTwinSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "json_map_keys_twin_sse",
+            argNames: ["map"],
+          );
+
+  @override
+  Future<Map<String, dynamic>>
+      crateApiPseudoManualJsonTypeTwinSseJsonMapTwinSse(
+          {required Map<String, dynamic> map}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_JsonMap(map, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__json_type_twin_sse__json_map_twin_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_JsonMap,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualJsonTypeTwinSseJsonMapTwinSseConstMeta,
+      argValues: [map],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualJsonTypeTwinSseJsonMapTwinSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "json_map_twin_sse",
+            argNames: ["map"],
+          );
+
+  @override
+  Future<String> crateApiPseudoManualJsonTypeTwinSseJsonValueToStringTwinSse(
+      {required Object? value}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_JsonValue(value, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__json_type_twin_sse__json_value_to_string_twin_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualJsonTypeTwinSseJsonValueToStringTwinSseConstMeta,
+      argValues: [value],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualJsonTypeTwinSseJsonValueToStringTwinSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "json_value_to_string_twin_sse",
+            argNames: ["value"],
+          );
+
+  @override
+  Future<Object?> crateApiPseudoManualJsonTypeTwinSseJsonValueTwinSse(
+      {required Object? value}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_JsonValue(value, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__json_type_twin_sse__json_value_twin_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_JsonValue,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiPseudoManualJsonTypeTwinSseJsonValueTwinSseConstMeta,
+      argValues: [value],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualJsonTypeTwinSseJsonValueTwinSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "json_value_twin_sse",
+            argNames: ["value"],
+          );
+
+  @override
+  Future<List<Object?>> crateApiPseudoManualJsonTypeTwinSseJsonValuesTwinSse(
+      {required List<Object?> values}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_list_JsonValue(values, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__json_type_twin_sse__json_values_twin_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_JsonValue,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualJsonTypeTwinSseJsonValuesTwinSseConstMeta,
+      argValues: [values],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualJsonTypeTwinSseJsonValuesTwinSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "json_values_twin_sse",
+            argNames: ["values"],
+          );
+
+  @override
+  Future<Object?>
+      crateApiPseudoManualJsonTypeTwinSseOptionalJsonValueTwinSse(
+          {Object? value}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_opt_JsonValue(value, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__json_type_twin_sse__optional_json_value_twin_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_JsonValue,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualJsonTypeTwinSseOptionalJsonValueTwinSseConstMeta,
+      argValues: [value],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualJsonTypeTwinSseOptionalJsonValueTwinSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "optional_json_value_twin_sse",
+            argNames: ["value"],
+          );
+
+  @override
+  Map<String, dynamic>
+      crateApiPseudoManualJsonTypeTwinSyncCreateJsonMapTwinSync() {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        return wire
+            .wire__crate__api__pseudo_manual__json_type_twin_sync__create_json_map_twin_sync();
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_JsonMap,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualJsonTypeTwinSyncCreateJsonMapTwinSyncConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualJsonTypeTwinSyncCreateJsonMapTwinSyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "create_json_map_twin_sync",
+            argNames: [],
+          );
+
+  @override
+  Object? crateApiPseudoManualJsonTypeTwinSyncCreateJsonValueTwinSync() {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        return wire
+            .wire__crate__api__pseudo_manual__json_type_twin_sync__create_json_value_twin_sync();
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_JsonValue,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualJsonTypeTwinSyncCreateJsonValueTwinSyncConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualJsonTypeTwinSyncCreateJsonValueTwinSyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "create_json_value_twin_sync",
+            argNames: [],
+          );
+
+  @override
+  JsonDocumentTwinSync crateApiPseudoManualJsonTypeTwinSyncJsonDocumentTwinSync(
+      {required JsonDocumentTwinSync document}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        var arg0 = cst_encode_box_autoadd_json_document_twin_sync(document);
+        return wire
+            .wire__crate__api__pseudo_manual__json_type_twin_sync__json_document_twin_sync(
+                arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_json_document_twin_sync,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualJsonTypeTwinSyncJsonDocumentTwinSyncConstMeta,
+      argValues: [document],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualJsonTypeTwinSyncJsonDocumentTwinSyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "json_document_twin_sync",
+            argNames: ["document"],
+          );
+
+  @override
+  List<String> crateApiPseudoManualJsonTypeTwinSyncJsonMapKeysTwinSync(
+      {required Map<String, dynamic> map}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        var arg0 = cst_encode_JsonMap(map);
+        return wire
+            .wire__crate__api__pseudo_manual__json_type_twin_sync__json_map_keys_twin_sync(
+                arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_list_String,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualJsonTypeTwinSyncJsonMapKeysTwinSyncConstMeta,
+      argValues: [map],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualJsonTypeTwinSyncJsonMapKeysTwinSyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "json_map_keys_twin_sync",
+            argNames: ["map"],
+          );
+
+  @override
+  Map<String, dynamic> crateApiPseudoManualJsonTypeTwinSyncJsonMapTwinSync(
+      {required Map<String, dynamic> map}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        var arg0 = cst_encode_JsonMap(map);
+        return wire
+            .wire__crate__api__pseudo_manual__json_type_twin_sync__json_map_twin_sync(
+                arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_JsonMap,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiPseudoManualJsonTypeTwinSyncJsonMapTwinSyncConstMeta,
+      argValues: [map],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualJsonTypeTwinSyncJsonMapTwinSyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "json_map_twin_sync",
+            argNames: ["map"],
+          );
+
+  @override
+  String crateApiPseudoManualJsonTypeTwinSyncJsonValueToStringTwinSync(
+      {required Object? value}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        var arg0 = cst_encode_JsonValue(value);
+        return wire
+            .wire__crate__api__pseudo_manual__json_type_twin_sync__json_value_to_string_twin_sync(
+                arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_String,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualJsonTypeTwinSyncJsonValueToStringTwinSyncConstMeta,
+      argValues: [value],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualJsonTypeTwinSyncJsonValueToStringTwinSyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "json_value_to_string_twin_sync",
+            argNames: ["value"],
+          );
+
+  @override
+  Object? crateApiPseudoManualJsonTypeTwinSyncJsonValueTwinSync(
+      {required Object? value}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        var arg0 = cst_encode_JsonValue(value);
+        return wire
+            .wire__crate__api__pseudo_manual__json_type_twin_sync__json_value_twin_sync(
+                arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_JsonValue,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualJsonTypeTwinSyncJsonValueTwinSyncConstMeta,
+      argValues: [value],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualJsonTypeTwinSyncJsonValueTwinSyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "json_value_twin_sync",
+            argNames: ["value"],
+          );
+
+  @override
+  List<Object?> crateApiPseudoManualJsonTypeTwinSyncJsonValuesTwinSync(
+      {required List<Object?> values}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        var arg0 = cst_encode_list_JsonValue(values);
+        return wire
+            .wire__crate__api__pseudo_manual__json_type_twin_sync__json_values_twin_sync(
+                arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_list_JsonValue,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualJsonTypeTwinSyncJsonValuesTwinSyncConstMeta,
+      argValues: [values],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualJsonTypeTwinSyncJsonValuesTwinSyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "json_values_twin_sync",
+            argNames: ["values"],
+          );
+
+  @override
+  Object?
+      crateApiPseudoManualJsonTypeTwinSyncOptionalJsonValueTwinSync(
+          {Object? value}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        var arg0 = cst_encode_opt_JsonValue(value);
+        return wire
+            .wire__crate__api__pseudo_manual__json_type_twin_sync__optional_json_value_twin_sync(
+                arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_opt_JsonValue,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualJsonTypeTwinSyncOptionalJsonValueTwinSyncConstMeta,
+      argValues: [value],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualJsonTypeTwinSyncOptionalJsonValueTwinSyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "optional_json_value_twin_sync",
+            argNames: ["value"],
+          );
+
+  @override
+  Map<String, dynamic>
+      crateApiPseudoManualJsonTypeTwinSyncSseCreateJsonMapTwinSyncSse() {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__json_type_twin_sync_sse__create_json_map_twin_sync_sse(
+                raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_JsonMap,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualJsonTypeTwinSyncSseCreateJsonMapTwinSyncSseConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualJsonTypeTwinSyncSseCreateJsonMapTwinSyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "create_json_map_twin_sync_sse",
+            argNames: [],
+          );
+
+  @override
+  Object? crateApiPseudoManualJsonTypeTwinSyncSseCreateJsonValueTwinSyncSse() {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__json_type_twin_sync_sse__create_json_value_twin_sync_sse(
+                raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_JsonValue,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualJsonTypeTwinSyncSseCreateJsonValueTwinSyncSseConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualJsonTypeTwinSyncSseCreateJsonValueTwinSyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "create_json_value_twin_sync_sse",
+            argNames: [],
+          );
+
+  @override
+  JsonDocumentTwinSyncSse
+      crateApiPseudoManualJsonTypeTwinSyncSseJsonDocumentTwinSyncSse(
+          {required JsonDocumentTwinSyncSse document}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_json_document_twin_sync_sse(
+            document, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__json_type_twin_sync_sse__json_document_twin_sync_sse(
+                raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_json_document_twin_sync_sse,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualJsonTypeTwinSyncSseJsonDocumentTwinSyncSseConstMeta,
+      argValues: [document],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualJsonTypeTwinSyncSseJsonDocumentTwinSyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "json_document_twin_sync_sse",
+            argNames: ["document"],
+          );
+
+  @override
+  List<String> crateApiPseudoManualJsonTypeTwinSyncSseJsonMapKeysTwinSyncSse(
+      {required Map<String, dynamic> map}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_JsonMap(map, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__json_type_twin_sync_sse__json_map_keys_twin_sync_sse(
+                raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_String,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualJsonTypeTwinSyncSseJsonMapKeysTwinSyncSseConstMeta,
+      argValues: [map],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualJsonTypeTwinSyncSseJsonMapKeysTwinSyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "json_map_keys_twin_sync_sse",
+            argNames: ["map"],
+          );
+
+  @override
+  Map<String, dynamic>
+      crateApiPseudoManualJsonTypeTwinSyncSseJsonMapTwinSyncSse(
+          {required Map<String, dynamic> map}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_JsonMap(map, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__json_type_twin_sync_sse__json_map_twin_sync_sse(
+                raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_JsonMap,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualJsonTypeTwinSyncSseJsonMapTwinSyncSseConstMeta,
+      argValues: [map],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualJsonTypeTwinSyncSseJsonMapTwinSyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "json_map_twin_sync_sse",
+            argNames: ["map"],
+          );
+
+  @override
+  String crateApiPseudoManualJsonTypeTwinSyncSseJsonValueToStringTwinSyncSse(
+      {required Object? value}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_JsonValue(value, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__json_type_twin_sync_sse__json_value_to_string_twin_sync_sse(
+                raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualJsonTypeTwinSyncSseJsonValueToStringTwinSyncSseConstMeta,
+      argValues: [value],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualJsonTypeTwinSyncSseJsonValueToStringTwinSyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "json_value_to_string_twin_sync_sse",
+            argNames: ["value"],
+          );
+
+  @override
+  Object? crateApiPseudoManualJsonTypeTwinSyncSseJsonValueTwinSyncSse(
+      {required Object? value}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_JsonValue(value, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__json_type_twin_sync_sse__json_value_twin_sync_sse(
+                raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_JsonValue,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualJsonTypeTwinSyncSseJsonValueTwinSyncSseConstMeta,
+      argValues: [value],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualJsonTypeTwinSyncSseJsonValueTwinSyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "json_value_twin_sync_sse",
+            argNames: ["value"],
+          );
+
+  @override
+  List<Object?> crateApiPseudoManualJsonTypeTwinSyncSseJsonValuesTwinSyncSse(
+      {required List<Object?> values}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_list_JsonValue(values, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__json_type_twin_sync_sse__json_values_twin_sync_sse(
+                raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_JsonValue,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualJsonTypeTwinSyncSseJsonValuesTwinSyncSseConstMeta,
+      argValues: [values],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualJsonTypeTwinSyncSseJsonValuesTwinSyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "json_values_twin_sync_sse",
+            argNames: ["values"],
+          );
+
+  @override
+  Object?
+      crateApiPseudoManualJsonTypeTwinSyncSseOptionalJsonValueTwinSyncSse(
+          {Object? value}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_opt_JsonValue(value, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__json_type_twin_sync_sse__optional_json_value_twin_sync_sse(
+                raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_JsonValue,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualJsonTypeTwinSyncSseOptionalJsonValueTwinSyncSseConstMeta,
+      argValues: [value],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualJsonTypeTwinSyncSseOptionalJsonValueTwinSyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "optional_json_value_twin_sync_sse",
+            argNames: ["value"],
+          );
+
   @override
   String
       crateApiPseudoManualLifetimeableTwinSyncLifetimeableAutoOwnedRustOpaqueFlutterRustBridgeforGeneratedRustAutoOpaqueInnerLtNestedTypeWithLifetimeTwinSyncstaticGreetBorrowMutSelfTwinSync(
@@ -54191,6 +61127,122 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             argNames: ["arg"],
           );
 
+  @override
+  Future<Map<int, int>>
+      crateApiPseudoManualMapAndSetTwinRustAsyncFuncBtreeMapI32I32TwinRustAsync(
+          {required Map<int, int> arg}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_BTreeMap_i_32_i_32(arg);
+        return wire
+            .wire__crate__api__pseudo_manual__map_and_set_twin_rust_async__func_btree_map_i32_i32_twin_rust_async(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_BTreeMap_i_32_i_32,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualMapAndSetTwinRustAsyncFuncBtreeMapI32I32TwinRustAsyncConstMeta,
+      argValues: [arg],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualMapAndSetTwinRustAsyncFuncBtreeMapI32I32TwinRustAsyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "func_btree_map_i32_i32_twin_rust_async",
+            argNames: ["arg"],
+          );
+
+  @override
+  Future<Map<String, MySize>>
+      crateApiPseudoManualMapAndSetTwinRustAsyncFuncBtreeMapStringStructTwinRustAsync(
+          {required Map<String, MySize> arg}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_BTreeMap_String_my_size(arg);
+        return wire
+            .wire__crate__api__pseudo_manual__map_and_set_twin_rust_async__func_btree_map_string_struct_twin_rust_async(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_BTreeMap_String_my_size,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualMapAndSetTwinRustAsyncFuncBtreeMapStringStructTwinRustAsyncConstMeta,
+      argValues: [arg],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualMapAndSetTwinRustAsyncFuncBtreeMapStringStructTwinRustAsyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "func_btree_map_string_struct_twin_rust_async",
+            argNames: ["arg"],
+          );
+
+  @override
+  Future<Set<int>>
+      crateApiPseudoManualMapAndSetTwinRustAsyncFuncBtreeSetI32TwinRustAsync(
+          {required Set<int> arg}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_BTreeSet_i_32(arg);
+        return wire
+            .wire__crate__api__pseudo_manual__map_and_set_twin_rust_async__func_btree_set_i32_twin_rust_async(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_BTreeSet_i_32,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualMapAndSetTwinRustAsyncFuncBtreeSetI32TwinRustAsyncConstMeta,
+      argValues: [arg],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualMapAndSetTwinRustAsyncFuncBtreeSetI32TwinRustAsyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "func_btree_set_i32_twin_rust_async",
+            argNames: ["arg"],
+          );
+
+  @override
+  Future<Set<String>>
+      crateApiPseudoManualMapAndSetTwinRustAsyncFuncBtreeSetStringTwinRustAsync(
+          {required Set<String> arg}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_BTreeSet_String(arg);
+        return wire
+            .wire__crate__api__pseudo_manual__map_and_set_twin_rust_async__func_btree_set_string_twin_rust_async(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_BTreeSet_String,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualMapAndSetTwinRustAsyncFuncBtreeSetStringTwinRustAsyncConstMeta,
+      argValues: [arg],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualMapAndSetTwinRustAsyncFuncBtreeSetStringTwinRustAsyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "func_btree_set_string_twin_rust_async",
+            argNames: ["arg"],
+          );
+
   @override
   Future<Map<int, int>>
       crateApiPseudoManualMapAndSetTwinRustAsyncFuncHashMapI32I32TwinRustAsync(
@@ -54423,6 +61475,188 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             argNames: ["arg"],
           );
 
+  @override
+  Future<Int32List>
+      crateApiPseudoManualMapAndSetTwinRustAsyncFuncVecDequeI32TwinRustAsync(
+          {required Int32List arg}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_VecDeque_i_32(arg);
+        return wire
+            .wire__crate__api__pseudo_manual__map_and_set_twin_rust_async__func_vec_deque_i32_twin_rust_async(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_VecDeque_i_32,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualMapAndSetTwinRustAsyncFuncVecDequeI32TwinRustAsyncConstMeta,
+      argValues: [arg],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualMapAndSetTwinRustAsyncFuncVecDequeI32TwinRustAsyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "func_vec_deque_i32_twin_rust_async",
+            argNames: ["arg"],
+          );
+
+  @override
+  Future<List<String>>
+      crateApiPseudoManualMapAndSetTwinRustAsyncFuncVecDequeStringTwinRustAsync(
+          {required List<String> arg}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_VecDeque_String(arg);
+        return wire
+            .wire__crate__api__pseudo_manual__map_and_set_twin_rust_async__func_vec_deque_string_twin_rust_async(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_VecDeque_String,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualMapAndSetTwinRustAsyncFuncVecDequeStringTwinRustAsyncConstMeta,
+      argValues: [arg],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualMapAndSetTwinRustAsyncFuncVecDequeStringTwinRustAsyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "func_vec_deque_string_twin_rust_async",
+            argNames: ["arg"],
+          );
+
+  @override
+  Future<Map<int, int>>
+      crateApiPseudoManualMapAndSetTwinRustAsyncSseFuncBtreeMapI32I32TwinRustAsyncSse(
+          {required Map<int, int> arg}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_BTreeMap_i_32_i_32(arg, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__map_and_set_twin_rust_async_sse__func_btree_map_i32_i32_twin_rust_async_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_BTreeMap_i_32_i_32,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualMapAndSetTwinRustAsyncSseFuncBtreeMapI32I32TwinRustAsyncSseConstMeta,
+      argValues: [arg],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualMapAndSetTwinRustAsyncSseFuncBtreeMapI32I32TwinRustAsyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "func_btree_map_i32_i32_twin_rust_async_sse",
+            argNames: ["arg"],
+          );
+
+  @override
+  Future<Map<String, MySize>>
+      crateApiPseudoManualMapAndSetTwinRustAsyncSseFuncBtreeMapStringStructTwinRustAsyncSse(
+          {required Map<String, MySize> arg}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_BTreeMap_String_my_size(arg, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__map_and_set_twin_rust_async_sse__func_btree_map_string_struct_twin_rust_async_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_BTreeMap_String_my_size,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualMapAndSetTwinRustAsyncSseFuncBtreeMapStringStructTwinRustAsyncSseConstMeta,
+      argValues: [arg],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualMapAndSetTwinRustAsyncSseFuncBtreeMapStringStructTwinRustAsyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "func_btree_map_string_struct_twin_rust_async_sse",
+            argNames: ["arg"],
+          );
+
+  @override
+  Future<Set<int>>
+      crateApiPseudoManualMapAndSetTwinRustAsyncSseFuncBtreeSetI32TwinRustAsyncSse(
+          {required Set<int> arg}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_BTreeSet_i_32(arg, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__map_and_set_twin_rust_async_sse__func_btree_set_i32_twin_rust_async_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_BTreeSet_i_32,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualMapAndSetTwinRustAsyncSseFuncBtreeSetI32TwinRustAsyncSseConstMeta,
+      argValues: [arg],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualMapAndSetTwinRustAsyncSseFuncBtreeSetI32TwinRustAsyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "func_btree_set_i32_twin_rust_async_sse",
+            argNames: ["arg"],
+          );
+
+  @override
+  Future<Set<String>>
+      crateApiPseudoManualMapAndSetTwinRustAsyncSseFuncBtreeSetStringTwinRustAsyncSse(
+          {required Set<String> arg}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_BTreeSet_String(arg, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__map_and_set_twin_rust_async_sse__func_btree_set_string_twin_rust_async_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_BTreeSet_String,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualMapAndSetTwinRustAsyncSseFuncBtreeSetStringTwinRustAsyncSseConstMeta,
+      argValues: [arg],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualMapAndSetTwinRustAsyncSseFuncBtreeSetStringTwinRustAsyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "func_btree_set_string_twin_rust_async_sse",
+            argNames: ["arg"],
+          );
+
   @override
   Future<Map<int, int>>
       crateApiPseudoManualMapAndSetTwinRustAsyncSseFuncHashMapI32I32TwinRustAsyncSse(
@@ -54673,6 +61907,191 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             argNames: ["arg"],
           );
 
+  @override
+  Future<Int32List>
+      crateApiPseudoManualMapAndSetTwinRustAsyncSseFuncVecDequeI32TwinRustAsyncSse(
+          {required Int32List arg}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_VecDeque_i_32(arg, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__map_and_set_twin_rust_async_sse__func_vec_deque_i32_twin_rust_async_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_VecDeque_i_32,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualMapAndSetTwinRustAsyncSseFuncVecDequeI32TwinRustAsyncSseConstMeta,
+      argValues: [arg],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualMapAndSetTwinRustAsyncSseFuncVecDequeI32TwinRustAsyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "func_vec_deque_i32_twin_rust_async_sse",
+            argNames: ["arg"],
+          );
+
+  @override
+  Future<List<String>>
+      crateApiPseudoManualMapAndSetTwinRustAsyncSseFuncVecDequeStringTwinRustAsyncSse(
+          {required List<String> arg}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_VecDeque_String(arg, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__map_and_set_twin_rust_async_sse__func_vec_deque_string_twin_rust_async_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_VecDeque_String,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualMapAndSetTwinRustAsyncSseFuncVecDequeStringTwinRustAsyncSseConstMeta,
+      argValues: [arg],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualMapAndSetTwinRustAsyncSseFuncVecDequeStringTwinRustAsyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "func_vec_deque_string_twin_rust_async_sse",
+            argNames: ["arg"],
+          );
+
+  @override
+  Future<Map<int, int>>
+      crateApiPseudoManualMapAndSetTwinSseFuncBtreeMapI32I32TwinSse(
+          {required Map<int, int> arg}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_BTreeMap_i_32_i_32(arg, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__map_and_set_twin_sse__func_btree_map_i32_i32_twin_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_BTreeMap_i_32_i_32,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualMapAndSetTwinSseFuncBtreeMapI32I32TwinSseConstMeta,
+      argValues: [arg],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualMapAndSetTwinSseFuncBtreeMapI32I32TwinSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "func_btree_map_i32_i32_twin_sse",
+            argNames: ["arg"],
+          );
+
+  @override
+  Future<Map<String, MySize>>
+      crateApiPseudoManualMapAndSetTwinSseFuncBtreeMapStringStructTwinSse(
+          {required Map<String, MySize> arg}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_BTreeMap_String_my_size(arg, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__map_and_set_twin_sse__func_btree_map_string_struct_twin_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_BTreeMap_String_my_size,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualMapAndSetTwinSseFuncBtreeMapStringStructTwinSseConstMeta,
+      argValues: [arg],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualMapAndSetTwinSseFuncBtreeMapStringStructTwinSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "func_btree_map_string_struct_twin_sse",
+            argNames: ["arg"],
+          );
+
+  @override
+  Future<Set<int>> crateApiPseudoManualMapAndSetTwinSseFuncBtreeSetI32TwinSse(
+      {required Set<int> arg}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_BTreeSet_i_32(arg, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__map_and_set_twin_sse__func_btree_set_i32_twin_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_BTreeSet_i_32,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualMapAndSetTwinSseFuncBtreeSetI32TwinSseConstMeta,
+      argValues: [arg],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualMapAndSetTwinSseFuncBtreeSetI32TwinSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "func_btree_set_i32_twin_sse",
+            argNames: ["arg"],
+          );
+
+  @override
+  Future<Set<String>>
+      crateApiPseudoManualMapAndSetTwinSseFuncBtreeSetStringTwinSse(
+          {required Set<String> arg}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_BTreeSet_String(arg, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__map_and_set_twin_sse__func_btree_set_string_twin_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_BTreeSet_String,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualMapAndSetTwinSseFuncBtreeSetStringTwinSseConstMeta,
+      argValues: [arg],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualMapAndSetTwinSseFuncBtreeSetStringTwinSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "func_btree_set_string_twin_sse",
+            argNames: ["arg"],
+          );
+
   @override
   Future<Map<int, int>>
       crateApiPseudoManualMapAndSetTwinSseFuncHashMapI32I32TwinSse(
@@ -54920,6 +62339,180 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             argNames: ["arg"],
           );
 
+  @override
+  Future<Int32List> crateApiPseudoManualMapAndSetTwinSseFuncVecDequeI32TwinSse(
+      {required Int32List arg}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_VecDeque_i_32(arg, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__map_and_set_twin_sse__func_vec_deque_i32_twin_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_VecDeque_i_32,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualMapAndSetTwinSseFuncVecDequeI32TwinSseConstMeta,
+      argValues: [arg],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualMapAndSetTwinSseFuncVecDequeI32TwinSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "func_vec_deque_i32_twin_sse",
+            argNames: ["arg"],
+          );
+
+  @override
+  Future<List<String>>
+      crateApiPseudoManualMapAndSetTwinSseFuncVecDequeStringTwinSse(
+          {required List<String> arg}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_VecDeque_String(arg, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__map_and_set_twin_sse__func_vec_deque_string_twin_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_VecDeque_String,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualMapAndSetTwinSseFuncVecDequeStringTwinSseConstMeta,
+      argValues: [arg],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualMapAndSetTwinSseFuncVecDequeStringTwinSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "func_vec_deque_string_twin_sse",
+            argNames: ["arg"],
+          );
+
+  @override
+  Map<int, int> crateApiPseudoManualMapAndSetTwinSyncFuncBtreeMapI32I32TwinSync(
+      {required Map<int, int> arg}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        var arg0 = cst_encode_BTreeMap_i_32_i_32(arg);
+        return wire
+            .wire__crate__api__pseudo_manual__map_and_set_twin_sync__func_btree_map_i32_i32_twin_sync(
+                arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_BTreeMap_i_32_i_32,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualMapAndSetTwinSyncFuncBtreeMapI32I32TwinSyncConstMeta,
+      argValues: [arg],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualMapAndSetTwinSyncFuncBtreeMapI32I32TwinSyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "func_btree_map_i32_i32_twin_sync",
+            argNames: ["arg"],
+          );
+
+  @override
+  Map<String, MySize>
+      crateApiPseudoManualMapAndSetTwinSyncFuncBtreeMapStringStructTwinSync(
+          {required Map<String, MySize> arg}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        var arg0 = cst_encode_BTreeMap_String_my_size(arg);
+        return wire
+            .wire__crate__api__pseudo_manual__map_and_set_twin_sync__func_btree_map_string_struct_twin_sync(
+                arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_BTreeMap_String_my_size,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualMapAndSetTwinSyncFuncBtreeMapStringStructTwinSyncConstMeta,
+      argValues: [arg],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualMapAndSetTwinSyncFuncBtreeMapStringStructTwinSyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "func_btree_map_string_struct_twin_sync",
+            argNames: ["arg"],
+          );
+
+  @override
+  Set<int> crateApiPseudoManualMapAndSetTwinSyncFuncBtreeSetI32TwinSync(
+      {required Set<int> arg}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        var arg0 = cst_encode_BTreeSet_i_32(arg);
+        return wire
+            .wire__crate__api__pseudo_manual__map_and_set_twin_sync__func_btree_set_i32_twin_sync(
+                arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_BTreeSet_i_32,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualMapAndSetTwinSyncFuncBtreeSetI32TwinSyncConstMeta,
+      argValues: [arg],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualMapAndSetTwinSyncFuncBtreeSetI32TwinSyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "func_btree_set_i32_twin_sync",
+            argNames: ["arg"],
+          );
+
+  @override
+  Set<String> crateApiPseudoManualMapAndSetTwinSyncFuncBtreeSetStringTwinSync(
+      {required Set<String> arg}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        var arg0 = cst_encode_BTreeSet_String(arg);
+        return wire
+            .wire__crate__api__pseudo_manual__map_and_set_twin_sync__func_btree_set_string_twin_sync(
+                arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_BTreeSet_String,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualMapAndSetTwinSyncFuncBtreeSetStringTwinSyncConstMeta,
+      argValues: [arg],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualMapAndSetTwinSyncFuncBtreeSetStringTwinSyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "func_btree_set_string_twin_sync",
+            argNames: ["arg"],
+          );
+
   @override
   Map<int, int> crateApiPseudoManualMapAndSetTwinSyncFuncHashMapI32I32TwinSync(
       {required Map<int, int> arg}) {
@@ -55149,6 +62742,185 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             argNames: ["arg"],
           );
 
+  @override
+  Int32List crateApiPseudoManualMapAndSetTwinSyncFuncVecDequeI32TwinSync(
+      {required Int32List arg}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        var arg0 = cst_encode_VecDeque_i_32(arg);
+        return wire
+            .wire__crate__api__pseudo_manual__map_and_set_twin_sync__func_vec_deque_i32_twin_sync(
+                arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_VecDeque_i_32,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualMapAndSetTwinSyncFuncVecDequeI32TwinSyncConstMeta,
+      argValues: [arg],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualMapAndSetTwinSyncFuncVecDequeI32TwinSyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "func_vec_deque_i32_twin_sync",
+            argNames: ["arg"],
+          );
+
+  @override
+  List<String> crateApiPseudoManualMapAndSetTwinSyncFuncVecDequeStringTwinSync(
+      {required List<String> arg}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        var arg0 = cst_encode_VecDeque_String(arg);
+        return wire
+            .wire__crate__api__pseudo_manual__map_and_set_twin_sync__func_vec_deque_string_twin_sync(
+                arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_VecDeque_String,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualMapAndSetTwinSyncFuncVecDequeStringTwinSyncConstMeta,
+      argValues: [arg],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualMapAndSetTwinSyncFuncVecDequeStringTwinSyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "func_vec_deque_string_twin_sync",
+            argNames: ["arg"],
+          );
+
+  @override
+  Map<int, int>
+      crateApiPseudoManualMapAndSetTwinSyncSseFuncBtreeMapI32I32TwinSyncSse(
+          {required Map<int, int> arg}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_BTreeMap_i_32_i_32(arg, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__map_and_set_twin_sync_sse__func_btree_map_i32_i32_twin_sync_sse(
+                raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_BTreeMap_i_32_i_32,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualMapAndSetTwinSyncSseFuncBtreeMapI32I32TwinSyncSseConstMeta,
+      argValues: [arg],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualMapAndSetTwinSyncSseFuncBtreeMapI32I32TwinSyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "func_btree_map_i32_i32_twin_sync_sse",
+            argNames: ["arg"],
+          );
+
+  @override
+  Map<String, MySize>
+      crateApiPseudoManualMapAndSetTwinSyncSseFuncBtreeMapStringStructTwinSyncSse(
+          {required Map<String, MySize> arg}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_BTreeMap_String_my_size(arg, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__map_and_set_twin_sync_sse__func_btree_map_string_struct_twin_sync_sse(
+                raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_BTreeMap_String_my_size,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualMapAndSetTwinSyncSseFuncBtreeMapStringStructTwinSyncSseConstMeta,
+      argValues: [arg],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualMapAndSetTwinSyncSseFuncBtreeMapStringStructTwinSyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "func_btree_map_string_struct_twin_sync_sse",
+            argNames: ["arg"],
+          );
+
+  @override
+  Set<int> crateApiPseudoManualMapAndSetTwinSyncSseFuncBtreeSetI32TwinSyncSse(
+      {required Set<int> arg}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_BTreeSet_i_32(arg, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__map_and_set_twin_sync_sse__func_btree_set_i32_twin_sync_sse(
+                raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_BTreeSet_i_32,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualMapAndSetTwinSyncSseFuncBtreeSetI32TwinSyncSseConstMeta,
+      argValues: [arg],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualMapAndSetTwinSyncSseFuncBtreeSetI32TwinSyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "func_btree_set_i32_twin_sync_sse",
+            argNames: ["arg"],
+          );
+
+  @override
+  Set<String>
+      crateApiPseudoManualMapAndSetTwinSyncSseFuncBtreeSetStringTwinSyncSse(
+          {required Set<String> arg}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_BTreeSet_String(arg, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__map_and_set_twin_sync_sse__func_btree_set_string_twin_sync_sse(
+                raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_BTreeSet_String,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualMapAndSetTwinSyncSseFuncBtreeSetStringTwinSyncSseConstMeta,
+      argValues: [arg],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualMapAndSetTwinSyncSseFuncBtreeSetStringTwinSyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "func_btree_set_string_twin_sync_sse",
+            argNames: ["arg"],
+          );
+
   @override
   Map<int, int>
       crateApiPseudoManualMapAndSetTwinSyncSseFuncHashMapI32I32TwinSyncSse(
@@ -55396,6 +63168,67 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             argNames: ["arg"],
           );
 
+  @override
+  Int32List crateApiPseudoManualMapAndSetTwinSyncSseFuncVecDequeI32TwinSyncSse(
+      {required Int32List arg}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_VecDeque_i_32(arg, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__map_and_set_twin_sync_sse__func_vec_deque_i32_twin_sync_sse(
+                raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_VecDeque_i_32,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualMapAndSetTwinSyncSseFuncVecDequeI32TwinSyncSseConstMeta,
+      argValues: [arg],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualMapAndSetTwinSyncSseFuncVecDequeI32TwinSyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "func_vec_deque_i32_twin_sync_sse",
+            argNames: ["arg"],
+          );
+
+  @override
+  List<String>
+      crateApiPseudoManualMapAndSetTwinSyncSseFuncVecDequeStringTwinSyncSse(
+          {required List<String> arg}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_VecDeque_String(arg, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__map_and_set_twin_sync_sse__func_vec_deque_string_twin_sync_sse(
+                raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_VecDeque_String,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualMapAndSetTwinSyncSseFuncVecDequeStringTwinSyncSseConstMeta,
+      argValues: [arg],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualMapAndSetTwinSyncSseFuncVecDequeStringTwinSyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "func_vec_deque_string_twin_sync_sse",
+            argNames: ["arg"],
+          );
+
   @override
   Future<int>
       crateApiPseudoManualMethodTwinRustAsyncStaticGetterOnlyTwinRustAsyncStaticGetterTwinRustAsync() {
@@ -89160,6 +96993,1050 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             argNames: ["a", "b"],
           );
 
+  @override
+  Future<Duration>
+      crateApiPseudoManualStdTimeTwinRustAsyncStdDurationTwinRustAsync(
+          {required Duration d}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_StdDuration(d);
+        return wire
+            .wire__crate__api__pseudo_manual__std_time_twin_rust_async__std_duration_twin_rust_async(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_StdDuration,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualStdTimeTwinRustAsyncStdDurationTwinRustAsyncConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualStdTimeTwinRustAsyncStdDurationTwinRustAsyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "std_duration_twin_rust_async",
+            argNames: ["d"],
+          );
+
+  @override
+  Future<List<Duration>>
+      crateApiPseudoManualStdTimeTwinRustAsyncStdDurationsTwinRustAsync(
+          {required List<Duration> durations}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_list_StdDuration(durations);
+        return wire
+            .wire__crate__api__pseudo_manual__std_time_twin_rust_async__std_durations_twin_rust_async(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_list_StdDuration,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualStdTimeTwinRustAsyncStdDurationsTwinRustAsyncConstMeta,
+      argValues: [durations],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualStdTimeTwinRustAsyncStdDurationsTwinRustAsyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "std_durations_twin_rust_async",
+            argNames: ["durations"],
+          );
+
+  @override
+  Future<Duration?>
+      crateApiPseudoManualStdTimeTwinRustAsyncStdOptionalDurationTwinRustAsync(
+          {Duration? d}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_opt_box_autoadd_StdDuration(d);
+        return wire
+            .wire__crate__api__pseudo_manual__std_time_twin_rust_async__std_optional_duration_twin_rust_async(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_opt_box_autoadd_StdDuration,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualStdTimeTwinRustAsyncStdOptionalDurationTwinRustAsyncConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualStdTimeTwinRustAsyncStdOptionalDurationTwinRustAsyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "std_optional_duration_twin_rust_async",
+            argNames: ["d"],
+          );
+
+  @override
+  Future<DateTime?>
+      crateApiPseudoManualStdTimeTwinRustAsyncStdOptionalSystemTimeTwinRustAsync(
+          {DateTime? t}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_opt_box_autoadd_StdSystemTime(t);
+        return wire
+            .wire__crate__api__pseudo_manual__std_time_twin_rust_async__std_optional_system_time_twin_rust_async(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_opt_box_autoadd_StdSystemTime,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualStdTimeTwinRustAsyncStdOptionalSystemTimeTwinRustAsyncConstMeta,
+      argValues: [t],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualStdTimeTwinRustAsyncStdOptionalSystemTimeTwinRustAsyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "std_optional_system_time_twin_rust_async",
+            argNames: ["t"],
+          );
+
+  @override
+  Future<DateTime>
+      crateApiPseudoManualStdTimeTwinRustAsyncStdSystemTimeTwinRustAsync(
+          {required DateTime t}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_StdSystemTime(t);
+        return wire
+            .wire__crate__api__pseudo_manual__std_time_twin_rust_async__std_system_time_twin_rust_async(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_StdSystemTime,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualStdTimeTwinRustAsyncStdSystemTimeTwinRustAsyncConstMeta,
+      argValues: [t],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualStdTimeTwinRustAsyncStdSystemTimeTwinRustAsyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "std_system_time_twin_rust_async",
+            argNames: ["t"],
+          );
+
+  @override
+  Future<List<DateTime>>
+      crateApiPseudoManualStdTimeTwinRustAsyncStdSystemTimesTwinRustAsync(
+          {required List<DateTime> times}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_list_StdSystemTime(times);
+        return wire
+            .wire__crate__api__pseudo_manual__std_time_twin_rust_async__std_system_times_twin_rust_async(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_list_StdSystemTime,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualStdTimeTwinRustAsyncStdSystemTimesTwinRustAsyncConstMeta,
+      argValues: [times],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualStdTimeTwinRustAsyncStdSystemTimesTwinRustAsyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "std_system_times_twin_rust_async",
+            argNames: ["times"],
+          );
+
+  @override
+  Future<StdTimeTwinRustAsync>
+      crateApiPseudoManualStdTimeTwinRustAsyncStdTimeStructTwinRustAsync(
+          {required StdTimeTwinRustAsync value}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_box_autoadd_std_time_twin_rust_async(value);
+        return wire
+            .wire__crate__api__pseudo_manual__std_time_twin_rust_async__std_time_struct_twin_rust_async(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_std_time_twin_rust_async,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualStdTimeTwinRustAsyncStdTimeStructTwinRustAsyncConstMeta,
+      argValues: [value],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualStdTimeTwinRustAsyncStdTimeStructTwinRustAsyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "std_time_struct_twin_rust_async",
+            argNames: ["value"],
+          );
+
+  @override
+  Future<Duration>
+      crateApiPseudoManualStdTimeTwinRustAsyncSseStdDurationTwinRustAsyncSse(
+          {required Duration d}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_StdDuration(d, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__std_time_twin_rust_async_sse__std_duration_twin_rust_async_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_StdDuration,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualStdTimeTwinRustAsyncSseStdDurationTwinRustAsyncSseConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualStdTimeTwinRustAsyncSseStdDurationTwinRustAsyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "std_duration_twin_rust_async_sse",
+            argNames: ["d"],
+          );
+
+  @override
+  Future<List<Duration>>
+      crateApiPseudoManualStdTimeTwinRustAsyncSseStdDurationsTwinRustAsyncSse(
+          {required List<Duration> durations}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_list_StdDuration(durations, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__std_time_twin_rust_async_sse__std_durations_twin_rust_async_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_StdDuration,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualStdTimeTwinRustAsyncSseStdDurationsTwinRustAsyncSseConstMeta,
+      argValues: [durations],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualStdTimeTwinRustAsyncSseStdDurationsTwinRustAsyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "std_durations_twin_rust_async_sse",
+            argNames: ["durations"],
+          );
+
+  @override
+  Future<Duration?>
+      crateApiPseudoManualStdTimeTwinRustAsyncSseStdOptionalDurationTwinRustAsyncSse(
+          {Duration? d}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_opt_box_autoadd_StdDuration(d, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__std_time_twin_rust_async_sse__std_optional_duration_twin_rust_async_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_box_autoadd_StdDuration,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualStdTimeTwinRustAsyncSseStdOptionalDurationTwinRustAsyncSseConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualStdTimeTwinRustAsyncSseStdOptionalDurationTwinRustAsyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "std_optional_duration_twin_rust_async_sse",
+            argNames: ["d"],
+          );
+
+  @override
+  Future<DateTime?>
+      crateApiPseudoManualStdTimeTwinRustAsyncSseStdOptionalSystemTimeTwinRustAsyncSse(
+          {DateTime? t}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_opt_box_autoadd_StdSystemTime(t, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__std_time_twin_rust_async_sse__std_optional_system_time_twin_rust_async_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_box_autoadd_StdSystemTime,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualStdTimeTwinRustAsyncSseStdOptionalSystemTimeTwinRustAsyncSseConstMeta,
+      argValues: [t],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualStdTimeTwinRustAsyncSseStdOptionalSystemTimeTwinRustAsyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "std_optional_system_time_twin_rust_async_sse",
+            argNames: ["t"],
+          );
+
+  @override
+  Future<DateTime>
+      crateApiPseudoManualStdTimeTwinRustAsyncSseStdSystemTimeTwinRustAsyncSse(
+          {required DateTime t}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_StdSystemTime(t, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__std_time_twin_rust_async_sse__std_system_time_twin_rust_async_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_StdSystemTime,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualStdTimeTwinRustAsyncSseStdSystemTimeTwinRustAsyncSseConstMeta,
+      argValues: [t],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualStdTimeTwinRustAsyncSseStdSystemTimeTwinRustAsyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "std_system_time_twin_rust_async_sse",
+            argNames: ["t"],
+          );
+
+  @override
+  Future<List<DateTime>>
+      crateApiPseudoManualStdTimeTwinRustAsyncSseStdSystemTimesTwinRustAsyncSse(
+          {required List<DateTime> times}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_list_StdSystemTime(times, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__std_time_twin_rust_async_sse__std_system_times_twin_rust_async_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_StdSystemTime,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualStdTimeTwinRustAsyncSseStdSystemTimesTwinRustAsyncSseConstMeta,
+      argValues: [times],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualStdTimeTwinRustAsyncSseStdSystemTimesTwinRustAsyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "std_system_times_twin_rust_async_sse",
+            argNames: ["times"],
+          );
+
+  @override
+  Future<StdTimeTwinRustAsyncSse>
+      crateApiPseudoManualStdTimeTwinRustAsyncSseStdTimeStructTwinRustAsyncSse(
+          {required StdTimeTwinRustAsyncSse value}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_std_time_twin_rust_async_sse(value, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__std_time_twin_rust_async_sse__std_time_struct_twin_rust_async_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_std_time_twin_rust_async_sse,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualStdTimeTwinRustAsyncSseStdTimeStructTwinRustAsyncSseConstMeta,
+      argValues: [value],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualStdTimeTwinRustAsyncSseStdTimeStructTwinRustAsyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "std_time_struct_twin_rust_async_sse",
+            argNames: ["value"],
+          );
+
+  @override
+  Future<Duration> crateApiPseudoManualStdTimeTwinSseStdDurationTwinSse(
+      {required Duration d}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_StdDuration(d, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__std_time_twin_sse__std_duration_twin_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_StdDuration,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiPseudoManualStdTimeTwinSseStdDurationTwinSseConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualStdTimeTwinSseStdDurationTwinSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "std_duration_twin_sse",
+            argNames: ["d"],
+          );
+
+  @override
+  Future<List<Duration>> crateApiPseudoManualStdTimeTwinSseStdDurationsTwinSse(
+      {required List<Duration> durations}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_list_StdDuration(durations, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__std_time_twin_sse__std_durations_twin_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_StdDuration,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualStdTimeTwinSseStdDurationsTwinSseConstMeta,
+      argValues: [durations],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualStdTimeTwinSseStdDurationsTwinSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "std_durations_twin_sse",
+            argNames: ["durations"],
+          );
+
+  @override
+  Future<Duration?>
+      crateApiPseudoManualStdTimeTwinSseStdOptionalDurationTwinSse(
+          {Duration? d}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_opt_box_autoadd_StdDuration(d, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__std_time_twin_sse__std_optional_duration_twin_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_box_autoadd_StdDuration,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualStdTimeTwinSseStdOptionalDurationTwinSseConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualStdTimeTwinSseStdOptionalDurationTwinSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "std_optional_duration_twin_sse",
+            argNames: ["d"],
+          );
+
+  @override
+  Future<DateTime?>
+      crateApiPseudoManualStdTimeTwinSseStdOptionalSystemTimeTwinSse(
+          {DateTime? t}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_opt_box_autoadd_StdSystemTime(t, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__std_time_twin_sse__std_optional_system_time_twin_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_box_autoadd_StdSystemTime,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualStdTimeTwinSseStdOptionalSystemTimeTwinSseConstMeta,
+      argValues: [t],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualStdTimeTwinSseStdOptionalSystemTimeTwinSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "std_optional_system_time_twin_sse",
+            argNames: ["t"],
+          );
+
+  @override
+  Future<DateTime> crateApiPseudoManualStdTimeTwinSseStdSystemTimeTwinSse(
+      {required DateTime t}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_StdSystemTime(t, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__std_time_twin_sse__std_system_time_twin_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_StdSystemTime,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualStdTimeTwinSseStdSystemTimeTwinSseConstMeta,
+      argValues: [t],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualStdTimeTwinSseStdSystemTimeTwinSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "std_system_time_twin_sse",
+            argNames: ["t"],
+          );
+
+  @override
+  Future<List<DateTime>>
+      crateApiPseudoManualStdTimeTwinSseStdSystemTimesTwinSse(
+          {required List<DateTime> times}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_list_StdSystemTime(times, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__std_time_twin_sse__std_system_times_twin_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_StdSystemTime,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualStdTimeTwinSseStdSystemTimesTwinSseConstMeta,
+      argValues: [times],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualStdTimeTwinSseStdSystemTimesTwinSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "std_system_times_twin_sse",
+            argNames: ["times"],
+          );
+
+  @override
+  Future<StdTimeTwinSse> crateApiPseudoManualStdTimeTwinSseStdTimeStructTwinSse(
+      {required StdTimeTwinSse value}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_std_time_twin_sse(value, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__std_time_twin_sse__std_time_struct_twin_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_std_time_twin_sse,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualStdTimeTwinSseStdTimeStructTwinSseConstMeta,
+      argValues: [value],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualStdTimeTwinSseStdTimeStructTwinSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "std_time_struct_twin_sse",
+            argNames: ["value"],
+          );
+
+  @override
+  Duration crateApiPseudoManualStdTimeTwinSyncStdDurationTwinSync(
+      {required Duration d}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        var arg0 = cst_encode_StdDuration(d);
+        return wire
+            .wire__crate__api__pseudo_manual__std_time_twin_sync__std_duration_twin_sync(
+                arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_StdDuration,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualStdTimeTwinSyncStdDurationTwinSyncConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualStdTimeTwinSyncStdDurationTwinSyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "std_duration_twin_sync",
+            argNames: ["d"],
+          );
+
+  @override
+  List<Duration> crateApiPseudoManualStdTimeTwinSyncStdDurationsTwinSync(
+      {required List<Duration> durations}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        var arg0 = cst_encode_list_StdDuration(durations);
+        return wire
+            .wire__crate__api__pseudo_manual__std_time_twin_sync__std_durations_twin_sync(
+                arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_list_StdDuration,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualStdTimeTwinSyncStdDurationsTwinSyncConstMeta,
+      argValues: [durations],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualStdTimeTwinSyncStdDurationsTwinSyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "std_durations_twin_sync",
+            argNames: ["durations"],
+          );
+
+  @override
+  Duration?
+      crateApiPseudoManualStdTimeTwinSyncStdOptionalDurationTwinSync(
+          {Duration? d}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        var arg0 = cst_encode_opt_box_autoadd_StdDuration(d);
+        return wire
+            .wire__crate__api__pseudo_manual__std_time_twin_sync__std_optional_duration_twin_sync(
+                arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_opt_box_autoadd_StdDuration,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualStdTimeTwinSyncStdOptionalDurationTwinSyncConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualStdTimeTwinSyncStdOptionalDurationTwinSyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "std_optional_duration_twin_sync",
+            argNames: ["d"],
+          );
+
+  @override
+  DateTime?
+      crateApiPseudoManualStdTimeTwinSyncStdOptionalSystemTimeTwinSync(
+          {DateTime? t}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        var arg0 = cst_encode_opt_box_autoadd_StdSystemTime(t);
+        return wire
+            .wire__crate__api__pseudo_manual__std_time_twin_sync__std_optional_system_time_twin_sync(
+                arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_opt_box_autoadd_StdSystemTime,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualStdTimeTwinSyncStdOptionalSystemTimeTwinSyncConstMeta,
+      argValues: [t],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualStdTimeTwinSyncStdOptionalSystemTimeTwinSyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "std_optional_system_time_twin_sync",
+            argNames: ["t"],
+          );
+
+  @override
+  DateTime crateApiPseudoManualStdTimeTwinSyncStdSystemTimeTwinSync(
+      {required DateTime t}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        var arg0 = cst_encode_StdSystemTime(t);
+        return wire
+            .wire__crate__api__pseudo_manual__std_time_twin_sync__std_system_time_twin_sync(
+                arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_StdSystemTime,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualStdTimeTwinSyncStdSystemTimeTwinSyncConstMeta,
+      argValues: [t],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualStdTimeTwinSyncStdSystemTimeTwinSyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "std_system_time_twin_sync",
+            argNames: ["t"],
+          );
+
+  @override
+  List<DateTime> crateApiPseudoManualStdTimeTwinSyncStdSystemTimesTwinSync(
+      {required List<DateTime> times}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        var arg0 = cst_encode_list_StdSystemTime(times);
+        return wire
+            .wire__crate__api__pseudo_manual__std_time_twin_sync__std_system_times_twin_sync(
+                arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_list_StdSystemTime,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualStdTimeTwinSyncStdSystemTimesTwinSyncConstMeta,
+      argValues: [times],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualStdTimeTwinSyncStdSystemTimesTwinSyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "std_system_times_twin_sync",
+            argNames: ["times"],
+          );
+
+  @override
+  StdTimeTwinSync crateApiPseudoManualStdTimeTwinSyncStdTimeStructTwinSync(
+      {required StdTimeTwinSync value}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        var arg0 = cst_encode_box_autoadd_std_time_twin_sync(value);
+        return wire
+            .wire__crate__api__pseudo_manual__std_time_twin_sync__std_time_struct_twin_sync(
+                arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_std_time_twin_sync,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualStdTimeTwinSyncStdTimeStructTwinSyncConstMeta,
+      argValues: [value],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualStdTimeTwinSyncStdTimeStructTwinSyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "std_time_struct_twin_sync",
+            argNames: ["value"],
+          );
+
+  @override
+  Duration crateApiPseudoManualStdTimeTwinSyncSseStdDurationTwinSyncSse(
+      {required Duration d}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_StdDuration(d, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__std_time_twin_sync_sse__std_duration_twin_sync_sse(
+                raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_StdDuration,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualStdTimeTwinSyncSseStdDurationTwinSyncSseConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualStdTimeTwinSyncSseStdDurationTwinSyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "std_duration_twin_sync_sse",
+            argNames: ["d"],
+          );
+
+  @override
+  List<Duration> crateApiPseudoManualStdTimeTwinSyncSseStdDurationsTwinSyncSse(
+      {required List<Duration> durations}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_list_StdDuration(durations, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__std_time_twin_sync_sse__std_durations_twin_sync_sse(
+                raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_StdDuration,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualStdTimeTwinSyncSseStdDurationsTwinSyncSseConstMeta,
+      argValues: [durations],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualStdTimeTwinSyncSseStdDurationsTwinSyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "std_durations_twin_sync_sse",
+            argNames: ["durations"],
+          );
+
+  @override
+  Duration?
+      crateApiPseudoManualStdTimeTwinSyncSseStdOptionalDurationTwinSyncSse(
+          {Duration? d}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_opt_box_autoadd_StdDuration(d, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__std_time_twin_sync_sse__std_optional_duration_twin_sync_sse(
+                raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_box_autoadd_StdDuration,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualStdTimeTwinSyncSseStdOptionalDurationTwinSyncSseConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualStdTimeTwinSyncSseStdOptionalDurationTwinSyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "std_optional_duration_twin_sync_sse",
+            argNames: ["d"],
+          );
+
+  @override
+  DateTime?
+      crateApiPseudoManualStdTimeTwinSyncSseStdOptionalSystemTimeTwinSyncSse(
+          {DateTime? t}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_opt_box_autoadd_StdSystemTime(t, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__std_time_twin_sync_sse__std_optional_system_time_twin_sync_sse(
+                raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_box_autoadd_StdSystemTime,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualStdTimeTwinSyncSseStdOptionalSystemTimeTwinSyncSseConstMeta,
+      argValues: [t],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualStdTimeTwinSyncSseStdOptionalSystemTimeTwinSyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "std_optional_system_time_twin_sync_sse",
+            argNames: ["t"],
+          );
+
+  @override
+  DateTime crateApiPseudoManualStdTimeTwinSyncSseStdSystemTimeTwinSyncSse(
+      {required DateTime t}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_StdSystemTime(t, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__std_time_twin_sync_sse__std_system_time_twin_sync_sse(
+                raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_StdSystemTime,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualStdTimeTwinSyncSseStdSystemTimeTwinSyncSseConstMeta,
+      argValues: [t],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualStdTimeTwinSyncSseStdSystemTimeTwinSyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "std_system_time_twin_sync_sse",
+            argNames: ["t"],
+          );
+
+  @override
+  List<DateTime>
+      crateApiPseudoManualStdTimeTwinSyncSseStdSystemTimesTwinSyncSse(
+          {required List<DateTime> times}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_list_StdSystemTime(times, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__std_time_twin_sync_sse__std_system_times_twin_sync_sse(
+                raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_StdSystemTime,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualStdTimeTwinSyncSseStdSystemTimesTwinSyncSseConstMeta,
+      argValues: [times],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualStdTimeTwinSyncSseStdSystemTimesTwinSyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "std_system_times_twin_sync_sse",
+            argNames: ["times"],
+          );
+
+  @override
+  StdTimeTwinSyncSse
+      crateApiPseudoManualStdTimeTwinSyncSseStdTimeStructTwinSyncSse(
+          {required StdTimeTwinSyncSse value}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_std_time_twin_sync_sse(value, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__std_time_twin_sync_sse__std_time_struct_twin_sync_sse(
+                raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_std_time_twin_sync_sse,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualStdTimeTwinSyncSseStdTimeStructTwinSyncSseConstMeta,
+      argValues: [value],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualStdTimeTwinSyncSseStdTimeStructTwinSyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "std_time_struct_twin_sync_sse",
+            argNames: ["value"],
+          );
+
   @override
   Stream<String>
       crateApiPseudoManualStreamMiscTwinSseFuncStreamRealisticTwinSse(
@@ -89194,6 +98071,105 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             argNames: ["sink", "arg"],
           );
 
+  @override
+  Stream<int>
+      crateApiPseudoManualStreamMiscTwinSseStreamSinkBoundedAddAsyncTwinSse() {
+    final sink = RustStreamSink<int>(
+        capacity: 2, binding: generalizedFrbRustBinding);
+    unawaited(handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_StreamSink_i_32_Sse(sink, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__stream_misc_twin_sse__stream_sink_bounded_add_async_twin_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualStreamMiscTwinSseStreamSinkBoundedAddAsyncTwinSseConstMeta,
+      argValues: [sink],
+      apiImpl: this,
+    )));
+    return sink.stream;
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualStreamMiscTwinSseStreamSinkBoundedAddAsyncTwinSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "stream_sink_bounded_add_async_twin_sse",
+            argNames: ["sink"],
+          );
+
+  @override
+  Stream<int>
+      crateApiPseudoManualStreamMiscTwinSseStreamSinkBoundedAddWithErrorTwinSse() {
+    final sink = RustStreamSink<int>(
+        capacity: 1, binding: generalizedFrbRustBinding);
+    unawaited(handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_StreamSink_i_32_Sse(sink, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__stream_misc_twin_sse__stream_sink_bounded_add_with_error_twin_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualStreamMiscTwinSseStreamSinkBoundedAddWithErrorTwinSseConstMeta,
+      argValues: [sink],
+      apiImpl: this,
+    )));
+    return sink.stream;
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualStreamMiscTwinSseStreamSinkBoundedAddWithErrorTwinSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "stream_sink_bounded_add_with_error_twin_sse",
+            argNames: ["sink"],
+          );
+
+  @override
+  Stream<int>
+      crateApiPseudoManualStreamMiscTwinSseStreamSinkBoundedKeepLatestTwinSse() {
+    final sink = RustStreamSink<int>(
+        capacity: 1, binding: generalizedFrbRustBinding);
+    unawaited(handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_StreamSink_i_32_Sse(sink, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__stream_misc_twin_sse__stream_sink_bounded_keep_latest_twin_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualStreamMiscTwinSseStreamSinkBoundedKeepLatestTwinSseConstMeta,
+      argValues: [sink],
+      apiImpl: this,
+    )));
+    return sink.stream;
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualStreamMiscTwinSseStreamSinkBoundedKeepLatestTwinSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "stream_sink_bounded_keep_latest_twin_sse",
+            argNames: ["sink"],
+          );
+
   @override
   Future<Stream<int>>
       crateApiPseudoManualStreamMiscTwinSseStreamSinkDartAsyncTwinSse() async {
@@ -91397,6 +100373,1343 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             argNames: ["arg"],
           );
 
+  @override
+  Future<DateTime> crateApiPseudoManualTimeTypeTwinRustAsyncDateTwinRustAsync(
+      {required DateTime d}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_TimeDate(d);
+        return wire
+            .wire__crate__api__pseudo_manual__time_type_twin_rust_async__date_twin_rust_async(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_TimeDate,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualTimeTypeTwinRustAsyncDateTwinRustAsyncConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualTimeTypeTwinRustAsyncDateTwinRustAsyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "date_twin_rust_async",
+            argNames: ["d"],
+          );
+
+  @override
+  Future<Duration>
+      crateApiPseudoManualTimeTypeTwinRustAsyncNegateTimeDurationTwinRustAsync(
+          {required Duration d}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_TimeDuration(d);
+        return wire
+            .wire__crate__api__pseudo_manual__time_type_twin_rust_async__negate_time_duration_twin_rust_async(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_TimeDuration,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualTimeTypeTwinRustAsyncNegateTimeDurationTwinRustAsyncConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualTimeTypeTwinRustAsyncNegateTimeDurationTwinRustAsyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "negate_time_duration_twin_rust_async",
+            argNames: ["d"],
+          );
+
+  @override
+  Future<DateTime>
+      crateApiPseudoManualTimeTypeTwinRustAsyncOffsetDateTimeTwinRustAsync(
+          {required DateTime d}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_TimeOffsetDateTime(d);
+        return wire
+            .wire__crate__api__pseudo_manual__time_type_twin_rust_async__offset_date_time_twin_rust_async(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_TimeOffsetDateTime,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualTimeTypeTwinRustAsyncOffsetDateTimeTwinRustAsyncConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualTimeTypeTwinRustAsyncOffsetDateTimeTwinRustAsyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "offset_date_time_twin_rust_async",
+            argNames: ["d"],
+          );
+
+  @override
+  Future<DateTime?>
+      crateApiPseudoManualTimeTypeTwinRustAsyncOptionalOffsetDateTimeTwinRustAsync(
+          {DateTime? d}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_opt_box_autoadd_TimeOffsetDateTime(d);
+        return wire
+            .wire__crate__api__pseudo_manual__time_type_twin_rust_async__optional_offset_date_time_twin_rust_async(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_opt_box_autoadd_TimeOffsetDateTime,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualTimeTypeTwinRustAsyncOptionalOffsetDateTimeTwinRustAsyncConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualTimeTypeTwinRustAsyncOptionalOffsetDateTimeTwinRustAsyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "optional_offset_date_time_twin_rust_async",
+            argNames: ["d"],
+          );
+
+  @override
+  Future<Duration?>
+      crateApiPseudoManualTimeTypeTwinRustAsyncOptionalTimeDurationTwinRustAsync(
+          {Duration? d}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_opt_box_autoadd_TimeDuration(d);
+        return wire
+            .wire__crate__api__pseudo_manual__time_type_twin_rust_async__optional_time_duration_twin_rust_async(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_opt_box_autoadd_TimeDuration,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualTimeTypeTwinRustAsyncOptionalTimeDurationTwinRustAsyncConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualTimeTypeTwinRustAsyncOptionalTimeDurationTwinRustAsyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "optional_time_duration_twin_rust_async",
+            argNames: ["d"],
+          );
+
+  @override
+  Future<DateTime>
+      crateApiPseudoManualTimeTypeTwinRustAsyncPrimitiveDateTimeTwinRustAsync(
+          {required DateTime d}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_TimePrimitiveDateTime(d);
+        return wire
+            .wire__crate__api__pseudo_manual__time_type_twin_rust_async__primitive_date_time_twin_rust_async(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_TimePrimitiveDateTime,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualTimeTypeTwinRustAsyncPrimitiveDateTimeTwinRustAsyncConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualTimeTypeTwinRustAsyncPrimitiveDateTimeTwinRustAsyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "primitive_date_time_twin_rust_async",
+            argNames: ["d"],
+          );
+
+  @override
+  Future<Duration>
+      crateApiPseudoManualTimeTypeTwinRustAsyncTimeDurationTwinRustAsync(
+          {required Duration d}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_TimeDuration(d);
+        return wire
+            .wire__crate__api__pseudo_manual__time_type_twin_rust_async__time_duration_twin_rust_async(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_TimeDuration,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualTimeTypeTwinRustAsyncTimeDurationTwinRustAsyncConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualTimeTypeTwinRustAsyncTimeDurationTwinRustAsyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "time_duration_twin_rust_async",
+            argNames: ["d"],
+          );
+
+  @override
+  Future<List<Duration>>
+      crateApiPseudoManualTimeTypeTwinRustAsyncTimeDurationsTwinRustAsync(
+          {required List<Duration> durations}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_list_TimeDuration(durations);
+        return wire
+            .wire__crate__api__pseudo_manual__time_type_twin_rust_async__time_durations_twin_rust_async(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_list_TimeDuration,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualTimeTypeTwinRustAsyncTimeDurationsTwinRustAsyncConstMeta,
+      argValues: [durations],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualTimeTypeTwinRustAsyncTimeDurationsTwinRustAsyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "time_durations_twin_rust_async",
+            argNames: ["durations"],
+          );
+
+  @override
+  Future<TimeTypesTwinRustAsync>
+      crateApiPseudoManualTimeTypeTwinRustAsyncTimeTypesTwinRustAsync(
+          {required TimeTypesTwinRustAsync value}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_box_autoadd_time_types_twin_rust_async(value);
+        return wire
+            .wire__crate__api__pseudo_manual__time_type_twin_rust_async__time_types_twin_rust_async(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_time_types_twin_rust_async,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualTimeTypeTwinRustAsyncTimeTypesTwinRustAsyncConstMeta,
+      argValues: [value],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualTimeTypeTwinRustAsyncTimeTypesTwinRustAsyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "time_types_twin_rust_async",
+            argNames: ["value"],
+          );
+
+  @override
+  Future<DateTime>
+      crateApiPseudoManualTimeTypeTwinRustAsyncSseDateTwinRustAsyncSse(
+          {required DateTime d}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_TimeDate(d, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__time_type_twin_rust_async_sse__date_twin_rust_async_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_TimeDate,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualTimeTypeTwinRustAsyncSseDateTwinRustAsyncSseConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualTimeTypeTwinRustAsyncSseDateTwinRustAsyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "date_twin_rust_async_sse",
+            argNames: ["d"],
+          );
+
+  @override
+  Future<Duration>
+      crateApiPseudoManualTimeTypeTwinRustAsyncSseNegateTimeDurationTwinRustAsyncSse(
+          {required Duration d}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_TimeDuration(d, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__time_type_twin_rust_async_sse__negate_time_duration_twin_rust_async_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_TimeDuration,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualTimeTypeTwinRustAsyncSseNegateTimeDurationTwinRustAsyncSseConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualTimeTypeTwinRustAsyncSseNegateTimeDurationTwinRustAsyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "negate_time_duration_twin_rust_async_sse",
+            argNames: ["d"],
+          );
+
+  @override
+  Future<DateTime>
+      crateApiPseudoManualTimeTypeTwinRustAsyncSseOffsetDateTimeTwinRustAsyncSse(
+          {required DateTime d}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_TimeOffsetDateTime(d, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__time_type_twin_rust_async_sse__offset_date_time_twin_rust_async_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_TimeOffsetDateTime,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualTimeTypeTwinRustAsyncSseOffsetDateTimeTwinRustAsyncSseConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualTimeTypeTwinRustAsyncSseOffsetDateTimeTwinRustAsyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "offset_date_time_twin_rust_async_sse",
+            argNames: ["d"],
+          );
+
+  @override
+  Future<DateTime?>
+      crateApiPseudoManualTimeTypeTwinRustAsyncSseOptionalOffsetDateTimeTwinRustAsyncSse(
+          {DateTime? d}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_opt_box_autoadd_TimeOffsetDateTime(d, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__time_type_twin_rust_async_sse__optional_offset_date_time_twin_rust_async_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_box_autoadd_TimeOffsetDateTime,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualTimeTypeTwinRustAsyncSseOptionalOffsetDateTimeTwinRustAsyncSseConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualTimeTypeTwinRustAsyncSseOptionalOffsetDateTimeTwinRustAsyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "optional_offset_date_time_twin_rust_async_sse",
+            argNames: ["d"],
+          );
+
+  @override
+  Future<Duration?>
+      crateApiPseudoManualTimeTypeTwinRustAsyncSseOptionalTimeDurationTwinRustAsyncSse(
+          {Duration? d}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_opt_box_autoadd_TimeDuration(d, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__time_type_twin_rust_async_sse__optional_time_duration_twin_rust_async_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_box_autoadd_TimeDuration,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualTimeTypeTwinRustAsyncSseOptionalTimeDurationTwinRustAsyncSseConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualTimeTypeTwinRustAsyncSseOptionalTimeDurationTwinRustAsyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "optional_time_duration_twin_rust_async_sse",
+            argNames: ["d"],
+          );
+
+  @override
+  Future<DateTime>
+      crateApiPseudoManualTimeTypeTwinRustAsyncSsePrimitiveDateTimeTwinRustAsyncSse(
+          {required DateTime d}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_TimePrimitiveDateTime(d, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__time_type_twin_rust_async_sse__primitive_date_time_twin_rust_async_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_TimePrimitiveDateTime,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualTimeTypeTwinRustAsyncSsePrimitiveDateTimeTwinRustAsyncSseConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualTimeTypeTwinRustAsyncSsePrimitiveDateTimeTwinRustAsyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "primitive_date_time_twin_rust_async_sse",
+            argNames: ["d"],
+          );
+
+  @override
+  Future<Duration>
+      crateApiPseudoManualTimeTypeTwinRustAsyncSseTimeDurationTwinRustAsyncSse(
+          {required Duration d}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_TimeDuration(d, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__time_type_twin_rust_async_sse__time_duration_twin_rust_async_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_TimeDuration,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualTimeTypeTwinRustAsyncSseTimeDurationTwinRustAsyncSseConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualTimeTypeTwinRustAsyncSseTimeDurationTwinRustAsyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "time_duration_twin_rust_async_sse",
+            argNames: ["d"],
+          );
+
+  @override
+  Future<List<Duration>>
+      crateApiPseudoManualTimeTypeTwinRustAsyncSseTimeDurationsTwinRustAsyncSse(
+          {required List<Duration> durations}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_list_TimeDuration(durations, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__time_type_twin_rust_async_sse__time_durations_twin_rust_async_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_TimeDuration,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualTimeTypeTwinRustAsyncSseTimeDurationsTwinRustAsyncSseConstMeta,
+      argValues: [durations],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualTimeTypeTwinRustAsyncSseTimeDurationsTwinRustAsyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "time_durations_twin_rust_async_sse",
+            argNames: ["durations"],
+          );
+
+  @override
+  Future<TimeTypesTwinRustAsyncSse>
+      crateApiPseudoManualTimeTypeTwinRustAsyncSseTimeTypesTwinRustAsyncSse(
+          {required TimeTypesTwinRustAsyncSse value}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_time_types_twin_rust_async_sse(
+            value, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__time_type_twin_rust_async_sse__time_types_twin_rust_async_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_time_types_twin_rust_async_sse,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualTimeTypeTwinRustAsyncSseTimeTypesTwinRustAsyncSseConstMeta,
+      argValues: [value],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualTimeTypeTwinRustAsyncSseTimeTypesTwinRustAsyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "time_types_twin_rust_async_sse",
+            argNames: ["value"],
+          );
+
+  @override
+  Future<DateTime> crateApiPseudoManualTimeTypeTwinSseDateTwinSse(
+      {required DateTime d}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_TimeDate(d, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__time_type_twin_sse__date_twin_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_TimeDate,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiPseudoManualTimeTypeTwinSseDateTwinSseConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPseudoManualTimeTypeTwinSseDateTwinSseConstMeta =>
+      const TaskConstMeta(
+        debugName: "date_twin_sse",
+        argNames: ["d"],
+      );
+
+  @override
+  Future<Duration> crateApiPseudoManualTimeTypeTwinSseNegateTimeDurationTwinSse(
+      {required Duration d}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_TimeDuration(d, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__time_type_twin_sse__negate_time_duration_twin_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_TimeDuration,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualTimeTypeTwinSseNegateTimeDurationTwinSseConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualTimeTypeTwinSseNegateTimeDurationTwinSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "negate_time_duration_twin_sse",
+            argNames: ["d"],
+          );
+
+  @override
+  Future<DateTime> crateApiPseudoManualTimeTypeTwinSseOffsetDateTimeTwinSse(
+      {required DateTime d}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_TimeOffsetDateTime(d, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__time_type_twin_sse__offset_date_time_twin_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_TimeOffsetDateTime,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualTimeTypeTwinSseOffsetDateTimeTwinSseConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualTimeTypeTwinSseOffsetDateTimeTwinSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "offset_date_time_twin_sse",
+            argNames: ["d"],
+          );
+
+  @override
+  Future<DateTime?>
+      crateApiPseudoManualTimeTypeTwinSseOptionalOffsetDateTimeTwinSse(
+          {DateTime? d}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_opt_box_autoadd_TimeOffsetDateTime(d, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__time_type_twin_sse__optional_offset_date_time_twin_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_box_autoadd_TimeOffsetDateTime,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualTimeTypeTwinSseOptionalOffsetDateTimeTwinSseConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualTimeTypeTwinSseOptionalOffsetDateTimeTwinSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "optional_offset_date_time_twin_sse",
+            argNames: ["d"],
+          );
+
+  @override
+  Future<Duration?>
+      crateApiPseudoManualTimeTypeTwinSseOptionalTimeDurationTwinSse(
+          {Duration? d}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_opt_box_autoadd_TimeDuration(d, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__time_type_twin_sse__optional_time_duration_twin_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_box_autoadd_TimeDuration,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualTimeTypeTwinSseOptionalTimeDurationTwinSseConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualTimeTypeTwinSseOptionalTimeDurationTwinSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "optional_time_duration_twin_sse",
+            argNames: ["d"],
+          );
+
+  @override
+  Future<DateTime> crateApiPseudoManualTimeTypeTwinSsePrimitiveDateTimeTwinSse(
+      {required DateTime d}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_TimePrimitiveDateTime(d, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__time_type_twin_sse__primitive_date_time_twin_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_TimePrimitiveDateTime,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualTimeTypeTwinSsePrimitiveDateTimeTwinSseConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualTimeTypeTwinSsePrimitiveDateTimeTwinSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "primitive_date_time_twin_sse",
+            argNames: ["d"],
+          );
+
+  @override
+  Future<Duration> crateApiPseudoManualTimeTypeTwinSseTimeDurationTwinSse(
+      {required Duration d}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_TimeDuration(d, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__time_type_twin_sse__time_duration_twin_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_TimeDuration,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualTimeTypeTwinSseTimeDurationTwinSseConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualTimeTypeTwinSseTimeDurationTwinSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "time_duration_twin_sse",
+            argNames: ["d"],
+          );
+
+  @override
+  Future<List<Duration>>
+      crateApiPseudoManualTimeTypeTwinSseTimeDurationsTwinSse(
+          {required List<Duration> durations}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_list_TimeDuration(durations, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__time_type_twin_sse__time_durations_twin_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_TimeDuration,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualTimeTypeTwinSseTimeDurationsTwinSseConstMeta,
+      argValues: [durations],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualTimeTypeTwinSseTimeDurationsTwinSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "time_durations_twin_sse",
+            argNames: ["durations"],
+          );
+
+  @override
+  Future<TimeTypesTwinSse> crateApiPseudoManualTimeTypeTwinSseTimeTypesTwinSse(
+      {required TimeTypesTwinSse value}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_time_types_twin_sse(value, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__time_type_twin_sse__time_types_twin_sse(
+                port_, raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_time_types_twin_sse,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiPseudoManualTimeTypeTwinSseTimeTypesTwinSseConstMeta,
+      argValues: [value],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualTimeTypeTwinSseTimeTypesTwinSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "time_types_twin_sse",
+            argNames: ["value"],
+          );
+
+  @override
+  DateTime crateApiPseudoManualTimeTypeTwinSyncDateTwinSync(
+      {required DateTime d}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        var arg0 = cst_encode_TimeDate(d);
+        return wire
+            .wire__crate__api__pseudo_manual__time_type_twin_sync__date_twin_sync(
+                arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_TimeDate,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiPseudoManualTimeTypeTwinSyncDateTwinSyncConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualTimeTypeTwinSyncDateTwinSyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "date_twin_sync",
+            argNames: ["d"],
+          );
+
+  @override
+  Duration crateApiPseudoManualTimeTypeTwinSyncNegateTimeDurationTwinSync(
+      {required Duration d}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        var arg0 = cst_encode_TimeDuration(d);
+        return wire
+            .wire__crate__api__pseudo_manual__time_type_twin_sync__negate_time_duration_twin_sync(
+                arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_TimeDuration,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualTimeTypeTwinSyncNegateTimeDurationTwinSyncConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualTimeTypeTwinSyncNegateTimeDurationTwinSyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "negate_time_duration_twin_sync",
+            argNames: ["d"],
+          );
+
+  @override
+  DateTime crateApiPseudoManualTimeTypeTwinSyncOffsetDateTimeTwinSync(
+      {required DateTime d}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        var arg0 = cst_encode_TimeOffsetDateTime(d);
+        return wire
+            .wire__crate__api__pseudo_manual__time_type_twin_sync__offset_date_time_twin_sync(
+                arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_TimeOffsetDateTime,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualTimeTypeTwinSyncOffsetDateTimeTwinSyncConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualTimeTypeTwinSyncOffsetDateTimeTwinSyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "offset_date_time_twin_sync",
+            argNames: ["d"],
+          );
+
+  @override
+  DateTime?
+      crateApiPseudoManualTimeTypeTwinSyncOptionalOffsetDateTimeTwinSync(
+          {DateTime? d}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        var arg0 = cst_encode_opt_box_autoadd_TimeOffsetDateTime(d);
+        return wire
+            .wire__crate__api__pseudo_manual__time_type_twin_sync__optional_offset_date_time_twin_sync(
+                arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_opt_box_autoadd_TimeOffsetDateTime,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualTimeTypeTwinSyncOptionalOffsetDateTimeTwinSyncConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualTimeTypeTwinSyncOptionalOffsetDateTimeTwinSyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "optional_offset_date_time_twin_sync",
+            argNames: ["d"],
+          );
+
+  @override
+  Duration?
+      crateApiPseudoManualTimeTypeTwinSyncOptionalTimeDurationTwinSync(
+          {Duration? d}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        var arg0 = cst_encode_opt_box_autoadd_TimeDuration(d);
+        return wire
+            .wire__crate__api__pseudo_manual__time_type_twin_sync__optional_time_duration_twin_sync(
+                arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_opt_box_autoadd_TimeDuration,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualTimeTypeTwinSyncOptionalTimeDurationTwinSyncConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualTimeTypeTwinSyncOptionalTimeDurationTwinSyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "optional_time_duration_twin_sync",
+            argNames: ["d"],
+          );
+
+  @override
+  DateTime crateApiPseudoManualTimeTypeTwinSyncPrimitiveDateTimeTwinSync(
+      {required DateTime d}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        var arg0 = cst_encode_TimePrimitiveDateTime(d);
+        return wire
+            .wire__crate__api__pseudo_manual__time_type_twin_sync__primitive_date_time_twin_sync(
+                arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_TimePrimitiveDateTime,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualTimeTypeTwinSyncPrimitiveDateTimeTwinSyncConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualTimeTypeTwinSyncPrimitiveDateTimeTwinSyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "primitive_date_time_twin_sync",
+            argNames: ["d"],
+          );
+
+  @override
+  Duration crateApiPseudoManualTimeTypeTwinSyncTimeDurationTwinSync(
+      {required Duration d}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        var arg0 = cst_encode_TimeDuration(d);
+        return wire
+            .wire__crate__api__pseudo_manual__time_type_twin_sync__time_duration_twin_sync(
+                arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_TimeDuration,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualTimeTypeTwinSyncTimeDurationTwinSyncConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualTimeTypeTwinSyncTimeDurationTwinSyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "time_duration_twin_sync",
+            argNames: ["d"],
+          );
+
+  @override
+  List<Duration> crateApiPseudoManualTimeTypeTwinSyncTimeDurationsTwinSync(
+      {required List<Duration> durations}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        var arg0 = cst_encode_list_TimeDuration(durations);
+        return wire
+            .wire__crate__api__pseudo_manual__time_type_twin_sync__time_durations_twin_sync(
+                arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_list_TimeDuration,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualTimeTypeTwinSyncTimeDurationsTwinSyncConstMeta,
+      argValues: [durations],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualTimeTypeTwinSyncTimeDurationsTwinSyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "time_durations_twin_sync",
+            argNames: ["durations"],
+          );
+
+  @override
+  TimeTypesTwinSync crateApiPseudoManualTimeTypeTwinSyncTimeTypesTwinSync(
+      {required TimeTypesTwinSync value}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        var arg0 = cst_encode_box_autoadd_time_types_twin_sync(value);
+        return wire
+            .wire__crate__api__pseudo_manual__time_type_twin_sync__time_types_twin_sync(
+                arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_time_types_twin_sync,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualTimeTypeTwinSyncTimeTypesTwinSyncConstMeta,
+      argValues: [value],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualTimeTypeTwinSyncTimeTypesTwinSyncConstMeta =>
+          const TaskConstMeta(
+            debugName: "time_types_twin_sync",
+            argNames: ["value"],
+          );
+
+  @override
+  DateTime crateApiPseudoManualTimeTypeTwinSyncSseDateTwinSyncSse(
+      {required DateTime d}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_TimeDate(d, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__time_type_twin_sync_sse__date_twin_sync_sse(
+                raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_TimeDate,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualTimeTypeTwinSyncSseDateTwinSyncSseConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualTimeTypeTwinSyncSseDateTwinSyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "date_twin_sync_sse",
+            argNames: ["d"],
+          );
+
+  @override
+  Duration crateApiPseudoManualTimeTypeTwinSyncSseNegateTimeDurationTwinSyncSse(
+      {required Duration d}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_TimeDuration(d, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__time_type_twin_sync_sse__negate_time_duration_twin_sync_sse(
+                raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_TimeDuration,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualTimeTypeTwinSyncSseNegateTimeDurationTwinSyncSseConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualTimeTypeTwinSyncSseNegateTimeDurationTwinSyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "negate_time_duration_twin_sync_sse",
+            argNames: ["d"],
+          );
+
+  @override
+  DateTime crateApiPseudoManualTimeTypeTwinSyncSseOffsetDateTimeTwinSyncSse(
+      {required DateTime d}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_TimeOffsetDateTime(d, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__time_type_twin_sync_sse__offset_date_time_twin_sync_sse(
+                raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_TimeOffsetDateTime,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualTimeTypeTwinSyncSseOffsetDateTimeTwinSyncSseConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualTimeTypeTwinSyncSseOffsetDateTimeTwinSyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "offset_date_time_twin_sync_sse",
+            argNames: ["d"],
+          );
+
+  @override
+  DateTime?
+      crateApiPseudoManualTimeTypeTwinSyncSseOptionalOffsetDateTimeTwinSyncSse(
+          {DateTime? d}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_opt_box_autoadd_TimeOffsetDateTime(d, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__time_type_twin_sync_sse__optional_offset_date_time_twin_sync_sse(
+                raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_box_autoadd_TimeOffsetDateTime,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualTimeTypeTwinSyncSseOptionalOffsetDateTimeTwinSyncSseConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualTimeTypeTwinSyncSseOptionalOffsetDateTimeTwinSyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "optional_offset_date_time_twin_sync_sse",
+            argNames: ["d"],
+          );
+
+  @override
+  Duration?
+      crateApiPseudoManualTimeTypeTwinSyncSseOptionalTimeDurationTwinSyncSse(
+          {Duration? d}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_opt_box_autoadd_TimeDuration(d, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__time_type_twin_sync_sse__optional_time_duration_twin_sync_sse(
+                raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_box_autoadd_TimeDuration,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualTimeTypeTwinSyncSseOptionalTimeDurationTwinSyncSseConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualTimeTypeTwinSyncSseOptionalTimeDurationTwinSyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "optional_time_duration_twin_sync_sse",
+            argNames: ["d"],
+          );
+
+  @override
+  DateTime crateApiPseudoManualTimeTypeTwinSyncSsePrimitiveDateTimeTwinSyncSse(
+      {required DateTime d}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_TimePrimitiveDateTime(d, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__time_type_twin_sync_sse__primitive_date_time_twin_sync_sse(
+                raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_TimePrimitiveDateTime,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualTimeTypeTwinSyncSsePrimitiveDateTimeTwinSyncSseConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualTimeTypeTwinSyncSsePrimitiveDateTimeTwinSyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "primitive_date_time_twin_sync_sse",
+            argNames: ["d"],
+          );
+
+  @override
+  Duration crateApiPseudoManualTimeTypeTwinSyncSseTimeDurationTwinSyncSse(
+      {required Duration d}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_TimeDuration(d, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__time_type_twin_sync_sse__time_duration_twin_sync_sse(
+                raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_TimeDuration,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualTimeTypeTwinSyncSseTimeDurationTwinSyncSseConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualTimeTypeTwinSyncSseTimeDurationTwinSyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "time_duration_twin_sync_sse",
+            argNames: ["d"],
+          );
+
+  @override
+  List<Duration>
+      crateApiPseudoManualTimeTypeTwinSyncSseTimeDurationsTwinSyncSse(
+          {required List<Duration> durations}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_list_TimeDuration(durations, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__time_type_twin_sync_sse__time_durations_twin_sync_sse(
+                raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_TimeDuration,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualTimeTypeTwinSyncSseTimeDurationsTwinSyncSseConstMeta,
+      argValues: [durations],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualTimeTypeTwinSyncSseTimeDurationsTwinSyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "time_durations_twin_sync_sse",
+            argNames: ["durations"],
+          );
+
+  @override
+  TimeTypesTwinSyncSse
+      crateApiPseudoManualTimeTypeTwinSyncSseTimeTypesTwinSyncSse(
+          {required TimeTypesTwinSyncSse value}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_time_types_twin_sync_sse(value, serializer);
+        final raw_ = serializer.intoRaw();
+        return wire
+            .wire__crate__api__pseudo_manual__time_type_twin_sync_sse__time_types_twin_sync_sse(
+                raw_.ptr, raw_.rustVecLen, raw_.dataLen);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_time_types_twin_sync_sse,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiPseudoManualTimeTypeTwinSyncSseTimeTypesTwinSyncSseConstMeta,
+      argValues: [value],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiPseudoManualTimeTypeTwinSyncSseTimeTypesTwinSyncSseConstMeta =>
+          const TaskConstMeta(
+            debugName: "time_types_twin_sync_sse",
+            argNames: ["value"],
+          );
+
   @override
   Future<void> crateApiPseudoManualTupleTwinRustAsyncTestTuple2TwinRustAsync(
       {required List<(String, int)> value}) {
@@ -94520,6 +104833,182 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["a", "b"],
       );
 
+  @override
+  Future<Duration> crateApiStdTimeStdDurationTwinNormal({required Duration d}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_StdDuration(d);
+        return wire.wire__crate__api__std_time__std_duration_twin_normal(
+            port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_StdDuration,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiStdTimeStdDurationTwinNormalConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiStdTimeStdDurationTwinNormalConstMeta =>
+      const TaskConstMeta(
+        debugName: "std_duration_twin_normal",
+        argNames: ["d"],
+      );
+
+  @override
+  Future<List<Duration>> crateApiStdTimeStdDurationsTwinNormal(
+      {required List<Duration> durations}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_list_StdDuration(durations);
+        return wire.wire__crate__api__std_time__std_durations_twin_normal(
+            port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_list_StdDuration,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiStdTimeStdDurationsTwinNormalConstMeta,
+      argValues: [durations],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiStdTimeStdDurationsTwinNormalConstMeta =>
+      const TaskConstMeta(
+        debugName: "std_durations_twin_normal",
+        argNames: ["durations"],
+      );
+
+  @override
+  Future<Duration?> crateApiStdTimeStdOptionalDurationTwinNormal(
+      {Duration? d}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_opt_box_autoadd_StdDuration(d);
+        return wire
+            .wire__crate__api__std_time__std_optional_duration_twin_normal(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_opt_box_autoadd_StdDuration,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiStdTimeStdOptionalDurationTwinNormalConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiStdTimeStdOptionalDurationTwinNormalConstMeta =>
+      const TaskConstMeta(
+        debugName: "std_optional_duration_twin_normal",
+        argNames: ["d"],
+      );
+
+  @override
+  Future<DateTime?> crateApiStdTimeStdOptionalSystemTimeTwinNormal(
+      {DateTime? t}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_opt_box_autoadd_StdSystemTime(t);
+        return wire
+            .wire__crate__api__std_time__std_optional_system_time_twin_normal(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_opt_box_autoadd_StdSystemTime,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiStdTimeStdOptionalSystemTimeTwinNormalConstMeta,
+      argValues: [t],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiStdTimeStdOptionalSystemTimeTwinNormalConstMeta =>
+      const TaskConstMeta(
+        debugName: "std_optional_system_time_twin_normal",
+        argNames: ["t"],
+      );
+
+  @override
+  Future<DateTime> crateApiStdTimeStdSystemTimeTwinNormal(
+      {required DateTime t}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_StdSystemTime(t);
+        return wire.wire__crate__api__std_time__std_system_time_twin_normal(
+            port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_StdSystemTime,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiStdTimeStdSystemTimeTwinNormalConstMeta,
+      argValues: [t],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiStdTimeStdSystemTimeTwinNormalConstMeta =>
+      const TaskConstMeta(
+        debugName: "std_system_time_twin_normal",
+        argNames: ["t"],
+      );
+
+  @override
+  Future<List<DateTime>> crateApiStdTimeStdSystemTimesTwinNormal(
+      {required List<DateTime> times}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_list_StdSystemTime(times);
+        return wire.wire__crate__api__std_time__std_system_times_twin_normal(
+            port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_list_StdSystemTime,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiStdTimeStdSystemTimesTwinNormalConstMeta,
+      argValues: [times],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiStdTimeStdSystemTimesTwinNormalConstMeta =>
+      const TaskConstMeta(
+        debugName: "std_system_times_twin_normal",
+        argNames: ["times"],
+      );
+
+  @override
+  Future<StdTimeTwinNormal> crateApiStdTimeStdTimeStructTwinNormal(
+      {required StdTimeTwinNormal value}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_box_autoadd_std_time_twin_normal(value);
+        return wire.wire__crate__api__std_time__std_time_struct_twin_normal(
+            port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_std_time_twin_normal,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiStdTimeStdTimeStructTwinNormalConstMeta,
+      argValues: [value],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiStdTimeStdTimeStructTwinNormalConstMeta =>
+      const TaskConstMeta(
+        debugName: "std_time_struct_twin_normal",
+        argNames: ["value"],
+      );
+
   @override
   Stream<int> crateApiStreamFuncStreamAddValueAndErrorTwinNormal() {
     final sink = RustStreamSink<int>();
@@ -94864,6 +105353,96 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["sink", "arg"],
       );
 
+  @override
+  Stream<int> crateApiStreamMiscStreamSinkBoundedAddAsyncTwinNormal() {
+    final sink = RustStreamSink<int>(
+        capacity: 2, binding: generalizedFrbRustBinding);
+    unawaited(handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_StreamSink_i_32_Dco(sink);
+        return wire
+            .wire__crate__api__stream_misc__stream_sink_bounded_add_async_twin_normal(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiStreamMiscStreamSinkBoundedAddAsyncTwinNormalConstMeta,
+      argValues: [sink],
+      apiImpl: this,
+    )));
+    return sink.stream;
+  }
+
+  TaskConstMeta
+      get kCrateApiStreamMiscStreamSinkBoundedAddAsyncTwinNormalConstMeta =>
+          const TaskConstMeta(
+            debugName: "stream_sink_bounded_add_async_twin_normal",
+            argNames: ["sink"],
+          );
+
+  @override
+  Stream<int> crateApiStreamMiscStreamSinkBoundedAddWithErrorTwinNormal() {
+    final sink = RustStreamSink<int>(
+        capacity: 1, binding: generalizedFrbRustBinding);
+    unawaited(handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_StreamSink_i_32_Dco(sink);
+        return wire
+            .wire__crate__api__stream_misc__stream_sink_bounded_add_with_error_twin_normal(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiStreamMiscStreamSinkBoundedAddWithErrorTwinNormalConstMeta,
+      argValues: [sink],
+      apiImpl: this,
+    )));
+    return sink.stream;
+  }
+
+  TaskConstMeta
+      get kCrateApiStreamMiscStreamSinkBoundedAddWithErrorTwinNormalConstMeta =>
+          const TaskConstMeta(
+            debugName: "stream_sink_bounded_add_with_error_twin_normal",
+            argNames: ["sink"],
+          );
+
+  @override
+  Stream<int> crateApiStreamMiscStreamSinkBoundedKeepLatestTwinNormal() {
+    final sink = RustStreamSink<int>(
+        capacity: 1, binding: generalizedFrbRustBinding);
+    unawaited(handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_StreamSink_i_32_Dco(sink);
+        return wire
+            .wire__crate__api__stream_misc__stream_sink_bounded_keep_latest_twin_normal(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateApiStreamMiscStreamSinkBoundedKeepLatestTwinNormalConstMeta,
+      argValues: [sink],
+      apiImpl: this,
+    )));
+    return sink.stream;
+  }
+
+  TaskConstMeta
+      get kCrateApiStreamMiscStreamSinkBoundedKeepLatestTwinNormalConstMeta =>
+          const TaskConstMeta(
+            debugName: "stream_sink_bounded_keep_latest_twin_normal",
+            argNames: ["sink"],
+          );
+
   @override
   Future<Stream<int>> crateApiStreamMiscStreamSinkDartAsyncTwinNormal() async {
     final sink = RustStreamSink<int>();
@@ -95100,6 +105679,235 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             argNames: ["arg"],
           );
 
+  @override
+  Future<DateTime> crateApiTimeTypeDateTwinNormal({required DateTime d}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_TimeDate(d);
+        return wire.wire__crate__api__time_type__date_twin_normal(
+            port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_TimeDate,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiTimeTypeDateTwinNormalConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiTimeTypeDateTwinNormalConstMeta =>
+      const TaskConstMeta(
+        debugName: "date_twin_normal",
+        argNames: ["d"],
+      );
+
+  @override
+  Future<Duration> crateApiTimeTypeNegateTimeDurationTwinNormal(
+      {required Duration d}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_TimeDuration(d);
+        return wire
+            .wire__crate__api__time_type__negate_time_duration_twin_normal(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_TimeDuration,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiTimeTypeNegateTimeDurationTwinNormalConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiTimeTypeNegateTimeDurationTwinNormalConstMeta =>
+      const TaskConstMeta(
+        debugName: "negate_time_duration_twin_normal",
+        argNames: ["d"],
+      );
+
+  @override
+  Future<DateTime> crateApiTimeTypeOffsetDateTimeTwinNormal(
+      {required DateTime d}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_TimeOffsetDateTime(d);
+        return wire.wire__crate__api__time_type__offset_date_time_twin_normal(
+            port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_TimeOffsetDateTime,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiTimeTypeOffsetDateTimeTwinNormalConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiTimeTypeOffsetDateTimeTwinNormalConstMeta =>
+      const TaskConstMeta(
+        debugName: "offset_date_time_twin_normal",
+        argNames: ["d"],
+      );
+
+  @override
+  Future<DateTime?> crateApiTimeTypeOptionalOffsetDateTimeTwinNormal(
+      {DateTime? d}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_opt_box_autoadd_TimeOffsetDateTime(d);
+        return wire
+            .wire__crate__api__time_type__optional_offset_date_time_twin_normal(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_opt_box_autoadd_TimeOffsetDateTime,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiTimeTypeOptionalOffsetDateTimeTwinNormalConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiTimeTypeOptionalOffsetDateTimeTwinNormalConstMeta =>
+          const TaskConstMeta(
+            debugName: "optional_offset_date_time_twin_normal",
+            argNames: ["d"],
+          );
+
+  @override
+  Future<Duration?> crateApiTimeTypeOptionalTimeDurationTwinNormal(
+      {Duration? d}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_opt_box_autoadd_TimeDuration(d);
+        return wire
+            .wire__crate__api__time_type__optional_time_duration_twin_normal(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_opt_box_autoadd_TimeDuration,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiTimeTypeOptionalTimeDurationTwinNormalConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiTimeTypeOptionalTimeDurationTwinNormalConstMeta =>
+      const TaskConstMeta(
+        debugName: "optional_time_duration_twin_normal",
+        argNames: ["d"],
+      );
+
+  @override
+  Future<DateTime> crateApiTimeTypePrimitiveDateTimeTwinNormal(
+      {required DateTime d}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_TimePrimitiveDateTime(d);
+        return wire
+            .wire__crate__api__time_type__primitive_date_time_twin_normal(
+                port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_TimePrimitiveDateTime,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiTimeTypePrimitiveDateTimeTwinNormalConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiTimeTypePrimitiveDateTimeTwinNormalConstMeta =>
+      const TaskConstMeta(
+        debugName: "primitive_date_time_twin_normal",
+        argNames: ["d"],
+      );
+
+  @override
+  Future<Duration> crateApiTimeTypeTimeDurationTwinNormal(
+      {required Duration d}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_TimeDuration(d);
+        return wire.wire__crate__api__time_type__time_duration_twin_normal(
+            port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_TimeDuration,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiTimeTypeTimeDurationTwinNormalConstMeta,
+      argValues: [d],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiTimeTypeTimeDurationTwinNormalConstMeta =>
+      const TaskConstMeta(
+        debugName: "time_duration_twin_normal",
+        argNames: ["d"],
+      );
+
+  @override
+  Future<List<Duration>> crateApiTimeTypeTimeDurationsTwinNormal(
+      {required List<Duration> durations}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_list_TimeDuration(durations);
+        return wire.wire__crate__api__time_type__time_durations_twin_normal(
+            port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_list_TimeDuration,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiTimeTypeTimeDurationsTwinNormalConstMeta,
+      argValues: [durations],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiTimeTypeTimeDurationsTwinNormalConstMeta =>
+      const TaskConstMeta(
+        debugName: "time_durations_twin_normal",
+        argNames: ["durations"],
+      );
+
+  @override
+  Future<TimeTypesTwinNormal> crateApiTimeTypeTimeTypesTwinNormal(
+      {required TimeTypesTwinNormal value}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_box_autoadd_time_types_twin_normal(value);
+        return wire.wire__crate__api__time_type__time_types_twin_normal(
+            port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_time_types_twin_normal,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiTimeTypeTimeTypesTwinNormalConstMeta,
+      argValues: [value],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiTimeTypeTimeTypesTwinNormalConstMeta =>
+      const TaskConstMeta(
+        debugName: "time_types_twin_normal",
+        argNames: ["value"],
+      );
+
   @override
   Future<void> crateApiTupleTestTuple2TwinNormal(
       {required List<(String, int)> value}) {
@@ -95305,6 +106113,112 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["ids"],
       );
 
+  @override
+  Future<int>
+      crateFrbGeneratedDartImplementableTraitTwinNormalDartImplAddTwinNormal(
+          {required DartImplementableTraitTwinNormalDartImpl that,
+          required int a,
+          required int b}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 =
+            cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl(
+                that);
+        var arg1 = cst_encode_i_32(a);
+        var arg2 = cst_encode_i_32(b);
+        return wire
+            .wire__crate__frb_generated__DartImplementableTraitTwinNormalDartImpl_add_twin_normal(
+                port_, arg0, arg1, arg2);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_i_32,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateFrbGeneratedDartImplementableTraitTwinNormalDartImplAddTwinNormalConstMeta,
+      argValues: [that, a, b],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateFrbGeneratedDartImplementableTraitTwinNormalDartImplAddTwinNormalConstMeta =>
+          const TaskConstMeta(
+            debugName:
+                "DartImplementableTraitTwinNormalDartImpl_add_twin_normal",
+            argNames: ["that", "a", "b"],
+          );
+
+  @override
+  Future<String>
+      crateFrbGeneratedDartImplementableTraitTwinNormalDartImplDescribeTwinNormal(
+          {required DartImplementableTraitTwinNormalDartImpl that,
+          required int value}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 =
+            cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl(
+                that);
+        var arg1 = cst_encode_i_32(value);
+        return wire
+            .wire__crate__frb_generated__DartImplementableTraitTwinNormalDartImpl_describe_twin_normal(
+                port_, arg0, arg1);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_String,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateFrbGeneratedDartImplementableTraitTwinNormalDartImplDescribeTwinNormalConstMeta,
+      argValues: [that, value],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateFrbGeneratedDartImplementableTraitTwinNormalDartImplDescribeTwinNormalConstMeta =>
+          const TaskConstMeta(
+            debugName:
+                "DartImplementableTraitTwinNormalDartImpl_describe_twin_normal",
+            argNames: ["that", "value"],
+          );
+
+  @override
+  DartImplementableTraitTwinNormalDartImpl
+      crateFrbGeneratedDartImplementableTraitTwinNormalDartImplNew(
+          {required FutureOr<int> Function(int, int) addTwinNormal,
+          required FutureOr<String> Function(int) describeTwinNormal}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        var arg0 =
+            cst_encode_DartFn_Inputs_i_32_i_32_Output_i_32_AnyhowException(
+                addTwinNormal);
+        var arg1 =
+            cst_encode_DartFn_Inputs_i_32_Output_String_AnyhowException(
+                describeTwinNormal);
+        return wire
+            .wire__crate__frb_generated__DartImplementableTraitTwinNormalDartImpl_new(
+                arg0, arg1);
+      },
+      codec: DcoCodec(
+        decodeSuccessData:
+            dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl,
+        decodeErrorData: null,
+      ),
+      constMeta:
+          kCrateFrbGeneratedDartImplementableTraitTwinNormalDartImplNewConstMeta,
+      argValues: [addTwinNormal, describeTwinNormal],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateFrbGeneratedDartImplementableTraitTwinNormalDartImplNewConstMeta =>
+          const TaskConstMeta(
+            debugName: "DartImplementableTraitTwinNormalDartImpl_new",
+            argNames: ["addTwinNormal", "describeTwinNormal"],
+          );
+
   Future<void> Function(int, dynamic)
       encode_DartFn_Inputs_DartOpaque_Output_unit_AnyhowException(
           FutureOr<void> Function(Object) raw) {
@@ -95404,19 +106318,264 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     };
   }
 
-  Future<void> Function(int, dynamic, dynamic)
-      encode_DartFn_Inputs_String_demo_struct_for_rust_call_dart_twin_normal_Output_unit_AnyhowException(
-          FutureOr<void> Function(String, DemoStructForRustCallDartTwinNormal)
-              raw) {
-    return (callId, rawArg0, rawArg1) async {
-      final arg0 = dco_decode_String(rawArg0);
-      final arg1 =
-          dco_decode_demo_struct_for_rust_call_dart_twin_normal(rawArg1);
+  Future<void> Function(int, dynamic, dynamic)
+      encode_DartFn_Inputs_String_demo_struct_for_rust_call_dart_twin_normal_Output_unit_AnyhowException(
+          FutureOr<void> Function(String, DemoStructForRustCallDartTwinNormal)
+              raw) {
+    return (callId, rawArg0, rawArg1) async {
+      final arg0 = dco_decode_String(rawArg0);
+      final arg1 =
+          dco_decode_demo_struct_for_rust_call_dart_twin_normal(rawArg1);
+
+      Box<void>? rawOutput;
+      Box<AnyhowException>? rawError;
+      try {
+        rawOutput = Box(await raw(arg0, arg1));
+      } catch (e, s) {
+        rawError = Box(AnyhowException("$e\n\n$s"));
+      }
+
+      final serializer = SseSerializer(generalizedFrbRustBinding);
+      assert((rawOutput != null) ^ (rawError != null));
+      if (rawOutput != null) {
+        serializer.buffer.putUint8(0);
+        sse_encode_unit(rawOutput.value, serializer);
+      } else {
+        serializer.buffer.putUint8(1);
+        sse_encode_AnyhowException(rawError!.value, serializer);
+      }
+      final output = serializer.intoRaw();
+
+      generalizedFrbRustBinding.dartFnDeliverOutput(
+          callId: callId,
+          ptr: output.ptr,
+          rustVecLen: output.rustVecLen,
+          dataLen: output.dataLen);
+    };
+  }
+
+  Future<void> Function(int, dynamic, dynamic)
+      encode_DartFn_Inputs_String_demo_struct_for_rust_call_dart_twin_rust_async_Output_unit_AnyhowException(
+          FutureOr<void> Function(
+                  String, DemoStructForRustCallDartTwinRustAsync)
+              raw) {
+    return (callId, rawArg0, rawArg1) async {
+      final arg0 = dco_decode_String(rawArg0);
+      final arg1 =
+          dco_decode_demo_struct_for_rust_call_dart_twin_rust_async(rawArg1);
+
+      Box<void>? rawOutput;
+      Box<AnyhowException>? rawError;
+      try {
+        rawOutput = Box(await raw(arg0, arg1));
+      } catch (e, s) {
+        rawError = Box(AnyhowException("$e\n\n$s"));
+      }
+
+      final serializer = SseSerializer(generalizedFrbRustBinding);
+      assert((rawOutput != null) ^ (rawError != null));
+      if (rawOutput != null) {
+        serializer.buffer.putUint8(0);
+        sse_encode_unit(rawOutput.value, serializer);
+      } else {
+        serializer.buffer.putUint8(1);
+        sse_encode_AnyhowException(rawError!.value, serializer);
+      }
+      final output = serializer.intoRaw();
+
+      generalizedFrbRustBinding.dartFnDeliverOutput(
+          callId: callId,
+          ptr: output.ptr,
+          rustVecLen: output.rustVecLen,
+          dataLen: output.dataLen);
+    };
+  }
+
+  Future<void> Function(int, dynamic, dynamic)
+      encode_DartFn_Inputs_String_demo_struct_for_rust_call_dart_twin_rust_async_sse_Output_unit_AnyhowException(
+          FutureOr<void> Function(
+                  String, DemoStructForRustCallDartTwinRustAsyncSse)
+              raw) {
+    return (callId, rawArg0, rawArg1) async {
+      final arg0 = dco_decode_String(rawArg0);
+      final arg1 =
+          dco_decode_demo_struct_for_rust_call_dart_twin_rust_async_sse(
+              rawArg1);
+
+      Box<void>? rawOutput;
+      Box<AnyhowException>? rawError;
+      try {
+        rawOutput = Box(await raw(arg0, arg1));
+      } catch (e, s) {
+        rawError = Box(AnyhowException("$e\n\n$s"));
+      }
+
+      final serializer = SseSerializer(generalizedFrbRustBinding);
+      assert((rawOutput != null) ^ (rawError != null));
+      if (rawOutput != null) {
+        serializer.buffer.putUint8(0);
+        sse_encode_unit(rawOutput.value, serializer);
+      } else {
+        serializer.buffer.putUint8(1);
+        sse_encode_AnyhowException(rawError!.value, serializer);
+      }
+      final output = serializer.intoRaw();
+
+      generalizedFrbRustBinding.dartFnDeliverOutput(
+          callId: callId,
+          ptr: output.ptr,
+          rustVecLen: output.rustVecLen,
+          dataLen: output.dataLen);
+    };
+  }
+
+  Future<void> Function(int, dynamic, dynamic)
+      encode_DartFn_Inputs_String_demo_struct_for_rust_call_dart_twin_sse_Output_unit_AnyhowException(
+          FutureOr<void> Function(String, DemoStructForRustCallDartTwinSse)
+              raw) {
+    return (callId, rawArg0, rawArg1) async {
+      final arg0 = dco_decode_String(rawArg0);
+      final arg1 = dco_decode_demo_struct_for_rust_call_dart_twin_sse(rawArg1);
+
+      Box<void>? rawOutput;
+      Box<AnyhowException>? rawError;
+      try {
+        rawOutput = Box(await raw(arg0, arg1));
+      } catch (e, s) {
+        rawError = Box(AnyhowException("$e\n\n$s"));
+      }
+
+      final serializer = SseSerializer(generalizedFrbRustBinding);
+      assert((rawOutput != null) ^ (rawError != null));
+      if (rawOutput != null) {
+        serializer.buffer.putUint8(0);
+        sse_encode_unit(rawOutput.value, serializer);
+      } else {
+        serializer.buffer.putUint8(1);
+        sse_encode_AnyhowException(rawError!.value, serializer);
+      }
+      final output = serializer.intoRaw();
+
+      generalizedFrbRustBinding.dartFnDeliverOutput(
+          callId: callId,
+          ptr: output.ptr,
+          rustVecLen: output.rustVecLen,
+          dataLen: output.dataLen);
+    };
+  }
+
+  Future<void> Function(
+    int,
+  ) encode_DartFn_Inputs__Output_DartOpaque_AnyhowException(
+      FutureOr<Object> Function() raw) {
+    return (
+      callId,
+    ) async {
+      Box<Object>? rawOutput;
+      Box<AnyhowException>? rawError;
+      try {
+        rawOutput = Box(await raw());
+      } catch (e, s) {
+        rawError = Box(AnyhowException("$e\n\n$s"));
+      }
+
+      final serializer = SseSerializer(generalizedFrbRustBinding);
+      assert((rawOutput != null) ^ (rawError != null));
+      if (rawOutput != null) {
+        serializer.buffer.putUint8(0);
+        sse_encode_DartOpaque(rawOutput.value, serializer);
+      } else {
+        serializer.buffer.putUint8(1);
+        sse_encode_AnyhowException(rawError!.value, serializer);
+      }
+      final output = serializer.intoRaw();
+
+      generalizedFrbRustBinding.dartFnDeliverOutput(
+          callId: callId,
+          ptr: output.ptr,
+          rustVecLen: output.rustVecLen,
+          dataLen: output.dataLen);
+    };
+  }
+
+  Future<void> Function(
+    int,
+  ) encode_DartFn_Inputs__Output_String_AnyhowException(
+      FutureOr<String> Function() raw) {
+    return (
+      callId,
+    ) async {
+      Box<String>? rawOutput;
+      Box<AnyhowException>? rawError;
+      try {
+        rawOutput = Box(await raw());
+      } catch (e, s) {
+        rawError = Box(AnyhowException("$e\n\n$s"));
+      }
+
+      final serializer = SseSerializer(generalizedFrbRustBinding);
+      assert((rawOutput != null) ^ (rawError != null));
+      if (rawOutput != null) {
+        serializer.buffer.putUint8(0);
+        sse_encode_String(rawOutput.value, serializer);
+      } else {
+        serializer.buffer.putUint8(1);
+        sse_encode_AnyhowException(rawError!.value, serializer);
+      }
+      final output = serializer.intoRaw();
+
+      generalizedFrbRustBinding.dartFnDeliverOutput(
+          callId: callId,
+          ptr: output.ptr,
+          rustVecLen: output.rustVecLen,
+          dataLen: output.dataLen);
+    };
+  }
+
+  Future<void> Function(
+    int,
+  ) encode_DartFn_Inputs__Output_unit_AnyhowException(
+      FutureOr<void> Function() raw) {
+    return (
+      callId,
+    ) async {
+      Box<void>? rawOutput;
+      Box<AnyhowException>? rawError;
+      try {
+        rawOutput = Box(await raw());
+      } catch (e, s) {
+        rawError = Box(AnyhowException("$e\n\n$s"));
+      }
+
+      final serializer = SseSerializer(generalizedFrbRustBinding);
+      assert((rawOutput != null) ^ (rawError != null));
+      if (rawOutput != null) {
+        serializer.buffer.putUint8(0);
+        sse_encode_unit(rawOutput.value, serializer);
+      } else {
+        serializer.buffer.putUint8(1);
+        sse_encode_AnyhowException(rawError!.value, serializer);
+      }
+      final output = serializer.intoRaw();
+
+      generalizedFrbRustBinding.dartFnDeliverOutput(
+          callId: callId,
+          ptr: output.ptr,
+          rustVecLen: output.rustVecLen,
+          dataLen: output.dataLen);
+    };
+  }
+
+  Future<void> Function(int, dynamic)
+      encode_DartFn_Inputs_bool_Output_opt_String_AnyhowException(
+          FutureOr<String?> Function(bool) raw) {
+    return (callId, rawArg0) async {
+      final arg0 = dco_decode_bool(rawArg0);
 
-      Box<void>? rawOutput;
+      Box<String?>? rawOutput;
       Box<AnyhowException>? rawError;
       try {
-        rawOutput = Box(await raw(arg0, arg1));
+        rawOutput = Box(await raw(arg0));
       } catch (e, s) {
         rawError = Box(AnyhowException("$e\n\n$s"));
       }
@@ -95425,7 +106584,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       assert((rawOutput != null) ^ (rawError != null));
       if (rawOutput != null) {
         serializer.buffer.putUint8(0);
-        sse_encode_unit(rawOutput.value, serializer);
+        sse_encode_opt_String(rawOutput.value, serializer);
       } else {
         serializer.buffer.putUint8(1);
         sse_encode_AnyhowException(rawError!.value, serializer);
@@ -95440,20 +106599,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     };
   }
 
-  Future<void> Function(int, dynamic, dynamic)
-      encode_DartFn_Inputs_String_demo_struct_for_rust_call_dart_twin_rust_async_Output_unit_AnyhowException(
-          FutureOr<void> Function(
-                  String, DemoStructForRustCallDartTwinRustAsync)
-              raw) {
-    return (callId, rawArg0, rawArg1) async {
-      final arg0 = dco_decode_String(rawArg0);
-      final arg1 =
-          dco_decode_demo_struct_for_rust_call_dart_twin_rust_async(rawArg1);
+  Future<void> Function(int, dynamic)
+      encode_DartFn_Inputs_bool_Output_opt_box_autoadd_i_32_AnyhowException(
+          FutureOr<int?> Function(bool) raw) {
+    return (callId, rawArg0) async {
+      final arg0 = dco_decode_bool(rawArg0);
 
-      Box<void>? rawOutput;
+      Box<int?>? rawOutput;
       Box<AnyhowException>? rawError;
       try {
-        rawOutput = Box(await raw(arg0, arg1));
+        rawOutput = Box(await raw(arg0));
       } catch (e, s) {
         rawError = Box(AnyhowException("$e\n\n$s"));
       }
@@ -95462,7 +106617,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       assert((rawOutput != null) ^ (rawError != null));
       if (rawOutput != null) {
         serializer.buffer.putUint8(0);
-        sse_encode_unit(rawOutput.value, serializer);
+        sse_encode_opt_box_autoadd_i_32(rawOutput.value, serializer);
       } else {
         serializer.buffer.putUint8(1);
         sse_encode_AnyhowException(rawError!.value, serializer);
@@ -95477,21 +106632,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     };
   }
 
-  Future<void> Function(int, dynamic, dynamic)
-      encode_DartFn_Inputs_String_demo_struct_for_rust_call_dart_twin_rust_async_sse_Output_unit_AnyhowException(
-          FutureOr<void> Function(
-                  String, DemoStructForRustCallDartTwinRustAsyncSse)
+  Future<void> Function(int, dynamic)
+      encode_DartFn_Inputs_demo_struct_for_rust_call_dart_twin_normal_Output_demo_struct_for_rust_call_dart_twin_normal_AnyhowException(
+          FutureOr<DemoStructForRustCallDartTwinNormal> Function(
+                  DemoStructForRustCallDartTwinNormal)
               raw) {
-    return (callId, rawArg0, rawArg1) async {
-      final arg0 = dco_decode_String(rawArg0);
-      final arg1 =
-          dco_decode_demo_struct_for_rust_call_dart_twin_rust_async_sse(
-              rawArg1);
+    return (callId, rawArg0) async {
+      final arg0 =
+          dco_decode_demo_struct_for_rust_call_dart_twin_normal(rawArg0);
 
-      Box<void>? rawOutput;
+      Box<DemoStructForRustCallDartTwinNormal>? rawOutput;
       Box<AnyhowException>? rawError;
       try {
-        rawOutput = Box(await raw(arg0, arg1));
+        rawOutput = Box(await raw(arg0));
       } catch (e, s) {
         rawError = Box(AnyhowException("$e\n\n$s"));
       }
@@ -95500,7 +106653,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       assert((rawOutput != null) ^ (rawError != null));
       if (rawOutput != null) {
         serializer.buffer.putUint8(0);
-        sse_encode_unit(rawOutput.value, serializer);
+        sse_encode_demo_struct_for_rust_call_dart_twin_normal(
+            rawOutput.value, serializer);
       } else {
         serializer.buffer.putUint8(1);
         sse_encode_AnyhowException(rawError!.value, serializer);
@@ -95515,120 +106669,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     };
   }
 
-  Future<void> Function(int, dynamic, dynamic)
-      encode_DartFn_Inputs_String_demo_struct_for_rust_call_dart_twin_sse_Output_unit_AnyhowException(
-          FutureOr<void> Function(String, DemoStructForRustCallDartTwinSse)
+  Future<void> Function(int, dynamic)
+      encode_DartFn_Inputs_demo_struct_for_rust_call_dart_twin_rust_async_Output_demo_struct_for_rust_call_dart_twin_rust_async_AnyhowException(
+          FutureOr<DemoStructForRustCallDartTwinRustAsync> Function(
+                  DemoStructForRustCallDartTwinRustAsync)
               raw) {
-    return (callId, rawArg0, rawArg1) async {
-      final arg0 = dco_decode_String(rawArg0);
-      final arg1 = dco_decode_demo_struct_for_rust_call_dart_twin_sse(rawArg1);
-
-      Box<void>? rawOutput;
-      Box<AnyhowException>? rawError;
-      try {
-        rawOutput = Box(await raw(arg0, arg1));
-      } catch (e, s) {
-        rawError = Box(AnyhowException("$e\n\n$s"));
-      }
-
-      final serializer = SseSerializer(generalizedFrbRustBinding);
-      assert((rawOutput != null) ^ (rawError != null));
-      if (rawOutput != null) {
-        serializer.buffer.putUint8(0);
-        sse_encode_unit(rawOutput.value, serializer);
-      } else {
-        serializer.buffer.putUint8(1);
-        sse_encode_AnyhowException(rawError!.value, serializer);
-      }
-      final output = serializer.intoRaw();
-
-      generalizedFrbRustBinding.dartFnDeliverOutput(
-          callId: callId,
-          ptr: output.ptr,
-          rustVecLen: output.rustVecLen,
-          dataLen: output.dataLen);
-    };
-  }
-
-  Future<void> Function(
-    int,
-  ) encode_DartFn_Inputs__Output_DartOpaque_AnyhowException(
-      FutureOr<Object> Function() raw) {
-    return (
-      callId,
-    ) async {
-      Box<Object>? rawOutput;
-      Box<AnyhowException>? rawError;
-      try {
-        rawOutput = Box(await raw());
-      } catch (e, s) {
-        rawError = Box(AnyhowException("$e\n\n$s"));
-      }
-
-      final serializer = SseSerializer(generalizedFrbRustBinding);
-      assert((rawOutput != null) ^ (rawError != null));
-      if (rawOutput != null) {
-        serializer.buffer.putUint8(0);
-        sse_encode_DartOpaque(rawOutput.value, serializer);
-      } else {
-        serializer.buffer.putUint8(1);
-        sse_encode_AnyhowException(rawError!.value, serializer);
-      }
-      final output = serializer.intoRaw();
-
-      generalizedFrbRustBinding.dartFnDeliverOutput(
-          callId: callId,
-          ptr: output.ptr,
-          rustVecLen: output.rustVecLen,
-          dataLen: output.dataLen);
-    };
-  }
-
-  Future<void> Function(
-    int,
-  ) encode_DartFn_Inputs__Output_String_AnyhowException(
-      FutureOr<String> Function() raw) {
-    return (
-      callId,
-    ) async {
-      Box<String>? rawOutput;
-      Box<AnyhowException>? rawError;
-      try {
-        rawOutput = Box(await raw());
-      } catch (e, s) {
-        rawError = Box(AnyhowException("$e\n\n$s"));
-      }
-
-      final serializer = SseSerializer(generalizedFrbRustBinding);
-      assert((rawOutput != null) ^ (rawError != null));
-      if (rawOutput != null) {
-        serializer.buffer.putUint8(0);
-        sse_encode_String(rawOutput.value, serializer);
-      } else {
-        serializer.buffer.putUint8(1);
-        sse_encode_AnyhowException(rawError!.value, serializer);
-      }
-      final output = serializer.intoRaw();
-
-      generalizedFrbRustBinding.dartFnDeliverOutput(
-          callId: callId,
-          ptr: output.ptr,
-          rustVecLen: output.rustVecLen,
-          dataLen: output.dataLen);
-    };
-  }
+    return (callId, rawArg0) async {
+      final arg0 =
+          dco_decode_demo_struct_for_rust_call_dart_twin_rust_async(rawArg0);
 
-  Future<void> Function(
-    int,
-  ) encode_DartFn_Inputs__Output_unit_AnyhowException(
-      FutureOr<void> Function() raw) {
-    return (
-      callId,
-    ) async {
-      Box<void>? rawOutput;
+      Box<DemoStructForRustCallDartTwinRustAsync>? rawOutput;
       Box<AnyhowException>? rawError;
       try {
-        rawOutput = Box(await raw());
+        rawOutput = Box(await raw(arg0));
       } catch (e, s) {
         rawError = Box(AnyhowException("$e\n\n$s"));
       }
@@ -95637,7 +106690,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       assert((rawOutput != null) ^ (rawError != null));
       if (rawOutput != null) {
         serializer.buffer.putUint8(0);
-        sse_encode_unit(rawOutput.value, serializer);
+        sse_encode_demo_struct_for_rust_call_dart_twin_rust_async(
+            rawOutput.value, serializer);
       } else {
         serializer.buffer.putUint8(1);
         sse_encode_AnyhowException(rawError!.value, serializer);
@@ -95653,15 +106707,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   Future<void> Function(int, dynamic)
-      encode_DartFn_Inputs_demo_struct_for_rust_call_dart_twin_normal_Output_demo_struct_for_rust_call_dart_twin_normal_AnyhowException(
-          FutureOr<DemoStructForRustCallDartTwinNormal> Function(
-                  DemoStructForRustCallDartTwinNormal)
+      encode_DartFn_Inputs_demo_struct_for_rust_call_dart_twin_rust_async_sse_Output_demo_struct_for_rust_call_dart_twin_rust_async_sse_AnyhowException(
+          FutureOr<DemoStructForRustCallDartTwinRustAsyncSse> Function(
+                  DemoStructForRustCallDartTwinRustAsyncSse)
               raw) {
     return (callId, rawArg0) async {
       final arg0 =
-          dco_decode_demo_struct_for_rust_call_dart_twin_normal(rawArg0);
+          dco_decode_demo_struct_for_rust_call_dart_twin_rust_async_sse(
+              rawArg0);
 
-      Box<DemoStructForRustCallDartTwinNormal>? rawOutput;
+      Box<DemoStructForRustCallDartTwinRustAsyncSse>? rawOutput;
       Box<AnyhowException>? rawError;
       try {
         rawOutput = Box(await raw(arg0));
@@ -95673,7 +106728,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       assert((rawOutput != null) ^ (rawError != null));
       if (rawOutput != null) {
         serializer.buffer.putUint8(0);
-        sse_encode_demo_struct_for_rust_call_dart_twin_normal(
+        sse_encode_demo_struct_for_rust_call_dart_twin_rust_async_sse(
             rawOutput.value, serializer);
       } else {
         serializer.buffer.putUint8(1);
@@ -95690,15 +106745,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   Future<void> Function(int, dynamic)
-      encode_DartFn_Inputs_demo_struct_for_rust_call_dart_twin_rust_async_Output_demo_struct_for_rust_call_dart_twin_rust_async_AnyhowException(
-          FutureOr<DemoStructForRustCallDartTwinRustAsync> Function(
-                  DemoStructForRustCallDartTwinRustAsync)
+      encode_DartFn_Inputs_demo_struct_for_rust_call_dart_twin_sse_Output_demo_struct_for_rust_call_dart_twin_sse_AnyhowException(
+          FutureOr<DemoStructForRustCallDartTwinSse> Function(
+                  DemoStructForRustCallDartTwinSse)
               raw) {
     return (callId, rawArg0) async {
-      final arg0 =
-          dco_decode_demo_struct_for_rust_call_dart_twin_rust_async(rawArg0);
+      final arg0 = dco_decode_demo_struct_for_rust_call_dart_twin_sse(rawArg0);
 
-      Box<DemoStructForRustCallDartTwinRustAsync>? rawOutput;
+      Box<DemoStructForRustCallDartTwinSse>? rawOutput;
       Box<AnyhowException>? rawError;
       try {
         rawOutput = Box(await raw(arg0));
@@ -95710,7 +106764,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       assert((rawOutput != null) ^ (rawError != null));
       if (rawOutput != null) {
         serializer.buffer.putUint8(0);
-        sse_encode_demo_struct_for_rust_call_dart_twin_rust_async(
+        sse_encode_demo_struct_for_rust_call_dart_twin_sse(
             rawOutput.value, serializer);
       } else {
         serializer.buffer.putUint8(1);
@@ -95727,16 +106781,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   Future<void> Function(int, dynamic)
-      encode_DartFn_Inputs_demo_struct_for_rust_call_dart_twin_rust_async_sse_Output_demo_struct_for_rust_call_dart_twin_rust_async_sse_AnyhowException(
-          FutureOr<DemoStructForRustCallDartTwinRustAsyncSse> Function(
-                  DemoStructForRustCallDartTwinRustAsyncSse)
-              raw) {
+      encode_DartFn_Inputs_i_32_Output_String_AnyhowException(
+          FutureOr<String> Function(int) raw) {
     return (callId, rawArg0) async {
-      final arg0 =
-          dco_decode_demo_struct_for_rust_call_dart_twin_rust_async_sse(
-              rawArg0);
+      final arg0 = dco_decode_i_32(rawArg0);
 
-      Box<DemoStructForRustCallDartTwinRustAsyncSse>? rawOutput;
+      Box<String>? rawOutput;
       Box<AnyhowException>? rawError;
       try {
         rawOutput = Box(await raw(arg0));
@@ -95748,8 +106798,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       assert((rawOutput != null) ^ (rawError != null));
       if (rawOutput != null) {
         serializer.buffer.putUint8(0);
-        sse_encode_demo_struct_for_rust_call_dart_twin_rust_async_sse(
-            rawOutput.value, serializer);
+        sse_encode_String(rawOutput.value, serializer);
       } else {
         serializer.buffer.putUint8(1);
         sse_encode_AnyhowException(rawError!.value, serializer);
@@ -95764,18 +106813,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     };
   }
 
-  Future<void> Function(int, dynamic)
-      encode_DartFn_Inputs_demo_struct_for_rust_call_dart_twin_sse_Output_demo_struct_for_rust_call_dart_twin_sse_AnyhowException(
-          FutureOr<DemoStructForRustCallDartTwinSse> Function(
-                  DemoStructForRustCallDartTwinSse)
-              raw) {
-    return (callId, rawArg0) async {
-      final arg0 = dco_decode_demo_struct_for_rust_call_dart_twin_sse(rawArg0);
+  Future<void> Function(int, dynamic, dynamic)
+      encode_DartFn_Inputs_i_32_i_32_Output_i_32_AnyhowException(
+          FutureOr<int> Function(int, int) raw) {
+    return (callId, rawArg0, rawArg1) async {
+      final arg0 = dco_decode_i_32(rawArg0);
+      final arg1 = dco_decode_i_32(rawArg1);
 
-      Box<DemoStructForRustCallDartTwinSse>? rawOutput;
+      Box<int>? rawOutput;
       Box<AnyhowException>? rawError;
       try {
-        rawOutput = Box(await raw(arg0));
+        rawOutput = Box(await raw(arg0, arg1));
       } catch (e, s) {
         rawError = Box(AnyhowException("$e\n\n$s"));
       }
@@ -95784,8 +106832,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       assert((rawOutput != null) ^ (rawError != null));
       if (rawOutput != null) {
         serializer.buffer.putUint8(0);
-        sse_encode_demo_struct_for_rust_call_dart_twin_sse(
-            rawOutput.value, serializer);
+        sse_encode_i_32(rawOutput.value, serializer);
       } else {
         serializer.buffer.putUint8(1);
         sse_encode_AnyhowException(rawError!.value, serializer);
@@ -95799,7 +106846,6 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           dataLen: output.dataLen);
     };
   }
-
   RustArcIncrementStrongCountFnType
       get rust_arc_increment_strong_count_BoxDartDebugTwinMoi => wire
           .rust_arc_increment_strong_count_RustOpaque_BoxdynDartDebugTwinMoi;
@@ -96568,6 +107614,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       get rust_arc_decrement_strong_count_ConstructorOpaqueSyncStructTwinNormal =>
           wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConstructorOpaqueSyncStructTwinNormal;
 
+  RustArcIncrementStrongCountFnType
+      get rust_arc_increment_strong_count_DartImplementableTraitTwinNormalDartImpl =>
+          wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl;
+
+  RustArcDecrementStrongCountFnType
+      get rust_arc_decrement_strong_count_DartImplementableTraitTwinNormalDartImpl =>
+          wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl;
+
   RustArcIncrementStrongCountFnType
       get rust_arc_increment_strong_count_DeliberateFailSanityCheckTwinNormal =>
           wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeliberateFailSanityCheckTwinNormal;
@@ -97424,6 +108478,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       get rust_arc_decrement_strong_count_StructTwoWithTraitTwinSyncSse => wire
           .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitTwinSyncSse;
 
+  RustArcIncrementStrongCountFnType
+      get rust_arc_increment_strong_count_StructWithDartImplementableTraitTwinNormal =>
+          wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal;
+
+  RustArcDecrementStrongCountFnType
+      get rust_arc_decrement_strong_count_StructWithDartImplementableTraitTwinNormal =>
+          wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal;
+
   RustArcIncrementStrongCountFnType
       get rust_arc_increment_strong_count_StructWithGoodAndOpaqueFieldWithoutOptionTwinMoi =>
           wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinMoi;
@@ -97551,7 +108613,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   @protected
   AnyhowException dco_decode_AnyhowException(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AnyhowException(raw as String);
+    final inner = (raw as List<dynamic>).cast<String>();
+    return AnyhowException(inner[0],
+        backtrace: inner[1].isEmpty ? null : inner[1],
+        typeName: inner[2].isEmpty ? null : inner[2],
+        causes: inner.sublist(3));
+  }
+
+  @protected
+  DartImplementableTraitTwinNormalDartImpl
+      dco_decode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl(
+          dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl(
+        raw);
   }
 
   @protected
@@ -97752,6 +108827,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         raw);
   }
 
+  @protected
+  StructWithDartImplementableTraitTwinNormal
+      dco_decode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+          dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+        raw);
+  }
+
   @protected
   BoxAnyMyDartTypeRename
       dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynAnySendSyncstatic(
@@ -97888,6 +108972,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         raw as List<dynamic>);
   }
 
+  @protected
+  DartImplementableTraitTwinNormalDartImpl
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl(
+          dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return DartImplementableTraitTwinNormalDartImplImpl.frbInternalDcoDecode(
+        raw as List<dynamic>);
+  }
+
   @protected
   DeliberateFailSanityCheckTwinNormal
       dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeliberateFailSanityCheckTwinNormal(
@@ -98771,6 +109864,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         raw as List<dynamic>);
   }
 
+  @protected
+  StructWithDartImplementableTraitTwinNormal
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+          dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return StructWithDartImplementableTraitTwinNormalImpl.frbInternalDcoDecode(
+        raw as List<dynamic>);
+  }
+
   @protected
   StructWithGoodAndOpaqueFieldWithoutOptionTwinMoi
       dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinMoi(
@@ -99195,6 +110297,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         raw as List<dynamic>);
   }
 
+  @protected
+  StructWithDartImplementableTraitTwinNormal
+      dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+          dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return StructWithDartImplementableTraitTwinNormalImpl.frbInternalDcoDecode(
+        raw as List<dynamic>);
+  }
+
   @protected
   StructWithGoodAndOpaqueFieldWithoutOptionTwinMoi
       dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinMoi(
@@ -99330,6 +110441,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         raw as List<dynamic>);
   }
 
+  @protected
+  DartImplementableTraitTwinNormalDartImpl
+      dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl(
+          dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return DartImplementableTraitTwinNormalDartImplImpl.frbInternalDcoDecode(
+        raw as List<dynamic>);
+  }
+
   @protected
   DeliberateFailSanityCheckTwinNormal
       dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeliberateFailSanityCheckTwinNormal(
@@ -99841,6 +110961,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         raw as List<dynamic>);
   }
 
+  @protected
+  StructWithDartImplementableTraitTwinNormal
+      dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+          dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return StructWithDartImplementableTraitTwinNormalImpl.frbInternalDcoDecode(
+        raw as List<dynamic>);
+  }
+
   @protected
   StructWithGoodAndOpaqueFieldWithoutOptionTwinMoi
       dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinMoi(
@@ -99967,12 +111096,44 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         raw as List<dynamic>);
   }
 
+  @protected
+  Map<String, MySize> dco_decode_BTreeMap_String_my_size(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Map.fromEntries(dco_decode_list_record_string_my_size(raw)
+        .map((e) => MapEntry(e.$1, e.$2)));
+  }
+
+  @protected
+  Map<int, int> dco_decode_BTreeMap_i_32_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Map.fromEntries(
+        dco_decode_list_record_i_32_i_32(raw).map((e) => MapEntry(e.$1, e.$2)));
+  }
+
+  @protected
+  Set<String> dco_decode_BTreeSet_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Set.from(dco_decode_list_String(raw));
+  }
+
+  @protected
+  Set<int> dco_decode_BTreeSet_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Set.from(dco_decode_list_prim_i_32_strict(raw));
+  }
+
   @protected
   String dco_decode_Backtrace(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return raw as String;
   }
 
+  @protected
+  BigInt dco_decode_BigInt(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return BigInt.parse(raw);
+  }
+
   @protected
   int dco_decode_CastedPrimitive_i_64(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -100013,6 +111174,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dcoDecodeDuration(dco_decode_i_64(raw).toInt());
   }
 
+  @protected
+  DateTimeWithOffset dco_decode_Chrono_FixedOffset(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return DateTimeWithOffset.parse(dco_decode_String(raw));
+  }
+
   @protected
   DateTime dco_decode_Chrono_Local(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -100025,6 +111192,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dcoDecodeTimestamp(ts: dco_decode_i_64(raw).toInt(), isUtc: true);
   }
 
+  @protected
+  DateTime dco_decode_Chrono_NaiveDate(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeTimestamp(ts: dco_decode_i_64(raw).toInt(), isUtc: true);
+  }
+
+  @protected
+  Duration dco_decode_Chrono_NaiveTime(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeDuration(dco_decode_i_64(raw).toInt());
+  }
+
+  @protected
+  DateTimeWithOffset dco_decode_Chrono_Tz(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return DateTimeWithOffset.parse(dco_decode_String(raw));
+  }
+
   @protected
   DateTime dco_decode_Chrono_Utc(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -100115,6 +111300,22 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     throw UnimplementedError('');
   }
 
+  @protected
+  FutureOr<String?> Function(bool)
+      dco_decode_DartFn_Inputs_bool_Output_opt_String_AnyhowException(
+          dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError('');
+  }
+
+  @protected
+  FutureOr<int?> Function(bool)
+      dco_decode_DartFn_Inputs_bool_Output_opt_box_autoadd_i_32_AnyhowException(
+          dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError('');
+  }
+
   @protected
   FutureOr<DemoStructForRustCallDartTwinNormal> Function(
           DemoStructForRustCallDartTwinNormal)
@@ -100151,6 +111352,22 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     throw UnimplementedError('');
   }
 
+  @protected
+  FutureOr<String> Function(int)
+      dco_decode_DartFn_Inputs_i_32_Output_String_AnyhowException(
+          dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError('');
+  }
+
+  @protected
+  FutureOr<int> Function(int, int)
+      dco_decode_DartFn_Inputs_i_32_i_32_Output_i_32_AnyhowException(
+          dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError('');
+  }
+
   @protected
   Object dco_decode_DartOpaque(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -100164,6 +111381,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         (raw as List<dynamic>).map(dco_decode_DartOpaque).toList());
   }
 
+  @protected
+  Stream<String> dco_decode_DartStream_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  Stream<int> dco_decode_DartStream_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  Decimal dco_decode_Decimal(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Decimal.parse(raw);
+  }
+
   @protected
   SimpleTraitForDynTwinNormal dco_decode_DynTrait_SimpleTraitForDynTwinNormal(
       dynamic raw) {
@@ -100177,6 +111412,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return BigInt.parse(raw);
   }
 
+  @protected
+  Map<String, dynamic> dco_decode_JsonMap(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return jsonDecode(dco_decode_String(raw)) as Map<String, dynamic>;
+  }
+
+  @protected
+  Object? dco_decode_JsonValue(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return jsonDecode(dco_decode_String(raw));
+  }
+
   @protected
   LtNestedTypeWithLifetimeTwinNormal
       dco_decode_Lifetimeable_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLtNestedTypeWithLifetimeTwinNormalstatic(
@@ -101593,6 +112840,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         raw as List<dynamic>);
   }
 
+  @protected
+  DartImplementableTraitTwinNormalDartImpl
+      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl(
+          dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return DartImplementableTraitTwinNormalDartImplImpl.frbInternalDcoDecode(
+        raw as List<dynamic>);
+  }
+
   @protected
   DeliberateFailSanityCheckTwinNormal
       dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeliberateFailSanityCheckTwinNormal(
@@ -102530,6 +113786,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         raw as List<dynamic>);
   }
 
+  @protected
+  StructWithDartImplementableTraitTwinNormal
+      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+          dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return StructWithDartImplementableTraitTwinNormalImpl.frbInternalDcoDecode(
+        raw as List<dynamic>);
+  }
+
   @protected
   StructWithGoodAndOpaqueFieldWithoutOptionTwinMoi
       dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinMoi(
@@ -102686,6 +113951,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return Set.from(dco_decode_list_prim_i_32_strict(raw));
   }
 
+  @protected
+  Duration dco_decode_StdDuration(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeDuration(dco_decode_i_64(raw).toInt());
+  }
+
+  @protected
+  DateTime dco_decode_StdSystemTime(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeTimestamp(ts: dco_decode_i_64(raw).toInt(), isUtc: true);
+  }
+
   @protected
   RustStreamSink<NonCloneSimpleTwinMoi>
       dco_decode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinMoi_Dco(
@@ -103133,6 +114410,37 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw as String;
   }
 
+  @protected
+  DateTime dco_decode_TimeDate(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeTimestamp(ts: dco_decode_i_64(raw).toInt(), isUtc: true);
+  }
+
+  @protected
+  Duration dco_decode_TimeDuration(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeDuration(dco_decode_i_64(raw).toInt());
+  }
+
+  @protected
+  DateTime dco_decode_TimeOffsetDateTime(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeTimestamp(ts: dco_decode_i_64(raw).toInt(), isUtc: true);
+  }
+
+  @protected
+  DateTime dco_decode_TimePrimitiveDateTime(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeTimestamp(ts: dco_decode_i_64(raw).toInt(), isUtc: true);
+  }
+
+  @protected
+  DartImplementableTraitTwinNormal
+      dco_decode_TraitDef_DartImplementableTraitTwinNormal(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
   @protected
   Issue2170Trait dco_decode_TraitDef_Issue2170Trait(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -103286,6 +114594,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return UuidValue.fromByteList(dco_decode_list_prim_u_8_strict(raw));
   }
 
+  @protected
+  List<String> dco_decode_VecDeque_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_list_String(raw);
+  }
+
+  @protected
+  Int32List dco_decode_VecDeque_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_list_prim_i_32_strict(raw);
+  }
+
   @protected
   ATwinNormal dco_decode_a_twin_normal(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -104263,6 +115583,85 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  BigNumbersTwinNormal dco_decode_big_numbers_twin_normal(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return BigNumbersTwinNormal(
+      amount: dco_decode_Decimal(arr[0]),
+      discount: dco_decode_opt_Decimal(arr[1]),
+      serial: dco_decode_BigInt(arr[2]),
+    );
+  }
+
+  @protected
+  BigNumbersTwinRustAsync dco_decode_big_numbers_twin_rust_async(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return BigNumbersTwinRustAsync(
+      amount: dco_decode_Decimal(arr[0]),
+      discount: dco_decode_opt_Decimal(arr[1]),
+      serial: dco_decode_BigInt(arr[2]),
+    );
+  }
+
+  @protected
+  BigNumbersTwinRustAsyncSse dco_decode_big_numbers_twin_rust_async_sse(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return BigNumbersTwinRustAsyncSse(
+      amount: dco_decode_Decimal(arr[0]),
+      discount: dco_decode_opt_Decimal(arr[1]),
+      serial: dco_decode_BigInt(arr[2]),
+    );
+  }
+
+  @protected
+  BigNumbersTwinSse dco_decode_big_numbers_twin_sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return BigNumbersTwinSse(
+      amount: dco_decode_Decimal(arr[0]),
+      discount: dco_decode_opt_Decimal(arr[1]),
+      serial: dco_decode_BigInt(arr[2]),
+    );
+  }
+
+  @protected
+  BigNumbersTwinSync dco_decode_big_numbers_twin_sync(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return BigNumbersTwinSync(
+      amount: dco_decode_Decimal(arr[0]),
+      discount: dco_decode_opt_Decimal(arr[1]),
+      serial: dco_decode_BigInt(arr[2]),
+    );
+  }
+
+  @protected
+  BigNumbersTwinSyncSse dco_decode_big_numbers_twin_sync_sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return BigNumbersTwinSyncSse(
+      amount: dco_decode_Decimal(arr[0]),
+      discount: dco_decode_opt_Decimal(arr[1]),
+      serial: dco_decode_BigInt(arr[2]),
+    );
+  }
+
   @protected
   BlobTwinNormal dco_decode_blob_twin_normal(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -104335,6 +115734,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw as bool;
   }
 
+  @protected
+  DartImplementableTraitTwinNormal
+      dco_decode_box_DynTrait_DartImplementableTraitTwinNormal(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
   @protected
   ApplicationEnv dco_decode_box_application_env(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -104461,6 +115867,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_Chrono_Naive(raw);
   }
 
+  @protected
+  Duration dco_decode_box_autoadd_Chrono_NaiveTime(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_Chrono_NaiveTime(raw);
+  }
+
   @protected
   DateTime dco_decode_box_autoadd_Chrono_Utc(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -104586,6 +115998,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_RustOpaque_HideDataTwinSyncSseMoi(raw);
   }
 
+  @protected
+  Duration dco_decode_box_autoadd_StdDuration(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_StdDuration(raw);
+  }
+
+  @protected
+  DateTime dco_decode_box_autoadd_StdSystemTime(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_StdSystemTime(raw);
+  }
+
+  @protected
+  Duration dco_decode_box_autoadd_TimeDuration(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_TimeDuration(raw);
+  }
+
+  @protected
+  DateTime dco_decode_box_autoadd_TimeOffsetDateTime(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_TimeOffsetDateTime(raw);
+  }
+
+  @protected
+  DateTime dco_decode_box_autoadd_TimePrimitiveDateTime(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_TimePrimitiveDateTime(raw);
+  }
+
   @protected
   ATwinNormal dco_decode_box_autoadd_a_twin_normal(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -104975,6 +116417,46 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_benchmark_blob_twin_sync_sse(raw);
   }
 
+  @protected
+  BigNumbersTwinNormal dco_decode_box_autoadd_big_numbers_twin_normal(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_big_numbers_twin_normal(raw);
+  }
+
+  @protected
+  BigNumbersTwinRustAsync dco_decode_box_autoadd_big_numbers_twin_rust_async(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_big_numbers_twin_rust_async(raw);
+  }
+
+  @protected
+  BigNumbersTwinRustAsyncSse
+      dco_decode_box_autoadd_big_numbers_twin_rust_async_sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_big_numbers_twin_rust_async_sse(raw);
+  }
+
+  @protected
+  BigNumbersTwinSse dco_decode_box_autoadd_big_numbers_twin_sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_big_numbers_twin_sse(raw);
+  }
+
+  @protected
+  BigNumbersTwinSync dco_decode_box_autoadd_big_numbers_twin_sync(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_big_numbers_twin_sync(raw);
+  }
+
+  @protected
+  BigNumbersTwinSyncSse dco_decode_box_autoadd_big_numbers_twin_sync_sse(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_big_numbers_twin_sync_sse(raw);
+  }
+
   @protected
   bool dco_decode_box_autoadd_bool(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -105320,6 +116802,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_customized_twin_sync_sse(raw);
   }
 
+  @protected
+  DartImplementableTraitTwinNormalImplementor
+      dco_decode_box_autoadd_dart_implementable_trait_twin_normal_implementor(
+          dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_dart_implementable_trait_twin_normal_implementor(raw);
+  }
+
   @protected
   DartOpaqueNestedTwinNormal
       dco_decode_box_autoadd_dart_opaque_nested_twin_normal(dynamic raw) {
@@ -106022,6 +117512,48 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_item_container_solution_two_twin_normal(raw);
   }
 
+  @protected
+  JsonDocumentTwinNormal dco_decode_box_autoadd_json_document_twin_normal(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_json_document_twin_normal(raw);
+  }
+
+  @protected
+  JsonDocumentTwinRustAsync
+      dco_decode_box_autoadd_json_document_twin_rust_async(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_json_document_twin_rust_async(raw);
+  }
+
+  @protected
+  JsonDocumentTwinRustAsyncSse
+      dco_decode_box_autoadd_json_document_twin_rust_async_sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_json_document_twin_rust_async_sse(raw);
+  }
+
+  @protected
+  JsonDocumentTwinSse dco_decode_box_autoadd_json_document_twin_sse(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_json_document_twin_sse(raw);
+  }
+
+  @protected
+  JsonDocumentTwinSync dco_decode_box_autoadd_json_document_twin_sync(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_json_document_twin_sync(raw);
+  }
+
+  @protected
+  JsonDocumentTwinSyncSse dco_decode_box_autoadd_json_document_twin_sync_sse(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_json_document_twin_sync_sse(raw);
+  }
+
   @protected
   KitchenSinkTwinNormal dco_decode_box_autoadd_kitchen_sink_twin_normal(
       dynamic raw) {
@@ -106554,6 +118086,45 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw as (String, int);
   }
 
+  @protected
+  ScheduleTwinNormal dco_decode_box_autoadd_schedule_twin_normal(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_schedule_twin_normal(raw);
+  }
+
+  @protected
+  ScheduleTwinRustAsync dco_decode_box_autoadd_schedule_twin_rust_async(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_schedule_twin_rust_async(raw);
+  }
+
+  @protected
+  ScheduleTwinRustAsyncSse dco_decode_box_autoadd_schedule_twin_rust_async_sse(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_schedule_twin_rust_async_sse(raw);
+  }
+
+  @protected
+  ScheduleTwinSse dco_decode_box_autoadd_schedule_twin_sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_schedule_twin_sse(raw);
+  }
+
+  @protected
+  ScheduleTwinSync dco_decode_box_autoadd_schedule_twin_sync(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_schedule_twin_sync(raw);
+  }
+
+  @protected
+  ScheduleTwinSyncSse dco_decode_box_autoadd_schedule_twin_sync_sse(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_schedule_twin_sync_sse(raw);
+  }
+
   @protected
   Sequences dco_decode_box_autoadd_sequences(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -106743,6 +118314,45 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_some_struct_twin_sync_sse(raw);
   }
 
+  @protected
+  StdTimeTwinNormal dco_decode_box_autoadd_std_time_twin_normal(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_std_time_twin_normal(raw);
+  }
+
+  @protected
+  StdTimeTwinRustAsync dco_decode_box_autoadd_std_time_twin_rust_async(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_std_time_twin_rust_async(raw);
+  }
+
+  @protected
+  StdTimeTwinRustAsyncSse dco_decode_box_autoadd_std_time_twin_rust_async_sse(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_std_time_twin_rust_async_sse(raw);
+  }
+
+  @protected
+  StdTimeTwinSse dco_decode_box_autoadd_std_time_twin_sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_std_time_twin_sse(raw);
+  }
+
+  @protected
+  StdTimeTwinSync dco_decode_box_autoadd_std_time_twin_sync(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_std_time_twin_sync(raw);
+  }
+
+  @protected
+  StdTimeTwinSyncSse dco_decode_box_autoadd_std_time_twin_sync_sse(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_std_time_twin_sync_sse(raw);
+  }
+
   @protected
   StructInLowerLevel dco_decode_box_autoadd_struct_in_lower_level(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -107360,6 +118970,46 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_test_id_twin_sync_sse(raw);
   }
 
+  @protected
+  TimeTypesTwinNormal dco_decode_box_autoadd_time_types_twin_normal(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_time_types_twin_normal(raw);
+  }
+
+  @protected
+  TimeTypesTwinRustAsync dco_decode_box_autoadd_time_types_twin_rust_async(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_time_types_twin_rust_async(raw);
+  }
+
+  @protected
+  TimeTypesTwinRustAsyncSse
+      dco_decode_box_autoadd_time_types_twin_rust_async_sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_time_types_twin_rust_async_sse(raw);
+  }
+
+  @protected
+  TimeTypesTwinSse dco_decode_box_autoadd_time_types_twin_sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_time_types_twin_sse(raw);
+  }
+
+  @protected
+  TimeTypesTwinSync dco_decode_box_autoadd_time_types_twin_sync(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_time_types_twin_sync(raw);
+  }
+
+  @protected
+  TimeTypesTwinSyncSse dco_decode_box_autoadd_time_types_twin_sync_sse(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_time_types_twin_sync_sse(raw);
+  }
+
   @protected
   TranslatableStructWithDartCodeTwinNormal
       dco_decode_box_autoadd_translatable_struct_with_dart_code_twin_normal(
@@ -109121,6 +120771,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  DartImplementableTraitTwinNormalImplementor
+      dco_decode_dart_implementable_trait_twin_normal_implementor(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return DartImplementableTraitTwinNormalImplementor_Variant0(
+          dco_decode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl(
+              raw[1]),
+        );
+      case 1:
+        return DartImplementableTraitTwinNormalImplementor_Variant1(
+          dco_decode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+              raw[1]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
   @protected
   DartOpaqueNestedTwinNormal dco_decode_dart_opaque_nested_twin_normal(
       dynamic raw) {
@@ -111226,6 +122896,86 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  JsonDocumentTwinNormal dco_decode_json_document_twin_normal(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return JsonDocumentTwinNormal(
+      body: dco_decode_JsonValue(arr[0]),
+      meta: dco_decode_JsonMap(arr[1]),
+      extra: dco_decode_opt_JsonValue(arr[2]),
+    );
+  }
+
+  @protected
+  JsonDocumentTwinRustAsync dco_decode_json_document_twin_rust_async(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return JsonDocumentTwinRustAsync(
+      body: dco_decode_JsonValue(arr[0]),
+      meta: dco_decode_JsonMap(arr[1]),
+      extra: dco_decode_opt_JsonValue(arr[2]),
+    );
+  }
+
+  @protected
+  JsonDocumentTwinRustAsyncSse dco_decode_json_document_twin_rust_async_sse(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return JsonDocumentTwinRustAsyncSse(
+      body: dco_decode_JsonValue(arr[0]),
+      meta: dco_decode_JsonMap(arr[1]),
+      extra: dco_decode_opt_JsonValue(arr[2]),
+    );
+  }
+
+  @protected
+  JsonDocumentTwinSse dco_decode_json_document_twin_sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return JsonDocumentTwinSse(
+      body: dco_decode_JsonValue(arr[0]),
+      meta: dco_decode_JsonMap(arr[1]),
+      extra: dco_decode_opt_JsonValue(arr[2]),
+    );
+  }
+
+  @protected
+  JsonDocumentTwinSync dco_decode_json_document_twin_sync(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return JsonDocumentTwinSync(
+      body: dco_decode_JsonValue(arr[0]),
+      meta: dco_decode_JsonMap(arr[1]),
+      extra: dco_decode_opt_JsonValue(arr[2]),
+    );
+  }
+
+  @protected
+  JsonDocumentTwinSyncSse dco_decode_json_document_twin_sync_sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return JsonDocumentTwinSyncSse(
+      body: dco_decode_JsonValue(arr[0]),
+      meta: dco_decode_JsonMap(arr[1]),
+      extra: dco_decode_opt_JsonValue(arr[2]),
+    );
+  }
+
   @protected
   KitchenSinkTwinNormal dco_decode_kitchen_sink_twin_normal(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -111592,12 +123342,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         .toList();
   }
 
+  @protected
+  List<BigInt> dco_decode_list_BigInt(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_BigInt).toList();
+  }
+
   @protected
   List<Duration> dco_decode_list_Chrono_Duration(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return (raw as List<dynamic>).map(dco_decode_Chrono_Duration).toList();
   }
 
+  @protected
+  List<DateTimeWithOffset> dco_decode_list_Chrono_FixedOffset(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_Chrono_FixedOffset).toList();
+  }
+
   @protected
   List<DateTime> dco_decode_list_Chrono_Local(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -111610,12 +123372,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (raw as List<dynamic>).map(dco_decode_Chrono_Naive).toList();
   }
 
+  @protected
+  List<DateTime> dco_decode_list_Chrono_NaiveDate(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_Chrono_NaiveDate).toList();
+  }
+
+  @protected
+  List<DateTimeWithOffset> dco_decode_list_Chrono_Tz(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_Chrono_Tz).toList();
+  }
+
   @protected
   List<Object> dco_decode_list_DartOpaque(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return (raw as List<dynamic>).map(dco_decode_DartOpaque).toList();
   }
 
+  @protected
+  List<Decimal> dco_decode_list_Decimal(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_Decimal).toList();
+  }
+
+  @protected
+  List<Object?> dco_decode_list_JsonValue(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_JsonValue).toList();
+  }
+
   @protected
   List<HideDataTwinMoi> dco_decode_list_RustOpaque_HideDataTwinMoi(
       dynamic raw) {
@@ -111724,6 +123510,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         .toList();
   }
 
+  @protected
+  List<Duration> dco_decode_list_StdDuration(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_StdDuration).toList();
+  }
+
+  @protected
+  List<DateTime> dco_decode_list_StdSystemTime(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_StdSystemTime).toList();
+  }
+
   @protected
   List<RustStreamSink<int>> dco_decode_list_StreamSink_i_32_Dco(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -111742,6 +123540,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (raw as List<dynamic>).map(dco_decode_String).toList();
   }
 
+  @protected
+  List<Duration> dco_decode_list_TimeDuration(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_TimeDuration).toList();
+  }
+
   @protected
   List<UuidValue> dco_decode_list_Uuid(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -114312,12 +126116,42 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  BigInt? dco_decode_opt_BigInt(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_BigInt(raw);
+  }
+
+  @protected
+  DateTimeWithOffset? dco_decode_opt_Chrono_FixedOffset(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_Chrono_FixedOffset(raw);
+  }
+
+  @protected
+  DateTimeWithOffset? dco_decode_opt_Chrono_Tz(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_Chrono_Tz(raw);
+  }
+
+  @protected
+  Decimal? dco_decode_opt_Decimal(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_Decimal(raw);
+  }
+
   @protected
   BigInt? dco_decode_opt_I128(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return raw == null ? null : dco_decode_I128(raw);
   }
 
+  @protected
+  Object? dco_decode_opt_JsonValue(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_JsonValue(raw);
+  }
+
   @protected
   String? dco_decode_opt_String(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -114474,6 +126308,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw == null ? null : dco_decode_box_autoadd_Chrono_Naive(raw);
   }
 
+  @protected
+  Duration? dco_decode_opt_box_autoadd_Chrono_NaiveTime(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_Chrono_NaiveTime(raw);
+  }
+
   @protected
   DateTime? dco_decode_opt_box_autoadd_Chrono_Utc(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -114638,6 +126478,39 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         : dco_decode_box_autoadd_RustOpaque_HideDataTwinSyncSseMoi(raw);
   }
 
+  @protected
+  Duration? dco_decode_opt_box_autoadd_StdDuration(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_StdDuration(raw);
+  }
+
+  @protected
+  DateTime? dco_decode_opt_box_autoadd_StdSystemTime(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_StdSystemTime(raw);
+  }
+
+  @protected
+  Duration? dco_decode_opt_box_autoadd_TimeDuration(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_TimeDuration(raw);
+  }
+
+  @protected
+  DateTime? dco_decode_opt_box_autoadd_TimeOffsetDateTime(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_TimeOffsetDateTime(raw);
+  }
+
+  @protected
+  DateTime?
+      dco_decode_opt_box_autoadd_TimePrimitiveDateTime(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null
+        ? null
+        : dco_decode_box_autoadd_TimePrimitiveDateTime(raw);
+  }
+
   @protected
   ApplicationEnv? dco_decode_opt_box_autoadd_application_env(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -116783,6 +128656,91 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  ScheduleTwinNormal dco_decode_schedule_twin_normal(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ScheduleTwinNormal(
+      day: dco_decode_Chrono_NaiveDate(arr[0]),
+      starts: dco_decode_Chrono_NaiveTime(arr[1]),
+      ends: dco_decode_opt_box_autoadd_Chrono_NaiveTime(arr[2]),
+      at: dco_decode_Chrono_FixedOffset(arr[3]),
+    );
+  }
+
+  @protected
+  ScheduleTwinRustAsync dco_decode_schedule_twin_rust_async(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ScheduleTwinRustAsync(
+      day: dco_decode_Chrono_NaiveDate(arr[0]),
+      starts: dco_decode_Chrono_NaiveTime(arr[1]),
+      ends: dco_decode_opt_box_autoadd_Chrono_NaiveTime(arr[2]),
+      at: dco_decode_Chrono_FixedOffset(arr[3]),
+    );
+  }
+
+  @protected
+  ScheduleTwinRustAsyncSse dco_decode_schedule_twin_rust_async_sse(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ScheduleTwinRustAsyncSse(
+      day: dco_decode_Chrono_NaiveDate(arr[0]),
+      starts: dco_decode_Chrono_NaiveTime(arr[1]),
+      ends: dco_decode_opt_box_autoadd_Chrono_NaiveTime(arr[2]),
+      at: dco_decode_Chrono_FixedOffset(arr[3]),
+    );
+  }
+
+  @protected
+  ScheduleTwinSse dco_decode_schedule_twin_sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ScheduleTwinSse(
+      day: dco_decode_Chrono_NaiveDate(arr[0]),
+      starts: dco_decode_Chrono_NaiveTime(arr[1]),
+      ends: dco_decode_opt_box_autoadd_Chrono_NaiveTime(arr[2]),
+      at: dco_decode_Chrono_FixedOffset(arr[3]),
+    );
+  }
+
+  @protected
+  ScheduleTwinSync dco_decode_schedule_twin_sync(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ScheduleTwinSync(
+      day: dco_decode_Chrono_NaiveDate(arr[0]),
+      starts: dco_decode_Chrono_NaiveTime(arr[1]),
+      ends: dco_decode_opt_box_autoadd_Chrono_NaiveTime(arr[2]),
+      at: dco_decode_Chrono_FixedOffset(arr[3]),
+    );
+  }
+
+  @protected
+  ScheduleTwinSyncSse dco_decode_schedule_twin_sync_sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ScheduleTwinSyncSse(
+      day: dco_decode_Chrono_NaiveDate(arr[0]),
+      starts: dco_decode_Chrono_NaiveTime(arr[1]),
+      ends: dco_decode_opt_box_autoadd_Chrono_NaiveTime(arr[2]),
+      at: dco_decode_Chrono_FixedOffset(arr[3]),
+    );
+  }
+
   @protected
   Sequences dco_decode_sequences(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -117323,6 +129281,84 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  StdTimeTwinNormal dco_decode_std_time_twin_normal(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return StdTimeTwinNormal(
+      duration: dco_decode_StdDuration(arr[0]),
+      time: dco_decode_StdSystemTime(arr[1]),
+      optionalDuration: dco_decode_opt_box_autoadd_StdDuration(arr[2]),
+    );
+  }
+
+  @protected
+  StdTimeTwinRustAsync dco_decode_std_time_twin_rust_async(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return StdTimeTwinRustAsync(
+      duration: dco_decode_StdDuration(arr[0]),
+      time: dco_decode_StdSystemTime(arr[1]),
+      optionalDuration: dco_decode_opt_box_autoadd_StdDuration(arr[2]),
+    );
+  }
+
+  @protected
+  StdTimeTwinRustAsyncSse dco_decode_std_time_twin_rust_async_sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return StdTimeTwinRustAsyncSse(
+      duration: dco_decode_StdDuration(arr[0]),
+      time: dco_decode_StdSystemTime(arr[1]),
+      optionalDuration: dco_decode_opt_box_autoadd_StdDuration(arr[2]),
+    );
+  }
+
+  @protected
+  StdTimeTwinSse dco_decode_std_time_twin_sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return StdTimeTwinSse(
+      duration: dco_decode_StdDuration(arr[0]),
+      time: dco_decode_StdSystemTime(arr[1]),
+      optionalDuration: dco_decode_opt_box_autoadd_StdDuration(arr[2]),
+    );
+  }
+
+  @protected
+  StdTimeTwinSync dco_decode_std_time_twin_sync(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return StdTimeTwinSync(
+      duration: dco_decode_StdDuration(arr[0]),
+      time: dco_decode_StdSystemTime(arr[1]),
+      optionalDuration: dco_decode_opt_box_autoadd_StdDuration(arr[2]),
+    );
+  }
+
+  @protected
+  StdTimeTwinSyncSse dco_decode_std_time_twin_sync_sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return StdTimeTwinSyncSse(
+      duration: dco_decode_StdDuration(arr[0]),
+      time: dco_decode_StdSystemTime(arr[1]),
+      optionalDuration: dco_decode_opt_box_autoadd_StdDuration(arr[2]),
+    );
+  }
+
   @protected
   StructInLowerLevel dco_decode_struct_in_lower_level(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -118760,6 +130796,91 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  TimeTypesTwinNormal dco_decode_time_types_twin_normal(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return TimeTypesTwinNormal(
+      offset: dco_decode_TimeOffsetDateTime(arr[0]),
+      primitive: dco_decode_opt_box_autoadd_TimePrimitiveDateTime(arr[1]),
+      date: dco_decode_TimeDate(arr[2]),
+      duration: dco_decode_TimeDuration(arr[3]),
+    );
+  }
+
+  @protected
+  TimeTypesTwinRustAsync dco_decode_time_types_twin_rust_async(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return TimeTypesTwinRustAsync(
+      offset: dco_decode_TimeOffsetDateTime(arr[0]),
+      primitive: dco_decode_opt_box_autoadd_TimePrimitiveDateTime(arr[1]),
+      date: dco_decode_TimeDate(arr[2]),
+      duration: dco_decode_TimeDuration(arr[3]),
+    );
+  }
+
+  @protected
+  TimeTypesTwinRustAsyncSse dco_decode_time_types_twin_rust_async_sse(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return TimeTypesTwinRustAsyncSse(
+      offset: dco_decode_TimeOffsetDateTime(arr[0]),
+      primitive: dco_decode_opt_box_autoadd_TimePrimitiveDateTime(arr[1]),
+      date: dco_decode_TimeDate(arr[2]),
+      duration: dco_decode_TimeDuration(arr[3]),
+    );
+  }
+
+  @protected
+  TimeTypesTwinSse dco_decode_time_types_twin_sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return TimeTypesTwinSse(
+      offset: dco_decode_TimeOffsetDateTime(arr[0]),
+      primitive: dco_decode_opt_box_autoadd_TimePrimitiveDateTime(arr[1]),
+      date: dco_decode_TimeDate(arr[2]),
+      duration: dco_decode_TimeDuration(arr[3]),
+    );
+  }
+
+  @protected
+  TimeTypesTwinSync dco_decode_time_types_twin_sync(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return TimeTypesTwinSync(
+      offset: dco_decode_TimeOffsetDateTime(arr[0]),
+      primitive: dco_decode_opt_box_autoadd_TimePrimitiveDateTime(arr[1]),
+      date: dco_decode_TimeDate(arr[2]),
+      duration: dco_decode_TimeDuration(arr[3]),
+    );
+  }
+
+  @protected
+  TimeTypesTwinSyncSse dco_decode_time_types_twin_sync_sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return TimeTypesTwinSyncSse(
+      offset: dco_decode_TimeOffsetDateTime(arr[0]),
+      primitive: dco_decode_opt_box_autoadd_TimePrimitiveDateTime(arr[1]),
+      date: dco_decode_TimeDate(arr[2]),
+      duration: dco_decode_TimeDuration(arr[3]),
+    );
+  }
+
   @protected
   TranslatableStructWithDartCodeTwinNormal
       dco_decode_translatable_struct_with_dart_code_twin_normal(dynamic raw) {
@@ -118922,6 +131043,150 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  TypedExceptionErrorTwinNormal dco_decode_typed_exception_error_twin_normal(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return TypedExceptionErrorTwinNormal_NotFound(
+          path: dco_decode_String(raw[1]),
+          displayMessage: dco_decode_String(raw[2]),
+        );
+      case 1:
+        return TypedExceptionErrorTwinNormal_Io(
+          dco_decode_String(raw[1]),
+          displayMessage: dco_decode_String(raw[2]),
+        );
+      case 2:
+        return TypedExceptionErrorTwinNormal_Unknown(
+          displayMessage: dco_decode_String(raw[1]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  TypedExceptionErrorTwinRustAsync
+      dco_decode_typed_exception_error_twin_rust_async(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return TypedExceptionErrorTwinRustAsync_NotFound(
+          path: dco_decode_String(raw[1]),
+          displayMessage: dco_decode_String(raw[2]),
+        );
+      case 1:
+        return TypedExceptionErrorTwinRustAsync_Io(
+          dco_decode_String(raw[1]),
+          displayMessage: dco_decode_String(raw[2]),
+        );
+      case 2:
+        return TypedExceptionErrorTwinRustAsync_Unknown(
+          displayMessage: dco_decode_String(raw[1]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  TypedExceptionErrorTwinRustAsyncSse
+      dco_decode_typed_exception_error_twin_rust_async_sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return TypedExceptionErrorTwinRustAsyncSse_NotFound(
+          path: dco_decode_String(raw[1]),
+          displayMessage: dco_decode_String(raw[2]),
+        );
+      case 1:
+        return TypedExceptionErrorTwinRustAsyncSse_Io(
+          dco_decode_String(raw[1]),
+          displayMessage: dco_decode_String(raw[2]),
+        );
+      case 2:
+        return TypedExceptionErrorTwinRustAsyncSse_Unknown(
+          displayMessage: dco_decode_String(raw[1]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  TypedExceptionErrorTwinSse dco_decode_typed_exception_error_twin_sse(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return TypedExceptionErrorTwinSse_NotFound(
+          path: dco_decode_String(raw[1]),
+          displayMessage: dco_decode_String(raw[2]),
+        );
+      case 1:
+        return TypedExceptionErrorTwinSse_Io(
+          dco_decode_String(raw[1]),
+          displayMessage: dco_decode_String(raw[2]),
+        );
+      case 2:
+        return TypedExceptionErrorTwinSse_Unknown(
+          displayMessage: dco_decode_String(raw[1]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  TypedExceptionErrorTwinSync dco_decode_typed_exception_error_twin_sync(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return TypedExceptionErrorTwinSync_NotFound(
+          path: dco_decode_String(raw[1]),
+          displayMessage: dco_decode_String(raw[2]),
+        );
+      case 1:
+        return TypedExceptionErrorTwinSync_Io(
+          dco_decode_String(raw[1]),
+          displayMessage: dco_decode_String(raw[2]),
+        );
+      case 2:
+        return TypedExceptionErrorTwinSync_Unknown(
+          displayMessage: dco_decode_String(raw[1]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  TypedExceptionErrorTwinSyncSse dco_decode_typed_exception_error_twin_sync_sse(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return TypedExceptionErrorTwinSyncSse_NotFound(
+          path: dco_decode_String(raw[1]),
+          displayMessage: dco_decode_String(raw[2]),
+        );
+      case 1:
+        return TypedExceptionErrorTwinSyncSse_Io(
+          dco_decode_String(raw[1]),
+          displayMessage: dco_decode_String(raw[2]),
+        );
+      case 2:
+        return TypedExceptionErrorTwinSyncSse_Unknown(
+          displayMessage: dco_decode_String(raw[1]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
   @protected
   int dco_decode_u_16(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -119226,8 +131491,22 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   @protected
   AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_String(deserializer);
-    return AnyhowException(inner);
+    var inner = sse_decode_list_String(deserializer);
+    return AnyhowException(inner[0],
+        backtrace: inner[1].isEmpty ? null : inner[1],
+        typeName: inner[2].isEmpty ? null : inner[2],
+        causes: inner.sublist(3));
+  }
+
+  @protected
+  DartImplementableTraitTwinNormalDartImpl
+      sse_decode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl(
+          SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner =
+        sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl(
+            deserializer);
+    return inner;
   }
 
   @protected
@@ -119472,6 +131751,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return inner;
   }
 
+  @protected
+  StructWithDartImplementableTraitTwinNormal
+      sse_decode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+          SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner =
+        sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+            deserializer);
+    return inner;
+  }
+
   @protected
   BoxAnyMyDartTypeRename
       sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynAnySendSyncstatic(
@@ -119616,6 +131906,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
   }
 
+  @protected
+  DartImplementableTraitTwinNormalDartImpl
+      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl(
+          SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return DartImplementableTraitTwinNormalDartImplImpl.frbInternalSseDecode(
+        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
   @protected
   DeliberateFailSanityCheckTwinNormal
       sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeliberateFailSanityCheckTwinNormal(
@@ -120534,6 +132833,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
   }
 
+  @protected
+  StructWithDartImplementableTraitTwinNormal
+      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+          SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return StructWithDartImplementableTraitTwinNormalImpl.frbInternalSseDecode(
+        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
   @protected
   StructWithGoodAndOpaqueFieldWithoutOptionTwinMoi
       sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinMoi(
@@ -120978,6 +133286,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
   }
 
+  @protected
+  StructWithDartImplementableTraitTwinNormal
+      sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+          SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return StructWithDartImplementableTraitTwinNormalImpl.frbInternalSseDecode(
+        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
   @protected
   StructWithGoodAndOpaqueFieldWithoutOptionTwinMoi
       sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinMoi(
@@ -121125,6 +133442,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
   }
 
+  @protected
+  DartImplementableTraitTwinNormalDartImpl
+      sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl(
+          SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return DartImplementableTraitTwinNormalDartImplImpl.frbInternalSseDecode(
+        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
   @protected
   DeliberateFailSanityCheckTwinNormal
       sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeliberateFailSanityCheckTwinNormal(
@@ -121647,6 +133973,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
   }
 
+  @protected
+  StructWithDartImplementableTraitTwinNormal
+      sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+          SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return StructWithDartImplementableTraitTwinNormalImpl.frbInternalSseDecode(
+        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
   @protected
   StructWithGoodAndOpaqueFieldWithoutOptionTwinMoi
       sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinMoi(
@@ -121785,6 +134120,35 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
   }
 
+  @protected
+  Map<String, MySize> sse_decode_BTreeMap_String_my_size(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_record_string_my_size(deserializer);
+    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
+  }
+
+  @protected
+  Map<int, int> sse_decode_BTreeMap_i_32_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_record_i_32_i_32(deserializer);
+    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
+  }
+
+  @protected
+  Set<String> sse_decode_BTreeSet_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_String(deserializer);
+    return Set.from(inner);
+  }
+
+  @protected
+  Set<int> sse_decode_BTreeSet_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_prim_i_32_strict(deserializer);
+    return Set.from(inner);
+  }
+
   @protected
   String sse_decode_Backtrace(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -121792,6 +134156,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return inner;
   }
 
+  @protected
+  BigInt sse_decode_BigInt(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_String(deserializer);
+    return BigInt.parse(inner);
+  }
+
   @protected
   int sse_decode_CastedPrimitive_i_64(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -121834,6 +134205,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return Duration(microseconds: inner.toInt());
   }
 
+  @protected
+  DateTimeWithOffset sse_decode_Chrono_FixedOffset(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_String(deserializer);
+    return DateTimeWithOffset.parse(inner);
+  }
+
   @protected
   DateTime sse_decode_Chrono_Local(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -121848,6 +134227,27 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return DateTime.fromMicrosecondsSinceEpoch(inner.toInt(), isUtc: true);
   }
 
+  @protected
+  DateTime sse_decode_Chrono_NaiveDate(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_64(deserializer);
+    return DateTime.fromMicrosecondsSinceEpoch(inner.toInt(), isUtc: true);
+  }
+
+  @protected
+  Duration sse_decode_Chrono_NaiveTime(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_64(deserializer);
+    return Duration(microseconds: inner.toInt());
+  }
+
+  @protected
+  DateTimeWithOffset sse_decode_Chrono_Tz(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_String(deserializer);
+    return DateTimeWithOffset.parse(inner);
+  }
+
   @protected
   DateTime sse_decode_Chrono_Utc(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -121877,6 +134277,27 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ObjectArray1(inner);
   }
 
+  @protected
+  Stream<String> sse_decode_DartStream_String(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  Stream<int> sse_decode_DartStream_i_32(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  Decimal sse_decode_Decimal(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_String(deserializer);
+    return Decimal.parse(inner);
+  }
+
   @protected
   SimpleTraitForDynTwinNormal sse_decode_DynTrait_SimpleTraitForDynTwinNormal(
       SseDeserializer deserializer) {
@@ -121891,6 +134312,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return BigInt.parse(inner);
   }
 
+  @protected
+  Map<String, dynamic> sse_decode_JsonMap(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_String(deserializer);
+    return jsonDecode(inner) as Map<String, dynamic>;
+  }
+
+  @protected
+  Object? sse_decode_JsonValue(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_String(deserializer);
+    return jsonDecode(inner);
+  }
+
   @protected
   LtNestedTypeWithLifetimeTwinNormal
       sse_decode_Lifetimeable_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLtNestedTypeWithLifetimeTwinNormalstatic(
@@ -123414,6 +135849,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
   }
 
+  @protected
+  DartImplementableTraitTwinNormalDartImpl
+      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl(
+          SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return DartImplementableTraitTwinNormalDartImplImpl.frbInternalSseDecode(
+        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
   @protected
   DeliberateFailSanityCheckTwinNormal
       sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeliberateFailSanityCheckTwinNormal(
@@ -124386,6 +136830,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
   }
 
+  @protected
+  StructWithDartImplementableTraitTwinNormal
+      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+          SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return StructWithDartImplementableTraitTwinNormalImpl.frbInternalSseDecode(
+        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
   @protected
   StructWithGoodAndOpaqueFieldWithoutOptionTwinMoi
       sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinMoi(
@@ -124560,6 +137013,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return Set.from(inner);
   }
 
+  @protected
+  Duration sse_decode_StdDuration(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_64(deserializer);
+    return Duration(microseconds: inner.toInt());
+  }
+
+  @protected
+  DateTime sse_decode_StdSystemTime(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_64(deserializer);
+    return DateTime.fromMicrosecondsSinceEpoch(inner.toInt(), isUtc: true);
+  }
+
   @protected
   RustStreamSink<NonCloneSimpleTwinMoi>
       sse_decode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinMoi_Dco(
@@ -125045,6 +137512,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return utf8.decoder.convert(inner);
   }
 
+  @protected
+  DateTime sse_decode_TimeDate(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_64(deserializer);
+    return DateTime.fromMicrosecondsSinceEpoch(inner.toInt(), isUtc: true);
+  }
+
+  @protected
+  Duration sse_decode_TimeDuration(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_64(deserializer);
+    return Duration(microseconds: inner.toInt());
+  }
+
+  @protected
+  DateTime sse_decode_TimeOffsetDateTime(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_64(deserializer);
+    return DateTime.fromMicrosecondsSinceEpoch(inner.toInt(), isUtc: true);
+  }
+
+  @protected
+  DateTime sse_decode_TimePrimitiveDateTime(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_64(deserializer);
+    return DateTime.fromMicrosecondsSinceEpoch(inner.toInt(), isUtc: true);
+  }
+
   @protected
   BigInt sse_decode_U128(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -125059,6 +137554,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return UuidValue.fromByteList(inner);
   }
 
+  @protected
+  List<String> sse_decode_VecDeque_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_String(deserializer);
+    return inner;
+  }
+
+  @protected
+  Int32List sse_decode_VecDeque_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_prim_i_32_strict(deserializer);
+    return inner;
+  }
+
   @protected
   ATwinNormal sse_decode_a_twin_normal(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -125900,6 +138409,72 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return BigBuffersTwinSyncSse(int64: var_int64, uint64: var_uint64);
   }
 
+  @protected
+  BigNumbersTwinNormal sse_decode_big_numbers_twin_normal(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_amount = sse_decode_Decimal(deserializer);
+    var var_discount = sse_decode_opt_Decimal(deserializer);
+    var var_serial = sse_decode_BigInt(deserializer);
+    return BigNumbersTwinNormal(
+        amount: var_amount, discount: var_discount, serial: var_serial);
+  }
+
+  @protected
+  BigNumbersTwinRustAsync sse_decode_big_numbers_twin_rust_async(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_amount = sse_decode_Decimal(deserializer);
+    var var_discount = sse_decode_opt_Decimal(deserializer);
+    var var_serial = sse_decode_BigInt(deserializer);
+    return BigNumbersTwinRustAsync(
+        amount: var_amount, discount: var_discount, serial: var_serial);
+  }
+
+  @protected
+  BigNumbersTwinRustAsyncSse sse_decode_big_numbers_twin_rust_async_sse(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_amount = sse_decode_Decimal(deserializer);
+    var var_discount = sse_decode_opt_Decimal(deserializer);
+    var var_serial = sse_decode_BigInt(deserializer);
+    return BigNumbersTwinRustAsyncSse(
+        amount: var_amount, discount: var_discount, serial: var_serial);
+  }
+
+  @protected
+  BigNumbersTwinSse sse_decode_big_numbers_twin_sse(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_amount = sse_decode_Decimal(deserializer);
+    var var_discount = sse_decode_opt_Decimal(deserializer);
+    var var_serial = sse_decode_BigInt(deserializer);
+    return BigNumbersTwinSse(
+        amount: var_amount, discount: var_discount, serial: var_serial);
+  }
+
+  @protected
+  BigNumbersTwinSync sse_decode_big_numbers_twin_sync(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_amount = sse_decode_Decimal(deserializer);
+    var var_discount = sse_decode_opt_Decimal(deserializer);
+    var var_serial = sse_decode_BigInt(deserializer);
+    return BigNumbersTwinSync(
+        amount: var_amount, discount: var_discount, serial: var_serial);
+  }
+
+  @protected
+  BigNumbersTwinSyncSse sse_decode_big_numbers_twin_sync_sse(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_amount = sse_decode_Decimal(deserializer);
+    var var_discount = sse_decode_opt_Decimal(deserializer);
+    var var_serial = sse_decode_BigInt(deserializer);
+    return BigNumbersTwinSyncSse(
+        amount: var_amount, discount: var_discount, serial: var_serial);
+  }
+
   @protected
   BlobTwinNormal sse_decode_blob_twin_normal(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -125950,6 +138525,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return deserializer.buffer.getUint8() != 0;
   }
 
+  @protected
+  DartImplementableTraitTwinNormal
+      sse_decode_box_DynTrait_DartImplementableTraitTwinNormal(
+          SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('');
+  }
+
   @protected
   ApplicationEnv sse_decode_box_application_env(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -126077,6 +138660,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_Chrono_Naive(deserializer));
   }
 
+  @protected
+  Duration sse_decode_box_autoadd_Chrono_NaiveTime(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_Chrono_NaiveTime(deserializer));
+  }
+
   @protected
   DateTime sse_decode_box_autoadd_Chrono_Utc(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -126209,6 +138799,38 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_RustOpaque_HideDataTwinSyncSseMoi(deserializer));
   }
 
+  @protected
+  Duration sse_decode_box_autoadd_StdDuration(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_StdDuration(deserializer));
+  }
+
+  @protected
+  DateTime sse_decode_box_autoadd_StdSystemTime(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_StdSystemTime(deserializer));
+  }
+
+  @protected
+  Duration sse_decode_box_autoadd_TimeDuration(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_TimeDuration(deserializer));
+  }
+
+  @protected
+  DateTime sse_decode_box_autoadd_TimeOffsetDateTime(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_TimeOffsetDateTime(deserializer));
+  }
+
+  @protected
+  DateTime sse_decode_box_autoadd_TimePrimitiveDateTime(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_TimePrimitiveDateTime(deserializer));
+  }
+
   @protected
   ATwinNormal sse_decode_box_autoadd_a_twin_normal(
       SseDeserializer deserializer) {
@@ -126632,6 +139254,49 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_benchmark_blob_twin_sync_sse(deserializer));
   }
 
+  @protected
+  BigNumbersTwinNormal sse_decode_box_autoadd_big_numbers_twin_normal(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_big_numbers_twin_normal(deserializer));
+  }
+
+  @protected
+  BigNumbersTwinRustAsync sse_decode_box_autoadd_big_numbers_twin_rust_async(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_big_numbers_twin_rust_async(deserializer));
+  }
+
+  @protected
+  BigNumbersTwinRustAsyncSse
+      sse_decode_box_autoadd_big_numbers_twin_rust_async_sse(
+          SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_big_numbers_twin_rust_async_sse(deserializer));
+  }
+
+  @protected
+  BigNumbersTwinSse sse_decode_box_autoadd_big_numbers_twin_sse(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_big_numbers_twin_sse(deserializer));
+  }
+
+  @protected
+  BigNumbersTwinSync sse_decode_box_autoadd_big_numbers_twin_sync(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_big_numbers_twin_sync(deserializer));
+  }
+
+  @protected
+  BigNumbersTwinSyncSse sse_decode_box_autoadd_big_numbers_twin_sync_sse(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_big_numbers_twin_sync_sse(deserializer));
+  }
+
   @protected
   bool sse_decode_box_autoadd_bool(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -127003,6 +139668,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_customized_twin_sync_sse(deserializer));
   }
 
+  @protected
+  DartImplementableTraitTwinNormalImplementor
+      sse_decode_box_autoadd_dart_implementable_trait_twin_normal_implementor(
+          SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_dart_implementable_trait_twin_normal_implementor(
+        deserializer));
+  }
+
   @protected
   DartOpaqueNestedTwinNormal
       sse_decode_box_autoadd_dart_opaque_nested_twin_normal(
@@ -127759,6 +140433,50 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_item_container_solution_two_twin_normal(deserializer));
   }
 
+  @protected
+  JsonDocumentTwinNormal sse_decode_box_autoadd_json_document_twin_normal(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_json_document_twin_normal(deserializer));
+  }
+
+  @protected
+  JsonDocumentTwinRustAsync
+      sse_decode_box_autoadd_json_document_twin_rust_async(
+          SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_json_document_twin_rust_async(deserializer));
+  }
+
+  @protected
+  JsonDocumentTwinRustAsyncSse
+      sse_decode_box_autoadd_json_document_twin_rust_async_sse(
+          SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_json_document_twin_rust_async_sse(deserializer));
+  }
+
+  @protected
+  JsonDocumentTwinSse sse_decode_box_autoadd_json_document_twin_sse(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_json_document_twin_sse(deserializer));
+  }
+
+  @protected
+  JsonDocumentTwinSync sse_decode_box_autoadd_json_document_twin_sync(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_json_document_twin_sync(deserializer));
+  }
+
+  @protected
+  JsonDocumentTwinSyncSse sse_decode_box_autoadd_json_document_twin_sync_sse(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_json_document_twin_sync_sse(deserializer));
+  }
+
   @protected
   KitchenSinkTwinNormal sse_decode_box_autoadd_kitchen_sink_twin_normal(
       SseDeserializer deserializer) {
@@ -128330,6 +141048,48 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_record_string_i_32(deserializer));
   }
 
+  @protected
+  ScheduleTwinNormal sse_decode_box_autoadd_schedule_twin_normal(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_schedule_twin_normal(deserializer));
+  }
+
+  @protected
+  ScheduleTwinRustAsync sse_decode_box_autoadd_schedule_twin_rust_async(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_schedule_twin_rust_async(deserializer));
+  }
+
+  @protected
+  ScheduleTwinRustAsyncSse sse_decode_box_autoadd_schedule_twin_rust_async_sse(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_schedule_twin_rust_async_sse(deserializer));
+  }
+
+  @protected
+  ScheduleTwinSse sse_decode_box_autoadd_schedule_twin_sse(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_schedule_twin_sse(deserializer));
+  }
+
+  @protected
+  ScheduleTwinSync sse_decode_box_autoadd_schedule_twin_sync(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_schedule_twin_sync(deserializer));
+  }
+
+  @protected
+  ScheduleTwinSyncSse sse_decode_box_autoadd_schedule_twin_sync_sse(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_schedule_twin_sync_sse(deserializer));
+  }
+
   @protected
   Sequences sse_decode_box_autoadd_sequences(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -128533,6 +141293,48 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_some_struct_twin_sync_sse(deserializer));
   }
 
+  @protected
+  StdTimeTwinNormal sse_decode_box_autoadd_std_time_twin_normal(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_std_time_twin_normal(deserializer));
+  }
+
+  @protected
+  StdTimeTwinRustAsync sse_decode_box_autoadd_std_time_twin_rust_async(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_std_time_twin_rust_async(deserializer));
+  }
+
+  @protected
+  StdTimeTwinRustAsyncSse sse_decode_box_autoadd_std_time_twin_rust_async_sse(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_std_time_twin_rust_async_sse(deserializer));
+  }
+
+  @protected
+  StdTimeTwinSse sse_decode_box_autoadd_std_time_twin_sse(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_std_time_twin_sse(deserializer));
+  }
+
+  @protected
+  StdTimeTwinSync sse_decode_box_autoadd_std_time_twin_sync(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_std_time_twin_sync(deserializer));
+  }
+
+  @protected
+  StdTimeTwinSyncSse sse_decode_box_autoadd_std_time_twin_sync_sse(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_std_time_twin_sync_sse(deserializer));
+  }
+
   @protected
   StructInLowerLevel sse_decode_box_autoadd_struct_in_lower_level(
       SseDeserializer deserializer) {
@@ -129205,6 +142007,49 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_test_id_twin_sync_sse(deserializer));
   }
 
+  @protected
+  TimeTypesTwinNormal sse_decode_box_autoadd_time_types_twin_normal(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_time_types_twin_normal(deserializer));
+  }
+
+  @protected
+  TimeTypesTwinRustAsync sse_decode_box_autoadd_time_types_twin_rust_async(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_time_types_twin_rust_async(deserializer));
+  }
+
+  @protected
+  TimeTypesTwinRustAsyncSse
+      sse_decode_box_autoadd_time_types_twin_rust_async_sse(
+          SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_time_types_twin_rust_async_sse(deserializer));
+  }
+
+  @protected
+  TimeTypesTwinSse sse_decode_box_autoadd_time_types_twin_sse(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_time_types_twin_sse(deserializer));
+  }
+
+  @protected
+  TimeTypesTwinSync sse_decode_box_autoadd_time_types_twin_sync(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_time_types_twin_sync(deserializer));
+  }
+
+  @protected
+  TimeTypesTwinSyncSse sse_decode_box_autoadd_time_types_twin_sync_sse(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_time_types_twin_sync_sse(deserializer));
+  }
+
   @protected
   TranslatableStructWithDartCodeTwinNormal
       sse_decode_box_autoadd_translatable_struct_with_dart_code_twin_normal(
@@ -130928,6 +143773,29 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         finalField: var_finalField, nonFinalField: var_nonFinalField);
   }
 
+  @protected
+  DartImplementableTraitTwinNormalImplementor
+      sse_decode_dart_implementable_trait_twin_normal_implementor(
+          SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_field0 =
+            sse_decode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl(
+                deserializer);
+        return DartImplementableTraitTwinNormalImplementor_Variant0(var_field0);
+      case 1:
+        var var_field0 =
+            sse_decode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+                deserializer);
+        return DartImplementableTraitTwinNormalImplementor_Variant1(var_field0);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
   @protected
   DartOpaqueNestedTwinNormal sse_decode_dart_opaque_nested_twin_normal(
       SseDeserializer deserializer) {
@@ -133084,6 +145952,72 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ItemContainerSolutionTwoTwinNormal(name: var_name, items: var_items);
   }
 
+  @protected
+  JsonDocumentTwinNormal sse_decode_json_document_twin_normal(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_body = sse_decode_JsonValue(deserializer);
+    var var_meta = sse_decode_JsonMap(deserializer);
+    var var_extra = sse_decode_opt_JsonValue(deserializer);
+    return JsonDocumentTwinNormal(
+        body: var_body, meta: var_meta, extra: var_extra);
+  }
+
+  @protected
+  JsonDocumentTwinRustAsync sse_decode_json_document_twin_rust_async(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_body = sse_decode_JsonValue(deserializer);
+    var var_meta = sse_decode_JsonMap(deserializer);
+    var var_extra = sse_decode_opt_JsonValue(deserializer);
+    return JsonDocumentTwinRustAsync(
+        body: var_body, meta: var_meta, extra: var_extra);
+  }
+
+  @protected
+  JsonDocumentTwinRustAsyncSse sse_decode_json_document_twin_rust_async_sse(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_body = sse_decode_JsonValue(deserializer);
+    var var_meta = sse_decode_JsonMap(deserializer);
+    var var_extra = sse_decode_opt_JsonValue(deserializer);
+    return JsonDocumentTwinRustAsyncSse(
+        body: var_body, meta: var_meta, extra: var_extra);
+  }
+
+  @protected
+  JsonDocumentTwinSse sse_decode_json_document_twin_sse(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_body = sse_decode_JsonValue(deserializer);
+    var var_meta = sse_decode_JsonMap(deserializer);
+    var var_extra = sse_decode_opt_JsonValue(deserializer);
+    return JsonDocumentTwinSse(
+        body: var_body, meta: var_meta, extra: var_extra);
+  }
+
+  @protected
+  JsonDocumentTwinSync sse_decode_json_document_twin_sync(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_body = sse_decode_JsonValue(deserializer);
+    var var_meta = sse_decode_JsonMap(deserializer);
+    var var_extra = sse_decode_opt_JsonValue(deserializer);
+    return JsonDocumentTwinSync(
+        body: var_body, meta: var_meta, extra: var_extra);
+  }
+
+  @protected
+  JsonDocumentTwinSyncSse sse_decode_json_document_twin_sync_sse(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_body = sse_decode_JsonValue(deserializer);
+    var var_meta = sse_decode_JsonMap(deserializer);
+    var var_extra = sse_decode_opt_JsonValue(deserializer);
+    return JsonDocumentTwinSyncSse(
+        body: var_body, meta: var_meta, extra: var_extra);
+  }
+
   @protected
   KitchenSinkTwinNormal sse_decode_kitchen_sink_twin_normal(
       SseDeserializer deserializer) {
@@ -133515,6 +146449,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<BigInt> sse_decode_list_BigInt(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <BigInt>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_BigInt(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<Duration> sse_decode_list_Chrono_Duration(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -133527,6 +146473,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<DateTimeWithOffset> sse_decode_list_Chrono_FixedOffset(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <DateTimeWithOffset>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_Chrono_FixedOffset(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<DateTime> sse_decode_list_Chrono_Local(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -133551,6 +146510,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<DateTime> sse_decode_list_Chrono_NaiveDate(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <DateTime>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_Chrono_NaiveDate(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<DateTimeWithOffset> sse_decode_list_Chrono_Tz(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <DateTimeWithOffset>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_Chrono_Tz(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<Object> sse_decode_list_DartOpaque(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -133563,6 +146548,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<Decimal> sse_decode_list_Decimal(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <Decimal>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_Decimal(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<Object?> sse_decode_list_JsonValue(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <Object?>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_JsonValue(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<HideDataTwinMoi> sse_decode_list_RustOpaque_HideDataTwinMoi(
       SseDeserializer deserializer) {
@@ -133723,6 +146732,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<Duration> sse_decode_list_StdDuration(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <Duration>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_StdDuration(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<DateTime> sse_decode_list_StdSystemTime(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <DateTime>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_StdSystemTime(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<RustStreamSink<int>> sse_decode_list_StreamSink_i_32_Dco(
       SseDeserializer deserializer) {
@@ -133761,6 +146794,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<Duration> sse_decode_list_TimeDuration(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <Duration>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_TimeDuration(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<UuidValue> sse_decode_list_Uuid(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -136962,6 +150007,51 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return OpaqueNestedTwinSyncSseMoi(first: var_first, second: var_second);
   }
 
+  @protected
+  BigInt? sse_decode_opt_BigInt(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_BigInt(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  DateTimeWithOffset? sse_decode_opt_Chrono_FixedOffset(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_Chrono_FixedOffset(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  DateTimeWithOffset? sse_decode_opt_Chrono_Tz(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_Chrono_Tz(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  Decimal? sse_decode_opt_Decimal(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_Decimal(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   BigInt? sse_decode_opt_I128(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -136973,6 +150063,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  Object? sse_decode_opt_JsonValue(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_JsonValue(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   String? sse_decode_opt_String(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -137187,6 +150288,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  Duration? sse_decode_opt_box_autoadd_Chrono_NaiveTime(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_Chrono_NaiveTime(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   DateTime? sse_decode_opt_box_autoadd_Chrono_Utc(
       SseDeserializer deserializer) {
@@ -137426,6 +150539,66 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  Duration? sse_decode_opt_box_autoadd_StdDuration(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_StdDuration(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  DateTime? sse_decode_opt_box_autoadd_StdSystemTime(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_StdSystemTime(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  Duration? sse_decode_opt_box_autoadd_TimeDuration(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_TimeDuration(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  DateTime? sse_decode_opt_box_autoadd_TimeOffsetDateTime(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_TimeOffsetDateTime(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  DateTime? sse_decode_opt_box_autoadd_TimePrimitiveDateTime(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_TimePrimitiveDateTime(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   ApplicationEnv? sse_decode_opt_box_autoadd_application_env(
       SseDeserializer deserializer) {
@@ -139777,6 +152950,94 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (var_field0, var_field1);
   }
 
+  @protected
+  ScheduleTwinNormal sse_decode_schedule_twin_normal(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_day = sse_decode_Chrono_NaiveDate(deserializer);
+    var var_starts = sse_decode_Chrono_NaiveTime(deserializer);
+    var var_ends = sse_decode_opt_box_autoadd_Chrono_NaiveTime(deserializer);
+    var var_at = sse_decode_Chrono_FixedOffset(deserializer);
+    return ScheduleTwinNormal(
+        day: var_day,
+        starts: var_starts,
+        ends: var_ends,
+        at: var_at);
+  }
+
+  @protected
+  ScheduleTwinRustAsync sse_decode_schedule_twin_rust_async(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_day = sse_decode_Chrono_NaiveDate(deserializer);
+    var var_starts = sse_decode_Chrono_NaiveTime(deserializer);
+    var var_ends = sse_decode_opt_box_autoadd_Chrono_NaiveTime(deserializer);
+    var var_at = sse_decode_Chrono_FixedOffset(deserializer);
+    return ScheduleTwinRustAsync(
+        day: var_day,
+        starts: var_starts,
+        ends: var_ends,
+        at: var_at);
+  }
+
+  @protected
+  ScheduleTwinRustAsyncSse sse_decode_schedule_twin_rust_async_sse(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_day = sse_decode_Chrono_NaiveDate(deserializer);
+    var var_starts = sse_decode_Chrono_NaiveTime(deserializer);
+    var var_ends = sse_decode_opt_box_autoadd_Chrono_NaiveTime(deserializer);
+    var var_at = sse_decode_Chrono_FixedOffset(deserializer);
+    return ScheduleTwinRustAsyncSse(
+        day: var_day,
+        starts: var_starts,
+        ends: var_ends,
+        at: var_at);
+  }
+
+  @protected
+  ScheduleTwinSse sse_decode_schedule_twin_sse(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_day = sse_decode_Chrono_NaiveDate(deserializer);
+    var var_starts = sse_decode_Chrono_NaiveTime(deserializer);
+    var var_ends = sse_decode_opt_box_autoadd_Chrono_NaiveTime(deserializer);
+    var var_at = sse_decode_Chrono_FixedOffset(deserializer);
+    return ScheduleTwinSse(
+        day: var_day,
+        starts: var_starts,
+        ends: var_ends,
+        at: var_at);
+  }
+
+  @protected
+  ScheduleTwinSync sse_decode_schedule_twin_sync(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_day = sse_decode_Chrono_NaiveDate(deserializer);
+    var var_starts = sse_decode_Chrono_NaiveTime(deserializer);
+    var var_ends = sse_decode_opt_box_autoadd_Chrono_NaiveTime(deserializer);
+    var var_at = sse_decode_Chrono_FixedOffset(deserializer);
+    return ScheduleTwinSync(
+        day: var_day,
+        starts: var_starts,
+        ends: var_ends,
+        at: var_at);
+  }
+
+  @protected
+  ScheduleTwinSyncSse sse_decode_schedule_twin_sync_sse(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_day = sse_decode_Chrono_NaiveDate(deserializer);
+    var var_starts = sse_decode_Chrono_NaiveTime(deserializer);
+    var var_ends = sse_decode_opt_box_autoadd_Chrono_NaiveTime(deserializer);
+    var var_at = sse_decode_Chrono_FixedOffset(deserializer);
+    return ScheduleTwinSyncSse(
+        day: var_day,
+        starts: var_starts,
+        ends: var_ends,
+        at: var_at);
+  }
+
   @protected
   Sequences sse_decode_sequences(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -140263,6 +153524,88 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return StaticOnlyTwinSyncSse(one: var_one);
   }
 
+  @protected
+  StdTimeTwinNormal sse_decode_std_time_twin_normal(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_duration = sse_decode_StdDuration(deserializer);
+    var var_time = sse_decode_StdSystemTime(deserializer);
+    var var_optionalDuration =
+        sse_decode_opt_box_autoadd_StdDuration(deserializer);
+    return StdTimeTwinNormal(
+        duration: var_duration,
+        time: var_time,
+        optionalDuration: var_optionalDuration);
+  }
+
+  @protected
+  StdTimeTwinRustAsync sse_decode_std_time_twin_rust_async(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_duration = sse_decode_StdDuration(deserializer);
+    var var_time = sse_decode_StdSystemTime(deserializer);
+    var var_optionalDuration =
+        sse_decode_opt_box_autoadd_StdDuration(deserializer);
+    return StdTimeTwinRustAsync(
+        duration: var_duration,
+        time: var_time,
+        optionalDuration: var_optionalDuration);
+  }
+
+  @protected
+  StdTimeTwinRustAsyncSse sse_decode_std_time_twin_rust_async_sse(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_duration = sse_decode_StdDuration(deserializer);
+    var var_time = sse_decode_StdSystemTime(deserializer);
+    var var_optionalDuration =
+        sse_decode_opt_box_autoadd_StdDuration(deserializer);
+    return StdTimeTwinRustAsyncSse(
+        duration: var_duration,
+        time: var_time,
+        optionalDuration: var_optionalDuration);
+  }
+
+  @protected
+  StdTimeTwinSse sse_decode_std_time_twin_sse(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_duration = sse_decode_StdDuration(deserializer);
+    var var_time = sse_decode_StdSystemTime(deserializer);
+    var var_optionalDuration =
+        sse_decode_opt_box_autoadd_StdDuration(deserializer);
+    return StdTimeTwinSse(
+        duration: var_duration,
+        time: var_time,
+        optionalDuration: var_optionalDuration);
+  }
+
+  @protected
+  StdTimeTwinSync sse_decode_std_time_twin_sync(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_duration = sse_decode_StdDuration(deserializer);
+    var var_time = sse_decode_StdSystemTime(deserializer);
+    var var_optionalDuration =
+        sse_decode_opt_box_autoadd_StdDuration(deserializer);
+    return StdTimeTwinSync(
+        duration: var_duration,
+        time: var_time,
+        optionalDuration: var_optionalDuration);
+  }
+
+  @protected
+  StdTimeTwinSyncSse sse_decode_std_time_twin_sync_sse(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_duration = sse_decode_StdDuration(deserializer);
+    var var_time = sse_decode_StdSystemTime(deserializer);
+    var var_optionalDuration =
+        sse_decode_opt_box_autoadd_StdDuration(deserializer);
+    return StdTimeTwinSyncSse(
+        duration: var_duration,
+        time: var_time,
+        optionalDuration: var_optionalDuration);
+  }
+
   @protected
   StructInLowerLevel sse_decode_struct_in_lower_level(
       SseDeserializer deserializer) {
@@ -141441,6 +154784,102 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         aliasStruct: var_aliasStruct);
   }
 
+  @protected
+  TimeTypesTwinNormal sse_decode_time_types_twin_normal(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_offset = sse_decode_TimeOffsetDateTime(deserializer);
+    var var_primitive =
+        sse_decode_opt_box_autoadd_TimePrimitiveDateTime(deserializer);
+    var var_date = sse_decode_TimeDate(deserializer);
+    var var_duration = sse_decode_TimeDuration(deserializer);
+    return TimeTypesTwinNormal(
+        offset: var_offset,
+        primitive: var_primitive,
+        date: var_date,
+        duration: var_duration);
+  }
+
+  @protected
+  TimeTypesTwinRustAsync sse_decode_time_types_twin_rust_async(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_offset = sse_decode_TimeOffsetDateTime(deserializer);
+    var var_primitive =
+        sse_decode_opt_box_autoadd_TimePrimitiveDateTime(deserializer);
+    var var_date = sse_decode_TimeDate(deserializer);
+    var var_duration = sse_decode_TimeDuration(deserializer);
+    return TimeTypesTwinRustAsync(
+        offset: var_offset,
+        primitive: var_primitive,
+        date: var_date,
+        duration: var_duration);
+  }
+
+  @protected
+  TimeTypesTwinRustAsyncSse sse_decode_time_types_twin_rust_async_sse(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_offset = sse_decode_TimeOffsetDateTime(deserializer);
+    var var_primitive =
+        sse_decode_opt_box_autoadd_TimePrimitiveDateTime(deserializer);
+    var var_date = sse_decode_TimeDate(deserializer);
+    var var_duration = sse_decode_TimeDuration(deserializer);
+    return TimeTypesTwinRustAsyncSse(
+        offset: var_offset,
+        primitive: var_primitive,
+        date: var_date,
+        duration: var_duration);
+  }
+
+  @protected
+  TimeTypesTwinSse sse_decode_time_types_twin_sse(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_offset = sse_decode_TimeOffsetDateTime(deserializer);
+    var var_primitive =
+        sse_decode_opt_box_autoadd_TimePrimitiveDateTime(deserializer);
+    var var_date = sse_decode_TimeDate(deserializer);
+    var var_duration = sse_decode_TimeDuration(deserializer);
+    return TimeTypesTwinSse(
+        offset: var_offset,
+        primitive: var_primitive,
+        date: var_date,
+        duration: var_duration);
+  }
+
+  @protected
+  TimeTypesTwinSync sse_decode_time_types_twin_sync(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_offset = sse_decode_TimeOffsetDateTime(deserializer);
+    var var_primitive =
+        sse_decode_opt_box_autoadd_TimePrimitiveDateTime(deserializer);
+    var var_date = sse_decode_TimeDate(deserializer);
+    var var_duration = sse_decode_TimeDuration(deserializer);
+    return TimeTypesTwinSync(
+        offset: var_offset,
+        primitive: var_primitive,
+        date: var_date,
+        duration: var_duration);
+  }
+
+  @protected
+  TimeTypesTwinSyncSse sse_decode_time_types_twin_sync_sse(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_offset = sse_decode_TimeOffsetDateTime(deserializer);
+    var var_primitive =
+        sse_decode_opt_box_autoadd_TimePrimitiveDateTime(deserializer);
+    var var_date = sse_decode_TimeDate(deserializer);
+    var var_duration = sse_decode_TimeDuration(deserializer);
+    return TimeTypesTwinSyncSse(
+        offset: var_offset,
+        primitive: var_primitive,
+        date: var_date,
+        duration: var_duration);
+  }
+
   @protected
   TranslatableStructWithDartCodeTwinNormal
       sse_decode_translatable_struct_with_dart_code_twin_normal(
@@ -141570,6 +155009,164 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         field0: var_field0, field1: var_field1);
   }
 
+  @protected
+  TypedExceptionErrorTwinNormal sse_decode_typed_exception_error_twin_normal(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_path = sse_decode_String(deserializer);
+        var var_displayMessage = sse_decode_String(deserializer);
+        return TypedExceptionErrorTwinNormal_NotFound(
+            path: var_path, displayMessage: var_displayMessage);
+      case 1:
+        var var_field0 = sse_decode_String(deserializer);
+        var var_displayMessage = sse_decode_String(deserializer);
+        return TypedExceptionErrorTwinNormal_Io(
+            var_field0, displayMessage: var_displayMessage);
+      case 2:
+        var var_displayMessage = sse_decode_String(deserializer);
+        return TypedExceptionErrorTwinNormal_Unknown(
+            displayMessage: var_displayMessage);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  TypedExceptionErrorTwinRustAsync
+      sse_decode_typed_exception_error_twin_rust_async(
+          SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_path = sse_decode_String(deserializer);
+        var var_displayMessage = sse_decode_String(deserializer);
+        return TypedExceptionErrorTwinRustAsync_NotFound(
+            path: var_path, displayMessage: var_displayMessage);
+      case 1:
+        var var_field0 = sse_decode_String(deserializer);
+        var var_displayMessage = sse_decode_String(deserializer);
+        return TypedExceptionErrorTwinRustAsync_Io(
+            var_field0, displayMessage: var_displayMessage);
+      case 2:
+        var var_displayMessage = sse_decode_String(deserializer);
+        return TypedExceptionErrorTwinRustAsync_Unknown(
+            displayMessage: var_displayMessage);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  TypedExceptionErrorTwinRustAsyncSse
+      sse_decode_typed_exception_error_twin_rust_async_sse(
+          SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_path = sse_decode_String(deserializer);
+        var var_displayMessage = sse_decode_String(deserializer);
+        return TypedExceptionErrorTwinRustAsyncSse_NotFound(
+            path: var_path, displayMessage: var_displayMessage);
+      case 1:
+        var var_field0 = sse_decode_String(deserializer);
+        var var_displayMessage = sse_decode_String(deserializer);
+        return TypedExceptionErrorTwinRustAsyncSse_Io(
+            var_field0, displayMessage: var_displayMessage);
+      case 2:
+        var var_displayMessage = sse_decode_String(deserializer);
+        return TypedExceptionErrorTwinRustAsyncSse_Unknown(
+            displayMessage: var_displayMessage);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  TypedExceptionErrorTwinSse sse_decode_typed_exception_error_twin_sse(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_path = sse_decode_String(deserializer);
+        var var_displayMessage = sse_decode_String(deserializer);
+        return TypedExceptionErrorTwinSse_NotFound(
+            path: var_path, displayMessage: var_displayMessage);
+      case 1:
+        var var_field0 = sse_decode_String(deserializer);
+        var var_displayMessage = sse_decode_String(deserializer);
+        return TypedExceptionErrorTwinSse_Io(
+            var_field0, displayMessage: var_displayMessage);
+      case 2:
+        var var_displayMessage = sse_decode_String(deserializer);
+        return TypedExceptionErrorTwinSse_Unknown(
+            displayMessage: var_displayMessage);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  TypedExceptionErrorTwinSync sse_decode_typed_exception_error_twin_sync(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_path = sse_decode_String(deserializer);
+        var var_displayMessage = sse_decode_String(deserializer);
+        return TypedExceptionErrorTwinSync_NotFound(
+            path: var_path, displayMessage: var_displayMessage);
+      case 1:
+        var var_field0 = sse_decode_String(deserializer);
+        var var_displayMessage = sse_decode_String(deserializer);
+        return TypedExceptionErrorTwinSync_Io(
+            var_field0, displayMessage: var_displayMessage);
+      case 2:
+        var var_displayMessage = sse_decode_String(deserializer);
+        return TypedExceptionErrorTwinSync_Unknown(
+            displayMessage: var_displayMessage);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  TypedExceptionErrorTwinSyncSse sse_decode_typed_exception_error_twin_sync_sse(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_path = sse_decode_String(deserializer);
+        var var_displayMessage = sse_decode_String(deserializer);
+        return TypedExceptionErrorTwinSyncSse_NotFound(
+            path: var_path, displayMessage: var_displayMessage);
+      case 1:
+        var var_field0 = sse_decode_String(deserializer);
+        var var_displayMessage = sse_decode_String(deserializer);
+        return TypedExceptionErrorTwinSyncSse_Io(
+            var_field0, displayMessage: var_displayMessage);
+      case 2:
+        var var_displayMessage = sse_decode_String(deserializer);
+        return TypedExceptionErrorTwinSyncSse_Unknown(
+            displayMessage: var_displayMessage);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
   @protected
   int sse_decode_u_16(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -141912,7 +155509,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynAnySendSyncstatic(
       BoxAnyMyDartTypeRename raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as BoxAnyMyDartTypeRenameImpl).frbInternalCstEncode(move: true);
   }
 
@@ -141920,7 +155517,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynFnStringStringSendSync(
       BoxFnStringString raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as BoxFnStringStringImpl).frbInternalCstEncode(move: true);
   }
 
@@ -141928,7 +155525,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynMyTraitTwinMoiSendSync(
       BoxMyTraitTwinMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as BoxMyTraitTwinMoiImpl).frbInternalCstEncode(move: true);
   }
 
@@ -141936,7 +155533,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynMyTraitTwinNormalSendSync(
       BoxMyTraitTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as BoxMyTraitTwinNormalImpl).frbInternalCstEncode(move: true);
   }
 
@@ -141944,7 +155541,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynMyTraitTwinRustAsyncMoiSendSync(
       BoxMyTraitTwinRustAsyncMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as BoxMyTraitTwinRustAsyncMoiImpl)
         .frbInternalCstEncode(move: true);
   }
@@ -141953,7 +155550,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynMyTraitTwinRustAsyncSendSync(
       BoxMyTraitTwinRustAsync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as BoxMyTraitTwinRustAsyncImpl)
         .frbInternalCstEncode(move: true);
   }
@@ -141962,7 +155559,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynMyTraitTwinSyncMoiSendSync(
       BoxMyTraitTwinSyncMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as BoxMyTraitTwinSyncMoiImpl).frbInternalCstEncode(move: true);
   }
 
@@ -141970,7 +155567,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynMyTraitTwinSyncSendSync(
       BoxMyTraitTwinSync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as BoxMyTraitTwinSyncImpl).frbInternalCstEncode(move: true);
   }
 
@@ -141978,7 +155575,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConstructorOpaqueStructTwinNormal(
       ConstructorOpaqueStructTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as ConstructorOpaqueStructTwinNormalImpl)
         .frbInternalCstEncode(move: true);
   }
@@ -141987,16 +155584,25 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConstructorOpaqueSyncStructTwinNormal(
       ConstructorOpaqueSyncStructTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as ConstructorOpaqueSyncStructTwinNormalImpl)
         .frbInternalCstEncode(move: true);
   }
 
+  @protected
+  int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl(
+      DartImplementableTraitTwinNormalDartImpl raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+  // ignore: invalid_use_of_internal_member
+    return (raw as DartImplementableTraitTwinNormalDartImplImpl)
+        .frbInternalCstEncode(move: true);
+  }
+
   @protected
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeliberateFailSanityCheckTwinNormal(
       DeliberateFailSanityCheckTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as DeliberateFailSanityCheckTwinNormalImpl)
         .frbInternalCstEncode(move: true);
   }
@@ -142005,7 +155611,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDroppableTwinNormal(
       DroppableTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as DroppableTwinNormalImpl).frbInternalCstEncode(move: true);
   }
 
@@ -142013,7 +155619,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDroppableTwinRustAsync(
       DroppableTwinRustAsync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as DroppableTwinRustAsyncImpl).frbInternalCstEncode(move: true);
   }
 
@@ -142021,7 +155627,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDroppableTwinSync(
       DroppableTwinSync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as DroppableTwinSyncImpl).frbInternalCstEncode(move: true);
   }
 
@@ -142029,7 +155635,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnumWithGoodAndOpaqueWithoutOptionTwinMoi(
       EnumWithGoodAndOpaqueWithoutOptionTwinMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as EnumWithGoodAndOpaqueWithoutOptionTwinMoiImpl)
         .frbInternalCstEncode(move: true);
   }
@@ -142038,7 +155644,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnumWithGoodAndOpaqueWithoutOptionTwinNormal(
       EnumWithGoodAndOpaqueWithoutOptionTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as EnumWithGoodAndOpaqueWithoutOptionTwinNormalImpl)
         .frbInternalCstEncode(move: true);
   }
@@ -142047,7 +155653,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnumWithGoodAndOpaqueWithoutOptionTwinRustAsync(
       EnumWithGoodAndOpaqueWithoutOptionTwinRustAsync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as EnumWithGoodAndOpaqueWithoutOptionTwinRustAsyncImpl)
         .frbInternalCstEncode(move: true);
   }
@@ -142056,7 +155662,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnumWithGoodAndOpaqueWithoutOptionTwinRustAsyncMoi(
       EnumWithGoodAndOpaqueWithoutOptionTwinRustAsyncMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as EnumWithGoodAndOpaqueWithoutOptionTwinRustAsyncMoiImpl)
         .frbInternalCstEncode(move: true);
   }
@@ -142065,7 +155671,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnumWithGoodAndOpaqueWithoutOptionTwinSync(
       EnumWithGoodAndOpaqueWithoutOptionTwinSync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as EnumWithGoodAndOpaqueWithoutOptionTwinSyncImpl)
         .frbInternalCstEncode(move: true);
   }
@@ -142074,7 +155680,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnumWithGoodAndOpaqueWithoutOptionTwinSyncMoi(
       EnumWithGoodAndOpaqueWithoutOptionTwinSyncMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as EnumWithGoodAndOpaqueWithoutOptionTwinSyncMoiImpl)
         .frbInternalCstEncode(move: true);
   }
@@ -142083,7 +155689,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerItemContainerSolutionOneTwinNormal(
       ItemContainerSolutionOneTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as ItemContainerSolutionOneTwinNormalImpl)
         .frbInternalCstEncode(move: true);
   }
@@ -142092,7 +155698,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMyImplTraitWithSelfTwinNormal(
       MyImplTraitWithSelfTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as MyImplTraitWithSelfTwinNormalImpl)
         .frbInternalCstEncode(move: true);
   }
@@ -142101,7 +155707,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMyImplTraitWithSelfTwinSse(
       MyImplTraitWithSelfTwinSse raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as MyImplTraitWithSelfTwinSseImpl)
         .frbInternalCstEncode(move: true);
   }
@@ -142110,7 +155716,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMyImplTraitWithSelfTwinSync(
       MyImplTraitWithSelfTwinSync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as MyImplTraitWithSelfTwinSyncImpl)
         .frbInternalCstEncode(move: true);
   }
@@ -142119,7 +155725,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMyImplTraitWithSelfTwinSyncSse(
       MyImplTraitWithSelfTwinSyncSse raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as MyImplTraitWithSelfTwinSyncSseImpl)
         .frbInternalCstEncode(move: true);
   }
@@ -142128,7 +155734,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMyStructWithTryFromTwinNormal(
       MyStructWithTryFromTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as MyStructWithTryFromTwinNormalImpl)
         .frbInternalCstEncode(move: true);
   }
@@ -142137,7 +155743,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleEnumTwinMoi(
       NonCloneSimpleEnumTwinMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as NonCloneSimpleEnumTwinMoiImpl)
         .frbInternalCstEncode(move: true);
   }
@@ -142146,7 +155752,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleEnumTwinNormal(
       NonCloneSimpleEnumTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as NonCloneSimpleEnumTwinNormalImpl)
         .frbInternalCstEncode(move: true);
   }
@@ -142155,7 +155761,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleEnumTwinRustAsync(
       NonCloneSimpleEnumTwinRustAsync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as NonCloneSimpleEnumTwinRustAsyncImpl)
         .frbInternalCstEncode(move: true);
   }
@@ -142164,7 +155770,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleEnumTwinRustAsyncMoi(
       NonCloneSimpleEnumTwinRustAsyncMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as NonCloneSimpleEnumTwinRustAsyncMoiImpl)
         .frbInternalCstEncode(move: true);
   }
@@ -142173,7 +155779,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleEnumTwinSync(
       NonCloneSimpleEnumTwinSync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as NonCloneSimpleEnumTwinSyncImpl)
         .frbInternalCstEncode(move: true);
   }
@@ -142182,7 +155788,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleEnumTwinSyncMoi(
       NonCloneSimpleEnumTwinSyncMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as NonCloneSimpleEnumTwinSyncMoiImpl)
         .frbInternalCstEncode(move: true);
   }
@@ -142191,7 +155797,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinMoi(
       NonCloneSimpleTwinMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as NonCloneSimpleTwinMoiImpl).frbInternalCstEncode(move: true);
   }
 
@@ -142199,7 +155805,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinNormal(
       NonCloneSimpleTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as NonCloneSimpleTwinNormalImpl)
         .frbInternalCstEncode(move: true);
   }
@@ -142208,7 +155814,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinRustAsync(
       NonCloneSimpleTwinRustAsync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as NonCloneSimpleTwinRustAsyncImpl)
         .frbInternalCstEncode(move: true);
   }
@@ -142217,7 +155823,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinRustAsyncMoi(
       NonCloneSimpleTwinRustAsyncMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as NonCloneSimpleTwinRustAsyncMoiImpl)
         .frbInternalCstEncode(move: true);
   }
@@ -142226,7 +155832,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSync(
       NonCloneSimpleTwinSync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as NonCloneSimpleTwinSyncImpl).frbInternalCstEncode(move: true);
   }
 
@@ -142234,7 +155840,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSyncMoi(
       NonCloneSimpleTwinSyncMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as NonCloneSimpleTwinSyncMoiImpl)
         .frbInternalCstEncode(move: true);
   }
@@ -142243,7 +155849,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpaqueItemTwinNormal(
       OpaqueItemTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as OpaqueItemTwinNormalImpl).frbInternalCstEncode(move: true);
   }
 
@@ -142251,7 +155857,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpaqueOneTwinMoi(
       OpaqueOneTwinMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as OpaqueOneTwinMoiImpl).frbInternalCstEncode(move: true);
   }
 
@@ -142259,7 +155865,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpaqueOneTwinNormal(
       OpaqueOneTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as OpaqueOneTwinNormalImpl).frbInternalCstEncode(move: true);
   }
 
@@ -142267,7 +155873,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpaqueOneTwinRustAsync(
       OpaqueOneTwinRustAsync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as OpaqueOneTwinRustAsyncImpl).frbInternalCstEncode(move: true);
   }
 
@@ -142275,7 +155881,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpaqueOneTwinRustAsyncMoi(
       OpaqueOneTwinRustAsyncMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as OpaqueOneTwinRustAsyncMoiImpl)
         .frbInternalCstEncode(move: true);
   }
@@ -142284,7 +155890,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpaqueOneTwinSync(
       OpaqueOneTwinSync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as OpaqueOneTwinSyncImpl).frbInternalCstEncode(move: true);
   }
 
@@ -142292,7 +155898,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpaqueOneTwinSyncMoi(
       OpaqueOneTwinSyncMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as OpaqueOneTwinSyncMoiImpl).frbInternalCstEncode(move: true);
   }
 
@@ -142300,7 +155906,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpaqueStructWithDartCodeTwinNormal(
       OpaqueStructWithDartCodeTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as OpaqueStructWithDartCodeTwinNormalImpl)
         .frbInternalCstEncode(move: true);
   }
@@ -142309,7 +155915,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpaqueTwoTwinMoi(
       OpaqueTwoTwinMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as OpaqueTwoTwinMoiImpl).frbInternalCstEncode(move: true);
   }
 
@@ -142317,7 +155923,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpaqueTwoTwinNormal(
       OpaqueTwoTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as OpaqueTwoTwinNormalImpl).frbInternalCstEncode(move: true);
   }
 
@@ -142325,7 +155931,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpaqueTwoTwinRustAsync(
       OpaqueTwoTwinRustAsync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as OpaqueTwoTwinRustAsyncImpl).frbInternalCstEncode(move: true);
   }
 
@@ -142333,7 +155939,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpaqueTwoTwinRustAsyncMoi(
       OpaqueTwoTwinRustAsyncMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as OpaqueTwoTwinRustAsyncMoiImpl)
         .frbInternalCstEncode(move: true);
   }
@@ -142342,7 +155948,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpaqueTwoTwinSync(
       OpaqueTwoTwinSync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as OpaqueTwoTwinSyncImpl).frbInternalCstEncode(move: true);
   }
 
@@ -142350,7 +155956,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpaqueTwoTwinSyncMoi(
       OpaqueTwoTwinSyncMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as OpaqueTwoTwinSyncMoiImpl).frbInternalCstEncode(move: true);
   }
 
@@ -142358,7 +155964,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSimpleLogger(
       SimpleLogger raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as SimpleLoggerImpl).frbInternalCstEncode(move: true);
   }
 
@@ -142366,7 +155972,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSimpleOpaqueExternalStructWithMethod(
       SimpleOpaqueExternalStructWithMethod raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as SimpleOpaqueExternalStructWithMethodImpl)
         .frbInternalCstEncode(move: true);
   }
@@ -142375,7 +155981,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStaticGetterOnlyTwinNormal(
       StaticGetterOnlyTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StaticGetterOnlyTwinNormalImpl)
         .frbInternalCstEncode(move: true);
   }
@@ -142384,7 +155990,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStaticGetterOnlyTwinRustAsync(
       StaticGetterOnlyTwinRustAsync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StaticGetterOnlyTwinRustAsyncImpl)
         .frbInternalCstEncode(move: true);
   }
@@ -142393,7 +155999,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStaticGetterOnlyTwinSync(
       StaticGetterOnlyTwinSync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StaticGetterOnlyTwinSyncImpl)
         .frbInternalCstEncode(move: true);
   }
@@ -142402,7 +156008,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructInMiscNoTwinExampleA(
       StructInMiscNoTwinExampleA raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructInMiscNoTwinExampleAImpl)
         .frbInternalCstEncode(move: true);
   }
@@ -142411,7 +156017,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructInMiscNoTwinExampleB(
       StructInMiscNoTwinExampleB raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructInMiscNoTwinExampleBImpl)
         .frbInternalCstEncode(move: true);
   }
@@ -142420,7 +156026,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructOneWithTraitForDynTwinNormal(
       StructOneWithTraitForDynTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructOneWithTraitForDynTwinNormalImpl)
         .frbInternalCstEncode(move: true);
   }
@@ -142429,7 +156035,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructOneWithTraitTwinNormal(
       StructOneWithTraitTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructOneWithTraitTwinNormalImpl)
         .frbInternalCstEncode(move: true);
   }
@@ -142438,7 +156044,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructOneWithTraitTwinSse(
       StructOneWithTraitTwinSse raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructOneWithTraitTwinSseImpl)
         .frbInternalCstEncode(move: true);
   }
@@ -142447,7 +156053,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructOneWithTraitTwinSync(
       StructOneWithTraitTwinSync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructOneWithTraitTwinSyncImpl)
         .frbInternalCstEncode(move: true);
   }
@@ -142456,7 +156062,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructOneWithTraitTwinSyncSse(
       StructOneWithTraitTwinSyncSse raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructOneWithTraitTwinSyncSseImpl)
         .frbInternalCstEncode(move: true);
   }
@@ -142465,7 +156071,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitForDynTwinNormal(
       StructTwoWithTraitForDynTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructTwoWithTraitForDynTwinNormalImpl)
         .frbInternalCstEncode(move: true);
   }
@@ -142474,7 +156080,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitTwinNormal(
       StructTwoWithTraitTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructTwoWithTraitTwinNormalImpl)
         .frbInternalCstEncode(move: true);
   }
@@ -142483,7 +156089,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitTwinSse(
       StructTwoWithTraitTwinSse raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructTwoWithTraitTwinSseImpl)
         .frbInternalCstEncode(move: true);
   }
@@ -142492,7 +156098,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitTwinSync(
       StructTwoWithTraitTwinSync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructTwoWithTraitTwinSyncImpl)
         .frbInternalCstEncode(move: true);
   }
@@ -142501,16 +156107,25 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitTwinSyncSse(
       StructTwoWithTraitTwinSyncSse raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructTwoWithTraitTwinSyncSseImpl)
         .frbInternalCstEncode(move: true);
   }
 
+  @protected
+  int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+      StructWithDartImplementableTraitTwinNormal raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+  // ignore: invalid_use_of_internal_member
+    return (raw as StructWithDartImplementableTraitTwinNormalImpl)
+        .frbInternalCstEncode(move: true);
+  }
+
   @protected
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinMoi(
       StructWithGoodAndOpaqueFieldWithoutOptionTwinMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructWithGoodAndOpaqueFieldWithoutOptionTwinMoiImpl)
         .frbInternalCstEncode(move: true);
   }
@@ -142519,7 +156134,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinNormal(
       StructWithGoodAndOpaqueFieldWithoutOptionTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructWithGoodAndOpaqueFieldWithoutOptionTwinNormalImpl)
         .frbInternalCstEncode(move: true);
   }
@@ -142528,7 +156143,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinRustAsync(
       StructWithGoodAndOpaqueFieldWithoutOptionTwinRustAsync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructWithGoodAndOpaqueFieldWithoutOptionTwinRustAsyncImpl)
         .frbInternalCstEncode(move: true);
   }
@@ -142537,7 +156152,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinRustAsyncMoi(
       StructWithGoodAndOpaqueFieldWithoutOptionTwinRustAsyncMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw
             as StructWithGoodAndOpaqueFieldWithoutOptionTwinRustAsyncMoiImpl)
         .frbInternalCstEncode(move: true);
@@ -142547,7 +156162,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinRustAsyncSse(
       StructWithGoodAndOpaqueFieldWithoutOptionTwinRustAsyncSse raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw
             as StructWithGoodAndOpaqueFieldWithoutOptionTwinRustAsyncSseImpl)
         .frbInternalCstEncode(move: true);
@@ -142557,7 +156172,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinRustAsyncSseMoi(
       StructWithGoodAndOpaqueFieldWithoutOptionTwinRustAsyncSseMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw
             as StructWithGoodAndOpaqueFieldWithoutOptionTwinRustAsyncSseMoiImpl)
         .frbInternalCstEncode(move: true);
@@ -142567,7 +156182,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinSse(
       StructWithGoodAndOpaqueFieldWithoutOptionTwinSse raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructWithGoodAndOpaqueFieldWithoutOptionTwinSseImpl)
         .frbInternalCstEncode(move: true);
   }
@@ -142576,7 +156191,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinSseMoi(
       StructWithGoodAndOpaqueFieldWithoutOptionTwinSseMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructWithGoodAndOpaqueFieldWithoutOptionTwinSseMoiImpl)
         .frbInternalCstEncode(move: true);
   }
@@ -142585,7 +156200,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinSync(
       StructWithGoodAndOpaqueFieldWithoutOptionTwinSync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructWithGoodAndOpaqueFieldWithoutOptionTwinSyncImpl)
         .frbInternalCstEncode(move: true);
   }
@@ -142594,7 +156209,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinSyncMoi(
       StructWithGoodAndOpaqueFieldWithoutOptionTwinSyncMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructWithGoodAndOpaqueFieldWithoutOptionTwinSyncMoiImpl)
         .frbInternalCstEncode(move: true);
   }
@@ -142603,7 +156218,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinSyncSse(
       StructWithGoodAndOpaqueFieldWithoutOptionTwinSyncSse raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructWithGoodAndOpaqueFieldWithoutOptionTwinSyncSseImpl)
         .frbInternalCstEncode(move: true);
   }
@@ -142612,7 +156227,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinSyncSseMoi(
       StructWithGoodAndOpaqueFieldWithoutOptionTwinSyncSseMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructWithGoodAndOpaqueFieldWithoutOptionTwinSyncSseMoiImpl)
         .frbInternalCstEncode(move: true);
   }
@@ -142621,7 +156236,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithImplBlockInMultiFile(
       StructWithImplBlockInMultiFile raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructWithImplBlockInMultiFileImpl)
         .frbInternalCstEncode(move: true);
   }
@@ -142630,7 +156245,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithSimpleSetterTwinNormal(
       StructWithSimpleSetterTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructWithSimpleSetterTwinNormalImpl)
         .frbInternalCstEncode(move: true);
   }
@@ -142639,7 +156254,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConstructorOpaqueStructTwinNormal(
       ConstructorOpaqueStructTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as ConstructorOpaqueStructTwinNormalImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -142648,7 +156263,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConstructorOpaqueSyncStructTwinNormal(
       ConstructorOpaqueSyncStructTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as ConstructorOpaqueSyncStructTwinNormalImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -142657,7 +156272,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeliberateFailSanityCheckTwinNormal(
       DeliberateFailSanityCheckTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as DeliberateFailSanityCheckTwinNormalImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -142666,7 +156281,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDroppableTwinNormal(
       DroppableTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as DroppableTwinNormalImpl).frbInternalCstEncode(move: false);
   }
 
@@ -142674,7 +156289,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDroppableTwinRustAsync(
       DroppableTwinRustAsync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as DroppableTwinRustAsyncImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -142683,7 +156298,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDroppableTwinSync(
       DroppableTwinSync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as DroppableTwinSyncImpl).frbInternalCstEncode(move: false);
   }
 
@@ -142691,7 +156306,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerItemContainerSolutionOneTwinNormal(
       ItemContainerSolutionOneTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as ItemContainerSolutionOneTwinNormalImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -142700,7 +156315,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinMoi(
       NonCloneSimpleTwinMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as NonCloneSimpleTwinMoiImpl).frbInternalCstEncode(move: false);
   }
 
@@ -142708,7 +156323,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinNormal(
       NonCloneSimpleTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as NonCloneSimpleTwinNormalImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -142717,7 +156332,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinRustAsync(
       NonCloneSimpleTwinRustAsync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as NonCloneSimpleTwinRustAsyncImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -142726,7 +156341,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinRustAsyncMoi(
       NonCloneSimpleTwinRustAsyncMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as NonCloneSimpleTwinRustAsyncMoiImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -142735,7 +156350,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSync(
       NonCloneSimpleTwinSync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as NonCloneSimpleTwinSyncImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -142744,7 +156359,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSyncMoi(
       NonCloneSimpleTwinSyncMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as NonCloneSimpleTwinSyncMoiImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -142753,7 +156368,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructOneWithTraitForDynTwinNormal(
       StructOneWithTraitForDynTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructOneWithTraitForDynTwinNormalImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -142762,7 +156377,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructOneWithTraitTwinNormal(
       StructOneWithTraitTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructOneWithTraitTwinNormalImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -142771,7 +156386,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructOneWithTraitTwinSse(
       StructOneWithTraitTwinSse raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructOneWithTraitTwinSseImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -142780,7 +156395,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructOneWithTraitTwinSync(
       StructOneWithTraitTwinSync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructOneWithTraitTwinSyncImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -142789,7 +156404,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructOneWithTraitTwinSyncSse(
       StructOneWithTraitTwinSyncSse raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructOneWithTraitTwinSyncSseImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -142798,7 +156413,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitForDynTwinNormal(
       StructTwoWithTraitForDynTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructTwoWithTraitForDynTwinNormalImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -142807,7 +156422,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitTwinNormal(
       StructTwoWithTraitTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructTwoWithTraitTwinNormalImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -142816,7 +156431,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitTwinSse(
       StructTwoWithTraitTwinSse raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructTwoWithTraitTwinSseImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -142825,7 +156440,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitTwinSync(
       StructTwoWithTraitTwinSync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructTwoWithTraitTwinSyncImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -142834,16 +156449,25 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitTwinSyncSse(
       StructTwoWithTraitTwinSyncSse raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructTwoWithTraitTwinSyncSseImpl)
         .frbInternalCstEncode(move: false);
   }
 
+  @protected
+  int cst_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+      StructWithDartImplementableTraitTwinNormal raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+  // ignore: invalid_use_of_internal_member
+    return (raw as StructWithDartImplementableTraitTwinNormalImpl)
+        .frbInternalCstEncode(move: false);
+  }
+
   @protected
   int cst_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinMoi(
       StructWithGoodAndOpaqueFieldWithoutOptionTwinMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructWithGoodAndOpaqueFieldWithoutOptionTwinMoiImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -142852,7 +156476,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinNormal(
       StructWithGoodAndOpaqueFieldWithoutOptionTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructWithGoodAndOpaqueFieldWithoutOptionTwinNormalImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -142861,7 +156485,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinRustAsync(
       StructWithGoodAndOpaqueFieldWithoutOptionTwinRustAsync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructWithGoodAndOpaqueFieldWithoutOptionTwinRustAsyncImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -142870,7 +156494,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinRustAsyncMoi(
       StructWithGoodAndOpaqueFieldWithoutOptionTwinRustAsyncMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw
             as StructWithGoodAndOpaqueFieldWithoutOptionTwinRustAsyncMoiImpl)
         .frbInternalCstEncode(move: false);
@@ -142880,7 +156504,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinRustAsyncSse(
       StructWithGoodAndOpaqueFieldWithoutOptionTwinRustAsyncSse raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw
             as StructWithGoodAndOpaqueFieldWithoutOptionTwinRustAsyncSseImpl)
         .frbInternalCstEncode(move: false);
@@ -142890,7 +156514,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinRustAsyncSseMoi(
       StructWithGoodAndOpaqueFieldWithoutOptionTwinRustAsyncSseMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw
             as StructWithGoodAndOpaqueFieldWithoutOptionTwinRustAsyncSseMoiImpl)
         .frbInternalCstEncode(move: false);
@@ -142900,7 +156524,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinSse(
       StructWithGoodAndOpaqueFieldWithoutOptionTwinSse raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructWithGoodAndOpaqueFieldWithoutOptionTwinSseImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -142909,7 +156533,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinSseMoi(
       StructWithGoodAndOpaqueFieldWithoutOptionTwinSseMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructWithGoodAndOpaqueFieldWithoutOptionTwinSseMoiImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -142918,7 +156542,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinSync(
       StructWithGoodAndOpaqueFieldWithoutOptionTwinSync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructWithGoodAndOpaqueFieldWithoutOptionTwinSyncImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -142927,7 +156551,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinSyncMoi(
       StructWithGoodAndOpaqueFieldWithoutOptionTwinSyncMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructWithGoodAndOpaqueFieldWithoutOptionTwinSyncMoiImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -142936,7 +156560,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinSyncSse(
       StructWithGoodAndOpaqueFieldWithoutOptionTwinSyncSse raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructWithGoodAndOpaqueFieldWithoutOptionTwinSyncSseImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -142945,7 +156569,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinSyncSseMoi(
       StructWithGoodAndOpaqueFieldWithoutOptionTwinSyncSseMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructWithGoodAndOpaqueFieldWithoutOptionTwinSyncSseMoiImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -142954,7 +156578,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithSimpleSetterTwinNormal(
       StructWithSimpleSetterTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructWithSimpleSetterTwinNormalImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -142963,7 +156587,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConstructorOpaqueStructTwinNormal(
       ConstructorOpaqueStructTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as ConstructorOpaqueStructTwinNormalImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -142972,16 +156596,25 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConstructorOpaqueSyncStructTwinNormal(
       ConstructorOpaqueSyncStructTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as ConstructorOpaqueSyncStructTwinNormalImpl)
         .frbInternalCstEncode(move: false);
   }
 
+  @protected
+  int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl(
+      DartImplementableTraitTwinNormalDartImpl raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+  // ignore: invalid_use_of_internal_member
+    return (raw as DartImplementableTraitTwinNormalDartImplImpl)
+        .frbInternalCstEncode(move: false);
+  }
+
   @protected
   int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeliberateFailSanityCheckTwinNormal(
       DeliberateFailSanityCheckTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as DeliberateFailSanityCheckTwinNormalImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -142990,7 +156623,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDroppableTwinNormal(
       DroppableTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as DroppableTwinNormalImpl).frbInternalCstEncode(move: false);
   }
 
@@ -142998,7 +156631,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDroppableTwinRustAsync(
       DroppableTwinRustAsync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as DroppableTwinRustAsyncImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -143007,7 +156640,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDroppableTwinSync(
       DroppableTwinSync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as DroppableTwinSyncImpl).frbInternalCstEncode(move: false);
   }
 
@@ -143015,7 +156648,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerItemContainerSolutionOneTwinNormal(
       ItemContainerSolutionOneTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as ItemContainerSolutionOneTwinNormalImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -143024,7 +156657,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMyImplTraitWithSelfTwinNormal(
       MyImplTraitWithSelfTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as MyImplTraitWithSelfTwinNormalImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -143033,7 +156666,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMyImplTraitWithSelfTwinSse(
       MyImplTraitWithSelfTwinSse raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as MyImplTraitWithSelfTwinSseImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -143042,7 +156675,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMyImplTraitWithSelfTwinSync(
       MyImplTraitWithSelfTwinSync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as MyImplTraitWithSelfTwinSyncImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -143051,7 +156684,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMyImplTraitWithSelfTwinSyncSse(
       MyImplTraitWithSelfTwinSyncSse raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as MyImplTraitWithSelfTwinSyncSseImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -143060,7 +156693,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMyStructWithTryFromTwinNormal(
       MyStructWithTryFromTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as MyStructWithTryFromTwinNormalImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -143069,7 +156702,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleEnumTwinMoi(
       NonCloneSimpleEnumTwinMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as NonCloneSimpleEnumTwinMoiImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -143078,7 +156711,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleEnumTwinNormal(
       NonCloneSimpleEnumTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as NonCloneSimpleEnumTwinNormalImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -143087,7 +156720,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleEnumTwinRustAsync(
       NonCloneSimpleEnumTwinRustAsync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as NonCloneSimpleEnumTwinRustAsyncImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -143096,7 +156729,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleEnumTwinRustAsyncMoi(
       NonCloneSimpleEnumTwinRustAsyncMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as NonCloneSimpleEnumTwinRustAsyncMoiImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -143105,7 +156738,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleEnumTwinSync(
       NonCloneSimpleEnumTwinSync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as NonCloneSimpleEnumTwinSyncImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -143114,7 +156747,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleEnumTwinSyncMoi(
       NonCloneSimpleEnumTwinSyncMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as NonCloneSimpleEnumTwinSyncMoiImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -143123,7 +156756,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinMoi(
       NonCloneSimpleTwinMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as NonCloneSimpleTwinMoiImpl).frbInternalCstEncode(move: false);
   }
 
@@ -143131,7 +156764,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinNormal(
       NonCloneSimpleTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as NonCloneSimpleTwinNormalImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -143140,7 +156773,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinRustAsync(
       NonCloneSimpleTwinRustAsync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as NonCloneSimpleTwinRustAsyncImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -143149,7 +156782,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinRustAsyncMoi(
       NonCloneSimpleTwinRustAsyncMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as NonCloneSimpleTwinRustAsyncMoiImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -143158,7 +156791,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSync(
       NonCloneSimpleTwinSync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as NonCloneSimpleTwinSyncImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -143167,7 +156800,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSyncMoi(
       NonCloneSimpleTwinSyncMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as NonCloneSimpleTwinSyncMoiImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -143176,7 +156809,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpaqueStructWithDartCodeTwinNormal(
       OpaqueStructWithDartCodeTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as OpaqueStructWithDartCodeTwinNormalImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -143185,7 +156818,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSimpleLogger(
       SimpleLogger raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as SimpleLoggerImpl).frbInternalCstEncode(move: false);
   }
 
@@ -143193,7 +156826,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSimpleOpaqueExternalStructWithMethod(
       SimpleOpaqueExternalStructWithMethod raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as SimpleOpaqueExternalStructWithMethodImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -143202,7 +156835,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructInMiscNoTwinExampleA(
       StructInMiscNoTwinExampleA raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructInMiscNoTwinExampleAImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -143211,7 +156844,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructInMiscNoTwinExampleB(
       StructInMiscNoTwinExampleB raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructInMiscNoTwinExampleBImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -143220,7 +156853,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructOneWithTraitForDynTwinNormal(
       StructOneWithTraitForDynTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructOneWithTraitForDynTwinNormalImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -143229,7 +156862,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructOneWithTraitTwinNormal(
       StructOneWithTraitTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructOneWithTraitTwinNormalImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -143238,7 +156871,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructOneWithTraitTwinSse(
       StructOneWithTraitTwinSse raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructOneWithTraitTwinSseImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -143247,7 +156880,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructOneWithTraitTwinSync(
       StructOneWithTraitTwinSync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructOneWithTraitTwinSyncImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -143256,7 +156889,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructOneWithTraitTwinSyncSse(
       StructOneWithTraitTwinSyncSse raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructOneWithTraitTwinSyncSseImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -143265,7 +156898,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitForDynTwinNormal(
       StructTwoWithTraitForDynTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructTwoWithTraitForDynTwinNormalImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -143274,7 +156907,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitTwinNormal(
       StructTwoWithTraitTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructTwoWithTraitTwinNormalImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -143283,7 +156916,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitTwinSse(
       StructTwoWithTraitTwinSse raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructTwoWithTraitTwinSseImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -143292,7 +156925,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitTwinSync(
       StructTwoWithTraitTwinSync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructTwoWithTraitTwinSyncImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -143301,16 +156934,25 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitTwinSyncSse(
       StructTwoWithTraitTwinSyncSse raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructTwoWithTraitTwinSyncSseImpl)
         .frbInternalCstEncode(move: false);
   }
 
+  @protected
+  int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+      StructWithDartImplementableTraitTwinNormal raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+  // ignore: invalid_use_of_internal_member
+    return (raw as StructWithDartImplementableTraitTwinNormalImpl)
+        .frbInternalCstEncode(move: false);
+  }
+
   @protected
   int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinMoi(
       StructWithGoodAndOpaqueFieldWithoutOptionTwinMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructWithGoodAndOpaqueFieldWithoutOptionTwinMoiImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -143319,7 +156961,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinNormal(
       StructWithGoodAndOpaqueFieldWithoutOptionTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructWithGoodAndOpaqueFieldWithoutOptionTwinNormalImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -143328,7 +156970,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinRustAsync(
       StructWithGoodAndOpaqueFieldWithoutOptionTwinRustAsync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructWithGoodAndOpaqueFieldWithoutOptionTwinRustAsyncImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -143337,7 +156979,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinRustAsyncMoi(
       StructWithGoodAndOpaqueFieldWithoutOptionTwinRustAsyncMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw
             as StructWithGoodAndOpaqueFieldWithoutOptionTwinRustAsyncMoiImpl)
         .frbInternalCstEncode(move: false);
@@ -143347,7 +156989,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinRustAsyncSse(
       StructWithGoodAndOpaqueFieldWithoutOptionTwinRustAsyncSse raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw
             as StructWithGoodAndOpaqueFieldWithoutOptionTwinRustAsyncSseImpl)
         .frbInternalCstEncode(move: false);
@@ -143357,7 +156999,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinRustAsyncSseMoi(
       StructWithGoodAndOpaqueFieldWithoutOptionTwinRustAsyncSseMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw
             as StructWithGoodAndOpaqueFieldWithoutOptionTwinRustAsyncSseMoiImpl)
         .frbInternalCstEncode(move: false);
@@ -143367,7 +157009,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinSse(
       StructWithGoodAndOpaqueFieldWithoutOptionTwinSse raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructWithGoodAndOpaqueFieldWithoutOptionTwinSseImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -143376,7 +157018,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinSseMoi(
       StructWithGoodAndOpaqueFieldWithoutOptionTwinSseMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructWithGoodAndOpaqueFieldWithoutOptionTwinSseMoiImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -143385,7 +157027,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinSync(
       StructWithGoodAndOpaqueFieldWithoutOptionTwinSync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructWithGoodAndOpaqueFieldWithoutOptionTwinSyncImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -143394,7 +157036,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinSyncMoi(
       StructWithGoodAndOpaqueFieldWithoutOptionTwinSyncMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructWithGoodAndOpaqueFieldWithoutOptionTwinSyncMoiImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -143403,7 +157045,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinSyncSse(
       StructWithGoodAndOpaqueFieldWithoutOptionTwinSyncSse raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructWithGoodAndOpaqueFieldWithoutOptionTwinSyncSseImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -143412,7 +157054,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinSyncSseMoi(
       StructWithGoodAndOpaqueFieldWithoutOptionTwinSyncSseMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructWithGoodAndOpaqueFieldWithoutOptionTwinSyncSseMoiImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -143421,7 +157063,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithImplBlockInMultiFile(
       StructWithImplBlockInMultiFile raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructWithImplBlockInMultiFileImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -143430,7 +157072,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithSimpleSetterTwinNormal(
       StructWithSimpleSetterTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructWithSimpleSetterTwinNormalImpl)
         .frbInternalCstEncode(move: false);
   }
@@ -143507,6 +157149,25 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         encode_DartFn_Inputs__Output_unit_AnyhowException(raw));
   }
 
+  @protected
+  PlatformPointer
+      cst_encode_DartFn_Inputs_bool_Output_opt_String_AnyhowException(
+          FutureOr<String?> Function(bool) raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_DartOpaque(
+        encode_DartFn_Inputs_bool_Output_opt_String_AnyhowException(raw));
+  }
+
+  @protected
+  PlatformPointer
+      cst_encode_DartFn_Inputs_bool_Output_opt_box_autoadd_i_32_AnyhowException(
+          FutureOr<int?> Function(bool) raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_DartOpaque(
+        encode_DartFn_Inputs_bool_Output_opt_box_autoadd_i_32_AnyhowException(
+            raw));
+  }
+
   @protected
   PlatformPointer
       cst_encode_DartFn_Inputs_demo_struct_for_rust_call_dart_twin_normal_Output_demo_struct_for_rust_call_dart_twin_normal_AnyhowException(
@@ -143531,6 +157192,23 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             raw));
   }
 
+  @protected
+  PlatformPointer cst_encode_DartFn_Inputs_i_32_Output_String_AnyhowException(
+      FutureOr<String> Function(int) raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_DartOpaque(
+        encode_DartFn_Inputs_i_32_Output_String_AnyhowException(raw));
+  }
+
+  @protected
+  PlatformPointer
+      cst_encode_DartFn_Inputs_i_32_i_32_Output_i_32_AnyhowException(
+          FutureOr<int> Function(int, int) raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_DartOpaque(
+        encode_DartFn_Inputs_i_32_i_32_Output_i_32_AnyhowException(raw));
+  }
+
   @protected
   PlatformPointer cst_encode_DartOpaque(Object raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -143541,7 +157219,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   @protected
   int cst_encode_RustOpaque_BoxdynDartDebugTwinMoi(BoxDartDebugTwinMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as BoxDartDebugTwinMoiImpl).frbInternalCstEncode();
   }
 
@@ -143549,7 +157227,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_BoxdynDartDebugTwinNormal(
       BoxDartDebugTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as BoxDartDebugTwinNormalImpl).frbInternalCstEncode();
   }
 
@@ -143557,7 +157235,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_BoxdynDartDebugTwinRustAsync(
       BoxDartDebugTwinRustAsync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as BoxDartDebugTwinRustAsyncImpl).frbInternalCstEncode();
   }
 
@@ -143565,14 +157243,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_BoxdynDartDebugTwinRustAsyncMoi(
       BoxDartDebugTwinRustAsyncMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as BoxDartDebugTwinRustAsyncMoiImpl).frbInternalCstEncode();
   }
 
   @protected
   int cst_encode_RustOpaque_BoxdynDartDebugTwinSync(BoxDartDebugTwinSync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as BoxDartDebugTwinSyncImpl).frbInternalCstEncode();
   }
 
@@ -143580,14 +157258,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_BoxdynDartDebugTwinSyncMoi(
       BoxDartDebugTwinSyncMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as BoxDartDebugTwinSyncMoiImpl).frbInternalCstEncode();
   }
 
   @protected
   int cst_encode_RustOpaque_FrbOpaqueReturnTwinMoi(FrbOpaqueReturnTwinMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as FrbOpaqueReturnTwinMoiImpl).frbInternalCstEncode();
   }
 
@@ -143595,7 +157273,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_FrbOpaqueReturnTwinNormal(
       FrbOpaqueReturnTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as FrbOpaqueReturnTwinNormalImpl).frbInternalCstEncode();
   }
 
@@ -143603,7 +157281,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_FrbOpaqueReturnTwinRustAsync(
       FrbOpaqueReturnTwinRustAsync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as FrbOpaqueReturnTwinRustAsyncImpl).frbInternalCstEncode();
   }
 
@@ -143611,7 +157289,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_FrbOpaqueReturnTwinRustAsyncMoi(
       FrbOpaqueReturnTwinRustAsyncMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as FrbOpaqueReturnTwinRustAsyncMoiImpl).frbInternalCstEncode();
   }
 
@@ -143619,7 +157297,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_FrbOpaqueReturnTwinSync(
       FrbOpaqueReturnTwinSync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as FrbOpaqueReturnTwinSyncImpl).frbInternalCstEncode();
   }
 
@@ -143627,7 +157305,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_FrbOpaqueReturnTwinSyncMoi(
       FrbOpaqueReturnTwinSyncMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as FrbOpaqueReturnTwinSyncMoiImpl).frbInternalCstEncode();
   }
 
@@ -143635,7 +157313,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_FrbOpaqueSyncReturnTwinMoi(
       FrbOpaqueSyncReturnTwinMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as FrbOpaqueSyncReturnTwinMoiImpl).frbInternalCstEncode();
   }
 
@@ -143643,14 +157321,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_FrbOpaqueSyncReturnTwinNormal(
       FrbOpaqueSyncReturnTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as FrbOpaqueSyncReturnTwinNormalImpl).frbInternalCstEncode();
   }
 
   @protected
   int cst_encode_RustOpaque_HideDataAnotherTwinMoi(HideDataAnotherTwinMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as HideDataAnotherTwinMoiImpl).frbInternalCstEncode();
   }
 
@@ -143658,28 +157336,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_HideDataAnotherTwinNormal(
       HideDataAnotherTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as HideDataAnotherTwinNormalImpl).frbInternalCstEncode();
   }
 
   @protected
   int cst_encode_RustOpaque_HideDataTwinMoi(HideDataTwinMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as HideDataTwinMoiImpl).frbInternalCstEncode();
   }
 
   @protected
   int cst_encode_RustOpaque_HideDataTwinNormal(HideDataTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as HideDataTwinNormalImpl).frbInternalCstEncode();
   }
 
   @protected
   int cst_encode_RustOpaque_HideDataTwinRustAsync(HideDataTwinRustAsync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as HideDataTwinRustAsyncImpl).frbInternalCstEncode();
   }
 
@@ -143687,28 +157365,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_HideDataTwinRustAsyncMoi(
       HideDataTwinRustAsyncMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as HideDataTwinRustAsyncMoiImpl).frbInternalCstEncode();
   }
 
   @protected
   int cst_encode_RustOpaque_HideDataTwinSync(HideDataTwinSync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as HideDataTwinSyncImpl).frbInternalCstEncode();
   }
 
   @protected
   int cst_encode_RustOpaque_HideDataTwinSyncMoi(HideDataTwinSyncMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as HideDataTwinSyncMoiImpl).frbInternalCstEncode();
   }
 
   @protected
   int cst_encode_RustOpaque_MutexHideDataTwinMoi(MutexHideDataTwinMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as MutexHideDataTwinMoiImpl).frbInternalCstEncode();
   }
 
@@ -143716,7 +157394,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_MutexHideDataTwinNormal(
       MutexHideDataTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as MutexHideDataTwinNormalImpl).frbInternalCstEncode();
   }
 
@@ -143724,7 +157402,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_MutexHideDataTwinRustAsync(
       MutexHideDataTwinRustAsync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as MutexHideDataTwinRustAsyncImpl).frbInternalCstEncode();
   }
 
@@ -143732,14 +157410,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_MutexHideDataTwinRustAsyncMoi(
       MutexHideDataTwinRustAsyncMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as MutexHideDataTwinRustAsyncMoiImpl).frbInternalCstEncode();
   }
 
   @protected
   int cst_encode_RustOpaque_MutexHideDataTwinSync(MutexHideDataTwinSync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as MutexHideDataTwinSyncImpl).frbInternalCstEncode();
   }
 
@@ -143747,21 +157425,21 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_MutexHideDataTwinSyncMoi(
       MutexHideDataTwinSyncMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as MutexHideDataTwinSyncMoiImpl).frbInternalCstEncode();
   }
 
   @protected
   int cst_encode_RustOpaque_NonCloneDataTwinMoi(NonCloneDataTwinMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as NonCloneDataTwinMoiImpl).frbInternalCstEncode();
   }
 
   @protected
   int cst_encode_RustOpaque_NonCloneDataTwinNormal(NonCloneDataTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as NonCloneDataTwinNormalImpl).frbInternalCstEncode();
   }
 
@@ -143769,7 +157447,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_NonCloneDataTwinRustAsync(
       NonCloneDataTwinRustAsync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as NonCloneDataTwinRustAsyncImpl).frbInternalCstEncode();
   }
 
@@ -143777,14 +157455,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_NonCloneDataTwinRustAsyncMoi(
       NonCloneDataTwinRustAsyncMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as NonCloneDataTwinRustAsyncMoiImpl).frbInternalCstEncode();
   }
 
   @protected
   int cst_encode_RustOpaque_NonCloneDataTwinSync(NonCloneDataTwinSync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as NonCloneDataTwinSyncImpl).frbInternalCstEncode();
   }
 
@@ -143792,14 +157470,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_NonCloneDataTwinSyncMoi(
       NonCloneDataTwinSyncMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as NonCloneDataTwinSyncMoiImpl).frbInternalCstEncode();
   }
 
   @protected
   int cst_encode_RustOpaque_RwLockHideDataTwinMoi(RwLockHideDataTwinMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as RwLockHideDataTwinMoiImpl).frbInternalCstEncode();
   }
 
@@ -143807,7 +157485,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_RwLockHideDataTwinNormal(
       RwLockHideDataTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as RwLockHideDataTwinNormalImpl).frbInternalCstEncode();
   }
 
@@ -143815,7 +157493,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_RwLockHideDataTwinRustAsync(
       RwLockHideDataTwinRustAsync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as RwLockHideDataTwinRustAsyncImpl).frbInternalCstEncode();
   }
 
@@ -143823,14 +157501,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_RwLockHideDataTwinRustAsyncMoi(
       RwLockHideDataTwinRustAsyncMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as RwLockHideDataTwinRustAsyncMoiImpl).frbInternalCstEncode();
   }
 
   @protected
   int cst_encode_RustOpaque_RwLockHideDataTwinSync(RwLockHideDataTwinSync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as RwLockHideDataTwinSyncImpl).frbInternalCstEncode();
   }
 
@@ -143838,7 +157516,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_RwLockHideDataTwinSyncMoi(
       RwLockHideDataTwinSyncMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as RwLockHideDataTwinSyncMoiImpl).frbInternalCstEncode();
   }
 
@@ -143846,7 +157524,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynAnySendSyncstatic(
       BoxAnyMyDartTypeRename raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as BoxAnyMyDartTypeRenameImpl).frbInternalCstEncode();
   }
 
@@ -143854,7 +157532,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynFnStringStringSendSync(
       BoxFnStringString raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as BoxFnStringStringImpl).frbInternalCstEncode();
   }
 
@@ -143862,7 +157540,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynMyTraitTwinMoiSendSync(
       BoxMyTraitTwinMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as BoxMyTraitTwinMoiImpl).frbInternalCstEncode();
   }
 
@@ -143870,7 +157548,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynMyTraitTwinNormalSendSync(
       BoxMyTraitTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as BoxMyTraitTwinNormalImpl).frbInternalCstEncode();
   }
 
@@ -143878,7 +157556,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynMyTraitTwinRustAsyncMoiSendSync(
       BoxMyTraitTwinRustAsyncMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as BoxMyTraitTwinRustAsyncMoiImpl).frbInternalCstEncode();
   }
 
@@ -143886,7 +157564,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynMyTraitTwinRustAsyncSendSync(
       BoxMyTraitTwinRustAsync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as BoxMyTraitTwinRustAsyncImpl).frbInternalCstEncode();
   }
 
@@ -143894,7 +157572,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynMyTraitTwinSyncMoiSendSync(
       BoxMyTraitTwinSyncMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as BoxMyTraitTwinSyncMoiImpl).frbInternalCstEncode();
   }
 
@@ -143902,7 +157580,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynMyTraitTwinSyncSendSync(
       BoxMyTraitTwinSync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as BoxMyTraitTwinSyncImpl).frbInternalCstEncode();
   }
 
@@ -143910,7 +157588,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConstructorOpaqueStructTwinNormal(
       ConstructorOpaqueStructTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as ConstructorOpaqueStructTwinNormalImpl)
         .frbInternalCstEncode();
   }
@@ -143919,16 +157597,25 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConstructorOpaqueSyncStructTwinNormal(
       ConstructorOpaqueSyncStructTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as ConstructorOpaqueSyncStructTwinNormalImpl)
         .frbInternalCstEncode();
   }
 
+  @protected
+  int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl(
+      DartImplementableTraitTwinNormalDartImpl raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+  // ignore: invalid_use_of_internal_member
+    return (raw as DartImplementableTraitTwinNormalDartImplImpl)
+        .frbInternalCstEncode();
+  }
+
   @protected
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeliberateFailSanityCheckTwinNormal(
       DeliberateFailSanityCheckTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as DeliberateFailSanityCheckTwinNormalImpl)
         .frbInternalCstEncode();
   }
@@ -143937,7 +157624,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDroppableTwinNormal(
       DroppableTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as DroppableTwinNormalImpl).frbInternalCstEncode();
   }
 
@@ -143945,7 +157632,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDroppableTwinRustAsync(
       DroppableTwinRustAsync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as DroppableTwinRustAsyncImpl).frbInternalCstEncode();
   }
 
@@ -143953,7 +157640,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDroppableTwinSync(
       DroppableTwinSync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as DroppableTwinSyncImpl).frbInternalCstEncode();
   }
 
@@ -143961,7 +157648,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnumWithGoodAndOpaqueWithoutOptionTwinMoi(
       EnumWithGoodAndOpaqueWithoutOptionTwinMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as EnumWithGoodAndOpaqueWithoutOptionTwinMoiImpl)
         .frbInternalCstEncode();
   }
@@ -143970,7 +157657,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnumWithGoodAndOpaqueWithoutOptionTwinNormal(
       EnumWithGoodAndOpaqueWithoutOptionTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as EnumWithGoodAndOpaqueWithoutOptionTwinNormalImpl)
         .frbInternalCstEncode();
   }
@@ -143979,7 +157666,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnumWithGoodAndOpaqueWithoutOptionTwinRustAsync(
       EnumWithGoodAndOpaqueWithoutOptionTwinRustAsync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as EnumWithGoodAndOpaqueWithoutOptionTwinRustAsyncImpl)
         .frbInternalCstEncode();
   }
@@ -143988,7 +157675,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnumWithGoodAndOpaqueWithoutOptionTwinRustAsyncMoi(
       EnumWithGoodAndOpaqueWithoutOptionTwinRustAsyncMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as EnumWithGoodAndOpaqueWithoutOptionTwinRustAsyncMoiImpl)
         .frbInternalCstEncode();
   }
@@ -143997,7 +157684,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnumWithGoodAndOpaqueWithoutOptionTwinSync(
       EnumWithGoodAndOpaqueWithoutOptionTwinSync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as EnumWithGoodAndOpaqueWithoutOptionTwinSyncImpl)
         .frbInternalCstEncode();
   }
@@ -144006,7 +157693,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEnumWithGoodAndOpaqueWithoutOptionTwinSyncMoi(
       EnumWithGoodAndOpaqueWithoutOptionTwinSyncMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as EnumWithGoodAndOpaqueWithoutOptionTwinSyncMoiImpl)
         .frbInternalCstEncode();
   }
@@ -144015,7 +157702,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerItemContainerSolutionOneTwinNormal(
       ItemContainerSolutionOneTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as ItemContainerSolutionOneTwinNormalImpl)
         .frbInternalCstEncode();
   }
@@ -144024,7 +157711,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMyImplTraitWithSelfTwinNormal(
       MyImplTraitWithSelfTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as MyImplTraitWithSelfTwinNormalImpl).frbInternalCstEncode();
   }
 
@@ -144032,7 +157719,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMyImplTraitWithSelfTwinSse(
       MyImplTraitWithSelfTwinSse raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as MyImplTraitWithSelfTwinSseImpl).frbInternalCstEncode();
   }
 
@@ -144040,7 +157727,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMyImplTraitWithSelfTwinSync(
       MyImplTraitWithSelfTwinSync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as MyImplTraitWithSelfTwinSyncImpl).frbInternalCstEncode();
   }
 
@@ -144048,7 +157735,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMyImplTraitWithSelfTwinSyncSse(
       MyImplTraitWithSelfTwinSyncSse raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as MyImplTraitWithSelfTwinSyncSseImpl).frbInternalCstEncode();
   }
 
@@ -144056,7 +157743,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMyNodeTwinNormal(
       MyNodeTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as MyNodeTwinNormalImpl).frbInternalCstEncode();
   }
 
@@ -144064,7 +157751,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMyStructWithTryFromTwinNormal(
       MyStructWithTryFromTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as MyStructWithTryFromTwinNormalImpl).frbInternalCstEncode();
   }
 
@@ -144072,7 +157759,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleEnumTwinMoi(
       NonCloneSimpleEnumTwinMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as NonCloneSimpleEnumTwinMoiImpl).frbInternalCstEncode();
   }
 
@@ -144080,7 +157767,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleEnumTwinNormal(
       NonCloneSimpleEnumTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as NonCloneSimpleEnumTwinNormalImpl).frbInternalCstEncode();
   }
 
@@ -144088,7 +157775,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleEnumTwinRustAsync(
       NonCloneSimpleEnumTwinRustAsync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as NonCloneSimpleEnumTwinRustAsyncImpl).frbInternalCstEncode();
   }
 
@@ -144096,7 +157783,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleEnumTwinRustAsyncMoi(
       NonCloneSimpleEnumTwinRustAsyncMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as NonCloneSimpleEnumTwinRustAsyncMoiImpl)
         .frbInternalCstEncode();
   }
@@ -144105,7 +157792,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleEnumTwinSync(
       NonCloneSimpleEnumTwinSync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as NonCloneSimpleEnumTwinSyncImpl).frbInternalCstEncode();
   }
 
@@ -144113,7 +157800,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleEnumTwinSyncMoi(
       NonCloneSimpleEnumTwinSyncMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as NonCloneSimpleEnumTwinSyncMoiImpl).frbInternalCstEncode();
   }
 
@@ -144121,7 +157808,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinMoi(
       NonCloneSimpleTwinMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as NonCloneSimpleTwinMoiImpl).frbInternalCstEncode();
   }
 
@@ -144129,7 +157816,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinNormal(
       NonCloneSimpleTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as NonCloneSimpleTwinNormalImpl).frbInternalCstEncode();
   }
 
@@ -144137,7 +157824,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinRustAsync(
       NonCloneSimpleTwinRustAsync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as NonCloneSimpleTwinRustAsyncImpl).frbInternalCstEncode();
   }
 
@@ -144145,7 +157832,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinRustAsyncMoi(
       NonCloneSimpleTwinRustAsyncMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as NonCloneSimpleTwinRustAsyncMoiImpl).frbInternalCstEncode();
   }
 
@@ -144153,7 +157840,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSync(
       NonCloneSimpleTwinSync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as NonCloneSimpleTwinSyncImpl).frbInternalCstEncode();
   }
 
@@ -144161,7 +157848,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSyncMoi(
       NonCloneSimpleTwinSyncMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as NonCloneSimpleTwinSyncMoiImpl).frbInternalCstEncode();
   }
 
@@ -144169,7 +157856,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpaqueItemTwinNormal(
       OpaqueItemTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as OpaqueItemTwinNormalImpl).frbInternalCstEncode();
   }
 
@@ -144177,7 +157864,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpaqueOneTwinMoi(
       OpaqueOneTwinMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as OpaqueOneTwinMoiImpl).frbInternalCstEncode();
   }
 
@@ -144185,7 +157872,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpaqueOneTwinNormal(
       OpaqueOneTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as OpaqueOneTwinNormalImpl).frbInternalCstEncode();
   }
 
@@ -144193,7 +157880,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpaqueOneTwinRustAsync(
       OpaqueOneTwinRustAsync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as OpaqueOneTwinRustAsyncImpl).frbInternalCstEncode();
   }
 
@@ -144201,7 +157888,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpaqueOneTwinRustAsyncMoi(
       OpaqueOneTwinRustAsyncMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as OpaqueOneTwinRustAsyncMoiImpl).frbInternalCstEncode();
   }
 
@@ -144209,7 +157896,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpaqueOneTwinSync(
       OpaqueOneTwinSync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as OpaqueOneTwinSyncImpl).frbInternalCstEncode();
   }
 
@@ -144217,7 +157904,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpaqueOneTwinSyncMoi(
       OpaqueOneTwinSyncMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as OpaqueOneTwinSyncMoiImpl).frbInternalCstEncode();
   }
 
@@ -144225,7 +157912,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpaqueStructWithDartCodeTwinNormal(
       OpaqueStructWithDartCodeTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as OpaqueStructWithDartCodeTwinNormalImpl)
         .frbInternalCstEncode();
   }
@@ -144234,7 +157921,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpaqueTwoTwinMoi(
       OpaqueTwoTwinMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as OpaqueTwoTwinMoiImpl).frbInternalCstEncode();
   }
 
@@ -144242,7 +157929,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpaqueTwoTwinNormal(
       OpaqueTwoTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as OpaqueTwoTwinNormalImpl).frbInternalCstEncode();
   }
 
@@ -144250,7 +157937,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpaqueTwoTwinRustAsync(
       OpaqueTwoTwinRustAsync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as OpaqueTwoTwinRustAsyncImpl).frbInternalCstEncode();
   }
 
@@ -144258,7 +157945,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpaqueTwoTwinRustAsyncMoi(
       OpaqueTwoTwinRustAsyncMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as OpaqueTwoTwinRustAsyncMoiImpl).frbInternalCstEncode();
   }
 
@@ -144266,7 +157953,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpaqueTwoTwinSync(
       OpaqueTwoTwinSync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as OpaqueTwoTwinSyncImpl).frbInternalCstEncode();
   }
 
@@ -144274,7 +157961,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpaqueTwoTwinSyncMoi(
       OpaqueTwoTwinSyncMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as OpaqueTwoTwinSyncMoiImpl).frbInternalCstEncode();
   }
 
@@ -144282,7 +157969,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSimpleLogger(
       SimpleLogger raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as SimpleLoggerImpl).frbInternalCstEncode();
   }
 
@@ -144290,7 +157977,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSimpleOpaqueExternalStructWithMethod(
       SimpleOpaqueExternalStructWithMethod raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as SimpleOpaqueExternalStructWithMethodImpl)
         .frbInternalCstEncode();
   }
@@ -144299,7 +157986,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStaticGetterOnlyTwinNormal(
       StaticGetterOnlyTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StaticGetterOnlyTwinNormalImpl).frbInternalCstEncode();
   }
 
@@ -144307,7 +157994,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStaticGetterOnlyTwinRustAsync(
       StaticGetterOnlyTwinRustAsync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StaticGetterOnlyTwinRustAsyncImpl).frbInternalCstEncode();
   }
 
@@ -144315,7 +158002,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStaticGetterOnlyTwinSync(
       StaticGetterOnlyTwinSync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StaticGetterOnlyTwinSyncImpl).frbInternalCstEncode();
   }
 
@@ -144323,7 +158010,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructInMiscNoTwinExampleA(
       StructInMiscNoTwinExampleA raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructInMiscNoTwinExampleAImpl).frbInternalCstEncode();
   }
 
@@ -144331,7 +158018,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructInMiscNoTwinExampleB(
       StructInMiscNoTwinExampleB raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructInMiscNoTwinExampleBImpl).frbInternalCstEncode();
   }
 
@@ -144339,7 +158026,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructOneWithTraitForDynTwinNormal(
       StructOneWithTraitForDynTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructOneWithTraitForDynTwinNormalImpl)
         .frbInternalCstEncode();
   }
@@ -144348,7 +158035,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructOneWithTraitTwinNormal(
       StructOneWithTraitTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructOneWithTraitTwinNormalImpl).frbInternalCstEncode();
   }
 
@@ -144356,7 +158043,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructOneWithTraitTwinSse(
       StructOneWithTraitTwinSse raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructOneWithTraitTwinSseImpl).frbInternalCstEncode();
   }
 
@@ -144364,7 +158051,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructOneWithTraitTwinSync(
       StructOneWithTraitTwinSync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructOneWithTraitTwinSyncImpl).frbInternalCstEncode();
   }
 
@@ -144372,7 +158059,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructOneWithTraitTwinSyncSse(
       StructOneWithTraitTwinSyncSse raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructOneWithTraitTwinSyncSseImpl).frbInternalCstEncode();
   }
 
@@ -144380,7 +158067,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitForDynTwinNormal(
       StructTwoWithTraitForDynTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructTwoWithTraitForDynTwinNormalImpl)
         .frbInternalCstEncode();
   }
@@ -144389,7 +158076,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitTwinNormal(
       StructTwoWithTraitTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructTwoWithTraitTwinNormalImpl).frbInternalCstEncode();
   }
 
@@ -144397,7 +158084,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitTwinSse(
       StructTwoWithTraitTwinSse raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructTwoWithTraitTwinSseImpl).frbInternalCstEncode();
   }
 
@@ -144405,7 +158092,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitTwinSync(
       StructTwoWithTraitTwinSync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructTwoWithTraitTwinSyncImpl).frbInternalCstEncode();
   }
 
@@ -144413,15 +158100,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitTwinSyncSse(
       StructTwoWithTraitTwinSyncSse raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructTwoWithTraitTwinSyncSseImpl).frbInternalCstEncode();
   }
 
+  @protected
+  int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+      StructWithDartImplementableTraitTwinNormal raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+  // ignore: invalid_use_of_internal_member
+    return (raw as StructWithDartImplementableTraitTwinNormalImpl)
+        .frbInternalCstEncode();
+  }
+
   @protected
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinMoi(
       StructWithGoodAndOpaqueFieldWithoutOptionTwinMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructWithGoodAndOpaqueFieldWithoutOptionTwinMoiImpl)
         .frbInternalCstEncode();
   }
@@ -144430,7 +158126,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinNormal(
       StructWithGoodAndOpaqueFieldWithoutOptionTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructWithGoodAndOpaqueFieldWithoutOptionTwinNormalImpl)
         .frbInternalCstEncode();
   }
@@ -144439,7 +158135,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinRustAsync(
       StructWithGoodAndOpaqueFieldWithoutOptionTwinRustAsync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructWithGoodAndOpaqueFieldWithoutOptionTwinRustAsyncImpl)
         .frbInternalCstEncode();
   }
@@ -144448,7 +158144,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinRustAsyncMoi(
       StructWithGoodAndOpaqueFieldWithoutOptionTwinRustAsyncMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw
             as StructWithGoodAndOpaqueFieldWithoutOptionTwinRustAsyncMoiImpl)
         .frbInternalCstEncode();
@@ -144458,7 +158154,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinRustAsyncSse(
       StructWithGoodAndOpaqueFieldWithoutOptionTwinRustAsyncSse raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw
             as StructWithGoodAndOpaqueFieldWithoutOptionTwinRustAsyncSseImpl)
         .frbInternalCstEncode();
@@ -144468,7 +158164,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinRustAsyncSseMoi(
       StructWithGoodAndOpaqueFieldWithoutOptionTwinRustAsyncSseMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw
             as StructWithGoodAndOpaqueFieldWithoutOptionTwinRustAsyncSseMoiImpl)
         .frbInternalCstEncode();
@@ -144478,7 +158174,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinSse(
       StructWithGoodAndOpaqueFieldWithoutOptionTwinSse raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructWithGoodAndOpaqueFieldWithoutOptionTwinSseImpl)
         .frbInternalCstEncode();
   }
@@ -144487,7 +158183,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinSseMoi(
       StructWithGoodAndOpaqueFieldWithoutOptionTwinSseMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructWithGoodAndOpaqueFieldWithoutOptionTwinSseMoiImpl)
         .frbInternalCstEncode();
   }
@@ -144496,7 +158192,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinSync(
       StructWithGoodAndOpaqueFieldWithoutOptionTwinSync raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructWithGoodAndOpaqueFieldWithoutOptionTwinSyncImpl)
         .frbInternalCstEncode();
   }
@@ -144505,7 +158201,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinSyncMoi(
       StructWithGoodAndOpaqueFieldWithoutOptionTwinSyncMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructWithGoodAndOpaqueFieldWithoutOptionTwinSyncMoiImpl)
         .frbInternalCstEncode();
   }
@@ -144514,7 +158210,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinSyncSse(
       StructWithGoodAndOpaqueFieldWithoutOptionTwinSyncSse raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructWithGoodAndOpaqueFieldWithoutOptionTwinSyncSseImpl)
         .frbInternalCstEncode();
   }
@@ -144523,7 +158219,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinSyncSseMoi(
       StructWithGoodAndOpaqueFieldWithoutOptionTwinSyncSseMoi raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructWithGoodAndOpaqueFieldWithoutOptionTwinSyncSseMoiImpl)
         .frbInternalCstEncode();
   }
@@ -144532,7 +158228,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithImplBlockInMultiFile(
       StructWithImplBlockInMultiFile raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructWithImplBlockInMultiFileImpl).frbInternalCstEncode();
   }
 
@@ -144540,21 +158236,21 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithSimpleSetterTwinNormal(
       StructWithSimpleSetterTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as StructWithSimpleSetterTwinNormalImpl).frbInternalCstEncode();
   }
 
   @protected
   int cst_encode_RustOpaque_i16(I16 raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as I16Impl).frbInternalCstEncode();
   }
 
   @protected
   int cst_encode_RustOpaque_i32(I32 raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
+  // ignore: invalid_use_of_internal_member
     return (raw as I32Impl).frbInternalCstEncode();
   }
 
@@ -144733,7 +158429,22 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   void sse_encode_AnyhowException(
       AnyhowException self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.message, serializer);
+    sse_encode_list_String([
+      self.message,
+      self.backtrace ?? '',
+      self.typeName ?? '',
+      ...self.causes
+    ], serializer);
+  }
+
+  @protected
+  void
+      sse_encode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl(
+          DartImplementableTraitTwinNormalDartImpl self,
+          SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl(
+        self, serializer);
   }
 
   @protected
@@ -144934,6 +158645,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         self, serializer);
   }
 
+  @protected
+  void
+      sse_encode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+          StructWithDartImplementableTraitTwinNormal self,
+          SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+        self, serializer);
+  }
+
   @protected
   void
       sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynAnySendSyncstatic(
@@ -145100,6 +158821,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         serializer);
   }
 
+  @protected
+  void
+      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl(
+          DartImplementableTraitTwinNormalDartImpl self,
+          SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+        (self as DartImplementableTraitTwinNormalDartImplImpl)
+            .frbInternalSseEncode(move: true),
+        serializer);
+  }
+
   @protected
   void
       sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeliberateFailSanityCheckTwinNormal(
@@ -146186,6 +159919,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         serializer);
   }
 
+  @protected
+  void
+      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+          StructWithDartImplementableTraitTwinNormal self,
+          SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+        (self as StructWithDartImplementableTraitTwinNormalImpl)
+            .frbInternalSseEncode(move: true),
+        serializer);
+  }
+
   @protected
   void
       sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinMoi(
@@ -146717,6 +160462,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         serializer);
   }
 
+  @protected
+  void
+      sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+          StructWithDartImplementableTraitTwinNormal self,
+          SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+        (self as StructWithDartImplementableTraitTwinNormalImpl)
+            .frbInternalSseEncode(move: false),
+        serializer);
+  }
+
   @protected
   void
       sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinMoi(
@@ -146895,6 +160652,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         serializer);
   }
 
+  @protected
+  void
+      sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl(
+          DartImplementableTraitTwinNormalDartImpl self,
+          SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+        (self as DartImplementableTraitTwinNormalDartImplImpl)
+            .frbInternalSseEncode(move: false),
+        serializer);
+  }
+
   @protected
   void
       sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeliberateFailSanityCheckTwinNormal(
@@ -147519,6 +161288,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         serializer);
   }
 
+  @protected
+  void
+      sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+          StructWithDartImplementableTraitTwinNormal self,
+          SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+        (self as StructWithDartImplementableTraitTwinNormalImpl)
+            .frbInternalSseEncode(move: false),
+        serializer);
+  }
+
   @protected
   void
       sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinMoi(
@@ -147685,12 +161466,47 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         serializer);
   }
 
+  @protected
+  void sse_encode_BTreeMap_String_my_size(
+      Map<String, MySize> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_record_string_my_size(
+        self.entries.map((e) => (e.key, e.value)).toList(), serializer);
+  }
+
+  @protected
+  void sse_encode_BTreeMap_i_32_i_32(
+      Map<int, int> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_record_i_32_i_32(
+        self.entries.map((e) => (e.key, e.value)).toList(), serializer);
+  }
+
+  @protected
+  void sse_encode_BTreeSet_String(Set<String> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_String(self.toList(), serializer);
+  }
+
+  @protected
+  void sse_encode_BTreeSet_i_32(Set<int> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_i_32_strict(
+        Int32List.fromList(self.toList()), serializer);
+  }
+
   @protected
   void sse_encode_Backtrace(String self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     throw UnimplementedError('Unreachable ()');
   }
 
+  @protected
+  void sse_encode_BigInt(BigInt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.toString(), serializer);
+  }
+
   @protected
   void sse_encode_CastedPrimitive_i_64(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -147727,6 +161543,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_i_64(PlatformInt64Util.from(self.inMicroseconds), serializer);
   }
 
+  @protected
+  void
+      sse_encode_Chrono_FixedOffset(
+          DateTimeWithOffset self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.toString(), serializer);
+  }
+
   @protected
   void sse_encode_Chrono_Local(DateTime self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -147741,6 +161565,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         PlatformInt64Util.from(self.microsecondsSinceEpoch), serializer);
   }
 
+  @protected
+  void sse_encode_Chrono_NaiveDate(DateTime self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(
+        PlatformInt64Util.from(
+            DateTime.utc(self.year, self.month, self.day)
+                .microsecondsSinceEpoch),
+        serializer);
+  }
+
+  @protected
+  void sse_encode_Chrono_NaiveTime(Duration self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(PlatformInt64Util.from(self.inMicroseconds), serializer);
+  }
+
+  @protected
+  void sse_encode_Chrono_Tz(DateTimeWithOffset self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.toString(), serializer);
+  }
+
   @protected
   void sse_encode_Chrono_Utc(DateTime self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -147862,6 +161708,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         encode_DartFn_Inputs__Output_unit_AnyhowException(self), serializer);
   }
 
+  @protected
+  void sse_encode_DartFn_Inputs_bool_Output_opt_String_AnyhowException(
+      FutureOr<String?> Function(bool) self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_DartOpaque(
+        encode_DartFn_Inputs_bool_Output_opt_String_AnyhowException(self),
+        serializer);
+  }
+
+  @protected
+  void
+      sse_encode_DartFn_Inputs_bool_Output_opt_box_autoadd_i_32_AnyhowException(
+          FutureOr<int?> Function(bool) self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_DartOpaque(
+        encode_DartFn_Inputs_bool_Output_opt_box_autoadd_i_32_AnyhowException(
+            self),
+        serializer);
+  }
+
   @protected
   void
       sse_encode_DartFn_Inputs_demo_struct_for_rust_call_dart_twin_normal_Output_demo_struct_for_rust_call_dart_twin_normal_AnyhowException(
@@ -147918,6 +161784,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         serializer);
   }
 
+  @protected
+  void sse_encode_DartFn_Inputs_i_32_Output_String_AnyhowException(
+      FutureOr<String> Function(int) self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_DartOpaque(
+        encode_DartFn_Inputs_i_32_Output_String_AnyhowException(self),
+        serializer);
+  }
+
+  @protected
+  void sse_encode_DartFn_Inputs_i_32_i_32_Output_i_32_AnyhowException(
+      FutureOr<int> Function(int, int) self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_DartOpaque(
+        encode_DartFn_Inputs_i_32_i_32_Output_i_32_AnyhowException(self),
+        serializer);
+  }
+
   @protected
   void sse_encode_DartOpaque(Object self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -147935,6 +161819,27 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_list_DartOpaque(self.inner, serializer);
   }
 
+  @protected
+  void sse_encode_DartStream_String(
+      Stream<String> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_DartFn_Inputs_bool_Output_opt_String_AnyhowException(
+        encodeDartStream(self), serializer);
+  }
+
+  @protected
+  void sse_encode_DartStream_i_32(Stream<int> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_DartFn_Inputs_bool_Output_opt_box_autoadd_i_32_AnyhowException(
+        encodeDartStream(self), serializer);
+  }
+
+  @protected
+  void sse_encode_Decimal(Decimal self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.toString(), serializer);
+  }
+
   @protected
   void sse_encode_DynTrait_SimpleTraitForDynTwinNormal(
       SimpleTraitForDynTwinNormal self, SseSerializer serializer) {
@@ -147957,6 +161862,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.toString(), serializer);
   }
 
+  @protected
+  void sse_encode_JsonMap(Map<String, dynamic> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(jsonEncode(self), serializer);
+  }
+
+  @protected
+  void sse_encode_JsonValue(Object? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(jsonEncode(self), serializer);
+  }
+
   @protected
   void
       sse_encode_Lifetimeable_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLtNestedTypeWithLifetimeTwinNormalstatic(
@@ -149524,6 +163441,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         serializer);
   }
 
+  @protected
+  void
+      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl(
+          DartImplementableTraitTwinNormalDartImpl self,
+          SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+        (self as DartImplementableTraitTwinNormalDartImplImpl)
+            .frbInternalSseEncode(move: null),
+        serializer);
+  }
+
   @protected
   void
       sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeliberateFailSanityCheckTwinNormal(
@@ -150676,6 +164605,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         serializer);
   }
 
+  @protected
+  void
+      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+          StructWithDartImplementableTraitTwinNormal self,
+          SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+        (self as StructWithDartImplementableTraitTwinNormalImpl)
+            .frbInternalSseEncode(move: null),
+        serializer);
+  }
+
   @protected
   void
       sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinMoi(
@@ -150876,6 +164817,22 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         Int32List.fromList(self.toList()), serializer);
   }
 
+  @protected
+  void sse_encode_StdDuration(Duration self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    if (self.isNegative)
+      throw ArgumentError.value(
+          self, null, 'std::time::Duration cannot be negative');
+    sse_encode_i_64(PlatformInt64Util.from(self.inMicroseconds), serializer);
+  }
+
+  @protected
+  void sse_encode_StdSystemTime(DateTime self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(
+        PlatformInt64Util.from(self.microsecondsSinceEpoch), serializer);
+  }
+
   @protected
   void
       sse_encode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinMoi_Dco(
@@ -151738,6 +165695,37 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
   }
 
+  @protected
+  void sse_encode_TimeDate(DateTime self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(
+        PlatformInt64Util.from(
+            DateTime.utc(self.year, self.month, self.day)
+                .microsecondsSinceEpoch),
+        serializer);
+  }
+
+  @protected
+  void sse_encode_TimeDuration(Duration self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(PlatformInt64Util.from(self.inMicroseconds), serializer);
+  }
+
+  @protected
+  void sse_encode_TimeOffsetDateTime(DateTime self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(
+        PlatformInt64Util.from(self.microsecondsSinceEpoch), serializer);
+  }
+
+  @protected
+  void sse_encode_TimePrimitiveDateTime(
+      DateTime self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(
+        PlatformInt64Util.from(self.microsecondsSinceEpoch), serializer);
+  }
+
   @protected
   void sse_encode_U128(BigInt self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -151750,6 +165738,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_list_prim_u_8_strict(self.toBytes(), serializer);
   }
 
+  @protected
+  void sse_encode_VecDeque_String(
+      List<String> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_String(self, serializer);
+  }
+
+  @protected
+  void sse_encode_VecDeque_i_32(Int32List self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_i_32_strict(self, serializer);
+  }
+
   @protected
   void sse_encode_a_twin_normal(ATwinNormal self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -152480,6 +166481,60 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_list_prim_u_64_strict(self.uint64, serializer);
   }
 
+  @protected
+  void sse_encode_big_numbers_twin_normal(
+      BigNumbersTwinNormal self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_Decimal(self.amount, serializer);
+    sse_encode_opt_Decimal(self.discount, serializer);
+    sse_encode_BigInt(self.serial, serializer);
+  }
+
+  @protected
+  void sse_encode_big_numbers_twin_rust_async(
+      BigNumbersTwinRustAsync self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_Decimal(self.amount, serializer);
+    sse_encode_opt_Decimal(self.discount, serializer);
+    sse_encode_BigInt(self.serial, serializer);
+  }
+
+  @protected
+  void sse_encode_big_numbers_twin_rust_async_sse(
+      BigNumbersTwinRustAsyncSse self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_Decimal(self.amount, serializer);
+    sse_encode_opt_Decimal(self.discount, serializer);
+    sse_encode_BigInt(self.serial, serializer);
+  }
+
+  @protected
+  void sse_encode_big_numbers_twin_sse(
+      BigNumbersTwinSse self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_Decimal(self.amount, serializer);
+    sse_encode_opt_Decimal(self.discount, serializer);
+    sse_encode_BigInt(self.serial, serializer);
+  }
+
+  @protected
+  void sse_encode_big_numbers_twin_sync(
+      BigNumbersTwinSync self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_Decimal(self.amount, serializer);
+    sse_encode_opt_Decimal(self.discount, serializer);
+    sse_encode_BigInt(self.serial, serializer);
+  }
+
+  @protected
+  void sse_encode_big_numbers_twin_sync_sse(
+      BigNumbersTwinSyncSse self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_Decimal(self.amount, serializer);
+    sse_encode_opt_Decimal(self.discount, serializer);
+    sse_encode_BigInt(self.serial, serializer);
+  }
+
   @protected
   void sse_encode_blob_twin_normal(
       BlobTwinNormal self, SseSerializer serializer) {
@@ -152526,6 +166581,23 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     serializer.buffer.putUint8(self ? 1 : 0);
   }
 
+  @protected
+  void sse_encode_box_DynTrait_DartImplementableTraitTwinNormal(
+      DartImplementableTraitTwinNormal self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_dart_implementable_trait_twin_normal_implementor((() {
+      if (self is DartImplementableTraitTwinNormalDartImpl) {
+        return DartImplementableTraitTwinNormalImplementor.variant0(self);
+      }
+      if (self is StructWithDartImplementableTraitTwinNormal) {
+        return DartImplementableTraitTwinNormalImplementor.variant1(self);
+      }
+
+      return DartImplementableTraitTwinNormalImplementor.variant0(
+          DartImplementableTraitTwinNormalDartImpl.from(self));
+    })(), serializer);
+  }
+
   @protected
   void sse_encode_box_application_env(
       ApplicationEnv self, SseSerializer serializer) {
@@ -152655,6 +166727,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_Chrono_Naive(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_Chrono_NaiveTime(
+      Duration self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_Chrono_NaiveTime(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_Chrono_Utc(
       DateTime self, SseSerializer serializer) {
@@ -152781,6 +166860,41 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_RustOpaque_HideDataTwinSyncSseMoi(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_StdDuration(
+      Duration self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_StdDuration(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_StdSystemTime(
+      DateTime self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_StdSystemTime(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_TimeDuration(
+      Duration self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_TimeDuration(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_TimeOffsetDateTime(
+      DateTime self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_TimeOffsetDateTime(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_TimePrimitiveDateTime(
+      DateTime self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_TimePrimitiveDateTime(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_a_twin_normal(
       ATwinNormal self, SseSerializer serializer) {
@@ -153190,6 +167304,48 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_benchmark_blob_twin_sync_sse(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_big_numbers_twin_normal(
+      BigNumbersTwinNormal self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_big_numbers_twin_normal(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_big_numbers_twin_rust_async(
+      BigNumbersTwinRustAsync self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_big_numbers_twin_rust_async(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_big_numbers_twin_rust_async_sse(
+      BigNumbersTwinRustAsyncSse self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_big_numbers_twin_rust_async_sse(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_big_numbers_twin_sse(
+      BigNumbersTwinSse self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_big_numbers_twin_sse(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_big_numbers_twin_sync(
+      BigNumbersTwinSync self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_big_numbers_twin_sync(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_big_numbers_twin_sync_sse(
+      BigNumbersTwinSyncSse self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_big_numbers_twin_sync_sse(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -153532,6 +167688,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_customized_twin_sync_sse(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_dart_implementable_trait_twin_normal_implementor(
+      DartImplementableTraitTwinNormalImplementor self,
+      SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_dart_implementable_trait_twin_normal_implementor(
+        self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_dart_opaque_nested_twin_normal(
       DartOpaqueNestedTwinNormal self, SseSerializer serializer) {
@@ -154242,6 +168407,48 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_item_container_solution_two_twin_normal(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_json_document_twin_normal(
+      JsonDocumentTwinNormal self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_json_document_twin_normal(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_json_document_twin_rust_async(
+      JsonDocumentTwinRustAsync self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_json_document_twin_rust_async(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_json_document_twin_rust_async_sse(
+      JsonDocumentTwinRustAsyncSse self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_json_document_twin_rust_async_sse(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_json_document_twin_sse(
+      JsonDocumentTwinSse self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_json_document_twin_sse(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_json_document_twin_sync(
+      JsonDocumentTwinSync self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_json_document_twin_sync(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_json_document_twin_sync_sse(
+      JsonDocumentTwinSyncSse self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_json_document_twin_sync_sse(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_kitchen_sink_twin_normal(
       KitchenSinkTwinNormal self, SseSerializer serializer) {
@@ -154798,6 +169005,48 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_record_string_i_32(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_schedule_twin_normal(
+      ScheduleTwinNormal self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_schedule_twin_normal(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_schedule_twin_rust_async(
+      ScheduleTwinRustAsync self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_schedule_twin_rust_async(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_schedule_twin_rust_async_sse(
+      ScheduleTwinRustAsyncSse self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_schedule_twin_rust_async_sse(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_schedule_twin_sse(
+      ScheduleTwinSse self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_schedule_twin_sse(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_schedule_twin_sync(
+      ScheduleTwinSync self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_schedule_twin_sync(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_schedule_twin_sync_sse(
+      ScheduleTwinSyncSse self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_schedule_twin_sync_sse(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_sequences(
       Sequences self, SseSerializer serializer) {
@@ -154989,6 +169238,48 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_some_struct_twin_sync_sse(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_std_time_twin_normal(
+      StdTimeTwinNormal self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_std_time_twin_normal(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_std_time_twin_rust_async(
+      StdTimeTwinRustAsync self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_std_time_twin_rust_async(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_std_time_twin_rust_async_sse(
+      StdTimeTwinRustAsyncSse self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_std_time_twin_rust_async_sse(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_std_time_twin_sse(
+      StdTimeTwinSse self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_std_time_twin_sse(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_std_time_twin_sync(
+      StdTimeTwinSync self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_std_time_twin_sync(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_std_time_twin_sync_sse(
+      StdTimeTwinSyncSse self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_std_time_twin_sync_sse(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_struct_in_lower_level(
       StructInLowerLevel self, SseSerializer serializer) {
@@ -155615,6 +169906,48 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_test_id_twin_sync_sse(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_time_types_twin_normal(
+      TimeTypesTwinNormal self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_time_types_twin_normal(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_time_types_twin_rust_async(
+      TimeTypesTwinRustAsync self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_time_types_twin_rust_async(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_time_types_twin_rust_async_sse(
+      TimeTypesTwinRustAsyncSse self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_time_types_twin_rust_async_sse(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_time_types_twin_sse(
+      TimeTypesTwinSse self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_time_types_twin_sse(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_time_types_twin_sync(
+      TimeTypesTwinSync self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_time_types_twin_sync(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_time_types_twin_sync_sse(
+      TimeTypesTwinSyncSse self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_time_types_twin_sync_sse(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_translatable_struct_with_dart_code_twin_normal(
       TranslatableStructWithDartCodeTwinNormal self, SseSerializer serializer) {
@@ -157209,6 +171542,29 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_String(self.nonFinalField, serializer);
   }
 
+  @protected
+  void sse_encode_dart_implementable_trait_twin_normal_implementor(
+      DartImplementableTraitTwinNormalImplementor self,
+      SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case DartImplementableTraitTwinNormalImplementor_Variant0(
+          field0: final field0
+        ):
+        sse_encode_i_32(0, serializer);
+        sse_encode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl(
+            field0, serializer);
+      case DartImplementableTraitTwinNormalImplementor_Variant1(
+          field0: final field0
+        ):
+        sse_encode_i_32(1, serializer);
+        sse_encode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+            field0, serializer);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
   @protected
   void sse_encode_dart_opaque_nested_twin_normal(
       DartOpaqueNestedTwinNormal self, SseSerializer serializer) {
@@ -158963,6 +173319,60 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         self.items, serializer);
   }
 
+  @protected
+  void sse_encode_json_document_twin_normal(
+      JsonDocumentTwinNormal self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_JsonValue(self.body, serializer);
+    sse_encode_JsonMap(self.meta, serializer);
+    sse_encode_opt_JsonValue(self.extra, serializer);
+  }
+
+  @protected
+  void sse_encode_json_document_twin_rust_async(
+      JsonDocumentTwinRustAsync self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_JsonValue(self.body, serializer);
+    sse_encode_JsonMap(self.meta, serializer);
+    sse_encode_opt_JsonValue(self.extra, serializer);
+  }
+
+  @protected
+  void sse_encode_json_document_twin_rust_async_sse(
+      JsonDocumentTwinRustAsyncSse self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_JsonValue(self.body, serializer);
+    sse_encode_JsonMap(self.meta, serializer);
+    sse_encode_opt_JsonValue(self.extra, serializer);
+  }
+
+  @protected
+  void sse_encode_json_document_twin_sse(
+      JsonDocumentTwinSse self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_JsonValue(self.body, serializer);
+    sse_encode_JsonMap(self.meta, serializer);
+    sse_encode_opt_JsonValue(self.extra, serializer);
+  }
+
+  @protected
+  void sse_encode_json_document_twin_sync(
+      JsonDocumentTwinSync self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_JsonValue(self.body, serializer);
+    sse_encode_JsonMap(self.meta, serializer);
+    sse_encode_opt_JsonValue(self.extra, serializer);
+  }
+
+  @protected
+  void sse_encode_json_document_twin_sync_sse(
+      JsonDocumentTwinSyncSse self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_JsonValue(self.body, serializer);
+    sse_encode_JsonMap(self.meta, serializer);
+    sse_encode_opt_JsonValue(self.extra, serializer);
+  }
+
   @protected
   void sse_encode_kitchen_sink_twin_normal(
       KitchenSinkTwinNormal self, SseSerializer serializer) {
@@ -159378,6 +173788,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_BigInt(List<BigInt> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_BigInt(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_Chrono_Duration(
       List<Duration> self, SseSerializer serializer) {
@@ -159388,6 +173807,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_Chrono_FixedOffset(
+      List<DateTimeWithOffset> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_Chrono_FixedOffset(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_Chrono_Local(
       List<DateTime> self, SseSerializer serializer) {
@@ -159408,6 +173837,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_Chrono_NaiveDate(
+      List<DateTime> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_Chrono_NaiveDate(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_Chrono_Tz(
+      List<DateTimeWithOffset> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_Chrono_Tz(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_DartOpaque(List<Object> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -159417,6 +173866,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_Decimal(List<Decimal> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_Decimal(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_JsonValue(List<Object?> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_JsonValue(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_RustOpaque_HideDataTwinMoi(
       List<HideDataTwinMoi> self, SseSerializer serializer) {
@@ -159537,6 +174004,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_StdDuration(
+      List<Duration> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_StdDuration(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_StdSystemTime(
+      List<DateTime> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_StdSystemTime(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_StreamSink_i_32_Dco(
       List<RustStreamSink<int>> self, SseSerializer serializer) {
@@ -159566,6 +174053,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_TimeDuration(
+      List<Duration> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_TimeDuration(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_Uuid(List<UuidValue> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -160490,8 +174987,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       List<int> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.length, serializer);
-    serializer.buffer
-        .putUint8List(self is Uint8List ? self : Uint8List.fromList(self));
+    sseEncodeUint8List(
+        self is Uint8List ? self : Uint8List.fromList(self), serializer);
   }
 
   @protected
@@ -160499,7 +174996,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       Uint8List self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
+    sseEncodeUint8List(self, serializer);
   }
 
   @protected
@@ -162099,6 +176596,48 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_RustOpaque_HideDataTwinSyncSseMoi(self.second, serializer);
   }
 
+  @protected
+  void sse_encode_opt_BigInt(BigInt? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_BigInt(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_Chrono_FixedOffset(
+      DateTimeWithOffset? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_Chrono_FixedOffset(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_Chrono_Tz(
+      DateTimeWithOffset? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_Chrono_Tz(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_Decimal(Decimal? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_Decimal(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_I128(BigInt? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -162109,6 +176648,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_JsonValue(Object? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_JsonValue(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_String(String? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -162307,6 +176856,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_Chrono_NaiveTime(
+      Duration? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_Chrono_NaiveTime(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_box_autoadd_Chrono_Utc(
       DateTime? self, SseSerializer serializer) {
@@ -162513,6 +177073,61 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_StdDuration(
+      Duration? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_StdDuration(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_StdSystemTime(
+      DateTime? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_StdSystemTime(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_TimeDuration(
+      Duration? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_TimeDuration(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_TimeOffsetDateTime(
+      DateTime? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_TimeOffsetDateTime(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_TimePrimitiveDateTime(
+      DateTime? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_TimePrimitiveDateTime(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_box_autoadd_application_env(
       ApplicationEnv? self, SseSerializer serializer) {
@@ -164518,6 +179133,66 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_application_mode(self.$2, serializer);
   }
 
+  @protected
+  void sse_encode_schedule_twin_normal(
+      ScheduleTwinNormal self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_Chrono_NaiveDate(self.day, serializer);
+    sse_encode_Chrono_NaiveTime(self.starts, serializer);
+    sse_encode_opt_box_autoadd_Chrono_NaiveTime(self.ends, serializer);
+    sse_encode_Chrono_FixedOffset(self.at, serializer);
+  }
+
+  @protected
+  void sse_encode_schedule_twin_rust_async(
+      ScheduleTwinRustAsync self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_Chrono_NaiveDate(self.day, serializer);
+    sse_encode_Chrono_NaiveTime(self.starts, serializer);
+    sse_encode_opt_box_autoadd_Chrono_NaiveTime(self.ends, serializer);
+    sse_encode_Chrono_FixedOffset(self.at, serializer);
+  }
+
+  @protected
+  void sse_encode_schedule_twin_rust_async_sse(
+      ScheduleTwinRustAsyncSse self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_Chrono_NaiveDate(self.day, serializer);
+    sse_encode_Chrono_NaiveTime(self.starts, serializer);
+    sse_encode_opt_box_autoadd_Chrono_NaiveTime(self.ends, serializer);
+    sse_encode_Chrono_FixedOffset(self.at, serializer);
+  }
+
+  @protected
+  void sse_encode_schedule_twin_sse(
+      ScheduleTwinSse self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_Chrono_NaiveDate(self.day, serializer);
+    sse_encode_Chrono_NaiveTime(self.starts, serializer);
+    sse_encode_opt_box_autoadd_Chrono_NaiveTime(self.ends, serializer);
+    sse_encode_Chrono_FixedOffset(self.at, serializer);
+  }
+
+  @protected
+  void sse_encode_schedule_twin_sync(
+      ScheduleTwinSync self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_Chrono_NaiveDate(self.day, serializer);
+    sse_encode_Chrono_NaiveTime(self.starts, serializer);
+    sse_encode_opt_box_autoadd_Chrono_NaiveTime(self.ends, serializer);
+    sse_encode_Chrono_FixedOffset(self.at, serializer);
+  }
+
+  @protected
+  void sse_encode_schedule_twin_sync_sse(
+      ScheduleTwinSyncSse self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_Chrono_NaiveDate(self.day, serializer);
+    sse_encode_Chrono_NaiveTime(self.starts, serializer);
+    sse_encode_opt_box_autoadd_Chrono_NaiveTime(self.ends, serializer);
+    sse_encode_Chrono_FixedOffset(self.at, serializer);
+  }
+
   @protected
   void sse_encode_sequences(Sequences self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -164943,6 +179618,60 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.one, serializer);
   }
 
+  @protected
+  void sse_encode_std_time_twin_normal(
+      StdTimeTwinNormal self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_StdDuration(self.duration, serializer);
+    sse_encode_StdSystemTime(self.time, serializer);
+    sse_encode_opt_box_autoadd_StdDuration(self.optionalDuration, serializer);
+  }
+
+  @protected
+  void sse_encode_std_time_twin_rust_async(
+      StdTimeTwinRustAsync self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_StdDuration(self.duration, serializer);
+    sse_encode_StdSystemTime(self.time, serializer);
+    sse_encode_opt_box_autoadd_StdDuration(self.optionalDuration, serializer);
+  }
+
+  @protected
+  void sse_encode_std_time_twin_rust_async_sse(
+      StdTimeTwinRustAsyncSse self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_StdDuration(self.duration, serializer);
+    sse_encode_StdSystemTime(self.time, serializer);
+    sse_encode_opt_box_autoadd_StdDuration(self.optionalDuration, serializer);
+  }
+
+  @protected
+  void sse_encode_std_time_twin_sse(
+      StdTimeTwinSse self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_StdDuration(self.duration, serializer);
+    sse_encode_StdSystemTime(self.time, serializer);
+    sse_encode_opt_box_autoadd_StdDuration(self.optionalDuration, serializer);
+  }
+
+  @protected
+  void sse_encode_std_time_twin_sync(
+      StdTimeTwinSync self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_StdDuration(self.duration, serializer);
+    sse_encode_StdSystemTime(self.time, serializer);
+    sse_encode_opt_box_autoadd_StdDuration(self.optionalDuration, serializer);
+  }
+
+  @protected
+  void sse_encode_std_time_twin_sync_sse(
+      StdTimeTwinSyncSse self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_StdDuration(self.duration, serializer);
+    sse_encode_StdSystemTime(self.time, serializer);
+    sse_encode_opt_box_autoadd_StdDuration(self.optionalDuration, serializer);
+  }
+
   @protected
   void sse_encode_struct_in_lower_level(
       StructInLowerLevel self, SseSerializer serializer) {
@@ -165881,6 +180610,72 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_my_struct(self.aliasStruct, serializer);
   }
 
+  @protected
+  void sse_encode_time_types_twin_normal(
+      TimeTypesTwinNormal self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_TimeOffsetDateTime(self.offset, serializer);
+    sse_encode_opt_box_autoadd_TimePrimitiveDateTime(
+        self.primitive, serializer);
+    sse_encode_TimeDate(self.date, serializer);
+    sse_encode_TimeDuration(self.duration, serializer);
+  }
+
+  @protected
+  void sse_encode_time_types_twin_rust_async(
+      TimeTypesTwinRustAsync self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_TimeOffsetDateTime(self.offset, serializer);
+    sse_encode_opt_box_autoadd_TimePrimitiveDateTime(
+        self.primitive, serializer);
+    sse_encode_TimeDate(self.date, serializer);
+    sse_encode_TimeDuration(self.duration, serializer);
+  }
+
+  @protected
+  void sse_encode_time_types_twin_rust_async_sse(
+      TimeTypesTwinRustAsyncSse self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_TimeOffsetDateTime(self.offset, serializer);
+    sse_encode_opt_box_autoadd_TimePrimitiveDateTime(
+        self.primitive, serializer);
+    sse_encode_TimeDate(self.date, serializer);
+    sse_encode_TimeDuration(self.duration, serializer);
+  }
+
+  @protected
+  void sse_encode_time_types_twin_sse(
+      TimeTypesTwinSse self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_TimeOffsetDateTime(self.offset, serializer);
+    sse_encode_opt_box_autoadd_TimePrimitiveDateTime(
+        self.primitive, serializer);
+    sse_encode_TimeDate(self.date, serializer);
+    sse_encode_TimeDuration(self.duration, serializer);
+  }
+
+  @protected
+  void sse_encode_time_types_twin_sync(
+      TimeTypesTwinSync self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_TimeOffsetDateTime(self.offset, serializer);
+    sse_encode_opt_box_autoadd_TimePrimitiveDateTime(
+        self.primitive, serializer);
+    sse_encode_TimeDate(self.date, serializer);
+    sse_encode_TimeDuration(self.duration, serializer);
+  }
+
+  @protected
+  void sse_encode_time_types_twin_sync_sse(
+      TimeTypesTwinSyncSse self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_TimeOffsetDateTime(self.offset, serializer);
+    sse_encode_opt_box_autoadd_TimePrimitiveDateTime(
+        self.primitive, serializer);
+    sse_encode_TimeDate(self.date, serializer);
+    sse_encode_TimeDuration(self.duration, serializer);
+  }
+
   @protected
   void sse_encode_translatable_struct_with_dart_code_twin_normal(
       TranslatableStructWithDartCodeTwinNormal self, SseSerializer serializer) {
@@ -165978,6 +180773,114 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_i_32(self.field1, serializer);
   }
 
+  @protected
+  void sse_encode_typed_exception_error_twin_normal(
+      TypedExceptionErrorTwinNormal self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case TypedExceptionErrorTwinNormal_NotFound(path: final path):
+        sse_encode_i_32(0, serializer);
+        sse_encode_String(path, serializer);
+      case TypedExceptionErrorTwinNormal_Io(field0: final field0):
+        sse_encode_i_32(1, serializer);
+        sse_encode_String(field0, serializer);
+      case TypedExceptionErrorTwinNormal_Unknown():
+        sse_encode_i_32(2, serializer);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  void sse_encode_typed_exception_error_twin_rust_async(
+      TypedExceptionErrorTwinRustAsync self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case TypedExceptionErrorTwinRustAsync_NotFound(path: final path):
+        sse_encode_i_32(0, serializer);
+        sse_encode_String(path, serializer);
+      case TypedExceptionErrorTwinRustAsync_Io(field0: final field0):
+        sse_encode_i_32(1, serializer);
+        sse_encode_String(field0, serializer);
+      case TypedExceptionErrorTwinRustAsync_Unknown():
+        sse_encode_i_32(2, serializer);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  void sse_encode_typed_exception_error_twin_rust_async_sse(
+      TypedExceptionErrorTwinRustAsyncSse self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case TypedExceptionErrorTwinRustAsyncSse_NotFound(path: final path):
+        sse_encode_i_32(0, serializer);
+        sse_encode_String(path, serializer);
+      case TypedExceptionErrorTwinRustAsyncSse_Io(field0: final field0):
+        sse_encode_i_32(1, serializer);
+        sse_encode_String(field0, serializer);
+      case TypedExceptionErrorTwinRustAsyncSse_Unknown():
+        sse_encode_i_32(2, serializer);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  void sse_encode_typed_exception_error_twin_sse(
+      TypedExceptionErrorTwinSse self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case TypedExceptionErrorTwinSse_NotFound(path: final path):
+        sse_encode_i_32(0, serializer);
+        sse_encode_String(path, serializer);
+      case TypedExceptionErrorTwinSse_Io(field0: final field0):
+        sse_encode_i_32(1, serializer);
+        sse_encode_String(field0, serializer);
+      case TypedExceptionErrorTwinSse_Unknown():
+        sse_encode_i_32(2, serializer);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  void sse_encode_typed_exception_error_twin_sync(
+      TypedExceptionErrorTwinSync self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case TypedExceptionErrorTwinSync_NotFound(path: final path):
+        sse_encode_i_32(0, serializer);
+        sse_encode_String(path, serializer);
+      case TypedExceptionErrorTwinSync_Io(field0: final field0):
+        sse_encode_i_32(1, serializer);
+        sse_encode_String(field0, serializer);
+      case TypedExceptionErrorTwinSync_Unknown():
+        sse_encode_i_32(2, serializer);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  void sse_encode_typed_exception_error_twin_sync_sse(
+      TypedExceptionErrorTwinSyncSse self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case TypedExceptionErrorTwinSyncSse_NotFound(path: final path):
+        sse_encode_i_32(0, serializer);
+        sse_encode_String(path, serializer);
+      case TypedExceptionErrorTwinSyncSse_Io(field0: final field0):
+        sse_encode_i_32(1, serializer);
+        sse_encode_String(field0, serializer);
+      case TypedExceptionErrorTwinSyncSse_Unknown():
+        sse_encode_i_32(2, serializer);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
   @protected
   void sse_encode_u_16(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -166230,6 +181133,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 }
 
+// Rust type: RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DartImplementableTraitTwinNormalDartImpl>>
+abstract class DartImplementableTraitTwinNormalDartImpl
+    implements RustOpaqueInterface, DartImplementableTraitTwinNormal {
+  Future<int> addTwinNormal({required int a, required int b});
+
+  Future<String> describeTwinNormal({required int value});
+
+  factory DartImplementableTraitTwinNormalDartImpl(
+          {required FutureOr<int> Function(int, int) addTwinNormal,
+          required FutureOr<String> Function(int) describeTwinNormal}) =>
+      RustLib.instance.api
+          .crateFrbGeneratedDartImplementableTraitTwinNormalDartImplNew(
+              addTwinNormal: addTwinNormal,
+              describeTwinNormal: describeTwinNormal);
+
+  factory DartImplementableTraitTwinNormalDartImpl.from(
+          DartImplementableTraitTwinNormal implementation) =>
+      DartImplementableTraitTwinNormalDartImpl(
+        addTwinNormal: (a, b) => implementation.addTwinNormal(a: a, b: b),
+        describeTwinNormal: (value) =>
+            implementation.describeTwinNormal(value: value),
+      );
+}
+
 @freezed
 sealed class Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMyAudioParamTwinNormalProxyEnum
     with
@@ -166244,6 +181171,19 @@ sealed class Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueI
   ) = Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMyAudioParamTwinNormalProxyEnum_Variant1;
 }
 
+@freezed
+sealed class DartImplementableTraitTwinNormalImplementor
+    with _$DartImplementableTraitTwinNormalImplementor {
+  const DartImplementableTraitTwinNormalImplementor._();
+
+  const factory DartImplementableTraitTwinNormalImplementor.variant0(
+    DartImplementableTraitTwinNormalDartImpl field0,
+  ) = DartImplementableTraitTwinNormalImplementor_Variant0;
+  const factory DartImplementableTraitTwinNormalImplementor.variant1(
+    StructWithDartImplementableTraitTwinNormal field0,
+  ) = DartImplementableTraitTwinNormalImplementor_Variant1;
+}
+
 @freezed
 sealed class SimpleTraitForDynTwinNormalImplementor
     with _$SimpleTraitForDynTwinNormalImplementor {
@@ -166898,6 +181838,39 @@ class ConstructorOpaqueSyncStructTwinNormalImpl extends RustOpaque
       );
 }
 
+@sealed
+class DartImplementableTraitTwinNormalDartImplImpl extends RustOpaque
+    implements DartImplementableTraitTwinNormalDartImpl {
+  // Not to be used by end users
+  DartImplementableTraitTwinNormalDartImplImpl.frbInternalDcoDecode(
+      List<dynamic> wire)
+      : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  DartImplementableTraitTwinNormalDartImplImpl.frbInternalSseDecode(
+      BigInt ptr, int externalSizeOnNative)
+      : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount: RustLib.instance.api
+        .rust_arc_increment_strong_count_DartImplementableTraitTwinNormalDartImpl,
+    rustArcDecrementStrongCount: RustLib.instance.api
+        .rust_arc_decrement_strong_count_DartImplementableTraitTwinNormalDartImpl,
+    rustArcDecrementStrongCountPtr: RustLib.instance.api
+        .rust_arc_decrement_strong_count_DartImplementableTraitTwinNormalDartImplPtr,
+  );
+
+  Future<int> addTwinNormal({required int a, required int b}) => RustLib
+      .instance.api
+      .crateFrbGeneratedDartImplementableTraitTwinNormalDartImplAddTwinNormal(
+          that: this, a: a, b: b);
+
+  Future<String> describeTwinNormal({required int value}) => RustLib
+      .instance.api
+      .crateFrbGeneratedDartImplementableTraitTwinNormalDartImplDescribeTwinNormal(
+          that: this, value: value);
+}
+
 @sealed
 class DeliberateFailSanityCheckTwinNormalImpl extends RustOpaque
     implements DeliberateFailSanityCheckTwinNormal {
@@ -171611,6 +186584,48 @@ class StructTwoWithTraitTwinSyncSseImpl extends RustOpaque
       );
 }
 
+@sealed
+class StructWithDartImplementableTraitTwinNormalImpl extends RustOpaque
+    implements StructWithDartImplementableTraitTwinNormal {
+  // Not to be used by end users
+  StructWithDartImplementableTraitTwinNormalImpl.frbInternalDcoDecode(
+      List<dynamic> wire)
+      : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  StructWithDartImplementableTraitTwinNormalImpl.frbInternalSseDecode(
+      BigInt ptr, int externalSizeOnNative)
+      : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount: RustLib.instance.api
+        .rust_arc_increment_strong_count_StructWithDartImplementableTraitTwinNormal,
+    rustArcDecrementStrongCount: RustLib.instance.api
+        .rust_arc_decrement_strong_count_StructWithDartImplementableTraitTwinNormal,
+    rustArcDecrementStrongCountPtr: RustLib.instance.api
+        .rust_arc_decrement_strong_count_StructWithDartImplementableTraitTwinNormalPtr,
+  );
+
+  Future<int> addTwinNormal({required int a, required int b}) => RustLib
+      .instance.api
+      .crateApiDynTraitStructWithDartImplementableTraitTwinNormalAddTwinNormal(
+          that: this, a: a, b: b);
+
+  int get base => RustLib.instance.api
+          .crateApiDynTraitStructWithDartImplementableTraitTwinNormalAutoAccessorGetBase(
+        that: this,
+      );
+
+  set base(int base) => RustLib.instance.api
+      .crateApiDynTraitStructWithDartImplementableTraitTwinNormalAutoAccessorSetBase(
+          that: this, base: base);
+
+  Future<String> describeTwinNormal({required int value}) => RustLib
+      .instance.api
+      .crateApiDynTraitStructWithDartImplementableTraitTwinNormalDescribeTwinNormal(
+          that: this, value: value);
+}
+
 @sealed
 class StructWithGoodAndOpaqueFieldWithoutOptionTwinMoiImpl extends RustOpaque
     implements StructWithGoodAndOpaqueFieldWithoutOptionTwinMoi {
@@ -12,8 +12,10 @@ import 'api/async_misc.dart';
 import 'api/async_spawn.dart';
 import 'api/attribute.dart';
 import 'api/benchmark_misc.dart';
+import 'api/big_number.dart';
 import 'api/casted_primitive.dart';
 import 'api/chrono_type.dart';
+import 'api/chrono_tz_type.dart';
 import 'api/comment.dart';
 import 'api/constructor.dart';
 import 'api/custom_ser_des.dart';
@@ -33,6 +35,7 @@ import 'api/external_impl.dart';
 import 'api/external_type_in_crate.dart';
 import 'api/impl_trait.dart';
 import 'api/inside_macro.dart';
+import 'api/json_type.dart';
 import 'api/lifetimeable.dart';
 import 'api/map_and_set.dart';
 import 'api/method.dart';
@@ -89,6 +92,11 @@ import 'api/pseudo_manual/benchmark_api_twin_rust_async_sse.dart';
 import 'api/pseudo_manual/benchmark_api_twin_sse.dart';
 import 'api/pseudo_manual/benchmark_api_twin_sync.dart';
 import 'api/pseudo_manual/benchmark_api_twin_sync_sse.dart';
+import 'api/pseudo_manual/big_number_twin_rust_async.dart';
+import 'api/pseudo_manual/big_number_twin_rust_async_sse.dart';
+import 'api/pseudo_manual/big_number_twin_sse.dart';
+import 'api/pseudo_manual/big_number_twin_sync.dart';
+import 'api/pseudo_manual/big_number_twin_sync_sse.dart';
 import 'api/pseudo_manual/chrono_type_twin_rust_async.dart';
 import 'api/pseudo_manual/chrono_type_twin_rust_async_sse.dart';
 import 'api/pseudo_manual/chrono_type_twin_sse.dart';
@@ -136,6 +144,11 @@ import 'api/pseudo_manual/external_type_in_crate_twin_sync_sse.dart';
 import 'api/pseudo_manual/impl_trait_twin_sse.dart';
 import 'api/pseudo_manual/impl_trait_twin_sync.dart';
 import 'api/pseudo_manual/impl_trait_twin_sync_sse.dart';
+import 'api/pseudo_manual/json_type_twin_rust_async.dart';
+import 'api/pseudo_manual/json_type_twin_rust_async_sse.dart';
+import 'api/pseudo_manual/json_type_twin_sse.dart';
+import 'api/pseudo_manual/json_type_twin_sync.dart';
+import 'api/pseudo_manual/json_type_twin_sync_sse.dart';
 import 'api/pseudo_manual/lifetimeable_twin_sync.dart';
 import 'api/pseudo_manual/map_and_set_twin_rust_async.dart';
 import 'api/pseudo_manual/map_and_set_twin_rust_async_sse.dart';
@@ -227,6 +240,11 @@ import 'api/pseudo_manual/simple_twin_rust_async_sse.dart';
 import 'api/pseudo_manual/simple_twin_sse.dart';
 import 'api/pseudo_manual/simple_twin_sync.dart';
 import 'api/pseudo_manual/simple_twin_sync_sse.dart';
+import 'api/pseudo_manual/std_time_twin_rust_async.dart';
+import 'api/pseudo_manual/std_time_twin_rust_async_sse.dart';
+import 'api/pseudo_manual/std_time_twin_sse.dart';
+import 'api/pseudo_manual/std_time_twin_sync.dart';
+import 'api/pseudo_manual/std_time_twin_sync_sse.dart';
 import 'api/pseudo_manual/stream_misc_twin_sse.dart';
 import 'api/pseudo_manual/stream_twin_rust_async.dart';
 import 'api/pseudo_manual/stream_twin_rust_async_sse.dart';
@@ -236,6 +254,11 @@ import 'api/pseudo_manual/structure_twin_rust_async_sse.dart';
 import 'api/pseudo_manual/structure_twin_sse.dart';
 import 'api/pseudo_manual/structure_twin_sync.dart';
 import 'api/pseudo_manual/structure_twin_sync_sse.dart';
+import 'api/pseudo_manual/time_type_twin_rust_async.dart';
+import 'api/pseudo_manual/time_type_twin_rust_async_sse.dart';
+import 'api/pseudo_manual/time_type_twin_sse.dart';
+import 'api/pseudo_manual/time_type_twin_sync.dart';
+import 'api/pseudo_manual/time_type_twin_sync_sse.dart';
 import 'api/pseudo_manual/tuple_twin_rust_async.dart';
 import 'api/pseudo_manual/tuple_twin_rust_async_sse.dart';
 import 'api/pseudo_manual/tuple_twin_sse.dart';
@@ -256,9 +279,11 @@ import 'api/rust_auto_opaque.dart';
 import 'api/rust_opaque.dart';
 import 'api/rust_opaque_sync.dart';
 import 'api/simple.dart';
+import 'api/std_time.dart';
 import 'api/stream.dart';
 import 'api/stream_misc.dart';
 import 'api/structure.dart';
+import 'api/time_type.dart';
 import 'api/tuple.dart';
 import 'api/type_alias.dart';
 import 'api/uuid_type.dart';
@@ -269,6 +294,7 @@ import 'dart:async';
 import 'dart:convert';
 import 'deliberate_name_conflict.dart';
 import 'frb_generated.dart';
+import 'package:decimal/decimal.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_web.dart';
 import 'package:meta/meta.dart' as meta;
 import 'package:uuid/uuid.dart';
@@ -665,6 +691,10 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       get rust_arc_decrement_strong_count_ConstructorOpaqueSyncStructTwinNormalPtr =>
           wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConstructorOpaqueSyncStructTwinNormal;
 
+  CrossPlatformFinalizerArg
+      get rust_arc_decrement_strong_count_DartImplementableTraitTwinNormalDartImplPtr =>
+          wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl;
+
   CrossPlatformFinalizerArg
       get rust_arc_decrement_strong_count_DeliberateFailSanityCheckTwinNormalPtr =>
           wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeliberateFailSanityCheckTwinNormal;
@@ -1093,6 +1123,10 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       get rust_arc_decrement_strong_count_StructTwoWithTraitTwinSyncSsePtr => wire
           .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitTwinSyncSse;
 
+  CrossPlatformFinalizerArg
+      get rust_arc_decrement_strong_count_StructWithDartImplementableTraitTwinNormalPtr =>
+          wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal;
+
   CrossPlatformFinalizerArg
       get rust_arc_decrement_strong_count_StructWithGoodAndOpaqueFieldWithoutOptionTwinMoiPtr =>
           wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinMoi;
@@ -1158,6 +1192,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   AnyhowException dco_decode_AnyhowException(dynamic raw);
 
+  @protected
+  DartImplementableTraitTwinNormalDartImpl
+      dco_decode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl(
+          dynamic raw);
+
   @protected
   LtNestedTypeWithLifetimeTwinNormal
       dco_decode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLifetimeableLtNestedTypeWithLifetimeTwinNormalstatic(
@@ -1268,6 +1307,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       dco_decode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitForDynTwinNormal(
           dynamic raw);
 
+  @protected
+  StructWithDartImplementableTraitTwinNormal
+      dco_decode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+          dynamic raw);
+
   @protected
   BoxAnyMyDartTypeRename
       dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynAnySendSyncstatic(
@@ -1348,6 +1392,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConstructorOpaqueSyncStructTwinNormal(
           dynamic raw);
 
+  @protected
+  DartImplementableTraitTwinNormalDartImpl
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl(
+          dynamic raw);
+
   @protected
   DeliberateFailSanityCheckTwinNormal
       dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeliberateFailSanityCheckTwinNormal(
@@ -1853,6 +1902,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitTwinSyncSse(
           dynamic raw);
 
+  @protected
+  StructWithDartImplementableTraitTwinNormal
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+          dynamic raw);
+
   @protected
   StructWithGoodAndOpaqueFieldWithoutOptionTwinMoi
       dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinMoi(
@@ -2093,6 +2147,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitTwinSyncSse(
           dynamic raw);
 
+  @protected
+  StructWithDartImplementableTraitTwinNormal
+      dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+          dynamic raw);
+
   @protected
   StructWithGoodAndOpaqueFieldWithoutOptionTwinMoi
       dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinMoi(
@@ -2168,6 +2227,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConstructorOpaqueSyncStructTwinNormal(
           dynamic raw);
 
+  @protected
+  DartImplementableTraitTwinNormalDartImpl
+      dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl(
+          dynamic raw);
+
   @protected
   DeliberateFailSanityCheckTwinNormal
       dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeliberateFailSanityCheckTwinNormal(
@@ -2458,6 +2522,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitTwinSyncSse(
           dynamic raw);
 
+  @protected
+  StructWithDartImplementableTraitTwinNormal
+      dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+          dynamic raw);
+
   @protected
   StructWithGoodAndOpaqueFieldWithoutOptionTwinMoi
       dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinMoi(
@@ -2528,9 +2597,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithSimpleSetterTwinNormal(
           dynamic raw);
 
+  @protected
+  Map<String, MySize> dco_decode_BTreeMap_String_my_size(dynamic raw);
+
+  @protected
+  Map<int, int> dco_decode_BTreeMap_i_32_i_32(dynamic raw);
+
+  @protected
+  Set<String> dco_decode_BTreeSet_String(dynamic raw);
+
+  @protected
+  Set<int> dco_decode_BTreeSet_i_32(dynamic raw);
+
   @protected
   String dco_decode_Backtrace(dynamic raw);
 
+  @protected
+  BigInt dco_decode_BigInt(dynamic raw);
+
   @protected
   int dco_decode_CastedPrimitive_i_64(dynamic raw);
 
@@ -2549,12 +2633,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   Duration dco_decode_Chrono_Duration(dynamic raw);
 
+  @protected
+  DateTimeWithOffset dco_decode_Chrono_FixedOffset(dynamic raw);
+
   @protected
   DateTime dco_decode_Chrono_Local(dynamic raw);
 
   @protected
   DateTime dco_decode_Chrono_Naive(dynamic raw);
 
+  @protected
+  DateTime dco_decode_Chrono_NaiveDate(dynamic raw);
+
+  @protected
+  Duration dco_decode_Chrono_NaiveTime(dynamic raw);
+
+  @protected
+  DateTimeWithOffset dco_decode_Chrono_Tz(dynamic raw);
+
   @protected
   DateTime dco_decode_Chrono_Utc(dynamic raw);
 
@@ -2608,6 +2704,16 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   FutureOr<void> Function()
       dco_decode_DartFn_Inputs__Output_unit_AnyhowException(dynamic raw);
 
+  @protected
+  FutureOr<String?> Function(bool)
+      dco_decode_DartFn_Inputs_bool_Output_opt_String_AnyhowException(
+          dynamic raw);
+
+  @protected
+  FutureOr<int?> Function(bool)
+      dco_decode_DartFn_Inputs_bool_Output_opt_box_autoadd_i_32_AnyhowException(
+          dynamic raw);
+
   @protected
   FutureOr<DemoStructForRustCallDartTwinNormal> Function(
           DemoStructForRustCallDartTwinNormal)
@@ -2632,12 +2738,31 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       dco_decode_DartFn_Inputs_demo_struct_for_rust_call_dart_twin_sse_Output_demo_struct_for_rust_call_dart_twin_sse_AnyhowException(
           dynamic raw);
 
+  @protected
+  FutureOr<String> Function(int)
+      dco_decode_DartFn_Inputs_i_32_Output_String_AnyhowException(
+          dynamic raw);
+
+  @protected
+  FutureOr<int> Function(int, int)
+      dco_decode_DartFn_Inputs_i_32_i_32_Output_i_32_AnyhowException(
+          dynamic raw);
+
   @protected
   Object dco_decode_DartOpaque(dynamic raw);
 
   @protected
   ObjectArray1 dco_decode_DartOpaque_array_1(dynamic raw);
 
+  @protected
+  Stream<String> dco_decode_DartStream_String(dynamic raw);
+
+  @protected
+  Stream<int> dco_decode_DartStream_i_32(dynamic raw);
+
+  @protected
+  Decimal dco_decode_Decimal(dynamic raw);
+
   @protected
   SimpleTraitForDynTwinNormal dco_decode_DynTrait_SimpleTraitForDynTwinNormal(
       dynamic raw);
@@ -2645,6 +2770,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   BigInt dco_decode_I128(dynamic raw);
 
+  @protected
+  Map<String, dynamic> dco_decode_JsonMap(dynamic raw);
+
+  @protected
+  Object? dco_decode_JsonValue(dynamic raw);
+
   @protected
   LtNestedTypeWithLifetimeTwinNormal
       dco_decode_Lifetimeable_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLtNestedTypeWithLifetimeTwinNormalstatic(
@@ -3341,6 +3472,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConstructorOpaqueSyncStructTwinNormal(
           dynamic raw);
 
+  @protected
+  DartImplementableTraitTwinNormalDartImpl
+      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl(
+          dynamic raw);
+
   @protected
   DeliberateFailSanityCheckTwinNormal
       dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeliberateFailSanityCheckTwinNormal(
@@ -3876,6 +4012,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitTwinSyncSse(
           dynamic raw);
 
+  @protected
+  StructWithDartImplementableTraitTwinNormal
+      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+          dynamic raw);
+
   @protected
   StructWithGoodAndOpaqueFieldWithoutOptionTwinMoi
       dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinMoi(
@@ -3961,6 +4102,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   Set<int> dco_decode_Set_i_32(dynamic raw);
 
+  @protected
+  Duration dco_decode_StdDuration(dynamic raw);
+
+  @protected
+  DateTime dco_decode_StdSystemTime(dynamic raw);
+
   @protected
   RustStreamSink<NonCloneSimpleTwinMoi>
       dco_decode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinMoi_Dco(
@@ -4219,6 +4366,22 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   String dco_decode_String(dynamic raw);
 
+  @protected
+  DateTime dco_decode_TimeDate(dynamic raw);
+
+  @protected
+  Duration dco_decode_TimeDuration(dynamic raw);
+
+  @protected
+  DateTime dco_decode_TimeOffsetDateTime(dynamic raw);
+
+  @protected
+  DateTime dco_decode_TimePrimitiveDateTime(dynamic raw);
+
+  @protected
+  DartImplementableTraitTwinNormal
+      dco_decode_TraitDef_DartImplementableTraitTwinNormal(dynamic raw);
+
   @protected
   Issue2170Trait dco_decode_TraitDef_Issue2170Trait(dynamic raw);
 
@@ -4300,6 +4463,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   UuidValue dco_decode_Uuid(dynamic raw);
 
+  @protected
+  List<String> dco_decode_VecDeque_String(dynamic raw);
+
+  @protected
+  Int32List dco_decode_VecDeque_i_32(dynamic raw);
+
   @protected
   ATwinNormal dco_decode_a_twin_normal(dynamic raw);
 
@@ -4546,6 +4715,25 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   BigBuffersTwinSyncSse dco_decode_big_buffers_twin_sync_sse(dynamic raw);
 
+  @protected
+  BigNumbersTwinNormal dco_decode_big_numbers_twin_normal(dynamic raw);
+
+  @protected
+  BigNumbersTwinRustAsync dco_decode_big_numbers_twin_rust_async(dynamic raw);
+
+  @protected
+  BigNumbersTwinRustAsyncSse dco_decode_big_numbers_twin_rust_async_sse(
+      dynamic raw);
+
+  @protected
+  BigNumbersTwinSse dco_decode_big_numbers_twin_sse(dynamic raw);
+
+  @protected
+  BigNumbersTwinSync dco_decode_big_numbers_twin_sync(dynamic raw);
+
+  @protected
+  BigNumbersTwinSyncSse dco_decode_big_numbers_twin_sync_sse(dynamic raw);
+
   @protected
   BlobTwinNormal dco_decode_blob_twin_normal(dynamic raw);
 
@@ -4567,6 +4755,10 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   bool dco_decode_bool(dynamic raw);
 
+  @protected
+  DartImplementableTraitTwinNormal
+      dco_decode_box_DynTrait_DartImplementableTraitTwinNormal(dynamic raw);
+
   @protected
   ApplicationEnv dco_decode_box_application_env(dynamic raw);
 
@@ -4636,6 +4828,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   DateTime dco_decode_box_autoadd_Chrono_Naive(dynamic raw);
 
+  @protected
+  Duration dco_decode_box_autoadd_Chrono_NaiveTime(dynamic raw);
+
   @protected
   DateTime dco_decode_box_autoadd_Chrono_Utc(dynamic raw);
 
@@ -4707,6 +4902,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   HideDataTwinSyncSseMoi
       dco_decode_box_autoadd_RustOpaque_HideDataTwinSyncSseMoi(dynamic raw);
 
+  @protected
+  Duration dco_decode_box_autoadd_StdDuration(dynamic raw);
+
+  @protected
+  DateTime dco_decode_box_autoadd_StdSystemTime(dynamic raw);
+
+  @protected
+  Duration dco_decode_box_autoadd_TimeDuration(dynamic raw);
+
+  @protected
+  DateTime dco_decode_box_autoadd_TimeOffsetDateTime(dynamic raw);
+
+  @protected
+  DateTime dco_decode_box_autoadd_TimePrimitiveDateTime(dynamic raw);
+
   @protected
   ATwinNormal dco_decode_box_autoadd_a_twin_normal(dynamic raw);
 
@@ -4919,6 +5129,28 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   BenchmarkBlobTwinSyncSse dco_decode_box_autoadd_benchmark_blob_twin_sync_sse(
       dynamic raw);
 
+  @protected
+  BigNumbersTwinNormal dco_decode_box_autoadd_big_numbers_twin_normal(
+      dynamic raw);
+
+  @protected
+  BigNumbersTwinRustAsync dco_decode_box_autoadd_big_numbers_twin_rust_async(
+      dynamic raw);
+
+  @protected
+  BigNumbersTwinRustAsyncSse
+      dco_decode_box_autoadd_big_numbers_twin_rust_async_sse(dynamic raw);
+
+  @protected
+  BigNumbersTwinSse dco_decode_box_autoadd_big_numbers_twin_sse(dynamic raw);
+
+  @protected
+  BigNumbersTwinSync dco_decode_box_autoadd_big_numbers_twin_sync(dynamic raw);
+
+  @protected
+  BigNumbersTwinSyncSse dco_decode_box_autoadd_big_numbers_twin_sync_sse(
+      dynamic raw);
+
   @protected
   bool dco_decode_box_autoadd_bool(dynamic raw);
 
@@ -5114,6 +5346,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   CustomizedTwinSyncSse dco_decode_box_autoadd_customized_twin_sync_sse(
       dynamic raw);
 
+  @protected
+  DartImplementableTraitTwinNormalImplementor
+      dco_decode_box_autoadd_dart_implementable_trait_twin_normal_implementor(
+          dynamic raw);
+
   @protected
   DartOpaqueNestedTwinNormal
       dco_decode_box_autoadd_dart_opaque_nested_twin_normal(dynamic raw);
@@ -5508,6 +5745,30 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       dco_decode_box_autoadd_item_container_solution_two_twin_normal(
           dynamic raw);
 
+  @protected
+  JsonDocumentTwinNormal dco_decode_box_autoadd_json_document_twin_normal(
+      dynamic raw);
+
+  @protected
+  JsonDocumentTwinRustAsync
+      dco_decode_box_autoadd_json_document_twin_rust_async(dynamic raw);
+
+  @protected
+  JsonDocumentTwinRustAsyncSse
+      dco_decode_box_autoadd_json_document_twin_rust_async_sse(dynamic raw);
+
+  @protected
+  JsonDocumentTwinSse dco_decode_box_autoadd_json_document_twin_sse(
+      dynamic raw);
+
+  @protected
+  JsonDocumentTwinSync dco_decode_box_autoadd_json_document_twin_sync(
+      dynamic raw);
+
+  @protected
+  JsonDocumentTwinSyncSse dco_decode_box_autoadd_json_document_twin_sync_sse(
+      dynamic raw);
+
   @protected
   KitchenSinkTwinNormal dco_decode_box_autoadd_kitchen_sink_twin_normal(
       dynamic raw);
@@ -5799,6 +6060,27 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   (String, int) dco_decode_box_autoadd_record_string_i_32(dynamic raw);
 
+  @protected
+  ScheduleTwinNormal dco_decode_box_autoadd_schedule_twin_normal(dynamic raw);
+
+  @protected
+  ScheduleTwinRustAsync dco_decode_box_autoadd_schedule_twin_rust_async(
+      dynamic raw);
+
+  @protected
+  ScheduleTwinRustAsyncSse dco_decode_box_autoadd_schedule_twin_rust_async_sse(
+      dynamic raw);
+
+  @protected
+  ScheduleTwinSse dco_decode_box_autoadd_schedule_twin_sse(dynamic raw);
+
+  @protected
+  ScheduleTwinSync dco_decode_box_autoadd_schedule_twin_sync(dynamic raw);
+
+  @protected
+  ScheduleTwinSyncSse dco_decode_box_autoadd_schedule_twin_sync_sse(
+      dynamic raw);
+
   @protected
   Sequences dco_decode_box_autoadd_sequences(dynamic raw);
 
@@ -5907,6 +6189,26 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   SomeStructTwinSyncSse dco_decode_box_autoadd_some_struct_twin_sync_sse(
       dynamic raw);
 
+  @protected
+  StdTimeTwinNormal dco_decode_box_autoadd_std_time_twin_normal(dynamic raw);
+
+  @protected
+  StdTimeTwinRustAsync dco_decode_box_autoadd_std_time_twin_rust_async(
+      dynamic raw);
+
+  @protected
+  StdTimeTwinRustAsyncSse dco_decode_box_autoadd_std_time_twin_rust_async_sse(
+      dynamic raw);
+
+  @protected
+  StdTimeTwinSse dco_decode_box_autoadd_std_time_twin_sse(dynamic raw);
+
+  @protected
+  StdTimeTwinSync dco_decode_box_autoadd_std_time_twin_sync(dynamic raw);
+
+  @protected
+  StdTimeTwinSyncSse dco_decode_box_autoadd_std_time_twin_sync_sse(dynamic raw);
+
   @protected
   StructInLowerLevel dco_decode_box_autoadd_struct_in_lower_level(dynamic raw);
 
@@ -6267,6 +6569,28 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   TestIdTwinSyncSse dco_decode_box_autoadd_test_id_twin_sync_sse(dynamic raw);
 
+  @protected
+  TimeTypesTwinNormal dco_decode_box_autoadd_time_types_twin_normal(
+      dynamic raw);
+
+  @protected
+  TimeTypesTwinRustAsync dco_decode_box_autoadd_time_types_twin_rust_async(
+      dynamic raw);
+
+  @protected
+  TimeTypesTwinRustAsyncSse
+      dco_decode_box_autoadd_time_types_twin_rust_async_sse(dynamic raw);
+
+  @protected
+  TimeTypesTwinSse dco_decode_box_autoadd_time_types_twin_sse(dynamic raw);
+
+  @protected
+  TimeTypesTwinSync dco_decode_box_autoadd_time_types_twin_sync(dynamic raw);
+
+  @protected
+  TimeTypesTwinSyncSse dco_decode_box_autoadd_time_types_twin_sync_sse(
+      dynamic raw);
+
   @protected
   TranslatableStructWithDartCodeTwinNormal
       dco_decode_box_autoadd_translatable_struct_with_dart_code_twin_normal(
@@ -6866,6 +7190,10 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   CustomizedTwinSyncSse dco_decode_customized_twin_sync_sse(dynamic raw);
 
+  @protected
+  DartImplementableTraitTwinNormalImplementor
+      dco_decode_dart_implementable_trait_twin_normal_implementor(dynamic raw);
+
   @protected
   DartOpaqueNestedTwinNormal dco_decode_dart_opaque_nested_twin_normal(
       dynamic raw);
@@ -7357,6 +7685,26 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   ItemContainerSolutionTwoTwinNormal
       dco_decode_item_container_solution_two_twin_normal(dynamic raw);
 
+  @protected
+  JsonDocumentTwinNormal dco_decode_json_document_twin_normal(dynamic raw);
+
+  @protected
+  JsonDocumentTwinRustAsync dco_decode_json_document_twin_rust_async(
+      dynamic raw);
+
+  @protected
+  JsonDocumentTwinRustAsyncSse dco_decode_json_document_twin_rust_async_sse(
+      dynamic raw);
+
+  @protected
+  JsonDocumentTwinSse dco_decode_json_document_twin_sse(dynamic raw);
+
+  @protected
+  JsonDocumentTwinSync dco_decode_json_document_twin_sync(dynamic raw);
+
+  @protected
+  JsonDocumentTwinSyncSse dco_decode_json_document_twin_sync_sse(dynamic raw);
+
   @protected
   KitchenSinkTwinNormal dco_decode_kitchen_sink_twin_normal(dynamic raw);
 
@@ -7446,18 +7794,36 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpaqueItemTwinNormal(
           dynamic raw);
 
+  @protected
+  List<BigInt> dco_decode_list_BigInt(dynamic raw);
+
   @protected
   List<Duration> dco_decode_list_Chrono_Duration(dynamic raw);
 
+  @protected
+  List<DateTimeWithOffset> dco_decode_list_Chrono_FixedOffset(dynamic raw);
+
   @protected
   List<DateTime> dco_decode_list_Chrono_Local(dynamic raw);
 
   @protected
   List<DateTime> dco_decode_list_Chrono_Naive(dynamic raw);
 
+  @protected
+  List<DateTime> dco_decode_list_Chrono_NaiveDate(dynamic raw);
+
+  @protected
+  List<DateTimeWithOffset> dco_decode_list_Chrono_Tz(dynamic raw);
+
   @protected
   List<Object> dco_decode_list_DartOpaque(dynamic raw);
 
+  @protected
+  List<Decimal> dco_decode_list_Decimal(dynamic raw);
+
+  @protected
+  List<Object?> dco_decode_list_JsonValue(dynamic raw);
+
   @protected
   List<HideDataTwinMoi> dco_decode_list_RustOpaque_HideDataTwinMoi(dynamic raw);
 
@@ -7504,6 +7870,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   List<HideDataTwinSyncSseMoi>
       dco_decode_list_RustOpaque_HideDataTwinSyncSseMoi(dynamic raw);
 
+  @protected
+  List<Duration> dco_decode_list_StdDuration(dynamic raw);
+
+  @protected
+  List<DateTime> dco_decode_list_StdSystemTime(dynamic raw);
+
   @protected
   List<RustStreamSink<int>> dco_decode_list_StreamSink_i_32_Dco(dynamic raw);
 
@@ -7513,6 +7885,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<String> dco_decode_list_String(dynamic raw);
 
+  @protected
+  List<Duration> dco_decode_list_TimeDuration(dynamic raw);
+
   @protected
   List<UuidValue> dco_decode_list_Uuid(dynamic raw);
 
@@ -8453,9 +8828,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   OpaqueNestedTwinSyncSseMoi dco_decode_opaque_nested_twin_sync_sse_moi(
       dynamic raw);
 
+  @protected
+  BigInt? dco_decode_opt_BigInt(dynamic raw);
+
+  @protected
+  DateTimeWithOffset? dco_decode_opt_Chrono_FixedOffset(dynamic raw);
+
+  @protected
+  DateTimeWithOffset? dco_decode_opt_Chrono_Tz(dynamic raw);
+
+  @protected
+  Decimal? dco_decode_opt_Decimal(dynamic raw);
+
   @protected
   BigInt? dco_decode_opt_I128(dynamic raw);
 
+  @protected
+  Object? dco_decode_opt_JsonValue(dynamic raw);
+
   @protected
   String? dco_decode_opt_String(dynamic raw);
 
@@ -8528,6 +8918,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   DateTime? dco_decode_opt_box_autoadd_Chrono_Naive(dynamic raw);
 
+  @protected
+  Duration? dco_decode_opt_box_autoadd_Chrono_NaiveTime(dynamic raw);
+
   @protected
   DateTime? dco_decode_opt_box_autoadd_Chrono_Utc(dynamic raw);
 
@@ -8603,6 +8996,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   HideDataTwinSyncSseMoi?
       dco_decode_opt_box_autoadd_RustOpaque_HideDataTwinSyncSseMoi(dynamic raw);
 
+  @protected
+  Duration? dco_decode_opt_box_autoadd_StdDuration(dynamic raw);
+
+  @protected
+  DateTime? dco_decode_opt_box_autoadd_StdSystemTime(dynamic raw);
+
+  @protected
+  Duration? dco_decode_opt_box_autoadd_TimeDuration(dynamic raw);
+
+  @protected
+  DateTime? dco_decode_opt_box_autoadd_TimeOffsetDateTime(dynamic raw);
+
+  @protected
+  DateTime? dco_decode_opt_box_autoadd_TimePrimitiveDateTime(dynamic raw);
+
   @protected
   ApplicationEnv? dco_decode_opt_box_autoadd_application_env(dynamic raw);
 
@@ -9384,6 +9792,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   (int, ApplicationMode) dco_decode_record_u_8_application_mode(dynamic raw);
 
+  @protected
+  ScheduleTwinNormal dco_decode_schedule_twin_normal(dynamic raw);
+
+  @protected
+  ScheduleTwinRustAsync dco_decode_schedule_twin_rust_async(dynamic raw);
+
+  @protected
+  ScheduleTwinRustAsyncSse dco_decode_schedule_twin_rust_async_sse(dynamic raw);
+
+  @protected
+  ScheduleTwinSse dco_decode_schedule_twin_sse(dynamic raw);
+
+  @protected
+  ScheduleTwinSync dco_decode_schedule_twin_sync(dynamic raw);
+
+  @protected
+  ScheduleTwinSyncSse dco_decode_schedule_twin_sync_sse(dynamic raw);
+
   @protected
   Sequences dco_decode_sequences(dynamic raw);
 
@@ -9538,6 +9964,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   StaticOnlyTwinSyncSse dco_decode_static_only_twin_sync_sse(dynamic raw);
 
+  @protected
+  StdTimeTwinNormal dco_decode_std_time_twin_normal(dynamic raw);
+
+  @protected
+  StdTimeTwinRustAsync dco_decode_std_time_twin_rust_async(dynamic raw);
+
+  @protected
+  StdTimeTwinRustAsyncSse dco_decode_std_time_twin_rust_async_sse(dynamic raw);
+
+  @protected
+  StdTimeTwinSse dco_decode_std_time_twin_sse(dynamic raw);
+
+  @protected
+  StdTimeTwinSync dco_decode_std_time_twin_sync(dynamic raw);
+
+  @protected
+  StdTimeTwinSyncSse dco_decode_std_time_twin_sync_sse(dynamic raw);
+
   @protected
   StructInLowerLevel dco_decode_struct_in_lower_level(dynamic raw);
 
@@ -9973,6 +10417,25 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   TestModelTwinSyncSse dco_decode_test_model_twin_sync_sse(dynamic raw);
 
+  @protected
+  TimeTypesTwinNormal dco_decode_time_types_twin_normal(dynamic raw);
+
+  @protected
+  TimeTypesTwinRustAsync dco_decode_time_types_twin_rust_async(dynamic raw);
+
+  @protected
+  TimeTypesTwinRustAsyncSse dco_decode_time_types_twin_rust_async_sse(
+      dynamic raw);
+
+  @protected
+  TimeTypesTwinSse dco_decode_time_types_twin_sse(dynamic raw);
+
+  @protected
+  TimeTypesTwinSync dco_decode_time_types_twin_sync(dynamic raw);
+
+  @protected
+  TimeTypesTwinSyncSse dco_decode_time_types_twin_sync_sse(dynamic raw);
+
   @protected
   TranslatableStructWithDartCodeTwinNormal
       dco_decode_translatable_struct_with_dart_code_twin_normal(dynamic raw);
@@ -10025,6 +10488,30 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   TupleStructWithTwoFieldTwinSyncSse
       dco_decode_tuple_struct_with_two_field_twin_sync_sse(dynamic raw);
 
+  @protected
+  TypedExceptionErrorTwinNormal dco_decode_typed_exception_error_twin_normal(
+      dynamic raw);
+
+  @protected
+  TypedExceptionErrorTwinRustAsync
+      dco_decode_typed_exception_error_twin_rust_async(dynamic raw);
+
+  @protected
+  TypedExceptionErrorTwinRustAsyncSse
+      dco_decode_typed_exception_error_twin_rust_async_sse(dynamic raw);
+
+  @protected
+  TypedExceptionErrorTwinSse dco_decode_typed_exception_error_twin_sse(
+      dynamic raw);
+
+  @protected
+  TypedExceptionErrorTwinSync dco_decode_typed_exception_error_twin_sync(
+      dynamic raw);
+
+  @protected
+  TypedExceptionErrorTwinSyncSse dco_decode_typed_exception_error_twin_sync_sse(
+      dynamic raw);
+
   @protected
   int dco_decode_u_16(dynamic raw);
 
@@ -10121,6 +10608,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
 
+  @protected
+  DartImplementableTraitTwinNormalDartImpl
+      sse_decode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl(
+          SseDeserializer deserializer);
+
   @protected
   LtNestedTypeWithLifetimeTwinNormal
       sse_decode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLifetimeableLtNestedTypeWithLifetimeTwinNormalstatic(
@@ -10231,6 +10723,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       sse_decode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitForDynTwinNormal(
           SseDeserializer deserializer);
 
+  @protected
+  StructWithDartImplementableTraitTwinNormal
+      sse_decode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+          SseDeserializer deserializer);
+
   @protected
   BoxAnyMyDartTypeRename
       sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynAnySendSyncstatic(
@@ -10311,6 +10808,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConstructorOpaqueSyncStructTwinNormal(
           SseDeserializer deserializer);
 
+  @protected
+  DartImplementableTraitTwinNormalDartImpl
+      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl(
+          SseDeserializer deserializer);
+
   @protected
   DeliberateFailSanityCheckTwinNormal
       sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeliberateFailSanityCheckTwinNormal(
@@ -10816,6 +11318,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitTwinSyncSse(
           SseDeserializer deserializer);
 
+  @protected
+  StructWithDartImplementableTraitTwinNormal
+      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+          SseDeserializer deserializer);
+
   @protected
   StructWithGoodAndOpaqueFieldWithoutOptionTwinMoi
       sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinMoi(
@@ -11056,6 +11563,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitTwinSyncSse(
           SseDeserializer deserializer);
 
+  @protected
+  StructWithDartImplementableTraitTwinNormal
+      sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+          SseDeserializer deserializer);
+
   @protected
   StructWithGoodAndOpaqueFieldWithoutOptionTwinMoi
       sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinMoi(
@@ -11131,6 +11643,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConstructorOpaqueSyncStructTwinNormal(
           SseDeserializer deserializer);
 
+  @protected
+  DartImplementableTraitTwinNormalDartImpl
+      sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl(
+          SseDeserializer deserializer);
+
   @protected
   DeliberateFailSanityCheckTwinNormal
       sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeliberateFailSanityCheckTwinNormal(
@@ -11421,6 +11938,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitTwinSyncSse(
           SseDeserializer deserializer);
 
+  @protected
+  StructWithDartImplementableTraitTwinNormal
+      sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+          SseDeserializer deserializer);
+
   @protected
   StructWithGoodAndOpaqueFieldWithoutOptionTwinMoi
       sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinMoi(
@@ -11491,9 +12013,25 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithSimpleSetterTwinNormal(
           SseDeserializer deserializer);
 
+  @protected
+  Map<String, MySize> sse_decode_BTreeMap_String_my_size(
+      SseDeserializer deserializer);
+
+  @protected
+  Map<int, int> sse_decode_BTreeMap_i_32_i_32(SseDeserializer deserializer);
+
+  @protected
+  Set<String> sse_decode_BTreeSet_String(SseDeserializer deserializer);
+
+  @protected
+  Set<int> sse_decode_BTreeSet_i_32(SseDeserializer deserializer);
+
   @protected
   String sse_decode_Backtrace(SseDeserializer deserializer);
 
+  @protected
+  BigInt sse_decode_BigInt(SseDeserializer deserializer);
+
   @protected
   int sse_decode_CastedPrimitive_i_64(SseDeserializer deserializer);
 
@@ -11512,12 +12050,25 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   Duration sse_decode_Chrono_Duration(SseDeserializer deserializer);
 
+  @protected
+  DateTimeWithOffset sse_decode_Chrono_FixedOffset(
+      SseDeserializer deserializer);
+
   @protected
   DateTime sse_decode_Chrono_Local(SseDeserializer deserializer);
 
   @protected
   DateTime sse_decode_Chrono_Naive(SseDeserializer deserializer);
 
+  @protected
+  DateTime sse_decode_Chrono_NaiveDate(SseDeserializer deserializer);
+
+  @protected
+  Duration sse_decode_Chrono_NaiveTime(SseDeserializer deserializer);
+
+  @protected
+  DateTimeWithOffset sse_decode_Chrono_Tz(SseDeserializer deserializer);
+
   @protected
   DateTime sse_decode_Chrono_Utc(SseDeserializer deserializer);
 
@@ -11531,6 +12082,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ObjectArray1 sse_decode_DartOpaque_array_1(SseDeserializer deserializer);
 
+  @protected
+  Stream<String> sse_decode_DartStream_String(SseDeserializer deserializer);
+
+  @protected
+  Stream<int> sse_decode_DartStream_i_32(SseDeserializer deserializer);
+
+  @protected
+  Decimal sse_decode_Decimal(SseDeserializer deserializer);
+
   @protected
   SimpleTraitForDynTwinNormal sse_decode_DynTrait_SimpleTraitForDynTwinNormal(
       SseDeserializer deserializer);
@@ -11538,6 +12098,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   BigInt sse_decode_I128(SseDeserializer deserializer);
 
+  @protected
+  Map<String, dynamic> sse_decode_JsonMap(SseDeserializer deserializer);
+
+  @protected
+  Object? sse_decode_JsonValue(SseDeserializer deserializer);
+
   @protected
   LtNestedTypeWithLifetimeTwinNormal
       sse_decode_Lifetimeable_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLtNestedTypeWithLifetimeTwinNormalstatic(
@@ -12304,6 +12870,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConstructorOpaqueSyncStructTwinNormal(
           SseDeserializer deserializer);
 
+  @protected
+  DartImplementableTraitTwinNormalDartImpl
+      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl(
+          SseDeserializer deserializer);
+
   @protected
   DeliberateFailSanityCheckTwinNormal
       sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeliberateFailSanityCheckTwinNormal(
@@ -12839,6 +13410,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitTwinSyncSse(
           SseDeserializer deserializer);
 
+  @protected
+  StructWithDartImplementableTraitTwinNormal
+      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+          SseDeserializer deserializer);
+
   @protected
   StructWithGoodAndOpaqueFieldWithoutOptionTwinMoi
       sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinMoi(
@@ -12925,6 +13501,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   Set<int> sse_decode_Set_i_32(SseDeserializer deserializer);
 
+  @protected
+  Duration sse_decode_StdDuration(SseDeserializer deserializer);
+
+  @protected
+  DateTime sse_decode_StdSystemTime(SseDeserializer deserializer);
+
   @protected
   RustStreamSink<NonCloneSimpleTwinMoi>
       sse_decode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinMoi_Dco(
@@ -13220,12 +13802,30 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   String sse_decode_String(SseDeserializer deserializer);
 
+  @protected
+  DateTime sse_decode_TimeDate(SseDeserializer deserializer);
+
+  @protected
+  Duration sse_decode_TimeDuration(SseDeserializer deserializer);
+
+  @protected
+  DateTime sse_decode_TimeOffsetDateTime(SseDeserializer deserializer);
+
+  @protected
+  DateTime sse_decode_TimePrimitiveDateTime(SseDeserializer deserializer);
+
   @protected
   BigInt sse_decode_U128(SseDeserializer deserializer);
 
   @protected
   UuidValue sse_decode_Uuid(SseDeserializer deserializer);
 
+  @protected
+  List<String> sse_decode_VecDeque_String(SseDeserializer deserializer);
+
+  @protected
+  Int32List sse_decode_VecDeque_i_32(SseDeserializer deserializer);
+
   @protected
   ATwinNormal sse_decode_a_twin_normal(SseDeserializer deserializer);
 
@@ -13508,6 +14108,30 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   BigBuffersTwinSyncSse sse_decode_big_buffers_twin_sync_sse(
       SseDeserializer deserializer);
 
+  @protected
+  BigNumbersTwinNormal sse_decode_big_numbers_twin_normal(
+      SseDeserializer deserializer);
+
+  @protected
+  BigNumbersTwinRustAsync sse_decode_big_numbers_twin_rust_async(
+      SseDeserializer deserializer);
+
+  @protected
+  BigNumbersTwinRustAsyncSse sse_decode_big_numbers_twin_rust_async_sse(
+      SseDeserializer deserializer);
+
+  @protected
+  BigNumbersTwinSse sse_decode_big_numbers_twin_sse(
+      SseDeserializer deserializer);
+
+  @protected
+  BigNumbersTwinSync sse_decode_big_numbers_twin_sync(
+      SseDeserializer deserializer);
+
+  @protected
+  BigNumbersTwinSyncSse sse_decode_big_numbers_twin_sync_sse(
+      SseDeserializer deserializer);
+
   @protected
   BlobTwinNormal sse_decode_blob_twin_normal(SseDeserializer deserializer);
 
@@ -13531,6 +14155,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   bool sse_decode_bool(SseDeserializer deserializer);
 
+  @protected
+  DartImplementableTraitTwinNormal
+      sse_decode_box_DynTrait_DartImplementableTraitTwinNormal(
+          SseDeserializer deserializer);
+
   @protected
   ApplicationEnv sse_decode_box_application_env(SseDeserializer deserializer);
 
@@ -13600,6 +14229,10 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   DateTime sse_decode_box_autoadd_Chrono_Naive(SseDeserializer deserializer);
 
+  @protected
+  Duration sse_decode_box_autoadd_Chrono_NaiveTime(
+      SseDeserializer deserializer);
+
   @protected
   DateTime sse_decode_box_autoadd_Chrono_Utc(SseDeserializer deserializer);
 
@@ -13678,6 +14311,23 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       sse_decode_box_autoadd_RustOpaque_HideDataTwinSyncSseMoi(
           SseDeserializer deserializer);
 
+  @protected
+  Duration sse_decode_box_autoadd_StdDuration(SseDeserializer deserializer);
+
+  @protected
+  DateTime sse_decode_box_autoadd_StdSystemTime(SseDeserializer deserializer);
+
+  @protected
+  Duration sse_decode_box_autoadd_TimeDuration(SseDeserializer deserializer);
+
+  @protected
+  DateTime sse_decode_box_autoadd_TimeOffsetDateTime(
+      SseDeserializer deserializer);
+
+  @protected
+  DateTime sse_decode_box_autoadd_TimePrimitiveDateTime(
+      SseDeserializer deserializer);
+
   @protected
   ATwinNormal sse_decode_box_autoadd_a_twin_normal(
       SseDeserializer deserializer);
@@ -13926,6 +14576,31 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   BenchmarkBlobTwinSyncSse sse_decode_box_autoadd_benchmark_blob_twin_sync_sse(
       SseDeserializer deserializer);
 
+  @protected
+  BigNumbersTwinNormal sse_decode_box_autoadd_big_numbers_twin_normal(
+      SseDeserializer deserializer);
+
+  @protected
+  BigNumbersTwinRustAsync sse_decode_box_autoadd_big_numbers_twin_rust_async(
+      SseDeserializer deserializer);
+
+  @protected
+  BigNumbersTwinRustAsyncSse
+      sse_decode_box_autoadd_big_numbers_twin_rust_async_sse(
+          SseDeserializer deserializer);
+
+  @protected
+  BigNumbersTwinSse sse_decode_box_autoadd_big_numbers_twin_sse(
+      SseDeserializer deserializer);
+
+  @protected
+  BigNumbersTwinSync sse_decode_box_autoadd_big_numbers_twin_sync(
+      SseDeserializer deserializer);
+
+  @protected
+  BigNumbersTwinSyncSse sse_decode_box_autoadd_big_numbers_twin_sync_sse(
+      SseDeserializer deserializer);
+
   @protected
   bool sse_decode_box_autoadd_bool(SseDeserializer deserializer);
 
@@ -14148,6 +14823,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   CustomizedTwinSyncSse sse_decode_box_autoadd_customized_twin_sync_sse(
       SseDeserializer deserializer);
 
+  @protected
+  DartImplementableTraitTwinNormalImplementor
+      sse_decode_box_autoadd_dart_implementable_trait_twin_normal_implementor(
+          SseDeserializer deserializer);
+
   @protected
   DartOpaqueNestedTwinNormal
       sse_decode_box_autoadd_dart_opaque_nested_twin_normal(
@@ -14594,6 +15274,32 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       sse_decode_box_autoadd_item_container_solution_two_twin_normal(
           SseDeserializer deserializer);
 
+  @protected
+  JsonDocumentTwinNormal sse_decode_box_autoadd_json_document_twin_normal(
+      SseDeserializer deserializer);
+
+  @protected
+  JsonDocumentTwinRustAsync
+      sse_decode_box_autoadd_json_document_twin_rust_async(
+          SseDeserializer deserializer);
+
+  @protected
+  JsonDocumentTwinRustAsyncSse
+      sse_decode_box_autoadd_json_document_twin_rust_async_sse(
+          SseDeserializer deserializer);
+
+  @protected
+  JsonDocumentTwinSse sse_decode_box_autoadd_json_document_twin_sse(
+      SseDeserializer deserializer);
+
+  @protected
+  JsonDocumentTwinSync sse_decode_box_autoadd_json_document_twin_sync(
+      SseDeserializer deserializer);
+
+  @protected
+  JsonDocumentTwinSyncSse sse_decode_box_autoadd_json_document_twin_sync_sse(
+      SseDeserializer deserializer);
+
   @protected
   KitchenSinkTwinNormal sse_decode_box_autoadd_kitchen_sink_twin_normal(
       SseDeserializer deserializer);
@@ -14924,6 +15630,30 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   (String, int) sse_decode_box_autoadd_record_string_i_32(
       SseDeserializer deserializer);
 
+  @protected
+  ScheduleTwinNormal sse_decode_box_autoadd_schedule_twin_normal(
+      SseDeserializer deserializer);
+
+  @protected
+  ScheduleTwinRustAsync sse_decode_box_autoadd_schedule_twin_rust_async(
+      SseDeserializer deserializer);
+
+  @protected
+  ScheduleTwinRustAsyncSse sse_decode_box_autoadd_schedule_twin_rust_async_sse(
+      SseDeserializer deserializer);
+
+  @protected
+  ScheduleTwinSse sse_decode_box_autoadd_schedule_twin_sse(
+      SseDeserializer deserializer);
+
+  @protected
+  ScheduleTwinSync sse_decode_box_autoadd_schedule_twin_sync(
+      SseDeserializer deserializer);
+
+  @protected
+  ScheduleTwinSyncSse sse_decode_box_autoadd_schedule_twin_sync_sse(
+      SseDeserializer deserializer);
+
   @protected
   Sequences sse_decode_box_autoadd_sequences(SseDeserializer deserializer);
 
@@ -15043,6 +15773,30 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   SomeStructTwinSyncSse sse_decode_box_autoadd_some_struct_twin_sync_sse(
       SseDeserializer deserializer);
 
+  @protected
+  StdTimeTwinNormal sse_decode_box_autoadd_std_time_twin_normal(
+      SseDeserializer deserializer);
+
+  @protected
+  StdTimeTwinRustAsync sse_decode_box_autoadd_std_time_twin_rust_async(
+      SseDeserializer deserializer);
+
+  @protected
+  StdTimeTwinRustAsyncSse sse_decode_box_autoadd_std_time_twin_rust_async_sse(
+      SseDeserializer deserializer);
+
+  @protected
+  StdTimeTwinSse sse_decode_box_autoadd_std_time_twin_sse(
+      SseDeserializer deserializer);
+
+  @protected
+  StdTimeTwinSync sse_decode_box_autoadd_std_time_twin_sync(
+      SseDeserializer deserializer);
+
+  @protected
+  StdTimeTwinSyncSse sse_decode_box_autoadd_std_time_twin_sync_sse(
+      SseDeserializer deserializer);
+
   @protected
   StructInLowerLevel sse_decode_box_autoadd_struct_in_lower_level(
       SseDeserializer deserializer);
@@ -15437,6 +16191,31 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   TestIdTwinSyncSse sse_decode_box_autoadd_test_id_twin_sync_sse(
       SseDeserializer deserializer);
 
+  @protected
+  TimeTypesTwinNormal sse_decode_box_autoadd_time_types_twin_normal(
+      SseDeserializer deserializer);
+
+  @protected
+  TimeTypesTwinRustAsync sse_decode_box_autoadd_time_types_twin_rust_async(
+      SseDeserializer deserializer);
+
+  @protected
+  TimeTypesTwinRustAsyncSse
+      sse_decode_box_autoadd_time_types_twin_rust_async_sse(
+          SseDeserializer deserializer);
+
+  @protected
+  TimeTypesTwinSse sse_decode_box_autoadd_time_types_twin_sse(
+      SseDeserializer deserializer);
+
+  @protected
+  TimeTypesTwinSync sse_decode_box_autoadd_time_types_twin_sync(
+      SseDeserializer deserializer);
+
+  @protected
+  TimeTypesTwinSyncSse sse_decode_box_autoadd_time_types_twin_sync_sse(
+      SseDeserializer deserializer);
+
   @protected
   TranslatableStructWithDartCodeTwinNormal
       sse_decode_box_autoadd_translatable_struct_with_dart_code_twin_normal(
@@ -16126,6 +16905,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   CustomizedTwinSyncSse sse_decode_customized_twin_sync_sse(
       SseDeserializer deserializer);
 
+  @protected
+  DartImplementableTraitTwinNormalImplementor
+      sse_decode_dart_implementable_trait_twin_normal_implementor(
+          SseDeserializer deserializer);
+
   @protected
   DartOpaqueNestedTwinNormal sse_decode_dart_opaque_nested_twin_normal(
       SseDeserializer deserializer);
@@ -16692,6 +17476,30 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       sse_decode_item_container_solution_two_twin_normal(
           SseDeserializer deserializer);
 
+  @protected
+  JsonDocumentTwinNormal sse_decode_json_document_twin_normal(
+      SseDeserializer deserializer);
+
+  @protected
+  JsonDocumentTwinRustAsync sse_decode_json_document_twin_rust_async(
+      SseDeserializer deserializer);
+
+  @protected
+  JsonDocumentTwinRustAsyncSse sse_decode_json_document_twin_rust_async_sse(
+      SseDeserializer deserializer);
+
+  @protected
+  JsonDocumentTwinSse sse_decode_json_document_twin_sse(
+      SseDeserializer deserializer);
+
+  @protected
+  JsonDocumentTwinSync sse_decode_json_document_twin_sync(
+      SseDeserializer deserializer);
+
+  @protected
+  JsonDocumentTwinSyncSse sse_decode_json_document_twin_sync_sse(
+      SseDeserializer deserializer);
+
   @protected
   KitchenSinkTwinNormal sse_decode_kitchen_sink_twin_normal(
       SseDeserializer deserializer);
@@ -16786,18 +17594,38 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpaqueItemTwinNormal(
           SseDeserializer deserializer);
 
+  @protected
+  List<BigInt> sse_decode_list_BigInt(SseDeserializer deserializer);
+
   @protected
   List<Duration> sse_decode_list_Chrono_Duration(SseDeserializer deserializer);
 
+  @protected
+  List<DateTimeWithOffset> sse_decode_list_Chrono_FixedOffset(
+      SseDeserializer deserializer);
+
   @protected
   List<DateTime> sse_decode_list_Chrono_Local(SseDeserializer deserializer);
 
   @protected
   List<DateTime> sse_decode_list_Chrono_Naive(SseDeserializer deserializer);
 
+  @protected
+  List<DateTime> sse_decode_list_Chrono_NaiveDate(SseDeserializer deserializer);
+
+  @protected
+  List<DateTimeWithOffset> sse_decode_list_Chrono_Tz(
+      SseDeserializer deserializer);
+
   @protected
   List<Object> sse_decode_list_DartOpaque(SseDeserializer deserializer);
 
+  @protected
+  List<Decimal> sse_decode_list_Decimal(SseDeserializer deserializer);
+
+  @protected
+  List<Object?> sse_decode_list_JsonValue(SseDeserializer deserializer);
+
   @protected
   List<HideDataTwinMoi> sse_decode_list_RustOpaque_HideDataTwinMoi(
       SseDeserializer deserializer);
@@ -16850,6 +17678,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       sse_decode_list_RustOpaque_HideDataTwinSyncSseMoi(
           SseDeserializer deserializer);
 
+  @protected
+  List<Duration> sse_decode_list_StdDuration(SseDeserializer deserializer);
+
+  @protected
+  List<DateTime> sse_decode_list_StdSystemTime(SseDeserializer deserializer);
+
   @protected
   List<RustStreamSink<int>> sse_decode_list_StreamSink_i_32_Dco(
       SseDeserializer deserializer);
@@ -16861,6 +17695,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<String> sse_decode_list_String(SseDeserializer deserializer);
 
+  @protected
+  List<Duration> sse_decode_list_TimeDuration(SseDeserializer deserializer);
+
   @protected
   List<UuidValue> sse_decode_list_Uuid(SseDeserializer deserializer);
 
@@ -17956,9 +18793,25 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   OpaqueNestedTwinSyncSseMoi sse_decode_opaque_nested_twin_sync_sse_moi(
       SseDeserializer deserializer);
 
+  @protected
+  BigInt? sse_decode_opt_BigInt(SseDeserializer deserializer);
+
+  @protected
+  DateTimeWithOffset? sse_decode_opt_Chrono_FixedOffset(
+      SseDeserializer deserializer);
+
+  @protected
+  DateTimeWithOffset? sse_decode_opt_Chrono_Tz(SseDeserializer deserializer);
+
+  @protected
+  Decimal? sse_decode_opt_Decimal(SseDeserializer deserializer);
+
   @protected
   BigInt? sse_decode_opt_I128(SseDeserializer deserializer);
 
+  @protected
+  Object? sse_decode_opt_JsonValue(SseDeserializer deserializer);
+
   @protected
   String? sse_decode_opt_String(SseDeserializer deserializer);
 
@@ -18033,6 +18886,10 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   DateTime? sse_decode_opt_box_autoadd_Chrono_Naive(
       SseDeserializer deserializer);
 
+  @protected
+  Duration? sse_decode_opt_box_autoadd_Chrono_NaiveTime(
+      SseDeserializer deserializer);
+
   @protected
   DateTime? sse_decode_opt_box_autoadd_Chrono_Utc(SseDeserializer deserializer);
 
@@ -18114,6 +18971,26 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       sse_decode_opt_box_autoadd_RustOpaque_HideDataTwinSyncSseMoi(
           SseDeserializer deserializer);
 
+  @protected
+  Duration? sse_decode_opt_box_autoadd_StdDuration(
+      SseDeserializer deserializer);
+
+  @protected
+  DateTime? sse_decode_opt_box_autoadd_StdSystemTime(
+      SseDeserializer deserializer);
+
+  @protected
+  Duration? sse_decode_opt_box_autoadd_TimeDuration(
+      SseDeserializer deserializer);
+
+  @protected
+  DateTime? sse_decode_opt_box_autoadd_TimeOffsetDateTime(
+      SseDeserializer deserializer);
+
+  @protected
+  DateTime? sse_decode_opt_box_autoadd_TimePrimitiveDateTime(
+      SseDeserializer deserializer);
+
   @protected
   ApplicationEnv? sse_decode_opt_box_autoadd_application_env(
       SseDeserializer deserializer);
@@ -18986,6 +19863,28 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   (int, ApplicationMode) sse_decode_record_u_8_application_mode(
       SseDeserializer deserializer);
 
+  @protected
+  ScheduleTwinNormal sse_decode_schedule_twin_normal(
+      SseDeserializer deserializer);
+
+  @protected
+  ScheduleTwinRustAsync sse_decode_schedule_twin_rust_async(
+      SseDeserializer deserializer);
+
+  @protected
+  ScheduleTwinRustAsyncSse sse_decode_schedule_twin_rust_async_sse(
+      SseDeserializer deserializer);
+
+  @protected
+  ScheduleTwinSse sse_decode_schedule_twin_sse(SseDeserializer deserializer);
+
+  @protected
+  ScheduleTwinSync sse_decode_schedule_twin_sync(SseDeserializer deserializer);
+
+  @protected
+  ScheduleTwinSyncSse sse_decode_schedule_twin_sync_sse(
+      SseDeserializer deserializer);
+
   @protected
   Sequences sse_decode_sequences(SseDeserializer deserializer);
 
@@ -19169,6 +20068,28 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   StaticOnlyTwinSyncSse sse_decode_static_only_twin_sync_sse(
       SseDeserializer deserializer);
 
+  @protected
+  StdTimeTwinNormal sse_decode_std_time_twin_normal(
+      SseDeserializer deserializer);
+
+  @protected
+  StdTimeTwinRustAsync sse_decode_std_time_twin_rust_async(
+      SseDeserializer deserializer);
+
+  @protected
+  StdTimeTwinRustAsyncSse sse_decode_std_time_twin_rust_async_sse(
+      SseDeserializer deserializer);
+
+  @protected
+  StdTimeTwinSse sse_decode_std_time_twin_sse(SseDeserializer deserializer);
+
+  @protected
+  StdTimeTwinSync sse_decode_std_time_twin_sync(SseDeserializer deserializer);
+
+  @protected
+  StdTimeTwinSyncSse sse_decode_std_time_twin_sync_sse(
+      SseDeserializer deserializer);
+
   @protected
   StructInLowerLevel sse_decode_struct_in_lower_level(
       SseDeserializer deserializer);
@@ -19671,6 +20592,29 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   TestModelTwinSyncSse sse_decode_test_model_twin_sync_sse(
       SseDeserializer deserializer);
 
+  @protected
+  TimeTypesTwinNormal sse_decode_time_types_twin_normal(
+      SseDeserializer deserializer);
+
+  @protected
+  TimeTypesTwinRustAsync sse_decode_time_types_twin_rust_async(
+      SseDeserializer deserializer);
+
+  @protected
+  TimeTypesTwinRustAsyncSse sse_decode_time_types_twin_rust_async_sse(
+      SseDeserializer deserializer);
+
+  @protected
+  TimeTypesTwinSse sse_decode_time_types_twin_sse(SseDeserializer deserializer);
+
+  @protected
+  TimeTypesTwinSync sse_decode_time_types_twin_sync(
+      SseDeserializer deserializer);
+
+  @protected
+  TimeTypesTwinSyncSse sse_decode_time_types_twin_sync_sse(
+      SseDeserializer deserializer);
+
   @protected
   TranslatableStructWithDartCodeTwinNormal
       sse_decode_translatable_struct_with_dart_code_twin_normal(
@@ -19736,6 +20680,32 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       sse_decode_tuple_struct_with_two_field_twin_sync_sse(
           SseDeserializer deserializer);
 
+  @protected
+  TypedExceptionErrorTwinNormal sse_decode_typed_exception_error_twin_normal(
+      SseDeserializer deserializer);
+
+  @protected
+  TypedExceptionErrorTwinRustAsync
+      sse_decode_typed_exception_error_twin_rust_async(
+          SseDeserializer deserializer);
+
+  @protected
+  TypedExceptionErrorTwinRustAsyncSse
+      sse_decode_typed_exception_error_twin_rust_async_sse(
+          SseDeserializer deserializer);
+
+  @protected
+  TypedExceptionErrorTwinSse sse_decode_typed_exception_error_twin_sse(
+      SseDeserializer deserializer);
+
+  @protected
+  TypedExceptionErrorTwinSync sse_decode_typed_exception_error_twin_sync(
+      SseDeserializer deserializer);
+
+  @protected
+  TypedExceptionErrorTwinSyncSse sse_decode_typed_exception_error_twin_sync_sse(
+      SseDeserializer deserializer);
+
   @protected
   int sse_decode_u_16(SseDeserializer deserializer);
 
@@ -19839,11 +20809,19 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       SseDeserializer deserializer);
 
   @protected
-  String cst_encode_AnyhowException(AnyhowException raw) {
+  JSAny cst_encode_AnyhowException(AnyhowException raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
     throw UnimplementedError();
   }
 
+  @protected
+  int cst_encode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl(
+      DartImplementableTraitTwinNormalDartImpl raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl(
+        raw);
+  }
+
   @protected
   int cst_encode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMyNodeTwinNormal(
       MyNodeTwinNormal raw) {
@@ -19924,12 +20902,52 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
         raw);
   }
 
+  @protected
+  int cst_encode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+      StructWithDartImplementableTraitTwinNormal raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+        raw);
+  }
+
+  @protected
+  JSAny cst_encode_BTreeMap_String_my_size(Map<String, MySize> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_list_record_string_my_size(
+        raw.entries.map((e) => (e.key, e.value)).toList());
+  }
+
+  @protected
+  JSAny cst_encode_BTreeMap_i_32_i_32(Map<int, int> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_list_record_i_32_i_32(
+        raw.entries.map((e) => (e.key, e.value)).toList());
+  }
+
+  @protected
+  JSAny cst_encode_BTreeSet_String(Set<String> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_list_String(raw.toList());
+  }
+
+  @protected
+  JSAny cst_encode_BTreeSet_i_32(Set<int> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_list_prim_i_32_strict(Int32List.fromList(raw.toList()));
+  }
+
   @protected
   String cst_encode_Backtrace(String raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
     throw UnimplementedError();
   }
 
+  @protected
+  String cst_encode_BigInt(BigInt raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_String(raw.toString());
+  }
+
   @protected
   String cst_encode_Char(String raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -19942,6 +20960,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return cst_encode_i_64(BigInt.from(raw.inMilliseconds));
   }
 
+  @protected
+  String cst_encode_Chrono_FixedOffset(DateTimeWithOffset raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_String(raw.toString());
+  }
+
   @protected
   JSAny cst_encode_Chrono_Local(DateTime raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -19954,6 +20978,25 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return cst_encode_i_64(BigInt.from(raw.millisecondsSinceEpoch));
   }
 
+  @protected
+  JSAny cst_encode_Chrono_NaiveDate(DateTime raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_i_64(BigInt.from(
+        DateTime.utc(raw.year, raw.month, raw.day).millisecondsSinceEpoch));
+  }
+
+  @protected
+  JSAny cst_encode_Chrono_NaiveTime(Duration raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_i_64(BigInt.from(raw.inMilliseconds));
+  }
+
+  @protected
+  String cst_encode_Chrono_Tz(DateTimeWithOffset raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_String(raw.toString());
+  }
+
   @protected
   JSAny cst_encode_Chrono_Utc(DateTime raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -19966,12 +21009,44 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return cst_encode_list_DartOpaque(raw);
   }
 
+  @protected
+  PlatformPointer cst_encode_DartStream_String(Stream<String> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_DartFn_Inputs_bool_Output_opt_String_AnyhowException(
+        encodeDartStream(raw));
+  }
+
+  @protected
+  PlatformPointer cst_encode_DartStream_i_32(Stream<int> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_DartFn_Inputs_bool_Output_opt_box_autoadd_i_32_AnyhowException(
+        encodeDartStream(raw));
+  }
+
+  @protected
+  String cst_encode_Decimal(Decimal raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_String(raw.toString());
+  }
+
   @protected
   String cst_encode_I128(BigInt raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
     return cst_encode_String(raw.toString());
   }
 
+  @protected
+  String cst_encode_JsonMap(Map<String, dynamic> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_String(jsonEncode(raw));
+  }
+
+  @protected
+  String cst_encode_JsonValue(Object? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_String(jsonEncode(raw));
+  }
+
   @protected
   JSAny cst_encode_Map_String_String(Map<String, String> raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -20306,6 +21381,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return cst_encode_list_prim_i_32_strict(Int32List.fromList(raw.toList()));
   }
 
+  @protected
+  JSAny cst_encode_StdDuration(Duration raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    if (raw.isNegative)
+      throw ArgumentError.value(
+          raw, null, 'std::time::Duration cannot be negative');
+    return cst_encode_i_64(BigInt.from(raw.inMilliseconds));
+  }
+
+  @protected
+  JSAny cst_encode_StdSystemTime(DateTime raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_i_64(BigInt.from(raw.millisecondsSinceEpoch));
+  }
+
   @protected
   String
       cst_encode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinMoi_Dco(
@@ -20663,6 +21753,31 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return raw;
   }
 
+  @protected
+  JSAny cst_encode_TimeDate(DateTime raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_i_64(BigInt.from(
+        DateTime.utc(raw.year, raw.month, raw.day).millisecondsSinceEpoch));
+  }
+
+  @protected
+  JSAny cst_encode_TimeDuration(Duration raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_i_64(BigInt.from(raw.inMilliseconds));
+  }
+
+  @protected
+  JSAny cst_encode_TimeOffsetDateTime(DateTime raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_i_64(BigInt.from(raw.millisecondsSinceEpoch));
+  }
+
+  @protected
+  JSAny cst_encode_TimePrimitiveDateTime(DateTime raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_i_64(BigInt.from(raw.millisecondsSinceEpoch));
+  }
+
   @protected
   String cst_encode_U128(BigInt raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -20675,6 +21790,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return cst_encode_list_prim_u_8_strict(raw.toBytes());
   }
 
+  @protected
+  JSAny cst_encode_VecDeque_String(List<String> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_list_String(raw);
+  }
+
+  @protected
+  JSAny cst_encode_VecDeque_i_32(Int32List raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_list_prim_i_32_strict(raw);
+  }
+
   @protected
   JSAny cst_encode_a_twin_normal(ATwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -21062,6 +22189,36 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     ].jsify()!;
   }
 
+  @protected
+  JSAny cst_encode_big_numbers_twin_normal(BigNumbersTwinNormal raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_Decimal(raw.amount),
+      cst_encode_opt_Decimal(raw.discount),
+      cst_encode_BigInt(raw.serial)
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_big_numbers_twin_rust_async(BigNumbersTwinRustAsync raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_Decimal(raw.amount),
+      cst_encode_opt_Decimal(raw.discount),
+      cst_encode_BigInt(raw.serial)
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_big_numbers_twin_sync(BigNumbersTwinSync raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_Decimal(raw.amount),
+      cst_encode_opt_Decimal(raw.discount),
+      cst_encode_BigInt(raw.serial)
+    ].jsify()!;
+  }
+
   @protected
   JSAny cst_encode_blob_twin_normal(BlobTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -21146,6 +22303,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return cst_encode_Chrono_Naive(raw);
   }
 
+  @protected
+  JSAny cst_encode_box_autoadd_Chrono_NaiveTime(Duration raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_Chrono_NaiveTime(raw);
+  }
+
   @protected
   JSAny cst_encode_box_autoadd_Chrono_Utc(DateTime raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -21212,6 +22375,36 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return cst_encode_RustOpaque_HideDataTwinSyncMoi(raw);
   }
 
+  @protected
+  JSAny cst_encode_box_autoadd_StdDuration(Duration raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_StdDuration(raw);
+  }
+
+  @protected
+  JSAny cst_encode_box_autoadd_StdSystemTime(DateTime raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_StdSystemTime(raw);
+  }
+
+  @protected
+  JSAny cst_encode_box_autoadd_TimeDuration(Duration raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_TimeDuration(raw);
+  }
+
+  @protected
+  JSAny cst_encode_box_autoadd_TimeOffsetDateTime(DateTime raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_TimeOffsetDateTime(raw);
+  }
+
+  @protected
+  JSAny cst_encode_box_autoadd_TimePrimitiveDateTime(DateTime raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_TimePrimitiveDateTime(raw);
+  }
+
   @protected
   JSAny cst_encode_box_autoadd_a_twin_normal(ATwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -21416,6 +22609,26 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return cst_encode_benchmark_blob_twin_sync(raw);
   }
 
+  @protected
+  JSAny cst_encode_box_autoadd_big_numbers_twin_normal(
+      BigNumbersTwinNormal raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_big_numbers_twin_normal(raw);
+  }
+
+  @protected
+  JSAny cst_encode_box_autoadd_big_numbers_twin_rust_async(
+      BigNumbersTwinRustAsync raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_big_numbers_twin_rust_async(raw);
+  }
+
+  @protected
+  JSAny cst_encode_box_autoadd_big_numbers_twin_sync(BigNumbersTwinSync raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_big_numbers_twin_sync(raw);
+  }
+
   @protected
   bool cst_encode_box_autoadd_bool(bool raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -21586,6 +22799,13 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return cst_encode_customized_twin_sync(raw);
   }
 
+  @protected
+  JSAny cst_encode_box_autoadd_dart_implementable_trait_twin_normal_implementor(
+      DartImplementableTraitTwinNormalImplementor raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_dart_implementable_trait_twin_normal_implementor(raw);
+  }
+
   @protected
   JSAny cst_encode_box_autoadd_dart_opaque_nested_twin_normal(
       DartOpaqueNestedTwinNormal raw) {
@@ -21952,6 +23172,27 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return cst_encode_item_container_solution_two_twin_normal(raw);
   }
 
+  @protected
+  JSAny cst_encode_box_autoadd_json_document_twin_normal(
+      JsonDocumentTwinNormal raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_json_document_twin_normal(raw);
+  }
+
+  @protected
+  JSAny cst_encode_box_autoadd_json_document_twin_rust_async(
+      JsonDocumentTwinRustAsync raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_json_document_twin_rust_async(raw);
+  }
+
+  @protected
+  JSAny cst_encode_box_autoadd_json_document_twin_sync(
+      JsonDocumentTwinSync raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_json_document_twin_sync(raw);
+  }
+
   @protected
   JSAny cst_encode_box_autoadd_kitchen_sink_twin_normal(
       KitchenSinkTwinNormal raw) {
@@ -22239,6 +23480,25 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return cst_encode_record_string_i_32(raw);
   }
 
+  @protected
+  JSAny cst_encode_box_autoadd_schedule_twin_normal(ScheduleTwinNormal raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_schedule_twin_normal(raw);
+  }
+
+  @protected
+  JSAny cst_encode_box_autoadd_schedule_twin_rust_async(
+      ScheduleTwinRustAsync raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_schedule_twin_rust_async(raw);
+  }
+
+  @protected
+  JSAny cst_encode_box_autoadd_schedule_twin_sync(ScheduleTwinSync raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_schedule_twin_sync(raw);
+  }
+
   @protected
   JSAny cst_encode_box_autoadd_sequences(Sequences raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -22341,6 +23601,25 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return cst_encode_some_struct_twin_sync(raw);
   }
 
+  @protected
+  JSAny cst_encode_box_autoadd_std_time_twin_normal(StdTimeTwinNormal raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_std_time_twin_normal(raw);
+  }
+
+  @protected
+  JSAny cst_encode_box_autoadd_std_time_twin_rust_async(
+      StdTimeTwinRustAsync raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_std_time_twin_rust_async(raw);
+  }
+
+  @protected
+  JSAny cst_encode_box_autoadd_std_time_twin_sync(StdTimeTwinSync raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_std_time_twin_sync(raw);
+  }
+
   @protected
   JSAny cst_encode_box_autoadd_struct_in_lower_level(StructInLowerLevel raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -22640,6 +23919,25 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return cst_encode_test_id_twin_sync(raw);
   }
 
+  @protected
+  JSAny cst_encode_box_autoadd_time_types_twin_normal(TimeTypesTwinNormal raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_time_types_twin_normal(raw);
+  }
+
+  @protected
+  JSAny cst_encode_box_autoadd_time_types_twin_rust_async(
+      TimeTypesTwinRustAsync raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_time_types_twin_rust_async(raw);
+  }
+
+  @protected
+  JSAny cst_encode_box_autoadd_time_types_twin_sync(TimeTypesTwinSync raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_time_types_twin_sync(raw);
+  }
+
   @protected
   JSAny cst_encode_box_autoadd_translatable_struct_with_dart_code_twin_normal(
       TranslatableStructWithDartCodeTwinNormal raw) {
@@ -23427,6 +24725,28 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     ].jsify()!;
   }
 
+  @protected
+  JSAny cst_encode_dart_implementable_trait_twin_normal_implementor(
+      DartImplementableTraitTwinNormalImplementor raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    if (raw is DartImplementableTraitTwinNormalImplementor_Variant0) {
+      return [
+        0,
+        cst_encode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl(
+            raw.field0)
+      ].jsify()!;
+    }
+    if (raw is DartImplementableTraitTwinNormalImplementor_Variant1) {
+      return [
+        1,
+        cst_encode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+            raw.field0)
+      ].jsify()!;
+    }
+
+    throw Exception('unreachable');
+  }
+
   @protected
   JSAny cst_encode_dart_opaque_nested_twin_normal(
       DartOpaqueNestedTwinNormal raw) {
@@ -24283,6 +25603,37 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     ].jsify()!;
   }
 
+  @protected
+  JSAny cst_encode_json_document_twin_normal(JsonDocumentTwinNormal raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_JsonValue(raw.body),
+      cst_encode_JsonMap(raw.meta),
+      cst_encode_opt_JsonValue(raw.extra)
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_json_document_twin_rust_async(
+      JsonDocumentTwinRustAsync raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_JsonValue(raw.body),
+      cst_encode_JsonMap(raw.meta),
+      cst_encode_opt_JsonValue(raw.extra)
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_json_document_twin_sync(JsonDocumentTwinSync raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_JsonValue(raw.body),
+      cst_encode_JsonMap(raw.meta),
+      cst_encode_opt_JsonValue(raw.extra)
+    ].jsify()!;
+  }
+
   @protected
   JSAny cst_encode_kitchen_sink_twin_normal(KitchenSinkTwinNormal raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -24493,12 +25844,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
         .jsify()!;
   }
 
+  @protected
+  JSAny cst_encode_list_BigInt(List<BigInt> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw.map(cst_encode_BigInt).toList().jsify()!;
+  }
+
   @protected
   JSAny cst_encode_list_Chrono_Duration(List<Duration> raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
     return raw.map(cst_encode_Chrono_Duration).toList().jsify()!;
   }
 
+  @protected
+  JSAny cst_encode_list_Chrono_FixedOffset(List<DateTimeWithOffset> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw.map(cst_encode_Chrono_FixedOffset).toList().jsify()!;
+  }
+
   @protected
   JSAny cst_encode_list_Chrono_Local(List<DateTime> raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -24511,12 +25874,30 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return raw.map(cst_encode_Chrono_Naive).toList().jsify()!;
   }
 
+  @protected
+  JSAny cst_encode_list_Chrono_NaiveDate(List<DateTime> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw.map(cst_encode_Chrono_NaiveDate).toList().jsify()!;
+  }
+
   @protected
   JSAny cst_encode_list_DartOpaque(List<Object> raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
     return raw.map(cst_encode_DartOpaque).toList().jsify()!;
   }
 
+  @protected
+  JSAny cst_encode_list_Decimal(List<Decimal> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw.map(cst_encode_Decimal).toList().jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_list_JsonValue(List<Object?> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw.map(cst_encode_JsonValue).toList().jsify()!;
+  }
+
   @protected
   JSAny cst_encode_list_RustOpaque_HideDataTwinMoi(List<HideDataTwinMoi> raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -24564,6 +25945,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return raw.map(cst_encode_RustOpaque_HideDataTwinSyncMoi).toList().jsify()!;
   }
 
+  @protected
+  JSAny cst_encode_list_StdDuration(List<Duration> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw.map(cst_encode_StdDuration).toList().jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_list_StdSystemTime(List<DateTime> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw.map(cst_encode_StdSystemTime).toList().jsify()!;
+  }
+
   @protected
   JSAny cst_encode_list_StreamSink_i_32_Dco(List<RustStreamSink<int>> raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -24576,6 +25969,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return raw.map(cst_encode_String).toList().jsify()!;
   }
 
+  @protected
+  JSAny cst_encode_list_TimeDuration(List<Duration> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw.map(cst_encode_TimeDuration).toList().jsify()!;
+  }
+
   @protected
   JSAny cst_encode_list_Uuid(List<UuidValue> raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -25855,12 +27254,36 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     ].jsify()!;
   }
 
+  @protected
+  String? cst_encode_opt_BigInt(BigInt? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? null : cst_encode_BigInt(raw);
+  }
+
+  @protected
+  String? cst_encode_opt_Chrono_FixedOffset(DateTimeWithOffset? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? null : cst_encode_Chrono_FixedOffset(raw);
+  }
+
+  @protected
+  String? cst_encode_opt_Decimal(Decimal? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? null : cst_encode_Decimal(raw);
+  }
+
   @protected
   String? cst_encode_opt_I128(BigInt? raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
     return raw == null ? null : cst_encode_I128(raw);
   }
 
+  @protected
+  String? cst_encode_opt_JsonValue(Object? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? null : cst_encode_JsonValue(raw);
+  }
+
   @protected
   String? cst_encode_opt_String(String? raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -25951,6 +27374,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return raw == null ? null : cst_encode_box_autoadd_Chrono_Naive(raw);
   }
 
+  @protected
+  JSAny? cst_encode_opt_box_autoadd_Chrono_NaiveTime(Duration? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? null : cst_encode_box_autoadd_Chrono_NaiveTime(raw);
+  }
+
   @protected
   JSAny? cst_encode_opt_box_autoadd_Chrono_Utc(DateTime? raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -26035,6 +27464,39 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
         : cst_encode_box_autoadd_RustOpaque_HideDataTwinSyncMoi(raw);
   }
 
+  @protected
+  JSAny? cst_encode_opt_box_autoadd_StdDuration(Duration? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? null : cst_encode_box_autoadd_StdDuration(raw);
+  }
+
+  @protected
+  JSAny? cst_encode_opt_box_autoadd_StdSystemTime(DateTime? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? null : cst_encode_box_autoadd_StdSystemTime(raw);
+  }
+
+  @protected
+  JSAny? cst_encode_opt_box_autoadd_TimeDuration(Duration? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? null : cst_encode_box_autoadd_TimeDuration(raw);
+  }
+
+  @protected
+  JSAny? cst_encode_opt_box_autoadd_TimeOffsetDateTime(DateTime? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? null : cst_encode_box_autoadd_TimeOffsetDateTime(raw);
+  }
+
+  @protected
+  JSAny? cst_encode_opt_box_autoadd_TimePrimitiveDateTime(
+      DateTime? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null
+        ? null
+        : cst_encode_box_autoadd_TimePrimitiveDateTime(raw);
+  }
+
   @protected
   JSAny? cst_encode_opt_box_autoadd_application_env(ApplicationEnv? raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -27080,6 +28542,39 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
         .jsify()!;
   }
 
+  @protected
+  JSAny cst_encode_schedule_twin_normal(ScheduleTwinNormal raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_Chrono_NaiveDate(raw.day),
+      cst_encode_Chrono_NaiveTime(raw.starts),
+      cst_encode_opt_box_autoadd_Chrono_NaiveTime(raw.ends),
+      cst_encode_Chrono_FixedOffset(raw.at)
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_schedule_twin_rust_async(ScheduleTwinRustAsync raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_Chrono_NaiveDate(raw.day),
+      cst_encode_Chrono_NaiveTime(raw.starts),
+      cst_encode_opt_box_autoadd_Chrono_NaiveTime(raw.ends),
+      cst_encode_Chrono_FixedOffset(raw.at)
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_schedule_twin_sync(ScheduleTwinSync raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_Chrono_NaiveDate(raw.day),
+      cst_encode_Chrono_NaiveTime(raw.starts),
+      cst_encode_opt_box_autoadd_Chrono_NaiveTime(raw.ends),
+      cst_encode_Chrono_FixedOffset(raw.at)
+    ].jsify()!;
+  }
+
   @protected
   JSAny cst_encode_sequences(Sequences raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -27269,6 +28764,36 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return [cst_encode_String(raw.one)].jsify()!;
   }
 
+  @protected
+  JSAny cst_encode_std_time_twin_normal(StdTimeTwinNormal raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_StdDuration(raw.duration),
+      cst_encode_StdSystemTime(raw.time),
+      cst_encode_opt_box_autoadd_StdDuration(raw.optionalDuration)
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_std_time_twin_rust_async(StdTimeTwinRustAsync raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_StdDuration(raw.duration),
+      cst_encode_StdSystemTime(raw.time),
+      cst_encode_opt_box_autoadd_StdDuration(raw.optionalDuration)
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_std_time_twin_sync(StdTimeTwinSync raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_StdDuration(raw.duration),
+      cst_encode_StdSystemTime(raw.time),
+      cst_encode_opt_box_autoadd_StdDuration(raw.optionalDuration)
+    ].jsify()!;
+  }
+
   @protected
   JSAny cst_encode_struct_in_lower_level(StructInLowerLevel raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -27765,6 +29290,39 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     ].jsify()!;
   }
 
+  @protected
+  JSAny cst_encode_time_types_twin_normal(TimeTypesTwinNormal raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_TimeOffsetDateTime(raw.offset),
+      cst_encode_opt_box_autoadd_TimePrimitiveDateTime(raw.primitive),
+      cst_encode_TimeDate(raw.date),
+      cst_encode_TimeDuration(raw.duration)
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_time_types_twin_rust_async(TimeTypesTwinRustAsync raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_TimeOffsetDateTime(raw.offset),
+      cst_encode_opt_box_autoadd_TimePrimitiveDateTime(raw.primitive),
+      cst_encode_TimeDate(raw.date),
+      cst_encode_TimeDuration(raw.duration)
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_time_types_twin_sync(TimeTypesTwinSync raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_TimeOffsetDateTime(raw.offset),
+      cst_encode_opt_box_autoadd_TimePrimitiveDateTime(raw.primitive),
+      cst_encode_TimeDate(raw.date),
+      cst_encode_TimeDuration(raw.duration)
+    ].jsify()!;
+  }
+
   @protected
   JSAny cst_encode_translatable_struct_with_dart_code_twin_normal(
       TranslatableStructWithDartCodeTwinNormal raw) {
@@ -27814,6 +29372,57 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return [cst_encode_i_32(raw.field0), cst_encode_i_32(raw.field1)].jsify()!;
   }
 
+  @protected
+  JSAny cst_encode_typed_exception_error_twin_normal(
+      TypedExceptionErrorTwinNormal raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    if (raw is TypedExceptionErrorTwinNormal_NotFound) {
+      return [0, cst_encode_String(raw.path)].jsify()!;
+    }
+    if (raw is TypedExceptionErrorTwinNormal_Io) {
+      return [1, cst_encode_String(raw.field0)].jsify()!;
+    }
+    if (raw is TypedExceptionErrorTwinNormal_Unknown) {
+      return [2].jsify()!;
+    }
+
+    throw Exception('unreachable');
+  }
+
+  @protected
+  JSAny cst_encode_typed_exception_error_twin_rust_async(
+      TypedExceptionErrorTwinRustAsync raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    if (raw is TypedExceptionErrorTwinRustAsync_NotFound) {
+      return [0, cst_encode_String(raw.path)].jsify()!;
+    }
+    if (raw is TypedExceptionErrorTwinRustAsync_Io) {
+      return [1, cst_encode_String(raw.field0)].jsify()!;
+    }
+    if (raw is TypedExceptionErrorTwinRustAsync_Unknown) {
+      return [2].jsify()!;
+    }
+
+    throw Exception('unreachable');
+  }
+
+  @protected
+  JSAny cst_encode_typed_exception_error_twin_sync(
+      TypedExceptionErrorTwinSync raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    if (raw is TypedExceptionErrorTwinSync_NotFound) {
+      return [0, cst_encode_String(raw.path)].jsify()!;
+    }
+    if (raw is TypedExceptionErrorTwinSync_Io) {
+      return [1, cst_encode_String(raw.field0)].jsify()!;
+    }
+    if (raw is TypedExceptionErrorTwinSync_Unknown) {
+      return [2].jsify()!;
+    }
+
+    throw Exception('unreachable');
+  }
+
   @protected
   JSAny cst_encode_u_64(BigInt raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -27971,6 +29580,10 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConstructorOpaqueSyncStructTwinNormal(
       ConstructorOpaqueSyncStructTwinNormal raw);
 
+  @protected
+  int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl(
+      DartImplementableTraitTwinNormalDartImpl raw);
+
   @protected
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeliberateFailSanityCheckTwinNormal(
       DeliberateFailSanityCheckTwinNormal raw);
@@ -28207,6 +29820,10 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitTwinSyncSse(
       StructTwoWithTraitTwinSyncSse raw);
 
+  @protected
+  int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+      StructWithDartImplementableTraitTwinNormal raw);
+
   @protected
   int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinMoi(
       StructWithGoodAndOpaqueFieldWithoutOptionTwinMoi raw);
@@ -28355,6 +29972,10 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   int cst_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitTwinSyncSse(
       StructTwoWithTraitTwinSyncSse raw);
 
+  @protected
+  int cst_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+      StructWithDartImplementableTraitTwinNormal raw);
+
   @protected
   int cst_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinMoi(
       StructWithGoodAndOpaqueFieldWithoutOptionTwinMoi raw);
@@ -28415,6 +30036,10 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConstructorOpaqueSyncStructTwinNormal(
       ConstructorOpaqueSyncStructTwinNormal raw);
 
+  @protected
+  int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl(
+      DartImplementableTraitTwinNormalDartImpl raw);
+
   @protected
   int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeliberateFailSanityCheckTwinNormal(
       DeliberateFailSanityCheckTwinNormal raw);
@@ -28563,6 +30188,10 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitTwinSyncSse(
       StructTwoWithTraitTwinSyncSse raw);
 
+  @protected
+  int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+      StructWithDartImplementableTraitTwinNormal raw);
+
   @protected
   int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinMoi(
       StructWithGoodAndOpaqueFieldWithoutOptionTwinMoi raw);
@@ -28657,6 +30286,16 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   PlatformPointer cst_encode_DartFn_Inputs__Output_unit_AnyhowException(
       FutureOr<void> Function() raw);
 
+  @protected
+  PlatformPointer
+      cst_encode_DartFn_Inputs_bool_Output_opt_String_AnyhowException(
+          FutureOr<String?> Function(bool) raw);
+
+  @protected
+  PlatformPointer
+      cst_encode_DartFn_Inputs_bool_Output_opt_box_autoadd_i_32_AnyhowException(
+          FutureOr<int?> Function(bool) raw);
+
   @protected
   PlatformPointer
       cst_encode_DartFn_Inputs_demo_struct_for_rust_call_dart_twin_normal_Output_demo_struct_for_rust_call_dart_twin_normal_AnyhowException(
@@ -28671,6 +30310,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
                   DemoStructForRustCallDartTwinRustAsync)
               raw);
 
+  @protected
+  PlatformPointer cst_encode_DartFn_Inputs_i_32_Output_String_AnyhowException(
+      FutureOr<String> Function(int) raw);
+
+  @protected
+  PlatformPointer
+      cst_encode_DartFn_Inputs_i_32_i_32_Output_i_32_AnyhowException(
+          FutureOr<int> Function(int, int) raw);
+
   @protected
   PlatformPointer cst_encode_DartOpaque(Object raw);
 
@@ -28858,6 +30506,10 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConstructorOpaqueSyncStructTwinNormal(
       ConstructorOpaqueSyncStructTwinNormal raw);
 
+  @protected
+  int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl(
+      DartImplementableTraitTwinNormalDartImpl raw);
+
   @protected
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeliberateFailSanityCheckTwinNormal(
       DeliberateFailSanityCheckTwinNormal raw);
@@ -29098,6 +30750,10 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitTwinSyncSse(
       StructTwoWithTraitTwinSyncSse raw);
 
+  @protected
+  int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+      StructWithDartImplementableTraitTwinNormal raw);
+
   @protected
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinMoi(
       StructWithGoodAndOpaqueFieldWithoutOptionTwinMoi raw);
@@ -29253,6 +30909,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_AnyhowException(
       AnyhowException self, SseSerializer serializer);
 
+  @protected
+  void
+      sse_encode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl(
+          DartImplementableTraitTwinNormalDartImpl self,
+          SseSerializer serializer);
+
   @protected
   void
       sse_encode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLifetimeableLtNestedTypeWithLifetimeTwinNormalstatic(
@@ -29363,6 +31025,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       sse_encode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitForDynTwinNormal(
           StructTwoWithTraitForDynTwinNormal self, SseSerializer serializer);
 
+  @protected
+  void
+      sse_encode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+          StructWithDartImplementableTraitTwinNormal self,
+          SseSerializer serializer);
+
   @protected
   void
       sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynAnySendSyncstatic(
@@ -29443,6 +31111,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConstructorOpaqueSyncStructTwinNormal(
           ConstructorOpaqueSyncStructTwinNormal self, SseSerializer serializer);
 
+  @protected
+  void
+      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl(
+          DartImplementableTraitTwinNormalDartImpl self,
+          SseSerializer serializer);
+
   @protected
   void
       sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeliberateFailSanityCheckTwinNormal(
@@ -29960,6 +31634,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitTwinSyncSse(
           StructTwoWithTraitTwinSyncSse self, SseSerializer serializer);
 
+  @protected
+  void
+      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+          StructWithDartImplementableTraitTwinNormal self,
+          SseSerializer serializer);
+
   @protected
   void
       sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinMoi(
@@ -30212,6 +31892,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitTwinSyncSse(
           StructTwoWithTraitTwinSyncSse self, SseSerializer serializer);
 
+  @protected
+  void
+      sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+          StructWithDartImplementableTraitTwinNormal self,
+          SseSerializer serializer);
+
   @protected
   void
       sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinMoi(
@@ -30299,6 +31985,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConstructorOpaqueSyncStructTwinNormal(
           ConstructorOpaqueSyncStructTwinNormal self, SseSerializer serializer);
 
+  @protected
+  void
+      sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl(
+          DartImplementableTraitTwinNormalDartImpl self,
+          SseSerializer serializer);
+
   @protected
   void
       sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeliberateFailSanityCheckTwinNormal(
@@ -30589,6 +32281,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitTwinSyncSse(
           StructTwoWithTraitTwinSyncSse self, SseSerializer serializer);
 
+  @protected
+  void
+      sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+          StructWithDartImplementableTraitTwinNormal self,
+          SseSerializer serializer);
+
   @protected
   void
       sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinMoi(
@@ -30671,9 +32369,26 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithSimpleSetterTwinNormal(
           StructWithSimpleSetterTwinNormal self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_BTreeMap_String_my_size(
+      Map<String, MySize> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_BTreeMap_i_32_i_32(
+      Map<int, int> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_BTreeSet_String(Set<String> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_BTreeSet_i_32(Set<int> self, SseSerializer serializer);
+
   @protected
   void sse_encode_Backtrace(String self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_BigInt(BigInt self, SseSerializer serializer);
+
   @protected
   void sse_encode_CastedPrimitive_i_64(int self, SseSerializer serializer);
 
@@ -30692,12 +32407,25 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_Chrono_Duration(Duration self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_Chrono_FixedOffset(
+      DateTimeWithOffset self, SseSerializer serializer);
+
   @protected
   void sse_encode_Chrono_Local(DateTime self, SseSerializer serializer);
 
   @protected
   void sse_encode_Chrono_Naive(DateTime self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_Chrono_NaiveDate(DateTime self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_Chrono_NaiveTime(Duration self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_Chrono_Tz(DateTimeWithOffset self, SseSerializer serializer);
+
   @protected
   void sse_encode_Chrono_Utc(DateTime self, SseSerializer serializer);
 
@@ -30760,6 +32488,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_DartFn_Inputs__Output_unit_AnyhowException(
       FutureOr<void> Function() self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_DartFn_Inputs_bool_Output_opt_String_AnyhowException(
+      FutureOr<String?> Function(bool) self, SseSerializer serializer);
+
+  @protected
+  void
+      sse_encode_DartFn_Inputs_bool_Output_opt_box_autoadd_i_32_AnyhowException(
+          FutureOr<int?> Function(bool) self, SseSerializer serializer);
+
   @protected
   void
       sse_encode_DartFn_Inputs_demo_struct_for_rust_call_dart_twin_normal_Output_demo_struct_for_rust_call_dart_twin_normal_AnyhowException(
@@ -30792,6 +32529,14 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
               self,
           SseSerializer serializer);
 
+  @protected
+  void sse_encode_DartFn_Inputs_i_32_Output_String_AnyhowException(
+      FutureOr<String> Function(int) self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_DartFn_Inputs_i_32_i_32_Output_i_32_AnyhowException(
+      FutureOr<int> Function(int, int) self, SseSerializer serializer);
+
   @protected
   void sse_encode_DartOpaque(Object self, SseSerializer serializer);
 
@@ -30799,6 +32544,16 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_DartOpaque_array_1(
       ObjectArray1 self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_DartStream_String(
+      Stream<String> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_DartStream_i_32(Stream<int> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_Decimal(Decimal self, SseSerializer serializer);
+
   @protected
   void sse_encode_DynTrait_SimpleTraitForDynTwinNormal(
       SimpleTraitForDynTwinNormal self, SseSerializer serializer);
@@ -30806,6 +32561,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_I128(BigInt self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_JsonMap(Map<String, dynamic> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_JsonValue(Object? self, SseSerializer serializer);
+
   @protected
   void
       sse_encode_Lifetimeable_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLtNestedTypeWithLifetimeTwinNormalstatic(
@@ -31532,6 +33293,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConstructorOpaqueSyncStructTwinNormal(
           ConstructorOpaqueSyncStructTwinNormal self, SseSerializer serializer);
 
+  @protected
+  void
+      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl(
+          DartImplementableTraitTwinNormalDartImpl self,
+          SseSerializer serializer);
+
   @protected
   void
       sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeliberateFailSanityCheckTwinNormal(
@@ -32079,6 +33846,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitTwinSyncSse(
           StructTwoWithTraitTwinSyncSse self, SseSerializer serializer);
 
+  @protected
+  void
+      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+          StructWithDartImplementableTraitTwinNormal self,
+          SseSerializer serializer);
+
   @protected
   void
       sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinMoi(
@@ -32177,6 +33950,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_Set_i_32(Set<int> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_StdDuration(Duration self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_StdSystemTime(DateTime self, SseSerializer serializer);
+
   @protected
   void
       sse_encode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinMoi_Dco(
@@ -32457,12 +34236,31 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_String(String self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_TimeDate(DateTime self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_TimeDuration(Duration self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_TimeOffsetDateTime(DateTime self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_TimePrimitiveDateTime(
+      DateTime self, SseSerializer serializer);
+
   @protected
   void sse_encode_U128(BigInt self, SseSerializer serializer);
 
   @protected
   void sse_encode_Uuid(UuidValue self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_VecDeque_String(List<String> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_VecDeque_i_32(Int32List self, SseSerializer serializer);
+
   @protected
   void sse_encode_a_twin_normal(ATwinNormal self, SseSerializer serializer);
 
@@ -32750,6 +34548,30 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_big_buffers_twin_sync_sse(
       BigBuffersTwinSyncSse self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_big_numbers_twin_normal(
+      BigNumbersTwinNormal self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_big_numbers_twin_rust_async(
+      BigNumbersTwinRustAsync self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_big_numbers_twin_rust_async_sse(
+      BigNumbersTwinRustAsyncSse self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_big_numbers_twin_sse(
+      BigNumbersTwinSse self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_big_numbers_twin_sync(
+      BigNumbersTwinSync self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_big_numbers_twin_sync_sse(
+      BigNumbersTwinSyncSse self, SseSerializer serializer);
+
   @protected
   void sse_encode_blob_twin_normal(
       BlobTwinNormal self, SseSerializer serializer);
@@ -32775,6 +34597,10 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_bool(bool self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_DynTrait_DartImplementableTraitTwinNormal(
+      DartImplementableTraitTwinNormal self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_application_env(
       ApplicationEnv self, SseSerializer serializer);
@@ -32847,6 +34673,10 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_box_autoadd_Chrono_Naive(
       DateTime self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_Chrono_NaiveTime(
+      Duration self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_Chrono_Utc(
       DateTime self, SseSerializer serializer);
@@ -32918,6 +34748,26 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_box_autoadd_RustOpaque_HideDataTwinSyncSseMoi(
       HideDataTwinSyncSseMoi self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_StdDuration(
+      Duration self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_StdSystemTime(
+      DateTime self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_TimeDuration(
+      Duration self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_TimeOffsetDateTime(
+      DateTime self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_TimePrimitiveDateTime(
+      DateTime self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_a_twin_normal(
       ATwinNormal self, SseSerializer serializer);
@@ -33152,6 +35002,30 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_box_autoadd_benchmark_blob_twin_sync_sse(
       BenchmarkBlobTwinSyncSse self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_big_numbers_twin_normal(
+      BigNumbersTwinNormal self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_big_numbers_twin_rust_async(
+      BigNumbersTwinRustAsync self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_big_numbers_twin_rust_async_sse(
+      BigNumbersTwinRustAsyncSse self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_big_numbers_twin_sse(
+      BigNumbersTwinSse self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_big_numbers_twin_sync(
+      BigNumbersTwinSync self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_big_numbers_twin_sync_sse(
+      BigNumbersTwinSyncSse self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer);
 
@@ -33347,6 +35221,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_box_autoadd_customized_twin_sync_sse(
       CustomizedTwinSyncSse self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_dart_implementable_trait_twin_normal_implementor(
+      DartImplementableTraitTwinNormalImplementor self,
+      SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_dart_opaque_nested_twin_normal(
       DartOpaqueNestedTwinNormal self, SseSerializer serializer);
@@ -33750,6 +35629,30 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_box_autoadd_item_container_solution_two_twin_normal(
       ItemContainerSolutionTwoTwinNormal self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_json_document_twin_normal(
+      JsonDocumentTwinNormal self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_json_document_twin_rust_async(
+      JsonDocumentTwinRustAsync self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_json_document_twin_rust_async_sse(
+      JsonDocumentTwinRustAsyncSse self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_json_document_twin_sse(
+      JsonDocumentTwinSse self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_json_document_twin_sync(
+      JsonDocumentTwinSync self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_json_document_twin_sync_sse(
+      JsonDocumentTwinSyncSse self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_kitchen_sink_twin_normal(
       KitchenSinkTwinNormal self, SseSerializer serializer);
@@ -34066,6 +35969,30 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_box_autoadd_record_string_i_32(
       (String, int) self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_schedule_twin_normal(
+      ScheduleTwinNormal self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_schedule_twin_rust_async(
+      ScheduleTwinRustAsync self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_schedule_twin_rust_async_sse(
+      ScheduleTwinRustAsyncSse self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_schedule_twin_sse(
+      ScheduleTwinSse self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_schedule_twin_sync(
+      ScheduleTwinSync self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_schedule_twin_sync_sse(
+      ScheduleTwinSyncSse self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_sequences(
       Sequences self, SseSerializer serializer);
@@ -34175,6 +36102,30 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_box_autoadd_some_struct_twin_sync_sse(
       SomeStructTwinSyncSse self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_std_time_twin_normal(
+      StdTimeTwinNormal self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_std_time_twin_rust_async(
+      StdTimeTwinRustAsync self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_std_time_twin_rust_async_sse(
+      StdTimeTwinRustAsyncSse self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_std_time_twin_sse(
+      StdTimeTwinSse self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_std_time_twin_sync(
+      StdTimeTwinSync self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_std_time_twin_sync_sse(
+      StdTimeTwinSyncSse self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_struct_in_lower_level(
       StructInLowerLevel self, SseSerializer serializer);
@@ -34532,6 +36483,30 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_box_autoadd_test_id_twin_sync_sse(
       TestIdTwinSyncSse self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_time_types_twin_normal(
+      TimeTypesTwinNormal self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_time_types_twin_rust_async(
+      TimeTypesTwinRustAsync self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_time_types_twin_rust_async_sse(
+      TimeTypesTwinRustAsyncSse self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_time_types_twin_sse(
+      TimeTypesTwinSse self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_time_types_twin_sync(
+      TimeTypesTwinSync self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_time_types_twin_sync_sse(
+      TimeTypesTwinSyncSse self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_translatable_struct_with_dart_code_twin_normal(
       TranslatableStructWithDartCodeTwinNormal self, SseSerializer serializer);
@@ -35183,6 +37158,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_customized_twin_sync_sse(
       CustomizedTwinSyncSse self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_dart_implementable_trait_twin_normal_implementor(
+      DartImplementableTraitTwinNormalImplementor self,
+      SseSerializer serializer);
+
   @protected
   void sse_encode_dart_opaque_nested_twin_normal(
       DartOpaqueNestedTwinNormal self, SseSerializer serializer);
@@ -35729,6 +37709,30 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_item_container_solution_two_twin_normal(
       ItemContainerSolutionTwoTwinNormal self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_json_document_twin_normal(
+      JsonDocumentTwinNormal self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_json_document_twin_rust_async(
+      JsonDocumentTwinRustAsync self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_json_document_twin_rust_async_sse(
+      JsonDocumentTwinRustAsyncSse self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_json_document_twin_sse(
+      JsonDocumentTwinSse self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_json_document_twin_sync(
+      JsonDocumentTwinSync self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_json_document_twin_sync_sse(
+      JsonDocumentTwinSyncSse self, SseSerializer serializer);
+
   @protected
   void sse_encode_kitchen_sink_twin_normal(
       KitchenSinkTwinNormal self, SseSerializer serializer);
@@ -35824,10 +37828,17 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpaqueItemTwinNormal(
           List<OpaqueItemTwinNormal> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_BigInt(List<BigInt> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_Chrono_Duration(
       List<Duration> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_Chrono_FixedOffset(
+      List<DateTimeWithOffset> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_Chrono_Local(
       List<DateTime> self, SseSerializer serializer);
@@ -35836,9 +37847,23 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_list_Chrono_Naive(
       List<DateTime> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_Chrono_NaiveDate(
+      List<DateTime> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_Chrono_Tz(
+      List<DateTimeWithOffset> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_DartOpaque(List<Object> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_Decimal(List<Decimal> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_JsonValue(List<Object?> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_RustOpaque_HideDataTwinMoi(
       List<HideDataTwinMoi> self, SseSerializer serializer);
@@ -35887,6 +37912,14 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_list_RustOpaque_HideDataTwinSyncSseMoi(
       List<HideDataTwinSyncSseMoi> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_StdDuration(
+      List<Duration> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_StdSystemTime(
+      List<DateTime> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_StreamSink_i_32_Dco(
       List<RustStreamSink<int>> self, SseSerializer serializer);
@@ -35898,6 +37931,10 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_list_String(List<String> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_TimeDuration(
+      List<Duration> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_Uuid(List<UuidValue> self, SseSerializer serializer);
 
@@ -36965,9 +39002,26 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_opaque_nested_twin_sync_sse_moi(
       OpaqueNestedTwinSyncSseMoi self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_BigInt(BigInt? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_Chrono_FixedOffset(
+      DateTimeWithOffset? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_Chrono_Tz(
+      DateTimeWithOffset? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_Decimal(Decimal? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_I128(BigInt? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_JsonValue(Object? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_String(String? self, SseSerializer serializer);
 
@@ -37042,6 +39096,10 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_opt_box_autoadd_Chrono_Naive(
       DateTime? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_Chrono_NaiveTime(
+      Duration? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_box_autoadd_Chrono_Utc(
       DateTime? self, SseSerializer serializer);
@@ -37114,6 +39172,26 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_opt_box_autoadd_RustOpaque_HideDataTwinSyncSseMoi(
       HideDataTwinSyncSseMoi? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_StdDuration(
+      Duration? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_StdSystemTime(
+      DateTime? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_TimeDuration(
+      Duration? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_TimeOffsetDateTime(
+      DateTime? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_TimePrimitiveDateTime(
+      DateTime? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_box_autoadd_application_env(
       ApplicationEnv? self, SseSerializer serializer);
@@ -37923,6 +40001,30 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_record_u_8_application_mode(
       (int, ApplicationMode) self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_schedule_twin_normal(
+      ScheduleTwinNormal self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_schedule_twin_rust_async(
+      ScheduleTwinRustAsync self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_schedule_twin_rust_async_sse(
+      ScheduleTwinRustAsyncSse self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_schedule_twin_sse(
+      ScheduleTwinSse self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_schedule_twin_sync(
+      ScheduleTwinSync self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_schedule_twin_sync_sse(
+      ScheduleTwinSyncSse self, SseSerializer serializer);
+
   @protected
   void sse_encode_sequences(Sequences self, SseSerializer serializer);
 
@@ -38101,6 +40203,30 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_static_only_twin_sync_sse(
       StaticOnlyTwinSyncSse self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_std_time_twin_normal(
+      StdTimeTwinNormal self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_std_time_twin_rust_async(
+      StdTimeTwinRustAsync self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_std_time_twin_rust_async_sse(
+      StdTimeTwinRustAsyncSse self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_std_time_twin_sse(
+      StdTimeTwinSse self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_std_time_twin_sync(
+      StdTimeTwinSync self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_std_time_twin_sync_sse(
+      StdTimeTwinSyncSse self, SseSerializer serializer);
+
   @protected
   void sse_encode_struct_in_lower_level(
       StructInLowerLevel self, SseSerializer serializer);
@@ -38577,6 +40703,30 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_test_model_twin_sync_sse(
       TestModelTwinSyncSse self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_time_types_twin_normal(
+      TimeTypesTwinNormal self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_time_types_twin_rust_async(
+      TimeTypesTwinRustAsync self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_time_types_twin_rust_async_sse(
+      TimeTypesTwinRustAsyncSse self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_time_types_twin_sse(
+      TimeTypesTwinSse self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_time_types_twin_sync(
+      TimeTypesTwinSync self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_time_types_twin_sync_sse(
+      TimeTypesTwinSyncSse self, SseSerializer serializer);
+
   @protected
   void sse_encode_translatable_struct_with_dart_code_twin_normal(
       TranslatableStructWithDartCodeTwinNormal self, SseSerializer serializer);
@@ -38629,6 +40779,30 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_tuple_struct_with_two_field_twin_sync_sse(
       TupleStructWithTwoFieldTwinSyncSse self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_typed_exception_error_twin_normal(
+      TypedExceptionErrorTwinNormal self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_typed_exception_error_twin_rust_async(
+      TypedExceptionErrorTwinRustAsync self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_typed_exception_error_twin_rust_async_sse(
+      TypedExceptionErrorTwinRustAsyncSse self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_typed_exception_error_twin_sse(
+      TypedExceptionErrorTwinSse self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_typed_exception_error_twin_sync(
+      TypedExceptionErrorTwinSync self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_typed_exception_error_twin_sync_sse(
+      TypedExceptionErrorTwinSyncSse self, SseSerializer serializer);
+
   @protected
   void sse_encode_u_16(int self, SseSerializer serializer);
 
@@ -38789,6 +40963,12 @@ class RustLibWire implements BaseWire {
           NativePortType port_, JSAny id) =>
       wasmModule.wire__crate__api__array__use_msgid_twin_normal(port_, id);
 
+  void wire__crate__api__async_misc__func_async_never_complete_twin_normal(
+          NativePortType port_) =>
+      wasmModule
+          .wire__crate__api__async_misc__func_async_never_complete_twin_normal(
+              port_);
+
   void wire__crate__api__async_misc__func_async_simple_add_twin_normal(
           NativePortType port_, int a, int b) =>
       wasmModule
@@ -38800,6 +40980,12 @@ class RustLibWire implements BaseWire {
       wasmModule
           .wire__crate__api__async_misc__func_async_void_twin_normal(port_);
 
+  void wire__crate__api__async_misc__func_async_wait_for_cancellation_twin_normal(
+          NativePortType port_) =>
+      wasmModule
+          .wire__crate__api__async_misc__func_async_wait_for_cancellation_twin_normal(
+              port_);
+
   void wire__crate__api__async_spawn__simple_use_async_spawn(
           NativePortType port_, String arg) =>
       wasmModule.wire__crate__api__async_spawn__simple_use_async_spawn(
@@ -38831,6 +41017,49 @@ class RustLibWire implements BaseWire {
           wasmModule
               .wire__crate__api__benchmark_misc__benchmark_void_semi_serialize();
 
+  void wire__crate__api__big_number__add_decimals_twin_normal(
+          NativePortType port_, String a, String b) =>
+      wasmModule.wire__crate__api__big_number__add_decimals_twin_normal(
+          port_, a, b);
+
+  void wire__crate__api__big_number__big_int_twin_normal(
+          NativePortType port_, String i) =>
+      wasmModule.wire__crate__api__big_number__big_int_twin_normal(port_, i);
+
+  void wire__crate__api__big_number__big_ints_twin_normal(
+          NativePortType port_, JSAny ints) =>
+      wasmModule.wire__crate__api__big_number__big_ints_twin_normal(
+          port_, ints);
+
+  void wire__crate__api__big_number__big_numbers_twin_normal(
+          NativePortType port_, JSAny value) =>
+      wasmModule.wire__crate__api__big_number__big_numbers_twin_normal(
+          port_, value);
+
+  void wire__crate__api__big_number__decimal_twin_normal(
+          NativePortType port_, String d) =>
+      wasmModule.wire__crate__api__big_number__decimal_twin_normal(port_, d);
+
+  void wire__crate__api__big_number__decimals_twin_normal(
+          NativePortType port_, JSAny decimals) =>
+      wasmModule.wire__crate__api__big_number__decimals_twin_normal(
+          port_, decimals);
+
+  void wire__crate__api__big_number__multiply_big_ints_twin_normal(
+          NativePortType port_, String a, String b) =>
+      wasmModule.wire__crate__api__big_number__multiply_big_ints_twin_normal(
+          port_, a, b);
+
+  void wire__crate__api__big_number__optional_big_int_twin_normal(
+          NativePortType port_, String? i) =>
+      wasmModule.wire__crate__api__big_number__optional_big_int_twin_normal(
+          port_, i);
+
+  void wire__crate__api__big_number__optional_decimal_twin_normal(
+          NativePortType port_, String? d) =>
+      wasmModule.wire__crate__api__big_number__optional_decimal_twin_normal(
+          port_, d);
+
   void wire__crate__api__casted_primitive__casted_primitive_i64_twin_normal(
           NativePortType port_,
           PlatformGeneralizedUint8ListPtr ptr_,
@@ -38885,6 +41114,18 @@ class RustLibWire implements BaseWire {
           .wire__crate__api__casted_primitive__function_for_struct_with_casted_primitive_twin_normal(
               port_, ptr_, rust_vec_len_, data_len_);
 
+  void wire__crate__api__chrono_type__datetime_fixed_offset_twin_normal(
+          NativePortType port_, String d) =>
+      wasmModule
+          .wire__crate__api__chrono_type__datetime_fixed_offset_twin_normal(
+              port_, d);
+
+  void wire__crate__api__chrono_type__datetime_fixed_offsets_twin_normal(
+          NativePortType port_, JSAny dates) =>
+      wasmModule
+          .wire__crate__api__chrono_type__datetime_fixed_offsets_twin_normal(
+              port_, dates);
+
   void wire__crate__api__chrono_type__datetime_local_twin_normal(
           NativePortType port_, JSAny d) =>
       wasmModule.wire__crate__api__chrono_type__datetime_local_twin_normal(
@@ -38915,17 +41156,43 @@ class RustLibWire implements BaseWire {
           .wire__crate__api__chrono_type__how_long_does_it_take_twin_normal(
               port_, mine);
 
+  void wire__crate__api__chrono_type__naive_date_twin_normal(
+          NativePortType port_, JSAny d) =>
+      wasmModule.wire__crate__api__chrono_type__naive_date_twin_normal(
+          port_, d);
+
+  void wire__crate__api__chrono_type__naive_dates_twin_normal(
+          NativePortType port_, JSAny dates) =>
+      wasmModule.wire__crate__api__chrono_type__naive_dates_twin_normal(
+          port_, dates);
+
+  void wire__crate__api__chrono_type__naive_time_twin_normal(
+          NativePortType port_, JSAny d) =>
+      wasmModule.wire__crate__api__chrono_type__naive_time_twin_normal(
+          port_, d);
+
   void wire__crate__api__chrono_type__naivedatetime_twin_normal(
           NativePortType port_, JSAny d) =>
       wasmModule.wire__crate__api__chrono_type__naivedatetime_twin_normal(
           port_, d);
 
+  void wire__crate__api__chrono_type__optional_datetime_fixed_offset_twin_normal(
+          NativePortType port_, String? d) =>
+      wasmModule
+          .wire__crate__api__chrono_type__optional_datetime_fixed_offset_twin_normal(
+              port_, d);
+
   void wire__crate__api__chrono_type__optional_empty_datetime_utc_twin_normal(
           NativePortType port_, JSAny? d) =>
       wasmModule
           .wire__crate__api__chrono_type__optional_empty_datetime_utc_twin_normal(
               port_, d);
 
+  void wire__crate__api__chrono_type__schedule_twin_normal(
+          NativePortType port_, JSAny schedule) =>
+      wasmModule.wire__crate__api__chrono_type__schedule_twin_normal(
+          port_, schedule);
+
   void wire__crate__api__chrono_type__test_chrono_twin_normal(
           NativePortType port_) =>
       wasmModule.wire__crate__api__chrono_type__test_chrono_twin_normal(port_);
@@ -38935,6 +41202,51 @@ class RustLibWire implements BaseWire {
       wasmModule.wire__crate__api__chrono_type__test_precise_chrono_twin_normal(
           port_);
 
+  void wire__crate__api__chrono_tz_type__datetime_tz_name_twin_normal(
+          NativePortType port_, String d) =>
+      wasmModule.wire__crate__api__chrono_tz_type__datetime_tz_name_twin_normal(
+          port_, d);
+
+  void wire__crate__api__chrono_tz_type__datetime_tz_to_fixed_offset_twin_normal(
+          NativePortType port_, String d) =>
+      wasmModule
+          .wire__crate__api__chrono_tz_type__datetime_tz_to_fixed_offset_twin_normal(
+              port_, d);
+
+  void wire__crate__api__chrono_tz_type__datetime_tz_twin_normal(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule.wire__crate__api__chrono_tz_type__datetime_tz_twin_normal(
+          port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__chrono_tz_type__datetime_tzs_twin_normal(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule.wire__crate__api__chrono_tz_type__datetime_tzs_twin_normal(
+          port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__chrono_tz_type__datetime_with_time_zone_twin_normal(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__chrono_tz_type__datetime_with_time_zone_twin_normal(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__chrono_tz_type__optional_datetime_tz_twin_normal(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__chrono_tz_type__optional_datetime_tz_twin_normal(
+              port_, ptr_, rust_vec_len_, data_len_);
+
   void wire__crate__api__comment__function_with_comments_slash_star_star_twin_normal(
           NativePortType port_) =>
       wasmModule
@@ -39121,6 +41433,18 @@ class RustLibWire implements BaseWire {
           .wire__crate__api__dart_fn__rust_call_dart_with_dart_opaque_result_twin_normal(
               port_, callback);
 
+  void wire__crate__api__dart_fn__rust_consume_dart_stream_sum_twin_normal(
+          NativePortType port_, PlatformPointer stream) =>
+      wasmModule
+          .wire__crate__api__dart_fn__rust_consume_dart_stream_sum_twin_normal(
+              port_, stream);
+
+  void wire__crate__api__dart_fn__rust_consume_dart_stream_take_twin_normal(
+          NativePortType port_, PlatformPointer stream, JSAny count) =>
+      wasmModule
+          .wire__crate__api__dart_fn__rust_consume_dart_stream_take_twin_normal(
+              port_, stream, count);
+
   void wire__crate__api__dart_opaque__async_accept_dart_opaque_twin_normal(
           NativePortType port_, PlatformPointer opaque) =>
       wasmModule
@@ -39340,6 +41664,65 @@ class RustLibWire implements BaseWire {
           .wire__crate__api__dyn_trait__StructTwoWithTraitForDynTwinNormal_simple_method_twin_normal(
               port_, ptr_, rust_vec_len_, data_len_);
 
+  void wire__crate__api__dyn_trait__StructWithDartImplementableTraitTwinNormal_add_twin_normal(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__dyn_trait__StructWithDartImplementableTraitTwinNormal_add_twin_normal(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__dyn_trait__StructWithDartImplementableTraitTwinNormal_auto_accessor_get_base(
+              int that) =>
+          wasmModule
+              .wire__crate__api__dyn_trait__StructWithDartImplementableTraitTwinNormal_auto_accessor_get_base(
+                  that);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__dyn_trait__StructWithDartImplementableTraitTwinNormal_auto_accessor_set_base(
+              int that, int base) =>
+          wasmModule
+              .wire__crate__api__dyn_trait__StructWithDartImplementableTraitTwinNormal_auto_accessor_set_base(
+                  that, base);
+
+  void wire__crate__api__dyn_trait__StructWithDartImplementableTraitTwinNormal_describe_twin_normal(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__dyn_trait__StructWithDartImplementableTraitTwinNormal_describe_twin_normal(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__dyn_trait__StructWithDartImplementableTraitTwinNormal_new(
+              PlatformGeneralizedUint8ListPtr ptr_,
+              int rust_vec_len_,
+              int data_len_) =>
+          wasmModule
+              .wire__crate__api__dyn_trait__StructWithDartImplementableTraitTwinNormal_new(
+                  ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__dyn_trait__func_arg_box_dyn_dart_implementable_async_twin_normal(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__dyn_trait__func_arg_box_dyn_dart_implementable_async_twin_normal(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__dyn_trait__func_arg_box_dyn_dart_implementable_sync_twin_normal(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__dyn_trait__func_arg_box_dyn_dart_implementable_sync_twin_normal(
+              port_, ptr_, rust_vec_len_, data_len_);
+
   void wire__crate__api__dyn_trait__func_arg_dyn_trait_twin_normal(
           NativePortType port_,
           PlatformGeneralizedUint8ListPtr ptr_,
@@ -39599,10 +41982,27 @@ class RustLibWire implements BaseWire {
           .wire__crate__api__exception__stream_sink_throw_anyhow_twin_normal(
               port_, _sink);
 
+  void wire__crate__api__exception__throw_anyhow_io_error_twin_normal(
+          NativePortType port_) =>
+      wasmModule.wire__crate__api__exception__throw_anyhow_io_error_twin_normal(
+          port_);
+
   void wire__crate__api__exception__throw_anyhow_twin_normal(
           NativePortType port_) =>
       wasmModule.wire__crate__api__exception__throw_anyhow_twin_normal(port_);
 
+  void wire__crate__api__exception__throw_anyhow_with_context_twin_normal(
+          NativePortType port_) =>
+      wasmModule
+          .wire__crate__api__exception__throw_anyhow_with_context_twin_normal(
+              port_);
+
+  void wire__crate__api__exception__typed_exception_return_error_twin_normal(
+          NativePortType port_, int variant) =>
+      wasmModule
+          .wire__crate__api__exception__typed_exception_return_error_twin_normal(
+              port_, variant);
+
   JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
       wire__crate__api__external_impl__SimpleOpaqueExternalStructWithMethod_new(
               String a) =>
@@ -39733,6 +42133,50 @@ class RustLibWire implements BaseWire {
       wasmModule.wire__crate__api__inside_macro__func_macro_struct_twin_normal(
           port_, arg);
 
+  void wire__crate__api__json_type__create_json_map_twin_normal(
+          NativePortType port_) =>
+      wasmModule
+          .wire__crate__api__json_type__create_json_map_twin_normal(port_);
+
+  void wire__crate__api__json_type__create_json_value_twin_normal(
+          NativePortType port_) =>
+      wasmModule
+          .wire__crate__api__json_type__create_json_value_twin_normal(port_);
+
+  void wire__crate__api__json_type__json_document_twin_normal(
+          NativePortType port_, JSAny document) =>
+      wasmModule.wire__crate__api__json_type__json_document_twin_normal(
+          port_, document);
+
+  void wire__crate__api__json_type__json_map_keys_twin_normal(
+          NativePortType port_, String map) =>
+      wasmModule.wire__crate__api__json_type__json_map_keys_twin_normal(
+          port_, map);
+
+  void wire__crate__api__json_type__json_map_twin_normal(
+          NativePortType port_, String map) =>
+      wasmModule.wire__crate__api__json_type__json_map_twin_normal(port_, map);
+
+  void wire__crate__api__json_type__json_value_to_string_twin_normal(
+          NativePortType port_, String value) =>
+      wasmModule.wire__crate__api__json_type__json_value_to_string_twin_normal(
+          port_, value);
+
+  void wire__crate__api__json_type__json_value_twin_normal(
+          NativePortType port_, String value) =>
+      wasmModule.wire__crate__api__json_type__json_value_twin_normal(
+          port_, value);
+
+  void wire__crate__api__json_type__json_values_twin_normal(
+          NativePortType port_, JSAny values) =>
+      wasmModule.wire__crate__api__json_type__json_values_twin_normal(
+          port_, values);
+
+  void wire__crate__api__json_type__optional_json_value_twin_normal(
+          NativePortType port_, String? value) =>
+      wasmModule.wire__crate__api__json_type__optional_json_value_twin_normal(
+          port_, value);
+
   void wire__crate__api__lifetimeable__Lifetimeable_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLtNestedTypeWithLifetimeTwinNormalstatic_greet_borrow_mut_self_twin_normal(
           NativePortType port_,
           PlatformGeneralizedUint8ListPtr ptr_,
@@ -39877,6 +42321,29 @@ class RustLibWire implements BaseWire {
           .wire__crate__api__lifetimeable__lt_compute_with_lifetime_function_twin_normal(
               port_, ptr_, rust_vec_len_, data_len_);
 
+  void wire__crate__api__map_and_set__func_btree_map_i32_i32_twin_normal(
+          NativePortType port_, JSAny arg) =>
+      wasmModule
+          .wire__crate__api__map_and_set__func_btree_map_i32_i32_twin_normal(
+              port_, arg);
+
+  void wire__crate__api__map_and_set__func_btree_map_string_struct_twin_normal(
+          NativePortType port_, JSAny arg) =>
+      wasmModule
+          .wire__crate__api__map_and_set__func_btree_map_string_struct_twin_normal(
+              port_, arg);
+
+  void wire__crate__api__map_and_set__func_btree_set_i32_twin_normal(
+          NativePortType port_, JSAny arg) =>
+      wasmModule.wire__crate__api__map_and_set__func_btree_set_i32_twin_normal(
+          port_, arg);
+
+  void wire__crate__api__map_and_set__func_btree_set_string_twin_normal(
+          NativePortType port_, JSAny arg) =>
+      wasmModule
+          .wire__crate__api__map_and_set__func_btree_set_string_twin_normal(
+              port_, arg);
+
   void wire__crate__api__map_and_set__func_hash_map_i32_i32_twin_normal(
           NativePortType port_, JSAny arg) =>
       wasmModule
@@ -39924,6 +42391,17 @@ class RustLibWire implements BaseWire {
           .wire__crate__api__map_and_set__func_hash_set_string_twin_normal(
               port_, arg);
 
+  void wire__crate__api__map_and_set__func_vec_deque_i32_twin_normal(
+          NativePortType port_, JSAny arg) =>
+      wasmModule.wire__crate__api__map_and_set__func_vec_deque_i32_twin_normal(
+          port_, arg);
+
+  void wire__crate__api__map_and_set__func_vec_deque_string_twin_normal(
+          NativePortType port_, JSAny arg) =>
+      wasmModule
+          .wire__crate__api__map_and_set__func_vec_deque_string_twin_normal(
+              port_, arg);
+
   void wire__crate__api__method__StaticGetterOnlyTwinNormal_static_getter_twin_normal(
           NativePortType port_) =>
       wasmModule
@@ -41096,6 +43574,15 @@ class RustLibWire implements BaseWire {
               .wire__crate__api__pseudo_manual__array_twin_sync_sse__use_msgid_twin_sync_sse(
                   ptr_, rust_vec_len_, data_len_);
 
+  void wire__crate__api__pseudo_manual__async_misc_twin_sse__func_async_never_complete_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__async_misc_twin_sse__func_async_never_complete_twin_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
   void wire__crate__api__pseudo_manual__async_misc_twin_sse__func_async_simple_add_twin_sse(
           NativePortType port_,
           PlatformGeneralizedUint8ListPtr ptr_,
@@ -41114,6 +43601,15 @@ class RustLibWire implements BaseWire {
           .wire__crate__api__pseudo_manual__async_misc_twin_sse__func_async_void_twin_sse(
               port_, ptr_, rust_vec_len_, data_len_);
 
+  void wire__crate__api__pseudo_manual__async_misc_twin_sse__func_async_wait_for_cancellation_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__async_misc_twin_sse__func_async_wait_for_cancellation_twin_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
   void wire__crate__api__pseudo_manual__attribute_twin_rust_async__handle_customized_struct_twin_rust_async(
           NativePortType port_, JSAny val) =>
       wasmModule
@@ -45378,6 +47874,378 @@ class RustLibWire implements BaseWire {
               .wire__crate__api__pseudo_manual__benchmark_api_twin_sync_sse__benchmark_void_twin_sync_sse(
                   ptr_, rust_vec_len_, data_len_);
 
+  void wire__crate__api__pseudo_manual__big_number_twin_rust_async__add_decimals_twin_rust_async(
+          NativePortType port_, String a, String b) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__big_number_twin_rust_async__add_decimals_twin_rust_async(
+              port_, a, b);
+
+  void wire__crate__api__pseudo_manual__big_number_twin_rust_async__big_int_twin_rust_async(
+          NativePortType port_, String i) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__big_number_twin_rust_async__big_int_twin_rust_async(
+              port_, i);
+
+  void wire__crate__api__pseudo_manual__big_number_twin_rust_async__big_ints_twin_rust_async(
+          NativePortType port_, JSAny ints) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__big_number_twin_rust_async__big_ints_twin_rust_async(
+              port_, ints);
+
+  void wire__crate__api__pseudo_manual__big_number_twin_rust_async__big_numbers_twin_rust_async(
+          NativePortType port_, JSAny value) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__big_number_twin_rust_async__big_numbers_twin_rust_async(
+              port_, value);
+
+  void wire__crate__api__pseudo_manual__big_number_twin_rust_async__decimal_twin_rust_async(
+          NativePortType port_, String d) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__big_number_twin_rust_async__decimal_twin_rust_async(
+              port_, d);
+
+  void wire__crate__api__pseudo_manual__big_number_twin_rust_async__decimals_twin_rust_async(
+          NativePortType port_, JSAny decimals) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__big_number_twin_rust_async__decimals_twin_rust_async(
+              port_, decimals);
+
+  void wire__crate__api__pseudo_manual__big_number_twin_rust_async__multiply_big_ints_twin_rust_async(
+          NativePortType port_, String a, String b) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__big_number_twin_rust_async__multiply_big_ints_twin_rust_async(
+              port_, a, b);
+
+  void wire__crate__api__pseudo_manual__big_number_twin_rust_async__optional_big_int_twin_rust_async(
+          NativePortType port_, String? i) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__big_number_twin_rust_async__optional_big_int_twin_rust_async(
+              port_, i);
+
+  void wire__crate__api__pseudo_manual__big_number_twin_rust_async__optional_decimal_twin_rust_async(
+          NativePortType port_, String? d) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__big_number_twin_rust_async__optional_decimal_twin_rust_async(
+              port_, d);
+
+  void wire__crate__api__pseudo_manual__big_number_twin_rust_async_sse__add_decimals_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__big_number_twin_rust_async_sse__add_decimals_twin_rust_async_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__big_number_twin_rust_async_sse__big_int_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__big_number_twin_rust_async_sse__big_int_twin_rust_async_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__big_number_twin_rust_async_sse__big_ints_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__big_number_twin_rust_async_sse__big_ints_twin_rust_async_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__big_number_twin_rust_async_sse__big_numbers_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__big_number_twin_rust_async_sse__big_numbers_twin_rust_async_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__big_number_twin_rust_async_sse__decimal_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__big_number_twin_rust_async_sse__decimal_twin_rust_async_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__big_number_twin_rust_async_sse__decimals_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__big_number_twin_rust_async_sse__decimals_twin_rust_async_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__big_number_twin_rust_async_sse__multiply_big_ints_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__big_number_twin_rust_async_sse__multiply_big_ints_twin_rust_async_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__big_number_twin_rust_async_sse__optional_big_int_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__big_number_twin_rust_async_sse__optional_big_int_twin_rust_async_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__big_number_twin_rust_async_sse__optional_decimal_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__big_number_twin_rust_async_sse__optional_decimal_twin_rust_async_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__big_number_twin_sse__add_decimals_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__big_number_twin_sse__add_decimals_twin_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__big_number_twin_sse__big_int_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__big_number_twin_sse__big_int_twin_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__big_number_twin_sse__big_ints_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__big_number_twin_sse__big_ints_twin_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__big_number_twin_sse__big_numbers_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__big_number_twin_sse__big_numbers_twin_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__big_number_twin_sse__decimal_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__big_number_twin_sse__decimal_twin_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__big_number_twin_sse__decimals_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__big_number_twin_sse__decimals_twin_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__big_number_twin_sse__multiply_big_ints_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__big_number_twin_sse__multiply_big_ints_twin_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__big_number_twin_sse__optional_big_int_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__big_number_twin_sse__optional_big_int_twin_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__big_number_twin_sse__optional_decimal_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__big_number_twin_sse__optional_decimal_twin_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__big_number_twin_sync__add_decimals_twin_sync(
+              String a, String b) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__big_number_twin_sync__add_decimals_twin_sync(
+                  a, b);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__big_number_twin_sync__big_int_twin_sync(
+              String i) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__big_number_twin_sync__big_int_twin_sync(
+                  i);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__big_number_twin_sync__big_ints_twin_sync(
+              JSAny ints) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__big_number_twin_sync__big_ints_twin_sync(
+                  ints);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__big_number_twin_sync__big_numbers_twin_sync(
+              JSAny value) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__big_number_twin_sync__big_numbers_twin_sync(
+                  value);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__big_number_twin_sync__decimal_twin_sync(
+              String d) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__big_number_twin_sync__decimal_twin_sync(
+                  d);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__big_number_twin_sync__decimals_twin_sync(
+              JSAny decimals) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__big_number_twin_sync__decimals_twin_sync(
+                  decimals);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__big_number_twin_sync__multiply_big_ints_twin_sync(
+              String a, String b) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__big_number_twin_sync__multiply_big_ints_twin_sync(
+                  a, b);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__big_number_twin_sync__optional_big_int_twin_sync(
+              String? i) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__big_number_twin_sync__optional_big_int_twin_sync(
+                  i);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__big_number_twin_sync__optional_decimal_twin_sync(
+              String? d) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__big_number_twin_sync__optional_decimal_twin_sync(
+                  d);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__big_number_twin_sync_sse__add_decimals_twin_sync_sse(
+              PlatformGeneralizedUint8ListPtr ptr_,
+              int rust_vec_len_,
+              int data_len_) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__big_number_twin_sync_sse__add_decimals_twin_sync_sse(
+                  ptr_, rust_vec_len_, data_len_);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__big_number_twin_sync_sse__big_int_twin_sync_sse(
+              PlatformGeneralizedUint8ListPtr ptr_,
+              int rust_vec_len_,
+              int data_len_) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__big_number_twin_sync_sse__big_int_twin_sync_sse(
+                  ptr_, rust_vec_len_, data_len_);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__big_number_twin_sync_sse__big_ints_twin_sync_sse(
+              PlatformGeneralizedUint8ListPtr ptr_,
+              int rust_vec_len_,
+              int data_len_) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__big_number_twin_sync_sse__big_ints_twin_sync_sse(
+                  ptr_, rust_vec_len_, data_len_);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__big_number_twin_sync_sse__big_numbers_twin_sync_sse(
+              PlatformGeneralizedUint8ListPtr ptr_,
+              int rust_vec_len_,
+              int data_len_) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__big_number_twin_sync_sse__big_numbers_twin_sync_sse(
+                  ptr_, rust_vec_len_, data_len_);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__big_number_twin_sync_sse__decimal_twin_sync_sse(
+              PlatformGeneralizedUint8ListPtr ptr_,
+              int rust_vec_len_,
+              int data_len_) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__big_number_twin_sync_sse__decimal_twin_sync_sse(
+                  ptr_, rust_vec_len_, data_len_);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__big_number_twin_sync_sse__decimals_twin_sync_sse(
+              PlatformGeneralizedUint8ListPtr ptr_,
+              int rust_vec_len_,
+              int data_len_) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__big_number_twin_sync_sse__decimals_twin_sync_sse(
+                  ptr_, rust_vec_len_, data_len_);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__big_number_twin_sync_sse__multiply_big_ints_twin_sync_sse(
+              PlatformGeneralizedUint8ListPtr ptr_,
+              int rust_vec_len_,
+              int data_len_) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__big_number_twin_sync_sse__multiply_big_ints_twin_sync_sse(
+                  ptr_, rust_vec_len_, data_len_);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__big_number_twin_sync_sse__optional_big_int_twin_sync_sse(
+              PlatformGeneralizedUint8ListPtr ptr_,
+              int rust_vec_len_,
+              int data_len_) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__big_number_twin_sync_sse__optional_big_int_twin_sync_sse(
+                  ptr_, rust_vec_len_, data_len_);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__big_number_twin_sync_sse__optional_decimal_twin_sync_sse(
+              PlatformGeneralizedUint8ListPtr ptr_,
+              int rust_vec_len_,
+              int data_len_) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__big_number_twin_sync_sse__optional_decimal_twin_sync_sse(
+                  ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__datetime_fixed_offset_twin_rust_async(
+          NativePortType port_, String d) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__datetime_fixed_offset_twin_rust_async(
+              port_, d);
+
+  void wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__datetime_fixed_offsets_twin_rust_async(
+          NativePortType port_, JSAny dates) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__datetime_fixed_offsets_twin_rust_async(
+              port_, dates);
+
   void wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__datetime_local_twin_rust_async(
           NativePortType port_, JSAny d) =>
       wasmModule
@@ -45414,18 +48282,48 @@ class RustLibWire implements BaseWire {
           .wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__how_long_does_it_take_twin_rust_async(
               port_, mine);
 
+  void wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__naive_date_twin_rust_async(
+          NativePortType port_, JSAny d) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__naive_date_twin_rust_async(
+              port_, d);
+
+  void wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__naive_dates_twin_rust_async(
+          NativePortType port_, JSAny dates) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__naive_dates_twin_rust_async(
+              port_, dates);
+
+  void wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__naive_time_twin_rust_async(
+          NativePortType port_, JSAny d) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__naive_time_twin_rust_async(
+              port_, d);
+
   void wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__naivedatetime_twin_rust_async(
           NativePortType port_, JSAny d) =>
       wasmModule
           .wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__naivedatetime_twin_rust_async(
               port_, d);
 
+  void wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__optional_datetime_fixed_offset_twin_rust_async(
+          NativePortType port_, String? d) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__optional_datetime_fixed_offset_twin_rust_async(
+              port_, d);
+
   void wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__optional_empty_datetime_utc_twin_rust_async(
           NativePortType port_, JSAny? d) =>
       wasmModule
           .wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__optional_empty_datetime_utc_twin_rust_async(
               port_, d);
 
+  void wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__schedule_twin_rust_async(
+          NativePortType port_, JSAny schedule) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__schedule_twin_rust_async(
+              port_, schedule);
+
   void wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__test_chrono_twin_rust_async(
           NativePortType port_) =>
       wasmModule
@@ -45438,6 +48336,24 @@ class RustLibWire implements BaseWire {
           .wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__test_precise_chrono_twin_rust_async(
               port_);
 
+  void wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__datetime_fixed_offset_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__datetime_fixed_offset_twin_rust_async_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__datetime_fixed_offsets_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__datetime_fixed_offsets_twin_rust_async_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
   void wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__datetime_local_twin_rust_async_sse(
           NativePortType port_,
           PlatformGeneralizedUint8ListPtr ptr_,
@@ -45492,6 +48408,33 @@ class RustLibWire implements BaseWire {
           .wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__how_long_does_it_take_twin_rust_async_sse(
               port_, ptr_, rust_vec_len_, data_len_);
 
+  void wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__naive_date_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__naive_date_twin_rust_async_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__naive_dates_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__naive_dates_twin_rust_async_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__naive_time_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__naive_time_twin_rust_async_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
   void wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__naivedatetime_twin_rust_async_sse(
           NativePortType port_,
           PlatformGeneralizedUint8ListPtr ptr_,
@@ -45501,6 +48444,15 @@ class RustLibWire implements BaseWire {
           .wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__naivedatetime_twin_rust_async_sse(
               port_, ptr_, rust_vec_len_, data_len_);
 
+  void wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__optional_datetime_fixed_offset_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__optional_datetime_fixed_offset_twin_rust_async_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
   void wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__optional_empty_datetime_utc_twin_rust_async_sse(
           NativePortType port_,
           PlatformGeneralizedUint8ListPtr ptr_,
@@ -45510,6 +48462,15 @@ class RustLibWire implements BaseWire {
           .wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__optional_empty_datetime_utc_twin_rust_async_sse(
               port_, ptr_, rust_vec_len_, data_len_);
 
+  void wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__schedule_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__schedule_twin_rust_async_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
   void wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__test_chrono_twin_rust_async_sse(
           NativePortType port_,
           PlatformGeneralizedUint8ListPtr ptr_,
@@ -45528,6 +48489,24 @@ class RustLibWire implements BaseWire {
           .wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__test_precise_chrono_twin_rust_async_sse(
               port_, ptr_, rust_vec_len_, data_len_);
 
+  void wire__crate__api__pseudo_manual__chrono_type_twin_sse__datetime_fixed_offset_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__chrono_type_twin_sse__datetime_fixed_offset_twin_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__chrono_type_twin_sse__datetime_fixed_offsets_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__chrono_type_twin_sse__datetime_fixed_offsets_twin_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
   void wire__crate__api__pseudo_manual__chrono_type_twin_sse__datetime_local_twin_sse(
           NativePortType port_,
           PlatformGeneralizedUint8ListPtr ptr_,
@@ -45582,6 +48561,33 @@ class RustLibWire implements BaseWire {
           .wire__crate__api__pseudo_manual__chrono_type_twin_sse__how_long_does_it_take_twin_sse(
               port_, ptr_, rust_vec_len_, data_len_);
 
+  void wire__crate__api__pseudo_manual__chrono_type_twin_sse__naive_date_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__chrono_type_twin_sse__naive_date_twin_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__chrono_type_twin_sse__naive_dates_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__chrono_type_twin_sse__naive_dates_twin_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__chrono_type_twin_sse__naive_time_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__chrono_type_twin_sse__naive_time_twin_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
   void wire__crate__api__pseudo_manual__chrono_type_twin_sse__naivedatetime_twin_sse(
           NativePortType port_,
           PlatformGeneralizedUint8ListPtr ptr_,
@@ -45591,6 +48597,15 @@ class RustLibWire implements BaseWire {
           .wire__crate__api__pseudo_manual__chrono_type_twin_sse__naivedatetime_twin_sse(
               port_, ptr_, rust_vec_len_, data_len_);
 
+  void wire__crate__api__pseudo_manual__chrono_type_twin_sse__optional_datetime_fixed_offset_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__chrono_type_twin_sse__optional_datetime_fixed_offset_twin_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
   void wire__crate__api__pseudo_manual__chrono_type_twin_sse__optional_empty_datetime_utc_twin_sse(
           NativePortType port_,
           PlatformGeneralizedUint8ListPtr ptr_,
@@ -45600,6 +48615,15 @@ class RustLibWire implements BaseWire {
           .wire__crate__api__pseudo_manual__chrono_type_twin_sse__optional_empty_datetime_utc_twin_sse(
               port_, ptr_, rust_vec_len_, data_len_);
 
+  void wire__crate__api__pseudo_manual__chrono_type_twin_sse__schedule_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__chrono_type_twin_sse__schedule_twin_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
   void wire__crate__api__pseudo_manual__chrono_type_twin_sse__test_chrono_twin_sse(
           NativePortType port_,
           PlatformGeneralizedUint8ListPtr ptr_,
@@ -45618,6 +48642,20 @@ class RustLibWire implements BaseWire {
           .wire__crate__api__pseudo_manual__chrono_type_twin_sse__test_precise_chrono_twin_sse(
               port_, ptr_, rust_vec_len_, data_len_);
 
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__chrono_type_twin_sync__datetime_fixed_offset_twin_sync(
+              String d) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__chrono_type_twin_sync__datetime_fixed_offset_twin_sync(
+                  d);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__chrono_type_twin_sync__datetime_fixed_offsets_twin_sync(
+              JSAny dates) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__chrono_type_twin_sync__datetime_fixed_offsets_twin_sync(
+                  dates);
+
   JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
       wire__crate__api__pseudo_manual__chrono_type_twin_sync__datetime_local_twin_sync(
               JSAny d) =>
@@ -45660,6 +48698,27 @@ class RustLibWire implements BaseWire {
               .wire__crate__api__pseudo_manual__chrono_type_twin_sync__how_long_does_it_take_twin_sync(
                   mine);
 
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__chrono_type_twin_sync__naive_date_twin_sync(
+              JSAny d) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__chrono_type_twin_sync__naive_date_twin_sync(
+                  d);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__chrono_type_twin_sync__naive_dates_twin_sync(
+              JSAny dates) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__chrono_type_twin_sync__naive_dates_twin_sync(
+                  dates);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__chrono_type_twin_sync__naive_time_twin_sync(
+              JSAny d) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__chrono_type_twin_sync__naive_time_twin_sync(
+                  d);
+
   JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
       wire__crate__api__pseudo_manual__chrono_type_twin_sync__naivedatetime_twin_sync(
               JSAny d) =>
@@ -45667,6 +48726,13 @@ class RustLibWire implements BaseWire {
               .wire__crate__api__pseudo_manual__chrono_type_twin_sync__naivedatetime_twin_sync(
                   d);
 
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__chrono_type_twin_sync__optional_datetime_fixed_offset_twin_sync(
+              String? d) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__chrono_type_twin_sync__optional_datetime_fixed_offset_twin_sync(
+                  d);
+
   JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
       wire__crate__api__pseudo_manual__chrono_type_twin_sync__optional_empty_datetime_utc_twin_sync(
               JSAny? d) =>
@@ -45674,6 +48740,13 @@ class RustLibWire implements BaseWire {
               .wire__crate__api__pseudo_manual__chrono_type_twin_sync__optional_empty_datetime_utc_twin_sync(
                   d);
 
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__chrono_type_twin_sync__schedule_twin_sync(
+              JSAny schedule) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__chrono_type_twin_sync__schedule_twin_sync(
+                  schedule);
+
   JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
       wire__crate__api__pseudo_manual__chrono_type_twin_sync__test_chrono_twin_sync() =>
           wasmModule
@@ -45684,6 +48757,24 @@ class RustLibWire implements BaseWire {
           wasmModule
               .wire__crate__api__pseudo_manual__chrono_type_twin_sync__test_precise_chrono_twin_sync();
 
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__chrono_type_twin_sync_sse__datetime_fixed_offset_twin_sync_sse(
+              PlatformGeneralizedUint8ListPtr ptr_,
+              int rust_vec_len_,
+              int data_len_) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__chrono_type_twin_sync_sse__datetime_fixed_offset_twin_sync_sse(
+                  ptr_, rust_vec_len_, data_len_);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__chrono_type_twin_sync_sse__datetime_fixed_offsets_twin_sync_sse(
+              PlatformGeneralizedUint8ListPtr ptr_,
+              int rust_vec_len_,
+              int data_len_) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__chrono_type_twin_sync_sse__datetime_fixed_offsets_twin_sync_sse(
+                  ptr_, rust_vec_len_, data_len_);
+
   JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
       wire__crate__api__pseudo_manual__chrono_type_twin_sync_sse__datetime_local_twin_sync_sse(
               PlatformGeneralizedUint8ListPtr ptr_,
@@ -45738,6 +48829,33 @@ class RustLibWire implements BaseWire {
               .wire__crate__api__pseudo_manual__chrono_type_twin_sync_sse__how_long_does_it_take_twin_sync_sse(
                   ptr_, rust_vec_len_, data_len_);
 
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__chrono_type_twin_sync_sse__naive_date_twin_sync_sse(
+              PlatformGeneralizedUint8ListPtr ptr_,
+              int rust_vec_len_,
+              int data_len_) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__chrono_type_twin_sync_sse__naive_date_twin_sync_sse(
+                  ptr_, rust_vec_len_, data_len_);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__chrono_type_twin_sync_sse__naive_dates_twin_sync_sse(
+              PlatformGeneralizedUint8ListPtr ptr_,
+              int rust_vec_len_,
+              int data_len_) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__chrono_type_twin_sync_sse__naive_dates_twin_sync_sse(
+                  ptr_, rust_vec_len_, data_len_);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__chrono_type_twin_sync_sse__naive_time_twin_sync_sse(
+              PlatformGeneralizedUint8ListPtr ptr_,
+              int rust_vec_len_,
+              int data_len_) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__chrono_type_twin_sync_sse__naive_time_twin_sync_sse(
+                  ptr_, rust_vec_len_, data_len_);
+
   JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
       wire__crate__api__pseudo_manual__chrono_type_twin_sync_sse__naivedatetime_twin_sync_sse(
               PlatformGeneralizedUint8ListPtr ptr_,
@@ -45747,6 +48865,15 @@ class RustLibWire implements BaseWire {
               .wire__crate__api__pseudo_manual__chrono_type_twin_sync_sse__naivedatetime_twin_sync_sse(
                   ptr_, rust_vec_len_, data_len_);
 
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__chrono_type_twin_sync_sse__optional_datetime_fixed_offset_twin_sync_sse(
+              PlatformGeneralizedUint8ListPtr ptr_,
+              int rust_vec_len_,
+              int data_len_) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__chrono_type_twin_sync_sse__optional_datetime_fixed_offset_twin_sync_sse(
+                  ptr_, rust_vec_len_, data_len_);
+
   JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
       wire__crate__api__pseudo_manual__chrono_type_twin_sync_sse__optional_empty_datetime_utc_twin_sync_sse(
               PlatformGeneralizedUint8ListPtr ptr_,
@@ -45756,6 +48883,15 @@ class RustLibWire implements BaseWire {
               .wire__crate__api__pseudo_manual__chrono_type_twin_sync_sse__optional_empty_datetime_utc_twin_sync_sse(
                   ptr_, rust_vec_len_, data_len_);
 
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__chrono_type_twin_sync_sse__schedule_twin_sync_sse(
+              PlatformGeneralizedUint8ListPtr ptr_,
+              int rust_vec_len_,
+              int data_len_) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__chrono_type_twin_sync_sse__schedule_twin_sync_sse(
+                  ptr_, rust_vec_len_, data_len_);
+
   JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
       wire__crate__api__pseudo_manual__chrono_type_twin_sync_sse__test_chrono_twin_sync_sse(
               PlatformGeneralizedUint8ListPtr ptr_,
@@ -46035,6 +49171,18 @@ class RustLibWire implements BaseWire {
           .wire__crate__api__pseudo_manual__dart_fn_twin_rust_async__rust_call_dart_with_dart_opaque_result_twin_rust_async(
               port_, callback);
 
+  void wire__crate__api__pseudo_manual__dart_fn_twin_rust_async__rust_consume_dart_stream_sum_twin_rust_async(
+          NativePortType port_, PlatformPointer stream) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__dart_fn_twin_rust_async__rust_consume_dart_stream_sum_twin_rust_async(
+              port_, stream);
+
+  void wire__crate__api__pseudo_manual__dart_fn_twin_rust_async__rust_consume_dart_stream_take_twin_rust_async(
+          NativePortType port_, PlatformPointer stream, JSAny count) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__dart_fn_twin_rust_async__rust_consume_dart_stream_take_twin_rust_async(
+              port_, stream, count);
+
   void wire__crate__api__pseudo_manual__dart_fn_twin_rust_async_sse__rust_call_dart_loopback_twin_rust_async_sse(
           NativePortType port_,
           PlatformGeneralizedUint8ListPtr ptr_,
@@ -46116,6 +49264,24 @@ class RustLibWire implements BaseWire {
           .wire__crate__api__pseudo_manual__dart_fn_twin_rust_async_sse__rust_call_dart_with_dart_opaque_result_twin_rust_async_sse(
               port_, ptr_, rust_vec_len_, data_len_);
 
+  void wire__crate__api__pseudo_manual__dart_fn_twin_rust_async_sse__rust_consume_dart_stream_sum_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__dart_fn_twin_rust_async_sse__rust_consume_dart_stream_sum_twin_rust_async_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__dart_fn_twin_rust_async_sse__rust_consume_dart_stream_take_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__dart_fn_twin_rust_async_sse__rust_consume_dart_stream_take_twin_rust_async_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
   void wire__crate__api__pseudo_manual__dart_fn_twin_sse__rust_call_dart_loopback_twin_sse(
           NativePortType port_,
           PlatformGeneralizedUint8ListPtr ptr_,
@@ -46197,6 +49363,24 @@ class RustLibWire implements BaseWire {
           .wire__crate__api__pseudo_manual__dart_fn_twin_sse__rust_call_dart_with_dart_opaque_result_twin_sse(
               port_, ptr_, rust_vec_len_, data_len_);
 
+  void wire__crate__api__pseudo_manual__dart_fn_twin_sse__rust_consume_dart_stream_sum_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__dart_fn_twin_sse__rust_consume_dart_stream_sum_twin_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__dart_fn_twin_sse__rust_consume_dart_stream_take_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__dart_fn_twin_sse__rust_consume_dart_stream_take_twin_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
   JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
       wire__crate__api__pseudo_manual__dart_opaque_sync_twin_sse__sync_accept_dart_opaque_twin_sse(
               PlatformGeneralizedUint8ListPtr ptr_,
@@ -47704,12 +50888,30 @@ class RustLibWire implements BaseWire {
           .wire__crate__api__pseudo_manual__exception_twin_rust_async__stream_sink_throw_anyhow_twin_rust_async(
               port_, _sink);
 
+  void wire__crate__api__pseudo_manual__exception_twin_rust_async__throw_anyhow_io_error_twin_rust_async(
+          NativePortType port_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__exception_twin_rust_async__throw_anyhow_io_error_twin_rust_async(
+              port_);
+
   void wire__crate__api__pseudo_manual__exception_twin_rust_async__throw_anyhow_twin_rust_async(
           NativePortType port_) =>
       wasmModule
           .wire__crate__api__pseudo_manual__exception_twin_rust_async__throw_anyhow_twin_rust_async(
               port_);
 
+  void wire__crate__api__pseudo_manual__exception_twin_rust_async__throw_anyhow_with_context_twin_rust_async(
+          NativePortType port_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__exception_twin_rust_async__throw_anyhow_with_context_twin_rust_async(
+              port_);
+
+  void wire__crate__api__pseudo_manual__exception_twin_rust_async__typed_exception_return_error_twin_rust_async(
+          NativePortType port_, int variant) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__exception_twin_rust_async__typed_exception_return_error_twin_rust_async(
+              port_, variant);
+
   void wire__crate__api__pseudo_manual__exception_twin_rust_async_sse__custom_enum_error_panic_twin_rust_async_sse(
           NativePortType port_,
           PlatformGeneralizedUint8ListPtr ptr_,
@@ -47962,6 +51164,15 @@ class RustLibWire implements BaseWire {
           .wire__crate__api__pseudo_manual__exception_twin_rust_async_sse__stream_sink_throw_anyhow_twin_rust_async_sse(
               port_, ptr_, rust_vec_len_, data_len_);
 
+  void wire__crate__api__pseudo_manual__exception_twin_rust_async_sse__throw_anyhow_io_error_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__exception_twin_rust_async_sse__throw_anyhow_io_error_twin_rust_async_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
   void wire__crate__api__pseudo_manual__exception_twin_rust_async_sse__throw_anyhow_twin_rust_async_sse(
           NativePortType port_,
           PlatformGeneralizedUint8ListPtr ptr_,
@@ -47971,6 +51182,24 @@ class RustLibWire implements BaseWire {
           .wire__crate__api__pseudo_manual__exception_twin_rust_async_sse__throw_anyhow_twin_rust_async_sse(
               port_, ptr_, rust_vec_len_, data_len_);
 
+  void wire__crate__api__pseudo_manual__exception_twin_rust_async_sse__throw_anyhow_with_context_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__exception_twin_rust_async_sse__throw_anyhow_with_context_twin_rust_async_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__exception_twin_rust_async_sse__typed_exception_return_error_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__exception_twin_rust_async_sse__typed_exception_return_error_twin_rust_async_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
   void wire__crate__api__pseudo_manual__exception_twin_sse__custom_enum_error_panic_twin_sse(
           NativePortType port_,
           PlatformGeneralizedUint8ListPtr ptr_,
@@ -48223,6 +51452,15 @@ class RustLibWire implements BaseWire {
           .wire__crate__api__pseudo_manual__exception_twin_sse__stream_sink_throw_anyhow_twin_sse(
               port_, ptr_, rust_vec_len_, data_len_);
 
+  void wire__crate__api__pseudo_manual__exception_twin_sse__throw_anyhow_io_error_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__exception_twin_sse__throw_anyhow_io_error_twin_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
   void wire__crate__api__pseudo_manual__exception_twin_sse__throw_anyhow_twin_sse(
           NativePortType port_,
           PlatformGeneralizedUint8ListPtr ptr_,
@@ -48232,6 +51470,24 @@ class RustLibWire implements BaseWire {
           .wire__crate__api__pseudo_manual__exception_twin_sse__throw_anyhow_twin_sse(
               port_, ptr_, rust_vec_len_, data_len_);
 
+  void wire__crate__api__pseudo_manual__exception_twin_sse__throw_anyhow_with_context_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__exception_twin_sse__throw_anyhow_with_context_twin_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__exception_twin_sse__typed_exception_return_error_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__exception_twin_sse__typed_exception_return_error_twin_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
   JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
       wire__crate__api__pseudo_manual__exception_twin_sync__custom_enum_error_panic_twin_sync() =>
           wasmModule
@@ -48394,11 +51650,28 @@ class RustLibWire implements BaseWire {
               .wire__crate__api__pseudo_manual__exception_twin_sync__stream_sink_throw_anyhow_twin_sync(
                   _sink);
 
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__exception_twin_sync__throw_anyhow_io_error_twin_sync() =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__exception_twin_sync__throw_anyhow_io_error_twin_sync();
+
   JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
       wire__crate__api__pseudo_manual__exception_twin_sync__throw_anyhow_twin_sync() =>
           wasmModule
               .wire__crate__api__pseudo_manual__exception_twin_sync__throw_anyhow_twin_sync();
 
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__exception_twin_sync__throw_anyhow_with_context_twin_sync() =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__exception_twin_sync__throw_anyhow_with_context_twin_sync();
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__exception_twin_sync__typed_exception_return_error_twin_sync(
+              int variant) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__exception_twin_sync__typed_exception_return_error_twin_sync(
+                  variant);
+
   JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
       wire__crate__api__pseudo_manual__exception_twin_sync_sse__custom_enum_error_panic_twin_sync_sse(
               PlatformGeneralizedUint8ListPtr ptr_,
@@ -48651,6 +51924,15 @@ class RustLibWire implements BaseWire {
               .wire__crate__api__pseudo_manual__exception_twin_sync_sse__stream_sink_throw_anyhow_twin_sync_sse(
                   ptr_, rust_vec_len_, data_len_);
 
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__exception_twin_sync_sse__throw_anyhow_io_error_twin_sync_sse(
+              PlatformGeneralizedUint8ListPtr ptr_,
+              int rust_vec_len_,
+              int data_len_) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__exception_twin_sync_sse__throw_anyhow_io_error_twin_sync_sse(
+                  ptr_, rust_vec_len_, data_len_);
+
   JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
       wire__crate__api__pseudo_manual__exception_twin_sync_sse__throw_anyhow_twin_sync_sse(
               PlatformGeneralizedUint8ListPtr ptr_,
@@ -48660,6 +51942,24 @@ class RustLibWire implements BaseWire {
               .wire__crate__api__pseudo_manual__exception_twin_sync_sse__throw_anyhow_twin_sync_sse(
                   ptr_, rust_vec_len_, data_len_);
 
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__exception_twin_sync_sse__throw_anyhow_with_context_twin_sync_sse(
+              PlatformGeneralizedUint8ListPtr ptr_,
+              int rust_vec_len_,
+              int data_len_) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__exception_twin_sync_sse__throw_anyhow_with_context_twin_sync_sse(
+                  ptr_, rust_vec_len_, data_len_);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__exception_twin_sync_sse__typed_exception_return_error_twin_sync_sse(
+              PlatformGeneralizedUint8ListPtr ptr_,
+              int rust_vec_len_,
+              int data_len_) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__exception_twin_sync_sse__typed_exception_return_error_twin_sync_sse(
+                  ptr_, rust_vec_len_, data_len_);
+
   void wire__crate__api__pseudo_manual__external_type_in_crate_twin_rust_async__call_new_module_system_twin_rust_async(
           NativePortType port_) =>
       wasmModule
@@ -49044,6 +52344,362 @@ class RustLibWire implements BaseWire {
           .wire__crate__api__pseudo_manual__impl_trait_twin_sync_sse__StructTwoWithTraitTwinSyncSse_simple_trait_fn_with_default_impl_twin_sync_sse(
               port_);
 
+  void wire__crate__api__pseudo_manual__json_type_twin_rust_async__create_json_map_twin_rust_async(
+          NativePortType port_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__json_type_twin_rust_async__create_json_map_twin_rust_async(
+              port_);
+
+  void wire__crate__api__pseudo_manual__json_type_twin_rust_async__create_json_value_twin_rust_async(
+          NativePortType port_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__json_type_twin_rust_async__create_json_value_twin_rust_async(
+              port_);
+
+  void wire__crate__api__pseudo_manual__json_type_twin_rust_async__json_document_twin_rust_async(
+          NativePortType port_, JSAny document) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__json_type_twin_rust_async__json_document_twin_rust_async(
+              port_, document);
+
+  void wire__crate__api__pseudo_manual__json_type_twin_rust_async__json_map_keys_twin_rust_async(
+          NativePortType port_, String map) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__json_type_twin_rust_async__json_map_keys_twin_rust_async(
+              port_, map);
+
+  void wire__crate__api__pseudo_manual__json_type_twin_rust_async__json_map_twin_rust_async(
+          NativePortType port_, String map) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__json_type_twin_rust_async__json_map_twin_rust_async(
+              port_, map);
+
+  void wire__crate__api__pseudo_manual__json_type_twin_rust_async__json_value_to_string_twin_rust_async(
+          NativePortType port_, String value) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__json_type_twin_rust_async__json_value_to_string_twin_rust_async(
+              port_, value);
+
+  void wire__crate__api__pseudo_manual__json_type_twin_rust_async__json_value_twin_rust_async(
+          NativePortType port_, String value) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__json_type_twin_rust_async__json_value_twin_rust_async(
+              port_, value);
+
+  void wire__crate__api__pseudo_manual__json_type_twin_rust_async__json_values_twin_rust_async(
+          NativePortType port_, JSAny values) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__json_type_twin_rust_async__json_values_twin_rust_async(
+              port_, values);
+
+  void wire__crate__api__pseudo_manual__json_type_twin_rust_async__optional_json_value_twin_rust_async(
+          NativePortType port_, String? value) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__json_type_twin_rust_async__optional_json_value_twin_rust_async(
+              port_, value);
+
+  void wire__crate__api__pseudo_manual__json_type_twin_rust_async_sse__create_json_map_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__json_type_twin_rust_async_sse__create_json_map_twin_rust_async_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__json_type_twin_rust_async_sse__create_json_value_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__json_type_twin_rust_async_sse__create_json_value_twin_rust_async_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__json_type_twin_rust_async_sse__json_document_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__json_type_twin_rust_async_sse__json_document_twin_rust_async_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__json_type_twin_rust_async_sse__json_map_keys_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__json_type_twin_rust_async_sse__json_map_keys_twin_rust_async_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__json_type_twin_rust_async_sse__json_map_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__json_type_twin_rust_async_sse__json_map_twin_rust_async_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__json_type_twin_rust_async_sse__json_value_to_string_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__json_type_twin_rust_async_sse__json_value_to_string_twin_rust_async_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__json_type_twin_rust_async_sse__json_value_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__json_type_twin_rust_async_sse__json_value_twin_rust_async_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__json_type_twin_rust_async_sse__json_values_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__json_type_twin_rust_async_sse__json_values_twin_rust_async_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__json_type_twin_rust_async_sse__optional_json_value_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__json_type_twin_rust_async_sse__optional_json_value_twin_rust_async_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__json_type_twin_sse__create_json_map_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__json_type_twin_sse__create_json_map_twin_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__json_type_twin_sse__create_json_value_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__json_type_twin_sse__create_json_value_twin_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__json_type_twin_sse__json_document_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__json_type_twin_sse__json_document_twin_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__json_type_twin_sse__json_map_keys_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__json_type_twin_sse__json_map_keys_twin_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__json_type_twin_sse__json_map_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__json_type_twin_sse__json_map_twin_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__json_type_twin_sse__json_value_to_string_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__json_type_twin_sse__json_value_to_string_twin_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__json_type_twin_sse__json_value_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__json_type_twin_sse__json_value_twin_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__json_type_twin_sse__json_values_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__json_type_twin_sse__json_values_twin_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__json_type_twin_sse__optional_json_value_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__json_type_twin_sse__optional_json_value_twin_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__json_type_twin_sync__create_json_map_twin_sync() =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__json_type_twin_sync__create_json_map_twin_sync();
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__json_type_twin_sync__create_json_value_twin_sync() =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__json_type_twin_sync__create_json_value_twin_sync();
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__json_type_twin_sync__json_document_twin_sync(
+              JSAny document) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__json_type_twin_sync__json_document_twin_sync(
+                  document);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__json_type_twin_sync__json_map_keys_twin_sync(
+              String map) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__json_type_twin_sync__json_map_keys_twin_sync(
+                  map);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__json_type_twin_sync__json_map_twin_sync(
+              String map) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__json_type_twin_sync__json_map_twin_sync(
+                  map);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__json_type_twin_sync__json_value_to_string_twin_sync(
+              String value) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__json_type_twin_sync__json_value_to_string_twin_sync(
+                  value);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__json_type_twin_sync__json_value_twin_sync(
+              String value) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__json_type_twin_sync__json_value_twin_sync(
+                  value);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__json_type_twin_sync__json_values_twin_sync(
+              JSAny values) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__json_type_twin_sync__json_values_twin_sync(
+                  values);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__json_type_twin_sync__optional_json_value_twin_sync(
+              String? value) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__json_type_twin_sync__optional_json_value_twin_sync(
+                  value);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__json_type_twin_sync_sse__create_json_map_twin_sync_sse(
+              PlatformGeneralizedUint8ListPtr ptr_,
+              int rust_vec_len_,
+              int data_len_) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__json_type_twin_sync_sse__create_json_map_twin_sync_sse(
+                  ptr_, rust_vec_len_, data_len_);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__json_type_twin_sync_sse__create_json_value_twin_sync_sse(
+              PlatformGeneralizedUint8ListPtr ptr_,
+              int rust_vec_len_,
+              int data_len_) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__json_type_twin_sync_sse__create_json_value_twin_sync_sse(
+                  ptr_, rust_vec_len_, data_len_);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__json_type_twin_sync_sse__json_document_twin_sync_sse(
+              PlatformGeneralizedUint8ListPtr ptr_,
+              int rust_vec_len_,
+              int data_len_) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__json_type_twin_sync_sse__json_document_twin_sync_sse(
+                  ptr_, rust_vec_len_, data_len_);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__json_type_twin_sync_sse__json_map_keys_twin_sync_sse(
+              PlatformGeneralizedUint8ListPtr ptr_,
+              int rust_vec_len_,
+              int data_len_) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__json_type_twin_sync_sse__json_map_keys_twin_sync_sse(
+                  ptr_, rust_vec_len_, data_len_);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__json_type_twin_sync_sse__json_map_twin_sync_sse(
+              PlatformGeneralizedUint8ListPtr ptr_,
+              int rust_vec_len_,
+              int data_len_) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__json_type_twin_sync_sse__json_map_twin_sync_sse(
+                  ptr_, rust_vec_len_, data_len_);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__json_type_twin_sync_sse__json_value_to_string_twin_sync_sse(
+              PlatformGeneralizedUint8ListPtr ptr_,
+              int rust_vec_len_,
+              int data_len_) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__json_type_twin_sync_sse__json_value_to_string_twin_sync_sse(
+                  ptr_, rust_vec_len_, data_len_);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__json_type_twin_sync_sse__json_value_twin_sync_sse(
+              PlatformGeneralizedUint8ListPtr ptr_,
+              int rust_vec_len_,
+              int data_len_) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__json_type_twin_sync_sse__json_value_twin_sync_sse(
+                  ptr_, rust_vec_len_, data_len_);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__json_type_twin_sync_sse__json_values_twin_sync_sse(
+              PlatformGeneralizedUint8ListPtr ptr_,
+              int rust_vec_len_,
+              int data_len_) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__json_type_twin_sync_sse__json_values_twin_sync_sse(
+                  ptr_, rust_vec_len_, data_len_);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__json_type_twin_sync_sse__optional_json_value_twin_sync_sse(
+              PlatformGeneralizedUint8ListPtr ptr_,
+              int rust_vec_len_,
+              int data_len_) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__json_type_twin_sync_sse__optional_json_value_twin_sync_sse(
+                  ptr_, rust_vec_len_, data_len_);
+
   JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
       wire__crate__api__pseudo_manual__lifetimeable_twin_sync__Lifetimeable_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLtNestedTypeWithLifetimeTwinSyncstatic_greet_borrow_mut_self_twin_sync(
               PlatformGeneralizedUint8ListPtr ptr_,
@@ -49188,6 +52844,30 @@ class RustLibWire implements BaseWire {
           .wire__crate__api__pseudo_manual__lifetimeable_twin_sync__lt_compute_with_lifetime_function_twin_sync(
               port_, ptr_, rust_vec_len_, data_len_);
 
+  void wire__crate__api__pseudo_manual__map_and_set_twin_rust_async__func_btree_map_i32_i32_twin_rust_async(
+          NativePortType port_, JSAny arg) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__map_and_set_twin_rust_async__func_btree_map_i32_i32_twin_rust_async(
+              port_, arg);
+
+  void wire__crate__api__pseudo_manual__map_and_set_twin_rust_async__func_btree_map_string_struct_twin_rust_async(
+          NativePortType port_, JSAny arg) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__map_and_set_twin_rust_async__func_btree_map_string_struct_twin_rust_async(
+              port_, arg);
+
+  void wire__crate__api__pseudo_manual__map_and_set_twin_rust_async__func_btree_set_i32_twin_rust_async(
+          NativePortType port_, JSAny arg) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__map_and_set_twin_rust_async__func_btree_set_i32_twin_rust_async(
+              port_, arg);
+
+  void wire__crate__api__pseudo_manual__map_and_set_twin_rust_async__func_btree_set_string_twin_rust_async(
+          NativePortType port_, JSAny arg) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__map_and_set_twin_rust_async__func_btree_set_string_twin_rust_async(
+              port_, arg);
+
   void wire__crate__api__pseudo_manual__map_and_set_twin_rust_async__func_hash_map_i32_i32_twin_rust_async(
           NativePortType port_, JSAny arg) =>
       wasmModule
@@ -49236,6 +52916,54 @@ class RustLibWire implements BaseWire {
           .wire__crate__api__pseudo_manual__map_and_set_twin_rust_async__func_hash_set_string_twin_rust_async(
               port_, arg);
 
+  void wire__crate__api__pseudo_manual__map_and_set_twin_rust_async__func_vec_deque_i32_twin_rust_async(
+          NativePortType port_, JSAny arg) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__map_and_set_twin_rust_async__func_vec_deque_i32_twin_rust_async(
+              port_, arg);
+
+  void wire__crate__api__pseudo_manual__map_and_set_twin_rust_async__func_vec_deque_string_twin_rust_async(
+          NativePortType port_, JSAny arg) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__map_and_set_twin_rust_async__func_vec_deque_string_twin_rust_async(
+              port_, arg);
+
+  void wire__crate__api__pseudo_manual__map_and_set_twin_rust_async_sse__func_btree_map_i32_i32_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__map_and_set_twin_rust_async_sse__func_btree_map_i32_i32_twin_rust_async_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__map_and_set_twin_rust_async_sse__func_btree_map_string_struct_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__map_and_set_twin_rust_async_sse__func_btree_map_string_struct_twin_rust_async_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__map_and_set_twin_rust_async_sse__func_btree_set_i32_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__map_and_set_twin_rust_async_sse__func_btree_set_i32_twin_rust_async_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__map_and_set_twin_rust_async_sse__func_btree_set_string_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__map_and_set_twin_rust_async_sse__func_btree_set_string_twin_rust_async_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
   void wire__crate__api__pseudo_manual__map_and_set_twin_rust_async_sse__func_hash_map_i32_i32_twin_rust_async_sse(
           NativePortType port_,
           PlatformGeneralizedUint8ListPtr ptr_,
@@ -49308,6 +53036,60 @@ class RustLibWire implements BaseWire {
           .wire__crate__api__pseudo_manual__map_and_set_twin_rust_async_sse__func_hash_set_string_twin_rust_async_sse(
               port_, ptr_, rust_vec_len_, data_len_);
 
+  void wire__crate__api__pseudo_manual__map_and_set_twin_rust_async_sse__func_vec_deque_i32_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__map_and_set_twin_rust_async_sse__func_vec_deque_i32_twin_rust_async_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__map_and_set_twin_rust_async_sse__func_vec_deque_string_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__map_and_set_twin_rust_async_sse__func_vec_deque_string_twin_rust_async_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__map_and_set_twin_sse__func_btree_map_i32_i32_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__map_and_set_twin_sse__func_btree_map_i32_i32_twin_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__map_and_set_twin_sse__func_btree_map_string_struct_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__map_and_set_twin_sse__func_btree_map_string_struct_twin_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__map_and_set_twin_sse__func_btree_set_i32_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__map_and_set_twin_sse__func_btree_set_i32_twin_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__map_and_set_twin_sse__func_btree_set_string_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__map_and_set_twin_sse__func_btree_set_string_twin_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
   void wire__crate__api__pseudo_manual__map_and_set_twin_sse__func_hash_map_i32_i32_twin_sse(
           NativePortType port_,
           PlatformGeneralizedUint8ListPtr ptr_,
@@ -49380,6 +53162,52 @@ class RustLibWire implements BaseWire {
           .wire__crate__api__pseudo_manual__map_and_set_twin_sse__func_hash_set_string_twin_sse(
               port_, ptr_, rust_vec_len_, data_len_);
 
+  void wire__crate__api__pseudo_manual__map_and_set_twin_sse__func_vec_deque_i32_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__map_and_set_twin_sse__func_vec_deque_i32_twin_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__map_and_set_twin_sse__func_vec_deque_string_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__map_and_set_twin_sse__func_vec_deque_string_twin_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__map_and_set_twin_sync__func_btree_map_i32_i32_twin_sync(
+              JSAny arg) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__map_and_set_twin_sync__func_btree_map_i32_i32_twin_sync(
+                  arg);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__map_and_set_twin_sync__func_btree_map_string_struct_twin_sync(
+              JSAny arg) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__map_and_set_twin_sync__func_btree_map_string_struct_twin_sync(
+                  arg);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__map_and_set_twin_sync__func_btree_set_i32_twin_sync(
+              JSAny arg) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__map_and_set_twin_sync__func_btree_set_i32_twin_sync(
+                  arg);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__map_and_set_twin_sync__func_btree_set_string_twin_sync(
+              JSAny arg) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__map_and_set_twin_sync__func_btree_set_string_twin_sync(
+                  arg);
+
   JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
       wire__crate__api__pseudo_manual__map_and_set_twin_sync__func_hash_map_i32_i32_twin_sync(
               JSAny arg) =>
@@ -49436,6 +53264,56 @@ class RustLibWire implements BaseWire {
               .wire__crate__api__pseudo_manual__map_and_set_twin_sync__func_hash_set_string_twin_sync(
                   arg);
 
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__map_and_set_twin_sync__func_vec_deque_i32_twin_sync(
+              JSAny arg) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__map_and_set_twin_sync__func_vec_deque_i32_twin_sync(
+                  arg);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__map_and_set_twin_sync__func_vec_deque_string_twin_sync(
+              JSAny arg) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__map_and_set_twin_sync__func_vec_deque_string_twin_sync(
+                  arg);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__map_and_set_twin_sync_sse__func_btree_map_i32_i32_twin_sync_sse(
+              PlatformGeneralizedUint8ListPtr ptr_,
+              int rust_vec_len_,
+              int data_len_) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__map_and_set_twin_sync_sse__func_btree_map_i32_i32_twin_sync_sse(
+                  ptr_, rust_vec_len_, data_len_);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__map_and_set_twin_sync_sse__func_btree_map_string_struct_twin_sync_sse(
+              PlatformGeneralizedUint8ListPtr ptr_,
+              int rust_vec_len_,
+              int data_len_) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__map_and_set_twin_sync_sse__func_btree_map_string_struct_twin_sync_sse(
+                  ptr_, rust_vec_len_, data_len_);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__map_and_set_twin_sync_sse__func_btree_set_i32_twin_sync_sse(
+              PlatformGeneralizedUint8ListPtr ptr_,
+              int rust_vec_len_,
+              int data_len_) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__map_and_set_twin_sync_sse__func_btree_set_i32_twin_sync_sse(
+                  ptr_, rust_vec_len_, data_len_);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__map_and_set_twin_sync_sse__func_btree_set_string_twin_sync_sse(
+              PlatformGeneralizedUint8ListPtr ptr_,
+              int rust_vec_len_,
+              int data_len_) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__map_and_set_twin_sync_sse__func_btree_set_string_twin_sync_sse(
+                  ptr_, rust_vec_len_, data_len_);
+
   JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
       wire__crate__api__pseudo_manual__map_and_set_twin_sync_sse__func_hash_map_i32_i32_twin_sync_sse(
               PlatformGeneralizedUint8ListPtr ptr_,
@@ -49508,6 +53386,24 @@ class RustLibWire implements BaseWire {
               .wire__crate__api__pseudo_manual__map_and_set_twin_sync_sse__func_hash_set_string_twin_sync_sse(
                   ptr_, rust_vec_len_, data_len_);
 
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__map_and_set_twin_sync_sse__func_vec_deque_i32_twin_sync_sse(
+              PlatformGeneralizedUint8ListPtr ptr_,
+              int rust_vec_len_,
+              int data_len_) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__map_and_set_twin_sync_sse__func_vec_deque_i32_twin_sync_sse(
+                  ptr_, rust_vec_len_, data_len_);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__map_and_set_twin_sync_sse__func_vec_deque_string_twin_sync_sse(
+              PlatformGeneralizedUint8ListPtr ptr_,
+              int rust_vec_len_,
+              int data_len_) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__map_and_set_twin_sync_sse__func_vec_deque_string_twin_sync_sse(
+                  ptr_, rust_vec_len_, data_len_);
+
   void wire__crate__api__pseudo_manual__method_twin_rust_async__StaticGetterOnlyTwinRustAsync_static_getter_twin_rust_async(
           NativePortType port_) =>
       wasmModule
@@ -58001,6 +61897,286 @@ class RustLibWire implements BaseWire {
               .wire__crate__api__pseudo_manual__simple_twin_sync_sse__simple_adder_twin_sync_sse(
                   ptr_, rust_vec_len_, data_len_);
 
+  void wire__crate__api__pseudo_manual__std_time_twin_rust_async__std_duration_twin_rust_async(
+          NativePortType port_, JSAny d) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__std_time_twin_rust_async__std_duration_twin_rust_async(
+              port_, d);
+
+  void wire__crate__api__pseudo_manual__std_time_twin_rust_async__std_durations_twin_rust_async(
+          NativePortType port_, JSAny durations) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__std_time_twin_rust_async__std_durations_twin_rust_async(
+              port_, durations);
+
+  void wire__crate__api__pseudo_manual__std_time_twin_rust_async__std_optional_duration_twin_rust_async(
+          NativePortType port_, JSAny? d) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__std_time_twin_rust_async__std_optional_duration_twin_rust_async(
+              port_, d);
+
+  void wire__crate__api__pseudo_manual__std_time_twin_rust_async__std_optional_system_time_twin_rust_async(
+          NativePortType port_, JSAny? t) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__std_time_twin_rust_async__std_optional_system_time_twin_rust_async(
+              port_, t);
+
+  void wire__crate__api__pseudo_manual__std_time_twin_rust_async__std_system_time_twin_rust_async(
+          NativePortType port_, JSAny t) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__std_time_twin_rust_async__std_system_time_twin_rust_async(
+              port_, t);
+
+  void wire__crate__api__pseudo_manual__std_time_twin_rust_async__std_system_times_twin_rust_async(
+          NativePortType port_, JSAny times) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__std_time_twin_rust_async__std_system_times_twin_rust_async(
+              port_, times);
+
+  void wire__crate__api__pseudo_manual__std_time_twin_rust_async__std_time_struct_twin_rust_async(
+          NativePortType port_, JSAny value) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__std_time_twin_rust_async__std_time_struct_twin_rust_async(
+              port_, value);
+
+  void wire__crate__api__pseudo_manual__std_time_twin_rust_async_sse__std_duration_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__std_time_twin_rust_async_sse__std_duration_twin_rust_async_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__std_time_twin_rust_async_sse__std_durations_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__std_time_twin_rust_async_sse__std_durations_twin_rust_async_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__std_time_twin_rust_async_sse__std_optional_duration_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__std_time_twin_rust_async_sse__std_optional_duration_twin_rust_async_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__std_time_twin_rust_async_sse__std_optional_system_time_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__std_time_twin_rust_async_sse__std_optional_system_time_twin_rust_async_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__std_time_twin_rust_async_sse__std_system_time_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__std_time_twin_rust_async_sse__std_system_time_twin_rust_async_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__std_time_twin_rust_async_sse__std_system_times_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__std_time_twin_rust_async_sse__std_system_times_twin_rust_async_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__std_time_twin_rust_async_sse__std_time_struct_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__std_time_twin_rust_async_sse__std_time_struct_twin_rust_async_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__std_time_twin_sse__std_duration_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__std_time_twin_sse__std_duration_twin_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__std_time_twin_sse__std_durations_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__std_time_twin_sse__std_durations_twin_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__std_time_twin_sse__std_optional_duration_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__std_time_twin_sse__std_optional_duration_twin_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__std_time_twin_sse__std_optional_system_time_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__std_time_twin_sse__std_optional_system_time_twin_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__std_time_twin_sse__std_system_time_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__std_time_twin_sse__std_system_time_twin_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__std_time_twin_sse__std_system_times_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__std_time_twin_sse__std_system_times_twin_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__std_time_twin_sse__std_time_struct_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__std_time_twin_sse__std_time_struct_twin_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__std_time_twin_sync__std_duration_twin_sync(
+              JSAny d) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__std_time_twin_sync__std_duration_twin_sync(
+                  d);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__std_time_twin_sync__std_durations_twin_sync(
+              JSAny durations) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__std_time_twin_sync__std_durations_twin_sync(
+                  durations);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__std_time_twin_sync__std_optional_duration_twin_sync(
+              JSAny? d) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__std_time_twin_sync__std_optional_duration_twin_sync(
+                  d);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__std_time_twin_sync__std_optional_system_time_twin_sync(
+              JSAny? t) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__std_time_twin_sync__std_optional_system_time_twin_sync(
+                  t);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__std_time_twin_sync__std_system_time_twin_sync(
+              JSAny t) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__std_time_twin_sync__std_system_time_twin_sync(
+                  t);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__std_time_twin_sync__std_system_times_twin_sync(
+              JSAny times) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__std_time_twin_sync__std_system_times_twin_sync(
+                  times);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__std_time_twin_sync__std_time_struct_twin_sync(
+              JSAny value) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__std_time_twin_sync__std_time_struct_twin_sync(
+                  value);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__std_time_twin_sync_sse__std_duration_twin_sync_sse(
+              PlatformGeneralizedUint8ListPtr ptr_,
+              int rust_vec_len_,
+              int data_len_) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__std_time_twin_sync_sse__std_duration_twin_sync_sse(
+                  ptr_, rust_vec_len_, data_len_);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__std_time_twin_sync_sse__std_durations_twin_sync_sse(
+              PlatformGeneralizedUint8ListPtr ptr_,
+              int rust_vec_len_,
+              int data_len_) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__std_time_twin_sync_sse__std_durations_twin_sync_sse(
+                  ptr_, rust_vec_len_, data_len_);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__std_time_twin_sync_sse__std_optional_duration_twin_sync_sse(
+              PlatformGeneralizedUint8ListPtr ptr_,
+              int rust_vec_len_,
+              int data_len_) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__std_time_twin_sync_sse__std_optional_duration_twin_sync_sse(
+                  ptr_, rust_vec_len_, data_len_);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__std_time_twin_sync_sse__std_optional_system_time_twin_sync_sse(
+              PlatformGeneralizedUint8ListPtr ptr_,
+              int rust_vec_len_,
+              int data_len_) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__std_time_twin_sync_sse__std_optional_system_time_twin_sync_sse(
+                  ptr_, rust_vec_len_, data_len_);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__std_time_twin_sync_sse__std_system_time_twin_sync_sse(
+              PlatformGeneralizedUint8ListPtr ptr_,
+              int rust_vec_len_,
+              int data_len_) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__std_time_twin_sync_sse__std_system_time_twin_sync_sse(
+                  ptr_, rust_vec_len_, data_len_);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__std_time_twin_sync_sse__std_system_times_twin_sync_sse(
+              PlatformGeneralizedUint8ListPtr ptr_,
+              int rust_vec_len_,
+              int data_len_) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__std_time_twin_sync_sse__std_system_times_twin_sync_sse(
+                  ptr_, rust_vec_len_, data_len_);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__std_time_twin_sync_sse__std_time_struct_twin_sync_sse(
+              PlatformGeneralizedUint8ListPtr ptr_,
+              int rust_vec_len_,
+              int data_len_) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__std_time_twin_sync_sse__std_time_struct_twin_sync_sse(
+                  ptr_, rust_vec_len_, data_len_);
+
   void wire__crate__api__pseudo_manual__stream_misc_twin_sse__func_stream_realistic_twin_sse(
           NativePortType port_,
           PlatformGeneralizedUint8ListPtr ptr_,
@@ -58010,6 +62186,33 @@ class RustLibWire implements BaseWire {
           .wire__crate__api__pseudo_manual__stream_misc_twin_sse__func_stream_realistic_twin_sse(
               port_, ptr_, rust_vec_len_, data_len_);
 
+  void wire__crate__api__pseudo_manual__stream_misc_twin_sse__stream_sink_bounded_add_async_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__stream_misc_twin_sse__stream_sink_bounded_add_async_twin_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__stream_misc_twin_sse__stream_sink_bounded_add_with_error_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__stream_misc_twin_sse__stream_sink_bounded_add_with_error_twin_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__stream_misc_twin_sse__stream_sink_bounded_keep_latest_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__stream_misc_twin_sse__stream_sink_bounded_keep_latest_twin_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
   void wire__crate__api__pseudo_manual__stream_misc_twin_sse__stream_sink_dart_async_twin_sse(
           NativePortType port_,
           PlatformGeneralizedUint8ListPtr ptr_,
@@ -58563,6 +62766,366 @@ class RustLibWire implements BaseWire {
               .wire__crate__api__pseudo_manual__structure_twin_sync_sse__func_tuple_struct_with_two_field_twin_sync_sse(
                   ptr_, rust_vec_len_, data_len_);
 
+  void wire__crate__api__pseudo_manual__time_type_twin_rust_async__date_twin_rust_async(
+          NativePortType port_, JSAny d) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__time_type_twin_rust_async__date_twin_rust_async(
+              port_, d);
+
+  void wire__crate__api__pseudo_manual__time_type_twin_rust_async__negate_time_duration_twin_rust_async(
+          NativePortType port_, JSAny d) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__time_type_twin_rust_async__negate_time_duration_twin_rust_async(
+              port_, d);
+
+  void wire__crate__api__pseudo_manual__time_type_twin_rust_async__offset_date_time_twin_rust_async(
+          NativePortType port_, JSAny d) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__time_type_twin_rust_async__offset_date_time_twin_rust_async(
+              port_, d);
+
+  void wire__crate__api__pseudo_manual__time_type_twin_rust_async__optional_offset_date_time_twin_rust_async(
+          NativePortType port_, JSAny? d) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__time_type_twin_rust_async__optional_offset_date_time_twin_rust_async(
+              port_, d);
+
+  void wire__crate__api__pseudo_manual__time_type_twin_rust_async__optional_time_duration_twin_rust_async(
+          NativePortType port_, JSAny? d) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__time_type_twin_rust_async__optional_time_duration_twin_rust_async(
+              port_, d);
+
+  void wire__crate__api__pseudo_manual__time_type_twin_rust_async__primitive_date_time_twin_rust_async(
+          NativePortType port_, JSAny d) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__time_type_twin_rust_async__primitive_date_time_twin_rust_async(
+              port_, d);
+
+  void wire__crate__api__pseudo_manual__time_type_twin_rust_async__time_duration_twin_rust_async(
+          NativePortType port_, JSAny d) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__time_type_twin_rust_async__time_duration_twin_rust_async(
+              port_, d);
+
+  void wire__crate__api__pseudo_manual__time_type_twin_rust_async__time_durations_twin_rust_async(
+          NativePortType port_, JSAny durations) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__time_type_twin_rust_async__time_durations_twin_rust_async(
+              port_, durations);
+
+  void wire__crate__api__pseudo_manual__time_type_twin_rust_async__time_types_twin_rust_async(
+          NativePortType port_, JSAny value) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__time_type_twin_rust_async__time_types_twin_rust_async(
+              port_, value);
+
+  void wire__crate__api__pseudo_manual__time_type_twin_rust_async_sse__date_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__time_type_twin_rust_async_sse__date_twin_rust_async_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__time_type_twin_rust_async_sse__negate_time_duration_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__time_type_twin_rust_async_sse__negate_time_duration_twin_rust_async_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__time_type_twin_rust_async_sse__offset_date_time_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__time_type_twin_rust_async_sse__offset_date_time_twin_rust_async_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__time_type_twin_rust_async_sse__optional_offset_date_time_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__time_type_twin_rust_async_sse__optional_offset_date_time_twin_rust_async_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__time_type_twin_rust_async_sse__optional_time_duration_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__time_type_twin_rust_async_sse__optional_time_duration_twin_rust_async_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__time_type_twin_rust_async_sse__primitive_date_time_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__time_type_twin_rust_async_sse__primitive_date_time_twin_rust_async_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__time_type_twin_rust_async_sse__time_duration_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__time_type_twin_rust_async_sse__time_duration_twin_rust_async_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__time_type_twin_rust_async_sse__time_durations_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__time_type_twin_rust_async_sse__time_durations_twin_rust_async_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__time_type_twin_rust_async_sse__time_types_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__time_type_twin_rust_async_sse__time_types_twin_rust_async_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__time_type_twin_sse__date_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__time_type_twin_sse__date_twin_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__time_type_twin_sse__negate_time_duration_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__time_type_twin_sse__negate_time_duration_twin_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__time_type_twin_sse__offset_date_time_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__time_type_twin_sse__offset_date_time_twin_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__time_type_twin_sse__optional_offset_date_time_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__time_type_twin_sse__optional_offset_date_time_twin_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__time_type_twin_sse__optional_time_duration_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__time_type_twin_sse__optional_time_duration_twin_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__time_type_twin_sse__primitive_date_time_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__time_type_twin_sse__primitive_date_time_twin_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__time_type_twin_sse__time_duration_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__time_type_twin_sse__time_duration_twin_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__time_type_twin_sse__time_durations_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__time_type_twin_sse__time_durations_twin_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  void wire__crate__api__pseudo_manual__time_type_twin_sse__time_types_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_) =>
+      wasmModule
+          .wire__crate__api__pseudo_manual__time_type_twin_sse__time_types_twin_sse(
+              port_, ptr_, rust_vec_len_, data_len_);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__time_type_twin_sync__date_twin_sync(
+              JSAny d) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__time_type_twin_sync__date_twin_sync(
+                  d);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__time_type_twin_sync__negate_time_duration_twin_sync(
+              JSAny d) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__time_type_twin_sync__negate_time_duration_twin_sync(
+                  d);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__time_type_twin_sync__offset_date_time_twin_sync(
+              JSAny d) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__time_type_twin_sync__offset_date_time_twin_sync(
+                  d);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__time_type_twin_sync__optional_offset_date_time_twin_sync(
+              JSAny? d) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__time_type_twin_sync__optional_offset_date_time_twin_sync(
+                  d);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__time_type_twin_sync__optional_time_duration_twin_sync(
+              JSAny? d) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__time_type_twin_sync__optional_time_duration_twin_sync(
+                  d);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__time_type_twin_sync__primitive_date_time_twin_sync(
+              JSAny d) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__time_type_twin_sync__primitive_date_time_twin_sync(
+                  d);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__time_type_twin_sync__time_duration_twin_sync(
+              JSAny d) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__time_type_twin_sync__time_duration_twin_sync(
+                  d);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__time_type_twin_sync__time_durations_twin_sync(
+              JSAny durations) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__time_type_twin_sync__time_durations_twin_sync(
+                  durations);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__time_type_twin_sync__time_types_twin_sync(
+              JSAny value) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__time_type_twin_sync__time_types_twin_sync(
+                  value);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__time_type_twin_sync_sse__date_twin_sync_sse(
+              PlatformGeneralizedUint8ListPtr ptr_,
+              int rust_vec_len_,
+              int data_len_) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__time_type_twin_sync_sse__date_twin_sync_sse(
+                  ptr_, rust_vec_len_, data_len_);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__time_type_twin_sync_sse__negate_time_duration_twin_sync_sse(
+              PlatformGeneralizedUint8ListPtr ptr_,
+              int rust_vec_len_,
+              int data_len_) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__time_type_twin_sync_sse__negate_time_duration_twin_sync_sse(
+                  ptr_, rust_vec_len_, data_len_);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__time_type_twin_sync_sse__offset_date_time_twin_sync_sse(
+              PlatformGeneralizedUint8ListPtr ptr_,
+              int rust_vec_len_,
+              int data_len_) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__time_type_twin_sync_sse__offset_date_time_twin_sync_sse(
+                  ptr_, rust_vec_len_, data_len_);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__time_type_twin_sync_sse__optional_offset_date_time_twin_sync_sse(
+              PlatformGeneralizedUint8ListPtr ptr_,
+              int rust_vec_len_,
+              int data_len_) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__time_type_twin_sync_sse__optional_offset_date_time_twin_sync_sse(
+                  ptr_, rust_vec_len_, data_len_);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__time_type_twin_sync_sse__optional_time_duration_twin_sync_sse(
+              PlatformGeneralizedUint8ListPtr ptr_,
+              int rust_vec_len_,
+              int data_len_) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__time_type_twin_sync_sse__optional_time_duration_twin_sync_sse(
+                  ptr_, rust_vec_len_, data_len_);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__time_type_twin_sync_sse__primitive_date_time_twin_sync_sse(
+              PlatformGeneralizedUint8ListPtr ptr_,
+              int rust_vec_len_,
+              int data_len_) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__time_type_twin_sync_sse__primitive_date_time_twin_sync_sse(
+                  ptr_, rust_vec_len_, data_len_);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__time_type_twin_sync_sse__time_duration_twin_sync_sse(
+              PlatformGeneralizedUint8ListPtr ptr_,
+              int rust_vec_len_,
+              int data_len_) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__time_type_twin_sync_sse__time_duration_twin_sync_sse(
+                  ptr_, rust_vec_len_, data_len_);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__time_type_twin_sync_sse__time_durations_twin_sync_sse(
+              PlatformGeneralizedUint8ListPtr ptr_,
+              int rust_vec_len_,
+              int data_len_) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__time_type_twin_sync_sse__time_durations_twin_sync_sse(
+                  ptr_, rust_vec_len_, data_len_);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__time_type_twin_sync_sse__time_types_twin_sync_sse(
+              PlatformGeneralizedUint8ListPtr ptr_,
+              int rust_vec_len_,
+              int data_len_) =>
+          wasmModule
+              .wire__crate__api__pseudo_manual__time_type_twin_sync_sse__time_types_twin_sync_sse(
+                  ptr_, rust_vec_len_, data_len_);
+
   void wire__crate__api__pseudo_manual__tuple_twin_rust_async__test_tuple_2_twin_rust_async(
           NativePortType port_, JSAny value) =>
       wasmModule
@@ -59269,6 +63832,41 @@ class RustLibWire implements BaseWire {
       wasmModule.wire__crate__api__simple__simple_adder_twin_normal(
           port_, a, b);
 
+  void wire__crate__api__std_time__std_duration_twin_normal(
+          NativePortType port_, JSAny d) =>
+      wasmModule.wire__crate__api__std_time__std_duration_twin_normal(port_, d);
+
+  void wire__crate__api__std_time__std_durations_twin_normal(
+          NativePortType port_, JSAny durations) =>
+      wasmModule.wire__crate__api__std_time__std_durations_twin_normal(
+          port_, durations);
+
+  void wire__crate__api__std_time__std_optional_duration_twin_normal(
+          NativePortType port_, JSAny? d) =>
+      wasmModule.wire__crate__api__std_time__std_optional_duration_twin_normal(
+          port_, d);
+
+  void wire__crate__api__std_time__std_optional_system_time_twin_normal(
+          NativePortType port_, JSAny? t) =>
+      wasmModule
+          .wire__crate__api__std_time__std_optional_system_time_twin_normal(
+              port_, t);
+
+  void wire__crate__api__std_time__std_system_time_twin_normal(
+          NativePortType port_, JSAny t) =>
+      wasmModule.wire__crate__api__std_time__std_system_time_twin_normal(
+          port_, t);
+
+  void wire__crate__api__std_time__std_system_times_twin_normal(
+          NativePortType port_, JSAny times) =>
+      wasmModule.wire__crate__api__std_time__std_system_times_twin_normal(
+          port_, times);
+
+  void wire__crate__api__std_time__std_time_struct_twin_normal(
+          NativePortType port_, JSAny value) =>
+      wasmModule.wire__crate__api__std_time__std_time_struct_twin_normal(
+          port_, value);
+
   void wire__crate__api__stream__func_stream_add_value_and_error_twin_normal(
           NativePortType port_, String sink) =>
       wasmModule
@@ -59334,6 +63932,24 @@ class RustLibWire implements BaseWire {
           .wire__crate__api__stream_misc__func_stream_realistic_twin_normal(
               port_, sink, arg);
 
+  void wire__crate__api__stream_misc__stream_sink_bounded_add_async_twin_normal(
+          NativePortType port_, String sink) =>
+      wasmModule
+          .wire__crate__api__stream_misc__stream_sink_bounded_add_async_twin_normal(
+              port_, sink);
+
+  void wire__crate__api__stream_misc__stream_sink_bounded_add_with_error_twin_normal(
+          NativePortType port_, String sink) =>
+      wasmModule
+          .wire__crate__api__stream_misc__stream_sink_bounded_add_with_error_twin_normal(
+              port_, sink);
+
+  void wire__crate__api__stream_misc__stream_sink_bounded_keep_latest_twin_normal(
+          NativePortType port_, String sink) =>
+      wasmModule
+          .wire__crate__api__stream_misc__stream_sink_bounded_keep_latest_twin_normal(
+              port_, sink);
+
   void wire__crate__api__stream_misc__stream_sink_dart_async_twin_normal(
           NativePortType port_, String sink) =>
       wasmModule
@@ -59382,6 +63998,52 @@ class RustLibWire implements BaseWire {
           .wire__crate__api__structure__func_tuple_struct_with_two_field_twin_normal(
               port_, arg);
 
+  void wire__crate__api__time_type__date_twin_normal(
+          NativePortType port_, JSAny d) =>
+      wasmModule.wire__crate__api__time_type__date_twin_normal(port_, d);
+
+  void wire__crate__api__time_type__negate_time_duration_twin_normal(
+          NativePortType port_, JSAny d) =>
+      wasmModule.wire__crate__api__time_type__negate_time_duration_twin_normal(
+          port_, d);
+
+  void wire__crate__api__time_type__offset_date_time_twin_normal(
+          NativePortType port_, JSAny d) =>
+      wasmModule.wire__crate__api__time_type__offset_date_time_twin_normal(
+          port_, d);
+
+  void wire__crate__api__time_type__optional_offset_date_time_twin_normal(
+          NativePortType port_, JSAny? d) =>
+      wasmModule
+          .wire__crate__api__time_type__optional_offset_date_time_twin_normal(
+              port_, d);
+
+  void wire__crate__api__time_type__optional_time_duration_twin_normal(
+          NativePortType port_, JSAny? d) =>
+      wasmModule
+          .wire__crate__api__time_type__optional_time_duration_twin_normal(
+              port_, d);
+
+  void wire__crate__api__time_type__primitive_date_time_twin_normal(
+          NativePortType port_, JSAny d) =>
+      wasmModule.wire__crate__api__time_type__primitive_date_time_twin_normal(
+          port_, d);
+
+  void wire__crate__api__time_type__time_duration_twin_normal(
+          NativePortType port_, JSAny d) =>
+      wasmModule.wire__crate__api__time_type__time_duration_twin_normal(
+          port_, d);
+
+  void wire__crate__api__time_type__time_durations_twin_normal(
+          NativePortType port_, JSAny durations) =>
+      wasmModule.wire__crate__api__time_type__time_durations_twin_normal(
+          port_, durations);
+
+  void wire__crate__api__time_type__time_types_twin_normal(
+          NativePortType port_, JSAny value) =>
+      wasmModule.wire__crate__api__time_type__time_types_twin_normal(
+          port_, value);
+
   void wire__crate__api__tuple__test_tuple_2_twin_normal(
           NativePortType port_, JSAny value) =>
       wasmModule.wire__crate__api__tuple__test_tuple_2_twin_normal(
@@ -59423,6 +64085,26 @@ class RustLibWire implements BaseWire {
       wasmModule.wire__crate__api__uuid_type__handle_uuids_twin_normal(
           port_, ids);
 
+  void wire__crate__frb_generated__DartImplementableTraitTwinNormalDartImpl_add_twin_normal(
+          NativePortType port_, int that, int a, int b) =>
+      wasmModule
+          .wire__crate__frb_generated__DartImplementableTraitTwinNormalDartImpl_add_twin_normal(
+              port_, that, a, b);
+
+  void wire__crate__frb_generated__DartImplementableTraitTwinNormalDartImpl_describe_twin_normal(
+          NativePortType port_, int that, int value) =>
+      wasmModule
+          .wire__crate__frb_generated__DartImplementableTraitTwinNormalDartImpl_describe_twin_normal(
+              port_, that, value);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__frb_generated__DartImplementableTraitTwinNormalDartImpl_new(
+              PlatformPointer add_twin_normal,
+              PlatformPointer describe_twin_normal) =>
+          wasmModule
+              .wire__crate__frb_generated__DartImplementableTraitTwinNormalDartImpl_new(
+                  add_twin_normal, describe_twin_normal);
+
   void rust_arc_increment_strong_count_RustOpaque_BoxdynDartDebugTwinMoi(
           int ptr) =>
       wasmModule
@@ -60541,6 +65223,18 @@ class RustLibWire implements BaseWire {
           .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConstructorOpaqueSyncStructTwinNormal(
               ptr);
 
+  void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl(
+          int ptr) =>
+      wasmModule
+          .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl(
+              ptr);
+
+  void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl(
+          int ptr) =>
+      wasmModule
+          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl(
+              ptr);
+
   void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeliberateFailSanityCheckTwinNormal(
           int ptr) =>
       wasmModule
@@ -61825,6 +66519,18 @@ class RustLibWire implements BaseWire {
           .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitTwinSyncSse(
               ptr);
 
+  void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+          int ptr) =>
+      wasmModule
+          .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+              ptr);
+
+  void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+          int ptr) =>
+      wasmModule
+          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+              ptr);
+
   void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinMoi(
           int ptr) =>
       wasmModule
@@ -62047,12 +66753,20 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
   external void wire__crate__api__array__use_msgid_twin_normal(
       NativePortType port_, JSAny id);
 
+  external void
+      wire__crate__api__async_misc__func_async_never_complete_twin_normal(
+          NativePortType port_);
+
   external void wire__crate__api__async_misc__func_async_simple_add_twin_normal(
       NativePortType port_, int a, int b);
 
   external void wire__crate__api__async_misc__func_async_void_twin_normal(
       NativePortType port_);
 
+  external void
+      wire__crate__api__async_misc__func_async_wait_for_cancellation_twin_normal(
+          NativePortType port_);
+
   external void wire__crate__api__async_spawn__simple_use_async_spawn(
       NativePortType port_, String arg);
 
@@ -62072,6 +66786,33 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
   external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
       wire__crate__api__benchmark_misc__benchmark_void_semi_serialize();
 
+  external void wire__crate__api__big_number__add_decimals_twin_normal(
+      NativePortType port_, String a, String b);
+
+  external void wire__crate__api__big_number__big_int_twin_normal(
+      NativePortType port_, String i);
+
+  external void wire__crate__api__big_number__big_ints_twin_normal(
+      NativePortType port_, JSAny ints);
+
+  external void wire__crate__api__big_number__big_numbers_twin_normal(
+      NativePortType port_, JSAny value);
+
+  external void wire__crate__api__big_number__decimal_twin_normal(
+      NativePortType port_, String d);
+
+  external void wire__crate__api__big_number__decimals_twin_normal(
+      NativePortType port_, JSAny decimals);
+
+  external void wire__crate__api__big_number__multiply_big_ints_twin_normal(
+      NativePortType port_, String a, String b);
+
+  external void wire__crate__api__big_number__optional_big_int_twin_normal(
+      NativePortType port_, String? i);
+
+  external void wire__crate__api__big_number__optional_decimal_twin_normal(
+      NativePortType port_, String? d);
+
   external void
       wire__crate__api__casted_primitive__casted_primitive_i64_twin_normal(
           NativePortType port_,
@@ -62114,6 +66855,14 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
           int rust_vec_len_,
           int data_len_);
 
+  external void
+      wire__crate__api__chrono_type__datetime_fixed_offset_twin_normal(
+          NativePortType port_, String d);
+
+  external void
+      wire__crate__api__chrono_type__datetime_fixed_offsets_twin_normal(
+          NativePortType port_, JSAny dates);
+
   external void wire__crate__api__chrono_type__datetime_local_twin_normal(
       NativePortType port_, JSAny d);
 
@@ -62133,19 +66882,68 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
       wire__crate__api__chrono_type__how_long_does_it_take_twin_normal(
           NativePortType port_, JSAny mine);
 
+  external void wire__crate__api__chrono_type__naive_date_twin_normal(
+      NativePortType port_, JSAny d);
+
+  external void wire__crate__api__chrono_type__naive_dates_twin_normal(
+      NativePortType port_, JSAny dates);
+
+  external void wire__crate__api__chrono_type__naive_time_twin_normal(
+      NativePortType port_, JSAny d);
+
   external void wire__crate__api__chrono_type__naivedatetime_twin_normal(
       NativePortType port_, JSAny d);
 
+  external void
+      wire__crate__api__chrono_type__optional_datetime_fixed_offset_twin_normal(
+          NativePortType port_, String? d);
+
   external void
       wire__crate__api__chrono_type__optional_empty_datetime_utc_twin_normal(
           NativePortType port_, JSAny? d);
 
+  external void wire__crate__api__chrono_type__schedule_twin_normal(
+      NativePortType port_, JSAny schedule);
+
   external void wire__crate__api__chrono_type__test_chrono_twin_normal(
       NativePortType port_);
 
   external void wire__crate__api__chrono_type__test_precise_chrono_twin_normal(
       NativePortType port_);
 
+  external void wire__crate__api__chrono_tz_type__datetime_tz_name_twin_normal(
+      NativePortType port_, String d);
+
+  external void
+      wire__crate__api__chrono_tz_type__datetime_tz_to_fixed_offset_twin_normal(
+          NativePortType port_, String d);
+
+  external void wire__crate__api__chrono_tz_type__datetime_tz_twin_normal(
+      NativePortType port_,
+      PlatformGeneralizedUint8ListPtr ptr_,
+      int rust_vec_len_,
+      int data_len_);
+
+  external void wire__crate__api__chrono_tz_type__datetime_tzs_twin_normal(
+      NativePortType port_,
+      PlatformGeneralizedUint8ListPtr ptr_,
+      int rust_vec_len_,
+      int data_len_);
+
+  external void
+      wire__crate__api__chrono_tz_type__datetime_with_time_zone_twin_normal(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__chrono_tz_type__optional_datetime_tz_twin_normal(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
   external void
       wire__crate__api__comment__function_with_comments_slash_star_star_twin_normal(
           NativePortType port_);
@@ -62268,6 +67066,14 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
       wire__crate__api__dart_fn__rust_call_dart_with_dart_opaque_result_twin_normal(
           NativePortType port_, PlatformPointer callback);
 
+  external void
+      wire__crate__api__dart_fn__rust_consume_dart_stream_sum_twin_normal(
+          NativePortType port_, PlatformPointer stream);
+
+  external void
+      wire__crate__api__dart_fn__rust_consume_dart_stream_take_twin_normal(
+          NativePortType port_, PlatformPointer stream, JSAny count);
+
   external void
       wire__crate__api__dart_opaque__async_accept_dart_opaque_twin_normal(
           NativePortType port_, PlatformPointer opaque);
@@ -62407,6 +67213,48 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
           int rust_vec_len_,
           int data_len_);
 
+  external void
+      wire__crate__api__dyn_trait__StructWithDartImplementableTraitTwinNormal_add_twin_normal(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__dyn_trait__StructWithDartImplementableTraitTwinNormal_auto_accessor_get_base(
+          int that);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__dyn_trait__StructWithDartImplementableTraitTwinNormal_auto_accessor_set_base(
+          int that, int base);
+
+  external void
+      wire__crate__api__dyn_trait__StructWithDartImplementableTraitTwinNormal_describe_twin_normal(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__dyn_trait__StructWithDartImplementableTraitTwinNormal_new(
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__dyn_trait__func_arg_box_dyn_dart_implementable_async_twin_normal(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__dyn_trait__func_arg_box_dyn_dart_implementable_sync_twin_normal(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
   external void wire__crate__api__dyn_trait__func_arg_dyn_trait_twin_normal(
       NativePortType port_,
       PlatformGeneralizedUint8ListPtr ptr_,
@@ -62576,9 +67424,20 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
       wire__crate__api__exception__stream_sink_throw_anyhow_twin_normal(
           NativePortType port_, String _sink);
 
+  external void wire__crate__api__exception__throw_anyhow_io_error_twin_normal(
+      NativePortType port_);
+
   external void wire__crate__api__exception__throw_anyhow_twin_normal(
       NativePortType port_);
 
+  external void
+      wire__crate__api__exception__throw_anyhow_with_context_twin_normal(
+          NativePortType port_);
+
+  external void
+      wire__crate__api__exception__typed_exception_return_error_twin_normal(
+          NativePortType port_, int variant);
+
   external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
       wire__crate__api__external_impl__SimpleOpaqueExternalStructWithMethod_new(
           String a);
@@ -62662,6 +67521,33 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
   external void wire__crate__api__inside_macro__func_macro_struct_twin_normal(
       NativePortType port_, JSAny arg);
 
+  external void wire__crate__api__json_type__create_json_map_twin_normal(
+      NativePortType port_);
+
+  external void wire__crate__api__json_type__create_json_value_twin_normal(
+      NativePortType port_);
+
+  external void wire__crate__api__json_type__json_document_twin_normal(
+      NativePortType port_, JSAny document);
+
+  external void wire__crate__api__json_type__json_map_keys_twin_normal(
+      NativePortType port_, String map);
+
+  external void wire__crate__api__json_type__json_map_twin_normal(
+      NativePortType port_, String map);
+
+  external void wire__crate__api__json_type__json_value_to_string_twin_normal(
+      NativePortType port_, String value);
+
+  external void wire__crate__api__json_type__json_value_twin_normal(
+      NativePortType port_, String value);
+
+  external void wire__crate__api__json_type__json_values_twin_normal(
+      NativePortType port_, JSAny values);
+
+  external void wire__crate__api__json_type__optional_json_value_twin_normal(
+      NativePortType port_, String? value);
+
   external void
       wire__crate__api__lifetimeable__Lifetimeable_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLtNestedTypeWithLifetimeTwinNormalstatic_greet_borrow_mut_self_twin_normal(
           NativePortType port_,
@@ -62774,6 +67660,21 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
           int rust_vec_len_,
           int data_len_);
 
+  external void
+      wire__crate__api__map_and_set__func_btree_map_i32_i32_twin_normal(
+          NativePortType port_, JSAny arg);
+
+  external void
+      wire__crate__api__map_and_set__func_btree_map_string_struct_twin_normal(
+          NativePortType port_, JSAny arg);
+
+  external void wire__crate__api__map_and_set__func_btree_set_i32_twin_normal(
+      NativePortType port_, JSAny arg);
+
+  external void
+      wire__crate__api__map_and_set__func_btree_set_string_twin_normal(
+          NativePortType port_, JSAny arg);
+
   external void
       wire__crate__api__map_and_set__func_hash_map_i32_i32_twin_normal(
           NativePortType port_, JSAny arg);
@@ -62804,6 +67705,13 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
   external void wire__crate__api__map_and_set__func_hash_set_string_twin_normal(
       NativePortType port_, JSAny arg);
 
+  external void wire__crate__api__map_and_set__func_vec_deque_i32_twin_normal(
+      NativePortType port_, JSAny arg);
+
+  external void
+      wire__crate__api__map_and_set__func_vec_deque_string_twin_normal(
+          NativePortType port_, JSAny arg);
+
   external void
       wire__crate__api__method__StaticGetterOnlyTwinNormal_static_getter_twin_normal(
           NativePortType port_);
@@ -63581,6 +68489,13 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
           int rust_vec_len_,
           int data_len_);
 
+  external void
+      wire__crate__api__pseudo_manual__async_misc_twin_sse__func_async_never_complete_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
   external void
       wire__crate__api__pseudo_manual__async_misc_twin_sse__func_async_simple_add_twin_sse(
           NativePortType port_,
@@ -63595,6 +68510,13 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
           int rust_vec_len_,
           int data_len_);
 
+  external void
+      wire__crate__api__pseudo_manual__async_misc_twin_sse__func_async_wait_for_cancellation_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
   external void
       wire__crate__api__pseudo_manual__attribute_twin_rust_async__handle_customized_struct_twin_rust_async(
           NativePortType port_, JSAny val);
@@ -66562,6 +71484,266 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
           int rust_vec_len_,
           int data_len_);
 
+  external void
+      wire__crate__api__pseudo_manual__big_number_twin_rust_async__add_decimals_twin_rust_async(
+          NativePortType port_, String a, String b);
+
+  external void
+      wire__crate__api__pseudo_manual__big_number_twin_rust_async__big_int_twin_rust_async(
+          NativePortType port_, String i);
+
+  external void
+      wire__crate__api__pseudo_manual__big_number_twin_rust_async__big_ints_twin_rust_async(
+          NativePortType port_, JSAny ints);
+
+  external void
+      wire__crate__api__pseudo_manual__big_number_twin_rust_async__big_numbers_twin_rust_async(
+          NativePortType port_, JSAny value);
+
+  external void
+      wire__crate__api__pseudo_manual__big_number_twin_rust_async__decimal_twin_rust_async(
+          NativePortType port_, String d);
+
+  external void
+      wire__crate__api__pseudo_manual__big_number_twin_rust_async__decimals_twin_rust_async(
+          NativePortType port_, JSAny decimals);
+
+  external void
+      wire__crate__api__pseudo_manual__big_number_twin_rust_async__multiply_big_ints_twin_rust_async(
+          NativePortType port_, String a, String b);
+
+  external void
+      wire__crate__api__pseudo_manual__big_number_twin_rust_async__optional_big_int_twin_rust_async(
+          NativePortType port_, String? i);
+
+  external void
+      wire__crate__api__pseudo_manual__big_number_twin_rust_async__optional_decimal_twin_rust_async(
+          NativePortType port_, String? d);
+
+  external void
+      wire__crate__api__pseudo_manual__big_number_twin_rust_async_sse__add_decimals_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__big_number_twin_rust_async_sse__big_int_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__big_number_twin_rust_async_sse__big_ints_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__big_number_twin_rust_async_sse__big_numbers_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__big_number_twin_rust_async_sse__decimal_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__big_number_twin_rust_async_sse__decimals_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__big_number_twin_rust_async_sse__multiply_big_ints_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__big_number_twin_rust_async_sse__optional_big_int_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__big_number_twin_rust_async_sse__optional_decimal_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__big_number_twin_sse__add_decimals_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__big_number_twin_sse__big_int_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__big_number_twin_sse__big_ints_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__big_number_twin_sse__big_numbers_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__big_number_twin_sse__decimal_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__big_number_twin_sse__decimals_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__big_number_twin_sse__multiply_big_ints_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__big_number_twin_sse__optional_big_int_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__big_number_twin_sse__optional_decimal_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__big_number_twin_sync__add_decimals_twin_sync(
+          String a, String b);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__big_number_twin_sync__big_int_twin_sync(
+          String i);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__big_number_twin_sync__big_ints_twin_sync(
+          JSAny ints);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__big_number_twin_sync__big_numbers_twin_sync(
+          JSAny value);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__big_number_twin_sync__decimal_twin_sync(
+          String d);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__big_number_twin_sync__decimals_twin_sync(
+          JSAny decimals);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__big_number_twin_sync__multiply_big_ints_twin_sync(
+          String a, String b);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__big_number_twin_sync__optional_big_int_twin_sync(
+          String? i);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__big_number_twin_sync__optional_decimal_twin_sync(
+          String? d);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__big_number_twin_sync_sse__add_decimals_twin_sync_sse(
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__big_number_twin_sync_sse__big_int_twin_sync_sse(
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__big_number_twin_sync_sse__big_ints_twin_sync_sse(
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__big_number_twin_sync_sse__big_numbers_twin_sync_sse(
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__big_number_twin_sync_sse__decimal_twin_sync_sse(
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__big_number_twin_sync_sse__decimals_twin_sync_sse(
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__big_number_twin_sync_sse__multiply_big_ints_twin_sync_sse(
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__big_number_twin_sync_sse__optional_big_int_twin_sync_sse(
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__big_number_twin_sync_sse__optional_decimal_twin_sync_sse(
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__datetime_fixed_offset_twin_rust_async(
+          NativePortType port_, String d);
+
+  external void
+      wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__datetime_fixed_offsets_twin_rust_async(
+          NativePortType port_, JSAny dates);
+
   external void
       wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__datetime_local_twin_rust_async(
           NativePortType port_, JSAny d);
@@ -66586,14 +71768,34 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
       wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__how_long_does_it_take_twin_rust_async(
           NativePortType port_, JSAny mine);
 
+  external void
+      wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__naive_date_twin_rust_async(
+          NativePortType port_, JSAny d);
+
+  external void
+      wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__naive_dates_twin_rust_async(
+          NativePortType port_, JSAny dates);
+
+  external void
+      wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__naive_time_twin_rust_async(
+          NativePortType port_, JSAny d);
+
   external void
       wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__naivedatetime_twin_rust_async(
           NativePortType port_, JSAny d);
 
+  external void
+      wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__optional_datetime_fixed_offset_twin_rust_async(
+          NativePortType port_, String? d);
+
   external void
       wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__optional_empty_datetime_utc_twin_rust_async(
           NativePortType port_, JSAny? d);
 
+  external void
+      wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__schedule_twin_rust_async(
+          NativePortType port_, JSAny schedule);
+
   external void
       wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__test_chrono_twin_rust_async(
           NativePortType port_);
@@ -66602,6 +71804,20 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
       wire__crate__api__pseudo_manual__chrono_type_twin_rust_async__test_precise_chrono_twin_rust_async(
           NativePortType port_);
 
+  external void
+      wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__datetime_fixed_offset_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__datetime_fixed_offsets_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
   external void
       wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__datetime_local_twin_rust_async_sse(
           NativePortType port_,
@@ -66644,6 +71860,27 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
           int rust_vec_len_,
           int data_len_);
 
+  external void
+      wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__naive_date_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__naive_dates_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__naive_time_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
   external void
       wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__naivedatetime_twin_rust_async_sse(
           NativePortType port_,
@@ -66651,6 +71888,13 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
           int rust_vec_len_,
           int data_len_);
 
+  external void
+      wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__optional_datetime_fixed_offset_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
   external void
       wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__optional_empty_datetime_utc_twin_rust_async_sse(
           NativePortType port_,
@@ -66658,6 +71902,13 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
           int rust_vec_len_,
           int data_len_);
 
+  external void
+      wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__schedule_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
   external void
       wire__crate__api__pseudo_manual__chrono_type_twin_rust_async_sse__test_chrono_twin_rust_async_sse(
           NativePortType port_,
@@ -66672,6 +71923,20 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
           int rust_vec_len_,
           int data_len_);
 
+  external void
+      wire__crate__api__pseudo_manual__chrono_type_twin_sse__datetime_fixed_offset_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__chrono_type_twin_sse__datetime_fixed_offsets_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
   external void
       wire__crate__api__pseudo_manual__chrono_type_twin_sse__datetime_local_twin_sse(
           NativePortType port_,
@@ -66714,6 +71979,27 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
           int rust_vec_len_,
           int data_len_);
 
+  external void
+      wire__crate__api__pseudo_manual__chrono_type_twin_sse__naive_date_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__chrono_type_twin_sse__naive_dates_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__chrono_type_twin_sse__naive_time_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
   external void
       wire__crate__api__pseudo_manual__chrono_type_twin_sse__naivedatetime_twin_sse(
           NativePortType port_,
@@ -66721,6 +72007,13 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
           int rust_vec_len_,
           int data_len_);
 
+  external void
+      wire__crate__api__pseudo_manual__chrono_type_twin_sse__optional_datetime_fixed_offset_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
   external void
       wire__crate__api__pseudo_manual__chrono_type_twin_sse__optional_empty_datetime_utc_twin_sse(
           NativePortType port_,
@@ -66728,6 +72021,13 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
           int rust_vec_len_,
           int data_len_);
 
+  external void
+      wire__crate__api__pseudo_manual__chrono_type_twin_sse__schedule_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
   external void
       wire__crate__api__pseudo_manual__chrono_type_twin_sse__test_chrono_twin_sse(
           NativePortType port_,
@@ -66742,6 +72042,14 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
           int rust_vec_len_,
           int data_len_);
 
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__chrono_type_twin_sync__datetime_fixed_offset_twin_sync(
+          String d);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__chrono_type_twin_sync__datetime_fixed_offsets_twin_sync(
+          JSAny dates);
+
   external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
       wire__crate__api__pseudo_manual__chrono_type_twin_sync__datetime_local_twin_sync(
           JSAny d);
@@ -66766,20 +72074,52 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
       wire__crate__api__pseudo_manual__chrono_type_twin_sync__how_long_does_it_take_twin_sync(
           JSAny mine);
 
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__chrono_type_twin_sync__naive_date_twin_sync(
+          JSAny d);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__chrono_type_twin_sync__naive_dates_twin_sync(
+          JSAny dates);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__chrono_type_twin_sync__naive_time_twin_sync(
+          JSAny d);
+
   external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
       wire__crate__api__pseudo_manual__chrono_type_twin_sync__naivedatetime_twin_sync(
           JSAny d);
 
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__chrono_type_twin_sync__optional_datetime_fixed_offset_twin_sync(
+          String? d);
+
   external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
       wire__crate__api__pseudo_manual__chrono_type_twin_sync__optional_empty_datetime_utc_twin_sync(
           JSAny? d);
 
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__chrono_type_twin_sync__schedule_twin_sync(
+          JSAny schedule);
+
   external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
       wire__crate__api__pseudo_manual__chrono_type_twin_sync__test_chrono_twin_sync();
 
   external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
       wire__crate__api__pseudo_manual__chrono_type_twin_sync__test_precise_chrono_twin_sync();
 
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__chrono_type_twin_sync_sse__datetime_fixed_offset_twin_sync_sse(
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__chrono_type_twin_sync_sse__datetime_fixed_offsets_twin_sync_sse(
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
   external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
       wire__crate__api__pseudo_manual__chrono_type_twin_sync_sse__datetime_local_twin_sync_sse(
           PlatformGeneralizedUint8ListPtr ptr_,
@@ -66816,18 +72156,48 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
           int rust_vec_len_,
           int data_len_);
 
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__chrono_type_twin_sync_sse__naive_date_twin_sync_sse(
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__chrono_type_twin_sync_sse__naive_dates_twin_sync_sse(
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__chrono_type_twin_sync_sse__naive_time_twin_sync_sse(
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
   external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
       wire__crate__api__pseudo_manual__chrono_type_twin_sync_sse__naivedatetime_twin_sync_sse(
           PlatformGeneralizedUint8ListPtr ptr_,
           int rust_vec_len_,
           int data_len_);
 
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__chrono_type_twin_sync_sse__optional_datetime_fixed_offset_twin_sync_sse(
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
   external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
       wire__crate__api__pseudo_manual__chrono_type_twin_sync_sse__optional_empty_datetime_utc_twin_sync_sse(
           PlatformGeneralizedUint8ListPtr ptr_,
           int rust_vec_len_,
           int data_len_);
 
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__chrono_type_twin_sync_sse__schedule_twin_sync_sse(
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
   external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
       wire__crate__api__pseudo_manual__chrono_type_twin_sync_sse__test_chrono_twin_sync_sse(
           PlatformGeneralizedUint8ListPtr ptr_,
@@ -67023,6 +72393,14 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
       wire__crate__api__pseudo_manual__dart_fn_twin_rust_async__rust_call_dart_with_dart_opaque_result_twin_rust_async(
           NativePortType port_, PlatformPointer callback);
 
+  external void
+      wire__crate__api__pseudo_manual__dart_fn_twin_rust_async__rust_consume_dart_stream_sum_twin_rust_async(
+          NativePortType port_, PlatformPointer stream);
+
+  external void
+      wire__crate__api__pseudo_manual__dart_fn_twin_rust_async__rust_consume_dart_stream_take_twin_rust_async(
+          NativePortType port_, PlatformPointer stream, JSAny count);
+
   external void
       wire__crate__api__pseudo_manual__dart_fn_twin_rust_async_sse__rust_call_dart_loopback_twin_rust_async_sse(
           NativePortType port_,
@@ -67086,6 +72464,20 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
           int rust_vec_len_,
           int data_len_);
 
+  external void
+      wire__crate__api__pseudo_manual__dart_fn_twin_rust_async_sse__rust_consume_dart_stream_sum_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__dart_fn_twin_rust_async_sse__rust_consume_dart_stream_take_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
   external void
       wire__crate__api__pseudo_manual__dart_fn_twin_sse__rust_call_dart_loopback_twin_sse(
           NativePortType port_,
@@ -67149,6 +72541,20 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
           int rust_vec_len_,
           int data_len_);
 
+  external void
+      wire__crate__api__pseudo_manual__dart_fn_twin_sse__rust_consume_dart_stream_sum_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__dart_fn_twin_sse__rust_consume_dart_stream_take_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
   external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
       wire__crate__api__pseudo_manual__dart_opaque_sync_twin_sse__sync_accept_dart_opaque_twin_sse(
           PlatformGeneralizedUint8ListPtr ptr_,
@@ -68203,10 +73609,22 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
       wire__crate__api__pseudo_manual__exception_twin_rust_async__stream_sink_throw_anyhow_twin_rust_async(
           NativePortType port_, String _sink);
 
+  external void
+      wire__crate__api__pseudo_manual__exception_twin_rust_async__throw_anyhow_io_error_twin_rust_async(
+          NativePortType port_);
+
   external void
       wire__crate__api__pseudo_manual__exception_twin_rust_async__throw_anyhow_twin_rust_async(
           NativePortType port_);
 
+  external void
+      wire__crate__api__pseudo_manual__exception_twin_rust_async__throw_anyhow_with_context_twin_rust_async(
+          NativePortType port_);
+
+  external void
+      wire__crate__api__pseudo_manual__exception_twin_rust_async__typed_exception_return_error_twin_rust_async(
+          NativePortType port_, int variant);
+
   external void
       wire__crate__api__pseudo_manual__exception_twin_rust_async_sse__custom_enum_error_panic_twin_rust_async_sse(
           NativePortType port_,
@@ -68403,6 +73821,13 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
           int rust_vec_len_,
           int data_len_);
 
+  external void
+      wire__crate__api__pseudo_manual__exception_twin_rust_async_sse__throw_anyhow_io_error_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
   external void
       wire__crate__api__pseudo_manual__exception_twin_rust_async_sse__throw_anyhow_twin_rust_async_sse(
           NativePortType port_,
@@ -68410,6 +73835,20 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
           int rust_vec_len_,
           int data_len_);
 
+  external void
+      wire__crate__api__pseudo_manual__exception_twin_rust_async_sse__throw_anyhow_with_context_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__exception_twin_rust_async_sse__typed_exception_return_error_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
   external void
       wire__crate__api__pseudo_manual__exception_twin_sse__custom_enum_error_panic_twin_sse(
           NativePortType port_,
@@ -68606,6 +74045,13 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
           int rust_vec_len_,
           int data_len_);
 
+  external void
+      wire__crate__api__pseudo_manual__exception_twin_sse__throw_anyhow_io_error_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
   external void
       wire__crate__api__pseudo_manual__exception_twin_sse__throw_anyhow_twin_sse(
           NativePortType port_,
@@ -68613,6 +74059,20 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
           int rust_vec_len_,
           int data_len_);
 
+  external void
+      wire__crate__api__pseudo_manual__exception_twin_sse__throw_anyhow_with_context_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__exception_twin_sse__typed_exception_return_error_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
   external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
       wire__crate__api__pseudo_manual__exception_twin_sync__custom_enum_error_panic_twin_sync();
 
@@ -68708,9 +74168,19 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
       wire__crate__api__pseudo_manual__exception_twin_sync__stream_sink_throw_anyhow_twin_sync(
           String _sink);
 
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__exception_twin_sync__throw_anyhow_io_error_twin_sync();
+
   external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
       wire__crate__api__pseudo_manual__exception_twin_sync__throw_anyhow_twin_sync();
 
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__exception_twin_sync__throw_anyhow_with_context_twin_sync();
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__exception_twin_sync__typed_exception_return_error_twin_sync(
+          int variant);
+
   external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
       wire__crate__api__pseudo_manual__exception_twin_sync_sse__custom_enum_error_panic_twin_sync_sse(
           PlatformGeneralizedUint8ListPtr ptr_,
@@ -68879,12 +74349,30 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
           int rust_vec_len_,
           int data_len_);
 
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__exception_twin_sync_sse__throw_anyhow_io_error_twin_sync_sse(
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
   external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
       wire__crate__api__pseudo_manual__exception_twin_sync_sse__throw_anyhow_twin_sync_sse(
           PlatformGeneralizedUint8ListPtr ptr_,
           int rust_vec_len_,
           int data_len_);
 
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__exception_twin_sync_sse__throw_anyhow_with_context_twin_sync_sse(
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__exception_twin_sync_sse__typed_exception_return_error_twin_sync_sse(
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
   external void
       wire__crate__api__pseudo_manual__external_type_in_crate_twin_rust_async__call_new_module_system_twin_rust_async(
           NativePortType port_);
@@ -69139,6 +74627,256 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
       wire__crate__api__pseudo_manual__impl_trait_twin_sync_sse__StructTwoWithTraitTwinSyncSse_simple_trait_fn_with_default_impl_twin_sync_sse(
           NativePortType port_);
 
+  external void
+      wire__crate__api__pseudo_manual__json_type_twin_rust_async__create_json_map_twin_rust_async(
+          NativePortType port_);
+
+  external void
+      wire__crate__api__pseudo_manual__json_type_twin_rust_async__create_json_value_twin_rust_async(
+          NativePortType port_);
+
+  external void
+      wire__crate__api__pseudo_manual__json_type_twin_rust_async__json_document_twin_rust_async(
+          NativePortType port_, JSAny document);
+
+  external void
+      wire__crate__api__pseudo_manual__json_type_twin_rust_async__json_map_keys_twin_rust_async(
+          NativePortType port_, String map);
+
+  external void
+      wire__crate__api__pseudo_manual__json_type_twin_rust_async__json_map_twin_rust_async(
+          NativePortType port_, String map);
+
+  external void
+      wire__crate__api__pseudo_manual__json_type_twin_rust_async__json_value_to_string_twin_rust_async(
+          NativePortType port_, String value);
+
+  external void
+      wire__crate__api__pseudo_manual__json_type_twin_rust_async__json_value_twin_rust_async(
+          NativePortType port_, String value);
+
+  external void
+      wire__crate__api__pseudo_manual__json_type_twin_rust_async__json_values_twin_rust_async(
+          NativePortType port_, JSAny values);
+
+  external void
+      wire__crate__api__pseudo_manual__json_type_twin_rust_async__optional_json_value_twin_rust_async(
+          NativePortType port_, String? value);
+
+  external void
+      wire__crate__api__pseudo_manual__json_type_twin_rust_async_sse__create_json_map_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__json_type_twin_rust_async_sse__create_json_value_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__json_type_twin_rust_async_sse__json_document_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__json_type_twin_rust_async_sse__json_map_keys_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__json_type_twin_rust_async_sse__json_map_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__json_type_twin_rust_async_sse__json_value_to_string_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__json_type_twin_rust_async_sse__json_value_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__json_type_twin_rust_async_sse__json_values_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__json_type_twin_rust_async_sse__optional_json_value_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__json_type_twin_sse__create_json_map_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__json_type_twin_sse__create_json_value_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__json_type_twin_sse__json_document_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__json_type_twin_sse__json_map_keys_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__json_type_twin_sse__json_map_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__json_type_twin_sse__json_value_to_string_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__json_type_twin_sse__json_value_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__json_type_twin_sse__json_values_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__json_type_twin_sse__optional_json_value_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__json_type_twin_sync__create_json_map_twin_sync();
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__json_type_twin_sync__create_json_value_twin_sync();
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__json_type_twin_sync__json_document_twin_sync(
+          JSAny document);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__json_type_twin_sync__json_map_keys_twin_sync(
+          String map);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__json_type_twin_sync__json_map_twin_sync(
+          String map);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__json_type_twin_sync__json_value_to_string_twin_sync(
+          String value);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__json_type_twin_sync__json_value_twin_sync(
+          String value);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__json_type_twin_sync__json_values_twin_sync(
+          JSAny values);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__json_type_twin_sync__optional_json_value_twin_sync(
+          String? value);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__json_type_twin_sync_sse__create_json_map_twin_sync_sse(
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__json_type_twin_sync_sse__create_json_value_twin_sync_sse(
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__json_type_twin_sync_sse__json_document_twin_sync_sse(
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__json_type_twin_sync_sse__json_map_keys_twin_sync_sse(
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__json_type_twin_sync_sse__json_map_twin_sync_sse(
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__json_type_twin_sync_sse__json_value_to_string_twin_sync_sse(
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__json_type_twin_sync_sse__json_value_twin_sync_sse(
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__json_type_twin_sync_sse__json_values_twin_sync_sse(
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__json_type_twin_sync_sse__optional_json_value_twin_sync_sse(
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
   external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
       wire__crate__api__pseudo_manual__lifetimeable_twin_sync__Lifetimeable_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLtNestedTypeWithLifetimeTwinSyncstatic_greet_borrow_mut_self_twin_sync(
           PlatformGeneralizedUint8ListPtr ptr_,
@@ -69241,6 +74979,22 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
           int rust_vec_len_,
           int data_len_);
 
+  external void
+      wire__crate__api__pseudo_manual__map_and_set_twin_rust_async__func_btree_map_i32_i32_twin_rust_async(
+          NativePortType port_, JSAny arg);
+
+  external void
+      wire__crate__api__pseudo_manual__map_and_set_twin_rust_async__func_btree_map_string_struct_twin_rust_async(
+          NativePortType port_, JSAny arg);
+
+  external void
+      wire__crate__api__pseudo_manual__map_and_set_twin_rust_async__func_btree_set_i32_twin_rust_async(
+          NativePortType port_, JSAny arg);
+
+  external void
+      wire__crate__api__pseudo_manual__map_and_set_twin_rust_async__func_btree_set_string_twin_rust_async(
+          NativePortType port_, JSAny arg);
+
   external void
       wire__crate__api__pseudo_manual__map_and_set_twin_rust_async__func_hash_map_i32_i32_twin_rust_async(
           NativePortType port_, JSAny arg);
@@ -69273,6 +75027,42 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
       wire__crate__api__pseudo_manual__map_and_set_twin_rust_async__func_hash_set_string_twin_rust_async(
           NativePortType port_, JSAny arg);
 
+  external void
+      wire__crate__api__pseudo_manual__map_and_set_twin_rust_async__func_vec_deque_i32_twin_rust_async(
+          NativePortType port_, JSAny arg);
+
+  external void
+      wire__crate__api__pseudo_manual__map_and_set_twin_rust_async__func_vec_deque_string_twin_rust_async(
+          NativePortType port_, JSAny arg);
+
+  external void
+      wire__crate__api__pseudo_manual__map_and_set_twin_rust_async_sse__func_btree_map_i32_i32_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__map_and_set_twin_rust_async_sse__func_btree_map_string_struct_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__map_and_set_twin_rust_async_sse__func_btree_set_i32_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__map_and_set_twin_rust_async_sse__func_btree_set_string_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
   external void
       wire__crate__api__pseudo_manual__map_and_set_twin_rust_async_sse__func_hash_map_i32_i32_twin_rust_async_sse(
           NativePortType port_,
@@ -69329,6 +75119,48 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
           int rust_vec_len_,
           int data_len_);
 
+  external void
+      wire__crate__api__pseudo_manual__map_and_set_twin_rust_async_sse__func_vec_deque_i32_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__map_and_set_twin_rust_async_sse__func_vec_deque_string_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__map_and_set_twin_sse__func_btree_map_i32_i32_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__map_and_set_twin_sse__func_btree_map_string_struct_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__map_and_set_twin_sse__func_btree_set_i32_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__map_and_set_twin_sse__func_btree_set_string_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
   external void
       wire__crate__api__pseudo_manual__map_and_set_twin_sse__func_hash_map_i32_i32_twin_sse(
           NativePortType port_,
@@ -69385,6 +75217,36 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
           int rust_vec_len_,
           int data_len_);
 
+  external void
+      wire__crate__api__pseudo_manual__map_and_set_twin_sse__func_vec_deque_i32_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__map_and_set_twin_sse__func_vec_deque_string_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__map_and_set_twin_sync__func_btree_map_i32_i32_twin_sync(
+          JSAny arg);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__map_and_set_twin_sync__func_btree_map_string_struct_twin_sync(
+          JSAny arg);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__map_and_set_twin_sync__func_btree_set_i32_twin_sync(
+          JSAny arg);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__map_and_set_twin_sync__func_btree_set_string_twin_sync(
+          JSAny arg);
+
   external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
       wire__crate__api__pseudo_manual__map_and_set_twin_sync__func_hash_map_i32_i32_twin_sync(
           JSAny arg);
@@ -69417,6 +75279,38 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
       wire__crate__api__pseudo_manual__map_and_set_twin_sync__func_hash_set_string_twin_sync(
           JSAny arg);
 
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__map_and_set_twin_sync__func_vec_deque_i32_twin_sync(
+          JSAny arg);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__map_and_set_twin_sync__func_vec_deque_string_twin_sync(
+          JSAny arg);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__map_and_set_twin_sync_sse__func_btree_map_i32_i32_twin_sync_sse(
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__map_and_set_twin_sync_sse__func_btree_map_string_struct_twin_sync_sse(
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__map_and_set_twin_sync_sse__func_btree_set_i32_twin_sync_sse(
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__map_and_set_twin_sync_sse__func_btree_set_string_twin_sync_sse(
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
   external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
       wire__crate__api__pseudo_manual__map_and_set_twin_sync_sse__func_hash_map_i32_i32_twin_sync_sse(
           PlatformGeneralizedUint8ListPtr ptr_,
@@ -69465,6 +75359,18 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
           int rust_vec_len_,
           int data_len_);
 
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__map_and_set_twin_sync_sse__func_vec_deque_i32_twin_sync_sse(
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__map_and_set_twin_sync_sse__func_vec_deque_string_twin_sync_sse(
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
   external void
       wire__crate__api__pseudo_manual__method_twin_rust_async__StaticGetterOnlyTwinRustAsync_static_getter_twin_rust_async(
           NativePortType port_);
@@ -75402,6 +81308,202 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
           int rust_vec_len_,
           int data_len_);
 
+  external void
+      wire__crate__api__pseudo_manual__std_time_twin_rust_async__std_duration_twin_rust_async(
+          NativePortType port_, JSAny d);
+
+  external void
+      wire__crate__api__pseudo_manual__std_time_twin_rust_async__std_durations_twin_rust_async(
+          NativePortType port_, JSAny durations);
+
+  external void
+      wire__crate__api__pseudo_manual__std_time_twin_rust_async__std_optional_duration_twin_rust_async(
+          NativePortType port_, JSAny? d);
+
+  external void
+      wire__crate__api__pseudo_manual__std_time_twin_rust_async__std_optional_system_time_twin_rust_async(
+          NativePortType port_, JSAny? t);
+
+  external void
+      wire__crate__api__pseudo_manual__std_time_twin_rust_async__std_system_time_twin_rust_async(
+          NativePortType port_, JSAny t);
+
+  external void
+      wire__crate__api__pseudo_manual__std_time_twin_rust_async__std_system_times_twin_rust_async(
+          NativePortType port_, JSAny times);
+
+  external void
+      wire__crate__api__pseudo_manual__std_time_twin_rust_async__std_time_struct_twin_rust_async(
+          NativePortType port_, JSAny value);
+
+  external void
+      wire__crate__api__pseudo_manual__std_time_twin_rust_async_sse__std_duration_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__std_time_twin_rust_async_sse__std_durations_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__std_time_twin_rust_async_sse__std_optional_duration_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__std_time_twin_rust_async_sse__std_optional_system_time_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__std_time_twin_rust_async_sse__std_system_time_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__std_time_twin_rust_async_sse__std_system_times_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__std_time_twin_rust_async_sse__std_time_struct_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__std_time_twin_sse__std_duration_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__std_time_twin_sse__std_durations_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__std_time_twin_sse__std_optional_duration_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__std_time_twin_sse__std_optional_system_time_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__std_time_twin_sse__std_system_time_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__std_time_twin_sse__std_system_times_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__std_time_twin_sse__std_time_struct_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__std_time_twin_sync__std_duration_twin_sync(
+          JSAny d);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__std_time_twin_sync__std_durations_twin_sync(
+          JSAny durations);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__std_time_twin_sync__std_optional_duration_twin_sync(
+          JSAny? d);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__std_time_twin_sync__std_optional_system_time_twin_sync(
+          JSAny? t);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__std_time_twin_sync__std_system_time_twin_sync(
+          JSAny t);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__std_time_twin_sync__std_system_times_twin_sync(
+          JSAny times);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__std_time_twin_sync__std_time_struct_twin_sync(
+          JSAny value);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__std_time_twin_sync_sse__std_duration_twin_sync_sse(
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__std_time_twin_sync_sse__std_durations_twin_sync_sse(
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__std_time_twin_sync_sse__std_optional_duration_twin_sync_sse(
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__std_time_twin_sync_sse__std_optional_system_time_twin_sync_sse(
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__std_time_twin_sync_sse__std_system_time_twin_sync_sse(
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__std_time_twin_sync_sse__std_system_times_twin_sync_sse(
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__std_time_twin_sync_sse__std_time_struct_twin_sync_sse(
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
   external void
       wire__crate__api__pseudo_manual__stream_misc_twin_sse__func_stream_realistic_twin_sse(
           NativePortType port_,
@@ -75409,6 +81511,27 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
           int rust_vec_len_,
           int data_len_);
 
+  external void
+      wire__crate__api__pseudo_manual__stream_misc_twin_sse__stream_sink_bounded_add_async_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__stream_misc_twin_sse__stream_sink_bounded_add_with_error_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__stream_misc_twin_sse__stream_sink_bounded_keep_latest_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
   external void
       wire__crate__api__pseudo_manual__stream_misc_twin_sse__stream_sink_dart_async_twin_sse(
           NativePortType port_,
@@ -75810,6 +81933,258 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
           int rust_vec_len_,
           int data_len_);
 
+  external void
+      wire__crate__api__pseudo_manual__time_type_twin_rust_async__date_twin_rust_async(
+          NativePortType port_, JSAny d);
+
+  external void
+      wire__crate__api__pseudo_manual__time_type_twin_rust_async__negate_time_duration_twin_rust_async(
+          NativePortType port_, JSAny d);
+
+  external void
+      wire__crate__api__pseudo_manual__time_type_twin_rust_async__offset_date_time_twin_rust_async(
+          NativePortType port_, JSAny d);
+
+  external void
+      wire__crate__api__pseudo_manual__time_type_twin_rust_async__optional_offset_date_time_twin_rust_async(
+          NativePortType port_, JSAny? d);
+
+  external void
+      wire__crate__api__pseudo_manual__time_type_twin_rust_async__optional_time_duration_twin_rust_async(
+          NativePortType port_, JSAny? d);
+
+  external void
+      wire__crate__api__pseudo_manual__time_type_twin_rust_async__primitive_date_time_twin_rust_async(
+          NativePortType port_, JSAny d);
+
+  external void
+      wire__crate__api__pseudo_manual__time_type_twin_rust_async__time_duration_twin_rust_async(
+          NativePortType port_, JSAny d);
+
+  external void
+      wire__crate__api__pseudo_manual__time_type_twin_rust_async__time_durations_twin_rust_async(
+          NativePortType port_, JSAny durations);
+
+  external void
+      wire__crate__api__pseudo_manual__time_type_twin_rust_async__time_types_twin_rust_async(
+          NativePortType port_, JSAny value);
+
+  external void
+      wire__crate__api__pseudo_manual__time_type_twin_rust_async_sse__date_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__time_type_twin_rust_async_sse__negate_time_duration_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__time_type_twin_rust_async_sse__offset_date_time_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__time_type_twin_rust_async_sse__optional_offset_date_time_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__time_type_twin_rust_async_sse__optional_time_duration_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__time_type_twin_rust_async_sse__primitive_date_time_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__time_type_twin_rust_async_sse__time_duration_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__time_type_twin_rust_async_sse__time_durations_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__time_type_twin_rust_async_sse__time_types_twin_rust_async_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__time_type_twin_sse__date_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__time_type_twin_sse__negate_time_duration_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__time_type_twin_sse__offset_date_time_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__time_type_twin_sse__optional_offset_date_time_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__time_type_twin_sse__optional_time_duration_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__time_type_twin_sse__primitive_date_time_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__time_type_twin_sse__time_duration_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__time_type_twin_sse__time_durations_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external void
+      wire__crate__api__pseudo_manual__time_type_twin_sse__time_types_twin_sse(
+          NativePortType port_,
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__time_type_twin_sync__date_twin_sync(
+          JSAny d);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__time_type_twin_sync__negate_time_duration_twin_sync(
+          JSAny d);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__time_type_twin_sync__offset_date_time_twin_sync(
+          JSAny d);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__time_type_twin_sync__optional_offset_date_time_twin_sync(
+          JSAny? d);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__time_type_twin_sync__optional_time_duration_twin_sync(
+          JSAny? d);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__time_type_twin_sync__primitive_date_time_twin_sync(
+          JSAny d);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__time_type_twin_sync__time_duration_twin_sync(
+          JSAny d);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__time_type_twin_sync__time_durations_twin_sync(
+          JSAny durations);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__api__pseudo_manual__time_type_twin_sync__time_types_twin_sync(
+          JSAny value);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__time_type_twin_sync_sse__date_twin_sync_sse(
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__time_type_twin_sync_sse__negate_time_duration_twin_sync_sse(
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__time_type_twin_sync_sse__offset_date_time_twin_sync_sse(
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__time_type_twin_sync_sse__optional_offset_date_time_twin_sync_sse(
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__time_type_twin_sync_sse__optional_time_duration_twin_sync_sse(
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__time_type_twin_sync_sse__primitive_date_time_twin_sync_sse(
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__time_type_twin_sync_sse__time_duration_twin_sync_sse(
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__time_type_twin_sync_sse__time_durations_twin_sync_sse(
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartSse */
+      wire__crate__api__pseudo_manual__time_type_twin_sync_sse__time_types_twin_sync_sse(
+          PlatformGeneralizedUint8ListPtr ptr_,
+          int rust_vec_len_,
+          int data_len_);
+
   external void
       wire__crate__api__pseudo_manual__tuple_twin_rust_async__test_tuple_2_twin_rust_async(
           NativePortType port_, JSAny value);
@@ -76284,6 +82659,28 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
   external void wire__crate__api__simple__simple_adder_twin_normal(
       NativePortType port_, int a, int b);
 
+  external void wire__crate__api__std_time__std_duration_twin_normal(
+      NativePortType port_, JSAny d);
+
+  external void wire__crate__api__std_time__std_durations_twin_normal(
+      NativePortType port_, JSAny durations);
+
+  external void wire__crate__api__std_time__std_optional_duration_twin_normal(
+      NativePortType port_, JSAny? d);
+
+  external void
+      wire__crate__api__std_time__std_optional_system_time_twin_normal(
+          NativePortType port_, JSAny? t);
+
+  external void wire__crate__api__std_time__std_system_time_twin_normal(
+      NativePortType port_, JSAny t);
+
+  external void wire__crate__api__std_time__std_system_times_twin_normal(
+      NativePortType port_, JSAny times);
+
+  external void wire__crate__api__std_time__std_time_struct_twin_normal(
+      NativePortType port_, JSAny value);
+
   external void
       wire__crate__api__stream__func_stream_add_value_and_error_twin_normal(
           NativePortType port_, String sink);
@@ -76324,6 +82721,18 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
       wire__crate__api__stream_misc__func_stream_realistic_twin_normal(
           NativePortType port_, String sink, String arg);
 
+  external void
+      wire__crate__api__stream_misc__stream_sink_bounded_add_async_twin_normal(
+          NativePortType port_, String sink);
+
+  external void
+      wire__crate__api__stream_misc__stream_sink_bounded_add_with_error_twin_normal(
+          NativePortType port_, String sink);
+
+  external void
+      wire__crate__api__stream_misc__stream_sink_bounded_keep_latest_twin_normal(
+          NativePortType port_, String sink);
+
   external void
       wire__crate__api__stream_misc__stream_sink_dart_async_twin_normal(
           NativePortType port_, String sink);
@@ -76356,6 +82765,34 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
       wire__crate__api__structure__func_tuple_struct_with_two_field_twin_normal(
           NativePortType port_, JSAny arg);
 
+  external void wire__crate__api__time_type__date_twin_normal(
+      NativePortType port_, JSAny d);
+
+  external void wire__crate__api__time_type__negate_time_duration_twin_normal(
+      NativePortType port_, JSAny d);
+
+  external void wire__crate__api__time_type__offset_date_time_twin_normal(
+      NativePortType port_, JSAny d);
+
+  external void
+      wire__crate__api__time_type__optional_offset_date_time_twin_normal(
+          NativePortType port_, JSAny? d);
+
+  external void wire__crate__api__time_type__optional_time_duration_twin_normal(
+      NativePortType port_, JSAny? d);
+
+  external void wire__crate__api__time_type__primitive_date_time_twin_normal(
+      NativePortType port_, JSAny d);
+
+  external void wire__crate__api__time_type__time_duration_twin_normal(
+      NativePortType port_, JSAny d);
+
+  external void wire__crate__api__time_type__time_durations_twin_normal(
+      NativePortType port_, JSAny durations);
+
+  external void wire__crate__api__time_type__time_types_twin_normal(
+      NativePortType port_, JSAny value);
+
   external void wire__crate__api__tuple__test_tuple_2_twin_normal(
       NativePortType port_, JSAny value);
 
@@ -76382,6 +82819,19 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
   external void wire__crate__api__uuid_type__handle_uuids_twin_normal(
       NativePortType port_, JSAny ids);
 
+  external void
+      wire__crate__frb_generated__DartImplementableTraitTwinNormalDartImpl_add_twin_normal(
+          NativePortType port_, int that, int a, int b);
+
+  external void
+      wire__crate__frb_generated__DartImplementableTraitTwinNormalDartImpl_describe_twin_normal(
+          NativePortType port_, int that, int value);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+      wire__crate__frb_generated__DartImplementableTraitTwinNormalDartImpl_new(
+          PlatformPointer add_twin_normal,
+          PlatformPointer describe_twin_normal);
+
   external void
       rust_arc_increment_strong_count_RustOpaque_BoxdynDartDebugTwinMoi(
           int ptr);
@@ -77118,6 +83568,14 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
       rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConstructorOpaqueSyncStructTwinNormal(
           int ptr);
 
+  external void
+      rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl(
+          int ptr);
+
+  external void
+      rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl(
+          int ptr);
+
   external void
       rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeliberateFailSanityCheckTwinNormal(
           int ptr);
@@ -77974,6 +84432,14 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
       rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitTwinSyncSse(
           int ptr);
 
+  external void
+      rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+          int ptr);
+
+  external void
+      rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+          int ptr);
+
   external void
       rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinMoi(
           int ptr);
@@ -0,0 +1,49 @@
+// FRB_INTERNAL_GENERATOR: {"forbiddenDuplicatorModes": ["sync", "rustAsync", "sse", "sync sse", "rustAsync sse"]}
+
+use flutter_rust_bridge::frb;
+
+// Returning `DateTime<chrono_tz::Tz>` needs SSE,
+// because `flutter_rust_bridge` does not depend on `chrono-tz`
+
+#[frb(serialize)]
+pub fn datetime_tz_twin_normal(
+    d: chrono::DateTime<chrono_tz::Tz>,
+) -> chrono::DateTime<chrono_tz::Tz> {
+    assert_eq!(d.timezone(), chrono_tz::Asia::Shanghai);
+    d
+}
+
+#[frb(serialize)]
+pub fn optional_datetime_tz_twin_normal(
+    d: Option<chrono::DateTime<chrono_tz::Tz>>,
+) -> Option<chrono::DateTime<chrono_tz::Tz>> {
+    d
+}
+
+#[frb(serialize)]
+pub fn datetime_tzs_twin_normal(
+    dates: Vec<chrono::DateTime<chrono_tz::Tz>>,
+) -> Vec<chrono::DateTime<chrono_tz::Tz>> {
+    dates
+}
+
+#[frb(serialize)]
+pub fn datetime_with_time_zone_twin_normal(
+    d: chrono::DateTime<chrono::FixedOffset>,
+    time_zone: String,
+) -> chrono::DateTime<chrono_tz::Tz> {
+    d.with_timezone(&time_zone.parse::<chrono_tz::Tz>().unwrap())
+}
+
+// Passing it to Rust works with the default codec as well
+
+pub fn datetime_tz_name_twin_normal(d: chrono::DateTime<chrono_tz::Tz>) -> String {
+    d.timezone().name().to_owned()
+}
+
+pub fn datetime_tz_to_fixed_offset_twin_normal(
+    d: chrono::DateTime<chrono_tz::Tz>,
+) -> chrono::DateTime<chrono::FixedOffset> {
+    use chrono::Offset;
+    d.with_timezone(&d.offset().fix())
+}
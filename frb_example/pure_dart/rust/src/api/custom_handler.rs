@@ -8,6 +8,7 @@ use crate::frb_generated::FLUTTER_RUST_BRIDGE_CODEGEN_VERSION;
 //
 // Here, for simplicity, we still generate code for default handler.
 // But surely you can copy-paste the content of that macro and modify according to your needs.
-flutter_rust_bridge::frb_generated_default_handler!();
+// The thread pool is used by functions annotated with `#[frb(executor = "io")]`.
+flutter_rust_bridge::frb_generated_default_handler!(thread_pools: [("io", Some(2))]);
 
 // NOTE: For more tests about customizing handler types and contents, please visit `src/auxiliary/custom_handler.rs`
@@ -0,0 +1,56 @@
+use serde_json::json;
+
+pub fn json_value_twin_normal(value: serde_json::Value) -> serde_json::Value {
+    value
+}
+
+pub fn optional_json_value_twin_normal(
+    value: Option<serde_json::Value>,
+) -> Option<serde_json::Value> {
+    value
+}
+
+pub fn json_values_twin_normal(values: Vec<serde_json::Value>) -> Vec<serde_json::Value> {
+    values
+}
+
+pub fn json_map_twin_normal(
+    map: serde_json::Map<String, serde_json::Value>,
+) -> serde_json::Map<String, serde_json::Value> {
+    map
+}
+
+pub fn json_value_to_string_twin_normal(value: serde_json::Value) -> String {
+    value.to_string()
+}
+
+pub fn json_map_keys_twin_normal(map: serde_json::Map<String, serde_json::Value>) -> Vec<String> {
+    map.keys().cloned().collect()
+}
+
+pub fn create_json_value_twin_normal() -> serde_json::Value {
+    json!({
+        "name": "frb",
+        "version": 2,
+        "ratio": 0.5,
+        "tags": ["rust", "dart"],
+        "nested": { "ok": true, "missing": null },
+    })
+}
+
+pub fn create_json_map_twin_normal() -> serde_json::Map<String, serde_json::Value> {
+    let mut map = serde_json::Map::new();
+    map.insert("count".to_owned(), json!(-3));
+    map.insert("items".to_owned(), json!([{ "id": 1 }, { "id": 2 }]));
+    map
+}
+
+pub struct JsonDocumentTwinNormal {
+    pub body: serde_json::Value,
+    pub meta: serde_json::Map<String, serde_json::Value>,
+    pub extra: Option<serde_json::Value>,
+}
+
+pub fn json_document_twin_normal(document: JsonDocumentTwinNormal) -> JsonDocumentTwinNormal {
+    document
+}
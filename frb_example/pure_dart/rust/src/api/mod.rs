@@ -4,8 +4,10 @@ pub mod async_misc;
 pub mod async_spawn;
 pub mod attribute;
 pub mod benchmark_misc;
+pub mod big_number;
 pub mod casted_primitive;
 pub mod chrono_type;
+pub mod chrono_tz_type;
 pub mod comment;
 #[cfg(target_os = "non_existent_os")]
 pub mod conditionally_compiled_module;
@@ -30,6 +32,7 @@ pub mod external_impl;
 pub mod external_type_in_crate;
 pub mod impl_trait;
 pub mod inside_macro;
+pub mod json_type;
 pub mod lifetimeable;
 pub mod map_and_set;
 pub mod method;
@@ -51,9 +54,11 @@ pub mod rust_auto_opaque;
 pub mod rust_opaque;
 pub mod rust_opaque_sync;
 pub mod simple;
+pub mod std_time;
 pub mod stream;
 pub mod stream_misc;
 pub mod structure;
+pub mod time_type;
 pub mod tuple;
 pub mod type_alias;
 pub mod uuid_type;
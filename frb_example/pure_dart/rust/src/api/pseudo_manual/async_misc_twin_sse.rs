@@ -11,3 +11,16 @@ pub async fn func_async_void_twin_sse() {}
 pub async fn func_async_simple_add_twin_sse(a: i32, b: i32) -> i32 {
     a + b
 }
+
+#[flutter_rust_bridge::frb(serialize)]
+pub async fn func_async_never_complete_twin_sse() {
+    futures::future::pending::<()>().await
+}
+
+#[flutter_rust_bridge::frb(serialize)]
+pub async fn func_async_wait_for_cancellation_twin_sse() {
+    let token = flutter_rust_bridge::CancellationToken::current().unwrap();
+    while !token.is_cancelled() {
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+}
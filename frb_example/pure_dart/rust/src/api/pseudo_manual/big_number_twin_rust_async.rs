@@ -0,0 +1,50 @@
+// NOTE: This file is mimicking how a human developer writes tests,
+// and is auto-generated from `big_number.rs` by frb_internal
+// Please do not modify manually, but modify the origin and re-run frb_internal generator
+
+use num_bigint::BigInt;
+use rust_decimal::Decimal;
+
+pub async fn decimal_twin_rust_async(d: Decimal) -> Decimal {
+    d
+}
+
+pub async fn add_decimals_twin_rust_async(a: Decimal, b: Decimal) -> Decimal {
+    a + b
+}
+
+pub async fn optional_decimal_twin_rust_async(d: Option<Decimal>) -> Option<Decimal> {
+    d
+}
+
+pub async fn decimals_twin_rust_async(decimals: Vec<Decimal>) -> Vec<Decimal> {
+    decimals
+}
+
+pub async fn big_int_twin_rust_async(i: BigInt) -> BigInt {
+    i
+}
+
+pub async fn multiply_big_ints_twin_rust_async(a: BigInt, b: BigInt) -> BigInt {
+    a * b
+}
+
+pub async fn optional_big_int_twin_rust_async(i: Option<BigInt>) -> Option<BigInt> {
+    i
+}
+
+pub async fn big_ints_twin_rust_async(ints: Vec<BigInt>) -> Vec<BigInt> {
+    ints
+}
+
+pub struct BigNumbersTwinRustAsync {
+    pub amount: Decimal,
+    pub discount: Option<Decimal>,
+    pub serial: BigInt,
+}
+
+pub async fn big_numbers_twin_rust_async(
+    value: BigNumbersTwinRustAsync,
+) -> BigNumbersTwinRustAsync {
+    value
+}
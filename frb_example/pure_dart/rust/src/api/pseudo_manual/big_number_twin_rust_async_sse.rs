@@ -0,0 +1,59 @@
+// NOTE: This file is mimicking how a human developer writes tests,
+// and is auto-generated from `big_number.rs` by frb_internal
+// Please do not modify manually, but modify the origin and re-run frb_internal generator
+
+use num_bigint::BigInt;
+use rust_decimal::Decimal;
+
+#[flutter_rust_bridge::frb(serialize)]
+pub async fn decimal_twin_rust_async_sse(d: Decimal) -> Decimal {
+    d
+}
+
+#[flutter_rust_bridge::frb(serialize)]
+pub async fn add_decimals_twin_rust_async_sse(a: Decimal, b: Decimal) -> Decimal {
+    a + b
+}
+
+#[flutter_rust_bridge::frb(serialize)]
+pub async fn optional_decimal_twin_rust_async_sse(d: Option<Decimal>) -> Option<Decimal> {
+    d
+}
+
+#[flutter_rust_bridge::frb(serialize)]
+pub async fn decimals_twin_rust_async_sse(decimals: Vec<Decimal>) -> Vec<Decimal> {
+    decimals
+}
+
+#[flutter_rust_bridge::frb(serialize)]
+pub async fn big_int_twin_rust_async_sse(i: BigInt) -> BigInt {
+    i
+}
+
+#[flutter_rust_bridge::frb(serialize)]
+pub async fn multiply_big_ints_twin_rust_async_sse(a: BigInt, b: BigInt) -> BigInt {
+    a * b
+}
+
+#[flutter_rust_bridge::frb(serialize)]
+pub async fn optional_big_int_twin_rust_async_sse(i: Option<BigInt>) -> Option<BigInt> {
+    i
+}
+
+#[flutter_rust_bridge::frb(serialize)]
+pub async fn big_ints_twin_rust_async_sse(ints: Vec<BigInt>) -> Vec<BigInt> {
+    ints
+}
+
+pub struct BigNumbersTwinRustAsyncSse {
+    pub amount: Decimal,
+    pub discount: Option<Decimal>,
+    pub serial: BigInt,
+}
+
+#[flutter_rust_bridge::frb(serialize)]
+pub async fn big_numbers_twin_rust_async_sse(
+    value: BigNumbersTwinRustAsyncSse,
+) -> BigNumbersTwinRustAsyncSse {
+    value
+}
@@ -149,3 +149,53 @@ pub async fn how_long_does_it_take_twin_rust_async(
     assert_eq!(&mine.naive.nanosecond(), &123_456_000);
     Ok(difference)
 }
+
+pub async fn naive_date_twin_rust_async(d: chrono::NaiveDate) -> chrono::NaiveDate {
+    assert_eq!(d, chrono::NaiveDate::from_ymd_opt(2022, 9, 10).unwrap());
+    d
+}
+
+pub async fn naive_time_twin_rust_async(d: chrono::NaiveTime) -> chrono::NaiveTime {
+    assert_eq!(
+        d,
+        chrono::NaiveTime::from_hms_milli_opt(20, 48, 53, 123).unwrap()
+    );
+    d
+}
+
+pub async fn datetime_fixed_offset_twin_rust_async(
+    d: chrono::DateTime<chrono::FixedOffset>,
+) -> chrono::DateTime<chrono::FixedOffset> {
+    use chrono::Timelike;
+    assert_eq!(d.offset().local_minus_utc(), 8 * 3600);
+    assert_eq!(d.hour(), 20);
+    assert_eq!(d.minute(), 48);
+    d
+}
+
+pub async fn optional_datetime_fixed_offset_twin_rust_async(
+    d: Option<chrono::DateTime<chrono::FixedOffset>>,
+) -> Option<chrono::DateTime<chrono::FixedOffset>> {
+    d
+}
+
+pub async fn naive_dates_twin_rust_async(dates: Vec<chrono::NaiveDate>) -> Vec<chrono::NaiveDate> {
+    dates
+}
+
+pub async fn datetime_fixed_offsets_twin_rust_async(
+    dates: Vec<chrono::DateTime<chrono::FixedOffset>>,
+) -> Vec<chrono::DateTime<chrono::FixedOffset>> {
+    dates
+}
+
+pub struct ScheduleTwinRustAsync {
+    pub day: chrono::NaiveDate,
+    pub starts: chrono::NaiveTime,
+    pub ends: Option<chrono::NaiveTime>,
+    pub at: chrono::DateTime<chrono::FixedOffset>,
+}
+
+pub async fn schedule_twin_rust_async(schedule: ScheduleTwinRustAsync) -> ScheduleTwinRustAsync {
+    schedule
+}
@@ -4,7 +4,8 @@
 
 // FRB_INTERNAL_GENERATOR: {"forbiddenDuplicatorModes": ["sync", "sync sse"]}
 
-use flutter_rust_bridge::{DartFnFuture, DartOpaque};
+use flutter_rust_bridge::{DartFnFuture, DartOpaque, DartStream};
+use futures::{StreamExt, TryStreamExt};
 
 pub struct DemoStructForRustCallDartTwinSse {
     pub name: String,
@@ -83,3 +84,18 @@ pub async fn rust_call_dart_return_result_twin_sse(
 ) {
     assert_eq!(callback("hi".to_owned()).await.ok(), expect_output);
 }
+
+#[flutter_rust_bridge::frb(serialize)]
+pub async fn rust_consume_dart_stream_sum_twin_sse(stream: DartStream<i32>) -> anyhow::Result<i32> {
+    stream
+        .try_fold(0, |acc, x| async move { Ok(acc + x) })
+        .await
+}
+
+#[flutter_rust_bridge::frb(serialize)]
+pub async fn rust_consume_dart_stream_take_twin_sse(
+    stream: DartStream<String>,
+    count: usize,
+) -> anyhow::Result<Vec<String>> {
+    stream.take(count).try_collect().await
+}
@@ -285,3 +285,49 @@ pub async fn stream_sink_throw_anyhow_twin_rust_async_sse(
 ) -> Result<()> {
     Err(anyhow!("anyhow error"))
 }
+
+#[frb(typed_exceptions)]
+pub enum TypedExceptionErrorTwinRustAsyncSse {
+    NotFound { path: String },
+    Io(String),
+    Unknown,
+}
+
+impl std::fmt::Display for TypedExceptionErrorTwinRustAsyncSse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotFound { path } => write!(f, "not found: {path}"),
+            Self::Io(message) => write!(f, "io: {message}"),
+            Self::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+#[flutter_rust_bridge::frb(serialize)]
+pub async fn typed_exception_return_error_twin_rust_async_sse(
+    variant: i32,
+) -> Result<i32, TypedExceptionErrorTwinRustAsyncSse> {
+    match variant {
+        0 => Err(TypedExceptionErrorTwinRustAsyncSse::NotFound {
+            path: "/tmp/a.txt".to_owned(),
+        }),
+        1 => Err(TypedExceptionErrorTwinRustAsyncSse::Io(
+            "disk full".to_owned(),
+        )),
+        2 => Err(TypedExceptionErrorTwinRustAsyncSse::Unknown),
+        _ => Ok(variant),
+    }
+}
+
+#[flutter_rust_bridge::frb(serialize)]
+pub async fn throw_anyhow_with_context_twin_rust_async_sse() -> Result<()> {
+    Err(anyhow!("root cause")
+        .context("middle context")
+        .context("outer context"))
+}
+
+#[flutter_rust_bridge::frb(serialize)]
+pub async fn throw_anyhow_io_error_twin_rust_async_sse() -> Result<()> {
+    let error = std::io::Error::new(std::io::ErrorKind::NotFound, "missing file");
+    Err(anyhow::Error::new(error).context("reading config"))
+}
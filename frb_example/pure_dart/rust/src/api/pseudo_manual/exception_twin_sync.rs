@@ -263,3 +263,47 @@ pub fn panic_with_custom_result_twin_sync() -> Result<(), CustomErrorTwinSync> {
 pub fn stream_sink_throw_anyhow_twin_sync(_sink: StreamSink<String>) -> Result<()> {
     Err(anyhow!("anyhow error"))
 }
+
+#[frb(typed_exceptions)]
+pub enum TypedExceptionErrorTwinSync {
+    NotFound { path: String },
+    Io(String),
+    Unknown,
+}
+
+impl std::fmt::Display for TypedExceptionErrorTwinSync {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotFound { path } => write!(f, "not found: {path}"),
+            Self::Io(message) => write!(f, "io: {message}"),
+            Self::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn typed_exception_return_error_twin_sync(
+    variant: i32,
+) -> Result<i32, TypedExceptionErrorTwinSync> {
+    match variant {
+        0 => Err(TypedExceptionErrorTwinSync::NotFound {
+            path: "/tmp/a.txt".to_owned(),
+        }),
+        1 => Err(TypedExceptionErrorTwinSync::Io("disk full".to_owned())),
+        2 => Err(TypedExceptionErrorTwinSync::Unknown),
+        _ => Ok(variant),
+    }
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn throw_anyhow_with_context_twin_sync() -> Result<()> {
+    Err(anyhow!("root cause")
+        .context("middle context")
+        .context("outer context"))
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn throw_anyhow_io_error_twin_sync() -> Result<()> {
+    let error = std::io::Error::new(std::io::ErrorKind::NotFound, "missing file");
+    Err(anyhow::Error::new(error).context("reading config"))
+}
@@ -0,0 +1,64 @@
+// NOTE: This file is mimicking how a human developer writes tests,
+// and is auto-generated from `json_type.rs` by frb_internal
+// Please do not modify manually, but modify the origin and re-run frb_internal generator
+
+use serde_json::json;
+
+pub async fn json_value_twin_rust_async(value: serde_json::Value) -> serde_json::Value {
+    value
+}
+
+pub async fn optional_json_value_twin_rust_async(
+    value: Option<serde_json::Value>,
+) -> Option<serde_json::Value> {
+    value
+}
+
+pub async fn json_values_twin_rust_async(values: Vec<serde_json::Value>) -> Vec<serde_json::Value> {
+    values
+}
+
+pub async fn json_map_twin_rust_async(
+    map: serde_json::Map<String, serde_json::Value>,
+) -> serde_json::Map<String, serde_json::Value> {
+    map
+}
+
+pub async fn json_value_to_string_twin_rust_async(value: serde_json::Value) -> String {
+    value.to_string()
+}
+
+pub async fn json_map_keys_twin_rust_async(
+    map: serde_json::Map<String, serde_json::Value>,
+) -> Vec<String> {
+    map.keys().cloned().collect()
+}
+
+pub async fn create_json_value_twin_rust_async() -> serde_json::Value {
+    json!({
+        "name": "frb",
+        "version": 2,
+        "ratio": 0.5,
+        "tags": ["rust", "dart"],
+        "nested": { "ok": true, "missing": null },
+    })
+}
+
+pub async fn create_json_map_twin_rust_async() -> serde_json::Map<String, serde_json::Value> {
+    let mut map = serde_json::Map::new();
+    map.insert("count".to_owned(), json!(-3));
+    map.insert("items".to_owned(), json!([{ "id": 1 }, { "id": 2 }]));
+    map
+}
+
+pub struct JsonDocumentTwinRustAsync {
+    pub body: serde_json::Value,
+    pub meta: serde_json::Map<String, serde_json::Value>,
+    pub extra: Option<serde_json::Value>,
+}
+
+pub async fn json_document_twin_rust_async(
+    document: JsonDocumentTwinRustAsync,
+) -> JsonDocumentTwinRustAsync {
+    document
+}
@@ -0,0 +1,78 @@
+// NOTE: This file is mimicking how a human developer writes tests,
+// and is auto-generated from `json_type.rs` by frb_internal
+// Please do not modify manually, but modify the origin and re-run frb_internal generator
+
+use serde_json::json;
+
+#[flutter_rust_bridge::frb(serialize)]
+#[flutter_rust_bridge::frb(sync)]
+pub fn json_value_twin_sync_sse(value: serde_json::Value) -> serde_json::Value {
+    value
+}
+
+#[flutter_rust_bridge::frb(serialize)]
+#[flutter_rust_bridge::frb(sync)]
+pub fn optional_json_value_twin_sync_sse(
+    value: Option<serde_json::Value>,
+) -> Option<serde_json::Value> {
+    value
+}
+
+#[flutter_rust_bridge::frb(serialize)]
+#[flutter_rust_bridge::frb(sync)]
+pub fn json_values_twin_sync_sse(values: Vec<serde_json::Value>) -> Vec<serde_json::Value> {
+    values
+}
+
+#[flutter_rust_bridge::frb(serialize)]
+#[flutter_rust_bridge::frb(sync)]
+pub fn json_map_twin_sync_sse(
+    map: serde_json::Map<String, serde_json::Value>,
+) -> serde_json::Map<String, serde_json::Value> {
+    map
+}
+
+#[flutter_rust_bridge::frb(serialize)]
+#[flutter_rust_bridge::frb(sync)]
+pub fn json_value_to_string_twin_sync_sse(value: serde_json::Value) -> String {
+    value.to_string()
+}
+
+#[flutter_rust_bridge::frb(serialize)]
+#[flutter_rust_bridge::frb(sync)]
+pub fn json_map_keys_twin_sync_sse(map: serde_json::Map<String, serde_json::Value>) -> Vec<String> {
+    map.keys().cloned().collect()
+}
+
+#[flutter_rust_bridge::frb(serialize)]
+#[flutter_rust_bridge::frb(sync)]
+pub fn create_json_value_twin_sync_sse() -> serde_json::Value {
+    json!({
+        "name": "frb",
+        "version": 2,
+        "ratio": 0.5,
+        "tags": ["rust", "dart"],
+        "nested": { "ok": true, "missing": null },
+    })
+}
+
+#[flutter_rust_bridge::frb(serialize)]
+#[flutter_rust_bridge::frb(sync)]
+pub fn create_json_map_twin_sync_sse() -> serde_json::Map<String, serde_json::Value> {
+    let mut map = serde_json::Map::new();
+    map.insert("count".to_owned(), json!(-3));
+    map.insert("items".to_owned(), json!([{ "id": 1 }, { "id": 2 }]));
+    map
+}
+
+pub struct JsonDocumentTwinSyncSse {
+    pub body: serde_json::Value,
+    pub meta: serde_json::Map<String, serde_json::Value>,
+    pub extra: Option<serde_json::Value>,
+}
+
+#[flutter_rust_bridge::frb(serialize)]
+#[flutter_rust_bridge::frb(sync)]
+pub fn json_document_twin_sync_sse(document: JsonDocumentTwinSyncSse) -> JsonDocumentTwinSyncSse {
+    document
+}
@@ -6,7 +6,7 @@ use crate::api::pseudo_manual::enumeration_twin_rust_async::{
     EnumSimpleTwinRustAsync, KitchenSinkTwinRustAsync,
 };
 use crate::auxiliary::sample_types::MySize;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
 
 pub async fn func_hash_map_i32_i32_twin_rust_async(arg: HashMap<i32, i32>) -> HashMap<i32, i32> {
     arg
@@ -49,3 +49,29 @@ pub async fn func_hash_map_string_complex_enum_twin_rust_async(
 ) -> HashMap<String, KitchenSinkTwinRustAsync> {
     arg
 }
+
+pub async fn func_btree_map_i32_i32_twin_rust_async(arg: BTreeMap<i32, i32>) -> BTreeMap<i32, i32> {
+    arg
+}
+
+pub async fn func_btree_set_i32_twin_rust_async(arg: BTreeSet<i32>) -> BTreeSet<i32> {
+    arg
+}
+
+pub async fn func_btree_map_string_struct_twin_rust_async(
+    arg: BTreeMap<String, MySize>,
+) -> BTreeMap<String, MySize> {
+    arg
+}
+
+pub async fn func_btree_set_string_twin_rust_async(arg: BTreeSet<String>) -> BTreeSet<String> {
+    arg
+}
+
+pub async fn func_vec_deque_i32_twin_rust_async(arg: VecDeque<i32>) -> VecDeque<i32> {
+    arg
+}
+
+pub async fn func_vec_deque_string_twin_rust_async(arg: VecDeque<String>) -> VecDeque<String> {
+    arg
+}
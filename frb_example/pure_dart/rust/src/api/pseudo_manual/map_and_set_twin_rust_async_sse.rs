@@ -6,7 +6,7 @@ use crate::api::pseudo_manual::enumeration_twin_rust_async_sse::{
     EnumSimpleTwinRustAsyncSse, KitchenSinkTwinRustAsyncSse,
 };
 use crate::auxiliary::sample_types::MySize;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
 
 #[flutter_rust_bridge::frb(serialize)]
 pub async fn func_hash_map_i32_i32_twin_rust_async_sse(
@@ -59,3 +59,37 @@ pub async fn func_hash_map_string_complex_enum_twin_rust_async_sse(
 ) -> HashMap<String, KitchenSinkTwinRustAsyncSse> {
     arg
 }
+
+#[flutter_rust_bridge::frb(serialize)]
+pub async fn func_btree_map_i32_i32_twin_rust_async_sse(
+    arg: BTreeMap<i32, i32>,
+) -> BTreeMap<i32, i32> {
+    arg
+}
+
+#[flutter_rust_bridge::frb(serialize)]
+pub async fn func_btree_set_i32_twin_rust_async_sse(arg: BTreeSet<i32>) -> BTreeSet<i32> {
+    arg
+}
+
+#[flutter_rust_bridge::frb(serialize)]
+pub async fn func_btree_map_string_struct_twin_rust_async_sse(
+    arg: BTreeMap<String, MySize>,
+) -> BTreeMap<String, MySize> {
+    arg
+}
+
+#[flutter_rust_bridge::frb(serialize)]
+pub async fn func_btree_set_string_twin_rust_async_sse(arg: BTreeSet<String>) -> BTreeSet<String> {
+    arg
+}
+
+#[flutter_rust_bridge::frb(serialize)]
+pub async fn func_vec_deque_i32_twin_rust_async_sse(arg: VecDeque<i32>) -> VecDeque<i32> {
+    arg
+}
+
+#[flutter_rust_bridge::frb(serialize)]
+pub async fn func_vec_deque_string_twin_rust_async_sse(arg: VecDeque<String>) -> VecDeque<String> {
+    arg
+}
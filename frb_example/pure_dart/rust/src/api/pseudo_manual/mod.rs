@@ -40,6 +40,11 @@ pub mod benchmark_api_twin_rust_async_sse;
 pub mod benchmark_api_twin_sse;
 pub mod benchmark_api_twin_sync;
 pub mod benchmark_api_twin_sync_sse;
+pub mod big_number_twin_rust_async;
+pub mod big_number_twin_rust_async_sse;
+pub mod big_number_twin_sse;
+pub mod big_number_twin_sync;
+pub mod big_number_twin_sync_sse;
 pub mod chrono_type_twin_rust_async;
 pub mod chrono_type_twin_rust_async_sse;
 pub mod chrono_type_twin_sse;
@@ -87,6 +92,11 @@ pub mod external_type_in_crate_twin_sync_sse;
 pub mod impl_trait_twin_sse;
 pub mod impl_trait_twin_sync;
 pub mod impl_trait_twin_sync_sse;
+pub mod json_type_twin_rust_async;
+pub mod json_type_twin_rust_async_sse;
+pub mod json_type_twin_sse;
+pub mod json_type_twin_sync;
+pub mod json_type_twin_sync_sse;
 pub mod lifetimeable_twin_sync;
 pub mod map_and_set_twin_rust_async;
 pub mod map_and_set_twin_rust_async_sse;
@@ -178,6 +188,11 @@ pub mod simple_twin_rust_async_sse;
 pub mod simple_twin_sse;
 pub mod simple_twin_sync;
 pub mod simple_twin_sync_sse;
+pub mod std_time_twin_rust_async;
+pub mod std_time_twin_rust_async_sse;
+pub mod std_time_twin_sse;
+pub mod std_time_twin_sync;
+pub mod std_time_twin_sync_sse;
 pub mod stream_misc_twin_sse;
 pub mod stream_twin_rust_async;
 pub mod stream_twin_rust_async_sse;
@@ -187,6 +202,11 @@ pub mod structure_twin_rust_async_sse;
 pub mod structure_twin_sse;
 pub mod structure_twin_sync;
 pub mod structure_twin_sync_sse;
+pub mod time_type_twin_rust_async;
+pub mod time_type_twin_rust_async_sse;
+pub mod time_type_twin_sse;
+pub mod time_type_twin_sync;
+pub mod time_type_twin_sync_sse;
 pub mod tuple_twin_rust_async;
 pub mod tuple_twin_rust_async_sse;
 pub mod tuple_twin_sse;
@@ -0,0 +1,51 @@
+// NOTE: This file is mimicking how a human developer writes tests,
+// and is auto-generated from `std_time.rs` by frb_internal
+// Please do not modify manually, but modify the origin and re-run frb_internal generator
+
+use std::time::{Duration, SystemTime};
+
+#[derive(Debug, Clone)]
+pub struct StdTimeTwinRustAsyncSse {
+    pub duration: Duration,
+    pub time: SystemTime,
+    pub optional_duration: Option<Duration>,
+}
+
+#[flutter_rust_bridge::frb(serialize)]
+pub async fn std_duration_twin_rust_async_sse(d: Duration) -> Duration {
+    d
+}
+
+#[flutter_rust_bridge::frb(serialize)]
+pub async fn std_system_time_twin_rust_async_sse(t: SystemTime) -> SystemTime {
+    t
+}
+
+#[flutter_rust_bridge::frb(serialize)]
+pub async fn std_durations_twin_rust_async_sse(durations: Vec<Duration>) -> Vec<Duration> {
+    durations
+}
+
+#[flutter_rust_bridge::frb(serialize)]
+pub async fn std_system_times_twin_rust_async_sse(times: Vec<SystemTime>) -> Vec<SystemTime> {
+    times
+}
+
+#[flutter_rust_bridge::frb(serialize)]
+pub async fn std_optional_duration_twin_rust_async_sse(d: Option<Duration>) -> Option<Duration> {
+    d
+}
+
+#[flutter_rust_bridge::frb(serialize)]
+pub async fn std_optional_system_time_twin_rust_async_sse(
+    t: Option<SystemTime>,
+) -> Option<SystemTime> {
+    t
+}
+
+#[flutter_rust_bridge::frb(serialize)]
+pub async fn std_time_struct_twin_rust_async_sse(
+    value: StdTimeTwinRustAsyncSse,
+) -> StdTimeTwinRustAsyncSse {
+    value
+}
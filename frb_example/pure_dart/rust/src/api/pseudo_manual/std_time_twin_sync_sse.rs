@@ -0,0 +1,54 @@
+// NOTE: This file is mimicking how a human developer writes tests,
+// and is auto-generated from `std_time.rs` by frb_internal
+// Please do not modify manually, but modify the origin and re-run frb_internal generator
+
+use std::time::{Duration, SystemTime};
+
+#[derive(Debug, Clone)]
+pub struct StdTimeTwinSyncSse {
+    pub duration: Duration,
+    pub time: SystemTime,
+    pub optional_duration: Option<Duration>,
+}
+
+#[flutter_rust_bridge::frb(serialize)]
+#[flutter_rust_bridge::frb(sync)]
+pub fn std_duration_twin_sync_sse(d: Duration) -> Duration {
+    d
+}
+
+#[flutter_rust_bridge::frb(serialize)]
+#[flutter_rust_bridge::frb(sync)]
+pub fn std_system_time_twin_sync_sse(t: SystemTime) -> SystemTime {
+    t
+}
+
+#[flutter_rust_bridge::frb(serialize)]
+#[flutter_rust_bridge::frb(sync)]
+pub fn std_durations_twin_sync_sse(durations: Vec<Duration>) -> Vec<Duration> {
+    durations
+}
+
+#[flutter_rust_bridge::frb(serialize)]
+#[flutter_rust_bridge::frb(sync)]
+pub fn std_system_times_twin_sync_sse(times: Vec<SystemTime>) -> Vec<SystemTime> {
+    times
+}
+
+#[flutter_rust_bridge::frb(serialize)]
+#[flutter_rust_bridge::frb(sync)]
+pub fn std_optional_duration_twin_sync_sse(d: Option<Duration>) -> Option<Duration> {
+    d
+}
+
+#[flutter_rust_bridge::frb(serialize)]
+#[flutter_rust_bridge::frb(sync)]
+pub fn std_optional_system_time_twin_sync_sse(t: Option<SystemTime>) -> Option<SystemTime> {
+    t
+}
+
+#[flutter_rust_bridge::frb(serialize)]
+#[flutter_rust_bridge::frb(sync)]
+pub fn std_time_struct_twin_sync_sse(value: StdTimeTwinSyncSse) -> StdTimeTwinSyncSse {
+    value
+}
@@ -0,0 +1,73 @@
+// NOTE: This file is mimicking how a human developer writes tests,
+// and is auto-generated from `time_type.rs` by frb_internal
+// Please do not modify manually, but modify the origin and re-run frb_internal generator
+
+use time::{Date, Duration};
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn offset_date_time_twin_sync(d: time::OffsetDateTime) -> time::OffsetDateTime {
+    assert_eq!(d.year(), 2022);
+    assert_eq!(d.month(), time::Month::September);
+    assert_eq!(d.day(), 10);
+    assert_eq!(d.hour(), 20);
+    assert_eq!(d.minute(), 48);
+    assert_eq!(d.second(), 53);
+    assert_eq!(d.millisecond(), 123);
+    assert_eq!(d.offset(), time::UtcOffset::UTC);
+    d
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn primitive_date_time_twin_sync(d: time::PrimitiveDateTime) -> time::PrimitiveDateTime {
+    assert_eq!(d.year(), 2022);
+    assert_eq!(d.hour(), 20);
+    assert_eq!(d.millisecond(), 123);
+    d
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn date_twin_sync(d: Date) -> Date {
+    assert_eq!(d.year(), 1969);
+    assert_eq!(d.month(), time::Month::July);
+    assert_eq!(d.day(), 20);
+    d
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn time_duration_twin_sync(d: Duration) -> Duration {
+    d
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn negate_time_duration_twin_sync(d: Duration) -> Duration {
+    -d
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn time_durations_twin_sync(durations: Vec<Duration>) -> Vec<Duration> {
+    durations
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn optional_time_duration_twin_sync(d: Option<Duration>) -> Option<Duration> {
+    d
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn optional_offset_date_time_twin_sync(
+    d: Option<time::OffsetDateTime>,
+) -> Option<time::OffsetDateTime> {
+    d
+}
+
+pub struct TimeTypesTwinSync {
+    pub offset: time::OffsetDateTime,
+    pub primitive: Option<time::PrimitiveDateTime>,
+    pub date: Date,
+    pub duration: Duration,
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn time_types_twin_sync(value: TimeTypesTwinSync) -> TimeTypesTwinSync {
+    value
+}
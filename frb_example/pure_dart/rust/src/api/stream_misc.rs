@@ -2,8 +2,9 @@
 
 use crate::frb_generated::StreamSink;
 use crate::frb_generated::FLUTTER_RUST_BRIDGE_HANDLER;
+use anyhow::anyhow;
 use flutter_rust_bridge::for_generated::BaseThreadPool;
-use flutter_rust_bridge::{frb, transfer};
+use flutter_rust_bridge::{frb, transfer, StreamSinkOverflowPolicy};
 use log::info;
 use std::sync::atomic::{AtomicI32, Ordering};
 use std::sync::Arc;
@@ -42,3 +43,31 @@ pub fn func_stream_realistic_twin_normal(sink: StreamSink<String>, arg: String)
 pub fn stream_sink_dart_async_twin_normal(sink: StreamSink<i32>) {
     sink.add(100).unwrap()
 }
+
+#[frb(stream_capacity = 2)]
+pub fn stream_sink_bounded_add_async_twin_normal(sink: StreamSink<i32>) {
+    flutter_rust_bridge::spawn(async move {
+        for i in 0..10 {
+            sink.add_async(i).await.unwrap();
+        }
+    });
+}
+
+#[frb(stream_capacity = 1)]
+pub fn stream_sink_bounded_keep_latest_twin_normal(sink: StreamSink<i32>) {
+    sink.set_overflow_policy(StreamSinkOverflowPolicy::KeepLatest);
+    for i in 0..10 {
+        sink.add(i).unwrap();
+    }
+}
+
+#[frb(stream_capacity = 1)]
+pub fn stream_sink_bounded_add_with_error_twin_normal(sink: StreamSink<i32>) {
+    for i in 0..5 {
+        sink.add(i).unwrap();
+    }
+    sink.add_error(anyhow!("deliberate error")).unwrap();
+    for i in 5..10 {
+        sink.add(i).unwrap();
+    }
+}
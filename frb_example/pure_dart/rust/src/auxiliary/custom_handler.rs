@@ -16,7 +16,7 @@ pub type MyUnmodifiedHandler = SimpleHandler<
 pub struct MyCustomErrorListener;
 
 impl ErrorListener for MyCustomErrorListener {
-    fn on_error(&self, error: HandlerError) {
+    fn on_error(&self, error: HandlerError, task_info: &TaskInfo) {
         unimplemented!()
     }
 }
@@ -35,6 +35,8 @@ impl BaseThreadPool for MyCustomThreadPool {
 pub struct MyCustomAsyncRuntime;
 
 impl BaseAsyncRuntime for MyCustomAsyncRuntime {
+    type JoinHandle<T: Send + 'static> = JoinHandle<T>;
+
     fn spawn<F>(&self, future: F) -> JoinHandle<F::Output>
     where
         F: Future + Send + 'static,
@@ -87,6 +89,25 @@ impl Executor for MyCustomExecutor {
 
 pub type MyCustomHandlerWithCustomExecutor = SimpleHandler<MyCustomExecutor, MyCustomErrorListener>;
 
+#[derive(Clone, Copy)]
+pub struct MyCustomInterceptor;
+
+impl Interceptor for MyCustomInterceptor {
+    fn on_enter(&self, task_info: &TaskInfo, phase: InterceptorPhase) {
+        unimplemented!()
+    }
+
+    fn on_exit(&self, task_info: &TaskInfo, phase: InterceptorPhase) {
+        unimplemented!()
+    }
+}
+
+pub type MyCustomHandlerWithInterceptor = SimpleHandler<
+    SimpleExecutor<NoOpErrorListener, SimpleThreadPool, SimpleAsyncRuntime>,
+    NoOpErrorListener,
+    MyCustomInterceptor,
+>;
+
 pub struct MyFullyCustomHandler;
 
 impl Handler for MyFullyCustomHandler {
@@ -150,6 +171,18 @@ mod tests {
     #[test]
     pub fn test_create_custom_handlers() {
         MyUnmodifiedHandler::new_simple(Default::default());
+        MyUnmodifiedHandler::new(
+            SimpleExecutor::new(
+                NoOpErrorListener,
+                Default::default(),
+                SimpleAsyncRuntime::builder()
+                    .current_thread()
+                    .thread_name_prefix("my-runtime")
+                    .build()
+                    .unwrap(),
+            ),
+            NoOpErrorListener,
+        );
         MyCustomSimpleHandler::new(
             SimpleExecutor::new(
                 MyCustomErrorListener,
@@ -159,5 +192,7 @@ mod tests {
             MyCustomErrorListener,
         );
         MyCustomHandlerWithCustomExecutor::new(MyCustomExecutor, MyCustomErrorListener);
+        let _: MyCustomHandlerWithInterceptor = MyUnmodifiedHandler::new_simple(Default::default())
+            .with_interceptor(MyCustomInterceptor);
     }
 }
@@ -1,9 +1,12 @@
 // FRB_INTERNAL_GENERATOR: {"forbiddenDuplicatorModes": ["sync", "rustAsync", "sync sse", "rustAsync sse"]}
 
+import 'package:flutter_rust_bridge/flutter_rust_bridge.dart';
 import 'package:frb_example_pure_dart/src/rust/api/async_misc.dart';
 import 'package:frb_example_pure_dart/src/rust/frb_generated.dart';
 import 'package:test/test.dart';
 
+import '../test_utils.dart';
+
 Future<void> main({bool skipRustLibInit = false}) async {
   if (!skipRustLibInit) await RustLib.init();
 
@@ -14,4 +17,29 @@ Future<void> main({bool skipRustLibInit = false}) async {
   test('dart call funcAsyncSimpleAdd', () async {
     expect(await funcAsyncSimpleAddTwinNormal(a: 10, b: 20), 30);
   });
+
+  group('cancellation', skip: kIsWeb, () {
+    test('when the future is pending', () async {
+      final token = RustCancelToken();
+      final future = token.run(() => funcAsyncNeverCompleteTwinNormal());
+      await Future<void>.delayed(const Duration(milliseconds: 50));
+      token.cancel();
+      await expectLater(future, throwsA(isA<RustCancelledException>()));
+    });
+
+    test('when the function checks the token', () async {
+      final operation = RustCancelToken.cancelable(
+          () => funcAsyncWaitForCancellationTwinNormal());
+      await Future<void>.delayed(const Duration(milliseconds: 50));
+      await operation.cancel();
+      expect(operation.isCanceled, true);
+    });
+
+    test('when the token is cancelled before calling', () async {
+      final token = RustCancelToken()..cancel();
+      await expectLater(
+          token.run(() => funcAsyncSimpleAddTwinNormal(a: 1, b: 2)),
+          throwsA(isA<RustCancelledException>()));
+    });
+  });
 }
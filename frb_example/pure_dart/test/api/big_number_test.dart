@@ -0,0 +1,75 @@
+import 'package:decimal/decimal.dart';
+import 'package:frb_example_pure_dart/src/rust/api/big_number.dart';
+import 'package:frb_example_pure_dart/src/rust/frb_generated.dart';
+import 'package:test/test.dart';
+
+Future<void> main({bool skipRustLibInit = false}) async {
+  if (!skipRustLibInit) await RustLib.init();
+
+  test('Decimal', () async {
+    for (final value in [
+      '0',
+      '-1.50',
+      '3.1415926535897932384626433832',
+      '79228162514264337593543950335',
+      '-0.0000000000000000000000000001',
+    ]) {
+      final d = Decimal.parse(value);
+      expect(await decimalTwinNormal(d: d), d);
+    }
+  });
+
+  test('Decimal does not lose precision', () async {
+    final resp = await addDecimalsTwinNormal(
+        a: Decimal.parse('0.1'), b: Decimal.parse('0.2'));
+    expect(resp, Decimal.parse('0.3'));
+  });
+
+  test('Option<Decimal>', () async {
+    final d = Decimal.parse('-42.125');
+    expect(await optionalDecimalTwinNormal(d: d), d);
+    expect(await optionalDecimalTwinNormal(d: null), null);
+  });
+
+  test('List<Decimal>', () async {
+    final decimals = [Decimal.one, Decimal.parse('-9.99'), Decimal.zero];
+    expect(await decimalsTwinNormal(decimals: decimals), decimals);
+  });
+
+  test('BigInt', () async {
+    for (final value in [
+      BigInt.zero,
+      BigInt.from(-1),
+      BigInt.parse('123456789012345678901234567890123456789'),
+      -BigInt.two.pow(200),
+    ]) {
+      expect(await bigIntTwinNormal(i: value), value);
+    }
+  });
+
+  test('BigInt does not overflow', () async {
+    final a = BigInt.two.pow(64);
+    final resp = await multiplyBigIntsTwinNormal(a: a, b: -a);
+    expect(resp, -BigInt.two.pow(128));
+  });
+
+  test('Option<BigInt>', () async {
+    final i = BigInt.parse('-98765432109876543210');
+    expect(await optionalBigIntTwinNormal(i: i), i);
+    expect(await optionalBigIntTwinNormal(i: null), null);
+  });
+
+  test('List<BigInt>', () async {
+    final ints = [BigInt.one, -BigInt.two.pow(100), BigInt.zero];
+    expect(await bigIntsTwinNormal(ints: ints), ints);
+  });
+
+  test('struct', () async {
+    final value = BigNumbersTwinNormal(
+      amount: Decimal.parse('1234.5678'),
+      discount: Decimal.parse('0.05'),
+      serial: BigInt.parse('340282366920938463463374607431768211456'),
+    );
+    expect(await bigNumbersTwinNormal(value: value), value);
+  });
+}
@@ -0,0 +1,47 @@
+// FRB_INTERNAL_GENERATOR: {"forbiddenDuplicatorModes": ["sync", "rustAsync", "sse", "sync sse", "rustAsync sse"]}
+
+import 'package:flutter_rust_bridge/flutter_rust_bridge.dart';
+import 'package:frb_example_pure_dart/src/rust/api/chrono_tz_type.dart';
+import 'package:frb_example_pure_dart/src/rust/frb_generated.dart';
+import 'package:test/test.dart';
+
+Future<void> main({bool skipRustLibInit = false}) async {
+  if (!skipRustLibInit) await RustLib.init();
+
+  final shanghai = DateTimeWithOffset(
+      DateTime.utc(2022, 09, 10, 12, 48, 53), const Duration(hours: 8),
+      timeZone: 'Asia/Shanghai');
+  final newYork = DateTimeWithOffset(
+      DateTime.utc(2022, 07, 01, 16), const Duration(hours: -4),
+      timeZone: 'America/New_York');
+
+  test('DateTime<Tz>', () async {
+    final resp = await datetimeTzTwinNormal(d: shanghai);
+    expect(resp, shanghai);
+    expect(resp.wallClock.hour, 20);
+  });
+
+  test('Option<DateTime<Tz>>', () async {
+    expect(await optionalDatetimeTzTwinNormal(d: newYork), newYork);
+    expect(await optionalDatetimeTzTwinNormal(d: null), null);
+  });
+
+  test('List<DateTime<Tz>>', () async {
+    final dates = [shanghai, newYork];
+    expect(await datetimeTzsTwinNormal(dates: dates), dates);
+  });
+
+  test('offset is computed from the time zone', () async {
+    final date =
+        DateTimeWithOffset(DateTime.utc(2022, 07, 01, 16), Duration.zero);
+    final resp = await datetimeWithTimeZoneTwinNormal(
+        d: date, timeZone: 'America/New_York');
+    expect(resp, newYork);
+  });
+
+  test('DateTime<Tz> with the default codec', () async {
+    expect(await datetimeTzNameTwinNormal(d: newYork), 'America/New_York');
+    expect(await datetimeTzToFixedOffsetTwinNormal(d: newYork),
+        DateTimeWithOffset(newYork.utc, const Duration(hours: -4)));
+  });
+}
@@ -94,4 +94,49 @@ Future<void> main({bool skipRustLibInit = false}) async {
           expectOutput: null);
     });
   });
+
+  group('rustConsumeDartStream', () {
+    test('sum all items', () async {
+      expect(
+          await rustConsumeDartStreamSumTwinNormal(
+              stream: Stream.fromIterable([1, 2, 3, 4])),
+          10);
+    });
+
+    test('empty stream', () async {
+      expect(
+          await rustConsumeDartStreamSumTwinNormal(stream: const Stream.empty()),
+          0);
+    });
+
+    test('forward error', () async {
+      await expectLater(
+          () => rustConsumeDartStreamSumTwinNormal(stream: () async* {
+                yield 1;
+                throw Exception('dummy exception');
+              }()),
+          throwsA(isA<AnyhowException>().having(
+              (e) => e.message, 'message', contains('dummy exception'))));
+    });
+
+    test('cancel Dart stream when Rust stops', () async {
+      var cancelled = false;
+      Stream<String> produce() async* {
+        try {
+          for (var i = 0;; i++) {
+            yield 'item-$i';
+          }
+        } finally {
+          cancelled = true;
+        }
+      }
+
+      expect(
+          await rustConsumeDartStreamTakeTwinNormal(
+              stream: produce(), count: 3),
+          ['item-0', 'item-1', 'item-2']);
+      await Future.delayed(const Duration(milliseconds: 50));
+      expect(cancelled, true);
+    });
+  });
 }
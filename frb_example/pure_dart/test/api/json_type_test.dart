@@ -0,0 +1,87 @@
+import 'package:frb_example_pure_dart/src/rust/api/json_type.dart';
+import 'package:frb_example_pure_dart/src/rust/frb_generated.dart';
+import 'package:test/test.dart';
+
+Future<void> main({bool skipRustLibInit = false}) async {
+  if (!skipRustLibInit) await RustLib.init();
+
+  test('Value', () async {
+    for (final value in [
+      null,
+      true,
+      42,
+      -1.5,
+      'hello',
+      [1, 'two', null],
+      {
+        'a': {'b': []},
+        'c': false,
+      },
+    ]) {
+      expect(await jsonValueTwinNormal(value: value), value);
+    }
+  });
+
+  test('Option<Value>', () async {
+    expect(await optionalJsonValueTwinNormal(value: 'x'), 'x');
+    expect(await optionalJsonValueTwinNormal(value: null), null);
+  });
+
+  test('List<Value>', () async {
+    final values = [
+      {'id': 1},
+      'text',
+      [true],
+    ];
+    expect(await jsonValuesTwinNormal(values: values), values);
+  });
+
+  test('Map', () async {
+    final map = {
+      'x': 1,
+      'y': [null, 'z'],
+    };
+    expect(await jsonMapTwinNormal(map: map), map);
+    expect(await jsonMapTwinNormal(map: {}), <String, dynamic>{});
+  });
+
+  test('Dart to Rust', () async {
+    final resp = await jsonValueToStringTwinNormal(value: {
+      'b': [1, 'x', null],
+      'a': true,
+    });
+    expect(resp, '{"a":true,"b":[1,"x",null]}');
+    final keys = await jsonMapKeysTwinNormal(map: {'k2': 2, 'k1': '1'});
+    expect(keys, ['k1', 'k2']);
+  });
+
+  test('Rust to Dart', () async {
+    expect(await createJsonValueTwinNormal(), {
+      'name': 'frb',
+      'version': 2,
+      'ratio': 0.5,
+      'tags': ['rust', 'dart'],
+      'nested': {'ok': true, 'missing': null},
+    });
+    expect(await createJsonMapTwinNormal(), {
+      'count': -3,
+      'items': [
+        {'id': 1},
+        {'id': 2},
+      ],
+    });
+  });
+
+  test('struct', () async {
+    final resp = await jsonDocumentTwinNormal(
+      document: JsonDocumentTwinNormal(
+        body: [1, 2, 3],
+        meta: {'author': 'me'},
+        extra: null,
+      ),
+    );
+    expect(resp.body, [1, 2, 3]);
+    expect(resp.meta, {'author': 'me'});
+    expect(resp.extra, null);
+  });
+}
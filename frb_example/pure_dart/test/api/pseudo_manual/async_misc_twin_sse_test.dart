@@ -4,10 +4,13 @@
 
 // FRB_INTERNAL_GENERATOR: {"forbiddenDuplicatorModes": ["sync", "rustAsync", "sync sse", "rustAsync sse"]}
 
+import 'package:flutter_rust_bridge/flutter_rust_bridge.dart';
 import 'package:frb_example_pure_dart/src/rust/api/pseudo_manual/async_misc_twin_sse.dart';
 import 'package:frb_example_pure_dart/src/rust/frb_generated.dart';
 import 'package:test/test.dart';
 
+import '../../test_utils.dart';
+
 Future<void> main({bool skipRustLibInit = false}) async {
   if (!skipRustLibInit) await RustLib.init();
 
@@ -18,4 +21,29 @@ Future<void> main({bool skipRustLibInit = false}) async {
   test('dart call funcAsyncSimpleAdd', () async {
     expect(await funcAsyncSimpleAddTwinSse(a: 10, b: 20), 30);
   });
+
+  group('cancellation', skip: kIsWeb, () {
+    test('when the future is pending', () async {
+      final token = RustCancelToken();
+      final future = token.run(() => funcAsyncNeverCompleteTwinSse());
+      await Future<void>.delayed(const Duration(milliseconds: 50));
+      token.cancel();
+      await expectLater(future, throwsA(isA<RustCancelledException>()));
+    });
+
+    test('when the function checks the token', () async {
+      final operation = RustCancelToken.cancelable(
+          () => funcAsyncWaitForCancellationTwinSse());
+      await Future<void>.delayed(const Duration(milliseconds: 50));
+      await operation.cancel();
+      expect(operation.isCanceled, true);
+    });
+
+    test('when the token is cancelled before calling', () async {
+      final token = RustCancelToken()..cancel();
+      await expectLater(
+          token.run(() => funcAsyncSimpleAddTwinSse(a: 1, b: 2)),
+          throwsA(isA<RustCancelledException>()));
+    });
+  });
 }
@@ -2,6 +2,7 @@
 // and is auto-generated from `chrono_type_test.dart` by frb_internal
 // Please do not modify manually, but modify the origin and re-run frb_internal generator
 
+import 'package:flutter_rust_bridge/flutter_rust_bridge.dart';
 import 'package:frb_example_pure_dart/src/rust/api/pseudo_manual/chrono_type_twin_rust_async_sse.dart';
 import 'package:frb_example_pure_dart/src/rust/frb_generated.dart';
 import 'package:test/test.dart';
@@ -121,4 +122,61 @@ Future<void> main({bool skipRustLibInit = false}) async {
             utc: utc, local: local, duration: duration, naive: naive));
     debugPrint('$difference');
   });
+
+  test('NaiveDate', () async {
+    final date = DateTime.utc(2022, 09, 10);
+    expect(await naiveDateTwinRustAsyncSse(d: date), date);
+  });
+
+  test('NaiveTime', () async {
+    const time =
+        Duration(hours: 20, minutes: 48, seconds: 53, milliseconds: 123);
+    expect(await naiveTimeTwinRustAsyncSse(d: time), time);
+  });
+
+  test('DateTime<FixedOffset>', () async {
+    final date = DateTimeWithOffset(
+        DateTime.utc(2022, 09, 10, 12, 48, 53, 123), const Duration(hours: 8));
+    final resp = await datetimeFixedOffsetTwinRustAsyncSse(d: date);
+    expect(resp, date);
+    expect(resp.offset, const Duration(hours: 8));
+    expect(resp.wallClock.hour, 20);
+    expect(resp.timeZone, null);
+  });
+
+  test('Option<DateTime<FixedOffset>>', () async {
+    final date = DateTimeWithOffset(
+        DateTime.utc(2022, 09, 10), const Duration(hours: -5, minutes: -30));
+    expect(await optionalDatetimeFixedOffsetTwinRustAsyncSse(d: date), date);
+    expect(await optionalDatetimeFixedOffsetTwinRustAsyncSse(d: null), null);
+  });
+
+  test('List<NaiveDate>', () async {
+    final dates = [
+      DateTime.utc(1969, 07, 20),
+      DateTime.utc(1970, 01, 01),
+      DateTime.utc(2022, 09, 10),
+    ];
+    expect(await naiveDatesTwinRustAsyncSse(dates: dates), dates);
+  });
+
+  test('List<DateTime<FixedOffset>>', () async {
+    final dates = [
+      DateTimeWithOffset(DateTime.utc(1969, 07, 20, 20, 17), Duration.zero),
+      DateTimeWithOffset(
+          DateTime.utc(2022, 09, 10, 12, 48, 53), const Duration(hours: 8)),
+    ];
+    expect(await datetimeFixedOffsetsTwinRustAsyncSse(dates: dates), dates);
+  });
+
+  test('struct with NaiveDate, NaiveTime and DateTime<FixedOffset>', () async {
+    final schedule = ScheduleTwinRustAsyncSse(
+      day: DateTime.utc(2022, 09, 10),
+      starts: const Duration(hours: 9),
+      ends: const Duration(hours: 17, minutes: 30),
+      at: DateTimeWithOffset(
+          DateTime.utc(2022, 09, 10, 1), const Duration(hours: 8)),
+    );
+    expect(await scheduleTwinRustAsyncSse(schedule: schedule), schedule);
+  });
 }
@@ -244,4 +244,61 @@ Future<void> main({bool skipRustLibInit = false}) async {
           messageMatcherOnNative: matcher);
     });
   });
+
+  group('typed exceptions', () {
+    test('catch by variant', () async {
+      try {
+        await typedExceptionReturnErrorTwinRustAsyncSse(variant: 0);
+        fail('should throw');
+      } on TypedExceptionErrorTwinRustAsyncSse_NotFound catch (e) {
+        expect(e.path, '/tmp/a.txt');
+        expect(e.toString(), 'not found: /tmp/a.txt');
+      }
+    });
+
+    test('catch by base class', () async {
+      await expectLater(
+          () async => typedExceptionReturnErrorTwinRustAsyncSse(variant: 1),
+          throwsA(isA<TypedExceptionErrorTwinRustAsyncSse_Io>()
+              .having((e) => e.field0, 'field0', 'disk full')
+              .having((e) => e.toString(), 'toString', 'io: disk full')));
+      await expectLater(
+          () async => typedExceptionReturnErrorTwinRustAsyncSse(variant: 2),
+          throwsA(isA<TypedExceptionErrorTwinRustAsyncSse>()
+              .having((e) => e.displayMessage, 'displayMessage', 'unknown')
+              .having((e) => e.toString(), 'toString', 'unknown')));
+    });
+
+    test('return ok', () async {
+      expect(await typedExceptionReturnErrorTwinRustAsyncSse(variant: 42), 42);
+    });
+  });
+
+  group('anyhow exception details', () {
+    test('chain of causes', () async {
+      await expectLater(
+          () async => throwAnyhowWithContextTwinRustAsyncSse(),
+          throwsA(isA<AnyhowException>()
+              .having((e) => e.causes, 'causes',
+                  ['outer context', 'middle context', 'root cause'])
+              .having((e) => e.typeName, 'typeName', isNull)));
+    });
+
+    test('type name of the root cause', () async {
+      await expectLater(
+          () async => throwAnyhowIoErrorTwinRustAsyncSse(),
+          throwsA(isA<AnyhowException>()
+              .having((e) => e.causes, 'causes',
+                  ['reading config', 'missing file'])
+              .having(
+                  (e) => e.typeName, 'typeName', 'std::io::error::Error')));
+    });
+
+    test('backtrace', skip: kIsWeb, () async {
+      await expectLater(
+          () async => funcReturnErrorTwinRustAsyncSse(),
+          throwsA(isA<AnyhowException>().having((e) => e.backtrace,
+              'backtrace', allOf(isNotNull, contains('.rs')))));
+    });
+  });
 }
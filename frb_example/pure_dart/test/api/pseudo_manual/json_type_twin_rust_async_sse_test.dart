@@ -0,0 +1,91 @@
+// NOTE: This file is mimicking how a human developer writes tests,
+// and is auto-generated from `json_type_test.dart` by frb_internal
+// Please do not modify manually, but modify the origin and re-run frb_internal generator
+
+import 'package:frb_example_pure_dart/src/rust/api/pseudo_manual/json_type_twin_rust_async_sse.dart';
+import 'package:frb_example_pure_dart/src/rust/frb_generated.dart';
+import 'package:test/test.dart';
+
+Future<void> main({bool skipRustLibInit = false}) async {
+  if (!skipRustLibInit) await RustLib.init();
+
+  test('Value', () async {
+    for (final value in [
+      null,
+      true,
+      42,
+      -1.5,
+      'hello',
+      [1, 'two', null],
+      {
+        'a': {'b': []},
+        'c': false,
+      },
+    ]) {
+      expect(await jsonValueTwinRustAsyncSse(value: value), value);
+    }
+  });
+
+  test('Option<Value>', () async {
+    expect(await optionalJsonValueTwinRustAsyncSse(value: 'x'), 'x');
+    expect(await optionalJsonValueTwinRustAsyncSse(value: null), null);
+  });
+
+  test('List<Value>', () async {
+    final values = [
+      {'id': 1},
+      'text',
+      [true],
+    ];
+    expect(await jsonValuesTwinRustAsyncSse(values: values), values);
+  });
+
+  test('Map', () async {
+    final map = {
+      'x': 1,
+      'y': [null, 'z'],
+    };
+    expect(await jsonMapTwinRustAsyncSse(map: map), map);
+    expect(await jsonMapTwinRustAsyncSse(map: {}), <String, dynamic>{});
+  });
+
+  test('Dart to Rust', () async {
+    final resp = await jsonValueToStringTwinRustAsyncSse(value: {
+      'b': [1, 'x', null],
+      'a': true,
+    });
+    expect(resp, '{"a":true,"b":[1,"x",null]}');
+    final keys = await jsonMapKeysTwinRustAsyncSse(map: {'k2': 2, 'k1': '1'});
+    expect(keys, ['k1', 'k2']);
+  });
+
+  test('Rust to Dart', () async {
+    expect(await createJsonValueTwinRustAsyncSse(), {
+      'name': 'frb',
+      'version': 2,
+      'ratio': 0.5,
+      'tags': ['rust', 'dart'],
+      'nested': {'ok': true, 'missing': null},
+    });
+    expect(await createJsonMapTwinRustAsyncSse(), {
+      'count': -3,
+      'items': [
+        {'id': 1},
+        {'id': 2},
+      ],
+    });
+  });
+
+  test('struct', () async {
+    final resp = await jsonDocumentTwinRustAsyncSse(
+      document: JsonDocumentTwinRustAsyncSse(
+        body: [1, 2, 3],
+        meta: {'author': 'me'},
+        extra: null,
+      ),
+    );
+    expect(resp.body, [1, 2, 3]);
+    expect(resp.meta, {'author': 'me'});
+    expect(resp.extra, null);
+  });
+}
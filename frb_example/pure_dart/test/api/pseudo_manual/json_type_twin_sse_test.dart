@@ -0,0 +1,91 @@
+// NOTE: This file is mimicking how a human developer writes tests,
+// and is auto-generated from `json_type_test.dart` by frb_internal
+// Please do not modify manually, but modify the origin and re-run frb_internal generator
+
+import 'package:frb_example_pure_dart/src/rust/api/pseudo_manual/json_type_twin_sse.dart';
+import 'package:frb_example_pure_dart/src/rust/frb_generated.dart';
+import 'package:test/test.dart';
+
+Future<void> main({bool skipRustLibInit = false}) async {
+  if (!skipRustLibInit) await RustLib.init();
+
+  test('Value', () async {
+    for (final value in [
+      null,
+      true,
+      42,
+      -1.5,
+      'hello',
+      [1, 'two', null],
+      {
+        'a': {'b': []},
+        'c': false,
+      },
+    ]) {
+      expect(await jsonValueTwinSse(value: value), value);
+    }
+  });
+
+  test('Option<Value>', () async {
+    expect(await optionalJsonValueTwinSse(value: 'x'), 'x');
+    expect(await optionalJsonValueTwinSse(value: null), null);
+  });
+
+  test('List<Value>', () async {
+    final values = [
+      {'id': 1},
+      'text',
+      [true],
+    ];
+    expect(await jsonValuesTwinSse(values: values), values);
+  });
+
+  test('Map', () async {
+    final map = {
+      'x': 1,
+      'y': [null, 'z'],
+    };
+    expect(await jsonMapTwinSse(map: map), map);
+    expect(await jsonMapTwinSse(map: {}), <String, dynamic>{});
+  });
+
+  test('Dart to Rust', () async {
+    final resp = await jsonValueToStringTwinSse(value: {
+      'b': [1, 'x', null],
+      'a': true,
+    });
+    expect(resp, '{"a":true,"b":[1,"x",null]}');
+    final keys = await jsonMapKeysTwinSse(map: {'k2': 2, 'k1': '1'});
+    expect(keys, ['k1', 'k2']);
+  });
+
+  test('Rust to Dart', () async {
+    expect(await createJsonValueTwinSse(), {
+      'name': 'frb',
+      'version': 2,
+      'ratio': 0.5,
+      'tags': ['rust', 'dart'],
+      'nested': {'ok': true, 'missing': null},
+    });
+    expect(await createJsonMapTwinSse(), {
+      'count': -3,
+      'items': [
+        {'id': 1},
+        {'id': 2},
+      ],
+    });
+  });
+
+  test('struct', () async {
+    final resp = await jsonDocumentTwinSse(
+      document: JsonDocumentTwinSse(
+        body: [1, 2, 3],
+        meta: {'author': 'me'},
+        extra: null,
+      ),
+    );
+    expect(resp.body, [1, 2, 3]);
+    expect(resp.meta, {'author': 'me'});
+    expect(resp.extra, null);
+  });
+}
@@ -0,0 +1,91 @@
+// NOTE: This file is mimicking how a human developer writes tests,
+// and is auto-generated from `json_type_test.dart` by frb_internal
+// Please do not modify manually, but modify the origin and re-run frb_internal generator
+
+import 'package:frb_example_pure_dart/src/rust/api/pseudo_manual/json_type_twin_sync_sse.dart';
+import 'package:frb_example_pure_dart/src/rust/frb_generated.dart';
+import 'package:test/test.dart';
+
+Future<void> main({bool skipRustLibInit = false}) async {
+  if (!skipRustLibInit) await RustLib.init();
+
+  test('Value', () async {
+    for (final value in [
+      null,
+      true,
+      42,
+      -1.5,
+      'hello',
+      [1, 'two', null],
+      {
+        'a': {'b': []},
+        'c': false,
+      },
+    ]) {
+      expect(await jsonValueTwinSyncSse(value: value), value);
+    }
+  });
+
+  test('Option<Value>', () async {
+    expect(await optionalJsonValueTwinSyncSse(value: 'x'), 'x');
+    expect(await optionalJsonValueTwinSyncSse(value: null), null);
+  });
+
+  test('List<Value>', () async {
+    final values = [
+      {'id': 1},
+      'text',
+      [true],
+    ];
+    expect(await jsonValuesTwinSyncSse(values: values), values);
+  });
+
+  test('Map', () async {
+    final map = {
+      'x': 1,
+      'y': [null, 'z'],
+    };
+    expect(await jsonMapTwinSyncSse(map: map), map);
+    expect(await jsonMapTwinSyncSse(map: {}), <String, dynamic>{});
+  });
+
+  test('Dart to Rust', () async {
+    final resp = await jsonValueToStringTwinSyncSse(value: {
+      'b': [1, 'x', null],
+      'a': true,
+    });
+    expect(resp, '{"a":true,"b":[1,"x",null]}');
+    final keys = await jsonMapKeysTwinSyncSse(map: {'k2': 2, 'k1': '1'});
+    expect(keys, ['k1', 'k2']);
+  });
+
+  test('Rust to Dart', () async {
+    expect(await createJsonValueTwinSyncSse(), {
+      'name': 'frb',
+      'version': 2,
+      'ratio': 0.5,
+      'tags': ['rust', 'dart'],
+      'nested': {'ok': true, 'missing': null},
+    });
+    expect(await createJsonMapTwinSyncSse(), {
+      'count': -3,
+      'items': [
+        {'id': 1},
+        {'id': 2},
+      ],
+    });
+  });
+
+  test('struct', () async {
+    final resp = await jsonDocumentTwinSyncSse(
+      document: JsonDocumentTwinSyncSse(
+        body: [1, 2, 3],
+        meta: {'author': 'me'},
+        extra: null,
+      ),
+    );
+    expect(resp.body, [1, 2, 3]);
+    expect(resp.meta, {'author': 'me'});
+    expect(resp.extra, null);
+  });
+}
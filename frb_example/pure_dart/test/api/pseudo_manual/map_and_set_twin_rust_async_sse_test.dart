@@ -81,4 +81,51 @@ Future<void> main({bool skipRustLibInit = false}) async {
           'b': KitchenSinkTwinRustAsyncSse.nested(42),
         },
       ]);
+
+  addTestsIdentityFunctionCall(funcBtreeMapI32I32TwinRustAsyncSse, <Map<int, int>>[
+    {},
+    {10: 20},
+    {10: 20, 30: 40},
+  ]);
+  addTestsIdentityFunctionCall(funcBtreeSetI32TwinRustAsyncSse, <Set<int>>[
+    {},
+    {10},
+    {10, 20},
+  ]);
+
+  addTestsIdentityFunctionCall(
+      funcBtreeMapStringStructTwinRustAsyncSse, <Map<String, MySize>>[
+    {},
+    {'a': MySize(width: 1, height: 2)},
+    {
+      'a': MySize(width: 1, height: 2),
+      'b': MySize(width: 3, height: 4),
+    },
+  ]);
+  addTestsIdentityFunctionCall(funcBtreeSetStringTwinRustAsyncSse, <Set<String>>[
+    {},
+    {'a'},
+    {'a', 'b'},
+  ]);
+
+  test('BTreeMap and BTreeSet keep the Rust ordering', () async {
+    final map =
+        await funcBtreeMapI32I32TwinRustAsyncSse(arg: {30: 40, 10: 20, 20: 30});
+    expect(map.keys.toList(), [10, 20, 30]);
+    expect(map.values.toList(), [20, 30, 40]);
+
+    final set = await funcBtreeSetStringTwinRustAsyncSse(arg: {'c', 'a', 'b'});
+    expect(set.toList(), ['a', 'b', 'c']);
+  });
+
+  addTestsIdentityFunctionCall(funcVecDequeI32TwinRustAsyncSse, <Int32List>[
+    Int32List.fromList([]),
+    Int32List.fromList([10]),
+    Int32List.fromList([30, 10, 20]),
+  ]);
+  addTestsIdentityFunctionCall(funcVecDequeStringTwinRustAsyncSse, <List<String>>[
+    [],
+    ['a'],
+    ['c', 'a', 'b'],
+  ]);
 }
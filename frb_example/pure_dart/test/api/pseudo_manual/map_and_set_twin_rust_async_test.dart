@@ -80,4 +80,51 @@ Future<void> main({bool skipRustLibInit = false}) async {
           'b': KitchenSinkTwinRustAsync.nested(42),
         },
       ]);
+
+  addTestsIdentityFunctionCall(funcBtreeMapI32I32TwinRustAsync, <Map<int, int>>[
+    {},
+    {10: 20},
+    {10: 20, 30: 40},
+  ]);
+  addTestsIdentityFunctionCall(funcBtreeSetI32TwinRustAsync, <Set<int>>[
+    {},
+    {10},
+    {10, 20},
+  ]);
+
+  addTestsIdentityFunctionCall(
+      funcBtreeMapStringStructTwinRustAsync, <Map<String, MySize>>[
+    {},
+    {'a': MySize(width: 1, height: 2)},
+    {
+      'a': MySize(width: 1, height: 2),
+      'b': MySize(width: 3, height: 4),
+    },
+  ]);
+  addTestsIdentityFunctionCall(funcBtreeSetStringTwinRustAsync, <Set<String>>[
+    {},
+    {'a'},
+    {'a', 'b'},
+  ]);
+
+  test('BTreeMap and BTreeSet keep the Rust ordering', () async {
+    final map =
+        await funcBtreeMapI32I32TwinRustAsync(arg: {30: 40, 10: 20, 20: 30});
+    expect(map.keys.toList(), [10, 20, 30]);
+    expect(map.values.toList(), [20, 30, 40]);
+
+    final set = await funcBtreeSetStringTwinRustAsync(arg: {'c', 'a', 'b'});
+    expect(set.toList(), ['a', 'b', 'c']);
+  });
+
+  addTestsIdentityFunctionCall(funcVecDequeI32TwinRustAsync, <Int32List>[
+    Int32List.fromList([]),
+    Int32List.fromList([10]),
+    Int32List.fromList([30, 10, 20]),
+  ]);
+  addTestsIdentityFunctionCall(funcVecDequeStringTwinRustAsync, <List<String>>[
+    [],
+    ['a'],
+    ['c', 'a', 'b'],
+  ]);
 }
@@ -80,4 +80,51 @@ Future<void> main({bool skipRustLibInit = false}) async {
           'b': KitchenSinkTwinSyncSse.nested(42),
         },
       ]);
+
+  addTestsIdentityFunctionCall(funcBtreeMapI32I32TwinSyncSse, <Map<int, int>>[
+    {},
+    {10: 20},
+    {10: 20, 30: 40},
+  ]);
+  addTestsIdentityFunctionCall(funcBtreeSetI32TwinSyncSse, <Set<int>>[
+    {},
+    {10},
+    {10, 20},
+  ]);
+
+  addTestsIdentityFunctionCall(
+      funcBtreeMapStringStructTwinSyncSse, <Map<String, MySize>>[
+    {},
+    {'a': MySize(width: 1, height: 2)},
+    {
+      'a': MySize(width: 1, height: 2),
+      'b': MySize(width: 3, height: 4),
+    },
+  ]);
+  addTestsIdentityFunctionCall(funcBtreeSetStringTwinSyncSse, <Set<String>>[
+    {},
+    {'a'},
+    {'a', 'b'},
+  ]);
+
+  test('BTreeMap and BTreeSet keep the Rust ordering', () async {
+    final map =
+        await funcBtreeMapI32I32TwinSyncSse(arg: {30: 40, 10: 20, 20: 30});
+    expect(map.keys.toList(), [10, 20, 30]);
+    expect(map.values.toList(), [20, 30, 40]);
+
+    final set = await funcBtreeSetStringTwinSyncSse(arg: {'c', 'a', 'b'});
+    expect(set.toList(), ['a', 'b', 'c']);
+  });
+
+  addTestsIdentityFunctionCall(funcVecDequeI32TwinSyncSse, <Int32List>[
+    Int32List.fromList([]),
+    Int32List.fromList([10]),
+    Int32List.fromList([30, 10, 20]),
+  ]);
+  addTestsIdentityFunctionCall(funcVecDequeStringTwinSyncSse, <List<String>>[
+    [],
+    ['a'],
+    ['c', 'a', 'b'],
+  ]);
 }
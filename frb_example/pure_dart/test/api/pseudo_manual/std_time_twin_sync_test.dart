@@ -0,0 +1,84 @@
+// NOTE: This file is mimicking how a human developer writes tests,
+// and is auto-generated from `std_time_test.dart` by frb_internal
+// Please do not modify manually, but modify the origin and re-run frb_internal generator
+
+import 'package:flutter_rust_bridge/flutter_rust_bridge.dart';
+import 'package:frb_example_pure_dart/src/rust/api/pseudo_manual/std_time_twin_sync.dart';
+import 'package:frb_example_pure_dart/src/rust/frb_generated.dart';
+import 'package:test/test.dart';
+
+import '../../test_utils.dart';
+
+Future<void> main({bool skipRustLibInit = false}) async {
+  if (!skipRustLibInit) await RustLib.init();
+
+  final duration = Duration(
+      seconds: 3, milliseconds: 456, microseconds: kIsWeb ? 0 : 789);
+  final time = DateTime.utc(2024, 1, 2, 3, 4, 5, 6, kIsWeb ? 0 : 7);
+  final timeBeforeEpoch = DateTime.utc(1969, 7, 20, 20, 17, 40, 123);
+  // Sync calls report errors thrown while encoding arguments as panics
+  final throwsNegativeDuration = throwsA('TwinSync'.contains('Sync')
+      ? isA<PanicException>()
+      : isA<ArgumentError>());
+
+  test('Duration', () async {
+    expect(await stdDurationTwinSync(d: duration), duration);
+    expect(await stdDurationTwinSync(d: Duration.zero), Duration.zero);
+  });
+
+  test('negative Duration', () async {
+    await expectLater(() => stdDurationTwinSync(d: -duration),
+        throwsNegativeDuration);
+  });
+
+  test('SystemTime', () async {
+    for (final t in [time, timeBeforeEpoch]) {
+      final resp = await stdSystemTimeTwinSync(t: t);
+      expect(resp, t);
+      expect(resp.isUtc, true);
+    }
+  });
+
+  test('SystemTime from local DateTime', () async {
+    final local = time.toLocal();
+    expect(await stdSystemTimeTwinSync(t: local), time);
+  });
+
+  test('Vec', () async {
+    final durations = [Duration.zero, duration, Duration(days: 100)];
+    expect(await stdDurationsTwinSync(durations: durations), durations);
+    final times = [time, timeBeforeEpoch];
+    expect(await stdSystemTimesTwinSync(times: times), times);
+  });
+
+  test('Option', () async {
+    expect(await stdOptionalDurationTwinSync(d: duration), duration);
+    expect(await stdOptionalDurationTwinSync(d: null), null);
+    expect(await stdOptionalSystemTimeTwinSync(t: time), time);
+    expect(await stdOptionalSystemTimeTwinSync(t: null), null);
+  });
+
+  test('struct', () async {
+    for (final optionalDuration in [duration, null]) {
+      final value = StdTimeTwinSync(
+        duration: duration,
+        time: timeBeforeEpoch,
+        optionalDuration: optionalDuration,
+      );
+      final resp = await stdTimeStructTwinSync(value: value);
+      expect(resp.duration, value.duration);
+      expect(resp.time, value.time);
+      expect(resp.optionalDuration, value.optionalDuration);
+    }
+  });
+
+  test('negative Duration in struct', () async {
+    final value = StdTimeTwinSync(
+      duration: duration,
+      time: time,
+      optionalDuration: -duration,
+    );
+    await expectLater(() => stdTimeStructTwinSync(value: value),
+        throwsNegativeDuration);
+  });
+}
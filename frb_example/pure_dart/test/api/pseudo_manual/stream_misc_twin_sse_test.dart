@@ -8,6 +8,8 @@ import 'package:frb_example_pure_dart/src/rust/api/pseudo_manual/stream_misc_twi
 import 'package:frb_example_pure_dart/src/rust/frb_generated.dart';
 import 'package:test/test.dart';
 
+import '../../test_utils.dart';
+
 Future<void> main({bool skipRustLibInit = false}) async {
   if (!skipRustLibInit) await RustLib.init();
 
@@ -25,4 +27,33 @@ Future<void> main({bool skipRustLibInit = false}) async {
     final stream = await streamSinkDartAsyncTwinSse();
     expect(await stream.toList(), [100]);
   });
+
+  test('streamSinkBoundedAddAsyncTwinSse', () async {
+    final stream = streamSinkBoundedAddAsyncTwinSse();
+    expect(await stream.toList(), List.generate(10, (i) => i));
+  });
+
+  test('streamSinkBoundedKeepLatestTwinSse', () async {
+    final stream = streamSinkBoundedKeepLatestTwinSse();
+    // Only start consuming after Rust has added all items,
+    // thus the first item is sent at once while the others are coalesced
+    await Future.delayed(const Duration(milliseconds: 100));
+    expect(await stream.toList(), [0, 9]);
+  }, skip: skipWeb('bounded stream sinks are unbounded on web'));
+
+  test('streamSinkBoundedAddWithErrorTwinSse', () async {
+    final stream = streamSinkBoundedAddWithErrorTwinSse();
+    // Only start consuming after Rust has added all items,
+    // thus all of them (including the error) are buffered without loss
+    await Future.delayed(const Duration(milliseconds: 100));
+    final events = <String>[];
+    await stream
+        .handleError((Object e) => events.add('error'))
+        .forEach((value) => events.add('data $value'));
+    expect(events, [
+      for (var i = 0; i < 5; i++) 'data $i',
+      'error',
+      for (var i = 5; i < 10; i++) 'data $i',
+    ]);
+  });
 }
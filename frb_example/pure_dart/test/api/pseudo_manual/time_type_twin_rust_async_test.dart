@@ -0,0 +1,74 @@
+// NOTE: This file is mimicking how a human developer writes tests,
+// and is auto-generated from `time_type_test.dart` by frb_internal
+// Please do not modify manually, but modify the origin and re-run frb_internal generator
+
+import 'package:frb_example_pure_dart/src/rust/api/pseudo_manual/time_type_twin_rust_async.dart';
+import 'package:frb_example_pure_dart/src/rust/frb_generated.dart';
+import 'package:test/test.dart';
+
+Future<void> main({bool skipRustLibInit = false}) async {
+  if (!skipRustLibInit) await RustLib.init();
+
+  test('OffsetDateTime', () async {
+    final date = DateTime.utc(2022, 09, 10, 20, 48, 53, 123);
+    expect(await offsetDateTimeTwinRustAsync(d: date), date);
+  });
+
+  test('OffsetDateTime from local time', () async {
+    final date = DateTime.utc(2022, 09, 10, 20, 48, 53, 123).toLocal();
+    final resp = await offsetDateTimeTwinRustAsync(d: date);
+    expect(resp.isUtc, true);
+    expect(resp, date.toUtc());
+  });
+
+  test('PrimitiveDateTime', () async {
+    final date = DateTime.utc(2022, 09, 10, 20, 48, 53, 123);
+    expect(await primitiveDateTimeTwinRustAsync(d: date), date);
+  });
+
+  test('Date', () async {
+    final date = DateTime.utc(1969, 07, 20);
+    expect(await dateTwinRustAsync(d: date), date);
+  });
+
+  test('Duration', () async {
+    const duration = Duration(hours: 4, milliseconds: 123);
+    expect(await timeDurationTwinRustAsync(d: duration), duration);
+  });
+
+  test('negative Duration', () async {
+    const duration = Duration(days: -3, minutes: -1, milliseconds: -500);
+    expect(await timeDurationTwinRustAsync(d: duration), duration);
+    expect(await negateTimeDurationTwinRustAsync(d: duration), -duration);
+    expect(await negateTimeDurationTwinRustAsync(d: -duration), duration);
+  });
+
+  test('List<Duration>', () async {
+    const durations = [
+      Duration(days: -1),
+      Duration.zero,
+      Duration(milliseconds: 333),
+      Duration(hours: 2),
+    ];
+    expect(await timeDurationsTwinRustAsync(durations: durations), durations);
+  });
+
+  test('Option', () async {
+    const duration = Duration(seconds: -30);
+    expect(await optionalTimeDurationTwinRustAsync(d: duration), duration);
+    expect(await optionalTimeDurationTwinRustAsync(d: null), null);
+    final date = DateTime.utc(1800, 01, 23, 12, 56, 25);
+    expect(await optionalOffsetDateTimeTwinRustAsync(d: date), date);
+    expect(await optionalOffsetDateTimeTwinRustAsync(d: null), null);
+  });
+
+  test('struct', () async {
+    final value = TimeTypesTwinRustAsync(
+      offset: DateTime.utc(2022, 09, 10, 20, 48, 53),
+      primitive: DateTime.utc(2002, 02, 23, 12, 13, 55),
+      date: DateTime.utc(1969, 07, 20),
+      duration: const Duration(hours: -4),
+    );
+    expect(await timeTypesTwinRustAsync(value: value), value);
+  });
+}
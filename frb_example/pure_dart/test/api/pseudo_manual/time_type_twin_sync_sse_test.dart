@@ -0,0 +1,74 @@
+// NOTE: This file is mimicking how a human developer writes tests,
+// and is auto-generated from `time_type_test.dart` by frb_internal
+// Please do not modify manually, but modify the origin and re-run frb_internal generator
+
+import 'package:frb_example_pure_dart/src/rust/api/pseudo_manual/time_type_twin_sync_sse.dart';
+import 'package:frb_example_pure_dart/src/rust/frb_generated.dart';
+import 'package:test/test.dart';
+
+Future<void> main({bool skipRustLibInit = false}) async {
+  if (!skipRustLibInit) await RustLib.init();
+
+  test('OffsetDateTime', () async {
+    final date = DateTime.utc(2022, 09, 10, 20, 48, 53, 123);
+    expect(await offsetDateTimeTwinSyncSse(d: date), date);
+  });
+
+  test('OffsetDateTime from local time', () async {
+    final date = DateTime.utc(2022, 09, 10, 20, 48, 53, 123).toLocal();
+    final resp = await offsetDateTimeTwinSyncSse(d: date);
+    expect(resp.isUtc, true);
+    expect(resp, date.toUtc());
+  });
+
+  test('PrimitiveDateTime', () async {
+    final date = DateTime.utc(2022, 09, 10, 20, 48, 53, 123);
+    expect(await primitiveDateTimeTwinSyncSse(d: date), date);
+  });
+
+  test('Date', () async {
+    final date = DateTime.utc(1969, 07, 20);
+    expect(await dateTwinSyncSse(d: date), date);
+  });
+
+  test('Duration', () async {
+    const duration = Duration(hours: 4, milliseconds: 123);
+    expect(await timeDurationTwinSyncSse(d: duration), duration);
+  });
+
+  test('negative Duration', () async {
+    const duration = Duration(days: -3, minutes: -1, milliseconds: -500);
+    expect(await timeDurationTwinSyncSse(d: duration), duration);
+    expect(await negateTimeDurationTwinSyncSse(d: duration), -duration);
+    expect(await negateTimeDurationTwinSyncSse(d: -duration), duration);
+  });
+
+  test('List<Duration>', () async {
+    const durations = [
+      Duration(days: -1),
+      Duration.zero,
+      Duration(milliseconds: 333),
+      Duration(hours: 2),
+    ];
+    expect(await timeDurationsTwinSyncSse(durations: durations), durations);
+  });
+
+  test('Option', () async {
+    const duration = Duration(seconds: -30);
+    expect(await optionalTimeDurationTwinSyncSse(d: duration), duration);
+    expect(await optionalTimeDurationTwinSyncSse(d: null), null);
+    final date = DateTime.utc(1800, 01, 23, 12, 56, 25);
+    expect(await optionalOffsetDateTimeTwinSyncSse(d: date), date);
+    expect(await optionalOffsetDateTimeTwinSyncSse(d: null), null);
+  });
+
+  test('struct', () async {
+    final value = TimeTypesTwinSyncSse(
+      offset: DateTime.utc(2022, 09, 10, 20, 48, 53),
+      primitive: DateTime.utc(2002, 02, 23, 12, 13, 55),
+      date: DateTime.utc(1969, 07, 20),
+      duration: const Duration(hours: -4),
+    );
+    expect(await timeTypesTwinSyncSse(value: value), value);
+  });
+}
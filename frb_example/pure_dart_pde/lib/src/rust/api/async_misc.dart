@@ -12,3 +12,9 @@ Future<void> funcAsyncVoidTwinNormal() =>
 Future<int> funcAsyncSimpleAddTwinNormal({required int a, required int b}) =>
     RustLib.instance.api
         .crateApiAsyncMiscFuncAsyncSimpleAddTwinNormal(a: a, b: b);
+
+Future<void> funcAsyncNeverCompleteTwinNormal() => RustLib.instance.api
+    .crateApiAsyncMiscFuncAsyncNeverCompleteTwinNormal();
+
+Future<void> funcAsyncWaitForCancellationTwinNormal() => RustLib.instance.api
+    .crateApiAsyncMiscFuncAsyncWaitForCancellationTwinNormal();
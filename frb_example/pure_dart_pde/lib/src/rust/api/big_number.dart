@@ -0,0 +1,67 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.3.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:decimal/decimal.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+Future<Decimal> decimalTwinNormal({required Decimal d}) =>
+    RustLib.instance.api.crateApiBigNumberDecimalTwinNormal(d: d);
+
+Future<Decimal> addDecimalsTwinNormal(
+        {required Decimal a, required Decimal b}) =>
+    RustLib.instance.api.crateApiBigNumberAddDecimalsTwinNormal(
+        a: a, b: b);
+
+Future<Decimal?> optionalDecimalTwinNormal({Decimal? d}) =>
+    RustLib.instance.api
+        .crateApiBigNumberOptionalDecimalTwinNormal(d: d);
+
+Future<List<Decimal>> decimalsTwinNormal({required List<Decimal> decimals}) =>
+    RustLib.instance.api
+        .crateApiBigNumberDecimalsTwinNormal(decimals: decimals);
+
+Future<BigInt> bigIntTwinNormal({required BigInt i}) =>
+    RustLib.instance.api.crateApiBigNumberBigIntTwinNormal(i: i);
+
+Future<BigInt> multiplyBigIntsTwinNormal(
+        {required BigInt a, required BigInt b}) =>
+    RustLib.instance.api.crateApiBigNumberMultiplyBigIntsTwinNormal(
+        a: a, b: b);
+
+Future<BigInt?> optionalBigIntTwinNormal({BigInt? i}) =>
+    RustLib.instance.api
+        .crateApiBigNumberOptionalBigIntTwinNormal(i: i);
+
+Future<List<BigInt>> bigIntsTwinNormal({required List<BigInt> ints}) =>
+    RustLib.instance.api.crateApiBigNumberBigIntsTwinNormal(ints: ints);
+
+Future<BigNumbersTwinNormal> bigNumbersTwinNormal(
+        {required BigNumbersTwinNormal value}) =>
+    RustLib.instance.api.crateApiBigNumberBigNumbersTwinNormal(value: value);
+
+class BigNumbersTwinNormal {
+  final Decimal amount;
+  final Decimal? discount;
+  final BigInt serial;
+
+  const BigNumbersTwinNormal({
+    required this.amount,
+    this.discount,
+    required this.serial,
+  });
+
+  @override
+  int get hashCode => amount.hashCode ^ discount.hashCode ^ serial.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BigNumbersTwinNormal &&
+          runtimeType == other.runtimeType &&
+          amount == other.amount &&
+          discount == other.discount &&
+          serial == other.serial;
+}
@@ -45,6 +45,34 @@ Future<Duration> howLongDoesItTakeTwinNormal(
     RustLib.instance.api
         .crateApiChronoTypeHowLongDoesItTakeTwinNormal(mine: mine);
 
+Future<DateTime> naiveDateTwinNormal({required DateTime d}) =>
+    RustLib.instance.api.crateApiChronoTypeNaiveDateTwinNormal(d: d);
+
+Future<Duration> naiveTimeTwinNormal({required Duration d}) =>
+    RustLib.instance.api.crateApiChronoTypeNaiveTimeTwinNormal(d: d);
+
+Future<DateTimeWithOffset> datetimeFixedOffsetTwinNormal(
+        {required DateTimeWithOffset d}) =>
+    RustLib.instance.api.crateApiChronoTypeDatetimeFixedOffsetTwinNormal(d: d);
+
+Future<DateTimeWithOffset?> optionalDatetimeFixedOffsetTwinNormal(
+        {DateTimeWithOffset? d}) =>
+    RustLib.instance.api
+        .crateApiChronoTypeOptionalDatetimeFixedOffsetTwinNormal(d: d);
+
+Future<List<DateTime>> naiveDatesTwinNormal({required List<DateTime> dates}) =>
+    RustLib.instance.api.crateApiChronoTypeNaiveDatesTwinNormal(dates: dates);
+
+Future<List<DateTimeWithOffset>> datetimeFixedOffsetsTwinNormal(
+        {required List<DateTimeWithOffset> dates}) =>
+    RustLib.instance.api
+        .crateApiChronoTypeDatetimeFixedOffsetsTwinNormal(dates: dates);
+
+Future<ScheduleTwinNormal> scheduleTwinNormal(
+        {required ScheduleTwinNormal schedule}) =>
+    RustLib.instance.api
+        .crateApiChronoTypeScheduleTwinNormal(schedule: schedule);
+
 class FeatureChronoTwinNormal {
   final DateTime utc;
   final DateTime local;
@@ -73,6 +101,34 @@ class FeatureChronoTwinNormal {
           naive == other.naive;
 }
 
+class ScheduleTwinNormal {
+  final DateTime day;
+  final Duration starts;
+  final Duration? ends;
+  final DateTimeWithOffset at;
+
+  const ScheduleTwinNormal({
+    required this.day,
+    required this.starts,
+    this.ends,
+    required this.at,
+  });
+
+  @override
+  int get hashCode =>
+      day.hashCode ^ starts.hashCode ^ ends.hashCode ^ at.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ScheduleTwinNormal &&
+          runtimeType == other.runtimeType &&
+          day == other.day &&
+          starts == other.starts &&
+          ends == other.ends &&
+          at == other.at;
+}
+
 class TestChronoTwinNormal {
   final DateTime? dt;
   final DateTime? dt2;
@@ -58,6 +58,15 @@ Future<void> rustCallDartReturnResultTwinNormal(
     RustLib.instance.api.crateApiDartFnRustCallDartReturnResultTwinNormal(
         callback: callback, expectOutput: expectOutput);
 
+Future<int> rustConsumeDartStreamSumTwinNormal({required Stream<int> stream}) =>
+    RustLib.instance.api
+        .crateApiDartFnRustConsumeDartStreamSumTwinNormal(stream: stream);
+
+Future<List<String>> rustConsumeDartStreamTakeTwinNormal(
+        {required Stream<String> stream, required BigInt count}) =>
+    RustLib.instance.api.crateApiDartFnRustConsumeDartStreamTakeTwinNormal(
+        stream: stream, count: count);
+
 class DemoStructForRustCallDartTwinNormal {
   final String name;
 
@@ -0,0 +1,64 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.3.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+Future<Object?> jsonValueTwinNormal({required Object? value}) =>
+    RustLib.instance.api.crateApiJsonTypeJsonValueTwinNormal(value: value);
+
+Future<Object?> optionalJsonValueTwinNormal({Object? value}) =>
+    RustLib.instance.api
+        .crateApiJsonTypeOptionalJsonValueTwinNormal(value: value);
+
+Future<List<Object?>> jsonValuesTwinNormal({required List<Object?> values}) =>
+    RustLib.instance.api.crateApiJsonTypeJsonValuesTwinNormal(values: values);
+
+Future<Map<String, dynamic>> jsonMapTwinNormal(
+        {required Map<String, dynamic> map}) =>
+    RustLib.instance.api.crateApiJsonTypeJsonMapTwinNormal(map: map);
+
+Future<String> jsonValueToStringTwinNormal({required Object? value}) =>
+    RustLib.instance.api
+        .crateApiJsonTypeJsonValueToStringTwinNormal(value: value);
+
+Future<List<String>> jsonMapKeysTwinNormal(
+        {required Map<String, dynamic> map}) =>
+    RustLib.instance.api.crateApiJsonTypeJsonMapKeysTwinNormal(map: map);
+
+Future<Object?> createJsonValueTwinNormal() =>
+    RustLib.instance.api.crateApiJsonTypeCreateJsonValueTwinNormal();
+
+Future<Map<String, dynamic>> createJsonMapTwinNormal() =>
+    RustLib.instance.api.crateApiJsonTypeCreateJsonMapTwinNormal();
+
+Future<JsonDocumentTwinNormal> jsonDocumentTwinNormal(
+        {required JsonDocumentTwinNormal document}) =>
+    RustLib.instance.api
+        .crateApiJsonTypeJsonDocumentTwinNormal(document: document);
+
+class JsonDocumentTwinNormal {
+  final Object? body;
+  final Map<String, dynamic> meta;
+  final Object? extra;
+
+  const JsonDocumentTwinNormal({
+    required this.body,
+    required this.meta,
+    this.extra,
+  });
+
+  @override
+  int get hashCode => body.hashCode ^ meta.hashCode ^ extra.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is JsonDocumentTwinNormal &&
+          runtimeType == other.runtimeType &&
+          body == other.body &&
+          meta == other.meta &&
+          extra == other.extra;
+}
@@ -44,3 +44,28 @@ Future<Map<String, KitchenSinkTwinNormal>>
             {required Map<String, KitchenSinkTwinNormal> arg}) =>
         RustLib.instance.api
             .crateApiMapAndSetFuncHashMapStringComplexEnumTwinNormal(arg: arg);
+
+Future<Map<int, int>> funcBtreeMapI32I32TwinNormal(
+        {required Map<int, int> arg}) =>
+    RustLib.instance.api
+        .crateApiMapAndSetFuncBtreeMapI32I32TwinNormal(arg: arg);
+
+Future<Set<int>> funcBtreeSetI32TwinNormal({required Set<int> arg}) =>
+    RustLib.instance.api.crateApiMapAndSetFuncBtreeSetI32TwinNormal(arg: arg);
+
+Future<Map<String, MySize>> funcBtreeMapStringStructTwinNormal(
+        {required Map<String, MySize> arg}) =>
+    RustLib.instance.api
+        .crateApiMapAndSetFuncBtreeMapStringStructTwinNormal(arg: arg);
+
+Future<Set<String>> funcBtreeSetStringTwinNormal({required Set<String> arg}) =>
+    RustLib.instance.api
+        .crateApiMapAndSetFuncBtreeSetStringTwinNormal(arg: arg);
+
+Future<Int32List> funcVecDequeI32TwinNormal({required Int32List arg}) =>
+    RustLib.instance.api.crateApiMapAndSetFuncVecDequeI32TwinNormal(arg: arg);
+
+Future<List<String>> funcVecDequeStringTwinNormal(
+        {required List<String> arg}) =>
+    RustLib.instance.api
+        .crateApiMapAndSetFuncVecDequeStringTwinNormal(arg: arg);
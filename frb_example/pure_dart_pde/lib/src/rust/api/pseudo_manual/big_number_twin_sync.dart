@@ -0,0 +1,65 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.3.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import 'package:decimal/decimal.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+Decimal decimalTwinSync({required Decimal d}) => RustLib.instance.api
+    .crateApiPseudoManualBigNumberTwinSyncDecimalTwinSync(d: d);
+
+Decimal addDecimalsTwinSync({required Decimal a, required Decimal b}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualBigNumberTwinSyncAddDecimalsTwinSync(a: a, b: b);
+
+Decimal? optionalDecimalTwinSync({Decimal? d}) => RustLib.instance.api
+    .crateApiPseudoManualBigNumberTwinSyncOptionalDecimalTwinSync(d: d);
+
+List<Decimal> decimalsTwinSync({required List<Decimal> decimals}) => RustLib
+    .instance.api
+    .crateApiPseudoManualBigNumberTwinSyncDecimalsTwinSync(decimals: decimals);
+
+BigInt bigIntTwinSync({required BigInt i}) => RustLib.instance.api
+    .crateApiPseudoManualBigNumberTwinSyncBigIntTwinSync(i: i);
+
+BigInt multiplyBigIntsTwinSync({required BigInt a, required BigInt b}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualBigNumberTwinSyncMultiplyBigIntsTwinSync(
+            a: a, b: b);
+
+BigInt? optionalBigIntTwinSync({BigInt? i}) => RustLib.instance.api
+    .crateApiPseudoManualBigNumberTwinSyncOptionalBigIntTwinSync(i: i);
+
+List<BigInt> bigIntsTwinSync({required List<BigInt> ints}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualBigNumberTwinSyncBigIntsTwinSync(ints: ints);
+
+BigNumbersTwinSync bigNumbersTwinSync({required BigNumbersTwinSync value}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualBigNumberTwinSyncBigNumbersTwinSync(value: value);
+
+class BigNumbersTwinSync {
+  final Decimal amount;
+  final Decimal? discount;
+  final BigInt serial;
+
+  const BigNumbersTwinSync({
+    required this.amount,
+    this.discount,
+    required this.serial,
+  });
+
+  @override
+  int get hashCode => amount.hashCode ^ discount.hashCode ^ serial.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BigNumbersTwinSync &&
+          runtimeType == other.runtimeType &&
+          amount == other.amount &&
+          discount == other.discount &&
+          serial == other.serial;
+}
@@ -66,6 +66,18 @@ Future<void> rustCallDartReturnResultTwinRustAsync(
         .crateApiPseudoManualDartFnTwinRustAsyncRustCallDartReturnResultTwinRustAsync(
             callback: callback, expectOutput: expectOutput);
 
+Future<int> rustConsumeDartStreamSumTwinRustAsync(
+        {required Stream<int> stream}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualDartFnTwinRustAsyncRustConsumeDartStreamSumTwinRustAsync(
+            stream: stream);
+
+Future<List<String>> rustConsumeDartStreamTakeTwinRustAsync(
+        {required Stream<String> stream, required BigInt count}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualDartFnTwinRustAsyncRustConsumeDartStreamTakeTwinRustAsync(
+            stream: stream, count: count);
+
 class DemoStructForRustCallDartTwinRustAsync {
   final String name;
 
@@ -0,0 +1,67 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.3.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+Object? jsonValueTwinSync({required Object? value}) => RustLib.instance.api
+    .crateApiPseudoManualJsonTypeTwinSyncJsonValueTwinSync(value: value);
+
+Object? optionalJsonValueTwinSync({Object? value}) => RustLib.instance.api
+    .crateApiPseudoManualJsonTypeTwinSyncOptionalJsonValueTwinSync(
+        value: value);
+
+List<Object?> jsonValuesTwinSync({required List<Object?> values}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualJsonTypeTwinSyncJsonValuesTwinSync(values: values);
+
+Map<String, dynamic> jsonMapTwinSync({required Map<String, dynamic> map}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualJsonTypeTwinSyncJsonMapTwinSync(map: map);
+
+String jsonValueToStringTwinSync({required Object? value}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualJsonTypeTwinSyncJsonValueToStringTwinSync(
+            value: value);
+
+List<String> jsonMapKeysTwinSync({required Map<String, dynamic> map}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualJsonTypeTwinSyncJsonMapKeysTwinSync(map: map);
+
+Object? createJsonValueTwinSync() => RustLib.instance.api
+    .crateApiPseudoManualJsonTypeTwinSyncCreateJsonValueTwinSync();
+
+Map<String, dynamic> createJsonMapTwinSync() => RustLib.instance.api
+    .crateApiPseudoManualJsonTypeTwinSyncCreateJsonMapTwinSync();
+
+JsonDocumentTwinSync jsonDocumentTwinSync(
+        {required JsonDocumentTwinSync document}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualJsonTypeTwinSyncJsonDocumentTwinSync(
+            document: document);
+
+class JsonDocumentTwinSync {
+  final Object? body;
+  final Map<String, dynamic> meta;
+  final Object? extra;
+
+  const JsonDocumentTwinSync({
+    required this.body,
+    required this.meta,
+    this.extra,
+  });
+
+  @override
+  int get hashCode => body.hashCode ^ meta.hashCode ^ extra.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is JsonDocumentTwinSync &&
+          runtimeType == other.runtimeType &&
+          body == other.body &&
+          meta == other.meta &&
+          extra == other.extra;
+}
@@ -49,3 +49,30 @@ Map<String, KitchenSinkTwinSync> funcHashMapStringComplexEnumTwinSync(
     RustLib.instance.api
         .crateApiPseudoManualMapAndSetTwinSyncFuncHashMapStringComplexEnumTwinSync(
             arg: arg);
+
+Map<int, int> funcBtreeMapI32I32TwinSync({required Map<int, int> arg}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualMapAndSetTwinSyncFuncBtreeMapI32I32TwinSync(
+            arg: arg);
+
+Set<int> funcBtreeSetI32TwinSync({required Set<int> arg}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualMapAndSetTwinSyncFuncBtreeSetI32TwinSync(arg: arg);
+
+Map<String, MySize> funcBtreeMapStringStructTwinSync(
+        {required Map<String, MySize> arg}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualMapAndSetTwinSyncFuncBtreeMapStringStructTwinSync(
+            arg: arg);
+
+Set<String> funcBtreeSetStringTwinSync({required Set<String> arg}) => RustLib
+    .instance.api
+    .crateApiPseudoManualMapAndSetTwinSyncFuncBtreeSetStringTwinSync(arg: arg);
+
+Int32List funcVecDequeI32TwinSync({required Int32List arg}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualMapAndSetTwinSyncFuncVecDequeI32TwinSync(arg: arg);
+
+List<String> funcVecDequeStringTwinSync({required List<String> arg}) => RustLib
+    .instance.api
+    .crateApiPseudoManualMapAndSetTwinSyncFuncVecDequeStringTwinSync(arg: arg);
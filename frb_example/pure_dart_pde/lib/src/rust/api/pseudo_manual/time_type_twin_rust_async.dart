@@ -0,0 +1,79 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.3.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+Future<DateTime> offsetDateTimeTwinRustAsync({required DateTime d}) => RustLib
+    .instance.api
+    .crateApiPseudoManualTimeTypeTwinRustAsyncOffsetDateTimeTwinRustAsync(d: d);
+
+Future<DateTime> primitiveDateTimeTwinRustAsync({required DateTime d}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualTimeTypeTwinRustAsyncPrimitiveDateTimeTwinRustAsync(
+            d: d);
+
+Future<DateTime> dateTwinRustAsync({required DateTime d}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualTimeTypeTwinRustAsyncDateTwinRustAsync(d: d);
+
+Future<Duration> timeDurationTwinRustAsync({required Duration d}) => RustLib
+    .instance.api
+    .crateApiPseudoManualTimeTypeTwinRustAsyncTimeDurationTwinRustAsync(d: d);
+
+Future<Duration> negateTimeDurationTwinRustAsync({required Duration d}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualTimeTypeTwinRustAsyncNegateTimeDurationTwinRustAsync(
+            d: d);
+
+Future<List<Duration>> timeDurationsTwinRustAsync(
+        {required List<Duration> durations}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualTimeTypeTwinRustAsyncTimeDurationsTwinRustAsync(
+            durations: durations);
+
+Future<Duration?> optionalTimeDurationTwinRustAsync({Duration? d}) => RustLib
+    .instance.api
+    .crateApiPseudoManualTimeTypeTwinRustAsyncOptionalTimeDurationTwinRustAsync(
+        d: d);
+
+Future<DateTime?> optionalOffsetDateTimeTwinRustAsync({DateTime? d}) => RustLib
+    .instance.api
+    .crateApiPseudoManualTimeTypeTwinRustAsyncOptionalOffsetDateTimeTwinRustAsync(
+        d: d);
+
+Future<TimeTypesTwinRustAsync> timeTypesTwinRustAsync(
+        {required TimeTypesTwinRustAsync value}) =>
+    RustLib.instance.api
+        .crateApiPseudoManualTimeTypeTwinRustAsyncTimeTypesTwinRustAsync(
+            value: value);
+
+class TimeTypesTwinRustAsync {
+  final DateTime offset;
+  final DateTime? primitive;
+  final DateTime date;
+  final Duration duration;
+
+  const TimeTypesTwinRustAsync({
+    required this.offset,
+    this.primitive,
+    required this.date,
+    required this.duration,
+  });
+
+  @override
+  int get hashCode =>
+      offset.hashCode ^ primitive.hashCode ^ date.hashCode ^ duration.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TimeTypesTwinRustAsync &&
+          runtimeType == other.runtimeType &&
+          offset == other.offset &&
+          primitive == other.primitive &&
+          date == other.date &&
+          duration == other.duration;
+}
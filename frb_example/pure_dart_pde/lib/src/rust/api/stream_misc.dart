@@ -12,3 +12,12 @@ Stream<String> funcStreamRealisticTwinNormal({required String arg}) =>
 
 Future<Stream<int>> streamSinkDartAsyncTwinNormal() =>
     RustLib.instance.api.crateApiStreamMiscStreamSinkDartAsyncTwinNormal();
+
+Stream<int> streamSinkBoundedAddAsyncTwinNormal() => RustLib.instance.api
+    .crateApiStreamMiscStreamSinkBoundedAddAsyncTwinNormal();
+
+Stream<int> streamSinkBoundedKeepLatestTwinNormal() => RustLib.instance.api
+    .crateApiStreamMiscStreamSinkBoundedKeepLatestTwinNormal();
+
+Stream<int> streamSinkBoundedAddWithErrorTwinNormal() => RustLib.instance.api
+    .crateApiStreamMiscStreamSinkBoundedAddWithErrorTwinNormal();
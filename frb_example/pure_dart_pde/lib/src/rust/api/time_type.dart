@@ -0,0 +1,67 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.3.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+Future<DateTime> offsetDateTimeTwinNormal({required DateTime d}) =>
+    RustLib.instance.api.crateApiTimeTypeOffsetDateTimeTwinNormal(d: d);
+
+Future<DateTime> primitiveDateTimeTwinNormal({required DateTime d}) =>
+    RustLib.instance.api.crateApiTimeTypePrimitiveDateTimeTwinNormal(d: d);
+
+Future<DateTime> dateTwinNormal({required DateTime d}) =>
+    RustLib.instance.api.crateApiTimeTypeDateTwinNormal(d: d);
+
+Future<Duration> timeDurationTwinNormal({required Duration d}) =>
+    RustLib.instance.api.crateApiTimeTypeTimeDurationTwinNormal(d: d);
+
+Future<Duration> negateTimeDurationTwinNormal({required Duration d}) =>
+    RustLib.instance.api.crateApiTimeTypeNegateTimeDurationTwinNormal(d: d);
+
+Future<List<Duration>> timeDurationsTwinNormal(
+        {required List<Duration> durations}) =>
+    RustLib.instance.api
+        .crateApiTimeTypeTimeDurationsTwinNormal(durations: durations);
+
+Future<Duration?> optionalTimeDurationTwinNormal({Duration? d}) =>
+    RustLib.instance.api
+        .crateApiTimeTypeOptionalTimeDurationTwinNormal(d: d);
+
+Future<DateTime?> optionalOffsetDateTimeTwinNormal({DateTime? d}) =>
+    RustLib.instance.api
+        .crateApiTimeTypeOptionalOffsetDateTimeTwinNormal(d: d);
+
+Future<TimeTypesTwinNormal> timeTypesTwinNormal(
+        {required TimeTypesTwinNormal value}) =>
+    RustLib.instance.api.crateApiTimeTypeTimeTypesTwinNormal(value: value);
+
+class TimeTypesTwinNormal {
+  final DateTime offset;
+  final DateTime? primitive;
+  final DateTime date;
+  final Duration duration;
+
+  const TimeTypesTwinNormal({
+    required this.offset,
+    this.primitive,
+    required this.date,
+    required this.duration,
+  });
+
+  @override
+  int get hashCode =>
+      offset.hashCode ^ primitive.hashCode ^ date.hashCode ^ duration.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TimeTypesTwinNormal &&
+          runtimeType == other.runtimeType &&
+          offset == other.offset &&
+          primitive == other.primitive &&
+          date == other.date &&
+          duration == other.duration;
+}
@@ -12,8 +12,10 @@ import 'api/async_misc.dart';
 import 'api/async_spawn.dart';
 import 'api/attribute.dart';
 import 'api/benchmark_misc.dart';
+import 'api/big_number.dart';
 import 'api/casted_primitive.dart';
 import 'api/chrono_type.dart';
+import 'api/chrono_tz_type.dart';
 import 'api/comment.dart';
 import 'api/constructor.dart';
 import 'api/custom_ser_des.dart';
@@ -32,6 +34,7 @@ import 'api/external_impl.dart';
 import 'api/external_type_in_crate.dart';
 import 'api/impl_trait.dart';
 import 'api/inside_macro.dart';
+import 'api/json_type.dart';
 import 'api/lifetimeable.dart';
 import 'api/map_and_set.dart';
 import 'api/method.dart';
@@ -66,6 +69,8 @@ import 'api/pseudo_manual/basic_twin_sync.dart';
 import 'api/pseudo_manual/benchmark_api.dart';
 import 'api/pseudo_manual/benchmark_api_twin_rust_async.dart';
 import 'api/pseudo_manual/benchmark_api_twin_sync.dart';
+import 'api/pseudo_manual/big_number_twin_rust_async.dart';
+import 'api/pseudo_manual/big_number_twin_sync.dart';
 import 'api/pseudo_manual/chrono_type_twin_rust_async.dart';
 import 'api/pseudo_manual/chrono_type_twin_sync.dart';
 import 'api/pseudo_manual/comment_twin_rust_async.dart';
@@ -83,6 +88,8 @@ import 'api/pseudo_manual/exception_twin_sync.dart';
 import 'api/pseudo_manual/external_type_in_crate_twin_rust_async.dart';
 import 'api/pseudo_manual/external_type_in_crate_twin_sync.dart';
 import 'api/pseudo_manual/impl_trait_twin_sync.dart';
+import 'api/pseudo_manual/json_type_twin_rust_async.dart';
+import 'api/pseudo_manual/json_type_twin_sync.dart';
 import 'api/pseudo_manual/lifetimeable_twin_sync.dart';
 import 'api/pseudo_manual/map_and_set_twin_rust_async.dart';
 import 'api/pseudo_manual/map_and_set_twin_sync.dart';
@@ -114,9 +121,13 @@ import 'api/pseudo_manual/rust_opaque_twin_rust_async.dart';
 import 'api/pseudo_manual/rust_opaque_twin_sync.dart';
 import 'api/pseudo_manual/simple_twin_rust_async.dart';
 import 'api/pseudo_manual/simple_twin_sync.dart';
+import 'api/pseudo_manual/std_time_twin_rust_async.dart';
+import 'api/pseudo_manual/std_time_twin_sync.dart';
 import 'api/pseudo_manual/stream_twin_rust_async.dart';
 import 'api/pseudo_manual/structure_twin_rust_async.dart';
 import 'api/pseudo_manual/structure_twin_sync.dart';
+import 'api/pseudo_manual/time_type_twin_rust_async.dart';
+import 'api/pseudo_manual/time_type_twin_sync.dart';
 import 'api/pseudo_manual/tuple_twin_rust_async.dart';
 import 'api/pseudo_manual/tuple_twin_sync.dart';
 import 'api/pseudo_manual/type_alias_twin_rust_async.dart';
@@ -128,9 +139,11 @@ import 'api/rust_auto_opaque.dart';
 import 'api/rust_opaque.dart';
 import 'api/rust_opaque_sync.dart';
 import 'api/simple.dart';
+import 'api/std_time.dart';
 import 'api/stream.dart';
 import 'api/stream_misc.dart';
 import 'api/structure.dart';
+import 'api/time_type.dart';
 import 'api/tuple.dart';
 import 'api/type_alias.dart';
 import 'api/uuid_type.dart';
@@ -141,6 +154,7 @@ import 'dart:async';
 import 'dart:convert';
 import 'deliberate_name_conflict.dart';
 import 'frb_generated.dart';
+import 'package:decimal/decimal.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_web.dart';
 import 'package:meta/meta.dart' as meta;
 import 'package:uuid/uuid.dart';
@@ -261,6 +275,10 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       get rust_arc_decrement_strong_count_ConstructorOpaqueSyncStructTwinNormalPtr =>
           wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConstructorOpaqueSyncStructTwinNormal;
 
+  CrossPlatformFinalizerArg
+      get rust_arc_decrement_strong_count_DartImplementableTraitTwinNormalDartImplPtr =>
+          wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl;
+
   CrossPlatformFinalizerArg
       get rust_arc_decrement_strong_count_DeliberateFailSanityCheckTwinNormalPtr =>
           wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeliberateFailSanityCheckTwinNormal;
@@ -461,6 +479,10 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       get rust_arc_decrement_strong_count_StructTwoWithTraitTwinSyncPtr => wire
           .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitTwinSync;
 
+  CrossPlatformFinalizerArg
+      get rust_arc_decrement_strong_count_StructWithDartImplementableTraitTwinNormalPtr =>
+          wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal;
+
   CrossPlatformFinalizerArg
       get rust_arc_decrement_strong_count_StructWithGoodAndOpaqueFieldWithoutOptionTwinNormalPtr =>
           wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinNormal;
@@ -487,6 +509,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   AnyhowException dco_decode_AnyhowException(dynamic raw);
 
+  @protected
+  DartImplementableTraitTwinNormalDartImpl
+      dco_decode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl(
+          dynamic raw);
+
   @protected
   LtNestedTypeWithLifetimeTwinNormal
       dco_decode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLifetimeableLtNestedTypeWithLifetimeTwinNormalstatic(
@@ -552,6 +579,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       dco_decode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitForDynTwinNormal(
           dynamic raw);
 
+  @protected
+  StructWithDartImplementableTraitTwinNormal
+      dco_decode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+          dynamic raw);
+
   @protected
   BoxAnyMyDartTypeRename
       dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynAnySendSyncstatic(
@@ -587,6 +619,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConstructorOpaqueSyncStructTwinNormal(
           dynamic raw);
 
+  @protected
+  DartImplementableTraitTwinNormalDartImpl
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl(
+          dynamic raw);
+
   @protected
   DeliberateFailSanityCheckTwinNormal
       dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeliberateFailSanityCheckTwinNormal(
@@ -807,6 +844,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitTwinSync(
           dynamic raw);
 
+  @protected
+  StructWithDartImplementableTraitTwinNormal
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+          dynamic raw);
+
   @protected
   StructWithGoodAndOpaqueFieldWithoutOptionTwinNormal
       dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinNormal(
@@ -922,6 +964,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitTwinSync(
           dynamic raw);
 
+  @protected
+  StructWithDartImplementableTraitTwinNormal
+      dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+          dynamic raw);
+
   @protected
   StructWithGoodAndOpaqueFieldWithoutOptionTwinNormal
       dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinNormal(
@@ -952,6 +999,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConstructorOpaqueSyncStructTwinNormal(
           dynamic raw);
 
+  @protected
+  DartImplementableTraitTwinNormalDartImpl
+      dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl(
+          dynamic raw);
+
   @protected
   DeliberateFailSanityCheckTwinNormal
       dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeliberateFailSanityCheckTwinNormal(
@@ -1107,6 +1159,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitTwinSync(
           dynamic raw);
 
+  @protected
+  StructWithDartImplementableTraitTwinNormal
+      dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+          dynamic raw);
+
   @protected
   StructWithGoodAndOpaqueFieldWithoutOptionTwinNormal
       dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinNormal(
@@ -1132,9 +1189,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithSimpleSetterTwinNormal(
           dynamic raw);
 
+  @protected
+  Map<String, MySize> dco_decode_BTreeMap_String_my_size(dynamic raw);
+
+  @protected
+  Map<int, int> dco_decode_BTreeMap_i_32_i_32(dynamic raw);
+
+  @protected
+  Set<String> dco_decode_BTreeSet_String(dynamic raw);
+
+  @protected
+  Set<int> dco_decode_BTreeSet_i_32(dynamic raw);
+
   @protected
   String dco_decode_Backtrace(dynamic raw);
 
+  @protected
+  BigInt dco_decode_BigInt(dynamic raw);
+
   @protected
   int dco_decode_CastedPrimitive_i_64(dynamic raw);
 
@@ -1153,12 +1225,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   Duration dco_decode_Chrono_Duration(dynamic raw);
 
+  @protected
+  DateTimeWithOffset dco_decode_Chrono_FixedOffset(dynamic raw);
+
   @protected
   DateTime dco_decode_Chrono_Local(dynamic raw);
 
   @protected
   DateTime dco_decode_Chrono_Naive(dynamic raw);
 
+  @protected
+  DateTime dco_decode_Chrono_NaiveDate(dynamic raw);
+
+  @protected
+  Duration dco_decode_Chrono_NaiveTime(dynamic raw);
+
+  @protected
+  DateTimeWithOffset dco_decode_Chrono_Tz(dynamic raw);
+
   @protected
   DateTime dco_decode_Chrono_Utc(dynamic raw);
 
@@ -1202,6 +1286,16 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   FutureOr<void> Function()
       dco_decode_DartFn_Inputs__Output_unit_AnyhowException(dynamic raw);
 
+  @protected
+  FutureOr<String?> Function(bool)
+      dco_decode_DartFn_Inputs_bool_Output_opt_String_AnyhowException(
+          dynamic raw);
+
+  @protected
+  FutureOr<int?> Function(bool)
+      dco_decode_DartFn_Inputs_bool_Output_opt_box_autoadd_i_32_AnyhowException(
+          dynamic raw);
+
   @protected
   FutureOr<DemoStructForRustCallDartTwinNormal> Function(
           DemoStructForRustCallDartTwinNormal)
@@ -1214,12 +1308,31 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       dco_decode_DartFn_Inputs_demo_struct_for_rust_call_dart_twin_rust_async_Output_demo_struct_for_rust_call_dart_twin_rust_async_AnyhowException(
           dynamic raw);
 
+  @protected
+  FutureOr<String> Function(int)
+      dco_decode_DartFn_Inputs_i_32_Output_String_AnyhowException(
+          dynamic raw);
+
+  @protected
+  FutureOr<int> Function(int, int)
+      dco_decode_DartFn_Inputs_i_32_i_32_Output_i_32_AnyhowException(
+          dynamic raw);
+
   @protected
   Object dco_decode_DartOpaque(dynamic raw);
 
   @protected
   ObjectArray1 dco_decode_DartOpaque_array_1(dynamic raw);
 
+  @protected
+  Stream<String> dco_decode_DartStream_String(dynamic raw);
+
+  @protected
+  Stream<int> dco_decode_DartStream_i_32(dynamic raw);
+
+  @protected
+  Decimal dco_decode_Decimal(dynamic raw);
+
   @protected
   SimpleTraitForDynTwinNormal dco_decode_DynTrait_SimpleTraitForDynTwinNormal(
       dynamic raw);
@@ -1227,6 +1340,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   BigInt dco_decode_I128(dynamic raw);
 
+  @protected
+  Map<String, dynamic> dco_decode_JsonMap(dynamic raw);
+
+  @protected
+  Object? dco_decode_JsonValue(dynamic raw);
+
   @protected
   LtNestedTypeWithLifetimeTwinNormal
       dco_decode_Lifetimeable_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLtNestedTypeWithLifetimeTwinNormalstatic(
@@ -1553,6 +1672,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConstructorOpaqueSyncStructTwinNormal(
           dynamic raw);
 
+  @protected
+  DartImplementableTraitTwinNormalDartImpl
+      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl(
+          dynamic raw);
+
   @protected
   DeliberateFailSanityCheckTwinNormal
       dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeliberateFailSanityCheckTwinNormal(
@@ -1803,6 +1927,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitTwinSync(
           dynamic raw);
 
+  @protected
+  StructWithDartImplementableTraitTwinNormal
+      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+          dynamic raw);
+
   @protected
   StructWithGoodAndOpaqueFieldWithoutOptionTwinNormal
       dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinNormal(
@@ -1840,6 +1969,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   Set<int> dco_decode_Set_i_32(dynamic raw);
 
+  @protected
+  Duration dco_decode_StdDuration(dynamic raw);
+
+  @protected
+  DateTime dco_decode_StdSystemTime(dynamic raw);
+
   @protected
   RustStreamSink<NonCloneSimpleTwinNormal>
       dco_decode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinNormal_Sse(
@@ -1955,6 +2090,22 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   String dco_decode_String(dynamic raw);
 
+  @protected
+  DateTime dco_decode_TimeDate(dynamic raw);
+
+  @protected
+  Duration dco_decode_TimeDuration(dynamic raw);
+
+  @protected
+  DateTime dco_decode_TimeOffsetDateTime(dynamic raw);
+
+  @protected
+  DateTime dco_decode_TimePrimitiveDateTime(dynamic raw);
+
+  @protected
+  DartImplementableTraitTwinNormal
+      dco_decode_TraitDef_DartImplementableTraitTwinNormal(dynamic raw);
+
   @protected
   Issue2170Trait dco_decode_TraitDef_Issue2170Trait(dynamic raw);
 
@@ -1991,6 +2142,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   UuidValue dco_decode_Uuid(dynamic raw);
 
+  @protected
+  List<String> dco_decode_VecDeque_String(dynamic raw);
+
+  @protected
+  Int32List dco_decode_VecDeque_i_32(dynamic raw);
+
   @protected
   ATwinNormal dco_decode_a_twin_normal(dynamic raw);
 
@@ -2126,6 +2283,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   BigBuffersTwinSync dco_decode_big_buffers_twin_sync(dynamic raw);
 
+  @protected
+  BigNumbersTwinNormal dco_decode_big_numbers_twin_normal(dynamic raw);
+
+  @protected
+  BigNumbersTwinRustAsync dco_decode_big_numbers_twin_rust_async(dynamic raw);
+
+  @protected
+  BigNumbersTwinSync dco_decode_big_numbers_twin_sync(dynamic raw);
+
   @protected
   BlobTwinNormal dco_decode_blob_twin_normal(dynamic raw);
 
@@ -2138,6 +2304,10 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   bool dco_decode_bool(dynamic raw);
 
+  @protected
+  DartImplementableTraitTwinNormal
+      dco_decode_box_DynTrait_DartImplementableTraitTwinNormal(dynamic raw);
+
   @protected
   ApplicationEnv dco_decode_box_application_env(dynamic raw);
 
@@ -2162,6 +2332,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   DateTime dco_decode_box_autoadd_Chrono_Naive(dynamic raw);
 
+  @protected
+  Duration dco_decode_box_autoadd_Chrono_NaiveTime(dynamic raw);
+
   @protected
   DateTime dco_decode_box_autoadd_Chrono_Utc(dynamic raw);
 
@@ -2184,6 +2357,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   HideDataTwinSync dco_decode_box_autoadd_RustOpaque_HideDataTwinSync(
       dynamic raw);
 
+  @protected
+  Duration dco_decode_box_autoadd_StdDuration(dynamic raw);
+
+  @protected
+  DateTime dco_decode_box_autoadd_StdSystemTime(dynamic raw);
+
+  @protected
+  Duration dco_decode_box_autoadd_TimeDuration(dynamic raw);
+
+  @protected
+  DateTime dco_decode_box_autoadd_TimeOffsetDateTime(dynamic raw);
+
+  @protected
+  DateTime dco_decode_box_autoadd_TimePrimitiveDateTime(dynamic raw);
+
   @protected
   ATwinNormal dco_decode_box_autoadd_a_twin_normal(dynamic raw);
 
@@ -2295,6 +2483,17 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   BenchmarkBlobTwinSync dco_decode_box_autoadd_benchmark_blob_twin_sync(
       dynamic raw);
 
+  @protected
+  BigNumbersTwinNormal dco_decode_box_autoadd_big_numbers_twin_normal(
+      dynamic raw);
+
+  @protected
+  BigNumbersTwinRustAsync dco_decode_box_autoadd_big_numbers_twin_rust_async(
+      dynamic raw);
+
+  @protected
+  BigNumbersTwinSync dco_decode_box_autoadd_big_numbers_twin_sync(dynamic raw);
+
   @protected
   bool dco_decode_box_autoadd_bool(dynamic raw);
 
@@ -2392,6 +2591,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   CustomizedTwinSync dco_decode_box_autoadd_customized_twin_sync(dynamic raw);
 
+  @protected
+  DartImplementableTraitTwinNormalImplementor
+      dco_decode_box_autoadd_dart_implementable_trait_twin_normal_implementor(
+          dynamic raw);
+
   @protected
   DartOpaqueNestedTwinNormal
       dco_decode_box_autoadd_dart_opaque_nested_twin_normal(dynamic raw);
@@ -2573,6 +2777,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       dco_decode_box_autoadd_item_container_solution_two_twin_normal(
           dynamic raw);
 
+  @protected
+  JsonDocumentTwinNormal dco_decode_box_autoadd_json_document_twin_normal(
+      dynamic raw);
+
+  @protected
+  JsonDocumentTwinRustAsync
+      dco_decode_box_autoadd_json_document_twin_rust_async(dynamic raw);
+
+  @protected
+  JsonDocumentTwinSync dco_decode_box_autoadd_json_document_twin_sync(
+      dynamic raw);
+
   @protected
   KitchenSinkTwinNormal dco_decode_box_autoadd_kitchen_sink_twin_normal(
       dynamic raw);
@@ -2721,6 +2937,16 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   (String, int) dco_decode_box_autoadd_record_string_i_32(dynamic raw);
 
+  @protected
+  ScheduleTwinNormal dco_decode_box_autoadd_schedule_twin_normal(dynamic raw);
+
+  @protected
+  ScheduleTwinRustAsync dco_decode_box_autoadd_schedule_twin_rust_async(
+      dynamic raw);
+
+  @protected
+  ScheduleTwinSync dco_decode_box_autoadd_schedule_twin_sync(dynamic raw);
+
   @protected
   Sequences dco_decode_box_autoadd_sequences(dynamic raw);
 
@@ -2781,6 +3007,16 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   SomeStructTwinSync dco_decode_box_autoadd_some_struct_twin_sync(dynamic raw);
 
+  @protected
+  StdTimeTwinNormal dco_decode_box_autoadd_std_time_twin_normal(dynamic raw);
+
+  @protected
+  StdTimeTwinRustAsync dco_decode_box_autoadd_std_time_twin_rust_async(
+      dynamic raw);
+
+  @protected
+  StdTimeTwinSync dco_decode_box_autoadd_std_time_twin_sync(dynamic raw);
+
   @protected
   StructInLowerLevel dco_decode_box_autoadd_struct_in_lower_level(dynamic raw);
 
@@ -2938,6 +3174,17 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   TestIdTwinSync dco_decode_box_autoadd_test_id_twin_sync(dynamic raw);
 
+  @protected
+  TimeTypesTwinNormal dco_decode_box_autoadd_time_types_twin_normal(
+      dynamic raw);
+
+  @protected
+  TimeTypesTwinRustAsync dco_decode_box_autoadd_time_types_twin_rust_async(
+      dynamic raw);
+
+  @protected
+  TimeTypesTwinSync dco_decode_box_autoadd_time_types_twin_sync(dynamic raw);
+
   @protected
   TranslatableStructWithDartCodeTwinNormal
       dco_decode_box_autoadd_translatable_struct_with_dart_code_twin_normal(
@@ -3263,6 +3510,10 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   CustomizedTwinSync dco_decode_customized_twin_sync(dynamic raw);
 
+  @protected
+  DartImplementableTraitTwinNormalImplementor
+      dco_decode_dart_implementable_trait_twin_normal_implementor(dynamic raw);
+
   @protected
   DartOpaqueNestedTwinNormal dco_decode_dart_opaque_nested_twin_normal(
       dynamic raw);
@@ -3488,6 +3739,16 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   ItemContainerSolutionTwoTwinNormal
       dco_decode_item_container_solution_two_twin_normal(dynamic raw);
 
+  @protected
+  JsonDocumentTwinNormal dco_decode_json_document_twin_normal(dynamic raw);
+
+  @protected
+  JsonDocumentTwinRustAsync dco_decode_json_document_twin_rust_async(
+      dynamic raw);
+
+  @protected
+  JsonDocumentTwinSync dco_decode_json_document_twin_sync(dynamic raw);
+
   @protected
   KitchenSinkTwinNormal dco_decode_kitchen_sink_twin_normal(dynamic raw);
 
@@ -3522,18 +3783,36 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpaqueItemTwinNormal(
           dynamic raw);
 
+  @protected
+  List<BigInt> dco_decode_list_BigInt(dynamic raw);
+
   @protected
   List<Duration> dco_decode_list_Chrono_Duration(dynamic raw);
 
+  @protected
+  List<DateTimeWithOffset> dco_decode_list_Chrono_FixedOffset(dynamic raw);
+
   @protected
   List<DateTime> dco_decode_list_Chrono_Local(dynamic raw);
 
   @protected
   List<DateTime> dco_decode_list_Chrono_Naive(dynamic raw);
 
+  @protected
+  List<DateTime> dco_decode_list_Chrono_NaiveDate(dynamic raw);
+
+  @protected
+  List<DateTimeWithOffset> dco_decode_list_Chrono_Tz(dynamic raw);
+
   @protected
   List<Object> dco_decode_list_DartOpaque(dynamic raw);
 
+  @protected
+  List<Decimal> dco_decode_list_Decimal(dynamic raw);
+
+  @protected
+  List<Object?> dco_decode_list_JsonValue(dynamic raw);
+
   @protected
   List<HideDataTwinNormal> dco_decode_list_RustOpaque_HideDataTwinNormal(
       dynamic raw);
@@ -3546,12 +3825,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   List<HideDataTwinSync> dco_decode_list_RustOpaque_HideDataTwinSync(
       dynamic raw);
 
+  @protected
+  List<Duration> dco_decode_list_StdDuration(dynamic raw);
+
+  @protected
+  List<DateTime> dco_decode_list_StdSystemTime(dynamic raw);
+
   @protected
   List<RustStreamSink<int>> dco_decode_list_StreamSink_i_32_Sse(dynamic raw);
 
   @protected
   List<String> dco_decode_list_String(dynamic raw);
 
+  @protected
+  List<Duration> dco_decode_list_TimeDuration(dynamic raw);
+
   @protected
   List<UuidValue> dco_decode_list_Uuid(dynamic raw);
 
@@ -4095,9 +4383,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   OpaqueNestedTwinSync dco_decode_opaque_nested_twin_sync(dynamic raw);
 
+  @protected
+  BigInt? dco_decode_opt_BigInt(dynamic raw);
+
+  @protected
+  DateTimeWithOffset? dco_decode_opt_Chrono_FixedOffset(dynamic raw);
+
+  @protected
+  DateTimeWithOffset? dco_decode_opt_Chrono_Tz(dynamic raw);
+
+  @protected
+  Decimal? dco_decode_opt_Decimal(dynamic raw);
+
   @protected
   BigInt? dco_decode_opt_I128(dynamic raw);
 
+  @protected
+  Object? dco_decode_opt_JsonValue(dynamic raw);
+
   @protected
   String? dco_decode_opt_String(dynamic raw);
 
@@ -4125,6 +4428,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   DateTime? dco_decode_opt_box_autoadd_Chrono_Naive(dynamic raw);
 
+  @protected
+  Duration? dco_decode_opt_box_autoadd_Chrono_NaiveTime(dynamic raw);
+
   @protected
   DateTime? dco_decode_opt_box_autoadd_Chrono_Utc(dynamic raw);
 
@@ -4148,6 +4454,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   HideDataTwinSync? dco_decode_opt_box_autoadd_RustOpaque_HideDataTwinSync(
       dynamic raw);
 
+  @protected
+  Duration? dco_decode_opt_box_autoadd_StdDuration(dynamic raw);
+
+  @protected
+  DateTime? dco_decode_opt_box_autoadd_StdSystemTime(dynamic raw);
+
+  @protected
+  Duration? dco_decode_opt_box_autoadd_TimeDuration(dynamic raw);
+
+  @protected
+  DateTime? dco_decode_opt_box_autoadd_TimeOffsetDateTime(dynamic raw);
+
+  @protected
+  DateTime? dco_decode_opt_box_autoadd_TimePrimitiveDateTime(dynamic raw);
+
   @protected
   ApplicationEnv? dco_decode_opt_box_autoadd_application_env(dynamic raw);
 
@@ -4596,6 +4917,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   (int, ApplicationMode) dco_decode_record_u_8_application_mode(dynamic raw);
 
+  @protected
+  ScheduleTwinNormal dco_decode_schedule_twin_normal(dynamic raw);
+
+  @protected
+  ScheduleTwinRustAsync dco_decode_schedule_twin_rust_async(dynamic raw);
+
+  @protected
+  ScheduleTwinSync dco_decode_schedule_twin_sync(dynamic raw);
+
   @protected
   Sequences dco_decode_sequences(dynamic raw);
 
@@ -4677,6 +5007,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   StaticOnlyTwinSync dco_decode_static_only_twin_sync(dynamic raw);
 
+  @protected
+  StdTimeTwinNormal dco_decode_std_time_twin_normal(dynamic raw);
+
+  @protected
+  StdTimeTwinRustAsync dco_decode_std_time_twin_rust_async(dynamic raw);
+
+  @protected
+  StdTimeTwinSync dco_decode_std_time_twin_sync(dynamic raw);
+
   @protected
   StructInLowerLevel dco_decode_struct_in_lower_level(dynamic raw);
 
@@ -4876,6 +5215,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   TestModelTwinSync dco_decode_test_model_twin_sync(dynamic raw);
 
+  @protected
+  TimeTypesTwinNormal dco_decode_time_types_twin_normal(dynamic raw);
+
+  @protected
+  TimeTypesTwinRustAsync dco_decode_time_types_twin_rust_async(dynamic raw);
+
+  @protected
+  TimeTypesTwinSync dco_decode_time_types_twin_sync(dynamic raw);
+
   @protected
   TranslatableStructWithDartCodeTwinNormal
       dco_decode_translatable_struct_with_dart_code_twin_normal(dynamic raw);
@@ -4904,6 +5252,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   TupleStructWithTwoFieldTwinSync
       dco_decode_tuple_struct_with_two_field_twin_sync(dynamic raw);
 
+  @protected
+  TypedExceptionErrorTwinNormal dco_decode_typed_exception_error_twin_normal(
+      dynamic raw);
+
+  @protected
+  TypedExceptionErrorTwinRustAsync
+      dco_decode_typed_exception_error_twin_rust_async(dynamic raw);
+
+  @protected
+  TypedExceptionErrorTwinSync dco_decode_typed_exception_error_twin_sync(
+      dynamic raw);
+
   @protected
   int dco_decode_u_16(dynamic raw);
 
@@ -4970,6 +5330,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
 
+  @protected
+  DartImplementableTraitTwinNormalDartImpl
+      sse_decode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl(
+          SseDeserializer deserializer);
+
   @protected
   LtNestedTypeWithLifetimeTwinNormal
       sse_decode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLifetimeableLtNestedTypeWithLifetimeTwinNormalstatic(
@@ -5035,6 +5400,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       sse_decode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitForDynTwinNormal(
           SseDeserializer deserializer);
 
+  @protected
+  StructWithDartImplementableTraitTwinNormal
+      sse_decode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+          SseDeserializer deserializer);
+
   @protected
   BoxAnyMyDartTypeRename
       sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynAnySendSyncstatic(
@@ -5070,6 +5440,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConstructorOpaqueSyncStructTwinNormal(
           SseDeserializer deserializer);
 
+  @protected
+  DartImplementableTraitTwinNormalDartImpl
+      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl(
+          SseDeserializer deserializer);
+
   @protected
   DeliberateFailSanityCheckTwinNormal
       sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeliberateFailSanityCheckTwinNormal(
@@ -5290,6 +5665,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitTwinSync(
           SseDeserializer deserializer);
 
+  @protected
+  StructWithDartImplementableTraitTwinNormal
+      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+          SseDeserializer deserializer);
+
   @protected
   StructWithGoodAndOpaqueFieldWithoutOptionTwinNormal
       sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinNormal(
@@ -5405,6 +5785,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitTwinSync(
           SseDeserializer deserializer);
 
+  @protected
+  StructWithDartImplementableTraitTwinNormal
+      sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+          SseDeserializer deserializer);
+
   @protected
   StructWithGoodAndOpaqueFieldWithoutOptionTwinNormal
       sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinNormal(
@@ -5435,6 +5820,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConstructorOpaqueSyncStructTwinNormal(
           SseDeserializer deserializer);
 
+  @protected
+  DartImplementableTraitTwinNormalDartImpl
+      sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl(
+          SseDeserializer deserializer);
+
   @protected
   DeliberateFailSanityCheckTwinNormal
       sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeliberateFailSanityCheckTwinNormal(
@@ -5590,6 +5980,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitTwinSync(
           SseDeserializer deserializer);
 
+  @protected
+  StructWithDartImplementableTraitTwinNormal
+      sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+          SseDeserializer deserializer);
+
   @protected
   StructWithGoodAndOpaqueFieldWithoutOptionTwinNormal
       sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinNormal(
@@ -5615,9 +6010,25 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithSimpleSetterTwinNormal(
           SseDeserializer deserializer);
 
+  @protected
+  Map<String, MySize> sse_decode_BTreeMap_String_my_size(
+      SseDeserializer deserializer);
+
+  @protected
+  Map<int, int> sse_decode_BTreeMap_i_32_i_32(SseDeserializer deserializer);
+
+  @protected
+  Set<String> sse_decode_BTreeSet_String(SseDeserializer deserializer);
+
+  @protected
+  Set<int> sse_decode_BTreeSet_i_32(SseDeserializer deserializer);
+
   @protected
   String sse_decode_Backtrace(SseDeserializer deserializer);
 
+  @protected
+  BigInt sse_decode_BigInt(SseDeserializer deserializer);
+
   @protected
   int sse_decode_CastedPrimitive_i_64(SseDeserializer deserializer);
 
@@ -5636,12 +6047,25 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   Duration sse_decode_Chrono_Duration(SseDeserializer deserializer);
 
+  @protected
+  DateTimeWithOffset sse_decode_Chrono_FixedOffset(
+      SseDeserializer deserializer);
+
   @protected
   DateTime sse_decode_Chrono_Local(SseDeserializer deserializer);
 
   @protected
   DateTime sse_decode_Chrono_Naive(SseDeserializer deserializer);
 
+  @protected
+  DateTime sse_decode_Chrono_NaiveDate(SseDeserializer deserializer);
+
+  @protected
+  Duration sse_decode_Chrono_NaiveTime(SseDeserializer deserializer);
+
+  @protected
+  DateTimeWithOffset sse_decode_Chrono_Tz(SseDeserializer deserializer);
+
   @protected
   DateTime sse_decode_Chrono_Utc(SseDeserializer deserializer);
 
@@ -5655,6 +6079,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ObjectArray1 sse_decode_DartOpaque_array_1(SseDeserializer deserializer);
 
+  @protected
+  Stream<String> sse_decode_DartStream_String(SseDeserializer deserializer);
+
+  @protected
+  Stream<int> sse_decode_DartStream_i_32(SseDeserializer deserializer);
+
+  @protected
+  Decimal sse_decode_Decimal(SseDeserializer deserializer);
+
   @protected
   SimpleTraitForDynTwinNormal sse_decode_DynTrait_SimpleTraitForDynTwinNormal(
       SseDeserializer deserializer);
@@ -5662,6 +6095,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   BigInt sse_decode_I128(SseDeserializer deserializer);
 
+  @protected
+  Map<String, dynamic> sse_decode_JsonMap(SseDeserializer deserializer);
+
+  @protected
+  Object? sse_decode_JsonValue(SseDeserializer deserializer);
+
   @protected
   LtNestedTypeWithLifetimeTwinNormal
       sse_decode_Lifetimeable_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLtNestedTypeWithLifetimeTwinNormalstatic(
@@ -6012,6 +6451,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConstructorOpaqueSyncStructTwinNormal(
           SseDeserializer deserializer);
 
+  @protected
+  DartImplementableTraitTwinNormalDartImpl
+      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl(
+          SseDeserializer deserializer);
+
   @protected
   DeliberateFailSanityCheckTwinNormal
       sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeliberateFailSanityCheckTwinNormal(
@@ -6262,6 +6706,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitTwinSync(
           SseDeserializer deserializer);
 
+  @protected
+  StructWithDartImplementableTraitTwinNormal
+      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+          SseDeserializer deserializer);
+
   @protected
   StructWithGoodAndOpaqueFieldWithoutOptionTwinNormal
       sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinNormal(
@@ -6300,6 +6749,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   Set<int> sse_decode_Set_i_32(SseDeserializer deserializer);
 
+  @protected
+  Duration sse_decode_StdDuration(SseDeserializer deserializer);
+
+  @protected
+  DateTime sse_decode_StdSystemTime(SseDeserializer deserializer);
+
   @protected
   RustStreamSink<NonCloneSimpleTwinNormal>
       sse_decode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinNormal_Sse(
@@ -6434,12 +6889,30 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   String sse_decode_String(SseDeserializer deserializer);
 
+  @protected
+  DateTime sse_decode_TimeDate(SseDeserializer deserializer);
+
+  @protected
+  Duration sse_decode_TimeDuration(SseDeserializer deserializer);
+
+  @protected
+  DateTime sse_decode_TimeOffsetDateTime(SseDeserializer deserializer);
+
+  @protected
+  DateTime sse_decode_TimePrimitiveDateTime(SseDeserializer deserializer);
+
   @protected
   BigInt sse_decode_U128(SseDeserializer deserializer);
 
   @protected
   UuidValue sse_decode_Uuid(SseDeserializer deserializer);
 
+  @protected
+  List<String> sse_decode_VecDeque_String(SseDeserializer deserializer);
+
+  @protected
+  Int32List sse_decode_VecDeque_i_32(SseDeserializer deserializer);
+
   @protected
   ATwinNormal sse_decode_a_twin_normal(SseDeserializer deserializer);
 
@@ -6595,6 +7068,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   BigBuffersTwinSync sse_decode_big_buffers_twin_sync(
       SseDeserializer deserializer);
 
+  @protected
+  BigNumbersTwinNormal sse_decode_big_numbers_twin_normal(
+      SseDeserializer deserializer);
+
+  @protected
+  BigNumbersTwinRustAsync sse_decode_big_numbers_twin_rust_async(
+      SseDeserializer deserializer);
+
+  @protected
+  BigNumbersTwinSync sse_decode_big_numbers_twin_sync(
+      SseDeserializer deserializer);
+
   @protected
   BlobTwinNormal sse_decode_blob_twin_normal(SseDeserializer deserializer);
 
@@ -6608,6 +7093,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   bool sse_decode_bool(SseDeserializer deserializer);
 
+  @protected
+  DartImplementableTraitTwinNormal
+      sse_decode_box_DynTrait_DartImplementableTraitTwinNormal(
+          SseDeserializer deserializer);
+
   @protected
   ApplicationEnv sse_decode_box_application_env(SseDeserializer deserializer);
 
@@ -6632,6 +7122,10 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   DateTime sse_decode_box_autoadd_Chrono_Naive(SseDeserializer deserializer);
 
+  @protected
+  Duration sse_decode_box_autoadd_Chrono_NaiveTime(
+      SseDeserializer deserializer);
+
   @protected
   DateTime sse_decode_box_autoadd_Chrono_Utc(SseDeserializer deserializer);
 
@@ -6655,6 +7149,23 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   HideDataTwinSync sse_decode_box_autoadd_RustOpaque_HideDataTwinSync(
       SseDeserializer deserializer);
 
+  @protected
+  Duration sse_decode_box_autoadd_StdDuration(SseDeserializer deserializer);
+
+  @protected
+  DateTime sse_decode_box_autoadd_StdSystemTime(SseDeserializer deserializer);
+
+  @protected
+  Duration sse_decode_box_autoadd_TimeDuration(SseDeserializer deserializer);
+
+  @protected
+  DateTime sse_decode_box_autoadd_TimeOffsetDateTime(
+      SseDeserializer deserializer);
+
+  @protected
+  DateTime sse_decode_box_autoadd_TimePrimitiveDateTime(
+      SseDeserializer deserializer);
+
   @protected
   ATwinNormal sse_decode_box_autoadd_a_twin_normal(
       SseDeserializer deserializer);
@@ -6787,6 +7298,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   BenchmarkBlobTwinSync sse_decode_box_autoadd_benchmark_blob_twin_sync(
       SseDeserializer deserializer);
 
+  @protected
+  BigNumbersTwinNormal sse_decode_box_autoadd_big_numbers_twin_normal(
+      SseDeserializer deserializer);
+
+  @protected
+  BigNumbersTwinRustAsync sse_decode_box_autoadd_big_numbers_twin_rust_async(
+      SseDeserializer deserializer);
+
+  @protected
+  BigNumbersTwinSync sse_decode_box_autoadd_big_numbers_twin_sync(
+      SseDeserializer deserializer);
+
   @protected
   bool sse_decode_box_autoadd_bool(SseDeserializer deserializer);
 
@@ -6899,6 +7422,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   CustomizedTwinSync sse_decode_box_autoadd_customized_twin_sync(
       SseDeserializer deserializer);
 
+  @protected
+  DartImplementableTraitTwinNormalImplementor
+      sse_decode_box_autoadd_dart_implementable_trait_twin_normal_implementor(
+          SseDeserializer deserializer);
+
   @protected
   DartOpaqueNestedTwinNormal
       sse_decode_box_autoadd_dart_opaque_nested_twin_normal(
@@ -7106,6 +7634,19 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       sse_decode_box_autoadd_item_container_solution_two_twin_normal(
           SseDeserializer deserializer);
 
+  @protected
+  JsonDocumentTwinNormal sse_decode_box_autoadd_json_document_twin_normal(
+      SseDeserializer deserializer);
+
+  @protected
+  JsonDocumentTwinRustAsync
+      sse_decode_box_autoadd_json_document_twin_rust_async(
+          SseDeserializer deserializer);
+
+  @protected
+  JsonDocumentTwinSync sse_decode_box_autoadd_json_document_twin_sync(
+      SseDeserializer deserializer);
+
   @protected
   KitchenSinkTwinNormal sse_decode_box_autoadd_kitchen_sink_twin_normal(
       SseDeserializer deserializer);
@@ -7271,6 +7812,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   (String, int) sse_decode_box_autoadd_record_string_i_32(
       SseDeserializer deserializer);
 
+  @protected
+  ScheduleTwinNormal sse_decode_box_autoadd_schedule_twin_normal(
+      SseDeserializer deserializer);
+
+  @protected
+  ScheduleTwinRustAsync sse_decode_box_autoadd_schedule_twin_rust_async(
+      SseDeserializer deserializer);
+
+  @protected
+  ScheduleTwinSync sse_decode_box_autoadd_schedule_twin_sync(
+      SseDeserializer deserializer);
+
   @protected
   Sequences sse_decode_box_autoadd_sequences(SseDeserializer deserializer);
 
@@ -7336,6 +7889,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   SomeStructTwinSync sse_decode_box_autoadd_some_struct_twin_sync(
       SseDeserializer deserializer);
 
+  @protected
+  StdTimeTwinNormal sse_decode_box_autoadd_std_time_twin_normal(
+      SseDeserializer deserializer);
+
+  @protected
+  StdTimeTwinRustAsync sse_decode_box_autoadd_std_time_twin_rust_async(
+      SseDeserializer deserializer);
+
+  @protected
+  StdTimeTwinSync sse_decode_box_autoadd_std_time_twin_sync(
+      SseDeserializer deserializer);
+
   @protected
   StructInLowerLevel sse_decode_box_autoadd_struct_in_lower_level(
       SseDeserializer deserializer);
@@ -7512,6 +8077,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   TestIdTwinSync sse_decode_box_autoadd_test_id_twin_sync(
       SseDeserializer deserializer);
 
+  @protected
+  TimeTypesTwinNormal sse_decode_box_autoadd_time_types_twin_normal(
+      SseDeserializer deserializer);
+
+  @protected
+  TimeTypesTwinRustAsync sse_decode_box_autoadd_time_types_twin_rust_async(
+      SseDeserializer deserializer);
+
+  @protected
+  TimeTypesTwinSync sse_decode_box_autoadd_time_types_twin_sync(
+      SseDeserializer deserializer);
+
   @protected
   TranslatableStructWithDartCodeTwinNormal
       sse_decode_box_autoadd_translatable_struct_with_dart_code_twin_normal(
@@ -7882,6 +8459,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   CustomizedTwinSync sse_decode_customized_twin_sync(
       SseDeserializer deserializer);
 
+  @protected
+  DartImplementableTraitTwinNormalImplementor
+      sse_decode_dart_implementable_trait_twin_normal_implementor(
+          SseDeserializer deserializer);
+
   @protected
   DartOpaqueNestedTwinNormal sse_decode_dart_opaque_nested_twin_normal(
       SseDeserializer deserializer);
@@ -8138,6 +8720,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       sse_decode_item_container_solution_two_twin_normal(
           SseDeserializer deserializer);
 
+  @protected
+  JsonDocumentTwinNormal sse_decode_json_document_twin_normal(
+      SseDeserializer deserializer);
+
+  @protected
+  JsonDocumentTwinRustAsync sse_decode_json_document_twin_rust_async(
+      SseDeserializer deserializer);
+
+  @protected
+  JsonDocumentTwinSync sse_decode_json_document_twin_sync(
+      SseDeserializer deserializer);
+
   @protected
   KitchenSinkTwinNormal sse_decode_kitchen_sink_twin_normal(
       SseDeserializer deserializer);
@@ -8175,18 +8769,38 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpaqueItemTwinNormal(
           SseDeserializer deserializer);
 
+  @protected
+  List<BigInt> sse_decode_list_BigInt(SseDeserializer deserializer);
+
   @protected
   List<Duration> sse_decode_list_Chrono_Duration(SseDeserializer deserializer);
 
+  @protected
+  List<DateTimeWithOffset> sse_decode_list_Chrono_FixedOffset(
+      SseDeserializer deserializer);
+
   @protected
   List<DateTime> sse_decode_list_Chrono_Local(SseDeserializer deserializer);
 
   @protected
   List<DateTime> sse_decode_list_Chrono_Naive(SseDeserializer deserializer);
 
+  @protected
+  List<DateTime> sse_decode_list_Chrono_NaiveDate(SseDeserializer deserializer);
+
+  @protected
+  List<DateTimeWithOffset> sse_decode_list_Chrono_Tz(
+      SseDeserializer deserializer);
+
   @protected
   List<Object> sse_decode_list_DartOpaque(SseDeserializer deserializer);
 
+  @protected
+  List<Decimal> sse_decode_list_Decimal(SseDeserializer deserializer);
+
+  @protected
+  List<Object?> sse_decode_list_JsonValue(SseDeserializer deserializer);
+
   @protected
   List<HideDataTwinNormal> sse_decode_list_RustOpaque_HideDataTwinNormal(
       SseDeserializer deserializer);
@@ -8199,6 +8813,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   List<HideDataTwinSync> sse_decode_list_RustOpaque_HideDataTwinSync(
       SseDeserializer deserializer);
 
+  @protected
+  List<Duration> sse_decode_list_StdDuration(SseDeserializer deserializer);
+
+  @protected
+  List<DateTime> sse_decode_list_StdSystemTime(SseDeserializer deserializer);
+
   @protected
   List<RustStreamSink<int>> sse_decode_list_StreamSink_i_32_Sse(
       SseDeserializer deserializer);
@@ -8206,6 +8826,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<String> sse_decode_list_String(SseDeserializer deserializer);
 
+  @protected
+  List<Duration> sse_decode_list_TimeDuration(SseDeserializer deserializer);
+
   @protected
   List<UuidValue> sse_decode_list_Uuid(SseDeserializer deserializer);
 
@@ -8840,9 +9463,25 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   OpaqueNestedTwinSync sse_decode_opaque_nested_twin_sync(
       SseDeserializer deserializer);
 
+  @protected
+  BigInt? sse_decode_opt_BigInt(SseDeserializer deserializer);
+
+  @protected
+  DateTimeWithOffset? sse_decode_opt_Chrono_FixedOffset(
+      SseDeserializer deserializer);
+
+  @protected
+  DateTimeWithOffset? sse_decode_opt_Chrono_Tz(SseDeserializer deserializer);
+
+  @protected
+  Decimal? sse_decode_opt_Decimal(SseDeserializer deserializer);
+
   @protected
   BigInt? sse_decode_opt_I128(SseDeserializer deserializer);
 
+  @protected
+  Object? sse_decode_opt_JsonValue(SseDeserializer deserializer);
+
   @protected
   String? sse_decode_opt_String(SseDeserializer deserializer);
 
@@ -8872,6 +9511,10 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   DateTime? sse_decode_opt_box_autoadd_Chrono_Naive(
       SseDeserializer deserializer);
 
+  @protected
+  Duration? sse_decode_opt_box_autoadd_Chrono_NaiveTime(
+      SseDeserializer deserializer);
+
   @protected
   DateTime? sse_decode_opt_box_autoadd_Chrono_Utc(SseDeserializer deserializer);
 
@@ -8896,6 +9539,26 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   HideDataTwinSync? sse_decode_opt_box_autoadd_RustOpaque_HideDataTwinSync(
       SseDeserializer deserializer);
 
+  @protected
+  Duration? sse_decode_opt_box_autoadd_StdDuration(
+      SseDeserializer deserializer);
+
+  @protected
+  DateTime? sse_decode_opt_box_autoadd_StdSystemTime(
+      SseDeserializer deserializer);
+
+  @protected
+  Duration? sse_decode_opt_box_autoadd_TimeDuration(
+      SseDeserializer deserializer);
+
+  @protected
+  DateTime? sse_decode_opt_box_autoadd_TimeOffsetDateTime(
+      SseDeserializer deserializer);
+
+  @protected
+  DateTime? sse_decode_opt_box_autoadd_TimePrimitiveDateTime(
+      SseDeserializer deserializer);
+
   @protected
   ApplicationEnv? sse_decode_opt_box_autoadd_application_env(
       SseDeserializer deserializer);
@@ -9396,6 +10059,17 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   (int, ApplicationMode) sse_decode_record_u_8_application_mode(
       SseDeserializer deserializer);
 
+  @protected
+  ScheduleTwinNormal sse_decode_schedule_twin_normal(
+      SseDeserializer deserializer);
+
+  @protected
+  ScheduleTwinRustAsync sse_decode_schedule_twin_rust_async(
+      SseDeserializer deserializer);
+
+  @protected
+  ScheduleTwinSync sse_decode_schedule_twin_sync(SseDeserializer deserializer);
+
   @protected
   Sequences sse_decode_sequences(SseDeserializer deserializer);
 
@@ -9494,6 +10168,17 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   StaticOnlyTwinSync sse_decode_static_only_twin_sync(
       SseDeserializer deserializer);
 
+  @protected
+  StdTimeTwinNormal sse_decode_std_time_twin_normal(
+      SseDeserializer deserializer);
+
+  @protected
+  StdTimeTwinRustAsync sse_decode_std_time_twin_rust_async(
+      SseDeserializer deserializer);
+
+  @protected
+  StdTimeTwinSync sse_decode_std_time_twin_sync(SseDeserializer deserializer);
+
   @protected
   StructInLowerLevel sse_decode_struct_in_lower_level(
       SseDeserializer deserializer);
@@ -9730,6 +10415,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   TestModelTwinSync sse_decode_test_model_twin_sync(
       SseDeserializer deserializer);
 
+  @protected
+  TimeTypesTwinNormal sse_decode_time_types_twin_normal(
+      SseDeserializer deserializer);
+
+  @protected
+  TimeTypesTwinRustAsync sse_decode_time_types_twin_rust_async(
+      SseDeserializer deserializer);
+
+  @protected
+  TimeTypesTwinSync sse_decode_time_types_twin_sync(
+      SseDeserializer deserializer);
+
   @protected
   TranslatableStructWithDartCodeTwinNormal
       sse_decode_translatable_struct_with_dart_code_twin_normal(
@@ -9765,6 +10462,19 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       sse_decode_tuple_struct_with_two_field_twin_sync(
           SseDeserializer deserializer);
 
+  @protected
+  TypedExceptionErrorTwinNormal sse_decode_typed_exception_error_twin_normal(
+      SseDeserializer deserializer);
+
+  @protected
+  TypedExceptionErrorTwinRustAsync
+      sse_decode_typed_exception_error_twin_rust_async(
+          SseDeserializer deserializer);
+
+  @protected
+  TypedExceptionErrorTwinSync sse_decode_typed_exception_error_twin_sync(
+      SseDeserializer deserializer);
+
   @protected
   int sse_decode_u_16(SseDeserializer deserializer);
 
@@ -9836,6 +10546,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_AnyhowException(
       AnyhowException self, SseSerializer serializer);
 
+  @protected
+  void
+      sse_encode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl(
+          DartImplementableTraitTwinNormalDartImpl self,
+          SseSerializer serializer);
+
   @protected
   void
       sse_encode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLifetimeableLtNestedTypeWithLifetimeTwinNormalstatic(
@@ -9898,8 +10614,14 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
 
   @protected
   void
-      sse_encode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitForDynTwinNormal(
-          StructTwoWithTraitForDynTwinNormal self, SseSerializer serializer);
+      sse_encode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitForDynTwinNormal(
+          StructTwoWithTraitForDynTwinNormal self, SseSerializer serializer);
+
+  @protected
+  void
+      sse_encode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+          StructWithDartImplementableTraitTwinNormal self,
+          SseSerializer serializer);
 
   @protected
   void
@@ -9936,6 +10658,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConstructorOpaqueSyncStructTwinNormal(
           ConstructorOpaqueSyncStructTwinNormal self, SseSerializer serializer);
 
+  @protected
+  void
+      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl(
+          DartImplementableTraitTwinNormalDartImpl self,
+          SseSerializer serializer);
+
   @protected
   void
       sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeliberateFailSanityCheckTwinNormal(
@@ -10159,6 +10887,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitTwinSync(
           StructTwoWithTraitTwinSync self, SseSerializer serializer);
 
+  @protected
+  void
+      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+          StructWithDartImplementableTraitTwinNormal self,
+          SseSerializer serializer);
+
   @protected
   void
       sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinNormal(
@@ -10277,6 +11011,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitTwinSync(
           StructTwoWithTraitTwinSync self, SseSerializer serializer);
 
+  @protected
+  void
+      sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+          StructWithDartImplementableTraitTwinNormal self,
+          SseSerializer serializer);
+
   @protected
   void
       sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinNormal(
@@ -10310,6 +11050,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConstructorOpaqueSyncStructTwinNormal(
           ConstructorOpaqueSyncStructTwinNormal self, SseSerializer serializer);
 
+  @protected
+  void
+      sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl(
+          DartImplementableTraitTwinNormalDartImpl self,
+          SseSerializer serializer);
+
   @protected
   void
       sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeliberateFailSanityCheckTwinNormal(
@@ -10465,6 +11211,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitTwinSync(
           StructTwoWithTraitTwinSync self, SseSerializer serializer);
 
+  @protected
+  void
+      sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+          StructWithDartImplementableTraitTwinNormal self,
+          SseSerializer serializer);
+
   @protected
   void
       sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinNormal(
@@ -10493,9 +11245,26 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithSimpleSetterTwinNormal(
           StructWithSimpleSetterTwinNormal self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_BTreeMap_String_my_size(
+      Map<String, MySize> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_BTreeMap_i_32_i_32(
+      Map<int, int> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_BTreeSet_String(Set<String> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_BTreeSet_i_32(Set<int> self, SseSerializer serializer);
+
   @protected
   void sse_encode_Backtrace(String self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_BigInt(BigInt self, SseSerializer serializer);
+
   @protected
   void sse_encode_CastedPrimitive_i_64(int self, SseSerializer serializer);
 
@@ -10514,12 +11283,25 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_Chrono_Duration(Duration self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_Chrono_FixedOffset(
+      DateTimeWithOffset self, SseSerializer serializer);
+
   @protected
   void sse_encode_Chrono_Local(DateTime self, SseSerializer serializer);
 
   @protected
   void sse_encode_Chrono_Naive(DateTime self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_Chrono_NaiveDate(DateTime self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_Chrono_NaiveTime(Duration self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_Chrono_Tz(DateTimeWithOffset self, SseSerializer serializer);
+
   @protected
   void sse_encode_Chrono_Utc(DateTime self, SseSerializer serializer);
 
@@ -10567,6 +11349,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_DartFn_Inputs__Output_unit_AnyhowException(
       FutureOr<void> Function() self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_DartFn_Inputs_bool_Output_opt_String_AnyhowException(
+      FutureOr<String?> Function(bool) self, SseSerializer serializer);
+
+  @protected
+  void
+      sse_encode_DartFn_Inputs_bool_Output_opt_box_autoadd_i_32_AnyhowException(
+          FutureOr<int?> Function(bool) self, SseSerializer serializer);
+
   @protected
   void
       sse_encode_DartFn_Inputs_demo_struct_for_rust_call_dart_twin_normal_Output_demo_struct_for_rust_call_dart_twin_normal_AnyhowException(
@@ -10583,6 +11374,14 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
               self,
           SseSerializer serializer);
 
+  @protected
+  void sse_encode_DartFn_Inputs_i_32_Output_String_AnyhowException(
+      FutureOr<String> Function(int) self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_DartFn_Inputs_i_32_i_32_Output_i_32_AnyhowException(
+      FutureOr<int> Function(int, int) self, SseSerializer serializer);
+
   @protected
   void sse_encode_DartOpaque(Object self, SseSerializer serializer);
 
@@ -10590,6 +11389,16 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_DartOpaque_array_1(
       ObjectArray1 self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_DartStream_String(
+      Stream<String> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_DartStream_i_32(Stream<int> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_Decimal(Decimal self, SseSerializer serializer);
+
   @protected
   void sse_encode_DynTrait_SimpleTraitForDynTwinNormal(
       SimpleTraitForDynTwinNormal self, SseSerializer serializer);
@@ -10597,6 +11406,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_I128(BigInt self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_JsonMap(Map<String, dynamic> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_JsonValue(Object? self, SseSerializer serializer);
+
   @protected
   void
       sse_encode_Lifetimeable_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLtNestedTypeWithLifetimeTwinNormalstatic(
@@ -10940,6 +11755,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConstructorOpaqueSyncStructTwinNormal(
           ConstructorOpaqueSyncStructTwinNormal self, SseSerializer serializer);
 
+  @protected
+  void
+      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl(
+          DartImplementableTraitTwinNormalDartImpl self,
+          SseSerializer serializer);
+
   @protected
   void
       sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeliberateFailSanityCheckTwinNormal(
@@ -11193,6 +12014,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitTwinSync(
           StructTwoWithTraitTwinSync self, SseSerializer serializer);
 
+  @protected
+  void
+      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+          StructWithDartImplementableTraitTwinNormal self,
+          SseSerializer serializer);
+
   @protected
   void
       sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinNormal(
@@ -11234,6 +12061,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_Set_i_32(Set<int> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_StdDuration(Duration self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_StdSystemTime(DateTime self, SseSerializer serializer);
+
   @protected
   void
       sse_encode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinNormal_Sse(
@@ -11358,12 +12191,31 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_String(String self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_TimeDate(DateTime self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_TimeDuration(Duration self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_TimeOffsetDateTime(DateTime self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_TimePrimitiveDateTime(
+      DateTime self, SseSerializer serializer);
+
   @protected
   void sse_encode_U128(BigInt self, SseSerializer serializer);
 
   @protected
   void sse_encode_Uuid(UuidValue self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_VecDeque_String(List<String> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_VecDeque_i_32(Int32List self, SseSerializer serializer);
+
   @protected
   void sse_encode_a_twin_normal(ATwinNormal self, SseSerializer serializer);
 
@@ -11524,6 +12376,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_big_buffers_twin_sync(
       BigBuffersTwinSync self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_big_numbers_twin_normal(
+      BigNumbersTwinNormal self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_big_numbers_twin_rust_async(
+      BigNumbersTwinRustAsync self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_big_numbers_twin_sync(
+      BigNumbersTwinSync self, SseSerializer serializer);
+
   @protected
   void sse_encode_blob_twin_normal(
       BlobTwinNormal self, SseSerializer serializer);
@@ -11538,6 +12402,10 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_bool(bool self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_DynTrait_DartImplementableTraitTwinNormal(
+      DartImplementableTraitTwinNormal self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_application_env(
       ApplicationEnv self, SseSerializer serializer);
@@ -11565,6 +12433,10 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_box_autoadd_Chrono_Naive(
       DateTime self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_Chrono_NaiveTime(
+      Duration self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_Chrono_Utc(
       DateTime self, SseSerializer serializer);
@@ -11588,6 +12460,26 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_box_autoadd_RustOpaque_HideDataTwinSync(
       HideDataTwinSync self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_StdDuration(
+      Duration self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_StdSystemTime(
+      DateTime self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_TimeDuration(
+      Duration self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_TimeOffsetDateTime(
+      DateTime self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_TimePrimitiveDateTime(
+      DateTime self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_a_twin_normal(
       ATwinNormal self, SseSerializer serializer);
@@ -11714,6 +12606,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_box_autoadd_benchmark_blob_twin_sync(
       BenchmarkBlobTwinSync self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_big_numbers_twin_normal(
+      BigNumbersTwinNormal self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_big_numbers_twin_rust_async(
+      BigNumbersTwinRustAsync self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_big_numbers_twin_sync(
+      BigNumbersTwinSync self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer);
 
@@ -11813,6 +12717,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_box_autoadd_customized_twin_sync(
       CustomizedTwinSync self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_dart_implementable_trait_twin_normal_implementor(
+      DartImplementableTraitTwinNormalImplementor self,
+      SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_dart_opaque_nested_twin_normal(
       DartOpaqueNestedTwinNormal self, SseSerializer serializer);
@@ -12004,6 +12913,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_box_autoadd_item_container_solution_two_twin_normal(
       ItemContainerSolutionTwoTwinNormal self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_json_document_twin_normal(
+      JsonDocumentTwinNormal self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_json_document_twin_rust_async(
+      JsonDocumentTwinRustAsync self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_json_document_twin_sync(
+      JsonDocumentTwinSync self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_kitchen_sink_twin_normal(
       KitchenSinkTwinNormal self, SseSerializer serializer);
@@ -12166,6 +13087,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_box_autoadd_record_string_i_32(
       (String, int) self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_schedule_twin_normal(
+      ScheduleTwinNormal self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_schedule_twin_rust_async(
+      ScheduleTwinRustAsync self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_schedule_twin_sync(
+      ScheduleTwinSync self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_sequences(
       Sequences self, SseSerializer serializer);
@@ -12227,6 +13160,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_box_autoadd_some_struct_twin_sync(
       SomeStructTwinSync self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_std_time_twin_normal(
+      StdTimeTwinNormal self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_std_time_twin_rust_async(
+      StdTimeTwinRustAsync self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_std_time_twin_sync(
+      StdTimeTwinSync self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_struct_in_lower_level(
       StructInLowerLevel self, SseSerializer serializer);
@@ -12380,6 +13325,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_box_autoadd_test_id_twin_sync(
       TestIdTwinSync self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_time_types_twin_normal(
+      TimeTypesTwinNormal self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_time_types_twin_rust_async(
+      TimeTypesTwinRustAsync self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_time_types_twin_sync(
+      TimeTypesTwinSync self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_translatable_struct_with_dart_code_twin_normal(
       TranslatableStructWithDartCodeTwinNormal self, SseSerializer serializer);
@@ -12734,6 +13691,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_customized_twin_sync(
       CustomizedTwinSync self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_dart_implementable_trait_twin_normal_implementor(
+      DartImplementableTraitTwinNormalImplementor self,
+      SseSerializer serializer);
+
   @protected
   void sse_encode_dart_opaque_nested_twin_normal(
       DartOpaqueNestedTwinNormal self, SseSerializer serializer);
@@ -12987,6 +13949,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_item_container_solution_two_twin_normal(
       ItemContainerSolutionTwoTwinNormal self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_json_document_twin_normal(
+      JsonDocumentTwinNormal self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_json_document_twin_rust_async(
+      JsonDocumentTwinRustAsync self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_json_document_twin_sync(
+      JsonDocumentTwinSync self, SseSerializer serializer);
+
   @protected
   void sse_encode_kitchen_sink_twin_normal(
       KitchenSinkTwinNormal self, SseSerializer serializer);
@@ -13024,10 +13998,17 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpaqueItemTwinNormal(
           List<OpaqueItemTwinNormal> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_BigInt(List<BigInt> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_Chrono_Duration(
       List<Duration> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_Chrono_FixedOffset(
+      List<DateTimeWithOffset> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_Chrono_Local(
       List<DateTime> self, SseSerializer serializer);
@@ -13036,9 +14017,23 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_list_Chrono_Naive(
       List<DateTime> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_Chrono_NaiveDate(
+      List<DateTime> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_Chrono_Tz(
+      List<DateTimeWithOffset> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_DartOpaque(List<Object> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_Decimal(List<Decimal> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_JsonValue(List<Object?> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_RustOpaque_HideDataTwinNormal(
       List<HideDataTwinNormal> self, SseSerializer serializer);
@@ -13051,6 +14046,14 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_list_RustOpaque_HideDataTwinSync(
       List<HideDataTwinSync> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_StdDuration(
+      List<Duration> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_StdSystemTime(
+      List<DateTime> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_StreamSink_i_32_Sse(
       List<RustStreamSink<int>> self, SseSerializer serializer);
@@ -13058,6 +14061,10 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_list_String(List<String> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_TimeDuration(
+      List<Duration> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_Uuid(List<UuidValue> self, SseSerializer serializer);
 
@@ -13689,9 +14696,26 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_opaque_nested_twin_sync(
       OpaqueNestedTwinSync self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_BigInt(BigInt? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_Chrono_FixedOffset(
+      DateTimeWithOffset? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_Chrono_Tz(
+      DateTimeWithOffset? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_Decimal(Decimal? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_I128(BigInt? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_JsonValue(Object? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_String(String? self, SseSerializer serializer);
 
@@ -13721,6 +14745,10 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_opt_box_autoadd_Chrono_Naive(
       DateTime? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_Chrono_NaiveTime(
+      Duration? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_box_autoadd_Chrono_Utc(
       DateTime? self, SseSerializer serializer);
@@ -13745,6 +14773,26 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_opt_box_autoadd_RustOpaque_HideDataTwinSync(
       HideDataTwinSync? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_StdDuration(
+      Duration? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_StdSystemTime(
+      DateTime? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_TimeDuration(
+      Duration? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_TimeOffsetDateTime(
+      DateTime? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_TimePrimitiveDateTime(
+      DateTime? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_box_autoadd_application_env(
       ApplicationEnv? self, SseSerializer serializer);
@@ -14227,6 +15275,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_record_u_8_application_mode(
       (int, ApplicationMode) self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_schedule_twin_normal(
+      ScheduleTwinNormal self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_schedule_twin_rust_async(
+      ScheduleTwinRustAsync self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_schedule_twin_sync(
+      ScheduleTwinSync self, SseSerializer serializer);
+
   @protected
   void sse_encode_sequences(Sequences self, SseSerializer serializer);
 
@@ -14322,6 +15382,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_static_only_twin_sync(
       StaticOnlyTwinSync self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_std_time_twin_normal(
+      StdTimeTwinNormal self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_std_time_twin_rust_async(
+      StdTimeTwinRustAsync self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_std_time_twin_sync(
+      StdTimeTwinSync self, SseSerializer serializer);
+
   @protected
   void sse_encode_struct_in_lower_level(
       StructInLowerLevel self, SseSerializer serializer);
@@ -14545,6 +15617,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_test_model_twin_sync(
       TestModelTwinSync self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_time_types_twin_normal(
+      TimeTypesTwinNormal self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_time_types_twin_rust_async(
+      TimeTypesTwinRustAsync self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_time_types_twin_sync(
+      TimeTypesTwinSync self, SseSerializer serializer);
+
   @protected
   void sse_encode_translatable_struct_with_dart_code_twin_normal(
       TranslatableStructWithDartCodeTwinNormal self, SseSerializer serializer);
@@ -14573,6 +15657,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_tuple_struct_with_two_field_twin_sync(
       TupleStructWithTwoFieldTwinSync self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_typed_exception_error_twin_normal(
+      TypedExceptionErrorTwinNormal self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_typed_exception_error_twin_rust_async(
+      TypedExceptionErrorTwinRustAsync self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_typed_exception_error_twin_sync(
+      TypedExceptionErrorTwinSync self, SseSerializer serializer);
+
   @protected
   void sse_encode_u_16(int self, SseSerializer serializer);
 
@@ -14962,6 +16058,18 @@ class RustLibWire implements BaseWire {
           .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConstructorOpaqueSyncStructTwinNormal(
               ptr);
 
+  void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl(
+          int ptr) =>
+      wasmModule
+          .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl(
+              ptr);
+
+  void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl(
+          int ptr) =>
+      wasmModule
+          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl(
+              ptr);
+
   void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeliberateFailSanityCheckTwinNormal(
           int ptr) =>
       wasmModule
@@ -15562,6 +16670,18 @@ class RustLibWire implements BaseWire {
           .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitTwinSync(
               ptr);
 
+  void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+          int ptr) =>
+      wasmModule
+          .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+              ptr);
+
+  void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+          int ptr) =>
+      wasmModule
+          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+              ptr);
+
   void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinNormal(
           int ptr) =>
       wasmModule
@@ -15841,6 +16961,14 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
       rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerConstructorOpaqueSyncStructTwinNormal(
           int ptr);
 
+  external void
+      rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl(
+          int ptr);
+
+  external void
+      rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDartImplementableTraitTwinNormalDartImpl(
+          int ptr);
+
   external void
       rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeliberateFailSanityCheckTwinNormal(
           int ptr);
@@ -16241,6 +17369,14 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
       rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructTwoWithTraitTwinSync(
           int ptr);
 
+  external void
+      rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+          int ptr);
+
+  external void
+      rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithDartImplementableTraitTwinNormal(
+          int ptr);
+
   external void
       rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStructWithGoodAndOpaqueFieldWithoutOptionTwinNormal(
           int ptr);
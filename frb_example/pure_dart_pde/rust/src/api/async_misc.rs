@@ -7,3 +7,14 @@ pub async fn func_async_void_twin_normal() {}
 pub async fn func_async_simple_add_twin_normal(a: i32, b: i32) -> i32 {
     a + b
 }
+
+pub async fn func_async_never_complete_twin_normal() {
+    futures::future::pending::<()>().await
+}
+
+pub async fn func_async_wait_for_cancellation_twin_normal() {
+    let token = flutter_rust_bridge::CancellationToken::current().unwrap();
+    while !token.is_cancelled() {
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+}
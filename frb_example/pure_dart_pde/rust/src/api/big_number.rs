@@ -0,0 +1,46 @@
+// AUTO-GENERATED FROM frb_example/pure_dart, DO NOT EDIT
+
+use num_bigint::BigInt;
+use rust_decimal::Decimal;
+
+pub fn decimal_twin_normal(d: Decimal) -> Decimal {
+    d
+}
+
+pub fn add_decimals_twin_normal(a: Decimal, b: Decimal) -> Decimal {
+    a + b
+}
+
+pub fn optional_decimal_twin_normal(d: Option<Decimal>) -> Option<Decimal> {
+    d
+}
+
+pub fn decimals_twin_normal(decimals: Vec<Decimal>) -> Vec<Decimal> {
+    decimals
+}
+
+pub fn big_int_twin_normal(i: BigInt) -> BigInt {
+    i
+}
+
+pub fn multiply_big_ints_twin_normal(a: BigInt, b: BigInt) -> BigInt {
+    a * b
+}
+
+pub fn optional_big_int_twin_normal(i: Option<BigInt>) -> Option<BigInt> {
+    i
+}
+
+pub fn big_ints_twin_normal(ints: Vec<BigInt>) -> Vec<BigInt> {
+    ints
+}
+
+pub struct BigNumbersTwinNormal {
+    pub amount: Decimal,
+    pub discount: Option<Decimal>,
+    pub serial: BigInt,
+}
+
+pub fn big_numbers_twin_normal(value: BigNumbersTwinNormal) -> BigNumbersTwinNormal {
+    value
+}
@@ -2,7 +2,7 @@
 
 // FRB_INTERNAL_GENERATOR: {"forbiddenDuplicatorModes": ["sync", "rustAsync", "sse", "sync sse", "rustAsync sse"]}
 
-use flutter_rust_bridge::frb;
+use flutter_rust_bridge::{frb, DartFnFuture};
 
 pub trait SimpleTraitForDynTwinNormal {
     #[frb(serialize)]
@@ -51,3 +51,55 @@ impl SimpleTraitForDynTwinNormal for StructTwoWithTraitForDynTwinNormal {
 pub fn func_arg_dyn_trait_twin_normal(arg: &dyn SimpleTraitForDynTwinNormal) -> i32 {
     arg.simple_method_twin_normal()
 }
+
+#[frb(dart_implementable)]
+pub trait DartImplementableTraitTwinNormal: Send + Sync {
+    #[frb(serialize)]
+    fn add_twin_normal(&self, a: i32, b: i32) -> i32;
+
+    #[frb(serialize)]
+    fn describe_twin_normal(&self, value: i32) -> DartFnFuture<String>;
+}
+
+#[frb(opaque)]
+pub struct StructWithDartImplementableTraitTwinNormal {
+    pub base: i32,
+}
+
+impl StructWithDartImplementableTraitTwinNormal {
+    #[frb(serialize, sync)]
+    pub fn new(base: i32) -> Self {
+        Self { base }
+    }
+}
+
+impl DartImplementableTraitTwinNormal for StructWithDartImplementableTraitTwinNormal {
+    #[frb(serialize)]
+    fn add_twin_normal(&self, a: i32, b: i32) -> i32 {
+        self.base + a + b
+    }
+
+    #[frb(serialize)]
+    fn describe_twin_normal(&self, value: i32) -> DartFnFuture<String> {
+        let base = self.base;
+        Box::pin(async move { format!("Rust({base}, {value})") })
+    }
+}
+
+// Not async, since the synchronous method blocks the current thread until Dart returns
+#[frb(serialize)]
+pub fn func_arg_box_dyn_dart_implementable_sync_twin_normal(
+    arg: Box<dyn DartImplementableTraitTwinNormal>,
+    a: i32,
+    b: i32,
+) -> i32 {
+    arg.add_twin_normal(a, b)
+}
+
+#[frb(serialize)]
+pub async fn func_arg_box_dyn_dart_implementable_async_twin_normal(
+    arg: Box<dyn DartImplementableTraitTwinNormal>,
+    value: i32,
+) -> String {
+    arg.describe_twin_normal(value).await
+}
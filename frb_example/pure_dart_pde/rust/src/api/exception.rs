@@ -237,3 +237,44 @@ pub fn panic_with_custom_result_twin_normal() -> Result<(), CustomErrorTwinNorma
 pub fn stream_sink_throw_anyhow_twin_normal(_sink: StreamSink<String>) -> Result<()> {
     Err(anyhow!("anyhow error"))
 }
+
+#[frb(typed_exceptions)]
+pub enum TypedExceptionErrorTwinNormal {
+    NotFound { path: String },
+    Io(String),
+    Unknown,
+}
+
+impl std::fmt::Display for TypedExceptionErrorTwinNormal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotFound { path } => write!(f, "not found: {path}"),
+            Self::Io(message) => write!(f, "io: {message}"),
+            Self::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+pub fn typed_exception_return_error_twin_normal(
+    variant: i32,
+) -> Result<i32, TypedExceptionErrorTwinNormal> {
+    match variant {
+        0 => Err(TypedExceptionErrorTwinNormal::NotFound {
+            path: "/tmp/a.txt".to_owned(),
+        }),
+        1 => Err(TypedExceptionErrorTwinNormal::Io("disk full".to_owned())),
+        2 => Err(TypedExceptionErrorTwinNormal::Unknown),
+        _ => Ok(variant),
+    }
+}
+
+pub fn throw_anyhow_with_context_twin_normal() -> Result<()> {
+    Err(anyhow!("root cause")
+        .context("middle context")
+        .context("outer context"))
+}
+
+pub fn throw_anyhow_io_error_twin_normal() -> Result<()> {
+    let error = std::io::Error::new(std::io::ErrorKind::NotFound, "missing file");
+    Err(anyhow::Error::new(error).context("reading config"))
+}
@@ -2,7 +2,7 @@
 
 use crate::api::enumeration::{EnumSimpleTwinNormal, KitchenSinkTwinNormal};
 use crate::auxiliary::sample_types::MySize;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
 
 pub fn func_hash_map_i32_i32_twin_normal(arg: HashMap<i32, i32>) -> HashMap<i32, i32> {
     arg
@@ -45,3 +45,29 @@ pub fn func_hash_map_string_complex_enum_twin_normal(
 ) -> HashMap<String, KitchenSinkTwinNormal> {
     arg
 }
+
+pub fn func_btree_map_i32_i32_twin_normal(arg: BTreeMap<i32, i32>) -> BTreeMap<i32, i32> {
+    arg
+}
+
+pub fn func_btree_set_i32_twin_normal(arg: BTreeSet<i32>) -> BTreeSet<i32> {
+    arg
+}
+
+pub fn func_btree_map_string_struct_twin_normal(
+    arg: BTreeMap<String, MySize>,
+) -> BTreeMap<String, MySize> {
+    arg
+}
+
+pub fn func_btree_set_string_twin_normal(arg: BTreeSet<String>) -> BTreeSet<String> {
+    arg
+}
+
+pub fn func_vec_deque_i32_twin_normal(arg: VecDeque<i32>) -> VecDeque<i32> {
+    arg
+}
+
+pub fn func_vec_deque_string_twin_normal(arg: VecDeque<String>) -> VecDeque<String> {
+    arg
+}
@@ -0,0 +1,59 @@
+// NOTE: This file is mimicking how a human developer writes tests,
+// and is auto-generated from `big_number.rs` by frb_internal
+// Please do not modify manually, but modify the origin and re-run frb_internal generator
+
+// AUTO-GENERATED FROM frb_example/pure_dart, DO NOT EDIT
+
+use num_bigint::BigInt;
+use rust_decimal::Decimal;
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn decimal_twin_sync(d: Decimal) -> Decimal {
+    d
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn add_decimals_twin_sync(a: Decimal, b: Decimal) -> Decimal {
+    a + b
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn optional_decimal_twin_sync(d: Option<Decimal>) -> Option<Decimal> {
+    d
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn decimals_twin_sync(decimals: Vec<Decimal>) -> Vec<Decimal> {
+    decimals
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn big_int_twin_sync(i: BigInt) -> BigInt {
+    i
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn multiply_big_ints_twin_sync(a: BigInt, b: BigInt) -> BigInt {
+    a * b
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn optional_big_int_twin_sync(i: Option<BigInt>) -> Option<BigInt> {
+    i
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn big_ints_twin_sync(ints: Vec<BigInt>) -> Vec<BigInt> {
+    ints
+}
+
+pub struct BigNumbersTwinSync {
+    pub amount: Decimal,
+    pub discount: Option<Decimal>,
+    pub serial: BigInt,
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn big_numbers_twin_sync(value: BigNumbersTwinSync) -> BigNumbersTwinSync {
+    value
+}
@@ -159,3 +159,60 @@ pub fn how_long_does_it_take_twin_sync(
     assert_eq!(&mine.naive.nanosecond(), &123_456_000);
     Ok(difference)
 }
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn naive_date_twin_sync(d: chrono::NaiveDate) -> chrono::NaiveDate {
+    assert_eq!(d, chrono::NaiveDate::from_ymd_opt(2022, 9, 10).unwrap());
+    d
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn naive_time_twin_sync(d: chrono::NaiveTime) -> chrono::NaiveTime {
+    assert_eq!(
+        d,
+        chrono::NaiveTime::from_hms_milli_opt(20, 48, 53, 123).unwrap()
+    );
+    d
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn datetime_fixed_offset_twin_sync(
+    d: chrono::DateTime<chrono::FixedOffset>,
+) -> chrono::DateTime<chrono::FixedOffset> {
+    use chrono::Timelike;
+    assert_eq!(d.offset().local_minus_utc(), 8 * 3600);
+    assert_eq!(d.hour(), 20);
+    assert_eq!(d.minute(), 48);
+    d
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn optional_datetime_fixed_offset_twin_sync(
+    d: Option<chrono::DateTime<chrono::FixedOffset>>,
+) -> Option<chrono::DateTime<chrono::FixedOffset>> {
+    d
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn naive_dates_twin_sync(dates: Vec<chrono::NaiveDate>) -> Vec<chrono::NaiveDate> {
+    dates
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn datetime_fixed_offsets_twin_sync(
+    dates: Vec<chrono::DateTime<chrono::FixedOffset>>,
+) -> Vec<chrono::DateTime<chrono::FixedOffset>> {
+    dates
+}
+
+pub struct ScheduleTwinSync {
+    pub day: chrono::NaiveDate,
+    pub starts: chrono::NaiveTime,
+    pub ends: Option<chrono::NaiveTime>,
+    pub at: chrono::DateTime<chrono::FixedOffset>,
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn schedule_twin_sync(schedule: ScheduleTwinSync) -> ScheduleTwinSync {
+    schedule
+}
@@ -252,3 +252,44 @@ pub async fn panic_with_custom_result_twin_rust_async() -> Result<(), CustomErro
 pub async fn stream_sink_throw_anyhow_twin_rust_async(_sink: StreamSink<String>) -> Result<()> {
     Err(anyhow!("anyhow error"))
 }
+
+#[frb(typed_exceptions)]
+pub enum TypedExceptionErrorTwinRustAsync {
+    NotFound { path: String },
+    Io(String),
+    Unknown,
+}
+
+impl std::fmt::Display for TypedExceptionErrorTwinRustAsync {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotFound { path } => write!(f, "not found: {path}"),
+            Self::Io(message) => write!(f, "io: {message}"),
+            Self::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+pub async fn typed_exception_return_error_twin_rust_async(
+    variant: i32,
+) -> Result<i32, TypedExceptionErrorTwinRustAsync> {
+    match variant {
+        0 => Err(TypedExceptionErrorTwinRustAsync::NotFound {
+            path: "/tmp/a.txt".to_owned(),
+        }),
+        1 => Err(TypedExceptionErrorTwinRustAsync::Io("disk full".to_owned())),
+        2 => Err(TypedExceptionErrorTwinRustAsync::Unknown),
+        _ => Ok(variant),
+    }
+}
+
+pub async fn throw_anyhow_with_context_twin_rust_async() -> Result<()> {
+    Err(anyhow!("root cause")
+        .context("middle context")
+        .context("outer context"))
+}
+
+pub async fn throw_anyhow_io_error_twin_rust_async() -> Result<()> {
+    let error = std::io::Error::new(std::io::ErrorKind::NotFound, "missing file");
+    Err(anyhow::Error::new(error).context("reading config"))
+}
@@ -18,6 +18,8 @@ pub mod basic_twin_sync;
 pub mod benchmark_api;
 pub mod benchmark_api_twin_rust_async;
 pub mod benchmark_api_twin_sync;
+pub mod big_number_twin_rust_async;
+pub mod big_number_twin_sync;
 pub mod chrono_type_twin_rust_async;
 pub mod chrono_type_twin_sync;
 pub mod comment_twin_rust_async;
@@ -35,6 +37,8 @@ pub mod exception_twin_sync;
 pub mod external_type_in_crate_twin_rust_async;
 pub mod external_type_in_crate_twin_sync;
 pub mod impl_trait_twin_sync;
+pub mod json_type_twin_rust_async;
+pub mod json_type_twin_sync;
 pub mod lifetimeable_twin_sync;
 pub mod map_and_set_twin_rust_async;
 pub mod map_and_set_twin_sync;
@@ -66,9 +70,13 @@ pub mod rust_opaque_twin_rust_async;
 pub mod rust_opaque_twin_sync;
 pub mod simple_twin_rust_async;
 pub mod simple_twin_sync;
+pub mod std_time_twin_rust_async;
+pub mod std_time_twin_sync;
 pub mod stream_twin_rust_async;
 pub mod structure_twin_rust_async;
 pub mod structure_twin_sync;
+pub mod time_type_twin_rust_async;
+pub mod time_type_twin_sync;
 pub mod tuple_twin_rust_async;
 pub mod tuple_twin_sync;
 pub mod type_alias_twin_rust_async;
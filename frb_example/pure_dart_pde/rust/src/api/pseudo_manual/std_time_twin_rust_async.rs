@@ -0,0 +1,42 @@
+// NOTE: This file is mimicking how a human developer writes tests,
+// and is auto-generated from `std_time.rs` by frb_internal
+// Please do not modify manually, but modify the origin and re-run frb_internal generator
+
+// AUTO-GENERATED FROM frb_example/pure_dart, DO NOT EDIT
+
+use std::time::{Duration, SystemTime};
+
+#[derive(Debug, Clone)]
+pub struct StdTimeTwinRustAsync {
+    pub duration: Duration,
+    pub time: SystemTime,
+    pub optional_duration: Option<Duration>,
+}
+
+pub async fn std_duration_twin_rust_async(d: Duration) -> Duration {
+    d
+}
+
+pub async fn std_system_time_twin_rust_async(t: SystemTime) -> SystemTime {
+    t
+}
+
+pub async fn std_durations_twin_rust_async(durations: Vec<Duration>) -> Vec<Duration> {
+    durations
+}
+
+pub async fn std_system_times_twin_rust_async(times: Vec<SystemTime>) -> Vec<SystemTime> {
+    times
+}
+
+pub async fn std_optional_duration_twin_rust_async(d: Option<Duration>) -> Option<Duration> {
+    d
+}
+
+pub async fn std_optional_system_time_twin_rust_async(t: Option<SystemTime>) -> Option<SystemTime> {
+    t
+}
+
+pub async fn std_time_struct_twin_rust_async(value: StdTimeTwinRustAsync) -> StdTimeTwinRustAsync {
+    value
+}
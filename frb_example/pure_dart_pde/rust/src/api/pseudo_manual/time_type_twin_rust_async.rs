@@ -0,0 +1,68 @@
+// NOTE: This file is mimicking how a human developer writes tests,
+// and is auto-generated from `time_type.rs` by frb_internal
+// Please do not modify manually, but modify the origin and re-run frb_internal generator
+
+// AUTO-GENERATED FROM frb_example/pure_dart, DO NOT EDIT
+
+use time::{Date, Duration};
+
+pub async fn offset_date_time_twin_rust_async(d: time::OffsetDateTime) -> time::OffsetDateTime {
+    assert_eq!(d.year(), 2022);
+    assert_eq!(d.month(), time::Month::September);
+    assert_eq!(d.day(), 10);
+    assert_eq!(d.hour(), 20);
+    assert_eq!(d.minute(), 48);
+    assert_eq!(d.second(), 53);
+    assert_eq!(d.millisecond(), 123);
+    assert_eq!(d.offset(), time::UtcOffset::UTC);
+    d
+}
+
+pub async fn primitive_date_time_twin_rust_async(
+    d: time::PrimitiveDateTime,
+) -> time::PrimitiveDateTime {
+    assert_eq!(d.year(), 2022);
+    assert_eq!(d.hour(), 20);
+    assert_eq!(d.millisecond(), 123);
+    d
+}
+
+pub async fn date_twin_rust_async(d: Date) -> Date {
+    assert_eq!(d.year(), 1969);
+    assert_eq!(d.month(), time::Month::July);
+    assert_eq!(d.day(), 20);
+    d
+}
+
+pub async fn time_duration_twin_rust_async(d: Duration) -> Duration {
+    d
+}
+
+pub async fn negate_time_duration_twin_rust_async(d: Duration) -> Duration {
+    -d
+}
+
+pub async fn time_durations_twin_rust_async(durations: Vec<Duration>) -> Vec<Duration> {
+    durations
+}
+
+pub async fn optional_time_duration_twin_rust_async(d: Option<Duration>) -> Option<Duration> {
+    d
+}
+
+pub async fn optional_offset_date_time_twin_rust_async(
+    d: Option<time::OffsetDateTime>,
+) -> Option<time::OffsetDateTime> {
+    d
+}
+
+pub struct TimeTypesTwinRustAsync {
+    pub offset: time::OffsetDateTime,
+    pub primitive: Option<time::PrimitiveDateTime>,
+    pub date: Date,
+    pub duration: Duration,
+}
+
+pub async fn time_types_twin_rust_async(value: TimeTypesTwinRustAsync) -> TimeTypesTwinRustAsync {
+    value
+}
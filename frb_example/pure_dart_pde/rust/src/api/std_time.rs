@@ -0,0 +1,38 @@
+// AUTO-GENERATED FROM frb_example/pure_dart, DO NOT EDIT
+
+use std::time::{Duration, SystemTime};
+
+#[derive(Debug, Clone)]
+pub struct StdTimeTwinNormal {
+    pub duration: Duration,
+    pub time: SystemTime,
+    pub optional_duration: Option<Duration>,
+}
+
+pub fn std_duration_twin_normal(d: Duration) -> Duration {
+    d
+}
+
+pub fn std_system_time_twin_normal(t: SystemTime) -> SystemTime {
+    t
+}
+
+pub fn std_durations_twin_normal(durations: Vec<Duration>) -> Vec<Duration> {
+    durations
+}
+
+pub fn std_system_times_twin_normal(times: Vec<SystemTime>) -> Vec<SystemTime> {
+    times
+}
+
+pub fn std_optional_duration_twin_normal(d: Option<Duration>) -> Option<Duration> {
+    d
+}
+
+pub fn std_optional_system_time_twin_normal(t: Option<SystemTime>) -> Option<SystemTime> {
+    t
+}
+
+pub fn std_time_struct_twin_normal(value: StdTimeTwinNormal) -> StdTimeTwinNormal {
+    value
+}
@@ -0,0 +1,62 @@
+// AUTO-GENERATED FROM frb_example/pure_dart, DO NOT EDIT
+
+use time::{Date, Duration};
+
+pub fn offset_date_time_twin_normal(d: time::OffsetDateTime) -> time::OffsetDateTime {
+    assert_eq!(d.year(), 2022);
+    assert_eq!(d.month(), time::Month::September);
+    assert_eq!(d.day(), 10);
+    assert_eq!(d.hour(), 20);
+    assert_eq!(d.minute(), 48);
+    assert_eq!(d.second(), 53);
+    assert_eq!(d.millisecond(), 123);
+    assert_eq!(d.offset(), time::UtcOffset::UTC);
+    d
+}
+
+pub fn primitive_date_time_twin_normal(d: time::PrimitiveDateTime) -> time::PrimitiveDateTime {
+    assert_eq!(d.year(), 2022);
+    assert_eq!(d.hour(), 20);
+    assert_eq!(d.millisecond(), 123);
+    d
+}
+
+pub fn date_twin_normal(d: Date) -> Date {
+    assert_eq!(d.year(), 1969);
+    assert_eq!(d.month(), time::Month::July);
+    assert_eq!(d.day(), 20);
+    d
+}
+
+pub fn time_duration_twin_normal(d: Duration) -> Duration {
+    d
+}
+
+pub fn negate_time_duration_twin_normal(d: Duration) -> Duration {
+    -d
+}
+
+pub fn time_durations_twin_normal(durations: Vec<Duration>) -> Vec<Duration> {
+    durations
+}
+
+pub fn optional_time_duration_twin_normal(d: Option<Duration>) -> Option<Duration> {
+    d
+}
+
+pub fn optional_offset_date_time_twin_normal(
+    d: Option<time::OffsetDateTime>,
+) -> Option<time::OffsetDateTime> {
+    d
+}
+
+pub struct TimeTypesTwinNormal {
+    pub offset: time::OffsetDateTime,
+    pub primitive: Option<time::PrimitiveDateTime>,
+    pub date: Date,
+    pub duration: Duration,
+}
+
+pub fn time_types_twin_normal(value: TimeTypesTwinNormal) -> TimeTypesTwinNormal {
+    value
+}
@@ -17,4 +17,42 @@ Future<void> main({bool skipRustLibInit = false}) async {
     expect(await funcArgDynTraitTwinNormal(arg: one), 10);
     expect(await funcArgDynTraitTwinNormal(arg: two), 200);
   });
+  group('support Box<dyn TraitType> implemented in Dart', () {
+    test('call sync method', () async {
+      expect(
+          await funcArgBoxDynDartImplementableSyncTwinNormal(
+              arg: _DartImplementableTraitTwinNormal(), a: 3, b: 4),
+          12);
+    });
+
+    test('call async method', () async {
+      expect(
+          await funcArgBoxDynDartImplementableAsyncTwinNormal(
+              arg: _DartImplementableTraitTwinNormal(), value: 42),
+          'Dart(42)');
+    });
+
+    test('pass Rust implementor still referenced by Dart', () async {
+      final obj = StructWithDartImplementableTraitTwinNormal(base: 10);
+      expect(
+          await funcArgBoxDynDartImplementableSyncTwinNormal(
+              arg: obj, a: 1, b: 2),
+          13);
+      expect(
+          await funcArgBoxDynDartImplementableAsyncTwinNormal(
+              arg: obj, value: 42),
+          'Rust(10, 42)');
+      expect(obj.base, 10);
+    });
+  });
+}
+
+class _DartImplementableTraitTwinNormal
+    implements DartImplementableTraitTwinNormal {
+  @override
+  Future<int> addTwinNormal({required int a, required int b}) async => a * b;
+
+  @override
+  Future<String> describeTwinNormal({required int value}) async =>
+      'Dart($value)';
 }
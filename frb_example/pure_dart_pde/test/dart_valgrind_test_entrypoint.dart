@@ -11,8 +11,10 @@ import 'api/array_test.dart' as array_test;
 import 'api/async_misc_test.dart' as async_misc_test;
 import 'api/async_spawn_test.dart' as async_spawn_test;
 import 'api/attribute_test.dart' as attribute_test;
+import 'api/big_number_test.dart' as big_number_test;
 import 'api/casted_primitive_test.dart' as casted_primitive_test;
 import 'api/chrono_type_test.dart' as chrono_type_test;
+import 'api/chrono_tz_type_test.dart' as chrono_tz_type_test;
 import 'api/comment_test.dart' as comment_test;
 import 'api/constructor_test.dart' as constructor_test;
 import 'api/custom_ser_des_test.dart' as custom_ser_des_test;
@@ -29,6 +31,7 @@ import 'api/external_impl_test.dart' as external_impl_test;
 import 'api/external_type_in_crate_test.dart' as external_type_in_crate_test;
 import 'api/impl_trait_test.dart' as impl_trait_test;
 import 'api/inside_macro_test.dart' as inside_macro_test;
+import 'api/json_type_test.dart' as json_type_test;
 import 'api/lifetimeable_test.dart' as lifetimeable_test;
 import 'api/map_and_set_test.dart' as map_and_set_test;
 import 'api/method_test.dart' as method_test;
@@ -70,6 +73,10 @@ import 'api/pseudo_manual/basic_test.dart' as basic_test;
 import 'api/pseudo_manual/basic_twin_rust_async_test.dart'
     as basic_twin_rust_async_test;
 import 'api/pseudo_manual/basic_twin_sync_test.dart' as basic_twin_sync_test;
+import 'api/pseudo_manual/big_number_twin_rust_async_test.dart'
+    as big_number_twin_rust_async_test;
+import 'api/pseudo_manual/big_number_twin_sync_test.dart'
+    as big_number_twin_sync_test;
 import 'api/pseudo_manual/chrono_type_twin_rust_async_test.dart'
     as chrono_type_twin_rust_async_test;
 import 'api/pseudo_manual/chrono_type_twin_sync_test.dart'
@@ -100,6 +107,10 @@ import 'api/pseudo_manual/external_type_in_crate_twin_sync_test.dart'
     as external_type_in_crate_twin_sync_test;
 import 'api/pseudo_manual/impl_trait_twin_sync_test.dart'
     as impl_trait_twin_sync_test;
+import 'api/pseudo_manual/json_type_twin_rust_async_test.dart'
+    as json_type_twin_rust_async_test;
+import 'api/pseudo_manual/json_type_twin_sync_test.dart'
+    as json_type_twin_sync_test;
 import 'api/pseudo_manual/lifetimeable_twin_sync_test.dart'
     as lifetimeable_twin_sync_test;
 import 'api/pseudo_manual/map_and_set_twin_rust_async_test.dart'
@@ -159,12 +170,20 @@ import 'api/pseudo_manual/rust_opaque_twin_sync_test.dart'
 import 'api/pseudo_manual/simple_twin_rust_async_test.dart'
     as simple_twin_rust_async_test;
 import 'api/pseudo_manual/simple_twin_sync_test.dart' as simple_twin_sync_test;
+import 'api/pseudo_manual/std_time_twin_rust_async_test.dart'
+    as std_time_twin_rust_async_test;
+import 'api/pseudo_manual/std_time_twin_sync_test.dart'
+    as std_time_twin_sync_test;
 import 'api/pseudo_manual/stream_twin_rust_async_test.dart'
     as stream_twin_rust_async_test;
 import 'api/pseudo_manual/structure_twin_rust_async_test.dart'
     as structure_twin_rust_async_test;
 import 'api/pseudo_manual/structure_twin_sync_test.dart'
     as structure_twin_sync_test;
+import 'api/pseudo_manual/time_type_twin_rust_async_test.dart'
+    as time_type_twin_rust_async_test;
+import 'api/pseudo_manual/time_type_twin_sync_test.dart'
+    as time_type_twin_sync_test;
 import 'api/pseudo_manual/tuple_twin_rust_async_test.dart'
     as tuple_twin_rust_async_test;
 import 'api/pseudo_manual/tuple_twin_sync_test.dart' as tuple_twin_sync_test;
@@ -181,9 +200,11 @@ import 'api/rust_auto_opaque_test.dart' as rust_auto_opaque_test;
 import 'api/rust_opaque_sync_test.dart' as rust_opaque_sync_test;
 import 'api/rust_opaque_test.dart' as rust_opaque_test;
 import 'api/simple_test.dart' as simple_test;
+import 'api/std_time_test.dart' as std_time_test;
 import 'api/stream_misc_test.dart' as stream_misc_test;
 import 'api/stream_test.dart' as stream_test;
 import 'api/structure_test.dart' as structure_test;
+import 'api/time_type_test.dart' as time_type_test;
 import 'api/tuple_test.dart' as tuple_test;
 import 'api/type_alias_test.dart' as type_alias_test;
 import 'api/uuid_type_test.dart' as uuid_type_test;
@@ -210,8 +231,10 @@ Future<void> callFileEntrypoints() async {
   await async_misc_test.main(skipRustLibInit: true);
   await async_spawn_test.main(skipRustLibInit: true);
   await attribute_test.main(skipRustLibInit: true);
+  await big_number_test.main(skipRustLibInit: true);
   await casted_primitive_test.main(skipRustLibInit: true);
   await chrono_type_test.main(skipRustLibInit: true);
+  await chrono_tz_type_test.main(skipRustLibInit: true);
   await comment_test.main(skipRustLibInit: true);
   await constructor_test.main(skipRustLibInit: true);
   await custom_ser_des_test.main(skipRustLibInit: true);
@@ -228,6 +251,7 @@ Future<void> callFileEntrypoints() async {
   await external_type_in_crate_test.main(skipRustLibInit: true);
   await impl_trait_test.main(skipRustLibInit: true);
   await inside_macro_test.main(skipRustLibInit: true);
+  await json_type_test.main(skipRustLibInit: true);
   await lifetimeable_test.main(skipRustLibInit: true);
   await map_and_set_test.main(skipRustLibInit: true);
   await method_test.main(skipRustLibInit: true);
@@ -259,6 +283,8 @@ Future<void> callFileEntrypoints() async {
   await basic_test.main(skipRustLibInit: true);
   await basic_twin_rust_async_test.main(skipRustLibInit: true);
   await basic_twin_sync_test.main(skipRustLibInit: true);
+  await big_number_twin_rust_async_test.main(skipRustLibInit: true);
+  await big_number_twin_sync_test.main(skipRustLibInit: true);
   await chrono_type_twin_rust_async_test.main(skipRustLibInit: true);
   await chrono_type_twin_sync_test.main(skipRustLibInit: true);
   await comment_twin_rust_async_test.main(skipRustLibInit: true);
@@ -274,6 +300,8 @@ Future<void> callFileEntrypoints() async {
   await external_type_in_crate_twin_rust_async_test.main(skipRustLibInit: true);
   await external_type_in_crate_twin_sync_test.main(skipRustLibInit: true);
   await impl_trait_twin_sync_test.main(skipRustLibInit: true);
+  await json_type_twin_rust_async_test.main(skipRustLibInit: true);
+  await json_type_twin_sync_test.main(skipRustLibInit: true);
   await lifetimeable_twin_sync_test.main(skipRustLibInit: true);
   await map_and_set_twin_rust_async_test.main(skipRustLibInit: true);
   await map_and_set_twin_sync_test.main(skipRustLibInit: true);
@@ -306,9 +334,13 @@ Future<void> callFileEntrypoints() async {
   await rust_opaque_twin_sync_test.main(skipRustLibInit: true);
   await simple_twin_rust_async_test.main(skipRustLibInit: true);
   await simple_twin_sync_test.main(skipRustLibInit: true);
+  await std_time_twin_rust_async_test.main(skipRustLibInit: true);
+  await std_time_twin_sync_test.main(skipRustLibInit: true);
   await stream_twin_rust_async_test.main(skipRustLibInit: true);
   await structure_twin_rust_async_test.main(skipRustLibInit: true);
   await structure_twin_sync_test.main(skipRustLibInit: true);
+  await time_type_twin_rust_async_test.main(skipRustLibInit: true);
+  await time_type_twin_sync_test.main(skipRustLibInit: true);
   await tuple_twin_rust_async_test.main(skipRustLibInit: true);
   await tuple_twin_sync_test.main(skipRustLibInit: true);
   await type_alias_twin_rust_async_test.main(skipRustLibInit: true);
@@ -320,9 +352,11 @@ Future<void> callFileEntrypoints() async {
   await rust_opaque_sync_test.main(skipRustLibInit: true);
   await rust_opaque_test.main(skipRustLibInit: true);
   await simple_test.main(skipRustLibInit: true);
+  await std_time_test.main(skipRustLibInit: true);
   await stream_misc_test.main(skipRustLibInit: true);
   await stream_test.main(skipRustLibInit: true);
   await structure_test.main(skipRustLibInit: true);
+  await time_type_test.main(skipRustLibInit: true);
   await tuple_test.main(skipRustLibInit: true);
   await type_alias_test.main(skipRustLibInit: true);
   await uuid_type_test.main(skipRustLibInit: true);
@@ -56,6 +56,7 @@ fn wire__crate__app__RustState_auto_accessor_get_count_impl(
             debug_name: "RustState_auto_accessor_get_count",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -81,7 +82,14 @@ fn wire__crate__app__RustState_auto_accessor_get_count_impl(
                     ]);
                 for i in decode_indices_ {
                     match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        0 => {
+                            api_that_guard =
+                                Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                    "RustState_auto_accessor_get_count",
+                                    "that",
+                                    || api_that.lockable_decode_sync_ref(),
+                                ))
+                        }
                         _ => unreachable!(),
                     }
                 }
@@ -102,6 +110,7 @@ fn wire__crate__app__RustState_auto_accessor_set_count_impl(
             debug_name: "RustState_auto_accessor_set_count",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -128,7 +137,14 @@ fn wire__crate__app__RustState_auto_accessor_set_count_impl(
                     ]);
                 for i in decode_indices_ {
                     match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                        0 => {
+                            api_that_guard =
+                                Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                    "RustState_auto_accessor_set_count",
+                                    "that",
+                                    || api_that.lockable_decode_sync_ref_mut(),
+                                ))
+                        }
                         _ => unreachable!(),
                     }
                 }
@@ -154,6 +170,7 @@ fn wire__crate__app__RustState_increment_impl(
             debug_name: "RustState_increment",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -179,7 +196,14 @@ fn wire__crate__app__RustState_increment_impl(
                     ]);
                 for i in decode_indices_ {
                     match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                        0 => {
+                            api_that_guard =
+                                Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                    "RustState_increment",
+                                    "that",
+                                    || api_that.lockable_decode_sync_ref_mut(),
+                                ))
+                        }
                         _ => unreachable!(),
                     }
                 }
@@ -203,6 +227,7 @@ fn wire__crate__app__RustState_new_impl(
             debug_name: "RustState_new",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -232,6 +257,7 @@ fn wire__crate__app__RustState_set_base_state_impl(
             debug_name: "RustState_set_base_state",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -258,7 +284,14 @@ fn wire__crate__app__RustState_set_base_state_impl(
                     ]);
                 for i in decode_indices_ {
                     match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                        0 => {
+                            api_that_guard =
+                                Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                    "RustState_set_base_state",
+                                    "that",
+                                    || api_that.lockable_decode_sync_ref_mut(),
+                                ))
+                        }
                         _ => unreachable!(),
                     }
                 }
@@ -281,6 +314,7 @@ fn wire__crate__frb_generated__BaseRustState_create_notify_ui_stream_impl(
             debug_name: "BaseRustState_create_notify_ui_stream",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -310,7 +344,14 @@ fn wire__crate__frb_generated__BaseRustState_create_notify_ui_stream_impl(
                     ]);
                 for i in decode_indices_ {
                     match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                        0 => {
+                            api_that_guard =
+                                Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                    "BaseRustState_create_notify_ui_stream",
+                                    "that",
+                                    || api_that.lockable_decode_sync_ref_mut(),
+                                ))
+                        }
                         _ => unreachable!(),
                     }
                 }
@@ -336,6 +377,7 @@ fn wire__crate__frb_generated__BaseRustState_empty_impl(
             debug_name: "BaseRustState_empty",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -402,8 +444,8 @@ impl BaseRustState {
 impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <String>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
+        let mut inner = <Vec<String>>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::anyhow_error_decode(inner);
     }
 }
 
@@ -466,22 +508,30 @@ impl SseDecode for String {
 impl SseDecode for i32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+        deserializer.read_i32()
     }
 }
 
-impl SseDecode for Vec<u8> {
+impl SseDecode for Vec<String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = vec![];
         for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
+            ans_.push(<String>::sse_decode(deserializer));
         }
         return ans_;
     }
 }
 
+impl SseDecode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        return deserializer.decode_u8_list(len_);
+    }
+}
+
 impl SseDecode for u8 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -497,7 +547,7 @@ impl SseDecode for () {
 impl SseDecode for usize {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u64::<NativeEndian>().unwrap() as _
+        deserializer.read_u64() as _
     }
 }
 
@@ -579,7 +629,10 @@ impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<RustState>> for RustState {
 impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(format!("{:?}", self), serializer);
+        <Vec<String>>::sse_encode(
+            flutter_rust_bridge::for_generated::anyhow_error_encode(self),
+            serializer,
+        );
     }
 }
 
@@ -636,7 +689,17 @@ impl SseEncode for String {
 impl SseEncode for i32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+        serializer.write_i32(self);
+    }
+}
+
+impl SseEncode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <String>::sse_encode(item, serializer);
+        }
     }
 }
 
@@ -665,10 +728,7 @@ impl SseEncode for () {
 impl SseEncode for usize {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer
-            .cursor
-            .write_u64::<NativeEndian>(self as _)
-            .unwrap();
+        serializer.write_u64(self as _);
     }
 }
 
@@ -56,6 +56,7 @@ fn wire__crate__app__RustState_add_impl(
             debug_name: "RustState_add",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -81,7 +82,14 @@ fn wire__crate__app__RustState_add_impl(
                     ]);
                 for i in decode_indices_ {
                     match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                        0 => {
+                            api_that_guard =
+                                Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                    "RustState_add",
+                                    "that",
+                                    || api_that.lockable_decode_sync_ref_mut(),
+                                ))
+                        }
                         _ => unreachable!(),
                     }
                 }
@@ -105,6 +113,7 @@ fn wire__crate__app__RustState_auto_accessor_get_filter_impl(
             debug_name: "RustState_auto_accessor_get_filter",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -130,7 +139,14 @@ fn wire__crate__app__RustState_auto_accessor_get_filter_impl(
                     ]);
                 for i in decode_indices_ {
                     match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        0 => {
+                            api_that_guard =
+                                Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                    "RustState_auto_accessor_get_filter",
+                                    "that",
+                                    || api_that.lockable_decode_sync_ref(),
+                                ))
+                        }
                         _ => unreachable!(),
                     }
                 }
@@ -151,6 +167,7 @@ fn wire__crate__app__RustState_auto_accessor_get_input_text_impl(
             debug_name: "RustState_auto_accessor_get_input_text",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -176,7 +193,14 @@ fn wire__crate__app__RustState_auto_accessor_get_input_text_impl(
                     ]);
                 for i in decode_indices_ {
                     match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        0 => {
+                            api_that_guard =
+                                Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                    "RustState_auto_accessor_get_input_text",
+                                    "that",
+                                    || api_that.lockable_decode_sync_ref(),
+                                ))
+                        }
                         _ => unreachable!(),
                     }
                 }
@@ -197,6 +221,7 @@ fn wire__crate__app__RustState_auto_accessor_set_filter_impl(
             debug_name: "RustState_auto_accessor_set_filter",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -223,7 +248,14 @@ fn wire__crate__app__RustState_auto_accessor_set_filter_impl(
                     ]);
                 for i in decode_indices_ {
                     match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                        0 => {
+                            api_that_guard =
+                                Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                    "RustState_auto_accessor_set_filter",
+                                    "that",
+                                    || api_that.lockable_decode_sync_ref_mut(),
+                                ))
+                        }
                         _ => unreachable!(),
                     }
                 }
@@ -249,6 +281,7 @@ fn wire__crate__app__RustState_auto_accessor_set_input_text_impl(
             debug_name: "RustState_auto_accessor_set_input_text",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -275,7 +308,14 @@ fn wire__crate__app__RustState_auto_accessor_set_input_text_impl(
                     ]);
                 for i in decode_indices_ {
                     match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                        0 => {
+                            api_that_guard =
+                                Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                    "RustState_auto_accessor_set_input_text",
+                                    "that",
+                                    || api_that.lockable_decode_sync_ref_mut(),
+                                ))
+                        }
                         _ => unreachable!(),
                     }
                 }
@@ -301,6 +341,7 @@ fn wire__crate__app__RustState_filtered_items_impl(
             debug_name: "RustState_filtered_items",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -326,7 +367,14 @@ fn wire__crate__app__RustState_filtered_items_impl(
                     ]);
                 for i in decode_indices_ {
                     match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        0 => {
+                            api_that_guard =
+                                Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                    "RustState_filtered_items",
+                                    "that",
+                                    || api_that.lockable_decode_sync_ref(),
+                                ))
+                        }
                         _ => unreachable!(),
                     }
                 }
@@ -348,6 +396,7 @@ fn wire__crate__app__RustState_new_impl(
             debug_name: "RustState_new",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -377,6 +426,7 @@ fn wire__crate__app__RustState_remove_impl(
             debug_name: "RustState_remove",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -403,7 +453,14 @@ fn wire__crate__app__RustState_remove_impl(
                     ]);
                 for i in decode_indices_ {
                     match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                        0 => {
+                            api_that_guard =
+                                Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                    "RustState_remove",
+                                    "that",
+                                    || api_that.lockable_decode_sync_ref_mut(),
+                                ))
+                        }
                         _ => unreachable!(),
                     }
                 }
@@ -427,6 +484,7 @@ fn wire__crate__app__RustState_set_base_state_impl(
             debug_name: "RustState_set_base_state",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -453,7 +511,14 @@ fn wire__crate__app__RustState_set_base_state_impl(
                     ]);
                 for i in decode_indices_ {
                     match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                        0 => {
+                            api_that_guard =
+                                Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                    "RustState_set_base_state",
+                                    "that",
+                                    || api_that.lockable_decode_sync_ref_mut(),
+                                ))
+                        }
                         _ => unreachable!(),
                     }
                 }
@@ -476,6 +541,7 @@ fn wire__crate__app__RustState_toggle_impl(
             debug_name: "RustState_toggle",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -502,7 +568,14 @@ fn wire__crate__app__RustState_toggle_impl(
                     ]);
                 for i in decode_indices_ {
                     match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                        0 => {
+                            api_that_guard =
+                                Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                    "RustState_toggle",
+                                    "that",
+                                    || api_that.lockable_decode_sync_ref_mut(),
+                                ))
+                        }
                         _ => unreachable!(),
                     }
                 }
@@ -526,6 +599,7 @@ fn wire__crate__frb_generated__BaseRustState_create_notify_ui_stream_impl(
             debug_name: "BaseRustState_create_notify_ui_stream",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -555,7 +629,14 @@ fn wire__crate__frb_generated__BaseRustState_create_notify_ui_stream_impl(
                     ]);
                 for i in decode_indices_ {
                     match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                        0 => {
+                            api_that_guard =
+                                Some(flutter_rust_bridge::for_generated::lockable_with_site(
+                                    "BaseRustState_create_notify_ui_stream",
+                                    "that",
+                                    || api_that.lockable_decode_sync_ref_mut(),
+                                ))
+                        }
                         _ => unreachable!(),
                     }
                 }
@@ -581,6 +662,7 @@ fn wire__crate__frb_generated__BaseRustState_empty_impl(
             debug_name: "BaseRustState_empty",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            executor: None,
         },
         move || {
             let message = unsafe {
@@ -647,8 +729,8 @@ impl BaseRustState {
 impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <String>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
+        let mut inner = <Vec<String>>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::anyhow_error_decode(inner);
     }
 }
 
@@ -731,7 +813,7 @@ impl SseDecode for crate::app::Filter {
 impl SseDecode for i32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+        deserializer.read_i32()
     }
 }
 
@@ -749,30 +831,38 @@ impl SseDecode for crate::app::Item {
     }
 }
 
-impl SseDecode for Vec<crate::app::Item> {
+impl SseDecode for Vec<String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = vec![];
         for idx_ in 0..len_ {
-            ans_.push(<crate::app::Item>::sse_decode(deserializer));
+            ans_.push(<String>::sse_decode(deserializer));
         }
         return ans_;
     }
 }
 
-impl SseDecode for Vec<u8> {
+impl SseDecode for Vec<crate::app::Item> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = vec![];
         for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
+            ans_.push(<crate::app::Item>::sse_decode(deserializer));
         }
         return ans_;
     }
 }
 
+impl SseDecode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        return deserializer.decode_u8_list(len_);
+    }
+}
+
 impl SseDecode for u8 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -788,7 +878,7 @@ impl SseDecode for () {
 impl SseDecode for usize {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u64::<NativeEndian>().unwrap() as _
+        deserializer.read_u64() as _
     }
 }
 
@@ -911,7 +1001,10 @@ impl flutter_rust_bridge::IntoIntoDart<crate::app::Item> for crate::app::Item {
 impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(format!("{:?}", self), serializer);
+        <Vec<String>>::sse_encode(
+            flutter_rust_bridge::for_generated::anyhow_error_encode(self),
+            serializer,
+        );
     }
 }
 
@@ -992,7 +1085,7 @@ impl SseEncode for crate::app::Filter {
 impl SseEncode for i32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+        serializer.write_i32(self);
     }
 }
 
@@ -1005,6 +1098,16 @@ impl SseEncode for crate::app::Item {
     }
 }
 
+impl SseEncode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <String>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<crate::app::Item> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1040,10 +1143,7 @@ impl SseEncode for () {
 impl SseEncode for usize {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer
-            .cursor
-            .write_u64::<NativeEndian>(self as _)
-            .unwrap();
+        serializer.write_u64(self as _);
     }
 }
 
@@ -0,0 +1,147 @@
+use super::sse::{Rust2DartMessageSse, SseSerializer};
+use super::BaseCodec;
+use crate::rust2dart::action::Rust2DartAction;
+use byteorder::{ReadBytesExt, WriteBytesExt};
+use std::any::Any;
+use std::backtrace::Backtrace;
+use std::io::Cursor;
+
+/// Codec that does a compact serialization.
+///
+/// It shares the generated encoders and decoders with [`SseCodec`](crate::for_generated::SseCodec),
+/// but multi-byte integers (including lengths and enum tags) are written as LEB128 varints
+/// (zigzag-encoded for signed integers) instead of fixed-width bytes, and primitive lists of them
+/// are packed as varints as well.
+/// Thus payloads with many small integers or short strings are much smaller.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CseCodec;
+
+impl BaseCodec for CseCodec {
+    type Message = Rust2DartMessageSse;
+
+    fn encode_panic(error: &Box<dyn Any + Send>, backtrace: &Option<Backtrace>) -> Self::Message {
+        SseSerializer::new_compact().into_panic_message(error, backtrace)
+    }
+
+    fn encode_close_stream() -> Self::Message {
+        Self::encode(Rust2DartAction::CloseStream, |_| {})
+    }
+}
+
+impl CseCodec {
+    // Only to be used by generated code, thus hidden in doc
+    #[doc(hidden)]
+    pub fn encode(
+        result_code: Rust2DartAction,
+        data_fn: impl FnOnce(&mut SseSerializer),
+    ) -> Rust2DartMessageSse {
+        SseSerializer::new_compact().into_message(result_code, data_fn)
+    }
+}
+
+pub(crate) fn write_varint(cursor: &mut Cursor<Vec<u8>>, mut value: u64) {
+    while value >= 0x80 {
+        cursor.write_u8((value as u8) | 0x80).unwrap();
+        value >>= 7;
+    }
+    cursor.write_u8(value as u8).unwrap();
+}
+
+pub(crate) fn read_varint(cursor: &mut Cursor<Vec<u8>>) -> u64 {
+    let mut ans = 0u64;
+    for shift in (0..64).step_by(7) {
+        let byte = cursor.read_u8().unwrap();
+        ans |= ((byte & 0x7f) as u64) << shift;
+        if byte < 0x80 {
+            return ans;
+        }
+    }
+    panic!("varint is too long")
+}
+
+pub(crate) fn write_signed_varint(cursor: &mut Cursor<Vec<u8>>, value: i64) {
+    write_varint(cursor, ((value << 1) ^ (value >> 63)) as u64);
+}
+
+pub(crate) fn read_signed_varint(cursor: &mut Cursor<Vec<u8>>) -> i64 {
+    let value = read_varint(cursor);
+    ((value >> 1) as i64) ^ -((value & 1) as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::for_generated::{Dart2RustMessageSse, SseDeserializer};
+
+    #[test]
+    fn test_varint() {
+        let values = [0, 1, 127, 128, 300, u32::MAX as u64, u64::MAX];
+        let mut cursor = Cursor::new(vec![]);
+        for value in values {
+            write_varint(&mut cursor, value);
+        }
+        assert_eq!(&cursor.get_ref()[..5], &[0, 1, 127, 0x80, 1]);
+
+        cursor.set_position(0);
+        for value in values {
+            assert_eq!(read_varint(&mut cursor), value);
+        }
+    }
+
+    #[test]
+    fn test_signed_varint() {
+        let values = [0, -1, 1, -64, 64, i32::MIN as i64, i64::MIN, i64::MAX];
+        let mut cursor = Cursor::new(vec![]);
+        for value in values {
+            write_signed_varint(&mut cursor, value);
+        }
+        assert_eq!(&cursor.get_ref()[..5], &[0, 1, 2, 127, 0x80]);
+
+        cursor.set_position(0);
+        for value in values {
+            assert_eq!(read_signed_varint(&mut cursor), value);
+        }
+    }
+
+    #[test]
+    fn test_serializer_and_deserializer() {
+        let mut serializer = SseSerializer::new_compact();
+        serializer.write_i32(-3);
+        serializer.write_u16(u16::MAX);
+        serializer.write_u64(5);
+        let bytes = serializer.cursor.into_inner();
+        assert_eq!(bytes, vec![5, 0xff, 0xff, 3, 5]);
+
+        let message = Dart2RustMessageSse {
+            data_len: bytes.len() as i32,
+            vec: bytes,
+        };
+        let mut deserializer = SseDeserializer::new_compact(message);
+        assert_eq!(deserializer.read_i32(), -3);
+        assert_eq!(deserializer.read_u16(), u16::MAX);
+        assert_eq!(deserializer.read_u64(), 5);
+        deserializer.end();
+    }
+
+    #[test]
+    fn test_encode_panic() {
+        let message = CseCodec::encode_panic(&(Box::new("hi") as Box<dyn Any + Send>), &None);
+        // The length is an i32, thus zigzag-encoded
+        assert_eq!(message.0, vec![Rust2DartAction::Panic as u8, 4, b'h', b'i']);
+    }
+
+    #[test]
+    fn test_encode_lock_timeout() {
+        use crate::lockable::timeout::LockTimeoutPanic;
+        use std::time::Duration;
+
+        let panic = LockTimeoutPanic {
+            site: None,
+            timeout: Duration::from_micros(3),
+        };
+        let message = CseCodec::encode_panic(&(Box::new(panic) as Box<dyn Any + Send>), &None);
+        assert_eq!(message.0[0], Rust2DartAction::LockTimeout as u8);
+        // Without the function and argument names, then the zigzag-encoded timeout micros
+        assert_eq!(message.0[message.0.len() - 2..], [0, 6]);
+    }
+}
@@ -1,6 +1,8 @@
 use super::{BaseCodec, Rust2DartMessageTrait};
 use crate::generalized_isolate::IntoDart;
 use crate::handler::error::error_to_string;
+use crate::handler::implementation::interceptor::intercept_encode;
+use crate::lockable::timeout::LockTimeoutPanic;
 use crate::misc::into_into_dart::IntoIntoDart;
 use crate::platform_types::{DartAbi, WireSyncRust2DartDco};
 use crate::rust2dart::action::Rust2DartAction;
@@ -14,7 +16,19 @@ impl BaseCodec for DcoCodec {
     type Message = Rust2DartMessageDco;
 
     fn encode_panic(error: &Box<dyn Any + Send>, backtrace: &Option<Backtrace>) -> Self::Message {
-        Self::encode(Rust2DartAction::Panic, error_to_string(error, backtrace))
+        let msg = error_to_string(error, backtrace);
+        match error.downcast_ref::<LockTimeoutPanic>() {
+            Some(panic) => Self::encode(
+                Rust2DartAction::LockTimeout,
+                vec![
+                    msg.into_dart(),
+                    panic.site.map(|site| site.func_name.to_owned()).into_dart(),
+                    panic.site.map(|site| site.arg_name.to_owned()).into_dart(),
+                    (panic.timeout.as_micros() as i64).into_dart(),
+                ],
+            ),
+            None => Self::encode(Rust2DartAction::Panic, msg),
+        }
     }
 
     fn encode_close_stream() -> Self::Message {
@@ -66,13 +80,13 @@ where
     T2: IntoDart,
     E: IntoDart,
 {
-    match raw {
+    intercept_encode(|| match raw {
         Ok(raw) => Ok(DcoCodec::encode(
             Rust2DartAction::Success,
             raw.into_into_dart(),
         )),
         Err(raw) => Err(DcoCodec::encode(Rust2DartAction::Error, raw)),
-    }
+    })
 }
 
 #[cfg(test)]
@@ -2,6 +2,7 @@ use crate::platform_types::DartAbi;
 use std::any::Any;
 use std::backtrace::Backtrace;
 
+pub(crate) mod cse;
 pub(crate) mod cst;
 pub(crate) mod dco;
 pub(crate) mod sse;
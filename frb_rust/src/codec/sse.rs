@@ -1,13 +1,14 @@
-use super::{BaseCodec, Rust2DartMessageTrait};
+use super::{cse, BaseCodec, Rust2DartMessageTrait};
 use crate::generalized_isolate::IntoDart;
 use crate::handler::error::error_to_string;
+use crate::lockable::timeout::LockTimeoutPanic;
 use crate::platform_types::{DartAbi, PlatformGeneralizedUint8ListPtr, WireSyncRust2DartSse};
 use crate::rust2dart::action::Rust2DartAction;
 use byteorder::NativeEndian;
-use byteorder::WriteBytesExt;
+use byteorder::{ReadBytesExt, WriteBytesExt};
 use std::any::Any;
 use std::backtrace::Backtrace;
-use std::io::Cursor;
+use std::io::{Cursor, Read};
 
 /// Codec that does a simple serialization
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -17,17 +18,7 @@ impl BaseCodec for SseCodec {
     type Message = Rust2DartMessageSse;
 
     fn encode_panic(error: &Box<dyn Any + Send>, backtrace: &Option<Backtrace>) -> Self::Message {
-        let msg = error_to_string(error, backtrace);
-        Self::encode(Rust2DartAction::Panic, |serializer| {
-            // NOTE roughly copied from the auto-generated serialization of String
-            let bytes = msg.into_bytes();
-            (serializer.cursor)
-                .write_i32::<NativeEndian>(bytes.len() as _)
-                .unwrap();
-            for byte in bytes {
-                serializer.cursor.write_u8(byte).unwrap();
-            }
-        })
+        SseSerializer::new().into_panic_message(error, backtrace)
     }
 
     fn encode_close_stream() -> Self::Message {
@@ -42,14 +33,11 @@ impl SseCodec {
         result_code: Rust2DartAction,
         data_fn: impl FnOnce(&mut SseSerializer),
     ) -> Rust2DartMessageSse {
-        let mut serializer = SseSerializer::new();
-        (serializer.cursor).write_u8(result_code as _).unwrap();
-        data_fn(&mut serializer);
-        Rust2DartMessageSse(serializer.cursor.into_inner())
+        SseSerializer::new().into_message(result_code, data_fn)
     }
 }
 
-pub struct Rust2DartMessageSse(Vec<u8>);
+pub struct Rust2DartMessageSse(pub(crate) Vec<u8>);
 
 impl Rust2DartMessageTrait for Rust2DartMessageSse {
     type WireSyncRust2DartType = WireSyncRust2DartSse;
@@ -87,8 +75,8 @@ impl Rust2DartMessageTrait for Rust2DartMessageSse {
 
 #[derive(Debug)]
 pub struct Dart2RustMessageSse {
-    vec: Vec<u8>,
-    data_len: i32,
+    pub(crate) vec: Vec<u8>,
+    pub(crate) data_len: i32,
 }
 
 impl Dart2RustMessageSse {
@@ -115,6 +103,7 @@ pub struct SseDeserializer {
     // Only to be used for generated code
     pub cursor: Cursor<Vec<u8>>,
     data_len: i32,
+    compact: bool,
 }
 
 impl SseDeserializer {
@@ -122,16 +111,52 @@ impl SseDeserializer {
         Self {
             cursor: Cursor::new(message.vec),
             data_len: message.data_len,
+            compact: false,
+        }
+    }
+
+    /// Creates a deserializer of the compact format, see [`CseCodec`](crate::for_generated::CseCodec).
+    pub fn new_compact(message: Dart2RustMessageSse) -> Self {
+        Self {
+            compact: true,
+            ..Self::new(message)
         }
     }
 
     pub fn end(self) {
         assert_eq!(self.data_len as u64, self.cursor.position());
     }
+
+    /// Decodes the content of a `Vec<u8>`, whose length is already decoded.
+    ///
+    /// On native platforms, the Dart side puts large lists into standalone Rust `Vec<u8>`s
+    /// instead of this buffer, thus they can be taken without copying.
+    pub fn decode_u8_list(&mut self, len: i32) -> Vec<u8> {
+        match self.cursor.read_u8().unwrap() {
+            U8_LIST_INLINE => {
+                let mut ans = vec![0; len as usize];
+                self.cursor.read_exact(&mut ans).unwrap();
+                ans
+            }
+            U8_LIST_EXTERNAL => {
+                let ptr = self.read_u64() as usize as *mut u8;
+                #[cfg(not(wasm))]
+                // SAFETY: The Dart side allocates it via `rust_vec_u8_new` and gives up the ownership
+                return unsafe { crate::for_generated::vec_from_leak_ptr(ptr, len) };
+                #[cfg(wasm)]
+                unreachable!("standalone buffer {ptr:?} is not supported on the web")
+            }
+            mode => unreachable!("unknown u8 list mode {mode}"),
+        }
+    }
 }
 
+const U8_LIST_INLINE: u8 = 0;
+const U8_LIST_EXTERNAL: u8 = 1;
+
 pub struct SseSerializer {
     pub cursor: Cursor<Vec<u8>>,
+    compact: bool,
 }
 
 impl Default for SseSerializer {
@@ -144,10 +169,104 @@ impl SseSerializer {
     pub fn new() -> Self {
         Self {
             cursor: Cursor::new(vec![]),
+            compact: false,
+        }
+    }
+
+    /// Creates a serializer of the compact format, see [`CseCodec`](crate::for_generated::CseCodec).
+    pub fn new_compact() -> Self {
+        Self {
+            compact: true,
+            ..Self::new()
         }
     }
+
+    pub(crate) fn into_message(
+        mut self,
+        result_code: Rust2DartAction,
+        data_fn: impl FnOnce(&mut SseSerializer),
+    ) -> Rust2DartMessageSse {
+        (self.cursor).write_u8(result_code as _).unwrap();
+        data_fn(&mut self);
+        Rust2DartMessageSse(self.cursor.into_inner())
+    }
+
+    /// Lock timeouts are encoded with their details, which become a dedicated exception in Dart.
+    pub(crate) fn into_panic_message(
+        self,
+        error: &Box<dyn Any + Send>,
+        backtrace: &Option<Backtrace>,
+    ) -> Rust2DartMessageSse {
+        let msg = error_to_string(error, backtrace);
+        match error.downcast_ref::<LockTimeoutPanic>() {
+            Some(panic) => self.into_message(Rust2DartAction::LockTimeout, |serializer| {
+                serializer.encode_lock_timeout(msg, panic)
+            }),
+            None => self.into_message(Rust2DartAction::Panic, |serializer| {
+                serializer.encode_panic_message(msg)
+            }),
+        }
+    }
+
+    fn encode_panic_message(&mut self, msg: String) {
+        // NOTE roughly copied from the auto-generated serialization of String
+        let bytes = msg.into_bytes();
+        self.write_i32(bytes.len() as _);
+        for byte in bytes {
+            self.cursor.write_u8(byte).unwrap();
+        }
+    }
+
+    fn encode_lock_timeout(&mut self, msg: String, panic: &LockTimeoutPanic) {
+        self.encode_panic_message(msg);
+        self.cursor.write_u8(panic.site.is_some() as _).unwrap();
+        if let Some(site) = panic.site {
+            self.encode_panic_message(site.func_name.to_owned());
+            self.encode_panic_message(site.arg_name.to_owned());
+        }
+        self.write_i64(panic.timeout.as_micros() as _);
+    }
 }
 
+// Integers are fixed-width, or varints in the compact format, thus generated code uses these
+// instead of writing to the cursor directly.
+macro_rules! impl_integers {
+    ($($read:ident, $write:ident, $ty:ty, $compact_read:ident, $compact_write:ident;)*) => {
+        impl SseSerializer {
+            $(
+            pub fn $write(&mut self, value: $ty) {
+                if self.compact {
+                    cse::$compact_write(&mut self.cursor, value as _);
+                } else {
+                    self.cursor.$write::<NativeEndian>(value).unwrap();
+                }
+            }
+            )*
+        }
+
+        impl SseDeserializer {
+            $(
+            pub fn $read(&mut self) -> $ty {
+                if self.compact {
+                    <$ty>::try_from(cse::$compact_read(&mut self.cursor)).unwrap()
+                } else {
+                    self.cursor.$read::<NativeEndian>().unwrap()
+                }
+            }
+            )*
+        }
+    };
+}
+
+impl_integers!(
+    read_i16, write_i16, i16, read_signed_varint, write_signed_varint;
+    read_u16, write_u16, u16, read_varint, write_varint;
+    read_i32, write_i32, i32, read_signed_varint, write_signed_varint;
+    read_u32, write_u32, u32, read_varint, write_varint;
+    read_i64, write_i64, i64, read_signed_varint, write_signed_varint;
+    read_u64, write_u64, u64, read_varint, write_varint;
+);
+
 #[cfg(test)]
 mod tests {
     use crate::for_generated::{Rust2DartMessageSse, SseSerializer};
@@ -155,11 +274,37 @@ mod tests {
     #[test]
     fn test_simplest() {
         use crate::codec::Rust2DartMessageTrait;
-        assert_eq!(Rust2DartMessageSse::simplest().0, vec![]);
+        assert_eq!(Rust2DartMessageSse::simplest().0, Vec::<u8>::new());
+    }
+
+    #[test]
+    #[cfg(not(wasm))]
+    fn test_decode_u8_list() {
+        use crate::for_generated::{into_leak_vec_ptr, Dart2RustMessageSse, SseDeserializer};
+        use byteorder::{NativeEndian, WriteBytesExt};
+
+        let (external_ptr, external_len) = into_leak_vec_ptr(vec![4u8, 5, 6]);
+        let mut bytes = vec![0, 1, 2, 3, 1];
+        bytes
+            .write_u64::<NativeEndian>(external_ptr as usize as u64)
+            .unwrap();
+        let data_len = bytes.len() as i32;
+        let message = Dart2RustMessageSse {
+            vec: bytes,
+            data_len,
+        };
+
+        let mut deserializer = SseDeserializer::new(message);
+        assert_eq!(deserializer.decode_u8_list(3), vec![1, 2, 3]);
+        assert_eq!(deserializer.decode_u8_list(external_len), vec![4, 5, 6]);
+        deserializer.end();
     }
 
     #[test]
     fn test_serializer_default() {
-        assert_eq!(SseSerializer::default().cursor.into_inner(), vec![]);
+        assert_eq!(
+            SseSerializer::default().cursor.into_inner(),
+            Vec::<u8>::new()
+        );
     }
 }
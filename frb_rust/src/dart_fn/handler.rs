@@ -58,7 +58,9 @@ impl DartFnHandler {
         let _ = panic::catch_unwind(move || {
             let catch_unwind_result = panic::catch_unwind(move || {
                 if let Some(completer) = (self.completers.lock().unwrap()).remove(&call_id) {
-                    completer.send(message).unwrap();
+                    // The receiver is dropped if Rust no longer waits for the output,
+                    // e.g. for fire-and-forget calls, so it is fine to ignore the error
+                    let _ = completer.send(message);
                 }
             });
             if let Err(err) = catch_unwind_result {
@@ -5,3 +5,13 @@ use std::pin::Pin;
 
 /// Roughly speaking, just BoxFuture + UnwindSafe.
 pub type DartFnFuture<T> = Pin<Box<dyn Future<Output = T> + Send + 'static>>;
+
+/// Block the current thread until the Dart function completes, used by the non-async methods of
+/// Rust traits implemented in Dart.
+///
+/// The Dart function runs on the Dart thread, thus this must never be called on it (e.g. inside a
+/// `#[frb(sync)]` function), otherwise it waits forever. It is not supported on the web either,
+/// since the thread cannot be blocked there.
+pub fn dart_fn_block_on<T>(future: DartFnFuture<T>) -> T {
+    futures::executor::block_on(future)
+}
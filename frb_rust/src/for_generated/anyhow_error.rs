@@ -0,0 +1,134 @@
+use crate::generalized_isolate::IntoDart;
+use crate::platform_types::DartAbi;
+
+/// Encode an `anyhow::Error` as `[message, backtrace, type_name, causes...]`, where
+/// * `message` is the `Debug` representation of the error,
+/// * `backtrace` is the captured backtrace (empty if not captured or the `backtrace` feature is disabled),
+/// * `type_name` is the name of a well-known type the error can be downcast to (empty if unknown),
+/// * `causes` are the `Display` messages of the chain of causes (outermost first).
+pub fn anyhow_error_encode(error: anyhow::Error) -> Vec<String> {
+    let header = [
+        format!("{:?}", error),
+        backtrace_string(&error),
+        downcastable_type_name(&error)
+            .unwrap_or_default()
+            .to_owned(),
+    ];
+    (header.into_iter())
+        .chain(error.chain().map(ToString::to_string))
+        .collect()
+}
+
+/// Decode an `anyhow::Error` encoded by [anyhow_error_encode].
+/// The chain of causes is restored as layers of context.
+pub fn anyhow_error_decode(raw: Vec<String>) -> anyhow::Error {
+    let mut raw = raw.into_iter();
+    let message = raw.next().unwrap_or_default();
+    // The backtrace and type name cannot be attached to a new `anyhow::Error`
+    let mut causes = raw.skip(2).rev();
+    match causes.next() {
+        Some(root_cause) => causes.fold(anyhow::anyhow!("{}", root_cause), |error, cause| {
+            error.context(cause)
+        }),
+        None => anyhow::anyhow!("{}", message),
+    }
+}
+
+/// Sends an `anyhow::Error` to Dart in the DCO codec using the format of [anyhow_error_encode],
+/// since the `IntoDart` of `anyhow::Error` itself only keeps the `Debug` representation.
+pub struct DcoAnyhowError(pub anyhow::Error);
+
+impl From<anyhow::Error> for DcoAnyhowError {
+    fn from(error: anyhow::Error) -> Self {
+        Self(error)
+    }
+}
+
+impl std::fmt::Debug for DcoAnyhowError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl IntoDart for DcoAnyhowError {
+    fn into_dart(self) -> DartAbi {
+        anyhow_error_encode(self.0).into_dart()
+    }
+}
+
+#[cfg(feature = "backtrace")]
+fn backtrace_string(error: &anyhow::Error) -> String {
+    // Depending on the toolchain, `anyhow` may not expose `std::backtrace::Backtrace::status`,
+    // thus we recognize the placeholder texts of a backtrace that is not captured
+    let backtrace = error.backtrace().to_string();
+    match backtrace.as_str() {
+        "disabled backtrace" | "unsupported backtrace" => String::new(),
+        _ => backtrace,
+    }
+}
+
+#[cfg(not(feature = "backtrace"))]
+fn backtrace_string(_error: &anyhow::Error) -> String {
+    String::new()
+}
+
+fn downcastable_type_name(error: &anyhow::Error) -> Option<&'static str> {
+    macro_rules! find_downcastable {
+        ($($ty:ty),* $(,)?) => {
+            $(
+                if error.downcast_ref::<$ty>().is_some() {
+                    return Some(std::any::type_name::<$ty>());
+                }
+            )*
+        };
+    }
+
+    find_downcastable!(
+        std::io::Error,
+        std::fmt::Error,
+        std::num::ParseIntError,
+        std::num::ParseFloatError,
+        std::str::ParseBoolError,
+        std::str::Utf8Error,
+        std::string::FromUtf8Error,
+    );
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{anyhow_error_decode, anyhow_error_encode, DcoAnyhowError};
+
+    #[test]
+    fn test_anyhow_error_encode_decode() {
+        let error = anyhow::anyhow!("root").context("middle").context("outer");
+
+        let raw = anyhow_error_encode(error);
+        assert!(raw[0].starts_with("outer\n\nCaused by:"));
+        assert_eq!(raw[2], "");
+        assert_eq!(&raw[3..], ["outer", "middle", "root"]);
+
+        let decoded = anyhow_error_decode(raw);
+        let chain = decoded.chain().map(ToString::to_string).collect::<Vec<_>>();
+        assert_eq!(chain, ["outer", "middle", "root"]);
+    }
+
+    #[test]
+    fn test_anyhow_error_encode_type_name() {
+        let error = anyhow::Error::new("x".parse::<i32>().unwrap_err()).context("parsing");
+        let raw = anyhow_error_encode(error);
+        assert_eq!(raw[2], "core::num::error::ParseIntError");
+    }
+
+    #[test]
+    fn test_anyhow_error_decode_message_only() {
+        let decoded = anyhow_error_decode(vec!["hello".to_owned()]);
+        assert_eq!(decoded.to_string(), "hello");
+    }
+
+    #[test]
+    fn test_dco_anyhow_error_debug() {
+        let error = DcoAnyhowError::from(anyhow::anyhow!("root").context("outer"));
+        assert!(format!("{error:?}").starts_with("outer\n\nCaused by:"));
+    }
+}
@@ -108,14 +108,36 @@ macro_rules! frb_generated_sse_codec {
         {
             use $crate::for_generated::{Rust2DartAction, SseCodec};
 
-            match raw {
+            $crate::for_generated::intercept_encode(|| match raw {
                 Ok(raw) => Ok(SseCodec::encode(Rust2DartAction::Success, |serializer| {
                     raw.sse_encode(serializer)
                 })),
                 Err(raw) => Err(SseCodec::encode(Rust2DartAction::Error, |serializer| {
                     raw.sse_encode(serializer)
                 })),
-            }
+            })
+        }
+
+        fn transform_result_cse<T, E>(
+            raw: Result<T, E>,
+        ) -> Result<
+            $crate::for_generated::Rust2DartMessageSse,
+            $crate::for_generated::Rust2DartMessageSse,
+        >
+        where
+            T: SseEncode,
+            E: SseEncode,
+        {
+            use $crate::for_generated::{CseCodec, Rust2DartAction};
+
+            $crate::for_generated::intercept_encode(|| match raw {
+                Ok(raw) => Ok(CseCodec::encode(Rust2DartAction::Success, |serializer| {
+                    raw.sse_encode(serializer)
+                })),
+                Err(raw) => Err(CseCodec::encode(Rust2DartAction::Error, |serializer| {
+                    raw.sse_encode(serializer)
+                })),
+            })
         }
     };
 }
@@ -136,6 +158,10 @@ macro_rules! frb_generated_stream_sink {
             pub fn deserialize(raw: String) -> Self {
                 Self { base: $crate::for_generated::StreamSinkBase::deserialize(raw) }
             }
+
+            pub fn set_overflow_policy(&self, policy: $crate::StreamSinkOverflowPolicy) {
+                self.base.set_overflow_policy(policy)
+            }
         }
 
         impl<T> StreamSink<T, $crate::for_generated::DcoCodec> {
@@ -155,6 +181,28 @@ macro_rules! frb_generated_stream_sink {
                 self.add_raw($crate::for_generated::Rust2DartAction::Error, value)
             }
 
+            pub fn try_add<T2>(&self, value: T) -> Result<(), $crate::StreamSinkTryAddError>
+            where
+                T: $crate::IntoIntoDart<T2>,
+                T2: $crate::IntoDart,
+            {
+                self.base.try_add_raw($crate::for_generated::DcoCodec::encode(
+                    $crate::for_generated::Rust2DartAction::Success,
+                    value.into_into_dart(),
+                ))
+            }
+
+            pub fn add_async<T2>(&self, value: T) -> impl std::future::Future<Output = Result<(), $crate::Rust2DartSendError>> + Send + '_
+            where
+                T: $crate::IntoIntoDart<T2>,
+                T2: $crate::IntoDart,
+            {
+                self.base.add_raw_async($crate::for_generated::DcoCodec::encode(
+                    $crate::for_generated::Rust2DartAction::Success,
+                    value.into_into_dart(),
+                ))
+            }
+
             fn add_raw<TR, T2>(&self, action: $crate::for_generated::Rust2DartAction, value: TR) -> Result<(), $crate::Rust2DartSendError>
             where
                 TR: $crate::IntoIntoDart<T2>,
@@ -179,6 +227,20 @@ macro_rules! frb_generated_stream_sink {
                 self.add_raw($crate::for_generated::Rust2DartAction::Error, value)
             }
 
+            pub fn try_add(&self, value: T) -> Result<(), $crate::StreamSinkTryAddError> {
+                self.base.try_add_raw($crate::for_generated::SseCodec::encode(
+                    $crate::for_generated::Rust2DartAction::Success,
+                    |serializer| value.sse_encode(serializer),
+                ))
+            }
+
+            pub fn add_async(&self, value: T) -> impl std::future::Future<Output = Result<(), $crate::Rust2DartSendError>> + Send + '_ {
+                self.base.add_raw_async($crate::for_generated::SseCodec::encode(
+                    $crate::for_generated::Rust2DartAction::Success,
+                    |serializer| value.sse_encode(serializer),
+                ))
+            }
+
             pub fn add_raw<TR: SseEncode>(&self, action: $crate::for_generated::Rust2DartAction, value: TR) -> Result<(), $crate::Rust2DartSendError> {
                 self.base.add_raw($crate::for_generated::SseCodec::encode(
                     action,
@@ -208,6 +270,9 @@ macro_rules! frb_generated_stream_sink {
 #[macro_export]
 macro_rules! frb_generated_default_handler {
     () => {
+        $crate::frb_generated_default_handler!(thread_pools: []);
+    };
+    (thread_pools: [$(($name:literal, $num_threads:expr)),* $(,)?]) => {
         #[cfg(not(target_family = "wasm"))]
         $crate::for_generated::lazy_static! {
             pub static ref FLUTTER_RUST_BRIDGE_HANDLER:$crate::DefaultHandler<$crate::for_generated::SimpleThreadPool> = {
@@ -219,7 +284,10 @@ macro_rules! frb_generated_default_handler {
                     flutter_rust_bridge::for_generated::FLUTTER_RUST_BRIDGE_RUNTIME_VERSION,
                 );
 
-                $crate::DefaultHandler::new_simple(Default::default())
+                $crate::DefaultHandler::new_simple_with_thread_pools(
+                    Default::default(),
+                    [$(($name, $crate::for_generated::SimpleThreadPool::new_with_name($name, $num_threads))),*],
+                )
             };
         }
 
@@ -228,6 +296,7 @@ macro_rules! frb_generated_default_handler {
             pub static THREAD_POOL: $crate::for_generated::SimpleThreadPool = Default::default();
         }
 
+        // Named thread pools are not supported on the web, thus the default one is used for all
         #[cfg(target_family = "wasm")]
         $crate::for_generated::lazy_static! {
             pub static ref FLUTTER_RUST_BRIDGE_HANDLER: $crate::DefaultHandler<&'static std::thread::LocalKey<$crate::for_generated::SimpleThreadPool>>
@@ -1,6 +1,8 @@
 //! Utilities to support the auto-generated Rust code.
 //! These functions are usually *not* meant to be used by humans directly.
 
+#[cfg(feature = "anyhow")]
+mod anyhow_error;
 mod boilerplate;
 mod boilerplate_io;
 mod boilerplate_web;
@@ -14,7 +16,9 @@ pub use crate::codec::sse::{
     Dart2RustMessageSse, Rust2DartMessageSse, SseDeserializer, SseSerializer,
 };
 pub use crate::codec::Rust2DartMessageTrait;
-pub use crate::codec::{cst::CstCodec, dco::DcoCodec, sse::SseCodec, BaseCodec};
+pub use crate::codec::{cse::CseCodec, cst::CstCodec, dco::DcoCodec, sse::SseCodec, BaseCodec};
+#[cfg(all(feature = "rust-async", feature = "dart-opaque"))]
+pub use crate::dart_fn::dart_fn_block_on;
 #[cfg(feature = "dart-opaque")]
 pub use crate::dart_opaque::dart2rust::{cst_decode_dart_opaque, sse_decode_dart_opaque};
 pub use crate::generalized_arc::base_arc::BaseArc;
@@ -29,14 +33,21 @@ pub use crate::handler::handler::{TaskContext, TaskRetFutTrait};
 pub use crate::handler::implementation::error_listener::NoOpErrorListener;
 pub use crate::handler::implementation::executor::SimpleExecutor;
 pub use crate::handler::implementation::handler::SimpleHandler;
+pub use crate::handler::implementation::interceptor::{intercept_encode, NoOpInterceptor};
+pub use crate::handler::interceptor::{Interceptor, InterceptorPhase};
 pub use crate::lifetimeable::lifetime_changer::{
     ouroboros_change_lifetime, ouroboros_change_lifetime_mut,
 };
 pub use crate::lifetimeable::{dependency::LifetimeableDependency, Lifetimeable};
-#[cfg(feature = "rust-async")]
 pub use crate::lockable::{
-    base::Lockable, order::LockableOrder, order_computer::lockable_compute_decode_order,
+    base::Lockable,
+    order::LockableOrder,
+    order_computer::lockable_compute_decode_order,
     order_info::LockableOrderInfo,
+    site::{lockable_with_site, lockable_with_site_async},
+};
+pub use crate::misc::error_debug::{
+    record_error_debug, ErrorDebug, ErrorDebugFallback, ErrorDebugViaDebug,
 };
 #[allow(unused)]
 pub use crate::misc::manual_impl::*;
@@ -50,15 +61,16 @@ pub use crate::platform_types::{
 pub use crate::rust2dart::action::Rust2DartAction;
 pub use crate::rust_async;
 pub use crate::rust_async::{BaseAsyncRuntime, SimpleAsyncRuntime};
-#[cfg(feature = "rust-async")]
 pub use crate::rust_auto_opaque::dart2rust_explicit::rust_auto_opaque_explicit_decode;
-#[cfg(feature = "rust-async")]
 pub use crate::rust_auto_opaque::dart2rust_implicit::{
-    rust_auto_opaque_decode_owned, rust_auto_opaque_encode, rust_auto_opaque_lockable_order,
+    rust_auto_opaque_decode_owned, rust_auto_opaque_encode,
+    rust_auto_opaque_encode_with_lock_strategy, rust_auto_opaque_lockable_exclusive,
+    rust_auto_opaque_lockable_order, rust_auto_opaque_try_into_inner,
+};
+pub use crate::rust_auto_opaque::lock::{
+    RustAutoOpaqueLockStrategy, RustAutoOpaqueReadGuard, RustAutoOpaqueWriteGuard,
 };
-#[cfg(feature = "rust-async")]
 pub use crate::rust_auto_opaque::rust2dart_explicit::rust_auto_opaque_explicit_encode;
-#[cfg(feature = "rust-async")]
 pub use crate::rust_auto_opaque::{inner::RustAutoOpaqueInner, RustAutoOpaqueBase};
 pub use crate::rust_opaque::{dart2rust::decode_rust_opaque_nom, RustOpaqueBase};
 pub use crate::stream::stream_sink::StreamSinkBase;
@@ -67,6 +79,8 @@ pub use crate::thread_pool::{BaseThreadPool, SimpleThreadPool};
 pub use crate::web_transfer::transfer_closure::TransferClosure;
 #[cfg(feature = "anyhow")]
 pub use anyhow;
+#[cfg(feature = "anyhow")]
+pub use anyhow_error::{anyhow_error_decode, anyhow_error_encode, DcoAnyhowError};
 pub use byteorder;
 #[cfg(wasm)]
 pub use cast::slice_from_byte_buffer;
@@ -0,0 +1,46 @@
+use super::CancellationToken;
+use crate::platform_types::MessagePort;
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+/// Tokens of in-flight non-sync calls, indexed by the port that their results are sent to
+static REGISTRY: Mutex<BTreeMap<MessagePort, CancellationToken>> = Mutex::new(BTreeMap::new());
+
+pub(crate) fn register_cancellation(port: &MessagePort) -> CancellationToken {
+    let token = CancellationToken::default();
+    REGISTRY.lock().unwrap().insert(*port, token.clone());
+    token
+}
+
+pub(crate) fn unregister_cancellation(port: &MessagePort) {
+    REGISTRY.lock().unwrap().remove(port);
+}
+
+fn cancel_task_inner(port: MessagePort) {
+    let token = REGISTRY.lock().unwrap().remove(&port);
+    if let Some(token) = token {
+        token.cancel();
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn frb_cancel_task(port: MessagePort) {
+    cancel_task_inner(port)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{cancel_task_inner, register_cancellation, unregister_cancellation};
+
+    #[test]
+    fn test_cancel_task() {
+        let token = register_cancellation(&200001);
+        cancel_task_inner(200001);
+        assert!(token.is_cancelled());
+
+        let token = register_cancellation(&200002);
+        unregister_cancellation(&200002);
+        cancel_task_inner(200002);
+        assert!(!token.is_cancelled());
+    }
+}
@@ -0,0 +1,178 @@
+use std::cell::RefCell;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+#[cfg(wasm)]
+mod web;
+#[cfg(wasm)]
+pub(crate) use web::*;
+
+#[cfg(not(wasm))]
+mod io;
+#[cfg(not(wasm))]
+pub(crate) use io::*;
+
+/// Tells whether the Dart side has cancelled the call of a Rust function.
+///
+/// For `async` Rust functions, the future is dropped at its next `.await` point after cancellation,
+/// so there is usually no need to check it manually. For other functions, cancellation is cooperative,
+/// i.e. long-running work should check [`is_cancelled`](CancellationToken::is_cancelled) periodically
+/// and return early.
+#[derive(Clone, Default)]
+pub struct CancellationToken(Arc<CancellationTokenInner>);
+
+#[derive(Default)]
+struct CancellationTokenInner {
+    cancelled: AtomicBool,
+    waker: Mutex<Option<Waker>>,
+}
+
+thread_local! {
+    static CURRENT: RefCell<Option<CancellationToken>> = const { RefCell::new(None) };
+}
+
+impl CancellationToken {
+    /// The token of the Rust function call that is currently executing on this thread, if any.
+    pub fn current() -> Option<Self> {
+        CURRENT.with(|current| current.borrow().clone())
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.cancelled.load(Ordering::Acquire)
+    }
+
+    pub(crate) fn cancel(&self) {
+        self.0.cancelled.store(true, Ordering::Release);
+        if let Some(waker) = self.0.waker.lock().unwrap().take() {
+            waker.wake();
+        }
+    }
+
+    /// Make this token the [`current`](CancellationToken::current) one while `f` runs.
+    pub(crate) fn enter<R>(&self, f: impl FnOnce() -> R) -> R {
+        let previous = CURRENT.with(|current| current.replace(Some(self.clone())));
+        struct Reset(Option<CancellationToken>);
+        impl Drop for Reset {
+            fn drop(&mut self) {
+                CURRENT.with(|current| *current.borrow_mut() = self.0.take());
+            }
+        }
+        let _reset = Reset(previous);
+        f()
+    }
+}
+
+/// Resolves to `None` without polling the inner future any more, once the token is cancelled.
+pub(crate) struct Cancellable<F> {
+    inner: F,
+    token: CancellationToken,
+}
+
+impl<F> Cancellable<F> {
+    pub(crate) fn new(inner: F, token: CancellationToken) -> Self {
+        Self { inner, token }
+    }
+}
+
+impl<F: Future> Future for Cancellable<F> {
+    type Output = Option<F::Output>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // SAFETY: `inner` is structurally pinned, i.e. it is never moved out of `self`
+        let this = unsafe { self.get_unchecked_mut() };
+        // Store the waker before checking the flag, otherwise a cancellation in between would
+        // take the previous waker (if any) and this future would never be woken
+        *this.token.0.waker.lock().unwrap() = Some(cx.waker().clone());
+        if this.token.is_cancelled() {
+            return Poll::Ready(None);
+        }
+
+        let inner = unsafe { Pin::new_unchecked(&mut this.inner) };
+        this.token.enter(|| inner.poll(cx)).map(Some)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Cancellable, CancellationToken};
+    use std::future::Future;
+    use std::pin::pin;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::{Arc, Barrier};
+    use std::task::{Context, Poll, Wake};
+
+    struct NoopWaker;
+
+    impl Wake for NoopWaker {
+        fn wake(self: Arc<Self>) {}
+    }
+
+    #[derive(Default)]
+    struct FlagWaker(AtomicBool);
+
+    impl Wake for FlagWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.store(true, Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn test_current() {
+        let token = CancellationToken::default();
+        assert!(CancellationToken::current().is_none());
+        token.enter(|| {
+            token.cancel();
+            assert!(CancellationToken::current().unwrap().is_cancelled());
+        });
+        assert!(CancellationToken::current().is_none());
+    }
+
+    #[test]
+    fn test_cancellable() {
+        let token = CancellationToken::default();
+        let mut future = pin!(Cancellable::new(
+            std::future::pending::<()>(),
+            token.clone()
+        ));
+        let waker = Arc::new(NoopWaker).into();
+        let mut cx = Context::from_waker(&waker);
+
+        assert_eq!(future.as_mut().poll(&mut cx), Poll::Pending);
+        token.cancel();
+        assert_eq!(future.as_mut().poll(&mut cx), Poll::Ready(None));
+    }
+
+    #[cfg(not(wasm))]
+    #[test]
+    fn test_cancellable_cancel_from_another_thread_during_poll() {
+        for _ in 0..1000 {
+            let token = CancellationToken::default();
+            let mut future = pin!(Cancellable::new(
+                std::future::pending::<()>(),
+                token.clone()
+            ));
+            let flag_waker = Arc::new(FlagWaker::default());
+            let waker = flag_waker.clone().into();
+            let mut cx = Context::from_waker(&waker);
+
+            let barrier = Arc::new(Barrier::new(2));
+            let canceller = {
+                let barrier = barrier.clone();
+                std::thread::spawn(move || {
+                    barrier.wait();
+                    token.cancel();
+                })
+            };
+            barrier.wait();
+            let output = future.as_mut().poll(&mut cx);
+            canceller.join().unwrap();
+
+            // Either the cancellation is seen by this poll, or the future is woken to see it later
+            assert!(output == Poll::Ready(None) || flag_waker.0.load(Ordering::SeqCst));
+            assert_eq!(future.as_mut().poll(&mut cx), Poll::Ready(None));
+        }
+    }
+}
@@ -0,0 +1,9 @@
+use super::CancellationToken;
+use crate::platform_types::MessagePort;
+
+/// Cancellation is not supported on web yet, thus the token is never cancelled.
+pub(crate) fn register_cancellation(_port: &MessagePort) -> CancellationToken {
+    CancellationToken::default()
+}
+
+pub(crate) fn unregister_cancellation(_port: &MessagePort) {}
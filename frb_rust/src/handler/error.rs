@@ -1,20 +1,32 @@
+use crate::lockable::timeout::LockTimeoutPanic;
 use std::any::Any;
 use std::backtrace::Backtrace;
 
 /// Errors that occur from normal code execution.
 pub enum Error {
-    /// Non-panic errors
-    CustomError,
-    /// Exceptional errors from panicking.
-    Panic(Box<dyn Any + Send>),
+    /// Non-panic errors, i.e. the `Err` returned by the Rust function.
+    ///
+    /// It contains the `Debug` representation of the error value,
+    /// or [None] if the error type does not implement `Debug`.
+    CustomError(Option<String>),
+    /// Exceptional errors from panicking, with the backtrace if it is captured.
+    Panic(Box<dyn Any + Send>, Option<Backtrace>),
 }
 
 impl Error {
     /// The message of the error.
     pub fn message(&self) -> String {
         match self {
-            Error::CustomError => "CustomError".to_string(),
-            Error::Panic(panic_err) => error_to_string(panic_err, &None),
+            Error::CustomError(debug) => debug.clone().unwrap_or_else(|| "CustomError".to_string()),
+            Error::Panic(panic_err, _) => error_to_string(panic_err, &None),
+        }
+    }
+
+    /// The backtrace captured when panicking.
+    pub fn backtrace(&self) -> Option<&Backtrace> {
+        match self {
+            Error::CustomError(_) => None,
+            Error::Panic(_, backtrace) => backtrace.as_ref(),
         }
     }
 }
@@ -24,13 +36,15 @@ pub(crate) fn error_to_string(
     backtrace: &Option<Backtrace>,
 ) -> String {
     let err_string = match panic_err.downcast_ref::<&'static str>() {
-        Some(s) => *s,
+        Some(s) => s.to_string(),
         None => match panic_err.downcast_ref::<String>() {
-            Some(s) => &s[..],
-            None => "Box<dyn Any>",
+            Some(s) => s.clone(),
+            None => match panic_err.downcast_ref::<LockTimeoutPanic>() {
+                Some(s) => s.to_string(),
+                None => "Box<dyn Any>".to_owned(),
+            },
         },
-    }
-    .to_string();
+    };
     let backtrace_string = backtrace
         .as_ref()
         .map(|b| format!("{:?}", b))
@@ -44,13 +58,17 @@ mod tests {
 
     #[test]
     fn test_error_message() {
-        assert_eq!(Error::CustomError.message(), "CustomError".to_owned());
+        assert_eq!(Error::CustomError(None).message(), "CustomError".to_owned());
+        assert_eq!(
+            Error::CustomError(Some("MyError".to_owned())).message(),
+            "MyError".to_owned()
+        );
         assert_eq!(
-            Error::Panic(Box::new(42)).message(),
+            Error::Panic(Box::new(42), None).message(),
             "Box<dyn Any>".to_owned()
         );
         assert_eq!(
-            Error::Panic(Box::new("Hello".to_string())).message(),
+            Error::Panic(Box::new("Hello".to_string()), None).message(),
             "Hello".to_owned()
         );
     }
@@ -1,6 +1,9 @@
 use crate::handler::error::Error;
+use crate::handler::handler::TaskInfo;
 
 /// Listens when error happens
 pub trait ErrorListener: Copy + Send + 'static {
-    fn on_error(&self, error: Error);
+    /// Called when a Rust function returns an error or panics.
+    /// The `task_info` tells which function it is.
+    fn on_error(&self, error: Error, task_info: &TaskInfo);
 }
@@ -1,6 +1,7 @@
 use crate::codec::sse::Dart2RustMessageSse;
 use crate::codec::BaseCodec;
 use crate::codec::Rust2DartMessageTrait;
+use crate::handler::cancellation::CancellationToken;
 use crate::platform_types::DartAbi;
 use crate::platform_types::MessagePort;
 use std::future::Future;
@@ -76,10 +77,12 @@ pub struct TaskInfo {
     pub debug_name: &'static str,
     /// The call mode of this function.
     pub mode: FfiCallMode,
+    /// The executor class specified by `#[frb(executor = "...")]`. [None] for the default one.
+    pub executor: Option<&'static str>,
 }
 
 /// The types of return values for a particular Rust function.
-#[derive(Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum FfiCallMode {
     /// The default mode, returns a Dart `Future<T>`.
     Normal,
@@ -97,9 +100,10 @@ pub trait TaskRetFutTrait {}
 #[cfg(wasm)]
 impl<T> TaskRetFutTrait for T {}
 
-// Originally there were things for StreamSink, but it was moved, so now it is empty
 /// A context for task execution
-pub struct TaskContext {}
+pub struct TaskContext {
+    cancellation_token: CancellationToken,
+}
 
 // frb-coverage:ignore-start
 impl Default for TaskContext {
@@ -111,6 +115,19 @@ impl Default for TaskContext {
 
 impl TaskContext {
     pub fn new() -> Self {
-        Self {}
+        Self::with_cancellation_token(CancellationToken::default())
+    }
+
+    pub fn with_cancellation_token(cancellation_token: CancellationToken) -> Self {
+        Self { cancellation_token }
+    }
+
+    /// Whether the Dart side has cancelled this call
+    pub fn is_cancelled(&self) -> bool {
+        self.cancellation_token.is_cancelled()
+    }
+
+    pub fn cancellation_token(&self) -> &CancellationToken {
+        &self.cancellation_token
     }
 }
@@ -3,8 +3,8 @@ use crate::codec::Rust2DartMessageTrait;
 use crate::generalized_isolate::Channel;
 use crate::handler::error::Error;
 use crate::handler::error_listener::ErrorListener;
+use crate::handler::handler::TaskInfo;
 use crate::misc::panic_backtrace::CatchUnwindWithBacktrace;
-use crate::platform_types::MessagePort;
 use crate::rust2dart::sender::Rust2DartSender;
 
 /// The default one.
@@ -12,18 +12,20 @@ use crate::rust2dart::sender::Rust2DartSender;
 pub struct NoOpErrorListener;
 
 impl ErrorListener for NoOpErrorListener {
-    fn on_error(&self, _error: Error) {
+    fn on_error(&self, _error: Error, _task_info: &TaskInfo) {
         // nothing
     }
 }
 
 pub(crate) fn handle_non_sync_panic_error<Rust2DartCodec: BaseCodec>(
     error_listener: impl ErrorListener,
-    port: MessagePort,
+    task_info: &TaskInfo,
     error: CatchUnwindWithBacktrace,
 ) {
+    #[allow(clippy::clone_on_copy)]
+    let port = task_info.port.clone().unwrap();
     let message = Rust2DartCodec::encode_panic(&error.err, &error.backtrace).into_dart_abi();
-    error_listener.on_error(Error::Panic(error.err));
+    error_listener.on_error(Error::Panic(error.err, error.backtrace), task_info);
     Rust2DartSender::new(Channel::new(port))
         .send(message)
         .unwrap();
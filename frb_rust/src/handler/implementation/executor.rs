@@ -1,11 +1,19 @@
 use crate::codec::BaseCodec;
 use crate::codec::Rust2DartMessageTrait;
 use crate::generalized_isolate::Channel;
+#[cfg(feature = "rust-async")]
+use crate::handler::cancellation::Cancellable;
+use crate::handler::cancellation::{register_cancellation, unregister_cancellation};
 use crate::handler::error::Error;
 use crate::handler::error_listener::ErrorListener;
 use crate::handler::executor::Executor;
 use crate::handler::handler::{TaskContext, TaskInfo, TaskRetFutTrait};
 use crate::handler::implementation::error_listener::handle_non_sync_panic_error;
+#[cfg(feature = "rust-async")]
+use crate::lockable::wait_for_graph::with_task_borrower;
+use crate::misc::error_debug::take_last_error_debug;
+#[cfg(feature = "thread-pool")]
+use crate::misc::logs::log_warn_or_println;
 use crate::misc::panic_backtrace::{CatchUnwindWithBacktrace, PanicBacktrace};
 use crate::platform_types::MessagePort;
 use crate::rust2dart::sender::Rust2DartSender;
@@ -14,15 +22,42 @@ use crate::thread_pool::BaseThreadPool;
 use crate::transfer;
 #[cfg(feature = "rust-async")]
 use futures::FutureExt;
+#[cfg(feature = "thread-pool")]
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+#[cfg(all(feature = "thread-pool", not(wasm)))]
+use std::collections::VecDeque;
 use std::future::Future;
 use std::panic::AssertUnwindSafe;
+#[cfg(all(feature = "thread-pool", not(wasm)))]
+use std::sync::Arc;
+#[cfg(feature = "thread-pool")]
+use std::sync::Mutex;
+
+/// The executor class which spawns a new thread for each call.
+#[cfg(not(wasm))]
+const DEDICATED_THREAD_EXECUTOR: &str = "dedicated_thread";
+
+/// The executor class which runs in the default thread pool, but before the other waiting calls.
+#[cfg(not(wasm))]
+const PRIORITY_EXECUTOR: &str = "priority";
 
 /// The default executor used.
 /// It creates an internal thread pool, and each call to a Rust function is
 /// handled by a different thread.
+///
+/// Functions annotated with `#[frb(executor = "name")]` are executed by the thread pool
+/// registered via [`with_thread_pool`](SimpleExecutor::with_thread_pool) under that name,
+/// or a new thread for each call if the name is `"dedicated_thread"` (not supported on the web).
+/// The name `"priority"` means the default thread pool, but the call is executed before
+/// the other calls waiting for a thread (not supported on the web).
+/// Other names fall back to the default thread pool, with a warning logged once per name.
 pub struct SimpleExecutor<EL: ErrorListener, TP: BaseThreadPool, AR: BaseAsyncRuntime> {
     error_listener: EL,
     thread_pool: TP,
+    named_thread_pools: HashMap<String, TP>,
+    #[cfg(all(feature = "thread-pool", not(wasm)))]
+    waiting_jobs: Arc<Mutex<WaitingJobs>>,
     async_runtime: AR,
 }
 
@@ -32,19 +67,100 @@ impl<EL: ErrorListener, TP: BaseThreadPool, AR: BaseAsyncRuntime> SimpleExecutor
         SimpleExecutor {
             error_listener,
             thread_pool,
+            named_thread_pools: HashMap::new(),
+            #[cfg(all(feature = "thread-pool", not(wasm)))]
+            waiting_jobs: Default::default(),
             async_runtime,
         }
     }
 
+    /// Execute the functions annotated with `#[frb(executor = "name")]` in the given thread pool,
+    /// such that e.g. long-running tasks do not block the others.
+    pub fn with_thread_pool(mut self, name: impl Into<String>, thread_pool: TP) -> Self {
+        self.named_thread_pools.insert(name.into(), thread_pool);
+        self
+    }
+
     pub fn thread_pool(&self) -> &TP {
         &self.thread_pool
     }
 
+    /// The thread pool registered via [`with_thread_pool`](SimpleExecutor::with_thread_pool).
+    pub fn named_thread_pool(&self, name: &str) -> Option<&TP> {
+        self.named_thread_pools.get(name)
+    }
+
+    #[cfg(feature = "thread-pool")]
+    fn thread_pool_for(&self, executor: Option<&'static str>) -> &TP {
+        let Some(name) = executor else {
+            return &self.thread_pool;
+        };
+        self.named_thread_pool(name).unwrap_or_else(|| {
+            warn_unknown_executor(name);
+            &self.thread_pool
+        })
+    }
+
+    /// Execute the job in the default thread pool, before the non-prioritized waiting jobs if `priority`.
+    #[cfg(all(feature = "thread-pool", not(wasm)))]
+    fn execute_in_default_thread_pool(&self, job: Job, priority: bool) {
+        let mut waiting_jobs = self.waiting_jobs.lock().unwrap();
+        if priority {
+            waiting_jobs.priority.push_back(job);
+        } else {
+            waiting_jobs.normal.push_back(job);
+        }
+        drop(waiting_jobs);
+
+        // Each execution runs exactly one job, thus every job is run, but whichever is the most
+        // important at the time when a thread becomes available, instead of this very job
+        let waiting_jobs = self.waiting_jobs.clone();
+        self.thread_pool.execute(move || {
+            let job = waiting_jobs.lock().unwrap().pop();
+            if let Some(job) = job {
+                job();
+            }
+        });
+    }
+
     pub fn async_runtime(&self) -> &AR {
         &self.async_runtime
     }
 }
 
+#[cfg(all(feature = "thread-pool", not(wasm)))]
+type Job = Box<dyn FnOnce() + Send>;
+
+/// The jobs waiting for a thread of the default thread pool.
+#[cfg(all(feature = "thread-pool", not(wasm)))]
+#[derive(Default)]
+struct WaitingJobs {
+    priority: VecDeque<Job>,
+    normal: VecDeque<Job>,
+}
+
+#[cfg(all(feature = "thread-pool", not(wasm)))]
+impl WaitingJobs {
+    fn pop(&mut self) -> Option<Job> {
+        self.priority
+            .pop_front()
+            .or_else(|| self.normal.pop_front())
+    }
+}
+
+/// Warn (once per name) when a function uses an executor name that has no registered thread pool,
+/// which is usually a typo or a missing [`with_thread_pool`](SimpleExecutor::with_thread_pool).
+#[cfg(feature = "thread-pool")]
+fn warn_unknown_executor(name: &'static str) {
+    static WARNED: Mutex<BTreeSet<&'static str>> = Mutex::new(BTreeSet::new());
+    if WARNED.lock().unwrap().insert(name) {
+        log_warn_or_println(&format!(
+            "No thread pool is registered for executor \"{name}\", thus the default thread pool is used. \
+            Register one via `SimpleExecutor::with_thread_pool`."
+        ));
+    }
+}
+
 impl<EL: ErrorListener + Sync, TP: BaseThreadPool, AR: BaseAsyncRuntime> Executor
     for SimpleExecutor<EL, TP, AR>
 {
@@ -59,32 +175,62 @@ impl<EL: ErrorListener + Sync, TP: BaseThreadPool, AR: BaseAsyncRuntime> Executo
         let el = self.error_listener;
         let el2 = self.error_listener;
 
-        let TaskInfo { port, .. } = task_info;
+        let TaskInfo {
+            port,
+            debug_name,
+            mode,
+            executor,
+        } = task_info;
         let port: MessagePort = port.unwrap();
+        let cancellation_token = register_cancellation(&port);
 
-        self.thread_pool
-            .execute(transfer!(|port: crate::platform_types::MessagePort| {
+        let job = transfer!(|port: crate::platform_types::MessagePort| {
+            #[allow(clippy::clone_on_copy)]
+            let task_info = TaskInfo {
+                port: Some(port.clone()),
+                debug_name,
+                mode,
+                executor,
+            };
+            let thread_result = PanicBacktrace::catch_unwind(AssertUnwindSafe(|| {
                 #[allow(clippy::clone_on_copy)]
-                let port2 = port.clone();
-                let thread_result = PanicBacktrace::catch_unwind(AssertUnwindSafe(|| {
-                    #[allow(clippy::clone_on_copy)]
-                    let sender = Rust2DartSender::new(Channel::new(port2.clone()));
-                    let task_context = TaskContext::new();
+                let sender = Rust2DartSender::new(Channel::new(port.clone()));
+                let task_context = TaskContext::with_cancellation_token(cancellation_token.clone());
 
-                    let ret = task(task_context);
+                let ret = cancellation_token.enter(|| task(task_context));
 
-                    ExecuteNormalOrAsyncUtils::handle_result::<Rust2DartCodec, _>(ret, sender, el2);
-                }));
-
-                if let Err(error) = thread_result {
-                    handle_non_sync_panic_error::<Rust2DartCodec>(el, port, error);
-                }
+                ExecuteNormalOrAsyncUtils::handle_result::<Rust2DartCodec, _>(
+                    ret, sender, el2, &task_info,
+                );
             }));
+
+            unregister_cancellation(&port);
+            if let Err(error) = thread_result {
+                handle_non_sync_panic_error::<Rust2DartCodec>(el, &task_info, error);
+            }
+        });
+
+        #[cfg(not(wasm))]
+        if executor == Some(DEDICATED_THREAD_EXECUTOR) {
+            std::thread::Builder::new()
+                .name(debug_name.to_owned())
+                .spawn(job)
+                .expect("Failed to spawn a dedicated thread");
+            return;
+        }
+
+        #[cfg(not(wasm))]
+        if executor.is_none() || executor == Some(PRIORITY_EXECUTOR) {
+            self.execute_in_default_thread_pool(Box::new(job), executor == Some(PRIORITY_EXECUTOR));
+            return;
+        }
+
+        self.thread_pool_for(executor).execute(job);
     }
 
     fn execute_sync<Rust2DartCodec, SyncTaskFn>(
         &self,
-        _task_info: TaskInfo,
+        task_info: TaskInfo,
         sync_task: SyncTaskFn,
     ) -> Rust2DartCodec::Message
     where
@@ -94,7 +240,8 @@ impl<EL: ErrorListener + Sync, TP: BaseThreadPool, AR: BaseAsyncRuntime> Executo
         match sync_task() {
             Ok(data) => data,
             Err(err) => {
-                self.error_listener.on_error(Error::CustomError);
+                (self.error_listener)
+                    .on_error(Error::CustomError(take_last_error_debug()), &task_info);
                 err
             }
         }
@@ -111,27 +258,40 @@ impl<EL: ErrorListener + Sync, TP: BaseThreadPool, AR: BaseAsyncRuntime> Executo
         let el = self.error_listener;
         let el2 = self.error_listener;
 
+        let cancellation_token = register_cancellation(task_info.port.as_ref().unwrap());
+
         self.async_runtime.spawn(async move {
-            let TaskInfo { port, .. } = task_info;
-            let port = port.unwrap();
+            #[allow(clippy::clone_on_copy)]
+            let port = task_info.port.clone().unwrap();
             #[allow(clippy::clone_on_copy)]
             let port2 = port.clone();
 
             let async_result = AssertUnwindSafe(async {
                 #[allow(clippy::clone_on_copy)]
                 let sender = Rust2DartSender::new(Channel::new(port2.clone()));
-                let task_context = TaskContext::new();
+                let task_context = TaskContext::with_cancellation_token(cancellation_token.clone());
 
-                let ret = task(task_context).await;
+                let ret =
+                    Cancellable::new(with_task_borrower(task(task_context)), cancellation_token)
+                        .await;
 
-                ExecuteNormalOrAsyncUtils::handle_result::<Rust2DartCodec, _>(ret, sender, el2);
+                match ret {
+                    Some(ret) => ExecuteNormalOrAsyncUtils::handle_result::<Rust2DartCodec, _>(
+                        ret, sender, el2, &task_info,
+                    ),
+                    // The Dart side no longer waits for the result, but the port still needs a message to be closed
+                    None => {
+                        sender.send_or_warn(Rust2DartCodec::Message::simplest().into_dart_abi())
+                    }
+                }
             })
             .catch_unwind()
             .await;
 
+            unregister_cancellation(&port);
             if let Err(err) = async_result {
                 let err = CatchUnwindWithBacktrace::new(err, PanicBacktrace::take_last());
-                handle_non_sync_panic_error::<Rust2DartCodec>(el, port, err);
+                handle_non_sync_panic_error::<Rust2DartCodec>(el, &task_info, err);
             }
         });
     }
@@ -144,6 +304,7 @@ impl ExecuteNormalOrAsyncUtils {
         ret: Result<Rust2DartCodec::Message, Rust2DartCodec::Message>,
         sender: Rust2DartSender,
         el: EL,
+        task_info: &TaskInfo,
     ) where
         EL: ErrorListener + Sync,
         Rust2DartCodec: BaseCodec,
@@ -153,9 +314,108 @@ impl ExecuteNormalOrAsyncUtils {
                 sender.send_or_warn(result.into_dart_abi());
             }
             Err(error) => {
-                el.on_error(Error::CustomError);
+                el.on_error(Error::CustomError(take_last_error_debug()), task_info);
                 sender.send_or_warn(error.into_dart_abi());
             }
         };
     }
 }
+
+#[cfg(all(test, feature = "thread-pool", not(wasm)))]
+mod tests {
+    use super::SimpleExecutor;
+    use crate::codec::sse::SseCodec;
+    use crate::handler::executor::Executor;
+    use crate::handler::handler::{FfiCallMode, TaskInfo};
+    use crate::handler::implementation::error_listener::NoOpErrorListener;
+    use crate::rust2dart::action::Rust2DartAction;
+    use crate::rust_async::SimpleAsyncRuntime;
+    use crate::thread_pool::SimpleThreadPool;
+    use std::sync::mpsc;
+
+    type MyExecutor = SimpleExecutor<NoOpErrorListener, SimpleThreadPool, SimpleAsyncRuntime>;
+
+    fn execute(
+        executor: &MyExecutor,
+        executor_class: Option<&'static str>,
+        task: impl FnOnce() + Send + 'static,
+    ) {
+        let task_info = TaskInfo {
+            port: Some(0),
+            debug_name: "my_func",
+            mode: FfiCallMode::Normal,
+            executor: executor_class,
+        };
+        executor.execute_normal::<SseCodec, _>(task_info, move |_| {
+            task();
+            Ok(SseCodec::encode(Rust2DartAction::Success, |_| {}))
+        });
+    }
+
+    fn execute_and_get_thread_name(
+        executor: &MyExecutor,
+        executor_class: Option<&'static str>,
+    ) -> String {
+        let (sender, receiver) = mpsc::channel();
+        execute(executor, executor_class, move || {
+            let name = std::thread::current().name().map(|x| x.to_owned());
+            sender.send(name.unwrap_or_default()).unwrap();
+        });
+        receiver.recv().unwrap()
+    }
+
+    #[test]
+    fn test_execute_normal_with_executor_class() {
+        let executor = SimpleExecutor::new(
+            NoOpErrorListener,
+            SimpleThreadPool(
+                threadpool::Builder::new()
+                    .thread_name("default".to_owned())
+                    .build(),
+            ),
+            SimpleAsyncRuntime::default(),
+        )
+        .with_thread_pool("io", SimpleThreadPool::new_with_name("io", Some(1)));
+
+        assert_eq!(execute_and_get_thread_name(&executor, None), "default");
+        assert_eq!(execute_and_get_thread_name(&executor, Some("io")), "io");
+        assert_eq!(
+            execute_and_get_thread_name(&executor, Some("unknown")),
+            "default"
+        );
+        assert_eq!(
+            execute_and_get_thread_name(&executor, Some("dedicated_thread")),
+            "my_func"
+        );
+    }
+
+    #[test]
+    fn test_execute_normal_with_priority() {
+        let executor = SimpleExecutor::new(
+            NoOpErrorListener,
+            SimpleThreadPool::new(1),
+            SimpleAsyncRuntime::default(),
+        );
+
+        // Occupy the only thread, such that the following calls wait
+        let (started_sender, started_receiver) = mpsc::channel();
+        let (release_sender, release_receiver) = mpsc::channel::<()>();
+        execute(&executor, None, move || {
+            started_sender.send(()).unwrap();
+            release_receiver.recv().unwrap();
+        });
+        started_receiver.recv().unwrap();
+
+        let (sender, receiver) = mpsc::channel();
+        for (name, executor_class) in [("a", None), ("b", None), ("c", Some("priority"))] {
+            let sender = sender.clone();
+            execute(&executor, executor_class, move || {
+                sender.send(name).unwrap()
+            });
+        }
+        drop(sender);
+        release_sender.send(()).unwrap();
+
+        assert_eq!(receiver.iter().collect::<Vec<_>>(), vec!["c", "a", "b"]);
+    }
+}
@@ -9,6 +9,8 @@ use crate::handler::implementation::error_listener::{
     handle_non_sync_panic_error, NoOpErrorListener,
 };
 use crate::handler::implementation::executor::SimpleExecutor;
+use crate::handler::implementation::interceptor::{CallInterceptor, NoOpInterceptor};
+use crate::handler::interceptor::Interceptor;
 use crate::misc::panic_backtrace::PanicBacktrace;
 use crate::platform_types::DartAbi;
 use crate::rust_async::SimpleAsyncRuntime;
@@ -29,6 +31,19 @@ impl<TP: BaseThreadPool> DefaultHandler<TP> {
         )
     }
 
+    /// Similar to [`new_simple`](Self::new_simple), and also registers the thread pools
+    /// for functions annotated with `#[frb(executor = "name")]`.
+    pub fn new_simple_with_thread_pools(
+        thread_pool: TP,
+        named_thread_pools: impl IntoIterator<Item = (&'static str, TP)>,
+    ) -> Self {
+        let executor = (named_thread_pools.into_iter()).fold(
+            SimpleExecutor::new(NoOpErrorListener, thread_pool, Default::default()),
+            |executor, (name, thread_pool)| executor.with_thread_pool(name, thread_pool),
+        );
+        Self::new(executor, NoOpErrorListener)
+    }
+
     pub fn thread_pool(&self) -> &TP {
         self.executor.thread_pool()
     }
@@ -39,9 +54,10 @@ impl<TP: BaseThreadPool> DefaultHandler<TP> {
 }
 
 /// The simple handler uses a simple thread pool to execute tasks.
-pub struct SimpleHandler<E: Executor, EL: ErrorListener> {
+pub struct SimpleHandler<E: Executor, EL: ErrorListener, I: Interceptor = NoOpInterceptor> {
     executor: E,
     error_listener: EL,
+    interceptor: I,
     #[cfg(all(feature = "rust-async", feature = "dart-opaque"))]
     dart_fn_handler: crate::dart_fn::handler::DartFnHandler,
 }
@@ -52,13 +68,27 @@ impl<E: Executor, H: ErrorListener> SimpleHandler<E, H> {
         SimpleHandler {
             executor,
             error_listener,
+            interceptor: NoOpInterceptor,
             #[cfg(all(feature = "rust-async", feature = "dart-opaque"))]
             dart_fn_handler: crate::dart_fn::handler::DartFnHandler::new(),
         }
     }
 }
 
-impl<E: Executor, EL: ErrorListener> Handler for SimpleHandler<E, EL> {
+impl<E: Executor, EL: ErrorListener, I: Interceptor> SimpleHandler<E, EL, I> {
+    /// Use the given interceptor to hook into every call to Rust functions.
+    pub fn with_interceptor<I2: Interceptor>(self, interceptor: I2) -> SimpleHandler<E, EL, I2> {
+        SimpleHandler {
+            executor: self.executor,
+            error_listener: self.error_listener,
+            interceptor,
+            #[cfg(all(feature = "rust-async", feature = "dart-opaque"))]
+            dart_fn_handler: self.dart_fn_handler,
+        }
+    }
+}
+
+impl<E: Executor, EL: ErrorListener, I: Interceptor> Handler for SimpleHandler<E, EL, I> {
     #[cfg(feature = "thread-pool")]
     fn wrap_normal<Rust2DartCodec, PrepareFn, TaskFn>(
         &self,
@@ -74,9 +104,11 @@ impl<E: Executor, EL: ErrorListener> Handler for SimpleHandler<E, EL> {
         self.wrap_normal_or_async::<Rust2DartCodec, _, _, _, _>(
             task_info,
             prepare,
-            |task_info, task| {
+            |task_info, task, call| {
                 self.executor
-                    .execute_normal::<Rust2DartCodec, _>(task_info, task)
+                    .execute_normal::<Rust2DartCodec, _>(task_info, move |task_context| {
+                        call.execute(|| task(task_context))
+                    })
             },
         )
     }
@@ -93,13 +125,17 @@ impl<E: Executor, EL: ErrorListener> Handler for SimpleHandler<E, EL> {
         // NOTE This extra [catch_unwind] **SHOULD** be put outside **ALL** code!
         // For reason, see comments in [wrap]
         panic::catch_unwind(AssertUnwindSafe(move || {
+            let task_info2 = task_info.clone();
+            let call = CallInterceptor::new(&self.interceptor, &task_info);
             let catch_unwind_result = PanicBacktrace::catch_unwind(AssertUnwindSafe(move || {
-                (self.executor).execute_sync::<Rust2DartCodec, _>(task_info, sync_task)
+                (self.executor)
+                    .execute_sync::<Rust2DartCodec, _>(task_info2, || call.execute(sync_task))
             }));
             catch_unwind_result
                 .unwrap_or_else(|error| {
                     let message = Rust2DartCodec::encode_panic(&error.err, &error.backtrace);
-                    self.error_listener.on_error(Error::Panic(error.err));
+                    (self.error_listener)
+                        .on_error(Error::Panic(error.err, error.backtrace), &task_info);
                     message
                 })
                 .into_raw_wire_sync()
@@ -125,9 +161,11 @@ impl<E: Executor, EL: ErrorListener> Handler for SimpleHandler<E, EL> {
         self.wrap_normal_or_async::<Rust2DartCodec, _, _, _, _>(
             task_info,
             prepare,
-            |task_info, task| {
+            |task_info, task, call| {
                 self.executor
-                    .execute_async::<Rust2DartCodec, _, _>(task_info, task)
+                    .execute_async::<Rust2DartCodec, _, _>(task_info, move |task_context| {
+                        call.execute_async(task(task_context))
+                    })
             },
         )
     }
@@ -147,7 +185,7 @@ impl<E: Executor, EL: ErrorListener> Handler for SimpleHandler<E, EL> {
     }
 }
 
-impl<E: Executor, EL: ErrorListener> SimpleHandler<E, EL> {
+impl<E: Executor, EL: ErrorListener, I: Interceptor> SimpleHandler<E, EL, I> {
     fn wrap_normal_or_async<Rust2DartCodec, PrepareFn, TaskFn, TaskFnRet, ExecuteFn>(
         &self,
         task_info: TaskInfo,
@@ -156,7 +194,7 @@ impl<E: Executor, EL: ErrorListener> SimpleHandler<E, EL> {
     ) where
         PrepareFn: FnOnce() -> TaskFn,
         TaskFn: FnOnce(TaskContext) -> TaskFnRet,
-        ExecuteFn: FnOnce(TaskInfo, TaskFn),
+        ExecuteFn: FnOnce(TaskInfo, TaskFn, CallInterceptor<I>),
         Rust2DartCodec: BaseCodec,
     {
         // NOTE This extra [catch_unwind] **SHOULD** be put outside **ALL** code!
@@ -170,12 +208,13 @@ impl<E: Executor, EL: ErrorListener> SimpleHandler<E, EL> {
         let _ = panic::catch_unwind(AssertUnwindSafe(move || {
             let task_info2 = task_info.clone();
             if let Err(error) = PanicBacktrace::catch_unwind(AssertUnwindSafe(move || {
-                let task = prepare();
-                execute(task_info2, task);
+                let call = CallInterceptor::new(&self.interceptor, &task_info2);
+                let task = call.prepare(&task_info2, prepare);
+                execute(task_info2, task, call);
             })) {
                 handle_non_sync_panic_error::<Rust2DartCodec>(
                     self.error_listener,
-                    task_info.port.unwrap(),
+                    &task_info,
                     error,
                 );
             }
@@ -0,0 +1,307 @@
+use crate::handler::handler::{FfiCallMode, TaskInfo};
+use crate::handler::interceptor::{Interceptor, InterceptorPhase};
+use std::cell::RefCell;
+#[cfg(feature = "rust-async")]
+use std::future::Future;
+#[cfg(feature = "rust-async")]
+use std::pin::Pin;
+use std::sync::Arc;
+#[cfg(feature = "rust-async")]
+use std::task::{Context, Poll};
+
+/// The default one.
+#[derive(Clone, Copy)]
+pub struct NoOpInterceptor;
+
+impl Interceptor for NoOpInterceptor {}
+
+type EncodeHook = Arc<dyn Fn(&mut dyn FnMut()) + Send + Sync>;
+
+thread_local! {
+    static ENCODE_HOOK: RefCell<Option<EncodeHook>> = const { RefCell::new(None) };
+}
+
+/// Runs the encoding of the output of a Rust function in the [`InterceptorPhase::Encode`] phase.
+#[doc(hidden)]
+pub fn intercept_encode<R>(f: impl FnOnce() -> R) -> R {
+    let Some(hook) = ENCODE_HOOK.with(|x| x.borrow().clone()) else {
+        return f();
+    };
+
+    let mut f = Some(f);
+    let mut output = None;
+    hook(&mut || output = Some((f.take().unwrap())()));
+    output.unwrap()
+}
+
+/// Intercepts the phases of one call.
+#[derive(Clone)]
+pub(crate) struct CallInterceptor<I> {
+    interceptor: I,
+    debug_name: &'static str,
+    mode: FfiCallMode,
+    executor: Option<&'static str>,
+    #[cfg(feature = "tracing")]
+    span: tracing::Span,
+}
+
+impl<I: Interceptor> CallInterceptor<I> {
+    pub(crate) fn new(interceptor: &I, task_info: &TaskInfo) -> Self {
+        Self {
+            interceptor: interceptor.clone(),
+            debug_name: task_info.debug_name,
+            mode: task_info.mode,
+            executor: task_info.executor,
+            #[cfg(feature = "tracing")]
+            span: tracing::info_span!(
+                "frb_call",
+                function = task_info.debug_name,
+                mode = ?task_info.mode
+            ),
+        }
+    }
+
+    pub(crate) fn prepare<R>(&self, task_info: &TaskInfo, f: impl FnOnce() -> R) -> R {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::info_span!(parent: &self.span, "frb_prepare").entered();
+        self.intercept(task_info, InterceptorPhase::Prepare, f)
+    }
+
+    pub(crate) fn execute<R>(&self, f: impl FnOnce() -> R) -> R {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::info_span!(parent: &self.span, "frb_execute").entered();
+        let task_info = self.task_info();
+        self.intercept(&task_info, InterceptorPhase::Execute, || {
+            self.with_encode_hook(&self.encode_hook(), f)
+        })
+    }
+
+    #[cfg(feature = "rust-async")]
+    pub(crate) fn execute_async<F: Future>(self, future: F) -> InterceptedFuture<F, I> {
+        InterceptedFuture {
+            inner: future,
+            #[cfg(feature = "tracing")]
+            span: tracing::info_span!(parent: &self.span, "frb_execute"),
+            encode_hook: self.encode_hook(),
+            call: self,
+            state: InterceptedFutureState::NotStarted,
+        }
+    }
+
+    fn intercept<R>(
+        &self,
+        task_info: &TaskInfo,
+        phase: InterceptorPhase,
+        f: impl FnOnce() -> R,
+    ) -> R {
+        self.interceptor.on_enter(task_info, phase);
+        let _guard = ExitGuard {
+            interceptor: &self.interceptor,
+            task_info,
+            phase,
+        };
+        f()
+    }
+
+    fn encode_hook(&self) -> EncodeHook {
+        let call = self.clone();
+        Arc::new(move |f| {
+            #[cfg(feature = "tracing")]
+            let _span = tracing::info_span!(parent: &call.span, "frb_encode").entered();
+            call.intercept(&call.task_info(), InterceptorPhase::Encode, f)
+        })
+    }
+
+    fn with_encode_hook<R>(&self, hook: &EncodeHook, f: impl FnOnce() -> R) -> R {
+        let previous = ENCODE_HOOK.with(|x| x.replace(Some(hook.clone())));
+        struct Reset(Option<EncodeHook>);
+        impl Drop for Reset {
+            fn drop(&mut self) {
+                ENCODE_HOOK.with(|x| *x.borrow_mut() = self.0.take());
+            }
+        }
+        let _reset = Reset(previous);
+        f()
+    }
+
+    // The port is not kept, since it cannot be sent across threads on the web
+    fn task_info(&self) -> TaskInfo {
+        TaskInfo {
+            port: None,
+            debug_name: self.debug_name,
+            mode: self.mode,
+            executor: self.executor,
+        }
+    }
+}
+
+struct ExitGuard<'a, I: Interceptor> {
+    interceptor: &'a I,
+    task_info: &'a TaskInfo,
+    phase: InterceptorPhase,
+}
+
+impl<I: Interceptor> Drop for ExitGuard<'_, I> {
+    fn drop(&mut self) {
+        self.interceptor.on_exit(self.task_info, self.phase);
+    }
+}
+
+#[cfg(feature = "rust-async")]
+pub(crate) struct InterceptedFuture<F, I: Interceptor> {
+    inner: F,
+    call: CallInterceptor<I>,
+    encode_hook: EncodeHook,
+    #[cfg(feature = "tracing")]
+    span: tracing::Span,
+    state: InterceptedFutureState,
+}
+
+#[cfg(feature = "rust-async")]
+#[derive(PartialEq, Eq)]
+enum InterceptedFutureState {
+    NotStarted,
+    Started,
+    Done,
+}
+
+#[cfg(feature = "rust-async")]
+impl<F: Future, I: Interceptor> Future for InterceptedFuture<F, I> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // SAFETY: `inner` is structurally pinned, i.e. it is never moved out of `self`
+        let this = unsafe { self.get_unchecked_mut() };
+        #[cfg(feature = "tracing")]
+        let _span = this.span.clone().entered();
+
+        if this.state == InterceptedFutureState::NotStarted {
+            this.state = InterceptedFutureState::Started;
+            let task_info = this.call.task_info();
+            (this.call.interceptor).on_enter(&task_info, InterceptorPhase::Execute);
+        }
+
+        let inner = unsafe { Pin::new_unchecked(&mut this.inner) };
+        let output = this
+            .call
+            .with_encode_hook(&this.encode_hook, || inner.poll(cx));
+
+        if output.is_ready() {
+            this.finish();
+        }
+        output
+    }
+}
+
+#[cfg(feature = "rust-async")]
+impl<F, I: Interceptor> InterceptedFuture<F, I> {
+    fn finish(&mut self) {
+        if self.state == InterceptedFutureState::Started {
+            self.state = InterceptedFutureState::Done;
+            let task_info = self.call.task_info();
+            (self.call.interceptor).on_exit(&task_info, InterceptorPhase::Execute);
+        }
+    }
+}
+
+#[cfg(feature = "rust-async")]
+impl<F, I: Interceptor> Drop for InterceptedFuture<F, I> {
+    fn drop(&mut self) {
+        // e.g. when cancelled or panicked
+        self.finish();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{intercept_encode, CallInterceptor};
+    use crate::handler::handler::{FfiCallMode, TaskInfo};
+    use crate::handler::interceptor::{Interceptor, InterceptorPhase};
+    use std::panic::AssertUnwindSafe;
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Clone, Default)]
+    struct RecordingInterceptor(Arc<Mutex<Vec<String>>>);
+
+    impl Interceptor for RecordingInterceptor {
+        fn on_enter(&self, task_info: &TaskInfo, phase: InterceptorPhase) {
+            (self.0.lock().unwrap()).push(format!("enter {} {phase:?}", task_info.debug_name));
+        }
+
+        fn on_exit(&self, task_info: &TaskInfo, phase: InterceptorPhase) {
+            (self.0.lock().unwrap()).push(format!("exit {} {phase:?}", task_info.debug_name));
+        }
+    }
+
+    fn create_call() -> (
+        CallInterceptor<RecordingInterceptor>,
+        RecordingInterceptor,
+        TaskInfo,
+    ) {
+        let interceptor = RecordingInterceptor::default();
+        let task_info = TaskInfo {
+            port: None,
+            debug_name: "f",
+            mode: FfiCallMode::Normal,
+            executor: None,
+        };
+        let call = CallInterceptor::new(&interceptor, &task_info);
+        (call, interceptor, task_info)
+    }
+
+    #[test]
+    fn test_phases() {
+        let (call, interceptor, task_info) = create_call();
+        let output = call.prepare(&task_info, || 1);
+        let output = call.execute(|| intercept_encode(|| output + 1));
+        assert_eq!(output, 2);
+        assert_eq!(
+            *interceptor.0.lock().unwrap(),
+            vec![
+                "enter f Prepare",
+                "exit f Prepare",
+                "enter f Execute",
+                "enter f Encode",
+                "exit f Encode",
+                "exit f Execute",
+            ]
+        );
+
+        // outside of a call
+        assert_eq!(intercept_encode(|| 3), 3);
+        assert_eq!(interceptor.0.lock().unwrap().len(), 6);
+    }
+
+    #[test]
+    fn test_exit_when_panic() {
+        let (call, interceptor, _) = create_call();
+        let result = std::panic::catch_unwind(AssertUnwindSafe(|| call.execute(|| panic!())));
+        assert!(result.is_err());
+        assert_eq!(
+            *interceptor.0.lock().unwrap(),
+            vec!["enter f Execute", "exit f Execute"]
+        );
+    }
+
+    #[cfg(feature = "rust-async")]
+    #[tokio::test]
+    async fn test_execute_async() {
+        let (call, interceptor, _) = create_call();
+        let output = call
+            .execute_async(async {
+                tokio::task::yield_now().await;
+                intercept_encode(|| 42)
+            })
+            .await;
+        assert_eq!(output, 42);
+        assert_eq!(
+            *interceptor.0.lock().unwrap(),
+            vec![
+                "enter f Execute",
+                "enter f Encode",
+                "exit f Encode",
+                "exit f Execute",
+            ]
+        );
+    }
+}
@@ -1,3 +1,4 @@
 pub(crate) mod error_listener;
 pub(crate) mod executor;
 pub(crate) mod handler;
+pub(crate) mod interceptor;
@@ -0,0 +1,26 @@
+use crate::handler::handler::TaskInfo;
+
+/// The phases of handling a call to a Rust function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InterceptorPhase {
+    /// Decoding the arguments. Not available for sync functions, which decode inside [`Execute`](InterceptorPhase::Execute).
+    Prepare,
+    /// Executing the Rust function, including [`Encode`](InterceptorPhase::Encode).
+    Execute,
+    /// Encoding the output (or error) of the Rust function.
+    Encode,
+}
+
+/// Hooks around the phases of every call to Rust functions,
+/// e.g. to measure the latency or to add logs.
+///
+/// `on_exit` is always called once for each `on_enter`, even if the phase panics.
+/// For async functions, the [`Execute`](InterceptorPhase::Execute) phase starts at the first poll
+/// of the future and ends when it completes, thus the two hooks may run on different threads.
+///
+/// Only in the [`Prepare`](InterceptorPhase::Prepare) phase, `task_info.port` is available.
+pub trait Interceptor: Clone + Send + Sync + 'static {
+    fn on_enter(&self, _task_info: &TaskInfo, _phase: InterceptorPhase) {}
+
+    fn on_exit(&self, _task_info: &TaskInfo, _phase: InterceptorPhase) {}
+}
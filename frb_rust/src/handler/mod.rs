@@ -1,10 +1,13 @@
+pub(crate) mod cancellation;
 pub(crate) mod error;
 pub(crate) mod error_listener;
 pub(crate) mod executor;
 #[allow(clippy::module_inception)]
 pub(crate) mod handler;
 pub(crate) mod implementation;
+pub(crate) mod interceptor;
 
+pub use cancellation::CancellationToken;
 pub use error::Error as HandlerError;
 pub use error_listener::ErrorListener;
 pub use executor::Executor;
@@ -13,3 +16,5 @@ pub use handler::{TaskContext, TaskRetFutTrait};
 pub use implementation::error_listener::NoOpErrorListener;
 pub use implementation::executor::SimpleExecutor;
 pub use implementation::handler::SimpleHandler;
+pub use implementation::interceptor::NoOpInterceptor;
+pub use interceptor::{Interceptor, InterceptorPhase};
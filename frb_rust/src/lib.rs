@@ -22,12 +22,12 @@ pub(crate) mod lifetimeable;
 pub(crate) mod lockable;
 #[doc(hidden)] // only to be used as `for_generated::rust_async`
 pub mod rust_async;
-#[cfg(feature = "rust-async")]
 pub(crate) mod rust_auto_opaque;
 pub(crate) mod rust_opaque;
 pub(crate) mod stream;
 pub(crate) mod web_transfer;
 
+pub use crate::codec::cse::CseCodec;
 pub use crate::codec::sse::Dart2RustMessageSse;
 pub use crate::codec::sse::SseCodec;
 pub use crate::codec::{BaseCodec, Rust2DartMessageTrait};
@@ -38,6 +38,8 @@ pub use crate::dart_opaque::DartOpaque;
 pub use crate::generalized_isolate::{IntoDart, ZeroCopyBuffer};
 pub use crate::handler::handler::Handler;
 pub use crate::handler::implementation::handler::DefaultHandler;
+pub use crate::handler::CancellationToken;
+pub use crate::lockable::timeout::set_rust_auto_opaque_lock_timeout;
 pub use crate::misc::dart_dynamic::DartDynamic;
 pub use crate::misc::into_into_dart::IntoIntoDart;
 pub use crate::misc::panic_backtrace::{CatchUnwindWithBacktrace, PanicBacktrace};
@@ -47,12 +49,24 @@ pub use crate::platform_types::DartAbi;
 pub use crate::rust2dart::sender::Rust2DartSendError;
 #[cfg(all(feature = "rust-async", feature = "thread-pool"))]
 pub use crate::rust_async::spawn_blocking_with;
+#[cfg(all(feature = "rust-async", not(wasm)))]
+pub use crate::rust_async::AsyncRuntimeBuilder;
+#[cfg(all(feature = "async-std", not(wasm)))]
+pub use crate::rust_async::AsyncStdAsyncRuntime;
 #[cfg(feature = "rust-async")]
 pub use crate::rust_async::{spawn, spawn_local, BaseAsyncRuntime, JoinHandle, SimpleAsyncRuntime};
-#[cfg(feature = "rust-async")]
+#[cfg(all(feature = "smol", not(wasm)))]
+pub use crate::rust_async::{SmolAsyncRuntime, SmolJoinHandle};
+pub use crate::rust_auto_opaque::lock::{
+    RustAutoOpaqueLockStrategy, RustAutoOpaqueReadGuard, RustAutoOpaqueTryLockError,
+    RustAutoOpaqueWriteGuard,
+};
 pub use crate::rust_auto_opaque::RustAutoOpaqueNom;
 #[allow(deprecated)]
 pub use crate::rust_opaque::{DartSafe, RustOpaqueNom};
+#[cfg(all(feature = "rust-async", feature = "dart-opaque", feature = "anyhow"))]
+pub use crate::stream::dart_stream::DartStream;
+pub use crate::stream::stream_sink::{StreamSinkOverflowPolicy, StreamSinkTryAddError};
 #[cfg(feature = "thread-pool")]
 pub use crate::thread_pool::{BaseThreadPool, SimpleThreadPool};
 pub use flutter_rust_bridge_macros::frb;
@@ -12,6 +12,11 @@ pub trait Lockable {
 
     fn lockable_order(&self) -> LockableOrder;
 
+    /// Whether a shared borrow excludes all other borrows, e.g. when the lock is a mutex.
+    fn lockable_exclusive(&self) -> bool {
+        false
+    }
+
     fn lockable_decode_sync_ref(&self) -> Self::RwLockReadGuard<'_>;
 
     fn lockable_decode_sync_ref_mut(&self) -> Self::RwLockWriteGuard<'_>;
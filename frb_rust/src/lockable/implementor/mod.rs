@@ -1,2 +1 @@
-#[cfg(feature = "rust-async")]
 pub(crate) mod rust_auto_opaque;
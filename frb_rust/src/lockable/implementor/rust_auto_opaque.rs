@@ -1,25 +1,36 @@
 use crate::for_generated::{BaseArc, RustAutoOpaqueInner, RustOpaqueBase};
 use crate::lockable::base::Lockable;
 use crate::lockable::order::LockableOrder;
+use crate::rust_auto_opaque::lock::{RustAutoOpaqueReadGuard, RustAutoOpaqueWriteGuard};
 use std::future::Future;
 use std::pin::Pin;
 
 impl<T: Send + Sync, A: BaseArc<RustAutoOpaqueInner<T>>> Lockable
     for RustOpaqueBase<RustAutoOpaqueInner<T>, A>
 {
-    type RwLockReadGuard<'a> = crate::rust_async::RwLockReadGuard<'a, T> where A: 'a;
-    type RwLockWriteGuard<'a> = crate::rust_async::RwLockWriteGuard<'a, T>where A: 'a;
+    type RwLockReadGuard<'a>
+        = RustAutoOpaqueReadGuard<'a, T>
+    where
+        A: 'a;
+    type RwLockWriteGuard<'a>
+        = RustAutoOpaqueWriteGuard<'a, T>
+    where
+        A: 'a;
 
     fn lockable_order(&self) -> LockableOrder {
         self.order
     }
 
+    fn lockable_exclusive(&self) -> bool {
+        self.data.is_exclusive()
+    }
+
     fn lockable_decode_sync_ref(&self) -> Self::RwLockReadGuard<'_> {
-        self.data.blocking_read()
+        (**self).blocking_read()
     }
 
     fn lockable_decode_sync_ref_mut(&self) -> Self::RwLockWriteGuard<'_> {
-        self.data.blocking_write()
+        (**self).blocking_write()
     }
 
     fn lockable_decode_async_ref<'a>(
@@ -28,7 +39,7 @@ impl<T: Send + Sync, A: BaseArc<RustAutoOpaqueInner<T>>> Lockable
     where
         Self: Sync + 'a,
     {
-        Box::pin(async move { self.data.read().await })
+        Box::pin(async move { (**self).read().await })
     }
 
     fn lockable_decode_async_ref_mut<'a>(
@@ -37,6 +48,6 @@ impl<T: Send + Sync, A: BaseArc<RustAutoOpaqueInner<T>>> Lockable
     where
         Self: Sync + 'a,
     {
-        Box::pin(async move { self.data.write().await })
+        Box::pin(async move { (**self).write().await })
     }
 }
@@ -4,3 +4,6 @@ pub(crate) mod implementor;
 pub(crate) mod order;
 pub(crate) mod order_computer;
 pub(crate) mod order_info;
+pub(crate) mod site;
+pub(crate) mod timeout;
+pub(crate) mod wait_for_graph;
@@ -12,7 +12,23 @@ impl LockableOrderInfo {
         Self {
             object_order: object.lockable_order(),
             index,
-            mutable,
+            mutable: mutable || object.lockable_exclusive(),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{RustAutoOpaqueLockStrategy, RustAutoOpaqueNom};
+
+    #[test]
+    fn test_mutable_when_exclusive() {
+        let rw_lock = RustAutoOpaqueNom::new(42);
+        let mutex =
+            RustAutoOpaqueNom::new_with_lock_strategy(42, RustAutoOpaqueLockStrategy::Mutex);
+        assert!(!LockableOrderInfo::new(&rw_lock.0, 0, false).mutable);
+        assert!(LockableOrderInfo::new(&rw_lock.0, 0, true).mutable);
+        assert!(LockableOrderInfo::new(&mutex.0, 0, false).mutable);
+    }
+}
@@ -0,0 +1,111 @@
+use std::cell::Cell;
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// Which argument of which function is being borrowed, only used to produce helpful errors.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct LockableSite {
+    pub(crate) func_name: &'static str,
+    pub(crate) arg_name: &'static str,
+}
+
+thread_local! {
+    static CURRENT: Cell<Option<LockableSite>> = const { Cell::new(None) };
+}
+
+impl LockableSite {
+    pub(crate) fn current() -> Option<Self> {
+        CURRENT.with(|current| current.get())
+    }
+
+    fn enter<R>(self, f: impl FnOnce() -> R) -> R {
+        let previous = CURRENT.with(|current| current.replace(Some(self)));
+        struct Reset(Option<LockableSite>);
+        impl Drop for Reset {
+            fn drop(&mut self) {
+                CURRENT.with(|current| current.set(self.0));
+            }
+        }
+        let _reset = Reset(previous);
+        f()
+    }
+}
+
+impl fmt::Display for LockableSite {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "argument `{}` of function `{}`",
+            self.arg_name, self.func_name
+        )
+    }
+}
+
+/// Human-readable description of the object being borrowed on this thread.
+pub(crate) fn describe_current_lockable_site() -> String {
+    match LockableSite::current() {
+        Some(site) => site.to_string(),
+        None => "a RustAutoOpaque object".to_owned(),
+    }
+}
+
+pub fn lockable_with_site<R>(
+    func_name: &'static str,
+    arg_name: &'static str,
+    f: impl FnOnce() -> R,
+) -> R {
+    LockableSite {
+        func_name,
+        arg_name,
+    }
+    .enter(f)
+}
+
+pub fn lockable_with_site_async<F: Future>(
+    func_name: &'static str,
+    arg_name: &'static str,
+    inner: F,
+) -> LockableWithSite<F> {
+    LockableWithSite {
+        inner,
+        site: LockableSite {
+            func_name,
+            arg_name,
+        },
+    }
+}
+
+pub struct LockableWithSite<F> {
+    inner: F,
+    site: LockableSite,
+}
+
+impl<F: Future> Future for LockableWithSite<F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // SAFETY: `inner` is structurally pinned, i.e. it is never moved out of `self`
+        let this = unsafe { self.get_unchecked_mut() };
+        let inner = unsafe { Pin::new_unchecked(&mut this.inner) };
+        this.site.enter(|| inner.poll(cx))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lockable_with_site() {
+        assert_eq!(describe_current_lockable_site(), "a RustAutoOpaque object");
+        lockable_with_site("f", "a", || {
+            assert_eq!(
+                describe_current_lockable_site(),
+                "argument `a` of function `f`"
+            );
+        });
+        assert_eq!(describe_current_lockable_site(), "a RustAutoOpaque object");
+    }
+}
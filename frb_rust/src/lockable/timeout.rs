@@ -0,0 +1,278 @@
+use crate::lockable::site::LockableSite;
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+/// Zero means no timeout
+static LOCK_TIMEOUT_MICROS: AtomicU64 = AtomicU64::new(0);
+
+/// Make borrows of `RustAutoOpaque` objects fail (i.e. panic, thus the Dart side receives an exception)
+/// when they wait longer than the timeout, instead of hanging forever, e.g. in a deadlock.
+///
+/// Borrows that need to wait are polled with backoff instead of queued fairly when the timeout is set.
+/// It is not supported on the web.
+pub fn set_rust_auto_opaque_lock_timeout(timeout: Option<Duration>) {
+    let micros = timeout.map_or(0, |x| (x.as_micros() as u64).max(1));
+    LOCK_TIMEOUT_MICROS.store(micros, Ordering::Relaxed);
+}
+
+pub(crate) fn lock_timeout() -> Option<Duration> {
+    if cfg!(wasm) {
+        return None;
+    }
+    match LOCK_TIMEOUT_MICROS.load(Ordering::Relaxed) {
+        0 => None,
+        micros => Some(Duration::from_micros(micros)),
+    }
+}
+
+/// The panic payload when a borrow times out, which becomes a `RustAutoOpaqueLockTimeoutException`
+/// instead of a plain `PanicException` on the Dart side.
+#[derive(Debug)]
+pub(crate) struct LockTimeoutPanic {
+    pub(crate) site: Option<LockableSite>,
+    pub(crate) timeout: Duration,
+}
+
+impl fmt::Display for LockTimeoutPanic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Timed out after {:?} when borrowing ", self.timeout)?;
+        match &self.site {
+            Some(site) => write!(f, "{site}")?,
+            None => write!(f, "a RustAutoOpaque object")?,
+        }
+        write!(f, ", maybe because of a deadlock")
+    }
+}
+
+#[cfg(not(wasm))]
+pub(crate) fn blocking_acquire_with_timeout<G, E>(
+    try_acquire: impl Fn() -> Result<G, E>,
+    timeout: Duration,
+) -> Option<G> {
+    let deadline = std::time::Instant::now() + timeout;
+    let mut backoff = Duration::from_micros(50);
+    loop {
+        if let Ok(guard) = try_acquire() {
+            return Some(guard);
+        }
+        if std::time::Instant::now() >= deadline {
+            return None;
+        }
+        std::thread::sleep(backoff);
+        backoff = (backoff * 2).min(Duration::from_millis(10));
+    }
+}
+
+#[cfg(wasm)]
+pub(crate) fn blocking_acquire_with_timeout<G, E>(
+    _try_acquire: impl Fn() -> Result<G, E>,
+    _timeout: Duration,
+) -> Option<G> {
+    unreachable!("lock timeout is not supported on the web")
+}
+
+#[cfg(all(feature = "rust-async", not(wasm)))]
+pub(crate) async fn acquire_with_timeout<F: std::future::Future>(
+    inner: F,
+    timeout: Duration,
+) -> Option<F::Output> {
+    use futures::future::{select, Either};
+
+    match select(std::pin::pin!(inner), Delay::new(timeout)).await {
+        Either::Left((guard, _)) => Some(guard),
+        Either::Right(_) => None,
+    }
+}
+
+#[cfg(not(all(feature = "rust-async", not(wasm))))]
+pub(crate) async fn acquire_with_timeout<F: std::future::Future>(
+    inner: F,
+    _timeout: Duration,
+) -> Option<F::Output> {
+    Some(inner.await)
+}
+
+/// A timer which does not depend on the async runtime, since the runtime is pluggable.
+/// All delays share one timer thread, which is spawned when a borrow first has to wait.
+#[cfg(all(feature = "rust-async", not(wasm)))]
+struct Delay(std::sync::Arc<std::sync::Mutex<DelayState>>);
+
+#[cfg(all(feature = "rust-async", not(wasm)))]
+#[derive(Default)]
+struct DelayState {
+    done: bool,
+    waker: Option<std::task::Waker>,
+}
+
+#[cfg(all(feature = "rust-async", not(wasm)))]
+impl Delay {
+    fn new(duration: Duration) -> Self {
+        let state = std::sync::Arc::new(std::sync::Mutex::new(DelayState::default()));
+        timer::schedule(
+            std::time::Instant::now() + duration,
+            std::sync::Arc::downgrade(&state),
+        );
+        Self(state)
+    }
+}
+
+#[cfg(all(feature = "rust-async", not(wasm)))]
+mod timer {
+    use super::DelayState;
+    use lazy_static::lazy_static;
+    use std::cmp::{Ordering, Reverse};
+    use std::collections::BinaryHeap;
+    use std::sync::{Condvar, Mutex, Weak};
+    use std::time::Instant;
+
+    struct Entry {
+        deadline: Instant,
+        // Weak, since the delay is usually dropped early, i.e. when the borrow succeeds
+        state: Weak<Mutex<DelayState>>,
+    }
+
+    impl PartialEq for Entry {
+        fn eq(&self, other: &Self) -> bool {
+            self.deadline == other.deadline
+        }
+    }
+
+    impl Eq for Entry {}
+
+    impl PartialOrd for Entry {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Entry {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.deadline.cmp(&other.deadline)
+        }
+    }
+
+    #[derive(Default)]
+    struct Timer {
+        queue: Mutex<BinaryHeap<Reverse<Entry>>>,
+        condvar: Condvar,
+    }
+
+    lazy_static! {
+        static ref TIMER: &'static Timer = {
+            let timer: &'static Timer = Box::leak(Box::default());
+            std::thread::Builder::new()
+                .name("frb_lock_timeout".to_owned())
+                .spawn(move || timer.run())
+                .expect("failed to spawn the lock timeout thread");
+            timer
+        };
+    }
+
+    pub(super) fn schedule(deadline: Instant, state: Weak<Mutex<DelayState>>) {
+        TIMER
+            .queue
+            .lock()
+            .unwrap()
+            .push(Reverse(Entry { deadline, state }));
+        // The new entry may be the earliest one
+        TIMER.condvar.notify_one();
+    }
+
+    impl Timer {
+        fn run(&self) {
+            let mut queue = self.queue.lock().unwrap();
+            loop {
+                let now = Instant::now();
+                queue = match queue.peek() {
+                    None => self.condvar.wait(queue).unwrap(),
+                    Some(Reverse(entry)) if entry.deadline > now => {
+                        let timeout = entry.deadline - now;
+                        self.condvar.wait_timeout(queue, timeout).unwrap().0
+                    }
+                    Some(_) => {
+                        let Reverse(entry) = queue.pop().unwrap();
+                        if let Some(state) = entry.state.upgrade() {
+                            let mut state = state.lock().unwrap();
+                            state.done = true;
+                            if let Some(waker) = state.waker.take() {
+                                waker.wake();
+                            }
+                        }
+                        queue
+                    }
+                };
+            }
+        }
+    }
+}
+
+#[cfg(all(feature = "rust-async", not(wasm)))]
+impl std::future::Future for Delay {
+    type Output = ();
+
+    fn poll(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<()> {
+        let mut state = self.0.lock().unwrap();
+        if state.done {
+            return std::task::Poll::Ready(());
+        }
+        state.waker = Some(cx.waker().clone());
+        std::task::Poll::Pending
+    }
+}
+
+#[cfg(all(test, not(wasm)))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blocking_acquire_with_timeout() {
+        assert_eq!(
+            blocking_acquire_with_timeout(|| Ok::<_, ()>(42), Duration::from_millis(10)),
+            Some(42)
+        );
+        assert_eq!(
+            blocking_acquire_with_timeout(|| Err::<(), _>(()), Duration::from_millis(10)),
+            None
+        );
+    }
+
+    #[cfg(feature = "rust-async")]
+    #[tokio::test]
+    async fn test_delay() {
+        let start = std::time::Instant::now();
+        let long = Delay::new(Duration::from_secs(60));
+        Delay::new(Duration::from_millis(10)).await;
+        Delay::new(Duration::from_millis(10)).await;
+        assert!(start.elapsed() < Duration::from_secs(30));
+        drop(long);
+    }
+
+    #[test]
+    fn test_lock_timeout_panic_message() {
+        let panic = LockTimeoutPanic {
+            site: None,
+            timeout: Duration::from_millis(50),
+        };
+        assert_eq!(
+            panic.to_string(),
+            "Timed out after 50ms when borrowing a RustAutoOpaque object, maybe because of a deadlock"
+        );
+    }
+
+    #[cfg(feature = "rust-async")]
+    #[tokio::test]
+    async fn test_acquire_with_timeout() {
+        assert_eq!(
+            acquire_with_timeout(async { 42 }, Duration::from_millis(10)).await,
+            Some(42)
+        );
+        assert_eq!(
+            acquire_with_timeout(std::future::pending::<()>(), Duration::from_millis(10)).await,
+            None
+        );
+    }
+}
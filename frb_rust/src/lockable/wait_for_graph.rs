@@ -0,0 +1,239 @@
+//! A wait-for graph over the borrowed objects, used to detect deadlocks instead of hanging forever.
+//!
+//! It is only enabled in debug mode, since every borrow needs to update the global graph.
+
+use crate::lockable::order::LockableOrder;
+use lazy_static::lazy_static;
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::task::{Context, Poll};
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub(crate) struct BorrowerId(u64);
+
+static NEXT_BORROWER_ID: AtomicU64 = AtomicU64::new(0);
+
+impl BorrowerId {
+    fn new() -> Self {
+        Self(NEXT_BORROWER_ID.fetch_add(1, Ordering::Relaxed))
+    }
+
+    /// A thread cannot do anything else while blocked in a borrow, thus it is the borrower.
+    pub(crate) fn current_thread() -> Self {
+        thread_local! {
+            static CURRENT: BorrowerId = BorrowerId::new();
+        }
+        CURRENT.with(|id| *id)
+    }
+
+    /// An async borrow is not bound to any thread, thus it is a separate borrower,
+    /// unless it is inside a task wrapped by [`with_task_borrower`].
+    pub(crate) fn unique() -> Self {
+        Self::new()
+    }
+
+    /// The task being polled on this thread if any, since a blocking borrow blocks the whole task,
+    /// otherwise the thread itself.
+    pub(crate) fn current() -> Self {
+        Self::current_task().unwrap_or_else(Self::current_thread)
+    }
+
+    /// The task being polled on this thread, if it is wrapped by [`with_task_borrower`].
+    pub(crate) fn current_task() -> Option<Self> {
+        CURRENT_TASK.with(|current| current.get())
+    }
+}
+
+thread_local! {
+    static CURRENT_TASK: Cell<Option<BorrowerId>> = const { Cell::new(None) };
+}
+
+/// Make all borrows inside `inner` share one borrower, like the borrows of a thread,
+/// since the task cannot proceed while it waits for any of them.
+///
+/// Thus borrows that are awaited concurrently inside one task (e.g. via `join!`)
+/// may be reported as a deadlock.
+pub(crate) fn with_task_borrower<F: Future>(inner: F) -> WithTaskBorrower<F> {
+    WithTaskBorrower {
+        inner,
+        borrower: BorrowerId::new(),
+    }
+}
+
+pub(crate) struct WithTaskBorrower<F> {
+    inner: F,
+    borrower: BorrowerId,
+}
+
+impl<F: Future> Future for WithTaskBorrower<F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // SAFETY: `inner` is structurally pinned, i.e. it is never moved out of `self`
+        let this = unsafe { self.get_unchecked_mut() };
+        let inner = unsafe { Pin::new_unchecked(&mut this.inner) };
+
+        let previous = CURRENT_TASK.with(|current| current.replace(Some(this.borrower)));
+        struct Reset(Option<BorrowerId>);
+        impl Drop for Reset {
+            fn drop(&mut self) {
+                CURRENT_TASK.with(|current| current.set(self.0));
+            }
+        }
+        let _reset = Reset(previous);
+        inner.poll(cx)
+    }
+}
+
+#[derive(Default)]
+struct WaitForGraph {
+    holders: HashMap<LockableOrder, Vec<BorrowerId>>,
+    waiting: HashMap<BorrowerId, LockableOrder>,
+}
+
+impl WaitForGraph {
+    /// Whether any holder of `object` is `borrower`, or is waiting (transitively) for it.
+    fn reaches(&self, object: LockableOrder, borrower: BorrowerId) -> bool {
+        let mut visited = vec![];
+        let mut pending = vec![object];
+        while let Some(object) = pending.pop() {
+            if visited.contains(&object) {
+                continue;
+            }
+            visited.push(object);
+
+            for holder in self.holders.get(&object).into_iter().flatten() {
+                if *holder == borrower {
+                    return true;
+                }
+                pending.extend(self.waiting.get(holder));
+            }
+        }
+        false
+    }
+}
+
+lazy_static! {
+    static ref GRAPH: Mutex<WaitForGraph> = Mutex::new(Default::default());
+}
+
+fn enabled() -> bool {
+    cfg!(debug_assertions)
+}
+
+fn with_graph<R>(f: impl FnOnce(&mut WaitForGraph) -> R) -> R {
+    // The graph is always consistent even if another thread panicked
+    f(&mut GRAPH.lock().unwrap_or_else(|e| e.into_inner()))
+}
+
+#[derive(Debug)]
+pub(crate) struct LockableDeadlockError;
+
+/// Marks `borrower` as waiting for `object` until dropped.
+pub(crate) struct LockableWaiting(BorrowerId);
+
+impl Drop for LockableWaiting {
+    fn drop(&mut self) {
+        with_graph(|graph| graph.waiting.remove(&self.0));
+    }
+}
+
+/// Start waiting for `object`, unless it would wait forever.
+pub(crate) fn lockable_wait(
+    borrower: BorrowerId,
+    object: LockableOrder,
+) -> Result<Option<LockableWaiting>, LockableDeadlockError> {
+    if !enabled() {
+        return Ok(None);
+    }
+
+    with_graph(|graph| {
+        if graph.reaches(object, borrower) {
+            return Err(LockableDeadlockError);
+        }
+        graph.waiting.insert(borrower, object);
+        Ok(Some(LockableWaiting(borrower)))
+    })
+}
+
+/// Marks `borrower` as holding `object` until dropped.
+pub(crate) struct LockableHolding {
+    borrower: BorrowerId,
+    object: LockableOrder,
+}
+
+impl Drop for LockableHolding {
+    fn drop(&mut self) {
+        with_graph(|graph| {
+            if let Some(holders) = graph.holders.get_mut(&self.object) {
+                if let Some(index) = holders.iter().position(|x| *x == self.borrower) {
+                    holders.swap_remove(index);
+                }
+                if holders.is_empty() {
+                    graph.holders.remove(&self.object);
+                }
+            }
+        });
+    }
+}
+
+pub(crate) fn lockable_hold(
+    borrower: BorrowerId,
+    object: LockableOrder,
+) -> Option<LockableHolding> {
+    if !enabled() {
+        return None;
+    }
+
+    with_graph(|graph| graph.holders.entry(object).or_default().push(borrower));
+    Some(LockableHolding { borrower, object })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lockable_wait() {
+        let (a, b) = (LockableOrder::new(), LockableOrder::new());
+        let (borrower_one, borrower_two) = (BorrowerId::unique(), BorrowerId::unique());
+
+        let _holding_one_a = lockable_hold(borrower_one, a);
+        let _holding_two_b = lockable_hold(borrower_two, b);
+        let waiting_one_b = lockable_wait(borrower_one, b).unwrap();
+        assert!(lockable_wait(borrower_two, a).is_err());
+
+        drop(waiting_one_b);
+        assert!(lockable_wait(borrower_two, a).is_ok());
+    }
+
+    #[cfg(not(wasm))]
+    #[tokio::test]
+    async fn test_with_task_borrower() {
+        assert_eq!(BorrowerId::current_task(), None);
+        let (before, after) = with_task_borrower(async {
+            let before = BorrowerId::current_task();
+            tokio::task::yield_now().await;
+            (before, BorrowerId::current_task())
+        })
+        .await;
+        assert!(before.is_some());
+        assert_eq!(before, after);
+        assert_eq!(BorrowerId::current_task(), None);
+    }
+
+    #[test]
+    fn test_lockable_wait_self() {
+        let a = LockableOrder::new();
+        let borrower = BorrowerId::unique();
+
+        let holding = lockable_hold(borrower, a);
+        assert!(lockable_wait(borrower, a).is_err());
+        drop(holding);
+        assert!(lockable_wait(borrower, a).is_ok());
+    }
+}
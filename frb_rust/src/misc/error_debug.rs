@@ -0,0 +1,84 @@
+use std::cell::RefCell;
+use std::fmt::Debug;
+
+thread_local! {
+    static ERROR_DEBUG: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Records the `Debug` representation of the error returned by a Rust function,
+/// such that the error listener can report it later.
+///
+/// Since the error type may not implement `Debug`, [`frb_record_error_debug`](crate::frb_record_error_debug)
+/// uses autoref specialization: [`ErrorDebugViaDebug`] is picked when possible,
+/// and [`ErrorDebugFallback`] otherwise.
+#[doc(hidden)]
+pub struct ErrorDebug<'a, T>(pub &'a T);
+
+#[doc(hidden)]
+pub fn record_error_debug(value: Option<String>) {
+    ERROR_DEBUG.with(|x| *x.borrow_mut() = value);
+}
+
+/// Takes the error recorded by the latest [`frb_record_error_debug`](crate::frb_record_error_debug) on this thread.
+pub(crate) fn take_last_error_debug() -> Option<String> {
+    ERROR_DEBUG.with(|x| x.borrow_mut().take())
+}
+
+#[doc(hidden)]
+pub trait ErrorDebugViaDebug {
+    fn error_debug(&self) -> Option<String>;
+}
+
+impl<T: Debug> ErrorDebugViaDebug for ErrorDebug<'_, T> {
+    fn error_debug(&self) -> Option<String> {
+        Some(format!("{:?}", self.0))
+    }
+}
+
+#[doc(hidden)]
+pub trait ErrorDebugFallback {
+    fn error_debug(&self) -> Option<String>;
+}
+
+impl<T> ErrorDebugFallback for &ErrorDebug<'_, T> {
+    fn error_debug(&self) -> Option<String> {
+        None
+    }
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! frb_record_error_debug {
+    ($error_type:ty, $result:expr) => {{
+        let result: Result<_, $error_type> = $result;
+        if let Err(error) = &result {
+            #[allow(unused_imports)]
+            use $crate::for_generated::{ErrorDebugFallback, ErrorDebugViaDebug};
+            $crate::for_generated::record_error_debug(
+                (&$crate::for_generated::ErrorDebug(error)).error_debug(),
+            );
+        }
+        result
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::take_last_error_debug;
+
+    struct NotDebug;
+
+    #[test]
+    fn test_record_error_debug() {
+        let result = crate::frb_record_error_debug!(String, Err::<(), _>("hello".to_owned()));
+        assert!(result.is_err());
+        assert_eq!(take_last_error_debug(), Some("\"hello\"".to_owned()));
+        assert_eq!(take_last_error_debug(), None);
+
+        let _ = crate::frb_record_error_debug!(NotDebug, Err::<(), _>(NotDebug));
+        assert_eq!(take_last_error_debug(), None);
+
+        let _ = crate::frb_record_error_debug!(String, Ok::<_, String>(42));
+        assert_eq!(take_last_error_debug(), None);
+    }
+}
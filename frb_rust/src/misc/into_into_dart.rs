@@ -1,10 +1,9 @@
 use crate::codec::BaseCodec;
 use crate::for_generated::{BaseArc, StreamSinkBase};
 use crate::generalized_isolate::{IntoDart, ZeroCopyBuffer};
-#[cfg(feature = "rust-async")]
 use crate::rust_auto_opaque::{inner::RustAutoOpaqueInner, RustAutoOpaqueBase};
 use crate::rust_opaque::RustOpaqueBase;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
 
 /// Basically the Into trait.
 /// We need this separate trait because we need to implement it for Vec<T> etc.
@@ -45,7 +44,6 @@ impl<T, A: BaseArc<T>> IntoIntoDart<RustOpaqueBase<T, A>> for RustOpaqueBase<T,
     }
 }
 
-#[cfg(feature = "rust-async")]
 impl<T, A: BaseArc<RustAutoOpaqueInner<T>>> IntoIntoDart<RustAutoOpaqueBase<T, A>>
     for RustAutoOpaqueBase<T, A>
 {
@@ -117,6 +115,47 @@ where
     }
 }
 
+// Ordered collections are sent as lists, so the ordering is kept on the Dart side
+impl<KT, KD, VT, VD> IntoIntoDart<Vec<(KD, VD)>> for BTreeMap<KT, VT>
+where
+    KT: IntoIntoDart<KD>,
+    VT: IntoIntoDart<VD>,
+    Vec<(KD, VD)>: IntoDart,
+    KD: IntoDart,
+    VD: IntoDart,
+{
+    #[inline(always)]
+    fn into_into_dart(self) -> Vec<(KD, VD)> {
+        self.into_iter()
+            .map(|(k, v)| (k.into_into_dart(), v.into_into_dart()))
+            .collect()
+    }
+}
+
+impl<T, D> IntoIntoDart<Vec<D>> for BTreeSet<T>
+where
+    T: IntoIntoDart<D>,
+    Vec<D>: IntoDart,
+    D: IntoDart,
+{
+    #[inline(always)]
+    fn into_into_dart(self) -> Vec<D> {
+        self.into_iter().map(|e| e.into_into_dart()).collect()
+    }
+}
+
+impl<T, D> IntoIntoDart<Vec<D>> for VecDeque<T>
+where
+    T: IntoIntoDart<D>,
+    Vec<D>: IntoDart,
+    D: IntoDart,
+{
+    #[inline(always)]
+    fn into_into_dart(self) -> Vec<D> {
+        self.into_iter().map(|e| e.into_into_dart()).collect()
+    }
+}
+
 // frb-coverage:ignore-start
 impl<T, Rust2DartCodec: BaseCodec> IntoIntoDart<StreamSinkBase<T, Rust2DartCodec>>
     for StreamSinkBase<T, Rust2DartCodec>
@@ -205,18 +244,141 @@ impl_into_into_dart_by_self!(uuid::Uuid);
 #[cfg(feature = "backtrace")]
 impl_into_into_dart_by_self!(backtrace::Backtrace);
 #[cfg(feature = "anyhow")]
-impl_into_into_dart_by_self!(anyhow::Error);
+impl_into_into_dart_by_self!(crate::for_generated::DcoAnyhowError);
 // TODO await upstream
 // impl_into_into_dart_by_self!(std::backtrace::Backtrace);
 
+// Keep the chain of causes and the backtrace when e.g. `StreamSink::add_error` sends an anyhow error
+#[cfg(feature = "anyhow")]
+impl IntoIntoDart<crate::for_generated::DcoAnyhowError> for anyhow::Error {
+    #[inline(always)]
+    fn into_into_dart(self) -> crate::for_generated::DcoAnyhowError {
+        crate::for_generated::DcoAnyhowError(self)
+    }
+}
+
+/// Same as the chrono types, i.e. microseconds on native and milliseconds on web
+mod std_time_impls {
+    use super::IntoIntoDart;
+    use crate::misc::manual_impl::{
+        encode_timestamp, std_duration_to_micros, system_time_to_micros,
+    };
+    use std::time::{Duration, SystemTime};
+
+    impl IntoIntoDart<i64> for Duration {
+        #[inline(always)]
+        fn into_into_dart(self) -> i64 {
+            encode_timestamp(std_duration_to_micros(self))
+        }
+    }
+
+    impl IntoIntoDart<i64> for SystemTime {
+        #[inline(always)]
+        fn into_into_dart(self) -> i64 {
+            encode_timestamp(system_time_to_micros(self))
+        }
+    }
+}
+
+/// Sent as canonical strings, similar to `i128` and `u128`
+#[cfg(feature = "rust_decimal")]
+impl IntoIntoDart<String> for rust_decimal::Decimal {
+    #[inline(always)]
+    fn into_into_dart(self) -> String {
+        self.to_string()
+    }
+}
+
+#[cfg(feature = "num-bigint")]
+impl IntoIntoDart<String> for num_bigint::BigInt {
+    #[inline(always)]
+    fn into_into_dart(self) -> String {
+        self.to_string()
+    }
+}
+
+/// Sent as JSON strings, which are decoded on the Dart side
+#[cfg(feature = "serde_json")]
+mod serde_json_impls {
+    use super::IntoIntoDart;
+
+    impl IntoIntoDart<String> for serde_json::Value {
+        #[inline(always)]
+        fn into_into_dart(self) -> String {
+            self.to_string()
+        }
+    }
+
+    impl IntoIntoDart<String> for serde_json::Map<String, serde_json::Value> {
+        #[inline(always)]
+        fn into_into_dart(self) -> String {
+            serde_json::Value::Object(self).to_string()
+        }
+    }
+}
+
+/// Same as the chrono types, i.e. microseconds on native and milliseconds on web
+#[cfg(feature = "time")]
+mod time_impls {
+    use super::IntoIntoDart;
+    use crate::misc::manual_impl::{
+        date_to_micros, encode_timestamp, offset_date_time_to_micros,
+        primitive_date_time_to_micros, time_duration_to_micros,
+    };
+
+    macro_rules! impl_into_into_dart_by_micros {
+        ($name:ty, $to_micros:ident) => {
+            impl IntoIntoDart<i64> for $name {
+                #[inline(always)]
+                fn into_into_dart(self) -> i64 {
+                    encode_timestamp($to_micros(self))
+                }
+            }
+        };
+    }
+
+    impl_into_into_dart_by_micros!(time::OffsetDateTime, offset_date_time_to_micros);
+    impl_into_into_dart_by_micros!(time::PrimitiveDateTime, primitive_date_time_to_micros);
+    impl_into_into_dart_by_micros!(time::Date, date_to_micros);
+    impl_into_into_dart_by_micros!(time::Duration, time_duration_to_micros);
+}
+
 #[cfg(feature = "chrono")]
 mod chrono_impls {
     use super::IntoIntoDart;
+    use crate::misc::manual_impl::{encode_date_time_with_offset, encode_timestamp};
     use chrono::{Local, Utc};
     impl_into_into_dart_by_self!(chrono::Duration);
     impl_into_into_dart_by_self!(chrono::NaiveDateTime);
     impl_into_into_dart_by_self!(chrono::DateTime<Local>);
     impl_into_into_dart_by_self!(chrono::DateTime<Utc>);
+
+    impl IntoIntoDart<i64> for chrono::NaiveDate {
+        #[inline(always)]
+        fn into_into_dart(self) -> i64 {
+            let epoch = chrono::NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+            encode_duration(self.signed_duration_since(epoch))
+        }
+    }
+
+    impl IntoIntoDart<i64> for chrono::NaiveTime {
+        #[inline(always)]
+        fn into_into_dart(self) -> i64 {
+            let midnight = chrono::NaiveTime::from_hms_opt(0, 0, 0).unwrap();
+            encode_duration(self.signed_duration_since(midnight))
+        }
+    }
+
+    impl IntoIntoDart<String> for chrono::DateTime<chrono::FixedOffset> {
+        #[inline(always)]
+        fn into_into_dart(self) -> String {
+            encode_date_time_with_offset(&self, None)
+        }
+    }
+
+    fn encode_duration(duration: chrono::Duration) -> i64 {
+        encode_timestamp(duration.num_microseconds().expect("time is out of range"))
+    }
 }
 
 #[cfg(test)]
@@ -228,4 +390,71 @@ mod tests {
         let raw: allo_isolate::ZeroCopyBuffer<Vec<u8>> = allo_isolate::ZeroCopyBuffer(vec![10]);
         assert_eq!(raw.into_into_dart().0, vec![10]);
     }
+
+    #[test]
+    fn test_btree_map_keeps_ordering() {
+        use crate::misc::into_into_dart::IntoIntoDart;
+        let raw: std::collections::BTreeMap<i32, i32> = [(3, 30), (1, 10), (2, 20)].into();
+        let ans: Vec<(i32, i32)> = raw.into_into_dart();
+        assert_eq!(ans, vec![(1, 10), (2, 20), (3, 30)]);
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn test_json_round_trip() {
+        use crate::misc::into_into_dart::IntoIntoDart;
+        let value = serde_json::json!({"a": [1, 2.5, null, true], "b": {"c": "d"}});
+        let ans: String = value.clone().into_into_dart();
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&ans).unwrap(),
+            value
+        );
+
+        let map = value.as_object().unwrap().clone();
+        let ans: String = map.clone().into_into_dart();
+        assert_eq!(
+            serde_json::from_str::<serde_json::Map<String, serde_json::Value>>(&ans).unwrap(),
+            map
+        );
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    #[test]
+    fn test_decimal_round_trip() {
+        use crate::misc::into_into_dart::IntoIntoDart;
+        for raw in [
+            "0",
+            "-1.50",
+            "79228162514264337593543950335",
+            "0.0000000000000000000000000001",
+        ] {
+            let value: rust_decimal::Decimal = raw.parse().unwrap();
+            let ans: String = value.into_into_dart();
+            assert_eq!(ans, raw);
+            assert_eq!(ans.parse::<rust_decimal::Decimal>().unwrap(), value);
+        }
+    }
+
+    #[cfg(feature = "num-bigint")]
+    #[test]
+    fn test_big_int_round_trip() {
+        use crate::misc::into_into_dart::IntoIntoDart;
+        let raw = "-340282366920938463463374607431768211456000000000000";
+        let value: num_bigint::BigInt = raw.parse().unwrap();
+        let ans: String = value.clone().into_into_dart();
+        assert_eq!(ans, raw);
+        assert_eq!(ans.parse::<num_bigint::BigInt>().unwrap(), value);
+    }
+
+    #[cfg(all(feature = "chrono", not(wasm)))]
+    #[test]
+    fn test_chrono_naive_date_and_time() {
+        use crate::misc::into_into_dart::IntoIntoDart;
+        let date = chrono::NaiveDate::from_ymd_opt(1969, 12, 31).unwrap();
+        let ans: i64 = date.into_into_dart();
+        assert_eq!(ans, -86_400_000_000);
+        let time = chrono::NaiveTime::from_hms_micro_opt(1, 2, 3, 4).unwrap();
+        let ans: i64 = time.into_into_dart();
+        assert_eq!(ans, 3_723_000_004);
+    }
 }
@@ -1,4 +1,6 @@
-#[cfg(feature = "chrono")]
+use std::fmt;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
 #[inline]
 pub fn decode_timestamp(ts: i64) -> Timestamp {
     #[cfg(wasm)]
@@ -6,13 +8,12 @@ pub fn decode_timestamp(ts: i64) -> Timestamp {
     #[cfg(not(wasm))]
     const PRECISION: i64 = 1_000_000;
 
-    let s = ts / PRECISION;
+    let s = ts.div_euclid(PRECISION);
     let ns = (ts.rem_euclid(PRECISION) * (1_000_000_000 / PRECISION)) as u32;
     Timestamp { s, ns }
 }
 
 /// a timestamp with microseconds precision on native and milliseconds precision on web
-#[cfg(feature = "chrono")]
 pub struct Timestamp {
     /// seconds
     pub s: i64,
@@ -20,6 +21,210 @@ pub struct Timestamp {
     pub ns: u32,
 }
 
+/// Same precision as [decode_timestamp]
+#[inline]
+pub fn decode_std_duration(ts: i64) -> Result<Duration, NegativeDurationError> {
+    let Timestamp { s, ns } = decode_timestamp(ts);
+    let s = u64::try_from(s).map_err(|_| NegativeDurationError)?;
+    Ok(Duration::new(s, ns))
+}
+
+/// The error when decoding a negative Dart `Duration` as [Duration], which cannot be negative.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NegativeDurationError;
+
+impl fmt::Display for NegativeDurationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "std::time::Duration cannot be negative")
+    }
+}
+
+impl std::error::Error for NegativeDurationError {}
+
+/// Same precision as [decode_timestamp]
+#[inline]
+pub fn decode_system_time(ts: i64) -> SystemTime {
+    let Timestamp { s, ns } = decode_timestamp(ts);
+    let time = if s >= 0 {
+        UNIX_EPOCH.checked_add(Duration::from_secs(s as u64))
+    } else {
+        UNIX_EPOCH.checked_sub(Duration::from_secs(s.unsigned_abs()))
+    };
+    (time.and_then(|time| time.checked_add(Duration::from_nanos(ns as u64))))
+        .expect("invalid or out-of-range system time")
+}
+
+/// Inverse of [decode_timestamp]
+#[inline]
+pub(crate) fn encode_timestamp(micros: i64) -> i64 {
+    #[cfg(wasm)]
+    const PRECISION: i64 = 1_000;
+    #[cfg(not(wasm))]
+    const PRECISION: i64 = 1_000_000;
+
+    micros.div_euclid(1_000_000 / PRECISION)
+}
+
+#[inline]
+pub fn std_duration_to_micros(duration: Duration) -> i64 {
+    i64::try_from(duration.as_micros()).expect("std::time::Duration is too large")
+}
+
+#[inline]
+pub fn std_duration_from_micros(micros: i64) -> Result<Duration, NegativeDurationError> {
+    let micros = u64::try_from(micros).map_err(|_| NegativeDurationError)?;
+    Ok(Duration::from_micros(micros))
+}
+
+#[inline]
+pub fn system_time_to_micros(time: SystemTime) -> i64 {
+    match time.duration_since(UNIX_EPOCH) {
+        Ok(duration) => std_duration_to_micros(duration),
+        Err(e) => -std_duration_to_micros(e.duration()),
+    }
+}
+
+#[inline]
+pub fn system_time_from_micros(micros: i64) -> SystemTime {
+    let duration = Duration::from_micros(micros.unsigned_abs());
+    if micros >= 0 {
+        UNIX_EPOCH.checked_add(duration)
+    } else {
+        UNIX_EPOCH.checked_sub(duration)
+    }
+    .expect("invalid or out-of-range system time")
+}
+
+/// Same precision as [decode_timestamp]
+#[cfg(feature = "time")]
+#[inline]
+pub fn decode_offset_date_time(ts: i64) -> time::OffsetDateTime {
+    offset_date_time_from_micros(decode_timestamp_as_micros(ts))
+}
+
+/// Same precision as [decode_timestamp]
+#[cfg(feature = "time")]
+#[inline]
+pub fn decode_primitive_date_time(ts: i64) -> time::PrimitiveDateTime {
+    primitive_date_time_from_micros(decode_timestamp_as_micros(ts))
+}
+
+/// Same precision as [decode_timestamp]
+#[cfg(feature = "time")]
+#[inline]
+pub fn decode_date(ts: i64) -> time::Date {
+    date_from_micros(decode_timestamp_as_micros(ts))
+}
+
+/// Same precision as [decode_timestamp]
+#[cfg(feature = "time")]
+#[inline]
+pub fn decode_time_duration(ts: i64) -> time::Duration {
+    time_duration_from_micros(decode_timestamp_as_micros(ts))
+}
+
+#[cfg(feature = "time")]
+fn decode_timestamp_as_micros(ts: i64) -> i64 {
+    let Timestamp { s, ns } = decode_timestamp(ts);
+    s * 1_000_000 + (ns / 1_000) as i64
+}
+
+#[cfg(feature = "time")]
+#[inline]
+pub fn offset_date_time_to_micros(value: time::OffsetDateTime) -> i64 {
+    i64::try_from(value.unix_timestamp_nanos().div_euclid(1_000))
+        .expect("time::OffsetDateTime is out of range")
+}
+
+#[cfg(feature = "time")]
+#[inline]
+pub fn offset_date_time_from_micros(micros: i64) -> time::OffsetDateTime {
+    time::OffsetDateTime::from_unix_timestamp_nanos(micros as i128 * 1_000)
+        .expect("invalid or out-of-range datetime")
+}
+
+/// The value is assumed to be in UTC, same as `chrono::NaiveDateTime`
+#[cfg(feature = "time")]
+#[inline]
+pub fn primitive_date_time_to_micros(value: time::PrimitiveDateTime) -> i64 {
+    offset_date_time_to_micros(value.assume_utc())
+}
+
+#[cfg(feature = "time")]
+#[inline]
+pub fn primitive_date_time_from_micros(micros: i64) -> time::PrimitiveDateTime {
+    let value = offset_date_time_from_micros(micros);
+    time::PrimitiveDateTime::new(value.date(), value.time())
+}
+
+/// Midnight of the date in UTC
+#[cfg(feature = "time")]
+#[inline]
+pub fn date_to_micros(value: time::Date) -> i64 {
+    primitive_date_time_to_micros(value.midnight())
+}
+
+#[cfg(feature = "time")]
+#[inline]
+pub fn date_from_micros(micros: i64) -> time::Date {
+    offset_date_time_from_micros(micros).date()
+}
+
+#[cfg(feature = "time")]
+#[inline]
+pub fn time_duration_to_micros(value: time::Duration) -> i64 {
+    i64::try_from(value.whole_microseconds()).expect("time::Duration is too large")
+}
+
+#[cfg(feature = "time")]
+#[inline]
+pub fn time_duration_from_micros(micros: i64) -> time::Duration {
+    time::Duration::microseconds(micros)
+}
+
+/// RFC 3339 with an optional RFC 9557 time zone suffix,
+/// e.g. `2024-01-02T03:04:05.000006+08:00[Asia/Shanghai]`
+#[cfg(feature = "chrono")]
+pub fn encode_date_time_with_offset<Tz: chrono::TimeZone>(
+    value: &chrono::DateTime<Tz>,
+    time_zone: Option<&str>,
+) -> String
+where
+    Tz::Offset: std::fmt::Display,
+{
+    let date_time = value.to_rfc3339_opts(chrono::SecondsFormat::Micros, false);
+    match time_zone {
+        Some(time_zone) => format!("{date_time}[{time_zone}]"),
+        None => date_time,
+    }
+}
+
+/// Inverse of [encode_date_time_with_offset]
+#[cfg(feature = "chrono")]
+pub fn decode_date_time_with_offset(
+    raw: &str,
+) -> (chrono::DateTime<chrono::FixedOffset>, Option<&str>) {
+    let (date_time, time_zone) = match raw.strip_suffix(']').and_then(|x| x.split_once('[')) {
+        Some((date_time, time_zone)) => (date_time, Some(time_zone)),
+        None => (raw, None),
+    };
+    let date_time =
+        chrono::DateTime::parse_from_rfc3339(date_time).expect("invalid date time with offset");
+    (date_time, time_zone)
+}
+
+/// Decode into a named time zone, such as `chrono_tz::Tz`
+#[cfg(feature = "chrono")]
+pub fn decode_date_time_with_time_zone<Tz>(raw: &str) -> chrono::DateTime<Tz>
+where
+    Tz: chrono::TimeZone + std::str::FromStr,
+    Tz::Err: std::fmt::Debug,
+{
+    let (date_time, time_zone) = decode_date_time_with_offset(raw);
+    let time_zone: Tz = (time_zone.expect("missing time zone").parse()).expect("invalid time zone");
+    date_time.with_timezone(&time_zone)
+}
+
 /// see [uuid::Bytes](https://docs.rs/uuid/1.1.2/uuid/type.Bytes.html)
 #[cfg(feature = "uuid")]
 pub(crate) const UUID_SIZE_IN_BYTES: usize = 16;
@@ -49,8 +254,9 @@ pub fn decode_uuid(id: Vec<u8>) -> uuid::Uuid {
 // }
 
 #[cfg(test)]
-#[cfg(feature = "chrono")]
 mod tests {
+    use std::time::{Duration, UNIX_EPOCH};
+
     #[test]
     fn test_decode_timestamp() {
         #[cfg(not(wasm))]
@@ -71,4 +277,109 @@ mod tests {
             assert_eq!(ns, 567_000_000);
         }
     }
+
+    #[test]
+    fn test_decode_timestamp_before_epoch() {
+        #[cfg(not(wasm))]
+        {
+            let super::Timestamp { s, ns } = super::decode_timestamp(-1_500_000);
+            assert_eq!(s, -2);
+            assert_eq!(ns, 500_000_000);
+        }
+
+        #[cfg(wasm)]
+        {
+            let super::Timestamp { s, ns } = super::decode_timestamp(-1_500);
+            assert_eq!(s, -2);
+            assert_eq!(ns, 500_000_000);
+        }
+    }
+
+    #[test]
+    fn test_system_time_micros() {
+        for micros in [0, 1_234_567, -1_234_567] {
+            let time = super::system_time_from_micros(micros);
+            assert_eq!(super::system_time_to_micros(time), micros);
+        }
+        assert_eq!(
+            super::system_time_from_micros(-1_500_000),
+            UNIX_EPOCH - Duration::from_millis(1_500)
+        );
+    }
+
+    #[test]
+    fn test_std_duration_micros() {
+        let duration = super::std_duration_from_micros(3_496_567_123).unwrap();
+        assert_eq!(duration, Duration::new(3_496, 567_123_000));
+        assert_eq!(super::std_duration_to_micros(duration), 3_496_567_123);
+        assert_eq!(
+            super::std_duration_from_micros(-1),
+            Err(super::NegativeDurationError)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "time")]
+    fn test_time_micros() {
+        for micros in [0, 1_234_567, -1_234_567] {
+            let value = super::offset_date_time_from_micros(micros);
+            assert_eq!(super::offset_date_time_to_micros(value), micros);
+            let value = super::primitive_date_time_from_micros(micros);
+            assert_eq!(super::primitive_date_time_to_micros(value), micros);
+            let value = super::time_duration_from_micros(micros);
+            assert_eq!(super::time_duration_to_micros(value), micros);
+        }
+
+        let date = super::date_from_micros(-1);
+        assert_eq!(date.to_string(), "1969-12-31");
+        assert_eq!(super::date_to_micros(date), -86_400_000_000);
+    }
+
+    #[test]
+    #[cfg(all(feature = "time", not(wasm)))]
+    fn test_decode_time() {
+        assert_eq!(
+            super::decode_offset_date_time(-1_500_000).unix_timestamp_nanos(),
+            -1_500_000_000
+        );
+        assert_eq!(
+            super::decode_time_duration(-1_500_000),
+            time::Duration::milliseconds(-1_500)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn test_date_time_with_offset() {
+        let raw = "2024-01-02T03:04:05.000006+08:00";
+        let (date_time, time_zone) = super::decode_date_time_with_offset(raw);
+        assert_eq!(time_zone, None);
+        assert_eq!(date_time.offset().local_minus_utc(), 8 * 3600);
+        assert_eq!(super::encode_date_time_with_offset(&date_time, None), raw);
+
+        let (date_time, time_zone) =
+            super::decode_date_time_with_offset("2024-01-02T03:04:05.000006Z[Europe/London]");
+        assert_eq!(time_zone, Some("Europe/London"));
+        assert_eq!(
+            super::encode_date_time_with_offset(&date_time, time_zone),
+            "2024-01-02T03:04:05.000006+00:00[Europe/London]"
+        );
+    }
+
+    #[test]
+    #[cfg(not(wasm))]
+    fn test_decode_system_time() {
+        assert_eq!(
+            super::decode_system_time(-1_500_000),
+            UNIX_EPOCH - Duration::from_millis(1_500)
+        );
+        assert_eq!(
+            super::decode_std_duration(1_500_000),
+            Ok(Duration::from_millis(1_500))
+        );
+        assert_eq!(
+            super::decode_std_duration(-1),
+            Err(super::NegativeDurationError)
+        );
+    }
 }
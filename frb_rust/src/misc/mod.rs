@@ -1,4 +1,5 @@
 pub(crate) mod dart_dynamic;
+pub(crate) mod error_debug;
 pub(crate) mod into_into_dart;
 pub(crate) mod logs;
 pub(crate) mod manual_impl;
@@ -7,6 +7,7 @@ pub enum Rust2DartAction {
     Error = 1, // TODO rename?
     CloseStream = 2,
     Panic = 3,
+    LockTimeout = 4,
 }
 
 impl IntoDart for Rust2DartAction {
@@ -0,0 +1,21 @@
+use super::BaseAsyncRuntime;
+use std::future::Future;
+
+/// An async runtime powered by [async-std](https://docs.rs/async-std).
+///
+/// It uses the global executor of async-std, which is configured by the
+/// `ASYNC_STD_THREAD_COUNT` and `ASYNC_STD_THREAD_NAME` environment variables.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AsyncStdAsyncRuntime;
+
+impl BaseAsyncRuntime for AsyncStdAsyncRuntime {
+    type JoinHandle<T: Send + 'static> = async_std::task::JoinHandle<T>;
+
+    fn spawn<F>(&self, future: F) -> async_std::task::JoinHandle<F::Output>
+    where
+        F: Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        async_std::task::spawn(future)
+    }
+}
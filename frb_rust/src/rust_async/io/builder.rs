@@ -0,0 +1,77 @@
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+/// Configures and creates an async runtime,
+/// e.g. via [`SimpleAsyncRuntime::builder`](super::SimpleAsyncRuntime::builder).
+pub struct AsyncRuntimeBuilder<R> {
+    pub(crate) current_thread: bool,
+    pub(crate) worker_threads: Option<usize>,
+    pub(crate) thread_stack_size: Option<usize>,
+    pub(crate) thread_name_prefix: Option<String>,
+    pub(crate) on_thread_start: Option<Arc<dyn Fn() + Send + Sync>>,
+    _runtime: PhantomData<fn() -> R>,
+}
+
+impl<R> AsyncRuntimeBuilder<R> {
+    pub(crate) fn new() -> Self {
+        Self {
+            current_thread: false,
+            worker_threads: None,
+            thread_stack_size: None,
+            thread_name_prefix: None,
+            on_thread_start: None,
+            _runtime: PhantomData,
+        }
+    }
+
+    /// The number of worker threads, defaults to the number of CPU cores.
+    pub fn worker_threads(mut self, value: usize) -> Self {
+        assert!(value > 0, "worker_threads cannot be zero");
+        self.worker_threads = Some(value);
+        self
+    }
+
+    /// The stack size (in bytes) of the spawned threads.
+    pub fn thread_stack_size(mut self, value: usize) -> Self {
+        self.thread_stack_size = Some(value);
+        self
+    }
+
+    /// Threads are named as `{prefix}-{index}`.
+    pub fn thread_name_prefix(mut self, value: impl Into<String>) -> Self {
+        self.thread_name_prefix = Some(value.into());
+        self
+    }
+
+    /// Executed at the start of every spawned thread.
+    pub fn on_thread_start(mut self, f: impl Fn() + Send + Sync + 'static) -> Self {
+        self.on_thread_start = Some(Arc::new(f));
+        self
+    }
+
+    pub(crate) fn thread_builder(
+        &self,
+        index: usize,
+        default_prefix: &str,
+    ) -> std::thread::Builder {
+        let prefix = self.thread_name_prefix.as_deref().unwrap_or(default_prefix);
+        let builder = std::thread::Builder::new().name(format!("{prefix}-{index}"));
+        match self.thread_stack_size {
+            Some(size) => builder.stack_size(size),
+            None => builder,
+        }
+    }
+}
+
+impl<R> fmt::Debug for AsyncRuntimeBuilder<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AsyncRuntimeBuilder")
+            .field("current_thread", &self.current_thread)
+            .field("worker_threads", &self.worker_threads)
+            .field("thread_stack_size", &self.thread_stack_size)
+            .field("thread_name_prefix", &self.thread_name_prefix)
+            .field("on_thread_start", &self.on_thread_start.is_some())
+            .finish()
+    }
+}
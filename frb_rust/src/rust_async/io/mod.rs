@@ -1,35 +1,34 @@
 use std::future::Future;
-use std::panic::AssertUnwindSafe;
 pub use tokio::spawn;
 pub use tokio::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard};
 pub use tokio::task::spawn_local;
 pub use tokio::task::JoinHandle;
 
-pub trait BaseAsyncRuntime {
-    fn spawn<F>(&self, future: F) -> JoinHandle<F::Output>
-    where
-        F: Future + Send + 'static,
-        F::Output: Send + 'static;
-}
+#[cfg(feature = "async-std")]
+mod async_std_runtime;
+mod builder;
+#[cfg(feature = "smol")]
+mod smol_runtime;
+mod tokio_runtime;
 
-// Why AssertUnwindSafe: https://github.com/tokio-rs/tokio/issues/6188
-#[derive(Debug)]
-pub struct SimpleAsyncRuntime(pub AssertUnwindSafe<tokio::runtime::Runtime>);
+#[cfg(feature = "async-std")]
+pub use async_std_runtime::AsyncStdAsyncRuntime;
+pub use builder::AsyncRuntimeBuilder;
+#[cfg(feature = "smol")]
+pub use smol_runtime::{SmolAsyncRuntime, SmolJoinHandle};
+pub use tokio_runtime::SimpleAsyncRuntime;
 
-impl Default for SimpleAsyncRuntime {
-    fn default() -> Self {
-        Self(AssertUnwindSafe(tokio::runtime::Runtime::new().unwrap()))
-    }
-}
+pub trait BaseAsyncRuntime {
+    /// The handle of a spawned task.
+    ///
+    /// The handler does not keep the handles of the tasks it spawns,
+    /// thus dropping a handle should detach the task instead of cancelling it.
+    type JoinHandle<T: Send + 'static>;
 
-impl BaseAsyncRuntime for SimpleAsyncRuntime {
-    fn spawn<F>(&self, future: F) -> JoinHandle<F::Output>
+    fn spawn<F>(&self, future: F) -> Self::JoinHandle<F::Output>
     where
         F: Future + Send + 'static,
-        F::Output: Send + 'static,
-    {
-        self.0.spawn(future)
-    }
+        F::Output: Send + 'static;
 }
 
 /// Similar to tokio's `spawn_blocking`, except that you need to provide a second argumnet.
@@ -0,0 +1,115 @@
+use super::{AsyncRuntimeBuilder, BaseAsyncRuntime};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+const DEFAULT_THREAD_NAME_PREFIX: &str = "smol-worker";
+
+/// An async runtime powered by [smol](https://docs.rs/smol),
+/// which runs an executor on its own worker threads.
+#[derive(Debug)]
+pub struct SmolAsyncRuntime {
+    executor: Arc<smol::Executor<'static>>,
+    // The worker threads stop when this is dropped
+    _shutdown: smol::channel::Sender<()>,
+}
+
+impl SmolAsyncRuntime {
+    pub fn builder() -> AsyncRuntimeBuilder<Self> {
+        AsyncRuntimeBuilder::new()
+    }
+}
+
+impl Default for SmolAsyncRuntime {
+    fn default() -> Self {
+        Self::builder().build().unwrap()
+    }
+}
+
+impl BaseAsyncRuntime for SmolAsyncRuntime {
+    type JoinHandle<T: Send + 'static> = SmolJoinHandle<T>;
+
+    fn spawn<F>(&self, future: F) -> SmolJoinHandle<F::Output>
+    where
+        F: Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        SmolJoinHandle(Some(self.executor.spawn(future)))
+    }
+}
+
+impl AsyncRuntimeBuilder<SmolAsyncRuntime> {
+    pub fn build(self) -> std::io::Result<SmolAsyncRuntime> {
+        let executor = Arc::new(smol::Executor::new());
+        let (shutdown_sender, shutdown_receiver) = smol::channel::bounded::<()>(1);
+
+        let worker_threads = (self.worker_threads)
+            .or_else(|| std::thread::available_parallelism().ok().map(|x| x.get()))
+            .unwrap_or(1);
+        for index in 0..worker_threads {
+            let executor = executor.clone();
+            let shutdown_receiver = shutdown_receiver.clone();
+            let on_thread_start = self.on_thread_start.clone();
+            (self.thread_builder(index, DEFAULT_THREAD_NAME_PREFIX)).spawn(move || {
+                if let Some(on_thread_start) = on_thread_start {
+                    on_thread_start();
+                }
+                // Returns when the sender is dropped
+                let _ = smol::block_on(executor.run(shutdown_receiver.recv()));
+            })?;
+        }
+
+        Ok(SmolAsyncRuntime {
+            executor,
+            _shutdown: shutdown_sender,
+        })
+    }
+}
+
+/// The handle of a task spawned by [`SmolAsyncRuntime`].
+///
+/// Unlike `smol::Task`, dropping it detaches the task instead of cancelling it.
+#[derive(Debug)]
+pub struct SmolJoinHandle<T>(Option<smol::Task<T>>);
+
+impl<T> Future for SmolJoinHandle<T> {
+    type Output = T;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        Pin::new(self.0.as_mut().unwrap()).poll(cx)
+    }
+}
+
+impl<T> Drop for SmolJoinHandle<T> {
+    fn drop(&mut self) {
+        if let Some(task) = self.0.take() {
+            task.detach();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SmolAsyncRuntime;
+    use crate::rust_async::BaseAsyncRuntime;
+    use std::sync::mpsc;
+
+    #[test]
+    fn test_spawn() {
+        let runtime = SmolAsyncRuntime::builder()
+            .worker_threads(2)
+            .thread_name_prefix("my-worker")
+            .build()
+            .unwrap();
+
+        assert_eq!(smol::block_on(runtime.spawn(async { 42 })), 42);
+
+        let (sender, receiver) = mpsc::channel();
+        drop(runtime.spawn(async move {
+            let name = std::thread::current().name().unwrap().to_owned();
+            sender.send(name).unwrap();
+        }));
+        assert!(receiver.recv().unwrap().starts_with("my-worker-"));
+    }
+}
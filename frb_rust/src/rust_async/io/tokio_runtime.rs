@@ -0,0 +1,179 @@
+use super::{AsyncRuntimeBuilder, BaseAsyncRuntime};
+use std::future::Future;
+use std::panic::AssertUnwindSafe;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use tokio::runtime::{Builder, Handle, Runtime};
+use tokio::sync::oneshot;
+use tokio::task::JoinHandle;
+
+const DEFAULT_THREAD_NAME_PREFIX: &str = "tokio-runtime-worker";
+
+/// The default async runtime, which is powered by tokio.
+///
+/// By default it is a multi-threaded runtime, use [`SimpleAsyncRuntime::builder`] to configure it.
+// Why AssertUnwindSafe: https://github.com/tokio-rs/tokio/issues/6188
+#[derive(Debug)]
+pub struct SimpleAsyncRuntime(AssertUnwindSafe<Inner>);
+
+#[derive(Debug)]
+struct Inner {
+    handle: Handle,
+    // Only for multi-thread runtimes
+    _runtime: Option<Runtime>,
+    // The current-thread runtime is driven by a dedicated thread, which stops when this is dropped
+    _shutdown: Option<oneshot::Sender<()>>,
+}
+
+impl SimpleAsyncRuntime {
+    pub fn builder() -> AsyncRuntimeBuilder<Self> {
+        AsyncRuntimeBuilder::new()
+    }
+
+    /// The handle of the underlying tokio runtime.
+    pub fn handle(&self) -> &Handle {
+        &self.0.handle
+    }
+}
+
+impl Default for SimpleAsyncRuntime {
+    fn default() -> Self {
+        Self::builder().build().unwrap()
+    }
+}
+
+impl BaseAsyncRuntime for SimpleAsyncRuntime {
+    type JoinHandle<T: Send + 'static> = JoinHandle<T>;
+
+    fn spawn<F>(&self, future: F) -> JoinHandle<F::Output>
+    where
+        F: Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        self.0.handle.spawn(future)
+    }
+}
+
+impl AsyncRuntimeBuilder<SimpleAsyncRuntime> {
+    /// Use a single-threaded runtime, which executes all tasks on one dedicated thread.
+    /// Then [`worker_threads`](AsyncRuntimeBuilder::worker_threads) is ignored.
+    pub fn current_thread(mut self) -> Self {
+        self.current_thread = true;
+        self
+    }
+
+    pub fn build(self) -> std::io::Result<SimpleAsyncRuntime> {
+        let inner = if self.current_thread {
+            self.build_current_thread()?
+        } else {
+            self.build_multi_thread()?
+        };
+        Ok(SimpleAsyncRuntime(AssertUnwindSafe(inner)))
+    }
+
+    fn build_multi_thread(&self) -> std::io::Result<Inner> {
+        let mut builder = Builder::new_multi_thread();
+        if let Some(worker_threads) = self.worker_threads {
+            builder.worker_threads(worker_threads);
+        }
+        let runtime = self.configure(&mut builder, 0).build()?;
+        Ok(Inner {
+            handle: runtime.handle().clone(),
+            _runtime: Some(runtime),
+            _shutdown: None,
+        })
+    }
+
+    fn build_current_thread(&self) -> std::io::Result<Inner> {
+        // index 0 is taken by the thread driving the runtime
+        let runtime = self
+            .configure(&mut Builder::new_current_thread(), 1)
+            .build()?;
+        let handle = runtime.handle().clone();
+
+        let (shutdown_sender, shutdown_receiver) = oneshot::channel::<()>();
+        let on_thread_start = self.on_thread_start.clone();
+        (self.thread_builder(0, DEFAULT_THREAD_NAME_PREFIX)).spawn(move || {
+            if let Some(on_thread_start) = on_thread_start {
+                on_thread_start();
+            }
+            // Returns when the sender is dropped
+            let _ = runtime.block_on(shutdown_receiver);
+        })?;
+
+        Ok(Inner {
+            handle,
+            _runtime: None,
+            _shutdown: Some(shutdown_sender),
+        })
+    }
+
+    fn configure<'a>(
+        &self,
+        builder: &'a mut Builder,
+        first_thread_index: usize,
+    ) -> &'a mut Builder {
+        builder.enable_all();
+        if let Some(thread_stack_size) = self.thread_stack_size {
+            builder.thread_stack_size(thread_stack_size);
+        }
+        if let Some(prefix) = self.thread_name_prefix.clone() {
+            let next_index = AtomicUsize::new(first_thread_index);
+            builder.thread_name_fn(move || {
+                format!("{prefix}-{}", next_index.fetch_add(1, Ordering::Relaxed))
+            });
+        }
+        if let Some(on_thread_start) = self.on_thread_start.clone() {
+            builder.on_thread_start(move || on_thread_start());
+        }
+        builder
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SimpleAsyncRuntime;
+    use crate::rust_async::BaseAsyncRuntime;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{mpsc, Arc};
+
+    fn spawn_and_get_thread_name(runtime: &SimpleAsyncRuntime) -> String {
+        let (sender, receiver) = mpsc::channel();
+        runtime.spawn(async move {
+            let name = std::thread::current().name().unwrap().to_owned();
+            sender.send(name).unwrap();
+        });
+        receiver.recv().unwrap()
+    }
+
+    #[test]
+    fn test_multi_thread() {
+        let started_threads = Arc::new(AtomicUsize::new(0));
+        let runtime = SimpleAsyncRuntime::builder()
+            .worker_threads(2)
+            .thread_name_prefix("my-worker")
+            .on_thread_start({
+                let started_threads = started_threads.clone();
+                move || {
+                    started_threads.fetch_add(1, Ordering::Relaxed);
+                }
+            })
+            .build()
+            .unwrap();
+
+        assert!(spawn_and_get_thread_name(&runtime).starts_with("my-worker-"));
+        assert!(started_threads.load(Ordering::Relaxed) >= 1);
+    }
+
+    #[test]
+    fn test_current_thread() {
+        let runtime = SimpleAsyncRuntime::builder()
+            .current_thread()
+            .thread_name_prefix("my-worker")
+            .build()
+            .unwrap();
+
+        for _ in 0..3 {
+            assert_eq!(spawn_and_get_thread_name(&runtime), "my-worker-0");
+        }
+    }
+}
@@ -1,45 +1,56 @@
 use crate::generalized_arc::base_arc::BaseArc;
-use crate::rust_async::{RwLockReadGuard, RwLockWriteGuard};
 use crate::rust_auto_opaque::inner::RustAutoOpaqueInner;
+use crate::rust_auto_opaque::lock::{
+    RustAutoOpaqueLockStrategy, RustAutoOpaqueReadGuard, RustAutoOpaqueTryLockError,
+    RustAutoOpaqueWriteGuard,
+};
 use crate::rust_auto_opaque::RustAutoOpaqueBase;
 use crate::rust_opaque::RustOpaqueBase;
-use tokio::sync::{RwLock, TryLockError};
 
 impl<T, A: BaseArc<RustAutoOpaqueInner<T>>> RustAutoOpaqueBase<T, A> {
     pub fn new(value: T) -> Self {
-        Self(RustOpaqueBase::new(RustAutoOpaqueInner::new(RwLock::new(
+        Self::new_with_lock_strategy(value, RustAutoOpaqueLockStrategy::default())
+    }
+
+    pub fn new_with_lock_strategy(value: T, lock_strategy: RustAutoOpaqueLockStrategy) -> Self {
+        Self(RustOpaqueBase::new(RustAutoOpaqueInner::new(
             value,
-        ))))
+            lock_strategy,
+        )))
+    }
+
+    pub fn lock_strategy(&self) -> RustAutoOpaqueLockStrategy {
+        self.0.data.strategy()
     }
 
-    pub fn blocking_read(&self) -> RwLockReadGuard<'_, T> {
-        self.0.data.blocking_read()
+    pub fn blocking_read(&self) -> RustAutoOpaqueReadGuard<'_, T> {
+        self.0.blocking_read()
     }
 
-    pub fn blocking_write(&self) -> RwLockWriteGuard<'_, T> {
-        self.0.data.blocking_write()
+    pub fn blocking_write(&self) -> RustAutoOpaqueWriteGuard<'_, T> {
+        self.0.blocking_write()
     }
 
-    pub async fn read(&self) -> RwLockReadGuard<'_, T> {
-        self.0.data.read().await
+    pub async fn read(&self) -> RustAutoOpaqueReadGuard<'_, T> {
+        self.0.read().await
     }
 
-    pub async fn write(&self) -> RwLockWriteGuard<'_, T> {
-        self.0.data.write().await
+    pub async fn write(&self) -> RustAutoOpaqueWriteGuard<'_, T> {
+        self.0.write().await
     }
 
-    pub fn try_read(&self) -> Result<RwLockReadGuard<'_, T>, TryLockError> {
-        self.0.data.try_read()
+    pub fn try_read(&self) -> Result<RustAutoOpaqueReadGuard<'_, T>, RustAutoOpaqueTryLockError> {
+        self.0.try_read()
     }
 
-    pub fn try_write(&self) -> Result<RwLockWriteGuard<'_, T>, TryLockError> {
-        self.0.data.try_write()
+    pub fn try_write(&self) -> Result<RustAutoOpaqueWriteGuard<'_, T>, RustAutoOpaqueTryLockError> {
+        self.0.try_write()
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::RustAutoOpaqueNom;
+    use crate::{RustAutoOpaqueLockStrategy, RustAutoOpaqueNom};
 
     #[test]
     fn test_api_sync() {
@@ -58,6 +69,25 @@ mod tests {
         assert_eq!(*opaque.write().await, 42);
     }
 
+    #[cfg(all(feature = "rust-async", not(wasm)))]
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_api_sync_waiting_inside_async_context() {
+        let opaque = RustAutoOpaqueNom::new(42);
+        let (locked_tx, locked_rx) = std::sync::mpsc::channel();
+        let holder = {
+            let opaque = opaque.clone();
+            std::thread::spawn(move || {
+                let mut guard = opaque.blocking_write();
+                locked_tx.send(()).unwrap();
+                std::thread::sleep(std::time::Duration::from_millis(100));
+                *guard = 100;
+            })
+        };
+        locked_rx.recv().unwrap();
+        assert_eq!(*opaque.blocking_read(), 100);
+        holder.join().unwrap();
+    }
+
     #[test]
     fn test_clone() {
         let a = RustAutoOpaqueNom::new(42);
@@ -65,4 +95,150 @@ mod tests {
         *a.blocking_write() = 200;
         assert_eq!(*b.blocking_read(), 200);
     }
+
+    #[test]
+    fn test_lock_strategy() {
+        for lock_strategy in [
+            RustAutoOpaqueLockStrategy::RwLock,
+            RustAutoOpaqueLockStrategy::Mutex,
+            RustAutoOpaqueLockStrategy::None,
+        ] {
+            let opaque = RustAutoOpaqueNom::new_with_lock_strategy(42, lock_strategy);
+            assert_eq!(opaque.lock_strategy(), lock_strategy);
+            assert_eq!(*opaque.blocking_read(), 42);
+            assert_eq!(*opaque.try_read().unwrap(), 42);
+        }
+
+        let opaque =
+            RustAutoOpaqueNom::new_with_lock_strategy(42, RustAutoOpaqueLockStrategy::Mutex);
+        let guard = opaque.blocking_read();
+        assert!(opaque.try_read().is_err());
+        drop(guard);
+        *opaque.blocking_write() = 100;
+        assert_eq!(*opaque.blocking_read(), 100);
+
+        let opaque =
+            RustAutoOpaqueNom::new_with_lock_strategy(42, RustAutoOpaqueLockStrategy::None);
+        let _guard = opaque.blocking_read();
+        assert_eq!(*opaque.blocking_read(), 42);
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot borrow mutably")]
+    fn test_lock_strategy_none_borrow_mut() {
+        let opaque =
+            RustAutoOpaqueNom::new_with_lock_strategy(42, RustAutoOpaqueLockStrategy::None);
+        let _ = opaque.blocking_write();
+    }
+
+    #[test]
+    #[should_panic(expected = "Deadlock detected")]
+    fn test_deadlock_borrow_mut_while_borrowed() {
+        let opaque = RustAutoOpaqueNom::new(42);
+        let _guard = opaque.blocking_read();
+        let _ = opaque.blocking_write();
+    }
+
+    #[cfg(not(wasm))]
+    #[test]
+    fn test_deadlock_between_threads() {
+        use crate::for_generated::lockable_with_site;
+        use std::sync::{Arc, Barrier};
+
+        let a = RustAutoOpaqueNom::new(1);
+        let b = RustAutoOpaqueNom::new(2);
+        let barrier = Arc::new(Barrier::new(2));
+
+        let spawn = |first: RustAutoOpaqueNom<i32>, second: RustAutoOpaqueNom<i32>| {
+            let barrier = barrier.clone();
+            std::thread::spawn(move || {
+                std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                    let _guard = first.blocking_write();
+                    barrier.wait();
+                    lockable_with_site("my_func", "second", || *second.blocking_write() += 10);
+                }))
+                .map_err(|e| *e.downcast::<String>().unwrap())
+            })
+        };
+        let threads = [spawn(a.clone(), b.clone()), spawn(b.clone(), a.clone())];
+        let results = threads.map(|thread| thread.join().unwrap());
+
+        // Exactly one of them fails, and the other one succeeds after that
+        let errors = results
+            .iter()
+            .filter_map(|x| x.as_ref().err())
+            .collect::<Vec<_>>();
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].starts_with(
+            "Deadlock detected when borrowing argument `second` of function `my_func`"
+        ));
+        assert_eq!(*a.blocking_read() + *b.blocking_read(), 13);
+    }
+
+    #[cfg(not(wasm))]
+    #[cfg(all(feature = "rust-async", not(wasm)))]
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_deadlock_between_tasks() {
+        use crate::lockable::wait_for_graph::with_task_borrower;
+        use futures::FutureExt;
+        use std::panic::AssertUnwindSafe;
+        use std::sync::Arc;
+        use tokio::sync::Barrier;
+
+        let a = RustAutoOpaqueNom::new(1);
+        let b = RustAutoOpaqueNom::new(2);
+        let barrier = Arc::new(Barrier::new(2));
+
+        let spawn = |first: RustAutoOpaqueNom<i32>, second: RustAutoOpaqueNom<i32>| {
+            let barrier = barrier.clone();
+            tokio::spawn(with_task_borrower(async move {
+                AssertUnwindSafe(async {
+                    let _guard = first.write().await;
+                    barrier.wait().await;
+                    *second.write().await += 10;
+                })
+                .catch_unwind()
+                .await
+                .is_err()
+            }))
+        };
+        let tasks = [spawn(a.clone(), b.clone()), spawn(b.clone(), a.clone())];
+        let mut failures = 0;
+        for task in tasks {
+            failures += task.await.unwrap() as i32;
+        }
+
+        // Exactly one of them fails, and the other one succeeds after that
+        assert_eq!(failures, 1);
+        assert_eq!(*a.read().await + *b.read().await, 13);
+    }
+
+    #[cfg(not(wasm))]
+    #[test]
+    fn test_lock_timeout() {
+        use crate::for_generated::lockable_with_site;
+        use crate::lockable::timeout::LockTimeoutPanic;
+        use crate::set_rust_auto_opaque_lock_timeout;
+        use std::time::Duration;
+
+        let opaque = RustAutoOpaqueNom::new(42);
+        let guard = opaque.blocking_write();
+
+        set_rust_auto_opaque_lock_timeout(Some(Duration::from_millis(50)));
+        let result = std::thread::spawn({
+            let opaque = opaque.clone();
+            move || lockable_with_site("my_func", "my_arg", || *opaque.blocking_read())
+        })
+        .join();
+        set_rust_auto_opaque_lock_timeout(None);
+
+        let panic = result.unwrap_err().downcast::<LockTimeoutPanic>().unwrap();
+        assert_eq!(panic.timeout, Duration::from_millis(50));
+        assert_eq!(
+            panic.to_string(),
+            "Timed out after 50ms when borrowing argument `my_arg` of function `my_func`, \
+            maybe because of a deadlock"
+        );
+        drop(guard);
+    }
 }
@@ -1,7 +1,7 @@
 use crate::for_generated::{BaseArc, Lockable, LockableOrder, RustAutoOpaqueBase};
 use crate::rust_auto_opaque::inner::RustAutoOpaqueInner;
+use crate::rust_auto_opaque::lock::RustAutoOpaqueLockStrategy;
 use crate::rust_opaque::RustOpaqueBase;
-use tokio::sync::RwLock;
 
 // NOTE: Make these functions instead of methods, thus we can control its visibility by exporting
 // only through `for_generated::...` and do not expose to end users.
@@ -21,7 +21,14 @@ pub fn rust_auto_opaque_decode_owned<T, A: BaseArc<RustAutoOpaqueInner<T>>>(
 pub fn rust_auto_opaque_encode<T, A: BaseArc<RustAutoOpaqueInner<T>>>(
     value: T,
 ) -> RustOpaqueBase<RustAutoOpaqueInner<T>, A> {
-    RustOpaqueBase::new(RustAutoOpaqueInner::new(RwLock::new(value)))
+    rust_auto_opaque_encode_with_lock_strategy(value, RustAutoOpaqueLockStrategy::default())
+}
+
+pub fn rust_auto_opaque_encode_with_lock_strategy<T, A: BaseArc<RustAutoOpaqueInner<T>>>(
+    value: T,
+    lock_strategy: RustAutoOpaqueLockStrategy,
+) -> RustOpaqueBase<RustAutoOpaqueInner<T>, A> {
+    RustOpaqueBase::new(RustAutoOpaqueInner::new(value, lock_strategy))
 }
 
 pub fn rust_auto_opaque_lockable_order<T: Send + Sync, A: BaseArc<RustAutoOpaqueInner<T>>>(
@@ -29,3 +36,33 @@ pub fn rust_auto_opaque_lockable_order<T: Send + Sync, A: BaseArc<RustAutoOpaque
 ) -> LockableOrder {
     opaque.0.lockable_order()
 }
+
+pub fn rust_auto_opaque_lockable_exclusive<T: Send + Sync, A: BaseArc<RustAutoOpaqueInner<T>>>(
+    opaque: &RustAutoOpaqueBase<T, A>,
+) -> bool {
+    opaque.0.lockable_exclusive()
+}
+
+/// Moves the value out if there are no other references, otherwise gives the object back.
+pub fn rust_auto_opaque_try_into_inner<T, A: BaseArc<RustAutoOpaqueInner<T>>>(
+    opaque: RustAutoOpaqueBase<T, A>,
+) -> Result<T, RustAutoOpaqueBase<T, A>> {
+    (opaque.0.try_unwrap())
+        .map(|inner| inner.data.into_inner())
+        .map_err(RustAutoOpaqueBase)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::for_generated::rust_auto_opaque_try_into_inner;
+    use crate::RustAutoOpaqueNom;
+
+    #[test]
+    fn test_try_into_inner() {
+        let opaque = RustAutoOpaqueNom::new(42);
+        let another = opaque.clone();
+        let opaque = rust_auto_opaque_try_into_inner(opaque).unwrap_err();
+        drop(another);
+        assert_eq!(rust_auto_opaque_try_into_inner(opaque).ok(), Some(42));
+    }
+}
@@ -1,16 +1,124 @@
 use crate::lockable::order::LockableOrder;
-use tokio::sync::RwLock;
+use crate::lockable::site::{describe_current_lockable_site, LockableSite};
+use crate::lockable::timeout::{
+    acquire_with_timeout, blocking_acquire_with_timeout, lock_timeout, LockTimeoutPanic,
+};
+use crate::lockable::wait_for_graph::{lockable_hold, lockable_wait, BorrowerId, LockableHolding};
+use crate::rust_auto_opaque::lock::{
+    RustAutoOpaqueLock, RustAutoOpaqueLockStrategy, RustAutoOpaqueReadGuard,
+    RustAutoOpaqueTryLockError, RustAutoOpaqueWriteGuard,
+};
+use std::future::Future;
+use std::time::Duration;
 
 pub struct RustAutoOpaqueInner<T> {
-    pub(crate) data: RwLock<T>,
+    pub(crate) data: RustAutoOpaqueLock<T>,
     pub(crate) order: LockableOrder,
 }
 
 impl<T> RustAutoOpaqueInner<T> {
-    pub(crate) fn new(data: RwLock<T>) -> Self {
+    pub(crate) fn new(data: T, lock_strategy: RustAutoOpaqueLockStrategy) -> Self {
         Self {
-            data,
+            data: RustAutoOpaqueLock::new(data, lock_strategy),
             order: LockableOrder::new(),
         }
     }
+
+    pub(crate) fn blocking_read(&self) -> RustAutoOpaqueReadGuard<'_, T> {
+        let (guard, holding) =
+            self.blocking_acquire(|| self.data.try_read(), || self.data.blocking_read());
+        guard.with_holding(holding)
+    }
+
+    pub(crate) fn blocking_write(&self) -> RustAutoOpaqueWriteGuard<'_, T> {
+        let (guard, holding) =
+            self.blocking_acquire(|| self.data.try_write(), || self.data.blocking_write());
+        guard.with_holding(holding)
+    }
+
+    pub(crate) async fn read(&self) -> RustAutoOpaqueReadGuard<'_, T> {
+        let (guard, holding) = self
+            .acquire(|| self.data.try_read(), self.data.read())
+            .await;
+        guard.with_holding(holding)
+    }
+
+    pub(crate) async fn write(&self) -> RustAutoOpaqueWriteGuard<'_, T> {
+        let (guard, holding) = self
+            .acquire(|| self.data.try_write(), self.data.write())
+            .await;
+        guard.with_holding(holding)
+    }
+
+    pub(crate) fn try_read(
+        &self,
+    ) -> Result<RustAutoOpaqueReadGuard<'_, T>, RustAutoOpaqueTryLockError> {
+        let guard = self.data.try_read()?;
+        Ok(guard.with_holding(lockable_hold(BorrowerId::current(), self.order)))
+    }
+
+    pub(crate) fn try_write(
+        &self,
+    ) -> Result<RustAutoOpaqueWriteGuard<'_, T>, RustAutoOpaqueTryLockError> {
+        let guard = self.data.try_write()?;
+        Ok(guard.with_holding(lockable_hold(BorrowerId::current(), self.order)))
+    }
+
+    fn blocking_acquire<G>(
+        &self,
+        try_acquire: impl Fn() -> Result<G, RustAutoOpaqueTryLockError>,
+        acquire: impl FnOnce() -> G,
+    ) -> (G, Option<LockableHolding>) {
+        let borrower = BorrowerId::current();
+        let guard = match try_acquire() {
+            Ok(guard) => guard,
+            Err(_) => {
+                let _waiting =
+                    lockable_wait(borrower, self.order).unwrap_or_else(|_| panic_deadlock());
+                match lock_timeout() {
+                    Some(timeout) => blocking_acquire_with_timeout(try_acquire, timeout)
+                        .unwrap_or_else(|| panic_timeout(timeout)),
+                    None => acquire(),
+                }
+            }
+        };
+        (guard, lockable_hold(borrower, self.order))
+    }
+
+    // Async borrows wait on behalf of the task, which does not block any thread.
+    // Outside of a task spawned by the executor, they only take part in the deadlock detection
+    // as holders, thus use the lock timeout for them instead.
+    async fn acquire<G>(
+        &self,
+        try_acquire: impl Fn() -> Result<G, RustAutoOpaqueTryLockError>,
+        acquire: impl Future<Output = G>,
+    ) -> (G, Option<LockableHolding>) {
+        let borrower = BorrowerId::current_task().unwrap_or_else(BorrowerId::unique);
+        // Not `match`, otherwise the failed attempt is regarded as being held across `.await`
+        if let Ok(guard) = try_acquire() {
+            return (guard, lockable_hold(borrower, self.order));
+        }
+
+        let _waiting = lockable_wait(borrower, self.order).unwrap_or_else(|_| panic_deadlock());
+        let guard = match lock_timeout() {
+            Some(timeout) => (acquire_with_timeout(acquire, timeout).await)
+                .unwrap_or_else(|| panic_timeout(timeout)),
+            None => acquire.await,
+        };
+        (guard, lockable_hold(borrower, self.order))
+    }
+}
+
+fn panic_deadlock() -> ! {
+    panic!(
+        "Deadlock detected when borrowing {}, since it is held by someone waiting for the current thread or task",
+        describe_current_lockable_site()
+    )
+}
+
+fn panic_timeout(timeout: Duration) -> ! {
+    std::panic::panic_any(LockTimeoutPanic {
+        site: LockableSite::current(),
+        timeout,
+    })
 }
@@ -0,0 +1,327 @@
+use crate::lockable::wait_for_graph::LockableHolding;
+use std::fmt;
+use std::ops::{Deref, DerefMut};
+#[cfg(not(feature = "rust-async"))]
+use std::sync::PoisonError;
+
+#[cfg(feature = "rust-async")]
+use tokio::sync::{
+    Mutex, MutexGuard, RwLock, RwLockReadGuard as RwLockReadGuardRaw,
+    RwLockWriteGuard as RwLockWriteGuardRaw,
+};
+
+#[cfg(not(feature = "rust-async"))]
+use std::sync::{
+    Mutex, MutexGuard, RwLock, RwLockReadGuard as RwLockReadGuardRaw,
+    RwLockWriteGuard as RwLockWriteGuardRaw,
+};
+
+/// How the data inside a `RustAutoOpaque` is locked when it is borrowed.
+///
+/// The locks are the async-aware ones in `tokio` when the `rust-async` feature is enabled,
+/// thus async functions can hold the borrows across `.await`, and the ones in `std` otherwise.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RustAutoOpaqueLockStrategy {
+    /// A read-write lock, i.e. many shared borrows or one mutable borrow at the same time.
+    #[default]
+    RwLock,
+    /// A mutex, i.e. even shared borrows are exclusive.
+    Mutex,
+    /// `parking_lot::RwLock`, which is a faster but blocking read-write lock.
+    #[cfg(feature = "parking_lot")]
+    ParkingLot,
+    /// No lock at all, thus only shared borrows are allowed.
+    None,
+}
+
+pub(crate) enum RustAutoOpaqueLock<T> {
+    RwLock(RwLock<T>),
+    Mutex(Mutex<T>),
+    #[cfg(feature = "parking_lot")]
+    ParkingLot(parking_lot::RwLock<T>),
+    None(T),
+}
+
+/// The error when a lock cannot be acquired immediately.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RustAutoOpaqueTryLockError;
+
+impl fmt::Display for RustAutoOpaqueTryLockError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "operation would block")
+    }
+}
+
+impl std::error::Error for RustAutoOpaqueTryLockError {}
+
+impl<T> RustAutoOpaqueLock<T> {
+    pub(crate) fn new(value: T, strategy: RustAutoOpaqueLockStrategy) -> Self {
+        match strategy {
+            RustAutoOpaqueLockStrategy::RwLock => Self::RwLock(RwLock::new(value)),
+            RustAutoOpaqueLockStrategy::Mutex => Self::Mutex(Mutex::new(value)),
+            #[cfg(feature = "parking_lot")]
+            RustAutoOpaqueLockStrategy::ParkingLot => {
+                Self::ParkingLot(parking_lot::RwLock::new(value))
+            }
+            RustAutoOpaqueLockStrategy::None => Self::None(value),
+        }
+    }
+
+    pub(crate) fn strategy(&self) -> RustAutoOpaqueLockStrategy {
+        match self {
+            Self::RwLock(_) => RustAutoOpaqueLockStrategy::RwLock,
+            Self::Mutex(_) => RustAutoOpaqueLockStrategy::Mutex,
+            #[cfg(feature = "parking_lot")]
+            Self::ParkingLot(_) => RustAutoOpaqueLockStrategy::ParkingLot,
+            Self::None(_) => RustAutoOpaqueLockStrategy::None,
+        }
+    }
+
+    /// Whether a shared borrow excludes all other borrows.
+    pub(crate) fn is_exclusive(&self) -> bool {
+        matches!(self, Self::Mutex(_))
+    }
+
+    pub(crate) fn into_inner(self) -> T {
+        match self {
+            Self::RwLock(inner) => unpoison(inner.into_inner()),
+            Self::Mutex(inner) => unpoison(inner.into_inner()),
+            #[cfg(feature = "parking_lot")]
+            Self::ParkingLot(inner) => inner.into_inner(),
+            Self::None(inner) => inner,
+        }
+    }
+
+    pub(crate) fn blocking_read(&self) -> RustAutoOpaqueReadGuard<'_, T> {
+        RustAutoOpaqueReadGuard::new(match self {
+            Self::RwLock(inner) => ReadGuardInner::RwLock(blocking_read_raw(inner)),
+            Self::Mutex(inner) => ReadGuardInner::Mutex(blocking_lock_raw(inner)),
+            #[cfg(feature = "parking_lot")]
+            Self::ParkingLot(inner) => ReadGuardInner::ParkingLot(inner.read()),
+            Self::None(inner) => ReadGuardInner::None(inner),
+        })
+    }
+
+    pub(crate) fn blocking_write(&self) -> RustAutoOpaqueWriteGuard<'_, T> {
+        RustAutoOpaqueWriteGuard::new(match self {
+            Self::RwLock(inner) => WriteGuardInner::RwLock(blocking_write_raw(inner)),
+            Self::Mutex(inner) => WriteGuardInner::Mutex(blocking_lock_raw(inner)),
+            #[cfg(feature = "parking_lot")]
+            Self::ParkingLot(inner) => WriteGuardInner::ParkingLot(inner.write()),
+            Self::None(_) => panic_borrow_mut_without_lock(),
+        })
+    }
+
+    pub(crate) async fn read(&self) -> RustAutoOpaqueReadGuard<'_, T> {
+        #[cfg(feature = "rust-async")]
+        match self {
+            Self::RwLock(inner) => {
+                return RustAutoOpaqueReadGuard::new(ReadGuardInner::RwLock(inner.read().await))
+            }
+            Self::Mutex(inner) => {
+                return RustAutoOpaqueReadGuard::new(ReadGuardInner::Mutex(inner.lock().await))
+            }
+            _ => {}
+        }
+        self.blocking_read()
+    }
+
+    pub(crate) async fn write(&self) -> RustAutoOpaqueWriteGuard<'_, T> {
+        #[cfg(feature = "rust-async")]
+        match self {
+            Self::RwLock(inner) => {
+                return RustAutoOpaqueWriteGuard::new(WriteGuardInner::RwLock(inner.write().await))
+            }
+            Self::Mutex(inner) => {
+                return RustAutoOpaqueWriteGuard::new(WriteGuardInner::Mutex(inner.lock().await))
+            }
+            _ => {}
+        }
+        self.blocking_write()
+    }
+
+    pub(crate) fn try_read(
+        &self,
+    ) -> Result<RustAutoOpaqueReadGuard<'_, T>, RustAutoOpaqueTryLockError> {
+        Ok(RustAutoOpaqueReadGuard::new(match self {
+            Self::RwLock(inner) => ReadGuardInner::RwLock(try_lock_raw(inner.try_read())?),
+            Self::Mutex(inner) => ReadGuardInner::Mutex(try_lock_raw(inner.try_lock())?),
+            #[cfg(feature = "parking_lot")]
+            Self::ParkingLot(inner) => {
+                ReadGuardInner::ParkingLot(inner.try_read().ok_or(RustAutoOpaqueTryLockError)?)
+            }
+            Self::None(inner) => ReadGuardInner::None(inner),
+        }))
+    }
+
+    pub(crate) fn try_write(
+        &self,
+    ) -> Result<RustAutoOpaqueWriteGuard<'_, T>, RustAutoOpaqueTryLockError> {
+        Ok(RustAutoOpaqueWriteGuard::new(match self {
+            Self::RwLock(inner) => WriteGuardInner::RwLock(try_lock_raw(inner.try_write())?),
+            Self::Mutex(inner) => WriteGuardInner::Mutex(try_lock_raw(inner.try_lock())?),
+            #[cfg(feature = "parking_lot")]
+            Self::ParkingLot(inner) => {
+                WriteGuardInner::ParkingLot(inner.try_write().ok_or(RustAutoOpaqueTryLockError)?)
+            }
+            Self::None(_) => panic_borrow_mut_without_lock(),
+        }))
+    }
+}
+
+fn panic_borrow_mut_without_lock() -> ! {
+    panic!("Cannot borrow mutably an object whose lock strategy is `None`")
+}
+
+#[cfg(feature = "rust-async")]
+fn unpoison<T>(value: T) -> T {
+    value
+}
+
+#[cfg(not(feature = "rust-async"))]
+fn unpoison<T>(value: Result<T, PoisonError<T>>) -> T {
+    // A panic inside a Rust function is caught and reported to Dart,
+    // thus the data is still usable, similar to the case of `rust-async`
+    value.unwrap_or_else(PoisonError::into_inner)
+}
+
+// Not `blocking_read` and friends of tokio, which panic when called inside an async context,
+// e.g. by a non-async trait method forwarded to an object borrowed by an async function
+#[cfg(feature = "rust-async")]
+fn blocking_read_raw<T>(lock: &RwLock<T>) -> RwLockReadGuardRaw<'_, T> {
+    futures::executor::block_on(lock.read())
+}
+
+#[cfg(not(feature = "rust-async"))]
+fn blocking_read_raw<T>(lock: &RwLock<T>) -> RwLockReadGuardRaw<'_, T> {
+    unpoison(lock.read())
+}
+
+#[cfg(feature = "rust-async")]
+fn blocking_write_raw<T>(lock: &RwLock<T>) -> RwLockWriteGuardRaw<'_, T> {
+    futures::executor::block_on(lock.write())
+}
+
+#[cfg(not(feature = "rust-async"))]
+fn blocking_write_raw<T>(lock: &RwLock<T>) -> RwLockWriteGuardRaw<'_, T> {
+    unpoison(lock.write())
+}
+
+#[cfg(feature = "rust-async")]
+fn blocking_lock_raw<T>(lock: &Mutex<T>) -> MutexGuard<'_, T> {
+    futures::executor::block_on(lock.lock())
+}
+
+#[cfg(not(feature = "rust-async"))]
+fn blocking_lock_raw<T>(lock: &Mutex<T>) -> MutexGuard<'_, T> {
+    unpoison(lock.lock())
+}
+
+#[cfg(feature = "rust-async")]
+fn try_lock_raw<G, E>(result: Result<G, E>) -> Result<G, RustAutoOpaqueTryLockError> {
+    result.map_err(|_| RustAutoOpaqueTryLockError)
+}
+
+#[cfg(not(feature = "rust-async"))]
+fn try_lock_raw<G>(result: std::sync::TryLockResult<G>) -> Result<G, RustAutoOpaqueTryLockError> {
+    match result {
+        Ok(guard) => Ok(guard),
+        Err(std::sync::TryLockError::Poisoned(e)) => Ok(e.into_inner()),
+        Err(std::sync::TryLockError::WouldBlock) => Err(RustAutoOpaqueTryLockError),
+    }
+}
+
+/// A shared borrow of the data inside a `RustAutoOpaque`.
+pub struct RustAutoOpaqueReadGuard<'a, T> {
+    // Declared first to be dropped before the lock is released
+    holding: Option<LockableHolding>,
+    inner: ReadGuardInner<'a, T>,
+}
+
+impl<'a, T> RustAutoOpaqueReadGuard<'a, T> {
+    fn new(inner: ReadGuardInner<'a, T>) -> Self {
+        Self {
+            holding: None,
+            inner,
+        }
+    }
+
+    pub(crate) fn with_holding(mut self, holding: Option<LockableHolding>) -> Self {
+        self.holding = holding;
+        self
+    }
+}
+
+enum ReadGuardInner<'a, T> {
+    RwLock(RwLockReadGuardRaw<'a, T>),
+    Mutex(MutexGuard<'a, T>),
+    #[cfg(feature = "parking_lot")]
+    ParkingLot(parking_lot::RwLockReadGuard<'a, T>),
+    None(&'a T),
+}
+
+impl<T> Deref for RustAutoOpaqueReadGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        match &self.inner {
+            ReadGuardInner::RwLock(inner) => inner,
+            ReadGuardInner::Mutex(inner) => inner,
+            #[cfg(feature = "parking_lot")]
+            ReadGuardInner::ParkingLot(inner) => inner,
+            ReadGuardInner::None(inner) => inner,
+        }
+    }
+}
+
+/// A mutable borrow of the data inside a `RustAutoOpaque`.
+pub struct RustAutoOpaqueWriteGuard<'a, T> {
+    // Declared first to be dropped before the lock is released
+    holding: Option<LockableHolding>,
+    inner: WriteGuardInner<'a, T>,
+}
+
+impl<'a, T> RustAutoOpaqueWriteGuard<'a, T> {
+    fn new(inner: WriteGuardInner<'a, T>) -> Self {
+        Self {
+            holding: None,
+            inner,
+        }
+    }
+
+    pub(crate) fn with_holding(mut self, holding: Option<LockableHolding>) -> Self {
+        self.holding = holding;
+        self
+    }
+}
+
+enum WriteGuardInner<'a, T> {
+    RwLock(RwLockWriteGuardRaw<'a, T>),
+    Mutex(MutexGuard<'a, T>),
+    #[cfg(feature = "parking_lot")]
+    ParkingLot(parking_lot::RwLockWriteGuard<'a, T>),
+}
+
+impl<T> Deref for RustAutoOpaqueWriteGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        match &self.inner {
+            WriteGuardInner::RwLock(inner) => inner,
+            WriteGuardInner::Mutex(inner) => inner,
+            #[cfg(feature = "parking_lot")]
+            WriteGuardInner::ParkingLot(inner) => inner,
+        }
+    }
+}
+
+impl<T> DerefMut for RustAutoOpaqueWriteGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        match &mut self.inner {
+            WriteGuardInner::RwLock(inner) => inner,
+            WriteGuardInner::Mutex(inner) => inner,
+            #[cfg(feature = "parking_lot")]
+            WriteGuardInner::ParkingLot(inner) => inner,
+        }
+    }
+}
@@ -6,14 +6,21 @@ mod api;
 pub(crate) mod dart2rust_explicit;
 pub(crate) mod dart2rust_implicit;
 pub(crate) mod inner;
+pub(crate) mod lock;
 pub(crate) mod rust2dart_common;
 pub(crate) mod rust2dart_explicit;
 
-#[derive(Clone)]
 pub struct RustAutoOpaqueBase<T: 'static, A: BaseArc<inner::RustAutoOpaqueInner<T>>>(
     pub(crate) RustOpaqueBase<inner::RustAutoOpaqueInner<T>, A>,
 );
 
+// Not derived, since only the reference is cloned, thus `T: Clone` is not needed
+impl<T: 'static, A: BaseArc<inner::RustAutoOpaqueInner<T>>> Clone for RustAutoOpaqueBase<T, A> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
 /// Please refer to `RustAutoOpaque` for doc.
 pub type RustAutoOpaqueNom<T> = RustAutoOpaqueBase<T, StdArc<inner::RustAutoOpaqueInner<T>>>;
 
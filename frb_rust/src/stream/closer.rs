@@ -1,18 +1,25 @@
 use crate::codec::BaseCodec;
 use crate::codec::Rust2DartMessageTrait;
 use crate::generalized_isolate::SendableChannelHandle;
+use crate::stream::flow_control::StreamSinkFlowControl;
 use std::marker::PhantomData;
+use std::sync::Arc;
 
 // *NOT* cloneable, since it invokes stream-close when dropped
 pub(crate) struct StreamSinkCloser<Rust2DartCodec: BaseCodec> {
     sendable_channel_handle: SendableChannelHandle,
+    flow_control: Option<Arc<StreamSinkFlowControl>>,
     _phantom_data: PhantomData<Rust2DartCodec>,
 }
 
 impl<Rust2DartCodec: BaseCodec> StreamSinkCloser<Rust2DartCodec> {
-    pub fn new(sendable_channel_handle: SendableChannelHandle) -> Self {
+    pub fn new(
+        sendable_channel_handle: SendableChannelHandle,
+        flow_control: Option<Arc<StreamSinkFlowControl>>,
+    ) -> Self {
         Self {
             sendable_channel_handle,
+            flow_control,
             _phantom_data: PhantomData,
         }
     }
@@ -20,7 +27,13 @@ impl<Rust2DartCodec: BaseCodec> StreamSinkCloser<Rust2DartCodec> {
 
 impl<Rust2DartCodec: BaseCodec> Drop for StreamSinkCloser<Rust2DartCodec> {
     fn drop(&mut self) {
-        super::stream_sink::sender(&self.sendable_channel_handle)
-            .send_or_warn(Rust2DartCodec::encode_close_stream().into_dart_abi())
+        let msg = Rust2DartCodec::encode_close_stream().into_dart_abi();
+
+        // Bounded streams may still have buffered items, which should be sent before closing
+        if let Some(flow_control) = &self.flow_control {
+            return flow_control.close(msg);
+        }
+
+        super::stream_sink::sender(&self.sendable_channel_handle).send_or_warn(msg)
     }
 }
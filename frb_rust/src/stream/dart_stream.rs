@@ -0,0 +1,137 @@
+use crate::dart_fn::DartFnFuture;
+use futures::task::noop_waker_ref;
+use futures::{FutureExt, Stream};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+type DartStreamPullFn<T> =
+    Box<dyn Fn(bool) -> DartFnFuture<anyhow::Result<Option<T>>> + Send + Sync + 'static>;
+
+/// A Dart [`Stream`](https://api.dart.dev/stable/dart-async/Stream-class.html)
+/// passed to Rust, and consumed as a [`futures::Stream`].
+///
+/// Items are pulled from Dart one by one, and the Dart stream is paused while Rust is not polling,
+/// so a slow Rust consumer naturally applies backpressure to the Dart producer.
+/// Error events of the Dart stream are yielded as `Err` items, and like in Dart,
+/// they do not end the stream by themselves (e.g. an `async*` generator ends after throwing).
+/// The stream ends when the Dart stream is done, and dropping it cancels the subscription on the Dart side.
+///
+/// Since items are delivered by the Dart event loop, do not block on this stream
+/// inside a `sync` function, otherwise it deadlocks.
+pub struct DartStream<T> {
+    pull: DartStreamPullFn<T>,
+    pending: Option<DartFnFuture<anyhow::Result<Option<T>>>>,
+    done: bool,
+}
+
+impl<T> DartStream<T> {
+    #[doc(hidden)]
+    pub fn new(
+        pull: impl Fn(bool) -> DartFnFuture<anyhow::Result<Option<T>>> + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            pull: Box::new(pull),
+            pending: None,
+            done: false,
+        }
+    }
+}
+
+impl<T> Stream for DartStream<T> {
+    type Item = anyhow::Result<T>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        if this.done {
+            return Poll::Ready(None);
+        }
+
+        let pending = (this.pending).get_or_insert_with(|| (this.pull)(false));
+        let output = futures::ready!(pending.poll_unpin(cx));
+        this.pending = None;
+
+        Poll::Ready(match output {
+            Ok(Some(item)) => Some(Ok(item)),
+            Ok(None) => {
+                this.done = true;
+                None
+            }
+            Err(e) => Some(Err(e)),
+        })
+    }
+}
+
+impl<T> Drop for DartStream<T> {
+    fn drop(&mut self) {
+        if self.done {
+            return;
+        }
+
+        // Polling the call once is enough to send the cancellation request to Dart,
+        // and we do not need to wait for its (empty) response.
+        let mut cancel = (self.pull)(true);
+        let _ = cancel.poll_unpin(&mut Context::from_waker(noop_waker_ref()));
+    }
+}
+
+#[cfg(test)]
+#[cfg(not(wasm))]
+mod tests {
+    use super::DartStream;
+    use futures::{StreamExt, TryStreamExt};
+    use std::sync::{Arc, Mutex};
+
+    fn create_stream(len: i32) -> (DartStream<i32>, Arc<Mutex<Vec<bool>>>) {
+        let calls = Arc::new(Mutex::new(vec![]));
+        let next = Arc::new(Mutex::new(0));
+        let stream = DartStream::new({
+            let calls = calls.clone();
+            move |cancel| {
+                calls.lock().unwrap().push(cancel);
+                let next = next.clone();
+                Box::pin(async move {
+                    let mut next = next.lock().unwrap();
+                    *next += 1;
+                    Ok((*next <= len).then_some(*next))
+                })
+            }
+        });
+        (stream, calls)
+    }
+
+    #[tokio::test]
+    async fn test_pull_until_done() {
+        let (stream, calls) = create_stream(3);
+        assert_eq!(stream.try_collect::<Vec<_>>().await.unwrap(), vec![1, 2, 3]);
+        assert_eq!(*calls.lock().unwrap(), vec![false; 4]);
+    }
+
+    #[tokio::test]
+    async fn test_cancel_when_dropped() {
+        let (mut stream, calls) = create_stream(3);
+        assert_eq!(stream.next().await.unwrap().unwrap(), 1);
+        drop(stream);
+        assert_eq!(*calls.lock().unwrap(), vec![false, true]);
+    }
+
+    #[tokio::test]
+    async fn test_forward_error() {
+        let next = Arc::new(Mutex::new(0));
+        let mut stream = DartStream::new(move |_| {
+            let next = next.clone();
+            Box::pin(async move {
+                let mut next = next.lock().unwrap();
+                *next += 1;
+                match *next {
+                    1 => Err(anyhow::anyhow!("e")),
+                    2 => Ok(Some(42)),
+                    _ => Ok(None),
+                }
+            })
+        });
+        assert_eq!(stream.next().await.unwrap().unwrap_err().to_string(), "e");
+        assert_eq!(stream.next().await.unwrap().unwrap(), 42);
+        assert!(stream.next().await.is_none());
+        assert!(stream.next().await.is_none());
+    }
+}
@@ -0,0 +1,295 @@
+use crate::platform_types::{DartAbi, MessagePort, SendableMessagePortHandle};
+use crate::rust2dart::sender::{Rust2DartSendError, Rust2DartSender};
+use crate::stream::stream_sink::{StreamSinkOverflowPolicy, StreamSinkTryAddError};
+use std::collections::{BTreeMap, VecDeque};
+use std::future::{poll_fn, Future};
+use std::sync::{Arc, Mutex};
+use std::task::{Poll, Waker};
+
+/// Flow control states of bounded stream sinks, indexed by port.
+/// An entry is removed when the close-stream message is finally sent.
+static REGISTRY: Mutex<BTreeMap<MessagePort, Arc<StreamSinkFlowControl>>> =
+    Mutex::new(BTreeMap::new());
+
+/// Limits the number of items that are sent to Dart but not consumed yet.
+pub(crate) struct StreamSinkFlowControl {
+    port: MessagePort,
+    capacity: usize,
+    sender: Rust2DartSender,
+    state: Mutex<State>,
+}
+
+#[derive(Default)]
+struct State {
+    in_flight: usize,
+    pending: VecDeque<PendingMessage>,
+    pending_close: Option<PendingMessage>,
+    policy: StreamSinkOverflowPolicy,
+    /// Dart no longer listens to the stream, thus there is no need to limit anything
+    dart_cancelled: bool,
+    wakers: Vec<Waker>,
+}
+
+struct PendingMessage(DartAbi);
+
+// The message owns all its data, and is posted to Dart at most once, so it is safe to move it
+// between threads, in the same way as sending it from arbitrary threads.
+unsafe impl Send for PendingMessage {}
+
+impl StreamSinkFlowControl {
+    pub(crate) fn new(
+        port_handle: &SendableMessagePortHandle,
+        sender: Rust2DartSender,
+        capacity: usize,
+    ) -> Option<Arc<Self>> {
+        let ans = Arc::new(Self {
+            port: *port_handle,
+            capacity: capacity.max(1),
+            sender,
+            state: Default::default(),
+        });
+        REGISTRY.lock().unwrap().insert(*port_handle, ans.clone());
+        Some(ans)
+    }
+
+    pub(crate) fn set_policy(&self, policy: StreamSinkOverflowPolicy) {
+        self.state.lock().unwrap().policy = policy;
+    }
+
+    pub(crate) fn add(&self, msg: DartAbi) -> Result<(), Rust2DartSendError> {
+        let mut state = self.state.lock().unwrap();
+        if self.has_room(&state) {
+            return self.send(&mut state, msg);
+        }
+
+        match state.policy {
+            StreamSinkOverflowPolicy::BufferAll => {}
+            StreamSinkOverflowPolicy::DropOldest => {
+                if state.pending.len() >= self.capacity {
+                    state.pending.pop_front();
+                }
+            }
+            StreamSinkOverflowPolicy::KeepLatest => state.pending.clear(),
+        }
+        state.pending.push_back(PendingMessage(msg));
+        Ok(())
+    }
+
+    pub(crate) fn try_add(&self, msg: DartAbi) -> Result<(), StreamSinkTryAddError> {
+        let mut state = self.state.lock().unwrap();
+        if !self.has_room(&state) {
+            return Err(StreamSinkTryAddError::Full);
+        }
+        (self.send(&mut state, msg)).map_err(StreamSinkTryAddError::Send)
+    }
+
+    pub(crate) fn add_async(
+        &self,
+        msg: DartAbi,
+    ) -> impl Future<Output = Result<(), Rust2DartSendError>> + Send + '_ {
+        let mut msg = Some(PendingMessage(msg));
+        poll_fn(move |cx| {
+            let mut state = self.state.lock().unwrap();
+            if self.has_room(&state) {
+                Poll::Ready(self.send(&mut state, msg.take().unwrap().0))
+            } else {
+                state.wakers.push(cx.waker().clone());
+                Poll::Pending
+            }
+        })
+    }
+
+    /// Called when Dart has consumed `count` items, or has cancelled the stream if `count` is negative
+    pub(crate) fn ack(&self, count: i32) {
+        let mut state = self.state.lock().unwrap();
+        if count < 0 {
+            state.dart_cancelled = true;
+        }
+        state.in_flight = state.in_flight.saturating_sub(count.max(0) as usize);
+
+        while state.dart_cancelled || state.in_flight < self.capacity {
+            let Some(msg) = state.pending.pop_front() else {
+                break;
+            };
+            // The stream is not usable anymore if this fails, which is reported on the next `add`
+            let _ = self.send(&mut state, msg.0);
+        }
+
+        if state.pending.is_empty() {
+            if let Some(close) = state.pending_close.take() {
+                self.send_close(close.0);
+            }
+        }
+
+        for waker in state.wakers.drain(..) {
+            waker.wake();
+        }
+    }
+
+    /// Send the close-stream message after all pending items are sent
+    pub(crate) fn close(&self, msg: DartAbi) {
+        let mut state = self.state.lock().unwrap();
+        if state.pending.is_empty() || state.dart_cancelled {
+            self.send_close(msg);
+        } else {
+            state.pending_close = Some(PendingMessage(msg));
+        }
+    }
+
+    fn has_room(&self, state: &State) -> bool {
+        state.dart_cancelled || (state.in_flight < self.capacity && state.pending.is_empty())
+    }
+
+    fn send(&self, state: &mut State, msg: DartAbi) -> Result<(), Rust2DartSendError> {
+        self.sender.send(msg)?;
+        state.in_flight += 1;
+        Ok(())
+    }
+
+    fn send_close(&self, msg: DartAbi) {
+        self.sender.send_or_warn(msg);
+        REGISTRY.lock().unwrap().remove(&self.port);
+    }
+}
+
+fn stream_sink_ack_inner(port: MessagePort, count: i32) {
+    let flow_control = REGISTRY.lock().unwrap().get(&port).cloned();
+    if let Some(flow_control) = flow_control {
+        flow_control.ack(count);
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn frb_stream_sink_ack(port: MessagePort, count: i32) {
+    stream_sink_ack_inner(port, count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{stream_sink_ack_inner, StreamSinkFlowControl, REGISTRY};
+    use crate::generalized_isolate::{Channel, IntoDart};
+    use crate::rust2dart::sender::Rust2DartSender;
+    use crate::stream::stream_sink::{StreamSinkOverflowPolicy, StreamSinkTryAddError};
+    use allo_isolate::ffi::DartCObject;
+    use std::sync::Arc;
+
+    /// Pretends that Dart receives the messages posted to the ports used by the tests,
+    /// while posting to other ports fails
+    unsafe extern "C" fn fake_post_cobject(port: i64, _message: *mut DartCObject) -> bool {
+        (100001..=100099).contains(&port)
+    }
+
+    fn create(port: i64, capacity: usize) -> Arc<StreamSinkFlowControl> {
+        unsafe { allo_isolate::store_dart_post_cobject(fake_post_cobject) };
+        StreamSinkFlowControl::new(&port, Rust2DartSender::new(Channel::new(port)), capacity)
+            .unwrap()
+    }
+
+    fn state_of(flow_control: &StreamSinkFlowControl) -> (usize, Vec<i32>) {
+        let state = flow_control.state.lock().unwrap();
+        let pending = (state.pending.iter())
+            .map(|msg| unsafe { msg.0.value.as_int32 })
+            .collect();
+        (state.in_flight, pending)
+    }
+
+    #[test]
+    fn test_buffer_all_by_default() {
+        let flow_control = create(100007, 1);
+        for i in 0..4 {
+            flow_control.add(i.into_dart()).unwrap();
+        }
+        assert_eq!(state_of(&flow_control), (1, vec![1, 2, 3]));
+
+        stream_sink_ack_inner(100007, 1);
+        assert_eq!(state_of(&flow_control), (1, vec![2, 3]));
+    }
+
+    #[test]
+    fn test_drop_oldest() {
+        let flow_control = create(100001, 2);
+        flow_control.set_policy(StreamSinkOverflowPolicy::DropOldest);
+        for i in 0..5 {
+            let _ = flow_control.add(i.into_dart());
+        }
+        assert_eq!(state_of(&flow_control), (2, vec![3, 4]));
+
+        stream_sink_ack_inner(100001, 1);
+        assert_eq!(state_of(&flow_control), (2, vec![4]));
+    }
+
+    #[test]
+    fn test_keep_latest() {
+        let flow_control = create(100002, 1);
+        flow_control.set_policy(StreamSinkOverflowPolicy::KeepLatest);
+        for i in 0..5 {
+            let _ = flow_control.add(i.into_dart());
+        }
+        assert_eq!(state_of(&flow_control), (1, vec![4]));
+    }
+
+    #[test]
+    fn test_try_add() {
+        let flow_control = create(100003, 1);
+        assert!(flow_control.try_add(0.into_dart()).is_ok());
+        assert!(matches!(
+            flow_control.try_add(1.into_dart()),
+            Err(StreamSinkTryAddError::Full)
+        ));
+        assert_eq!(state_of(&flow_control), (1, vec![]));
+    }
+
+    #[test]
+    fn test_failed_send_is_not_in_flight() {
+        let flow_control = create(100101, 1);
+        assert!(flow_control.add(0.into_dart()).is_err());
+        assert!(matches!(
+            flow_control.try_add(1.into_dart()),
+            Err(StreamSinkTryAddError::Send(_))
+        ));
+        assert_eq!(state_of(&flow_control), (0, vec![]));
+    }
+
+    #[test]
+    fn test_close_after_pending_sent() {
+        let flow_control = create(100004, 1);
+        let _ = flow_control.add(0.into_dart());
+        let _ = flow_control.add(1.into_dart());
+        flow_control.close(().into_dart());
+        assert!(REGISTRY.lock().unwrap().contains_key(&100004));
+
+        stream_sink_ack_inner(100004, 1);
+        assert!(!REGISTRY.lock().unwrap().contains_key(&100004));
+    }
+
+    #[test]
+    fn test_no_limit_after_dart_cancelled() {
+        let flow_control = create(100005, 1);
+        let _ = flow_control.add(0.into_dart());
+        let _ = flow_control.add(1.into_dart());
+        stream_sink_ack_inner(100005, -1);
+        let _ = flow_control.add(2.into_dart());
+        assert_eq!(state_of(&flow_control), (3, vec![]));
+    }
+
+    #[tokio::test]
+    async fn test_add_async_waits_for_ack() {
+        let flow_control = create(100006, 1);
+        let _ = flow_control.add(0.into_dart());
+
+        let task = tokio::spawn({
+            let flow_control = flow_control.clone();
+            async move {
+                let _ = flow_control.add_async(1.into_dart()).await;
+            }
+        });
+        for _ in 0..10 {
+            tokio::task::yield_now().await;
+        }
+        assert!(!task.is_finished());
+
+        stream_sink_ack_inner(100006, 1);
+        task.await.unwrap();
+        assert_eq!(state_of(&flow_control), (1, vec![]));
+    }
+}
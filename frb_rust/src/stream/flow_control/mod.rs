@@ -0,0 +1,9 @@
+#[cfg(wasm)]
+mod web;
+#[cfg(wasm)]
+pub(crate) use web::*;
+
+#[cfg(not(wasm))]
+mod io;
+#[cfg(not(wasm))]
+pub(crate) use io::*;
@@ -0,0 +1,43 @@
+use crate::platform_types::{DartAbi, SendableMessagePortHandle};
+use crate::rust2dart::sender::{Rust2DartSendError, Rust2DartSender};
+use crate::stream::stream_sink::{StreamSinkOverflowPolicy, StreamSinkTryAddError};
+use std::future::Future;
+use std::sync::Arc;
+
+/// Bounded stream sinks are not supported on web yet, thus they behave as unbounded ones.
+pub(crate) enum StreamSinkFlowControl {}
+
+// frb-coverage:ignore-start
+impl StreamSinkFlowControl {
+    pub(crate) fn new(
+        _port_handle: &SendableMessagePortHandle,
+        _sender: Rust2DartSender,
+        _capacity: usize,
+    ) -> Option<Arc<Self>> {
+        None
+    }
+
+    pub(crate) fn set_policy(&self, _policy: StreamSinkOverflowPolicy) {
+        match *self {}
+    }
+
+    pub(crate) fn add(&self, _msg: DartAbi) -> Result<(), Rust2DartSendError> {
+        match *self {}
+    }
+
+    pub(crate) fn try_add(&self, _msg: DartAbi) -> Result<(), StreamSinkTryAddError> {
+        match *self {}
+    }
+
+    pub(crate) fn add_async(
+        &self,
+        _msg: DartAbi,
+    ) -> impl Future<Output = Result<(), Rust2DartSendError>> + Send + '_ {
+        async move { match *self {} }
+    }
+
+    pub(crate) fn close(&self, _msg: DartAbi) {
+        match *self {}
+    }
+}
+// frb-coverage:ignore-end
@@ -1,2 +1,5 @@
 mod closer;
+#[cfg(all(feature = "rust-async", feature = "dart-opaque", feature = "anyhow"))]
+pub(crate) mod dart_stream;
+mod flow_control;
 pub(crate) mod stream_sink;
@@ -8,6 +8,8 @@ use crate::generalized_isolate::{
 use crate::platform_types::{deserialize_sendable_message_port_handle, handle_to_message_port};
 use crate::rust2dart::sender::{Rust2DartSendError, Rust2DartSender};
 use crate::stream::closer::StreamSinkCloser;
+use crate::stream::flow_control::StreamSinkFlowControl;
+use std::future::Future;
 use std::marker::PhantomData;
 use std::sync::Arc;
 
@@ -17,28 +19,110 @@ use std::sync::Arc;
 #[derive(Clone)]
 pub struct StreamSinkBase<T, Rust2DartCodec: BaseCodec> {
     sendable_channel_handle: SendableChannelHandle,
+    flow_control: Option<Arc<StreamSinkFlowControl>>,
     _closer: Arc<StreamSinkCloser<Rust2DartCodec>>,
     _phantom_data: (PhantomData<T>, PhantomData<Rust2DartCodec>),
 }
 
 impl<T, Rust2DartCodec: BaseCodec> StreamSinkBase<T, Rust2DartCodec> {
+    /// The `raw` is the serialized port, optionally followed by `,{capacity}`
+    /// when the stream is bounded via `#[frb(stream_capacity = N)]`.
     pub fn deserialize(raw: String) -> Self {
-        let sendable_channel_handle = channel_to_handle(&Channel::new(handle_to_message_port(
-            &deserialize_sendable_message_port_handle(raw),
-        )));
+        let (raw_port, capacity) = match raw.split_once(',') {
+            Some((raw_port, capacity)) => (raw_port.to_owned(), capacity.parse::<usize>().ok()),
+            None => (raw, None),
+        };
+        let port_handle = deserialize_sendable_message_port_handle(raw_port);
+        let sendable_channel_handle =
+            channel_to_handle(&Channel::new(handle_to_message_port(&port_handle)));
+
+        let flow_control = capacity.and_then(|capacity| {
+            StreamSinkFlowControl::new(&port_handle, sender(&sendable_channel_handle), capacity)
+        });
+
         Self {
             #[allow(clippy::clone_on_copy)]
             sendable_channel_handle: sendable_channel_handle.clone(),
-            _closer: Arc::new(StreamSinkCloser::new(sendable_channel_handle)),
+            _closer: Arc::new(StreamSinkCloser::new(
+                sendable_channel_handle,
+                flow_control.clone(),
+            )),
+            flow_control,
             _phantom_data: Default::default(),
         }
     }
 
     /// Add data to the stream. Returns false when data could not be sent,
     /// or the stream has been closed.
+    ///
+    /// For bounded streams, when the Dart side has not consumed enough items yet,
+    /// the data is buffered according to the [`StreamSinkOverflowPolicy`].
     pub fn add_raw(&self, value: Rust2DartCodec::Message) -> Result<(), Rust2DartSendError> {
+        if let Some(flow_control) = &self.flow_control {
+            return flow_control.add(value.into_dart_abi());
+        }
         sender(&self.sendable_channel_handle).send(value.into_dart_abi())
     }
+
+    /// Add data to the stream, or return [`StreamSinkTryAddError::Full`] without buffering
+    /// when the stream is bounded and the Dart side has not consumed enough items yet.
+    pub fn try_add_raw(&self, value: Rust2DartCodec::Message) -> Result<(), StreamSinkTryAddError> {
+        if let Some(flow_control) = &self.flow_control {
+            return flow_control.try_add(value.into_dart_abi());
+        }
+        (sender(&self.sendable_channel_handle).send(value.into_dart_abi()))
+            .map_err(StreamSinkTryAddError::Send)
+    }
+
+    /// Add data to the stream, waiting until the Dart side has room for it when the stream is bounded.
+    pub fn add_raw_async(
+        &self,
+        value: Rust2DartCodec::Message,
+    ) -> impl Future<Output = Result<(), Rust2DartSendError>> + Send + '_ {
+        let (bounded, value) = match &self.flow_control {
+            Some(flow_control) => (Some(flow_control.add_async(value.into_dart_abi())), None),
+            None => (None, Some(value)),
+        };
+        let unbounded = value.map(|value| self.add_raw(value));
+
+        async move {
+            if let Some(bounded) = bounded {
+                return bounded.await;
+            }
+            unbounded.unwrap()
+        }
+    }
+
+    /// Configure what to do with new items when the stream is bounded and full.
+    /// Has no effect for unbounded streams.
+    pub fn set_overflow_policy(&self, policy: StreamSinkOverflowPolicy) {
+        if let Some(flow_control) = &self.flow_control {
+            flow_control.set_policy(policy);
+        }
+    }
+}
+
+/// What to do when items are added to a bounded [`StreamSinkBase`]
+/// while the Dart side has not consumed enough items yet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StreamSinkOverflowPolicy {
+    /// Buffer all items until the Dart side has room for them, thus no item is lost.
+    /// The buffer is not limited by `capacity`.
+    #[default]
+    BufferAll,
+    /// Buffer up to `capacity` items, and drop the oldest buffered item when the buffer is full.
+    DropOldest,
+    /// Only keep the latest item, i.e. coalesce the buffered items.
+    KeepLatest,
+}
+
+/// Error when trying to add data to a [`StreamSinkBase`] without waiting
+#[derive(Debug, Clone)]
+pub enum StreamSinkTryAddError {
+    /// The stream is bounded, and the Dart side has not consumed enough items yet
+    Full,
+    /// Fail to send the message
+    Send(Rust2DartSendError),
 }
 
 pub(super) fn sender(sendable_channel_handle: &SendableChannelHandle) -> Rust2DartSender {
@@ -7,6 +7,26 @@ pub trait BaseThreadPool {
 #[derive(Debug, Default)]
 pub struct SimpleThreadPool(pub threadpool::ThreadPool);
 
+impl SimpleThreadPool {
+    /// Create a thread pool with the given number of threads.
+    pub fn new(num_threads: usize) -> Self {
+        Self(threadpool::ThreadPool::new(num_threads))
+    }
+
+    /// Create a thread pool whose threads have the given name,
+    /// with the given number of threads, or as many as the CPUs if [None].
+    pub fn new_with_name(name: &str, num_threads: Option<usize>) -> Self {
+        let builder = threadpool::Builder::new().thread_name(name.to_owned());
+        Self(
+            match num_threads {
+                Some(num_threads) => builder.num_threads(num_threads),
+                None => builder,
+            }
+            .build(),
+        )
+    }
+}
+
 impl BaseThreadPool for SimpleThreadPool {
     fn execute<F>(&self, job: F)
     where
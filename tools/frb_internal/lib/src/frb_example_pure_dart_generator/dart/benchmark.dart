@@ -13,6 +13,7 @@ String generateBenchmark() {
     ..._benchmarkBytes(),
     ..._benchmarkBinaryTree(),
     ..._benchmarkBlob(),
+    ..._benchmarkSmallInts(),
   ];
 
   return '''
@@ -27,8 +28,11 @@ import 'dart:typed_data';
 
 import 'package:benchmark_harness/benchmark_harness.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart'
+    show SseDeserializer;
 import 'package:frb_example_pure_dart/src/rust/api/benchmark_misc.dart';
 import 'package:frb_example_pure_dart/src/rust/api/pseudo_manual/benchmark_api.dart';
+import 'package:frb_example_pure_dart/src/rust/api/pseudo_manual/benchmark_api_twin_sse.dart';
 import 'package:frb_example_pure_dart/src/rust/api/pseudo_manual/benchmark_api_twin_sync.dart';
 import 'package:frb_example_pure_dart/src/rust/api/pseudo_manual/benchmark_api_twin_sync_sse.dart';
 import 'package:frb_example_pure_dart/src/rust/frb_generated.dart';
@@ -67,6 +71,7 @@ enum _Approach {
   frb,
   frbSse,
   frbCstSse,
+  frbCse,
   raw,
   protobuf,
   json,
@@ -287,6 +292,20 @@ List<_Benchmark> _benchmarkBytes() {
         run:
             '${asynchronous ? "await" : ""} benchmarkInputBytesTwin${asynchronous ? "Normal" : "Sync"}(bytes: setupData);',
       ),
+    for (final asynchronous in [true, false])
+      _Benchmark(
+        task: task,
+        approach: _Approach.frbSse,
+        direction: _Direction.input,
+        asynchronous: asynchronous,
+        args: args,
+        // The largest one is roughly the size of an image frame
+        argValues: [...argValues, '50000000'],
+        setupDataType: 'Uint8List',
+        setup: 'setupData = Uint8List(len);',
+        run:
+            '${asynchronous ? "await" : ""} benchmarkInputBytesTwin${asynchronous ? "Sse" : "SyncSse"}(bytes: setupData);',
+      ),
     const _Benchmark(
       task: task,
       approach: _Approach.raw,
@@ -591,3 +610,58 @@ List<_Benchmark> _benchmarkBlob() {
     ),
   ];
 }
+
+// Only decodes the data (as is done for the output of Rust functions),
+// to compare the SSE codec with the compact (CSE) one
+List<_Benchmark> _benchmarkSmallInts() {
+  const task = 'SmallInts';
+  const args = [_TypedName('int', 'len')];
+  const argValues = ['10', '1000', '100000'];
+
+  return [
+    for (final compact in [false, true])
+      _Benchmark(
+        task: task,
+        approach: compact ? _Approach.frbCse : _Approach.frbSse,
+        direction: _Direction.output,
+        asynchronous: false,
+        args: args,
+        argValues: argValues,
+        setupDataType: 'ByteData',
+        setup: 'setupData = _encode(len);',
+        run: '''
+          final deserializer = SseDeserializer(setupData, compact: $compact);
+          final ans = deserializer.buffer.getInt32List(deserializer.buffer.getInt32());
+          dummyValue ^= ans.length;
+        ''',
+        extra: compact
+            ? '''
+              // Each value is a zigzag-encoded single-byte varint
+              static ByteData _encode(int len) {
+                final bytes = <int>[];
+                for (var rest = len * 2; ; rest ~/= 0x80) {
+                  if (rest < 0x80) {
+                    bytes.add(rest);
+                    break;
+                  }
+                  bytes.add(rest % 0x80 + 0x80);
+                }
+                for (var i = 0; i < len; ++i) {
+                  bytes.add((i % 50) * 2);
+                }
+                return Uint8List.fromList(bytes).buffer.asByteData();
+              }
+            '''
+            : '''
+              static ByteData _encode(int len) {
+                final data = ByteData(4 + 4 * len);
+                data.setInt32(0, len, Endian.host);
+                for (var i = 0; i < len; ++i) {
+                  data.setInt32(4 + 4 * i, i % 50, Endian.host);
+                }
+                return data;
+              }
+            ''',
+      ),
+  ];
+}
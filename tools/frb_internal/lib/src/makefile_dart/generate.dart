@@ -167,8 +167,10 @@ Future<void> generateInternalBookHelp(GenerateConfig config) async {
     for (final (cmd, extraArgs) in [
       ('', ''),
       ('generate', ''),
+      ('check', ''),
       ('create', ''),
       ('integrate', ''),
+      ('api-diff', ''),
       ('build-web', '--dart-root ${exec.pwd}frb_example/pure_dart'),
     ]) {
       final resp = await executeFrbCodegen(
@@ -70,8 +70,13 @@ const sidebars = {
                                         'guides/types/translatable/detailed/tuple',
                                         'guides/types/translatable/detailed/option',
                                         'guides/types/translatable/detailed/alias',
+                                        'guides/types/translatable/detailed/generics',
                                         'guides/types/translatable/detailed/map_set',
                                         'guides/types/translatable/detailed/chrono',
+                                        'guides/types/translatable/detailed/std_time',
+                                        'guides/types/translatable/detailed/time',
+                                        'guides/types/translatable/detailed/big_number',
+                                        'guides/types/translatable/detailed/json',
                                         'guides/types/translatable/detailed/uuid',
                                         'guides/types/translatable/detailed/primitive',
                                     ],
@@ -272,6 +277,7 @@ const sidebars = {
                             items: [
                                 'guides/custom/codegen/inputs',
                                 'guides/custom/codegen/full-list',
+                                'guides/custom/codegen/cache',
                             ],
                         },
                         {
@@ -408,6 +414,7 @@ const sidebars = {
                         'guides/how-to/regression',
                         'guides/how-to/object-pool',
                         'guides/how-to/gitignore',
+                        'guides/how-to/api-compatibility',
                         'guides/how-to/rust-compilation',
                         'guides/how-to/cargo-workspaces',
                         'guides/how-to/cross-origin',